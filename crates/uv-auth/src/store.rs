@@ -5,6 +5,7 @@ use fs_err as fs;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use uv_dirs::DirectorySource;
 use uv_fs::{LockedFile, LockedFileError, LockedFileMode};
 use uv_preview::{Preview, PreviewFeature};
 use uv_redacted::DisplaySafeUrl;
@@ -244,14 +245,35 @@ pub struct TextCredentialStore {
 impl TextCredentialStore {
     /// Return the directory for storing credentials.
     pub fn directory_path() -> Result<PathBuf, TomlCredentialError> {
-        if let Some(dir) = std::env::var_os(EnvVars::UV_CREDENTIALS_DIR)
+        Ok(Self::directory_path_with_source()?.0)
+    }
+
+    /// Return the directory for storing credentials, along with the [`DirectorySource`] from which
+    /// it was resolved.
+    ///
+    /// Warns if the directory was overridden, but is empty, while the default directory contains
+    /// existing credentials.
+    pub fn directory_path_with_source() -> Result<(PathBuf, DirectorySource), TomlCredentialError> {
+        let (dir, source) = if let Some(dir) = std::env::var_os(EnvVars::UV_CREDENTIALS_DIR)
             .filter(|s| !s.is_empty())
             .map(PathBuf::from)
         {
-            return Ok(dir);
-        }
-
-        Ok(StateStore::from_settings(None)?.bucket(StateBucket::Credentials))
+            (
+                dir,
+                DirectorySource::EnvironmentVariable(EnvVars::UV_CREDENTIALS_DIR),
+            )
+        } else {
+            let (store, source) = StateStore::user_with_source();
+            (store.bucket(StateBucket::Credentials), source)
+        };
+        uv_dirs::warn_if_hidden_default("Credentials", &dir, source, || {
+            Some(
+                StateStore::user_with_source()
+                    .0
+                    .bucket(StateBucket::Credentials),
+            )
+        });
+        Ok((dir, source))
     }
 
     /// Return the standard file path for storing credentials.
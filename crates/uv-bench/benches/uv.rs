@@ -215,6 +215,7 @@ fn install_wheel_many_files(c: &mut Criterion<WallTime>) {
                     None,
                     None::<&()>,
                     None::<&()>,
+                    None::<&()>,
                     Some("uv"),
                     true,
                     LinkMode::default(),
@@ -394,7 +395,9 @@ mod resolver {
 
     use uv_cache::Cache;
     use uv_client::RegistryClient;
-    use uv_configuration::{BuildOptions, Concurrency, Constraints, IndexStrategy, NoSources};
+    use uv_configuration::{
+        BuildOptions, BuildRuntime, Concurrency, Constraints, IndexStrategy, NoSources,
+    };
     use uv_dispatch::{BuildDispatch, SharedState};
     use uv_distribution::DistributionDatabase;
     use uv_distribution_types::{
@@ -463,6 +466,7 @@ mod resolver {
         let extra_build_requires = ExtraBuildRequires::default();
         let extra_build_variables = ExtraBuildVariables::default();
         let build_options = BuildOptions::default();
+        let build_runtime = BuildRuntime::default();
         let concurrency = Concurrency::default();
         let config_settings = ConfigSettings::default();
         let config_settings_package = PackageConfigSettings::default();
@@ -514,6 +518,7 @@ mod resolver {
             &extra_build_variables,
             LinkMode::default(),
             &build_options,
+            &build_runtime,
             &hashes,
             exclude_newer,
             sources,
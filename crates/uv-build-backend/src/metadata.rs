@@ -21,7 +21,8 @@ use uv_pep508::{
     ExtraOperator, MarkerExpression, MarkerTree, MarkerValueExtra, Requirement, VersionOrUrl,
 };
 use uv_pypi_types::{
-    Identifier, IdentifierParseError, Keywords, Metadata23, ProjectUrls, VerbatimParsedUrl,
+    Identifier, IdentifierParseError, Keywords, Metadata23, ObjectReference,
+    ObjectReferenceParseError, ProjectUrls, VerbatimParsedUrl,
 };
 use uv_toml::deserialize_unique_map;
 
@@ -80,12 +81,26 @@ pub enum ValidationError {
         "Script entry point name `{0}` must include a non-dot character and consist only of letters, numbers, dots, underscores and dashes"
     )]
     InvalidScriptName(String),
+    #[error("Invalid entry point `{name}` in `{table}`")]
+    InvalidObjectReference {
+        table: &'static str,
+        name: String,
+        #[source]
+        err: ObjectReferenceParseError,
+    },
     #[error("Use `project.scripts` instead of `project.entry-points.console_scripts`")]
     ReservedScripts,
     #[error("Use `project.gui-scripts` instead of `project.entry-points.gui_scripts`")]
     ReservedGuiScripts,
-    #[error("`project.license` is not a valid SPDX expression: {0}")]
-    InvalidSpdx(String, #[source] spdx::error::ParseError),
+    #[error(
+        "`project.license` is not a valid SPDX expression: {reason} in `{expression}`{}",
+        suggestion.as_ref().map(|suggestion| format!(" (did you mean `{suggestion}`?)")).unwrap_or_default()
+    )]
+    InvalidSpdx {
+        expression: String,
+        reason: String,
+        suggestion: Option<String>,
+    },
     #[error("`{field}` glob `{glob}` did not match any files")]
     LicenseGlobNoMatches { field: String, glob: String },
     #[error("License file `{}` must be UTF-8 encoded", _0)]
@@ -764,11 +779,6 @@ impl PyProjectToml {
                     .strip_prefix(root)
                     .expect("walkdir starts with root");
 
-                if !license_globs.match_path(relative) {
-                    trace!("Not a license files match: {}", relative.user_display());
-                    continue;
-                }
-
                 let file_type = entry.file_type();
 
                 if !(file_type.is_file() || file_type.is_symlink()) {
@@ -779,6 +789,11 @@ impl PyProjectToml {
                     continue;
                 }
 
+                if !license_globs.match_file(relative) {
+                    trace!("Not a license files match: {}", relative.user_display());
+                    continue;
+                }
+
                 error_on_venv(entry.file_name(), entry.path())?;
 
                 debug!("License files match: {}", relative.user_display());
@@ -837,12 +852,12 @@ impl PyProjectToml {
             }
         };
 
-        // Check that the license expression is a valid SPDX identifier.
-        if let Some(license_expression) = &license_expression {
-            if let Err(err) = spdx::Expression::parse(license_expression) {
-                return Err(ValidationError::InvalidSpdx(license_expression.clone(), err).into());
-            }
-        }
+        // Check that the license expression is a valid SPDX expression, normalizing the case of
+        // the license identifiers and operators.
+        let license_expression = license_expression
+            .as_deref()
+            .map(normalize_license_expression)
+            .transpose()?;
 
         // Reconcile any structured license metadata against the deprecated license classifiers.
         for classifier in self.project.classifiers.iter().flatten() {
@@ -933,8 +948,26 @@ impl PyProjectToml {
                 );
             }
 
-            // TODO(konsti): Validate that the object references are valid Python identifiers.
-            let _ = writeln!(writer, "{name} = {object_reference}");
+            // Scripts must reference a callable, so unlike plugin entry points, they can't
+            // reference a module.
+            let table = match group {
+                "console_scripts" => Some("project.scripts"),
+                "gui_scripts" => Some("project.gui-scripts"),
+                _ => None,
+            };
+            if let Some(table) = table {
+                let object_reference =
+                    ObjectReference::from_str(object_reference).map_err(|err| {
+                        ValidationError::InvalidObjectReference {
+                            table,
+                            name: name.clone(),
+                            err,
+                        }
+                    })?;
+                let _ = writeln!(writer, "{name} = {object_reference}");
+            } else {
+                let _ = writeln!(writer, "{name} = {object_reference}");
+            }
         }
         writer.push('\n');
         Ok(())
@@ -1247,6 +1280,81 @@ impl BuildSystem {
     }
 }
 
+/// Validate a PEP 639 license expression, returning it with the case of the license and exception
+/// identifiers and the operators normalized, e.g., `mit or apache-2.0` to `MIT OR Apache-2.0`.
+///
+/// <https://peps.python.org/pep-0639/#normalization>
+fn normalize_license_expression(expression: &str) -> Result<String, ValidationError> {
+    const LICENSE_REF: &str = "LicenseRef-";
+
+    // Only the ASCII case of each term changes, such that spans in the normalized expression
+    // point to the same terms in the original expression.
+    let mut normalized = String::with_capacity(expression.len());
+    let mut rest = expression;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+            .unwrap_or(rest.len());
+        if end == 0 {
+            let separator = rest.chars().next().expect("not empty");
+            normalized.push(separator);
+            rest = &rest[separator.len_utf8()..];
+            continue;
+        }
+        let (term, tail) = rest.split_at(end);
+        let (name, plus) = term
+            .strip_suffix('+')
+            .map_or((term, ""), |name| (name, "+"));
+        if ["AND", "OR", "WITH"]
+            .iter()
+            .any(|operator| operator.eq_ignore_ascii_case(term))
+        {
+            normalized.push_str(&term.to_ascii_uppercase());
+        } else if term
+            .get(..LICENSE_REF.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(LICENSE_REF))
+        {
+            normalized.push_str(LICENSE_REF);
+            normalized.push_str(&term[LICENSE_REF.len()..]);
+        } else if let Some(license) = spdx::identifiers::LICENSES
+            .iter()
+            .find(|license| license.name.eq_ignore_ascii_case(name))
+        {
+            normalized.push_str(license.name);
+            normalized.push_str(plus);
+        } else if let Some(exception) = spdx::identifiers::EXCEPTIONS
+            .iter()
+            .find(|exception| exception.name.eq_ignore_ascii_case(term))
+        {
+            normalized.push_str(exception.name);
+        } else {
+            normalized.push_str(term);
+        }
+        rest = tail;
+    }
+
+    if let Err(err) = spdx::Expression::parse(&normalized) {
+        let term = expression.get(err.span.clone()).unwrap_or_default();
+        let reason = if term.is_empty() {
+            err.reason.to_string()
+        } else {
+            format!("{} `{term}`", err.reason)
+        };
+        // Suggest a valid expression for common misspellings, e.g., `Apache 2.0`.
+        let suggestion = spdx::Expression::canonicalize(expression)
+            .ok()
+            .flatten()
+            .filter(|suggestion| spdx::Expression::parse(suggestion).is_ok());
+        return Err(ValidationError::InvalidSpdx {
+            expression: expression.to_string(),
+            reason,
+            suggestion,
+        });
+    }
+
+    Ok(normalized)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1362,7 +1470,7 @@ mod tests {
             foo = "foo.cli:__main__"
 
             [project.gui-scripts]
-            foo-gui = "foo.gui"
+            foo-gui = "foo.gui:main"
 
             [project.entry-points.bar_group]
             foo-bar = "foo:bar"
@@ -1416,7 +1524,7 @@ mod tests {
         foo = foo.cli:__main__
 
         [gui_scripts]
-        foo-gui = foo.gui
+        foo-gui = foo.gui:main
 
         [bar_group]
         foo-bar = foo:bar
@@ -1655,7 +1763,7 @@ mod tests {
             foo = "foo.cli:__main__"
 
             [project.gui-scripts]
-            foo-gui = "foo.gui"
+            foo-gui = "foo.gui:main"
 
             [project.entry-points.bar_group]
             foo-bar = "foo:bar"
@@ -1716,7 +1824,7 @@ mod tests {
         foo = foo.cli:__main__
 
         [gui_scripts]
-        foo-gui = foo.gui
+        foo-gui = foo.gui:main
 
         [bar_group]
         foo-bar = foo:bar
@@ -1941,12 +2049,44 @@ mod tests {
             .unwrap()
             .to_metadata(Path::new("/do/not/read"))
             .unwrap_err();
-        // TODO(konsti): We mess up the indentation in the error.
         assert_snapshot!(format_err(err), @"
         Invalid project metadata
-          Caused by: `project.license` is not a valid SPDX expression: MIT XOR Apache-2
-          Caused by: MIT XOR Apache-2
-            ^^^ unknown term
+          Caused by: `project.license` is not a valid SPDX expression: unknown term `XOR` in `MIT XOR Apache-2`
+        ");
+    }
+
+    /// License identifiers and operators are case-insensitive, and normalized in the metadata.
+    #[test]
+    fn license_case_normalization() {
+        let contents = extend_project(indoc! {r#"
+            license = "(apache-2.0 or mit) and gpl-2.0-or-later with classpath-exception-2.0 and licenseref-Proprietary"
+        "#
+        });
+        let metadata = toml::from_str::<PyProjectToml>(&contents)
+            .unwrap()
+            .to_metadata(Path::new("/do/not/read"))
+            .unwrap();
+        assert_snapshot!(metadata.core_metadata_format(), @"
+        Metadata-Version: 2.4
+        Name: hello-world
+        Version: 0.1.0
+        License-Expression: (Apache-2.0 OR MIT) AND GPL-2.0-or-later WITH Classpath-exception-2.0 AND LicenseRef-Proprietary
+        ");
+    }
+
+    #[test]
+    fn invalid_license_suggestion() {
+        let contents = extend_project(indoc! {r#"
+            license = "Apache2 OR MIT"
+        "#
+        });
+        let err = toml::from_str::<PyProjectToml>(&contents)
+            .unwrap()
+            .to_metadata(Path::new("/do/not/read"))
+            .unwrap_err();
+        assert_snapshot!(format_err(err), @"
+        Invalid project metadata
+          Caused by: `project.license` is not a valid SPDX expression: unknown term `Apache2` in `Apache2 OR MIT` (did you mean `Apache-2.0 OR MIT`?)
         ");
     }
 
@@ -2041,6 +2181,43 @@ mod tests {
         assert_snapshot!(script_error(&contents), @"Use `project.gui-scripts` instead of `project.entry-points.gui_scripts`");
     }
 
+    #[test]
+    fn invalid_entry_point_object_reference() {
+        let contents = extend_project(indoc! {r#"
+            [project.scripts]
+            mycli = "mypkg.cli.main"
+        "#
+        });
+        assert_snapshot!(script_error(&contents), @"
+        Invalid entry point `mycli` in `project.scripts`
+          Caused by: Expected an object reference of the form `module:object`, but `mypkg.cli.main` has no `:` (did you mean `mypkg.cli:main`?)
+        ");
+    }
+
+    #[test]
+    fn normalize_entry_point_object_reference() {
+        let contents = extend_project(indoc! {r#"
+            [project.gui-scripts]
+            mygui = "mypkg.gui : main [qt ,color]"
+
+            [project.entry-points.pytest11]
+            myplugin = "mypkg.plugin"
+        "#
+        });
+        let entry_points = toml::from_str::<PyProjectToml>(&contents)
+            .unwrap()
+            .to_entry_points()
+            .unwrap()
+            .unwrap();
+        assert_snapshot!(entry_points, @"
+        [gui_scripts]
+        mygui = mypkg.gui:main [qt, color]
+
+        [pytest11]
+        myplugin = mypkg.plugin
+        ");
+    }
+
     #[test]
     fn check_direct_build_ok() {
         let temp_dir = TempDir::new().unwrap();
@@ -594,7 +594,12 @@ fn wheel_subdir_from_globs(
             .strip_prefix(src)
             .expect("walkdir starts with root");
 
-        if !matcher.match_path(relative) {
+        let is_match = if entry.file_type().is_dir() {
+            matcher.match_directory(relative)
+        } else {
+            matcher.match_file(relative)
+        };
+        if !is_match {
             trace!("Excluding {}: {}", globs_field, relative.user_display());
             continue;
         }
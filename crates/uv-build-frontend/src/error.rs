@@ -1,20 +1,26 @@
 use std::env;
 use std::fmt::{Display, Formatter};
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
+use std::str::FromStr;
 use std::sync::LazyLock;
+use std::time::Duration;
 
 use crate::PythonRunnerOutput;
+use crate::events::Failure;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use regex::Regex;
 use thiserror::Error;
 use uv_configuration::BuildOutput;
-use uv_distribution_types::IsBuildBackendError;
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{BuildTarget, IsBuildBackendError};
 use uv_errors::{Hint, Hints};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_static::EnvVars;
 use uv_types::AnyErrorBuild;
 
 /// e.g. `pygraphviz/graphviz_wrap.c:3020:10: fatal error: graphviz/cgraph.h: No such file or directory`
@@ -55,6 +61,49 @@ static MODULE_NOT_FOUND: LazyLock<Regex> = LazyLock::new(|| {
 static DISTUTILS_NOT_FOUND_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"ModuleNotFoundError: No module named 'distutils'").unwrap());
 
+/// e.g. `FileNotFoundError: [WinError 206] The filename or extension is too long: 'C:\\Users\\...'`
+static WINDOWS_LONG_PATH_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[WinError (?:3|206)\] (?:The system cannot find the path specified|The filename or extension is too long): '(?<path>[^']+)'")
+        .unwrap()
+});
+
+/// The maximum length of a path on Windows without long path support (`MAX_PATH`).
+const WINDOWS_MAX_PATH: usize = 260;
+
+/// e.g. `PermissionError: [Errno 13] Permission denied: '/usr/lib/python3/dist-packages/foo.pth'`
+/// or `error: could not create '/usr/share/foo': Permission denied`
+static PERMISSION_DENIED_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[Errno 13\] Permission denied: '(?<path>[^']+)'|could not create '(?<created>[^']+)': Permission denied")
+        .unwrap()
+});
+
+/// e.g. `ERROR: Package 'foo' requires a different Python: 3.8.18 not in '>=3.9'`
+static REQUIRES_PYTHON_RE_PIP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"requires a different Python: (?<installed>\S+) not in ['"](?<required>[^'"]+)['"]"#,
+    )
+    .unwrap()
+});
+
+/// e.g. `ValueError: Python 3.8.18 is not supported by this project (requires-python: >=3.9)`
+static REQUIRES_PYTHON_RE_HATCHLING: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"Python (?<installed>\d+\.\d+\S*) is not supported by this project \(requires-python: ['"]?(?<required>[^'")]+)['"]?\)"#)
+        .unwrap()
+});
+
+/// e.g. `#error "Unknown architecture"` or `/bin/sh: ./configure-helper: cannot execute binary file: Exec format error`
+static CROSS_ARCHITECTURE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"Unknown architecture|cannot execute binary file: Exec format error").unwrap()
+});
+
+/// e.g. `PermissionError: [WinError 5] Access is denied: 'build\\lib\\foo.pyd'`,
+/// `[WinError 32] The process cannot access the file because it is being used by another process`,
+/// or `OSError: [Errno 116] Stale file handle`
+static TRANSIENT_IO_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[WinError (?:5|32)\]|Access is denied\. \(os error 5\)|being used by another process|Stale file handle")
+        .unwrap()
+});
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
@@ -71,10 +120,14 @@ pub enum Error {
     InvalidPyprojectTomlSchema(#[from] toml_edit::de::Error),
     #[error("`backend-path` entry `{0}` does not exist or is not a directory")]
     InvalidBackendPath(String),
+    #[error("`backend-path` entry `{0}` refers to a location outside the source tree")]
+    BackendPathOutsideSourceTree(String),
     #[error("Failed to resolve requirements from {0}")]
     RequirementsResolve(&'static str, #[source] AnyErrorBuild),
     #[error("Failed to install requirements from {0}")]
     RequirementsInstall(&'static str, #[source] AnyErrorBuild),
+    #[error("Failed to resolve the locked build requirements for `{0}`")]
+    LockedBuildRequirements(PackageName, #[source] AnyErrorBuild),
     #[error("Failed to create temporary virtualenv")]
     Virtualenv(#[from] uv_virtualenv::Error),
     // Build backend errors
@@ -84,6 +137,64 @@ pub enum Error {
     BuildBackend(#[from] BuildBackendError),
     #[error("The build backend returned an error")]
     MissingHeader(#[from] Box<MissingHeaderError>),
+    #[error(
+        "The build backend was terminated after exceeding the build timeout ({}s)",
+        elapsed.as_secs()
+    )]
+    BuildTimeout {
+        elapsed: Duration,
+        #[source]
+        err: Box<Self>,
+    },
+    #[error("The build failed after {attempts} attempts")]
+    BuildRetriesExhausted {
+        attempts: u32,
+        #[source]
+        err: Box<Self>,
+    },
+    #[error(
+        "`{package}` requires Python {requires_python}, but the build environment uses Python {python_version}"
+    )]
+    IncompatibleBuildPython {
+        package: String,
+        requires_python: VersionSpecifiers,
+        python_version: Version,
+        /// Whether an interpreter for builds was requested (e.g., with `--build-python`).
+        build_python: bool,
+    },
+    #[error("Failed to find a Python interpreter for `--build-python {request}`")]
+    BuildPython {
+        request: String,
+        #[source]
+        err: Box<uv_python::Error>,
+    },
+    #[error("The build backend `{0}` does not support editable installs")]
+    EditableUnsupported(String),
+    #[error("The build backend `{0}` does not support building wheels")]
+    WheelUnsupported(String),
+    #[error("Failed to {stage} while building a wheel via a source distribution")]
+    ViaSdist {
+        stage: ViaSdistStage,
+        #[source]
+        err: Box<Self>,
+    },
+    #[error("The build backend returned a wheel with an invalid filename")]
+    InvalidWheelFilename(#[from] uv_distribution_filename::WheelFilenameError),
+    #[error(transparent)]
+    Tags(#[from] uv_platform_tags::TagsError),
+    #[error(
+        "The built wheel `{filename}` is tagged for `{}-{}`, which is incompatible with the build target `{target}`",
+        filename.abi_tags().iter().join("."),
+        filename.platform_tags().iter().join("."),
+    )]
+    IncompatibleBuildTarget {
+        filename: Box<WheelFilename>,
+        target: BuildTarget,
+    },
+    #[error("The build backend returned a source distribution with an unsupported extension: `{}`", _0.user_display())]
+    InvalidSdistExtension(PathBuf, #[source] uv_distribution_filename::ExtensionError),
+    #[error(transparent)]
+    Extract(#[from] uv_extract::Error),
     #[error("Failed to build PATH for build script")]
     BuildScriptPath(#[source] env::JoinPathsError),
     // For the convenience of typing `setup_build` properly.
@@ -91,8 +202,11 @@ pub enum Error {
     NoSourceDistBuild(PackageName),
     #[error("Building source distributions is disabled")]
     NoSourceDistBuilds,
-    #[error("Cyclic build dependency detected for `{0}`")]
-    CyclicBuildDependency(PackageName),
+    #[error("Cyclic build dependency detected: {}", cycle.join(" -> "))]
+    CyclicBuildDependency {
+        package: PackageName,
+        cycle: Vec<String>,
+    },
     #[error(
         "Extra build requirement `{0}` was declared with `match-runtime = true`, but `{1}` does not declare static metadata, making runtime-matching impossible"
     )]
@@ -108,17 +222,32 @@ impl IsBuildBackendError for Error {
             | Self::InvalidPyprojectTomlSyntax(_)
             | Self::InvalidPyprojectTomlSchema(_)
             | Self::InvalidBackendPath(_)
+            | Self::BackendPathOutsideSourceTree(_)
             | Self::RequirementsResolve(_, _)
             | Self::RequirementsInstall(_, _)
+            | Self::LockedBuildRequirements(_, _)
             | Self::Virtualenv(_)
             | Self::NoSourceDistBuild(_)
             | Self::NoSourceDistBuilds
-            | Self::CyclicBuildDependency(_)
-            | Self::UnmatchedRuntime(_, _) => false,
+            | Self::CyclicBuildDependency { .. }
+            | Self::UnmatchedRuntime(_, _)
+            | Self::IncompatibleBuildPython { .. }
+            | Self::BuildPython { .. }
+            | Self::InvalidSdistExtension(_, _)
+            | Self::Tags(_)
+            | Self::Extract(_) => false,
             Self::CommandFailed(_, _)
             | Self::BuildBackend(_)
             | Self::MissingHeader(_)
+            | Self::BuildTimeout { .. }
+            | Self::EditableUnsupported(_)
+            | Self::WheelUnsupported(_)
+            | Self::InvalidWheelFilename(_)
+            | Self::IncompatibleBuildTarget { .. }
             | Self::BuildScriptPath(_) => true,
+            Self::BuildRetriesExhausted { err, .. } | Self::ViaSdist { err, .. } => {
+                err.is_build_backend_error()
+            }
         }
     }
 }
@@ -130,19 +259,89 @@ impl Hint for Error {
                 "Build failures usually indicate a problem with the package or the build environment",
             ),
             Self::MissingHeader(err) => Hints::from(err.cause.to_string()),
+            Self::BuildRetriesExhausted { err, .. } | Self::ViaSdist { err, .. } => err.hints(),
+            Self::BuildTimeout { err, .. } => {
+                let mut hints = Hints::from(format!(
+                    "To allow builds to take longer, increase the timeout with `{}` or `{}`",
+                    "--build-timeout".green(),
+                    EnvVars::UV_BUILD_TIMEOUT.green()
+                ));
+                hints.extend(err.hints());
+                hints
+            }
+            Self::EditableUnsupported(backend) => {
+                if crate::is_setuptools(backend) {
+                    Hints::from(format!(
+                        "`setuptools` added support for editable installs (PEP 660) in v64.0.0. Consider building with `{}`, or installing without `{}`",
+                        "setuptools>=64".green(),
+                        "--editable".green()
+                    ))
+                } else {
+                    Hints::from(format!(
+                        "Consider installing without `{}`",
+                        "--editable".green()
+                    ))
+                }
+            }
+            Self::CyclicBuildDependency { package, .. } => Hints::from(format!(
+                "Build `{package}` without isolation (e.g., with `{}`), or provide a pre-built wheel for one of the packages in the cycle",
+                format!("--no-build-isolation-package {package}").green()
+            )),
+            Self::IncompatibleBuildPython {
+                requires_python,
+                build_python: false,
+                ..
+            } => Hints::from(format!(
+                "To build with a compatible Python version, select an interpreter for builds with `{}` (e.g., `{}`)",
+                "--build-python".green(),
+                format!("--build-python \"{requires_python}\"").green(),
+            )),
+            Self::IncompatibleBuildTarget { .. } => Hints::from(format!(
+                "The build backend may not support cross-compilation, or may require additional configuration (e.g., a cross-compiler) to build for the target. Build the package on the target platform, or remove `{}`",
+                "--build-target".green()
+            )),
             Self::Lowering(err) => err.hints(),
+            Self::LockedBuildRequirements(..) => Hints::from(format!(
+                "The locked build requirements may no longer be available; run `{}` to refresh them",
+                "uv lock --upgrade".green()
+            )),
             Self::RequirementsResolve(_, err) | Self::RequirementsInstall(_, err) => err.hints(),
             _ => Hints::none(),
         }
     }
 }
 
+/// A stage of building a wheel via a source distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViaSdistStage {
+    /// Building the source distribution with the `build_sdist` hook.
+    BuildSdist,
+    /// Extracting the built source distribution.
+    Extract,
+    /// Building the wheel from the extracted source distribution with the `build_wheel` hook.
+    BuildWheel,
+}
+
+impl Display for ViaSdistStage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BuildSdist => write!(f, "build the source distribution"),
+            Self::Extract => write!(f, "extract the source distribution"),
+            Self::BuildWheel => write!(f, "build the wheel from the source distribution"),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum MissingLibrary {
     Header(String),
     Linker(String),
     BuildDependency(String),
     DeprecatedModule(String, Version),
+    RequiresPython { required: String, installed: String },
+    LongPath(usize),
+    PermissionDenied(PathBuf),
+    CrossArchitecture,
 }
 
 #[derive(Debug, Error)]
@@ -151,6 +350,8 @@ pub struct MissingHeaderCause {
     package_name: Option<PackageName>,
     package_version: Option<Version>,
     version_id: Option<String>,
+    /// Whether the package comes from a registry, such that a pre-built wheel may be available.
+    registry: bool,
 }
 
 /// Extract the package name from a version specifier string.
@@ -165,13 +366,43 @@ fn extract_package_name(version_id: &str) -> &str {
         .last()
         .map_or(0, |(i, c)| i + c.len_utf8());
 
-    if end == 0 {
+    let name = if end == 0 {
         version_id
     } else {
         &version_id[..end]
+    };
+
+    // Strip the version from identifiers of the form `{name}-{version}`, e.g., `foo-1.0`.
+    match name.rsplit_once('-') {
+        Some((prefix, version)) if !prefix.is_empty() && Version::from_str(version).is_ok() => {
+            prefix
+        }
+        _ => name,
     }
 }
 
+/// Return the length of a path as it appears in a Python `repr`, in which backslashes are escaped.
+fn windows_path_length(path: &str) -> usize {
+    path.replace(r"\\", r"\").chars().count()
+}
+
+/// Return the path that the build was denied access to, if it lies outside of the isolated build
+/// environment.
+///
+/// Permission errors within the build environment are left unclassified, as they're unlikely to be
+/// caused by the build script itself, as are permission errors of builds without build isolation
+/// (i.e., without an isolated build environment), which are expected to write to the environment.
+fn permission_denied_path(line: &str, build_environment: Option<&Path>) -> Option<PathBuf> {
+    let build_environment = build_environment?;
+    let caps = PERMISSION_DENIED_RE.captures(line)?;
+    let path = caps.name("path").or_else(|| caps.name("created"))?.as_str();
+    let path = PathBuf::from(path.replace(r"\\", r"\"));
+    if !path.has_root() || path.starts_with(build_environment) {
+        return None;
+    }
+    Some(path)
+}
+
 /// Write a hint about missing build dependencies.
 fn hint_build_dependency(
     f: &mut std::fmt::Formatter<'_>,
@@ -202,10 +433,51 @@ fn hint_build_dependency(
         table_key.cyan(),
         package.cyan(),
         format!("uv pip install {package}").green(),
-        "--no-build-isolation".green(),
+        format!("--no-build-isolation-package {package_name}").green(),
     )
 }
 
+impl MissingHeaderCause {
+    /// Return the display name of the package (e.g., `foo@1.0.0`), along with its name, if known.
+    fn package(&self) -> Option<(String, String)> {
+        if let (Some(package_name), Some(package_version)) =
+            (&self.package_name, &self.package_version)
+        {
+            Some((
+                format!("{package_name}@{package_version}"),
+                package_name.to_string(),
+            ))
+        } else {
+            self.version_id.as_deref().map(|version_id| {
+                (
+                    version_id.to_string(),
+                    extract_package_name(version_id).to_string(),
+                )
+            })
+        }
+    }
+
+    /// Return the kind of missing library, along with the missing library itself, if any.
+    fn classify(&self) -> (&'static str, Option<String>) {
+        match &self.missing_library {
+            MissingLibrary::Header(header) => ("header", Some(header.clone())),
+            MissingLibrary::Linker(library) => ("linker", Some(library.clone())),
+            MissingLibrary::BuildDependency(package) => ("build-dependency", Some(package.clone())),
+            MissingLibrary::DeprecatedModule(module, _) => {
+                ("deprecated-module", Some(module.clone()))
+            }
+            MissingLibrary::RequiresPython { required, .. } => {
+                ("requires-python", Some(required.clone()))
+            }
+            MissingLibrary::LongPath(length) => ("long-path", Some(length.to_string())),
+            MissingLibrary::PermissionDenied(path) => {
+                ("permission-denied", Some(path.user_display().to_string()))
+            }
+            MissingLibrary::CrossArchitecture => ("cross-architecture", None),
+        }
+    }
+}
+
 impl Display for MissingHeaderCause {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self.missing_library {
@@ -273,8 +545,7 @@ impl Display for MissingHeaderCause {
                         package,
                     )
                 } else if let Some(version_id) = &self.version_id {
-                    let package_name = extract_package_name(version_id);
-                    hint_build_dependency(f, package_name, package_name, package)
+                    hint_build_dependency(f, version_id, extract_package_name(version_id), package)
                 } else {
                     write!(
                         f,
@@ -287,6 +558,98 @@ impl Display for MissingHeaderCause {
                     )
                 }
             }
+            MissingLibrary::LongPath(length) => {
+                write!(
+                    f,
+                    "This error likely indicates that the build accessed a path ({length} characters) that exceeds the Windows path length limit of {WINDOWS_MAX_PATH} characters. Consider enabling long path support by setting `{}` in the Windows registry, or moving the cache to a shorter path with `{}`.",
+                    "LongPathsEnabled".cyan(),
+                    EnvVars::UV_CACHE_DIR.green(),
+                )
+            }
+            MissingLibrary::PermissionDenied(path) => {
+                if let (Some(package_name), Some(package_version)) =
+                    (&self.package_name, &self.package_version)
+                {
+                    write!(
+                        f,
+                        "This error likely indicates that the build script for `{}` attempted to write to `{}`, which is outside of the isolated build environment. Re-running with `{}` won't help; consider reporting the issue to the maintainers of `{}`, or using a pre-built wheel instead (e.g., with `{}`).",
+                        format!("{package_name}@{package_version}").cyan(),
+                        path.user_display().cyan(),
+                        "--no-build-isolation".green(),
+                        package_name.cyan(),
+                        format!("--only-binary {package_name}").green(),
+                    )
+                } else if let Some(version_id) = &self.version_id {
+                    write!(
+                        f,
+                        "This error likely indicates that the build script for `{}` attempted to write to `{}`, which is outside of the isolated build environment. Re-running with `{}` won't help; consider reporting the issue to the package's maintainers, or using a pre-built wheel instead (e.g., with `{}`).",
+                        version_id.cyan(),
+                        path.user_display().cyan(),
+                        "--no-build-isolation".green(),
+                        "--only-binary".green(),
+                    )
+                } else {
+                    write!(
+                        f,
+                        "This error likely indicates that the package's build script attempted to write to `{}`, which is outside of the isolated build environment. Re-running with `{}` won't help; consider reporting the issue to the package's maintainers, or using a pre-built wheel instead (e.g., with `{}`).",
+                        path.user_display().cyan(),
+                        "--no-build-isolation".green(),
+                        "--only-binary".green(),
+                    )
+                }
+            }
+            MissingLibrary::CrossArchitecture => {
+                let package = self.package();
+                let subject = match &package {
+                    Some((display_name, _)) => format!("`{}`", display_name.cyan()),
+                    None => "the package".to_string(),
+                };
+                // A pre-built wheel can only be used in place of a registry source distribution.
+                let alternative = match &package {
+                    _ if !self.registry => String::new(),
+                    Some((_, package_name)) => format!(
+                        "requiring a pre-built wheel with `{}`, or ",
+                        format!("--only-binary {package_name}").green()
+                    ),
+                    None => format!(
+                        "requiring a pre-built wheel with `{}`, or ",
+                        "--only-binary".green()
+                    ),
+                };
+                write!(
+                    f,
+                    "This error likely indicates that {subject} can't be built for the architecture of the build machine. Note that source distributions are built for the host platform, not the target platform (e.g., as selected with `{}`). Consider {alternative}building on the target architecture.",
+                    "--python-platform".green(),
+                )
+            }
+            MissingLibrary::RequiresPython {
+                required,
+                installed,
+            } => {
+                if let Some((display_name, package_name)) = self.package() {
+                    write!(
+                        f,
+                        "This error likely indicates that `{}` requires Python `{}`, but the build environment uses Python {}. Consider installing a compatible version with `{}` and selecting it with `{}`, or, if `{}` is a first-party package, relaxing its `{}`.",
+                        display_name.cyan(),
+                        required.cyan(),
+                        installed.cyan(),
+                        "uv python install".green(),
+                        "--python".green(),
+                        package_name.cyan(),
+                        "requires-python".green(),
+                    )
+                } else {
+                    write!(
+                        f,
+                        "This error likely indicates that the package requires Python `{}`, but the build environment uses Python {}. Consider installing a compatible version with `{}` and selecting it with `{}`, or, if the package is a first-party package, relaxing its `{}`.",
+                        required.cyan(),
+                        installed.cyan(),
+                        "uv python install".green(),
+                        "--python".green(),
+                        "requires-python".green(),
+                    )
+                }
+            }
             MissingLibrary::DeprecatedModule(package, version) => {
                 if let (Some(package_name), Some(package_version)) =
                     (&self.package_name, &self.package_version)
@@ -318,12 +681,22 @@ pub struct BuildBackendError {
     exit_code: ExitStatus,
     stdout: Vec<String>,
     stderr: Vec<String>,
+    log: Option<PathBuf>,
+    retained: Option<Box<RetainedEnvironment>>,
 }
 
 impl Display for BuildBackendError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.message, self.exit_code)?;
 
+        if let Some(log) = &self.log {
+            write!(f, "\nfull log: {}", log.user_display())?;
+        }
+
+        if let Some(retained) = &self.retained {
+            write!(f, "{retained}")?;
+        }
+
         let mut non_empty = false;
 
         if self.stdout.iter().any(|line| !line.trim().is_empty()) {
@@ -350,6 +723,8 @@ pub struct MissingHeaderError {
     exit_code: ExitStatus,
     stdout: Vec<String>,
     stderr: Vec<String>,
+    log: Option<PathBuf>,
+    retained: Option<Box<RetainedEnvironment>>,
     cause: MissingHeaderCause,
 }
 
@@ -357,6 +732,14 @@ impl Display for MissingHeaderError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.message, self.exit_code)?;
 
+        if let Some(log) = &self.log {
+            write!(f, "\nfull log: {}", log.user_display())?;
+        }
+
+        if let Some(retained) = &self.retained {
+            write!(f, "{retained}")?;
+        }
+
         if self.stdout.iter().any(|line| !line.trim().is_empty()) {
             write!(f, "\n\n{}\n{}", "[stdout]".red(), self.stdout.join("\n"))?;
         }
@@ -369,8 +752,135 @@ impl Display for MissingHeaderError {
     }
 }
 
+/// A build environment that was retained after a failed build, for debugging.
+#[derive(Debug, Clone)]
+pub(crate) struct RetainedEnvironment {
+    /// The virtual environment in which the build backend ran.
+    pub(crate) venv: PathBuf,
+    /// The source tree that was being built.
+    pub(crate) source_tree: PathBuf,
+}
+
+impl Display for RetainedEnvironment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\nbuild environment: {}\nsource tree: {}",
+            self.venv.user_display(),
+            self.source_tree.user_display()
+        )
+    }
+}
+
 impl Error {
+    /// Returns `true` if the error was raised by a failing build backend hook.
+    pub(crate) fn is_hook_failure(&self) -> bool {
+        match self {
+            Self::BuildBackend(_) | Self::MissingHeader(_) | Self::BuildTimeout { .. } => true,
+            Self::BuildRetriesExhausted { err, .. } | Self::ViaSdist { err, .. } => {
+                err.is_hook_failure()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the build likely failed due to a transient filesystem error (e.g., a file
+    /// held open by an antivirus scanner, or a stale NFS file handle), rather than an error in the
+    /// build itself.
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Self::Io(err) => matches!(
+                err.kind(),
+                io::ErrorKind::ResourceBusy | io::ErrorKind::StaleNetworkFileHandle
+            ),
+            Self::BuildBackend(err) => err
+                .stdout
+                .iter()
+                .chain(&err.stderr)
+                .any(|line| TRANSIENT_IO_RE.is_match(line)),
+            _ => false,
+        }
+    }
+
+    /// Classify the error for a build event.
+    ///
+    /// Errors that wrap another error (e.g., after exhausting retries) are classified by the
+    /// underlying error.
+    pub(crate) fn failure(&self) -> Failure {
+        let kind = match self {
+            Self::BuildRetriesExhausted { err, .. } | Self::ViaSdist { err, .. } => {
+                return err.failure();
+            }
+            Self::MissingHeader(err) => {
+                let (cause, detail) = err.cause.classify();
+                return Failure {
+                    kind: "missing-header",
+                    cause: Some(cause),
+                    detail,
+                };
+            }
+            Self::Io(_) => "io",
+            Self::Lowering(_) => "lowering",
+            Self::InvalidSourceDist(_) => "invalid-source-dist",
+            Self::InvalidPyprojectTomlSyntax(_) => "invalid-pyproject-toml-syntax",
+            Self::InvalidPyprojectTomlSchema(_) => "invalid-pyproject-toml-schema",
+            Self::InvalidBackendPath(_) => "invalid-backend-path",
+            Self::BackendPathOutsideSourceTree(_) => "backend-path-outside-source-tree",
+            Self::RequirementsResolve(_, _) => "requirements-resolve",
+            Self::RequirementsInstall(_, _) => "requirements-install",
+            Self::LockedBuildRequirements(_, _) => "locked-build-requirements",
+            Self::Virtualenv(_) => "virtualenv",
+            Self::CommandFailed(_, _) => "command-failed",
+            Self::BuildBackend(_) => "build-backend",
+            Self::BuildTimeout { .. } => "build-timeout",
+            Self::IncompatibleBuildPython { .. } => "incompatible-build-python",
+            Self::BuildPython { .. } => "build-python",
+            Self::EditableUnsupported(_) => "editable-unsupported",
+            Self::WheelUnsupported(_) => "wheel-unsupported",
+            Self::InvalidWheelFilename(_) => "invalid-wheel-filename",
+            Self::Tags(_) => "tags",
+            Self::IncompatibleBuildTarget { .. } => "incompatible-build-target",
+            Self::InvalidSdistExtension(_, _) => "invalid-sdist-extension",
+            Self::Extract(_) => "extract",
+            Self::BuildScriptPath(_) => "build-script-path",
+            Self::NoSourceDistBuild(_) | Self::NoSourceDistBuilds => "no-build",
+            Self::CyclicBuildDependency { .. } => "cyclic-build-dependency",
+            Self::UnmatchedRuntime(_, _) => "unmatched-runtime",
+        };
+        Failure {
+            kind,
+            cause: None,
+            detail: None,
+        }
+    }
+
+    /// Attach the location of a retained build environment to a build backend error.
+    pub(crate) fn with_retained_environment(self, retained: RetainedEnvironment) -> Self {
+        match self {
+            Self::BuildBackend(mut err) => {
+                err.retained = Some(Box::new(retained));
+                Self::BuildBackend(err)
+            }
+            Self::MissingHeader(mut err) => {
+                err.retained = Some(Box::new(retained));
+                Self::MissingHeader(err)
+            }
+            Self::BuildTimeout { elapsed, err } => Self::BuildTimeout {
+                elapsed,
+                err: Box::new(err.with_retained_environment(retained)),
+            },
+            Self::BuildRetriesExhausted { attempts, err } => Self::BuildRetriesExhausted {
+                attempts,
+                err: Box::new(err.with_retained_environment(retained)),
+            },
+            err => err,
+        }
+    }
+
     /// Construct an [`Error`] from the output of a failed command.
+    ///
+    /// If the command was terminated after exceeding the build timeout, the error is wrapped in
+    /// [`Error::BuildTimeout`].
     pub(crate) fn from_command_output(
         message: String,
         output: &PythonRunnerOutput,
@@ -378,6 +888,38 @@ impl Error {
         name: Option<&PackageName>,
         version: Option<&Version>,
         version_id: Option<&str>,
+        registry: bool,
+        build_environment: Option<&Path>,
+    ) -> Self {
+        let err = Self::classify_command_output(
+            message,
+            output,
+            level,
+            name,
+            version,
+            version_id,
+            registry,
+            build_environment,
+        );
+        match output.timed_out {
+            Some(elapsed) => Self::BuildTimeout {
+                elapsed,
+                err: Box::new(err),
+            },
+            None => err,
+        }
+    }
+
+    /// Classify the output of a failed command, e.g., to detect missing headers or libraries.
+    fn classify_command_output(
+        message: String,
+        output: &PythonRunnerOutput,
+        level: BuildOutput,
+        name: Option<&PackageName>,
+        version: Option<&Version>,
+        version_id: Option<&str>,
+        registry: bool,
+        build_environment: Option<&Path>,
     ) -> Self {
         // In the cases I've seen it was the 5th and 3rd last line (see test case), 10 seems like a reasonable cutoff.
         let missing_library = output.stderr.iter().rev().take(10).find_map(|line| {
@@ -399,6 +941,24 @@ impl Error {
                     "distutils".to_string(),
                     Version::new([3, 12]),
                 ))
+            } else if let Some(length) = WINDOWS_LONG_PATH_RE
+                .captures(line.trim())
+                .map(|caps| windows_path_length(&caps["path"]))
+                .filter(|length| *length > WINDOWS_MAX_PATH)
+            {
+                Some(MissingLibrary::LongPath(length))
+            } else if let Some(path) = permission_denied_path(line.trim(), build_environment) {
+                Some(MissingLibrary::PermissionDenied(path))
+            } else if CROSS_ARCHITECTURE_RE.is_match(line.trim()) {
+                Some(MissingLibrary::CrossArchitecture)
+            } else if let Some(caps) = REQUIRES_PYTHON_RE_PIP
+                .captures(line.trim())
+                .or_else(|| REQUIRES_PYTHON_RE_HATCHLING.captures(line.trim()))
+            {
+                Some(MissingLibrary::RequiresPython {
+                    required: caps["required"].trim().to_string(),
+                    installed: caps["installed"].to_string(),
+                })
             } else if let Some(caps) = MODULE_NOT_FOUND.captures(line.trim()) {
                 if let Some(module_match) = caps.get(1) {
                     let module_name = module_match.as_str();
@@ -417,17 +977,20 @@ impl Error {
 
         if let Some(missing_library) = missing_library {
             return match level {
-                BuildOutput::Stderr | BuildOutput::Quiet => {
+                BuildOutput::Stderr | BuildOutput::Stream | BuildOutput::Quiet => {
                     Self::MissingHeader(Box::new(MissingHeaderError {
                         message,
                         exit_code: output.status,
                         stdout: vec![],
                         stderr: vec![],
+                        log: output.log.clone(),
+                        retained: None,
                         cause: MissingHeaderCause {
                             missing_library,
                             package_name: name.cloned(),
                             package_version: version.cloned(),
                             version_id: version_id.map(ToString::to_string),
+                            registry,
                         },
                     }))
                 }
@@ -436,28 +999,37 @@ impl Error {
                     exit_code: output.status,
                     stdout: output.stdout.clone(),
                     stderr: output.stderr.clone(),
+                    log: output.log.clone(),
+                    retained: None,
                     cause: MissingHeaderCause {
                         missing_library,
                         package_name: name.cloned(),
                         package_version: version.cloned(),
                         version_id: version_id.map(ToString::to_string),
+                        registry,
                     },
                 })),
             };
         }
 
         match level {
-            BuildOutput::Stderr | BuildOutput::Quiet => Self::BuildBackend(BuildBackendError {
-                message,
-                exit_code: output.status,
-                stdout: vec![],
-                stderr: vec![],
-            }),
+            BuildOutput::Stderr | BuildOutput::Stream | BuildOutput::Quiet => {
+                Self::BuildBackend(BuildBackendError {
+                    message,
+                    exit_code: output.status,
+                    stdout: vec![],
+                    stderr: vec![],
+                    log: output.log.clone(),
+                    retained: None,
+                })
+            }
             BuildOutput::Debug => Self::BuildBackend(BuildBackendError {
                 message,
                 exit_code: output.status,
                 stdout: output.stdout.clone(),
                 stderr: output.stderr.clone(),
+                log: output.log.clone(),
+                retained: None,
             }),
         }
     }
@@ -467,13 +1039,18 @@ impl Error {
 mod test {
     use crate::{Error, PythonRunnerOutput};
     use indoc::indoc;
+    use std::path::{Path, PathBuf};
     use std::process::ExitStatus;
     use std::str::FromStr;
+    use std::time::Duration;
     use uv_configuration::BuildOutput;
     use uv_errors::{ErrorWithHints, Hint};
     use uv_normalize::PackageName;
     use uv_pep440::Version;
 
+    /// The root of the isolated build environment in which the build backend is invoked.
+    const BUILD_ENVIRONMENT: &str = "/home/ferris/.cache/uv/builds-v0/.tmpr4nd0m";
+
     fn format_error_with_hints(err: &Error) -> String {
         // Unix uses exit status, Windows uses exit code.
         let formatted = std::error::Error::source(err)
@@ -488,6 +1065,8 @@ mod test {
     fn missing_header() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
             stdout: indoc!(r"
                 running bdist_wheel
                 running build
@@ -517,6 +1096,8 @@ mod test {
             None,
             None,
             Some("pygraphviz-1.11"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
         );
 
         assert!(matches!(err, Error::MissingHeader { .. }));
@@ -550,6 +1131,8 @@ mod test {
     fn missing_linker_library() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -571,6 +1154,8 @@ mod test {
             None,
             None,
             Some("pygraphviz-1.11"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
         );
         assert!(matches!(err, Error::MissingHeader { .. }));
         let formatted = format_error_with_hints(&err);
@@ -592,6 +1177,8 @@ mod test {
     fn missing_wheel_package() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -614,6 +1201,8 @@ mod test {
             None,
             None,
             Some("pygraphviz-1.11"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
         );
         assert!(matches!(err, Error::MissingHeader { .. }));
         let formatted = format_error_with_hints(&err);
@@ -628,12 +1217,12 @@ mod test {
 
         error: invalid command 'bdist_wheel'
 
-        hint: This error likely indicates that `pygraphviz-1.11` depends on `wheel`, but doesn't declare it as a build dependency. If `pygraphviz-1.11` is a first-party package, consider adding `wheel` to its `build-system.requires`. Otherwise, either add it to your `pyproject.toml` under:
+        hint: This error likely indicates that `pygraphviz-1.11` depends on `wheel`, but doesn't declare it as a build dependency. If `pygraphviz` is a first-party package, consider adding `wheel` to its `build-system.requires`. Otherwise, either add it to your `pyproject.toml` under:
 
         [tool.uv.extra-build-dependencies]
-        "pygraphviz-1.11" = ["wheel"]
+        pygraphviz = ["wheel"]
 
-        or `uv pip install wheel` into the environment and re-run with `--no-build-isolation`.
+        or `uv pip install wheel` into the environment and re-run with `--no-build-isolation-package pygraphviz`.
         "#);
     }
 
@@ -641,6 +1230,8 @@ mod test {
     fn missing_distutils() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -660,6 +1251,8 @@ mod test {
             Some(&PackageName::from_str("pygraphviz").unwrap()),
             Some(&Version::new([1, 11])),
             Some("pygraphviz-1.11"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
         );
         assert!(matches!(err, Error::MissingHeader { .. }));
         let formatted = format_error_with_hints(&err);
@@ -673,4 +1266,585 @@ mod test {
         hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `pygraphviz >1.11`) to avoid building a version of `pygraphviz` that depends on `distutils`.
         ");
     }
+
+    #[test]
+    fn requires_python_pip() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: indoc!(
+                r"
+                Processing ./vendor/foo
+                ERROR: Package 'foo' requires a different Python: 3.8.18 not in '>=3.9'
+                "
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        let err = Error::from_command_output(
+            "Failed building wheel through setup.py".to_string(),
+            &output,
+            BuildOutput::Debug,
+            Some(&PackageName::from_str("foo").unwrap()),
+            Some(&Version::new([1, 0])),
+            Some("foo-1.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Failed building wheel through setup.py (exit code: 0)
+
+        [stderr]
+        Processing ./vendor/foo
+        ERROR: Package 'foo' requires a different Python: 3.8.18 not in '>=3.9'
+
+        hint: This error likely indicates that `foo@1.0` requires Python `>=3.9`, but the build environment uses Python 3.8.18. Consider installing a compatible version with `uv python install` and selecting it with `--python`, or, if `foo` is a first-party package, relaxing its `requires-python`.
+        ");
+    }
+
+    #[test]
+    fn requires_python_hatchling() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: indoc!(
+                r#"
+                Traceback (most recent call last):
+                  File "<string>", line 11, in <module>
+                ValueError: Python 3.8.18 is not supported by this project (requires-python: '>=3.9, <4')
+                "#
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `hatchling.build.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Debug,
+            None,
+            None,
+            Some("foo-1.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @r#"
+        Call to `hatchling.build.build_wheel` failed (exit code: 0)
+
+        [stderr]
+        Traceback (most recent call last):
+          File "<string>", line 11, in <module>
+        ValueError: Python 3.8.18 is not supported by this project (requires-python: '>=3.9, <4')
+
+        hint: This error likely indicates that `foo-1.0` requires Python `>=3.9, <4`, but the build environment uses Python 3.8.18. Consider installing a compatible version with `uv python install` and selecting it with `--python`, or, if `foo` is a first-party package, relaxing its `requires-python`.
+        "#);
+    }
+
+    /// Generate a Python `repr` of a deeply nested Windows path, with escaped backslashes.
+    fn nested_windows_path(depth: usize) -> String {
+        let mut path =
+            r"C:\\Users\\ferris\\AppData\\Local\\uv\\cache\\sdists-v9\\pypi\\nested\\1.0.0\\src"
+                .to_string();
+        for _ in 0..depth {
+            path.push_str(r"\\nested_package_directory");
+        }
+        path.push_str(r"\\__init__.py");
+        path
+    }
+
+    #[test]
+    fn windows_long_path_not_found() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: [
+                "Traceback (most recent call last):".to_string(),
+                r#"  File "C:\Users\ferris\AppData\Local\Temp\tmp8hx1\setup.py", line 12, in <module>"#.to_string(),
+                format!(
+                    "FileNotFoundError: [WinError 3] The system cannot find the path specified: '{}'",
+                    nested_windows_path(10)
+                ),
+            ]
+            .into(),
+        };
+
+        let err = Error::from_command_output(
+            "Failed building wheel through setup.py".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            None,
+            None,
+            Some("nested-1.0.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Failed building wheel through setup.py (exit code: 0)
+
+        hint: This error likely indicates that the build accessed a path (332 characters) that exceeds the Windows path length limit of 260 characters. Consider enabling long path support by setting `LongPathsEnabled` in the Windows registry, or moving the cache to a shorter path with `UV_CACHE_DIR`.
+        ");
+    }
+
+    #[test]
+    fn windows_long_path_too_long() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: [
+                "Traceback (most recent call last):".to_string(),
+                r#"  File "C:\Users\ferris\AppData\Local\Temp\tmp8hx1\setup.py", line 12, in <module>"#.to_string(),
+                format!(
+                    "OSError: [WinError 206] The filename or extension is too long: '{}'",
+                    nested_windows_path(10)
+                ),
+            ]
+            .into(),
+        };
+
+        let err = Error::from_command_output(
+            "Failed building wheel through setup.py".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            None,
+            None,
+            Some("nested-1.0.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Failed building wheel through setup.py (exit code: 0)
+
+        hint: This error likely indicates that the build accessed a path (332 characters) that exceeds the Windows path length limit of 260 characters. Consider enabling long path support by setting `LongPathsEnabled` in the Windows registry, or moving the cache to a shorter path with `UV_CACHE_DIR`.
+        ");
+    }
+
+    #[test]
+    fn windows_short_path_not_found() {
+        // The path is below the limit, so the failure is unrelated to long path support.
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: [
+                "Traceback (most recent call last):".to_string(),
+                format!(
+                    "FileNotFoundError: [WinError 3] The system cannot find the path specified: '{}'",
+                    nested_windows_path(1)
+                ),
+            ]
+            .into(),
+        };
+
+        let err = Error::from_command_output(
+            "Failed building wheel through setup.py".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            None,
+            None,
+            Some("nested-1.0.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::BuildBackend { .. }));
+    }
+
+    #[test]
+    fn permission_denied_outside_build_environment() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: indoc!(r#"
+                Traceback (most recent call last):
+                  File "<string>", line 14, in <module>
+                  File "/home/ferris/.cache/uv/builds-v0/.tmpr4nd0m/lib/python3.12/site-packages/setuptools/build_meta.py", line 325, in get_requires_for_build_wheel
+                    return self._get_build_requires(config_settings, requirements=['wheel'])
+                           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+                  File "/home/ferris/.cache/uv/builds-v0/.tmpr4nd0m/lib/python3.12/site-packages/setuptools/build_meta.py", line 295, in _get_build_requires
+                    self.run_setup()
+                  File "/home/ferris/.cache/uv/builds-v0/.tmpr4nd0m/lib/python3.12/site-packages/setuptools/build_meta.py", line 487, in run_setup
+                    super().run_setup(setup_script=setup_script)
+                  File "/home/ferris/.cache/uv/builds-v0/.tmpr4nd0m/lib/python3.12/site-packages/setuptools/build_meta.py", line 311, in run_setup
+                    exec(code, locals())
+                  File "<string>", line 9, in <module>
+                  File "/usr/lib/python3.12/shutil.py", line 431, in copy
+                    copyfile(src, dst, follow_symlinks=follow_symlinks)
+                  File "/usr/lib/python3.12/shutil.py", line 260, in copyfile
+                    with open(dst, 'wb') as fdst:
+                         ^^^^^^^^^^^^^^^^
+                PermissionError: [Errno 13] Permission denied: '/usr/lib/python3/dist-packages/legacy_plugin.pth'
+            "#)
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            Some(&PackageName::from_str("legacy-plugin").unwrap()),
+            Some(&Version::new([1, 0])),
+            Some("legacy-plugin-1.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Call to `setuptools.build_meta.build_wheel` failed (exit code: 0)
+
+        hint: This error likely indicates that the build script for `legacy-plugin@1.0` attempted to write to `/usr/lib/python3/dist-packages/legacy_plugin.pth`, which is outside of the isolated build environment. Re-running with `--no-build-isolation` won't help; consider reporting the issue to the maintainers of `legacy-plugin`, or using a pre-built wheel instead (e.g., with `--only-binary legacy-plugin`).
+        ");
+    }
+
+    #[test]
+    fn permission_denied_could_not_create() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: indoc!(
+                r"
+                running install
+                running install_data
+                creating /usr/share/legacy-plugin
+            "
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+            stderr: indoc!(
+                r"
+                error: could not create '/usr/share/legacy-plugin': Permission denied
+            "
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        let err = Error::from_command_output(
+            "Failed building wheel through setup.py".to_string(),
+            &output,
+            BuildOutput::Debug,
+            None,
+            None,
+            Some("legacy-plugin-1.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Failed building wheel through setup.py (exit code: 0)
+
+        [stdout]
+        running install
+        running install_data
+        creating /usr/share/legacy-plugin
+
+        [stderr]
+        error: could not create '/usr/share/legacy-plugin': Permission denied
+
+        hint: This error likely indicates that the build script for `legacy-plugin-1.0` attempted to write to `/usr/share/legacy-plugin`, which is outside of the isolated build environment. Re-running with `--no-build-isolation` won't help; consider reporting the issue to the package's maintainers, or using a pre-built wheel instead (e.g., with `--only-binary`).
+        ");
+    }
+
+    #[test]
+    fn permission_denied_inside_build_environment() {
+        // Permission errors within the build environment aren't caused by the build script.
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: [
+                "Traceback (most recent call last):".to_string(),
+                r#"  File "<string>", line 9, in <module>"#.to_string(),
+                format!(
+                    "PermissionError: [Errno 13] Permission denied: '{BUILD_ENVIRONMENT}/lib/python3.12/site-packages/legacy_plugin.pth'"
+                ),
+            ]
+            .into(),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            None,
+            None,
+            Some("legacy-plugin-1.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::BuildBackend { .. }));
+    }
+
+    #[test]
+    fn permission_denied_without_build_isolation() {
+        // Without build isolation, there's no isolated environment for the build to write outside
+        // of.
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: indoc!(r#"
+                Traceback (most recent call last):
+                  File "<string>", line 9, in <module>
+                PermissionError: [Errno 13] Permission denied: '/usr/lib/python3/dist-packages/legacy_plugin.pth'
+            "#)
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            None,
+            None,
+            Some("legacy-plugin-1.0"),
+            true,
+            None,
+        );
+        assert!(matches!(err, Error::BuildBackend { .. }));
+    }
+
+    #[test]
+    fn cross_architecture_exec_format_error() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: indoc!(
+                r"
+                running build_ext
+                building 'fastcodec._speedups' extension
+            "
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+            stderr: indoc!(
+                r"
+                /bin/sh: 1: ./build/gen-tables: cannot execute binary file: Exec format error
+                error: command '/bin/sh' failed with exit code 126
+            "
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            Some(&PackageName::from_str("fastcodec").unwrap()),
+            Some(&Version::new([2, 0])),
+            Some("fastcodec-2.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Call to `setuptools.build_meta.build_wheel` failed (exit code: 0)
+
+        hint: This error likely indicates that `fastcodec@2.0` can't be built for the architecture of the build machine. Note that source distributions are built for the host platform, not the target platform (e.g., as selected with `--python-platform`). Consider requiring a pre-built wheel with `--only-binary fastcodec`, or building on the target architecture.
+        ");
+    }
+
+    #[test]
+    fn cross_architecture_unknown_architecture() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: indoc!(
+                r#"
+                src/platform.h:42:2: error: #error "Unknown architecture"
+                   42 | #error "Unknown architecture"
+                      |  ^~~~~
+                error: command '/usr/bin/gcc' failed with exit code 1
+            "#
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Debug,
+            None,
+            None,
+            Some("fastcodec-2.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @r#"
+        Call to `setuptools.build_meta.build_wheel` failed (exit code: 0)
+
+        [stderr]
+        src/platform.h:42:2: error: #error "Unknown architecture"
+           42 | #error "Unknown architecture"
+              |  ^~~~~
+        error: command '/usr/bin/gcc' failed with exit code 1
+
+        hint: This error likely indicates that `fastcodec-2.0` can't be built for the architecture of the build machine. Note that source distributions are built for the host platform, not the target platform (e.g., as selected with `--python-platform`). Consider requiring a pre-built wheel with `--only-binary fastcodec`, or building on the target architecture.
+        "#);
+    }
+
+    #[test]
+    fn cross_architecture_source_tree() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            timed_out: None,
+            stdout: Vec::new(),
+            stderr: indoc!(
+                r"
+                /bin/sh: 1: ./build/gen-tables: cannot execute binary file: Exec format error
+                error: command '/bin/sh' failed with exit code 126
+            "
+            )
+            .lines()
+            .map(ToString::to_string)
+            .collect(),
+        };
+
+        // A pre-built wheel can't be used in place of a source tree, so it isn't suggested.
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Quiet,
+            Some(&PackageName::from_str("fastcodec").unwrap()),
+            Some(&Version::new([2, 0])),
+            Some("fastcodec @ file:///home/ferris/fastcodec"),
+            false,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Call to `setuptools.build_meta.build_wheel` failed (exit code: 0)
+
+        hint: This error likely indicates that `fastcodec@2.0` can't be built for the architecture of the build machine. Note that source distributions are built for the host platform, not the target platform (e.g., as selected with `--python-platform`). Consider building on the target architecture.
+        ");
+    }
+
+    #[test]
+    fn build_log_path() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: Some(PathBuf::from(
+                "/cache/build-logs-v0/foo-1.0-0123456789abcdef/build.log",
+            )),
+            stdout: vec!["running build_ext".to_string()],
+            stderr: vec!["error: command '/usr/bin/gcc' failed with exit code 1".to_string()],
+            timed_out: None,
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Debug,
+            Some(&PackageName::from_str("foo").unwrap()),
+            Some(&Version::new([1, 0])),
+            Some("foo-1.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        assert!(matches!(err, Error::BuildBackend { .. }));
+        let formatted = format_error_with_hints(&err);
+        insta::assert_snapshot!(formatted, @"
+        Call to `setuptools.build_meta.build_wheel` failed (exit code: 0)
+        full log: /cache/build-logs-v0/foo-1.0-0123456789abcdef/build.log
+
+        [stdout]
+        running build_ext
+
+        [stderr]
+        error: command '/usr/bin/gcc' failed with exit code 1
+
+
+        hint: Build failures usually indicate a problem with the package or the build environment
+        ");
+    }
+
+    #[test]
+    fn build_timeout() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            log: None,
+            stdout: Vec::new(),
+            stderr: vec!["ModuleNotFoundError: No module named 'distutils'".to_string()],
+            timed_out: Some(Duration::from_millis(5250)),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Debug,
+            Some(&PackageName::from_str("foo").unwrap()),
+            Some(&Version::new([1, 0])),
+            Some("foo-1.0"),
+            true,
+            Some(Path::new(BUILD_ENVIRONMENT)),
+        );
+        let Error::BuildTimeout { err: inner, .. } = &err else {
+            panic!("expected a build timeout, found: {err:?}");
+        };
+        assert!(matches!(**inner, Error::MissingHeader(_)));
+        let formatted = ErrorWithHints::new(err.to_string(), err.hints()).to_string();
+        let formatted = anstream::adapter::strip_str(&formatted).to_string();
+        insta::assert_snapshot!(formatted, @"
+        The build backend was terminated after exceeding the build timeout (5s)
+
+        hint: To allow builds to take longer, increase the timeout with `--build-timeout` or `UV_BUILD_TIMEOUT`
+        hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `foo >1.0`) to avoid building a version of `foo` that depends on `distutils`.
+        ");
+    }
+
+    #[test]
+    fn editable_unsupported() {
+        let err = Error::EditableUnsupported("setuptools.build_meta:__legacy__".to_string());
+        let formatted = ErrorWithHints::new(err.to_string(), err.hints()).to_string();
+        let formatted = anstream::adapter::strip_str(&formatted).to_string();
+        insta::assert_snapshot!(formatted, @"
+        The build backend `setuptools.build_meta:__legacy__` does not support editable installs
+
+        hint: `setuptools` added support for editable installs (PEP 660) in v64.0.0. Consider building with `setuptools>=64`, or installing without `--editable`
+        ");
+    }
 }
@@ -0,0 +1,342 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_configuration::BuildKind;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::Interpreter;
+use uv_warnings::warn_user_once;
+
+use crate::Error;
+
+/// The version of the build event schema.
+///
+/// Incremented whenever a field is removed or changes meaning; adding a field is not a breaking
+/// change.
+pub const BUILD_EVENTS_SCHEMA_VERSION: u32 = 2;
+
+/// The build events of a single uv invocation.
+///
+/// Each invocation is identified by a random ID, such that the events of concurrent or successive
+/// invocations that append to the same file can be told apart. Uses an [`Arc`] internally, such
+/// that builds are numbered uniquely, and each file is opened once, across all the builds of the
+/// invocation (e.g., across the build dispatches of a single command).
+#[derive(Debug, Clone)]
+pub struct BuildEvents(Arc<BuildEventsInner>);
+
+#[derive(Debug)]
+struct BuildEventsInner {
+    /// The invocation to which the build events belong.
+    invocation: Arc<Invocation>,
+    /// The sinks for each build events file.
+    sinks: Mutex<FxHashMap<PathBuf, Arc<BuildEventSink>>>,
+}
+
+impl Default for BuildEvents {
+    fn default() -> Self {
+        Self(Arc::new(BuildEventsInner {
+            invocation: Arc::new(Invocation {
+                id: uv_fastid::Id::secure().to_string(),
+                sequence: AtomicU64::default(),
+            }),
+            sinks: Mutex::default(),
+        }))
+    }
+}
+
+impl BuildEvents {
+    /// Return the [`BuildEventSink`] that appends to the given file (e.g., as configured with
+    /// `--build-events-file`).
+    pub fn sink(&self, path: &Path) -> Arc<BuildEventSink> {
+        self.0
+            .sinks
+            .lock()
+            .unwrap()
+            .entry(path.to_path_buf())
+            .or_insert_with(|| {
+                Arc::new(BuildEventSink {
+                    path: path.to_path_buf(),
+                    file: OnceLock::new(),
+                    invocation: self.0.invocation.clone(),
+                })
+            })
+            .clone()
+    }
+}
+
+/// A single uv invocation, for which build events are recorded.
+#[derive(Debug)]
+struct Invocation {
+    /// The ID of the invocation.
+    id: String,
+    /// The sequence ID of the most recently started build.
+    sequence: AtomicU64,
+}
+
+impl Invocation {
+    /// Return the sequence ID of the next build.
+    fn next(&self) -> u64 {
+        self.sequence.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+/// Appends a structured event to a file at the start and finish of every source build, as JSON
+/// Lines.
+#[derive(Debug)]
+pub struct BuildEventSink {
+    /// The file to which build events are appended.
+    path: PathBuf,
+    /// The open file, once the first event is recorded, or `None` if it couldn't be opened.
+    file: OnceLock<Option<Mutex<fs_err::File>>>,
+    /// The invocation to which this sink belongs.
+    invocation: Arc<Invocation>,
+}
+
+impl BuildEventSink {
+    /// Record the start of a build.
+    pub(crate) fn start(&self, context: &BuildEventContext, kind: BuildKind) -> StartedBuild {
+        let sequence = self.invocation.next();
+        self.write(&BuildEvent {
+            schema_version: BUILD_EVENTS_SCHEMA_VERSION,
+            invocation_id: &self.invocation.id,
+            event: EventKind::BuildStart,
+            sequence,
+            timestamp_ms: timestamp_ms(),
+            kind: kind.to_string(),
+            context,
+            status: None,
+            duration_ms: None,
+            failure: None,
+        });
+        StartedBuild {
+            sequence,
+            start: Instant::now(),
+        }
+    }
+
+    /// Record the finish of a build started with [`BuildEventSink::start`].
+    pub(crate) fn finish<T>(
+        &self,
+        started: StartedBuild,
+        context: &BuildEventContext,
+        kind: BuildKind,
+        result: &Result<T, Error>,
+    ) {
+        let duration = started.start.elapsed();
+        self.write(&BuildEvent {
+            schema_version: BUILD_EVENTS_SCHEMA_VERSION,
+            invocation_id: &self.invocation.id,
+            event: EventKind::BuildFinish,
+            sequence: started.sequence,
+            timestamp_ms: timestamp_ms(),
+            kind: kind.to_string(),
+            context,
+            status: Some(if result.is_ok() {
+                Status::Success
+            } else {
+                Status::Failure
+            }),
+            duration_ms: Some(duration_ms(duration)),
+            failure: result.as_ref().err().map(Error::failure),
+        });
+    }
+
+    /// Record a build that failed before it started, while setting up its build environment
+    /// (e.g., while installing its build requirements).
+    pub fn setup_failure(
+        &self,
+        package: Option<&PackageName>,
+        version: Option<&Version>,
+        interpreter: &Interpreter,
+        kind: BuildKind,
+        err: &Error,
+    ) {
+        self.write(&BuildEvent {
+            schema_version: BUILD_EVENTS_SCHEMA_VERSION,
+            invocation_id: &self.invocation.id,
+            event: EventKind::SetupFailure,
+            sequence: self.invocation.next(),
+            timestamp_ms: timestamp_ms(),
+            kind: kind.to_string(),
+            context: &SetupFailureContext {
+                package,
+                version,
+                interpreter: InterpreterInfo::from(interpreter),
+            },
+            status: Some(Status::Failure),
+            duration_ms: None,
+            failure: Some(err.failure()),
+        });
+    }
+
+    /// Open the build events file for appending, creating it if necessary.
+    fn open(&self) -> std::io::Result<fs_err::File> {
+        if let Some(parent) = self
+            .path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+    }
+
+    /// Append an event to the build events file.
+    ///
+    /// Build events are best-effort: if the file can't be opened, a warning is shown (once), and
+    /// the build proceeds without recording events.
+    fn write(&self, event: &BuildEvent<impl Serialize>) {
+        let file = self.file.get_or_init(|| match self.open() {
+            Ok(file) => Some(Mutex::new(file)),
+            Err(err) => {
+                warn_user_once!(
+                    "Failed to open build events file `{}`: {err}",
+                    self.path.user_display()
+                );
+                None
+            }
+        });
+        let Some(file) = file else {
+            return;
+        };
+        let mut line = match serde_json::to_string(event) {
+            Ok(line) => line,
+            Err(err) => {
+                debug!("Failed to serialize build event: {err}");
+                return;
+            }
+        };
+        line.push('\n');
+        if let Err(err) = file.lock().unwrap().write_all(line.as_bytes()) {
+            debug!(
+                "Failed to write build event to `{}`: {err}",
+                self.path.user_display()
+            );
+        }
+    }
+}
+
+/// The isolation mode of a build.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Isolation {
+    /// The build ran in a dedicated environment with its build requirements installed.
+    Isolated,
+    /// The build ran in an existing environment (e.g., `--no-build-isolation`).
+    None,
+}
+
+/// The properties of a build that are shared by its start and finish events.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct BuildEventContext {
+    pub(crate) package: Option<PackageName>,
+    pub(crate) version: Option<Version>,
+    pub(crate) backend: String,
+    pub(crate) interpreter: InterpreterInfo,
+    pub(crate) isolation: Isolation,
+    /// Whether the build environment was reused from an earlier build with identical build
+    /// requirements.
+    pub(crate) environment_reused: bool,
+}
+
+/// The properties of a build that failed while setting up its build environment.
+#[derive(Debug, Serialize)]
+struct SetupFailureContext<'a> {
+    package: Option<&'a PackageName>,
+    version: Option<&'a Version>,
+    interpreter: InterpreterInfo,
+}
+
+/// The interpreter that a build ran with.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct InterpreterInfo {
+    implementation: String,
+    version: String,
+    executable: PathBuf,
+}
+
+impl From<&Interpreter> for InterpreterInfo {
+    fn from(interpreter: &Interpreter) -> Self {
+        Self {
+            implementation: interpreter.implementation_name().to_string(),
+            version: interpreter.python_full_version().to_string(),
+            executable: interpreter.sys_executable().to_path_buf(),
+        }
+    }
+}
+
+/// A build for which a start event was recorded.
+#[derive(Debug)]
+pub(crate) struct StartedBuild {
+    sequence: u64,
+    start: Instant,
+}
+
+/// The classification of a failed build.
+#[derive(Debug, Serialize)]
+pub(crate) struct Failure {
+    /// The kind of error, e.g., `build-backend` or `missing-header`.
+    pub(crate) kind: &'static str,
+    /// For `missing-header` failures, the kind of missing library, e.g., `header` or `linker`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cause: Option<&'static str>,
+    /// For `missing-header` failures, the missing library, e.g., the name of the header file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) detail: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum EventKind {
+    BuildStart,
+    BuildFinish,
+    SetupFailure,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Status {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Serialize)]
+struct BuildEvent<'a, C> {
+    schema_version: u32,
+    invocation_id: &'a str,
+    event: EventKind,
+    sequence: u64,
+    timestamp_ms: u64,
+    kind: String,
+    #[serde(flatten)]
+    context: &'a C,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure: Option<Failure>,
+}
+
+/// The current time, in milliseconds since the Unix epoch.
+fn timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(duration_ms)
+        .unwrap_or_default()
+}
+
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
@@ -3,9 +3,15 @@
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
 mod error;
+mod events;
+mod log;
 mod pipreqs;
+mod pool;
+mod process;
+mod warnings;
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::ffi::OsString;
 use std::fmt::Formatter;
 use std::fmt::Write;
@@ -13,47 +19,72 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 use std::{env, iter};
 
 use fs_err as fs;
-use indoc::formatdoc;
+use indoc::{formatdoc, indoc};
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 use serde::de::{self, IntoDeserializer, SeqAccess, Visitor, value};
 use serde::{Deserialize, Deserializer};
 use tempfile::TempDir;
 use tokio::io::AsyncBufReadExt;
 use tokio::process::Command;
-use tokio::sync::{Mutex, Semaphore};
+use tokio::sync::{Mutex, OwnedMutexGuard, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, warn};
 use uv_auth::CredentialsCache;
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket, CacheEntry, CacheShard, Freshness};
 use uv_cache_key::cache_digest;
 use uv_configuration::{BuildKind, BuildOutput, NoSources};
 use uv_distribution::BuildRequires;
+use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations, Requirement,
+    BuildTarget, ConfigSettings, ExtraBuildRequirement, ExtraBuildRequires, IndexLocations,
+    LockedBuildDependency, Requirement,
 };
 use uv_fs::{LockedFile, LockedFileMode};
 use uv_fs::{PythonExt, Simplified};
 use uv_normalize::PackageName;
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_platform_tags::{Tags, TagsOptions};
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_static::EnvVars;
 use uv_types::{
-    AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, ResolvedRequirements, SourceBuildTrait,
+    AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy, ResolvedRequirements,
+    SourceBuildTrait,
 };
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
-pub use crate::error::{Error, MissingHeaderCause};
+use crate::error::RetainedEnvironment;
+pub use crate::error::{Error, MissingHeaderCause, ViaSdistStage};
+pub use crate::events::{BUILD_EVENTS_SCHEMA_VERSION, BuildEventSink, BuildEvents};
+use crate::events::{BuildEventContext, InterpreterInfo, Isolation};
+use crate::log::{BuildLog, Stream};
+use crate::pool::{BuildEnvironment, BuildEnvironmentPool};
+use crate::process::ProcessTree;
+use crate::warnings::BuildWarningCollector;
+pub use crate::warnings::{BuildWarnings, DEFAULT_BUILD_WARNING_PATTERNS};
+
+/// Read the `requires-python` of the project in the source tree, if any.
+///
+/// Returns `None` if the source tree doesn't contain a valid `pyproject.toml` with a
+/// `project.requires-python`.
+pub fn read_requires_python(source_tree: &Path) -> Option<VersionSpecifiers> {
+    let contents = fs::read_to_string(source_tree.join("pyproject.toml")).ok()?;
+    let pyproject_toml = toml_edit::de::from_str::<PyProjectToml>(&contents).ok()?;
+    pyproject_toml.project?.requires_python()
+}
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
     backend: "setuptools.build_meta:__legacy__".to_string(),
-    backend_path: None,
+    backend_path: Vec::new(),
     requirements: vec![Requirement::from(
         uv_pep508::Requirement::from_str("setuptools >= 40.8.0").unwrap(),
     )],
@@ -85,6 +116,25 @@ struct Project {
     /// Specifies which fields listed by PEP 621 were intentionally unspecified so another tool
     /// can/will provide such metadata dynamically.
     dynamic: Option<Vec<String>>,
+    /// The Python versions the project is compatible with.
+    ///
+    /// Parsed leniently, as a malformed value is for the build backend to reject.
+    requires_python: Option<String>,
+}
+
+impl Project {
+    /// The `requires-python` of the project, if it's present and valid.
+    fn requires_python(&self) -> Option<VersionSpecifiers> {
+        let requires_python = self.requires_python.as_deref()?;
+        VersionSpecifiers::from_str(requires_python)
+            .inspect_err(|err| {
+                debug!(
+                    "Ignoring invalid `requires-python` of `{}`: {err}",
+                    self.name
+                );
+            })
+            .ok()
+    }
 }
 
 /// The `[build-system]` section of a pyproject.toml as specified in PEP 517.
@@ -171,8 +221,10 @@ struct Pep517Backend {
     backend: String,
     /// `build-backend.requirements` in pyproject.toml
     requirements: Vec<Requirement>,
+    /// The canonicalized entries of `build-backend.backend-path`, in order.
+    ///
     /// <https://peps.python.org/pep-0517/#in-tree-build-backends>
-    backend_path: Option<BackendPath>,
+    backend_path: Vec<PathBuf>,
 }
 
 impl Pep517Backend {
@@ -186,9 +238,9 @@ impl Pep517Backend {
         let backend_path_encoded = self
             .backend_path
             .iter()
-            .flat_map(BackendPath::iter)
             .map(|path| {
                 // Turn into properly escaped python string
+                let path = path.to_string_lossy();
                 '"'.to_string()
                     + &path.replace('\\', "\\\\").replace('"', "\\\"")
                     + &'"'.to_string()
@@ -212,11 +264,62 @@ impl Pep517Backend {
     }
 
     fn is_setuptools(&self) -> bool {
-        // either `setuptools.build_meta` or `setuptools.build_meta:__legacy__`
-        self.backend.split(':').next() == Some("setuptools.build_meta")
+        is_setuptools(&self.backend)
     }
 }
 
+/// Returns `true` if the build backend is `setuptools`.
+pub(crate) fn is_setuptools(backend: &str) -> bool {
+    // either `setuptools.build_meta` or `setuptools.build_meta:__legacy__`
+    backend.split(':').next() == Some("setuptools.build_meta")
+}
+
+/// A fallback for `build_editable` with `setuptools` versions that predate PEP 660 (i.e., before
+/// v64.0.0), with the semantics of `setup.py develop`: extension modules are built in place, and
+/// the wheel adds the source tree to `sys.path` via a `.pth` file.
+const SETUPTOOLS_DEVELOP: &str = indoc! {r#"
+    def build_editable_develop(backend, wheel_directory, config_settings):
+        import base64
+        import hashlib
+        import os
+        import tempfile
+        import zipfile
+        from distutils.core import run_setup
+
+        distribution = run_setup("setup.py", script_args=["build_ext", "--inplace"])
+        root = os.path.abspath((distribution.package_dir or {}).get("", "."))
+
+        files = {}
+        with tempfile.TemporaryDirectory() as metadata_directory:
+            dist_info = backend.prepare_metadata_for_build_wheel(
+                metadata_directory, config_settings
+            )
+            for directory, _, filenames in os.walk(os.path.join(metadata_directory, dist_info)):
+                for filename in filenames:
+                    path = os.path.join(directory, filename)
+                    name = os.path.relpath(path, metadata_directory).replace(os.sep, "/")
+                    with open(path, "rb") as fp:
+                        files[name] = fp.read()
+
+        stem = dist_info[: -len(".dist-info")]
+        files.pop(f"{dist_info}/RECORD", None)
+        files[f"{dist_info}/WHEEL"] = (
+            b"Wheel-Version: 1.0\nGenerator: uv\nRoot-Is-Purelib: true\nTag: py3-none-any\n"
+        )
+        files[f"__editable__.{stem}.pth"] = f"{root}\n".encode()
+
+        wheel_filename = f"{stem}-py3-none-any.whl"
+        record = []
+        with zipfile.ZipFile(os.path.join(wheel_directory, wheel_filename), "w") as wheel:
+            for name, contents in files.items():
+                wheel.writestr(name, contents)
+                digest = base64.urlsafe_b64encode(hashlib.sha256(contents).digest())
+                record.append(f"{name},sha256={digest.rstrip(b'=').decode()},{len(contents)}\n")
+            record.append(f"{dist_info}/RECORD,,\n")
+            wheel.writestr(f"{dist_info}/RECORD", "".join(record))
+        return wheel_filename
+"#};
+
 /// Uses an [`Arc`] internally, clone freely.
 #[derive(Debug, Clone)]
 pub struct SourceBuildContext {
@@ -224,6 +327,16 @@ pub struct SourceBuildContext {
     default_resolution: Arc<Mutex<Option<ResolvedRequirements>>>,
     /// A shared semaphore to limit the number of concurrent builds.
     concurrent_build_slots: Arc<Semaphore>,
+    /// A pool of build environments, shared across builds with identical build requirements.
+    environment_pool: Arc<BuildEnvironmentPool>,
+    /// The maximum duration of a single build backend hook invocation, if any.
+    timeout: Option<Duration>,
+    /// The number of times to retry a build backend hook that fails with a transient error.
+    retries: u32,
+    /// The warnings emitted by build backends during successful builds, if collected.
+    warnings: Option<Arc<BuildWarningCollector>>,
+    /// The build events of the current invocation.
+    events: BuildEvents,
 }
 
 impl SourceBuildContext {
@@ -232,8 +345,68 @@ impl SourceBuildContext {
         Self {
             default_resolution: Arc::default(),
             concurrent_build_slots,
+            environment_pool: Arc::default(),
+            timeout: None,
+            retries: 0,
+            warnings: None,
+            events: BuildEvents::default(),
         }
     }
+
+    /// Set the maximum duration of a single build backend hook invocation (e.g.,
+    /// `--build-timeout`).
+    ///
+    /// By default, build backend hooks are not subject to a timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the number of times to retry a build backend hook that fails with a transient error
+    /// (e.g., `--build-retries`).
+    ///
+    /// By default, build backend hooks are not retried.
+    #[must_use]
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Collect the warnings emitted by build backends during successful builds, identified by the
+    /// given patterns (e.g., `--build-warning-pattern`).
+    ///
+    /// If no patterns are provided, the [`DEFAULT_BUILD_WARNING_PATTERNS`] are used. By default,
+    /// build backend output isn't scanned for warnings.
+    #[must_use]
+    pub fn with_warning_patterns(mut self, patterns: &[String]) -> Self {
+        self.warnings = Some(Arc::new(BuildWarningCollector::new(patterns)));
+        self
+    }
+
+    /// Set the build events of the current invocation, to share the numbering of builds (and the
+    /// open build events file) with other build contexts.
+    ///
+    /// Build events are only recorded if a file is configured (e.g., with `--build-events-file`).
+    #[must_use]
+    pub fn with_events(mut self, events: BuildEvents) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Return the build events of the current invocation.
+    pub fn events(&self) -> &BuildEvents {
+        &self.events
+    }
+
+    /// Take the warnings emitted by build backends during successful builds so far, sorted by
+    /// package.
+    pub fn take_warnings(&self) -> Vec<BuildWarnings> {
+        self.warnings
+            .as_ref()
+            .map(|warnings| warnings.take())
+            .unwrap_or_default()
+    }
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -268,6 +441,9 @@ pub struct SourceBuild {
     /// Distribution identifier, e.g., `foo-1.2.3`. Used for error reporting if the name and
     /// version are unknown.
     version_id: Option<String>,
+    /// Whether the package comes from a registry, such that a pre-built wheel may be available.
+    /// Used for error reporting.
+    registry: bool,
     /// Whether we do a regular PEP 517 build or a PEP 660 editable build
     build_kind: BuildKind,
     /// Whether to send build output to `stderr` or `tracing`, etc.
@@ -278,13 +454,29 @@ pub struct SourceBuild {
     environment_variables: FxHashMap<OsString, OsString>,
     /// Runner for Python scripts.
     runner: PythonRunner,
+    /// The number of times to retry a build backend hook that fails with a transient error.
+    retries: u32,
+    /// The platform to build wheels for, if not the current platform.
+    target: Option<BuildTarget>,
+    /// Whether to build wheels via a source distribution of the source tree.
+    via_sdist: bool,
+    /// Whether to retain the build environment if a build backend hook fails.
+    retain_environment: bool,
+    /// Set if a build backend hook failed and the build environment should outlive the build.
+    retained: AtomicBool,
+    /// The build environment, if it's shared with other builds.
+    environment: Option<Arc<BuildEnvironment>>,
+    /// The sink to which build events are recorded, if any.
+    events: Option<Arc<BuildEventSink>>,
+    /// The properties of the build to include in build events.
+    event_context: BuildEventContext,
 }
 
 impl SourceBuild {
     /// Create a virtual environment in which to build a source distribution, extracting the
     /// contents from an archive if necessary.
     ///
-    /// `source_dist` is for error reporting only.
+    /// `source_dist` and `registry` are for error reporting only.
     pub async fn setup(
         source: &Path,
         subdirectory: Option<&Path>,
@@ -296,6 +488,8 @@ impl SourceBuild {
         build_context: &impl BuildContext,
         source_build_context: SourceBuildContext,
         version_id: Option<&str>,
+        registry: bool,
+        cache_shard: Option<&CacheShard>,
         locations: &IndexLocations,
         no_sources: NoSources,
         workspace_cache: &WorkspaceCache,
@@ -305,11 +499,10 @@ impl SourceBuild {
         build_stack: &BuildStack,
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
+        inherit_environment: bool,
         level: BuildOutput,
         credentials_cache: &CredentialsCache,
     ) -> Result<Self, Error> {
-        let temp_dir = build_context.cache().venv_dir()?;
-
         let source_tree = if let Some(subdir) = subdirectory {
             source.join(subdir)
         } else {
@@ -342,6 +535,27 @@ impl SourceBuild {
             .or(fallback_package_version)
             .cloned();
 
+        // Fail early if the interpreter is incompatible with the project, rather than letting the
+        // build backend fail mid-build.
+        if let Some(requires_python) = project.as_ref().and_then(Project::requires_python)
+            && !requires_python.contains(interpreter.python_version())
+        {
+            let package = match (&package_name, &package_version) {
+                (Some(name), Some(version)) => format!("{name}@{version}"),
+                (Some(name), None) => name.to_string(),
+                (None, _) => version_id.map_or_else(
+                    || source_tree.user_display().to_string(),
+                    ToString::to_string,
+                ),
+            };
+            return Err(Error::IncompatibleBuildPython {
+                package,
+                requires_python,
+                python_version: interpreter.python_version().clone(),
+                build_python: build_context.build_python().is_some(),
+            });
+        }
+
         let extra_build_dependencies = package_name
             .as_ref()
             .and_then(|name| extra_build_requires.get(name).cloned())
@@ -365,11 +579,127 @@ impl SourceBuild {
             .map_ok(Requirement::from)
             .collect::<Result<Vec<_>, _>>()?;
 
-        // Create a virtual environment, or install into the shared environment if requested.
-        let venv = if let Some(venv) = build_isolation.shared_environment(package_name.as_ref()) {
-            venv.clone()
+        // If the build dependencies are locked for this environment, reuse exactly the locked
+        // versions.
+        let locked_build_dependencies = build_context
+            .build_dependency_lock()
+            .zip(package_name.as_ref())
+            .and_then(|(build_dependency_lock, package_name)| {
+                build_dependency_lock.get(package_name, interpreter.markers())
+            });
+
+        let dependency_sources = if locked_build_dependencies.is_some() {
+            "the locked build dependencies in `uv.lock`"
+        } else if extra_build_dependencies.is_empty() {
+            "`build-system.requires`"
+        } else {
+            "`build-system.requires` and `extra-build-dependencies`"
+        };
+
+        // Resolve the build requirements. If build isolation is disabled, we assume the build
+        // environment is already set up.
+        let resolved_requirements = if build_isolation.is_isolated(package_name.as_ref()) {
+            debug!("Resolving build requirements");
+
+            let resolved_requirements = if let Some((package_name, locked)) = package_name
+                .as_ref()
+                .zip(locked_build_dependencies.as_deref())
+            {
+                debug!("Reusing the locked build requirements for `{package_name}`");
+                Self::get_locked_requirements(
+                    build_context,
+                    &pep517_backend,
+                    extra_build_dependencies,
+                    package_name,
+                    locked,
+                    build_stack,
+                )
+                .await?
+            } else {
+                let resolved_requirements = Self::get_resolved_requirements(
+                    build_context,
+                    source_build_context.clone(),
+                    &pep517_backend,
+                    extra_build_dependencies,
+                    build_stack,
+                )
+                .await?;
+
+                // Record the resolved build requirements, such that they can be locked.
+                if let Some((build_dependency_lock, package_name)) = build_context
+                    .build_dependency_lock()
+                    .zip(package_name.as_ref())
+                {
+                    build_dependency_lock.record(
+                        package_name,
+                        resolved_requirements.resolution().hashes(),
+                        interpreter.markers(),
+                    );
+                }
+
+                resolved_requirements
+            };
+
+            Some(resolved_requirements)
+        } else {
+            debug!("Proceeding without build isolation");
+            None
+        };
+
+        // Determine whether to share the build environment with other builds that have identical
+        // build requirements.
+        // A shared environment can't be retained for a single build, so builds that retain their
+        // environment on failure get their own.
+        let retain_environment = build_context.build_runtime().keep_environment()
+            && !build_context.cache().is_temporary();
+        let pool = resolved_requirements
+            .as_ref()
+            .filter(|_| !build_context.build_runtime().no_environment_reuse())
+            .filter(|_| !retain_environment)
+            .map(|_| &source_build_context.environment_pool);
+
+        // Create a virtual environment, or install into the shared environment if requested. A
+        // pooled environment must not be modified by the build, so the build's outputs are written
+        // to a separate working directory.
+        let mut environment_reused = false;
+        let mut environment = None;
+        let (temp_dir, mut venv) = if let Some(venv) =
+            build_isolation.shared_environment(package_name.as_ref())
+        {
+            (
+                build_context
+                    .cache()
+                    .build_dir_in(build_context.build_runtime().build_dir())?,
+                venv.clone(),
+            )
+        } else if let (Some(pool), Some(resolved_requirements)) =
+            (pool, resolved_requirements.as_ref())
+        {
+            let (pooled, reused) = pool
+                .get_or_create(interpreter, resolved_requirements, || {
+                    create_build_environment(
+                        interpreter,
+                        build_context,
+                        resolved_requirements,
+                        build_stack,
+                        dependency_sources,
+                    )
+                })
+                .await?;
+            environment_reused = reused;
+            let venv = pooled.venv().clone();
+            environment = Some(pooled);
+            (
+                build_context
+                    .cache()
+                    .build_dir_in(build_context.build_runtime().build_dir())?,
+                venv,
+            )
         } else {
-            uv_virtualenv::create_venv(
+            let temp_dir = build_context
+                .cache()
+                .build_dir_in(build_context.build_runtime().build_dir())?;
+            let venv = uv_virtualenv::create_venv(
                 temp_dir.path(),
                 interpreter.clone(),
                 uv_virtualenv::Prompt::None,
@@ -380,37 +710,68 @@ impl SourceBuild {
                 false,
                 false,
                 false,
-            )?
+            )?;
+            if let Some(resolved_requirements) = &resolved_requirements {
+                build_context
+                    .install(resolved_requirements, &venv, build_stack)
+                    .await
+                    .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+            }
+            (temp_dir, venv)
         };
 
-        // Set up the build environment. If build isolation is disabled, we assume the build
-        // environment is already set up.
-        if build_isolation.is_isolated(package_name.as_ref()) {
-            debug!("Resolving build requirements");
-
-            let dependency_sources = if extra_build_dependencies.is_empty() {
-                "`build-system.requires`"
-            } else {
-                "`build-system.requires` and `extra-build-dependencies`"
-            };
-
-            let resolved_requirements = Self::get_resolved_requirements(
-                build_context,
-                source_build_context.clone(),
-                &pep517_backend,
-                extra_build_dependencies,
-                build_stack,
-            )
-            .await?;
-
-            build_context
-                .install(&resolved_requirements, &venv, build_stack)
-                .await
-                .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
-        } else {
-            debug!("Proceeding without build isolation");
+        // If building for another platform, instruct the build backend to target it, unless the
+        // user set the variables explicitly.
+        let target = build_context.build_runtime().target().cloned();
+        if let Some(target) = &target {
+            for (key, value) in target.environment_variables() {
+                environment_variables
+                    .entry(OsString::from(key))
+                    .or_insert_with(|| OsString::from(value));
+            }
         }
 
+        // Cache the requirements returned by `get_requires_for_build_*` in the source distribution
+        // revision, keyed by the inputs to the hook. Without build isolation, the build
+        // environment is outside of our control, so the requirements are never cached.
+        let requires_cache = cache_shard
+            .filter(|_| !build_context.cache().is_temporary())
+            .zip(resolved_requirements.as_ref())
+            .map(|(cache_shard, resolved_requirements)| {
+                let build_environment = resolved_requirements
+                    .resolution()
+                    .distributions()
+                    .map(ToString::to_string)
+                    .sorted()
+                    .collect::<Vec<_>>();
+                let environment_variables = environment_variables
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            key.to_string_lossy().into_owned(),
+                            value.to_string_lossy().into_owned(),
+                        )
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                // Backends may return different requirements per platform (e.g., based on
+                // `platform_machine` or the libc), so the key includes the markers and platform of
+                // the build interpreter.
+                let platform =
+                    serde_json::to_string(&(interpreter.markers(), interpreter.platform()))
+                        .unwrap_or_default();
+                let digest = cache_digest(&(
+                    &source_tree,
+                    &pep517_backend.backend,
+                    interpreter.implementation_name(),
+                    interpreter.python_full_version().to_string(),
+                    platform,
+                    &config_settings,
+                    build_environment,
+                    environment_variables,
+                ));
+                cache_shard.entry(format!("build-requires-{build_kind}-{digest}.json"))
+            });
+
         // Figure out what the modified path should be, and remove the PATH variable from the
         // environment variables if it's there.
         let user_path = environment_variables.remove(&OsString::from(EnvVars::PATH));
@@ -435,20 +796,58 @@ impl SourceBuild {
         };
 
         // Prepend the venv bin directory to the modified path
-        let modified_path = if let Some(path) = modified_path {
-            let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(&path));
-            env::join_paths(venv_path).map_err(Error::BuildScriptPath)?
+        let base_path = modified_path;
+        let mut modified_path = venv_path(&venv, base_path.as_ref())?;
+
+        // Persist the build backend output to the cache, for inspection after the build. A
+        // temporary cache is removed on exit, so there's no point in writing a log to it.
+        let cache = build_context.cache();
+        let log = if cache.is_temporary() {
+            None
         } else {
-            OsString::from(venv.scripts())
+            // Group the logs by package, such that they can be removed with `uv cache clean`.
+            let digest = cache_digest(&source_tree);
+            let bucket = cache.bucket(CacheBucket::BuildLogs);
+            let directory = match (&package_name, &package_version) {
+                (Some(name), Some(version)) => bucket
+                    .join(name.as_str())
+                    .join(format!("{version}-{digest}")),
+                (Some(name), None) => bucket.join(name.as_str()).join(digest),
+                (None, _) => bucket.join(digest),
+            };
+            if let Some(name) = &package_name {
+                BuildLog::prune(&bucket.join(name.as_str()));
+            }
+            BuildLog::create(
+                directory.join("build.log"),
+                build_context.build_runtime().keep_logs(),
+            )
         };
 
+        let runner = PythonRunner::new(
+            source_build_context.concurrent_build_slots.clone(),
+            source_build_context.timeout,
+            level,
+            package_name
+                .as_ref()
+                .map(ToString::to_string)
+                .or_else(|| version_id.map(ToString::to_string)),
+            match (&package_name, &package_version) {
+                (Some(name), Some(version)) => Some(format!("{name}=={version}")),
+                (Some(name), None) => Some(name.to_string()),
+                (None, _) => version_id.map(ToString::to_string),
+            },
+            source_build_context.warnings.clone(),
+            log,
+            inherit_environment,
+        );
+
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already set up.
-        let runner = PythonRunner::new(source_build_context.concurrent_build_slots.clone(), level);
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
-            create_pep517_build_environment(
+            let extra_requirements = match create_pep517_build_environment(
                 &runner,
                 &source_tree,
                 install_path,
@@ -458,6 +857,7 @@ impl SourceBuild {
                 package_name.as_ref(),
                 package_version.as_ref(),
                 version_id,
+                registry,
                 locations,
                 no_sources,
                 stop_discovery_at,
@@ -469,11 +869,68 @@ impl SourceBuild {
                 &environment_variables,
                 &modified_path,
                 &temp_dir,
+                requires_cache.as_ref(),
+                environment.as_deref(),
                 credentials_cache,
+                locked_build_dependencies.as_deref(),
             )
-            .await?;
+            .await
+            {
+                Ok(extra_requirements) => extra_requirements,
+                Err(err) if retain_environment && err.is_hook_failure() => {
+                    return Err(retain_build_environment(err, temp_dir, &venv, &source_tree));
+                }
+                Err(err) => return Err(err),
+            };
+
+            // If the build backend requires additional packages, install them. A pooled
+            // environment is shared with other builds, so use the pooled environment for the
+            // extended set of requirements instead.
+            if let Some(extra_requirements) = extra_requirements {
+                debug!("Installing extra requirements for build backend");
+                if let Some(pool) = pool {
+                    let (pooled, reused) = pool
+                        .get_or_create(interpreter, &extra_requirements, || {
+                            create_build_environment(
+                                interpreter,
+                                build_context,
+                                &extra_requirements,
+                                build_stack,
+                                "`build-system.requires`",
+                            )
+                        })
+                        .await?;
+                    environment_reused = reused;
+                    venv = pooled.venv().clone();
+                    environment = Some(pooled);
+                    modified_path = venv_path(&venv, base_path.as_ref())?;
+                } else {
+                    build_context
+                        .install(&extra_requirements, &venv, build_stack)
+                        .await
+                        .map_err(|err| {
+                            Error::RequirementsInstall(
+                                "`build-system.requires`",
+                                AnyErrorBuild::from(err),
+                            )
+                        })?;
+                }
+            }
         }
 
+        let event_context = BuildEventContext {
+            package: package_name.clone(),
+            version: package_version.clone(),
+            backend: pep517_backend.backend.clone(),
+            interpreter: InterpreterInfo::from(interpreter),
+            isolation: if build_isolation.is_isolated(package_name.as_ref()) {
+                Isolation::Isolated
+            } else {
+                Isolation::None
+            },
+            environment_reused,
+        };
+
         Ok(Self {
             temp_dir,
             source_tree,
@@ -487,9 +944,43 @@ impl SourceBuild {
             package_name,
             package_version,
             version_id: version_id.map(ToString::to_string),
+            registry,
             environment_variables,
             modified_path,
             runner,
+            retries: source_build_context.retries,
+            events: build_context
+                .build_runtime()
+                .events_file()
+                .map(|path| source_build_context.events.sink(path)),
+            target,
+            via_sdist: build_context.build_runtime().via_sdist(),
+            retain_environment,
+            retained: AtomicBool::new(false),
+            environment,
+            event_context,
+        })
+    }
+
+    /// Return the root of the isolated build environment, if the build runs in one.
+    fn isolated_environment(&self) -> Option<&Path> {
+        (self.event_context.isolation == Isolation::Isolated).then(|| self.venv.root())
+    }
+
+    /// If enabled, retain the build environment after a build backend hook failed, attaching its
+    /// location to the error.
+    fn retain_environment(&self, err: Error) -> Error {
+        if !self.retain_environment || !err.is_hook_failure() {
+            return err;
+        }
+        debug!(
+            "Retaining build environment at: {}",
+            self.temp_dir.path().display()
+        );
+        self.retained.store(true, Ordering::Relaxed);
+        err.with_retained_environment(RetainedEnvironment {
+            venv: self.venv.root().to_path_buf(),
+            source_tree: self.source_tree.clone(),
         })
     }
 
@@ -522,6 +1013,38 @@ impl SourceBuild {
         Ok(source_tree_lock)
     }
 
+    /// Lock the build environment, if it's shared with other builds.
+    async fn lock_environment(&self) -> Option<OwnedMutexGuard<()>> {
+        match &self.environment {
+            Some(environment) => Some(environment.lock().await),
+            None => None,
+        }
+    }
+
+    /// Resolve the locked build requirements for the given package, verifying the locked hashes
+    /// when installing them.
+    /// Resolve the build requirements, constrained to the locked build dependencies, and verify
+    /// the locked hashes.
+    async fn get_locked_requirements(
+        build_context: &impl BuildContext,
+        pep517_backend: &Pep517Backend,
+        extra_build_dependencies: Vec<Requirement>,
+        package_name: &PackageName,
+        locked: &[&LockedBuildDependency],
+        build_stack: &BuildStack,
+    ) -> Result<ResolvedRequirements, Error> {
+        let mut requirements = pep517_backend.requirements.clone();
+        requirements.extend(extra_build_dependencies);
+        resolve_locked_requirements(
+            build_context,
+            &requirements,
+            package_name,
+            locked,
+            build_stack,
+        )
+        .await
+    }
+
     async fn get_resolved_requirements(
         build_context: &impl BuildContext,
         source_build_context: SourceBuildContext,
@@ -538,7 +1061,7 @@ impl SourceBuild {
                     resolved_requirements.clone()
                 } else {
                     let resolved_requirements = build_context
-                        .resolve(&DEFAULT_BACKEND.requirements, build_stack)
+                        .resolve(&DEFAULT_BACKEND.requirements, &[], build_stack)
                         .await
                         .map_err(|err| {
                             Error::RequirementsResolve("`setup.py` build", err.into())
@@ -563,7 +1086,7 @@ impl SourceBuild {
                     )
                 };
                 build_context
-                    .resolve(&requirements, build_stack)
+                    .resolve(&requirements, &[], build_stack)
                     .await
                     .map_err(|err| Error::RequirementsResolve(dependency_sources, err.into()))?
             },
@@ -611,15 +1134,29 @@ impl SourceBuild {
             .as_ref()
             .and_then(|build_system| build_system.build_backend.as_deref());
 
-        if let Some(backend_path) = pyproject_toml
+        // Resolve each `backend-path` entry relative to the source tree, rather than the working
+        // directory of the hook subprocess.
+        let mut backend_path = Vec::new();
+        if let Some(entries) = pyproject_toml
             .build_system
             .as_ref()
             .and_then(|build_system| build_system.backend_path.as_ref())
         {
-            for path in backend_path.iter() {
-                if !source_tree.join(path).is_dir() {
-                    return Err(Box::new(Error::InvalidBackendPath(path.to_string())));
+            let canonical_source_tree = source_tree.simple_canonicalize().map_err(Error::Io)?;
+            for entry in entries.iter() {
+                let path = source_tree.join(entry);
+                if !path.is_dir() {
+                    return Err(Box::new(Error::InvalidBackendPath(entry.to_string())));
+                }
+                let path = path.simple_canonicalize().map_err(Error::Io)?;
+                // > Projects' backend-path entries must be relative to the project root and must
+                // > refer to a location within the source tree.
+                if !path.starts_with(&canonical_source_tree) {
+                    return Err(Box::new(Error::BackendPathOutsideSourceTree(
+                        entry.to_string(),
+                    )));
                 }
+                backend_path.push(path);
             }
         }
 
@@ -698,7 +1235,7 @@ impl SourceBuild {
                 backend: build_system
                     .build_backend
                     .unwrap_or_else(|| "setuptools.build_meta:__legacy__".to_string()),
-                backend_path: build_system.backend_path,
+                backend_path,
                 requirements,
             }
         } else {
@@ -742,6 +1279,38 @@ impl SourceBuild {
         Ok((backend, pyproject_toml.project))
     }
 
+    /// Call the build backend's `prepare_metadata_for_build_wheel` hook (or
+    /// `prepare_metadata_for_build_editable`, for editable builds) to get the metadata without
+    /// building a wheel.
+    ///
+    /// Returns the path to the `.dist-info` directory, or `None` if the backend doesn't implement
+    /// the hook, in which case the metadata can be read from a wheel built with
+    /// [`SourceBuild::build_wheel`] instead. Repeated calls return the existing directory. The
+    /// directory is located inside the build's temporary directory, i.e., it must be used before the
+    /// [`SourceBuild`] is dropped.
+    ///
+    /// ```rust,ignore
+    /// let mut builder = SourceBuild::setup(/* ... */).await?;
+    /// if let Some(dist_info) = builder.metadata().await? {
+    ///     let metadata = fs_err::read_to_string(dist_info.join("METADATA"))?;
+    /// }
+    /// ```
+    pub async fn metadata(&mut self) -> Result<Option<PathBuf>, Error> {
+        let metadata = self.get_metadata_without_build().await?;
+        self.runner.succeed();
+        Ok(metadata)
+    }
+
+    /// The build kind for which `prepare_metadata_for_build_*` is called.
+    ///
+    /// There's no metadata hook for source distributions, so the wheel hook is used instead.
+    fn metadata_build_kind(&self) -> BuildKind {
+        match self.build_kind {
+            BuildKind::Editable => BuildKind::Editable,
+            BuildKind::Wheel | BuildKind::Sdist => BuildKind::Wheel,
+        }
+    }
+
     /// Try calling `prepare_metadata_for_build_wheel` to get the metadata without executing the
     /// actual build.
     async fn get_metadata_without_build(&mut self) -> Result<Option<PathBuf>, Error> {
@@ -753,6 +1322,9 @@ impl SourceBuild {
         // Lock the source tree, if necessary.
         let _lock = self.acquire_lock().await?;
 
+        // Lock the build environment, if it's shared with other builds.
+        let _environment_lock = self.lock_environment().await;
+
         // Hatch allows for highly dynamic customization of metadata via hooks. In such cases, Hatch
         // can't uphold the PEP 517 contract, in that the metadata Hatch would return by
         // `prepare_metadata_for_build_wheel` isn't guaranteed to match that of the built wheel.
@@ -783,18 +1355,19 @@ impl SourceBuild {
             }
         }
 
+        let build_kind = self.metadata_build_kind();
         let metadata_directory = self.temp_dir.path().join("metadata_directory");
         fs::create_dir(&metadata_directory)?;
 
         // Write the hook output to a file so that we can read it back reliably.
-        let outfile = self.temp_dir.path().join(format!(
-            "prepare_metadata_for_build_{}.txt",
-            self.build_kind
-        ));
+        let outfile = self
+            .temp_dir
+            .path()
+            .join(format!("prepare_metadata_for_build_{build_kind}.txt"));
 
         debug!(
             "Calling `{}.prepare_metadata_for_build_{}()`",
-            self.pep517_backend.backend, self.build_kind,
+            self.pep517_backend.backend, build_kind,
         );
         let script = formatdoc! {
             r#"
@@ -811,19 +1384,21 @@ impl SourceBuild {
                 fp.write(dirname or "")
             "#,
             self.pep517_backend.backend_import(),
-            self.build_kind,
+            build_kind,
             escape_path_for_python(&metadata_directory),
             self.config_settings.escape_for_python(),
             outfile.escape_for_python(),
         };
+        let hook = format!("prepare_metadata_for_build_{build_kind}");
         let span = info_span!(
             "run_python_script",
-            script = format!("prepare_metadata_for_build_{}", self.build_kind),
+            script = hook,
             version_id = self.version_id,
         );
         let output = self
             .runner
             .run_script(
+                &hook,
                 &self.venv,
                 &script,
                 &self.source_tree,
@@ -832,18 +1407,20 @@ impl SourceBuild {
             )
             .instrument(span)
             .await?;
-        if !output.status.success() {
-            return Err(Error::from_command_output(
+        if !output.success() {
+            return Err(self.retain_environment(Error::from_command_output(
                 format!(
                     "Call to `{}.prepare_metadata_for_build_{}` failed",
-                    self.pep517_backend.backend, self.build_kind
+                    self.pep517_backend.backend, build_kind
                 ),
                 &output,
                 self.level,
                 self.package_name.as_ref(),
                 self.package_version.as_ref(),
                 self.version_id.as_deref(),
-            ));
+                self.registry,
+                self.isolated_environment(),
+            )));
         }
 
         let dirname = fs::read_to_string(&outfile)?;
@@ -861,32 +1438,247 @@ impl SourceBuild {
     /// the temporary directory.
     ///
     /// <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
+    ///
+    /// When building a wheel, the wheel is instead built from a source distribution of the source
+    /// tree if the build backend doesn't support `build_wheel`, or if requested for source trees
+    /// that aren't source distributions themselves (e.g., `--build-via-sdist`).
     #[instrument(skip_all, fields(version_id = self.version_id))]
     pub async fn build(&self, wheel_dir: &Path) -> Result<String, Error> {
+        self.record(self.build_kind, self.build_output(wheel_dir))
+            .await
+    }
+
+    /// Build the source tree for the [`BuildKind`] it was set up for, without recording build
+    /// events.
+    async fn build_output(&self, wheel_dir: &Path) -> Result<String, Error> {
+        if self.build_kind != BuildKind::Wheel {
+            return self
+                .build_with_retries(&self.source_tree, wheel_dir, self.build_kind)
+                .await;
+        }
+
+        if self.via_sdist && !self.source_tree.join("PKG-INFO").is_file() {
+            debug!("Building wheel via a source distribution");
+            return self.build_wheel_via_sdist(wheel_dir).await;
+        }
+
+        match self
+            .build_with_retries(&self.source_tree, wheel_dir, BuildKind::Wheel)
+            .await
+        {
+            Err(Error::WheelUnsupported(backend)) => {
+                debug!(
+                    "Build backend `{backend}` does not support `build_wheel`; building wheel via a source distribution"
+                );
+                self.build_wheel_via_sdist(wheel_dir).await
+            }
+            result => result,
+        }
+    }
+
+    /// Build a source distribution of the source tree, then build a wheel from the extracted
+    /// source distribution, and return the filename of the wheel inside `wheel_dir`.
+    async fn build_wheel_via_sdist(&self, wheel_dir: &Path) -> Result<String, Error> {
+        let via_sdist = |stage| {
+            move |err| Error::ViaSdist {
+                stage,
+                err: Box::new(err),
+            }
+        };
+
+        // Build the source distribution.
+        let sdist_dir = tempfile::tempdir_in(self.temp_dir.path())?;
+        let sdist = self
+            .sdist(sdist_dir.path())
+            .await
+            .map_err(via_sdist(ViaSdistStage::BuildSdist))?;
+
+        // Extract the source distribution.
+        let extracted_dir = tempfile::tempdir_in(self.temp_dir.path())?;
+        let source_tree = extract_sdist(&sdist, extracted_dir.path())
+            .await
+            .map_err(via_sdist(ViaSdistStage::Extract))?;
+
+        // Build the wheel from the extracted source distribution, using the backend it declares.
+        debug!(
+            "Building wheel from source distribution: {}",
+            source_tree.user_display()
+        );
+        self.build_with_retries(&source_tree, wheel_dir, BuildKind::Wheel)
+            .await
+            .map_err(via_sdist(ViaSdistStage::BuildWheel))
+    }
+
+    /// Call the build backend's `build_sdist` hook, and return the path to the built source
+    /// distribution inside `sdist_dir`.
+    ///
+    /// The hook runs in the environment prepared by [`SourceBuild::setup`], which only contains the
+    /// requirements the backend returned from `get_requires_for_build_*` for the [`BuildKind`] the
+    /// build was set up for. A single build can produce both a source distribution and a wheel
+    /// only if the backend requires the same packages for both; otherwise, set up a build for each.
+    ///
+    /// ```rust,ignore
+    /// let builder = SourceBuild::setup(/* ... */).await?;
+    /// let sdist = builder.build_sdist(Path::new("dist")).await?;
+    /// let wheel = builder.build_wheel(Path::new("dist")).await?;
+    /// ```
+    #[instrument(skip_all, fields(version_id = self.version_id))]
+    pub async fn build_sdist(&self, sdist_dir: &Path) -> Result<PathBuf, Error> {
+        self.record(BuildKind::Sdist, self.sdist(sdist_dir)).await
+    }
+
+    /// Build a source distribution of the source tree, without recording build events.
+    async fn sdist(&self, sdist_dir: &Path) -> Result<PathBuf, Error> {
+        let filename = self
+            .build_with_retries(&self.source_tree, sdist_dir, BuildKind::Sdist)
+            .await?;
+        Ok(std::path::absolute(sdist_dir)?.join(filename))
+    }
+
+    /// Call the build backend's `build_wheel` hook, and return the path to the built wheel inside
+    /// `wheel_dir`.
+    ///
+    /// If [`SourceBuild::metadata`] was called previously, the resulting `.dist-info` directory is
+    /// passed to the hook, such that the wheel's metadata matches.
+    #[instrument(skip_all, fields(version_id = self.version_id))]
+    pub async fn build_wheel(&self, wheel_dir: &Path) -> Result<PathBuf, Error> {
+        self.record(BuildKind::Wheel, async {
+            let filename = self
+                .build_with_retries(&self.source_tree, wheel_dir, BuildKind::Wheel)
+                .await?;
+            Ok(std::path::absolute(wheel_dir)?.join(filename))
+        })
+        .await
+    }
+
+    /// Run a build, recording its start and finish as build events, if enabled.
+    async fn record<T>(
+        &self,
+        build_kind: BuildKind,
+        build: impl Future<Output = Result<T, Error>>,
+    ) -> Result<T, Error> {
+        let Some(events) = &self.events else {
+            return Box::pin(build).await;
+        };
+        let started = events.start(&self.event_context, build_kind);
+        let result = Box::pin(build).await;
+        events.finish(started, &self.event_context, build_kind, &result);
+        result
+    }
+
+    /// Perform a PEP 517 build, retrying the hook if it fails with a transient error.
+    async fn build_with_retries(
+        &self,
+        source_tree: &Path,
+        output_dir: &Path,
+        build_kind: BuildKind,
+    ) -> Result<String, Error> {
         // The build scripts run with the extracted root as cwd, so they need the absolute path.
-        let wheel_dir = std::path::absolute(wheel_dir)?;
-        let filename = self.pep517_build(&wheel_dir).await?;
+        let output_dir = std::path::absolute(output_dir)?;
+        let retries = self.retries;
+
+        // Snapshot the source tree before the first attempt, such that each retry starts from a
+        // pristine copy, rather than from one with the partial build artifacts of a failed
+        // attempt. Editable builds must reference the original source tree, so they're retried in
+        // place.
+        let pristine_tree = if retries > 0 && build_kind != BuildKind::Editable {
+            let pristine_tree = tempfile::tempdir_in(self.temp_dir.path())?;
+            uv_fs::copy_dir_all(source_tree, pristine_tree.path())?;
+            Some(pristine_tree)
+        } else {
+            None
+        };
+
+        let mut attempts = 1;
+        let filename = loop {
+            // Retry in a fresh scratch directory, as the failed attempt may have left a partial
+            // distribution behind.
+            let scratch_dir = if attempts == 1 {
+                None
+            } else {
+                Some(tempfile::tempdir_in(self.temp_dir.path())?)
+            };
+            let (attempt_tree, attempt_dir) = if let Some(scratch_dir) = scratch_dir.as_ref() {
+                let attempt_tree = if let Some(pristine_tree) = pristine_tree.as_ref() {
+                    let attempt_tree = scratch_dir.path().join("source");
+                    uv_fs::copy_dir_all(pristine_tree.path(), &attempt_tree)?;
+                    Cow::Owned(attempt_tree)
+                } else {
+                    Cow::Borrowed(source_tree)
+                };
+                let attempt_dir = scratch_dir.path().join("dist");
+                fs::create_dir(&attempt_dir)?;
+                (attempt_tree, Cow::Owned(attempt_dir))
+            } else {
+                (
+                    Cow::Borrowed(source_tree),
+                    Cow::Borrowed(output_dir.as_path()),
+                )
+            };
+            match self
+                .pep517_build(&attempt_tree, &attempt_dir, build_kind)
+                .await
+            {
+                Ok(filename) => {
+                    if scratch_dir.is_some() {
+                        uv_fs::rename_with_retry(
+                            attempt_dir.join(&filename),
+                            output_dir.join(&filename),
+                        )
+                        .await?;
+                    }
+                    break filename;
+                }
+                Err(err) if attempts <= retries && err.is_transient() => {
+                    warn!(
+                        "Build failed with a transient error, retrying (attempt {} of {})",
+                        attempts + 1,
+                        retries + 1
+                    );
+                    attempts += 1;
+                }
+                Err(err) if attempts > 1 => {
+                    return Err(Error::BuildRetriesExhausted {
+                        attempts,
+                        err: Box::new(err),
+                    });
+                }
+                Err(err) => return Err(err),
+            }
+        };
+        self.runner.succeed();
         Ok(filename)
     }
 
-    /// Perform a PEP 517 build for a wheel or source distribution (sdist).
-    async fn pep517_build(&self, output_dir: &Path) -> Result<String, Error> {
+    /// Perform a PEP 517 build for a wheel or source distribution (sdist) of the given source tree.
+    async fn pep517_build(
+        &self,
+        source_tree: &Path,
+        output_dir: &Path,
+        build_kind: BuildKind,
+    ) -> Result<String, Error> {
         // Lock the source tree, if necessary.
         let _lock = self.acquire_lock().await?;
 
+        // Lock the build environment, if it's shared with other builds.
+        let _environment_lock = self.lock_environment().await;
+
         // Write the hook output to a file so that we can read it back reliably.
-        let outfile = self
+        let outfile = self.temp_dir.path().join(format!("build_{build_kind}.txt"));
+
+        // If the backend doesn't implement an optional hook, write a marker file instead.
+        let missing_hook = self
             .temp_dir
             .path()
-            .join(format!("build_{}.txt", self.build_kind));
+            .join(format!("missing_build_{build_kind}.txt"));
 
         // Construct the appropriate build script based on the build kind.
-        let script = match self.build_kind {
+        let script = match build_kind {
             BuildKind::Sdist => {
                 debug!(
                     r#"Calling `{}.build_{}("{}", {})`"#,
                     self.pep517_backend.backend,
-                    self.build_kind,
+                    build_kind,
                     output_dir.escape_for_python(),
                     self.config_settings.escape_for_python(),
                 );
@@ -899,23 +1691,62 @@ impl SourceBuild {
                         fp.write(sdist_filename)
                     "#,
                     self.pep517_backend.backend_import(),
-                    self.build_kind,
+                    build_kind,
                     output_dir.escape_for_python(),
                     self.config_settings.escape_for_python(),
                     outfile.escape_for_python()
                 }
             }
             BuildKind::Wheel | BuildKind::Editable => {
+                // PEP 660 hooks are optional, so check that the backend supports editable builds.
+                // Without `build_editable`, write the marker file to signal as much, unless the
+                // backend is a `setuptools` version that predates PEP 660, in which case we fall
+                // back to `setup.py develop` semantics. Similarly, some in-tree backends only
+                // implement `build_sdist`.
+                let hook_check = if build_kind == BuildKind::Editable
+                    && self.pep517_backend.is_setuptools()
+                    && source_tree.join("setup.py").is_file()
+                {
+                    formatdoc! {
+                        r#"
+                        {}
+
+                        if not hasattr(backend, "build_editable"):
+                            wheel_filename = build_editable_develop(backend, "{}", {})
+                            with open("{}", "w") as fp:
+                                fp.write(wheel_filename)
+                            sys.exit(0)
+                        "#,
+                        SETUPTOOLS_DEVELOP,
+                        output_dir.escape_for_python(),
+                        self.config_settings.escape_for_python(),
+                        outfile.escape_for_python()
+                    }
+                } else {
+                    formatdoc! {
+                        r#"
+                        if not hasattr(backend, "build_{}"):
+                            open("{}", "w").close()
+                            sys.exit(0)
+                        "#,
+                        build_kind,
+                        missing_hook.escape_for_python()
+                    }
+                };
+                // Only pass the metadata directory to the hook it was prepared for, from the
+                // source tree it was prepared in.
                 let metadata_directory = self
                     .metadata_directory
                     .as_deref()
+                    .filter(|_| build_kind == self.metadata_build_kind())
+                    .filter(|_| source_tree == self.source_tree)
                     .map_or("None".to_string(), |path| {
                         format!(r#""{}""#, path.escape_for_python())
                     });
                 debug!(
                     r#"Calling `{}.build_{}("{}", {}, {})`"#,
                     self.pep517_backend.backend,
-                    self.build_kind,
+                    build_kind,
                     output_dir.escape_for_python(),
                     self.config_settings.escape_for_python(),
                     metadata_directory,
@@ -923,13 +1754,14 @@ impl SourceBuild {
                 formatdoc! {
                     r#"
                     {}
-
+                    {}
                     wheel_filename = backend.build_{}("{}", {}, {})
                     with open("{}", "w") as fp:
                         fp.write(wheel_filename)
                     "#,
                     self.pep517_backend.backend_import(),
-                    self.build_kind,
+                    hook_check,
+                    build_kind,
                     output_dir.escape_for_python(),
                     self.config_settings.escape_for_python(),
                     metadata_directory,
@@ -938,64 +1770,150 @@ impl SourceBuild {
             }
         };
 
+        let hook = format!("build_{build_kind}");
         let span = info_span!(
             "run_python_script",
-            script = format!("build_{}", self.build_kind),
+            script = hook,
             version_id = self.version_id,
         );
         let output = self
             .runner
             .run_script(
+                &hook,
                 &self.venv,
                 &script,
-                &self.source_tree,
+                source_tree,
                 &self.environment_variables,
                 &self.modified_path,
             )
             .instrument(span)
             .await?;
-        if !output.status.success() {
-            return Err(Error::from_command_output(
+        if !output.success() {
+            return Err(self.retain_environment(Error::from_command_output(
                 format!(
                     "Call to `{}.build_{}` failed",
-                    self.pep517_backend.backend, self.build_kind
+                    self.pep517_backend.backend, build_kind
                 ),
                 &output,
                 self.level,
                 self.package_name.as_ref(),
                 self.package_version.as_ref(),
                 self.version_id.as_deref(),
-            ));
+                self.registry,
+                self.isolated_environment(),
+            )));
+        }
+
+        if missing_hook.is_file() {
+            fs::remove_file(&missing_hook)?;
+            let backend = self.pep517_backend.backend.clone();
+            return Err(if build_kind == BuildKind::Editable {
+                Error::EditableUnsupported(backend)
+            } else {
+                Error::WheelUnsupported(backend)
+            });
         }
 
         let distribution_filename = fs::read_to_string(&outfile)?;
         if !output_dir.join(&distribution_filename).is_file() {
-            return Err(Error::from_command_output(
+            return Err(self.retain_environment(Error::from_command_output(
                 format!(
                     "Call to `{}.build_{}` failed",
-                    self.pep517_backend.backend, self.build_kind
+                    self.pep517_backend.backend, build_kind
                 ),
                 &output,
                 self.level,
                 self.package_name.as_ref(),
                 self.package_version.as_ref(),
                 self.version_id.as_deref(),
-            ));
+                self.registry,
+                self.isolated_environment(),
+            )));
+        }
+        if matches!(build_kind, BuildKind::Wheel | BuildKind::Editable) {
+            self.check_build_target(&distribution_filename)?;
         }
         Ok(distribution_filename)
     }
-}
 
-impl SourceBuildTrait for SourceBuild {
-    async fn metadata(&mut self) -> Result<Option<PathBuf>, AnyErrorBuild> {
-        Ok(self.get_metadata_without_build().await?)
+    /// If building for another platform (e.g., `--build-target`), check that the wheel returned
+    /// by the build backend is compatible with the target.
+    fn check_build_target(&self, filename: &str) -> Result<(), Error> {
+        let Some(target) = &self.target else {
+            return Ok(());
+        };
+        let filename = WheelFilename::from_str(filename)?;
+        let interpreter = self.venv.interpreter();
+        let tags = Tags::from_env(
+            &target.platform(),
+            interpreter.python_tuple(),
+            interpreter.implementation_name(),
+            interpreter.implementation_tuple(),
+            TagsOptions {
+                manylinux_compatible: true,
+                gil_disabled: interpreter.gil_disabled(),
+                debug_enabled: interpreter.debug_enabled(),
+                is_cross: true,
+            },
+        )?;
+        if filename.is_compatible(&tags) {
+            return Ok(());
+        }
+        Err(Error::IncompatibleBuildTarget {
+            filename: Box::new(filename),
+            target: target.clone(),
+        })
     }
+}
 
-    async fn wheel<'a>(&'a self, wheel_dir: &'a Path) -> Result<String, AnyErrorBuild> {
+impl Drop for SourceBuild {
+    fn drop(&mut self) {
+        if *self.retained.get_mut() {
+            self.temp_dir.disable_cleanup(true);
+        }
+    }
+}
+
+impl SourceBuildTrait for SourceBuild {
+    async fn metadata(&mut self) -> Result<Option<PathBuf>, AnyErrorBuild> {
+        Ok(Self::metadata(self).await?)
+    }
+
+    async fn wheel<'a>(&'a self, wheel_dir: &'a Path) -> Result<String, AnyErrorBuild> {
         Ok(self.build(wheel_dir).await?)
     }
 }
 
+/// Retain the build environment of a build that failed during setup, attaching its location to the
+/// error.
+fn retain_build_environment(
+    err: Error,
+    temp_dir: TempDir,
+    venv: &PythonEnvironment,
+    source_tree: &Path,
+) -> Error {
+    let path = temp_dir.keep();
+    debug!("Retaining build environment at: {}", path.display());
+    err.with_retained_environment(RetainedEnvironment {
+        venv: venv.root().to_path_buf(),
+        source_tree: source_tree.to_path_buf(),
+    })
+}
+
+/// Extract a source distribution into the given directory, and return the path to its top-level
+/// directory.
+async fn extract_sdist(sdist: &Path, target: &Path) -> Result<PathBuf, Error> {
+    let ext = SourceDistExtension::from_path(sdist)
+        .map_err(|err| Error::InvalidSdistExtension(sdist.to_path_buf(), err))?;
+    let reader = fs_err::tokio::File::open(sdist).await?;
+    uv_extract::stream::archive(sdist.user_display(), reader, ext, target).await?;
+    match uv_extract::strip_component(target) {
+        Ok(top_level) => Ok(top_level),
+        Err(uv_extract::Error::NonSingularArchive(_)) => Ok(target.to_path_buf()),
+        Err(err) => Err(err.into()),
+    }
+}
+
 fn escape_path_for_python(path: &Path) -> String {
     path.to_string_lossy()
         .replace('\\', "\\\\")
@@ -1013,6 +1931,7 @@ async fn create_pep517_build_environment(
     package_name: Option<&PackageName>,
     package_version: Option<&Version>,
     version_id: Option<&str>,
+    registry: bool,
     locations: &IndexLocations,
     no_sources: NoSources,
     stop_discovery_at: Option<&Path>,
@@ -1024,8 +1943,221 @@ async fn create_pep517_build_environment(
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
     temp_dir: &TempDir,
+    requires_cache: Option<&CacheEntry>,
+    environment: Option<&BuildEnvironment>,
     credentials_cache: &CredentialsCache,
-) -> Result<(), Error> {
+    locked_build_dependencies: Option<&[&LockedBuildDependency]>,
+) -> Result<Option<ResolvedRequirements>, Error> {
+    // Reuse the requirements from a previous build of the same source distribution, unless the
+    // cache must be refreshed.
+    let cached_requires = if let Some(entry) = requires_cache {
+        read_cached_requires(entry, build_context.cache(), package_name, source_tree)
+    } else {
+        None
+    };
+
+    let extra_requires = if let Some(extra_requires) = cached_requires {
+        debug!(
+            "Using cached result of `{}.get_requires_for_build_{}()`",
+            pep517_backend.backend, build_kind
+        );
+        extra_requires
+    } else {
+        run_get_requires(
+            runner,
+            source_tree,
+            venv,
+            pep517_backend,
+            package_name,
+            package_version,
+            version_id,
+            registry,
+            build_kind,
+            level,
+            config_settings,
+            environment_variables,
+            modified_path,
+            temp_dir,
+            requires_cache,
+            environment,
+        )
+        .await?
+    };
+
+    // If necessary, lower the requirements.
+    let extra_requires = if no_sources.all() {
+        extra_requires.into_iter().map(Requirement::from).collect()
+    } else {
+        let build_requires = uv_pypi_types::BuildRequires {
+            name: package_name.cloned(),
+            requires_dist: extra_requires,
+        };
+        let build_requires = BuildRequires::from_project_maybe_workspace(
+            build_requires,
+            install_path,
+            locations,
+            &no_sources,
+            build_context
+                .source_tree_editable_policy()
+                .workspace_member_editable(None),
+            stop_discovery_at,
+            build_context.cache(),
+            workspace_cache,
+            credentials_cache,
+        )
+        .await
+        .map_err(Error::Lowering)?;
+        build_requires.requires_dist
+    };
+
+    // Some packages (such as tqdm 4.66.1) list only extra requires that have already been part of
+    // the pyproject.toml requires (in this case, `wheel`). We can skip doing the whole resolution
+    // and installation again.
+    // TODO(konstin): Do we still need this when we have a fast resolver?
+    if extra_requires
+        .iter()
+        .any(|req| !pep517_backend.requirements.contains(req))
+    {
+        debug!("Resolving extra requirements for build backend");
+        let requirements: Vec<_> = pep517_backend
+            .requirements
+            .iter()
+            .cloned()
+            .chain(extra_requires)
+            .collect();
+
+        // If the build dependencies are locked, constrain the extra requirements to the locked
+        // versions too. Otherwise, record the extra requirements, such that they can be locked.
+        let resolution = if let Some((package_name, locked)) =
+            package_name.zip(locked_build_dependencies)
+        {
+            resolve_locked_requirements(
+                build_context,
+                &requirements,
+                package_name,
+                locked,
+                build_stack,
+            )
+            .await?
+        } else {
+            let resolution = build_context
+                .resolve(&requirements, &[], build_stack)
+                .await
+                .map_err(|err| {
+                    Error::RequirementsResolve("`build-system.requires`", AnyErrorBuild::from(err))
+                })?;
+            if let Some((build_dependency_lock, package_name)) =
+                build_context.build_dependency_lock().zip(package_name)
+            {
+                build_dependency_lock.record(
+                    package_name,
+                    resolution.resolution().hashes(),
+                    venv.interpreter().markers(),
+                );
+            }
+            resolution
+        };
+
+        return Ok(Some(resolution));
+    }
+
+    Ok(None)
+}
+
+/// Resolve the given build requirements, constrained to the locked build dependencies, and verify
+/// the locked hashes.
+async fn resolve_locked_requirements(
+    build_context: &impl BuildContext,
+    requirements: &[Requirement],
+    package_name: &PackageName,
+    locked: &[&LockedBuildDependency],
+    build_stack: &BuildStack,
+) -> Result<ResolvedRequirements, Error> {
+    let constraints = locked
+        .iter()
+        .map(|dependency| dependency.requirement.clone())
+        .collect::<Vec<_>>();
+    let resolved_requirements = build_context
+        .resolve(requirements, &constraints, build_stack)
+        .await
+        .map_err(|err| Error::LockedBuildRequirements(package_name.clone(), err.into()))?;
+    let hashes = locked
+        .iter()
+        .filter(|dependency| !dependency.hashes.is_empty())
+        .filter_map(|dependency| Some((dependency.version_id()?, dependency.hashes.clone())))
+        .collect::<FxHashMap<_, _>>();
+    Ok(ResolvedRequirements::new(
+        resolved_requirements.resolution().clone(),
+        HashStrategy::Verify(Arc::new(hashes)),
+    ))
+}
+
+/// Create a virtual environment in which to build a source distribution, with the given build
+/// requirements installed.
+async fn create_build_environment(
+    interpreter: &Interpreter,
+    build_context: &impl BuildContext,
+    resolved_requirements: &ResolvedRequirements,
+    build_stack: &BuildStack,
+    dependency_sources: &'static str,
+) -> Result<BuildEnvironment, Error> {
+    let temp_dir = build_context
+        .cache()
+        .build_dir_in(build_context.build_runtime().build_dir())?;
+    debug!(
+        "Creating build environment: {}",
+        temp_dir.path().user_display()
+    );
+    let venv = uv_virtualenv::create_venv(
+        temp_dir.path(),
+        interpreter.clone(),
+        uv_virtualenv::Prompt::None,
+        false,
+        uv_virtualenv::OnExisting::Remove(uv_virtualenv::RemovalReason::TemporaryEnvironment),
+        false,
+        false,
+        false,
+    )?;
+    build_context
+        .install(resolved_requirements, &venv, build_stack)
+        .await
+        .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+    Ok(BuildEnvironment::new(temp_dir, venv))
+}
+
+/// Prepend the scripts directory of the virtual environment to the given `PATH`.
+fn venv_path(venv: &PythonEnvironment, path: Option<&OsString>) -> Result<OsString, Error> {
+    if let Some(path) = path {
+        let venv_path = iter::once(venv.scripts().to_path_buf()).chain(env::split_paths(path));
+        env::join_paths(venv_path).map_err(Error::BuildScriptPath)
+    } else {
+        Ok(OsString::from(venv.scripts()))
+    }
+}
+
+/// Call `get_requires_for_build_*` in the build environment, returning the additional
+/// requirements of the build backend.
+///
+/// If a cache entry is provided, the requirements are persisted to it for reuse in subsequent
+/// builds.
+async fn run_get_requires(
+    runner: &PythonRunner,
+    source_tree: &Path,
+    venv: &PythonEnvironment,
+    pep517_backend: &Pep517Backend,
+    package_name: Option<&PackageName>,
+    package_version: Option<&Version>,
+    version_id: Option<&str>,
+    registry: bool,
+    build_kind: BuildKind,
+    level: BuildOutput,
+    config_settings: &ConfigSettings,
+    environment_variables: &FxHashMap<OsString, OsString>,
+    modified_path: &OsString,
+    temp_dir: &TempDir,
+    requires_cache: Option<&CacheEntry>,
+    environment: Option<&BuildEnvironment>,
+) -> Result<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>, Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
         .path()
@@ -1042,6 +2174,9 @@ async fn create_pep517_build_environment(
             import json
 
             get_requires_for_build = getattr(backend, "get_requires_for_build_{}", None)
+            if get_requires_for_build is None and not hasattr(backend, "build_{}"):
+                # Editable builds with backends that predate PEP 660 fall back to the wheel build.
+                get_requires_for_build = getattr(backend, "get_requires_for_build_wheel", None)
             if get_requires_for_build:
                 requires = get_requires_for_build({})
             else:
@@ -1052,16 +2187,19 @@ async fn create_pep517_build_environment(
         "#,
         pep517_backend.backend_import(),
         build_kind,
+        build_kind,
         config_settings.escape_for_python(),
         outfile.escape_for_python()
     };
-    let span = info_span!(
-        "run_python_script",
-        script = format!("get_requires_for_build_{}", build_kind),
-        version_id = version_id,
-    );
+    let hook = format!("get_requires_for_build_{build_kind}");
+    let span = info_span!("run_python_script", script = hook, version_id = version_id,);
+    let _environment_lock = match environment {
+        Some(environment) => Some(environment.lock().await),
+        None => None,
+    };
     let output = runner
         .run_script(
+            &hook,
             venv,
             &script,
             source_tree,
@@ -1070,7 +2208,7 @@ async fn create_pep517_build_environment(
         )
         .instrument(span)
         .await?;
-    if !output.status.success() {
+    if !output.success() {
         return Err(Error::from_command_output(
             format!(
                 "Call to `{}.build_{}` failed",
@@ -1081,16 +2219,32 @@ async fn create_pep517_build_environment(
             package_name,
             package_version,
             version_id,
+            registry,
+            Some(venv.root()),
         ));
     }
 
     // Read and deserialize the requirements from the output file.
     let read_requires_result = fs_err::read(&outfile)
         .map_err(|err| err.to_string())
-        .and_then(|contents| serde_json::from_slice(&contents).map_err(|err| err.to_string()));
+        .and_then(|contents| {
+            serde_json::from_slice(&contents)
+                .map(|extra_requires| (contents, extra_requires))
+                .map_err(|err| err.to_string())
+        });
     let extra_requires: Vec<uv_pep508::Requirement<VerbatimParsedUrl>> = match read_requires_result
     {
-        Ok(extra_requires) => extra_requires,
+        Ok((contents, extra_requires)) => {
+            // Cache the requirements for subsequent builds.
+            if let Some(entry) = requires_cache {
+                if let Err(err) = fs_err::create_dir_all(entry.dir())
+                    .and_then(|()| uv_fs::write_atomic_sync(entry.path(), contents))
+                {
+                    warn!("Failed to cache build requirements: {err}");
+                }
+            }
+            extra_requires
+        }
         Err(err) => {
             return Err(Error::from_command_output(
                 format!(
@@ -1102,75 +2256,81 @@ async fn create_pep517_build_environment(
                 package_name,
                 package_version,
                 version_id,
+                registry,
+                Some(venv.root()),
             ));
         }
     };
 
-    // If necessary, lower the requirements.
-    let extra_requires = if no_sources.all() {
-        extra_requires.into_iter().map(Requirement::from).collect()
-    } else {
-        let build_requires = uv_pypi_types::BuildRequires {
-            name: package_name.cloned(),
-            requires_dist: extra_requires,
-        };
-        let build_requires = BuildRequires::from_project_maybe_workspace(
-            build_requires,
-            install_path,
-            locations,
-            &no_sources,
-            build_context
-                .source_tree_editable_policy()
-                .workspace_member_editable(None),
-            stop_discovery_at,
-            build_context.cache(),
-            workspace_cache,
-            credentials_cache,
-        )
-        .await
-        .map_err(Error::Lowering)?;
-        build_requires.requires_dist
-    };
-
-    // Some packages (such as tqdm 4.66.1) list only extra requires that have already been part of
-    // the pyproject.toml requires (in this case, `wheel`). We can skip doing the whole resolution
-    // and installation again.
-    // TODO(konstin): Do we still need this when we have a fast resolver?
-    if extra_requires
-        .iter()
-        .any(|req| !pep517_backend.requirements.contains(req))
-    {
-        debug!("Installing extra requirements for build backend");
-        let requirements: Vec<_> = pep517_backend
-            .requirements
-            .iter()
-            .cloned()
-            .chain(extra_requires)
-            .collect();
-        let resolution = build_context
-            .resolve(&requirements, build_stack)
-            .await
-            .map_err(|err| {
-                Error::RequirementsResolve("`build-system.requires`", AnyErrorBuild::from(err))
-            })?;
+    Ok(extra_requires)
+}
 
-        build_context
-            .install(&resolution, venv, build_stack)
-            .await
-            .map_err(|err| {
-                Error::RequirementsInstall("`build-system.requires`", AnyErrorBuild::from(err))
-            })?;
+/// Read the requirements returned by `get_requires_for_build_*` in a previous build, if they're
+/// cached and don't need to be refreshed.
+fn read_cached_requires(
+    entry: &CacheEntry,
+    cache: &Cache,
+    package_name: Option<&PackageName>,
+    source_tree: &Path,
+) -> Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>> {
+    match cache.freshness(entry, package_name, Some(source_tree)) {
+        Ok(Freshness::Fresh) => {}
+        Ok(Freshness::Stale | Freshness::Missing) => return None,
+        Err(err) => {
+            debug!("Failed to read cached build requirements: {err}");
+            return None;
+        }
     }
-
-    Ok(())
+    let contents = match fs_err::read(entry.path()) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            debug!("Failed to read cached build requirements: {err}");
+            return None;
+        }
+    };
+    serde_json::from_slice(&contents)
+        .inspect_err(|err| debug!("Failed to parse cached build requirements: {err}"))
+        .ok()
 }
 
+/// The environment variables that are visible to the build backend even if the build environment
+/// is restricted to an allowlist, as processes may fail to function without them.
+const BASELINE_ENVIRONMENT_VARIABLES: &[&str] = &[
+    EnvVars::HOME,
+    EnvVars::USERPROFILE,
+    EnvVars::TMPDIR,
+    EnvVars::TEMP,
+    EnvVars::TMP,
+    EnvVars::SYSTEMROOT,
+    EnvVars::SYSTEMDRIVE,
+    EnvVars::COMSPEC,
+    EnvVars::PATHEXT,
+    EnvVars::LANG,
+    EnvVars::LC_ALL,
+];
+
 /// A runner that manages the execution of external python processes with a
 /// concurrency limit.
 #[derive(Debug)]
 struct PythonRunner {
     concurrent_build_slots: Arc<Semaphore>,
+    /// The maximum duration of a single hook invocation, if any.
+    timeout: Option<Duration>,
     level: BuildOutput,
+    /// The name with which to prefix each line of streamed output, e.g., `numpy`.
+    prefix: Option<String>,
+    /// The package being built, e.g., `numpy==2.0.0`, to which build warnings are attributed.
+    package: Option<String>,
+    /// The warnings emitted by the build backend in successful hook invocations.
+    warnings: std::sync::Mutex<Vec<String>>,
+    /// The collector to which the warnings are reported once the build succeeds, if any.
+    build_warnings: Option<Arc<BuildWarningCollector>>,
+    /// The persistent log to which the build backend output is written.
+    log: Option<BuildLog>,
+    /// Whether the build backend inherits uv's environment. If `false`, only the variables
+    /// required for processes to function, and those set explicitly, are visible.
+    inherit_environment: bool,
 }
 
 #[derive(Debug)]
@@ -1178,14 +2338,53 @@ struct PythonRunnerOutput {
     stdout: Vec<String>,
     stderr: Vec<String>,
     status: ExitStatus,
+    /// The path to the persistent log of the build backend output, if any.
+    log: Option<PathBuf>,
+    /// The time after which the hook was terminated, if it exceeded the build timeout.
+    timed_out: Option<Duration>,
+}
+
+impl PythonRunnerOutput {
+    /// Returns `true` if the hook completed successfully within the build timeout.
+    fn success(&self) -> bool {
+        self.timed_out.is_none() && self.status.success()
+    }
 }
 
 impl PythonRunner {
     /// Create a `PythonRunner` with the provided shared concurrency semaphore and output level.
-    fn new(concurrent_build_slots: Arc<Semaphore>, level: BuildOutput) -> Self {
+    fn new(
+        concurrent_build_slots: Arc<Semaphore>,
+        timeout: Option<Duration>,
+        level: BuildOutput,
+        prefix: Option<String>,
+        package: Option<String>,
+        build_warnings: Option<Arc<BuildWarningCollector>>,
+        log: Option<BuildLog>,
+        inherit_environment: bool,
+    ) -> Self {
         Self {
             concurrent_build_slots,
+            timeout,
             level,
+            prefix,
+            package,
+            warnings: std::sync::Mutex::new(Vec::new()),
+            build_warnings,
+            log,
+            inherit_environment,
+        }
+    }
+
+    /// Mark the build as successful, such that its log isn't retained, and report any warnings
+    /// emitted by the build backend.
+    fn succeed(&self) {
+        if let Some(log) = &self.log {
+            log.succeed();
+        }
+        let warnings = std::mem::take(&mut *self.warnings.lock().unwrap());
+        if let (Some(build_warnings), Some(package)) = (&self.build_warnings, &self.package) {
+            build_warnings.record(package, warnings);
         }
     }
 
@@ -1197,24 +2396,29 @@ impl PythonRunner {
     /// Note: It is the caller's responsibility to create an informative span.
     async fn run_script(
         &self,
+        hook: &str,
         venv: &PythonEnvironment,
         script: &str,
         source_tree: &Path,
         environment_variables: &FxHashMap<OsString, OsString>,
         modified_path: &OsString,
     ) -> Result<PythonRunnerOutput, Error> {
-        /// Read lines from a reader and store them in a buffer.
+        /// Read lines from a reader and store them in a buffer (and the log, if any).
         async fn read_from(
             mut reader: tokio::io::Split<tokio::io::BufReader<impl tokio::io::AsyncRead + Unpin>>,
-            mut printer: Printer,
+            mut printer: Printer<'_>,
+            log: Option<(&BuildLog, Stream)>,
             buffer: &mut Vec<String>,
         ) -> io::Result<()> {
             loop {
                 match reader.next_segment().await? {
                     Some(line_buf) => {
                         let line_buf = line_buf.strip_suffix(b"\r").unwrap_or(&line_buf);
-                        let line = String::from_utf8_lossy(line_buf).into();
+                        let line: String = String::from_utf8_lossy(line_buf).into();
                         let _ = write!(printer, "{line}");
+                        if let Some((log, stream)) = log {
+                            log.write_line(stream, &line);
+                        }
                         buffer.push(line);
                     }
                     None => return Ok(()),
@@ -1224,7 +2428,19 @@ impl PythonRunner {
 
         let _permit = self.concurrent_build_slots.acquire().await.unwrap();
 
-        let mut child = Command::new(venv.python_executable())
+        if let Some(log) = &self.log {
+            log.start(hook);
+        }
+
+        let mut command = Command::new(venv.python_executable());
+        if !self.inherit_environment {
+            command.env_clear().envs(
+                BASELINE_ENVIRONMENT_VARIABLES
+                    .iter()
+                    .filter_map(|key| env::var_os(key).map(|value| (key, value))),
+            );
+        }
+        command
             .args(["-c", script])
             .current_dir(source_tree.simplified())
             .envs(environment_variables)
@@ -1242,9 +2458,29 @@ impl PythonRunner {
             .env_remove(EnvVars::PYX_AUTH_TOKEN)
             .env_remove(EnvVars::UV_AUTH_TOKEN)
             .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+
+        // If the hook is subject to a timeout, ensure that the build backend can be terminated
+        // along with any processes it spawns.
+        let timeout = self.timeout;
+        if timeout.is_some() {
+            ProcessTree::configure(&mut command);
+        }
+
+        let start = Instant::now();
+        let mut child = command
             .spawn()
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+        let tree = match timeout.map(|_| ProcessTree::new(&child)).transpose() {
+            Ok(tree) => tree,
+            Err(err) => {
+                let _ = child.start_kill();
+                return Err(Error::CommandFailed(
+                    venv.python_executable().to_path_buf(),
+                    err,
+                ));
+            }
+        };
 
         // Create buffers to capture `stdout` and `stderr`.
         let mut stdout_buf = Vec::with_capacity(1024);
@@ -1254,62 +2490,119 @@ impl PythonRunner {
         let stdout_reader = tokio::io::BufReader::new(child.stdout.take().unwrap()).split(b'\n');
         let stderr_reader = tokio::io::BufReader::new(child.stderr.take().unwrap()).split(b'\n');
 
-        // Asynchronously read from the in-memory pipes.
-        let printer = Printer::from(self.level);
-        let result = tokio::join!(
-            read_from(stdout_reader, printer, &mut stdout_buf),
-            read_from(stderr_reader, printer, &mut stderr_buf),
-        );
-        match result {
-            (Ok(()), Ok(())) => {}
-            (Err(err), _) | (_, Err(err)) => {
-                return Err(Error::CommandFailed(
-                    venv.python_executable().to_path_buf(),
-                    err,
-                ));
+        // Asynchronously read from the in-memory pipes, then wait for the child process to finish.
+        let printer = Printer::new(self.level, self.prefix.as_deref());
+        let run = async {
+            let result = tokio::join!(
+                read_from(
+                    stdout_reader,
+                    printer,
+                    self.log.as_ref().map(|log| (log, Stream::Stdout)),
+                    &mut stdout_buf,
+                ),
+                read_from(
+                    stderr_reader,
+                    printer,
+                    self.log.as_ref().map(|log| (log, Stream::Stderr)),
+                    &mut stderr_buf,
+                ),
+            );
+            match result {
+                (Ok(()), Ok(())) => {}
+                (Err(err), _) | (_, Err(err)) => return Err(err),
             }
-        }
+            child.wait().await
+        };
 
-        // Wait for the child process to finish.
-        let status = child
-            .wait()
-            .await
+        let result = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, run).await.ok(),
+            None => Some(run.await),
+        };
+
+        let (status, timed_out) = if let Some(status) = result {
+            // The hook exited, so any remaining processes are no longer terminated on drop.
+            if let Some(tree) = tree {
+                tree.release();
+            }
+            (status, None)
+        } else {
+            // The hook exceeded the timeout, so terminate the entire process tree, and collect the
+            // exit status of the build backend.
+            let elapsed = start.elapsed();
+            debug!(
+                "Terminating `{hook}` after exceeding the build timeout ({}s)",
+                elapsed.as_secs()
+            );
+            let _ = child.start_kill();
+            if let Some(tree) = tree {
+                tree.kill();
+            }
+            (child.wait().await, Some(elapsed))
+        };
+        let status = status
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
 
-        Ok(PythonRunnerOutput {
+        let output = PythonRunnerOutput {
             stdout: stdout_buf,
             stderr: stderr_buf,
             status,
-        })
+            log: self.log.as_ref().map(|log| log.path().to_path_buf()),
+            timed_out,
+        };
+
+        // Collect the warnings emitted by the build backend, which are otherwise discarded if the
+        // build succeeds.
+        if output.success()
+            && let Some(build_warnings) = &self.build_warnings
+        {
+            let warnings = build_warnings.scan(output.stdout.iter().chain(&output.stderr));
+            self.warnings.lock().unwrap().extend(warnings);
+        }
+
+        Ok(output)
     }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum Printer {
+pub enum Printer<'a> {
     /// Send the build backend output to `stderr`.
     Stderr,
     /// Send the build backend output to `tracing`.
     Debug,
+    /// Send the build backend output to `stderr`, prefixing each line with the given name.
+    Stream(Option<&'a str>),
     /// Hide the build backend output.
     Quiet,
 }
 
-impl From<BuildOutput> for Printer {
-    fn from(output: BuildOutput) -> Self {
+impl<'a> Printer<'a> {
+    /// Create a [`Printer`] for the given output level, with the name to prefix streamed lines.
+    pub fn new(output: BuildOutput, prefix: Option<&'a str>) -> Self {
         match output {
             BuildOutput::Stderr => Self::Stderr,
             BuildOutput::Debug => Self::Debug,
+            BuildOutput::Stream => Self::Stream(prefix),
             BuildOutput::Quiet => Self::Quiet,
         }
     }
 }
 
-impl Write for Printer {
+impl Write for Printer<'_> {
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
         match self {
             Self::Stderr => {
                 anstream::eprintln!("{s}");
             }
+            Self::Stream(prefix) => {
+                // Format the entire line up-front, such that output from concurrent builds is
+                // interleaved line-by-line.
+                let line = if let Some(prefix) = prefix {
+                    format!("{} {s}", format!("[{prefix}]").dimmed())
+                } else {
+                    s.to_string()
+                };
+                anstream::eprintln!("{line}");
+            }
             Self::Debug => {
                 debug!("{s}");
             }
@@ -1318,3 +2611,351 @@ impl Write for Printer {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "test-python"))]
+mod tests {
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use fs_err as fs;
+    use indoc::indoc;
+    use rustc_hash::FxHashMap;
+    use tokio::sync::Semaphore;
+
+    use uv_auth::CredentialsCache;
+    use uv_cache::{Cache, CacheShard};
+    use uv_configuration::{
+        BuildKind, BuildOptions, BuildOutput, BuildRuntime, Concurrency, NoSources,
+    };
+    use uv_distribution_filename::DistFilename;
+    use uv_distribution_types::{
+        CachedDist, ConfigSettings, DependencyMetadata, ExtraBuildRequires, ExtraBuildVariables,
+        IndexCapabilities, IndexLocations, PackageConfigSettings, Requirement, SourceDist,
+    };
+    use uv_git::GitResolver;
+    use uv_python::{
+        EnvironmentPreference, Interpreter, PythonEnvironment, PythonPreference, PythonRequest,
+    };
+    use uv_types::{
+        BuildArena, BuildContext, BuildIsolation, BuildStack, IsBuildBackendError,
+        ResolvedRequirements,
+    };
+    use uv_workspace::WorkspaceCache;
+
+    use crate::{Error, SourceBuild, SourceBuildContext};
+
+    /// A [`BuildContext`] that builds in an existing environment, without resolving or installing
+    /// any build requirements.
+    struct SharedContext {
+        cache: Cache,
+        environment: PythonEnvironment,
+        git: GitResolver,
+        build_arena: BuildArena<SourceBuild>,
+        capabilities: IndexCapabilities,
+        dependency_metadata: DependencyMetadata,
+        build_options: BuildOptions,
+        build_runtime: BuildRuntime,
+        concurrency: Concurrency,
+        config_settings: ConfigSettings,
+        config_settings_package: PackageConfigSettings,
+        sources: NoSources,
+        locations: IndexLocations,
+        workspace_cache: WorkspaceCache,
+        extra_build_requires: ExtraBuildRequires,
+        extra_build_variables: ExtraBuildVariables,
+    }
+
+    impl SharedContext {
+        fn new() -> Self {
+            let cache = Cache::temp().unwrap();
+            let environment = PythonEnvironment::find(
+                &PythonRequest::Any,
+                EnvironmentPreference::Any,
+                PythonPreference::System,
+                &cache,
+            )
+            .unwrap();
+            Self {
+                cache,
+                environment,
+                git: GitResolver::default(),
+                build_arena: BuildArena::default(),
+                capabilities: IndexCapabilities::default(),
+                dependency_metadata: DependencyMetadata::default(),
+                build_options: BuildOptions::default(),
+                build_runtime: BuildRuntime::default(),
+                concurrency: Concurrency::default(),
+                config_settings: ConfigSettings::default(),
+                config_settings_package: PackageConfigSettings::default(),
+                sources: NoSources::default(),
+                locations: IndexLocations::default(),
+                workspace_cache: WorkspaceCache::default(),
+                extra_build_requires: ExtraBuildRequires::default(),
+                extra_build_variables: ExtraBuildVariables::default(),
+            }
+        }
+
+        /// Set up a build of the source tree in the shared environment.
+        async fn setup(&self, source_tree: &Path) -> Result<SourceBuild, Error> {
+            SourceBuild::setup(
+                source_tree,
+                None,
+                source_tree,
+                None,
+                None,
+                None,
+                self.environment.interpreter(),
+                self,
+                SourceBuildContext::new(Arc::new(Semaphore::new(1))),
+                None,
+                false,
+                None,
+                &self.locations,
+                NoSources::default(),
+                &self.workspace_cache,
+                ConfigSettings::default(),
+                self.build_isolation(),
+                &self.extra_build_requires,
+                &BuildStack::default(),
+                BuildKind::Wheel,
+                FxHashMap::default(),
+                true,
+                BuildOutput::Debug,
+                &CredentialsCache::new(),
+            )
+            .await
+        }
+    }
+
+    impl BuildContext for SharedContext {
+        type SourceDistBuilder = SourceBuild;
+
+        async fn interpreter(&self) -> &Interpreter {
+            self.environment.interpreter()
+        }
+
+        fn cache(&self) -> &Cache {
+            &self.cache
+        }
+
+        fn git(&self) -> &GitResolver {
+            &self.git
+        }
+
+        fn build_arena(&self) -> &BuildArena<SourceBuild> {
+            &self.build_arena
+        }
+
+        fn capabilities(&self) -> &IndexCapabilities {
+            &self.capabilities
+        }
+
+        fn dependency_metadata(&self) -> &DependencyMetadata {
+            &self.dependency_metadata
+        }
+
+        fn build_options(&self) -> &BuildOptions {
+            &self.build_options
+        }
+
+        fn build_runtime(&self) -> &BuildRuntime {
+            &self.build_runtime
+        }
+
+        fn build_isolation(&self) -> BuildIsolation<'_> {
+            BuildIsolation::Shared(&self.environment)
+        }
+
+        fn concurrency(&self) -> &Concurrency {
+            &self.concurrency
+        }
+
+        fn config_settings(&self) -> &ConfigSettings {
+            &self.config_settings
+        }
+
+        fn config_settings_package(&self) -> &PackageConfigSettings {
+            &self.config_settings_package
+        }
+
+        fn sources(&self) -> &NoSources {
+            &self.sources
+        }
+
+        fn locations(&self) -> &IndexLocations {
+            &self.locations
+        }
+
+        fn workspace_cache(&self) -> &WorkspaceCache {
+            &self.workspace_cache
+        }
+
+        fn extra_build_requires(&self) -> &ExtraBuildRequires {
+            &self.extra_build_requires
+        }
+
+        fn extra_build_variables(&self) -> &ExtraBuildVariables {
+            &self.extra_build_variables
+        }
+
+        fn resolve<'a>(
+            &'a self,
+            _requirements: &'a [Requirement],
+            _constraints: &'a [Requirement],
+            _build_stack: &'a BuildStack,
+        ) -> impl Future<Output = Result<ResolvedRequirements, impl IsBuildBackendError>> + 'a
+        {
+            without_build_isolation("resolved")
+        }
+
+        fn install<'a>(
+            &'a self,
+            _requirements: &'a ResolvedRequirements,
+            _venv: &'a PythonEnvironment,
+            _build_stack: &'a BuildStack,
+        ) -> impl Future<Output = Result<Vec<CachedDist>, impl IsBuildBackendError>> + 'a {
+            without_build_isolation("installed")
+        }
+
+        fn setup_build<'a>(
+            &'a self,
+            source: &'a Path,
+            _subdirectory: Option<&'a Path>,
+            _install_path: &'a Path,
+            _stop_discovery_at: Option<&'a Path>,
+            _version_id: Option<&'a str>,
+            _dist: Option<&'a SourceDist>,
+            _cache_shard: Option<&'a CacheShard>,
+            _sources: &'a NoSources,
+            _build_kind: BuildKind,
+            _build_output: BuildOutput,
+            _build_stack: BuildStack,
+        ) -> impl Future<Output = Result<SourceBuild, impl IsBuildBackendError>> + 'a {
+            self.setup(source)
+        }
+
+        fn direct_build<'a>(
+            &'a self,
+            _source: &'a Path,
+            _subdirectory: Option<&'a Path>,
+            _output_dir: &'a Path,
+            _sources: NoSources,
+            _build_kind: BuildKind,
+            _version_id: Option<&'a str>,
+        ) -> impl Future<Output = Result<Option<DistFilename>, impl IsBuildBackendError>> + 'a
+        {
+            async { Ok::<_, Error>(None) }
+        }
+    }
+
+    /// Build requirements are neither resolved nor installed without build isolation.
+    async fn without_build_isolation<T>(operation: &'static str) -> Result<T, Error> {
+        unreachable!("build requirements aren't {operation} without build isolation")
+    }
+
+    /// Write a project with an in-tree build backend that implements each PEP 517 hook.
+    fn fixture(root: &Path) {
+        fs::write(
+            root.join("pyproject.toml"),
+            indoc! {r#"
+                [project]
+                name = "fixture"
+                version = "0.1.0"
+
+                [build-system]
+                requires = []
+                build-backend = "backend"
+                backend-path = ["."]
+            "#},
+        )
+        .unwrap();
+        fs::write(
+            root.join("backend.py"),
+            indoc! {r#"
+                import io
+                import os
+                import tarfile
+                import zipfile
+
+                METADATA = "Metadata-Version: 2.1\nName: fixture\nVersion: 0.1.0\n"
+
+
+                def prepare_metadata_for_build_wheel(metadata_directory, config_settings=None):
+                    dist_info = os.path.join(metadata_directory, "fixture-0.1.0.dist-info")
+                    os.mkdir(dist_info)
+                    with open(os.path.join(dist_info, "METADATA"), "w") as fp:
+                        fp.write(METADATA)
+                    return "fixture-0.1.0.dist-info"
+
+
+                def build_sdist(sdist_directory, config_settings=None):
+                    filename = "fixture-0.1.0.tar.gz"
+                    with tarfile.open(os.path.join(sdist_directory, filename), "w:gz") as sdist:
+                        info = tarfile.TarInfo("fixture-0.1.0/PKG-INFO")
+                        info.size = len(METADATA)
+                        sdist.addfile(info, io.BytesIO(METADATA.encode()))
+                    return filename
+
+
+                def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                    # The metadata from `prepare_metadata_for_build_wheel` is passed back in.
+                    assert metadata_directory.endswith("fixture-0.1.0.dist-info")
+                    filename = "fixture-0.1.0-py3-none-any.whl"
+                    with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                        wheel.writestr("fixture-0.1.0.dist-info/METADATA", METADATA)
+                    return filename
+            "#},
+        )
+        .unwrap();
+    }
+
+    /// Each PEP 517 hook can be called independently, in a single prepared environment.
+    #[tokio::test]
+    async fn hooks() {
+        let context = SharedContext::new();
+        let source_tree = tempfile::tempdir().unwrap();
+        fixture(source_tree.path());
+        let output_dir = tempfile::tempdir().unwrap();
+
+        let mut builder = context.setup(source_tree.path()).await.unwrap();
+
+        let dist_info = builder.metadata().await.unwrap().unwrap();
+        assert_eq!(dist_info.file_name().unwrap(), "fixture-0.1.0.dist-info");
+        assert!(
+            fs::read_to_string(dist_info.join("METADATA"))
+                .unwrap()
+                .contains("Name: fixture")
+        );
+
+        let sdist = builder.build_sdist(output_dir.path()).await.unwrap();
+        assert_eq!(sdist.file_name().unwrap(), "fixture-0.1.0.tar.gz");
+        assert!(sdist.starts_with(output_dir.path()) && sdist.is_file());
+
+        let wheel = builder.build_wheel(output_dir.path()).await.unwrap();
+        assert_eq!(wheel.file_name().unwrap(), "fixture-0.1.0-py3-none-any.whl");
+        assert!(wheel.starts_with(output_dir.path()) && wheel.is_file());
+    }
+
+    /// A failing hook returns the build backend error.
+    #[tokio::test]
+    async fn hook_failure() {
+        let context = SharedContext::new();
+        let source_tree = tempfile::tempdir().unwrap();
+        fixture(source_tree.path());
+        fs::write(
+            source_tree.path().join("backend.py"),
+            indoc! {r#"
+                def build_sdist(sdist_directory, config_settings=None):
+                    raise RuntimeError("sdists are not supported")
+            "#},
+        )
+        .unwrap();
+
+        let builder = context.setup(source_tree.path()).await.unwrap();
+        let err = builder
+            .build_sdist(tempfile::tempdir().unwrap().path())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::BuildBackend(_)), "{err:?}");
+    }
+}
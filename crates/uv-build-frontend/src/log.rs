@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+/// The stream to which the build backend wrote a line of output.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl Display for Stream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdout => f.write_str("stdout"),
+            Self::Stderr => f.write_str("stderr"),
+        }
+    }
+}
+
+/// The maximum number of build logs that are retained for a single package.
+const MAX_LOGS_PER_PACKAGE: usize = 5;
+
+/// A persistent log of the build backend output for a single build.
+///
+/// Every hook invocation is appended to the log, with both streams interleaved in the order in
+/// which they were read. When dropped, the log is removed if the last hook invocation succeeded,
+/// unless it was created with `retain` (e.g., with `--keep-build-logs`).
+#[derive(Debug)]
+pub(crate) struct BuildLog {
+    path: PathBuf,
+    file: Mutex<fs_err::File>,
+    retain: bool,
+    succeeded: AtomicBool,
+}
+
+impl BuildLog {
+    /// Create an empty build log at the given path, replacing any existing log.
+    ///
+    /// Returns `None` if the log can't be created, in which case the build proceeds without one.
+    pub(crate) fn create(path: PathBuf, retain: bool) -> Option<Self> {
+        let file = path
+            .parent()
+            .map(fs_err::create_dir_all)
+            .transpose()
+            .and_then(|_| fs_err::File::create(&path));
+        match file {
+            Ok(file) => Some(Self {
+                path,
+                file: Mutex::new(file),
+                retain,
+                succeeded: AtomicBool::new(false),
+            }),
+            Err(err) => {
+                debug!(
+                    "Failed to create build log at `{}`: {err}",
+                    path.user_display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Remove all but the most recent build logs in the given package directory, to make room for
+    /// a new log.
+    ///
+    /// Without a bound, every failed build of a new version (or of a modified source tree) would
+    /// leave a log behind until the cache is cleaned.
+    pub(crate) fn prune(directory: &Path) {
+        let Ok(entries) = fs_err::read_dir(directory) else {
+            return;
+        };
+        let mut logs = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                Some((modified, entry.path()))
+            })
+            .collect::<Vec<_>>();
+        if logs.len() < MAX_LOGS_PER_PACKAGE {
+            return;
+        }
+        logs.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+        for (_, path) in logs.into_iter().skip(MAX_LOGS_PER_PACKAGE - 1) {
+            debug!("Removing stale build log: `{}`", path.user_display());
+            if let Err(err) = fs_err::remove_dir_all(&path) {
+                debug!("Failed to remove stale build log: {err}");
+            }
+        }
+    }
+
+    /// Return the path to the log file.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record the start of a build backend hook invocation.
+    pub(crate) fn start(&self, hook: &str) {
+        self.succeeded.store(false, Ordering::Relaxed);
+        let mut file = self.file.lock().unwrap();
+        let _ = writeln!(file, "# {hook}");
+    }
+
+    /// Append a line of build backend output to the log.
+    pub(crate) fn write_line(&self, stream: Stream, line: &str) {
+        let mut file = self.file.lock().unwrap();
+        let _ = writeln!(file, "[{stream}] {line}");
+    }
+
+    /// Mark the build as successful, such that the log is removed when dropped (unless retained).
+    pub(crate) fn succeed(&self) {
+        self.succeeded.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for BuildLog {
+    fn drop(&mut self) {
+        if self.retain || !self.succeeded.load(Ordering::Relaxed) {
+            return;
+        }
+        if let Some(parent) = self.path.parent() {
+            if let Err(err) = fs_err::remove_dir_all(parent) {
+                debug!("Failed to remove build log: {err}");
+            }
+        }
+    }
+}
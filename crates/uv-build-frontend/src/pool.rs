@@ -0,0 +1,119 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+use tempfile::TempDir;
+use tokio::sync::{Mutex as AsyncMutex, OnceCell, OwnedMutexGuard};
+use tracing::debug;
+
+use uv_cache_key::cache_digest;
+use uv_fs::Simplified;
+use uv_python::{Interpreter, PythonEnvironment};
+use uv_types::ResolvedRequirements;
+
+use crate::Error;
+
+/// A virtual environment with a set of build requirements installed, which may be shared across
+/// builds.
+///
+/// The environment is removed once it's dropped from the pool and from every build that uses it.
+#[derive(Debug)]
+pub(crate) struct BuildEnvironment {
+    /// The directory containing the virtual environment, removed when the environment is dropped.
+    _temp_dir: TempDir,
+    /// The virtual environment in which to run the build backend.
+    venv: PythonEnvironment,
+    /// A lock held while a build backend hook runs in the environment.
+    lock: Arc<AsyncMutex<()>>,
+}
+
+impl BuildEnvironment {
+    /// Create a [`BuildEnvironment`] from a virtual environment in the given directory.
+    pub(crate) fn new(temp_dir: TempDir, venv: PythonEnvironment) -> Self {
+        Self {
+            _temp_dir: temp_dir,
+            venv,
+            lock: Arc::new(AsyncMutex::new(())),
+        }
+    }
+
+    /// Return the virtual environment in which to run the build backend.
+    pub(crate) fn venv(&self) -> &PythonEnvironment {
+        &self.venv
+    }
+
+    /// Acquire the lock on the environment, to run a build backend hook in it.
+    ///
+    /// Build backends may write to their environment (e.g., bytecode for the backend itself), so
+    /// builds that share an environment run their hooks one at a time.
+    pub(crate) async fn lock(&self) -> OwnedMutexGuard<()> {
+        self.lock.clone().lock_owned().await
+    }
+}
+
+/// A pool of build environments, keyed by the interpreter and the resolved build requirements.
+///
+/// Builds with identical build requirements share a single environment, which must not be
+/// modified once created; each build uses its own working directory for any outputs. Builds that
+/// require additional packages (e.g., from `get_requires_for_build_wheel`) use the environment
+/// for the extended set of requirements instead.
+///
+/// Environments are retained for the lifetime of the pool, i.e., until the end of the invocation,
+/// such that later builds can reuse them.
+#[derive(Debug, Default)]
+pub(crate) struct BuildEnvironmentPool {
+    environments: Mutex<FxHashMap<String, Arc<OnceCell<Arc<BuildEnvironment>>>>>,
+}
+
+impl BuildEnvironmentPool {
+    /// Return the environment for the given interpreter and build requirements, creating it if no
+    /// such environment exists in the pool, along with whether an existing environment was reused.
+    ///
+    /// Concurrent requests for the same environment wait on a single creation. If creation fails,
+    /// the next request retries.
+    pub(crate) async fn get_or_create<F>(
+        &self,
+        interpreter: &Interpreter,
+        requirements: &ResolvedRequirements,
+        create: impl FnOnce() -> F,
+    ) -> Result<(Arc<BuildEnvironment>, bool), Error>
+    where
+        F: Future<Output = Result<BuildEnvironment, Error>>,
+    {
+        let key = Self::key(interpreter, requirements);
+        let cell = self
+            .environments
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_default()
+            .clone();
+
+        let mut created = false;
+        let environment = cell
+            .get_or_try_init(async || {
+                created = true;
+                create().await.map(Arc::new)
+            })
+            .await?;
+        if !created {
+            debug!(
+                "Reusing build environment: {}",
+                environment.venv().root().user_display()
+            );
+        }
+        Ok((environment.clone(), !created))
+    }
+
+    /// Compute the key for an environment with the given interpreter and build requirements.
+    fn key(interpreter: &Interpreter, requirements: &ResolvedRequirements) -> String {
+        let distributions = requirements
+            .resolution()
+            .distributions()
+            .map(ToString::to_string)
+            .sorted()
+            .collect::<Vec<_>>();
+        cache_digest(&(interpreter.sys_executable(), distributions))
+    }
+}
@@ -0,0 +1,159 @@
+use tokio::process::{Child, Command};
+use tracing::debug;
+
+/// The process tree of a build backend hook, which can be terminated as a whole (e.g., when the
+/// build exceeds its timeout), including any processes spawned by the build backend.
+///
+/// On Unix, the hook is spawned in its own process group. On Windows, it's spawned suspended and
+/// assigned to a job object that terminates all of its processes when closed, before it's resumed,
+/// such that any process it spawns is assigned to the job too.
+///
+/// As processes in a separate process group don't receive the terminal's interrupt, the tree is
+/// terminated when dropped (e.g., if the build is cancelled before the hook exits), unless it was
+/// [released](Self::release) once the hook exited.
+pub(crate) struct ProcessTree {
+    #[cfg(unix)]
+    pgid: Option<nix::unistd::Pid>,
+    #[cfg(windows)]
+    job: Option<uv_windows::Job>,
+}
+
+impl ProcessTree {
+    /// Configure the [`Command`] such that its process tree can be terminated once spawned.
+    pub(crate) fn configure(command: &mut Command) {
+        #[cfg(unix)]
+        {
+            command.process_group(0);
+        }
+        #[cfg(windows)]
+        {
+            command.creation_flags(windows::Win32::System::Threading::CREATE_SUSPENDED.0);
+        }
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = command;
+        }
+    }
+
+    /// Track the process tree of a [`Child`] spawned from a [configured](Self::configure)
+    /// [`Command`].
+    ///
+    /// On Windows, the suspended child is resumed once it's been assigned to the job object. If it
+    /// can't be resumed, an error is returned and the child should be killed.
+    pub(crate) fn new(child: &Child) -> std::io::Result<Self> {
+        #[cfg(unix)]
+        {
+            Ok(Self {
+                pgid: child
+                    .id()
+                    .and_then(|pid| i32::try_from(pid).ok())
+                    .map(nix::unistd::Pid::from_raw),
+            })
+        }
+        #[cfg(windows)]
+        {
+            let job = child.raw_handle().and_then(|handle| {
+                let job = uv_windows::Job::new()
+                    .inspect_err(|err| debug!("Failed to create job object: {err}"))
+                    .ok()?;
+                // SAFETY: The handle is valid for as long as the child hasn't been awaited.
+                #[allow(unsafe_code)]
+                unsafe { job.assign_process(windows::Win32::Foundation::HANDLE(handle)) }
+                    .inspect_err(|err| debug!("Failed to assign build backend to job: {err}"))
+                    .ok()?;
+                Some(job)
+            });
+            // Resume the child even if it couldn't be assigned to the job, in which case only the
+            // build backend itself is terminated on timeout.
+            if let Some(pid) = child.id() {
+                resume_threads(pid)?;
+            }
+            Ok(Self { job })
+        }
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = child;
+            Ok(Self {})
+        }
+    }
+
+    /// Terminate every process in the tree.
+    pub(crate) fn kill(mut self) {
+        self.terminate();
+    }
+
+    /// Release the process tree once the hook has exited, such that any processes that outlive
+    /// the hook aren't terminated on Unix.
+    ///
+    /// On Windows, closing the job object terminates any remaining processes regardless.
+    pub(crate) fn release(self) {
+        #[cfg(unix)]
+        {
+            let mut tree = self;
+            tree.pgid = None;
+        }
+    }
+
+    fn terminate(&mut self) {
+        #[cfg(unix)]
+        if let Some(pgid) = self.pgid.take() {
+            if let Err(err) = nix::sys::signal::killpg(pgid, nix::sys::signal::Signal::SIGKILL) {
+                debug!("Failed to kill build backend process group {pgid}: {err}");
+            }
+        }
+        // Closing the job object terminates all of its processes.
+        #[cfg(windows)]
+        drop(self.job.take());
+    }
+}
+
+impl Drop for ProcessTree {
+    fn drop(&mut self) {
+        self.terminate();
+    }
+}
+
+/// Resume every thread of a process that was spawned with `CREATE_SUSPENDED`.
+///
+/// The standard library doesn't expose the handle of the primary thread, so the process' threads
+/// are enumerated instead.
+#[cfg(windows)]
+#[allow(unsafe_code)]
+fn resume_threads(pid: u32) -> std::io::Result<()> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, TH32CS_SNAPTHREAD, THREADENTRY32, Thread32First, Thread32Next,
+    };
+    use windows::Win32::System::Threading::{OpenThread, ResumeThread, THREAD_SUSPEND_RESUME};
+
+    // SAFETY: The snapshot handle is closed before returning.
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) }?;
+    let mut entry = THREADENTRY32 {
+        dwSize: u32::try_from(size_of::<THREADENTRY32>()).expect("size fits in `u32`"),
+        ..THREADENTRY32::default()
+    };
+    let result = (|| {
+        // SAFETY: `entry` is a valid, initialized `THREADENTRY32`.
+        let mut next = unsafe { Thread32First(snapshot, &raw mut entry) };
+        while next.is_ok() {
+            if entry.th32OwnerProcessID == pid {
+                // SAFETY: The thread handle is closed after resuming the thread.
+                let thread =
+                    unsafe { OpenThread(THREAD_SUSPEND_RESUME, false, entry.th32ThreadID) }?;
+                // SAFETY: The thread handle was opened with `THREAD_SUSPEND_RESUME` access.
+                let count = unsafe { ResumeThread(thread) };
+                // SAFETY: The thread handle is valid and not used afterwards.
+                let _ = unsafe { CloseHandle(thread) };
+                if count == u32::MAX {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            // SAFETY: `entry` is a valid, initialized `THREADENTRY32`.
+            next = unsafe { Thread32Next(snapshot, &raw mut entry) };
+        }
+        Ok(())
+    })();
+    // SAFETY: The snapshot handle is valid and not used afterwards.
+    let _ = unsafe { CloseHandle(snapshot) };
+    result
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+
+/// The patterns that identify a line of build backend output as a warning, by default.
+pub const DEFAULT_BUILD_WARNING_PATTERNS: &[&str] = &[
+    "DeprecationWarning",
+    "SetuptoolsDeprecationWarning",
+    "warning:",
+];
+
+/// Collects the warnings emitted by build backends during successful builds, by package.
+#[derive(Debug)]
+pub(crate) struct BuildWarningCollector {
+    /// The (lowercase) patterns that identify a line of build backend output as a warning.
+    patterns: Vec<String>,
+    /// The warnings emitted so far, by package.
+    warnings: Mutex<BTreeMap<String, Vec<String>>>,
+}
+
+impl Default for BuildWarningCollector {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl BuildWarningCollector {
+    /// Create a [`BuildWarningCollector`] that identifies warnings by the given patterns (e.g.,
+    /// `--build-warning-pattern`).
+    ///
+    /// If no patterns are provided, the [`DEFAULT_BUILD_WARNING_PATTERNS`] are used.
+    pub(crate) fn new(patterns: &[String]) -> Self {
+        let patterns = if patterns.is_empty() {
+            DEFAULT_BUILD_WARNING_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_lowercase())
+                .collect()
+        } else {
+            patterns
+                .iter()
+                .map(|pattern| pattern.to_lowercase())
+                .collect()
+        };
+        Self {
+            patterns,
+            warnings: Mutex::default(),
+        }
+    }
+
+    /// Return the lines of build backend output that match any of the patterns.
+    pub(crate) fn scan<'a>(&self, lines: impl IntoIterator<Item = &'a String>) -> Vec<String> {
+        matching_lines(lines, &self.patterns)
+    }
+
+    /// Record the warnings emitted while building the given package.
+    pub(crate) fn record(&self, package: &str, warnings: Vec<String>) {
+        if warnings.is_empty() {
+            return;
+        }
+        let mut build_warnings = self.warnings.lock().unwrap();
+        let entry = build_warnings.entry(package.to_string()).or_default();
+        for warning in warnings {
+            if !entry.contains(&warning) {
+                entry.push(warning);
+            }
+        }
+    }
+
+    /// Take the warnings recorded so far, sorted by package.
+    pub(crate) fn take(&self) -> Vec<BuildWarnings> {
+        std::mem::take(&mut *self.warnings.lock().unwrap())
+            .into_iter()
+            .map(|(package, warnings)| BuildWarnings { package, warnings })
+            .collect()
+    }
+}
+
+/// Return the lines that contain any of the (lowercase) patterns, ignoring case.
+fn matching_lines<'a>(
+    lines: impl IntoIterator<Item = &'a String>,
+    patterns: &[String],
+) -> Vec<String> {
+    lines
+        .into_iter()
+        .map(|line| line.trim())
+        .filter(|line| {
+            let line = line.to_lowercase();
+            patterns
+                .iter()
+                .any(|pattern| line.contains(pattern.as_str()))
+        })
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// The warnings emitted by the build backend while building a single package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildWarnings {
+    /// The package that was built, e.g., `numpy==2.0.0`.
+    pub package: String,
+    /// The lines of build backend output identified as warnings, in order and without duplicates.
+    pub warnings: Vec<String>,
+}
+
+impl Display for BuildWarnings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The build backend emitted {} while building `{}`:",
+            if self.warnings.len() == 1 {
+                "a warning".to_string()
+            } else {
+                format!("{} warnings", self.warnings.len())
+            },
+            self.package
+        )?;
+        for warning in &self.warnings {
+            write!(f, "\n  {warning}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use super::BuildWarningCollector;
+
+    #[test]
+    fn default_patterns() {
+        let output = [
+            "running bdist_wheel",
+            "/tmp/setuptools/config/_apply_pyprojecttoml.py:82: SetuptoolsDeprecationWarning: `project.license` as a TOML table is deprecated",
+            "!!",
+            "  warnings.warn(",
+            "The Meson build system",
+            "meson.build:3: WARNING: Project does not specify a license",
+            "src/module.c:12:5: warning: unused variable 'x' [-Wunused-variable]",
+            "/tmp/backend.py:4: DeprecationWarning: `setup.cfg` field `description-file` is deprecated",
+            "Successfully built example-0.1.0-py3-none-any.whl",
+        ]
+        .map(ToString::to_string);
+        let collector = BuildWarningCollector::default();
+        collector.record("example==0.1.0", collector.scan(&output));
+
+        let [summary] = collector.take().try_into().unwrap();
+        assert_snapshot!(summary, @"
+        The build backend emitted 4 warnings while building `example==0.1.0`:
+          /tmp/setuptools/config/_apply_pyprojecttoml.py:82: SetuptoolsDeprecationWarning: `project.license` as a TOML table is deprecated
+          meson.build:3: WARNING: Project does not specify a license
+          src/module.c:12:5: warning: unused variable 'x' [-Wunused-variable]
+          /tmp/backend.py:4: DeprecationWarning: `setup.cfg` field `description-file` is deprecated
+        ");
+    }
+
+    #[test]
+    fn custom_patterns() {
+        let output = [
+            "running bdist_wheel",
+            "Missing license file, see https://example.com",
+            "src/module.c:12:5: warning: unused variable 'x' [-Wunused-variable]",
+        ]
+        .map(ToString::to_string);
+
+        let collector = BuildWarningCollector::new(&["Missing License".to_string()]);
+        collector.record("example", collector.scan(&output));
+
+        let [summary] = collector.take().try_into().unwrap();
+        assert_snapshot!(summary, @"
+        The build backend emitted a warning while building `example`:
+          Missing license file, see https://example.com
+        ");
+    }
+}
@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::fmt::Write;
 use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 use tracing::{debug, info_span, warn};
 
+use uv_cache_key::cache_digest;
 use uv_fs::Simplified;
 
 use crate::git_info::{Commit, Tags};
@@ -39,6 +41,22 @@ pub struct CacheInfo {
     /// The timestamp or inode of any directories that should be considered in the cache key.
     #[serde(default)]
     directories: BTreeMap<Cow<'static, str>, Option<DirectoryTimestamp>>,
+    /// The entry points declared statically in the `pyproject.toml`, rendered in
+    /// `entry_points.txt` format.
+    ///
+    /// Unlike the other fields, the entry points are tracked regardless of the `cache-keys`.
+    #[serde(default)]
+    entry_points: Option<String>,
+    /// A digest of the `pyproject.toml`, excluding its entry points, if the `pyproject.toml` is
+    /// one of the cache keys.
+    #[serde(default)]
+    pyproject: Option<String>,
+    /// The most recent `ctime` of any relevant files other than the `pyproject.toml`.
+    ///
+    /// Along with the `pyproject` digest, this is used to determine whether a change was limited to
+    /// the entry points.
+    #[serde(default)]
+    timestamp_without_pyproject: Option<Timestamp>,
 }
 
 impl CacheInfo {
@@ -65,24 +83,29 @@ impl CacheInfo {
         let mut commit = None;
         let mut tags = None;
         let mut last_changed: Option<(PathBuf, Timestamp)> = None;
+        let mut last_changed_without_pyproject: Option<Timestamp> = None;
+        let mut pyproject = None;
         let mut directories = BTreeMap::new();
         let mut env = BTreeMap::new();
 
-        // Read the cache keys.
+        // Read the cache keys and entry points.
         let pyproject_path = directory.join("pyproject.toml");
-        let cache_keys = if let Ok(contents) = fs_err::read_to_string(&pyproject_path) {
+        let contents = fs_err::read_to_string(&pyproject_path).ok();
+        let (cache_keys, entry_points) = if let Some(contents) = contents.as_deref() {
             let result = info_span!("toml::from_str cache keys", path = %pyproject_path.display())
-                .in_scope(|| toml::from_str::<PyProjectToml>(&contents));
+                .in_scope(|| toml::from_str::<PyProjectToml>(contents));
             if let Ok(pyproject_toml) = result {
-                pyproject_toml
+                let entry_points = pyproject_toml.project.as_ref().and_then(entry_points);
+                let cache_keys = pyproject_toml
                     .tool
                     .and_then(|tool| tool.uv)
-                    .and_then(|tool_uv| tool_uv.cache_keys)
+                    .and_then(|tool_uv| tool_uv.cache_keys);
+                (cache_keys, entry_points)
             } else {
-                None
+                (None, None)
             }
         } else {
-            None
+            (None, None)
         };
 
         // If no cache keys were defined, use the defaults.
@@ -132,6 +155,11 @@ impl CacheInfo {
                         continue;
                     }
                     let timestamp = Timestamp::from_metadata(&metadata);
+                    if path == pyproject_path {
+                        pyproject = contents.as_deref().and_then(pyproject_digest);
+                    } else if last_changed_without_pyproject.is_none_or(|prev| prev < timestamp) {
+                        last_changed_without_pyproject = Some(timestamp);
+                    }
                     if last_changed.as_ref().is_none_or(|(_, prev_timestamp)| {
                         *prev_timestamp < Timestamp::from_metadata(&metadata)
                     }) {
@@ -223,6 +251,7 @@ impl CacheInfo {
 
         // If we have any globs, first cluster them using LCP and then do a single pass on each group.
         if !globs.is_empty() {
+            let mut environments = BTreeMap::new();
             for (glob_base, glob_patterns) in cluster_globs(&globs) {
                 let walker = globwalk::GlobWalkerBuilder::from_patterns(
                     directory.join(glob_base),
@@ -238,6 +267,9 @@ impl CacheInfo {
                             continue;
                         }
                     };
+                    if is_tool_managed(directory, entry.path(), &globs, &mut environments) {
+                        continue;
+                    }
                     let metadata = if entry.path_is_symlink() {
                         // resolve symlinks for leaf entries without following symlinks while globbing
                         match fs_err::metadata(entry.path()) {
@@ -267,6 +299,11 @@ impl CacheInfo {
                         continue;
                     }
                     let timestamp = Timestamp::from_metadata(&metadata);
+                    if entry.path() == pyproject_path {
+                        pyproject = contents.as_deref().and_then(pyproject_digest);
+                    } else if last_changed_without_pyproject.is_none_or(|prev| prev < timestamp) {
+                        last_changed_without_pyproject = Some(timestamp);
+                    }
                     if last_changed.as_ref().is_none_or(|(_, prev_timestamp)| {
                         *prev_timestamp < Timestamp::from_metadata(&metadata)
                     }) {
@@ -292,6 +329,9 @@ impl CacheInfo {
             tags,
             env,
             directories,
+            entry_points,
+            pyproject,
+            timestamp_without_pyproject: last_changed_without_pyproject,
         })
     }
 
@@ -313,16 +353,198 @@ impl CacheInfo {
             && self.tags.is_none()
             && self.env.is_empty()
             && self.directories.is_empty()
+            && self.entry_points.is_none()
+            && self.pyproject.is_none()
+            && self.timestamp_without_pyproject.is_none()
+    }
+
+    /// Return the entry points declared statically in the `pyproject.toml`, in `entry_points.txt`
+    /// format.
+    pub fn entry_points(&self) -> Option<&str> {
+        self.entry_points.as_deref()
+    }
+
+    /// Returns `true` if the cache info differs from `other` in its entry points, and nothing else.
+    ///
+    /// If the `pyproject.toml` is one of the cache keys (as it is by default), editing the entry
+    /// points changes its timestamp; in that case, the `pyproject.toml` contents (excluding the
+    /// entry points) and the timestamps of the remaining files are compared instead.
+    pub fn is_entry_points_change(&self, other: &Self) -> bool {
+        let Self {
+            timestamp,
+            commit,
+            tags,
+            env,
+            directories,
+            entry_points,
+            pyproject,
+            timestamp_without_pyproject,
+        } = self;
+        let unchanged_files = *timestamp == other.timestamp
+            || (pyproject.is_some()
+                && *pyproject == other.pyproject
+                && *timestamp_without_pyproject == other.timestamp_without_pyproject);
+        *entry_points != other.entry_points
+            && unchanged_files
+            && *commit == other.commit
+            && *tags == other.tags
+            && *env == other.env
+            && *directories == other.directories
     }
 }
 
-/// A `pyproject.toml` with an (optional) `[tool.uv]` section.
+/// Returns `true` if the given path, within the project `directory`, is managed by tooling rather
+/// than authored by the user, such that changes to it shouldn't invalidate the cache.
+///
+/// Editors and language servers routinely write to these paths (e.g., by compiling bytecode or
+/// indexing a virtual environment), so matching them in a broad glob (like `**/*.py`) would lead to
+/// spurious rebuilds.
+///
+/// Paths that are named explicitly by one of the `patterns` (e.g., `.idea/**/*.xml` or
+/// `**/*.pyc`) are never considered tool-managed, since the user opted in to tracking them.
+fn is_tool_managed(
+    directory: &Path,
+    path: &Path,
+    patterns: &[impl AsRef<str>],
+    environments: &mut BTreeMap<PathBuf, bool>,
+) -> bool {
+    /// Directories that are managed by tooling.
+    const DIRECTORIES: &[&str] = &[
+        "__pycache__",
+        ".git",
+        ".hg",
+        ".idea",
+        ".vscode",
+        ".mypy_cache",
+        ".pytest_cache",
+        ".ruff_cache",
+    ];
+
+    // Returns `true` if any pattern names the given path component literally.
+    let is_requested = |name: &std::ffi::OsStr| {
+        patterns
+            .iter()
+            .any(|pattern| pattern.as_ref().split('/').any(|part| name == part))
+    };
+
+    let bytecode = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .filter(|extension| *extension == "pyc" || *extension == "pyo");
+    if let Some(extension) = bytecode {
+        let requested = patterns
+            .iter()
+            .any(|pattern| pattern.as_ref().ends_with(&format!(".{extension}")));
+        if !requested {
+            return true;
+        }
+    }
+
+    let Ok(relative) = path.strip_prefix(directory) else {
+        return false;
+    };
+    let mut ancestor = directory.to_path_buf();
+    let mut components = relative.components().peekable();
+    while let Some(component) = components.next() {
+        // Skip the file itself.
+        if components.peek().is_none() {
+            break;
+        }
+        ancestor.push(component);
+        if is_requested(component.as_os_str()) {
+            continue;
+        }
+        if DIRECTORIES
+            .iter()
+            .any(|name| component.as_os_str() == *name)
+        {
+            return true;
+        }
+
+        // Skip any virtual environments, as identified by their `pyvenv.cfg`.
+        let is_environment = *environments
+            .entry(ancestor.clone())
+            .or_insert_with(|| ancestor.join("pyvenv.cfg").is_file());
+        if is_environment {
+            return true;
+        }
+    }
+    false
+}
+
+/// A `pyproject.toml` with an (optional) `[project]` and `[tool.uv]` section.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct PyProjectToml {
+    project: Option<toml::Table>,
     tool: Option<Tool>,
 }
 
+/// Compute a digest of the `pyproject.toml` contents, excluding the entry points.
+fn pyproject_digest(contents: &str) -> Option<String> {
+    let mut pyproject_toml = toml::from_str::<toml::Table>(contents).ok()?;
+    if let Some(project) = pyproject_toml
+        .get_mut("project")
+        .and_then(toml::Value::as_table_mut)
+    {
+        project.remove("scripts");
+        project.remove("gui-scripts");
+        project.remove("entry-points");
+    }
+    Some(cache_digest(&toml::to_string(&pyproject_toml).ok()?))
+}
+
+/// Render the entry points of a `[project]` table in `entry_points.txt` format.
+///
+/// Returns `None` if the project doesn't declare any entry points, if they're declared as dynamic
+/// (and so can only be determined by the build backend), or if they're malformed.
+fn entry_points(project: &toml::Table) -> Option<String> {
+    let scripts = project.get("scripts");
+    let gui_scripts = project.get("gui-scripts");
+    let entry_points = project.get("entry-points");
+    if scripts.is_none() && gui_scripts.is_none() && entry_points.is_none() {
+        return None;
+    }
+
+    if project
+        .get("dynamic")
+        .and_then(toml::Value::as_array)
+        .is_some_and(|dynamic| {
+            dynamic.iter().any(|field| {
+                matches!(
+                    field.as_str(),
+                    Some("scripts" | "gui-scripts" | "entry-points")
+                )
+            })
+        })
+    {
+        return None;
+    }
+
+    let mut groups = Vec::new();
+    if let Some(scripts) = scripts {
+        groups.push(("console_scripts", scripts.as_table()?));
+    }
+    if let Some(gui_scripts) = gui_scripts {
+        groups.push(("gui_scripts", gui_scripts.as_table()?));
+    }
+    if let Some(entry_points) = entry_points {
+        for (group, entries) in entry_points.as_table()? {
+            groups.push((group.as_str(), entries.as_table()?));
+        }
+    }
+
+    let mut writer = String::new();
+    for (group, entries) in groups {
+        let _ = writeln!(writer, "[{group}]");
+        for (name, object_reference) in entries {
+            let _ = writeln!(writer, "{name} = {}", object_reference.as_str()?);
+        }
+        writer.push('\n');
+    }
+    Some(writer)
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct Tool {
@@ -375,6 +597,195 @@ enum DirectoryTimestamp {
     Inode(u64),
 }
 
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::CacheInfo;
+
+    #[test]
+    fn test_cache_info_entry_points() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let pyproject_toml = dir.path().join("pyproject.toml");
+
+        // Entry points are tracked even if the `pyproject.toml` isn't a cache key.
+        fs_err::write(
+            &pyproject_toml,
+            r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+
+            [project.scripts]
+            foo = "foo:main"
+
+            [project.entry-points."foo.plugins"]
+            bar = "foo.bar:plugin"
+
+            [tool.uv]
+            cache-keys = [{ file = "src/**/*.py" }]
+            "#,
+        )?;
+        let before = CacheInfo::from_directory(dir.path())?;
+        assert_eq!(
+            before.entry_points(),
+            Some("[console_scripts]\nfoo = foo:main\n\n[foo.plugins]\nbar = foo.bar:plugin\n\n")
+        );
+
+        fs_err::write(
+            &pyproject_toml,
+            r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+
+            [project.gui-scripts]
+            foo-gui = "foo:gui"
+
+            [tool.uv]
+            cache-keys = [{ file = "src/**/*.py" }]
+            "#,
+        )?;
+        let after = CacheInfo::from_directory(dir.path())?;
+        assert_eq!(
+            after.entry_points(),
+            Some("[gui_scripts]\nfoo-gui = foo:gui\n\n")
+        );
+        assert!(after.is_entry_points_change(&before));
+
+        // Dynamic entry points can only be determined by the build backend.
+        fs_err::write(
+            &pyproject_toml,
+            r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+            dynamic = ["scripts"]
+            "#,
+        )?;
+        assert_eq!(CacheInfo::from_directory(dir.path())?.entry_points(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cache_info_entry_points_default_cache_keys() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let pyproject_toml = dir.path().join("pyproject.toml");
+
+        // With the default cache keys, the `pyproject.toml` timestamp changes along with the entry
+        // points, but the change is still limited to the entry points.
+        fs_err::write(
+            &pyproject_toml,
+            r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+
+            [project.scripts]
+            foo = "foo:main"
+            "#,
+        )?;
+        let before = CacheInfo::from_directory(dir.path())?;
+
+        fs_err::write(
+            &pyproject_toml,
+            r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+
+            [project.scripts]
+            bar = "foo:main"
+            "#,
+        )?;
+        let after = CacheInfo::from_directory(dir.path())?;
+        assert!(after.is_entry_points_change(&before));
+
+        // Any other change to the `pyproject.toml` requires a rebuild.
+        fs_err::write(
+            &pyproject_toml,
+            r#"
+            [project]
+            name = "foo"
+            version = "0.1.0"
+            dependencies = ["anyio"]
+
+            [project.scripts]
+            foo = "foo:main"
+            "#,
+        )?;
+        let after = CacheInfo::from_directory(dir.path())?;
+        assert!(!after.is_entry_points_change(&before));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cache_info_ignores_tool_managed_paths() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs_err::write(
+            dir.path().join("pyproject.toml"),
+            r#"
+            [tool.uv]
+            cache-keys = [{ file = "**/*.py*" }]
+            "#,
+        )?;
+        fs_err::create_dir_all(dir.path().join("src/foo"))?;
+        fs_err::write(dir.path().join("src/foo/__init__.py"), "")?;
+        let before = CacheInfo::from_directory(dir.path())?;
+
+        // Changes to bytecode, editor metadata, and virtual environments are ignored.
+        fs_err::create_dir_all(dir.path().join("src/foo/__pycache__"))?;
+        fs_err::write(
+            dir.path()
+                .join("src/foo/__pycache__/__init__.cpython-312.pyc"),
+            "",
+        )?;
+        fs_err::create_dir_all(dir.path().join(".vscode"))?;
+        fs_err::write(dir.path().join(".vscode/settings.py"), "")?;
+        fs_err::create_dir_all(dir.path().join("env/lib/site-packages"))?;
+        fs_err::write(dir.path().join("env/pyvenv.cfg"), "")?;
+        fs_err::write(dir.path().join("env/lib/site-packages/bar.py"), "")?;
+        assert_eq!(CacheInfo::from_directory(dir.path())?, before);
+
+        // Changes to the project's sources are not.
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs_err::write(dir.path().join("src/foo/bar.py"), "")?;
+        assert_ne!(CacheInfo::from_directory(dir.path())?, before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cache_info_honors_explicit_tool_managed_paths() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        fs_err::write(
+            dir.path().join("pyproject.toml"),
+            r#"
+            [tool.uv]
+            cache-keys = [{ file = ".idea/**/*.xml" }, { file = "src/**/*.pyc" }]
+            "#,
+        )?;
+        fs_err::create_dir_all(dir.path().join(".idea"))?;
+        fs_err::write(dir.path().join(".idea/workspace.xml"), "")?;
+        let before = CacheInfo::from_directory(dir.path())?;
+
+        // Paths named by a pattern are tracked, even if they're usually managed by tooling.
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs_err::create_dir_all(dir.path().join("src/foo"))?;
+        fs_err::write(dir.path().join("src/foo/bar.pyc"), "")?;
+        let after = CacheInfo::from_directory(dir.path())?;
+        assert_ne!(after, before);
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        fs_err::write(dir.path().join(".idea/workspace.xml"), "<project/>")?;
+        assert_ne!(CacheInfo::from_directory(dir.path())?, after);
+
+        Ok(())
+    }
+}
+
 #[cfg(all(test, unix))]
 mod tests_unix {
     use anyhow::Result;
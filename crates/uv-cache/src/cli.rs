@@ -1,5 +1,6 @@
 use std::io;
 use std::path::{Path, PathBuf};
+use uv_dirs::DirectorySource;
 use uv_static::EnvVars;
 
 use crate::Cache;
@@ -41,17 +42,42 @@ impl Cache {
     ///
     /// Returns an absolute cache dir.
     pub fn from_settings(no_cache: bool, cache_dir: Option<PathBuf>) -> Result<Self, io::Error> {
+        Ok(Self::from_settings_with_source(no_cache, cache_dir)?.0)
+    }
+
+    /// Like [`Cache::from_settings`], but also returns the [`DirectorySource`] from which the cache
+    /// directory was resolved.
+    ///
+    /// A cache directory provided by the caller is attributed to `--no-cache` or `--cache-dir`;
+    /// callers that know the directory came from elsewhere (e.g., a configuration file) should
+    /// substitute their own source.
+    pub fn from_settings_with_source(
+        no_cache: bool,
+        cache_dir: Option<PathBuf>,
+    ) -> Result<(Self, DirectorySource), io::Error> {
         if no_cache {
-            Self::temp()
+            Ok((Self::temp()?, DirectorySource::Argument("--no-cache")))
         } else if let Some(cache_dir) = cache_dir {
-            Ok(Self::from_path(cache_dir))
+            Ok((
+                Self::from_path(cache_dir),
+                DirectorySource::Argument("--cache-dir"),
+            ))
         } else if let Some(cache_dir) = uv_dirs::legacy_user_cache_dir().filter(|dir| dir.exists())
         {
             // If the user has an existing directory at (e.g.) `/Users/user/Library/Caches/uv`,
             // respect it for backwards compatibility. Otherwise, prefer the XDG strategy, even on
             // macOS.
-            Ok(Self::from_path(cache_dir))
-        } else if let Some(cache_dir) = uv_dirs::user_cache_dir() {
+            //
+            // On Linux, the legacy and XDG locations are the same, and on Windows, the legacy
+            // location is the default.
+            let source = match uv_dirs::user_cache_dir_with_source() {
+                Some((user_cache_dir, source)) if cfg!(windows) || user_cache_dir == cache_dir => {
+                    source
+                }
+                _ => DirectorySource::Legacy,
+            };
+            Ok((Self::from_path(cache_dir), source))
+        } else if let Some((cache_dir, directory_source)) = uv_dirs::user_cache_dir_with_source() {
             if cfg!(windows) {
                 // On Windows, we append `cache` to the LocalAppData directory, i.e., prefer
                 // `C:\Users\User\AppData\Local\uv\cache` over `C:\Users\User\AppData\Local\uv`.
@@ -68,12 +94,12 @@ impl Cache {
                     );
                 }
 
-                Ok(Self::from_path(destination))
+                Ok((Self::from_path(destination), directory_source))
             } else {
-                Ok(Self::from_path(cache_dir))
+                Ok((Self::from_path(cache_dir), directory_source))
             }
         } else {
-            Ok(Self::from_path(".uv_cache"))
+            Ok((Self::from_path(".uv_cache"), DirectorySource::Fallback))
         }
     }
 }
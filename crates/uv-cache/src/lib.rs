@@ -1,3 +1,4 @@
+use std::ffi::OsStr;
 use std::fmt::{Display, Formatter};
 use std::io;
 use std::io::Write;
@@ -6,7 +7,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
 
 use uv_cache_info::Timestamp;
@@ -18,7 +19,7 @@ pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
 use crate::removal::Remover;
-pub use crate::removal::{Removal, rm_rf};
+pub use crate::removal::{Removal, removal_size, rm_rf};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
@@ -102,6 +103,19 @@ impl CacheEntry {
         )
         .await?)
     }
+
+    /// Acquire the [`CacheEntry`] as an exclusive lock, if it isn't held by another process.
+    ///
+    /// Returns [`None`] if the lock is busy, in which case the caller should wait on
+    /// [`CacheEntry::lock`].
+    pub fn try_lock(&self) -> Result<Option<LockedFile>, Error> {
+        fs_err::create_dir_all(self.dir())?;
+        Ok(LockedFile::acquire_no_wait(
+            self.path(),
+            LockedFileMode::Exclusive,
+            self.path().display(),
+        ))
+    }
 }
 
 impl AsRef<Path> for CacheEntry {
@@ -137,6 +151,19 @@ impl CacheShard {
         .await?)
     }
 
+    /// Acquire the cache entry as an exclusive lock, if it isn't held by another process.
+    ///
+    /// Returns [`None`] if the lock is busy, in which case the caller should wait on
+    /// [`CacheShard::lock`].
+    pub fn try_lock(&self) -> Result<Option<LockedFile>, Error> {
+        fs_err::create_dir_all(self.as_ref())?;
+        Ok(LockedFile::acquire_no_wait(
+            self.join(".lock"),
+            LockedFileMode::Exclusive,
+            self.display(),
+        ))
+    }
+
     /// Return the [`CacheShard`] as a [`PathBuf`].
     pub fn into_path_buf(self) -> PathBuf {
         self.0
@@ -309,6 +336,39 @@ impl Cache {
         tempfile::tempdir_in(self.bucket(CacheBucket::Builds))
     }
 
+    /// Create a temporary directory to be used for executing PEP 517 source distribution builds
+    /// within the given build directory (e.g., `--build-dir`), if any, rather than the cache.
+    ///
+    /// The build directory is created if it doesn't exist.
+    pub fn build_dir_in(&self, build_dir: Option<&Path>) -> io::Result<tempfile::TempDir> {
+        let Some(build_dir) = build_dir else {
+            return self.build_dir();
+        };
+        if build_dir.exists() && !build_dir.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotADirectory,
+                format!(
+                    "Build directory `{}` exists, but is not a directory",
+                    build_dir.user_display()
+                ),
+            ));
+        }
+        fs_err::create_dir_all(build_dir)?;
+        tempfile::tempdir_in(build_dir).map_err(|err| {
+            if err.kind() == io::ErrorKind::PermissionDenied {
+                io::Error::new(
+                    err.kind(),
+                    format!(
+                        "Build directory `{}` is not writable",
+                        build_dir.user_display()
+                    ),
+                )
+            } else {
+                err
+            }
+        })
+    }
+
     /// Returns `true` if a cache entry must be revalidated given the [`Refresh`] policy.
     pub fn must_revalidate_package(&self, package: &PackageName) -> bool {
         match &self.refresh {
@@ -549,38 +609,244 @@ impl Cache {
         Ok(removal)
     }
 
-    /// Remove a package from the cache.
+    /// Determine the entries that [`Cache::clear`] would remove.
     ///
-    /// Returns the number of entries removed from the cache.
-    pub fn remove(&self, name: &PackageName) -> io::Result<Removal> {
-        // Collect the set of referenced archives.
-        let references = self.find_archive_references()?;
+    /// Archives in `preserve` (as returned by [`Cache::find_environment_references`]) are
+    /// retained, along with the entries in the wheel bucket that point to them (such that the
+    /// archives continue to be used for subsequent installs). In that case, the archive and wheel
+    /// buckets are listed entry-by-entry rather than as a whole.
+    pub fn plan_clear(&self, preserve: &FxHashSet<PathBuf>) -> io::Result<CleanPlan> {
+        let mut plan = CleanPlan::default();
+        let archive_bucket = self.bucket(CacheBucket::Archive);
+        let wheel_bucket = self.bucket(CacheBucket::Wheels);
+        for entry in fs_err::read_dir(&self.root)? {
+            let path = entry?.path();
+            if path.file_name().is_some_and(|name| name == ".lock") {
+                continue;
+            }
+            if path == archive_bucket && !preserve.is_empty() {
+                for entry in fs_err::read_dir(&path)? {
+                    let path = entry?.path();
+                    match fs_err::canonicalize(&path) {
+                        Ok(target) if preserve.contains(&target) => plan.preserved.push(path),
+                        Ok(_) => plan.entries.push(path),
+                        Err(err) => {
+                            // An unresolvable entry can't be referenced by an environment.
+                            warn!("Failed to resolve cache entry at {}: {err}", path.display());
+                            plan.entries.push(path);
+                        }
+                    }
+                }
+            } else if path == wheel_bucket && !preserve.is_empty() {
+                self.plan_wheel_entry(path, preserve, &mut plan)?;
+            } else {
+                plan.entries.push(path);
+            }
+        }
+        plan.entries.sort();
+        plan.preserved.sort();
+        Ok(plan)
+    }
 
-        // Remove any entries for the package from the cache.
-        let mut summary = Removal::default();
+    /// Determine the entries that [`Cache::remove`] would remove for a package, including any
+    /// archives that would no longer be referenced.
+    ///
+    /// Archives in `preserve` (as returned by [`Cache::find_environment_references`]) are
+    /// retained.
+    pub fn plan_remove(
+        &self,
+        name: &PackageName,
+        preserve: &FxHashSet<PathBuf>,
+    ) -> io::Result<CleanPlan> {
+        let mut plan = CleanPlan::default();
         for bucket in CacheBucket::iter() {
-            summary += bucket.remove(self, name)?;
+            let targets = bucket.targets(self, name)?;
+            if bucket == CacheBucket::Wheels && !preserve.is_empty() {
+                for target in targets {
+                    self.plan_wheel_entry(target, preserve, &mut plan)?;
+                }
+            } else {
+                plan.entries.extend(targets);
+            }
         }
 
+        // Collect the set of referenced archives.
+        let references = self.find_archive_references()?;
         if references.is_empty() {
-            return Ok(summary);
+            return Ok(plan);
         }
 
         // Only remove targets in the archive bucket. Cache entries may contain unexpected links
         // to paths outside the cache.
         let archive_root = fs_err::canonicalize(&self.root)?.join(CacheBucket::Archive.to_str());
 
-        // Remove any archives that are no longer referenced.
-        for (target, references) in references {
-            if target.starts_with(&archive_root) && references.iter().all(|path| !path.exists()) {
-                debug!("Removing dangling cache entry: {}", target.display());
-                summary += rm_rf(target)?;
+        // Remove any archives that would no longer be referenced.
+        let mut dangling = references
+            .into_iter()
+            .filter(|(target, references)| {
+                target.starts_with(&archive_root)
+                    && references.iter().all(|path| {
+                        !path.exists() || plan.entries.iter().any(|entry| path.starts_with(entry))
+                    })
+            })
+            .map(|(target, _)| target)
+            .collect::<Vec<_>>();
+        dangling.sort();
+        for target in dangling {
+            if preserve.contains(&target) {
+                plan.preserved.push(target);
+            } else {
+                plan.entries.push(target);
             }
         }
 
+        Ok(plan)
+    }
+
+    /// Remove a package from the cache.
+    ///
+    /// Returns the number of entries removed from the cache.
+    pub fn remove(&self, name: &PackageName) -> io::Result<Removal> {
+        let plan = self.plan_remove(name, &FxHashSet::default())?;
+        let archive_root = fs_err::canonicalize(&self.root)?.join(CacheBucket::Archive.to_str());
+
+        let mut summary = Removal::default();
+        for path in plan.entries {
+            if path.starts_with(&archive_root) {
+                debug!("Removing dangling cache entry: {}", path.display());
+            }
+            summary += rm_rf(path)?;
+        }
         Ok(summary)
     }
 
+    /// Plan the removal of an entry in the wheel bucket, retaining any links and pointers to the
+    /// archives in `preserve`.
+    ///
+    /// Directories that contain a retained entry are listed entry-by-entry rather than as a whole.
+    fn plan_wheel_entry(
+        &self,
+        path: PathBuf,
+        preserve: &FxHashSet<PathBuf>,
+        plan: &mut CleanPlan,
+    ) -> io::Result<()> {
+        if fs_err::symlink_metadata(&path)?.is_dir() {
+            let retained = walkdir::WalkDir::new(&path)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|entry| !entry.file_type().is_dir())
+                .any(|entry| self.is_preserved_reference(entry.path(), preserve));
+            if retained {
+                for entry in fs_err::read_dir(&path)? {
+                    self.plan_wheel_entry(entry?.path(), preserve, plan)?;
+                }
+            } else {
+                plan.entries.push(path);
+            }
+        } else if self.is_preserved_reference(&path, preserve) {
+            plan.preserved.push(path);
+        } else {
+            plan.entries.push(path);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the file at the given path in the wheel bucket refers to one of the
+    /// archives in `preserve`, either as a link or as an archive pointer (i.e., a `.http` or `.rev`
+    /// file, which records the archive's ID).
+    fn is_preserved_reference(&self, path: &Path, preserve: &FxHashSet<PathBuf>) -> bool {
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("http") || ext.eq_ignore_ascii_case("rev"))
+        {
+            let Ok(contents) = fs_err::read(path) else {
+                return false;
+            };
+            preserve
+                .iter()
+                .filter_map(|archive| archive.file_name())
+                .map(OsStr::as_encoded_bytes)
+                .any(|id| contents.windows(id.len()).any(|window| window == id))
+        } else {
+            self.resolve_link(path)
+                .is_ok_and(|target| preserve.contains(&target))
+        }
+    }
+
+    /// Find the entries in the archive bucket that are referenced by the given environments.
+    ///
+    /// An archive is referenced if an environment contains a symbolic link into it, or (on Unix)
+    /// a hard link to one of its files, as created by the `symlink` and `hardlink` link modes.
+    /// Environments populated with the `copy` or `clone` link modes don't reference the cache.
+    ///
+    /// Returns the canonicalized paths of the referenced archives. Unreadable files are ignored.
+    pub fn find_environment_references(
+        &self,
+        environments: &[PathBuf],
+    ) -> io::Result<FxHashSet<PathBuf>> {
+        let mut references = FxHashSet::default();
+        let archive_root = match fs_err::canonicalize(self.bucket(CacheBucket::Archive)) {
+            Ok(archive_root) => archive_root,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(references),
+            Err(err) => return Err(err),
+        };
+
+        // Collect any symbolic links into the archive bucket, along with any hard-linked files.
+        let mut hard_links = FxHashSet::default();
+        for environment in environments {
+            for entry in walkdir::WalkDir::new(environment)
+                .into_iter()
+                .filter_map(Result::ok)
+            {
+                if entry.file_type().is_symlink() {
+                    let Ok(target) = fs_err::canonicalize(entry.path()) else {
+                        continue;
+                    };
+                    if let Some(id) = target
+                        .strip_prefix(&archive_root)
+                        .ok()
+                        .and_then(|relative| relative.components().next())
+                    {
+                        references.insert(archive_root.join(id));
+                    }
+                } else if entry.file_type().is_file() {
+                    if let Some(id) = entry.metadata().ok().as_ref().and_then(hard_link_id) {
+                        hard_links.insert(id);
+                    }
+                }
+            }
+        }
+
+        if hard_links.is_empty() {
+            return Ok(references);
+        }
+
+        // Find the archives that share a file with any environment.
+        for entry in fs_err::read_dir(&archive_root)? {
+            let path = entry?.path();
+            if references.contains(&path) {
+                continue;
+            }
+            let referenced = walkdir::WalkDir::new(&path)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|entry| entry.file_type().is_file())
+                .any(|entry| {
+                    entry
+                        .metadata()
+                        .ok()
+                        .as_ref()
+                        .and_then(hard_link_id)
+                        .is_some_and(|id| hard_links.contains(&id))
+                });
+            if referenced {
+                references.insert(path);
+            }
+        }
+
+        Ok(references)
+    }
+
     /// Prune dangling cache entries and cached environments.
     pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
@@ -929,6 +1195,47 @@ impl FromStr for Link {
     }
 }
 
+/// The entries that a clean operation would remove from the cache, as determined by
+/// [`Cache::plan_clear`] or [`Cache::plan_remove`].
+#[derive(Debug, Default)]
+pub struct CleanPlan {
+    /// The entries to remove.
+    pub entries: Vec<PathBuf>,
+    /// The entries that would otherwise be removed, but are retained since they're referenced by an
+    /// existing environment.
+    pub preserved: Vec<PathBuf>,
+}
+
+impl CleanPlan {
+    /// Remove the planned entries from the cache, retaining any preserved entries.
+    pub fn execute(&self) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+        for path in &self.entries {
+            summary += rm_rf(path)?;
+        }
+        for path in &self.preserved {
+            debug!("Retaining referenced cache entry: {}", path.display());
+        }
+        Ok(summary)
+    }
+}
+
+/// Return an identifier for a file that has multiple hard links.
+#[cfg(unix)]
+fn hard_link_id(metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+
+    (metadata.nlink() > 1).then(|| (metadata.dev(), metadata.ino()))
+}
+
+/// Return an identifier for a file that has multiple hard links.
+///
+/// Hard links are not detected outside of Unix.
+#[cfg(not(unix))]
+fn hard_link_id(_metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
 pub trait CleanReporter: Send + Sync {
     /// Called after one file or directory is removed.
     fn on_clean(&self);
@@ -1177,6 +1484,11 @@ pub enum CacheBucket {
     Archive,
     /// Ephemeral virtual environments used to execute PEP 517 builds and other operations.
     Builds,
+    /// Output from the build backend during PEP 517 builds, retained for failed builds (and, with
+    /// `--verbose`, for successful builds).
+    ///
+    /// Cache structure: `build-logs-v0/<package-name>/<version>-<digest(source tree)>/build.log`
+    BuildLogs,
     /// Reusable virtual environments for Python tools and projects.
     Environments,
     /// Cached Python downloads
@@ -1188,6 +1500,20 @@ pub enum CacheBucket {
     /// Cache structure:
     ///  * `osv-v0/vulnerability/<vuln_id>.msgpack` — cached full vulnerability records
     Osv,
+    /// Universal resolutions (i.e., lockfiles), keyed by the inputs to the resolver.
+    ///
+    /// Cache structure: `resolutions-v0/<digest(inputs)>.json`
+    Resolutions,
+    /// Distribution archives (wheels and source distributions) fetched by `uv pip download`,
+    /// retained in their original form so that subsequent downloads can be served from the cache.
+    ///
+    /// The [`CacheBucket::Wheels`] and [`CacheBucket::SourceDistributions`] buckets can't be
+    /// reused here: wheels are unzipped as they're streamed, and source distributions are
+    /// extracted, so neither retains the archive itself. Repacking an unzipped wheel wouldn't
+    /// reproduce the original file or its published hashes.
+    ///
+    /// Cache structure: `downloads-v0/<package-name>/<digest(url)>/<filename>`
+    Downloads,
 }
 
 impl CacheBucket {
@@ -1209,17 +1535,18 @@ impl CacheBucket {
             // `ARCHIVE_VERSION` in `crates/uv-cache/src/lib.rs`.
             Self::Archive => "archive-v0",
             Self::Builds => "builds-v0",
+            Self::BuildLogs => "build-logs-v0",
             Self::Environments => "environments-v2",
             Self::Python => "python-v0",
             Self::Binaries => "binaries-v0",
             Self::Osv => "osv-v0",
+            Self::Resolutions => "resolutions-v0",
+            Self::Downloads => "downloads-v0",
         }
     }
 
-    /// Remove a package from the cache bucket.
-    ///
-    /// Returns the number of entries removed from the cache.
-    fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
+    /// Return the paths to remove from the cache bucket for a package.
+    fn targets(self, cache: &Cache, name: &PackageName) -> Result<Vec<PathBuf>, io::Error> {
         /// Returns `true` if the [`Path`] represents a built wheel for the given package.
         fn is_match(path: &Path, name: &PackageName) -> bool {
             let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
@@ -1231,37 +1558,37 @@ impl CacheBucket {
             metadata.name == *name
         }
 
-        let mut summary = Removal::default();
+        let mut targets = Vec::new();
         match self {
             Self::Wheels => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                targets.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    targets.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
                 // directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    targets.push(directory.join(name.to_string()));
                 }
             }
             Self::SourceDistributions => {
                 // For `pypi` wheels, we expect a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(name.to_string()))?;
+                targets.push(root.join(name.to_string()));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(name.to_string()))?;
+                    targets.push(directory.join(name.to_string()));
                 }
 
                 // For direct URLs, we expect a directory for every URL, followed by a
@@ -1270,7 +1597,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
                     if directories(&url)?.any(|version| is_match(&version, name)) {
-                        summary += rm_rf(url)?;
+                        targets.push(url);
                     }
                 }
 
@@ -1280,7 +1607,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
                     if directories(&path)?.any(|version| is_match(&version, name)) {
-                        summary += rm_rf(path)?;
+                        targets.push(path);
                     }
                 }
 
@@ -1291,7 +1618,7 @@ impl CacheBucket {
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
                         if is_match(&sha, name) {
-                            summary += rm_rf(sha)?;
+                            targets.push(sha);
                         }
                     }
                 }
@@ -1299,20 +1626,24 @@ impl CacheBucket {
             Self::Simple => {
                 // For `pypi` wheels, we expect a rkyv file per package, indexed by name.
                 let root = cache.bucket(self).join(WheelCacheKind::Pypi);
-                summary += rm_rf(root.join(format!("{name}.rkyv")))?;
+                targets.push(root.join(format!("{name}.rkyv")));
 
                 // For alternate indices, we expect a directory for every index (under an `index`
                 // subdirectory), followed by a directory per package (indexed by name).
                 let root = cache.bucket(self).join(WheelCacheKind::Index);
                 for directory in directories(root)? {
-                    summary += rm_rf(directory.join(format!("{name}.rkyv")))?;
+                    targets.push(directory.join(format!("{name}.rkyv")));
                 }
             }
-            Self::FlatIndex => {
-                // We can't know if the flat index includes a package, so we just remove the entire
-                // cache entry.
+            Self::FlatIndex | Self::Resolutions => {
+                // We can't know if the flat index (or a resolution) includes a package, so we just
+                // remove the entire cache entry.
                 let root = cache.bucket(self);
-                summary += rm_rf(root)?;
+                targets.push(root);
+            }
+            Self::BuildLogs | Self::Downloads => {
+                let root = cache.bucket(self).join(name.to_string());
+                targets.push(root);
             }
             Self::Git
             | Self::Interpreter
@@ -1325,7 +1656,9 @@ impl CacheBucket {
                 // Nothing to do.
             }
         }
-        Ok(summary)
+        // Only include targets that exist.
+        targets.retain(|target| fs_err::symlink_metadata(target).is_ok());
+        Ok(targets)
     }
 
     /// Return an iterator over all cache buckets.
@@ -1339,10 +1672,13 @@ impl CacheBucket {
             Self::Simple,
             Self::Archive,
             Self::Builds,
+            Self::BuildLogs,
             Self::Environments,
             Self::Python,
             Self::Binaries,
             Self::Osv,
+            Self::Resolutions,
+            Self::Downloads,
         ]
         .iter()
         .copied()
@@ -13,6 +13,20 @@ pub fn rm_rf(path: impl AsRef<Path>) -> io::Result<Removal> {
     Remover::default().rm_rf(path, false)
 }
 
+/// Return the number of bytes that [`rm_rf`] would report as removed for a file or directory.
+///
+/// Unreadable entries are ignored.
+pub fn removal_size(path: impl AsRef<Path>) -> u64 {
+    walkdir::WalkDir::new(path.as_ref())
+        .follow_root_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| !entry.file_type().is_dir())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
 /// A builder for a [`Remover`] that can remove files and directories.
 #[derive(Default)]
 pub(crate) struct Remover {
@@ -10,16 +10,10 @@ pub trait CompatArgs {
 /// Arguments for `pip-compile` compatibility.
 ///
 /// These represent a subset of the `pip-compile` interface that uv supports by default.
-/// For example, users often pass `--allow-unsafe`, which is unnecessary with uv. But it's a
-/// nice user experience to warn, rather than fail, when users pass `--allow-unsafe`.
+/// For example, users often pass `--no-reuse-hashes`, which is unnecessary with uv. But it's a
+/// nice user experience to warn, rather than fail, when users pass `--no-reuse-hashes`.
 #[derive(Args)]
 pub struct PipCompileCompatArgs {
-    #[clap(long, hide = true)]
-    allow_unsafe: bool,
-
-    #[clap(long, hide = true)]
-    no_allow_unsafe: bool,
-
     #[clap(long, hide = true)]
     reuse_hashes: bool,
 
@@ -67,18 +61,6 @@ impl CompatArgs for PipCompileCompatArgs {
     /// behavior. If an argument is passed that does _not_ match uv's behavior (e.g.,
     /// `--no-build-isolation`), this method will return an error.
     fn validate(&self) -> Result<()> {
-        if self.allow_unsafe {
-            warn_user!(
-                "pip-compile's `--allow-unsafe` has no effect (uv can safely pin `pip` and other packages)"
-            );
-        }
-
-        if self.no_allow_unsafe {
-            warn_user!(
-                "pip-compile's `--no-allow-unsafe` has no effect (uv can safely pin `pip` and other packages)"
-            );
-        }
-
         if self.reuse_hashes {
             return Err(anyhow!(
                 "pip-compile's `--reuse-hashes` is unsupported (uv doesn't reuse hashes)"
@@ -315,12 +297,6 @@ impl CompatArgs for VenvCompatArgs {
 /// These represent a subset of the `pip uninstall` interface that uv supports by default.
 #[derive(Args)]
 pub struct PipUninstallCompatArgs {
-    /// Don't ask for confirmation of uninstall deletions.
-    ///
-    /// This option is for compatibility with `pip uninstall` and has no effect.
-    #[clap(short, long, hide = true)]
-    yes: bool,
-
     #[clap(long, hide = true)]
     disable_pip_version_check: bool,
 }
@@ -332,10 +308,6 @@ impl CompatArgs for PipUninstallCompatArgs {
     /// behavior. If an argument is passed that does _not_ match uv's behavior, this method will
     /// return an error.
     fn validate(&self) -> Result<()> {
-        if self.yes {
-            warn_user!("`--yes` has no effect (uv never asks for confirmation)");
-        }
-
         if self.disable_pip_version_check {
             warn_user!("pip's `--disable-pip-version-check` has no effect");
         }
@@ -1,5 +1,6 @@
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
+use std::num::{NonZeroU64, NonZeroUsize};
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -16,11 +17,12 @@ use uv_auth::Service;
 use uv_cache::CacheArgs;
 use uv_configuration::{
     ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PipCompileFormat,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ProjectBuildBackend, TargetAbiFlags, TargetImplementation, TargetTriple, TrustedHost,
+    TrustedPublishing, VersionControlSystem,
 };
 use uv_distribution_types::{
-    ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
-    PipFindLinks, PipIndex,
+    BuildEnvEntry, BuildTarget, ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl,
+    Origin, PipExtraIndex, PipFindLinks, PipIndex,
 };
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
@@ -29,8 +31,9 @@ use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
-    AnnotationStyle, ExcludeNewerOverride, ExcludeNewerPackageEntry, ForkStrategy, PrereleaseMode,
-    ResolutionMode,
+    AnnotationStyle, ExcludeNewerOverride, ExcludeNewerPackageEntry, ForkStrategy,
+    PackageLocalVersionPreferenceEntry, PackagePrereleaseModeEntry, PackageResolutionModeEntry,
+    PrereleaseMode, RequiresPythonStrategy, ResolutionMode, YankedPolicy,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -59,6 +62,42 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum SelfDirsFormat {
+    /// Display the directories in a human-readable format.
+    #[default]
+    Text,
+    /// Display the directories in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheCleanFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum DryRunFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockReportFormat {
+    /// Write the report as Markdown.
+    #[default]
+    Markdown,
+    /// Write the report as JSON.
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -91,6 +130,15 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CheckFormat {
+    /// Display the diagnostics in a human-readable format.
+    #[default]
+    Text,
+    /// Display the diagnostics in a machine-readable JSON format.
+    Json,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -211,6 +259,23 @@ pub struct GlobalArgs {
     #[arg(global = true, long, help_heading = "Python options")]
     pub no_python_downloads: bool,
 
+    #[expect(clippy::doc_markdown)]
+    /// Whether to allow Python downloads. [env: UV_PYTHON_DOWNLOADS=]
+    ///
+    /// Takes precedence over the `UV_PYTHON_DOWNLOADS` environment variable and the
+    /// `python-downloads` setting in any configuration file.
+    // Hidden in favor of `--no-python-downloads`, like `--python-preference` is hidden in favor of
+    // `--managed-python`; both are documented in the Python versions guide.
+    #[arg(
+        global = true,
+        long,
+        help_heading = "Python options",
+        value_enum,
+        conflicts_with_all = ["allow_python_downloads", "no_python_downloads"],
+        hide = true
+    )]
+    pub python_downloads: Option<PythonDownloads>,
+
     /// Deprecated version of [`Self::python_downloads`].
     #[arg(global = true, long, hide = true)]
     pub python_fetch: Option<PythonDownloads>,
@@ -283,6 +348,16 @@ pub struct GlobalArgs {
     #[arg(global = true, long, overrides_with("offline"), hide = true)]
     pub no_offline: bool,
 
+    /// Fail when a registry artifact is missing, rather than refreshing the index metadata and
+    /// retrying.
+    ///
+    /// By default, if downloading a file from an index fails with a `404 Not Found` or
+    /// `410 Gone`, uv will refresh the package's index metadata once and retry with an
+    /// equivalent file for the same version (e.g., if the index re-uploaded or relocated the
+    /// file). Use this flag to disable that behavior in reproducibility-sensitive environments.
+    #[arg(global = true, long)]
+    pub no_retry_missing_artifacts: bool,
+
     /// Allow insecure connections to a host.
     ///
     /// Can be provided multiple times.
@@ -357,6 +432,23 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_installer_metadata: bool,
 
+    /// The maximum number of source distributions to build concurrently [env: UV_CONCURRENT_BUILDS=]
+    ///
+    /// Independent source distributions are built in parallel, up to the given limit. Builds that
+    /// require other packages to be built first (e.g., as build dependencies) wait for those
+    /// builds to complete. Defaults to the number of available CPU cores.
+    #[arg(global = true, long, value_name = "N")]
+    pub concurrent_builds: Option<NonZeroUsize>,
+
+    /// Continue building after a build fails, and report every failure.
+    ///
+    /// By default, uv stops at the first failed build. With `--no-fail-fast`, the remaining source
+    /// distributions are built to completion, such that all failures are reported at once. During
+    /// resolution, uv waits for the builds that are already in progress, and reports their failures
+    /// alongside the first.
+    #[arg(global = true, long)]
+    pub no_fail_fast: bool,
+
     /// Change to the given directory prior to running the command.
     ///
     /// Relative paths are resolved with the given directory as the base.
@@ -860,6 +952,24 @@ pub enum SelfCommand {
         #[arg(long, value_enum, default_value = "text")]
         output_format: VersionFormat,
     },
+    /// Show the directories used by uv.
+    ///
+    /// Displays the purpose and location of each directory in which uv stores data, along with
+    /// how the location was determined, e.g., by an environment variable like `UV_TOOL_DIR`, by
+    /// an XDG variable like `XDG_DATA_HOME`, or by the platform default.
+    ///
+    /// If a directory is overridden but empty while the default location contains existing data,
+    /// a warning is shown, since the existing data will not be used.
+    Dirs(SelfDirsArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct SelfDirsArgs {
+    /// The format in which to display the directories.
+    ///
+    /// The JSON format includes the name, purpose, path, and source of each directory.
+    #[arg(long, value_enum, default_value_t = SelfDirsFormat::default())]
+    pub output_format: SelfDirsFormat,
 }
 
 #[derive(Args, Debug)]
@@ -925,6 +1035,30 @@ pub struct CleanArgs {
     /// `--force` is used, `uv cache clean` will proceed without taking a lock.
     #[arg(long)]
     pub force: bool,
+
+    /// Show the cache entries that would be removed, without removing them.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Retain unzipped wheels in the cache that are linked into existing environments.
+    ///
+    /// When installing packages, uv links files from the cache into the environment (e.g., via
+    /// hard links, with the default link mode on Linux). With `--preserve-referenced`, uv scans
+    /// the project environments it has previously used, along with installed tools, for such
+    /// links, and retains the referenced cache entries.
+    ///
+    /// The set of scanned environments is best-effort: environments created by other means (e.g.,
+    /// `uv venv`) or not used by a project command since upgrading uv are not scanned. Links
+    /// created with the `copy` or `clone` link modes do not reference the cache, and hard links
+    /// are only detected on Unix.
+    #[arg(long)]
+    pub preserve_referenced: bool,
+
+    /// The format in which to display the cache entries that would be removed.
+    ///
+    /// The JSON format includes the path and size (in bytes) of each entry.
+    #[arg(long, value_enum, default_value_t = CacheCleanFormat::default(), requires = "dry_run")]
+    pub output_format: CacheCleanFormat,
 }
 
 #[derive(Args, Debug)]
@@ -973,7 +1107,7 @@ pub enum PipCommand {
         after_help = "Use `uv help pip compile` for more details.",
         after_long_help = ""
     )]
-    Compile(PipCompileArgs),
+    Compile(Box<PipCompileArgs>),
     /// Sync an environment with a `requirements.txt` or `pylock.toml` file.
     ///
     /// When syncing an environment, any packages not listed in the `requirements.txt` or
@@ -994,7 +1128,17 @@ pub enum PipCommand {
         after_help = "Use `uv help pip install` for more details.",
         after_long_help = ""
     )]
-    Install(PipInstallArgs),
+    Install(Box<PipInstallArgs>),
+    /// Download packages and their dependencies into a directory.
+    ///
+    /// The requirements are resolved for the target Python version and platform, and each selected
+    /// wheel or source distribution is saved under its original filename. The resulting directory
+    /// can be passed to `--find-links` to install the packages without network access.
+    #[command(
+        after_help = "Use `uv help pip download` for more details.",
+        after_long_help = ""
+    )]
+    Download(Box<PipDownloadArgs>),
     /// Uninstall packages from an environment.
     #[command(
         after_help = "Use `uv help pip uninstall` for more details.",
@@ -1150,8 +1294,9 @@ pub enum ProjectCommand {
     /// uv will search for a project in the current directory or any parent directory. If a project
     /// cannot be found, uv will exit with an error.
     ///
-    /// Note that, when installing from a lockfile, uv will not provide warnings for yanked package
-    /// versions.
+    /// When installing from a lockfile, uv queries the index to warn if any of the locked versions
+    /// have since been yanked (unless `--frozen` is provided). Use `--yanked forbid` to fail
+    /// instead.
     #[command(
         after_help = "Use `uv help sync` for more details.",
         after_long_help = ""
@@ -1452,7 +1597,6 @@ pub struct PipCompileArgs {
     /// any package listed in the provided file will be omitted from all resolved environments.
     #[arg(
         long,
-        alias = "exclude",
         env = EnvVars::UV_EXCLUDE,
         value_delimiter = ' ',
         value_parser = parse_maybe_file_path,
@@ -1503,6 +1647,9 @@ pub struct PipCompileArgs {
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
+    #[command(flatten)]
+    pub build_target: BuildTargetArgs,
+
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
@@ -1514,6 +1661,26 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("no_deps"), hide = true)]
     pub deps: bool,
 
+    /// Explain why the selected version of a package was chosen.
+    ///
+    /// Lists the requirements on the package that constrained its version, including the package
+    /// (and version) that declared each requirement, along with any constraints and overrides, and
+    /// any newer versions that were skipped as pre-releases, as yanked, or due to
+    /// `--exclude-newer`. If the package is not part of the resolution, explains why.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub explain: Vec<PackageName>,
+
+    /// When resolution fails, search for relaxations of the direct requirements that would allow
+    /// it to succeed, and suggest them as hints.
+    ///
+    /// Each requirement on a package involved in the conflict is relaxed in turn (e.g., by
+    /// removing its upper bound) and the resolution is retried. The search is bounded by a time
+    /// budget, and only applies to the direct requirements.
+    #[arg(long)]
+    pub hint_on_failure: bool,
+
     /// Write the compiled requirements to the given `requirements.txt` or `pylock.toml` file.
     ///
     /// If the file already exists, the existing versions will be preferred when resolving
@@ -1568,7 +1735,7 @@ pub struct PipCompileArgs {
     /// The style of the annotation comments included in the output file, used to indicate the
     /// source of each package.
     ///
-    /// Defaults to `split`.
+    /// Defaults to `split`. Use `none` to omit the annotations, as with `--no-annotate`.
     #[arg(long, value_enum)]
     pub annotation_style: Option<AnnotationStyle>,
 
@@ -1578,6 +1745,14 @@ pub struct PipCompileArgs {
     #[arg(long, env = EnvVars::UV_CUSTOM_COMPILE_COMMAND, value_hint = ValueHint::Other)]
     pub custom_compile_command: Option<String>,
 
+    /// A custom comment header to include at the top of the output file generated by
+    /// `uv pip compile`, in place of the default header.
+    ///
+    /// Each line of the header is rendered as a comment; lines that don't already start with `#`
+    /// are prefixed with `# `.
+    #[arg(long, conflicts_with = "no_header", value_hint = ValueHint::Other)]
+    pub custom_header: Option<String>,
+
     /// The Python interpreter to use during resolution.
     ///
     /// A Python interpreter is required for building source distributions to determine package
@@ -1700,6 +1875,22 @@ pub struct PipCompileArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// The Python implementation for which requirements should be resolved.
+    ///
+    /// Defaults to the implementation of the Python interpreter used for resolution. When
+    /// provided, the marker environment and compatible wheel tags are derived from the given
+    /// implementation, such that the interpreter itself need not be installed.
+    #[arg(long)]
+    pub python_implementation: Option<TargetImplementation>,
+
+    /// The ABI flags of the Python interpreter for which requirements should be resolved, as in
+    /// `sys.abiflags` (e.g., `t` for a free-threaded build of CPython).
+    ///
+    /// Defaults to the ABI flags of the Python interpreter used for resolution, if it matches the
+    /// target implementation. Pass an empty string to target a default build.
+    #[arg(long)]
+    pub python_abiflags: Option<TargetAbiFlags>,
+
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -1713,6 +1904,8 @@ pub struct PipCompileArgs {
         long,
         overrides_with("no_universal"),
         conflicts_with("python_platform"),
+        conflicts_with("python_implementation"),
+        conflicts_with("python_abiflags"),
         conflicts_with("strip_markers")
     )]
     pub universal: bool,
@@ -1722,9 +1915,28 @@ pub struct PipCompileArgs {
 
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
+    ///
+    /// When provided alongside `--no-allow-unsafe`, replaces the default set of "unsafe" packages.
     #[arg(long, alias = "unsafe-package", value_delimiter = ',', value_hint = ValueHint::Other)]
     pub no_emit_package: Option<Vec<PackageName>>,
 
+    /// Include "unsafe" packages in the output resolution, including any packages passed to
+    /// `--no-emit-package` (`--unsafe-package`).
+    ///
+    /// Equivalent to pip-compile's `--allow-unsafe` option.
+    #[arg(long, overrides_with("no_allow_unsafe"))]
+    pub allow_unsafe: bool,
+
+    /// Omit "unsafe" packages from the output resolution, as in pip-compile.
+    ///
+    /// Unless packages are provided via `--no-emit-package` (`--unsafe-package`), `pip`,
+    /// `setuptools`, and `wheel` are considered unsafe. Their dependencies will still be included
+    /// in the resolution.
+    ///
+    /// By default, uv only omits packages passed to `--no-emit-package`.
+    #[arg(long, overrides_with("allow_unsafe"))]
+    pub no_allow_unsafe: bool,
+
     /// Include `--index-url` and `--extra-index-url` entries in the generated output file.
     #[arg(long, overrides_with("no_emit_index_url"))]
     pub emit_index_url: bool,
@@ -2081,6 +2293,20 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// The format in which to display the dry-run plan.
+    ///
+    /// The JSON format lists the packages that would be downloaded or built from source, along
+    /// with each change to the environment (installs, removals, upgrades, downgrades, and
+    /// reinstalls).
+    #[arg(long, visible_alias = "format", value_enum, default_value_t = DryRunFormat::default(), requires = "dry_run")]
+    pub output_format: DryRunFormat,
+
+    /// Exit with a status of 1 if the sync would modify the environment.
+    ///
+    /// Useful for detecting drift between an environment and its requirements, e.g., in CI.
+    #[arg(long, requires = "dry_run")]
+    pub exit_code: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2186,7 +2412,6 @@ pub struct PipInstallArgs {
     /// any package listed in the provided file will be omitted from all resolved environments.
     #[arg(
         long,
-        alias = "exclude",
         env = EnvVars::UV_EXCLUDE,
         value_delimiter = ' ',
         value_parser = parse_maybe_file_path,
@@ -2471,6 +2696,36 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Report whether each resolved package has a compatible wheel for the target platform, rather
+    /// than the changes that would be made to the environment.
+    ///
+    /// Combine with `--python-platform` and `--python-version` to check whether the requirements
+    /// can be installed on another machine. Packages in the environment are ignored, and only
+    /// metadata is downloaded. Exits with a non-zero status if any package lacks a compatible
+    /// wheel, i.e., would need to be built from source.
+    #[arg(long, requires = "dry_run")]
+    pub compatibility_report: bool,
+
+    /// The format in which to display the dry-run plan or compatibility report.
+    ///
+    /// The JSON format lists the packages that would be downloaded or built from source, along
+    /// with each change to the environment (installs, removals, upgrades, downgrades, and
+    /// reinstalls).
+    #[arg(long, visible_alias = "format", value_enum, default_value_t = DryRunFormat::default(), requires = "dry_run")]
+    pub output_format: DryRunFormat,
+
+    /// Write a JSON report describing the installed distributions to the given path, or to stdout
+    /// with `-`.
+    ///
+    /// The report follows the format of `pip install --report`, including the metadata, download
+    /// URL, and hashes of each distribution. With `--dry-run`, the report describes the
+    /// distributions that would be installed; like pip, uv downloads (or builds) them to read
+    /// their metadata.
+    ///
+    /// `--report -` can't be combined with `--format json`, which also writes to stdout.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    pub report: Option<PathBuf>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2492,23 +2747,124 @@ pub struct PipInstallArgs {
 
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
-pub struct PipUninstallArgs {
-    /// Uninstall all listed packages.
+pub struct PipDownloadArgs {
+    /// Download all listed packages.
+    ///
+    /// The order of the packages is used to determine priority during resolution.
     #[arg(group = "sources", value_hint = ValueHint::Other)]
     pub package: Vec<String>,
 
-    /// Uninstall the packages listed in the given files.
+    /// Download the packages listed in the given files.
     ///
     /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
-    /// `pylock.toml`, `pyproject.toml`, `setup.py`, and `setup.cfg`.
-    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
+    /// `pyproject.toml`, `setup.py`, and `setup.cfg`.
+    ///
+    /// If `-` is provided, then requirements will be read from stdin.
+    #[arg(
+        long,
+        short,
+        alias = "requirement",
+        group = "sources",
+        value_parser = parse_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
     pub requirements: Vec<PathBuf>,
 
-    /// The Python interpreter from which packages should be uninstalled.
+    /// Constrain versions using the given requirements files.
     ///
-    /// By default, uninstallation requires a virtual environment. A path to an alternative Python
-    /// can be provided, but it is only recommended in continuous integration (CI) environments and
-    /// should be used with caution, as it can modify the system Python installation.
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's downloaded. However, including a package in a constraints file will
+    /// _not_ trigger the download of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(
+        long,
+        short,
+        alias = "constraint",
+        env = EnvVars::UV_CONSTRAINT,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be downloaded, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[arg(
+        long,
+        alias = "override",
+        env = EnvVars::UV_OVERRIDE,
+        value_delimiter = ' ',
+        value_parser = parse_maybe_file_path,
+        value_hint = ValueHint::FilePath,
+    )]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
+    /// The directory into which the distributions should be downloaded.
+    ///
+    /// Defaults to the current working directory. The directory is created if it does not exist.
+    #[arg(long, short, value_hint = ValueHint::DirPath)]
+    pub dest: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Ignore package dependencies, instead only downloading those packages explicitly listed
+    /// on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub deps: bool,
+
+    /// Require a matching hash for each requirement.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash.
+    ///
+    /// When `--require-hashes` is enabled, _all_ requirements must include a hash or set of hashes,
+    /// and _all_ requirements must either be pinned to exact versions (e.g., `==1.0.0`), or be
+    /// specified via direct URL.
+    #[arg(
+        long,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_hashes"),
+    )]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
+    pub verify_hashes: bool,
+
+    /// Disable validation of hashes in the requirements file.
+    ///
+    /// By default, uv will verify any available hashes in the requirements file, but will not
+    /// require that all requirements have an associated hash. To enforce hash validation, use
+    /// `--require-hashes`.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_VERIFY_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("verify_hashes"),
+    )]
+    pub no_verify_hashes: bool,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// A Python interpreter is required for building source distributions to determine package
+    /// metadata when there are not wheels.
+    ///
+    /// The interpreter is also used as the fallback value for the minimum Python version if
+    /// `--python-version` is not set.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
     #[arg(
@@ -2522,23 +2878,12 @@ pub struct PipUninstallArgs {
     )]
     pub python: Option<Maybe<String>>,
 
-    /// Attempt to use `keyring` for authentication for remote requirements files.
-    ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
-    /// the `keyring` CLI to handle authentication.
-    ///
-    /// Defaults to `disabled`.
-    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
-    pub keyring_provider: Option<KeyringProviderType>,
-
-    /// Use the system Python to uninstall packages.
-    ///
-    /// By default, uv uninstalls from the virtual environment in the current working directory or
-    /// any parent directory. The `--system` option instructs uv to instead use the first Python
-    /// found in the system `PATH`.
+    /// Use the system Python to resolve the requirements.
     ///
-    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
-    /// should be used with caution, as it can modify the system Python installation.
+    /// By default, uv uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--system`
+    /// option instructs uv to avoid using a virtual environment Python and restrict its search to
+    /// the system path.
     #[arg(
         long,
         env = EnvVars::UV_SYSTEM_PYTHON,
@@ -2550,35 +2895,177 @@ pub struct PipUninstallArgs {
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
 
-    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+    /// Don't build source distributions.
     ///
-    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
-    /// environments, when installing into Python installations that are managed by an external
-    /// package manager, like `apt`. It should be used with caution, as such Python installations
-    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    /// When enabled, resolving will not run arbitrary Python code, and only wheels will be
+    /// downloaded. The cached wheels of already-built source distributions will be reused, but
+    /// operations that require building distributions will exit with an error.
+    ///
+    /// Alias for `--only-binary :all:`.
     #[arg(
         long,
-        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
-        value_parser = clap::builder::BoolishValueParser::new(),
-        overrides_with("no_break_system_packages")
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
     )]
-    pub break_system_packages: bool,
-
-    #[arg(long, overrides_with("break_system_packages"))]
-    pub no_break_system_packages: bool,
-
-    /// Uninstall packages from the specified `--target` directory.
-    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
-    pub target: Option<PathBuf>,
+    pub no_build: bool,
 
-    /// Uninstall packages from the specified `--prefix` directory.
-    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub build: bool,
+
+    /// Don't download pre-built wheels.
+    ///
+    /// The given packages will be downloaded as source distributions. The resolver will still use
+    /// pre-built wheels to extract package metadata, if available.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only download pre-built wheels; don't download source distributions.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`. Clear
+    /// previously specified packages with `:none:`.
+    #[arg(long, value_delimiter = ',', conflicts_with = "no_build")]
+    pub only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// The minimum Python version that should be supported by the downloaded distributions (e.g.,
+    /// `3.7` or `3.7.9`).
+    ///
+    /// If a patch version is omitted, the minimum patch version is assumed. For example, `3.7` is
+    /// mapped to `3.7.0`.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform for which distributions should be downloaded.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// When targeting macOS (Darwin), the default minimum version is `13.0`. Use
+    /// `MACOSX_DEPLOYMENT_TARGET` to specify a different minimum version, e.g., `14.0`.
+    ///
+    /// WARNING: When specified, uv will select wheels that are compatible with the _target_
+    /// platform; as a result, the downloaded distributions may not be compatible with the _current_
+    /// platform. Any source distributions that must be built to determine their metadata will be
+    /// built for the _current_ platform.
+    #[arg(long)]
+    pub python_platform: Option<TargetTriple>,
+}
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipUninstallArgs {
+    /// Uninstall all listed packages.
+    #[arg(group = "sources", value_hint = ValueHint::Other)]
+    pub package: Vec<String>,
+
+    /// Uninstall the packages listed in the given files.
+    ///
+    /// The following formats are supported: `requirements.txt`, `.py` files with inline metadata,
+    /// `pylock.toml`, `pyproject.toml`, `setup.py`, and `setup.cfg`.
+    #[arg(long, short, alias = "requirement", group = "sources", value_parser = parse_file_path, value_hint = ValueHint::FilePath)]
+    pub requirements: Vec<PathBuf>,
+
+    /// The Python interpreter from which packages should be uninstalled.
+    ///
+    /// By default, uninstallation requires a virtual environment. A path to an alternative Python
+    /// can be provided, but it is only recommended in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// Attempt to use `keyring` for authentication for remote requirements files.
+    ///
+    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to use
+    /// the `keyring` CLI to handle authentication.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+
+    /// Use the system Python to uninstall packages.
+    ///
+    /// By default, uv uninstalls from the virtual environment in the current working directory or
+    /// any parent directory. The `--system` option instructs uv to instead use the first Python
+    /// found in the system `PATH`.
+    ///
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution, as it can modify the system Python installation.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Allow uv to modify an `EXTERNALLY-MANAGED` Python installation.
+    ///
+    /// WARNING: `--break-system-packages` is intended for use in continuous integration (CI)
+    /// environments, when installing into Python installations that are managed by an external
+    /// package manager, like `apt`. It should be used with caution, as such Python installations
+    /// explicitly recommend against modifications by other package managers (like uv or `pip`).
+    #[arg(
+        long,
+        env = EnvVars::UV_BREAK_SYSTEM_PACKAGES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_break_system_packages")
+    )]
+    pub break_system_packages: bool,
+
+    #[arg(long, overrides_with("break_system_packages"))]
+    pub no_break_system_packages: bool,
+
+    /// Uninstall packages from the specified `--target` directory.
+    #[arg(short = 't', long, conflicts_with = "prefix", value_hint = ValueHint::DirPath)]
+    pub target: Option<PathBuf>,
+
+    /// Uninstall packages from the specified `--prefix` directory.
+    #[arg(long, conflicts_with = "target", value_hint = ValueHint::DirPath)]
     pub prefix: Option<PathBuf>,
 
     /// Perform a dry run, i.e., don't actually uninstall anything but print the resulting plan.
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Also uninstall any dependencies of the listed packages that are no longer required by
+    /// other installed packages.
+    ///
+    /// Packages that were requested directly (e.g., with `uv pip install <package>`) are never
+    /// removed as dependencies. This information is only available for packages installed by uv.
+    #[arg(long)]
+    pub unneeded: bool,
+
+    /// Don't ask for confirmation when uninstalling packages that other installed packages depend
+    /// on.
+    ///
+    /// Confirmation is only requested when running in an interactive terminal; otherwise, uv warns
+    /// about the broken dependencies and proceeds.
+    #[arg(short, long)]
+    pub yes: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipUninstallCompatArgs,
 }
@@ -2672,6 +3159,9 @@ pub struct PipListArgs {
     ///
     /// The latest version of each package will be shown alongside the installed version. Up-to-date
     /// packages will be omitted from the output.
+    ///
+    /// Packages that weren't installed from a registry (e.g., from a URL, a local path, or a Git
+    /// repository) are marked with their source, rather than compared against the index.
     #[arg(long, overrides_with("no_outdated"))]
     pub outdated: bool,
 
@@ -2736,6 +3226,12 @@ pub struct PipListArgs {
 
 #[derive(Args)]
 pub struct PipCheckArgs {
+    /// Select the output format.
+    ///
+    /// In either format, uv exits with a non-zero status if any incompatibilities are found.
+    #[arg(long, value_enum, default_value_t = CheckFormat::default())]
+    pub format: CheckFormat,
+
     /// The Python interpreter for which packages should be checked.
     ///
     /// By default, uv checks packages in a virtual environment but will check packages in a system
@@ -2812,9 +3308,19 @@ pub struct PipShowArgs {
     pub no_strict: bool,
 
     /// Show the full list of installed files for each package.
+    ///
+    /// Files are read from the package's `RECORD` (or, for legacy `.egg-info` installs,
+    /// `installed-files.txt`), and are shown relative to the `site-packages` directory.
     #[arg(short, long)]
     pub files: bool,
 
+    /// Show why each package is installed.
+    ///
+    /// Displays the reason recorded by uv when the package was installed (e.g., the packages that
+    /// require it), along with the dependency paths from the project's lockfile, if available.
+    #[arg(long)]
+    pub why: bool,
+
     /// The Python interpreter to find the package in.
     ///
     /// By default, uv looks for packages in a virtual environment but will look for packages in a
@@ -3094,6 +3600,9 @@ pub struct BuildArgs {
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
+    #[command(flatten)]
+    pub build_target: BuildTargetArgs,
+
     #[command(flatten)]
     pub build: BuildOptionsArgs,
 
@@ -3770,6 +4279,22 @@ pub struct RunArgs {
     #[arg(long, conflicts_with_all = ["script", "module"])]
     pub gui_script: bool,
 
+    /// Display the command that would be executed, without running it.
+    ///
+    /// The run environment is prepared as usual (e.g., the project environment is synced), then
+    /// the resolved executable is displayed along with any arguments.
+    #[arg(long)]
+    pub which: bool,
+
+    /// Prefer commands on the `PATH` over those in the run environment.
+    ///
+    /// By default, a command is resolved from the run environment (e.g., the project's `.venv`)
+    /// first, then from the `PATH`. With `--from-path`, the `PATH` is searched first.
+    ///
+    /// This only affects commands given by name, e.g., `pytest`, not paths like `./pytest`.
+    #[arg(long)]
+    pub from_path: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3996,6 +4521,24 @@ pub struct SyncArgs {
     #[arg(long, overrides_with("inexact"), hide = true)]
     pub exact: bool,
 
+    /// Keep the given extraneous package(s) in the environment.
+    ///
+    /// By default, syncing will remove any packages that aren't included in the lockfile. The
+    /// `--keep` option allows specific packages (e.g., debugging tools installed with
+    /// `uv pip install`) to be retained, while still reporting them as extraneous.
+    ///
+    /// Packages that are included in the lockfile are always synced to the locked version.
+    ///
+    /// May also be set with the `keep-extraneous` setting.
+    #[arg(long, conflicts_with_all = ["inexact", "script"], value_hint = ValueHint::Other)]
+    pub keep: Vec<PackageName>,
+
+    /// List the extraneous packages that would be removed, without modifying the environment.
+    ///
+    /// Packages retained via `--keep` or the `keep-extraneous` setting are reported separately.
+    #[arg(long, conflicts_with_all = ["inexact", "dry_run", "check", "script"])]
+    pub list_extraneous: bool,
+
     /// Sync dependencies to the active virtual environment.
     ///
     /// Instead of creating or updating the virtual environment for the project or script, the
@@ -4203,6 +4746,14 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// Verify the contents of installed packages against their `RECORD` files.
+    ///
+    /// By default, uv determines whether the environment is up to date from the package metadata
+    /// (`.dist-info` directories) alone. With `--verify`, uv also hashes the files of each
+    /// installed package, and reinstalls any packages with missing or modified files.
+    #[arg(long)]
+    pub verify: bool,
 }
 
 #[derive(Args)]
@@ -4210,7 +4761,11 @@ pub struct LockArgs {
     /// Check if the lockfile is up-to-date.
     ///
     /// Asserts that the `uv.lock` would remain unchanged after a resolution. If the lockfile is
-    /// missing or needs to be updated, uv will exit with an error.
+    /// missing or needs to be updated, uv will exit with an error, along with a summary of the
+    /// packages that would be added, removed, or updated.
+    ///
+    /// Exits with status code 1 if the lockfile is outdated, and status code 2 if the resolution
+    /// fails.
     ///
     /// Equivalent to `--locked`.
     #[arg(long, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["check_exists", "upgrade"], overrides_with = "check")]
@@ -4243,6 +4798,84 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// Raise the lower bound of each direct dependency to its locked version.
+    ///
+    /// Compares the specifier of each dependency in `project.dependencies`,
+    /// `project.optional-dependencies`, and `dependency-groups` with the locked version, and
+    /// reports the narrowed specifier (e.g., `anyio>=3` → `anyio>=4.4.0`). Upper bounds and
+    /// markers are preserved. Workspace members, exact pins, and direct URL dependencies are
+    /// skipped.
+    ///
+    /// By default, the changes are only reported; use `--write` to apply them to the
+    /// `pyproject.toml`.
+    #[arg(long, alias = "narrow", conflicts_with = "script")]
+    pub narrow_bounds: bool,
+
+    /// Apply the changes proposed by `--narrow-bounds` to the `pyproject.toml`.
+    ///
+    /// The lockfile is updated to match the narrowed requirements.
+    #[arg(
+        long,
+        requires = "narrow_bounds",
+        conflicts_with_all = ["dry_run", "check", "locked", "check_exists"]
+    )]
+    pub write: bool,
+
+    /// Report the forks in the resolution and the packages that differ across them.
+    ///
+    /// Lists the marker expression of each fork, each package that was locked to more than one
+    /// version along with the forks that selected each version, and the requirements on those
+    /// packages, which are what cause the resolution to fork.
+    ///
+    /// The report is also shown in verbose mode (`-v`).
+    #[arg(long)]
+    pub summary: bool,
+
+    /// Explain why the selected version of a package was chosen.
+    ///
+    /// Lists the requirements on the package that constrained its version, including the package
+    /// (and version) that declared each requirement, along with any constraints and overrides, and
+    /// any newer versions that were skipped as pre-releases, as yanked, or due to
+    /// `--exclude-newer`. If the package is not part of the resolution, explains why.
+    ///
+    /// The project is always re-resolved (preferring the locked versions) to produce the
+    /// explanation. May be provided multiple times.
+    #[arg(long, value_name = "PACKAGE")]
+    pub explain: Vec<PackageName>,
+
+    /// How to handle dependencies that don't support the project's full `requires-python` range.
+    ///
+    /// By default, such a dependency (e.g., one that requires `<3.12` in a project that requires
+    /// `>=3.9`) narrows the range of Python versions supported by the lockfile, and the narrowed
+    /// range is reported. In `strict` mode, locking fails instead, naming the dependency, its
+    /// `requires-python`, and the packages that pulled it in; the project is always re-resolved
+    /// (preferring the locked versions) to perform the check.
+    #[arg(long, value_enum, default_value_t = RequiresPythonStrategy::default())]
+    pub requires_python_strategy: RequiresPythonStrategy,
+
+    /// Write a report of the changes to the lockfile to the given path.
+    ///
+    /// The report groups the changed packages into direct and transitive dependencies, with their
+    /// previous and updated versions, the release dates of the updated versions (if the index
+    /// provides upload times), and links to their PyPI project pages. It also lists any packages
+    /// that were held back from their latest version, along with the requirements that held them
+    /// back.
+    ///
+    /// The report is derived from the metadata that was fetched while locking, without any further
+    /// network requests. Intended for use with `--upgrade`, e.g., to generate the description of
+    /// a pull request that updates the lockfile.
+    #[arg(long, value_name = "PATH")]
+    pub preview_report: Option<PathBuf>,
+
+    /// The format in which to write the report.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = LockReportFormat::default(),
+        requires = "preview_report"
+    )]
+    pub preview_report_format: LockReportFormat,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -4871,7 +5504,8 @@ pub struct TreeArgs {
 pub struct ExportArgs {
     /// The format to which `uv.lock` should be exported.
     ///
-    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats,
+    /// along with a Dockerfile snippet that installs the project into an image.
     ///
     /// uv will infer the output format from the file extension of the output file, if
     /// provided. Otherwise, defaults to `requirements.txt`.
@@ -4991,6 +5625,15 @@ pub struct ExportArgs {
     #[arg(long, overrides_with("no_header"), hide = true)]
     pub header: bool,
 
+    /// Use a distroless image for the final stage of a Dockerfile snippet.
+    ///
+    /// The build stage installs a managed Python interpreter, which is copied into the final stage
+    /// alongside the project environment.
+    ///
+    /// Only applies to the `dockerfile-snippet` format.
+    #[arg(long)]
+    pub distroless: bool,
+
     /// Include `--index-url` and `--extra-index-url` entries in the generated output file.
     #[arg(long, overrides_with("no_emit_index_url"))]
     pub emit_index_url: bool,
@@ -5675,6 +6318,12 @@ pub enum ToolCommand {
     List(ToolListArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
+    /// Repair the installed tools.
+    ///
+    /// Removes the receipts of tools whose environments were deleted, removes executables that link
+    /// to tool environments that no longer exist, and re-links missing executables for tools whose
+    /// environments exist.
+    Repair,
     /// Ensure that the tool executable directory is on the `PATH`.
     ///
     /// If the tool executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -5976,7 +6625,6 @@ pub struct ToolInstallArgs {
     /// any package listed in the provided file will be omitted from all resolved environments.
     #[arg(
         long,
-        alias = "exclude",
         env = EnvVars::UV_EXCLUDE,
         value_delimiter = ' ',
         value_parser = parse_maybe_file_path,
@@ -6156,6 +6804,12 @@ pub struct ToolUninstallArgs {
     /// Uninstall all tools.
     #[arg(long, conflicts_with("name"))]
     pub all: bool,
+
+    /// Keep the given tool when uninstalling all tools.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with("name"), value_hint = ValueHint::Other)]
+    pub keep: Vec<PackageName>,
 }
 
 #[derive(Args)]
@@ -6290,6 +6944,17 @@ pub struct ToolUpgradeArgs {
     )]
     pub resolution: Option<ResolutionMode>,
 
+    /// The strategy to use when selecting between the different compatible versions for a specific
+    /// package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=STRATEGY`, where `STRATEGY` is one of
+    /// `highest`, `lowest`, or `lowest-direct` (e.g., `--resolution-package foo=lowest`). Takes
+    /// precedence over `--resolution` for the given package.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub resolution_package: Option<Vec<PackageResolutionModeEntry>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
@@ -6306,6 +6971,45 @@ pub struct ToolUpgradeArgs {
     #[arg(long, hide = true)]
     pub pre: bool,
 
+    /// The strategy to use when considering pre-release versions for a specific package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=STRATEGY`, where `STRATEGY` is one of
+    /// `disallow`, `allow`, `if-necessary`, `explicit`, or `if-necessary-or-explicit` (e.g.,
+    /// `--prerelease-package torch=allow`). Takes precedence over `--prerelease` for the given
+    /// package.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub prerelease_package: Option<Vec<PackagePrereleaseModeEntry>>,
+
+    /// The local version label to prefer for a specific package (e.g., `+cu121`).
+    ///
+    /// Accepts package-label pairs in the format `PACKAGE=+LABEL` (e.g.,
+    /// `--local-version-preference torch=+cu121`). When multiple builds of the selected version
+    /// differ only in their local version label (e.g., `2.3.0+cpu` and `2.3.0+cu121`), the build
+    /// with the given label is preferred.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub local_version_preference: Option<Vec<PackageLocalVersionPreferenceEntry>>,
+
+    /// The policy to apply to versions that are marked as yanked by their index.
+    ///
+    /// By default, uv skips yanked versions unless they're pinned with an exact (`==`) requirement
+    /// or by an existing lockfile, and warns when a pinned yanked version is selected (`warn`).
+    /// When installing from a lockfile, uv also warns if any of the locked distributions have
+    /// since been yanked from their index.
+    ///
+    /// Under `forbid`, yanked versions are never selected, even if pinned, and installing from a
+    /// lockfile fails if any of the locked distributions have since been yanked from their index.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_YANKED,
+        help_heading = "Resolver options"
+    )]
+    pub yanked: Option<YankedPolicy>,
+
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -6316,6 +7020,9 @@ pub struct ToolUpgradeArgs {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `fewest-versions`, uv will additionally prefer a version that's shared by the most
+    /// forks over the version that was previously locked for each fork.
     #[arg(
         long,
         value_enum,
@@ -6324,6 +7031,64 @@ pub struct ToolUpgradeArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The maximum number of source distributions to build to determine their metadata during
+    /// resolution.
+    ///
+    /// Resolving against source distributions that don't publish static metadata requires
+    /// building them, which can be slow, especially if the resolver backtracks through many
+    /// versions. If the limit is exceeded, resolution fails with a list of the source
+    /// distributions that were built.
+    ///
+    /// Only source distributions from package indexes count towards the limit. By default, the
+    /// number of builds is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_MAX_METADATA_BUILDS,
+        value_hint = ValueHint::Other,
+        help_heading = "Resolver options"
+    )]
+    pub max_metadata_builds: Option<usize>,
+
+    /// The maximum number of decisions the resolver may make before giving up.
+    ///
+    /// Some sets of requirements cause the resolver to backtrack through many versions before
+    /// finding a solution (or proving that none exists). If the limit is exceeded, resolution
+    /// fails with a list of the packages that caused the most backtracking, which are good
+    /// candidates for additional constraints.
+    ///
+    /// Progress is logged at the debug level every 1000 decisions. By default, the number of
+    /// decisions is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLVER_MAX_STEPS,
+        value_hint = ValueHint::Other,
+        help_heading = "Resolver options"
+    )]
+    pub resolution_effort: Option<usize>,
+
+    /// Exclude a package from the resolution.
+    ///
+    /// Excluded packages are treated as if they have no available versions, such that any
+    /// requirement on an excluded package (whether direct or transitive) causes resolution to fail
+    /// with the chain of dependencies that required it. Overrides, constraints, and URL
+    /// requirements cannot re-enable an excluded package.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other, help_heading = "Resolver options")]
+    pub exclude: Vec<PackageName>,
+
+    /// Ignore the index-provided metadata for a specific package.
+    ///
+    /// By default, uv trusts the metadata served by the index (e.g., via PEP 658 `.metadata`
+    /// files) when resolving dependencies. For the given packages, uv will instead download the
+    /// distribution itself and read its dependencies from the wheel's `METADATA` file (or build
+    /// the source distribution), which is necessary for packages whose index metadata does not
+    /// match their distributions.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other, help_heading = "Resolver options")]
+    pub no_index_metadata_package: Vec<PackageName>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6341,6 +7106,14 @@ pub struct ToolUpgradeArgs {
     )]
     pub config_setting_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to make visible to the build backend, specified as `KEY` (to pass the
+    /// variable through) or `KEY=VALUE` (to set the variable).
+    ///
+    /// When provided, all other variables are hidden from the build backend, except for those
+    /// set by uv itself and those required for processes to function (e.g., `HOME`).
+    #[arg(long, help_heading = "Build options")]
+    pub build_env: Option<Vec<BuildEnvEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -6367,16 +7140,180 @@ pub struct ToolUpgradeArgs {
     )]
     pub build_isolation: bool,
 
-    /// Limit candidate packages to those that were uploaded prior to the given date.
+    /// The maximum time (in seconds) to wait for a build backend hook to complete.
     ///
-    /// The date is compared against the upload time of each individual distribution artifact
-    /// (i.e., when each file was uploaded to the package index), not the release date of the
-    /// package version.
+    /// If building a source distribution exceeds the timeout, the build backend and any processes
+    /// it spawned are terminated, and the build fails. By default, builds are not subject to a
+    /// timeout.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Build options"
+    )]
+    pub build_timeout: Option<NonZeroU64>,
+
+    /// The number of times to retry a build backend hook that fails with a transient filesystem
+    /// error.
     ///
-    /// Accepts RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`), local dates in the same format
-    /// (e.g., `2006-12-02`) resolved based on your system's configured time zone, a "friendly"
-    /// duration (e.g., `24 hours`, `1 week`, `30 days`), or an ISO 8601 duration (e.g., `PT24H`,
-    /// `P7D`, `P30D`).
+    /// Builds that fail due to, e.g., a file held open by an antivirus scanner (`Access is
+    /// denied`), or a stale NFS file handle, are re-run in a fresh output directory. Other build
+    /// failures are never retried. By default, builds are not retried.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_RETRIES,
+        value_name = "N",
+        help_heading = "Build options"
+    )]
+    pub build_retries: Option<u32>,
+
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`.
+    ///
+    /// By default, packages are built with the interpreter of the target environment, and builds
+    /// fail early if the package's `requires-python` excludes it. When set, such packages are
+    /// instead built with the requested interpreter, e.g., `3.11`. If set to `any`, uv selects
+    /// any installed interpreter that satisfies the package's `requires-python`.
+    ///
+    /// The resulting distribution may not be installable into the target environment; this is
+    /// primarily useful to obtain metadata for packages that can only be built on other Python
+    /// versions.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_PYTHON,
+        value_name = "PYTHON",
+        help_heading = "Build options"
+    )]
+    pub build_python: Option<String>,
+
+    /// Summarize the warnings emitted by build backends during successful builds.
+    ///
+    /// The output of a build backend is discarded when the build succeeds, including warnings about,
+    /// e.g., deprecated configuration or missing license files. When set, lines of build backend
+    /// output that match a `--build-warning-pattern` are collected and reported for each package
+    /// once the installation completes. Warnings don't affect the exit code.
+    ///
+    /// Implied by `--verbose`.
+    #[arg(
+        long,
+        env = EnvVars::UV_SHOW_BUILD_WARNINGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub show_build_warnings: bool,
+
+    /// A pattern that identifies a line of build backend output as a warning, for
+    /// `--show-build-warnings`.
+    ///
+    /// Lines that contain any of the patterns, ignoring case, are reported. Defaults to
+    /// `DeprecationWarning`, `SetuptoolsDeprecationWarning`, and `warning:`.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_WARNING_PATTERN,
+        value_delimiter = ',',
+        value_name = "PATTERN",
+        help_heading = "Build options"
+    )]
+    pub build_warning_pattern: Vec<String>,
+
+    /// Append a structured event to the given file at the start and finish of every source
+    /// build.
+    ///
+    /// Each event is written as a single line of JSON, including the package name and version, the
+    /// build backend, the interpreter, the isolation mode, whether the build environment was
+    /// reused, and, for finished builds, the duration and the kind of failure, if any. Events of
+    /// the same build share a sequence ID, such that overlapping parallel builds can be correlated.
+    /// The file is created if it doesn't exist.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_EVENTS_FILE,
+        value_name = "PATH",
+        help_heading = "Build options"
+    )]
+    pub build_events_file: Option<PathBuf>,
+
+    /// Retain the build backend output of successful builds in the cache.
+    ///
+    /// The output of every build is written to a log in the cache, which is referenced from the error
+    /// when the build fails. By default, the log is removed once the build succeeds. Only the most
+    /// recent logs of each package are retained.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_LOGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub keep_build_logs: bool,
+
+    /// Retain the temporary build environment when a build backend hook fails.
+    ///
+    /// When building a source distribution fails, the virtual environment in which the build
+    /// backend ran is preserved, and its location is included in the error alongside the source
+    /// tree, e.g., to re-run the failing command by hand. Builds that succeed are cleaned up as
+    /// usual. Retained environments are removed by `uv cache clean`.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub keep_build_env: bool,
+
+    /// The directory in which to create temporary build directories.
+    ///
+    /// Source distributions are built, and their temporary build environments are created, in
+    /// scratch directories within this directory, which are removed once each build completes. The
+    /// directory is created if it doesn't exist.
+    ///
+    /// Defaults to the `builds-v0` directory within the cache.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_DIR,
+        value_name = "BUILD_DIR",
+        help_heading = "Build options"
+    )]
+    pub build_dir: Option<PathBuf>,
+
+    /// Build wheels for local and Git sources from a source distribution.
+    ///
+    /// By default, wheels are built directly from the source tree. When set, a source distribution
+    /// is built from the source tree first, and the wheel is built from the extracted source
+    /// distribution instead, such that the wheel only contains the files included in the source
+    /// distribution, as with a package downloaded from an index. Source trees that are already
+    /// source distributions are built directly.
+    ///
+    /// Wheels are always built via a source distribution if the build backend doesn't support
+    /// building wheels directly.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_VIA_SDIST,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub build_via_sdist: bool,
+
+    /// Create a separate build environment for each build.
+    ///
+    /// By default, builds that resolve to the same set of build requirements for the same interpreter
+    /// share a single build environment, rather than each creating and populating their own.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_BUILD_ENVIRONMENT_REUSE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub no_build_environment_reuse: bool,
+
+    /// Limit candidate packages to those that were uploaded prior to the given date.
+    ///
+    /// The date is compared against the upload time of each individual distribution artifact
+    /// (i.e., when each file was uploaded to the package index), not the release date of the
+    /// package version.
+    ///
+    /// Accepts RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`), local dates in the same format
+    /// (e.g., `2006-12-02`) resolved based on your system's configured time zone, a "friendly"
+    /// duration (e.g., `24 hours`, `1 week`, `30 days`), or an ISO 8601 duration (e.g., `PT24H`,
+    /// `P7D`, `P30D`).
     ///
     /// Durations do not respect semantics of the local time zone and are always resolved to a fixed
     /// number of seconds assuming that a day is 24 hours (e.g., DST transitions are ignored).
@@ -6403,6 +7340,29 @@ pub struct ToolUpgradeArgs {
     #[arg(long, help_heading = "Resolver options")]
     pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// Fail if `exclude-newer` can't be applied to a package because its source doesn't provide
+    /// upload times (e.g., a `--find-links` directory).
+    ///
+    /// By default, such packages are included, with a single warning for each source that lists
+    /// the affected packages.
+    ///
+    /// The same applies to `--yanked forbid` when installing from a lockfile, for sources that
+    /// don't provide yank status.
+    #[arg(
+        long,
+        overrides_with("no_strict_exclude_newer"),
+        help_heading = "Resolver options"
+    )]
+    pub strict_exclude_newer: bool,
+
+    #[arg(
+        long,
+        overrides_with("strict_exclude_newer"),
+        hide = true,
+        help_heading = "Resolver options"
+    )]
+    pub no_strict_exclude_newer: bool,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
@@ -6952,6 +7912,13 @@ pub struct PythonFindArgs {
     #[arg(long)]
     pub resolve_links: bool,
 
+    /// Select the output format.
+    ///
+    /// The JSON output includes the interpreter's version, path, and platform, along with the libc
+    /// detected for the interpreter and for the current system.
+    #[arg(long, value_enum, default_value_t = PythonListFormat::default(), conflicts_with = "show_version")]
+    pub output_format: PythonListFormat,
+
     /// URL pointing to JSON of custom Python installations.
     #[arg(long, value_hint = ValueHint::Other)]
     pub python_downloads_json_url: Option<String>,
@@ -6995,6 +7962,23 @@ pub struct PythonPinArgs {
     )]
     pub no_project: bool,
 
+    /// Treat an incompatibility between the Python pin and the project's `requires-python` as an
+    /// error.
+    ///
+    /// By default, uv warns when a request such as `cpython` resolves to an interpreter that is
+    /// incompatible with the `requires-python` of the project or any workspace member, or when an
+    /// existing pin is incompatible.
+    #[arg(long, conflicts_with = "force")]
+    pub strict: bool,
+
+    /// Write the Python pin even if it is incompatible with the project's `requires-python`.
+    ///
+    /// By default, uv refuses to pin a version that is excluded by the `requires-python` of the
+    /// project or any workspace member, or, with `--resolved`, an interpreter whose version is
+    /// excluded. When `--force` is used, uv warns instead.
+    #[arg(long)]
+    pub force: bool,
+
     /// Update the global Python version pin.
     ///
     /// Writes the pinned Python version to a `.python-version` file in the uv user configuration
@@ -7282,6 +8266,29 @@ pub struct RefreshArgs {
     refresh_package: Vec<PackageName>,
 }
 
+/// The platform to build wheels for, for commands that build wheels without installing them.
+#[derive(Args)]
+pub struct BuildTargetArgs {
+    /// The platform to build wheels for, as a wheel platform tag, e.g., `manylinux_2_28_aarch64`,
+    /// `macosx_11_0_arm64`, or `win_arm64`.
+    ///
+    /// By default, wheels are built for the current platform. When set, build backends are
+    /// instructed to build for the target via the conventional `_PYTHON_HOST_PLATFORM`, `ARCHFLAGS`
+    /// (on macOS), and `PLAT` environment variables, and built wheels are cached separately for
+    /// each target. Once a wheel is built, uv verifies that its tags are compatible with the target,
+    /// and fails otherwise.
+    ///
+    /// Cross-compiling packages with native extensions typically requires a cross-compiler, which
+    /// must be configured separately.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_TARGET,
+        value_name = "PLATFORM_TAG",
+        help_heading = "Build options"
+    )]
+    pub build_target: Option<BuildTarget>,
+}
+
 #[derive(Args)]
 pub struct BuildOptionsArgs {
     /// Don't build source distributions.
@@ -7420,6 +8427,14 @@ pub struct InstallerArgs {
     )]
     config_settings_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to make visible to the build backend, specified as `KEY` (to pass the
+    /// variable through) or `KEY=VALUE` (to set the variable).
+    ///
+    /// When provided, all other variables are hidden from the build backend, except for those
+    /// set by uv itself and those required for processes to function (e.g., `HOME`).
+    #[arg(long, help_heading = "Build options")]
+    build_env: Option<Vec<BuildEnvEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -7432,6 +8447,12 @@ pub struct InstallerArgs {
     )]
     no_build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    #[arg(long, help_heading = "Build options", value_hint = ValueHint::Other)]
+    no_build_isolation_package: Vec<PackageName>,
+
     #[arg(
         long,
         overrides_with("no_build_isolation"),
@@ -7440,6 +8461,170 @@ pub struct InstallerArgs {
     )]
     build_isolation: bool,
 
+    /// The maximum time (in seconds) to wait for a build backend hook to complete.
+    ///
+    /// If building a source distribution exceeds the timeout, the build backend and any processes
+    /// it spawned are terminated, and the build fails. By default, builds are not subject to a
+    /// timeout.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Build options"
+    )]
+    build_timeout: Option<NonZeroU64>,
+
+    /// The number of times to retry a build backend hook that fails with a transient filesystem
+    /// error.
+    ///
+    /// Builds that fail due to, e.g., a file held open by an antivirus scanner (`Access is
+    /// denied`), or a stale NFS file handle, are re-run in a fresh output directory. Other build
+    /// failures are never retried. By default, builds are not retried.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_RETRIES,
+        value_name = "N",
+        help_heading = "Build options"
+    )]
+    build_retries: Option<u32>,
+
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`.
+    ///
+    /// By default, packages are built with the interpreter of the target environment, and builds
+    /// fail early if the package's `requires-python` excludes it. When set, such packages are
+    /// instead built with the requested interpreter, e.g., `3.11`. If set to `any`, uv selects
+    /// any installed interpreter that satisfies the package's `requires-python`.
+    ///
+    /// The resulting distribution may not be installable into the target environment; this is
+    /// primarily useful to obtain metadata for packages that can only be built on other Python
+    /// versions.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_PYTHON,
+        value_name = "PYTHON",
+        help_heading = "Build options"
+    )]
+    build_python: Option<String>,
+
+    /// Summarize the warnings emitted by build backends during successful builds.
+    ///
+    /// The output of a build backend is discarded when the build succeeds, including warnings about,
+    /// e.g., deprecated configuration or missing license files. When set, lines of build backend
+    /// output that match a `--build-warning-pattern` are collected and reported for each package
+    /// once the installation completes. Warnings don't affect the exit code.
+    ///
+    /// Implied by `--verbose`.
+    #[arg(
+        long,
+        env = EnvVars::UV_SHOW_BUILD_WARNINGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    show_build_warnings: bool,
+
+    /// A pattern that identifies a line of build backend output as a warning, for
+    /// `--show-build-warnings`.
+    ///
+    /// Lines that contain any of the patterns, ignoring case, are reported. Defaults to
+    /// `DeprecationWarning`, `SetuptoolsDeprecationWarning`, and `warning:`.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_WARNING_PATTERN,
+        value_delimiter = ',',
+        value_name = "PATTERN",
+        help_heading = "Build options"
+    )]
+    build_warning_pattern: Vec<String>,
+
+    /// Append a structured event to the given file at the start and finish of every source
+    /// build.
+    ///
+    /// Each event is written as a single line of JSON, including the package name and version, the
+    /// build backend, the interpreter, the isolation mode, whether the build environment was
+    /// reused, and, for finished builds, the duration and the kind of failure, if any. Events of
+    /// the same build share a sequence ID, such that overlapping parallel builds can be correlated.
+    /// The file is created if it doesn't exist.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_EVENTS_FILE,
+        value_name = "PATH",
+        help_heading = "Build options"
+    )]
+    build_events_file: Option<PathBuf>,
+
+    /// Retain the build backend output of successful builds in the cache.
+    ///
+    /// The output of every build is written to a log in the cache, which is referenced from the error
+    /// when the build fails. By default, the log is removed once the build succeeds. Only the most
+    /// recent logs of each package are retained.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_LOGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    keep_build_logs: bool,
+
+    /// Retain the temporary build environment when a build backend hook fails.
+    ///
+    /// When building a source distribution fails, the virtual environment in which the build
+    /// backend ran is preserved, and its location is included in the error alongside the source
+    /// tree, e.g., to re-run the failing command by hand. Builds that succeed are cleaned up as
+    /// usual. Retained environments are removed by `uv cache clean`.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    keep_build_env: bool,
+
+    /// The directory in which to create temporary build directories.
+    ///
+    /// Source distributions are built, and their temporary build environments are created, in
+    /// scratch directories within this directory, which are removed once each build completes. The
+    /// directory is created if it doesn't exist.
+    ///
+    /// Defaults to the `builds-v0` directory within the cache.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_DIR,
+        value_name = "BUILD_DIR",
+        help_heading = "Build options"
+    )]
+    build_dir: Option<PathBuf>,
+
+    /// Build wheels for local and Git sources from a source distribution.
+    ///
+    /// By default, wheels are built directly from the source tree. When set, a source distribution
+    /// is built from the source tree first, and the wheel is built from the extracted source
+    /// distribution instead, such that the wheel only contains the files included in the source
+    /// distribution, as with a package downloaded from an index. Source trees that are already
+    /// source distributions are built directly.
+    ///
+    /// Wheels are always built via a source distribution if the build backend doesn't support
+    /// building wheels directly.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_VIA_SDIST,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    build_via_sdist: bool,
+
+    /// Create a separate build environment for each build.
+    ///
+    /// By default, builds that resolve to the same set of build requirements for the same interpreter
+    /// share a single build environment, rather than each creating and populating their own.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_BUILD_ENVIRONMENT_REUSE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    no_build_environment_reuse: bool,
+
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// The date is compared against the upload time of each individual distribution artifact
@@ -7476,6 +8661,20 @@ pub struct InstallerArgs {
     #[arg(long, help_heading = "Resolver options")]
     exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// The policy to apply to versions that are marked as yanked by their index.
+    ///
+    /// By default, uv warns when a yanked version is installed (`warn`), including when a
+    /// distribution in a `pylock.toml` has since been yanked from its index.
+    ///
+    /// Under `forbid`, installing a yanked version fails.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_YANKED,
+        help_heading = "Resolver options"
+    )]
+    yanked: Option<YankedPolicy>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
@@ -7612,6 +8811,17 @@ pub struct ResolverArgs {
     )]
     resolution: Option<ResolutionMode>,
 
+    /// The strategy to use when selecting between the different compatible versions for a specific
+    /// package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=STRATEGY`, where `STRATEGY` is one of
+    /// `highest`, `lowest`, or `lowest-direct` (e.g., `--resolution-package foo=lowest`). Takes
+    /// precedence over `--resolution` for the given package.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    resolution_package: Option<Vec<PackageResolutionModeEntry>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
@@ -7628,6 +8838,45 @@ pub struct ResolverArgs {
     #[arg(long, hide = true, help_heading = "Resolver options")]
     pre: bool,
 
+    /// The strategy to use when considering pre-release versions for a specific package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=STRATEGY`, where `STRATEGY` is one of
+    /// `disallow`, `allow`, `if-necessary`, `explicit`, or `if-necessary-or-explicit` (e.g.,
+    /// `--prerelease-package torch=allow`). Takes precedence over `--prerelease` for the given
+    /// package.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    prerelease_package: Option<Vec<PackagePrereleaseModeEntry>>,
+
+    /// The local version label to prefer for a specific package (e.g., `+cu121`).
+    ///
+    /// Accepts package-label pairs in the format `PACKAGE=+LABEL` (e.g.,
+    /// `--local-version-preference torch=+cu121`). When multiple builds of the selected version
+    /// differ only in their local version label (e.g., `2.3.0+cpu` and `2.3.0+cu121`), the build
+    /// with the given label is preferred.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    local_version_preference: Option<Vec<PackageLocalVersionPreferenceEntry>>,
+
+    /// The policy to apply to versions that are marked as yanked by their index.
+    ///
+    /// By default, uv skips yanked versions unless they're pinned with an exact (`==`) requirement
+    /// or by an existing lockfile, and warns when a pinned yanked version is selected (`warn`).
+    /// When installing from a lockfile, uv also warns if any of the locked distributions have
+    /// since been yanked from their index.
+    ///
+    /// Under `forbid`, yanked versions are never selected, even if pinned, and installing from a
+    /// lockfile fails if any of the locked distributions have since been yanked from their index.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_YANKED,
+        help_heading = "Resolver options"
+    )]
+    yanked: Option<YankedPolicy>,
+
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -7638,6 +8887,9 @@ pub struct ResolverArgs {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `fewest-versions`, uv will additionally prefer a version that's shared by the most
+    /// forks over the version that was previously locked for each fork.
     #[arg(
         long,
         value_enum,
@@ -7646,6 +8898,64 @@ pub struct ResolverArgs {
     )]
     fork_strategy: Option<ForkStrategy>,
 
+    /// The maximum number of source distributions to build to determine their metadata during
+    /// resolution.
+    ///
+    /// Resolving against source distributions that don't publish static metadata requires
+    /// building them, which can be slow, especially if the resolver backtracks through many
+    /// versions. If the limit is exceeded, resolution fails with a list of the source
+    /// distributions that were built.
+    ///
+    /// Only source distributions from package indexes count towards the limit. By default, the
+    /// number of builds is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_MAX_METADATA_BUILDS,
+        value_hint = ValueHint::Other,
+        help_heading = "Resolver options"
+    )]
+    max_metadata_builds: Option<usize>,
+
+    /// The maximum number of decisions the resolver may make before giving up.
+    ///
+    /// Some sets of requirements cause the resolver to backtrack through many versions before
+    /// finding a solution (or proving that none exists). If the limit is exceeded, resolution
+    /// fails with a list of the packages that caused the most backtracking, which are good
+    /// candidates for additional constraints.
+    ///
+    /// Progress is logged at the debug level every 1000 decisions. By default, the number of
+    /// decisions is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLVER_MAX_STEPS,
+        value_hint = ValueHint::Other,
+        help_heading = "Resolver options"
+    )]
+    resolution_effort: Option<usize>,
+
+    /// Exclude a package from the resolution.
+    ///
+    /// Excluded packages are treated as if they have no available versions, such that any
+    /// requirement on an excluded package (whether direct or transitive) causes resolution to fail
+    /// with the chain of dependencies that required it. Overrides, constraints, and URL
+    /// requirements cannot re-enable an excluded package.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other, help_heading = "Resolver options")]
+    exclude: Vec<PackageName>,
+
+    /// Ignore the index-provided metadata for a specific package.
+    ///
+    /// By default, uv trusts the metadata served by the index (e.g., via PEP 658 `.metadata`
+    /// files) when resolving dependencies. For the given packages, uv will instead download the
+    /// distribution itself and read its dependencies from the wheel's `METADATA` file (or build
+    /// the source distribution), which is necessary for packages whose index metadata does not
+    /// match their distributions.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other, help_heading = "Resolver options")]
+    no_index_metadata_package: Vec<PackageName>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -7663,6 +8973,14 @@ pub struct ResolverArgs {
     )]
     config_settings_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to make visible to the build backend, specified as `KEY` (to pass the
+    /// variable through) or `KEY=VALUE` (to set the variable).
+    ///
+    /// When provided, all other variables are hidden from the build backend, except for those
+    /// set by uv itself and those required for processes to function (e.g., `HOME`).
+    #[arg(long, help_heading = "Build options")]
+    build_env: Option<Vec<BuildEnvEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -7689,6 +9007,170 @@ pub struct ResolverArgs {
     )]
     build_isolation: bool,
 
+    /// The maximum time (in seconds) to wait for a build backend hook to complete.
+    ///
+    /// If building a source distribution exceeds the timeout, the build backend and any processes
+    /// it spawned are terminated, and the build fails. By default, builds are not subject to a
+    /// timeout.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Build options"
+    )]
+    build_timeout: Option<NonZeroU64>,
+
+    /// The number of times to retry a build backend hook that fails with a transient filesystem
+    /// error.
+    ///
+    /// Builds that fail due to, e.g., a file held open by an antivirus scanner (`Access is
+    /// denied`), or a stale NFS file handle, are re-run in a fresh output directory. Other build
+    /// failures are never retried. By default, builds are not retried.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_RETRIES,
+        value_name = "N",
+        help_heading = "Build options"
+    )]
+    build_retries: Option<u32>,
+
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`.
+    ///
+    /// By default, packages are built with the interpreter of the target environment, and builds
+    /// fail early if the package's `requires-python` excludes it. When set, such packages are
+    /// instead built with the requested interpreter, e.g., `3.11`. If set to `any`, uv selects
+    /// any installed interpreter that satisfies the package's `requires-python`.
+    ///
+    /// The resulting distribution may not be installable into the target environment; this is
+    /// primarily useful to obtain metadata for packages that can only be built on other Python
+    /// versions.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_PYTHON,
+        value_name = "PYTHON",
+        help_heading = "Build options"
+    )]
+    build_python: Option<String>,
+
+    /// Summarize the warnings emitted by build backends during successful builds.
+    ///
+    /// The output of a build backend is discarded when the build succeeds, including warnings about,
+    /// e.g., deprecated configuration or missing license files. When set, lines of build backend
+    /// output that match a `--build-warning-pattern` are collected and reported for each package
+    /// once the installation completes. Warnings don't affect the exit code.
+    ///
+    /// Implied by `--verbose`.
+    #[arg(
+        long,
+        env = EnvVars::UV_SHOW_BUILD_WARNINGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    show_build_warnings: bool,
+
+    /// A pattern that identifies a line of build backend output as a warning, for
+    /// `--show-build-warnings`.
+    ///
+    /// Lines that contain any of the patterns, ignoring case, are reported. Defaults to
+    /// `DeprecationWarning`, `SetuptoolsDeprecationWarning`, and `warning:`.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_WARNING_PATTERN,
+        value_delimiter = ',',
+        value_name = "PATTERN",
+        help_heading = "Build options"
+    )]
+    build_warning_pattern: Vec<String>,
+
+    /// Append a structured event to the given file at the start and finish of every source
+    /// build.
+    ///
+    /// Each event is written as a single line of JSON, including the package name and version, the
+    /// build backend, the interpreter, the isolation mode, whether the build environment was
+    /// reused, and, for finished builds, the duration and the kind of failure, if any. Events of
+    /// the same build share a sequence ID, such that overlapping parallel builds can be correlated.
+    /// The file is created if it doesn't exist.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_EVENTS_FILE,
+        value_name = "PATH",
+        help_heading = "Build options"
+    )]
+    build_events_file: Option<PathBuf>,
+
+    /// Retain the build backend output of successful builds in the cache.
+    ///
+    /// The output of every build is written to a log in the cache, which is referenced from the error
+    /// when the build fails. By default, the log is removed once the build succeeds. Only the most
+    /// recent logs of each package are retained.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_LOGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    keep_build_logs: bool,
+
+    /// Retain the temporary build environment when a build backend hook fails.
+    ///
+    /// When building a source distribution fails, the virtual environment in which the build
+    /// backend ran is preserved, and its location is included in the error alongside the source
+    /// tree, e.g., to re-run the failing command by hand. Builds that succeed are cleaned up as
+    /// usual. Retained environments are removed by `uv cache clean`.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    keep_build_env: bool,
+
+    /// The directory in which to create temporary build directories.
+    ///
+    /// Source distributions are built, and their temporary build environments are created, in
+    /// scratch directories within this directory, which are removed once each build completes. The
+    /// directory is created if it doesn't exist.
+    ///
+    /// Defaults to the `builds-v0` directory within the cache.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_DIR,
+        value_name = "BUILD_DIR",
+        help_heading = "Build options"
+    )]
+    build_dir: Option<PathBuf>,
+
+    /// Build wheels for local and Git sources from a source distribution.
+    ///
+    /// By default, wheels are built directly from the source tree. When set, a source distribution
+    /// is built from the source tree first, and the wheel is built from the extracted source
+    /// distribution instead, such that the wheel only contains the files included in the source
+    /// distribution, as with a package downloaded from an index. Source trees that are already
+    /// source distributions are built directly.
+    ///
+    /// Wheels are always built via a source distribution if the build backend doesn't support
+    /// building wheels directly.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_VIA_SDIST,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    build_via_sdist: bool,
+
+    /// Create a separate build environment for each build.
+    ///
+    /// By default, builds that resolve to the same set of build requirements for the same interpreter
+    /// share a single build environment, rather than each creating and populating their own.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_BUILD_ENVIRONMENT_REUSE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    no_build_environment_reuse: bool,
+
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// The date is compared against the upload time of each individual distribution artifact
@@ -7725,6 +9207,29 @@ pub struct ResolverArgs {
     #[arg(long, help_heading = "Resolver options")]
     exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// Fail if `exclude-newer` can't be applied to a package because its source doesn't provide
+    /// upload times (e.g., a `--find-links` directory).
+    ///
+    /// By default, such packages are included, with a single warning for each source that lists
+    /// the affected packages.
+    ///
+    /// The same applies to `--yanked forbid` when installing from a lockfile, for sources that
+    /// don't provide yank status.
+    #[arg(
+        long,
+        overrides_with("no_strict_exclude_newer"),
+        help_heading = "Resolver options"
+    )]
+    strict_exclude_newer: bool,
+
+    #[arg(
+        long,
+        overrides_with("strict_exclude_newer"),
+        hide = true,
+        help_heading = "Resolver options"
+    )]
+    no_strict_exclude_newer: bool,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// This option is only used when building source distributions.
@@ -7857,6 +9362,17 @@ pub struct ResolverInstallerArgs {
     )]
     pub resolution: Option<ResolutionMode>,
 
+    /// The strategy to use when selecting between the different compatible versions for a specific
+    /// package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=STRATEGY`, where `STRATEGY` is one of
+    /// `highest`, `lowest`, or `lowest-direct` (e.g., `--resolution-package foo=lowest`). Takes
+    /// precedence over `--resolution` for the given package.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub resolution_package: Option<Vec<PackageResolutionModeEntry>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases, along
@@ -7873,6 +9389,45 @@ pub struct ResolverInstallerArgs {
     #[arg(long, hide = true)]
     pub pre: bool,
 
+    /// The strategy to use when considering pre-release versions for a specific package.
+    ///
+    /// Accepts package-strategy pairs in the format `PACKAGE=STRATEGY`, where `STRATEGY` is one of
+    /// `disallow`, `allow`, `if-necessary`, `explicit`, or `if-necessary-or-explicit` (e.g.,
+    /// `--prerelease-package torch=allow`). Takes precedence over `--prerelease` for the given
+    /// package.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub prerelease_package: Option<Vec<PackagePrereleaseModeEntry>>,
+
+    /// The local version label to prefer for a specific package (e.g., `+cu121`).
+    ///
+    /// Accepts package-label pairs in the format `PACKAGE=+LABEL` (e.g.,
+    /// `--local-version-preference torch=+cu121`). When multiple builds of the selected version
+    /// differ only in their local version label (e.g., `2.3.0+cpu` and `2.3.0+cu121`), the build
+    /// with the given label is preferred.
+    ///
+    /// Can be provided multiple times for different packages.
+    #[arg(long, help_heading = "Resolver options")]
+    pub local_version_preference: Option<Vec<PackageLocalVersionPreferenceEntry>>,
+
+    /// The policy to apply to versions that are marked as yanked by their index.
+    ///
+    /// By default, uv skips yanked versions unless they're pinned with an exact (`==`) requirement
+    /// or by an existing lockfile, and warns when a pinned yanked version is selected (`warn`).
+    /// When installing from a lockfile, uv also warns if any of the locked distributions have
+    /// since been yanked from their index.
+    ///
+    /// Under `forbid`, yanked versions are never selected, even if pinned, and installing from a
+    /// lockfile fails if any of the locked distributions have since been yanked from their index.
+    #[arg(
+        long,
+        value_enum,
+        env = EnvVars::UV_YANKED,
+        help_heading = "Resolver options"
+    )]
+    pub yanked: Option<YankedPolicy>,
+
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -7883,6 +9438,9 @@ pub struct ResolverInstallerArgs {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `fewest-versions`, uv will additionally prefer a version that's shared by the most
+    /// forks over the version that was previously locked for each fork.
     #[arg(
         long,
         value_enum,
@@ -7891,6 +9449,64 @@ pub struct ResolverInstallerArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// The maximum number of source distributions to build to determine their metadata during
+    /// resolution.
+    ///
+    /// Resolving against source distributions that don't publish static metadata requires
+    /// building them, which can be slow, especially if the resolver backtracks through many
+    /// versions. If the limit is exceeded, resolution fails with a list of the source
+    /// distributions that were built.
+    ///
+    /// Only source distributions from package indexes count towards the limit. By default, the
+    /// number of builds is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_MAX_METADATA_BUILDS,
+        value_hint = ValueHint::Other,
+        help_heading = "Resolver options"
+    )]
+    pub max_metadata_builds: Option<usize>,
+
+    /// The maximum number of decisions the resolver may make before giving up.
+    ///
+    /// Some sets of requirements cause the resolver to backtrack through many versions before
+    /// finding a solution (or proving that none exists). If the limit is exceeded, resolution
+    /// fails with a list of the packages that caused the most backtracking, which are good
+    /// candidates for additional constraints.
+    ///
+    /// Progress is logged at the debug level every 1000 decisions. By default, the number of
+    /// decisions is unlimited.
+    #[arg(
+        long,
+        env = EnvVars::UV_RESOLVER_MAX_STEPS,
+        value_hint = ValueHint::Other,
+        help_heading = "Resolver options"
+    )]
+    pub resolution_effort: Option<usize>,
+
+    /// Exclude a package from the resolution.
+    ///
+    /// Excluded packages are treated as if they have no available versions, such that any
+    /// requirement on an excluded package (whether direct or transitive) causes resolution to fail
+    /// with the chain of dependencies that required it. Overrides, constraints, and URL
+    /// requirements cannot re-enable an excluded package.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other, help_heading = "Resolver options")]
+    pub exclude: Vec<PackageName>,
+
+    /// Ignore the index-provided metadata for a specific package.
+    ///
+    /// By default, uv trusts the metadata served by the index (e.g., via PEP 658 `.metadata`
+    /// files) when resolving dependencies. For the given packages, uv will instead download the
+    /// distribution itself and read its dependencies from the wheel's `METADATA` file (or build
+    /// the source distribution), which is necessary for packages whose index metadata does not
+    /// match their distributions.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other, help_heading = "Resolver options")]
+    pub no_index_metadata_package: Vec<PackageName>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -7910,6 +9526,18 @@ pub struct ResolverInstallerArgs {
     )]
     pub config_settings_package: Option<Vec<ConfigSettingPackageEntry>>,
 
+    /// Environment variables to make visible to the build backend, specified as `KEY` (to pass the
+    /// variable through) or `KEY=VALUE` (to set the variable).
+    ///
+    /// When provided, all other variables are hidden from the build backend, except for those
+    /// set by uv itself and those required for processes to function (e.g., `HOME`).
+    #[arg(
+        long,
+        help_heading = "Build options",
+        value_hint = ValueHint::Other,
+    )]
+    pub build_env: Option<Vec<BuildEnvEntry>>,
+
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by PEP 518 are already installed.
@@ -7936,6 +9564,170 @@ pub struct ResolverInstallerArgs {
     )]
     pub build_isolation: bool,
 
+    /// The maximum time (in seconds) to wait for a build backend hook to complete.
+    ///
+    /// If building a source distribution exceeds the timeout, the build backend and any processes
+    /// it spawned are terminated, and the build fails. By default, builds are not subject to a
+    /// timeout.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_TIMEOUT,
+        value_name = "SECONDS",
+        help_heading = "Build options"
+    )]
+    pub build_timeout: Option<NonZeroU64>,
+
+    /// The number of times to retry a build backend hook that fails with a transient filesystem
+    /// error.
+    ///
+    /// Builds that fail due to, e.g., a file held open by an antivirus scanner (`Access is
+    /// denied`), or a stale NFS file handle, are re-run in a fresh output directory. Other build
+    /// failures are never retried. By default, builds are not retried.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_RETRIES,
+        value_name = "N",
+        help_heading = "Build options"
+    )]
+    pub build_retries: Option<u32>,
+
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`.
+    ///
+    /// By default, packages are built with the interpreter of the target environment, and builds
+    /// fail early if the package's `requires-python` excludes it. When set, such packages are
+    /// instead built with the requested interpreter, e.g., `3.11`. If set to `any`, uv selects
+    /// any installed interpreter that satisfies the package's `requires-python`.
+    ///
+    /// The resulting distribution may not be installable into the target environment; this is
+    /// primarily useful to obtain metadata for packages that can only be built on other Python
+    /// versions.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_PYTHON,
+        value_name = "PYTHON",
+        help_heading = "Build options"
+    )]
+    pub build_python: Option<String>,
+
+    /// Summarize the warnings emitted by build backends during successful builds.
+    ///
+    /// The output of a build backend is discarded when the build succeeds, including warnings about,
+    /// e.g., deprecated configuration or missing license files. When set, lines of build backend
+    /// output that match a `--build-warning-pattern` are collected and reported for each package
+    /// once the installation completes. Warnings don't affect the exit code.
+    ///
+    /// Implied by `--verbose`.
+    #[arg(
+        long,
+        env = EnvVars::UV_SHOW_BUILD_WARNINGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub show_build_warnings: bool,
+
+    /// A pattern that identifies a line of build backend output as a warning, for
+    /// `--show-build-warnings`.
+    ///
+    /// Lines that contain any of the patterns, ignoring case, are reported. Defaults to
+    /// `DeprecationWarning`, `SetuptoolsDeprecationWarning`, and `warning:`.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_WARNING_PATTERN,
+        value_delimiter = ',',
+        value_name = "PATTERN",
+        help_heading = "Build options"
+    )]
+    pub build_warning_pattern: Vec<String>,
+
+    /// Append a structured event to the given file at the start and finish of every source
+    /// build.
+    ///
+    /// Each event is written as a single line of JSON, including the package name and version, the
+    /// build backend, the interpreter, the isolation mode, whether the build environment was
+    /// reused, and, for finished builds, the duration and the kind of failure, if any. Events of
+    /// the same build share a sequence ID, such that overlapping parallel builds can be correlated.
+    /// The file is created if it doesn't exist.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_EVENTS_FILE,
+        value_name = "PATH",
+        help_heading = "Build options"
+    )]
+    pub build_events_file: Option<PathBuf>,
+
+    /// Retain the build backend output of successful builds in the cache.
+    ///
+    /// The output of every build is written to a log in the cache, which is referenced from the error
+    /// when the build fails. By default, the log is removed once the build succeeds. Only the most
+    /// recent logs of each package are retained.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_LOGS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub keep_build_logs: bool,
+
+    /// Retain the temporary build environment when a build backend hook fails.
+    ///
+    /// When building a source distribution fails, the virtual environment in which the build
+    /// backend ran is preserved, and its location is included in the error alongside the source
+    /// tree, e.g., to re-run the failing command by hand. Builds that succeed are cleaned up as
+    /// usual. Retained environments are removed by `uv cache clean`.
+    #[arg(
+        long,
+        env = EnvVars::UV_KEEP_BUILD_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub keep_build_env: bool,
+
+    /// The directory in which to create temporary build directories.
+    ///
+    /// Source distributions are built, and their temporary build environments are created, in
+    /// scratch directories within this directory, which are removed once each build completes. The
+    /// directory is created if it doesn't exist.
+    ///
+    /// Defaults to the `builds-v0` directory within the cache.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_DIR,
+        value_name = "BUILD_DIR",
+        help_heading = "Build options"
+    )]
+    pub build_dir: Option<PathBuf>,
+
+    /// Build wheels for local and Git sources from a source distribution.
+    ///
+    /// By default, wheels are built directly from the source tree. When set, a source distribution
+    /// is built from the source tree first, and the wheel is built from the extracted source
+    /// distribution instead, such that the wheel only contains the files included in the source
+    /// distribution, as with a package downloaded from an index. Source trees that are already
+    /// source distributions are built directly.
+    ///
+    /// Wheels are always built via a source distribution if the build backend doesn't support
+    /// building wheels directly.
+    #[arg(
+        long,
+        env = EnvVars::UV_BUILD_VIA_SDIST,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub build_via_sdist: bool,
+
+    /// Create a separate build environment for each build.
+    ///
+    /// By default, builds that resolve to the same set of build requirements for the same interpreter
+    /// share a single build environment, rather than each creating and populating their own.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_BUILD_ENVIRONMENT_REUSE,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub no_build_environment_reuse: bool,
+
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// The date is compared against the upload time of each individual distribution artifact
@@ -7977,6 +9769,29 @@ pub struct ResolverInstallerArgs {
     #[arg(long, help_heading = "Resolver options", value_hint = ValueHint::Other)]
     pub exclude_newer_package: Option<Vec<ExcludeNewerPackageEntry>>,
 
+    /// Fail if `exclude-newer` can't be applied to a package because its source doesn't provide
+    /// upload times (e.g., a `--find-links` directory).
+    ///
+    /// By default, such packages are included, with a single warning for each source that lists
+    /// the affected packages.
+    ///
+    /// The same applies to `--yanked forbid` when installing from a lockfile, for sources that
+    /// don't provide yank status.
+    #[arg(
+        long,
+        overrides_with("no_strict_exclude_newer"),
+        help_heading = "Resolver options"
+    )]
+    pub strict_exclude_newer: bool,
+
+    #[arg(
+        long,
+        overrides_with("strict_exclude_newer"),
+        hide = true,
+        help_heading = "Resolver options"
+    )]
+    pub no_strict_exclude_newer: bool,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
@@ -5,7 +5,10 @@ use anstream::eprintln;
 use uv_cache::Refresh;
 use uv_configuration::{BuildIsolation, Reinstall, Upgrade};
 use uv_distribution_types::{ConfigSettings, Index, PackageConfigSettings, Requirement};
-use uv_resolver::{ExcludeNewerPackage, PrereleaseMode};
+use uv_resolver::{
+    ExcludeNewerPackage, PackageLocalVersionPreferences, PackagePrereleaseModes,
+    PackageResolutionModes, PrereleaseMode,
+};
 use uv_settings::{Combine, EnvFlag, PipOptions, ResolverInstallerOptions, ResolverOptions};
 use uv_warnings::owo_colors::OwoColorize;
 
@@ -256,19 +259,41 @@ impl From<ResolverArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
             pre,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            exclude,
+            no_index_metadata_package,
             config_setting,
             config_settings_package,
+            build_env,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             link_mode,
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            strict_exclude_newer,
+            no_strict_exclude_newer,
         } = args;
 
         if !upgrade_group.is_empty() {
@@ -287,7 +312,24 @@ impl From<ResolverArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package: resolution_package.map(PackageResolutionModes::from_iter),
+            prerelease_package: prerelease_package.map(PackagePrereleaseModes::from_iter),
+            local_version_preference: local_version_preference
+                .map(PackageLocalVersionPreferences::from_iter),
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            excluded_packages: if exclude.is_empty() {
+                None
+            } else {
+                Some(exclude)
+            },
+            reresolve_metadata: if no_index_metadata_package.is_empty() {
+                None
+            } else {
+                Some(no_index_metadata_package)
+            },
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
@@ -300,10 +342,27 @@ impl From<ResolverArgs> for PipOptions {
                     .into_iter()
                     .collect::<PackageConfigSettings>()
             }),
+            build_env,
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
             no_build_isolation_package: Some(no_build_isolation_package),
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings: show_build_warnings.then_some(true),
+            build_warning_pattern: Some(build_warning_pattern),
+            build_events_file,
+            keep_build_logs: keep_build_logs.then_some(true),
+            keep_build_env: keep_build_env.then_some(true),
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+            strict_exclude_newer: flag(
+                strict_exclude_newer,
+                no_strict_exclude_newer,
+                "strict-exclude-newer",
+            ),
             link_mode,
             no_sources: if no_sources { Some(true) } else { None },
             no_sources_package: if no_sources_package.is_empty() {
@@ -327,8 +386,21 @@ impl From<InstallerArgs> for PipOptions {
             keyring_provider,
             config_setting,
             config_settings_package,
+            build_env,
             no_build_isolation,
+            no_build_isolation_package,
             build_isolation,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             link_mode,
             compile_bytecode,
@@ -336,6 +408,7 @@ impl From<InstallerArgs> for PipOptions {
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            yanked,
         } = args;
 
         Self {
@@ -350,9 +423,23 @@ impl From<InstallerArgs> for PipOptions {
                     .into_iter()
                     .collect::<PackageConfigSettings>()
             }),
+            build_env,
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
+            no_build_isolation_package: Some(no_build_isolation_package),
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings: show_build_warnings.then_some(true),
+            build_warning_pattern: Some(build_warning_pattern),
+            build_events_file,
+            keep_build_logs: keep_build_logs.then_some(true),
+            keep_build_env: keep_build_env.then_some(true),
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+            yanked,
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
             no_sources: if no_sources { Some(true) } else { None },
@@ -380,14 +467,34 @@ impl From<ResolverInstallerArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
             pre,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            exclude,
+            no_index_metadata_package,
             config_setting,
             config_settings_package,
+            build_env,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             link_mode,
             compile_bytecode,
@@ -395,6 +502,8 @@ impl From<ResolverInstallerArgs> for PipOptions {
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            strict_exclude_newer,
+            no_strict_exclude_newer,
         } = args;
 
         if !upgrade_group.is_empty() {
@@ -415,12 +524,29 @@ impl From<ResolverInstallerArgs> for PipOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package: resolution_package.map(PackageResolutionModes::from_iter),
+            prerelease_package: prerelease_package.map(PackagePrereleaseModes::from_iter),
+            local_version_preference: local_version_preference
+                .map(PackageLocalVersionPreferences::from_iter),
+            yanked,
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
                 prerelease
             },
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            excluded_packages: if exclude.is_empty() {
+                None
+            } else {
+                Some(exclude)
+            },
+            reresolve_metadata: if no_index_metadata_package.is_empty() {
+                None
+            } else {
+                Some(no_index_metadata_package)
+            },
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             config_settings_package: config_settings_package.map(|config_settings| {
@@ -428,10 +554,27 @@ impl From<ResolverInstallerArgs> for PipOptions {
                     .into_iter()
                     .collect::<PackageConfigSettings>()
             }),
+            build_env,
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
             no_build_isolation_package: Some(no_build_isolation_package),
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings: show_build_warnings.then_some(true),
+            build_warning_pattern: Some(build_warning_pattern),
+            build_events_file,
+            keep_build_logs: keep_build_logs.then_some(true),
+            keep_build_env: keep_build_env.then_some(true),
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+            strict_exclude_newer: flag(
+                strict_exclude_newer,
+                no_strict_exclude_newer,
+                "strict-exclude-newer",
+            ),
             link_mode,
             compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
             no_sources: if no_sources { Some(true) } else { None },
@@ -509,19 +652,41 @@ pub fn resolver_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
         pre,
+        prerelease_package,
+        local_version_preference,
+        yanked,
         fork_strategy,
+        max_metadata_builds,
+        resolution_effort,
+        exclude,
+        no_index_metadata_package,
         config_setting,
         config_settings_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
+        build_timeout,
+        build_retries,
+        build_python,
+        show_build_warnings,
+        build_warning_pattern,
+        build_events_file,
+        keep_build_logs,
+        keep_build_env,
+        build_dir,
+        build_via_sdist,
+        no_build_environment_reuse,
         exclude_newer,
         link_mode,
         no_sources,
         no_sources_package,
         exclude_newer_package,
+        strict_exclude_newer,
+        no_strict_exclude_newer,
     } = resolver_args;
 
     let BuildOptionsArgs {
@@ -564,12 +729,29 @@ pub fn resolver_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package: resolution_package.map(PackageResolutionModes::from_iter),
+        prerelease_package: prerelease_package.map(PackagePrereleaseModes::from_iter),
+        local_version_preference: local_version_preference
+            .map(PackageLocalVersionPreferences::from_iter),
+        yanked,
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
             prerelease
         },
         fork_strategy,
+        max_metadata_builds,
+        resolution_effort,
+        excluded_packages: if exclude.is_empty() {
+            None
+        } else {
+            Some(exclude)
+        },
+        reresolve_metadata: if no_index_metadata_package.is_empty() {
+            None
+        } else {
+            Some(no_index_metadata_package)
+        },
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -578,14 +760,31 @@ pub fn resolver_options(
                 .into_iter()
                 .collect::<PackageConfigSettings>()
         }),
+        build_env,
         build_isolation: BuildIsolation::from_args(
             flag(no_build_isolation, build_isolation, "build-isolation"),
             no_build_isolation_package,
         ),
+        build_timeout,
+        build_retries,
+        build_python,
+        show_build_warnings: show_build_warnings.then_some(true),
+        build_warning_pattern: Some(build_warning_pattern),
+        build_events_file,
+        keep_build_logs: keep_build_logs.then_some(true),
+        keep_build_env: keep_build_env.then_some(true),
+        build_dir,
+        build_via_sdist,
+        no_build_environment_reuse,
         extra_build_dependencies: None,
         extra_build_variables: None,
         exclude_newer,
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+        strict_exclude_newer: flag(
+            strict_exclude_newer,
+            no_strict_exclude_newer,
+            "strict-exclude-newer",
+        ),
         link_mode,
         torch_backend: None,
         no_build: flag(no_build, build, "build"),
@@ -639,16 +838,38 @@ pub fn resolver_installer_options_with_indexes(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
         pre,
+        prerelease_package,
+        local_version_preference,
+        yanked,
         fork_strategy,
+        max_metadata_builds,
+        resolution_effort,
+        exclude,
+        no_index_metadata_package,
         config_setting,
         config_settings_package,
+        build_env,
         no_build_isolation,
         no_build_isolation_package,
         build_isolation,
+        build_timeout,
+        build_retries,
+        build_python,
+        show_build_warnings,
+        build_warning_pattern,
+        build_events_file,
+        keep_build_logs,
+        keep_build_env,
+        build_dir,
+        build_via_sdist,
+        no_build_environment_reuse,
         exclude_newer,
         exclude_newer_package,
+        strict_exclude_newer,
+        no_strict_exclude_newer,
         link_mode,
         compile_bytecode,
         no_compile_bytecode,
@@ -697,12 +918,29 @@ pub fn resolver_installer_options_with_indexes(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package: resolution_package.map(PackageResolutionModes::from_iter),
+        prerelease_package: prerelease_package.map(PackagePrereleaseModes::from_iter),
+        local_version_preference: local_version_preference
+            .map(PackageLocalVersionPreferences::from_iter),
+        yanked,
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
             prerelease
         },
         fork_strategy,
+        max_metadata_builds,
+        resolution_effort,
+        excluded_packages: if exclude.is_empty() {
+            None
+        } else {
+            Some(exclude)
+        },
+        reresolve_metadata: if no_index_metadata_package.is_empty() {
+            None
+        } else {
+            Some(no_index_metadata_package)
+        },
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -711,14 +949,31 @@ pub fn resolver_installer_options_with_indexes(
                 .into_iter()
                 .collect::<PackageConfigSettings>()
         }),
+        build_env,
         build_isolation: BuildIsolation::from_args(
             flag(no_build_isolation, build_isolation, "build-isolation"),
             no_build_isolation_package,
         ),
+        build_timeout,
+        build_retries,
+        build_python,
+        show_build_warnings: show_build_warnings.then_some(true),
+        build_warning_pattern: Some(build_warning_pattern),
+        build_events_file,
+        keep_build_logs: keep_build_logs.then_some(true),
+        keep_build_env: keep_build_env.then_some(true),
+        build_dir,
+        build_via_sdist,
+        no_build_environment_reuse,
         extra_build_dependencies: None,
         extra_build_variables: None,
         exclude_newer,
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
+        strict_exclude_newer: flag(
+            strict_exclude_newer,
+            no_strict_exclude_newer,
+            "strict-exclude-newer",
+        ),
         link_mode,
         compile_bytecode: flag(compile_bytecode, no_compile_bytecode, "compile-bytecode"),
         no_build: flag(no_build, build, "build"),
@@ -1,7 +1,7 @@
 use std::env;
 use std::fmt::{Debug, Write};
 use std::num::ParseIntError;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTimeError};
 
 use anyhow::anyhow;
@@ -16,6 +16,7 @@ use reqwest::{
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{Jitter, RetryTransientMiddleware};
+use rustc_hash::FxHashMap;
 use thiserror::Error;
 use tracing::{debug, warn};
 use url::ParseError;
@@ -26,7 +27,7 @@ use uv_auth::{
 };
 use uv_configuration::ProxyUrlKind;
 use uv_configuration::{KeyringProviderType, ProxyUrl, TrustedHost};
-use uv_distribution_types::IndexCredentialsError;
+use uv_distribution_types::{HttpVersion, IndexCredentialsError};
 use uv_git::GitHttpSettings;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -37,6 +38,9 @@ use uv_static::EnvVars;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+use crate::http_version::{
+    self, HttpVersionClients, HttpVersionMiddleware, HttpVersionPolicy, LearnedHosts,
+};
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
 use crate::tls::{Certificates, read_identity};
@@ -121,6 +125,15 @@ pub struct BaseClientBuilder<'a> {
     client_name: Option<&'static str>,
     /// Whether to disable retry delays (for testing).
     no_retry_delay: bool,
+    /// Whether to re-fetch index metadata and retry when a registry artifact is missing.
+    retry_missing_artifacts: bool,
+    /// The HTTP version to use for requests, unless overridden by an index.
+    http_version: Option<HttpVersion>,
+    /// The HTTP versions configured for specific indexes, keyed by the index host.
+    index_http_versions: FxHashMap<String, HttpVersion>,
+    /// The HTTP versions learned for hosts (e.g., after an HTTP/2 protocol error), shared across
+    /// uv clients.
+    http_learned: Arc<Mutex<LearnedHosts>>,
 }
 
 /// The policy for handling HTTP redirects.
@@ -185,6 +198,10 @@ impl Default for BaseClientBuilder<'_> {
             subcommand: None,
             client_name: None,
             no_retry_delay: env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some(),
+            retry_missing_artifacts: true,
+            http_version: None,
+            index_http_versions: FxHashMap::default(),
+            http_learned: Arc::default(),
         }
     }
 }
@@ -252,6 +269,28 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn retry_missing_artifacts(mut self, retry_missing_artifacts: bool) -> Self {
+        self.retry_missing_artifacts = retry_missing_artifacts;
+        self
+    }
+
+    #[must_use]
+    pub fn http_version(mut self, http_version: Option<HttpVersion>) -> Self {
+        self.http_version = http_version;
+        self
+    }
+
+    /// Set the HTTP versions for the hosts of the given index URLs.
+    #[must_use]
+    pub(crate) fn index_http_versions<'u>(
+        mut self,
+        indexes: impl IntoIterator<Item = (&'u DisplaySafeUrl, HttpVersion)>,
+    ) -> Self {
+        self.index_http_versions = http_version::index_hosts(indexes);
+        self
+    }
+
     #[must_use]
     pub fn system_certs(&self) -> bool {
         self.system_certs
@@ -402,21 +441,50 @@ impl<'a> BaseClientBuilder<'a> {
         }
 
         // Use the custom client if provided, otherwise create a new one
-        let (raw_client, raw_dangerous_client) = match &self.custom_client {
-            Some(client) => (client.clone(), client.clone()),
-            None => {
-                self.create_secure_and_insecure_clients(self.read_timeout, self.connect_timeout)?
-            }
+        let (raw_client, raw_dangerous_client, options) = if let Some(client) = &self.custom_client
+        {
+            (client.clone(), client.clone(), None)
+        } else {
+            let options = self.raw_client_options();
+            let raw_client = options.create_client(Security::Secure, self.http_version)?;
+            let raw_dangerous_client =
+                options.create_client(Security::Insecure, self.http_version)?;
+            (raw_client, raw_dangerous_client, Some(options))
         };
 
+        // Clients for other HTTP versions are only created on demand.
+        let http_version_clients = Arc::new(HttpVersionClients::new(
+            options.clone(),
+            Security::Secure,
+            self.http_version,
+        ));
+        let dangerous_http_version_clients = Arc::new(HttpVersionClients::new(
+            options,
+            Security::Insecure,
+            self.http_version,
+        ));
+        let http_version_policy = HttpVersionPolicy::new(
+            self.http_version,
+            self.index_http_versions.clone(),
+            self.http_learned.clone(),
+        );
+
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_client.clone()),
+            client: self.apply_middleware(
+                raw_client.clone(),
+                &http_version_policy,
+                &http_version_clients,
+            ),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
         let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(raw_dangerous_client.clone()),
+            client: self.apply_middleware(
+                raw_dangerous_client.clone(),
+                &http_version_policy,
+                &dangerous_http_version_clients,
+            ),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
@@ -426,10 +494,14 @@ impl<'a> BaseClientBuilder<'a> {
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
             no_retry_delay: self.no_retry_delay,
+            retry_missing_artifacts: self.retry_missing_artifacts,
             client,
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            http_version_policy,
+            http_version_clients,
+            dangerous_http_version_clients,
             read_timeout: self.read_timeout,
             connect_timeout: self.connect_timeout,
             credentials_cache: self.credentials_cache.clone(),
@@ -438,14 +510,29 @@ impl<'a> BaseClientBuilder<'a> {
 
     /// Share the underlying client between two different middleware configurations.
     pub(crate) fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
+        // The existing raw clients determine the default HTTP version.
+        let http_version_policy = HttpVersionPolicy::new(
+            existing.http_version_policy.default_version(),
+            self.index_http_versions.clone(),
+            self.http_learned.clone(),
+        );
+
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_client.clone()),
+            client: self.apply_middleware(
+                existing.raw_client.clone(),
+                &http_version_policy,
+                &existing.http_version_clients,
+            ),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
         let dangerous_client = RedirectClientWithMiddleware {
-            client: self.apply_middleware(existing.raw_dangerous_client.clone()),
+            client: self.apply_middleware(
+                existing.raw_dangerous_client.clone(),
+                &http_version_policy,
+                &existing.dangerous_http_version_clients,
+            ),
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
@@ -455,134 +542,53 @@ impl<'a> BaseClientBuilder<'a> {
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
             no_retry_delay: self.no_retry_delay,
+            retry_missing_artifacts: self.retry_missing_artifacts,
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            http_version_policy,
+            http_version_clients: existing.http_version_clients.clone(),
+            dangerous_http_version_clients: existing.dangerous_http_version_clients.clone(),
             read_timeout: existing.read_timeout,
             connect_timeout: existing.connect_timeout,
             credentials_cache: existing.credentials_cache.clone(),
         }
     }
 
-    fn create_secure_and_insecure_clients(
-        &self,
-        read_timeout: Duration,
-        connect_timeout: Duration,
-    ) -> Result<(Client, Client), ClientBuildError> {
+    fn raw_client_options(&self) -> RawClientOptions {
         // Create user agent.
-        let mut user_agent_string = format!("uv/{}", version());
+        let mut user_agent = format!("uv/{}", version());
 
         // Add linehaul metadata.
         let linehaul = LineHaul::new(self.markers, self.platform, self.subcommand.clone());
         if let Ok(output) = serde_json::to_string(&linehaul) {
-            let _ = write!(user_agent_string, " {output}");
+            let _ = write!(user_agent, " {output}");
         }
 
         // Load custom CA certificates from `SSL_CERT_FILE` and `SSL_CERT_DIR`.
         let custom_certs = Certificates::from_env().map(|certs| certs.to_reqwest_certs());
 
-        // Create a secure client that validates certificates.
-        let raw_client = self.create_client(
-            &user_agent_string,
-            read_timeout,
-            connect_timeout,
-            custom_certs.clone(),
-            Security::Secure,
-            self.redirect_policy,
-        )?;
-
-        // Create an insecure client that accepts invalid certificates.
-        let raw_dangerous_client = self.create_client(
-            &user_agent_string,
-            read_timeout,
-            connect_timeout,
+        RawClientOptions {
+            user_agent,
+            read_timeout: self.read_timeout,
+            connect_timeout: self.connect_timeout,
             custom_certs,
-            Security::Insecure,
-            self.redirect_policy,
-        )?;
-
-        Ok((raw_client, raw_dangerous_client))
-    }
-
-    fn create_client(
-        &self,
-        user_agent: &str,
-        read_timeout: Duration,
-        connect_timeout: Duration,
-        custom_certs: Option<Vec<Certificate>>,
-        security: Security,
-        redirect_policy: RedirectPolicy,
-    ) -> Result<Client, ClientBuildError> {
-        // Configure the builder.
-        let client_builder = ClientBuilder::new()
-            .http1_title_case_headers()
-            .user_agent(user_agent)
-            .pool_max_idle_per_host(20)
-            .read_timeout(read_timeout)
-            .connect_timeout(connect_timeout)
-            .redirect(redirect_policy.reqwest_policy());
-
-        // If necessary, accept invalid certificates.
-        let client_builder = match security {
-            Security::Secure => client_builder,
-            Security::Insecure => client_builder.danger_accept_invalid_certs(true),
-        };
-
-        let client_builder = client_builder.tls_backend_rustls();
-
-        // Configure the certificate source.
-        //
-        // `SSL_CERT_FILE` and `SSL_CERT_DIR` override the default certificate source when they
-        // contain valid certificates.
-        let client_builder = if let Some(custom_certs) = custom_certs {
-            client_builder.tls_certs_only(custom_certs)
-        } else if self.system_certs {
-            client_builder
-        } else {
-            client_builder.tls_certs_only(Certificates::webpki_roots().to_reqwest_certs())
-        };
-
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
-            match read_identity(&ssl_client_cert) {
-                Ok(identity) => client_builder.identity(identity),
-                Err(err) => {
-                    warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
-                    client_builder
-                }
-            }
-        } else {
-            client_builder
-        };
-
-        // apply proxies
-        let mut client_builder = client_builder;
-        for p in &self.proxies {
-            client_builder = client_builder.proxy(p.clone());
-        }
-
-        let no_proxy = self
-            .no_proxy
-            .as_ref()
-            .and_then(|no_proxy| NoProxy::from_string(&no_proxy.join(",")));
-
-        if let Some(http_proxy) = &self.http_proxy {
-            let proxy = http_proxy
-                .as_proxy(ProxyUrlKind::Http)
-                .no_proxy(no_proxy.clone());
-            client_builder = client_builder.proxy(proxy);
-        }
-
-        if let Some(https_proxy) = &self.https_proxy {
-            let proxy = https_proxy.as_proxy(ProxyUrlKind::Https).no_proxy(no_proxy);
-            client_builder = client_builder.proxy(proxy);
+            system_certs: self.system_certs,
+            proxies: self.proxies.clone(),
+            http_proxy: self.http_proxy.clone(),
+            https_proxy: self.https_proxy.clone(),
+            no_proxy: self.no_proxy.clone(),
+            redirect_policy: self.redirect_policy,
         }
-
-        client_builder.build().map_err(Into::into)
     }
 
-    fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
+    fn apply_middleware(
+        &self,
+        client: Client,
+        http_version_policy: &HttpVersionPolicy,
+        http_version_clients: &Arc<HttpVersionClients>,
+    ) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
                 // Create a base client to using in the authentication middleware.
@@ -606,6 +612,12 @@ impl<'a> BaseClientBuilder<'a> {
                         }
                     }
 
+                    // Select the HTTP version for the host.
+                    client = client.with(HttpVersionMiddleware::new(
+                        http_version_policy.clone(),
+                        http_version_clients.clone(),
+                    ));
+
                     client.build()
                 };
 
@@ -660,6 +672,12 @@ impl<'a> BaseClientBuilder<'a> {
                     }
                 }
 
+                // Select the HTTP version for the host, after authentication headers are set.
+                client = client.with(HttpVersionMiddleware::new(
+                    http_version_policy.clone(),
+                    http_version_clients.clone(),
+                ));
+
                 client.build()
             }
             Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
@@ -669,6 +687,104 @@ impl<'a> BaseClientBuilder<'a> {
     }
 }
 
+/// The options to create a raw [`Client`], detached from the [`BaseClientBuilder`] such that
+/// clients for other HTTP versions can be created on demand.
+#[derive(Debug, Clone)]
+pub(crate) struct RawClientOptions {
+    user_agent: String,
+    read_timeout: Duration,
+    connect_timeout: Duration,
+    custom_certs: Option<Vec<Certificate>>,
+    system_certs: bool,
+    proxies: Vec<Proxy>,
+    http_proxy: Option<ProxyUrl>,
+    https_proxy: Option<ProxyUrl>,
+    no_proxy: Option<Vec<String>>,
+    redirect_policy: RedirectPolicy,
+}
+
+impl RawClientOptions {
+    pub(crate) fn create_client(
+        &self,
+        security: Security,
+        http_version: Option<HttpVersion>,
+    ) -> Result<Client, ClientBuildError> {
+        // Configure the builder.
+        let client_builder = ClientBuilder::new()
+            .http1_title_case_headers()
+            .user_agent(&self.user_agent)
+            .pool_max_idle_per_host(20)
+            .read_timeout(self.read_timeout)
+            .connect_timeout(self.connect_timeout)
+            .redirect(self.redirect_policy.reqwest_policy());
+
+        // If necessary, constrain the HTTP version. HTTP/2 is negotiated via ALPN, as by default,
+        // rather than assumed with prior knowledge, which servers that don't expect it reject.
+        let client_builder = match http_version {
+            None | Some(HttpVersion::Http2) => client_builder,
+            Some(HttpVersion::Http1) => client_builder.http1_only(),
+        };
+
+        // If necessary, accept invalid certificates.
+        let client_builder = match security {
+            Security::Secure => client_builder,
+            Security::Insecure => client_builder.danger_accept_invalid_certs(true),
+        };
+
+        let client_builder = client_builder.tls_backend_rustls();
+
+        // Configure the certificate source.
+        //
+        // `SSL_CERT_FILE` and `SSL_CERT_DIR` override the default certificate source when they
+        // contain valid certificates.
+        let client_builder = if let Some(custom_certs) = self.custom_certs.clone() {
+            client_builder.tls_certs_only(custom_certs)
+        } else if self.system_certs {
+            client_builder
+        } else {
+            client_builder.tls_certs_only(Certificates::webpki_roots().to_reqwest_certs())
+        };
+
+        // Configure mTLS.
+        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+            match read_identity(&ssl_client_cert) {
+                Ok(identity) => client_builder.identity(identity),
+                Err(err) => {
+                    warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
+                    client_builder
+                }
+            }
+        } else {
+            client_builder
+        };
+
+        // apply proxies
+        let mut client_builder = client_builder;
+        for p in &self.proxies {
+            client_builder = client_builder.proxy(p.clone());
+        }
+
+        let no_proxy = self
+            .no_proxy
+            .as_ref()
+            .and_then(|no_proxy| NoProxy::from_string(&no_proxy.join(",")));
+
+        if let Some(http_proxy) = &self.http_proxy {
+            let proxy = http_proxy
+                .as_proxy(ProxyUrlKind::Http)
+                .no_proxy(no_proxy.clone());
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        if let Some(https_proxy) = &self.https_proxy {
+            let proxy = https_proxy.as_proxy(ProxyUrlKind::Https).no_proxy(no_proxy);
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        client_builder.build().map_err(Into::into)
+    }
+}
+
 /// A base client for HTTP requests
 #[derive(Debug, Clone)]
 pub struct BaseClient {
@@ -680,6 +796,13 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP version to use for requests to each host.
+    http_version_policy: HttpVersionPolicy,
+    /// The HTTP clients for hosts that use a non-default HTTP version.
+    http_version_clients: Arc<HttpVersionClients>,
+    /// The HTTP clients that accept invalid certificates for hosts that use a non-default HTTP
+    /// version.
+    dangerous_http_version_clients: Arc<HttpVersionClients>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client read timeout.
@@ -692,12 +815,14 @@ pub struct BaseClient {
     retries: u32,
     /// Whether to disable retry delays (for testing).
     no_retry_delay: bool,
+    /// Whether to re-fetch index metadata and retry when a registry artifact is missing.
+    retry_missing_artifacts: bool,
     /// Global authentication cache for a uv invocation to share credentials across uv clients.
     credentials_cache: Arc<CredentialsCache>,
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Security {
+pub(crate) enum Security {
     /// The client should use secure settings, i.e., valid certificates.
     Secure,
     /// The client should use insecure settings, i.e., skip certificate validation.
@@ -744,11 +869,24 @@ impl BaseClient {
         self.connectivity
     }
 
+    /// Whether to re-fetch index metadata and retry when a registry artifact is missing.
+    pub fn retry_missing_artifacts(&self) -> bool {
+        self.retry_missing_artifacts
+    }
+
     /// The [`RetryPolicy`] for the client.
     pub fn retry_policy(&self) -> ExponentialBackoff {
         retry_policy(self.retries, self.no_retry_delay)
     }
 
+    /// If a request failed with an HTTP/2 protocol error, e.g., while streaming the response body,
+    /// use HTTP/1.1 for all subsequent requests to the host.
+    ///
+    /// Returns `true` if the host fell back to HTTP/1.1.
+    pub fn fall_back_on_error(&self, url: &Url, err: &(dyn std::error::Error + 'static)) -> bool {
+        self.http_version_policy.fall_back_on_error(url, err)
+    }
+
     pub(crate) fn credentials_cache(&self) -> &CredentialsCache {
         &self.credentials_cache
     }
@@ -671,6 +671,8 @@ impl CachedClient {
             match result {
                 Ok(ok) => return Ok(ok),
                 Err(err) => {
+                    // If the response failed with an HTTP/2 protocol error, retry with HTTP/1.1.
+                    self.uncached().fall_back_on_error(req.url(), err.error());
                     if let Some(backoff) = retry_state.should_retry(err.error(), err.retries()) {
                         retry_state.sleep_backoff(backoff).await;
                         continue;
@@ -710,6 +712,8 @@ impl CachedClient {
             match result {
                 Ok(ok) => return Ok(ok),
                 Err(err) => {
+                    // If the response failed with an HTTP/2 protocol error, retry with HTTP/1.1.
+                    self.uncached().fall_back_on_error(req.url(), err.error());
                     if let Some(backoff) = retry_state.should_retry(err.error(), err.retries()) {
                         retry_state.sleep_backoff(backoff).await;
                         continue;
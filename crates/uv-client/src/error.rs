@@ -225,7 +225,7 @@ impl Error {
     }
 
     /// Returns `true` if this error corresponds to an offline error.
-    pub(crate) fn is_offline(&self) -> bool {
+    pub fn is_offline(&self) -> bool {
         matches!(&*self.kind, ErrorKind::Offline(_))
     }
 
@@ -242,6 +242,16 @@ impl Error {
         matches!(&*self.kind, ErrorKind::WrappedReqwestError(.., err) if err.is_ssl())
     }
 
+    /// Returns `true` if the server reported that the requested resource doesn't exist (i.e., a
+    /// `404 Not Found` or `410 Gone`).
+    pub fn is_http_missing(&self) -> bool {
+        matches!(
+            &*self.kind,
+            ErrorKind::WrappedReqwestError(.., err)
+                if matches!(err.status(), Some(reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE))
+        )
+    }
+
     /// Returns `true` if the error is due to the server not supporting HTTP range requests.
     pub(crate) fn is_http_range_requests_unsupported(
         &self,
@@ -0,0 +1,399 @@
+use std::error::Error;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::{io, iter};
+
+use http::Extensions;
+use reqwest::{Client, Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+use url::Url;
+
+use uv_distribution_types::HttpVersion;
+use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user_once;
+
+use crate::base_client::{RawClientOptions, Security};
+
+/// The HTTP version to use for requests to each host.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HttpVersionPolicy {
+    /// The HTTP version to use for hosts without an index-specific override, e.g., from
+    /// `UV_HTTP_VERSION`.
+    ///
+    /// If unset, the version is negotiated with the server.
+    default: Option<HttpVersion>,
+    /// The HTTP versions configured for specific indexes, keyed by the index host.
+    hosts: Arc<FxHashMap<String, HttpVersion>>,
+    /// The HTTP versions learned over the course of the invocation.
+    ///
+    /// Shared across all clients of a uv invocation.
+    learned: Arc<Mutex<LearnedHosts>>,
+}
+
+/// The HTTP versions learned for hosts over the course of a uv invocation.
+#[derive(Debug, Default)]
+pub(crate) struct LearnedHosts {
+    /// The hosts that fell back to HTTP/1.1 after an HTTP/2 protocol error.
+    fallback: FxHashSet<String>,
+    /// The hosts that a host with a configured HTTP version redirected to (e.g., a CDN that serves
+    /// an index's files), which inherit the configured HTTP version.
+    redirects: FxHashMap<String, HttpVersion>,
+}
+
+impl HttpVersionPolicy {
+    pub(crate) fn new(
+        default: Option<HttpVersion>,
+        hosts: FxHashMap<String, HttpVersion>,
+        learned: Arc<Mutex<LearnedHosts>>,
+    ) -> Self {
+        Self {
+            default,
+            hosts: Arc::new(hosts),
+            learned,
+        }
+    }
+
+    /// The HTTP version to use for hosts without an index-specific override.
+    pub(crate) fn default_version(&self) -> Option<HttpVersion> {
+        self.default
+    }
+
+    /// The HTTP version configured for a host, if any, including versions inherited through
+    /// redirects.
+    fn host_version(&self, host: &str) -> Option<HttpVersion> {
+        self.hosts.get(host).copied().or_else(|| {
+            let learned = self.learned.lock().unwrap();
+            learned.redirects.get(host).copied()
+        })
+    }
+
+    /// The configured HTTP version for a URL, if any.
+    fn configured(&self, url: &Url) -> Option<HttpVersion> {
+        host_key(url)
+            .and_then(|host| self.host_version(&host))
+            .or(self.default)
+    }
+
+    /// The HTTP version to use for a URL, accounting for previous fallbacks to HTTP/1.1.
+    fn version(&self, url: &Url) -> Option<HttpVersion> {
+        self.configured(url).or_else(|| {
+            let host = host_key(url)?;
+            self.learned
+                .lock()
+                .unwrap()
+                .fallback
+                .contains(&host)
+                .then_some(HttpVersion::Http1)
+        })
+    }
+
+    /// If a host with a configured HTTP version redirected to another host, use the same HTTP
+    /// version for the redirect target, unless the target has its own configuration.
+    fn inherit_on_redirect(&self, url: &Url, response: &Response) {
+        if !response.status().is_redirection() {
+            return;
+        }
+        let Some(version) = host_key(url).and_then(|host| self.host_version(&host)) else {
+            return;
+        };
+        let Some(target) = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| url.join(location).ok())
+        else {
+            return;
+        };
+        let Some(target) = host_key(&target) else {
+            return;
+        };
+        if self.hosts.contains_key(&target) {
+            return;
+        }
+        if self
+            .learned
+            .lock()
+            .unwrap()
+            .redirects
+            .insert(target.clone(), version)
+            .is_none()
+        {
+            debug!(
+                "Using {version} for `{target}`, which `{}` redirected to",
+                DisplaySafeUrl::ref_cast(url)
+            );
+        }
+    }
+
+    /// If the error is an HTTP/2 protocol error for a host without a configured HTTP version,
+    /// use HTTP/1.1 for all subsequent requests to that host.
+    ///
+    /// Returns `true` if the host fell back to HTTP/1.1.
+    pub(crate) fn fall_back_on_error(&self, url: &Url, err: &(dyn Error + 'static)) -> bool {
+        if self.configured(url).is_some() || !is_http2_protocol_error(err) {
+            return false;
+        }
+        let Some(host) = host_key(url) else {
+            return false;
+        };
+        if self.learned.lock().unwrap().fallback.insert(host.clone()) {
+            warn_user_once!(
+                "Request to `{host}` failed with an HTTP/2 protocol error; using HTTP/1.1 for the remainder of the invocation (set `http = \"1.1\"` on the index to skip HTTP/2 entirely)"
+            );
+            debug!(
+                "Falling back to HTTP/1.1 for `{host}` after HTTP/2 error for: {}",
+                DisplaySafeUrl::ref_cast(url)
+            );
+        }
+        true
+    }
+}
+
+/// Raw clients constrained to a specific HTTP version, constructed on first use.
+///
+/// The clients are shared across all clones of a [`BaseClient`](crate::BaseClient), such that
+/// indexes with the same HTTP version share a connection pool.
+#[derive(Debug)]
+pub(crate) struct HttpVersionClients {
+    /// The options to construct the clients with, or `None` for custom clients, which can't be
+    /// reconfigured.
+    options: Option<RawClientOptions>,
+    security: Security,
+    /// The HTTP version of the default client, which doesn't need a variant.
+    default: Option<HttpVersion>,
+    http1: OnceLock<Option<Client>>,
+    http2: OnceLock<Option<Client>>,
+}
+
+impl HttpVersionClients {
+    pub(crate) fn new(
+        options: Option<RawClientOptions>,
+        security: Security,
+        default: Option<HttpVersion>,
+    ) -> Self {
+        Self {
+            options,
+            security,
+            default,
+            http1: OnceLock::new(),
+            http2: OnceLock::new(),
+        }
+    }
+
+    /// Return the client for the given HTTP version, or `None` if the default client should be
+    /// used.
+    fn get(&self, version: HttpVersion) -> Option<&Client> {
+        // HTTP/2 is negotiated like the default, so only needs a variant if the default client is
+        // constrained to HTTP/1.1.
+        if self.default.unwrap_or(HttpVersion::Http2) == version {
+            return None;
+        }
+        let options = self.options.as_ref()?;
+        let cell = match version {
+            HttpVersion::Http1 => &self.http1,
+            HttpVersion::Http2 => &self.http2,
+        };
+        cell.get_or_init(|| {
+            debug!("Creating {version} client");
+            match options.create_client(self.security, Some(version)) {
+                Ok(client) => Some(client),
+                Err(err) => {
+                    debug!("Failed to create {version} client, using the default client: {err}");
+                    None
+                }
+            }
+        })
+        .as_ref()
+    }
+}
+
+/// A middleware that routes requests to a client for the host's HTTP version, and falls back to
+/// HTTP/1.1 when a request fails with an HTTP/2 protocol error.
+pub(crate) struct HttpVersionMiddleware {
+    policy: HttpVersionPolicy,
+    clients: Arc<HttpVersionClients>,
+}
+
+impl HttpVersionMiddleware {
+    pub(crate) fn new(policy: HttpVersionPolicy, clients: Arc<HttpVersionClients>) -> Self {
+        Self { policy, clients }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for HttpVersionMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let url = req.url().clone();
+        let result = if let Some(client) = self
+            .policy
+            .version(&url)
+            .and_then(|version| self.clients.get(version))
+        {
+            client.execute(req).await.map_err(Into::into)
+        } else {
+            let fallback = req.try_clone();
+            let result = next.run(req, extensions).await;
+            if let Err(err) = &result
+                && let Some(fallback) = fallback
+                && self.policy.fall_back_on_error(&url, err)
+                && let Some(client) = self.clients.get(HttpVersion::Http1)
+            {
+                client.execute(fallback).await.map_err(Into::into)
+            } else {
+                result
+            }
+        };
+        if let Ok(response) = &result {
+            self.policy.inherit_on_redirect(&url, response);
+        }
+        result
+    }
+}
+
+/// Whether the error was caused by an HTTP/2 protocol error, such as a stream reset or a
+/// `GOAWAY` frame with an error code.
+fn is_http2_protocol_error(err: &(dyn Error + 'static)) -> bool {
+    iter::successors(Some(err), |&err| {
+        // `io::Error` forwards to the source of the wrapped error, skipping the error itself.
+        if let Some(io_err) = err.downcast_ref::<io::Error>()
+            && let Some(inner) = io_err.get_ref()
+        {
+            Some(inner as &(dyn Error + 'static))
+        } else {
+            err.source()
+        }
+    })
+    .any(|err| {
+        err.downcast_ref::<h2::Error>()
+            .and_then(h2::Error::reason)
+            .is_some_and(|reason| reason != h2::Reason::NO_ERROR)
+    })
+}
+
+/// The key to track HTTP versions by, i.e., the host and port of the URL.
+fn host_key(url: &Url) -> Option<String> {
+    let host = url.host_str()?;
+    match url.port_or_known_default() {
+        Some(port) => Some(format!("{host}:{port}")),
+        None => Some(host.to_string()),
+    }
+}
+
+/// Return the host keys of the indexes with a configured HTTP version.
+pub(crate) fn index_hosts<'a>(
+    indexes: impl IntoIterator<Item = (&'a DisplaySafeUrl, HttpVersion)>,
+) -> FxHashMap<String, HttpVersion> {
+    indexes
+        .into_iter()
+        .filter_map(|(url, version)| Some((host_key(url)?, version)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_versions() {
+        let policy = HttpVersionPolicy::new(
+            None,
+            index_hosts([(
+                &DisplaySafeUrl::parse("https://internal.example.com/simple").unwrap(),
+                HttpVersion::Http1,
+            )]),
+            Arc::default(),
+        );
+
+        // The override applies to all URLs on the index host, e.g., file downloads.
+        assert_eq!(
+            policy.version(&Url::parse("https://internal.example.com/files/a.whl").unwrap()),
+            Some(HttpVersion::Http1)
+        );
+        assert_eq!(
+            policy.version(&Url::parse("https://internal.example.com:8443/simple").unwrap()),
+            None
+        );
+        assert_eq!(
+            policy.version(&Url::parse("https://pypi.org/simple").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn inherit_on_redirect() {
+        let policy = HttpVersionPolicy::new(
+            None,
+            index_hosts([(
+                &DisplaySafeUrl::parse("https://internal.example.com/simple").unwrap(),
+                HttpVersion::Http1,
+            )]),
+            Arc::default(),
+        );
+        let redirect = |location: &str| {
+            Response::from(
+                http::Response::builder()
+                    .status(302)
+                    .header("location", location)
+                    .body("")
+                    .unwrap(),
+            )
+        };
+
+        // Redirects from other hosts don't configure the target.
+        policy.inherit_on_redirect(
+            &Url::parse("https://pypi.org/simple/foo/").unwrap(),
+            &redirect("https://files.example.net/foo.whl"),
+        );
+        assert_eq!(
+            policy.version(&Url::parse("https://files.example.net/foo.whl").unwrap()),
+            None
+        );
+
+        // The target of a redirect from the index inherits the index's HTTP version.
+        policy.inherit_on_redirect(
+            &Url::parse("https://internal.example.com/files/foo.whl").unwrap(),
+            &redirect("https://cdn.example.net/foo.whl"),
+        );
+        assert_eq!(
+            policy.version(&Url::parse("https://cdn.example.net/bar.whl").unwrap()),
+            Some(HttpVersion::Http1)
+        );
+    }
+
+    #[test]
+    fn fall_back_on_protocol_error() {
+        let policy = HttpVersionPolicy::new(
+            None,
+            index_hosts([(
+                &DisplaySafeUrl::parse("https://pinned.example.com/simple").unwrap(),
+                HttpVersion::Http2,
+            )]),
+            Arc::default(),
+        );
+        let url = Url::parse("https://example.com/simple/foo/").unwrap();
+        let pinned = Url::parse("https://pinned.example.com/simple/foo/").unwrap();
+
+        // Only HTTP/2 protocol errors trigger the fallback.
+        let err = std::io::Error::other("connection closed");
+        assert!(!policy.fall_back_on_error(&url, &err));
+        let err = std::io::Error::other(h2::Error::from(h2::Reason::NO_ERROR));
+        assert!(!policy.fall_back_on_error(&url, &err));
+
+        let err = std::io::Error::other(h2::Error::from(h2::Reason::PROTOCOL_ERROR));
+        assert!(policy.fall_back_on_error(&url, &err));
+        assert_eq!(policy.version(&url), Some(HttpVersion::Http1));
+
+        // The fallback is shared across clones of the policy.
+        assert_eq!(policy.clone().version(&url), Some(HttpVersion::Http1));
+
+        // Hosts with a configured HTTP version never fall back.
+        assert!(!policy.fall_back_on_error(&pinned, &err));
+        assert_eq!(policy.version(&pinned), Some(HttpVersion::Http2));
+    }
+}
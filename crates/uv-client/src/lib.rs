@@ -19,6 +19,7 @@ mod cached_client;
 mod error;
 mod flat_index;
 mod html;
+mod http_version;
 mod httpcache;
 mod linehaul;
 mod middleware;
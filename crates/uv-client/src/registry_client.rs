@@ -182,7 +182,12 @@ impl<'a> RegistryClientBuilder<'a> {
         // Wrap in any relevant middleware and handle connectivity.
         let builder = self
             .base_client_builder
-            .indexes(Indexes::from(&self.index_locations));
+            .indexes(Indexes::from(&self.index_locations))
+            .index_http_versions(
+                self.index_locations
+                    .known_indexes()
+                    .filter_map(|index| Some((index.url.url(), index.http?))),
+            );
         let client = if let Some(existing) = existing {
             builder.wrap_existing(existing)
         } else {
@@ -268,6 +273,11 @@ impl RegistryClient {
         self.read_timeout
     }
 
+    /// Whether to re-fetch index metadata and retry when a registry artifact is missing.
+    pub fn retry_missing_artifacts(&self) -> bool {
+        self.client.uncached().retry_missing_artifacts()
+    }
+
     pub fn credentials_cache(&self) -> &CredentialsCache {
         self.client.uncached().credentials_cache()
     }
@@ -509,15 +519,7 @@ impl RegistryClient {
         capabilities: &IndexCapabilities,
         status_code_strategy: &IndexStatusCodeStrategy,
     ) -> Result<SimpleMetadataSearchOutcome, Error> {
-        // Format the URL for PyPI.
-        let mut url = index.url().clone();
-        url.path_segments_mut()
-            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
-            .pop_if_empty()
-            .push(package_name.as_ref())
-            // The URL *must* end in a trailing slash for proper relative path behavior
-            // ref https://github.com/servo/rust-url/issues/333
-            .push("");
+        let url = Self::simple_detail_url(package_name, index)?;
 
         trace!("Fetching metadata for {package_name} from {url}");
 
@@ -587,6 +589,60 @@ impl RegistryClient {
         }
     }
 
+    /// Fetch the [`SimpleDetailMetadata`] for a given package from a single remote index,
+    /// revalidating any cached response.
+    ///
+    /// Used to recover when an artifact referenced by previously fetched metadata (e.g., from a
+    /// lockfile) has since been removed from the index.
+    #[instrument(skip_all, fields(package = % package_name))]
+    pub async fn revalidate_simple_detail(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+    ) -> Result<OwnedArchive<SimpleDetailMetadata>, Error> {
+        let url = Self::simple_detail_url(package_name, index)?;
+
+        trace!("Revalidating metadata for {package_name} from {url}");
+
+        let cache_entry = self.cache.entry(
+            CacheBucket::Simple,
+            WheelCache::Index(index).root(),
+            format!("{package_name}.rkyv"),
+        );
+
+        // Acquire an advisory lock, to guard against concurrent writes.
+        #[cfg(windows)]
+        let _lock = {
+            let lock_entry = cache_entry.with_file(format!("{package_name}.lock"));
+            lock_entry.lock().await.map_err(ErrorKind::CacheLock)?
+        };
+
+        self.fetch_remote_simple_detail(
+            package_name,
+            &url,
+            index,
+            &cache_entry,
+            CacheControl::MustRevalidate,
+        )
+        .await
+    }
+
+    /// Format the Simple API URL for a given package on the given index.
+    fn simple_detail_url(
+        package_name: &PackageName,
+        index: &IndexUrl,
+    ) -> Result<DisplaySafeUrl, Error> {
+        let mut url = index.url().clone();
+        url.path_segments_mut()
+            .map_err(|()| ErrorKind::CannotBeABase(index.url().clone()))?
+            .pop_if_empty()
+            .push(package_name.as_ref())
+            // The URL *must* end in a trailing slash for proper relative path behavior
+            // ref https://github.com/servo/rust-url/issues/333
+            .push("");
+        Ok(url)
+    }
+
     /// Fetch the [`SimpleDetailMetadata`] from a remote URL, using the PEP 503 Simple Repository API.
     async fn fetch_remote_simple_detail(
         &self,
@@ -31,6 +31,8 @@ pub enum BuildOutput {
     Stderr,
     /// Send the build backend output to `tracing`.
     Debug,
+    /// Stream the build backend output to `stderr` line-by-line, prefixed with the package name.
+    Stream,
     /// Do not display the build backend output.
     Quiet,
 }
@@ -0,0 +1,220 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use uv_distribution_types::BuildTarget;
+
+/// The settings that control how source distributions are built during a single invocation.
+///
+/// Unlike [`BuildOptions`](crate::BuildOptions), these settings don't affect which distributions
+/// are selected, so they're excluded from the resolution cache key.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BuildRuntime {
+    /// The maximum duration of a single build backend hook invocation, if any.
+    timeout: Option<Duration>,
+    /// The number of times to retry a build backend hook that fails with a transient error.
+    retries: u32,
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`, if any.
+    build_python: Option<String>,
+    /// The platform to build wheels for, if not the current platform.
+    target: Option<BuildTarget>,
+    /// Whether to report the warnings emitted by build backends during successful builds.
+    show_warnings: bool,
+    /// The patterns that identify a line of build backend output as a warning, if not the
+    /// defaults.
+    warning_patterns: Vec<String>,
+    /// The file to which build events are appended, if any.
+    events_file: Option<PathBuf>,
+    /// Whether to retain the build backend output of successful builds in the cache.
+    keep_logs: bool,
+    /// Whether to retain the build environment when a build backend hook fails.
+    keep_environment: bool,
+    /// The directory in which to create temporary build directories, if not the cache.
+    build_dir: Option<PathBuf>,
+    /// Whether to build wheels for source trees via a source distribution.
+    via_sdist: bool,
+    /// Whether to create a separate build environment for each build.
+    no_environment_reuse: bool,
+}
+
+impl BuildRuntime {
+    /// Set the maximum duration of a single build backend hook invocation (e.g.,
+    /// `--build-timeout`).
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the number of times to retry a build backend hook that fails with a transient error
+    /// (e.g., `--build-retries`).
+    #[must_use]
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Set the Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python` (e.g., `--build-python`).
+    #[must_use]
+    pub fn with_build_python(mut self, build_python: Option<String>) -> Self {
+        self.build_python = build_python;
+        self
+    }
+
+    /// Set the platform to build wheels for (e.g., `--build-target`).
+    #[must_use]
+    pub fn with_target(mut self, target: Option<BuildTarget>) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Set whether to report the warnings emitted by build backends during successful builds
+    /// (e.g., `--show-build-warnings`).
+    #[must_use]
+    pub fn with_show_warnings(mut self, show_warnings: bool) -> Self {
+        self.show_warnings = show_warnings;
+        self
+    }
+
+    /// Set the patterns that identify a line of build backend output as a warning (e.g.,
+    /// `--build-warning-pattern`).
+    #[must_use]
+    pub fn with_warning_patterns(mut self, warning_patterns: Vec<String>) -> Self {
+        self.warning_patterns = warning_patterns;
+        self
+    }
+
+    /// Set the file to which the start and finish of every source build are appended (e.g.,
+    /// `--build-events-file`).
+    #[must_use]
+    pub fn with_events_file(mut self, events_file: Option<PathBuf>) -> Self {
+        self.events_file = events_file;
+        self
+    }
+
+    /// Set whether to retain the build backend output of successful builds in the cache (e.g.,
+    /// `--keep-build-logs`).
+    #[must_use]
+    pub fn with_keep_logs(mut self, keep_logs: bool) -> Self {
+        self.keep_logs = keep_logs;
+        self
+    }
+
+    /// Set whether to retain the build environment when a build backend hook fails (e.g.,
+    /// `--keep-build-env`).
+    #[must_use]
+    pub fn with_keep_environment(mut self, keep_environment: bool) -> Self {
+        self.keep_environment = keep_environment;
+        self
+    }
+
+    /// Set the directory in which to create temporary build directories (e.g., `--build-dir`).
+    #[must_use]
+    pub fn with_build_dir(mut self, build_dir: Option<PathBuf>) -> Self {
+        self.build_dir = build_dir;
+        self
+    }
+
+    /// Set whether to build wheels for source trees via a source distribution (e.g.,
+    /// `--build-via-sdist`).
+    #[must_use]
+    pub fn with_via_sdist(mut self, via_sdist: bool) -> Self {
+        self.via_sdist = via_sdist;
+        self
+    }
+
+    /// Set whether to create a separate build environment for each build (e.g.,
+    /// `--no-build-environment-reuse`).
+    #[must_use]
+    pub fn with_no_environment_reuse(mut self, no_environment_reuse: bool) -> Self {
+        self.no_environment_reuse = no_environment_reuse;
+        self
+    }
+
+    /// Return the maximum duration of a single build backend hook invocation, if any.
+    ///
+    /// By default, build backend hooks are not subject to a timeout.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Return the number of times to retry a build backend hook that fails with a transient error.
+    ///
+    /// By default, build backend hooks are not retried.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Return the Python interpreter to build packages with if the target interpreter doesn't
+    /// satisfy their `requires-python`, if any.
+    ///
+    /// By default, such builds fail.
+    pub fn build_python(&self) -> Option<&str> {
+        self.build_python.as_deref()
+    }
+
+    /// Return the platform to build wheels for, if not the current platform.
+    ///
+    /// By default, wheels are built for the current platform.
+    pub fn target(&self) -> Option<&BuildTarget> {
+        self.target.as_ref()
+    }
+
+    /// Return whether to report the warnings emitted by build backends during successful builds.
+    ///
+    /// By default, the output of successful builds is discarded.
+    pub fn show_warnings(&self) -> bool {
+        self.show_warnings
+    }
+
+    /// Return the patterns that identify a line of build backend output as a warning.
+    ///
+    /// If empty, the default patterns are used.
+    pub fn warning_patterns(&self) -> &[String] {
+        &self.warning_patterns
+    }
+
+    /// Return the file to which the start and finish of every source build are appended, if any.
+    ///
+    /// By default, no build events are recorded.
+    pub fn events_file(&self) -> Option<&Path> {
+        self.events_file.as_deref()
+    }
+
+    /// Return whether to retain the build backend output of successful builds in the cache.
+    ///
+    /// By default, only the output of failed builds is retained.
+    pub fn keep_logs(&self) -> bool {
+        self.keep_logs
+    }
+
+    /// Return whether to retain the build environment when a build backend hook fails.
+    ///
+    /// By default, the build environment is removed once the build completes.
+    pub fn keep_environment(&self) -> bool {
+        self.keep_environment
+    }
+
+    /// Return the directory in which to create temporary build directories, if not the cache.
+    ///
+    /// By default, temporary build directories are created in the cache.
+    pub fn build_dir(&self) -> Option<&Path> {
+        self.build_dir.as_deref()
+    }
+
+    /// Return whether to build wheels for source trees via a source distribution.
+    ///
+    /// By default, wheels are only built via a source distribution if the build backend doesn't
+    /// support building them from the source tree directly.
+    pub fn via_sdist(&self) -> bool {
+        self.via_sdist
+    }
+
+    /// Return whether to create a separate build environment for each build.
+    ///
+    /// By default, builds with identical build requirements share a build environment.
+    pub fn no_environment_reuse(&self) -> bool {
+        self.no_environment_reuse
+    }
+}
@@ -20,10 +20,20 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub installs: usize,
+    /// The maximum number of concurrent metadata prefetches during resolution.
+    ///
+    /// Note this value must be non-zero.
+    pub metadata: usize,
+    /// Whether to abort the remaining builds as soon as any build fails, rather than completing
+    /// them and reporting every failure.
+    pub fail_fast: bool,
     /// A global semaphore to limit the number of concurrent downloads.
     pub downloads_semaphore: Arc<Semaphore>,
     /// A global semaphore to limit the number of concurrent builds.
     pub builds_semaphore: Arc<Semaphore>,
+    /// A global semaphore to limit the number of concurrent metadata prefetches, shared by every
+    /// resolution (including those for build requirements).
+    pub metadata_semaphore: Arc<Semaphore>,
 }
 
 /// Custom `Debug` to hide semaphore fields from `--show-settings` output.
@@ -34,6 +44,8 @@ impl fmt::Debug for Concurrency {
             .field("downloads", &self.downloads)
             .field("builds", &self.builds)
             .field("installs", &self.installs)
+            .field("metadata", &self.metadata)
+            .field("fail_fast", &self.fail_fast)
             .finish()
     }
 }
@@ -48,17 +60,39 @@ impl Concurrency {
     // The default concurrent downloads limit.
     pub const DEFAULT_DOWNLOADS: usize = 50;
 
+    // The default concurrent metadata prefetches limit.
+    pub const DEFAULT_METADATA: usize = 50;
+
     /// Create a new [`Concurrency`] with the given limits.
     pub fn new(downloads: usize, builds: usize, installs: usize) -> Self {
         Self {
             downloads,
             builds,
             installs,
+            metadata: Self::DEFAULT_METADATA,
+            fail_fast: true,
             downloads_semaphore: Arc::new(Semaphore::new(downloads)),
             builds_semaphore: Arc::new(Semaphore::new(builds)),
+            metadata_semaphore: Arc::new(Semaphore::new(Self::DEFAULT_METADATA)),
         }
     }
 
+    /// Set the maximum number of concurrent metadata prefetches.
+    #[must_use]
+    pub fn with_metadata(self, metadata: usize) -> Self {
+        Self {
+            metadata,
+            metadata_semaphore: Arc::new(Semaphore::new(metadata)),
+            ..self
+        }
+    }
+
+    /// Set whether to abort the remaining builds as soon as any build fails.
+    #[must_use]
+    pub fn with_fail_fast(self, fail_fast: bool) -> Self {
+        Self { fail_fast, ..self }
+    }
+
     // The default concurrent builds and install limit.
     pub fn threads() -> usize {
         std::thread::available_parallelism()
@@ -289,6 +289,45 @@ impl DependencyGroupsHistory {
         }
         flags
     }
+
+    /// Returns the CLI arguments that this represents, including every value (e.g., `--group`,
+    /// `A`, `--group`, `B`), such that the arguments can be passed to another command.
+    pub fn as_flags(&self) -> Vec<String> {
+        let Self {
+            dev_mode,
+            group,
+            only_group,
+            no_group,
+            all_groups,
+            no_default_groups,
+            // defaults aren't CLI flags!
+            defaults: _,
+        } = self;
+
+        let mut flags = vec![];
+        if *all_groups {
+            flags.push("--all-groups".to_string());
+        }
+        if *no_default_groups {
+            flags.push("--no-default-groups".to_string());
+        }
+        if let Some(dev_mode) = dev_mode {
+            flags.push(dev_mode.as_flag().to_string());
+        }
+        for group in group {
+            flags.push("--group".to_string());
+            flags.push(group.to_string());
+        }
+        for group in only_group {
+            flags.push("--only-group".to_string());
+            flags.push(group.to_string());
+        }
+        for group in no_group {
+            flags.push("--no-group".to_string());
+            flags.push(group.to_string());
+        }
+        flags
+    }
 }
 
 /// A trivial newtype wrapped around [`DependencyGroups`][] that signifies "defaults applied"
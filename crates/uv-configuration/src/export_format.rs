@@ -22,6 +22,15 @@ pub enum ExportFormat {
         clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
     )]
     CycloneDX1_5,
+    /// Export as a Dockerfile snippet that installs the project into an image.
+    #[serde(rename = "dockerfile-snippet")]
+    #[cfg_attr(feature = "clap", clap(name = "dockerfile-snippet"))]
+    DockerfileSnippet,
+    /// Export as a JSON dependency graph, with a node for each package and an edge for each
+    /// dependency.
+    #[serde(rename = "json-graph")]
+    #[cfg_attr(feature = "clap", clap(name = "json-graph"))]
+    JsonGraph,
 }
 
 /// The output format to use in `uv pip compile`.
@@ -241,6 +241,41 @@ impl ExtrasSpecificationHistory {
         }
         flags
     }
+
+    /// Returns the CLI arguments that this represents, including every value (e.g., `--extra`,
+    /// `A`, `--extra`, `B`), such that the arguments can be passed to another command.
+    pub fn as_flags(&self) -> Vec<String> {
+        let Self {
+            extra,
+            no_extra,
+            all_extras,
+            only_extra,
+            no_default_extras,
+            // defaults aren't CLI flags!
+            defaults: _,
+        } = self;
+
+        let mut flags = vec![];
+        if *all_extras {
+            flags.push("--all-extras".to_string());
+        }
+        if *no_default_extras {
+            flags.push("--no-default-extras".to_string());
+        }
+        for extra in extra {
+            flags.push("--extra".to_string());
+            flags.push(extra.to_string());
+        }
+        for extra in only_extra {
+            flags.push("--only-extra".to_string());
+            flags.push(extra.to_string());
+        }
+        for extra in no_extra {
+            flags.push("--no-extra".to_string());
+            flags.push(extra.to_string());
+        }
+        flags
+    }
 }
 
 /// A trivial newtype wrapped around [`ExtrasSpecification`][] that signifies "defaults applied"
@@ -1,5 +1,6 @@
 pub use authentication::*;
 pub use build_options::*;
+pub use build_runtime::*;
 pub use concurrency::*;
 pub use constraints::*;
 pub use dependency_groups::*;
@@ -16,8 +17,10 @@ pub use overrides::*;
 pub use package_options::*;
 pub use project_build_backend::*;
 pub use proxy_url::*;
+pub use replacements::*;
 pub use required_version::*;
 pub use sources::*;
+pub use target_implementation::*;
 pub use target_triple::*;
 pub use threading::*;
 
@@ -27,6 +30,7 @@ pub use vcs::*;
 
 mod authentication;
 mod build_options;
+mod build_runtime;
 mod concurrency;
 mod constraints;
 mod dependency_groups;
@@ -43,8 +47,10 @@ mod overrides;
 mod package_options;
 mod project_build_backend;
 mod proxy_url;
+mod replacements;
 mod required_version;
 mod sources;
+mod target_implementation;
 mod target_triple;
 mod threading;
 
@@ -0,0 +1,139 @@
+use std::borrow::Cow;
+
+use rustc_hash::FxHashMap;
+use serde::de::IntoDeserializer;
+
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+/// A replacement for a package, i.e., a requirement that is substituted for every dependency on
+/// the replaced package, similar to Cargo's `[patch]`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", bound(serialize = "T: serde::Serialize"))]
+pub struct Replacement<T> {
+    /// The requirement to substitute for the replaced package, e.g., `acme-requests>=2.31`.
+    pub requirement: T,
+    /// The version of the replaced package that the replacement provides.
+    ///
+    /// If set, version specifiers on the replaced package (e.g., `requests>=2.28`) are checked
+    /// against this version. Otherwise, they're ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<String>",
+            description = "PEP 440-style package version, e.g., `2.31.0`"
+        )
+    )]
+    pub provides: Option<Version>,
+}
+
+// Accept either a bare requirement string or a table with `requirement` and `provides`, and use a
+// type-directed visitor to retain detailed requirement parse errors.
+impl<'de, T> serde::Deserialize<'de> for Replacement<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "kebab-case", deny_unknown_fields)]
+        struct TableReplacement<T> {
+            requirement: T,
+            provides: Option<Version>,
+        }
+
+        serde_untagged::UntaggedEnumVisitor::new()
+            .string(|string| {
+                T::deserialize(string.into_deserializer()).map(|requirement| Self {
+                    requirement,
+                    provides: None,
+                })
+            })
+            .map(|map| {
+                map.deserialize::<TableReplacement<T>>().map(|table| Self {
+                    requirement: table.requirement,
+                    provides: table.provides,
+                })
+            })
+            .deserialize(deserializer)
+    }
+}
+
+impl Replacement<Requirement> {
+    /// Returns `true` if the replacement satisfies the version constraints of a requirement on the
+    /// replaced package.
+    ///
+    /// Without a declared `provides` version, the constraints are ignored.
+    pub fn satisfies(&self, requirement: &Requirement) -> bool {
+        let Some(provides) = self.provides.as_ref() else {
+            return true;
+        };
+        match &requirement.source {
+            RequirementSource::Registry { specifier, .. } => specifier.contains(provides),
+            _ => true,
+        }
+    }
+}
+
+/// A set of replacements, keyed by the name of the package to replace.
+#[derive(Debug, Default, Clone)]
+pub struct Replacements(FxHashMap<PackageName, Replacement<Requirement>>);
+
+impl Replacements {
+    /// Create an indexed set of replacements.
+    pub fn from_entries(
+        entries: impl IntoIterator<Item = (PackageName, Replacement<Requirement>)>,
+    ) -> Self {
+        Self(entries.into_iter().collect())
+    }
+
+    /// Return `true` if there are no replacements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Get the replacement for a package, if any.
+    pub fn get(&self, name: &PackageName) -> Option<&Replacement<Requirement>> {
+        self.0.get(name)
+    }
+
+    /// Return an iterator over the replaced packages and their replacements.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &Replacement<Requirement>)> {
+        self.0.iter()
+    }
+
+    /// Return an iterator over the requirements of all replacements.
+    pub fn requirements(&self) -> impl Iterator<Item = &Requirement> {
+        self.0.values().map(|replacement| &replacement.requirement)
+    }
+
+    /// Substitute the replacement for a requirement on a replaced package.
+    ///
+    /// The substituted requirement retains the extras, groups, origin, and markers of the original
+    /// requirement, but takes its name and source from the replacement.
+    pub fn apply<'a>(&'a self, requirement: Cow<'a, Requirement>) -> Cow<'a, Requirement> {
+        let Some(replacement) = self.0.get(&requirement.name) else {
+            return requirement;
+        };
+        let replacement = &replacement.requirement;
+        let mut marker = requirement.marker;
+        marker.and(replacement.marker);
+        let mut extras = requirement.extras.to_vec();
+        extras.extend(replacement.extras.iter().cloned());
+        extras.sort_unstable();
+        extras.dedup();
+        Cow::Owned(Requirement {
+            name: replacement.name.clone(),
+            extras: extras.into_boxed_slice(),
+            groups: requirement.groups.clone(),
+            source: replacement.source.clone(),
+            origin: requirement.origin.clone(),
+            marker,
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use uv_pep440::Version;
+use uv_pep508::{MarkerEnvironment, StringVersion};
+
+/// The supported Python implementations to target during resolution.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum TargetImplementation {
+    /// CPython, the reference implementation.
+    #[cfg_attr(feature = "clap", value(name = "cpython"))]
+    #[serde(rename = "cpython")]
+    CPython,
+
+    /// PyPy.
+    #[cfg_attr(feature = "clap", value(name = "pypy"))]
+    #[serde(rename = "pypy")]
+    PyPy,
+
+    /// GraalPy.
+    #[cfg_attr(feature = "clap", value(name = "graalpy"))]
+    #[serde(rename = "graalpy")]
+    GraalPy,
+}
+
+impl TargetImplementation {
+    /// Return the implementation name, as in `sys.implementation.name` (e.g., `pypy`).
+    pub fn implementation_name(self) -> &'static str {
+        match self {
+            Self::CPython => "cpython",
+            Self::PyPy => "pypy",
+            Self::GraalPy => "graalpy",
+        }
+    }
+
+    /// Return the implementation name, as in `platform.python_implementation()` (e.g., `PyPy`).
+    pub fn platform_python_implementation(self) -> &'static str {
+        match self {
+            Self::CPython => "CPython",
+            Self::PyPy => "PyPy",
+            Self::GraalPy => "GraalVM",
+        }
+    }
+
+    /// Return the implementation version to assume when the implementation isn't installed.
+    ///
+    /// For CPython, the implementation version is the Python version. For PyPy and GraalPy, we
+    /// assume the latest release series at time of writing (PyPy 7.3 and GraalPy 24.2).
+    pub fn implementation_version(self, python_version: (u8, u8)) -> (u8, u8) {
+        match self {
+            Self::CPython => python_version,
+            Self::PyPy => (7, 3),
+            Self::GraalPy => (24, 2),
+        }
+    }
+
+    /// Return the [`MarkerEnvironment`] for this implementation, using the given implementation
+    /// version.
+    ///
+    /// For CPython, the implementation version is taken from the Python version of the base
+    /// environment.
+    pub fn markers(
+        self,
+        base: &MarkerEnvironment,
+        implementation_version: (u8, u8),
+    ) -> MarkerEnvironment {
+        let implementation_version = if self == Self::CPython {
+            base.python_full_version().clone()
+        } else {
+            let (major, minor) = implementation_version;
+            StringVersion {
+                string: format!("{major}.{minor}"),
+                version: Version::new([u64::from(major), u64::from(minor)]),
+            }
+        };
+        base.clone()
+            .with_implementation_name(self.implementation_name())
+            .with_platform_python_implementation(self.platform_python_implementation())
+            .with_implementation_version(implementation_version)
+    }
+}
+
+/// The ABI flags of the target Python interpreter, as in `sys.abiflags` (e.g., `t` for a
+/// free-threaded build of CPython, or `d` for a debug build).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct TargetAbiFlags {
+    gil_disabled: bool,
+    debug_enabled: bool,
+}
+
+impl TargetAbiFlags {
+    /// Returns `true` if the target interpreter is a free-threaded build.
+    pub fn gil_disabled(self) -> bool {
+        self.gil_disabled
+    }
+
+    /// Returns `true` if the target interpreter is a debug build.
+    pub fn debug_enabled(self) -> bool {
+        self.debug_enabled
+    }
+}
+
+impl FromStr for TargetAbiFlags {
+    type Err = String;
+
+    /// Parses [`TargetAbiFlags`] from a string of ABI flags (e.g., `t`, `d`, or `td`). An empty
+    /// string indicates a default build.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut flags = Self::default();
+        for flag in s.trim().chars() {
+            match flag {
+                't' => flags.gil_disabled = true,
+                'd' => flags.debug_enabled = true,
+                _ => {
+                    return Err(format!(
+                        "Invalid ABI flag `{flag}` in `{s}`: expected any of `t` (free-threaded) or `d` (debug)"
+                    ));
+                }
+            }
+        }
+        Ok(flags)
+    }
+}
+
+impl std::fmt::Display for TargetAbiFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.gil_disabled {
+            f.write_str("t")?;
+        }
+        if self.debug_enabled {
+            f.write_str("d")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TargetAbiFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <Cow<'_, str>>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for TargetAbiFlags {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("TargetAbiFlags")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "pattern": "^[td]*$",
+            "description": "The ABI flags of the target Python interpreter, e.g., `t` for a free-threaded build."
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_abiflags() {
+        let flags = TargetAbiFlags::from_str("t").unwrap();
+        assert!(flags.gil_disabled());
+        assert!(!flags.debug_enabled());
+
+        let flags = TargetAbiFlags::from_str("td").unwrap();
+        assert!(flags.gil_disabled());
+        assert!(flags.debug_enabled());
+        assert_eq!(flags.to_string(), "td");
+
+        assert_eq!(
+            TargetAbiFlags::from_str("").unwrap(),
+            TargetAbiFlags::default()
+        );
+        assert!(TargetAbiFlags::from_str("m").is_err());
+    }
+}
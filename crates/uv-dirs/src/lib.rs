@@ -6,7 +6,9 @@ use std::{
 
 use etcetera::BaseStrategy;
 
+use uv_fs::Simplified;
 use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 /// Returns an appropriate user-level directory for storing executables.
 ///
@@ -22,18 +24,43 @@ use uv_static::EnvVars;
 /// Returns `None` if a directory cannot be found, i.e., if `$HOME` cannot be resolved. Does not
 /// check if the directory exists.
 pub fn user_executable_directory(override_variable: Option<&'static str>) -> Option<PathBuf> {
+    user_executable_directory_with_source(override_variable).map(|(path, _)| path)
+}
+
+/// Returns the directory from [`user_executable_directory`], along with the [`DirectorySource`]
+/// from which it was resolved.
+pub fn user_executable_directory_with_source(
+    override_variable: Option<&'static str>,
+) -> Option<(PathBuf, DirectorySource)> {
     override_variable
-        .and_then(std::env::var_os)
-        .and_then(parse_path)
-        .or_else(|| std::env::var_os(EnvVars::XDG_BIN_HOME).and_then(parse_xdg_path))
+        .and_then(|variable| {
+            std::env::var_os(variable)
+                .and_then(parse_path)
+                .map(|path| (path, DirectorySource::EnvironmentVariable(variable)))
+        })
+        .or_else(|| {
+            std::env::var_os(EnvVars::XDG_BIN_HOME)
+                .and_then(parse_xdg_path)
+                .map(|path| {
+                    (
+                        path,
+                        DirectorySource::EnvironmentVariable(EnvVars::XDG_BIN_HOME),
+                    )
+                })
+        })
         .or_else(|| {
             std::env::var_os(EnvVars::XDG_DATA_HOME)
                 .and_then(parse_xdg_path)
-                .map(|path| path.join("../bin"))
+                .map(|path| {
+                    (
+                        path.join("../bin"),
+                        DirectorySource::EnvironmentVariable(EnvVars::XDG_DATA_HOME),
+                    )
+                })
         })
         .or_else(|| {
             let home_dir = etcetera::home_dir().ok();
-            home_dir.map(|path| path.join(".local").join("bin"))
+            home_dir.map(|path| (path.join(".local").join("bin"), DirectorySource::Default))
         })
 }
 
@@ -41,9 +68,20 @@ pub fn user_executable_directory(override_variable: Option<&'static str>) -> Opt
 ///
 /// Corresponds to `$XDG_CACHE_HOME/uv` on Unix.
 pub fn user_cache_dir() -> Option<PathBuf> {
+    user_cache_dir_with_source().map(|(path, _)| path)
+}
+
+/// Returns the directory from [`user_cache_dir`], along with the [`DirectorySource`] from which it
+/// was resolved.
+pub fn user_cache_dir_with_source() -> Option<(PathBuf, DirectorySource)> {
     etcetera::base_strategy::choose_base_strategy()
         .ok()
-        .map(|dirs| dirs.cache_dir().join("uv"))
+        .map(|dirs| {
+            (
+                dirs.cache_dir().join("uv"),
+                xdg_source(EnvVars::XDG_CACHE_HOME),
+            )
+        })
 }
 
 /// Returns the legacy cache directory path.
@@ -67,9 +105,20 @@ pub fn legacy_user_cache_dir() -> Option<PathBuf> {
 ///
 /// Corresponds to `$XDG_DATA_HOME/uv` on Unix.
 pub fn user_state_dir() -> Option<PathBuf> {
+    user_state_dir_with_source().map(|(path, _)| path)
+}
+
+/// Returns the directory from [`user_state_dir`], along with the [`DirectorySource`] from which it
+/// was resolved.
+pub fn user_state_dir_with_source() -> Option<(PathBuf, DirectorySource)> {
     etcetera::base_strategy::choose_base_strategy()
         .ok()
-        .map(|dirs| dirs.data_dir().join("uv"))
+        .map(|dirs| {
+            (
+                dirs.data_dir().join("uv"),
+                xdg_source(EnvVars::XDG_DATA_HOME),
+            )
+        })
 }
 
 /// Returns the legacy state directory path.
@@ -83,6 +132,110 @@ pub fn legacy_user_state_dir() -> Option<PathBuf> {
         .map(|dir| if cfg!(windows) { dir.join("data") } else { dir })
 }
 
+/// Returns an appropriate user-level directory for storing non-essential application state, e.g.,
+/// the registry of project environments.
+///
+/// Corresponds to `$XDG_STATE_HOME/uv` on Unix. Windows has no equivalent of the XDG state
+/// directory, so this returns `None` there and callers should fall back to [`user_state_dir`].
+pub fn user_local_state_dir() -> Option<PathBuf> {
+    user_local_state_dir_with_source().map(|(path, _)| path)
+}
+
+/// Returns the directory from [`user_local_state_dir`], along with the [`DirectorySource`] from
+/// which it was resolved.
+pub fn user_local_state_dir_with_source() -> Option<(PathBuf, DirectorySource)> {
+    etcetera::base_strategy::choose_base_strategy()
+        .ok()
+        .and_then(|dirs| dirs.state_dir())
+        .map(|dir| (dir.join("uv"), xdg_source(EnvVars::XDG_STATE_HOME)))
+}
+
+/// The source from which a uv storage directory was resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectorySource {
+    /// A command-line argument, e.g., `--cache-dir`.
+    Argument(&'static str),
+    /// An environment variable, e.g., `UV_TOOL_DIR` or `XDG_BIN_HOME`.
+    EnvironmentVariable(&'static str),
+    /// A setting in a configuration file, e.g., `cache-dir`.
+    Setting(&'static str),
+    /// A pre-existing directory at a legacy location, respected for backwards compatibility.
+    Legacy,
+    /// The platform default, e.g., `$XDG_DATA_HOME/uv` or `$HOME/.local/share/uv`.
+    Default,
+    /// A directory relative to the working directory, used if the home directory is unknown.
+    Fallback,
+}
+
+impl DirectorySource {
+    /// Returns `true` if the directory was explicitly requested by the user, rather than derived
+    /// from the platform conventions.
+    pub fn is_override(self) -> bool {
+        matches!(
+            self,
+            Self::Argument(_) | Self::EnvironmentVariable(_) | Self::Setting(_)
+        )
+    }
+}
+
+impl std::fmt::Display for DirectorySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Argument(name) | Self::EnvironmentVariable(name) => write!(f, "`{name}`"),
+            Self::Setting(name) => write!(f, "`{name}` setting"),
+            Self::Legacy => f.write_str("legacy location"),
+            Self::Default => f.write_str("default"),
+            Self::Fallback => f.write_str("fallback"),
+        }
+    }
+}
+
+/// Returns the default location of a directory if the directory was overridden, but is empty,
+/// while the default location contains existing data, warning the user if so.
+///
+/// uv doesn't migrate data between locations, so an override that hides existing data (e.g., a
+/// `UV_TOOL_DIR` that was set after tools were installed) is likely unintentional.
+pub fn warn_if_hidden_default(
+    purpose: &str,
+    path: &Path,
+    source: DirectorySource,
+    default: impl FnOnce() -> Option<PathBuf>,
+) -> Option<PathBuf> {
+    if !source.is_override() || !is_empty_dir(path) {
+        return None;
+    }
+    let default = default().filter(|default| default != path && !is_empty_dir(default))?;
+    warn_user_once!(
+        "{purpose} directory `{}` (from {source}) is empty, but existing data was found at the default location: `{}`",
+        path.user_display(),
+        default.user_display(),
+    );
+    Some(default)
+}
+
+/// Returns `true` if the path doesn't exist or is an empty directory.
+fn is_empty_dir(path: &Path) -> bool {
+    match fs_err::read_dir(path) {
+        Ok(mut entries) => entries.next().is_none(),
+        Err(_) => !path.exists(),
+    }
+}
+
+/// Returns the [`DirectorySource`] for a directory derived from the given XDG variable.
+///
+/// The XDG variables are only respected on Unix, and only if they contain an absolute path.
+fn xdg_source(variable: &'static str) -> DirectorySource {
+    if cfg!(not(windows))
+        && std::env::var_os(variable)
+            .and_then(parse_xdg_path)
+            .is_some()
+    {
+        DirectorySource::EnvironmentVariable(variable)
+    } else {
+        DirectorySource::Default
+    }
+}
+
 /// Return a [`PathBuf`] from the given [`OsString`], if non-empty.
 ///
 /// Unlike [`parse_xdg_path`], this function accepts both relative and absolute paths,
@@ -126,6 +279,12 @@ pub fn user_uv_config_dir() -> Option<PathBuf> {
     })
 }
 
+/// Returns the directory from [`user_uv_config_dir`], along with the [`DirectorySource`] from
+/// which it was resolved.
+pub fn user_uv_config_dir_with_source() -> Option<(PathBuf, DirectorySource)> {
+    user_uv_config_dir().map(|path| (path, xdg_source(EnvVars::XDG_CONFIG_HOME)))
+}
+
 #[cfg(not(windows))]
 fn locate_system_config_xdg(value: Option<&str>) -> Option<PathBuf> {
     // On Linux and macOS, read the `XDG_CONFIG_DIRS` environment variable.
@@ -2,36 +2,43 @@
 //! [installer][`uv_installer`] and [build][`uv_build`] through [`BuildDispatch`]
 //! implementing [`BuildContext`].
 
+use std::borrow::Cow;
 use std::ffi::{OsStr, OsString};
 use std::path::Path;
 
 use anyhow::{Context, Result};
 use futures::FutureExt;
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 use thiserror::Error;
-use tracing::{debug, instrument, trace};
+use tracing::{Level, debug, instrument, trace};
 
 use uv_build_backend::check_direct_build;
-use uv_build_frontend::{SourceBuild, SourceBuildContext};
-use uv_cache::Cache;
+use uv_build_frontend::{BuildEvents, BuildWarnings, SourceBuild, SourceBuildContext};
+use uv_cache::{Cache, CacheShard};
 use uv_client::RegistryClient;
 use uv_configuration::{
-    BuildKind, BuildOptions, Constraints, IndexStrategy, NoSources, Overrides, Reinstall,
+    BuildKind, BuildOptions, BuildRuntime, Constraints, IndexStrategy, NoSources, Overrides,
+    Reinstall,
 };
 use uv_configuration::{BuildOutput, Concurrency, Excludes};
 use uv_distribution::DistributionDatabase;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    CachedDist, ConfigSettings, DependencyMetadata, ExtraBuildRequires, ExtraBuildVariables,
-    Identifier, IndexCapabilities, IndexLocations, IsBuildBackendError, Name,
+    BuildDependencyLock, CachedDist, ConfigSettings, DependencyMetadata, ExtraBuildRequires,
+    ExtraBuildVariables, Identifier, IndexCapabilities, IndexLocations, IsBuildBackendError, Name,
     PackageConfigSettings, Requirement, Resolution, SourceDist, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
 use uv_installer::{InstallationStrategy, Installer, Plan, Planner, Preparer, SitePackages};
+use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_pypi_types::Conflicts;
-use uv_python::{Interpreter, PythonEnvironment};
+use uv_python::{
+    EnvironmentPreference, Interpreter, PythonEnvironment, PythonInstallation, PythonPreference,
+    PythonRequest, PythonVariant, VersionRequest,
+};
 use uv_requirements::LookaheadResolver;
 use uv_resolver::{
     ExcludeNewer, FlatIndex, Flexibility, InMemoryIndex, Manifest, OptionsBuilder,
@@ -65,6 +72,24 @@ pub enum BuildDispatchError {
 
     #[error(transparent)]
     Lookahead(#[from] uv_requirements::Error),
+
+    #[error("{context}")]
+    Constrained {
+        context: String,
+        constraints: Vec<Requirement>,
+        #[source]
+        err: uv_resolver::ResolveError,
+    },
+
+    #[error(
+        "Network connectivity is disabled, but build requirements are missing from the cache: {}",
+        requirements.iter().map(|requirement| format!("`{requirement}`")).join(", ")
+    )]
+    Offline {
+        requirements: Vec<String>,
+        #[source]
+        err: uv_installer::PrepareError,
+    },
 }
 
 impl uv_errors::Hint for BuildDispatchError {
@@ -72,6 +97,7 @@ impl uv_errors::Hint for BuildDispatchError {
         match self {
             Self::BuildFrontend(err) => err.hints(),
             Self::Resolve(err) => err.hints(),
+            Self::Prepare(err) => err.hints(),
             Self::Anyhow(err) => {
                 // Walk the anyhow error chain to find hint-bearing errors
                 // (e.g., ResolveError wrapped via `with_context`).
@@ -85,6 +111,26 @@ impl uv_errors::Hint for BuildDispatchError {
                 }
                 uv_errors::Hints::none()
             }
+            Self::Constrained {
+                constraints, err, ..
+            } => {
+                let mut hints = err.hints().into_owned();
+                for constraint in constraints {
+                    hints.push(format!(
+                        "`{}` was constrained to `{}` by a build constraint; consider relaxing the constraint in `{}` or `{}`",
+                        constraint.name.cyan(),
+                        constraint.cyan(),
+                        "--build-constraint".green(),
+                        "build-constraint-dependencies".green(),
+                    ));
+                }
+                hints
+            }
+            Self::Offline { .. } => uv_errors::Hints::from(format!(
+                "Build requirements can only be read from the cache when the network is disabled; run the command without `{}` to populate the cache, or use `{}` to install from pre-built wheels only",
+                "--offline".green(),
+                "--no-build".green(),
+            )),
             _ => uv_errors::Hints::none(),
         }
     }
@@ -98,7 +144,9 @@ impl IsBuildBackendError for BuildDispatchError {
             | Self::Join(_)
             | Self::Anyhow(_)
             | Self::Prepare(_)
-            | Self::Lookahead(_) => false,
+            | Self::Lookahead(_)
+            | Self::Constrained { .. }
+            | Self::Offline { .. } => false,
             Self::BuildFrontend(err) => err.is_build_backend_error(),
         }
     }
@@ -121,12 +169,16 @@ pub struct BuildDispatch<'a> {
     extra_build_variables: &'a ExtraBuildVariables,
     link_mode: uv_install_wheel::LinkMode,
     build_options: &'a BuildOptions,
+    build_runtime: &'a BuildRuntime,
     config_settings: &'a ConfigSettings,
     config_settings_package: &'a PackageConfigSettings,
     hasher: &'a HashStrategy,
     exclude_newer: ExcludeNewer,
     source_build_context: SourceBuildContext,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    build_dependency_lock: Option<BuildDependencyLock>,
+    excluded_packages: &'a [PackageName],
+    build_python: Option<PythonRequest>,
     sources: NoSources,
     source_tree_editable_policy: SourceTreeEditablePolicy,
     workspace_cache: WorkspaceCache,
@@ -152,6 +204,7 @@ impl<'a> BuildDispatch<'a> {
         extra_build_variables: &'a ExtraBuildVariables,
         link_mode: uv_install_wheel::LinkMode,
         build_options: &'a BuildOptions,
+        build_runtime: &'a BuildRuntime,
         hasher: &'a HashStrategy,
         exclude_newer: ExcludeNewer,
         sources: NoSources,
@@ -160,6 +213,17 @@ impl<'a> BuildDispatch<'a> {
         concurrency: Concurrency,
         preview: Preview,
     ) -> Self {
+        let mut source_build_context =
+            SourceBuildContext::new(concurrency.builds_semaphore.clone())
+                .with_timeout(build_runtime.timeout())
+                .with_retries(build_runtime.retries())
+                .with_events(shared_state.build_events.clone());
+        // Only scan the build backend output for warnings if they're shown, i.e., with
+        // `--show-build-warnings` or `--verbose` (which enables debug logging).
+        if build_runtime.show_warnings() || tracing::enabled!(Level::DEBUG) {
+            source_build_context =
+                source_build_context.with_warning_patterns(build_runtime.warning_patterns());
+        }
         Self {
             client,
             cache,
@@ -177,10 +241,14 @@ impl<'a> BuildDispatch<'a> {
             extra_build_variables,
             link_mode,
             build_options,
+            build_runtime,
             hasher,
             exclude_newer,
-            source_build_context: SourceBuildContext::new(concurrency.builds_semaphore.clone()),
+            source_build_context,
             build_extra_env_vars: FxHashMap::default(),
+            build_dependency_lock: None,
+            excluded_packages: &[],
+            build_python: build_runtime.build_python().map(PythonRequest::parse),
             sources,
             source_tree_editable_policy,
             workspace_cache,
@@ -203,6 +271,118 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Take the warnings emitted by build backends during successful builds so far, sorted by
+    /// package.
+    pub fn take_build_warnings(&self) -> Vec<BuildWarnings> {
+        self.source_build_context.take_warnings()
+    }
+
+    /// Reuse (and record) the locked build dependencies when setting up build environments.
+    #[must_use]
+    pub fn with_build_dependency_lock(
+        mut self,
+        build_dependency_lock: Option<BuildDependencyLock>,
+    ) -> Self {
+        self.build_dependency_lock = build_dependency_lock;
+        self
+    }
+
+    /// Set the packages that must never be included when resolving build dependencies (e.g.,
+    /// `--exclude`).
+    #[must_use]
+    pub fn with_excluded_packages(mut self, excluded_packages: &'a [PackageName]) -> Self {
+        self.excluded_packages = excluded_packages;
+        self
+    }
+
+    /// Create a [`BuildDispatch`] that builds and resolves for a different interpreter.
+    ///
+    /// The in-memory index is specific to the interpreter, so it isn't shared.
+    fn with_interpreter<'b>(&'b self, interpreter: &'b Interpreter) -> BuildDispatch<'b> {
+        BuildDispatch {
+            client: self.client,
+            cache: self.cache,
+            constraints: self.constraints,
+            interpreter,
+            index_locations: self.index_locations,
+            index_strategy: self.index_strategy,
+            flat_index: self.flat_index,
+            shared_state: self.shared_state.fork(),
+            dependency_metadata: self.dependency_metadata,
+            build_isolation: self.build_isolation,
+            extra_build_requires: self.extra_build_requires,
+            extra_build_variables: self.extra_build_variables,
+            link_mode: self.link_mode,
+            build_options: self.build_options,
+            build_runtime: self.build_runtime,
+            config_settings: self.config_settings,
+            config_settings_package: self.config_settings_package,
+            hasher: self.hasher,
+            exclude_newer: self.exclude_newer.clone(),
+            source_build_context: self.source_build_context.clone(),
+            build_extra_env_vars: self.build_extra_env_vars.clone(),
+            build_dependency_lock: self.build_dependency_lock.clone(),
+            excluded_packages: self.excluded_packages,
+            build_python: self.build_python.clone(),
+            sources: self.sources.clone(),
+            source_tree_editable_policy: self.source_tree_editable_policy,
+            workspace_cache: self.workspace_cache.clone(),
+            concurrency: self.concurrency.clone(),
+            preview: self.preview,
+        }
+    }
+
+    /// Find the interpreter to build with if the target interpreter doesn't satisfy the
+    /// `requires-python` of the source tree, as requested with `--build-python`.
+    ///
+    /// Returns `None` if the target interpreter should be used.
+    fn find_build_interpreter(
+        &self,
+        source_tree: &Path,
+        dist_name: Option<&PackageName>,
+    ) -> Result<Option<Interpreter>, uv_build_frontend::Error> {
+        let Some(request) = self.build_python.as_ref() else {
+            return Ok(None);
+        };
+        // Without build isolation, the build environment is fixed.
+        if !self.build_isolation.is_isolated(dist_name) {
+            return Ok(None);
+        }
+        let Some(requires_python) = uv_build_frontend::read_requires_python(source_tree) else {
+            return Ok(None);
+        };
+        if requires_python.contains(self.interpreter.python_version()) {
+            return Ok(None);
+        }
+
+        // If no specific interpreter was requested, select any interpreter that satisfies the
+        // `requires-python`. Otherwise, the build fails if the requested interpreter doesn't.
+        let request = match request {
+            PythonRequest::Default | PythonRequest::Any => PythonRequest::Version(
+                VersionRequest::from_specifiers(requires_python, PythonVariant::Default),
+            ),
+            request => request.clone(),
+        };
+        let interpreter = PythonInstallation::find_existing(
+            &request,
+            EnvironmentPreference::OnlySystem,
+            PythonPreference::default(),
+            self.cache,
+        )
+        .map_err(|err| uv_build_frontend::Error::BuildPython {
+            request: request.to_canonical_string(),
+            err: Box::new(err),
+        })?
+        .into_interpreter();
+        debug!(
+            "Building `{}` with Python {} at: {}",
+            source_tree.display(),
+            interpreter.python_version(),
+            interpreter.sys_executable().display()
+        );
+        Ok(Some(interpreter))
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -237,6 +417,14 @@ impl BuildContext for BuildDispatch<'_> {
         self.build_options
     }
 
+    fn build_runtime(&self) -> &BuildRuntime {
+        self.build_runtime
+    }
+
+    fn concurrency(&self) -> &Concurrency {
+        &self.concurrency
+    }
+
     fn build_isolation(&self) -> BuildIsolation<'_> {
         self.build_isolation
     }
@@ -273,12 +461,30 @@ impl BuildContext for BuildDispatch<'_> {
         self.extra_build_variables
     }
 
+    fn build_dependency_lock(&self) -> Option<&BuildDependencyLock> {
+        self.build_dependency_lock.as_ref()
+    }
+
+    fn build_python(&self) -> Option<&PythonRequest> {
+        self.build_python.as_ref()
+    }
+
     async fn resolve<'data>(
         &'data self,
         requirements: &'data [Requirement],
+        constraints: &'data [Requirement],
         build_stack: &'data BuildStack,
     ) -> Result<ResolvedRequirements, BuildDispatchError> {
         let python_requirement = PythonRequirement::from_interpreter(self.interpreter);
+
+        // Apply the given constraints in addition to the build constraints.
+        let constraints = if constraints.is_empty() {
+            Cow::Borrowed(self.constraints)
+        } else {
+            Cow::Owned(Constraints::from_requirements(
+                self.constraints.requirements().chain(constraints).cloned(),
+            ))
+        };
         let marker_env = self.interpreter.resolver_marker_environment();
         let resolver_env = ResolverEnvironment::specific(marker_env);
         let tags = self.interpreter.tags()?;
@@ -297,7 +503,7 @@ impl BuildContext for BuildDispatch<'_> {
         let excludes = Excludes::default();
         let (lookaheads, hasher) = LookaheadResolver::new(
             requirements,
-            self.constraints,
+            &constraints,
             &overrides,
             &excludes,
             &hasher,
@@ -313,7 +519,7 @@ impl BuildContext for BuildDispatch<'_> {
         .await?;
 
         let manifest = Manifest::simple(requirements.to_vec())
-            .with_constraints(self.constraints.clone())
+            .with_constraints(constraints.into_owned())
             .with_lookaheads(lookaheads);
 
         let resolver = Resolver::new(
@@ -322,6 +528,7 @@ impl BuildContext for BuildDispatch<'_> {
                 .exclude_newer(self.exclude_newer.clone())
                 .index_strategy(self.index_strategy)
                 .build_options(self.build_options.clone())
+                .excluded_packages(self.excluded_packages.to_vec())
                 .flexibility(Flexibility::Fixed)
                 .build(),
             &python_requirement,
@@ -342,7 +549,7 @@ impl BuildContext for BuildDispatch<'_> {
             )
             .with_build_stack(build_stack),
         )?;
-        let resolution = Resolution::from(resolver.resolve().await.with_context(|| {
+        let context = || {
             format!(
                 "No solution found when resolving: {}",
                 requirements
@@ -350,7 +557,30 @@ impl BuildContext for BuildDispatch<'_> {
                     .map(|requirement| format!("`{requirement}`"))
                     .join(", ")
             )
-        })?);
+        };
+        let resolution = match resolver.resolve().await {
+            Ok(resolution) => Resolution::from(resolution),
+            Err(uv_resolver::ResolveError::NoSolution(err)) => {
+                // Note any build constraints on the packages involved in the conflict, as they're
+                // applied implicitly.
+                let constraints = err
+                    .packages()
+                    .filter_map(|name| self.constraints.get(name))
+                    .flatten()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let err = uv_resolver::ResolveError::NoSolution(err);
+                if constraints.is_empty() {
+                    return Err(anyhow::Error::new(err).context(context()).into());
+                }
+                return Err(BuildDispatchError::Constrained {
+                    context: context(),
+                    constraints,
+                    err,
+                });
+            }
+            Err(err) => return Err(anyhow::Error::new(err).context(context()).into()),
+        };
         Ok(ResolvedRequirements::new(resolution, hasher))
     }
 
@@ -389,6 +619,7 @@ impl BuildContext for BuildDispatch<'_> {
             cached,
             remote,
             reinstalls,
+            entry_points,
             extraneous: _,
         } = Planner::new(resolution).build(
             site_packages,
@@ -407,7 +638,11 @@ impl BuildContext for BuildDispatch<'_> {
         )?;
 
         // Nothing to do.
-        if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() {
+        if remote.is_empty()
+            && cached.is_empty()
+            && reinstalls.is_empty()
+            && entry_points.is_empty()
+        {
             debug!("No build requirements to install for build");
             return Ok(vec![]);
         }
@@ -415,9 +650,13 @@ impl BuildContext for BuildDispatch<'_> {
         // Verify that none of the missing distributions are already in the build stack.
         for dist in &remote {
             let id = dist.distribution_id();
-            if build_stack.contains(&id) {
+            if let Some(cycle) = build_stack.cycle(&id) {
                 return Err(BuildDispatchError::BuildFrontend(
-                    uv_build_frontend::Error::CyclicBuildDependency(dist.name().clone()).into(),
+                    uv_build_frontend::Error::CyclicBuildDependency {
+                        package: dist.name().clone(),
+                        cycle,
+                    }
+                    .into(),
                 ));
             }
         }
@@ -445,9 +684,23 @@ impl BuildContext for BuildDispatch<'_> {
                 remote.iter().map(ToString::to_string).join(", ")
             );
 
+            // In offline mode, a build requirement that isn't cached can never be installed, so
+            // report the missing requirements rather than the underlying network error.
+            let requirements = self
+                .client
+                .connectivity()
+                .is_offline()
+                .then(|| remote.iter().map(ToString::to_string).collect::<Vec<_>>());
+
             preparer
                 .prepare(remote, &self.shared_state.in_flight, resolution)
-                .await?
+                .await
+                .map_err(|err| match requirements {
+                    Some(requirements) if err.is_offline() => {
+                        BuildDispatchError::Offline { requirements, err }
+                    }
+                    _ => BuildDispatchError::Prepare(err),
+                })?
         };
 
         // Remove any unnecessary packages.
@@ -468,6 +721,13 @@ impl BuildContext for BuildDispatch<'_> {
             }
         }
 
+        // Regenerate the entry points of any editables whose entry points changed.
+        for update in &entry_points {
+            update
+                .execute(venv)
+                .context("Failed to update entry points for build dependencies")?;
+        }
+
         // Install the resolved distributions.
         let mut wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
         if !wheels.is_empty() {
@@ -496,6 +756,7 @@ impl BuildContext for BuildDispatch<'_> {
         stop_discovery_at: Option<&'data Path>,
         version_id: Option<&'data str>,
         dist: Option<&'data SourceDist>,
+        cache_shard: Option<&'data CacheShard>,
         sources: &'data NoSources,
         build_kind: BuildKind,
         build_output: BuildOutput,
@@ -527,7 +788,14 @@ impl BuildContext for BuildDispatch<'_> {
 
         // Push the current distribution onto the build stack, to prevent cyclic dependencies.
         if let Some(dist) = dist {
-            build_stack.insert(dist.distribution_id());
+            let id = dist.distribution_id();
+            if let Some(cycle) = build_stack.cycle(&id) {
+                return Err(uv_build_frontend::Error::CyclicBuildDependency {
+                    package: dist.name().clone(),
+                    cycle,
+                });
+            }
+            build_stack.insert(id, dist.name(), dist_version);
         }
 
         // Get package-specific config settings if available; otherwise, use global settings.
@@ -541,18 +809,26 @@ impl BuildContext for BuildDispatch<'_> {
             self.config_settings.clone()
         };
 
-        // Get package-specific environment variables if available.
+        // Get package-specific environment variables, and those from the build environment
+        // allowlist, if available.
         let mut environment_variables = self.build_extra_env_vars.clone();
-        if let Some(name) = dist_name {
-            if let Some(package_vars) = self.extra_build_variables.get(name) {
-                environment_variables.extend(
-                    package_vars
-                        .iter()
-                        .map(|(key, value)| (OsString::from(key), OsString::from(value))),
-                );
-            }
+        if let Some(package_vars) = self.extra_build_variables.for_package(dist_name) {
+            environment_variables.extend(
+                package_vars
+                    .iter()
+                    .map(|(key, value)| (OsString::from(key), OsString::from(value))),
+            );
         }
 
+        // If requested, build with a different interpreter if the target interpreter is
+        // incompatible with the package.
+        let source_tree = subdirectory.map_or_else(|| source.to_path_buf(), |dir| source.join(dir));
+        let build_interpreter = self.find_build_interpreter(&source_tree, dist_name)?;
+        let build_dispatch = build_interpreter
+            .as_ref()
+            .map(|interpreter| self.with_interpreter(interpreter));
+        let build_dispatch = build_dispatch.as_ref().unwrap_or(self);
+
         let builder = SourceBuild::setup(
             source,
             subdirectory,
@@ -560,10 +836,12 @@ impl BuildContext for BuildDispatch<'_> {
             stop_discovery_at,
             dist_name,
             dist_version,
-            self.interpreter,
-            self,
+            build_dispatch.interpreter,
+            build_dispatch,
             self.source_build_context.clone(),
             version_id,
+            matches!(dist, Some(SourceDist::Registry(_))),
+            cache_shard,
             self.index_locations,
             sources.clone(),
             self.workspace_cache(),
@@ -573,12 +851,28 @@ impl BuildContext for BuildDispatch<'_> {
             &build_stack,
             build_kind,
             environment_variables,
+            self.extra_build_variables.build_env().is_none(),
             build_output,
             self.client.credentials_cache(),
         )
         .boxed_local()
-        .await?;
-        Ok(builder)
+        .await;
+
+        // Record any failure to set up the build environment as a build event, if enabled.
+        if let (Err(err), Some(events_file)) = (&builder, self.build_runtime.events_file()) {
+            self.source_build_context
+                .events()
+                .sink(events_file)
+                .setup_failure(
+                    dist_name,
+                    dist_version,
+                    build_dispatch.interpreter,
+                    build_kind,
+                    err,
+                );
+        }
+
+        builder
     }
 
     async fn direct_build<'data>(
@@ -604,6 +898,17 @@ impl BuildContext for BuildDispatch<'_> {
             return Ok(None);
         }
 
+        // Wheels built via a source distribution require the build frontend.
+        if build_kind == BuildKind::Wheel
+            && self.build_runtime.via_sdist()
+            && !source_tree.join("PKG-INFO").is_file()
+        {
+            trace!(
+                "Skipping direct build for {identifier}, since the wheel is built via a source distribution"
+            );
+            return Ok(None);
+        }
+
         debug!("Performing direct build for {identifier}");
 
         let output_dir = output_dir.to_path_buf();
@@ -662,6 +967,8 @@ pub struct SharedState {
     in_flight: InFlight,
     /// Build directories for any PEP 517 builds executed during resolution or installation.
     build_arena: BuildArena<SourceBuild>,
+    /// The build events of the current invocation.
+    build_events: BuildEvents,
 }
 
 impl SharedState {
@@ -675,6 +982,7 @@ impl SharedState {
             git: self.git.clone(),
             capabilities: self.capabilities.clone(),
             build_arena: self.build_arena.clone(),
+            build_events: self.build_events.clone(),
             ..Default::default()
         }
     }
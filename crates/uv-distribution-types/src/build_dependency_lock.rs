@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use arcstr::ArcStr;
+use uv_normalize::PackageName;
+use uv_pep440::VersionSpecifier;
+use uv_pep508::{
+    MarkerEnvironment, MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString,
+    MarkerValueVersion,
+};
+use uv_pypi_types::HashDigest;
+
+use crate::{Name, Requirement, RequirementSource, ResolvedDist, VersionId};
+
+/// A build dependency of a source distribution, pinned to the exact version or source that was
+/// resolved, as recorded in a lockfile.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LockedBuildDependency {
+    /// The pinned build dependency, limited to the environment for which it was resolved.
+    pub requirement: Requirement,
+    /// The hashes of the build dependency's distributions, if known.
+    pub hashes: Vec<HashDigest>,
+}
+
+impl LockedBuildDependency {
+    /// Pin a resolved build dependency to its version or source, limited to the given environment.
+    pub fn from_resolved_dist(
+        dist: &ResolvedDist,
+        hashes: &[HashDigest],
+        marker: MarkerTree,
+    ) -> Self {
+        // Omit the index of registry distributions: the index is determined by the configuration
+        // at build time, and the locked hashes verify the distribution itself.
+        let source = match RequirementSource::from(dist) {
+            RequirementSource::Registry { specifier, .. } => RequirementSource::Registry {
+                specifier,
+                index: None,
+                conflict: None,
+            },
+            source => source,
+        };
+        Self {
+            requirement: Requirement {
+                name: dist.name().clone(),
+                extras: Box::default(),
+                groups: Box::default(),
+                marker,
+                source,
+                origin: None,
+            },
+            hashes: hashes.to_vec(),
+        }
+    }
+
+    /// Returns the [`VersionId`] of the build dependency, if it's pinned.
+    pub fn version_id(&self) -> Option<VersionId> {
+        self.requirement.pinned_version_id()
+    }
+}
+
+/// The build dependencies of source distributions, by package, to reuse when setting up build
+/// environments (e.g., as read from `uv.lock`), along with the build dependencies that were
+/// resolved during the current operation.
+///
+/// Build dependencies are resolved for a specific environment, so each locked build dependency is
+/// limited to the Python version and platform for which it was resolved. Resolved build
+/// dependencies are recorded as they're encountered (including concurrently, across builds), such
+/// that they can be written back to the lockfile.
+#[derive(Debug, Default, Clone)]
+pub struct BuildDependencyLock {
+    /// The locked build dependencies, by package.
+    locked: BTreeMap<PackageName, Vec<LockedBuildDependency>>,
+    /// The build dependencies resolved during the current operation, by package.
+    resolved: Arc<Mutex<BTreeMap<PackageName, Vec<LockedBuildDependency>>>>,
+    /// Whether to resolve the build dependencies of every source distribution that lacks locked
+    /// build dependencies, even if its metadata is available without a build.
+    exhaustive: bool,
+}
+
+impl BuildDependencyLock {
+    /// Create a [`BuildDependencyLock`] from the given locked build dependencies.
+    pub fn new(locked: BTreeMap<PackageName, Vec<LockedBuildDependency>>) -> Self {
+        Self {
+            locked,
+            resolved: Arc::default(),
+            exhaustive: false,
+        }
+    }
+
+    /// Set whether to resolve the build dependencies of every source distribution that lacks
+    /// locked build dependencies (e.g., when locking).
+    #[must_use]
+    pub fn with_exhaustive(mut self, exhaustive: bool) -> Self {
+        self.exhaustive = exhaustive;
+        self
+    }
+
+    /// Returns the locked build dependencies for the given package that apply to the given
+    /// environment, if any.
+    pub fn get(
+        &self,
+        package_name: &PackageName,
+        markers: &MarkerEnvironment,
+    ) -> Option<Vec<&LockedBuildDependency>> {
+        let dependencies = self
+            .locked
+            .get(package_name)?
+            .iter()
+            .filter(|dependency| dependency.requirement.evaluate_markers(Some(markers), &[]))
+            .collect::<Vec<_>>();
+        if dependencies.is_empty() {
+            None
+        } else {
+            Some(dependencies)
+        }
+    }
+
+    /// Returns `true` if the build dependencies of the given package must be resolved for the
+    /// given environment, such that they can be locked, even if the package could otherwise be
+    /// used without a build.
+    pub fn requires_resolution(
+        &self,
+        package_name: &PackageName,
+        markers: &MarkerEnvironment,
+    ) -> bool {
+        self.exhaustive
+            && self.get(package_name, markers).is_none()
+            && !self.resolved.lock().unwrap().contains_key(package_name)
+    }
+
+    /// Record the build dependencies that were resolved for the given package in the given
+    /// environment, replacing any build dependencies previously recorded for it.
+    pub fn record<'a>(
+        &self,
+        package_name: &PackageName,
+        dependencies: impl Iterator<Item = (&'a ResolvedDist, &'a [HashDigest])>,
+        markers: &MarkerEnvironment,
+    ) {
+        let marker = environment_marker(markers);
+        let mut dependencies = dependencies
+            .map(|(dist, hashes)| LockedBuildDependency::from_resolved_dist(dist, hashes, marker))
+            .collect::<Vec<_>>();
+        dependencies.sort();
+        self.resolved
+            .lock()
+            .unwrap()
+            .insert(package_name.clone(), dependencies);
+    }
+
+    /// Returns the build dependencies to lock, by package: the locked build dependencies, updated
+    /// with any build dependencies that were resolved during the current operation.
+    ///
+    /// Locked build dependencies for other environments are retained.
+    pub fn to_locked(&self) -> BTreeMap<PackageName, Vec<LockedBuildDependency>> {
+        let mut build_dependencies = self.locked.clone();
+        for (package_name, resolved) in self.resolved.lock().unwrap().iter() {
+            let dependencies = build_dependencies.entry(package_name.clone()).or_default();
+            dependencies.retain(|locked| {
+                resolved.iter().all(|resolved| {
+                    locked
+                        .requirement
+                        .marker
+                        .is_disjoint(resolved.requirement.marker)
+                })
+            });
+            dependencies.extend(resolved.iter().cloned());
+            dependencies.sort();
+        }
+        build_dependencies
+    }
+}
+
+/// Returns a marker that matches the Python version and platform of the given environment, to
+/// which resolved build dependencies are limited.
+fn environment_marker(markers: &MarkerEnvironment) -> MarkerTree {
+    let mut marker = MarkerTree::expression(MarkerExpression::Version {
+        key: MarkerValueVersion::PythonVersion,
+        specifier: VersionSpecifier::equals_version(markers.python_version().version.clone()),
+    });
+    marker.and(MarkerTree::expression(MarkerExpression::String {
+        key: MarkerValueString::SysPlatform,
+        operator: MarkerOperator::Equal,
+        value: ArcStr::from(markers.sys_platform()),
+    }));
+    marker.and(MarkerTree::expression(MarkerExpression::String {
+        key: MarkerValueString::PlatformMachine,
+        operator: MarkerOperator::Equal,
+        value: ArcStr::from(markers.platform_machine()),
+    }));
+    marker
+}
@@ -1,6 +1,6 @@
 use uv_cache_key::{CacheKey, CacheKeyHasher, cache_digest};
 
-use crate::{BuildVariables, ConfigSettings, ExtraBuildRequirement};
+use crate::{BuildTarget, BuildVariables, ConfigSettings, ExtraBuildRequirement};
 
 /// A digest representing the build settings, such as build dependencies or other build-time
 /// configuration.
@@ -12,6 +12,8 @@ pub struct BuildInfo {
     extra_build_requires: Vec<ExtraBuildRequirement>,
     #[serde(default, skip_serializing_if = "BuildVariables::is_empty")]
     extra_build_variables: BuildVariables,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    build_target: Option<BuildTarget>,
 }
 
 impl CacheKey for BuildInfo {
@@ -19,21 +21,27 @@ impl CacheKey for BuildInfo {
         self.config_settings.cache_key(state);
         self.extra_build_requires.cache_key(state);
         self.extra_build_variables.cache_key(state);
+        // Only hash the build target if present, to retain existing cache keys.
+        if let Some(build_target) = &self.build_target {
+            build_target.cache_key(state);
+        }
     }
 }
 
 impl BuildInfo {
     /// Creates a [`BuildInfo`] instance with the given configuration settings, extra build
-    /// dependencies, and extra build variables.
+    /// dependencies, and extra build variables, along with the build target, if any.
     pub fn from_settings(
         config_settings: &ConfigSettings,
         extra_build_dependencies: &[ExtraBuildRequirement],
         extra_build_variables: Option<&BuildVariables>,
+        build_target: Option<&BuildTarget>,
     ) -> Self {
         Self {
             config_settings: config_settings.clone(),
             extra_build_requires: extra_build_dependencies.to_vec(),
             extra_build_variables: extra_build_variables.cloned().unwrap_or_default(),
+            build_target: build_target.cloned(),
         }
     }
 
@@ -42,6 +50,7 @@ impl BuildInfo {
         self.config_settings.is_empty()
             && self.extra_build_requires.is_empty()
             && self.extra_build_variables.is_empty()
+            && self.build_target.is_none()
     }
 
     /// Return the cache shard for this [`BuildInfo`].
@@ -53,3 +62,29 @@ impl BuildInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn cache_shard_includes_build_target() {
+        let config_settings = ConfigSettings::default();
+        let host = BuildInfo::from_settings(&config_settings, &[], None, None);
+
+        let macos = BuildTarget::from_str("macosx_11_0_arm64").unwrap();
+        let macos = BuildInfo::from_settings(&config_settings, &[], None, Some(&macos));
+
+        let linux = BuildTarget::from_str("manylinux_2_28_aarch64").unwrap();
+        let linux = BuildInfo::from_settings(&config_settings, &[], None, Some(&linux));
+
+        // Wheels built for the current platform retain the unsharded cache location, while wheels
+        // built for another platform are cached separately for each target.
+        assert_eq!(host.cache_shard(), None);
+        assert!(macos.cache_shard().is_some());
+        assert!(linux.cache_shard().is_some());
+        assert_ne!(macos.cache_shard(), linux.cache_shard());
+    }
+}
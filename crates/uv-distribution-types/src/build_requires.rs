@@ -1,9 +1,12 @@
+use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use uv_cache_key::{CacheKey, CacheKeyHasher};
 use uv_normalize::PackageName;
+use uv_warnings::warn_user_once;
 
 use crate::{Name, Requirement, RequirementSource, Resolution};
 
@@ -141,21 +144,86 @@ impl ExtraBuildRequires {
 pub type BuildVariables = BTreeMap<String, String>;
 
 /// Extra environment variables to set during builds, on a per-package basis.
+///
+/// Also includes the variables resolved from the build environment allowlist (`build-env`), if
+/// any, which apply to all builds.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
-pub struct ExtraBuildVariables(BTreeMap<PackageName, BuildVariables>);
+#[serde(transparent)]
+pub struct ExtraBuildVariables {
+    packages: BTreeMap<PackageName, BuildVariables>,
+    #[serde(skip)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    build_env: Option<BuildVariables>,
+}
+
+impl ExtraBuildVariables {
+    /// Restrict the build environment to the given allowlist of variables.
+    ///
+    /// Variables with an explicit value are set to that value; all others are passed through from
+    /// the current environment, if present and valid UTF-8.
+    #[must_use]
+    pub fn with_build_env(mut self, build_env: Option<Vec<BuildEnvEntry>>) -> Self {
+        self.build_env = build_env.map(|entries| {
+            let mut variables = BuildVariables::new();
+            for BuildEnvEntry { key, value } in entries {
+                let value = match value {
+                    Some(value) => value,
+                    None => match std::env::var(&key) {
+                        Ok(value) => value,
+                        Err(std::env::VarError::NotPresent) => continue,
+                        Err(std::env::VarError::NotUnicode(_)) => {
+                            warn_user_once!(
+                                "Ignoring `{key}` in the build environment allowlist, as its value is not valid UTF-8"
+                            );
+                            continue;
+                        }
+                    },
+                };
+                variables.entry(key).or_insert(value);
+            }
+            variables
+        });
+        self
+    }
+
+    /// Returns the variables resolved from the build environment allowlist, if the build
+    /// environment is restricted.
+    pub fn build_env(&self) -> Option<&BuildVariables> {
+        self.build_env.as_ref()
+    }
+
+    /// Returns the variables to set when building the given package, including those from the
+    /// build environment allowlist. Package-specific variables take precedence.
+    pub fn for_package(&self, name: Option<&PackageName>) -> Option<Cow<'_, BuildVariables>> {
+        let package = name.and_then(|name| self.packages.get(name));
+        match (self.build_env.as_ref(), package) {
+            (None, None) => None,
+            (Some(variables), None) | (None, Some(variables)) => Some(Cow::Borrowed(variables)),
+            (Some(build_env), Some(package)) => {
+                let mut variables = build_env.clone();
+                variables.extend(
+                    package
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone())),
+                );
+                Some(Cow::Owned(variables))
+            }
+        }
+    }
+}
 
 impl std::ops::Deref for ExtraBuildVariables {
     type Target = BTreeMap<PackageName, BuildVariables>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.packages
     }
 }
 
 impl std::ops::DerefMut for ExtraBuildVariables {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.packages
     }
 }
 
@@ -164,24 +232,99 @@ impl IntoIterator for ExtraBuildVariables {
     type IntoIter = std::collections::btree_map::IntoIter<PackageName, BuildVariables>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.packages.into_iter()
     }
 }
 
 impl FromIterator<(PackageName, BuildVariables)> for ExtraBuildVariables {
     fn from_iter<T: IntoIterator<Item = (PackageName, BuildVariables)>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        Self {
+            packages: iter.into_iter().collect(),
+            build_env: None,
+        }
     }
 }
 
 impl CacheKey for ExtraBuildVariables {
     fn cache_key(&self, state: &mut CacheKeyHasher) {
-        for (package, vars) in &self.0 {
+        for (package, vars) in &self.packages {
             package.as_str().cache_key(state);
             for (key, value) in vars {
                 key.cache_key(state);
                 value.cache_key(state);
             }
         }
+        if let Some(vars) = &self.build_env {
+            for (key, value) in vars {
+                key.cache_key(state);
+                value.cache_key(state);
+            }
+        }
+    }
+}
+
+/// An entry in the build environment allowlist, specified as `KEY` (to pass the variable through
+/// from the current environment) or `KEY=VALUE` (to set the variable).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuildEnvEntry {
+    /// The name of the variable.
+    key: String,
+    /// The value of the variable, if set explicitly.
+    value: Option<String>,
+}
+
+impl FromStr for BuildEnvEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = match s.split_once('=') {
+            Some((key, value)) => (key.trim(), Some(value.to_string())),
+            None => (s.trim(), None),
+        };
+        if key.is_empty() {
+            return Err(format!(
+                "Invalid build environment variable: {s} (expected `KEY` or `KEY=VALUE`)"
+            ));
+        }
+        Ok(Self {
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
+impl std::fmt::Display for BuildEnvEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}={value}", self.key),
+            None => write!(f, "{}", self.key),
+        }
+    }
+}
+
+impl Serialize for BuildEnvEntry {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BuildEnvEntry {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for BuildEnvEntry {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("BuildEnvEntry")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "An environment variable to pass through to the build backend, specified as `KEY`, or to set, specified as `KEY=VALUE`.",
+        })
     }
 }
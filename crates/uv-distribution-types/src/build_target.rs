@@ -0,0 +1,235 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use uv_cache_key::{CacheKey, CacheKeyHasher};
+use uv_platform_tags::{Arch, BinaryFormat, Os, Platform, PlatformTag};
+
+/// The platform to build wheels for, as a wheel platform tag (e.g., `--build-target
+/// manylinux_2_28_aarch64`).
+///
+/// Only Linux, macOS, and Windows targets are supported, as those are the targets for which the
+/// conventional cross-compilation environment variables are understood by build backends.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuildTarget(PlatformTag);
+
+/// An unsupported `--build-target`.
+#[derive(Debug, thiserror::Error)]
+pub enum BuildTargetError {
+    #[error(transparent)]
+    Parse(#[from] uv_platform_tags::ParsePlatformTagError),
+    #[error(
+        "`{0}` is not a supported build target; expected a Linux (`manylinux`, `musllinux`, or `linux`), macOS (`arm64` or `x86_64`), or Windows platform tag"
+    )]
+    Unsupported(PlatformTag),
+}
+
+impl BuildTarget {
+    /// Return the platform tag of the build target.
+    pub fn platform_tag(&self) -> &PlatformTag {
+        &self.0
+    }
+
+    /// Return the [`Platform`] that wheels built for the target are expected to be compatible with.
+    ///
+    /// The platform is derived from the tag, such that a wheel is accepted if its platform tag is
+    /// the target's or one that the target is compatible with (e.g., a `manylinux_2_17` wheel for a
+    /// `manylinux_2_28` target). A bare `linux` target only accepts `linux` wheels, as a
+    /// `manylinux` tag makes guarantees about the system libraries that the target doesn't.
+    pub fn platform(&self) -> Platform {
+        let (os, arch) = match self.0 {
+            PlatformTag::Manylinux { major, minor, arch } => (Os::Manylinux { major, minor }, arch),
+            PlatformTag::Musllinux { major, minor, arch } => (Os::Musllinux { major, minor }, arch),
+            PlatformTag::Manylinux1 { arch } => (Os::Manylinux { major: 2, minor: 5 }, arch),
+            PlatformTag::Manylinux2010 { arch } => (
+                Os::Manylinux {
+                    major: 2,
+                    minor: 12,
+                },
+                arch,
+            ),
+            PlatformTag::Manylinux2014 { arch } => (
+                Os::Manylinux {
+                    major: 2,
+                    minor: 17,
+                },
+                arch,
+            ),
+            // No `manylinux` version predates the minimum for any architecture, so only the `linux`
+            // tag is compatible.
+            PlatformTag::Linux { arch } => (Os::Manylinux { major: 2, minor: 0 }, arch),
+            PlatformTag::Macos {
+                major,
+                minor,
+                binary_format,
+            } => (
+                Os::Macos { major, minor },
+                if binary_format == BinaryFormat::Arm64 {
+                    Arch::Aarch64
+                } else {
+                    Arch::X86_64
+                },
+            ),
+            PlatformTag::Win32 => (Os::Windows, Arch::X86),
+            PlatformTag::WinArm64 => (Os::Windows, Arch::Aarch64),
+            // The remaining supported target is `win_amd64`.
+            _ => (Os::Windows, Arch::X86_64),
+        };
+        Platform::new(os, arch)
+    }
+
+    /// Return the environment variables that instruct build backends to build for the target.
+    ///
+    /// `_PYTHON_HOST_PLATFORM` overrides the platform reported by `sysconfig.get_platform()`, from
+    /// which most build backends derive the wheel's platform tag; `ARCHFLAGS` selects the
+    /// architecture for compilers on macOS; and `PLAT` is read by `cibuildwheel`-style build
+    /// scripts and `auditwheel`.
+    pub fn environment_variables(&self) -> Vec<(&'static str, String)> {
+        let host_platform = match &self.0 {
+            PlatformTag::Macos {
+                major,
+                minor,
+                binary_format,
+            } => format!("macosx-{major}.{minor}-{binary_format}"),
+            PlatformTag::Win32 => "win32".to_string(),
+            PlatformTag::WinAmd64 => "win-amd64".to_string(),
+            PlatformTag::WinArm64 => "win-arm64".to_string(),
+            _ => format!("linux-{}", self.platform().arch()),
+        };
+        let mut variables = vec![("_PYTHON_HOST_PLATFORM", host_platform)];
+        if let PlatformTag::Macos { binary_format, .. } = &self.0 {
+            variables.push(("ARCHFLAGS", format!("-arch {binary_format}")));
+        }
+        variables.push(("PLAT", self.0.to_string()));
+        variables
+    }
+}
+
+impl FromStr for BuildTarget {
+    type Err = BuildTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = PlatformTag::from_str(s)?;
+        match tag {
+            PlatformTag::Manylinux { .. }
+            | PlatformTag::Manylinux1 { .. }
+            | PlatformTag::Manylinux2010 { .. }
+            | PlatformTag::Manylinux2014 { .. }
+            | PlatformTag::Linux { .. }
+            | PlatformTag::Musllinux { .. }
+            | PlatformTag::Macos {
+                binary_format: BinaryFormat::Arm64 | BinaryFormat::X86_64,
+                ..
+            }
+            | PlatformTag::Win32
+            | PlatformTag::WinAmd64
+            | PlatformTag::WinArm64 => Ok(Self(tag)),
+            _ => Err(BuildTargetError::Unsupported(tag)),
+        }
+    }
+}
+
+impl Display for BuildTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl CacheKey for BuildTarget {
+    fn cache_key(&self, state: &mut CacheKeyHasher) {
+        self.0.to_string().cache_key(state);
+    }
+}
+
+impl serde::Serialize for BuildTarget {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BuildTarget {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_variables() {
+        let target = BuildTarget::from_str("manylinux_2_28_aarch64").unwrap();
+        assert_eq!(
+            target.environment_variables(),
+            vec![
+                ("_PYTHON_HOST_PLATFORM", "linux-aarch64".to_string()),
+                ("PLAT", "manylinux_2_28_aarch64".to_string()),
+            ]
+        );
+
+        let target = BuildTarget::from_str("macosx_11_0_arm64").unwrap();
+        assert_eq!(
+            target.environment_variables(),
+            vec![
+                ("_PYTHON_HOST_PLATFORM", "macosx-11.0-arm64".to_string()),
+                ("ARCHFLAGS", "-arch arm64".to_string()),
+                ("PLAT", "macosx_11_0_arm64".to_string()),
+            ]
+        );
+
+        let target = BuildTarget::from_str("win_amd64").unwrap();
+        assert_eq!(
+            target.environment_variables(),
+            vec![
+                ("_PYTHON_HOST_PLATFORM", "win-amd64".to_string()),
+                ("PLAT", "win_amd64".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn platform() {
+        let target = BuildTarget::from_str("manylinux_2_28_aarch64").unwrap();
+        assert_eq!(
+            target.platform().os(),
+            &Os::Manylinux {
+                major: 2,
+                minor: 28
+            }
+        );
+
+        let target = BuildTarget::from_str("manylinux2010_x86_64").unwrap();
+        assert_eq!(
+            target.platform().os(),
+            &Os::Manylinux {
+                major: 2,
+                minor: 12
+            }
+        );
+
+        let target = BuildTarget::from_str("linux_x86_64").unwrap();
+        assert_eq!(
+            target.platform().os(),
+            &Os::Manylinux { major: 2, minor: 0 }
+        );
+        assert_eq!(target.platform().arch(), Arch::X86_64);
+
+        let target = BuildTarget::from_str("macosx_11_0_arm64").unwrap();
+        assert_eq!(
+            target.platform().os(),
+            &Os::Macos {
+                major: 11,
+                minor: 0
+            }
+        );
+        assert_eq!(target.platform().arch(), Arch::Aarch64);
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(BuildTarget::from_str("macosx_10_9_universal2").is_err());
+        assert!(BuildTarget::from_str("freebsd_14_0_amd64").is_err());
+        assert!(BuildTarget::from_str("any").is_err());
+    }
+}
@@ -8,9 +8,11 @@ use version_ranges::Ranges;
 
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
+use uv_platform_tags::Tags;
 
 use crate::{
-    BuiltDist, Dist, DistRef, Edge, Name, Node, RequestedDist, Resolution, ResolvedDist, SourceDist,
+    BuiltDist, Dist, DistRef, Edge, Name, Node, RegistrySourceDist, RequestedDist, Resolution,
+    ResolvedDist, SourceDist,
 };
 
 /// Inspect whether an error type is a build error.
@@ -69,6 +71,43 @@ impl Display for DistErrorKind {
     }
 }
 
+/// The reason a source distribution was selected, despite the package publishing wheels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceBuildReason {
+    /// Using pre-built wheels was disabled for the package (e.g., with `--no-binary`).
+    NoBinary,
+    /// None of the published wheels are compatible with the current platform.
+    IncompatibleWheels,
+}
+
+impl SourceBuildReason {
+    /// Determine why a source distribution was selected for a package that publishes wheels, if
+    /// applicable.
+    ///
+    /// Without `tags` (e.g., in a universal resolution), incompatible wheels can't be detected.
+    pub fn from_source_dist(
+        sdist: &RegistrySourceDist,
+        no_binary: bool,
+        tags: Option<&Tags>,
+    ) -> Option<Self> {
+        if sdist.wheels.is_empty() {
+            return None;
+        }
+        if no_binary {
+            Some(Self::NoBinary)
+        } else if let Some(tags) = tags
+            && sdist
+                .wheels
+                .iter()
+                .all(|wheel| !wheel.filename.is_compatible(tags))
+        {
+            Some(Self::IncompatibleWheels)
+        } else {
+            None
+        }
+    }
+}
+
 /// A chain of derivation steps from the root package to the current package, to explain why a
 /// package is included in the resolution.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
@@ -13,6 +13,10 @@ pub enum HashPolicy<'a> {
     /// Hashes should be validated against a pre-defined list of hashes, and every digest must
     /// match. If necessary, hashes should be generated so as to ensure that the archive is valid.
     All(&'a [HashDigest]),
+    /// Hashes should be generated for distributions that aren't hosted on a registry (as in
+    /// [`HashPolicy::Generate`]), and validated against a pre-defined list of hashes, every digest
+    /// of which must match.
+    GenerateAndVerify(&'a [HashDigest]),
 }
 
 impl HashPolicy<'_> {
@@ -21,15 +25,20 @@ impl HashPolicy<'_> {
         matches!(self, Self::None)
     }
 
-    /// Returns `true` if the hash policy is `Any` or `All`.
+    /// Returns `true` if the hash policy is `Any`, `All`, or `GenerateAndVerify`.
     pub fn requires_validation(&self) -> bool {
-        matches!(self, Self::Any(_) | Self::All(_))
+        matches!(
+            self,
+            Self::Any(_) | Self::All(_) | Self::GenerateAndVerify(_)
+        )
     }
 
     /// Returns `true` if the hash policy indicates that hashes should be generated.
     pub fn is_generate(&self, dist: &crate::BuiltDist) -> bool {
         match self {
-            Self::Generate(HashGeneration::Url) => dist.file().is_none(),
+            Self::Generate(HashGeneration::Url) | Self::GenerateAndVerify(_) => {
+                dist.file().is_none()
+            }
             Self::Generate(HashGeneration::All) => {
                 dist.file().is_none_or(|file| file.hashes.is_empty())
             }
@@ -44,7 +53,7 @@ impl HashPolicy<'_> {
         match self {
             Self::None => vec![],
             Self::Generate(_) => vec![HashAlgorithm::Sha256],
-            Self::Any(hashes) | Self::All(hashes) => {
+            Self::Any(hashes) | Self::All(hashes) | Self::GenerateAndVerify(hashes) => {
                 let mut algorithms = hashes.iter().map(HashDigest::algorithm).collect::<Vec<_>>();
                 algorithms.sort();
                 algorithms.dedup();
@@ -58,7 +67,7 @@ impl HashPolicy<'_> {
         match self {
             Self::None => &[],
             Self::Generate(_) => &[],
-            Self::Any(hashes) | Self::All(hashes) => hashes,
+            Self::Any(hashes) | Self::All(hashes) | Self::GenerateAndVerify(hashes) => hashes,
         }
     }
 
@@ -72,7 +81,7 @@ impl HashPolicy<'_> {
             Self::Any(required) => {
                 !required.is_empty() && hashes.iter().any(|hash| required.contains(hash))
             }
-            Self::All(required) => {
+            Self::All(required) | Self::GenerateAndVerify(required) => {
                 !required.is_empty() && required.iter().all(|hash| hashes.contains(hash))
             }
         }
@@ -92,7 +101,7 @@ impl HashPolicy<'_> {
                         .map(HashDigest::algorithm)
                         .any(|algorithm| hashes.iter().any(|hash| hash.algorithm == algorithm))
             }
-            Self::All(required) => {
+            Self::All(required) | Self::GenerateAndVerify(required) => {
                 !required.is_empty()
                     && required
                         .iter()
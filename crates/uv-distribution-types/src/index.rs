@@ -253,6 +253,56 @@ pub struct Index {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "schemars", schemars(with = "ExcludeNewerOverride"))]
     pub exclude_newer: Option<ExcludeNewerOverride>,
+    /// The HTTP version to use for requests to the index.
+    ///
+    /// By default, uv negotiates the HTTP version with the server, preferring HTTP/2 when
+    /// available. Set to `"1.1"` to force HTTP/1.1 (e.g., for servers or proxies that reset
+    /// HTTP/2 streams), or to `"2"` to negotiate HTTP/2 without the automatic fallback to HTTP/1.1
+    /// on HTTP/2 protocol errors.
+    ///
+    /// The setting applies to all requests to the index's host, including file downloads, and to
+    /// any hosts that the index redirects to (e.g., a CDN that serves the index's files).
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "my-index"
+    /// url = "https://<omitted>/simple"
+    /// http = "1.1"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<HttpVersion>,
+    /// Whether source distributions may be selected from the index.
+    ///
+    /// Building a source distribution executes arbitrary code, so organizations may want to
+    /// restrict source distributions to a trusted index while still allowing wheels from others.
+    /// When set to `false`, source distributions on this index are ignored during resolution, and
+    /// uv will fall back to an older version that provides a compatible wheel, if one exists.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "pypi"
+    /// url = "https://pypi.org/simple"
+    /// allow-sdist = false
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_sdist: Option<bool>,
+    /// Whether wheels may be selected from the index.
+    ///
+    /// When set to `false`, wheels on this index are ignored during resolution, such that only
+    /// source distributions are used.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://internal.example.com/simple"
+    /// allow-wheels = false
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_wheels: Option<bool>,
 }
 
 #[derive(Debug, Error)]
@@ -277,6 +327,9 @@ impl PartialEq for Index {
             ignore_error_codes,
             cache_control,
             exclude_newer,
+            http,
+            allow_sdist,
+            allow_wheels,
         } = self;
         *url == other.url
             && *name == other.name
@@ -288,6 +341,9 @@ impl PartialEq for Index {
             && *ignore_error_codes == other.ignore_error_codes
             && *cache_control == other.cache_control
             && *exclude_newer == other.exclude_newer
+            && *http == other.http
+            && *allow_sdist == other.allow_sdist
+            && *allow_wheels == other.allow_wheels
     }
 }
 
@@ -313,6 +369,9 @@ impl Ord for Index {
             ignore_error_codes,
             cache_control,
             exclude_newer,
+            http,
+            allow_sdist,
+            allow_wheels,
         } = self;
         url.cmp(&other.url)
             .then_with(|| name.cmp(&other.name))
@@ -324,6 +383,9 @@ impl Ord for Index {
             .then_with(|| ignore_error_codes.cmp(&other.ignore_error_codes))
             .then_with(|| cache_control.cmp(&other.cache_control))
             .then_with(|| exclude_newer.cmp(&other.exclude_newer))
+            .then_with(|| http.cmp(&other.http))
+            .then_with(|| allow_sdist.cmp(&other.allow_sdist))
+            .then_with(|| allow_wheels.cmp(&other.allow_wheels))
     }
 }
 
@@ -341,6 +403,9 @@ impl std::hash::Hash for Index {
             ignore_error_codes,
             cache_control,
             exclude_newer,
+            http,
+            allow_sdist,
+            allow_wheels,
         } = self;
         url.hash(state);
         name.hash(state);
@@ -352,6 +417,9 @@ impl std::hash::Hash for Index {
         ignore_error_codes.hash(state);
         cache_control.hash(state);
         exclude_newer.hash(state);
+        http.hash(state);
+        allow_sdist.hash(state);
+        allow_wheels.hash(state);
     }
 }
 
@@ -378,6 +446,64 @@ pub enum IndexFormat {
     Flat,
 }
 
+/// The types of artifacts that may be selected from an index.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct IndexArtifactPolicy {
+    /// Whether source distributions may be selected from the index.
+    pub allow_sdist: bool,
+    /// Whether wheels may be selected from the index.
+    pub allow_wheels: bool,
+}
+
+impl Default for IndexArtifactPolicy {
+    fn default() -> Self {
+        Self {
+            allow_sdist: true,
+            allow_wheels: true,
+        }
+    }
+}
+
+impl IndexArtifactPolicy {
+    /// Returns `true` if all artifact types are allowed.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The HTTP version to use when communicating with an index.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum HttpVersion {
+    /// Use HTTP/1.1 only.
+    #[serde(rename = "1.1")]
+    Http1,
+    /// Negotiate HTTP/2, without falling back to HTTP/1.1.
+    #[serde(rename = "2")]
+    Http2,
+}
+
+impl FromStr for HttpVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1.1" => Ok(Self::Http1),
+            "2" => Ok(Self::Http2),
+            _ => Err(format!("expected `1.1` or `2`, found `{s}`")),
+        }
+    }
+}
+
+impl std::fmt::Display for HttpVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http1 => f.write_str("HTTP/1.1"),
+            Self::Http2 => f.write_str("HTTP/2"),
+        }
+    }
+}
+
 impl Index {
     /// Initialize an [`Index`] from a pip-style `--index-url`.
     pub fn from_index_url(url: IndexUrl) -> Self {
@@ -393,6 +519,9 @@ impl Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         }
     }
 
@@ -410,6 +539,9 @@ impl Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         }
     }
 
@@ -427,6 +559,9 @@ impl Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         }
     }
 
@@ -540,6 +675,14 @@ impl Index {
     pub(crate) fn exclude_newer(&self) -> Option<&ExcludeNewerOverride> {
         self.exclude_newer.as_ref()
     }
+
+    /// Return the [`IndexArtifactPolicy`] for this index.
+    pub fn artifact_policy(&self) -> IndexArtifactPolicy {
+        IndexArtifactPolicy {
+            allow_sdist: self.allow_sdist.unwrap_or(true),
+            allow_wheels: self.allow_wheels.unwrap_or(true),
+        }
+    }
 }
 
 impl From<IndexUrl> for Index {
@@ -556,6 +699,9 @@ impl From<IndexUrl> for Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         }
     }
 }
@@ -582,6 +728,9 @@ impl FromStr for Index {
                 ignore_error_codes: None,
                 cache_control: None,
                 exclude_newer: None,
+                http: None,
+                allow_sdist: None,
+                allow_wheels: None,
             });
         }
 
@@ -599,6 +748,9 @@ impl FromStr for Index {
             ignore_error_codes: None,
             cache_control: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         })
     }
 }
@@ -692,6 +844,12 @@ struct IndexWire {
     cache_control: Option<IndexCacheControl>,
     #[serde(default)]
     exclude_newer: Option<ExcludeNewerOverride>,
+    #[serde(default)]
+    http: Option<HttpVersion>,
+    #[serde(default)]
+    allow_sdist: Option<bool>,
+    #[serde(default)]
+    allow_wheels: Option<bool>,
 }
 
 impl<'de> Deserialize<'de> for Index {
@@ -720,6 +878,9 @@ impl<'de> Deserialize<'de> for Index {
             ignore_error_codes: wire.ignore_error_codes,
             cache_control: wire.cache_control,
             exclude_newer: wire.exclude_newer,
+            http: wire.http,
+            allow_sdist: wire.allow_sdist,
+            allow_wheels: wire.allow_wheels,
         })
     }
 }
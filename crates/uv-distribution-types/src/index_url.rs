@@ -15,7 +15,7 @@ use uv_pep508::{Scheme, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_redacted::DisplaySafeUrl;
 use uv_warnings::warn_user;
 
-use crate::{ExcludeNewerOverride, Index, IndexStatusCodeStrategy, Verbatim};
+use crate::{ExcludeNewerOverride, Index, IndexArtifactPolicy, IndexStatusCodeStrategy, Verbatim};
 
 pub static PYPI_URL: LazyLock<DisplaySafeUrl> =
     LazyLock::new(|| DisplaySafeUrl::parse("https://pypi.org/simple").unwrap());
@@ -518,6 +518,22 @@ impl<'a> IndexLocations {
     pub fn exclude_newer_for(&self, url: &IndexUrl) -> Option<&ExcludeNewerOverride> {
         self.index_for_url(url).and_then(Index::exclude_newer)
     }
+
+    /// Return the [`IndexArtifactPolicy`] for an [`IndexUrl`].
+    pub fn artifact_policy_for(&self, url: &IndexUrl) -> IndexArtifactPolicy {
+        self.index_for_url(url)
+            .map(Index::artifact_policy)
+            .unwrap_or_default()
+    }
+
+    /// Return the [`IndexArtifactPolicy`] for every index that restricts the artifact types that
+    /// may be selected from it.
+    pub fn artifact_policies(&self) -> impl Iterator<Item = (&IndexUrl, IndexArtifactPolicy)> {
+        self.indexes
+            .iter()
+            .map(|index| (index.url(), index.artifact_policy()))
+            .filter(|(_, policy)| !policy.is_default())
+    }
 }
 
 impl From<&IndexLocations> for uv_auth::Indexes {
@@ -694,6 +710,9 @@ mod tests {
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
                 exclude_newer: None,
+                http: None,
+                allow_sdist: None,
+                allow_wheels: None,
             },
             Index {
                 name: Some(IndexName::from_str("index2").unwrap()),
@@ -707,6 +726,9 @@ mod tests {
                 authenticate: uv_auth::AuthPolicy::default(),
                 ignore_error_codes: None,
                 exclude_newer: None,
+                http: None,
+                allow_sdist: None,
+                allow_wheels: None,
             },
         ];
 
@@ -746,6 +768,9 @@ mod tests {
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -782,6 +807,9 @@ mod tests {
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
@@ -813,6 +841,9 @@ mod tests {
             authenticate: uv_auth::AuthPolicy::default(),
             ignore_error_codes: None,
             exclude_newer: None,
+            http: None,
+            allow_sdist: None,
+            allow_wheels: None,
         }];
 
         let index_locations = IndexLocations::new(indexes, Vec::new(), false);
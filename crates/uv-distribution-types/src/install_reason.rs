@@ -0,0 +1,169 @@
+use std::fmt::{Display, Formatter};
+
+use petgraph::Direction;
+use petgraph::visit::EdgeRef;
+use rustc_hash::FxHashMap;
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+
+use crate::{Edge, Name, Node, Resolution};
+
+/// The reason a package was installed into an environment, as recorded in the `uv_reason.json`
+/// file in its `.dist-info` directory.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct InstallReason {
+    /// The context in which the package was installed.
+    pub context: InstallContext,
+    /// The requirements that caused the package to be installed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requested_by: Vec<InstallRequester>,
+}
+
+impl InstallReason {
+    /// Determine the reason for installing each package in a [`Resolution`].
+    ///
+    /// Every package is attributed to its immediate dependents in the resolution graph, or to the
+    /// root, if it was requested directly (e.g., on the command line or as a workspace member).
+    pub fn from_resolution(
+        resolution: &Resolution,
+        context: InstallContext,
+    ) -> FxHashMap<PackageName, Self> {
+        let graph = resolution.graph();
+        let mut reasons = FxHashMap::default();
+        for index in graph.node_indices() {
+            let Node::Dist { dist, install, .. } = &graph[index] else {
+                continue;
+            };
+            if !*install {
+                continue;
+            }
+            let mut requested_by = graph
+                .edges_directed(index, Direction::Incoming)
+                .map(|edge| {
+                    let (extra, group) = match edge.weight() {
+                        Edge::Prod => (None, None),
+                        Edge::Optional(extra) => (Some(extra.clone()), None),
+                        Edge::Dev(group) => (None, Some(group.clone())),
+                    };
+                    match &graph[edge.source()] {
+                        Node::Root => InstallRequester::Direct { group },
+                        Node::Dist { dist, .. } => InstallRequester::Dependency {
+                            package: dist.name().clone(),
+                            extra,
+                            group,
+                        },
+                    }
+                })
+                .collect::<Vec<_>>();
+            requested_by.sort();
+            requested_by.dedup();
+            reasons.insert(
+                dist.name().clone(),
+                Self {
+                    context,
+                    requested_by,
+                },
+            );
+        }
+        reasons
+    }
+
+    /// Combine the requirements of two [`InstallReason`]s, with the context of `self` taking
+    /// precedence.
+    ///
+    /// Used when the environment is only partially synchronized (e.g., with `uv pip install`), in
+    /// which case packages outside the resolution may still depend on the package.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        self.requested_by.extend(other.requested_by);
+        self.requested_by.sort();
+        self.requested_by.dedup();
+        self
+    }
+}
+
+impl Display for InstallReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.requested_by.is_empty() {
+            return write!(f, "unknown ({})", self.context);
+        }
+        for (i, requester) in self.requested_by.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{requester}")?;
+        }
+        write!(f, " ({})", self.context)
+    }
+}
+
+/// The context in which a package was installed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InstallContext {
+    /// The package was installed with the `uv pip` interface.
+    Pip,
+    /// The package was installed to sync a project or script environment.
+    Project,
+    /// The package was installed into an ephemeral overlay environment (e.g., with `--with`).
+    Overlay,
+    /// The package was installed into a tool environment.
+    Tool,
+}
+
+impl Display for InstallContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pip => f.write_str("pip"),
+            Self::Project => f.write_str("project"),
+            Self::Overlay => f.write_str("overlay"),
+            Self::Tool => f.write_str("tool"),
+        }
+    }
+}
+
+/// A requirement that caused a package to be installed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum InstallRequester {
+    /// The package was requested directly, optionally as part of a dependency group.
+    Direct {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        group: Option<GroupName>,
+    },
+    /// The package is a dependency of another package, optionally enabled by one of its extras or
+    /// dependency groups.
+    Dependency {
+        package: PackageName,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        extra: Option<ExtraName>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        group: Option<GroupName>,
+    },
+}
+
+impl Display for InstallRequester {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Direct { group: None } => f.write_str("requested directly"),
+            Self::Direct { group: Some(group) } => {
+                write!(f, "requested directly (group: {group})")
+            }
+            Self::Dependency {
+                package,
+                extra,
+                group,
+            } => {
+                write!(f, "required by {package}")?;
+                if let Some(extra) = extra {
+                    write!(f, "[{extra}]")?;
+                }
+                if let Some(group) = group {
+                    write!(f, " (group: {group})")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
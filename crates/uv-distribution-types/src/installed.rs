@@ -19,7 +19,8 @@ use uv_pypi_types::{DirectUrl, MetadataError};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::{
-    BuildInfo, DistributionMetadata, InstalledMetadata, InstalledVersion, Name, VersionOrUrlRef,
+    BuildInfo, DistributionMetadata, InstallReason, InstalledMetadata, InstalledVersion, Name,
+    VersionOrUrlRef,
 };
 
 #[derive(Error, Debug)]
@@ -492,6 +493,42 @@ impl InstalledDist {
         Ok(self.tags_cache.get().expect("tags should be set").as_ref())
     }
 
+    /// Read the `uv_reason.json` file from the `.dist-info` directory, if available.
+    pub fn read_install_reason(&self) -> Result<Option<InstallReason>, InstalledDistError> {
+        let path = match &self.kind {
+            InstalledDistKind::Registry(dist) => &dist.path,
+            InstalledDistKind::Url(dist) => &dist.path,
+            InstalledDistKind::EggInfoFile(_) => return Ok(None),
+            InstalledDistKind::EggInfoDirectory(_) => return Ok(None),
+            InstalledDistKind::LegacyEditable(_) => return Ok(None),
+        };
+        let file = match fs_err::File::open(path.join("uv_reason.json")) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let install_reason = serde_json::from_reader::<BufReader<fs_err::File>, InstallReason>(
+            BufReader::new(file),
+        )?;
+        Ok(Some(install_reason))
+    }
+
+    /// Read the `INSTALLER` file from the `.dist-info` directory, if available.
+    pub fn read_installer(&self) -> Result<Option<String>, InstalledDistError> {
+        let path = match &self.kind {
+            InstalledDistKind::Registry(dist) => &dist.path,
+            InstalledDistKind::Url(dist) => &dist.path,
+            InstalledDistKind::EggInfoFile(_) => return Ok(None),
+            InstalledDistKind::EggInfoDirectory(_) => return Ok(None),
+            InstalledDistKind::LegacyEditable(_) => return Ok(None),
+        };
+        match fs_err::read_to_string(path.join("INSTALLER")) {
+            Ok(installer) => Ok(Some(installer.trim().to_string())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Return true if the distribution is editable.
     pub fn is_editable(&self) -> bool {
         matches!(
@@ -501,6 +538,11 @@ impl InstalledDist {
         )
     }
 
+    /// Return true if the distribution is a legacy editable install (i.e., an `.egg-link` file).
+    pub fn is_legacy_editable(&self) -> bool {
+        matches!(&self.kind, InstalledDistKind::LegacyEditable(_))
+    }
+
     /// Return the [`Url`] of the distribution, if it is editable.
     pub fn as_editable(&self) -> Option<&Url> {
         match &self.kind {
@@ -57,8 +57,10 @@ use uv_redacted::DisplaySafeUrl;
 
 pub use crate::annotation::*;
 pub use crate::any::*;
+pub use crate::build_dependency_lock::*;
 pub use crate::build_info::*;
 pub use crate::build_requires::*;
+pub use crate::build_target::*;
 pub use crate::buildable::*;
 pub use crate::cached::*;
 pub use crate::config_settings::*;
@@ -73,6 +75,7 @@ pub use crate::id::*;
 pub use crate::index::*;
 pub use crate::index_name::*;
 pub use crate::index_url::*;
+pub use crate::install_reason::*;
 pub use crate::installed::*;
 pub use crate::known_platform::*;
 pub use crate::origin::*;
@@ -89,8 +92,10 @@ pub use crate::traits::*;
 
 mod annotation;
 mod any;
+mod build_dependency_lock;
 mod build_info;
 mod build_requires;
+mod build_target;
 mod buildable;
 mod cached;
 mod config_settings;
@@ -105,6 +110,7 @@ mod id;
 mod index;
 mod index_name;
 mod index_url;
+mod install_reason;
 mod installed;
 mod installed_modules;
 mod known_platform;
@@ -11,8 +11,8 @@ use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagPri
 use uv_pypi_types::{HashDigest, Yanked};
 
 use crate::{
-    File, InstalledDist, KnownPlatform, RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist,
-    ResolvedDistRef,
+    File, IndexUrl, InstalledDist, KnownPlatform, RegistryBuiltDist, RegistryBuiltWheel,
+    RegistrySourceDist, ResolvedDistRef,
 };
 
 /// A collection of distributions that have been filtered by relevance.
@@ -125,6 +125,7 @@ impl IncompatibleDist {
         match self {
             Self::Wheel(incompatibility) => match incompatibility {
                 IncompatibleWheel::NoBinary => format!("has {self}"),
+                IncompatibleWheel::IndexPolicy(_) => format!("has {self}"),
                 IncompatibleWheel::Tag(_) => format!("has {self}"),
                 IncompatibleWheel::Yanked(_) => format!("was {self}"),
                 IncompatibleWheel::ExcludeNewer(ts) => match ts {
@@ -136,6 +137,7 @@ impl IncompatibleDist {
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => format!("has {self}"),
+                IncompatibleSource::IndexPolicy(_) => format!("has {self}"),
                 IncompatibleSource::Yanked(_) => format!("was {self}"),
                 IncompatibleSource::ExcludeNewer(ts) => match ts {
                     Some(_) => format!("was {self}"),
@@ -153,6 +155,7 @@ impl IncompatibleDist {
         match self {
             Self::Wheel(incompatibility) => match incompatibility {
                 IncompatibleWheel::NoBinary => format!("have {self}"),
+                IncompatibleWheel::IndexPolicy(_) => format!("have {self}"),
                 IncompatibleWheel::Tag(_) => format!("have {self}"),
                 IncompatibleWheel::Yanked(_) => format!("were {self}"),
                 IncompatibleWheel::ExcludeNewer(ts) => match ts {
@@ -164,6 +167,7 @@ impl IncompatibleDist {
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => format!("have {self}"),
+                IncompatibleSource::IndexPolicy(_) => format!("have {self}"),
                 IncompatibleSource::Yanked(_) => format!("were {self}"),
                 IncompatibleSource::ExcludeNewer(ts) => match ts {
                     Some(_) => format!("were {self}"),
@@ -203,6 +207,7 @@ impl IncompatibleDist {
                 }
                 IncompatibleWheel::Tag(IncompatibleTag::Invalid) => None,
                 IncompatibleWheel::NoBinary => None,
+                IncompatibleWheel::IndexPolicy(..) => None,
                 IncompatibleWheel::Yanked(..) => None,
                 IncompatibleWheel::ExcludeNewer(..) => None,
                 IncompatibleWheel::RequiresPython(..) => None,
@@ -219,6 +224,10 @@ impl Display for IncompatibleDist {
         match self {
             Self::Wheel(incompatibility) => match incompatibility {
                 IncompatibleWheel::NoBinary => f.write_str("no source distribution"),
+                IncompatibleWheel::IndexPolicy(index) => write!(
+                    f,
+                    "no source distribution, and wheels from `{index}` are forbidden by `allow-wheels = false`"
+                ),
                 IncompatibleWheel::Tag(tag) => match tag {
                     IncompatibleTag::Invalid => f.write_str("no wheels with valid tags"),
                     IncompatibleTag::Python => {
@@ -262,6 +271,10 @@ impl Display for IncompatibleDist {
             },
             Self::Source(incompatibility) => match incompatibility {
                 IncompatibleSource::NoBuild => f.write_str("no usable wheels"),
+                IncompatibleSource::IndexPolicy(index) => write!(
+                    f,
+                    "no usable wheels, and source distributions from `{index}` are forbidden by `allow-sdist = false`"
+                ),
                 IncompatibleSource::Yanked(yanked) => match yanked {
                     Yanked::Bool(_) => f.write_str("yanked"),
                     Yanked::Reason(reason) => write!(
@@ -311,6 +324,8 @@ pub enum IncompatibleWheel {
     Yanked(Yanked),
     /// The use of binary wheels is disabled.
     NoBinary,
+    /// The use of binary wheels from the index is forbidden by its `allow-wheels` setting.
+    IndexPolicy(IndexUrl),
     /// Wheels are not available for the current platform.
     MissingPlatform(MarkerTree),
 }
@@ -327,6 +342,8 @@ pub enum IncompatibleSource {
     RequiresPython(VersionSpecifiers, PythonRequirementKind),
     Yanked(Yanked),
     NoBuild,
+    /// The use of source distributions from the index is forbidden by its `allow-sdist` setting.
+    IndexPolicy(IndexUrl),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -387,9 +404,13 @@ impl PrioritizedDist {
         if !compatibility.is_excluded() {
             self.0.hashes.extend(hashes);
         }
-        // Track the highest-priority wheel.
-        if let Some((.., existing_compatibility)) = self.best_wheel() {
-            if compatibility.is_more_compatible(existing_compatibility) {
+        // Track the highest-priority wheel. Among equally-compatible wheels, break ties by
+        // filename, such that the selection doesn't depend on the order of the index listing.
+        if let Some((existing, existing_compatibility)) = self.best_wheel() {
+            if compatibility.is_more_compatible(existing_compatibility)
+                || (!existing_compatibility.is_more_compatible(&compatibility)
+                    && dist.file.filename < existing.file.filename)
+            {
                 self.0.best_wheel_index = Some(self.0.wheels.len());
             }
         } else {
@@ -413,9 +434,12 @@ impl PrioritizedDist {
         if !compatibility.is_excluded() {
             self.0.hashes.extend(hashes);
         }
-        // Track the highest-priority source.
-        if let Some((.., existing_compatibility)) = &self.0.source {
-            if compatibility.is_more_compatible(existing_compatibility) {
+        // Track the highest-priority source. As with wheels, break ties by filename.
+        if let Some((existing, existing_compatibility)) = &self.0.source {
+            if compatibility.is_more_compatible(existing_compatibility)
+                || (!existing_compatibility.is_more_compatible(&compatibility)
+                    && dist.file.filename < existing.file.filename)
+            {
                 self.0.source = Some((dist, compatibility));
             }
         } else {
@@ -737,20 +761,24 @@ impl IncompatibleSource {
             Self::ExcludeNewer(timestamp_self) => match other {
                 // Smaller timestamps are closer to the cut-off time
                 Self::ExcludeNewer(timestamp_other) => timestamp_other < timestamp_self,
-                Self::NoBuild | Self::RequiresPython(_, _) | Self::Yanked(_) => true,
+                Self::NoBuild
+                | Self::IndexPolicy(_)
+                | Self::RequiresPython(_, _)
+                | Self::Yanked(_) => true,
             },
             Self::RequiresPython(_, _) => match other {
                 Self::ExcludeNewer(_) => false,
                 // Version specifiers cannot be reasonably compared
                 Self::RequiresPython(_, _) => false,
-                Self::NoBuild | Self::Yanked(_) => true,
+                Self::NoBuild | Self::IndexPolicy(_) | Self::Yanked(_) => true,
             },
             Self::Yanked(_) => match other {
                 Self::ExcludeNewer(_) | Self::RequiresPython(_, _) => false,
                 // Yanks with a reason are more helpful for errors
                 Self::Yanked(yanked_other) => matches!(yanked_other, Yanked::Reason(_)),
-                Self::NoBuild => true,
+                Self::NoBuild | Self::IndexPolicy(_) => true,
             },
+            Self::IndexPolicy(_) => matches!(other, Self::NoBuild),
             Self::NoBuild => false,
         }
     }
@@ -770,6 +798,7 @@ impl IncompatibleWheel {
                 },
                 Self::MissingPlatform(_)
                 | Self::NoBinary
+                | Self::IndexPolicy(_)
                 | Self::RequiresPython(_, _)
                 | Self::Tag(_)
                 | Self::Yanked(_) => true,
@@ -779,6 +808,7 @@ impl IncompatibleWheel {
                 Self::Tag(tag_other) => tag_self > tag_other,
                 Self::MissingPlatform(_)
                 | Self::NoBinary
+                | Self::IndexPolicy(_)
                 | Self::RequiresPython(_, _)
                 | Self::Yanked(_) => true,
             },
@@ -786,19 +816,31 @@ impl IncompatibleWheel {
                 Self::ExcludeNewer(_) | Self::Tag(_) => false,
                 // Version specifiers cannot be reasonably compared
                 Self::RequiresPython(_, _) => false,
-                Self::MissingPlatform(_) | Self::NoBinary | Self::Yanked(_) => true,
+                Self::MissingPlatform(_)
+                | Self::NoBinary
+                | Self::IndexPolicy(_)
+                | Self::Yanked(_) => true,
             },
             Self::Yanked(_) => match other {
                 Self::ExcludeNewer(_) | Self::Tag(_) | Self::RequiresPython(_, _) => false,
                 // Yanks with a reason are more helpful for errors
                 Self::Yanked(yanked_other) => matches!(yanked_other, Yanked::Reason(_)),
-                Self::MissingPlatform(_) | Self::NoBinary => true,
+                Self::MissingPlatform(_) | Self::NoBinary | Self::IndexPolicy(_) => true,
+            },
+            Self::IndexPolicy(_) => match other {
+                Self::ExcludeNewer(_)
+                | Self::Tag(_)
+                | Self::RequiresPython(_, _)
+                | Self::Yanked(_)
+                | Self::IndexPolicy(_) => false,
+                Self::NoBinary | Self::MissingPlatform(_) => true,
             },
             Self::NoBinary => match other {
                 Self::ExcludeNewer(_)
                 | Self::Tag(_)
                 | Self::RequiresPython(_, _)
-                | Self::Yanked(_) => false,
+                | Self::Yanked(_)
+                | Self::IndexPolicy(_) => false,
                 Self::NoBinary => false,
                 Self::MissingPlatform(_) => true,
             },
@@ -1036,7 +1078,11 @@ fn implied_python_markers(filename: &WheelFilename) -> MarkerTree {
 mod tests {
     use std::str::FromStr;
 
+    use uv_pypi_types::HashDigests;
+    use uv_redacted::DisplaySafeUrl;
+
     use super::*;
+    use crate::FileLocation;
 
     #[track_caller]
     fn assert_platform_markers(filename: &str, expected: &str) {
@@ -1202,4 +1248,48 @@ mod tests {
             "python_full_version >= '3.15' and platform_python_implementation == 'CPython' and sys_platform == 'linux' and platform_machine == 'x86_64'",
         );
     }
+
+    fn registry_wheel(filename: &str) -> RegistryBuiltWheel {
+        RegistryBuiltWheel {
+            filename: WheelFilename::from_str(filename).unwrap(),
+            file: Box::new(File {
+                dist_info_metadata: false,
+                filename: filename.into(),
+                hashes: HashDigests::empty(),
+                requires_python: None,
+                size: None,
+                upload_time_utc_ms: None,
+                url: FileLocation::AbsoluteUrl(
+                    DisplaySafeUrl::parse(&format!("https://example.com/{filename}"))
+                        .unwrap()
+                        .into(),
+                ),
+                yanked: None,
+                zstd: None,
+            }),
+            index: IndexUrl::from_str("https://example.com/simple").unwrap(),
+        }
+    }
+
+    /// Equally-compatible wheels are ordered by filename, regardless of insertion order.
+    #[test]
+    fn best_wheel_tie_break() {
+        let compatibility = || WheelCompatibility::Compatible(HashComparison::Matched, None, None);
+        for filenames in [
+            [
+                "example-1.0-py3-none-any.whl",
+                "example-1.0-py2.py3-none-any.whl",
+            ],
+            [
+                "example-1.0-py2.py3-none-any.whl",
+                "example-1.0-py3-none-any.whl",
+            ],
+        ] {
+            let mut dist =
+                PrioritizedDist::from_built(registry_wheel(filenames[0]), vec![], compatibility());
+            dist.insert_built(registry_wheel(filenames[1]), vec![], compatibility());
+            let (wheel, _) = dist.best_wheel().unwrap();
+            assert_eq!(&*wheel.file.filename, "example-1.0-py2.py3-none-any.whl");
+        }
+    }
 }
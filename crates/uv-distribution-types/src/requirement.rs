@@ -15,10 +15,10 @@ use uv_pep508::{
 };
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 
-use crate::{IndexMetadata, IndexUrl};
+use crate::{IndexMetadata, IndexUrl, VersionId};
 
 use uv_pypi_types::{
-    ConflictItem, Hashes, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitDirectoryUrl,
+    ConflictItem, HashError, Hashes, ParsedArchiveUrl, ParsedDirectoryUrl, ParsedGitDirectoryUrl,
     ParsedGitPathUrl, ParsedPathUrl, ParsedUrl, ParsedUrlError, VerbatimParsedUrl,
 };
 
@@ -91,12 +91,58 @@ impl Requirement {
     }
 
     /// Return the hashes of the requirement, as specified in the URL fragment.
-    pub fn hashes(&self) -> Option<Hashes> {
-        let RequirementSource::Url { ref url, .. } = self.source else {
-            return None;
+    ///
+    /// Returns an error if the fragment specifies a hash with an unsupported algorithm.
+    pub fn hashes(&self) -> Result<Option<Hashes>, HashError> {
+        let (RequirementSource::Url { url, .. } | RequirementSource::Path { url, .. }) =
+            &self.source
+        else {
+            return Ok(None);
+        };
+        let Some(fragment) = url.fragment() else {
+            return Ok(None);
         };
-        let fragment = url.fragment()?;
-        Hashes::parse_fragment(fragment).ok()
+        Hashes::from_url_fragment(fragment)
+    }
+
+    /// Returns the [`VersionId`] to which the requirement is pinned, if any: an exact version for
+    /// registry requirements, or the URL or path for direct requirements.
+    pub fn pinned_version_id(&self) -> Option<VersionId> {
+        match &self.source {
+            RequirementSource::Registry { specifier, .. } => {
+                // Must be a single specifier.
+                let [specifier] = specifier.as_ref() else {
+                    return None;
+                };
+
+                // Must be pinned to a specific version.
+                if *specifier.operator() != uv_pep440::Operator::Equal {
+                    return None;
+                }
+
+                Some(VersionId::from_registry(
+                    self.name.clone(),
+                    specifier.version().clone(),
+                ))
+            }
+            RequirementSource::Url {
+                location,
+                subdirectory,
+                ..
+            } => Some(VersionId::from_archive(location, subdirectory.as_deref())),
+            RequirementSource::GitDirectory {
+                git, subdirectory, ..
+            } => Some(VersionId::from_git(git, subdirectory.as_deref())),
+            RequirementSource::GitPath {
+                git, install_path, ..
+            } => Some(VersionId::from_git(git, Some(install_path))),
+            RequirementSource::Path { install_path, .. } => {
+                Some(VersionId::from_path(install_path))
+            }
+            RequirementSource::Directory { install_path, .. } => {
+                Some(VersionId::from_directory(install_path))
+            }
+        }
     }
 
     /// Set the source file containing the requirement.
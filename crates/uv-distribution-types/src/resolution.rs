@@ -1,9 +1,11 @@
 use uv_distribution_filename::DistExtension;
 use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_pypi_types::{HashDigest, HashDigests};
 
 use crate::{
-    BuiltDist, Diagnostic, Dist, IndexMetadata, Name, RequirementSource, ResolvedDist, SourceDist,
+    BuiltDist, Diagnostic, Dist, IndexMetadata, IndexUrl, Name, RequirementSource, RequiresPython,
+    ResolvedDist, SourceDist,
 };
 
 /// A set of packages pinned at specific versions.
@@ -135,6 +137,22 @@ pub enum ResolutionDiagnostic {
         /// resolution. For example, `black`.
         package_name: PackageName,
     },
+    ForbiddenSourceDistributions {
+        /// The distributions that were selected in place of a newer version that was only usable
+        /// as a source distribution from an index with `allow-sdist = false`, along with that
+        /// newer version and its index.
+        dists: Vec<(ResolvedDist, Version, IndexUrl)>,
+    },
+    NarrowedRequiresPython {
+        /// The supported Python versions of the resolution. For example, `>=3.9`.
+        requires_python: RequiresPython,
+        /// The Python versions that are supported by all selected distributions, if narrower than
+        /// the supported Python versions. For example, `>=3.9, <3.12`.
+        narrowed: Option<RequiresPython>,
+        /// The distributions that don't support the full range of supported Python versions,
+        /// along with their `Requires-Python` specifiers.
+        dists: Vec<(ResolvedDist, VersionSpecifiers)>,
+    },
 }
 
 impl Diagnostic for ResolutionDiagnostic {
@@ -163,6 +181,41 @@ impl Diagnostic for ResolutionDiagnostic {
                     `--resolution lowest` to avoid using outdated versions."
                 )
             }
+            Self::ForbiddenSourceDistributions { dists } => {
+                let dists = dists
+                    .iter()
+                    .map(|(dist, version, index)| {
+                        format!(
+                            "`{dist}` (instead of `{}=={version}` from `{index}`)",
+                            dist.name()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "Selected older versions because newer source distributions are forbidden by `allow-sdist = false`: {dists}"
+                )
+            }
+            Self::NarrowedRequiresPython {
+                requires_python,
+                narrowed,
+                dists,
+            } => {
+                let dists = dists
+                    .iter()
+                    .map(|(dist, specifiers)| format!("`{dist}` (requires Python `{specifiers}`)"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if let Some(narrowed) = narrowed {
+                    format!(
+                        "The supported Python range was narrowed from `{requires_python}` to `{narrowed}`, as not all dependencies support the full range: {dists}"
+                    )
+                } else {
+                    format!(
+                        "Not all dependencies support the full Python range (`{requires_python}`): {dists}"
+                    )
+                }
+            }
         }
     }
 
@@ -173,6 +226,12 @@ impl Diagnostic for ResolutionDiagnostic {
             Self::MissingGroup { dist, .. } => name == dist.name(),
             Self::YankedVersion { dist, .. } => name == dist.name(),
             Self::MissingLowerBound { package_name } => name == package_name,
+            Self::ForbiddenSourceDistributions { dists } => {
+                dists.iter().any(|(dist, ..)| name == dist.name())
+            }
+            Self::NarrowedRequiresPython { dists, .. } => {
+                dists.iter().any(|(dist, ..)| name == dist.name())
+            }
         }
     }
 }
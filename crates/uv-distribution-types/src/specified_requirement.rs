@@ -4,7 +4,7 @@ use std::fmt::{Display, Formatter};
 use uv_git_types::{GitLfs, GitReference};
 use uv_normalize::ExtraName;
 use uv_pep508::{MarkerEnvironment, MarkerTree, UnnamedRequirement};
-use uv_pypi_types::{Hashes, ParsedUrl};
+use uv_pypi_types::{HashError, Hashes, ParsedUrl};
 
 use crate::{Requirement, RequirementSource, VerbatimParsedUrl};
 
@@ -197,12 +197,16 @@ impl UnresolvedRequirement {
     }
 
     /// Return the hashes of the requirement, as specified in the URL fragment.
-    pub fn hashes(&self) -> Option<Hashes> {
+    ///
+    /// Returns an error if the fragment specifies a hash with an unsupported algorithm.
+    pub fn hashes(&self) -> Result<Option<Hashes>, HashError> {
         match self {
             Self::Named(requirement) => requirement.hashes(),
             Self::Unnamed(requirement) => {
-                let fragment = requirement.url.verbatim.fragment()?;
-                Hashes::parse_fragment(fragment).ok()
+                let Some(fragment) = requirement.url.verbatim.fragment() else {
+                    return Ok(None);
+                };
+                Hashes::from_url_fragment(fragment)
             }
         }
     }
@@ -9,13 +9,14 @@ use futures::{FutureExt, TryStreamExt};
 use tokio::io::{AsyncRead, AsyncSeekExt, ReadBuf};
 use tokio::sync::Semaphore;
 use tokio_util::compat::FuturesAsyncReadCompatExt;
-use tracing::{Instrument, info_span, instrument, warn};
+use tracing::{Instrument, debug, info_span, instrument, warn};
 use url::Url;
 
 use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
-    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
+    CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, OwnedArchive,
+    RegistryClient,
 };
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
@@ -23,22 +24,23 @@ use uv_distribution_types::{
     InstalledDist, Name, SourceDist, ToUrlError,
 };
 use uv_extract::hash::Hasher;
-use uv_fs::write_atomic;
+use uv_fs::{LockedFile, write_atomic};
 use uv_git::{GIT_LFS, GitError};
 use uv_install_wheel::validate_and_heal_record;
+use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
 use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
 use uv_python::PythonVariant;
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 
 use crate::archive::Archive;
 use crate::error::PythonVersion;
 use crate::hash::http_hash_algorithms;
 use crate::metadata::{ArchiveMetadata, Metadata};
 use crate::source::SourceDistributionBuilder;
-use crate::{Error, LocalWheel, Reporter, RequiresDist};
+use crate::{Error, LocalWheel, MetadataBuilds, Reporter, RequiresDist};
 
 /// A cached high-level interface to convert distributions (a requirement resolved to a location)
 /// to a wheel or wheel metadata.
@@ -57,6 +59,9 @@ pub struct DistributionDatabase<'a, Context: BuildContext> {
     builder: SourceDistributionBuilder<'a, Context>,
     client: ManagedClient<'a>,
     reporter: Option<Arc<dyn Reporter>>,
+    metadata_builds: Option<MetadataBuilds>,
+    reresolve_metadata: Vec<PackageName>,
+    locked: bool,
 }
 
 impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
@@ -70,6 +75,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             builder: SourceDistributionBuilder::new(build_context),
             client: ManagedClient::new(client, downloads_semaphore),
             reporter: None,
+            metadata_builds: None,
+            reresolve_metadata: Vec::new(),
+            locked: false,
         }
     }
 
@@ -92,6 +100,34 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         }
     }
 
+    /// Set the [`MetadataBuilds`] to use for the [`DistributionDatabase`], to track (and limit)
+    /// the source distributions that are built to determine their metadata.
+    #[must_use]
+    pub fn with_metadata_builds(self, metadata_builds: MetadataBuilds) -> Self {
+        Self {
+            builder: self.builder.with_metadata_builds(metadata_builds.clone()),
+            metadata_builds: Some(metadata_builds),
+            ..self
+        }
+    }
+
+    /// Set the packages for which to ignore the metadata provided by the index, reading it from
+    /// the distribution itself instead.
+    #[must_use]
+    pub fn with_reresolve_metadata(self, reresolve_metadata: Vec<PackageName>) -> Self {
+        Self {
+            reresolve_metadata,
+            ..self
+        }
+    }
+
+    /// Mark the distributions as pinned by a lockfile, such that errors for missing versions refer
+    /// to the lockfile.
+    #[must_use]
+    pub fn with_locked(self, locked: bool) -> Self {
+        Self { locked, ..self }
+    }
+
     /// Handle a specific `reqwest` error, and convert it to [`io::Error`].
     fn handle_response_errors(&self, err: reqwest::Error) -> io::Error {
         if err.is_timeout() {
@@ -121,10 +157,149 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<LocalWheel, Error> {
-        match dist {
+        let result = match dist {
             Dist::Built(built) => self.get_wheel(built, hashes).await,
             Dist::Source(source) => self.build_wheel(source, tags, hashes).await,
+        };
+        match result {
+            Err(Error::Client(err))
+                if err.is_http_missing() && self.client.unmanaged.retry_missing_artifacts() =>
+            {
+                let dist = self.replace_missing_artifact(dist, err).await?;
+                match &dist {
+                    Dist::Built(built) => self.get_wheel(built, hashes).await,
+                    Dist::Source(source) => self.build_wheel(source, tags, hashes).await,
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Recover from a registry artifact that's missing from the index (e.g., because the index
+    /// pruned it after the lockfile was created) by revalidating the package metadata and
+    /// selecting a replacement artifact for the same version.
+    ///
+    /// Returns the original error if no replacement is available, or [`Error::RemovedFromIndex`]
+    /// if the version itself no longer exists on the index.
+    async fn replace_missing_artifact(
+        &self,
+        dist: &Dist,
+        err: uv_client::Error,
+    ) -> Result<Dist, Error> {
+        let (name, version, index, file) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (
+                    wheel.name(),
+                    &wheel.filename.version,
+                    &wheel.index,
+                    &wheel.file,
+                )
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => {
+                (&sdist.name, &sdist.version, &sdist.index, &sdist.file)
+            }
+            _ => return Err(Error::Client(err)),
+        };
+        // Local and `--find-links` indexes don't expose Simple API metadata to revalidate.
+        if matches!(index, IndexUrl::Path(_))
+            || self
+                .build_context
+                .locations()
+                .flat_indexes()
+                .any(|flat_index| flat_index.url() == index)
+        {
+            return Err(Error::Client(err));
         }
+
+        debug!("Artifact for {dist} is missing from {index}; revalidating index metadata");
+        let metadata = match self
+            .client
+            .managed(|client| client.revalidate_simple_detail(name, index))
+            .await
+        {
+            Ok(metadata) => OwnedArchive::deserialize(&metadata),
+            // The package itself is gone from the index.
+            Err(revalidate_err) if revalidate_err.is_http_missing() => {
+                return Err(Error::RemovedFromIndex {
+                    name: name.clone(),
+                    version: version.clone(),
+                    index: Box::new(index.url().clone()),
+                    locked: self.locked,
+                    err,
+                });
+            }
+            Err(revalidate_err) => return Err(Error::Client(revalidate_err)),
+        };
+
+        let Some(datum) = metadata.iter().find(|datum| datum.version == *version) else {
+            return Err(Error::RemovedFromIndex {
+                name: name.clone(),
+                version: version.clone(),
+                index: Box::new(index.url().clone()),
+                locked: self.locked,
+                err,
+            });
+        };
+
+        // Prefer the same artifact at a new location; otherwise, accept an artifact with a
+        // different filename that's otherwise equivalent (e.g., a re-uploaded wheel with a new
+        // build tag).
+        let replacement = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                datum
+                    .files
+                    .wheels
+                    .iter()
+                    .filter(|candidate| candidate.file.url != file.url)
+                    .filter(|candidate| {
+                        candidate.name.python_tags() == wheel.filename.python_tags()
+                            && candidate.name.abi_tags() == wheel.filename.abi_tags()
+                            && candidate.name.platform_tags() == wheel.filename.platform_tags()
+                    })
+                    .min_by_key(|candidate| candidate.name != wheel.filename)
+                    .map(|candidate| {
+                        let mut wheels = wheels.clone();
+                        let wheel = &mut wheels.wheels[wheels.best_wheel_index];
+                        wheel.filename = candidate.name.clone();
+                        *wheel.file = candidate.file.clone();
+                        Dist::Built(BuiltDist::Registry(wheels))
+                    })
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => datum
+                .files
+                .source_dists
+                .iter()
+                .filter(|candidate| candidate.file.url != file.url)
+                .min_by_key(|candidate| candidate.file.filename != file.filename)
+                .map(|candidate| {
+                    let mut sdist = sdist.clone();
+                    *sdist.file = candidate.file.clone();
+                    sdist.ext = candidate.name.extension;
+                    Dist::Source(SourceDist::Registry(sdist))
+                }),
+            _ => None,
+        };
+
+        let Some(replacement) = replacement else {
+            return Err(Error::Client(err));
+        };
+        if let Some(replacement) = replacement.file() {
+            if replacement.filename == file.filename {
+                warn_user!(
+                    "`{}` was not found at its previous location; retrying with the location from the refreshed index metadata",
+                    file.filename,
+                );
+            } else {
+                warn_user!(
+                    "`{}` was not found on the index; retrying with `{}` from the refreshed index metadata",
+                    file.filename,
+                    replacement.filename,
+                );
+            }
+        }
+        Ok(replacement)
     }
 
     /// Either fetch the only wheel metadata (directly from the index or with range requests) or
@@ -568,7 +743,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // entire wheel to ensure that the hashes are included in the response. If the distribution
         // is hosted on an index, the hashes will be included in the simple metadata response.
         // For hash _validation_, callers are expected to enforce the policy when retrieving the
-        // wheel.
+        // wheel, unless hashes are being generated, in which case they're validated here.
         //
         // Historically, for `uv pip compile --universal`, we also generate hashes for
         // registry-based distributions when the relevant registry doesn't provide them. This was
@@ -578,8 +753,21 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         // not even be a compatible distribution!
         //
         // TODO(charlie): Request the hashes via a separate method, to reduce the coupling in this API.
-        if hashes.is_generate(dist) {
+        //
+        // Similarly, if the user opted out of the index-provided metadata for this package, fetch
+        // the entire wheel and read the metadata from its `.dist-info` directory.
+        if hashes.is_generate(dist)
+            || (matches!(dist, BuiltDist::Registry(_))
+                && self.reresolve_metadata.contains(dist.name()))
+        {
             let wheel = self.get_wheel(dist, hashes).await?;
+            if hashes.requires_validation() && !wheel.satisfies(hashes) {
+                return Err(Error::hash_mismatch(
+                    dist.to_string(),
+                    hashes.digests(),
+                    wheel.hashes(),
+                ));
+            }
             // If the metadata was provided by the user directly, prefer it.
             let metadata = if let Some(metadata) = self
                 .build_context
@@ -672,9 +860,15 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             .builder
             .download_and_build_metadata(source, hashes, &self.client)
             .boxed_local()
-            .await?;
+            .await;
+
+        if let (Some(metadata_builds), BuildableSource::Dist(SourceDist::Registry(dist))) =
+            (self.metadata_builds.as_ref(), source)
+        {
+            metadata_builds.finish(&dist.name, &dist.version);
+        }
 
-        Ok(metadata)
+        metadata
     }
 
     /// Return the [`RequiresDist`] from a `pyproject.toml`, if it can be statically extracted.
@@ -692,6 +886,34 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             .await
     }
 
+    /// Acquire an exclusive lock on the given wheel entry.
+    ///
+    /// If another process holds the lock (i.e., it's downloading the same wheel), we report that
+    /// we're waiting on it; once the lock is released, the download will typically be served from
+    /// the HTTP cache.
+    async fn lock_wheel(
+        &self,
+        wheel_entry: &CacheEntry,
+        filename: &WheelFilename,
+        name: &PackageName,
+    ) -> Result<LockedFile, Error> {
+        let lock_entry = wheel_entry.with_file(format!("{}.lock", filename.stem()));
+        if let Some(lock) = lock_entry.try_lock().map_err(Error::CacheLock)? {
+            return Ok(lock);
+        }
+
+        debug!("Waiting for another process to release the lock on: {filename}");
+        let id = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_download_wait_start(name));
+        let lock = lock_entry.lock().await.map_err(Error::CacheLock);
+        if let (Some(reporter), Some(id)) = (self.reporter.as_ref(), id) {
+            reporter.on_download_wait_complete(name, id);
+        }
+        lock
+    }
+
     /// Stream a wheel from a URL, unzipping it into the cache as it's downloaded.
     async fn stream_wheel(
         &self,
@@ -704,12 +926,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
     ) -> Result<Archive, Error> {
-        // Acquire an advisory lock, to guard against concurrent writes.
-        #[cfg(windows)]
-        let _lock = {
-            let lock_entry = wheel_entry.with_file(format!("{}.lock", filename.stem()));
-            lock_entry.lock().await.map_err(Error::CacheLock)?
-        };
+        // Acquire an advisory lock, to guard against concurrent writes, and to avoid downloading
+        // the same wheel in multiple processes at once.
+        let _lock = self.lock_wheel(wheel_entry, filename, dist.name()).await?;
 
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
@@ -801,25 +1020,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let req = self.request(url.clone())?;
 
         // Determine the cache control policy for the URL.
-        let cache_control = match self.client.unmanaged.connectivity() {
-            Connectivity::Online => {
-                if let Some(header) = index.and_then(|index| {
-                    self.build_context
-                        .locations()
-                        .artifact_cache_control_for(index)
-                }) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
-                            .map_err(Error::CacheRead)?,
-                    )
-                }
-            }
-            Connectivity::Offline => CacheControl::AllowStale,
-        };
+        let cache_control = self.wheel_cache_control(index, &http_entry, filename, hashes)?;
 
         let archive = self
             .client
@@ -837,9 +1038,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 CachedClientError::Client(err) => Error::Client(err),
             })?;
 
-        // If the archive is missing the required hashes, or has since been removed, force a refresh.
+        // If the archive is missing the required hashes, doesn't match a pinned hash, or has since
+        // been removed, force a refresh.
         let archive = Some(archive)
             .filter(|archive| archive.has_digests(hashes))
+            .filter(|archive| index.is_some() || hashes.matches(archive.hashes()))
             .filter(|archive| archive.exists(self.build_context.cache()));
 
         let archive = if let Some(archive) = archive {
@@ -867,6 +1070,43 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         Ok(archive)
     }
 
+    /// Determine the cache control policy for a wheel download.
+    ///
+    /// Direct URL wheels that are pinned by hash (e.g., from a lockfile) are reused from the cache
+    /// without revalidation, since the hash identifies the archive; if the cached archive doesn't
+    /// match the hash, the caller is expected to refresh it.
+    fn wheel_cache_control(
+        &self,
+        index: Option<&IndexUrl>,
+        http_entry: &CacheEntry,
+        filename: &WheelFilename,
+        hashes: HashPolicy<'_>,
+    ) -> Result<CacheControl, Error> {
+        Ok(match self.client.unmanaged.connectivity() {
+            Connectivity::Online => {
+                if let Some(header) = index.and_then(|index| {
+                    self.build_context
+                        .locations()
+                        .artifact_cache_control_for(index)
+                }) {
+                    CacheControl::Override(header)
+                } else {
+                    let freshness = self
+                        .build_context
+                        .cache()
+                        .freshness(http_entry, Some(&filename.name), None)
+                        .map_err(Error::CacheRead)?;
+                    if index.is_none() && hashes.requires_validation() && freshness.is_fresh() {
+                        CacheControl::AllowStale
+                    } else {
+                        CacheControl::from(freshness)
+                    }
+                }
+            }
+            Connectivity::Offline => CacheControl::AllowStale,
+        })
+    }
+
     /// Download a wheel from a URL, then unzip it into the cache.
     async fn download_wheel(
         &self,
@@ -879,12 +1119,9 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         dist: &BuiltDist,
         hashes: HashPolicy<'_>,
     ) -> Result<Archive, Error> {
-        // Acquire an advisory lock, to guard against concurrent writes.
-        #[cfg(windows)]
-        let _lock = {
-            let lock_entry = wheel_entry.with_file(format!("{}.lock", filename.stem()));
-            lock_entry.lock().await.map_err(Error::CacheLock)?
-        };
+        // Acquire an advisory lock, to guard against concurrent writes, and to avoid downloading
+        // the same wheel in multiple processes at once.
+        let _lock = self.lock_wheel(wheel_entry, filename, dist.name()).await?;
 
         // Create an entry for the HTTP cache.
         let http_entry = wheel_entry.with_file(format!("{}.http", filename.cache_key()));
@@ -977,25 +1214,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let req = self.request(url.clone())?;
 
         // Determine the cache control policy for the URL.
-        let cache_control = match self.client.unmanaged.connectivity() {
-            Connectivity::Online => {
-                if let Some(header) = index.and_then(|index| {
-                    self.build_context
-                        .locations()
-                        .artifact_cache_control_for(index)
-                }) {
-                    CacheControl::Override(header)
-                } else {
-                    CacheControl::from(
-                        self.build_context
-                            .cache()
-                            .freshness(&http_entry, Some(&filename.name), None)
-                            .map_err(Error::CacheRead)?,
-                    )
-                }
-            }
-            Connectivity::Offline => CacheControl::AllowStale,
-        };
+        let cache_control = self.wheel_cache_control(index, &http_entry, filename, hashes)?;
 
         let archive = self
             .client
@@ -1013,9 +1232,11 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
                 CachedClientError::Client(err) => Error::Client(err),
             })?;
 
-        // If the archive is missing the required hashes, or has since been removed, force a refresh.
+        // If the archive is missing the required hashes, doesn't match a pinned hash, or has since
+        // been removed, force a refresh.
         let archive = Some(archive)
             .filter(|archive| archive.has_digests(hashes))
+            .filter(|archive| index.is_some() || hashes.matches(archive.hashes()))
             .filter(|archive| archive.exists(self.build_context.cache()));
 
         let archive = if let Some(archive) = archive {
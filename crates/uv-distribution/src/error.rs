@@ -11,6 +11,7 @@ use uv_distribution_filename::{WheelFilename, WheelFilenameError};
 use uv_distribution_types::{InstalledDist, InstalledDistError, IsBuildBackendError};
 use uv_fs::Simplified;
 use uv_git::GitError;
+use uv_git_types::{GitOid, GitReference};
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::Platform;
@@ -32,12 +33,28 @@ impl fmt::Display for PythonVersion {
     }
 }
 
+/// Displays a [`GitReference`] along with its kind, e.g., ``branch `main` ``.
+struct DisplayReference<'a>(&'a GitReference);
+
+impl fmt::Display for DisplayReference<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.as_str() {
+            Some(rev) => write!(f, "{} `{rev}`", self.0.kind_str()),
+            None => write!(f, "the {}", self.0.kind_str()),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Building source distributions is disabled")]
     NoBuild,
     #[error("Building source distributions for `{0}` is disabled")]
     NoBuildPackage(PackageName),
+    #[error(
+        "Building `{0}=={1}` to determine its metadata would exceed the limit of {2} metadata builds"
+    )]
+    MetadataBuildLimit(PackageName, Version, usize),
 
     // Network error
     #[error(transparent)]
@@ -58,6 +75,15 @@ pub enum Error {
     CacheRead(#[source] std::io::Error),
     #[error("Failed to write to the distribution cache")]
     CacheWrite(#[source] std::io::Error),
+    #[error(
+        "Failed to move the built wheel into the distribution cache; the wheel was preserved at: `{}`",
+        path.user_display()
+    )]
+    CacheCommit {
+        path: PathBuf,
+        #[source]
+        err: std::io::Error,
+    },
     #[error("Failed to acquire lock on the distribution cache")]
     CacheLock(#[source] CacheError),
     #[error("Failed to deserialize cache entry")]
@@ -72,6 +98,8 @@ pub enum Error {
     // Build error
     #[error(transparent)]
     Build(AnyErrorBuild),
+    #[error("Failed to create a temporary build directory")]
+    BuildDir(#[source] std::io::Error),
     #[error("Built wheel has an invalid filename")]
     WheelFilename(#[from] WheelFilenameError),
     #[error("Package metadata name `{metadata}` does not match given name `{given}`")]
@@ -132,6 +160,17 @@ pub enum Error {
     MissingPkgInfo,
     #[error("The source distribution `{}` has no subdirectory `{}`", _0, _1.display())]
     MissingSubdirectory(DisplaySafeUrl, PathBuf),
+    #[error(
+        "The source distribution `{url}` has no subdirectory `{}` at {} (commit `{commit}`)",
+        subdirectory.display(),
+        DisplayReference(reference)
+    )]
+    MissingGitSubdirectory {
+        url: Box<DisplaySafeUrl>,
+        subdirectory: PathBuf,
+        reference: GitReference,
+        commit: GitOid,
+    },
     #[error("The source distribution `{0}` is missing Git LFS artifacts.")]
     MissingSourceDistGitLfsArtifacts(DisplaySafeUrl, #[source] GitError),
     #[error("The wheel `{0}` is missing Git LFS artifacts.")]
@@ -146,6 +185,18 @@ pub enum Error {
     MetadataLowering(#[from] MetadataError),
     #[error("Distribution not found at: {0}")]
     NotFound(DisplaySafeUrl),
+    #[error(
+        "The {}version `{name}=={version}` no longer exists on the index: {index}",
+        if *locked { "locked " } else { "" }
+    )]
+    RemovedFromIndex {
+        name: PackageName,
+        version: Version,
+        index: Box<DisplaySafeUrl>,
+        locked: bool,
+        #[source]
+        err: uv_client::Error,
+    },
     #[error("Attempted to re-extract the source distribution for `{}`, but the {} hash didn't match. Run `{}` to clear the cache.", _0, _1, "uv cache clean".green())]
     CacheHeal(String, HashAlgorithm),
     #[error("The source distribution requires Python {0}, but {1} is installed")]
@@ -226,6 +277,20 @@ impl uv_errors::Hint for Error {
         match self {
             Self::Build(err) => err.hints(),
             Self::MetadataLowering(err) => err.hints(),
+            Self::RemovedFromIndex {
+                name, locked: true, ..
+            } => uv_errors::Hints::from(format!(
+                "The version may have been removed from the index after the lockfile was created; re-lock with `{}` to select an available version",
+                format!("uv lock --upgrade-package {name}").green(),
+            )),
+            Self::RemovedFromIndex {
+                name,
+                locked: false,
+                ..
+            } => uv_errors::Hints::from(format!(
+                "The version may have been removed from the index after it was resolved; update the requirement on `{}` to select an available version",
+                name.cyan(),
+            )),
             _ => uv_errors::Hints::none(),
         }
     }
@@ -299,6 +364,7 @@ impl Error {
 #[cfg(test)]
 mod tests {
     use super::{Error, PythonVersion};
+    use std::path::PathBuf;
     use std::str::FromStr;
     use uv_distribution_filename::WheelFilename;
     use uv_platform_tags::{Arch, Os, Platform};
@@ -352,4 +418,17 @@ mod tests {
             "The built wheel `py313-0.1.0-py313-none-any.whl` is not compatible with the target Python 3.12 on Linux x86_64. Consider using `--no-build` to disable building wheels."
         );
     }
+
+    #[test]
+    fn cache_commit_error_includes_preserved_path() {
+        let err = Error::CacheCommit {
+            path: PathBuf::from("builds-v0/.tmpabc123/foo-1.0.0-py3-none-any.whl"),
+            err: std::io::Error::from(std::io::ErrorKind::StorageFull),
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "Failed to move the built wheel into the distribution cache; the wheel was preserved at: `builds-v0/.tmpabc123/foo-1.0.0-py3-none-any.whl`"
+        );
+    }
 }
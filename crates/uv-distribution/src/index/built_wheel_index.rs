@@ -16,7 +16,7 @@ use crate::Error;
 use crate::index::cached_wheel::{CachedWheel, ResolvedWheel};
 use crate::source::{
     HASHES, HTTP_REVISION, HttpRevisionPointer, LOCAL_REVISION, LocalRevisionPointer,
-    RevisionHashes,
+    RevisionHashes, VIA_SDIST,
 };
 
 /// A local index of built distributions for a specific source distribution.
@@ -29,6 +29,7 @@ pub struct BuiltWheelIndex<'a> {
     config_settings_package: &'a PackageConfigSettings,
     extra_build_requires: &'a ExtraBuildRequires,
     extra_build_variables: &'a ExtraBuildVariables,
+    via_sdist: bool,
 }
 
 impl<'a> BuiltWheelIndex<'a> {
@@ -41,6 +42,7 @@ impl<'a> BuiltWheelIndex<'a> {
         config_settings_package: &'a PackageConfigSettings,
         extra_build_requires: &'a ExtraBuildRequires,
         extra_build_variables: &'a ExtraBuildVariables,
+        via_sdist: bool,
     ) -> Self {
         Self {
             cache,
@@ -50,6 +52,7 @@ impl<'a> BuiltWheelIndex<'a> {
             config_settings_package,
             extra_build_requires,
             extra_build_variables,
+            via_sdist,
         }
     }
 
@@ -82,8 +85,12 @@ impl<'a> BuiltWheelIndex<'a> {
         let config_settings = self.config_settings_for(&source_dist.name);
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_vars.as_deref(),
+            None,
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -131,8 +138,12 @@ impl<'a> BuiltWheelIndex<'a> {
         let config_settings = self.config_settings_for(&source_dist.name);
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_vars.as_deref(),
+            None,
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -182,13 +193,24 @@ impl<'a> BuiltWheelIndex<'a> {
         let config_settings = self.config_settings_for(&source_dist.name);
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_vars.as_deref(),
+            None,
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.via_sdist {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         Ok(self.find(&cache_shard).map(|wheel| {
             CachedWheel::from_entry(wheel, revision.into_hashes(), cache_info, build_info)
         }))
@@ -212,13 +234,24 @@ impl<'a> BuiltWheelIndex<'a> {
         let config_settings = self.config_settings_for(&source_dist.name);
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_vars.as_deref(),
+            None,
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.via_sdist {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         self.find(&cache_shard).map(|wheel| {
             CachedWheel::from_entry(
                 wheel,
@@ -254,13 +287,24 @@ impl<'a> BuiltWheelIndex<'a> {
         let config_settings = self.config_settings_for(&source_dist.name);
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_vars.as_deref(),
+            None,
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.via_sdist {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         Ok(self.find(&cache_shard).map(|wheel| {
             CachedWheel::from_entry(
                 wheel,
@@ -346,7 +390,7 @@ impl<'a> BuiltWheelIndex<'a> {
     }
 
     /// Determine the extra build variables for the given package name.
-    fn extra_build_variables_for(&self, name: &PackageName) -> Option<&BuildVariables> {
-        self.extra_build_variables.get(name)
+    fn extra_build_variables_for(&self, name: &PackageName) -> Option<Cow<'_, BuildVariables>> {
+        self.extra_build_variables.for_package(Some(name))
     }
 }
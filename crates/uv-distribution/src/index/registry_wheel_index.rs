@@ -304,7 +304,8 @@ impl<'a> RegistryWheelIndex<'a> {
                     let build_info = BuildInfo::from_settings(
                         &config_settings,
                         extra_build_deps,
-                        extra_build_vars,
+                        extra_build_vars.as_deref(),
+                        None,
                     );
                     let cache_shard = build_info
                         .cache_shard()
@@ -394,7 +395,7 @@ impl<'a> RegistryWheelIndex<'a> {
     fn extra_build_variables_for<'settings>(
         name: &PackageName,
         extra_build_variables: &'settings ExtraBuildVariables,
-    ) -> Option<&'settings BuildVariables> {
-        extra_build_variables.get(name)
+    ) -> Option<Cow<'settings, BuildVariables>> {
+        extra_build_variables.for_package(Some(name))
     }
 }
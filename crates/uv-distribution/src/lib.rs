@@ -7,6 +7,7 @@ pub use metadata::{
     LoweredRequirement, LoweringError, Metadata, MetadataError, RequiresDist,
     SourcedDependencyGroups,
 };
+pub use metadata_builds::{MetadataBuild, MetadataBuilds};
 pub use reporter::Reporter;
 pub use source::{StaticMetadataDatabase, prune};
 
@@ -17,5 +18,6 @@ mod error;
 mod hash;
 mod index;
 mod metadata;
+mod metadata_builds;
 mod reporter;
 mod source;
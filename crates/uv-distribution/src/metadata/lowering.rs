@@ -147,15 +147,39 @@ impl LoweredRequirement {
             )));
         };
 
+        // Determine the space covered by the sources.
+        let mut total = MarkerTree::FALSE;
+        for source in sources.iter() {
+            total.or(source.marker());
+        }
+
+        // If the requirement is pinned to multiple indexes by marker, every environment declared
+        // in `tool.uv.environments` must be routed to one of them, rather than silently falling
+        // back to the default index.
+        if let Some(environments) = workspace.environments()
+            && sources.iter().nth(1).is_some()
+            && sources
+                .iter()
+                .all(|source| matches!(source, Source::Registry { .. }))
+        {
+            for environment in environments {
+                let mut gap = total.negate();
+                gap.and(*environment);
+                gap.and(requirement.marker);
+                if !gap.is_false() {
+                    return Either::Left(std::iter::once(Err(
+                        LoweringError::MissingIndexSourceMarkers {
+                            package: requirement.name.clone(),
+                            gap: gap.try_to_string().unwrap_or_default(),
+                        },
+                    )));
+                }
+            }
+        }
+
         // Determine whether the markers cover the full space for the requirement. If not, fill the
         // remaining space with the negation of the sources.
         let remaining = {
-            // Determine the space covered by the sources.
-            let mut total = MarkerTree::FALSE;
-            for source in sources.iter() {
-                total.or(source.marker());
-            }
-
             // Determine the space covered by the requirement.
             let mut remaining = total.negate();
             remaining.and(requirement.marker);
@@ -592,6 +616,10 @@ pub enum LoweringError {
         index: IndexName,
         hint: Option<String>,
     },
+    #[error(
+        "Index sources for `{package}` must cover all supported environments (from `tool.uv.environments`), but no index is declared for: `{gap}`"
+    )]
+    MissingIndexSourceMarkers { package: PackageName, gap: String },
     #[error("Workspace members are not allowed in non-workspace contexts")]
     WorkspaceMember,
     #[error(transparent)]
@@ -630,6 +658,9 @@ impl uv_errors::Hint for LoweringError {
             Self::MissingIndex {
                 hint: Some(hint), ..
             } => uv_errors::Hints::from(hint.clone()),
+            Self::MissingIndexSourceMarkers { package, gap } => uv_errors::Hints::from(format!(
+                "Add an entry for `{package}` with `marker = \"{gap}\"` to `tool.uv.sources`, or remove the environment from `tool.uv.environments`"
+            )),
             _ => uv_errors::Hints::none(),
         }
     }
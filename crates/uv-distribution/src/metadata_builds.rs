@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::Error;
+
+/// The source distributions that were built to determine their metadata, with an optional limit
+/// on the number of such builds (e.g., `--max-metadata-builds`).
+///
+/// Only source distributions from package indexes (including `--find-links`) are counted, since
+/// the versions to build are chosen by the resolver, rather than requested by the user.
+#[derive(Debug, Default, Clone)]
+pub struct MetadataBuilds(Arc<MetadataBuildsInner>);
+
+#[derive(Debug, Default)]
+struct MetadataBuildsInner {
+    limit: Option<usize>,
+    builds: Mutex<Vec<MetadataBuild>>,
+}
+
+/// A source distribution that was built to determine its metadata.
+#[derive(Debug, Clone)]
+pub struct MetadataBuild {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The version of the package.
+    pub version: Version,
+    /// The time at which the build started.
+    start: Instant,
+    /// The duration of the build, if it completed.
+    pub duration: Option<Duration>,
+}
+
+impl MetadataBuilds {
+    /// Track the metadata builds, failing once more than `limit` builds are required.
+    pub fn new(limit: Option<usize>) -> Self {
+        Self(Arc::new(MetadataBuildsInner {
+            limit,
+            builds: Mutex::new(Vec::new()),
+        }))
+    }
+
+    /// Return the maximum number of metadata builds, if any.
+    pub fn limit(&self) -> Option<usize> {
+        self.0.limit
+    }
+
+    /// Return the metadata builds, in the order in which they started.
+    pub fn builds(&self) -> Vec<MetadataBuild> {
+        self.0.builds.lock().unwrap().clone()
+    }
+
+    /// Record the start of a metadata build, or return an error if the build would exceed the
+    /// limit.
+    pub(crate) fn start(&self, name: &PackageName, version: &Version) -> Result<(), Error> {
+        let mut builds = self.0.builds.lock().unwrap();
+        if builds
+            .iter()
+            .any(|build| build.name == *name && build.version == *version)
+        {
+            return Ok(());
+        }
+        if let Some(limit) = self.0.limit {
+            if builds.len() >= limit {
+                return Err(Error::MetadataBuildLimit(
+                    name.clone(),
+                    version.clone(),
+                    limit,
+                ));
+            }
+        }
+        builds.push(MetadataBuild {
+            name: name.clone(),
+            version: version.clone(),
+            start: Instant::now(),
+            duration: None,
+        });
+        Ok(())
+    }
+
+    /// Record the completion of a metadata build, if one was started.
+    pub(crate) fn finish(&self, name: &PackageName, version: &Version) {
+        let mut builds = self.0.builds.lock().unwrap();
+        if let Some(build) = builds.iter_mut().find(|build| {
+            build.duration.is_none() && build.name == *name && build.version == *version
+        }) {
+            build.duration = Some(build.start.elapsed());
+        }
+    }
+}
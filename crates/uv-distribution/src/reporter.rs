@@ -11,6 +11,13 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when a build is blocked on another process building the same source
+    /// distribution.
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize;
+
+    /// Callback to invoke when another process has finished building the source distribution.
+    fn on_build_wait_complete(&self, source: &BuildableSource, id: usize);
+
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize;
 
@@ -26,6 +33,13 @@ pub trait Reporter: Send + Sync {
 
     /// Callback to invoke when a download is complete.
     fn on_download_complete(&self, name: &PackageName, id: usize);
+
+    /// Callback to invoke when a download is blocked on another process downloading the same
+    /// wheel.
+    fn on_download_wait_start(&self, name: &PackageName) -> usize;
+
+    /// Callback to invoke when another process has finished downloading the wheel.
+    fn on_download_wait_complete(&self, name: &PackageName, id: usize);
 }
 
 impl dyn Reporter {
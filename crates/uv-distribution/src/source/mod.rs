@@ -36,7 +36,7 @@ use uv_distribution_types::{
     PathSourceUrl, RequirementSource, RequiresPython, SourceDist, SourceUrl,
 };
 use uv_extract::hash::Hasher;
-use uv_fs::{Simplified, rename_with_retry, write_atomic};
+use uv_fs::{LockedFile, Simplified, copy_atomic_sync, rename_with_retry, write_atomic};
 use uv_git::{Fetch, GIT_LFS, GitError, GitHttpSettings, GitResolver};
 use uv_git_types::{GitHubRepository, GitOid, GitUrl};
 use uv_metadata::read_archive_metadata;
@@ -46,6 +46,7 @@ use uv_platform_tags::Tags;
 use uv_pypi_types::{HashAlgorithm, HashDigest, HashDigests, PyProjectToml, ResolutionMetadata};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildKey, BuildStack, SourceBuildTrait};
+use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::ToolUvSources;
 
 use crate::distribution_database::ManagedClient;
@@ -54,7 +55,7 @@ use crate::hash::http_hash_algorithms;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
 use crate::source::revision::Revision;
-use crate::{Reporter, RequiresDist};
+use crate::{MetadataBuilds, Reporter, RequiresDist};
 
 mod built_wheel_metadata;
 mod revision;
@@ -189,7 +190,17 @@ async fn fetch_git_source_tree(
     if let Some(subdirectory) = subdirectory
         && !fetch.path().join(subdirectory).is_dir()
     {
-        return Err(Error::MissingSubdirectory(url, subdirectory.to_path_buf()));
+        // Name the reference and the commit it resolved to, since the subdirectory may have been
+        // moved or removed on a branch after it was locked.
+        return Err(match fetch.git().precise() {
+            Some(commit) => Error::MissingGitSubdirectory {
+                url: Box::new(url),
+                subdirectory: subdirectory.to_path_buf(),
+                reference: git.reference().clone(),
+                commit,
+            },
+            None => Error::MissingSubdirectory(url, subdirectory.to_path_buf()),
+        });
     }
 
     if git.lfs().enabled() && !fetch.lfs_ready() {
@@ -213,6 +224,7 @@ pub(crate) struct SourceDistributionBuilder<'a, T: BuildContext> {
     build_context: &'a T,
     build_stack: Option<&'a BuildStack>,
     reporter: Option<Arc<dyn Reporter>>,
+    metadata_builds: Option<MetadataBuilds>,
 }
 
 /// The name of the file that contains the revision ID for a remote distribution, encoded via `MsgPack`.
@@ -224,6 +236,9 @@ pub(crate) const LOCAL_REVISION: &str = "revision.rev";
 /// The name of the file that contains the cached distribution hashes, encoded via `MsgPack`.
 pub(crate) const HASHES: &str = "hashes.msgpack";
 
+/// The name of the cache shard for wheels built via a source distribution of the source tree.
+pub(crate) const VIA_SDIST: &str = "via-sdist";
+
 /// The name of the file that contains the cached distribution metadata, encoded via `MsgPack`.
 const METADATA: &str = "metadata.msgpack";
 
@@ -237,6 +252,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             build_context,
             build_stack: None,
             reporter: None,
+            metadata_builds: None,
         }
     }
 
@@ -258,6 +274,15 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
     }
 
+    /// Set the [`MetadataBuilds`] to use for the [`SourceDistributionBuilder`].
+    #[must_use]
+    pub(crate) fn with_metadata_builds(self, metadata_builds: MetadataBuilds) -> Self {
+        Self {
+            metadata_builds: Some(metadata_builds),
+            ..self
+        }
+    }
+
     /// Download and build a [`SourceDist`].
     pub(crate) async fn download_and_build(
         &self,
@@ -619,8 +644,81 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     }
 
     /// Determine the extra build variables for the given package name.
-    fn extra_build_variables_for(&self, name: Option<&PackageName>) -> Option<&BuildVariables> {
-        name.and_then(|name| self.build_context.extra_build_variables().get(name))
+    fn extra_build_variables_for(
+        &self,
+        name: Option<&PackageName>,
+    ) -> Option<Cow<'_, BuildVariables>> {
+        self.build_context.extra_build_variables().for_package(name)
+    }
+
+    /// Returns `true` if the build dependencies of the given source must be resolved, such that
+    /// they can be locked, even if its metadata is available without a build.
+    async fn requires_build_dependencies(&self, source: &BuildableSource<'_>) -> bool {
+        let (Some(build_dependency_lock), Some(name)) =
+            (self.build_context.build_dependency_lock(), source.name())
+        else {
+            return false;
+        };
+        build_dependency_lock
+            .requires_resolution(name, self.build_context.interpreter().await.markers())
+    }
+
+    /// Acquire an exclusive lock on the given cache shard.
+    ///
+    /// If another process holds the lock (e.g., because it's building the same source
+    /// distribution), we report that we're waiting on it rather than building the distribution a
+    /// second time; once the lock is released, we'll typically find its wheel in the cache. The lock
+    /// is released by the operating system if the other process exits, and the wait is bounded by
+    /// `UV_LOCK_TIMEOUT`.
+    async fn lock_shard(
+        &self,
+        source: &BuildableSource<'_>,
+        cache_shard: &CacheShard,
+    ) -> Result<LockedFile, Error> {
+        if let Some(lock) = cache_shard.try_lock().map_err(Error::CacheLock)? {
+            return Ok(lock);
+        }
+
+        debug!("Waiting for another process to release the lock on: {source}");
+        let id = self
+            .reporter
+            .as_ref()
+            .map(|reporter| reporter.on_build_wait_start(source));
+        let lock = cache_shard.lock().await.map_err(Error::CacheLock);
+        if let (Some(reporter), Some(id)) = (self.reporter.as_ref(), id) {
+            reporter.on_build_wait_complete(source, id);
+        }
+        lock
+    }
+
+    /// Warn if a source distribution is about to be built for a target platform other than the
+    /// host (e.g., with `--python-platform`), since the build backend will target the host.
+    async fn warn_cross_platform_build(&self, source: &BuildableSource<'_>, tags: &Tags) {
+        if !tags.is_cross() {
+            return;
+        }
+        let host = self.build_context.interpreter().await.platform();
+        let target = tags.python_platform();
+        if host.arch() == target.arch()
+            && std::mem::discriminant(host.os()) == std::mem::discriminant(target.os())
+        {
+            return;
+        }
+        // A pre-built wheel can only be used in place of a registry source distribution.
+        if let BuildableSource::Dist(SourceDist::Registry(dist)) = source {
+            warn_user_once!(
+                "Building `{source}` from source for the host platform ({}) rather than the target platform ({}). The resulting wheel may not be compatible with the target; consider using `--only-binary {}` to require a pre-built wheel, or building on the target platform.",
+                host.pretty(),
+                target.pretty(),
+                dist.name,
+            );
+        } else {
+            warn_user_once!(
+                "Building `{source}` from source for the host platform ({}) rather than the target platform ({}). The resulting wheel may not be compatible with the target; consider building on the target platform instead.",
+                host.pretty(),
+                target.pretty(),
+            );
+        }
     }
 
     /// Build a source distribution from a remote URL.
@@ -636,7 +734,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, cache_shard).await?;
 
         // Fetch the revision for the source distribution.
         let revision = self
@@ -665,8 +763,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -713,6 +815,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        self.warn_cross_platform_build(source, tags).await;
+
         let task = self
             .reporter
             .as_ref()
@@ -766,7 +870,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         hashes: HashPolicy<'_>,
         client: &ManagedClient<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, cache_shard).await?;
 
         // Fetch the revision for the source distribution.
         let revision = self
@@ -787,23 +891,26 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_dist_entry = cache_shard.entry(SOURCE);
 
+        // If the build dependencies must be locked, neither static nor cached metadata can be used.
+        let requires_build = self.requires_build_dependencies(source).await;
+
         // If the metadata is static, return it.
         let dynamic =
             match StaticMetadata::read(source, source_dist_entry.path(), subdirectory).await? {
-                StaticMetadata::Some(metadata) => {
+                StaticMetadata::Some(metadata) if !requires_build => {
                     return Ok(ArchiveMetadata {
                         metadata: Metadata::from_metadata23(metadata),
                         hashes: revision.into_hashes(),
                     });
                 }
                 StaticMetadata::Dynamic => true,
-                StaticMetadata::None => false,
+                StaticMetadata::Some(_) | StaticMetadata::None => false,
             };
 
         // If the cache contains compatible metadata, return it.
         let metadata_entry = cache_shard.entry(METADATA);
         match CachedMetadata::read(&metadata_entry).await {
-            Ok(Some(metadata)) => {
+            Ok(Some(metadata)) if !requires_build => {
                 if metadata.matches(source.name(), source.version()) {
                     debug!("Using cached metadata for: {source}");
                     return Ok(ArchiveMetadata {
@@ -813,7 +920,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }
                 debug!("Cached metadata does not match expected name and version for: {source}");
             }
-            Ok(None) => {}
+            Ok(Some(_) | None) => {}
             Err(err) => {
                 debug!("Failed to deserialize cached metadata for: {source} ({err})");
             }
@@ -853,6 +960,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 source,
                 source_dist_entry.path(),
                 subdirectory,
+                &cache_shard,
                 NoSources::None,
             )
             .boxed_local()
@@ -886,8 +994,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1040,7 +1152,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         tags: &Tags,
         hashes: HashPolicy<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, cache_shard).await?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -1068,8 +1180,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1097,6 +1213,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 .await?
         };
 
+        self.warn_cross_platform_build(source, tags).await;
+
         let task = self
             .reporter
             .as_ref()
@@ -1145,7 +1263,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         cache_shard: &CacheShard,
         hashes: HashPolicy<'_>,
     ) -> Result<ArchiveMetadata, Error> {
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, cache_shard).await?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer { revision, .. } = self
@@ -1166,22 +1284,25 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let cache_shard = cache_shard.shard(revision.id());
         let source_entry = cache_shard.entry(SOURCE);
 
+        // If the build dependencies must be locked, neither static nor cached metadata can be used.
+        let requires_build = self.requires_build_dependencies(source).await;
+
         // If the metadata is static, return it.
         let dynamic = match StaticMetadata::read(source, source_entry.path(), None).await? {
-            StaticMetadata::Some(metadata) => {
+            StaticMetadata::Some(metadata) if !requires_build => {
                 return Ok(ArchiveMetadata {
                     metadata: Metadata::from_metadata23(metadata),
                     hashes: revision.into_hashes(),
                 });
             }
             StaticMetadata::Dynamic => true,
-            StaticMetadata::None => false,
+            StaticMetadata::Some(_) | StaticMetadata::None => false,
         };
 
         // If the cache contains compatible metadata, return it.
         let metadata_entry = cache_shard.entry(METADATA);
         match CachedMetadata::read(&metadata_entry).await {
-            Ok(Some(metadata)) => {
+            Ok(Some(metadata)) if !requires_build => {
                 if metadata.matches(source.name(), source.version()) {
                     debug!("Using cached metadata for: {source}");
                     return Ok(ArchiveMetadata {
@@ -1191,7 +1312,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }
                 debug!("Cached metadata does not match expected name and version for: {source}");
             }
-            Ok(None) => {}
+            Ok(Some(_) | None) => {}
             Err(err) => {
                 debug!("Failed to deserialize cached metadata for: {source} ({err})");
             }
@@ -1207,7 +1328,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, source_entry.path(), None, NoSources::None)
+            .build_metadata(
+                source,
+                source_entry.path(),
+                None,
+                &cache_shard,
+                NoSources::None,
+            )
             .boxed_local()
             .await?
         {
@@ -1239,8 +1366,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1366,7 +1497,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         );
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, &cache_shard).await?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer {
@@ -1384,13 +1515,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.build_context.build_runtime().via_sdist() {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         // If the cache contains a compatible wheel, return it.
         if let Some(file) = BuiltWheelFile::find_in_cache(tags, &cache_shard)
             .ok()
@@ -1406,6 +1548,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need to build a wheel.
+        self.warn_cross_platform_build(source, tags).await;
+
         let task = self
             .reporter
             .as_ref()
@@ -1468,9 +1612,12 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .source_tree_editable_policy()
             .workspace_member_editable(resource.editable);
 
+        // If the build dependencies must be locked, neither static nor cached metadata can be used.
+        let requires_build = self.requires_build_dependencies(source).await;
+
         // If the metadata is static, return it.
         let dynamic = match StaticMetadata::read(source, resource.install_path, None).await? {
-            StaticMetadata::Some(metadata) => {
+            StaticMetadata::Some(metadata) if !requires_build => {
                 return Ok(ArchiveMetadata::from(
                     Metadata::from_workspace(
                         metadata,
@@ -1487,7 +1634,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 ));
             }
             StaticMetadata::Dynamic => true,
-            StaticMetadata::None => false,
+            StaticMetadata::Some(_) | StaticMetadata::None => false,
         };
 
         let cache_shard = self.build_context.cache().shard(
@@ -1500,7 +1647,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         );
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, &cache_shard).await?;
 
         // Fetch the revision for the source distribution.
         let LocalRevisionPointer { revision, .. } = self
@@ -1514,7 +1661,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // If the cache contains compatible metadata, return it.
         let metadata_entry = cache_shard.entry(METADATA);
         match CachedMetadata::read(&metadata_entry).await {
-            Ok(Some(metadata)) => {
+            Ok(Some(metadata)) if !requires_build => {
                 if metadata.matches(source.name(), source.version()) {
                     debug!("Using cached metadata for: {source}");
 
@@ -1544,7 +1691,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }
                 debug!("Cached metadata does not match expected name and version for: {source}");
             }
-            Ok(None) => {}
+            Ok(Some(_) | None) => {}
             Err(err) => {
                 debug!("Failed to deserialize cached metadata for: {source} ({err})");
             }
@@ -1556,6 +1703,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 source,
                 resource.install_path,
                 None,
+                &cache_shard,
                 self.build_context.sources().clone(),
             )
             .boxed_local()
@@ -1599,13 +1747,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.build_context.build_runtime().via_sdist() {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         // Otherwise, we need to build a wheel.
         let task = self
             .reporter
@@ -1858,13 +2017,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.build_context.build_runtime().via_sdist() {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         // If the cache contains a compatible wheel, return it.
         if let Some(file) = BuiltWheelFile::find_in_cache(tags, &cache_shard)
             .ok()
@@ -1880,6 +2050,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need to build a wheel.
+        self.warn_cross_platform_build(source, tags).await;
+
         let task = self
             .reporter
             .as_ref()
@@ -1961,22 +2133,25 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         let source_entry = cache_shard.entry(SOURCE);
 
+        // If the build dependencies must be locked, neither static nor cached metadata can be used.
+        let requires_build = self.requires_build_dependencies(source).await;
+
         // If the metadata is static, return it.
         let dynamic = match StaticMetadata::read(source, source_entry.path(), None).await? {
-            StaticMetadata::Some(metadata) => {
+            StaticMetadata::Some(metadata) if !requires_build => {
                 return Ok(ArchiveMetadata {
                     metadata: Metadata::from_metadata23(metadata),
                     hashes: revision.into_hashes(),
                 });
             }
             StaticMetadata::Dynamic => true,
-            StaticMetadata::None => false,
+            StaticMetadata::Some(_) | StaticMetadata::None => false,
         };
 
         // If the cache contains compatible metadata, return it.
         let metadata_entry = cache_shard.entry(METADATA);
         match CachedMetadata::read(&metadata_entry).await {
-            Ok(Some(metadata)) => {
+            Ok(Some(metadata)) if !requires_build => {
                 if metadata.matches(source.name(), source.version()) {
                     debug!("Using cached metadata for: {source}");
                     return Ok(ArchiveMetadata {
@@ -1986,7 +2161,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 }
                 debug!("Cached metadata does not match expected name and version for: {source}");
             }
-            Ok(None) => {}
+            Ok(Some(_) | None) => {}
             Err(err) => {
                 debug!("Failed to deserialize cached metadata for: {source} ({err})");
             }
@@ -1994,7 +2169,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
 
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
-            .build_metadata(source, source_entry.path(), None, NoSources::None)
+            .build_metadata(
+                source,
+                source_entry.path(),
+                None,
+                &cache_shard,
+                NoSources::None,
+            )
             .boxed_local()
             .await?
         {
@@ -2026,13 +2207,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.build_context.build_runtime().via_sdist() {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         // Otherwise, we need to build a wheel.
         let task = self
             .reporter
@@ -2111,7 +2303,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, &cache_shard).await?;
 
         // We don't track any cache information for Git-based source distributions; they're assumed
         // to be immutable.
@@ -2125,13 +2317,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.build_context.build_runtime().via_sdist() {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         // If the cache contains a compatible wheel, return it.
         if let Some(file) = BuiltWheelFile::find_in_cache(tags, &cache_shard)
             .ok()
@@ -2143,6 +2346,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        self.warn_cross_platform_build(source, tags).await;
+
         let task = self
             .reporter
             .as_ref()
@@ -2209,11 +2414,16 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     WheelCache::Git(resource.url, oid.as_short_str()).root(),
                 )
             });
+        // If the build dependencies must be locked, neither static nor cached metadata can be used.
+        let requires_build = self.requires_build_dependencies(source).await;
+
         if cache_shard
             .as_ref()
             .is_some_and(|cache_shard| cache_shard.is_dir())
         {
             debug!("Skipping GitHub fast path for: {source} (shard exists)");
+        } else if requires_build {
+            debug!("Skipping GitHub fast path for: {source} (build dependencies must be locked)");
         } else {
             debug!("Attempting GitHub fast path for: {source}");
 
@@ -2300,7 +2510,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let metadata_entry = cache_shard.entry(METADATA);
 
         // Acquire the advisory lock.
-        let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
+        let _lock = self.lock_shard(source, &cache_shard).await?;
 
         let path = if let Some(subdirectory) = resource.subdirectory {
             Cow::Owned(fetch.path().join(subdirectory))
@@ -2316,7 +2526,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         // If the metadata is static, return it.
         let dynamic =
             match StaticMetadata::read(source, fetch.path(), resource.subdirectory).await? {
-                StaticMetadata::Some(metadata) => {
+                StaticMetadata::Some(metadata) if !requires_build => {
                     return Ok(ArchiveMetadata::from(
                         Metadata::from_workspace(
                             metadata,
@@ -2335,7 +2545,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     ));
                 }
                 StaticMetadata::Dynamic => true,
-                StaticMetadata::None => false,
+                StaticMetadata::Some(_) | StaticMetadata::None => false,
             };
 
         // If the cache contains compatible metadata, return it.
@@ -2347,7 +2557,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .is_fresh()
         {
             match CachedMetadata::read(&metadata_entry).await {
-                Ok(Some(metadata)) => {
+                Ok(Some(metadata)) if !requires_build => {
                     if metadata.matches(source.name(), source.version()) {
                         debug!("Using cached metadata for: {source}");
 
@@ -2376,7 +2586,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                         "Cached metadata does not match expected name and version for: {source}"
                     );
                 }
-                Ok(None) => {}
+                Ok(Some(_) | None) => {}
                 Err(err) => {
                     debug!("Failed to deserialize cached metadata for: {source} ({err})");
                 }
@@ -2389,6 +2599,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 source,
                 fetch.path(),
                 resource.subdirectory,
+                &cache_shard,
                 self.build_context.sources().clone(),
             )
             .boxed_local()
@@ -2434,13 +2645,24 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let config_settings = self.config_settings_for(source.name());
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
-        let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+        let build_info = BuildInfo::from_settings(
+            &config_settings,
+            extra_build_deps,
+            extra_build_variables.as_deref(),
+            self.build_context.build_runtime().target(),
+        );
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
             .unwrap_or(cache_shard);
 
+        // If the wheel is built via a source distribution, we need to scope to a cache shard.
+        let cache_shard = if self.build_context.build_runtime().via_sdist() {
+            cache_shard.shard(VIA_SDIST)
+        } else {
+            cache_shard
+        };
+
         // Otherwise, we need to build a wheel.
         let task = self
             .reporter
@@ -2957,8 +3179,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let temp_dir = self
             .build_context
             .cache()
-            .build_dir()
-            .map_err(Error::CacheWrite)?;
+            .build_dir_in(self.build_context.build_runtime().build_dir())
+            .map_err(Error::BuildDir)?;
 
         // Build the wheel.
         fs::create_dir_all(&cache_shard)
@@ -3044,17 +3266,14 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                         stop_discovery_at,
                         Some(&source.to_string()),
                         source.as_dist(),
+                        Some(cache_shard),
                         &no_sources,
                         if source.is_editable() {
                             BuildKind::Editable
                         } else {
                             BuildKind::Wheel
                         },
-                        if uv_flags::contains(uv_flags::EnvironmentFlags::HIDE_BUILD_OUTPUT) {
-                            BuildOutput::Quiet
-                        } else {
-                            BuildOutput::Debug
-                        },
+                        build_output(),
                         self.build_stack.cloned().unwrap_or_default(),
                     )
                     .await
@@ -3079,12 +3298,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         validate_filename(&filename, &metadata)?;
 
         // Move the wheel to the cache.
-        rename_with_retry(
-            temp_dir.path().join(&disk_filename),
-            cache_shard.join(&disk_filename),
+        commit_built_wheel(
+            temp_dir,
+            &disk_filename,
+            cache_shard,
+            !self.build_context.cache().is_temporary(),
         )
-        .await
-        .map_err(Error::CacheWrite)?;
+        .await?;
 
         debug!("Built `{source}` into `{disk_filename}`");
         Ok((disk_filename, filename, metadata))
@@ -3097,6 +3317,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         source: &BuildableSource<'_>,
         source_root: &Path,
         subdirectory: Option<&Path>,
+        cache_shard: &CacheShard,
         no_sources: NoSources,
     ) -> Result<Option<ResolutionMetadata>, Error> {
         debug!("Preparing metadata for: {source}");
@@ -3117,6 +3338,13 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             };
         }
 
+        // Count builds of index source distributions against the metadata build limit.
+        if let (Some(metadata_builds), BuildableSource::Dist(SourceDist::Registry(dist))) =
+            (self.metadata_builds.as_ref(), source)
+        {
+            metadata_builds.start(&dist.name, &dist.version)?;
+        }
+
         // Ensure that the _installed_ Python version is compatible with the `requires-python`
         // specifier.
         if let Some(requires_python) = source.requires_python() {
@@ -3178,13 +3406,10 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                 stop_discovery_at,
                 Some(&source.to_string()),
                 source.as_dist(),
+                Some(cache_shard),
                 &no_sources,
                 build_kind,
-                if uv_flags::contains(uv_flags::EnvironmentFlags::HIDE_BUILD_OUTPUT) {
-                    BuildOutput::Quiet
-                } else {
-                    BuildOutput::Debug
-                },
+                build_output(),
                 self.build_stack.cloned().unwrap_or_default(),
             )
             .await
@@ -3421,6 +3646,90 @@ impl StaticMetadata {
     }
 }
 
+/// Move a built wheel from the build directory into the cache, retrying on transient errors
+/// without rebuilding the wheel.
+///
+/// The wheel is always renamed into place, such that it only appears in the cache once complete. If
+/// the build directory and the cache are on different filesystems, the wheel is first copied to a
+/// temporary file alongside the destination.
+///
+/// If the move fails and `preserve` is set, the build directory is kept (rather than being
+/// discarded along with the wheel), and its path is included in the error, such that the wheel can
+/// be salvaged.
+async fn commit_built_wheel(
+    temp_dir: tempfile::TempDir,
+    disk_filename: &str,
+    cache_shard: &Path,
+    preserve: bool,
+) -> Result<(), Error> {
+    /// The number of times to retry the move on a transient error.
+    const RETRIES: u32 = 3;
+
+    let from = temp_dir.path().join(disk_filename);
+    let to = cache_shard.join(disk_filename);
+    let mut attempt = 0;
+    let result = loop {
+        let result = match rename_with_retry(&from, &to).await {
+            Err(err) if err.kind() == std::io::ErrorKind::CrossesDevices => {
+                debug!(
+                    "Copying built wheel into the cache across filesystems: `{}`",
+                    to.user_display()
+                );
+                let (from, to) = (from.clone(), to.clone());
+                tokio::task::spawn_blocking(move || copy_atomic_sync(from, to))
+                    .await
+                    .map_err(std::io::Error::from)
+                    .and_then(|result| result)
+            }
+            result => result,
+        };
+        match result {
+            Err(err) if attempt < RETRIES && is_transient(&err) => {
+                attempt += 1;
+                warn!(
+                    "Retrying move of built wheel to `{}` due to transient error: {err}",
+                    to.user_display()
+                );
+                tokio::time::sleep(std::time::Duration::from_millis(100 << attempt)).await;
+            }
+            result => break result,
+        }
+    };
+    match result {
+        Ok(()) => Ok(()),
+        Err(err) if preserve => {
+            let path = temp_dir.keep().join(disk_filename);
+            Err(Error::CacheCommit { path, err })
+        }
+        Err(err) => Err(Error::CacheWrite(err)),
+    }
+}
+
+/// Returns `true` if the I/O error is likely to resolve itself on retry.
+///
+/// Permission errors (e.g., due to antivirus software on Windows) are already retried by
+/// [`rename_with_retry`], and so aren't considered here.
+fn is_transient(err: &std::io::Error) -> bool {
+    matches!(
+        err.kind(),
+        std::io::ErrorKind::Interrupted
+            | std::io::ErrorKind::WouldBlock
+            | std::io::ErrorKind::TimedOut
+            | std::io::ErrorKind::ResourceBusy
+    )
+}
+
+/// Determine how the build backend output should be displayed, based on the environment.
+fn build_output() -> BuildOutput {
+    if uv_flags::contains(uv_flags::EnvironmentFlags::HIDE_BUILD_OUTPUT) {
+        BuildOutput::Quiet
+    } else if uv_flags::contains(uv_flags::EnvironmentFlags::STREAM_BUILD_OUTPUT) {
+        BuildOutput::Stream
+    } else {
+        BuildOutput::Debug
+    }
+}
+
 /// Returns `true` if a `pyproject.toml` has `tool.uv.sources`.
 fn has_sources(content: &str) -> Result<bool, toml::de::Error> {
     #[derive(serde::Deserialize)]
@@ -3699,3 +4008,41 @@ fn read_wheel_metadata(
         .map_err(|err| Error::WheelMetadata(wheel.to_path_buf(), Box::new(err)))?;
     Ok(ResolutionMetadata::parse_metadata(&dist_info)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, commit_built_wheel, is_transient};
+
+    #[tokio::test]
+    async fn commit_built_wheel_preserves_wheel_on_failure() {
+        let root = tempfile::tempdir().unwrap();
+        let temp_dir = tempfile::tempdir_in(root.path()).unwrap();
+        let filename = "foo-1.0.0-py3-none-any.whl";
+        fs_err::write(temp_dir.path().join(filename), b"wheel").unwrap();
+
+        // The cache shard doesn't exist, so the move fails.
+        let cache_shard = root.path().join("missing");
+        let err = commit_built_wheel(temp_dir, filename, &cache_shard, true)
+            .await
+            .unwrap_err();
+
+        let Error::CacheCommit { path, .. } = err else {
+            panic!("Expected a cache commit error, found: {err:?}");
+        };
+        assert_eq!(fs_err::read(&path).unwrap(), b"wheel");
+        assert!(!cache_shard.join(filename).exists());
+    }
+
+    #[test]
+    fn transient_errors() {
+        use std::io::{Error, ErrorKind};
+
+        // Transient errors are retried, without rebuilding the wheel.
+        assert!(is_transient(&Error::from(ErrorKind::Interrupted)));
+        assert!(is_transient(&Error::from(ErrorKind::ResourceBusy)));
+
+        // Persistent errors fail the commit immediately.
+        assert!(!is_transient(&Error::from(ErrorKind::NotFound)));
+        assert!(!is_transient(&Error::from(ErrorKind::StorageFull)));
+    }
+}
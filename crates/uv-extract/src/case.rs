@@ -0,0 +1,204 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+
+use crate::Error;
+
+/// Whether a filesystem distinguishes between paths that differ only in case.
+///
+/// Archives are extracted as-is to case-sensitive filesystems. On case-insensitive filesystems,
+/// archives with entries whose paths differ only in case (e.g., `LICENSE` and `license`) are
+/// rejected with [`Error::CaseCollision`], since one entry would otherwise overwrite the other.
+///
+/// Case sensitivity is a property of the filesystem (and, on some platforms, of the individual
+/// directory) rather than the platform, so it's detected at runtime via
+/// [`CaseSensitivity::detect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseSensitivity {
+    /// Paths that differ only in case refer to distinct files (e.g., most Linux filesystems).
+    Sensitive,
+    /// Paths that differ only in case refer to the same file (e.g., the default APFS and NTFS
+    /// configurations on macOS and Windows).
+    Insensitive,
+}
+
+impl CaseSensitivity {
+    /// Detect the case sensitivity of the filesystem underlying the given directory, by creating
+    /// a probe file and checking whether it can be found under a differently-cased name.
+    ///
+    /// The directory must exist and be writable; the probe file is removed before returning.
+    /// Returns an error if the probe file can't be created, removed, or checked.
+    pub fn detect(dir: &Path) -> io::Result<Self> {
+        let probe = dir.join(format!(".uv-case-probe-{}", std::process::id()));
+        let alias = dir.join(format!(".UV-CASE-PROBE-{}", std::process::id()));
+        fs_err::File::create(&probe)?;
+        let exists = alias.try_exists();
+        fs_err::remove_file(&probe)?;
+        if exists? {
+            Ok(Self::Insensitive)
+        } else {
+            Ok(Self::Sensitive)
+        }
+    }
+}
+
+/// Track the entries in an archive, to detect entries whose paths differ only in case.
+///
+/// On a case-insensitive filesystem, such entries (e.g., `LICENSE` and `license`) would be
+/// written to the same file, such that one silently overwrites the other. The filesystem is only
+/// probed once a candidate collision is found, so archives without any such entries don't pay for
+/// the check.
+#[derive(Debug)]
+pub(crate) struct CaseCollisions {
+    /// The entries seen so far, keyed by their case-folded path.
+    seen: FxHashMap<String, PathBuf>,
+    /// The case sensitivity of the target filesystem, if known.
+    sensitivity: Option<CaseSensitivity>,
+}
+
+impl CaseCollisions {
+    /// Create a tracker that detects the case sensitivity of the target filesystem on demand.
+    pub(crate) fn new() -> Self {
+        Self {
+            seen: FxHashMap::default(),
+            sensitivity: None,
+        }
+    }
+
+    /// Create a tracker that assumes the given case sensitivity, rather than probing the target
+    /// filesystem.
+    #[cfg(test)]
+    pub(crate) fn with_sensitivity(sensitivity: CaseSensitivity) -> Self {
+        Self {
+            seen: FxHashMap::default(),
+            sensitivity: Some(sensitivity),
+        }
+    }
+
+    /// Record an entry at the given path relative to `target`, returning an error if it collides
+    /// with a previously-recorded entry on the target filesystem.
+    pub(crate) fn insert(&mut self, relpath: &Path, target: &Path) -> Result<(), Error> {
+        let key = relpath.to_string_lossy().to_lowercase();
+        let Some(existing) = self.seen.get(&key) else {
+            self.seen.insert(key, relpath.to_path_buf());
+            return Ok(());
+        };
+
+        // Repeated entries with the same path are validated elsewhere.
+        if existing == relpath {
+            return Ok(());
+        }
+
+        if self.sensitivity.is_none() {
+            self.sensitivity = Some(CaseSensitivity::detect(target).map_err(Error::Io)?);
+        }
+
+        match self.sensitivity {
+            Some(CaseSensitivity::Insensitive) => Err(Error::CaseCollision {
+                existing: existing.clone(),
+                path: relpath.to_path_buf(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{CaseCollisions, CaseSensitivity};
+    use crate::Error;
+
+    #[test]
+    fn case_insensitive_collision() {
+        let target = Path::new("unused");
+        let mut collisions = CaseCollisions::with_sensitivity(CaseSensitivity::Insensitive);
+        collisions
+            .insert(Path::new("foo-1.0.dist-info/LICENSE"), target)
+            .unwrap();
+        collisions
+            .insert(Path::new("foo-1.0.dist-info/LICENSE"), target)
+            .unwrap();
+        collisions
+            .insert(Path::new("foo-1.0.dist-info/METADATA"), target)
+            .unwrap();
+
+        let err = collisions
+            .insert(Path::new("foo-1.0.dist-info/license"), target)
+            .unwrap_err();
+        let Error::CaseCollision { existing, path } = &err else {
+            panic!("expected `CaseCollision` error, found: {err:?}");
+        };
+        assert_eq!(existing, Path::new("foo-1.0.dist-info/LICENSE"));
+        assert_eq!(path, Path::new("foo-1.0.dist-info/license"));
+        assert_eq!(
+            err.to_string(),
+            "Archive contains entries whose paths differ only in case, which can't be extracted to a case-insensitive filesystem: `foo-1.0.dist-info/LICENSE` and `foo-1.0.dist-info/license`"
+        );
+
+        // Paths that differ only in the case of a parent directory collide, too.
+        collisions
+            .insert(Path::new("Foo/__init__.py"), target)
+            .unwrap();
+        assert!(
+            collisions
+                .insert(Path::new("foo/__init__.py"), target)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn case_sensitive_collision() {
+        let target = Path::new("unused");
+        let mut collisions = CaseCollisions::with_sensitivity(CaseSensitivity::Sensitive);
+        collisions.insert(Path::new("LICENSE"), target).unwrap();
+        collisions.insert(Path::new("license"), target).unwrap();
+    }
+
+    /// On a case-insensitive filesystem, the probe detects the collision without a shim.
+    #[test]
+    #[cfg(any(target_os = "macos", windows))]
+    fn detect_case_insensitive_filesystem() {
+        let target = tempfile::tempdir().unwrap();
+        if CaseSensitivity::detect(target.path()).unwrap() == CaseSensitivity::Sensitive {
+            // Case-sensitive volumes are rare, but supported, on both platforms.
+            return;
+        }
+
+        let mut collisions = CaseCollisions::new();
+        collisions
+            .insert(Path::new("LICENSE"), target.path())
+            .unwrap();
+        assert!(
+            collisions
+                .insert(Path::new("license"), target.path())
+                .is_err()
+        );
+
+        // The probe file is removed.
+        assert_eq!(fs_err::read_dir(target.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn detect_case_sensitive_filesystem() {
+        let target = tempfile::tempdir().unwrap();
+        if CaseSensitivity::detect(target.path()).unwrap() == CaseSensitivity::Insensitive {
+            // e.g., a case-folding ext4 directory.
+            return;
+        }
+
+        let mut collisions = CaseCollisions::new();
+        collisions
+            .insert(Path::new("LICENSE"), target.path())
+            .unwrap();
+        collisions
+            .insert(Path::new("license"), target.path())
+            .unwrap();
+
+        // The probe file is removed.
+        assert_eq!(fs_err::read_dir(target.path()).unwrap().count(), 0);
+    }
+}
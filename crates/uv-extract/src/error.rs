@@ -91,13 +91,15 @@ pub enum Error {
     EmptyFilename,
     #[error("Archive contains unacceptable filename: {filename}")]
     UnacceptableFilename { filename: String },
+    #[error("Archive contains entries whose paths differ only in case, which can't be extracted to a case-insensitive filesystem: `{}` and `{}`", existing.display(), path.display())]
+    CaseCollision { existing: PathBuf, path: PathBuf },
 }
 
 impl Error {
     /// When reading from an archive, the error can either be an IO error from the underlying
     /// operating system, or an error with the archive. Both get wrapper into an IO error through
-    /// e.g., `io::copy`. This method extracts zip and tar errors, to distinguish them from invalid
-    /// archives.
+    /// e.g., `io::copy`. This method extracts zip and tar errors (along with any extraction errors
+    /// raised while unpacking a tar archive), to distinguish them from invalid archives.
     pub(crate) fn io_or_compression(err: std::io::Error) -> Self {
         if err.kind() != std::io::ErrorKind::Other {
             return Self::Io(err);
@@ -111,6 +113,10 @@ impl Error {
             Ok(zip_err) => return Self::AsyncZip(zip_err),
             Err(err) => err,
         };
+        let err = match err.downcast::<Self>() {
+            Ok(err) => return err,
+            Err(err) => err,
+        };
         Self::Io(err)
     }
 
@@ -1,10 +1,12 @@
 use std::{fmt::Display, sync::LazyLock};
 
+pub use case::CaseSensitivity;
 pub use error::Error;
 use regex::Regex;
 pub use sync::*;
 use uv_static::EnvVars;
 
+mod case;
 mod error;
 pub mod hash;
 pub mod stream;
@@ -10,8 +10,10 @@ use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
 use tracing::{debug, warn};
 
 use uv_distribution_filename::SourceDistExtension;
+use uv_fs::verbatim_path;
 use uv_warnings::warn_user_once;
 
+use crate::case::CaseCollisions;
 use crate::{CompressionMethod, Error, insecure_no_validate, validate_archive_member_name};
 
 const DEFAULT_BUF_SIZE: usize = 128 * 1024;
@@ -78,11 +80,15 @@ pub async fn unzip<D: Display, R: tokio::io::AsyncRead + Unpin>(
     // Determine whether ZIP validation is disabled.
     let skip_validation = insecure_no_validate();
 
+    // On Windows, use extended-length paths, such that files nested deeper than `MAX_PATH` can be
+    // extracted without long path support enabled.
+    let target = verbatim_path(target.as_ref());
     let target = target.as_ref();
     let mut reader = futures::io::BufReader::with_capacity(DEFAULT_BUF_SIZE, reader.compat());
     let mut zip = async_zip::base::read::stream::ZipFileReader::new(&mut reader);
 
     let mut directories = FxHashSet::default();
+    let mut collisions = CaseCollisions::new();
     let mut local_headers = FxHashMap::default();
     let mut files = Vec::new();
     let mut offset = 0;
@@ -173,6 +179,11 @@ pub async fn unzip<D: Display, R: tokio::io::AsyncRead + Unpin>(
                 compressed_size: 0,
             }
         } else {
+            // Reject entries that would overwrite one another on a case-insensitive filesystem.
+            if !skip_validation {
+                collisions.insert(&relpath, target)?;
+            }
+
             if let Some(parent) = path.parent() {
                 if directories.insert(parent.to_path_buf()) {
                     fs_err::tokio::create_dir_all(parent)
@@ -601,6 +612,11 @@ async fn untar_in(
     // Memoize filesystem calls to canonicalize paths.
     let mut memo = FxHashSet::default();
 
+    // Track the unpacked files, to reject entries that would overwrite one another on a
+    // case-insensitive filesystem.
+    let skip_validation = insecure_no_validate();
+    let mut collisions = CaseCollisions::new();
+
     let mut files = Vec::new();
 
     let mut entries = archive.entries()?;
@@ -623,6 +639,11 @@ async fn untar_in(
         let entry_type = file.header().entry_type();
         if entry_type.is_file() || entry_type.is_hard_link() {
             let relpath = file.path()?.into_owned();
+            if !skip_validation {
+                collisions
+                    .insert(&relpath, &dst)
+                    .map_err(std::io::Error::other)?;
+            }
             let size = file.header().size()?;
             files.push((relpath, size));
         }
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use crate::case::CaseCollisions;
 use crate::vendor::CloneableSeekableReader;
 use crate::{CompressionMethod, Error, insecure_no_validate, validate_archive_member_name};
 use async_zip::base::read::seek::ZipFileReader;
@@ -11,6 +12,7 @@ use rayon::prelude::*;
 use rustc_hash::FxHashSet;
 use tracing::warn;
 use uv_configuration::initialize_rayon_once;
+use uv_fs::verbatim_path;
 use uv_warnings::warn_user_once;
 
 /// Unzip a `.zip` archive into the target directory.
@@ -19,6 +21,11 @@ use uv_warnings::warn_user_once;
 pub fn unzip(reader: fs_err::File, target: &Path) -> Result<Vec<(PathBuf, u64)>, Error> {
     let (reader, filename) = reader.into_parts();
 
+    // On Windows, use extended-length paths, such that files nested deeper than `MAX_PATH` can be
+    // extracted without long path support enabled.
+    let target = verbatim_path(target);
+    let target = target.as_ref();
+
     // Parse the central directory once, then clone the archive reader per Rayon worker so
     // extraction stays parallel for already-downloaded wheels.
     let archive = block_on(ZipFileReader::new(AllowStdIo::new(
@@ -26,6 +33,25 @@ pub fn unzip(reader: fs_err::File, target: &Path) -> Result<Vec<(PathBuf, u64)>,
     )))?;
     let directories = Mutex::new(FxHashSet::default());
     let skip_validation = insecure_no_validate();
+
+    // Reject entries that would overwrite one another on a case-insensitive filesystem. Since the
+    // entries are extracted in parallel, check them all upfront, in archive order.
+    if !skip_validation {
+        let mut collisions = CaseCollisions::new();
+        for entry in archive.file().entries() {
+            let Ok(file_name) = entry.filename().as_str() else {
+                continue;
+            };
+            let Some(enclosed_name) = crate::stream::enclosed_name(file_name) else {
+                continue;
+            };
+            if entry.dir()? {
+                continue;
+            }
+            collisions.insert(&enclosed_name, target)?;
+        }
+    }
+
     // Initialize the threadpool with the user settings.
     initialize_rayon_once();
     (0..archive.file().entries().len())
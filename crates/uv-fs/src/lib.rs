@@ -1,8 +1,13 @@
 use std::io;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 #[cfg(feature = "tokio")]
 use std::io::Read;
+#[cfg(feature = "test-crash-after-write")]
+use std::sync::LazyLock;
+#[cfg(feature = "test-crash-after-write")]
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(feature = "tokio")]
 use encoding_rs_io::DecodeReaderBytes;
@@ -401,6 +406,106 @@ pub fn write_atomic_sync(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> std:
     persist_with_retry_sync(temp_file, path.as_ref())
 }
 
+/// Write `data` to `path` atomically and durably.
+///
+/// Like [`write_atomic_sync`], but flushes the temporary file to disk before the rename and the
+/// parent directory after it, such that a crash leaves either the old or the new contents in
+/// place. The permissions of an existing file are preserved, and symbolic links are followed.
+///
+/// Intended for user-facing files that uv rewrites (e.g., `pyproject.toml` and `uv.lock`), rather
+/// than cache entries, which can always be recreated.
+pub fn write_durable_sync(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> std::io::Result<()> {
+    // Write through symbolic links, rather than replacing them.
+    let path = match fs_err::symlink_metadata(path.as_ref()) {
+        Ok(metadata) if metadata.is_symlink() => fs_err::canonicalize(path.as_ref())?,
+        _ => path.as_ref().to_path_buf(),
+    };
+    let parent = path.parent().expect("Write path must have a parent");
+
+    let mut temp_file = tempfile_in(parent)?;
+    temp_file.write_all(data.as_ref())?;
+    if let Ok(metadata) = fs_err::metadata(&path) {
+        temp_file
+            .as_file()
+            .set_permissions(metadata.permissions())?;
+    }
+    temp_file.as_file().sync_all()?;
+    persist_with_retry_sync(temp_file, &path)?;
+    sync_directory(parent)?;
+
+    #[cfg(feature = "test-crash-after-write")]
+    crash_after_write(&path);
+
+    Ok(())
+}
+
+/// Write `data` to `path` atomically and durably.
+///
+/// See [`write_durable_sync`].
+#[cfg(feature = "tokio")]
+pub async fn write_durable(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let path = path.as_ref().to_path_buf();
+    let data = data.as_ref().to_vec();
+    tokio::task::spawn_blocking(move || write_durable_sync(path, data))
+        .await
+        .map_err(std::io::Error::other)?
+}
+
+/// Flush a directory to disk, to persist any renames within it.
+#[cfg(unix)]
+fn sync_directory(path: &Path) -> std::io::Result<()> {
+    // Resolve the empty path (e.g., for a relative path with no parent) to the working directory.
+    let path = if path.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        path
+    };
+    fs_err::File::open(path)?.sync_all()
+}
+
+/// Flush a directory to disk, to persist any renames within it.
+///
+/// Directories can't be opened as files on Windows, where renames are persisted with the file.
+#[cfg(not(unix))]
+#[expect(clippy::unnecessary_wraps)]
+fn sync_directory(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Abort the process after a durable write to the given path, if requested by the test suite.
+///
+/// Reads `UV_INTERNAL__TEST_CRASH_AFTER_WRITE`, in the form `<file name>` or `<file name>:<n>`, to
+/// abort after the first (or `n`-th) write to a file with the given name.
+///
+/// Only compiled with the `test-crash-after-write` feature, which must not be enabled in release
+/// builds.
+#[cfg(feature = "test-crash-after-write")]
+fn crash_after_write(path: &Path) {
+    static CRASH_POINT: LazyLock<Option<(String, usize)>> = LazyLock::new(|| {
+        let value = std::env::var(uv_static::EnvVars::UV_INTERNAL__TEST_CRASH_AFTER_WRITE).ok()?;
+        match value.split_once(':') {
+            Some((name, count)) => Some((name.to_string(), count.parse().ok()?)),
+            None => Some((value, 1)),
+        }
+    });
+    static WRITES: AtomicUsize = AtomicUsize::new(0);
+
+    let Some((name, count)) = CRASH_POINT.as_ref() else {
+        return;
+    };
+    if path
+        .file_name()
+        .is_some_and(|file_name| file_name == name.as_str())
+        && WRITES.fetch_add(1, Ordering::SeqCst) + 1 == *count
+    {
+        #[expect(clippy::print_stderr)]
+        {
+            eprintln!("Aborting after write to: {}", path.display());
+        }
+        std::process::abort();
+    }
+}
+
 /// Copy `from` to `to` atomically using a temporary file and atomic rename.
 pub fn copy_atomic_sync(from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io::Result<()> {
     let temp_file = tempfile_in(to.as_ref().parent().expect("Write path must have a parent"))?;
@@ -981,4 +1086,27 @@ mod tests {
         assert!(environment.is_dir());
         Ok(())
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn write_durable_preserves_permissions_and_symlinks() -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tempdir = tempfile::tempdir()?;
+        let target = tempdir.path().join("script.py");
+        fs_err::write(&target, "old")?;
+        fs_err::set_permissions(&target, std::fs::Permissions::from_mode(0o755))?;
+        let link = tempdir.path().join("link.py");
+        fs_err::os::unix::fs::symlink(&target, &link)?;
+
+        write_durable_sync(&link, "new")?;
+
+        assert!(fs_err::symlink_metadata(&link)?.is_symlink());
+        assert_eq!(fs_err::read_to_string(&target)?, "new");
+        assert_eq!(
+            fs_err::metadata(&target)?.permissions().mode() & 0o777,
+            0o755
+        );
+        Ok(())
+    }
 }
@@ -79,6 +79,14 @@ impl GlobDirFilter {
         self.match_directory(path) || self.glob_set.is_match(path)
     }
 
+    /// Whether the file matches any of the globs.
+    ///
+    /// Unlike [`GlobDirFilter::match_path`], this doesn't match files that could only be a prefix
+    /// of a match, e.g., `licenses/README.md` for `licenses/**/*.txt`.
+    pub fn match_file(&self, path: &Path) -> bool {
+        self.glob_set.is_match(path)
+    }
+
     /// Check whether a directory or any of its children can be matched by any of the globs.
     ///
     /// This option never returns false if any child matches, but it may return true even if we
@@ -160,6 +168,18 @@ mod tests {
         assert!(!matcher.match_directory(&Path::new("path5").join("dir5")));
     }
 
+    #[test]
+    fn match_file() {
+        let patterns = [PortableGlobParser::Pep639
+            .parse("licenses/**/*.txt")
+            .unwrap()];
+        let matcher = GlobDirFilter::from_globs(&patterns).unwrap();
+        let readme = Path::new("licenses").join("README.md");
+        assert!(matcher.match_path(&readme));
+        assert!(!matcher.match_file(&readme));
+        assert!(matcher.match_file(&Path::new("licenses").join("vendored").join("LICENSE.txt")));
+    }
+
     /// Check that we skip directories that can never match.
     #[test]
     fn prefilter() {
@@ -1,20 +1,25 @@
 //! Like `wheel.rs`, but for installing wheels that have already been unzipped, rather than
 //! reading from a zip file.
 
-use std::path::Path;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use fs_err::File;
 use tracing::{instrument, trace};
 
 use uv_distribution_filename::WheelFilename;
+use uv_fs::Simplified;
 use uv_pep440::Version;
 use uv_pypi_types::{DirectUrl, Metadata10};
 
 use crate::linker::{InstallState, LinkMode, link_wheel_files};
+use crate::record::RecordEntry;
+use crate::script::scripts_from_ini;
 use crate::wheel::{
     LibKind, WheelFile, dist_info_metadata, find_dist_info, install_data, parse_scripts,
-    read_record, write_installer_metadata, write_record, write_script_entrypoints,
+    read_record, remove_script_entrypoints, write_file_recorded, write_installer_metadata,
+    write_record, write_script_entrypoints,
 };
 use crate::{Error, Layout};
 
@@ -26,7 +31,7 @@ use crate::{Error, Layout};
 ///
 /// Wheel 1.0: <https://www.python.org/dev/peps/pep-0427/>
 #[instrument(skip_all, fields(wheel = %filename))]
-pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
+pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize, Reason: serde::Serialize>(
     layout: &Layout,
     relocatable: bool,
     wheel: impl AsRef<Path>,
@@ -34,6 +39,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
+    install_reason: Option<&Reason>,
     installer: Option<&str>,
     installer_metadata: bool,
     link_mode: LinkMode,
@@ -143,6 +149,7 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
             direct_url,
             cache_info,
             build_info,
+            install_reason,
             installer,
             &mut record,
         )?;
@@ -153,3 +160,161 @@ pub fn install_wheel<Cache: serde::Serialize, Build: serde::Serialize>(
 
     Ok(())
 }
+
+/// Regenerate the entry points of an installed distribution in-place, without reinstalling it.
+///
+/// Removes the scripts generated for the existing `entry_points.txt`, replaces it with the given
+/// entry points, and generates the corresponding console and GUI scripts. The `RECORD` (and the
+/// `uv_cache.json`, if provided) is updated to match.
+#[instrument(skip_all, fields(dist_info = %dist_info.display()))]
+pub fn update_entry_points<Cache: serde::Serialize>(
+    layout: &Layout,
+    relocatable: bool,
+    dist_info: &Path,
+    entry_points: Option<&str>,
+    cache_info: Option<&Cache>,
+) -> Result<(), Error> {
+    let (site_packages, dist_info_prefix, mut record) = read_installed_record(dist_info)?;
+
+    // Remove the scripts for the existing entry points, including any that are no longer defined.
+    let (console_scripts, gui_scripts) = parse_scripts(
+        site_packages,
+        dist_info_prefix,
+        None,
+        layout.python_version.1,
+    )?;
+    trace!(dist_info_prefix, "Removing entrypoints");
+    remove_script_entrypoints(layout, site_packages, &console_scripts, &mut record)?;
+    remove_script_entrypoints(layout, site_packages, &gui_scripts, &mut record)?;
+
+    // Replace the `entry_points.txt`.
+    let entry_points_path = PathBuf::from(format!("{dist_info_prefix}.dist-info/entry_points.txt"));
+    let entry_points_record = entry_points_path.portable_display().to_string();
+    record.retain(|entry| entry.path != entry_points_record);
+    if let Some(entry_points) = entry_points {
+        write_file_recorded(site_packages, &entry_points_path, entry_points, &mut record)?;
+
+        let (console_scripts, gui_scripts) =
+            scripts_from_ini(None, layout.python_version.1, entry_points.to_string())?;
+        if !console_scripts.is_empty() || !gui_scripts.is_empty() {
+            trace!(dist_info_prefix, "Writing entrypoints");
+
+            fs_err::create_dir_all(&layout.scheme.scripts)?;
+            write_script_entrypoints(
+                layout,
+                relocatable,
+                site_packages,
+                &console_scripts,
+                &mut record,
+                false,
+            )?;
+            write_script_entrypoints(
+                layout,
+                relocatable,
+                site_packages,
+                &gui_scripts,
+                &mut record,
+                true,
+            )?;
+        }
+    } else {
+        match fs_err::remove_file(site_packages.join(&entry_points_path)) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    // Replace the `uv_cache.json`.
+    if let Some(cache_info) = cache_info {
+        replace_dist_info_file(
+            site_packages,
+            dist_info_prefix,
+            "uv_cache.json",
+            serde_json::to_string(cache_info)?.as_bytes(),
+            &mut record,
+        )?;
+    }
+
+    trace!(dist_info_prefix, "Writing record");
+    write_record(site_packages, dist_info_prefix, record)?;
+
+    Ok(())
+}
+
+/// Replace the `uv_reason.json` of an installed distribution in-place, without reinstalling it.
+///
+/// The `RECORD` is updated to match. If the recorded reason is unchanged, nothing is written.
+#[instrument(skip_all, fields(dist_info = %dist_info.display()))]
+pub fn update_install_reason<Reason: serde::Serialize>(
+    dist_info: &Path,
+    install_reason: &Reason,
+) -> Result<(), Error> {
+    let install_reason = serde_json::to_string(install_reason)?;
+    match fs_err::read_to_string(dist_info.join("uv_reason.json")) {
+        Ok(existing) if existing == install_reason => return Ok(()),
+        Ok(_) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    let (site_packages, dist_info_prefix, mut record) = read_installed_record(dist_info)?;
+    replace_dist_info_file(
+        site_packages,
+        dist_info_prefix,
+        "uv_reason.json",
+        install_reason.as_bytes(),
+        &mut record,
+    )?;
+
+    trace!(dist_info_prefix, "Writing record");
+    write_record(site_packages, dist_info_prefix, record)?;
+
+    Ok(())
+}
+
+/// Read the `RECORD` of an installed distribution, along with its `site-packages` directory and
+/// `.dist-info` prefix.
+fn read_installed_record(dist_info: &Path) -> Result<(&Path, &str, Vec<RecordEntry>), Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+    let Some(dist_info_prefix) = dist_info
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|name| name.strip_suffix(".dist-info"))
+    else {
+        return Err(Error::BrokenVenv(format!(
+            "Invalid dist-info directory: {}",
+            dist_info.display()
+        )));
+    };
+
+    let record_path = dist_info.join("RECORD");
+    let record = match File::open(&record_path) {
+        Ok(mut record_file) => read_record(&mut record_file)?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(Error::MissingRecord(record_path));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok((site_packages, dist_info_prefix, record))
+}
+
+/// Replace a file in the `.dist-info` directory of an installed distribution, along with its
+/// entry in the `RECORD`.
+fn replace_dist_info_file(
+    site_packages: &Path,
+    dist_info_prefix: &str,
+    filename: &str,
+    content: &[u8],
+    record: &mut Vec<RecordEntry>,
+) -> Result<(), Error> {
+    let path = PathBuf::from(format!("{dist_info_prefix}.dist-info/{filename}"));
+    let path_record = path.portable_display().to_string();
+    record.retain(|entry| entry.path != path_record);
+    write_file_recorded(site_packages, &path, content, record)
+}
@@ -11,10 +11,11 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::Scheme;
 
-pub use install::install_wheel;
+pub use install::{install_wheel, update_entry_points, update_install_reason};
 pub use linker::{InstallState, LinkMode};
 pub use record::RecordEntry;
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
+pub use verify::verify_record;
 pub use wheel::{WheelFile, read_record, validate_and_heal_record};
 
 mod install;
@@ -22,6 +23,7 @@ mod linker;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
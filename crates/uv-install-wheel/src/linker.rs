@@ -10,8 +10,8 @@ use rustc_hash::FxHashMap;
 use tracing::{debug, instrument};
 
 use uv_distribution_filename::WheelFilename;
-use uv_fs::Simplified;
 use uv_fs::link::{CopyLocks, LinkOptions, OnExistingDirectory, link_dir};
+use uv_fs::{Simplified, verbatim_path};
 use uv_preview::{Preview, PreviewFeature};
 use uv_warnings::warn_user;
 
@@ -266,7 +266,13 @@ pub(crate) fn link_wheel_files(
         .with_mutable_copy_filter(|p: &Path| p.ends_with("RECORD"))
         .with_copy_locks(state.copy_locks())
         .with_on_existing_directory(OnExistingDirectory::Merge);
-    let used_link_mode = link_dir(wheel, site_packages, &options)?;
+    // On Windows, use extended-length paths, such that files nested deeper than `MAX_PATH` (e.g.,
+    // in generated protobuf packages) can be linked without long path support enabled.
+    let used_link_mode = link_dir(
+        &verbatim_path(wheel),
+        &verbatim_path(site_packages),
+        &options,
+    )?;
 
     if used_link_mode == LinkMode::Clone {
         // The directory mtime is not updated when cloning and the mtime is
@@ -5,7 +5,7 @@ use std::sync::{LazyLock, Mutex, OnceLock};
 
 use tracing::trace;
 
-use uv_fs::write_atomic_sync;
+use uv_fs::{verbatim_path, write_atomic_sync};
 use uv_pypi_types::Identifier;
 use uv_warnings::warn_user;
 
@@ -77,7 +77,9 @@ pub fn uninstall_wheel(
             }
         }
 
-        match fs_err::remove_file(&path) {
+        // On Windows, use extended-length paths, such that files nested deeper than `MAX_PATH`
+        // can be removed without long path support enabled.
+        match fs_err::remove_file(verbatim_path(&path)) {
             Ok(()) => {
                 trace!("Removed file: {}", path.display());
                 file_count += 1;
@@ -86,7 +88,7 @@ pub fn uninstall_wheel(
                 }
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
-            Err(err) => match fs_err::remove_dir_all(&path) {
+            Err(err) => match fs_err::remove_dir_all(verbatim_path(&path)) {
                 Ok(()) => {
                     trace!("Removed directory: {}", path.display());
                     dir_count += 1;
@@ -119,7 +121,7 @@ pub fn uninstall_wheel(
             // may or may not be listed in the RECORD, but installers are expected to be smart
             // enough to remove it either way.
             let pycache = path.join("__pycache__");
-            match fs_err::remove_dir_all(&pycache) {
+            match fs_err::remove_dir_all(verbatim_path(&pycache)) {
                 Ok(()) => {
                     trace!("Removed directory: {}", pycache.display());
                     dir_count += 1;
@@ -130,7 +132,7 @@ pub fn uninstall_wheel(
 
             // Try to read from the directory. If it doesn't exist, assume we deleted it in a
             // previous iteration.
-            let mut read_dir = match fs_err::read_dir(path) {
+            let mut read_dir = match fs_err::read_dir(verbatim_path(path)) {
                 Ok(read_dir) => read_dir,
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => break,
                 Err(err) => return Err(err.into()),
@@ -141,7 +143,7 @@ pub fn uninstall_wheel(
                 break;
             }
 
-            fs_err::remove_dir(path)?;
+            fs_err::remove_dir(verbatim_path(path))?;
 
             trace!("Removed directory: {}", path.display());
             dir_count += 1;
@@ -369,6 +371,7 @@ pub fn uninstall_legacy_editable(egg_link: &Path) -> Result<Uninstall, Error> {
 
     // This comes from `pkg_resources.normalize_path`
     let target_line = normcase(target_line);
+    let target_line = target_line.trim_end_matches(['/', '\\']);
 
     match fs_err::remove_file(egg_link) {
         Ok(()) => {
@@ -389,13 +392,29 @@ pub fn uninstall_legacy_editable(egg_link: &Path) -> Result<Uninstall, Error> {
     // is modified).
     let _guard = EASY_INSTALL_PTH.lock().unwrap();
 
-    let content = fs_err::read_to_string(&easy_install)?;
+    let content = match fs_err::read_to_string(&easy_install) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            trace!(
+                "No `easy-install.pth` to update: {}",
+                easy_install.display()
+            );
+            return Ok(Uninstall {
+                file_count,
+                dir_count: 0usize,
+            });
+        }
+        Err(err) => return Err(err.into()),
+    };
     let mut new_content = String::with_capacity(content.len());
     let mut removed = false;
 
+    // Unlike pip, remove every entry that points to the target (including relative entries, and
+    // entries that only differ in case or separators on Windows), such that no entry keeps
+    // shadowing a package that's installed in its place.
     // https://github.com/pypa/pip/blob/41587f5e0017bcd849f42b314dc8a34a7db75621/src/pip/_internal/req/req_uninstall.py#L634
     for line in content.lines() {
-        if !removed && line.trim() == target_line {
+        if is_pth_entry(line, target_line, site_package) {
             removed = true;
         } else {
             new_content.push_str(line);
@@ -413,6 +432,21 @@ pub fn uninstall_legacy_editable(egg_link: &Path) -> Result<Uninstall, Error> {
     })
 }
 
+/// Returns `true` if the line of a `.pth` file in the given `site-packages` directory adds the
+/// (normalized) target path to `sys.path`.
+fn is_pth_entry(line: &str, target: &str, site_packages: &Path) -> bool {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') || line.starts_with("import ") {
+        return false;
+    }
+    let entry = if Path::new(line).is_absolute() {
+        normcase(line)
+    } else {
+        normcase(&uv_fs::normalize_path(site_packages.join(line)).to_string_lossy())
+    };
+    entry.trim_end_matches(['/', '\\']) == target
+}
+
 #[derive(Debug, Default)]
 pub struct Uninstall {
     /// The number of files that were removed during the uninstallation.
@@ -0,0 +1,108 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use data_encoding::BASE64URL_NOPAD;
+use sha2::{Digest, Sha256};
+use tracing::trace;
+
+use crate::Error;
+use crate::wheel::read_record;
+
+/// Verify the installed files of the wheel represented by the given `.dist-info` directory against
+/// the hashes in its `RECORD`, returning the paths of any files that are missing or modified.
+///
+/// Entries without a hash (such as the `RECORD` itself, or compiled bytecode) are skipped, as are
+/// entries hashed with an algorithm other than SHA-256.
+pub fn verify_record(dist_info: &Path) -> Result<Vec<PathBuf>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record = {
+        let record_path = dist_info.join("RECORD");
+        let mut record_file = match fs_err::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record(&mut record_file)?
+    };
+
+    let mut mismatches = Vec::new();
+    for entry in record {
+        let Some(expected) = entry
+            .hash
+            .as_deref()
+            .and_then(|hash| hash.strip_prefix("sha256="))
+        else {
+            continue;
+        };
+
+        let path = uv_fs::normalize_path(site_packages.join(&entry.path)).into_owned();
+        let mut file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                trace!("Missing file: {}", path.display());
+                mismatches.push(path);
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut hasher = Sha256::new();
+        io::copy(&mut file, &mut hasher)?;
+        if BASE64URL_NOPAD.encode(&hasher.finalize()) != expected {
+            trace!("Modified file: {}", path.display());
+            mismatches.push(path);
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use assert_fs::prelude::*;
+
+    use super::verify_record;
+
+    #[test]
+    fn verify() {
+        let site_packages = assert_fs::TempDir::new().unwrap();
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("print('hello')\n")
+            .unwrap();
+        site_packages
+            .child("foo/bar.py")
+            .write_str("print('world')\n")
+            .unwrap();
+        site_packages
+            .child("foo-1.0.dist-info/RECORD")
+            .write_str(indoc::indoc! {"
+                foo/__init__.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+                foo/bar.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+                foo/baz.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+                foo/__pycache__/__init__.cpython-312.pyc,,
+                foo-1.0.dist-info/RECORD,,
+            "})
+            .unwrap();
+
+        let mismatches = verify_record(&site_packages.child("foo-1.0.dist-info")).unwrap();
+        let mismatches = mismatches
+            .iter()
+            .map(|path| path.strip_prefix(site_packages.path()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mismatches,
+            vec![Path::new("foo/bar.py"), Path::new("foo/baz.py")]
+        );
+    }
+}
@@ -309,6 +309,30 @@ pub(crate) fn write_script_entrypoints(
     Ok(())
 }
 
+/// Remove the wrapper scripts for the given entry points from the bin folder of the venv, along
+/// with their entries in the RECORD.
+pub(crate) fn remove_script_entrypoints(
+    layout: &Layout,
+    site_packages: &Path,
+    entrypoints: &[Script],
+    record: &mut Vec<RecordEntry>,
+) -> Result<(), Error> {
+    for script in entrypoints {
+        let script = ValidatedScript::try_from_script(script, layout)?;
+        let entrypoint_relative = script.relative_to_site_package(site_packages)?;
+
+        match fs::remove_file(script.as_path()) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let entrypoint_relative = entrypoint_relative.portable_display().to_string();
+        record.retain(|entry| entry.path != entrypoint_relative);
+    }
+    Ok(())
+}
+
 /// A parsed `WHEEL` file.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WheelFile(FxHashMap<String, Vec<String>>);
@@ -743,7 +767,7 @@ pub(crate) fn install_data(
 ///
 /// We still the path in the absolute path to the site packages and the relative path in the
 /// site packages because we must only record the relative path in RECORD
-fn write_file_recorded(
+pub(crate) fn write_file_recorded(
     site_packages: &Path,
     relative_path: &Path,
     content: impl AsRef<[u8]>,
@@ -768,13 +792,18 @@ fn write_file_recorded(
 }
 
 /// Adds `INSTALLER`, `REQUESTED` and `direct_url.json` to the .dist-info dir
-pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Serialize>(
+pub(crate) fn write_installer_metadata<
+    Cache: serde::Serialize,
+    Build: serde::Serialize,
+    Reason: serde::Serialize,
+>(
     site_packages: &Path,
     dist_info_prefix: &str,
     requested: bool,
     direct_url: Option<&DirectUrl>,
     cache_info: Option<&Cache>,
     build_info: Option<&Build>,
+    install_reason: Option<&Reason>,
     installer: Option<&str>,
     record: &mut Vec<RecordEntry>,
 ) -> Result<(), Error> {
@@ -806,6 +835,14 @@ pub(crate) fn write_installer_metadata<Cache: serde::Serialize, Build: serde::Se
             record,
         )?;
     }
+    if let Some(install_reason) = install_reason {
+        write_file_recorded(
+            site_packages,
+            &dist_info_dir.join("uv_reason.json"),
+            serde_json::to_string(install_reason)?.as_bytes(),
+            record,
+        )?;
+    }
     if let Some(installer) = installer {
         write_file_recorded(
             site_packages,
@@ -1437,13 +1474,14 @@ mod test {
             .child("foo-0.1.0.dist-info")
             .create_dir_all()
             .unwrap();
-        write_installer_metadata::<(), ()>(
+        write_installer_metadata::<(), (), ()>(
             site_packages,
             "foo-0.1.0",
             true,
             None,
             None,
             None,
+            None,
             Some("uv"),
             &mut record,
         )
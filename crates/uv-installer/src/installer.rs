@@ -3,13 +3,15 @@ use std::sync::Arc;
 
 use anyhow::{Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::FxHashMap;
 use tokio::sync::oneshot;
 use tracing::{instrument, warn};
 
 use uv_cache::Cache;
 use uv_configuration::initialize_rayon_once;
-use uv_distribution_types::CachedDist;
+use uv_distribution_types::{CachedDist, InstallReason, Name};
 use uv_install_wheel::{Layout, LinkMode};
+use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_python::PythonEnvironment;
 
@@ -22,6 +24,8 @@ pub struct Installer<'a> {
     name: Option<String>,
     /// The metadata associated with the [`Installer`].
     metadata: bool,
+    /// The reasons for installing each package, to record in the `.dist-info` directory.
+    install_reasons: FxHashMap<PackageName, InstallReason>,
     /// Preview settings for the installer.
     preview: Preview,
 }
@@ -36,6 +40,7 @@ impl<'a> Installer<'a> {
             reporter: None,
             name: Some("uv".to_string()),
             metadata: true,
+            install_reasons: FxHashMap::default(),
             preview,
         }
     }
@@ -82,6 +87,18 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Set the reasons for installing each package.
+    #[must_use]
+    pub fn with_install_reasons(
+        self,
+        install_reasons: FxHashMap<PackageName, InstallReason>,
+    ) -> Self {
+        Self {
+            install_reasons,
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub async fn install(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
@@ -92,6 +109,7 @@ impl<'a> Installer<'a> {
             reporter,
             name: installer_name,
             metadata: installer_metadata,
+            install_reasons,
             preview,
         } = self;
 
@@ -118,6 +136,7 @@ impl<'a> Installer<'a> {
                 reporter.as_ref(),
                 relocatable,
                 installer_metadata,
+                &install_reasons,
                 preview,
             );
 
@@ -149,6 +168,7 @@ impl<'a> Installer<'a> {
             self.reporter.as_ref(),
             self.venv.relocatable(),
             self.metadata,
+            &self.install_reasons,
             self.preview,
         )
     }
@@ -164,6 +184,7 @@ fn install(
     reporter: Option<&Arc<dyn Reporter>>,
     relocatable: bool,
     installer_metadata: bool,
+    install_reasons: &FxHashMap<PackageName, InstallReason>,
     preview: Preview,
 ) -> Result<Vec<CachedDist>> {
     // Initialize the threadpool with the user settings.
@@ -185,6 +206,7 @@ fn install(
                 Some(wheel.cache_info())
             },
             wheel.build_info(),
+            install_reasons.get(wheel.name()),
             installer_name,
             installer_metadata,
             link_mode,
@@ -7,7 +7,7 @@ use owo_colors::OwoColorize;
 use tracing::{debug, warn};
 
 use uv_cache::{Cache, CacheBucket, WheelCache};
-use uv_cache_info::Timestamp;
+use uv_cache_info::{CacheInfo, Timestamp};
 use uv_configuration::{BuildOptions, Reinstall};
 use uv_distribution::{
     BuiltWheelIndex, HttpArchivePointer, PathArchivePointer, RegistryWheelIndex,
@@ -266,6 +266,7 @@ impl<'a> Planner<'a> {
         config_settings_package: &PackageConfigSettings,
         extra_build_requires: &ExtraBuildRequires,
         extra_build_variables: &ExtraBuildVariables,
+        build_via_sdist: bool,
         cache: &Cache,
         venv: &PythonEnvironment,
         tags: &Tags,
@@ -289,11 +290,13 @@ impl<'a> Planner<'a> {
             config_settings_package,
             extra_build_requires,
             extra_build_variables,
+            build_via_sdist,
         );
 
         let mut cached = vec![];
         let mut remote = vec![];
         let mut reinstalls = vec![];
+        let mut entry_points = vec![];
         let mut extraneous = vec![];
 
         // TODO(charlie): There are a few assumptions here that are hard to spot:
@@ -372,6 +375,16 @@ impl<'a> Planner<'a> {
                             RequirementSatisfaction::CacheInvalid => {
                                 // Already logged
                             }
+                            RequirementSatisfaction::EntryPointsOutOfDate(cache_info) => {
+                                debug!(
+                                    "Requirement installed, but entry points changed: {installed}"
+                                );
+                                entry_points.push(EntryPointsUpdate {
+                                    dist: installed.clone(),
+                                    cache_info,
+                                });
+                                continue;
+                            }
                         }
                         reinstalls.push(installed.clone());
                     }
@@ -751,6 +764,7 @@ impl<'a> Planner<'a> {
             cached,
             remote,
             reinstalls,
+            entry_points,
             extraneous,
         })
     }
@@ -813,17 +827,44 @@ pub struct Plan {
     /// re-installed (including upgraded) to satisfy the requirements.
     pub reinstalls: Vec<InstalledDist>,
 
+    /// Any editable distributions that are already installed in the current environment, but
+    /// whose entry points have changed, such that their scripts should be regenerated in-place.
+    pub entry_points: Vec<EntryPointsUpdate>,
+
     /// Any distributions that are already installed in the current environment, and are
     /// _not_ necessary to satisfy the requirements.
     pub extraneous: Vec<InstalledDist>,
 }
 
+/// An installed editable distribution whose entry points have changed.
+#[derive(Debug, Clone)]
+pub struct EntryPointsUpdate {
+    /// The installed distribution.
+    pub dist: InstalledDist,
+    /// The [`CacheInfo`] of the source tree, including the updated entry points.
+    pub cache_info: CacheInfo,
+}
+
+impl EntryPointsUpdate {
+    /// Regenerate the scripts and `entry_points.txt` of the installed distribution.
+    pub fn execute(&self, venv: &PythonEnvironment) -> Result<(), uv_install_wheel::Error> {
+        uv_install_wheel::update_entry_points(
+            &venv.interpreter().layout(),
+            venv.relocatable(),
+            self.dist.install_path(),
+            self.cache_info.entry_points(),
+            Some(&self.cache_info),
+        )
+    }
+}
+
 impl Plan {
     /// Returns `true` if the plan is empty.
     pub fn is_empty(&self) -> bool {
         self.cached.is_empty()
             && self.remote.is_empty()
             && self.reinstalls.is_empty()
+            && self.entry_points.is_empty()
             && self.extraneous.is_empty()
     }
 
@@ -832,9 +873,9 @@ impl Plan {
     /// Returns a tuple of plans, where the first plan contains the remote distributions that match
     /// the predicate, and the second plan contains those that do not.
     ///
-    /// Any extraneous and cached distributions will be returned in the first plan, while the second
-    /// plan will contain any `false` matches from the remote distributions, along with any
-    /// reinstalls for those distributions.
+    /// Any extraneous and cached distributions, along with any entry point updates, will be
+    /// returned in the first plan, while the second plan will contain any `false` matches from the
+    /// remote distributions, along with any reinstalls for those distributions.
     pub fn partition<F>(self, mut f: F) -> (Self, Self)
     where
         F: FnMut(&PackageName) -> bool,
@@ -843,6 +884,7 @@ impl Plan {
             cached,
             remote,
             reinstalls,
+            entry_points,
             extraneous,
         } = self;
 
@@ -875,6 +917,7 @@ impl Plan {
             cached: left_cached,
             remote: left_remote,
             reinstalls: left_reinstalls,
+            entry_points,
             extraneous: left_extraneous,
         };
 
@@ -884,6 +927,7 @@ impl Plan {
             cached: right_cached,
             remote: right_remote,
             reinstalls: right_reinstalls,
+            entry_points: vec![],
             extraneous: right_extraneous,
         };
 
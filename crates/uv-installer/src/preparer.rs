@@ -1,7 +1,8 @@
 use std::cmp::Reverse;
 use std::sync::Arc;
 
-use futures::{FutureExt, Stream, TryFutureExt, TryStreamExt, stream::FuturesUnordered};
+use futures::{FutureExt, Stream, StreamExt, TryFutureExt, TryStreamExt, stream::FuturesUnordered};
+use itertools::Itertools;
 use tracing::{debug, instrument};
 
 use uv_cache::Cache;
@@ -9,7 +10,7 @@ use uv_configuration::BuildOptions;
 use uv_distribution::{DistributionDatabase, LocalWheel};
 use uv_distribution_types::{
     BuildableSource, CachedDist, DerivationChain, Dist, DistErrorKind, Hashed, Identifier, Name,
-    RemoteSource, Resolution,
+    RemoteSource, Resolution, SourceBuildReason, SourceDist,
 };
 use uv_normalize::PackageName;
 use uv_platform_tags::Tags;
@@ -26,6 +27,7 @@ pub struct Preparer<'a, Context: BuildContext> {
     build_options: &'a BuildOptions,
     database: DistributionDatabase<'a, Context>,
     reporter: Option<Arc<dyn Reporter>>,
+    fail_fast: bool,
 }
 
 impl<'a, Context: BuildContext> Preparer<'a, Context> {
@@ -43,9 +45,17 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
             build_options,
             database,
             reporter: None,
+            fail_fast: true,
         }
     }
 
+    /// Set whether to abort as soon as any distribution fails to prepare, rather than preparing
+    /// the remaining distributions and reporting every failure.
+    #[must_use]
+    pub fn with_fail_fast(self, fail_fast: bool) -> Self {
+        Self { fail_fast, ..self }
+    }
+
     /// Set the [`Reporter`] to use for operations.
     #[must_use]
     pub fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
@@ -58,6 +68,7 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                 .database
                 .with_reporter(reporter.clone().into_distribution_reporter()),
             reporter: Some(reporter),
+            fail_fast: self.fail_fast,
         }
     }
 
@@ -95,10 +106,27 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
         distributions
             .sort_unstable_by_key(|distribution| Reverse(distribution.size().unwrap_or(u64::MAX)));
 
-        let wheels = self
-            .prepare_stream(distributions, in_flight, resolution)
-            .try_collect()
-            .await?;
+        let wheels = if self.fail_fast {
+            self.prepare_stream(distributions, in_flight, resolution)
+                .try_collect()
+                .await?
+        } else {
+            let (wheels, mut errors): (Vec<_>, Vec<_>) = self
+                .prepare_stream(distributions, in_flight, resolution)
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .partition_result();
+            match errors.len() {
+                0 => wheels,
+                1 => return Err(errors.remove(0)),
+                _ => {
+                    // Sort the errors, such that the report is independent of completion order.
+                    errors.sort_by_cached_key(ToString::to_string);
+                    return Err(Error::Multiple(errors));
+                }
+            }
+        };
 
         if let Some(reporter) = self.reporter.as_ref() {
             reporter.on_complete();
@@ -106,6 +134,20 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
 
         Ok(wheels)
     }
+
+    /// Determine why a source distribution was selected for a package that publishes wheels, if
+    /// applicable.
+    fn source_build_reason(&self, dist: &Dist) -> Option<SourceBuildReason> {
+        let Dist::Source(SourceDist::Registry(sdist)) = dist else {
+            return None;
+        };
+        SourceBuildReason::from_source_dist(
+            sdist,
+            self.build_options.no_binary_package(&sdist.name),
+            Some(self.tags),
+        )
+    }
+
     /// Download, build, and unzip a single wheel.
     #[instrument(skip_all, fields(name = % dist, size = ? dist.size(), url = dist.file().map(| file | file.url.to_string()).unwrap_or_default()))]
     async fn get_wheel(
@@ -151,7 +193,7 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                             given: dist.name().clone(),
                             metadata: cached.filename().name.clone(),
                         };
-                        return Err(Error::from_dist(dist, err, resolution));
+                        return Err(Error::from_dist(dist, err, resolution, None));
                     }
                     if let Some(version) = dist.version() {
                         if *version != cached.filename().version
@@ -161,7 +203,7 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                                 given: version.clone(),
                                 metadata: cached.filename().version.clone(),
                             };
-                            return Err(Error::from_dist(dist, err, resolution));
+                            return Err(Error::from_dist(dist, err, resolution, None));
                         }
                     }
                     Ok(cached.clone())
@@ -175,7 +217,10 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                 .database
                 .get_or_build_wheel(&dist, self.tags, policy)
                 .boxed_local()
-                .map_err(|err| Error::from_dist(dist.clone(), err, resolution))
+                .map_err(|err| {
+                    let reason = self.source_build_reason(&dist);
+                    Error::from_dist(dist.clone(), err, resolution, reason)
+                })
                 .await
                 .and_then(|wheel: LocalWheel| {
                     if wheel.satisfies(policy) {
@@ -186,7 +231,7 @@ impl<'a, Context: BuildContext> Preparer<'a, Context> {
                             policy.digests(),
                             wheel.hashes(),
                         );
-                        Err(Error::from_dist(dist, err, resolution))
+                        Err(Error::from_dist(dist, err, resolution, None))
                     }
                 })
                 .map(CachedDist::from);
@@ -215,26 +260,56 @@ pub enum Error {
         DistErrorKind,
         Box<Dist>,
         DerivationChain,
+        Option<SourceBuildReason>,
         #[source] Box<uv_distribution::Error>,
     ),
     #[error("Cyclic build dependency detected for `{0}`")]
     CyclicBuildDependency(PackageName),
     #[error("Unzip failed in another thread: {0}")]
     Thread(String),
+    #[error("Failed to prepare {} distributions", _0.len())]
+    Multiple(Vec<Self>),
 }
 
 impl Error {
     /// Create an [`Error`] from a distribution error.
-    fn from_dist(dist: Dist, err: uv_distribution::Error, resolution: &Resolution) -> Self {
+    fn from_dist(
+        dist: Dist,
+        err: uv_distribution::Error,
+        resolution: &Resolution,
+        reason: Option<SourceBuildReason>,
+    ) -> Self {
         let chain =
             DerivationChain::from_resolution(resolution, (&dist).into()).unwrap_or_default();
         Self::Dist(
             DistErrorKind::from_dist(&dist, &err),
             Box::new(dist),
             chain,
+            reason,
             Box::new(err),
         )
     }
+
+    /// Returns `true` if the error was caused by a distribution that's missing from the cache
+    /// while network access is disabled.
+    pub fn is_offline(&self) -> bool {
+        match self {
+            Self::Dist(.., err) => {
+                matches!(&**err, uv_distribution::Error::Client(err) if err.is_offline())
+            }
+            Self::Multiple(errs) => errs.iter().any(Self::is_offline),
+            _ => false,
+        }
+    }
+}
+
+impl uv_errors::Hint for Error {
+    fn hints(&self) -> uv_errors::Hints<'_> {
+        match self {
+            Self::Dist(.., err) => err.hints(),
+            _ => uv_errors::Hints::none(),
+        }
+    }
 }
 
 pub trait Reporter: Send + Sync {
@@ -255,12 +330,26 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a download is complete.
     fn on_download_complete(&self, name: &PackageName, index: usize);
 
+    /// Callback to invoke when a download is blocked on another process downloading the same
+    /// wheel.
+    fn on_download_wait_start(&self, name: &PackageName) -> usize;
+
+    /// Callback to invoke when another process has finished downloading the wheel.
+    fn on_download_wait_complete(&self, name: &PackageName, index: usize);
+
     /// Callback to invoke when a source distribution build is kicked off.
     fn on_build_start(&self, source: &BuildableSource) -> usize;
 
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when a build is blocked on another process building the same source
+    /// distribution.
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize;
+
+    /// Callback to invoke when another process has finished building the source distribution.
+    fn on_build_wait_complete(&self, source: &BuildableSource, id: usize);
+
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize;
 
@@ -310,4 +399,20 @@ impl uv_distribution::Reporter for Facade {
     fn on_download_complete(&self, name: &PackageName, index: usize) {
         self.reporter.on_download_complete(name, index);
     }
+
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_wait_start(source)
+    }
+
+    fn on_build_wait_complete(&self, source: &BuildableSource, id: usize) {
+        self.reporter.on_build_wait_complete(source, id);
+    }
+
+    fn on_download_wait_start(&self, name: &PackageName) -> usize {
+        self.reporter.on_download_wait_start(name)
+    }
+
+    fn on_download_wait_complete(&self, name: &PackageName, index: usize) {
+        self.reporter.on_download_wait_complete(name, index);
+    }
 }
@@ -21,12 +21,15 @@ use uv_pypi_types::{DirInfo, DirectUrl, VcsInfo, VcsKind};
 
 use crate::InstallationStrategy;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub(crate) enum RequirementSatisfaction {
     Mismatch,
     Satisfied,
     OutOfDate,
     CacheInvalid,
+    /// The distribution is an up-to-date editable, apart from its entry points, which should be
+    /// regenerated from the given [`CacheInfo`].
+    EntryPointsOutOfDate(CacheInfo),
 }
 
 impl RequirementSatisfaction {
@@ -59,7 +62,8 @@ impl RequirementSatisfaction {
             let build_info = BuildInfo::from_settings(
                 &config_settings,
                 extra_build_requires,
-                extra_build_variables,
+                extra_build_variables.as_deref(),
+                None,
             );
             dist_build_info != &build_info
         }) {
@@ -67,6 +71,9 @@ impl RequirementSatisfaction {
             return Self::OutOfDate;
         }
 
+        // The updated cache info for an editable whose entry points have changed.
+        let mut stale_entry_points = None;
+
         // Filter out already-installed packages.
         match source {
             // If the requirement comes from a registry, check by name.
@@ -405,7 +412,16 @@ impl RequirementSatisfaction {
                 match CacheInfo::from_path(requested_path) {
                     Ok(read_cache_info) => {
                         if *cache_info != read_cache_info {
-                            return Self::OutOfDate;
+                            // If only the entry points changed, an editable can be updated
+                            // in-place, without rebuilding it.
+                            if installed_editable.unwrap_or_default()
+                                && read_cache_info.is_entry_points_change(cache_info)
+                            {
+                                debug!("Entry points changed for {name}: {distribution}");
+                                stale_entry_points = Some(read_cache_info);
+                            } else {
+                                return Self::OutOfDate;
+                            }
                         }
                     }
                     Err(err) => {
@@ -443,6 +459,10 @@ impl RequirementSatisfaction {
             }
         }
 
+        if let Some(cache_info) = stale_entry_points {
+            return Self::EntryPointsOutOfDate(cache_info);
+        }
+
         // Otherwise, assume the requirement is up-to-date.
         Self::Satisfied
     }
@@ -476,8 +496,8 @@ fn extra_build_requires_for<'settings>(
 fn extra_build_variables_for<'settings>(
     name: &PackageName,
     extra_build_variables: &'settings ExtraBuildVariables,
-) -> Option<&'settings BuildVariables> {
-    extra_build_variables.get(name)
+) -> Option<Cow<'settings, BuildVariables>> {
+    extra_build_variables.for_package(Some(name))
 }
 
 /// Generate a hint for explaining tag compatibility issues.
@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 use std::collections::BTreeSet;
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use fs_err as fs;
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use tracing::debug;
 
 use uv_configuration::{ExcludeDependency, Excludes, Override, Overrides};
 use uv_distribution_types::{
@@ -14,11 +16,11 @@ use uv_distribution_types::{
     Requirement, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::VersionOrUrl;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{ResolverMarkerEnvironment, VerbatimParsedUrl};
+use uv_pypi_types::{ResolutionMetadata, ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::InstalledPackagesProvider;
@@ -42,6 +44,47 @@ pub struct SitePackages {
     by_name: FxHashMap<PackageName, Vec<usize>>,
     /// The installed editable distributions, keyed by URL.
     by_url: FxHashMap<DisplaySafeUrl, Vec<usize>>,
+    /// The legacy editable installs (`.egg-link` files) whose metadata couldn't be read.
+    unmanaged_legacy_editables: Vec<UnmanagedLegacyEditable>,
+}
+
+/// A legacy editable install (i.e., an `.egg-link` file, as created by `setup.py develop`) whose
+/// metadata can't be read, e.g., because the `.egg-info` directory in the project was removed.
+///
+/// uv can't determine the version of such an install, so it can't uninstall it safely; however,
+/// the entry in `easy-install.pth` may still shadow an installation of the same package.
+#[derive(Debug, Clone)]
+pub struct UnmanagedLegacyEditable {
+    /// The name of the package, as derived from the `.egg-link` filename.
+    pub name: PackageName,
+    /// The path to the `.egg-link` file.
+    pub egg_link: PathBuf,
+    /// The project directory that the `.egg-link` file points to, if any.
+    pub target: Option<PathBuf>,
+    /// The path to the `easy-install.pth` file alongside the `.egg-link` file, if it exists.
+    pub easy_install_pth: Option<PathBuf>,
+}
+
+impl UnmanagedLegacyEditable {
+    /// Read an [`UnmanagedLegacyEditable`] from the path to an `.egg-link` file.
+    fn from_egg_link(path: &Path) -> Option<Self> {
+        let name = PackageName::from_str(path.file_stem()?.to_str()?).ok()?;
+        let parent = path.parent()?;
+        let target = fs::read_to_string(path).ok().and_then(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .map(|line| parent.join(line))
+        });
+        let easy_install_pth = Some(parent.join("easy-install.pth")).filter(|pth| pth.is_file());
+        Some(Self {
+            name,
+            egg_link: path.to_path_buf(),
+            target,
+            easy_install_pth,
+        })
+    }
 }
 
 impl SitePackages {
@@ -55,6 +98,7 @@ impl SitePackages {
         let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
         let mut by_name = FxHashMap::default();
         let mut by_url = FxHashMap::default();
+        let mut unmanaged_legacy_editables = Vec::new();
 
         for site_packages in interpreter.site_packages() {
             // Read the site-packages directory.
@@ -89,6 +133,7 @@ impl SitePackages {
                         distributions,
                         by_name,
                         by_url,
+                        unmanaged_legacy_editables,
                     });
                 }
                 Err(err) => return Err(err).context("Failed to read site-packages directory"),
@@ -98,7 +143,15 @@ impl SitePackages {
             for path in site_packages {
                 let dist_info = match InstalledDist::try_from_path(&path) {
                     Ok(Some(dist_info)) => dist_info,
-                    Ok(None) => continue,
+                    Ok(None) => {
+                        // Track legacy editables that we can't read, as they may still shadow
+                        // other installations.
+                        if path.extension().is_some_and(|ext| ext == "egg-link") {
+                            unmanaged_legacy_editables
+                                .extend(UnmanagedLegacyEditable::from_egg_link(&path));
+                        }
+                        continue;
+                    }
                     Err(_)
                         if path.file_name().is_some_and(|name| {
                             name.to_str().is_some_and(|name| name.starts_with('~'))
@@ -141,6 +194,7 @@ impl SitePackages {
             distributions,
             by_name,
             by_url,
+            unmanaged_legacy_editables,
         })
     }
 
@@ -154,6 +208,42 @@ impl SitePackages {
         self.distributions.iter().flatten()
     }
 
+    /// Returns the legacy editable installs whose metadata couldn't be read.
+    pub fn unmanaged_legacy_editables(&self) -> &[UnmanagedLegacyEditable] {
+        &self.unmanaged_legacy_editables
+    }
+
+    /// Verify the installed files of each distribution against its `RECORD`, returning the names
+    /// of any distributions with missing or modified files.
+    ///
+    /// Distributions without a `RECORD` (e.g., those installed from `.egg-info` metadata) can't be
+    /// verified, and are skipped.
+    pub fn verify(&self) -> Result<Vec<PackageName>> {
+        let mut modified = Vec::new();
+        for distribution in self.iter() {
+            if !matches!(
+                distribution.kind,
+                InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+            ) {
+                continue;
+            }
+            let mismatches = match uv_install_wheel::verify_record(distribution.install_path()) {
+                Ok(mismatches) => mismatches,
+                Err(uv_install_wheel::Error::MissingRecord(_)) => continue,
+                Err(err) => return Err(err.into()),
+            };
+            if let Some(path) = mismatches.first() {
+                debug!(
+                    "Found {} missing or modified file(s) for {distribution}, e.g.: `{}`",
+                    mismatches.len(),
+                    path.user_display()
+                );
+                modified.push(distribution.name().clone());
+            }
+        }
+        Ok(modified)
+    }
+
     /// Returns the installed distributions for a given package.
     pub fn get_packages(&self, name: &PackageName) -> Vec<&InstalledDist> {
         let Some(indexes) = self.by_name.get(name) else {
@@ -305,6 +395,31 @@ impl SitePackages {
                                     }
                                 }
                             }
+
+                            // Verify that the installed version provides the requested extras.
+                            if dependency.extras.is_empty() {
+                                continue;
+                            }
+                            let installed_metadata = if let Some(metadata) =
+                                dependency_metadata.get(&dependency.name, Some(installed.version()))
+                            {
+                                Cow::Owned(metadata)
+                            } else if let Ok(metadata) = installed.read_metadata() {
+                                Cow::Borrowed(metadata)
+                            } else {
+                                // The missing metadata is reported for the dependency itself.
+                                continue;
+                            };
+                            for extra in &dependency.extras {
+                                if !provides_extra(&installed_metadata, extra, markers) {
+                                    diagnostics.push(SitePackagesDiagnostic::MissingExtra {
+                                        package: package.clone(),
+                                        version: installed.version().clone(),
+                                        requirement: dependency.clone(),
+                                        extra: extra.clone(),
+                                    });
+                                }
+                            }
                         }
                         _ => {
                             // There are multiple installed distributions for the same package.
@@ -508,7 +623,8 @@ impl SitePackages {
                         ) {
                             RequirementSatisfaction::Mismatch
                             | RequirementSatisfaction::OutOfDate
-                            | RequirementSatisfaction::CacheInvalid => {
+                            | RequirementSatisfaction::CacheInvalid
+                            | RequirementSatisfaction::EntryPointsOutOfDate(_) => {
                                 return Ok(SatisfiesResult::Unsatisfied(requirement.to_string()));
                             }
                             RequirementSatisfaction::Satisfied => {}
@@ -532,7 +648,8 @@ impl SitePackages {
                             ) {
                                 RequirementSatisfaction::Mismatch
                                 | RequirementSatisfaction::OutOfDate
-                                | RequirementSatisfaction::CacheInvalid => {
+                                | RequirementSatisfaction::CacheInvalid
+                                | RequirementSatisfaction::EntryPointsOutOfDate(_) => {
                                     return Ok(SatisfiesResult::Unsatisfied(
                                         requirement.to_string(),
                                     ));
@@ -664,6 +781,16 @@ pub enum SitePackagesDiagnostic {
         /// The dependency that is incompatible.
         requirement: uv_pep508::Requirement<VerbatimParsedUrl>,
     },
+    MissingExtra {
+        /// The package that requires an extra of its dependency.
+        package: PackageName,
+        /// The version of the dependency that is installed.
+        version: Version,
+        /// The dependency that requests the extra.
+        requirement: uv_pep508::Requirement<VerbatimParsedUrl>,
+        /// The extra that isn't provided by the installed dependency.
+        extra: ExtraName,
+    },
     DuplicatePackage {
         /// The package that has multiple installed distributions.
         package: PackageName,
@@ -672,6 +799,22 @@ pub enum SitePackagesDiagnostic {
     },
 }
 
+impl SitePackagesDiagnostic {
+    /// Returns the [`PackageName`] that the diagnostic applies to.
+    pub fn package(&self) -> &PackageName {
+        match self {
+            Self::MetadataUnavailable { package, .. } => package,
+            Self::TagsUnavailable { package, .. } => package,
+            Self::IncompatiblePythonVersion { package, .. } => package,
+            Self::IncompatiblePlatform { package } => package,
+            Self::MissingDependency { package, .. } => package,
+            Self::IncompatibleDependency { package, .. } => package,
+            Self::MissingExtra { package, .. } => package,
+            Self::DuplicatePackage { package, .. } => package,
+        }
+    }
+}
+
 impl Diagnostic for SitePackagesDiagnostic {
     /// Convert the diagnostic into a user-facing message.
     fn message(&self) -> String {
@@ -707,6 +850,15 @@ impl Diagnostic for SitePackagesDiagnostic {
             } => format!(
                 "The package `{package}` requires `{requirement}`, but `{version}` is installed"
             ),
+            Self::MissingExtra {
+                package,
+                version,
+                requirement,
+                extra,
+            } => format!(
+                "The package `{package}` requires `{requirement}`, but the installed version of `{}` (`{version}`) does not provide the extra `{extra}`",
+                requirement.name
+            ),
             Self::DuplicatePackage { package, paths } => {
                 let mut paths = paths.clone();
                 paths.sort();
@@ -732,11 +884,31 @@ impl Diagnostic for SitePackagesDiagnostic {
                 requirement,
                 ..
             } => name == package || &requirement.name == name,
+            Self::MissingExtra {
+                package,
+                requirement,
+                ..
+            } => name == package || &requirement.name == name,
             Self::DuplicatePackage { package, .. } => name == package,
         }
     }
 }
 
+/// Returns `true` if the given metadata provides the given extra, either by declaring it via
+/// `Provides-Extra` or by gating any of its dependencies on it (as older metadata versions may omit
+/// `Provides-Extra`).
+fn provides_extra(
+    metadata: &ResolutionMetadata,
+    extra: &ExtraName,
+    markers: &ResolverMarkerEnvironment,
+) -> bool {
+    metadata.provides_extra.contains(extra)
+        || metadata.requires_dist.iter().any(|requirement| {
+            requirement.evaluate_markers(markers, std::slice::from_ref(extra))
+                && !requirement.evaluate_markers(markers, &[])
+        })
+}
+
 impl InstalledPackagesProvider for SitePackages {
     fn iter(&self) -> impl Iterator<Item = &InstalledDist> {
         self.iter()
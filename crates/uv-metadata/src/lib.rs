@@ -12,7 +12,7 @@ use tokio::io::AsyncReadExt;
 use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
 use uv_distribution_filename::WheelFilename;
 use uv_normalize::{DistInfoName, InvalidNameError};
-use uv_pypi_types::ResolutionMetadata;
+use uv_pypi_types::{Metadata23, ResolutionMetadata};
 
 /// The caller is responsible for attaching the path or url we failed to read.
 #[derive(Debug, Error)]
@@ -311,6 +311,21 @@ pub fn read_flat_wheel_metadata(
     })
 }
 
+/// Read the complete core metadata ([`Metadata23`]) from an unzipped wheel.
+pub fn read_flat_wheel_core_metadata(
+    filename: &WheelFilename,
+    wheel: impl AsRef<Path>,
+) -> Result<Metadata23, Error> {
+    let dist_info_prefix = find_flat_dist_info(filename, &wheel)?;
+    let metadata = read_dist_info_metadata(&dist_info_prefix, &wheel)?;
+    Metadata23::parse(&metadata).map_err(|err| {
+        Error::InvalidMetadata(
+            format!("{dist_info_prefix}.dist-info/METADATA"),
+            Box::new(err),
+        )
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::find_archive_dist_info;
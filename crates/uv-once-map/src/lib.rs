@@ -134,6 +134,11 @@ impl<K: Eq + Hash + Clone, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
         items.get(key)?.get()
     }
 
+    /// Return the keys of all registered jobs, whether or not they've completed.
+    pub fn keys(&self) -> Vec<K> {
+        self.items.pin().keys().cloned().collect()
+    }
+
     /// Remove the result of a previous job, if any.
     pub fn remove<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<V>
     where
@@ -182,6 +182,16 @@ impl MarkerEnvironment {
 
 /// APIs for setting specific parts of a marker environment.
 impl MarkerEnvironment {
+    /// Set the Python implementation name for this environment.
+    ///
+    /// See also [`MarkerEnvironment::implementation_name`].
+    #[inline]
+    #[must_use]
+    pub fn with_implementation_name(mut self, value: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.inner).implementation_name = value.into();
+        self
+    }
+
     /// Set the Python implementation version for this environment.
     ///
     /// See also [`MarkerEnvironment::implementation_version`].
@@ -212,6 +222,16 @@ impl MarkerEnvironment {
         self
     }
 
+    /// Set the Python implementation for this environment.
+    ///
+    /// See also [`MarkerEnvironment::platform_python_implementation`].
+    #[inline]
+    #[must_use]
+    pub fn with_platform_python_implementation(mut self, value: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.inner).platform_python_implementation = value.into();
+        self
+    }
+
     /// Set the release for this environment's platform.
     ///
     /// See also [`MarkerEnvironment::platform_release`].
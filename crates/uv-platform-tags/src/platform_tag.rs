@@ -163,7 +163,7 @@ impl PlatformTag {
     }
 
     /// Returns `true` if the platform is manylinux-only.
-    pub(crate) fn is_manylinux(&self) -> bool {
+    pub fn is_manylinux(&self) -> bool {
         matches!(
             self,
             Self::Manylinux { .. }
@@ -4,6 +4,8 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use uv_redacted::{DisplaySafeUrl, DisplaySafeUrlError};
 
+use crate::HashDigest;
+
 /// Metadata for a distribution that was installed via a direct URL.
 ///
 /// See: <https://packaging.python.org/en/latest/specifications/direct-url-data-structure/>
@@ -64,6 +66,25 @@ pub struct ArchiveInfo {
     pub(crate) hashes: Option<BTreeMap<String, String>>,
 }
 
+impl ArchiveInfo {
+    /// Create an [`ArchiveInfo`] from the known hashes of the archive.
+    ///
+    /// The first hash is also used to populate the legacy `hash` field.
+    pub fn from_hashes(hashes: &[HashDigest]) -> Self {
+        Self {
+            hash: hashes
+                .first()
+                .map(|hash| format!("{}={}", hash.algorithm, hash.digest)),
+            hashes: (!hashes.is_empty()).then(|| {
+                hashes
+                    .iter()
+                    .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+                    .collect()
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct VcsInfo {
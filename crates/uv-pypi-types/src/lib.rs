@@ -7,6 +7,7 @@ pub use lenient_requirement::*;
 pub use marker_environment::*;
 pub use metadata::*;
 pub use module_name::*;
+pub use object_reference::*;
 pub use parsed_url::*;
 pub use project_status::*;
 pub use scheme::*;
@@ -22,6 +23,7 @@ mod lenient_requirement;
 mod marker_environment;
 mod metadata;
 mod module_name;
+mod object_reference;
 mod parsed_url;
 mod project_status;
 mod scheme;
@@ -0,0 +1,189 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::{Identifier, IdentifierParseError, ModuleName, ModuleNameParseError};
+
+/// A reference to a Python object in an entry point, like `mypkg.cli:main`.
+///
+/// The object reference consists of an importable module, a qualified name within that module,
+/// and, for legacy reasons, an optional list of extras, like `mypkg.cli:main [color]`.
+///
+/// See <https://packaging.python.org/en/latest/specifications/entry-points/#data-model>.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ObjectReference {
+    module: ModuleName,
+    object: Box<str>,
+    extras: Vec<Box<str>>,
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum ObjectReferenceParseError {
+    #[error(
+        "Expected an object reference of the form `module:object`, but `{value}` has no `:`{}",
+        suggestion.as_ref().map(|suggestion| format!(" (did you mean `{suggestion}`?)")).unwrap_or_default()
+    )]
+    MissingObject {
+        value: Box<str>,
+        suggestion: Option<Box<str>>,
+    },
+    #[error("Invalid module in object reference `{value}`")]
+    InvalidModule {
+        value: Box<str>,
+        #[source]
+        err: ModuleNameParseError,
+    },
+    #[error("Invalid object name in object reference `{value}`")]
+    InvalidObject {
+        value: Box<str>,
+        #[source]
+        err: IdentifierParseError,
+    },
+    #[error(
+        "Invalid extras in object reference `{value}`, expected `module:object [extra1, extra2]`"
+    )]
+    InvalidExtras { value: Box<str> },
+}
+
+impl ObjectReference {
+    /// The module to import, e.g., `mypkg.cli` for `mypkg.cli:main`.
+    pub fn module(&self) -> &ModuleName {
+        &self.module
+    }
+
+    /// The qualified name of the object within the module, e.g., `main` for `mypkg.cli:main`.
+    pub fn object(&self) -> &str {
+        &self.object
+    }
+}
+
+impl FromStr for ObjectReference {
+    type Err = ObjectReferenceParseError;
+
+    /// Parse an object reference, accepting spaces around the colon and the extras, as readers
+    /// are required to.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (reference, extras) = match value.split_once('[') {
+            Some((reference, extras)) => {
+                let Some(extras) = extras.trim_end().strip_suffix(']') else {
+                    return Err(ObjectReferenceParseError::InvalidExtras {
+                        value: value.into(),
+                    });
+                };
+                let extras = extras
+                    .split(',')
+                    .map(str::trim)
+                    .map(|extra| {
+                        if extra.is_empty() || extra.contains(['[', ']']) {
+                            Err(ObjectReferenceParseError::InvalidExtras {
+                                value: value.into(),
+                            })
+                        } else {
+                            Ok(Box::from(extra))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                (reference, extras)
+            }
+            None => (value, Vec::new()),
+        };
+
+        let Some((module, object)) = reference.split_once(':') else {
+            // For `mypkg.cli.main`, suggest `mypkg.cli:main`.
+            let suggestion = reference
+                .trim()
+                .rsplit_once('.')
+                .and_then(|(module, object)| {
+                    let suggestion = format!("{module}:{object}");
+                    Self::from_str(&suggestion)
+                        .is_ok()
+                        .then(|| suggestion.into_boxed_str())
+                });
+            return Err(ObjectReferenceParseError::MissingObject {
+                value: value.into(),
+                suggestion,
+            });
+        };
+
+        let module = ModuleName::from_str(module.trim()).map_err(|err| {
+            ObjectReferenceParseError::InvalidModule {
+                value: value.into(),
+                err,
+            }
+        })?;
+        let object = object.trim();
+        for component in object.split('.') {
+            Identifier::from_str(component).map_err(|err| {
+                ObjectReferenceParseError::InvalidObject {
+                    value: value.into(),
+                    err,
+                }
+            })?;
+        }
+
+        Ok(Self {
+            module,
+            object: object.into(),
+            extras,
+        })
+    }
+}
+
+impl Display for ObjectReference {
+    /// Write the object reference in its normalized form, without superfluous spaces.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.module, self.object)?;
+        if !self.extras.is_empty() {
+            write!(f, " [{}]", self.extras.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use insta::assert_snapshot;
+
+    use super::ObjectReference;
+
+    #[test]
+    fn valid() {
+        for (value, normalized) in [
+            ("mypkg:main", "mypkg:main"),
+            ("mypkg.cli:Cli.run", "mypkg.cli:Cli.run"),
+            ("mypkg.cli : main", "mypkg.cli:main"),
+            ("mypkg:main [color,  rich ]", "mypkg:main [color, rich]"),
+            ("_private.안녕:main", "_private.안녕:main"),
+        ] {
+            let reference = ObjectReference::from_str(value).unwrap();
+            assert_eq!(reference.to_string(), normalized, "{value}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_snapshot!(
+            ObjectReference::from_str("mypkg.cli.main").unwrap_err(),
+            @"Expected an object reference of the form `module:object`, but `mypkg.cli.main` has no `:` (did you mean `mypkg.cli:main`?)"
+        );
+        assert_snapshot!(
+            ObjectReference::from_str("mypkg").unwrap_err(),
+            @"Expected an object reference of the form `module:object`, but `mypkg` has no `:`"
+        );
+        assert_snapshot!(
+            ObjectReference::from_str("my-pkg:main").unwrap_err(),
+            @"Invalid module in object reference `my-pkg:main`"
+        );
+        assert_snapshot!(
+            ObjectReference::from_str("mypkg:main()").unwrap_err(),
+            @"Invalid object name in object reference `mypkg:main()`"
+        );
+        assert_snapshot!(
+            ObjectReference::from_str("mypkg:main [color").unwrap_err(),
+            @"Invalid extras in object reference `mypkg:main [color`, expected `module:object [extra1, extra2]`"
+        );
+    }
+}
@@ -410,6 +410,37 @@ impl Hashes {
             _ => Err(HashError::UnsupportedHashAlgorithm(fragment.to_string())),
         }
     }
+
+    /// Parse the hash from a direct URL fragment, as in: `sha256=6088930bfe239f0e6710546ab9c19c9ef35e29792895fed6e6e31a023a182a61`
+    ///
+    /// Unlike [`Hashes::parse_fragment`], fragments that don't describe a hash (like `egg=foo`)
+    /// are ignored, while fragments that describe a hash with an unsupported algorithm (like
+    /// `sha1=...`) are rejected.
+    pub fn from_url_fragment(fragment: &str) -> Result<Option<Self>, HashError> {
+        match Self::parse_fragment(fragment) {
+            Ok(hashes) => Ok(Some(hashes)),
+            Err(HashError::UnsupportedHashAlgorithm(fragment)) => {
+                let name = fragment.split_once('=').map_or("", |(name, _)| name);
+                if matches!(
+                    name,
+                    "sha1"
+                        | "sha224"
+                        | "sha3_224"
+                        | "sha3_256"
+                        | "sha3_384"
+                        | "sha3_512"
+                        | "blake2s"
+                        | "shake_128"
+                        | "shake_256"
+                ) {
+                    Err(HashError::UnsupportedHashAlgorithm(fragment))
+                } else {
+                    Ok(None)
+                }
+            }
+            Err(_) => Ok(None),
+        }
+    }
 }
 
 impl FromStr for Hashes {
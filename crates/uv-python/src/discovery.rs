@@ -1273,6 +1273,145 @@ pub fn find_all_python_installations(
     Ok(installations)
 }
 
+/// Find the Python installations that were discovered, but rejected for the given request.
+///
+/// Used to explain a failed discovery when Python downloads are disabled. Requests for a specific
+/// path or executable name are not enumerated, as there's only a single candidate.
+pub(crate) fn find_rejected_python_installations(
+    request: &PythonRequest,
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Option<RejectedPythonInstallations> {
+    if matches!(
+        request,
+        PythonRequest::File(_) | PythonRequest::Directory(_) | PythonRequest::ExecutableName(_)
+    ) {
+        return None;
+    }
+
+    // Search every source, such that we can report installations excluded by the preference.
+    let installations = match find_all_python_installations(
+        &PythonRequest::Any,
+        environments,
+        PythonPreference::Managed,
+        cache,
+    ) {
+        Ok(installations) => installations,
+        Err(err) => {
+            debug!("Failed to find rejected Python installations: {err}");
+            return None;
+        }
+    };
+
+    // The same interpreter may be discovered from multiple sources, e.g., an active virtual
+    // environment that's also in the working directory.
+    let mut seen = FxHashSet::default();
+    let installations = installations
+        .into_iter()
+        .filter(|installation| seen.insert(installation.interpreter.sys_executable().to_path_buf()))
+        .filter_map(|installation| {
+            let reason = if !preference.allows_installation(&installation) {
+                RejectionReason::Preference(preference)
+            } else if !request.satisfied(&installation.interpreter, cache)
+                || (installation.is_alternative_implementation()
+                    && !request.allows_alternative_implementations())
+            {
+                RejectionReason::Request
+            } else {
+                return None;
+            };
+            Some(RejectedPythonInstallation {
+                implementation: installation.implementation().pretty().to_string(),
+                version: installation.interpreter.python_full_version().to_string(),
+                path: installation.interpreter.sys_executable().to_path_buf(),
+                reason,
+            })
+        })
+        .collect();
+
+    Some(RejectedPythonInstallations {
+        request: request.clone(),
+        installations,
+    })
+}
+
+/// The Python installations that were discovered, but could not be used for a request.
+#[derive(Debug, Clone)]
+pub struct RejectedPythonInstallations {
+    request: PythonRequest,
+    installations: Vec<RejectedPythonInstallation>,
+}
+
+/// A Python installation that was discovered, but could not be used for a request.
+#[derive(Debug, Clone)]
+struct RejectedPythonInstallation {
+    implementation: String,
+    version: String,
+    path: PathBuf,
+    reason: RejectionReason,
+}
+
+/// The reason a discovered Python installation could not be used for a request.
+#[derive(Debug, Clone, Copy)]
+enum RejectionReason {
+    /// The installation does not satisfy the request, e.g., the project's `requires-python`.
+    Request,
+    /// The installation is excluded by the `python-preference`.
+    Preference(PythonPreference),
+}
+
+impl fmt::Display for RejectedPythonInstallations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.installations.is_empty() {
+            return write!(f, "No Python interpreters were found on the system");
+        }
+
+        let count = self.installations.len();
+        let noun = if count == 1 {
+            "interpreter"
+        } else {
+            "interpreters"
+        };
+        if matches!(self.request, PythonRequest::Default | PythonRequest::Any)
+            || self
+                .installations
+                .iter()
+                .any(|installation| matches!(installation.reason, RejectionReason::Preference(_)))
+        {
+            write!(f, "Found {count} Python {noun}, but none can be used:")?;
+        } else {
+            write!(
+                f,
+                "Found {count} Python {noun}, but none are compatible with {}:",
+                self.request
+            )?;
+        }
+
+        for installation in &self.installations {
+            write!(
+                f,
+                "\n  - {} {} at `{}`: ",
+                installation.implementation,
+                installation.version,
+                installation.path.user_display()
+            )?;
+            match installation.reason {
+                RejectionReason::Request => match self.request {
+                    PythonRequest::Default | PythonRequest::Any => {
+                        write!(f, "not used by default")?;
+                    }
+                    _ => write!(f, "incompatible with {}", self.request)?,
+                },
+                RejectionReason::Preference(preference) => {
+                    write!(f, "excluded by the Python preference '{preference}'")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Find a Python installation that satisfies the given request.
 ///
 /// If an error is encountered while locating or inspecting a candidate installation,
@@ -2180,7 +2319,7 @@ impl PythonRequest {
     }
 
     /// Whether this request opts-in to an alternative Python implementation, e.g., PyPy.
-    fn allows_alternative_implementations(&self) -> bool {
+    pub(crate) fn allows_alternative_implementations(&self) -> bool {
         match self {
             Self::Default => false,
             Self::Any => true,
@@ -40,7 +40,7 @@ use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
 use crate::installation::PythonInstallationKey;
-use crate::managed::ManagedPythonInstallation;
+use crate::managed::{INCOMPLETE_MARKER, ManagedPythonInstallation};
 use crate::python_version::{BuildVersionError, python_build_version_from_env};
 use crate::{Interpreter, PythonRequest, PythonVersion, VersionRequest};
 
@@ -97,6 +97,16 @@ pub enum Error {
         #[source]
         err: io::Error,
     },
+    #[error("Failed to run the Python interpreter in `{0}`")]
+    ProbeError(Box<PythonInstallationKey>, #[source] io::Error),
+    #[error(
+        "The Python interpreter in `{installation}` failed to start ({status})\n\n[stderr]\n{stderr}"
+    )]
+    ProbeFailed {
+        installation: Box<PythonInstallationKey>,
+        status: std::process::ExitStatus,
+        stderr: String,
+    },
     #[error("Failed to read managed Python installation directory: {0}", dir.user_display())]
     ReadError {
         dir: PathBuf,
@@ -1171,9 +1181,13 @@ impl ManagedPythonDownload {
     ) -> Result<DownloadResult, Error> {
         let path = installation_dir.join(self.key().to_string());
 
-        // If it is not a reinstall and the dir already exists, return it.
+        // If it is not a reinstall and the dir already exists, return it, unless it was left
+        // incomplete (e.g., by an interrupted process), in which case it's replaced.
         if !reinstall && path.is_dir() {
-            return Ok(DownloadResult::AlreadyAvailable(path));
+            if ManagedPythonInstallation::new(path.clone(), self).is_complete() {
+                return Ok(DownloadResult::AlreadyAvailable(path));
+            }
+            debug!("Replacing incomplete installation: {}", path.user_display());
         }
 
         // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
@@ -1333,11 +1347,28 @@ impl ManagedPythonDownload {
             }
         }
 
-        // Remove the target if it already exists.
-        if path.is_dir() {
+        // Verify that the interpreter runs before persisting it.
+        self.probe(&extracted).await?;
+
+        // Mark the installation as incomplete until it's fully set up, such that an interrupted
+        // install isn't mistaken for a complete one.
+        fs_err::tokio::write(extracted.join(INCOMPLETE_MARKER), "").await?;
+
+        // Move the target aside if it already exists, rather than removing it in place, such that
+        // an interrupted install can't leave a partially removed installation behind.
+        let previous = if path.is_dir() {
             debug!("Removing existing directory: {}", path.user_display());
-            fs_err::tokio::remove_dir_all(&path).await?;
-        }
+            let previous = tempfile::tempdir_in(scratch_dir).map_err(Error::DownloadDirError)?;
+            rename_with_retry(&path, previous.path().join("previous"))
+                .await
+                .map_err(|err| Error::CopyError {
+                    to: previous.path().to_path_buf(),
+                    err,
+                })?;
+            Some(previous)
+        } else {
+            None
+        };
 
         // Persist it to the target.
         debug!("Moving {} to {}", extracted.display(), path.user_display());
@@ -1348,9 +1379,44 @@ impl ManagedPythonDownload {
                 err,
             })?;
 
+        // Remove the previous installation.
+        drop(previous);
+
         Ok(DownloadResult::Fetched(path))
     }
 
+    /// Run the Python interpreter in an extracted distribution, to avoid installing a distribution
+    /// that is broken (e.g., due to a truncated archive).
+    ///
+    /// Distributions that can't run on the current platform are not checked.
+    async fn probe(&self, extracted: &Path) -> Result<(), Error> {
+        if !Platform::from_env().is_ok_and(|platform| platform.supports(self.key().platform())) {
+            return Ok(());
+        }
+
+        let executable =
+            ManagedPythonInstallation::new(extracted.to_path_buf(), self).executable(false);
+        debug!(
+            "Checking extracted interpreter: {}",
+            executable.user_display()
+        );
+        let output = tokio::process::Command::new(&executable)
+            .args(["-c", "import encodings, json, os, sys; print(sys.version)"])
+            .env_remove(EnvVars::PYTHONHOME)
+            .env_remove(EnvVars::PYTHONPATH)
+            .output()
+            .await
+            .map_err(|err| Error::ProbeError(Box::new(self.key().clone()), err))?;
+        if !output.status.success() {
+            return Err(Error::ProbeFailed {
+                installation: Box::new(self.key().clone()),
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        Ok(())
+    }
+
     /// Download the managed Python archive into the cache directory.
     async fn download_archive(
         &self,
@@ -18,7 +18,7 @@ use uv_platform::{Arch, Libc, Os, Platform};
 
 use crate::discovery::{
     EnvironmentPreference, PythonRequest, VersionRequest, find_best_python_installation,
-    find_python_installation,
+    find_python_installation, find_rejected_python_installations,
 };
 use crate::downloads::{
     DownloadResult, ManagedPythonDownload, ManagedPythonDownloadList, PythonDownloadRequest,
@@ -270,7 +270,15 @@ impl PythonInstallation {
                     return Err(err.with_hint(MissingPythonHint::DownloadsManual(request.clone())));
                 }
                 PythonDownloads::Never => {
-                    return Err(err.with_hint(MissingPythonHint::DownloadsNever(request.clone())));
+                    let rejected = find_rejected_python_installations(
+                        request,
+                        environments,
+                        preference,
+                        cache,
+                    );
+                    return Err(
+                        err.with_hint(MissingPythonHint::DownloadsNever(request.clone(), rejected))
+                    );
                 }
             }
 
@@ -370,6 +378,7 @@ impl PythonInstallation {
         if let Err(e) = installed.ensure_dylib_patched() {
             e.warn_user(&installed);
         }
+        installed.mark_complete()?;
 
         Ok(Self {
             source: PythonSource::Managed,
@@ -27,6 +27,7 @@ use uv_pep508::{MarkerEnvironment, StringVersion};
 use uv_platform::{Arch, Libc, Os};
 use uv_platform_tags::{Platform, Tags, TagsError, TagsOptions};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
+use uv_warnings::warn_user_once;
 
 use crate::implementation::LenientImplementationName;
 use crate::managed::ManagedPythonInstallations;
@@ -238,6 +239,37 @@ impl Interpreter {
         Libc::from(self.platform().os())
     }
 
+    /// Return the [`Libc`] of the current system, if it differs from the [`Libc`] that the
+    /// interpreter was built for (e.g., a glibc interpreter copied onto a musl system).
+    ///
+    /// Such interpreters report platform tags that don't match the system.
+    pub fn system_libc_mismatch(&self) -> Option<Libc> {
+        let libc = self.libc();
+        if !matches!(libc, Libc::Some(_)) {
+            return None;
+        }
+        let system_libc = uv_platform::Platform::from_env().ok()?.libc;
+        (matches!(system_libc, Libc::Some(_)) && system_libc.is_musl() != libc.is_musl())
+            .then_some(system_libc)
+    }
+
+    /// Warn if the interpreter was built for a different [`Libc`] than the current system.
+    pub fn warn_on_libc_mismatch(&self) {
+        /// Return a human-readable name for the given [`Libc`].
+        fn libc_name(libc: Libc) -> &'static str {
+            if libc.is_musl() { "musl libc" } else { "glibc" }
+        }
+
+        if let Some(system_libc) = self.system_libc_mismatch() {
+            warn_user_once!(
+                "The Python interpreter at `{}` was built for {}, but the system uses {}; wheels selected for this interpreter may not be compatible with the system",
+                self.sys_executable().user_display(),
+                libc_name(self.libc()),
+                libc_name(system_libc),
+            );
+        }
+    }
+
     /// Return the [`Os`] reported by the interpreter platform tags.
     pub(crate) fn os(&self) -> Os {
         Os::from(self.platform().os())
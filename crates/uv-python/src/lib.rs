@@ -6,8 +6,8 @@ use uv_static::EnvVars;
 
 pub use crate::discovery::{
     EnvironmentPreference, Error as DiscoveryError, PythonDownloads, PythonNotFound,
-    PythonPreference, PythonRequest, PythonSource, PythonVariant, VersionRequest,
-    find_all_python_installations, find_python_installations,
+    PythonPreference, PythonRequest, PythonSource, PythonVariant, RejectedPythonInstallations,
+    VersionRequest, find_all_python_installations, find_python_installations,
 };
 pub use crate::downloads::PlatformRequest;
 pub use crate::environment::{InvalidEnvironmentKind, PythonEnvironment};
@@ -113,8 +113,9 @@ pub enum MissingPythonHint {
     RequiresUpdate,
     /// Downloads are set to `manual`.
     DownloadsManual(PythonRequest),
-    /// Downloads are set to `never`.
-    DownloadsNever(PythonRequest),
+    /// Downloads are set to `never`, along with the installations that were rejected, if any
+    /// candidates were considered.
+    DownloadsNever(PythonRequest, Option<RejectedPythonInstallations>),
     /// Python preference is set to `only-system`.
     PreferenceOnlySystem(PythonRequest),
     /// uv is in offline mode.
@@ -147,7 +148,7 @@ impl std::fmt::Display for MissingPythonHint {
                     request.to_canonical_string(),
                 )
             }
-            Self::DownloadsNever(request) => {
+            Self::DownloadsNever(request, _) => {
                 write!(
                     f,
                     "A managed Python download is available{}, but Python downloads are set to 'never'",
@@ -175,7 +176,13 @@ impl std::fmt::Display for MissingPythonHint {
 impl uv_errors::Hint for Error {
     fn hints(&self) -> uv_errors::Hints<'_> {
         match self {
-            Self::MissingPython(_, Some(hint)) => uv_errors::Hints::from(hint.to_string()),
+            Self::MissingPython(_, Some(hint)) => {
+                let mut hints = uv_errors::Hints::from(hint.to_string());
+                if let MissingPythonHint::DownloadsNever(_, Some(rejected)) = hint.as_ref() {
+                    hints.push(rejected.to_string());
+                }
+                hints
+            }
             Self::Discovery(err) => err.hints(),
             _ => uv_errors::Hints::none(),
         }
@@ -15,6 +15,7 @@ use tracing::{debug, warn};
 #[cfg(windows)]
 use windows::Win32::Storage::FileSystem::FILE_ATTRIBUTE_REPARSE_POINT;
 
+use uv_dirs::DirectorySource;
 use uv_fs::{
     LockedFile, LockedFileError, LockedFileMode, Simplified, normalize_absolute_path,
     replace_symlink, symlink_or_copy_file, verbatim_path,
@@ -24,6 +25,7 @@ use uv_platform::{LibcDetectionError, Platform};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_trampoline_builder::{Launcher, LauncherKind};
+use uv_warnings::warn_user_once;
 
 use crate::discovery::VersionRequest;
 use crate::downloads::{Error as DownloadError, ManagedPythonDownload};
@@ -35,6 +37,9 @@ use crate::interpreter::Interpreter;
 use crate::python_version::PythonVersion;
 use crate::{PythonInstallationMinorVersionKey, PythonVariant, macos_dylib, sysconfig};
 
+/// The name of the file that marks an installation as incomplete, i.e., as not yet fully set up.
+pub(crate) const INCOMPLETE_MARKER: &str = ".uv-incomplete";
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error(transparent)]
@@ -125,17 +130,38 @@ impl ManagedPythonInstallations {
     /// 3. A directory in the system-appropriate user-level data directory, e.g., `~/.local/uv/python`.
     /// 4. A directory in the local data directory, e.g., `./.uv/python`.
     pub fn from_settings(install_dir: Option<PathBuf>) -> Result<Self, Error> {
-        if let Some(install_dir) = install_dir {
-            Ok(Self::from_path(install_dir))
+        Ok(Self::from_settings_with_source(install_dir)?.0)
+    }
+
+    /// Like [`ManagedPythonInstallations::from_settings`], but also returns the
+    /// [`DirectorySource`] from which the directory was resolved.
+    ///
+    /// Warns if the directory was overridden, but is empty, while the default directory contains
+    /// existing installations.
+    pub fn from_settings_with_source(
+        install_dir: Option<PathBuf>,
+    ) -> Result<(Self, DirectorySource), Error> {
+        let (root, source) = if let Some(install_dir) = install_dir {
+            (install_dir, DirectorySource::Argument("--install-dir"))
         } else if let Some(install_dir) =
             std::env::var_os(EnvVars::UV_PYTHON_INSTALL_DIR).filter(|s| !s.is_empty())
         {
-            Ok(Self::from_path(install_dir))
+            (
+                PathBuf::from(install_dir),
+                DirectorySource::EnvironmentVariable(EnvVars::UV_PYTHON_INSTALL_DIR),
+            )
         } else {
-            Ok(Self::from_path(
-                StateStore::from_settings(None)?.bucket(StateBucket::ManagedPython),
-            ))
-        }
+            let (store, source) = StateStore::user_with_source();
+            (store.bucket(StateBucket::ManagedPython), source)
+        };
+        uv_dirs::warn_if_hidden_default("Managed Python installations", &root, source, || {
+            Some(
+                StateStore::user_with_source()
+                    .0
+                    .bucket(StateBucket::ManagedPython),
+            )
+        });
+        Ok((Self::from_path(root), source))
     }
 
     /// Create a temporary Python installation directory.
@@ -252,6 +278,15 @@ impl ManagedPythonInstallations {
                     debug!("Skipping managed installation `{installation}`: not supported by current platform `{platform}`");
                     return false;
                 }
+                if !installation.is_complete() {
+                    warn_user_once!(
+                        "Ignoring incomplete managed Python installation `{}`; run `uv python install --reinstall {}` to repair it, or `uv python uninstall {}` to remove it",
+                        installation.key().green(),
+                        installation.key(),
+                        installation.key(),
+                    );
+                    return false;
+                }
                 true
             });
 
@@ -495,6 +530,31 @@ impl ManagedPythonInstallation {
         PythonInstallationMinorVersionKey::ref_cast(&self.key)
     }
 
+    /// Whether the installation is complete, i.e., whether it was not marked as incomplete and its
+    /// Python executable exists.
+    ///
+    /// An installation may be left incomplete if it was modified or removed by an interrupted
+    /// process. The executable of installations for another operating system can't be checked, as
+    /// its name depends on the current platform.
+    pub fn is_complete(&self) -> bool {
+        if self.path.join(INCOMPLETE_MARKER).exists() {
+            return false;
+        }
+        if self.key.os().is_windows() != cfg!(windows) || self.key.os().is_emscripten() {
+            return true;
+        }
+        self.executable(false).exists()
+    }
+
+    /// Mark the installation as complete, once it's fully set up.
+    pub fn mark_complete(&self) -> Result<(), Error> {
+        match fs::remove_file(self.path.join(INCOMPLETE_MARKER)) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Ensure the environment contains the canonical Python executable names.
     pub fn ensure_canonical_executables(&self) -> Result<(), Error> {
         let python = self.executable(false);
@@ -960,7 +1020,13 @@ impl fmt::Display for ManagedPythonInstallation {
 
 /// Find the directory to install Python executables into.
 pub fn python_executable_dir() -> Result<PathBuf, Error> {
-    uv_dirs::user_executable_directory(Some(EnvVars::UV_PYTHON_BIN_DIR))
+    Ok(python_executable_dir_with_source()?.0)
+}
+
+/// Find the directory to install Python executables into, along with the [`DirectorySource`] from
+/// which it was resolved.
+pub fn python_executable_dir_with_source() -> Result<(PathBuf, DirectorySource), Error> {
+    uv_dirs::user_executable_directory_with_source(Some(EnvVars::UV_PYTHON_BIN_DIR))
         .ok_or(Error::NoExecutableDirectory)
 }
 
@@ -1312,7 +1378,31 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
 
         // Create mock installation directories
-        fs::create_dir(temp_dir.path().join(format!("cpython-3.10.0-{platform}"))).unwrap();
+        for version in ["3.10", "3.12"] {
+            let installation = temp_dir
+                .path()
+                .join(format!("cpython-{version}.0-{platform}"));
+            let executable = if cfg!(windows) {
+                installation.join("python.exe")
+            } else {
+                installation.join("bin").join(format!("python{version}"))
+            };
+            fs::create_dir_all(executable.parent().unwrap()).unwrap();
+            fs::write(&executable, "").unwrap();
+        }
+
+        // Create an incomplete installation, without an executable
+        fs::create_dir(temp_dir.path().join(format!("cpython-3.11.0-{platform}"))).unwrap();
+
+        // Mark an installation as incomplete, as an interrupted install would leave it
+        fs::write(
+            temp_dir
+                .path()
+                .join(format!("cpython-3.12.0-{platform}"))
+                .join(INCOMPLETE_MARKER),
+            "",
+        )
+        .unwrap();
 
         temp_env::with_var(
             uv_static::EnvVars::UV_PYTHON_INSTALL_DIR,
@@ -1329,6 +1419,14 @@ mod tests {
                 let v3_10 = PythonVersion::from_str("3.10").unwrap();
                 let matched: Vec<_> = installations.find_version(&v3_10).unwrap().collect();
                 assert_eq!(matched.len(), 1);
+
+                // Incomplete installations are ignored
+                let v3_11 = PythonVersion::from_str("3.11").unwrap();
+                let matched: Vec<_> = installations.find_version(&v3_11).unwrap().collect();
+                assert_eq!(matched.len(), 0);
+                let v3_12 = PythonVersion::from_str("3.12").unwrap();
+                let matched: Vec<_> = installations.find_version(&v3_12).unwrap().collect();
+                assert_eq!(matched.len(), 0);
             },
         );
     }
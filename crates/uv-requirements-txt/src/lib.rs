@@ -69,6 +69,9 @@ mod shquote;
 /// A cache of file contents, keyed by path, to avoid re-reading files from disk.
 pub type SourceCache = FxHashMap<PathBuf, String>;
 
+/// The maximum depth of nested `-r` and `-c` inclusions.
+const MAX_INCLUDE_DEPTH: usize = 32;
+
 /// We emit one of those for each `requirements.txt` entry.
 enum RequirementsTxtStatement {
     /// `-r` inclusion filename
@@ -204,6 +207,35 @@ impl RequirementsTxt {
             client_builder,
             &mut visited,
             cache,
+            0,
+        )
+        .await
+    }
+
+    /// Parse a constraints file, using the given cache to avoid re-reading files from disk.
+    ///
+    /// Unlike [`RequirementsTxt::parse_with_cache`], editables and requirements with extras are
+    /// rejected, as they can't be applied as constraints.
+    #[instrument(
+        skip_all,
+        fields(requirements_txt = requirements_txt.as_ref().as_os_str().to_str())
+    )]
+    pub async fn parse_constraints_with_cache(
+        requirements_txt: impl AsRef<Path>,
+        working_dir: impl AsRef<Path>,
+        client_builder: &BaseClientBuilder<'_>,
+        cache: &mut SourceCache,
+    ) -> Result<Self, RequirementsTxtFileError> {
+        let mut visited = VisitedFiles::Constraints {
+            constraints: &mut FxHashSet::default(),
+        };
+        Self::parse_impl(
+            requirements_txt,
+            working_dir,
+            client_builder,
+            &mut visited,
+            cache,
+            0,
         )
         .await
     }
@@ -234,6 +266,7 @@ impl RequirementsTxt {
             requirements_txt,
             &mut visited,
             source_contents,
+            0,
         )
         .await
         .map_err(|err| RequirementsTxtFileError {
@@ -253,6 +286,7 @@ impl RequirementsTxt {
         client_builder: &BaseClientBuilder<'_>,
         visited: &mut VisitedFiles<'_>,
         cache: &mut SourceCache,
+        depth: usize,
     ) -> Result<Self, RequirementsTxtFileError> {
         let requirements_txt = requirements_txt.as_ref();
         let working_dir = working_dir.as_ref();
@@ -332,6 +366,7 @@ impl RequirementsTxt {
             requirements_txt,
             visited,
             cache,
+            depth,
         )
         .await
         .map_err(|err| RequirementsTxtFileError {
@@ -346,8 +381,8 @@ impl RequirementsTxt {
     ///
     /// When parsing, relative paths to requirements (e.g., `-e ../editable/`) are resolved against
     /// the current working directory. However, relative paths to sub-files (e.g., `-r ../requirements.txt`)
-    /// are resolved against the directory of the containing `requirements.txt` file, to match
-    /// `pip`'s behavior.
+    /// are resolved against the directory of the containing `requirements.txt` file (or, for a
+    /// remote file, against its URL), to match `pip`'s behavior.
     async fn parse_inner(
         content: &str,
         working_dir: &Path,
@@ -356,40 +391,34 @@ impl RequirementsTxt {
         requirements_txt: &Path,
         visited: &mut VisitedFiles<'_>,
         cache: &mut SourceCache,
+        depth: usize,
     ) -> Result<Self, RequirementsTxtParserError> {
         let mut s = Scanner::new(content);
 
+        // Within a constraints file, reject requirements that can't be expressed as constraints.
+        let constraints = matches!(visited, VisitedFiles::Constraints { .. });
+
         let mut data = Self::default();
-        while let Some(statement) = parse_entry(&mut s, content, working_dir, requirements_txt)? {
+        while let Some(statement) =
+            parse_entry(&mut s, content, working_dir, requirements_txt, constraints)?
+        {
             match statement {
                 RequirementsTxtStatement::Requirements {
                     filename,
                     start,
                     end,
                 } => {
+                    if depth >= MAX_INCLUDE_DEPTH {
+                        return Err(RequirementsTxtParserError::IncludeDepth { start, end });
+                    }
                     let filename = expand_env_vars(&filename);
-                    let sub_file =
-                        if filename.starts_with("http://") || filename.starts_with("https://") {
-                            PathBuf::from(filename.as_ref())
-                        } else if filename.starts_with("file://") {
-                            requirements_txt.join(
-                                Url::parse(filename.as_ref())
-                                    .map_err(|err| RequirementsTxtParserError::Url {
-                                        source: DisplaySafeUrlError::Url(err).into(),
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?
-                                    .to_file_path()
-                                    .map_err(|()| RequirementsTxtParserError::FileUrl {
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?,
-                            )
-                        } else {
-                            requirements_dir.join(filename.as_ref())
-                        };
+                    let sub_file = resolve_include(
+                        filename.as_ref(),
+                        requirements_txt,
+                        requirements_dir,
+                        start,
+                        end,
+                    )?;
                     match visited {
                         VisitedFiles::Requirements { requirements, .. } => {
                             if !requirements.insert(visited_file(&sub_file)) {
@@ -411,6 +440,7 @@ impl RequirementsTxt {
                         client_builder,
                         visited,
                         cache,
+                        depth + 1,
                     ))
                     .await
                     .map_err(|err| RequirementsTxtParserError::Subfile {
@@ -442,29 +472,17 @@ impl RequirementsTxt {
                     start,
                     end,
                 } => {
+                    if depth >= MAX_INCLUDE_DEPTH {
+                        return Err(RequirementsTxtParserError::IncludeDepth { start, end });
+                    }
                     let filename = expand_env_vars(&filename);
-                    let sub_file =
-                        if filename.starts_with("http://") || filename.starts_with("https://") {
-                            PathBuf::from(filename.as_ref())
-                        } else if filename.starts_with("file://") {
-                            requirements_txt.join(
-                                Url::parse(filename.as_ref())
-                                    .map_err(|err| RequirementsTxtParserError::Url {
-                                        source: DisplaySafeUrlError::Url(err).into(),
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?
-                                    .to_file_path()
-                                    .map_err(|()| RequirementsTxtParserError::FileUrl {
-                                        url: filename.to_string(),
-                                        start,
-                                        end,
-                                    })?,
-                            )
-                        } else {
-                            requirements_dir.join(filename.as_ref())
-                        };
+                    let sub_file = resolve_include(
+                        filename.as_ref(),
+                        requirements_txt,
+                        requirements_dir,
+                        start,
+                        end,
+                    )?;
 
                     // Switch to constraints mode, if we aren't in it already.
                     let mut visited = match visited {
@@ -488,6 +506,7 @@ impl RequirementsTxt {
                         client_builder,
                         &mut visited,
                         cache,
+                        depth + 1,
                     ))
                     .await
                     .map_err(|err| RequirementsTxtParserError::Subfile {
@@ -673,11 +692,15 @@ const fn is_terminal(c: char) -> bool {
 ///
 /// Consumes all preceding trivia (whitespace and comments). If it returns `None`, we've reached
 /// the end of file.
+///
+/// If `constraints` is set, the entry is parsed as part of a constraints file, in which editables
+/// and requirements with extras are rejected.
 fn parse_entry(
     s: &mut Scanner,
     content: &str,
     working_dir: &Path,
     requirements_txt: &Path,
+    constraints: bool,
 ) -> Result<Option<RequirementsTxtStatement>, RequirementsTxtParserError> {
     // Eat all preceding whitespace, this may run us to the end of file
     eat_wrappable_whitespace(s);
@@ -713,6 +736,15 @@ fn parse_entry(
             end,
         }
     } else if s.eat_if("-e") || s.eat_if("--editable") {
+        if constraints {
+            let (line, column) = calculate_row_column(content, start);
+            return Err(RequirementsTxtParserError::Parser {
+                message: "Editable requirements are not allowed as constraints".to_string(),
+                line,
+                column,
+            });
+        }
+
         if s.eat_if('=') {
             // Explicit equals sign.
         } else if s.eat_if(char::is_whitespace) {
@@ -883,6 +915,20 @@ fn parse_entry(
 
         let (requirement, hashes) =
             parse_requirement_and_hashes(s, content, source, working_dir, false)?;
+
+        // Like `pip`, reject constraints with extras, which would otherwise be silently ignored.
+        if constraints
+            && let RequirementsTxtRequirement::Named(requirement) = &requirement
+            && !requirement.extras.is_empty()
+        {
+            let (line, column) = calculate_row_column(content, start);
+            return Err(RequirementsTxtParserError::Parser {
+                message: format!("Constraints cannot have extras (found: `{requirement}`)"),
+                line,
+                column,
+            });
+        }
+
         RequirementsTxtStatement::RequirementEntry(RequirementEntry {
             requirement,
             hashes,
@@ -1201,6 +1247,10 @@ pub enum RequirementsTxtParserError {
         start: usize,
         end: usize,
     },
+    IncludeDepth {
+        start: usize,
+        end: usize,
+    },
     NonUnicodeUrl {
         url: PathBuf,
     },
@@ -1268,6 +1318,12 @@ impl Display for RequirementsTxtParserError {
             Self::Subfile { start, .. } => {
                 write!(f, "Error parsing included file at position {start}")
             }
+            Self::IncludeDepth { start, .. } => {
+                write!(
+                    f,
+                    "Included files are nested more than {MAX_INCLUDE_DEPTH} levels deep at position {start}"
+                )
+            }
             Self::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1317,6 +1373,7 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::Pep508 { source, .. } => Some(source),
             Self::ParsedUrl { source, .. } => Some(source),
             Self::Subfile { source, .. } => Some(source.as_ref()),
+            Self::IncludeDepth { .. } => None,
             Self::Parser { .. } => None,
             Self::NonUnicodeUrl { .. } => None,
             #[cfg(feature = "http")]
@@ -1442,6 +1499,13 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
+            RequirementsTxtParserError::IncludeDepth { start, .. } => {
+                write!(
+                    f,
+                    "Included files are nested more than {MAX_INCLUDE_DEPTH} levels deep in `{}` at position {start}",
+                    self.file.user_display(),
+                )
+            }
             RequirementsTxtParserError::NonUnicodeUrl { url } => {
                 write!(
                     f,
@@ -1511,6 +1575,59 @@ enum VisitedFiles<'a> {
     },
 }
 
+/// Resolve the path of a file included via `-r` or `-c`.
+///
+/// Relative paths are resolved against the including file: for a local file, against its parent
+/// directory; for a remote file, against its URL, such that `-r common.txt` in
+/// `https://example.com/reqs/base.txt` refers to `https://example.com/reqs/common.txt`.
+fn resolve_include(
+    filename: &str,
+    requirements_txt: &Path,
+    requirements_dir: &Path,
+    start: usize,
+    end: usize,
+) -> Result<PathBuf, RequirementsTxtParserError> {
+    if filename.starts_with("http://") || filename.starts_with("https://") {
+        return Ok(PathBuf::from(filename));
+    }
+
+    if filename.starts_with("file://") {
+        let path = Url::parse(filename)
+            .map_err(|err| RequirementsTxtParserError::Url {
+                source: DisplaySafeUrlError::Url(err).into(),
+                url: filename.to_string(),
+                start,
+                end,
+            })?
+            .to_file_path()
+            .map_err(|()| RequirementsTxtParserError::FileUrl {
+                url: filename.to_string(),
+                start,
+                end,
+            })?;
+        return Ok(requirements_txt.join(path));
+    }
+
+    if requirements_txt.starts_with("http://") || requirements_txt.starts_with("https://") {
+        let base = requirements_txt
+            .to_str()
+            .and_then(|url| Url::parse(url).ok());
+        if let Some(base) = base {
+            let url = base
+                .join(filename)
+                .map_err(|err| RequirementsTxtParserError::Url {
+                    source: DisplaySafeUrlError::Url(err).into(),
+                    url: filename.to_string(),
+                    start,
+                    end,
+                })?;
+            return Ok(PathBuf::from(url.as_str()));
+        }
+    }
+
+    Ok(requirements_dir.join(filename))
+}
+
 /// Return a stable identity for a requirements file without changing the path used to read it.
 fn visited_file(path: &Path) -> PathBuf {
     if path.starts_with("http://") || path.starts_with("https://") {
@@ -1575,9 +1692,10 @@ mod test {
     use test_case::test_case;
     use unscanny::Scanner;
 
+    use uv_client::{BaseClientBuilder, Connectivity};
     use uv_fs::Simplified;
 
-    use crate::{RequirementsTxt, calculate_row_column};
+    use crate::{RequirementsTxt, SourceCache, calculate_row_column, resolve_include};
 
     fn workspace_test_data_dir() -> PathBuf {
         Path::new("./test-data").simple_canonicalize().unwrap()
@@ -1921,6 +2039,72 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn constraint_extra() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let constraints_txt = temp_dir.child("constraints.txt");
+        constraints_txt.write_str(indoc! {"
+            django==2.1.15
+            flask[dotenv]<24.3.0
+        "})?;
+
+        let error = RequirementsTxt::parse_constraints_with_cache(
+            constraints_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::default().connectivity(Connectivity::Offline),
+            &mut SourceCache::default(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let constraints_txt = regex::escape(&constraints_txt.path().user_display().to_string());
+        let filters = vec![(constraints_txt.as_str(), "<CONSTRAINTS_TXT>")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @"Constraints cannot have extras (found: `flask[dotenv]<24.3.0`) at <CONSTRAINTS_TXT>:2:1");
+        });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn constraint_editable() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            django
+            -c constraints.txt
+        "})?;
+        let constraints_txt = temp_dir.child("constraints.txt");
+        constraints_txt.write_str(indoc! {"
+            -e ./editable
+        "})?;
+
+        let error = RequirementsTxt::parse(requirements_txt.path(), temp_dir.path())
+            .await
+            .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let constraints_txt = regex::escape(&constraints_txt.path().user_display().to_string());
+        let filters = vec![
+            (requirement_txt.as_str(), "<REQUIREMENTS_TXT>"),
+            (constraints_txt.as_str(), "<CONSTRAINTS_TXT>"),
+        ];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @"
+            Error parsing included file in `<REQUIREMENTS_TXT>` at position 7
+            Editable requirements are not allowed as constraints at <CONSTRAINTS_TXT>:1:1
+            ");
+        });
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn relative_index_url() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
@@ -3030,4 +3214,54 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn include_depth() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        for level in 0..40 {
+            temp_dir
+                .child(format!("level-{level}.txt"))
+                .write_str(&format!("-r level-{}.txt\n", level + 1))?;
+        }
+        temp_dir.child("level-40.txt").write_str("pkg\n")?;
+
+        let error = RequirementsTxt::parse(temp_dir.child("level-0.txt"), temp_dir.path())
+            .await
+            .unwrap_err();
+        let errors = anyhow::Error::new(error)
+            .chain()
+            .last()
+            .unwrap()
+            .to_string();
+
+        let filter = path_filter(temp_dir.path());
+        insta::with_settings!({
+            filters => path_filters(&filter),
+        }, {
+            insta::assert_snapshot!(errors, @"Included files are nested more than 32 levels deep in `<REQUIREMENTS_DIR>/level-32.txt` at position 0");
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_remote_include() {
+        let base = Path::new("https://example.com/reqs/base.txt");
+        let dir = base.parent().unwrap();
+        for (filename, expected) in [
+            ("common.txt", "https://example.com/reqs/common.txt"),
+            (
+                "nested/common.txt",
+                "https://example.com/reqs/nested/common.txt",
+            ),
+            ("../constraints.txt", "https://example.com/constraints.txt"),
+            ("/pins.txt", "https://example.com/pins.txt"),
+            ("https://other.com/base.txt", "https://other.com/base.txt"),
+        ] {
+            assert_eq!(
+                resolve_include(filename, base, dir, 0, 0).unwrap(),
+                PathBuf::from(expected),
+            );
+        }
+    }
 }
@@ -29,7 +29,7 @@ pub enum SourceTree {
 
 impl SourceTree {
     /// Return the [`Path`] to the file representing the source tree (e.g., the `pyproject.toml`).
-    fn path(&self) -> &Path {
+    pub fn path(&self) -> &Path {
         match self {
             Self::PyProjectToml(path, ..) => path,
             Self::SetupPy(path) => path,
@@ -37,6 +37,11 @@ impl SourceTree {
         }
     }
 
+    /// Returns `true` if this is a legacy source tree, defined only by a `setup.py` or `setup.cfg`.
+    pub fn is_legacy(&self) -> bool {
+        matches!(self, Self::SetupPy(..) | Self::SetupCfg(..))
+    }
+
     /// Return the [`PyProjectToml`] if this is a `pyproject.toml`-based source tree.
     fn pyproject_toml(&self) -> Option<&PyProjectToml> {
         match self {
@@ -207,7 +212,9 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
         // manual match.
         let hashes = match self.hasher {
             HashStrategy::None => HashPolicy::None,
-            HashStrategy::Generate(mode) => HashPolicy::Generate(*mode),
+            HashStrategy::Generate(mode) | HashStrategy::GenerateAndVerify(mode, _) => {
+                HashPolicy::Generate(*mode)
+            }
             HashStrategy::Verify(_) => HashPolicy::Generate(HashGeneration::All),
             HashStrategy::Require(_) => {
                 return Err(anyhow::anyhow!(
@@ -228,7 +235,20 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             } else {
                 // Run the PEP 517 build process to extract metadata from the source distribution.
                 let source = BuildableSource::Url(source);
-                let archive = self.database.build_wheel_metadata(&source, hashes).await?;
+                let archive = self.database.build_wheel_metadata(&source, hashes).await;
+
+                // For legacy projects, metadata is prepared via the `setuptools` legacy backend;
+                // note as much in the error, since the user may not expect a build to occur.
+                let archive = if source_tree.is_legacy() {
+                    archive.with_context(|| {
+                        format!(
+                            "Failed to extract dependencies from legacy project: `{}`",
+                            source_tree.path().user_display()
+                        )
+                    })?
+                } else {
+                    archive?
+                };
 
                 let metadata = archive.metadata.clone();
 
@@ -40,7 +40,7 @@ use uv_client::BaseClientBuilder;
 use uv_configuration::{
     DependencyGroups, ExcludeDependency, NoBinary, NoBuild, Override, PackageOverride,
 };
-use uv_distribution_types::{Index, Requirement};
+use uv_distribution_types::{Index, Requirement, RequirementSource};
 use uv_distribution_types::{
     IndexUrl, NameRequirementSpecification, UnresolvedRequirement,
     UnresolvedRequirementSpecification,
@@ -244,6 +244,37 @@ impl RequirementsSpecification {
         }
     }
 
+    /// Read the constraints from a source, using a cache for file contents.
+    ///
+    /// `requirements.txt` files are parsed in constraints mode, such that unsupported entries are
+    /// reported along with their location in the file.
+    async fn from_constraint_source_with_cache(
+        source: &RequirementsSource,
+        client_builder: &BaseClientBuilder<'_>,
+        cache: &mut SourceCache,
+    ) -> Result<Self> {
+        let RequirementsSource::RequirementsTxt(path) = source else {
+            return Self::from_source_with_cache(source, client_builder, cache).await;
+        };
+
+        if !(path.starts_with("http://") || path.starts_with("https://") || path.exists()) {
+            return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+        }
+
+        let requirements_txt =
+            RequirementsTxt::parse_constraints_with_cache(path, &*CWD, client_builder, cache)
+                .await?;
+
+        if requirements_txt == RequirementsTxt::default() {
+            warn_user!(
+                "Constraints file `{}` does not contain any dependencies",
+                path.user_display()
+            );
+        }
+
+        Ok(Self::from_requirements_txt(requirements_txt))
+    }
+
     /// Read the requirements and constraints from a source, using a cache for file contents.
     #[instrument(skip_all, level = tracing::Level::DEBUG, fields(source = % source))]
     async fn from_source_with_cache(
@@ -597,11 +628,29 @@ impl RequirementsSpecification {
 
         // Read all constraints, treating both requirements _and_ constraints as constraints.
         // Overrides are ignored.
-        for source in constraints {
-            let source = Self::from_source_with_cache(source, client_builder, &mut cache).await?;
+        for constraint in constraints {
+            let source =
+                Self::from_constraint_source_with_cache(constraint, client_builder, &mut cache)
+                    .await?;
             for entry in source.requirements {
                 match entry.requirement {
                     UnresolvedRequirement::Named(requirement) => {
+                        if matches!(
+                            requirement.source,
+                            RequirementSource::Directory {
+                                editable: Some(true),
+                                ..
+                            }
+                        ) {
+                            return Err(anyhow::anyhow!(
+                                "Editable requirements are not allowed as constraints (found: `{requirement}` in `{constraint}`)"
+                            ));
+                        }
+                        if !requirement.extras.is_empty() {
+                            return Err(anyhow::anyhow!(
+                                "Constraints cannot have extras (found: `{requirement}` in `{constraint}`)"
+                            ));
+                        }
                         spec.constraints.push(NameRequirementSpecification {
                             requirement,
                             hashes: entry.hashes,
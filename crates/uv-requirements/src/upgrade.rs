@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use anyhow::Result;
+use rustc_hash::FxHashSet;
 use tracing::info_span;
 
 use uv_configuration::Upgrade;
@@ -80,6 +81,18 @@ pub fn read_lock_requirements(
     // `--upgrade-group`.
     let upgrade_packages = UpgradePackages::for_workspace(lock, upgrade);
 
+    // Collect the Git references of the packages to upgrade. Git SHAs are pinned per repository
+    // and reference, so a package that shares a reference with an upgraded package (e.g., another
+    // subdirectory on the same branch) must not pin it to the locked commit.
+    let mut upgraded_git = FxHashSet::default();
+    for package in lock.packages() {
+        if upgrade_packages.contains(package.name())
+            && let Some(git_ref) = package.as_git_ref()?
+        {
+            upgraded_git.insert(git_ref.reference);
+        }
+    }
+
     let mut preferences = Vec::new();
     let mut git = Vec::new();
 
@@ -96,7 +109,9 @@ pub fn read_lock_requirements(
         }
 
         // Map each entry in the lockfile to a Git SHA.
-        if let Some(git_ref) = package.as_git_ref()? {
+        if let Some(git_ref) = package.as_git_ref()?
+            && !upgraded_git.contains(&git_ref.reference)
+        {
             git.push(git_ref);
         }
     }
@@ -3,6 +3,7 @@ use std::fmt::{Display, Formatter};
 use either::Either;
 use itertools::Itertools;
 use pubgrub::Range;
+use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use tracing::{debug, trace};
 
@@ -14,18 +15,24 @@ use uv_pep440::Version;
 use uv_platform_tags::Tags;
 use uv_types::InstalledPackagesProvider;
 
-use crate::preferences::{Entry, PreferenceSource, Preferences};
+use crate::preferences::{Entry, PreferenceIndex, PreferenceSource, Preferences};
 use crate::prerelease::{AllowPrerelease, PrereleaseStrategy};
 use crate::resolution_mode::ResolutionStrategy;
 use crate::version_map::{VersionMap, VersionMapDistHandle};
-use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
+use crate::{
+    Exclusions, ForkStrategy, Manifest, Options, PackageLocalVersionPreferences,
+    ResolverEnvironment,
+};
 
 #[derive(Debug, Clone)]
-#[expect(clippy::struct_field_names)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
+    package_resolution_strategy: FxHashMap<PackageName, ResolutionStrategy>,
     prerelease_strategy: PrereleaseStrategy,
+    package_prerelease_strategy: FxHashMap<PackageName, PrereleaseStrategy>,
+    local_version_preference: PackageLocalVersionPreferences,
     index_strategy: IndexStrategy,
+    fork_strategy: ForkStrategy,
 }
 
 impl CandidateSelector {
@@ -42,13 +49,45 @@ impl CandidateSelector {
                 env,
                 options.dependency_mode,
             ),
+            package_resolution_strategy: options
+                .resolution_package
+                .iter()
+                .map(|(name, mode)| {
+                    (
+                        name.clone(),
+                        ResolutionStrategy::from_mode(
+                            *mode,
+                            manifest,
+                            env,
+                            options.dependency_mode,
+                        ),
+                    )
+                })
+                .collect(),
             prerelease_strategy: PrereleaseStrategy::from_mode(
                 options.prerelease_mode,
                 manifest,
                 env,
                 options.dependency_mode,
             ),
+            package_prerelease_strategy: options
+                .prerelease_package
+                .iter()
+                .map(|(name, mode)| {
+                    (
+                        name.clone(),
+                        PrereleaseStrategy::from_mode(
+                            *mode,
+                            manifest,
+                            env,
+                            options.dependency_mode,
+                        ),
+                    )
+                })
+                .collect(),
+            local_version_preference: options.local_version_preference.clone(),
             index_strategy: options.index_strategy,
+            fork_strategy: options.fork_strategy,
         }
     }
 
@@ -58,12 +97,34 @@ impl CandidateSelector {
         &self.resolution_strategy
     }
 
+    /// Return the [`ResolutionStrategy`] for the given package, accounting for any
+    /// package-specific overrides.
+    pub(crate) fn resolution_strategy_for(
+        &self,
+        package_name: &PackageName,
+    ) -> &ResolutionStrategy {
+        self.package_resolution_strategy
+            .get(package_name)
+            .unwrap_or(&self.resolution_strategy)
+    }
+
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn prerelease_strategy(&self) -> &PrereleaseStrategy {
         &self.prerelease_strategy
     }
 
+    /// Return the [`PrereleaseStrategy`] for the given package, accounting for any
+    /// package-specific overrides.
+    pub(crate) fn prerelease_strategy_for(
+        &self,
+        package_name: &PackageName,
+    ) -> &PrereleaseStrategy {
+        self.package_prerelease_strategy
+            .get(package_name)
+            .unwrap_or(&self.prerelease_strategy)
+    }
+
     #[inline]
     #[allow(dead_code)]
     pub(crate) fn index_strategy(&self) -> &IndexStrategy {
@@ -189,7 +250,11 @@ impl CandidateSelector {
                 if index.is_some_and(|index| !entry.index().matches(index)) {
                     return None;
                 }
-                Either::Left(std::iter::once((entry.pin().version(), entry.source())))
+                Either::Left(std::iter::once((
+                    entry.pin().version(),
+                    entry.source(),
+                    entry.index(),
+                )))
             }
             [..] => {
                 type Entries<'a> = SmallVec<[&'a Entry; 3]>;
@@ -199,11 +264,25 @@ impl CandidateSelector {
                 // Filter out preferences that map to a conflicting index.
                 preferences.retain(|entry| index.is_none_or(|index| entry.index().matches(index)));
 
+                // Under `fewest-versions`, count the forks that pin each version.
+                let mut shared: FxHashMap<&Version, usize> = FxHashMap::default();
+                if self.fork_strategy == ForkStrategy::FewestVersions {
+                    for entry in &preferences {
+                        *shared.entry(entry.pin().version()).or_default() += 1;
+                    }
+                }
+
                 // Sort the preferences by priority.
                 let highest = self.use_highest_version(package_name, env);
                 preferences.sort_by_key(|entry| {
                     let marker = entry.marker();
 
+                    // Prefer versions that are shared by the most forks, if requested.
+                    let shared = shared
+                        .get(entry.pin().version())
+                        .copied()
+                        .unwrap_or_default();
+
                     // Prefer preferences that match the current environment.
                     let matches_env = env.included_by_marker(marker.pep508());
 
@@ -214,13 +293,13 @@ impl CandidateSelector {
                         Either::Right(std::cmp::Reverse(entry.pin().version()))
                     };
 
-                    std::cmp::Reverse((matches_env, version))
+                    std::cmp::Reverse((shared, matches_env, version))
                 });
 
                 Either::Right(
                     preferences
                         .into_iter()
-                        .map(|entry| (entry.pin().version(), entry.source())),
+                        .map(|entry| (entry.pin().version(), entry.source(), entry.index())),
                 )
             }
         };
@@ -240,7 +319,7 @@ impl CandidateSelector {
     /// Return the first preference that satisfies the current range and is allowed.
     fn get_preferred_from_iter<'a, InstalledPackages: InstalledPackagesProvider>(
         &'a self,
-        preferences: impl Iterator<Item = (&'a Version, PreferenceSource)>,
+        preferences: impl Iterator<Item = (&'a Version, PreferenceSource, &'a PreferenceIndex)>,
         package_name: &'a PackageName,
         range: &Range<Version>,
         version_maps: &'a [VersionMap],
@@ -249,7 +328,7 @@ impl CandidateSelector {
         env: &ResolverEnvironment,
         tags: Option<&Tags>,
     ) -> Option<Candidate<'a>> {
-        for (version, source) in preferences {
+        for (version, source, preference_index) in preferences {
             // Respect the version range for this requirement.
             if !range.contains(version) {
                 continue;
@@ -300,7 +379,10 @@ impl CandidateSelector {
 
             // Respect the pre-release strategy for this fork.
             if version.any_prerelease() {
-                let allow = match self.prerelease_strategy.allows(package_name, env) {
+                let allow = match self
+                    .prerelease_strategy_for(package_name)
+                    .allows(package_name, env)
+                {
                     AllowPrerelease::Yes => true,
                     AllowPrerelease::No => false,
                     // If the pre-release was provided via an existing file, rather than from the
@@ -317,11 +399,22 @@ impl CandidateSelector {
                 }
             }
 
-            // Check for a remote distribution that matches the preferred version
+            // Check for a remote distribution that matches the preferred version. If multiple
+            // indexes provide the version, prefer the index that the preference came from (e.g.,
+            // the index recorded in the lockfile), such that the selected files (and their hashes)
+            // are stable across resolutions; otherwise, fall back to index priority.
             if let Some((version_map, file)) = version_maps
                 .iter()
+                .filter(|version_map| {
+                    version_map
+                        .index()
+                        .is_some_and(|index| preference_index.is_explicit_match(index))
+                })
+                .chain(version_maps.iter())
                 .find_map(|version_map| version_map.get(version).map(|dist| (version_map, dist)))
             {
+                let version_maps = std::slice::from_ref(version_map);
+
                 // If the preferred version has a local variant, prefer that.
                 if version_map.local() {
                     for local in version_map
@@ -340,21 +433,24 @@ impl CandidateSelector {
                         }
                         if let Some(dist) = version_map.get(local) {
                             debug!("Preferring local version `{package_name}` (v{local})");
-                            return Some(Candidate::new(
-                                package_name,
-                                local,
-                                dist,
-                                VersionChoiceKind::Preference,
+                            return Some(self.prefer_local(
+                                Candidate::new(
+                                    package_name,
+                                    local,
+                                    dist,
+                                    VersionChoiceKind::Preference,
+                                ),
+                                range,
+                                version_maps,
                             ));
                         }
                     }
                 }
 
-                return Some(Candidate::new(
-                    package_name,
-                    version,
-                    file,
-                    VersionChoiceKind::Preference,
+                return Some(self.prefer_local(
+                    Candidate::new(package_name, version, file, VersionChoiceKind::Preference),
+                    range,
+                    version_maps,
                 ));
             }
         }
@@ -423,7 +519,10 @@ impl CandidateSelector {
         );
         let highest = self.use_highest_version(package_name, env);
 
-        let allow_prerelease = match self.prerelease_strategy.allows(package_name, env) {
+        let allow_prerelease = match self
+            .prerelease_strategy_for(package_name)
+            .allows(package_name, env)
+        {
             AllowPrerelease::Yes => true,
             AllowPrerelease::No => false,
             // Allow pre-releases if there are no stable versions available.
@@ -456,6 +555,7 @@ impl CandidateSelector {
                     range,
                     allow_prerelease,
                 )
+                .map(|candidate| self.prefer_local(candidate, range, version_maps))
             } else {
                 Self::select_candidate(
                     version_maps
@@ -478,6 +578,7 @@ impl CandidateSelector {
                     range,
                     allow_prerelease,
                 )
+                .map(|candidate| self.prefer_local(candidate, range, version_maps))
             }
         } else {
             if highest {
@@ -488,6 +589,9 @@ impl CandidateSelector {
                         range,
                         allow_prerelease,
                     )
+                    .map(|candidate| {
+                        self.prefer_local(candidate, range, std::slice::from_ref(version_map))
+                    })
                 })
             } else {
                 version_maps.iter().find_map(|version_map| {
@@ -497,11 +601,56 @@ impl CandidateSelector {
                         range,
                         allow_prerelease,
                     )
+                    .map(|candidate| {
+                        self.prefer_local(candidate, range, std::slice::from_ref(version_map))
+                    })
                 })
             }
         }
     }
 
+    /// If the package has a preferred local version label (e.g., `+cu121`), replace the candidate
+    /// with the build of the same public version that has that label, if it satisfies the range
+    /// and is compatible.
+    ///
+    /// The preference only breaks ties between builds of the same public version (e.g., `2.3.0`,
+    /// `2.3.0+cpu`, and `2.3.0+cu121`), so a newer version without the preferred label is still
+    /// selected over an older version with it.
+    fn prefer_local<'a>(
+        &self,
+        candidate: Candidate<'a>,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+    ) -> Candidate<'a> {
+        let Some(preference) = self.local_version_preference.get(candidate.name) else {
+            return candidate;
+        };
+        if preference.matches(candidate.version) {
+            return candidate;
+        }
+        let preferred = preference.apply(candidate.version);
+        if !range.contains(&preferred) {
+            return candidate;
+        }
+        let preferred = Range::singleton(preferred);
+        for version_map in version_maps {
+            for (version, dist) in version_map.iter(&preferred) {
+                let Some(dist) = dist.prioritized_dist() else {
+                    continue;
+                };
+                let local = Candidate::new(candidate.name, version, dist, candidate.choice_kind);
+                if matches!(local.dist(), CandidateDist::Compatible(_)) {
+                    debug!(
+                        "Preferring local version `{}` (v{version}) over v{}",
+                        candidate.name, candidate.version
+                    );
+                    return local;
+                }
+            }
+        }
+        candidate
+    }
+
     /// By default, we select the latest version, but we also allow using the lowest version instead
     /// to check the lower bounds.
     pub(crate) fn use_highest_version(
@@ -509,7 +658,7 @@ impl CandidateSelector {
         package_name: &PackageName,
         env: &ResolverEnvironment,
     ) -> bool {
-        match &self.resolution_strategy {
+        match self.resolution_strategy_for(package_name) {
             ResolutionStrategy::Highest => true,
             ResolutionStrategy::Lowest => false,
             ResolutionStrategy::LowestDirect(direct_dependencies) => {
@@ -770,3 +919,89 @@ impl DistributionMetadata for Candidate<'_> {
         uv_distribution_types::VersionOrUrlRef::Version(self.version)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+
+    use crate::prerelease::AllowPrerelease;
+    use crate::{
+        Manifest, OptionsBuilder, PackagePrereleaseModes, PackageResolutionModes, PrereleaseMode,
+        ResolutionMode, ResolverEnvironment,
+    };
+
+    use super::CandidateSelector;
+
+    fn candidate_selector(
+        mode: ResolutionMode,
+        package: &[(&str, ResolutionMode)],
+    ) -> CandidateSelector {
+        let options = OptionsBuilder::new()
+            .resolution_mode(mode)
+            .resolution_package(
+                package
+                    .iter()
+                    .map(|(name, mode)| (PackageName::from_str(name).unwrap(), *mode))
+                    .collect::<PackageResolutionModes>(),
+            )
+            .build();
+        CandidateSelector::for_resolution(
+            &options,
+            &Manifest::simple(vec![]),
+            &ResolverEnvironment::universal(vec![]),
+        )
+    }
+
+    #[test]
+    fn resolution_package_overrides_mode() {
+        let env = ResolverEnvironment::universal(vec![]);
+        let foo = PackageName::from_str("foo").unwrap();
+        let bar = PackageName::from_str("bar").unwrap();
+
+        // Without overrides, every package uses the global mode.
+        let selector = candidate_selector(ResolutionMode::Highest, &[]);
+        assert!(selector.use_highest_version(&foo, &env));
+        assert!(selector.use_highest_version(&bar, &env));
+
+        // Only the overridden package uses the lowest compatible version.
+        let selector =
+            candidate_selector(ResolutionMode::Highest, &[("foo", ResolutionMode::Lowest)]);
+        assert!(!selector.use_highest_version(&foo, &env));
+        assert!(selector.use_highest_version(&bar, &env));
+
+        // And vice versa.
+        let selector =
+            candidate_selector(ResolutionMode::Lowest, &[("foo", ResolutionMode::Highest)]);
+        assert!(selector.use_highest_version(&foo, &env));
+        assert!(!selector.use_highest_version(&bar, &env));
+    }
+
+    #[test]
+    fn prerelease_package_overrides_mode() {
+        let env = ResolverEnvironment::universal(vec![]);
+        let foo = PackageName::from_str("foo").unwrap();
+        let bar = PackageName::from_str("bar").unwrap();
+
+        let options = OptionsBuilder::new()
+            .prerelease_mode(PrereleaseMode::IfNecessary)
+            .prerelease_package(
+                [(foo.clone(), PrereleaseMode::Allow)]
+                    .into_iter()
+                    .collect::<PackagePrereleaseModes>(),
+            )
+            .build();
+        let selector = CandidateSelector::for_resolution(&options, &Manifest::simple(vec![]), &env);
+
+        // Only the overridden package accepts pre-releases unconditionally.
+        assert_eq!(
+            selector.prerelease_strategy_for(&foo).allows(&foo, &env),
+            AllowPrerelease::Yes
+        );
+        assert_eq!(
+            selector.prerelease_strategy_for(&bar).allows(&bar, &env),
+            AllowPrerelease::IfNecessary
+        );
+    }
+}
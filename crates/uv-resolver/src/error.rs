@@ -1,5 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet, Bound};
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Formatter, Write};
 use std::ops::Deref;
 use std::sync::{Arc, OnceLock};
 
@@ -10,12 +10,14 @@ use pubgrub::{DerivationTree, Derived, External, Map, Range, Ranges, Term};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::trace;
 
+use uv_distribution::MetadataBuild;
 use uv_distribution_types::{
     DerivationChain, DistErrorKind, IndexCapabilities, IndexLocations, IndexUrl, RequestedDist,
+    RequiresPython, SourceBuildReason,
 };
-use uv_normalize::{ExtraName, InvalidNameError, PackageName};
-use uv_pep440::{LocalVersionSlice, LowerBound, Version};
-use uv_pep508::MarkerEnvironment;
+use uv_normalize::{ExtraName, GroupName, InvalidNameError, PackageName};
+use uv_pep440::{LocalVersionSlice, LowerBound, Version, VersionSpecifiers};
+use uv_pep508::{MarkerEnvironment, MarkerTree};
 use uv_platform_tags::Tags;
 use uv_pypi_types::ParsedUrl;
 use uv_redacted::DisplaySafeUrl;
@@ -35,7 +37,7 @@ use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
     MetadataUnavailable, ResolverEnvironment, UnavailablePackage, UnavailableReason,
 };
-use crate::{InMemoryIndex, Options};
+use crate::{InMemoryIndex, MissingMetadataError, Options, Relaxation};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ResolveError {
@@ -93,6 +95,23 @@ pub enum ResolveError {
     #[error("Requirements contain conflicting indexes for package `{0}`: `{1}` vs. `{2}`")]
     ConflictingIndexes(PackageName, String, String),
 
+    #[error(
+        "Requirements contain conflicting sources for package `{package_name}`{}: it is requested from both a direct URL and an explicit index:\n- {}\n- {}",
+        if env.marker_environment().is_some() {
+            String::new()
+        } else {
+            format!(" in {env}")
+        },
+        DisplaySafeUrl::from(url.as_ref().clone()),
+        index,
+    )]
+    ConflictingSources {
+        package_name: PackageName,
+        url: Box<ParsedUrl>,
+        index: IndexUrl,
+        env: ResolverEnvironment,
+    },
+
     #[error(
         "Package `{name}` was included as a URL dependency. URL dependencies must be expressed as direct requirements or constraints. Consider adding `{requirement}` to your dependencies or constraints file.",
         name = name.cyan(),
@@ -108,9 +127,13 @@ pub enum ResolveError {
         DistErrorKind,
         Box<RequestedDist>,
         DerivationChain,
+        Option<SourceBuildReason>,
         #[source] Arc<uv_distribution::Error>,
     ),
 
+    #[error("Failed to build {} distributions", _0.len())]
+    Multiple(Vec<Self>),
+
     #[error(transparent)]
     NoSolution(#[from] Box<NoSolutionError>),
 
@@ -128,6 +151,9 @@ pub enum ResolveError {
     #[error("Package `{0}` is unavailable")]
     PackageUnavailable(PackageName),
 
+    #[error(transparent)]
+    NarrowedRequiresPython(Box<NarrowedRequiresPythonError>),
+
     #[error("Invalid extra value in conflict marker: {reason}: {raw_extra}")]
     InvalidExtraInConflictMarker {
         reason: String,
@@ -140,6 +166,15 @@ pub enum ResolveError {
         #[source]
         name_error: InvalidNameError,
     },
+    #[error(transparent)]
+    MetadataBuildLimit(Box<MetadataBuildLimitError>),
+
+    #[error(transparent)]
+    ResolutionEffort(Box<ResolutionEffortError>),
+
+    #[error(transparent)]
+    MissingMetadata(#[from] MissingMetadataError),
+
     #[error(
         "The index returned metadata for the wrong package: expected {request} for {expected}, got {request} for {actual}"
     )]
@@ -154,11 +189,191 @@ impl uv_errors::Hint for ResolveError {
     fn hints(&self) -> uv_errors::Hints<'_> {
         match self {
             Self::NoSolution(no_solution) => uv_errors::Hint::hints(no_solution.as_ref()),
+            Self::ConflictingSources { package_name, .. } => uv_errors::Hints::from(format!(
+                "A package can be installed from a direct URL or from an index, but not both in the same environment; use a single source for `{}` across the workspace",
+                package_name.cyan(),
+            )),
+            Self::NarrowedRequiresPython(err) => uv_errors::Hint::hints(err.as_ref()),
+            Self::MetadataBuildLimit(err) => uv_errors::Hint::hints(err.as_ref()),
+            Self::ResolutionEffort(err) => uv_errors::Hint::hints(err.as_ref()),
+            Self::MissingMetadata(err) => uv_errors::Hint::hints(err),
             _ => uv_errors::Hints::none(),
         }
     }
 }
 
+/// A distribution in the resolution doesn't support the full `Requires-Python` range, under
+/// `--requires-python-strategy strict`.
+#[derive(Debug)]
+pub struct NarrowedRequiresPythonError {
+    /// The package and version of the distribution.
+    pub name: PackageName,
+    pub version: Version,
+    /// The `Requires-Python` specifiers of the distribution.
+    pub requires_python: VersionSpecifiers,
+    /// The `Requires-Python` range of the resolution.
+    pub target: RequiresPython,
+    /// The path from the root to the distribution.
+    pub chain: DerivationChain,
+}
+
+impl std::fmt::Display for NarrowedRequiresPythonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` requires Python `{}`, which does not cover the full supported Python range (`{}`)",
+            format!("{}=={}", self.name, self.version).cyan(),
+            self.requires_python,
+            self.target,
+        )
+    }
+}
+
+impl std::error::Error for NarrowedRequiresPythonError {}
+
+impl uv_errors::Hint for NarrowedRequiresPythonError {
+    fn hints(&self) -> uv_errors::Hints<'_> {
+        let strategy = "--requires-python-strategy narrow".green();
+        if let Some(narrowed) = RequiresPython::intersection(
+            [self.target.specifiers(), &self.requires_python].into_iter(),
+        ) {
+            uv_errors::Hints::from(format!(
+                "Consider narrowing `requires-python` to `{}`, or use `{strategy}` to allow dependencies to narrow the supported Python range",
+                narrowed.cyan(),
+            ))
+        } else {
+            uv_errors::Hints::from(format!(
+                "Use `{strategy}` to allow dependencies to narrow the supported Python range"
+            ))
+        }
+    }
+}
+
+/// The resolution required building more source distributions to determine their metadata than
+/// allowed by `--max-metadata-builds`.
+#[derive(Debug)]
+pub struct MetadataBuildLimitError {
+    /// The maximum number of metadata builds.
+    pub(crate) limit: usize,
+    /// The package and version whose build would have exceeded the limit.
+    pub(crate) package: PackageName,
+    pub(crate) version: Version,
+    /// The builds of the versions the resolver had selected when it stopped.
+    pub(crate) selected: Vec<MetadataBuild>,
+    /// The builds of versions that the resolver tried and abandoned (or prefetched) while
+    /// backtracking.
+    pub(crate) backtracking: Vec<MetadataBuild>,
+}
+
+impl std::fmt::Display for MetadataBuildLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Resolution required building more than {} source distribution{} to determine their metadata (while building `{}`)",
+            self.limit,
+            if self.limit == 1 { "" } else { "s" },
+            format!("{}=={}", self.package, self.version).cyan(),
+        )?;
+        for (header, builds) in [
+            ("Builds for the selected versions:", &self.selected),
+            ("Builds triggered by backtracking:", &self.backtracking),
+        ] {
+            if builds.is_empty() {
+                continue;
+            }
+            write!(f, "\n\n  {header}")?;
+            for build in builds {
+                write!(f, "\n    {}=={}", build.name, build.version)?;
+                match build.duration {
+                    Some(duration) => write!(f, " ({:.2}s)", duration.as_secs_f64())?,
+                    None => write!(f, " (incomplete)")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MetadataBuildLimitError {}
+
+impl uv_errors::Hint for MetadataBuildLimitError {
+    fn hints(&self) -> uv_errors::Hints<'_> {
+        let mut hints = Vec::new();
+        if !self.backtracking.is_empty() {
+            hints.push(format!(
+                "Pin the packages built while backtracking (e.g., `{}`) to avoid building versions that the resolver would reject",
+                self.backtracking
+                    .iter()
+                    .map(|build| build.name.as_str())
+                    .unique()
+                    .join("`, `")
+                    .cyan(),
+            ));
+        }
+        hints.push(format!(
+            "Use `{}` to disallow building specific packages, or raise the limit with `{}`",
+            "--no-build-package".green(),
+            "--max-metadata-builds".green(),
+        ));
+        hints.into_iter().collect()
+    }
+}
+
+/// The resolver made more decisions than allowed by `--resolution-effort`.
+#[derive(Debug)]
+pub struct ResolutionEffortError {
+    /// The maximum number of resolver steps.
+    pub(crate) limit: usize,
+    /// The packages involved in the most conflicts, along with their conflict counts, in
+    /// descending order.
+    pub(crate) conflicts: Vec<(PackageName, usize)>,
+}
+
+impl std::fmt::Display for ResolutionEffortError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Resolution exceeded the maximum of {} resolver step{}",
+            self.limit,
+            if self.limit == 1 { "" } else { "s" },
+        )?;
+        if !self.conflicts.is_empty() {
+            write!(f, "\n\n  Packages that caused the most backtracking:")?;
+            for (name, count) in &self.conflicts {
+                write!(
+                    f,
+                    "\n    {name} ({count} conflict{})",
+                    if *count == 1 { "" } else { "s" }
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ResolutionEffortError {}
+
+impl uv_errors::Hint for ResolutionEffortError {
+    fn hints(&self) -> uv_errors::Hints<'_> {
+        let mut hints = Vec::new();
+        if !self.conflicts.is_empty() {
+            hints.push(format!(
+                "Add constraints for the packages that caused the most backtracking (e.g., `{}`) to narrow the search",
+                self.conflicts
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .join("`, `")
+                    .cyan(),
+            ));
+        }
+        hints.push(format!(
+            "Raise the limit with `{}`",
+            "--resolution-effort".green(),
+        ));
+        hints.into_iter().collect()
+    }
+}
+
 impl<T> From<tokio::sync::mpsc::error::SendError<T>> for ResolveError {
     /// Drop the value we want to send to not leak the private type we're sending.
     /// The tokio error only says "channel closed", so we don't lose information.
@@ -426,6 +641,9 @@ pub struct NoSolutionError {
     tags: Option<Tags>,
     workspace_members: BTreeSet<PackageName>,
     options: Options,
+    /// Relaxations of the direct requirements that would allow resolution to succeed (e.g.,
+    /// `--hint-on-failure`).
+    relaxations: Vec<Relaxation>,
     /// Cached report and hints, computed once on first access.
     cached: OnceLock<(String, IndexSet<PubGrubHint>)>,
 }
@@ -471,10 +689,17 @@ impl NoSolutionError {
             tags,
             workspace_members,
             options,
+            relaxations: Vec::new(),
             cached: OnceLock::new(),
         }
     }
 
+    /// Set the relaxations of the direct requirements that would allow resolution to succeed,
+    /// which are rendered as hints.
+    pub fn set_relaxations(&mut self, relaxations: Vec<Relaxation>) {
+        self.relaxations = relaxations;
+    }
+
     /// Get the cached report and hints, computing them on first access.
     fn cached(&self) -> &(String, IndexSet<PubGrubHint>) {
         self.cached.get_or_init(|| self.compute_report_and_hints())
@@ -677,7 +902,11 @@ impl NoSolutionError {
             display_tree(&tree, "Resolver derivation tree after reduction");
         }
 
-        let report = report_derivation_tree(&tree, &formatter);
+        let mut report = report_derivation_tree(&tree, &formatter);
+        if let Some(chains) = format_dependency_chains(&tree, &self.workspace_members) {
+            report.push_str("\n\n");
+            report.push_str(&chains);
+        }
 
         let inherited_exclude_newer_ranges = FxHashMap::default();
         let mut hints = IndexSet::default();
@@ -727,6 +956,7 @@ impl std::fmt::Debug for NoSolutionError {
             tags,
             workspace_members,
             options,
+            relaxations,
             cached: _,
         } = self;
         f.debug_struct("NoSolutionError")
@@ -747,6 +977,7 @@ impl std::fmt::Debug for NoSolutionError {
             .field("tags", tags)
             .field("workspace_members", workspace_members)
             .field("options", options)
+            .field("relaxations", relaxations)
             .finish()
     }
 }
@@ -758,6 +989,7 @@ impl uv_errors::Hint for NoSolutionError {
         self.pubgrub_hints()
             .iter()
             .map(ToString::to_string)
+            .chain(self.relaxations.iter().map(ToString::to_string))
             .collect()
     }
 }
@@ -855,6 +1087,214 @@ fn display_tree_inner(
     }
 }
 
+/// A package in a dependency chain, ignoring markers and the proxy kind.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct ChainNode {
+    name: PackageName,
+    extra: Option<ExtraName>,
+    group: Option<GroupName>,
+}
+
+impl ChainNode {
+    /// Returns `None` for the root package, along with any non-Python packages.
+    fn from_package(package: &PubGrubPackage) -> Option<Self> {
+        match &**package {
+            PubGrubPackageInner::Root(_)
+            | PubGrubPackageInner::Python(_)
+            | PubGrubPackageInner::System(_) => None,
+            PubGrubPackageInner::Package {
+                name, extra, group, ..
+            } => Some(Self {
+                name: name.clone(),
+                extra: extra.clone(),
+                group: group.clone(),
+            }),
+            PubGrubPackageInner::Extra { name, extra, .. } => Some(Self {
+                name: name.clone(),
+                extra: Some(extra.clone()),
+                group: None,
+            }),
+            PubGrubPackageInner::Group { name, group, .. } => Some(Self {
+                name: name.clone(),
+                extra: None,
+                group: Some(group.clone()),
+            }),
+            PubGrubPackageInner::Marker { name, .. } => Some(Self {
+                name: name.clone(),
+                extra: None,
+                group: None,
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for ChainNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(extra) = &self.extra {
+            write!(f, "{}[{extra}]", self.name)
+        } else if let Some(group) = &self.group {
+            write!(f, "{}:{group}", self.name)
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
+}
+
+/// A dependency edge in a derivation tree, where a `requirer` of `None` is the root package.
+#[derive(Debug, PartialEq)]
+struct ChainEdge {
+    requirer: Option<ChainNode>,
+    dependency: ChainNode,
+    range: Range<Version>,
+    marker: MarkerTree,
+}
+
+/// Describe how the conflicting requirements in a derivation tree were introduced, by tracing
+/// each requirement back to the root (e.g., `a -> b -> c -> foo>=2`).
+///
+/// A dependency is considered conflicting if it's required by multiple packages, and the
+/// requirements are disjoint. Returns `None` unless at least one of the requirements was
+/// introduced transitively (i.e., through a package other than the root or a workspace member),
+/// since the report itself already covers direct dependencies.
+fn format_dependency_chains(
+    tree: &ErrorTree,
+    workspace_members: &BTreeSet<PackageName>,
+) -> Option<String> {
+    /// The maximum number of packages to display in a chain before eliding the middle.
+    const MAX_CHAIN_LENGTH: usize = 6;
+
+    let mut edges = Vec::new();
+    let mut trees = vec![tree];
+    while let Some(tree) = trees.pop() {
+        match tree {
+            DerivationTree::External(External::FromDependencyOf(package, _, dependency, range)) => {
+                if matches!(
+                    &**package,
+                    PubGrubPackageInner::Python(_) | PubGrubPackageInner::System(_)
+                ) {
+                    continue;
+                }
+                let Some(node) = ChainNode::from_package(dependency) else {
+                    continue;
+                };
+                let edge = ChainEdge {
+                    requirer: ChainNode::from_package(package),
+                    dependency: node,
+                    range: range.clone(),
+                    marker: dependency.marker(),
+                };
+                if !edges.contains(&edge) {
+                    edges.push(edge);
+                }
+            }
+            DerivationTree::External(_) => {}
+            DerivationTree::Derived(derived) => {
+                trees.push(&derived.cause2);
+                trees.push(&derived.cause1);
+            }
+        }
+    }
+
+    // Index the edges by dependency, to walk from each requirement back to the root.
+    let mut requirers: BTreeMap<&ChainNode, Vec<&ChainEdge>> = BTreeMap::new();
+    for edge in &edges {
+        requirers.entry(&edge.dependency).or_default().push(edge);
+    }
+
+    // Returns the shortest chain of packages leading to the given requirer, along with the
+    // markers under which the chain applies.
+    let chain = |requirer: &ChainNode| -> (Vec<ChainNode>, MarkerTree) {
+        let mut next: FxHashMap<&ChainNode, &ChainEdge> = FxHashMap::default();
+        let mut queue = std::collections::VecDeque::from([requirer]);
+        let mut visited = FxHashSet::from_iter([requirer]);
+        while let Some(node) = queue.pop_front() {
+            let incoming = requirers.get(node).map(Vec::as_slice).unwrap_or_default();
+            let root = incoming.iter().find(|edge| edge.requirer.is_none());
+            if incoming.is_empty() || root.is_some() {
+                let mut marker = root.map(|edge| edge.marker).unwrap_or(MarkerTree::TRUE);
+                let mut nodes = vec![node.clone()];
+                let mut current = node;
+                while let Some(edge) = next.get(current) {
+                    marker.and(edge.marker);
+                    current = &edge.dependency;
+                    nodes.push(current.clone());
+                }
+                return (nodes, marker);
+            }
+            for edge in incoming {
+                let Some(parent) = edge.requirer.as_ref() else {
+                    continue;
+                };
+                if visited.insert(parent) {
+                    next.insert(parent, edge);
+                    queue.push_back(parent);
+                }
+            }
+        }
+        (vec![requirer.clone()], MarkerTree::TRUE)
+    };
+
+    let mut sections = Vec::new();
+    let mut transitive = false;
+    for (dependency, incoming) in &requirers {
+        let distinct = incoming.iter().map(|edge| &edge.requirer).unique().count();
+        if distinct < 2 {
+            continue;
+        }
+        let intersection = incoming
+            .iter()
+            .fold(Range::full(), |range, edge| range.intersection(&edge.range));
+        if !intersection.is_empty() {
+            continue;
+        }
+
+        let mut lines = Vec::new();
+        for edge in incoming {
+            let (nodes, mut marker) = match &edge.requirer {
+                Some(requirer) => chain(requirer),
+                None => (Vec::new(), MarkerTree::TRUE),
+            };
+            marker.and(edge.marker);
+            transitive |= nodes
+                .iter()
+                .filter(|node| !workspace_members.contains(&node.name))
+                .count()
+                > 1;
+
+            let mut line = if nodes.len() > MAX_CHAIN_LENGTH {
+                format!(
+                    "{} -> ({} more) -> {}",
+                    nodes[..2].iter().join(" -> "),
+                    nodes.len() - 4,
+                    nodes[nodes.len() - 2..].iter().join(" -> ")
+                )
+            } else {
+                nodes.iter().join(" -> ")
+            };
+            if !line.is_empty() {
+                line.push_str(" -> ");
+            }
+            let specifiers = VersionSpecifiers::from_release_only_bounds(edge.range.iter());
+            write!(line, "{dependency}{specifiers}").ok();
+            if let Some(marker) = marker.contents() {
+                write!(line, " (only when `{marker}`)").ok();
+            }
+            lines.push(format!("    {line}"));
+        }
+        lines.sort();
+        lines.dedup();
+        sections.push(format!(
+            "The conflicting requirements on {dependency} were introduced by:\n{}",
+            lines.join("\n")
+        ));
+    }
+
+    if !transitive {
+        return None;
+    }
+    Some(sections.join("\n\n"))
+}
+
 fn can_drop_no_versions(
     package: &PubGrubPackage,
     versions: &Range<Version>,
@@ -1621,7 +2061,7 @@ fn simplify_range(
 
     // Check if pre-releases are allowed
     let prereleases_not_allowed = candidate_selector
-        .prerelease_strategy()
+        .prerelease_strategy_for(name)
         .allows(name, resolver_environment)
         != AllowPrerelease::Yes;
 
@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Bound;
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use pubgrub::Range;
+
+use uv_distribution_types::Requirement;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::{LocalVersionSlice, Version};
+
+/// An explanation of why a version of a package was selected (or why the package is absent from
+/// the resolution), as requested with `--explain`.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    /// The package to explain.
+    pub(crate) name: PackageName,
+    /// The selected version of the package, if it's part of the resolution.
+    pub(crate) version: Option<Version>,
+    /// A description of the fork in which the explanation applies, if the resolution forked.
+    pub(crate) fork: Option<String>,
+    /// The requirements on the package that were encountered during resolution.
+    pub(crate) requirements: Vec<ExplainedRequirement>,
+    /// The user-provided constraints on the package (e.g., `--constraint`).
+    pub(crate) constraints: Vec<Requirement>,
+    /// The user-provided overrides for the package (e.g., `--override`).
+    pub(crate) overrides: Vec<Requirement>,
+    /// The versions that satisfied the requirements, but were removed from consideration.
+    pub(crate) filtered: BTreeMap<FilterReason, Vec<Version>>,
+}
+
+/// A requirement on the explained package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExplainedRequirement {
+    /// The package that declared the requirement.
+    pub(crate) requirer: Requirer,
+    /// The versions of the explained package allowed by the requirement.
+    pub(crate) range: Range<Version>,
+}
+
+/// The origin of a requirement on the explained package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Requirer {
+    /// The root requirements (e.g., the requirements provided to `uv pip compile`).
+    Root,
+    /// A package that depends on the explained package.
+    Package {
+        name: PackageName,
+        extra: Option<ExtraName>,
+        group: Option<GroupName>,
+        /// The versions of the requiring package that declare the requirement.
+        versions: Range<Version>,
+        /// The version of the requiring package in the resolution, if any.
+        selected: Option<Version>,
+    },
+}
+
+impl Requirer {
+    /// Returns `true` if the requirement was declared by a selected version of its requirer, such
+    /// that it applies to the resolution.
+    pub(crate) fn is_active(&self) -> bool {
+        match self {
+            Self::Root => true,
+            Self::Package {
+                versions, selected, ..
+            } => selected
+                .as_ref()
+                .is_some_and(|selected| versions.contains(selected)),
+        }
+    }
+}
+
+/// The reason that a version was removed from consideration during resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum FilterReason {
+    /// The version is a pre-release, and pre-releases were not allowed.
+    Prerelease,
+    /// All distributions for the version were yanked.
+    Yanked,
+    /// All distributions for the version were published after the `--exclude-newer` cutoff.
+    ExcludeNewer,
+}
+
+impl Explanation {
+    /// Return the name of the explained package.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// Return the selected version of the explained package, if it's part of the resolution.
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let fork = self
+            .fork
+            .as_ref()
+            .map(|fork| format!(" for {fork}"))
+            .unwrap_or_default();
+
+        let (active, inactive): (Vec<_>, Vec<_>) = self
+            .requirements
+            .iter()
+            .partition(|requirement| requirement.requirer.is_active());
+
+        if let Some(version) = &self.version {
+            write!(
+                f,
+                "{}{}",
+                format!("{}=={version}", self.name).bold(),
+                format!(" was selected{fork} because:").bold()
+            )?;
+            for requirement in &active {
+                write!(f, "\n  - {}", DisplayRequirement(&self.name, requirement))?;
+            }
+            if !inactive.is_empty() {
+                write!(
+                    f,
+                    "\n  - it is also required by versions that were not selected:"
+                )?;
+                for requirement in &inactive {
+                    write!(f, "\n    - {}", DisplayRequirement(&self.name, requirement))?;
+                }
+            }
+        } else {
+            write!(
+                f,
+                "{}{}",
+                self.name.bold(),
+                format!(" is not in the resolution{fork} because:").bold()
+            )?;
+            if inactive.is_empty() {
+                write!(f, "\n  - no package in the resolution requires it")?;
+            } else {
+                write!(
+                    f,
+                    "\n  - it is only required by versions that were not selected:"
+                )?;
+                for requirement in &inactive {
+                    write!(f, "\n    - {}", DisplayRequirement(&self.name, requirement))?;
+                }
+            }
+        }
+
+        for constraint in &self.constraints {
+            write!(f, "\n  - a constraint requires {}", constraint.cyan())?;
+        }
+        for r#override in &self.overrides {
+            write!(
+                f,
+                "\n  - an override replaces it with {}",
+                r#override.cyan()
+            )?;
+        }
+
+        for (reason, versions) in &self.filtered {
+            let (singular, plural) = match reason {
+                FilterReason::Prerelease => (
+                    "pre-release version was skipped",
+                    "pre-release versions were skipped",
+                ),
+                FilterReason::Yanked => {
+                    ("yanked version was skipped", "yanked versions were skipped")
+                }
+                FilterReason::ExcludeNewer => (
+                    "version was excluded by `--exclude-newer`",
+                    "versions were excluded by `--exclude-newer`",
+                ),
+            };
+            write!(
+                f,
+                "\n  - {} {}: {}",
+                versions.len(),
+                if versions.len() == 1 {
+                    singular
+                } else {
+                    plural
+                },
+                DisplayVersions(versions)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Display a requirement on the explained package, along with its requirer.
+struct DisplayRequirement<'a>(&'a PackageName, &'a ExplainedRequirement);
+
+impl Display for DisplayRequirement<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self(name, ExplainedRequirement { requirer, range }) = self;
+        let dependency = format!("{name}{}", DisplayRange(range));
+        match requirer {
+            Requirer::Root => write!(f, "your requirements require {}", dependency.cyan()),
+            Requirer::Package {
+                name,
+                extra,
+                group,
+                versions,
+                selected,
+            } => {
+                let name = if let Some(extra) = extra {
+                    format!("{name}[{extra}]")
+                } else if let Some(group) = group {
+                    format!("{name}:{group}")
+                } else {
+                    name.to_string()
+                };
+                match selected {
+                    Some(selected) if versions.contains(selected) => write!(
+                        f,
+                        "{} requires {}",
+                        format!("{name}=={selected}").cyan(),
+                        dependency.cyan()
+                    ),
+                    Some(selected) => write!(
+                        f,
+                        "{} requires {}, but {} was selected",
+                        format!("{name}{}", DisplayRange(versions)).cyan(),
+                        dependency.cyan(),
+                        format!("{name}=={selected}").cyan()
+                    ),
+                    None => write!(
+                        f,
+                        "{} requires {}, but {name} is not in the resolution",
+                        format!("{name}{}", DisplayRange(versions)).cyan(),
+                        dependency.cyan()
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Display a list of versions, truncating long lists.
+struct DisplayVersions<'a>(&'a [Version]);
+
+impl Display for DisplayVersions<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const LIMIT: usize = 5;
+        write!(f, "{}", self.0.iter().take(LIMIT).join(", "))?;
+        if self.0.len() > LIMIT {
+            write!(f, ", and {} more", self.0.len() - LIMIT)?;
+        }
+        Ok(())
+    }
+}
+
+/// Display a version range as PEP 440 specifiers, omitting the internal sentinel versions used to
+/// represent exclusive bounds and local versions.
+struct DisplayRange<'a>(&'a Range<Version>);
+
+impl Display for DisplayRange<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let segments = self.0.iter().collect::<Vec<_>>();
+        let Some(((start, _), (_, end))) = segments.first().zip(segments.last()) else {
+            return write!(f, " (no versions)");
+        };
+
+        // If every gap between segments excludes a single version, render the range as bounds
+        // with `!=` exclusions (e.g., `>=1.0, !=1.5, <2.0`); otherwise, render each segment.
+        let exclusions = segments
+            .iter()
+            .tuple_windows()
+            .map(|((_, upper), (lower, _))| match (upper, lower) {
+                (Bound::Excluded(upper), Bound::Included(lower) | Bound::Excluded(lower))
+                    if matches!(lower.local(), LocalVersionSlice::Max)
+                        && without_local_max(lower) == *upper =>
+                {
+                    Some(upper)
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        if let Some(exclusions) = exclusions {
+            let mut specifiers = DisplaySegment(start, end).specifiers();
+            let upper = if matches!(end, Bound::Unbounded) {
+                None
+            } else {
+                specifiers.pop()
+            };
+            specifiers.extend(exclusions.into_iter().map(|version| format!("!={version}")));
+            specifiers.extend(upper);
+            write!(f, "{}", specifiers.join(", "))
+        } else {
+            write!(
+                f,
+                "{}",
+                segments
+                    .iter()
+                    .map(|(lower, upper)| DisplaySegment(lower, upper).specifiers().join(", "))
+                    .join(" || ")
+            )
+        }
+    }
+}
+
+/// Display a single contiguous segment of a version range.
+struct DisplaySegment<'a>(&'a Bound<Version>, &'a Bound<Version>);
+
+impl DisplaySegment<'_> {
+    fn specifiers(&self) -> Vec<String> {
+        match (self.0, self.1) {
+            // Ex) `==1.0`, which includes `1.0+local`.
+            (Bound::Included(lower), Bound::Included(upper) | Bound::Excluded(upper))
+                if without_local_max(upper) == *lower =>
+            {
+                vec![format!("=={lower}")]
+            }
+            (lower, upper) => {
+                let mut specifiers = Vec::new();
+                match lower {
+                    Bound::Included(version) => specifiers.push(format!(">={version}")),
+                    Bound::Excluded(version) => specifiers.push(format!(">{version}")),
+                    Bound::Unbounded => {}
+                }
+                match upper {
+                    Bound::Included(version) => {
+                        specifiers.push(format!("<={}", without_local_max(version)));
+                    }
+                    Bound::Excluded(version) => specifiers.push(format!("<{version}")),
+                    Bound::Unbounded => {}
+                }
+                specifiers
+            }
+        }
+    }
+}
+
+/// Strip the internal "max" local version (e.g., from `<=1.0`, which includes `1.0+local`).
+fn without_local_max(version: &Version) -> Version {
+    if matches!(version.local(), LocalVersionSlice::Max) {
+        version.clone().without_local()
+    } else {
+        version.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::VersionSpecifiers;
+
+    use super::*;
+
+    fn display(specifiers: &str) -> String {
+        let range = Range::from(VersionSpecifiers::from_str(specifiers).unwrap());
+        DisplayRange(&range).to_string()
+    }
+
+    #[test]
+    fn display_range() {
+        assert_eq!(display(">=1.21.1,<3"), ">=1.21.1, <3");
+        assert_eq!(display("==1.26.18"), "==1.26.18");
+        assert_eq!(display("<=2.0"), "<=2.0");
+        assert_eq!(display(">1.0"), ">1.0");
+        assert_eq!(display(">=1.0,!=1.5,<2"), ">=1.0, !=1.5, <2");
+        assert_eq!(display(""), "");
+    }
+}
@@ -8,8 +8,9 @@ use uv_client::{FlatIndexEntries, FlatIndexEntry};
 use uv_configuration::BuildOptions;
 use uv_distribution_filename::{DistFilename, SourceDistFilename, WheelFilename};
 use uv_distribution_types::{
-    File, HashComparison, IncompatibleSource, IncompatibleWheel, IndexUrl, PrioritizedDist,
-    RegistryBuiltWheel, RegistrySourceDist, SourceDistCompatibility, WheelCompatibility,
+    File, HashComparison, IncompatibleSource, IncompatibleWheel, IndexArtifactPolicy,
+    IndexLocations, IndexUrl, PrioritizedDist, RegistryBuiltWheel, RegistrySourceDist,
+    SourceDistCompatibility, WheelCompatibility,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -30,12 +31,16 @@ pub struct FlatIndex {
 
 impl FlatIndex {
     /// Collect all files from a `--find-links` target into a [`FlatIndex`].
+    ///
+    /// The artifact policy of each entry (e.g., `allow-sdist = false`) is determined by the
+    /// [`IndexLocations`].
     #[instrument(skip_all)]
     pub fn from_entries(
         entries: FlatIndexEntries,
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        index_locations: &IndexLocations,
     ) -> Self {
         // Collect compatible distributions.
         let mut index = FxHashMap::<PackageName, FlatDistributions>::default();
@@ -43,8 +48,17 @@ impl FlatIndex {
 
         for entry in entries {
             let (filename, file, index_url) = entry.into_parts();
+            let artifact_policy = index_locations.artifact_policy_for(&index_url);
             let distributions = index.entry(filename.name().clone()).or_default();
-            distributions.add_file(file, filename, tags, hasher, build_options, index_url);
+            distributions.add_file(
+                file,
+                filename,
+                tags,
+                hasher,
+                build_options,
+                artifact_policy,
+                index_url,
+            );
         }
 
         Self { index, offline }
@@ -75,11 +89,20 @@ impl FlatDistributions {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        artifact_policy: IndexArtifactPolicy,
     ) -> Self {
         let mut distributions = Self::default();
         for entry in entries {
             let (filename, file, index) = entry.into_parts();
-            distributions.add_file(file, filename, tags, hasher, build_options, index);
+            distributions.add_file(
+                file,
+                filename,
+                tags,
+                hasher,
+                build_options,
+                artifact_policy,
+                index,
+            );
         }
         distributions
     }
@@ -97,6 +120,7 @@ impl FlatDistributions {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        artifact_policy: IndexArtifactPolicy,
         index: IndexUrl,
     ) {
         // No `requires-python` here: for source distributions, we don't have that information;
@@ -111,6 +135,8 @@ impl FlatDistributions {
                     tags,
                     hasher,
                     build_options,
+                    artifact_policy,
+                    &index,
                 );
                 let dist = RegistryBuiltWheel {
                     filename,
@@ -132,6 +158,8 @@ impl FlatDistributions {
                     file.hashes.as_slice(),
                     hasher,
                     build_options,
+                    artifact_policy,
+                    &index,
                 );
                 let dist = RegistrySourceDist {
                     name: filename.name.clone(),
@@ -158,12 +186,21 @@ impl FlatDistributions {
         hashes: &[HashDigest],
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        artifact_policy: IndexArtifactPolicy,
+        index: &IndexUrl,
     ) -> SourceDistCompatibility {
         // Check if source distributions are allowed for this package.
         if build_options.no_build_package(&filename.name) {
             return SourceDistCompatibility::Incompatible(IncompatibleSource::NoBuild);
         }
 
+        // Check if source distributions are allowed from this index.
+        if !artifact_policy.allow_sdist {
+            return SourceDistCompatibility::Incompatible(IncompatibleSource::IndexPolicy(
+                index.clone(),
+            ));
+        }
+
         // Check if hashes line up
         let hash_policy = hasher.get_package(&filename.name, &filename.version);
         let hash = if hash_policy.requires_validation() {
@@ -187,12 +224,19 @@ impl FlatDistributions {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        artifact_policy: IndexArtifactPolicy,
+        index: &IndexUrl,
     ) -> WheelCompatibility {
         // Check if binaries are allowed for this package.
         if build_options.no_binary_package(&filename.name) {
             return WheelCompatibility::Incompatible(IncompatibleWheel::NoBinary);
         }
 
+        // Check if binaries are allowed from this index.
+        if !artifact_policy.allow_wheels {
+            return WheelCompatibility::Incompatible(IncompatibleWheel::IndexPolicy(index.clone()));
+        }
+
         // Determine a compatibility for the wheel based on tags.
         let priority = match tags {
             Some(tags) => match filename.compatibility(tags) {
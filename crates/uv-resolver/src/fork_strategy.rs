@@ -7,6 +7,10 @@ pub enum ForkStrategy {
     /// be preferred if they are compatible with a wider range of supported Python versions or
     /// platforms.
     Fewest,
+    /// Optimize for selecting the fewest number of versions for each package, like `fewest`, while
+    /// also preferring a version that's shared by the most forks over the version that was
+    /// previously locked for the current fork.
+    FewestVersions,
     /// Optimize for selecting latest supported version of each package, for each supported Python
     /// version.
     #[default]
@@ -17,6 +21,7 @@ impl std::fmt::Display for ForkStrategy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Fewest => write!(f, "fewest"),
+            Self::FewestVersions => write!(f, "fewest-versions"),
             Self::RequiresPython => write!(f, "requires-python"),
         }
     }
@@ -1,28 +1,41 @@
 pub use dependency_mode::DependencyMode;
-pub use error::{ErrorTree, NoSolutionError, NoSolutionHeader, ResolveError, SentinelRange};
+pub use error::{
+    ErrorTree, MetadataBuildLimitError, NarrowedRequiresPythonError, NoSolutionError,
+    NoSolutionHeader, ResolutionEffortError, ResolveError, SentinelRange,
+};
 pub use exclude_newer::{
     ExcludeNewer, ExcludeNewerChange, ExcludeNewerOverrideChange, ExcludeNewerPackage,
     ExcludeNewerPackageChange, ExcludeNewerPackageEntry, ExcludeNewerValueChange,
     ExcludeNewerValueWithSpanRef, serialize_exclude_newer_package_with_spans,
 };
 pub use exclusions::Exclusions;
+pub use explain::Explanation;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
+pub use local_version::{
+    LocalVersionPreference, PackageLocalVersionPreferenceEntry, PackageLocalVersionPreferences,
+};
 pub use lock::{
-    DependencySelection, Installable, Lock, LockError, LockVersion, Metadata, Package, PackageMap,
+    DependencySelection, DockerfileExport, ForkSummary, IndexArtifactPolicies, Installable,
+    JsonGraphExport, Lock, LockError, LockReport, LockVersion, Metadata, Package, PackageMap,
     PylockToml, PylockTomlError, PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest,
     SatisfiesResult, TreeDisplay, VERSION, cyclonedx_json,
 };
 pub use manifest::Manifest;
+pub use missing_metadata::{
+    MissingMetadata, MissingMetadataError, MissingMetadataKind, MissingMetadataPolicy,
+};
 pub use options::{Flexibility, Options, OptionsBuilder};
 pub use preferences::{Preference, PreferenceError, Preferences};
-pub use prerelease::PrereleaseMode;
+pub use prerelease::{PackagePrereleaseModeEntry, PackagePrereleaseModes, PrereleaseMode};
 pub use pubgrub::PubGrubHint;
 pub use python_requirement::PythonRequirement;
+pub use relax::{Relaxation, RelaxationKind};
+pub use requires_python_strategy::RequiresPythonStrategy;
 pub use resolution::{
     AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
 };
-pub use resolution_mode::ResolutionMode;
+pub use resolution_mode::{PackageResolutionModeEntry, PackageResolutionModes, ResolutionMode};
 pub use resolver::{
     DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
     Reporter as ResolverReporter, Resolver, ResolverEnvironment, ResolverProvider,
@@ -32,7 +45,7 @@ pub use universal_marker::{ConflictMarker, UniversalMarker};
 pub use upgrade::UpgradePackages;
 pub use uv_distribution_types::{ExcludeNewerOverride, ExcludeNewerSpan, ExcludeNewerValue};
 pub use version_map::VersionMap;
-pub use yanks::AllowedYanks;
+pub use yanks::{AllowedYanks, YankedPolicy};
 
 /// A custom `HashSet` using `hashbrown`.
 ///
@@ -49,14 +62,17 @@ mod dependency_provider;
 mod error;
 mod exclude_newer;
 mod exclusions;
+mod explain;
 mod flat_index;
 mod fork_indexes;
 mod fork_strategy;
 mod fork_urls;
 mod graph_ops;
+mod local_version;
 mod lock;
 mod manifest;
 mod marker;
+mod missing_metadata;
 mod options;
 mod pins;
 mod preferences;
@@ -64,6 +80,8 @@ mod prerelease;
 pub mod pubgrub;
 mod python_requirement;
 mod redirect;
+mod relax;
+mod requires_python_strategy;
 mod resolution;
 mod resolution_mode;
 mod resolver;
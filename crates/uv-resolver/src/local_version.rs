@@ -0,0 +1,225 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use uv_normalize::PackageName;
+use uv_pep440::{LocalSegment, LocalVersionSlice, Version};
+
+/// A preferred local version label (e.g., `+cu121`), used to select between builds of the same
+/// version that differ only in their local version label (e.g., `2.3.0+cpu` and `2.3.0+cu121`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocalVersionPreference(Vec<LocalSegment>);
+
+impl LocalVersionPreference {
+    /// Returns `true` if the given version has the preferred local version label.
+    pub fn matches(&self, version: &Version) -> bool {
+        match version.local() {
+            LocalVersionSlice::Segments(segments) => segments == self.0.as_slice(),
+            LocalVersionSlice::Max => false,
+        }
+    }
+
+    /// Returns the build of the given version with the preferred local version label (e.g.,
+    /// `2.3.0+cu121` for `2.3.0` or `2.3.0+cpu`).
+    pub fn apply(&self, version: &Version) -> Version {
+        let public = version.clone().without_local();
+        Version::from_str(&format!("{public}{self}"))
+            .expect("a public version with a valid local version label is a valid version")
+    }
+}
+
+impl FromStr for LocalVersionPreference {
+    type Err = String;
+
+    /// Parses a [`LocalVersionPreference`] from a local version label, with or without the
+    /// leading `+` (e.g., `+cu121` or `cu121`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let label = s.trim();
+        let label = label.strip_prefix('+').unwrap_or(label);
+        if label.is_empty() {
+            return Err(format!(
+                "Invalid local version label `{s}`: expected a label like `+cu121`"
+            ));
+        }
+        let version = Version::from_str(&format!("0+{label}"))
+            .map_err(|_| format!("Invalid local version label `{s}`"))?;
+        match version.local() {
+            LocalVersionSlice::Segments(segments) => Ok(Self(segments.to_vec())),
+            LocalVersionSlice::Max => Err(format!("Invalid local version label `{s}`")),
+        }
+    }
+}
+
+impl std::fmt::Display for LocalVersionPreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "+{}", self.0.iter().join("."))
+    }
+}
+
+impl serde::Serialize for LocalVersionPreference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for LocalVersionPreference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <Cow<'_, str>>::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for LocalVersionPreference {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("LocalVersionPreference")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A local version label, e.g., `+cu121`."
+        })
+    }
+}
+
+/// Package-specific local version preferences, e.g., to prefer the `+cu121` build of `torch`
+/// over the `+cpu` build of the same version.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageLocalVersionPreferences(BTreeMap<PackageName, LocalVersionPreference>);
+
+impl PackageLocalVersionPreferences {
+    /// Returns the [`LocalVersionPreference`] for the given package, if any.
+    pub fn get(&self, package_name: &PackageName) -> Option<&LocalVersionPreference> {
+        self.0.get(package_name)
+    }
+
+    /// Returns `true` if there are no package-specific preferences.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the package-specific preferences.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &LocalVersionPreference)> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<PackageLocalVersionPreferenceEntry> for PackageLocalVersionPreferences {
+    fn from_iter<T: IntoIterator<Item = PackageLocalVersionPreferenceEntry>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|entry| (entry.package, entry.preference))
+                .collect(),
+        )
+    }
+}
+
+impl FromIterator<(PackageName, LocalVersionPreference)> for PackageLocalVersionPreferences {
+    fn from_iter<T: IntoIterator<Item = (PackageName, LocalVersionPreference)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for PackageLocalVersionPreferences {
+    type Item = (PackageName, LocalVersionPreference);
+    type IntoIter = std::collections::btree_map::IntoIter<PackageName, LocalVersionPreference>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// A package-specific local version preference entry, as in
+/// `--local-version-preference PACKAGE=+LABEL`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageLocalVersionPreferenceEntry {
+    package: PackageName,
+    preference: LocalVersionPreference,
+}
+
+impl FromStr for PackageLocalVersionPreferenceEntry {
+    type Err = String;
+
+    /// Parses a [`PackageLocalVersionPreferenceEntry`] from a string in the format
+    /// `PACKAGE=+LABEL`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, preference)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid `local-version-preference` value `{s}`: expected format `PACKAGE=+LABEL`"
+            ));
+        };
+
+        let package = PackageName::from_str(package.trim()).map_err(|err| {
+            format!("Invalid `local-version-preference` package name `{package}`: {err}")
+        })?;
+
+        let preference = LocalVersionPreference::from_str(preference)
+            .map_err(|err| format!("Invalid `local-version-preference` value `{s}`: {err}"))?;
+
+        Ok(Self {
+            package,
+            preference,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_local_version_preference() {
+        let preference = LocalVersionPreference::from_str("+cu121").unwrap();
+        assert_eq!(preference.to_string(), "+cu121");
+        assert_eq!(
+            LocalVersionPreference::from_str("cu121").unwrap(),
+            preference
+        );
+
+        // Labels are normalized.
+        let preference = LocalVersionPreference::from_str("+Ubuntu-1_2").unwrap();
+        assert_eq!(preference.to_string(), "+ubuntu.1.2");
+
+        assert!(LocalVersionPreference::from_str("+").is_err());
+        assert!(LocalVersionPreference::from_str("+cu 121").is_err());
+    }
+
+    #[test]
+    fn local_version_preference_matches() {
+        let preference = LocalVersionPreference::from_str("+cu121").unwrap();
+        assert!(preference.matches(&Version::from_str("2.3.0+cu121").unwrap()));
+        assert!(preference.matches(&Version::from_str("2.3.0+CU121").unwrap()));
+        assert!(!preference.matches(&Version::from_str("2.3.0+cpu").unwrap()));
+        assert!(!preference.matches(&Version::from_str("2.3.0+cu121.1").unwrap()));
+        assert!(!preference.matches(&Version::from_str("2.3.0").unwrap()));
+
+        assert_eq!(
+            preference.apply(&Version::from_str("2.3.0+cpu").unwrap()),
+            Version::from_str("2.3.0+cu121").unwrap()
+        );
+        assert_eq!(
+            preference.apply(&Version::from_str("2.3.0rc1").unwrap()),
+            Version::from_str("2.3.0rc1+cu121").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_local_version_preference_entry() {
+        let entry = PackageLocalVersionPreferenceEntry::from_str("torch=+cu121").unwrap();
+        assert_eq!(entry.package, PackageName::from_str("torch").unwrap());
+        assert_eq!(entry.preference.to_string(), "+cu121");
+
+        assert!(PackageLocalVersionPreferenceEntry::from_str("torch").is_err());
+        assert!(PackageLocalVersionPreferenceEntry::from_str("torch=").is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt::Formatter;
+use std::ops::Bound;
+
+use itertools::Itertools;
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_normalize::PackageName;
+
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::{Package, Source};
+use crate::{Installable, LockError};
+
+/// The user and group under which the application runs in the final stage.
+const USER: &str = "app";
+
+/// The distroless image used for the final stage with `--distroless`.
+///
+/// The image includes the C runtime required by the Python installation, along with a `nonroot`
+/// user, but no shell or Python interpreter.
+const DISTROLESS_IMAGE: &str = "gcr.io/distroless/cc-debian12:nonroot";
+
+/// The directory into which the Python installation is placed for distroless images.
+const PYTHON_INSTALL_DIR: &str = "/python";
+
+/// An export of a [`Lock`] that renders as a Dockerfile snippet, which installs the project into
+/// an image following the practices described in the uv Docker guide.
+///
+/// The snippet uses a build stage to sync the project environment, installing the dependencies
+/// in a separate layer from the project itself such that the layer is only invalidated when the
+/// lockfile changes, then copies the result into a final stage that runs as a non-root user.
+///
+/// For distroless images, the build stage installs a managed Python interpreter, since the final
+/// stage doesn't include one, and copies it into the final stage alongside the environment.
+#[derive(Debug)]
+pub struct DockerfileExport {
+    /// The `major.minor` Python version to use for the base images, or the major version alone if
+    /// the project places no lower bound on the minor version.
+    python_version: String,
+    /// Whether any package must be built from source, in which case the build stage uses the full
+    /// Python image, which includes a compiler toolchain.
+    build_from_source: bool,
+    /// Whether the project is a workspace with multiple members.
+    workspace: bool,
+    /// Whether the final stage uses a distroless image.
+    distroless: bool,
+    /// The arguments to pass to `uv sync` to select the packages, extras, and groups.
+    flags: Vec<String>,
+}
+
+impl DockerfileExport {
+    pub fn from_lock<'lock>(
+        target: &impl Installable<'lock>,
+        all_packages: bool,
+        packages: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        groups: &DependencyGroupsWithDefaults,
+        install_options: &'lock InstallOptions,
+        distroless: bool,
+    ) -> Result<Self, LockError> {
+        // Extract the packages from the lock file. The requested packages are the roots of the
+        // target, so only their dependencies determine whether a build stage is required; nothing
+        // is pruned, since `uv sync` installs the entire dependency tree of each root.
+        let ExportableRequirements(nodes) =
+            ExportableRequirements::from_lock(target, &[], extras, groups, false, install_options)?;
+
+        let build_from_source = nodes
+            .iter()
+            .any(|ExportableRequirement { package, .. }| requires_build(package));
+
+        let python_version = match &**target.lock().requires_python().range().lower() {
+            Bound::Included(version) | Bound::Excluded(version) => match &*version.release() {
+                [major] => major.to_string(),
+                [major, minor, ..] => format!("{major}.{minor}"),
+                [] => "3".to_string(),
+            },
+            Bound::Unbounded => "3".to_string(),
+        };
+
+        let mut flags = Vec::new();
+        if all_packages {
+            flags.push("--all-packages".to_string());
+        }
+        for package in packages {
+            flags.push("--package".to_string());
+            flags.push(package.to_string());
+        }
+        flags.extend(extras.history().as_flags());
+        flags.extend(groups.history().as_flags());
+
+        Ok(Self {
+            python_version,
+            build_from_source,
+            workspace: !target.lock().members().is_empty(),
+            distroless,
+            flags,
+        })
+    }
+}
+
+/// Returns `true` if the [`Package`] must be built from source, i.e., if it's not a workspace
+/// member or local directory and there are no wheels available.
+fn requires_build(package: &Package) -> bool {
+    match &package.id.source {
+        Source::Registry(_) | Source::Git(..) | Source::Direct(..) | Source::Path(_) => {
+            package.wheels.is_empty()
+        }
+        Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => false,
+    }
+}
+
+impl std::fmt::Display for DockerfileExport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let python_image = format!("python:{}-slim", self.python_version);
+        let build_image = match (self.distroless, self.build_from_source) {
+            // Building from source may require a compiler toolchain, which is only included in
+            // the full images.
+            (false, true) => format!("python:{}", self.python_version),
+            (false, false) => python_image.clone(),
+            // For distroless images, Python is installed by uv, so the build stage only needs a
+            // Debian release that matches the final stage.
+            (true, true) => "buildpack-deps:bookworm".to_string(),
+            (true, false) => "debian:bookworm-slim".to_string(),
+        };
+        let flags = self.flags.iter().map(|flag| format!(" {flag}")).join("");
+        let uv_version = uv_version::version();
+
+        // Without all the workspace members, the lockfile can't be validated in the first sync.
+        let (check, skip) = if self.workspace {
+            ("--frozen", "--no-install-workspace")
+        } else {
+            ("--locked", "--no-install-project")
+        };
+
+        writeln!(f, "FROM {build_image} AS builder")?;
+        writeln!(
+            f,
+            "COPY --from=ghcr.io/astral-sh/uv:{uv_version} /uv /uvx /bin/"
+        )?;
+        writeln!(f)?;
+        if self.distroless {
+            writeln!(
+                f,
+                "# Compile bytecode, copy packages out of the cache mount, and install Python in a"
+            )?;
+            writeln!(f, "# directory that's copied into the final image")?;
+            writeln!(
+                f,
+                "ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_INSTALL_DIR={PYTHON_INSTALL_DIR} UV_PYTHON_PREFERENCE=only-managed"
+            )?;
+            writeln!(f)?;
+            writeln!(
+                f,
+                "RUN --mount=type=cache,target=/root/.cache/uv uv python install {}",
+                self.python_version
+            )?;
+        } else {
+            writeln!(
+                f,
+                "# Compile bytecode, copy packages out of the cache mount, and use the image's Python"
+            )?;
+            writeln!(
+                f,
+                "ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=0"
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(f, "WORKDIR /app")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "# Install the dependencies, which only change with the lockfile"
+        )?;
+        writeln!(f, "RUN --mount=type=cache,target=/root/.cache/uv \\")?;
+        writeln!(f, "    --mount=type=bind,source=uv.lock,target=uv.lock \\")?;
+        writeln!(
+            f,
+            "    --mount=type=bind,source=pyproject.toml,target=pyproject.toml \\"
+        )?;
+        writeln!(f, "    uv sync {check} {skip} --no-editable{flags}")?;
+        writeln!(f)?;
+        writeln!(f, "# Install the project")?;
+        writeln!(f, "COPY . /app")?;
+        writeln!(f, "RUN --mount=type=cache,target=/root/.cache/uv \\")?;
+        writeln!(f, "    uv sync --locked --no-editable{flags}")?;
+        writeln!(f)?;
+        if self.distroless {
+            writeln!(f, "FROM {DISTROLESS_IMAGE}")?;
+            writeln!(f)?;
+            writeln!(
+                f,
+                "# Run the application as the image's non-root user, with the Python installation"
+            )?;
+            writeln!(f, "# at the same path as in the build stage")?;
+            writeln!(
+                f,
+                "COPY --from=builder {PYTHON_INSTALL_DIR} {PYTHON_INSTALL_DIR}"
+            )?;
+            writeln!(f, "COPY --from=builder --chown=nonroot:nonroot /app /app")?;
+            writeln!(f, "ENV PATH=\"/app/.venv/bin:$PATH\"")?;
+            writeln!(f, "USER nonroot")?;
+        } else {
+            writeln!(f, "FROM {python_image}")?;
+            writeln!(f)?;
+            writeln!(f, "# Run the application as a non-root user")?;
+            writeln!(
+                f,
+                "RUN groupadd --system {USER} && useradd --system --gid {USER} --create-home {USER}"
+            )?;
+            writeln!(f, "COPY --from=builder --chown={USER}:{USER} /app /app")?;
+            writeln!(f, "ENV PATH=\"/app/.venv/bin:$PATH\"")?;
+            writeln!(f, "USER {USER}")?;
+        }
+        writeln!(f, "WORKDIR /app")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::BTreeSet;
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_fs::PortablePath;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+
+use crate::lock::export::{ExportableEdge, ExportableRequirement, ExportableRequirements};
+use crate::lock::{Package, RegistrySource, Source};
+use crate::{Installable, LockError};
+
+/// The version of the `json-graph` schema.
+///
+/// Must be incremented whenever a field is removed or its meaning changes; new optional fields may
+/// be added without a version bump.
+const SCHEMA_VERSION: u32 = 1;
+
+/// An export of a [`Lock`] as a JSON dependency graph, for consumption by external tooling (e.g.,
+/// license checks or vulnerability scanners).
+///
+/// Nodes and edges are sorted, such that the output is deterministic and can be diffed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct JsonGraphExport {
+    /// The version of the schema.
+    version: u32,
+    /// The packages in the resolution.
+    nodes: Vec<JsonGraphNode>,
+    /// The dependencies between the packages.
+    edges: Vec<JsonGraphEdge>,
+}
+
+/// A package in the resolution.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct JsonGraphNode {
+    /// A unique identifier for the package, as referenced by [`JsonGraphEdge`].
+    id: String,
+    /// The name of the package.
+    name: PackageName,
+    /// The version of the package, if known (source trees with a dynamic version have none).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    /// The source of the package.
+    source: JsonGraphSource,
+    /// The marker under which the package is installed, if it isn't installed unconditionally.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
+    /// The hashes of the package's distributions, as `{algorithm}:{digest}`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    hashes: Vec<String>,
+}
+
+/// The source of a package.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum JsonGraphSource {
+    /// A package index, identified by URL, or a local `--find-links` directory, identified by
+    /// path.
+    Registry {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+    },
+    /// A Git repository, including the resolved commit.
+    Git { url: String },
+    /// A direct URL to a source or built distribution.
+    Url { url: String },
+    /// A local source or built distribution.
+    Path { path: String },
+    /// A local source tree.
+    Directory { path: String },
+    /// A local source tree, installed as editable.
+    Editable { path: String },
+    /// A local source tree that isn't built or installed.
+    Virtual { path: String },
+}
+
+/// A dependency of one package on another.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct JsonGraphEdge {
+    /// The [`JsonGraphNode`] that declared the dependency, or `None` for a requirement of the
+    /// project or workspace itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    /// The [`JsonGraphNode`] that satisfies the dependency.
+    to: String,
+    /// The version specifier of the requirement, if recorded in the lockfile.
+    ///
+    /// Specifiers are only retained for workspace members and requirements of the workspace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    specifier: Option<String>,
+    /// The extra of the declaring package that introduced the dependency, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    extra: Option<ExtraName>,
+    /// The dependency group that introduced the dependency, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<GroupName>,
+    /// The marker under which the dependency applies, if it isn't unconditional.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
+    /// The extras of the dependency that are enabled by the requirement.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extras: Vec<ExtraName>,
+}
+
+impl JsonGraphExport {
+    pub fn from_lock<'lock>(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        groups: &DependencyGroupsWithDefaults,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        // Extract the packages from the lock file, along with the edges that introduced them.
+        let ExportableRequirements(nodes) = ExportableRequirements::from_lock(
+            target,
+            prune,
+            extras,
+            groups,
+            true,
+            install_options,
+        )?;
+
+        let included = nodes
+            .iter()
+            .map(|node| &node.package.id)
+            .collect::<BTreeSet<_>>();
+
+        let mut edges = BTreeSet::new();
+        for ExportableRequirement {
+            package,
+            edges: incoming,
+            ..
+        } in &nodes
+        {
+            for edge in incoming {
+                // Omit edges from packages that were excluded from the export.
+                if edge
+                    .source
+                    .is_some_and(|source| !included.contains(&source.id))
+                {
+                    continue;
+                }
+                edges.insert(JsonGraphEdge::from_edge(target, package, edge));
+            }
+        }
+
+        let mut nodes = nodes
+            .iter()
+            .map(JsonGraphNode::from_requirement)
+            .collect::<Vec<_>>();
+        nodes.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(Self {
+            version: SCHEMA_VERSION,
+            nodes,
+            edges: edges.into_iter().collect(),
+        })
+    }
+
+    /// Serialize the export as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl JsonGraphNode {
+    fn from_requirement(requirement: &ExportableRequirement<'_>) -> Self {
+        let package = requirement.package;
+        let mut hashes = package
+            .hashes()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        hashes.sort_unstable();
+        hashes.dedup();
+        Self {
+            id: package.id.to_string(),
+            name: package.id.name.clone(),
+            version: package.id.version.as_ref().map(ToString::to_string),
+            source: JsonGraphSource::from(&package.id.source),
+            marker: requirement.marker.try_to_string(),
+            hashes,
+        }
+    }
+}
+
+impl From<&Source> for JsonGraphSource {
+    fn from(source: &Source) -> Self {
+        match source {
+            Source::Registry(RegistrySource::Url(url)) => Self::Registry {
+                url: Some(url.to_string()),
+                path: None,
+            },
+            Source::Registry(RegistrySource::Path(path)) => Self::Registry {
+                url: None,
+                path: Some(PortablePath::from(path).to_string()),
+            },
+            Source::Git(url, _) => Self::Git {
+                url: url.to_string(),
+            },
+            Source::Direct(url, _) => Self::Url {
+                url: url.to_string(),
+            },
+            Source::Path(path) => Self::Path {
+                path: PortablePath::from(path).to_string(),
+            },
+            Source::Directory(path) => Self::Directory {
+                path: PortablePath::from(path).to_string(),
+            },
+            Source::Editable(path) => Self::Editable {
+                path: PortablePath::from(path).to_string(),
+            },
+            Source::Virtual(path) => Self::Virtual {
+                path: PortablePath::from(path).to_string(),
+            },
+        }
+    }
+}
+
+impl JsonGraphEdge {
+    fn from_edge<'lock>(
+        target: &impl Installable<'lock>,
+        package: &Package,
+        edge: &ExportableEdge<'lock>,
+    ) -> Self {
+        let specifier =
+            find_requirement(target, package.name(), edge).and_then(
+                |requirement| match &requirement.source {
+                    RequirementSource::Registry { specifier, .. } if !specifier.is_empty() => {
+                        Some(specifier.to_string())
+                    }
+                    _ => None,
+                },
+            );
+        let mut extras = edge.dep_extras.clone();
+        extras.sort_unstable();
+        extras.dedup();
+        Self {
+            from: edge.source.map(|source| source.id.to_string()),
+            to: package.id.to_string(),
+            specifier,
+            extra: edge.extra.clone(),
+            group: edge.group.clone(),
+            marker: edge.marker.try_to_string(),
+            extras,
+        }
+    }
+}
+
+/// Find the requirement in the lockfile that introduced the given edge to the named package, if
+/// the lockfile retains it.
+fn find_requirement<'lock>(
+    target: &impl Installable<'lock>,
+    name: &PackageName,
+    edge: &ExportableEdge<'lock>,
+) -> Option<&'lock Requirement> {
+    let lock = target.lock();
+    let candidates: Box<dyn Iterator<Item = &'lock Requirement>> =
+        match (edge.source, edge.group.as_ref()) {
+            // A dependency group of a package.
+            (Some(source), Some(group)) => {
+                Box::new(source.dependency_groups().get(group).into_iter().flatten())
+            }
+            // A dependency, or optional dependency, of a package.
+            (Some(source), None) => Box::new(source.metadata.requires_dist.iter()),
+            // A dependency group of the workspace, or of one of the target's root packages.
+            (None, Some(group)) => Box::new(
+                lock.dependency_groups()
+                    .get(group)
+                    .into_iter()
+                    .flatten()
+                    .chain(
+                        target
+                            .roots()
+                            .filter_map(|root| lock.find_by_name(root).ok().flatten())
+                            .filter_map(|root| root.dependency_groups().get(group))
+                            .flatten(),
+                    ),
+            ),
+            // A requirement of the workspace.
+            (None, None) => Box::new(lock.requirements().iter()),
+        };
+
+    // Prefer the requirement declared for the edge's extra (or declared unconditionally, for edges
+    // outside any extra), falling back to any requirement on the package.
+    let candidates = candidates
+        .filter(|requirement| requirement.name == *name)
+        .collect::<Vec<_>>();
+    candidates
+        .iter()
+        .find(|requirement| {
+            requirement.marker.top_level_extra_name().as_deref() == edge.extra.as_ref()
+        })
+        .or_else(|| candidates.first())
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonGraphEdge, JsonGraphExport, JsonGraphNode, JsonGraphSource, SCHEMA_VERSION};
+
+    #[test]
+    fn round_trip() {
+        let export = JsonGraphExport {
+            version: SCHEMA_VERSION,
+            nodes: vec![
+                JsonGraphNode {
+                    id: "anyio==4.3.0 @ registry+https://pypi.org/simple".to_string(),
+                    name: "anyio".parse().unwrap(),
+                    version: Some("4.3.0".to_string()),
+                    source: JsonGraphSource::Registry {
+                        url: Some("https://pypi.org/simple".to_string()),
+                        path: None,
+                    },
+                    marker: Some("sys_platform == 'linux'".to_string()),
+                    hashes: vec![
+                        "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8"
+                            .to_string(),
+                    ],
+                },
+                JsonGraphNode {
+                    id: "project==0.1.0 @ editable+.".to_string(),
+                    name: "project".parse().unwrap(),
+                    version: Some("0.1.0".to_string()),
+                    source: JsonGraphSource::Editable {
+                        path: ".".to_string(),
+                    },
+                    marker: None,
+                    hashes: vec![],
+                },
+            ],
+            edges: vec![
+                JsonGraphEdge {
+                    from: None,
+                    to: "project==0.1.0 @ editable+.".to_string(),
+                    specifier: None,
+                    extra: None,
+                    group: None,
+                    marker: None,
+                    extras: vec![],
+                },
+                JsonGraphEdge {
+                    from: Some("project==0.1.0 @ editable+.".to_string()),
+                    to: "anyio==4.3.0 @ registry+https://pypi.org/simple".to_string(),
+                    specifier: Some(">=4".to_string()),
+                    extra: Some("async".parse().unwrap()),
+                    group: None,
+                    marker: Some("sys_platform == 'linux'".to_string()),
+                    extras: vec!["trio".parse().unwrap()],
+                },
+            ],
+        };
+
+        let json = export.to_json().unwrap();
+        insta::assert_snapshot!(json, @r#"
+        {
+          "version": 1,
+          "nodes": [
+            {
+              "id": "anyio==4.3.0 @ registry+https://pypi.org/simple",
+              "name": "anyio",
+              "version": "4.3.0",
+              "source": {
+                "kind": "registry",
+                "url": "https://pypi.org/simple"
+              },
+              "marker": "sys_platform == 'linux'",
+              "hashes": [
+                "sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8"
+              ]
+            },
+            {
+              "id": "project==0.1.0 @ editable+.",
+              "name": "project",
+              "version": "0.1.0",
+              "source": {
+                "kind": "editable",
+                "path": "."
+              }
+            }
+          ],
+          "edges": [
+            {
+              "to": "project==0.1.0 @ editable+."
+            },
+            {
+              "from": "project==0.1.0 @ editable+.",
+              "to": "anyio==4.3.0 @ registry+https://pypi.org/simple",
+              "specifier": ">=4",
+              "extra": "async",
+              "marker": "sys_platform == 'linux'",
+              "extras": [
+                "trio"
+              ]
+            }
+          ]
+        }
+        "#);
+
+        let parsed: JsonGraphExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, export);
+    }
+}
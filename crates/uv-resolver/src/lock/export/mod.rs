@@ -17,6 +17,8 @@ use uv_pypi_types::ConflictItem;
 
 use crate::graph_ops::Reachable;
 use crate::lock::LockErrorKind;
+pub use crate::lock::export::dockerfile::DockerfileExport;
+pub use crate::lock::export::json_graph::JsonGraphExport;
 pub use crate::lock::export::metadata::Metadata;
 pub(crate) use crate::lock::export::pylock_toml::PylockTomlPackage;
 pub use crate::lock::export::pylock_toml::{PylockToml, PylockTomlError, PylockTomlErrorKind};
@@ -25,6 +27,8 @@ use crate::universal_marker::resolve_activated_extras;
 use crate::{Installable, LockError, Package};
 
 pub mod cyclonedx_json;
+mod dockerfile;
+mod json_graph;
 mod metadata;
 mod pylock_toml;
 mod requirements_txt;
@@ -38,6 +42,24 @@ struct ExportableRequirement<'lock> {
     marker: MarkerTree,
     /// The list of packages that depend on this package.
     dependents: Vec<&'lock Package>,
+    /// The edges through which this package was introduced.
+    edges: Vec<ExportableEdge<'lock>>,
+}
+
+/// An edge into an [`ExportableRequirement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExportableEdge<'lock> {
+    /// The package that declared the dependency, or `None` for requirements of the project or
+    /// workspace root itself (including dependency groups).
+    source: Option<&'lock Package>,
+    /// The optional dependency group of the source package that declared the dependency, if any.
+    extra: Option<ExtraName>,
+    /// The dependency group that declared the dependency, if any.
+    group: Option<GroupName>,
+    /// The marker that must be satisfied to traverse the edge.
+    marker: MarkerTree,
+    /// The extras of the package activated by traversing the edge.
+    dep_extras: Vec<ExtraName>,
 }
 
 /// A set of flattened, exportable requirements, generated from a lockfile.
@@ -172,6 +194,7 @@ impl<'lock> ExportableRequirements<'lock> {
             .lock()
             .requirements()
             .iter()
+            .map(|dep| (None, dep))
             .chain(
                 target
                     .lock()
@@ -179,14 +202,14 @@ impl<'lock> ExportableRequirements<'lock> {
                     .iter()
                     .filter_map(|(group, deps)| {
                         if groups.contains(group) {
-                            Some(deps)
+                            Some(deps.iter().map(move |dep| (Some(group), dep)))
                         } else {
                             None
                         }
                     })
                     .flatten(),
             )
-            .filter(|dep| !prune.contains(&dep.name))
+            .filter(|(_, dep)| !prune.contains(&dep.name))
             .collect::<Vec<_>>();
 
         // Index the lockfile by package name, to avoid making multiple passes over the lockfile.
@@ -194,7 +217,7 @@ impl<'lock> ExportableRequirements<'lock> {
             let by_name: FxHashMap<_, Vec<_>> = {
                 let names = root_requirements
                     .iter()
-                    .map(|dep| &dep.name)
+                    .map(|(_, dep)| &dep.name)
                     .collect::<FxHashSet<_>>();
                 target.lock().packages().iter().fold(
                     FxHashMap::with_capacity_and_hasher(size_guess, FxBuildHasher),
@@ -207,7 +230,7 @@ impl<'lock> ExportableRequirements<'lock> {
                 )
             };
 
-            for requirement in root_requirements {
+            for (group, requirement) in root_requirements {
                 for dist in by_name.get(&requirement.name).into_iter().flatten() {
                     // Determine whether this entry is relevant for the requirement by
                     // intersecting and simplifying the markers.
@@ -222,12 +245,18 @@ impl<'lock> ExportableRequirements<'lock> {
                         .or_insert_with(|| graph.add_node(Node::Package(dist)));
 
                     // Add an edge from the root.
+                    let dep_extras = requirement.extras.iter().collect();
                     graph.add_edge(
                         root,
                         dep_index,
-                        Edge::Prod {
-                            marker,
-                            dep_extras: requirement.extras.iter().collect(),
+                        if let Some(group) = group {
+                            Edge::Dev {
+                                group,
+                                marker,
+                                dep_extras,
+                            }
+                        } else {
+                            Edge::Prod { marker, dep_extras }
                         },
                     );
 
@@ -338,6 +367,14 @@ impl<'lock> ExportableRequirements<'lock> {
                 } else {
                     Vec::new()
                 },
+                edges: if annotate {
+                    graph
+                        .edges_directed(index, Direction::Incoming)
+                        .map(|edge| ExportableEdge::from_edge(&graph[edge.source()], edge.weight()))
+                        .collect()
+                } else {
+                    Vec::new()
+                },
             })
             .filter(|requirement| !requirement.marker.is_false())
             .collect::<Vec<_>>();
@@ -392,6 +429,47 @@ impl Edge<'_> {
     }
 }
 
+impl<'lock> ExportableEdge<'lock> {
+    /// Create an [`ExportableEdge`] from an [`Edge`] in the resolution graph and its source node.
+    fn from_edge(source: &Node<'lock>, edge: &Edge<'_>) -> Self {
+        let source = match source {
+            Node::Root => None,
+            Node::Package(package) => Some(*package),
+        };
+        match edge {
+            Edge::Prod { marker, dep_extras } => Self {
+                source,
+                extra: None,
+                group: None,
+                marker: *marker,
+                dep_extras: dep_extras.iter().map(|extra| (*extra).clone()).collect(),
+            },
+            Edge::Optional {
+                extra,
+                marker,
+                dep_extras,
+            } => Self {
+                source,
+                extra: Some((*extra).clone()),
+                group: None,
+                marker: *marker,
+                dep_extras: dep_extras.iter().map(|extra| (*extra).clone()).collect(),
+            },
+            Edge::Dev {
+                group,
+                marker,
+                dep_extras,
+            } => Self {
+                source,
+                extra: None,
+                group: Some((*group).clone()),
+                marker: *marker,
+                dep_extras: dep_extras.iter().map(|extra| (*extra).clone()).collect(),
+            },
+        }
+    }
+}
+
 impl Reachable<MarkerTree> for Edge<'_> {
     fn true_marker() -> MarkerTree {
         MarkerTree::TRUE
@@ -7,7 +7,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use jiff::Timestamp;
 use owo_colors::OwoColorize;
 use petgraph::graph::NodeIndex;
@@ -21,7 +21,8 @@ use url::Url;
 use uv_cache_key::RepositoryUrl;
 use uv_configuration::{
     BuildOptions, Constraints, DependencyGroupsWithDefaults, ExcludeDependency,
-    ExtrasSpecificationWithDefaults, InstallTarget, Override, PackageOverride,
+    ExtrasSpecificationWithDefaults, InstallTarget, Override, PackageOverride, Replacement,
+    Replacements,
 };
 use uv_distribution::{DistributionDatabase, FlatRequiresDist, RequiresDist};
 use uv_distribution_filename::{
@@ -30,10 +31,10 @@ use uv_distribution_filename::{
 use uv_distribution_types::{
     BuiltDist, DependencyMetadata, DirectUrlBuiltDist, DirectUrlSourceDist, DirectorySourceDist,
     Dist, FileLocation, GitDirectorySourceDist, GitPathBuiltDist, GitPathSourceDist, Identifier,
-    IndexLocations, IndexMetadata, IndexUrl, Name, PYPI_URL, PathBuiltDist, PathSourceDist,
-    RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist, RemoteSource, Requirement,
-    RequirementSource, RequiresPython, ResolvedDist, SimplifiedMarkerTree, StaticMetadata,
-    ToUrlError, UrlString,
+    IndexArtifactPolicy, IndexLocations, IndexMetadata, IndexUrl, LockedBuildDependency, Name,
+    PYPI_URL, PathBuiltDist, PathSourceDist, RegistryBuiltDist, RegistryBuiltWheel,
+    RegistrySourceDist, RemoteSource, Requirement, RequirementSource, RequiresPython, ResolvedDist,
+    SimplifiedMarkerTree, StaticMetadata, ToUrlError, UrlString,
 };
 use uv_fs::{
     PortablePath, PortablePathBuf, Simplified, normalize_path, relative_to, try_relative_to_if,
@@ -46,7 +47,7 @@ use uv_pep508::{
     MarkerEnvironment, MarkerTree, Scheme, VerbatimUrl, VerbatimUrlError, split_scheme,
 };
 use uv_platform_tags::{
-    AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagCompatibility, TagPriority, Tags,
+    AbiTag, IncompatibleTag, LanguageTag, Os, PlatformTag, TagCompatibility, TagPriority, Tags,
 };
 use uv_pypi_types::{
     ConflictKind, Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, ParsedArchiveUrl,
@@ -61,21 +62,27 @@ use crate::fork_strategy::ForkStrategy;
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{
-    Metadata, PylockToml, PylockTomlError, PylockTomlErrorKind, cyclonedx_json,
+    DockerfileExport, JsonGraphExport, Metadata, PylockToml, PylockTomlError, PylockTomlErrorKind,
+    cyclonedx_json,
 };
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
+pub use crate::lock::report::LockReport;
+pub use crate::lock::summary::ForkSummary;
 pub use crate::lock::tree::TreeDisplay;
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     ExcludeNewer, ExcludeNewerOverride, ExcludeNewerPackage, ExcludeNewerSpan, ExcludeNewerValue,
-    InMemoryIndex, MetadataResponse, PrereleaseMode, ResolutionMode, ResolverOutput,
+    InMemoryIndex, MetadataResponse, PackageLocalVersionPreferences, PackagePrereleaseModes,
+    PackageResolutionModes, PrereleaseMode, ResolutionMode, ResolverOutput,
 };
 
 pub(crate) mod export;
 mod installable;
 mod map;
+mod report;
+mod summary;
 mod tree;
 
 /// The current version of the lockfile format.
@@ -415,6 +422,9 @@ impl Lock {
                 );
                 package.add_dependency(&requires_python, dependency_dist, marker, root)?;
             }
+            for dependency in &mut package.dependencies {
+                dependency.replaces = replaced_package(dist, dependency, &resolution.replacements);
+            }
 
             let id = package.id.clone();
             if let Some(locked_dist) = packages.insert(id, package) {
@@ -459,6 +469,15 @@ impl Lock {
                         root,
                     )?;
                 }
+                for dependency in package
+                    .optional_dependencies
+                    .get_mut(extra)
+                    .into_iter()
+                    .flatten()
+                {
+                    dependency.replaces =
+                        replaced_package(dist, dependency, &resolution.replacements);
+                }
             }
             if let Some(group) = dist.group.as_ref() {
                 let id = PackageId::from_annotated_dist(dist, root)?;
@@ -489,6 +508,15 @@ impl Lock {
                         root,
                     )?;
                 }
+                for dependency in package
+                    .dependency_groups
+                    .get_mut(group)
+                    .into_iter()
+                    .flatten()
+                {
+                    dependency.replaces =
+                        replaced_package(dist, dependency, &resolution.replacements);
+                }
             }
         }
 
@@ -496,9 +524,19 @@ impl Lock {
 
         let options = ResolverOptions {
             resolution_mode: resolution.options.resolution_mode,
+            resolution_package: resolution.options.resolution_package.clone(),
             prerelease_mode: resolution.options.prerelease_mode,
+            prerelease_package: resolution.options.prerelease_package.clone(),
+            local_version_preference: resolution.options.local_version_preference.clone(),
             fork_strategy: resolution.options.fork_strategy,
             exclude_newer: resolution.options.exclude_newer.clone().into(),
+            index_artifact_policies: IndexArtifactPolicies::default(),
+            reresolve_metadata: resolution
+                .options
+                .reresolve_metadata
+                .iter()
+                .cloned()
+                .collect(),
         };
         // Canonicalize the top-level fork markers to match what is persisted in
         // `uv.lock`. In particular, conflict-only fork markers can serialize to
@@ -697,6 +735,55 @@ impl Lock {
         self
     }
 
+    /// Record the locked build dependencies of each package, replacing any existing build
+    /// dependencies.
+    ///
+    /// Paths are stored relative to the given workspace root.
+    pub fn with_build_dependencies(
+        mut self,
+        build_dependencies: &BTreeMap<PackageName, Vec<LockedBuildDependency>>,
+        workspace_root: &Path,
+    ) -> Result<Self, LockError> {
+        for package in &mut self.packages {
+            package.build_dependencies = build_dependencies
+                .get(package.name())
+                .into_iter()
+                .flatten()
+                .map(|dependency| {
+                    Ok(LockedBuildDependency {
+                        requirement: dependency.requirement.clone().relative_to(workspace_root)?,
+                        hashes: dependency.hashes.clone(),
+                    })
+                })
+                .collect::<Result<_, io::Error>>()
+                .map_err(LockErrorKind::RequirementRelativePath)?;
+        }
+        Ok(self)
+    }
+
+    /// Returns the locked build dependencies of each package, by package name, with paths
+    /// resolved against the given workspace root.
+    pub fn build_dependencies(
+        &self,
+        workspace_root: &Path,
+    ) -> BTreeMap<PackageName, Vec<LockedBuildDependency>> {
+        self.packages
+            .iter()
+            .filter(|package| !package.build_dependencies.is_empty())
+            .map(|package| {
+                let dependencies = package
+                    .build_dependencies
+                    .iter()
+                    .map(|dependency| LockedBuildDependency {
+                        requirement: dependency.requirement.clone().to_absolute(workspace_root),
+                        hashes: dependency.hashes.clone(),
+                    })
+                    .collect();
+                (package.name().clone(), dependencies)
+            })
+            .collect()
+    }
+
     /// Record the conflicting groups that were used to generate this lock.
     #[must_use]
     pub fn with_conflicts(mut self, conflicts: Conflicts) -> Self {
@@ -704,6 +791,13 @@ impl Lock {
         self
     }
 
+    /// Record the artifact policies of the indexes that were used to generate this lock.
+    #[must_use]
+    pub fn with_index_artifact_policies(mut self, policies: IndexArtifactPolicies) -> Self {
+        self.options.index_artifact_policies = policies;
+        self
+    }
+
     /// Record the required platforms that were used to generate this lock.
     #[must_use]
     pub fn with_required_environments(mut self, required_environments: Vec<MarkerTree>) -> Self {
@@ -714,6 +808,34 @@ impl Lock {
         self
     }
 
+    /// Returns `true` if this [`Lock`] is equal to `other` up to the order of its entries.
+    ///
+    /// Packages and their dependencies are already kept in a canonical order, but wheels and
+    /// markers retain the order in which they were discovered, which can vary across resolutions
+    /// (e.g., if an index returns its files in a different order).
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        if self == other {
+            return true;
+        }
+        self.clone().into_canonical() == other.clone().into_canonical()
+    }
+
+    /// Sort any order-insensitive entries in the [`Lock`].
+    fn into_canonical(mut self) -> Self {
+        self.fork_markers.sort();
+        self.supported_environments
+            .sort_by_key(|marker| marker.try_to_string());
+        self.required_environments
+            .sort_by_key(|marker| marker.try_to_string());
+        for package in &mut self.packages {
+            package.fork_markers.sort();
+            package
+                .wheels
+                .sort_by(|wheel1, wheel2| wheel1.filename.cmp(&wheel2.filename));
+        }
+        self
+    }
+
     /// Returns `true` if this [`Lock`] includes `provides-extra` metadata.
     pub fn supports_provides_extra(&self) -> bool {
         // `provides-extra` was added in Version 1 Revision 1.
@@ -752,6 +874,25 @@ impl Lock {
         &self.packages
     }
 
+    /// Returns the source distributions of the packages in this lock that can be built from
+    /// source.
+    pub fn source_dists(
+        &self,
+        workspace_root: &Path,
+    ) -> Result<Vec<uv_distribution_types::SourceDist>, LockError> {
+        let mut source_dists = Vec::new();
+        for package in &self.packages {
+            // Virtual packages are never built.
+            if matches!(package.id.source, Source::Virtual(_)) {
+                continue;
+            }
+            if let Some(source_dist) = package.to_source_dist(workspace_root)? {
+                source_dists.push(source_dist);
+            }
+        }
+        Ok(source_dists)
+    }
+
     /// Returns the supported Python version range for the lockfile, if present.
     pub fn requires_python(&self) -> &RequiresPython {
         &self.requires_python
@@ -762,11 +903,26 @@ impl Lock {
         self.options.resolution_mode
     }
 
+    /// Returns the package-specific resolution modes used to generate this lock.
+    pub fn resolution_package(&self) -> &PackageResolutionModes {
+        &self.options.resolution_package
+    }
+
     /// Returns the pre-release mode used to generate this lock.
     pub fn prerelease_mode(&self) -> PrereleaseMode {
         self.options.prerelease_mode
     }
 
+    /// Returns the package-specific pre-release modes used to generate this lock.
+    pub fn prerelease_package(&self) -> &PackagePrereleaseModes {
+        &self.options.prerelease_package
+    }
+
+    /// Returns the package-specific local version preferences used to generate this lock.
+    pub fn local_version_preference(&self) -> &PackageLocalVersionPreferences {
+        &self.options.local_version_preference
+    }
+
     /// Returns the multi-version mode used to generate this lock.
     pub fn fork_strategy(&self) -> ForkStrategy {
         self.options.fork_strategy
@@ -779,6 +935,17 @@ impl Lock {
         self.options.exclude_newer.clone().into()
     }
 
+    /// Returns the index artifact policies that were used to generate this lock.
+    pub fn index_artifact_policies(&self) -> &IndexArtifactPolicies {
+        &self.options.index_artifact_policies
+    }
+
+    /// Returns the packages whose metadata was read from their distributions, rather than the
+    /// index, when generating this lock.
+    pub fn reresolve_metadata(&self) -> &BTreeSet<PackageName> {
+        &self.options.reresolve_metadata
+    }
+
     /// Returns the conflicting groups that were used to generate this lock.
     pub fn conflicts(&self) -> &Conflicts {
         &self.conflicts
@@ -1367,12 +1534,33 @@ impl Lock {
                     value(self.options.resolution_mode.to_string()),
                 );
             }
+            if !self.options.resolution_package.is_empty() {
+                let mut package_table = Table::new();
+                for (name, mode) in self.options.resolution_package.iter() {
+                    package_table.insert(name.as_ref(), value(mode.to_string()));
+                }
+                options_table.insert("resolution-package", Item::Table(package_table));
+            }
             if self.options.prerelease_mode != PrereleaseMode::default() {
                 options_table.insert(
                     "prerelease-mode",
                     value(self.options.prerelease_mode.to_string()),
                 );
             }
+            if !self.options.prerelease_package.is_empty() {
+                let mut package_table = Table::new();
+                for (name, mode) in self.options.prerelease_package.iter() {
+                    package_table.insert(name.as_ref(), value(mode.to_string()));
+                }
+                options_table.insert("prerelease-package", Item::Table(package_table));
+            }
+            if !self.options.local_version_preference.is_empty() {
+                let mut package_table = Table::new();
+                for (name, preference) in self.options.local_version_preference.iter() {
+                    package_table.insert(name.as_ref(), value(preference.to_string()));
+                }
+                options_table.insert("local-version-preference", Item::Table(package_table));
+            }
             if self.options.fork_strategy != ForkStrategy::default() {
                 options_table.insert(
                     "fork-strategy",
@@ -1430,6 +1618,41 @@ impl Lock {
                 }
             }
 
+            if !self.options.index_artifact_policies.is_empty() {
+                let policies = each_element_on_its_line_array(
+                    self.options
+                        .index_artifact_policies
+                        .0
+                        .iter()
+                        .map(|(source, policy)| {
+                            let mut table = InlineTable::new();
+                            let index = match source {
+                                RegistrySource::Url(url) => url.to_string(),
+                                RegistrySource::Path(path) => PortablePath::from(path).to_string(),
+                            };
+                            table.insert("index", Value::from(index));
+                            if !policy.allow_sdist {
+                                table.insert("allow-sdist", Value::from(false));
+                            }
+                            if !policy.allow_wheels {
+                                table.insert("allow-wheels", Value::from(false));
+                            }
+                            table
+                        }),
+                );
+                options_table.insert("index-artifact-policies", value(policies));
+            }
+
+            if !self.options.reresolve_metadata.is_empty() {
+                let packages = each_element_on_its_line_array(
+                    self.options
+                        .reresolve_metadata
+                        .iter()
+                        .map(ToString::to_string),
+                );
+                options_table.insert("reresolve-metadata", value(packages));
+            }
+
             if !options_table.is_empty() {
                 doc.insert("options", Item::Table(options_table));
             }
@@ -1528,6 +1751,18 @@ impl Lock {
                 manifest_table.insert("excludes", value(excludes));
             }
 
+            if !self.manifest.replacements.is_empty() {
+                let mut replacements = Table::new();
+                for (name, replacement) in &self.manifest.replacements {
+                    let replacement = serde::Serialize::serialize(
+                        &replacement,
+                        toml_edit::ser::ValueSerializer::new(),
+                    )?;
+                    replacements.insert(name.as_ref(), value(replacement));
+                }
+                manifest_table.insert("replacements", Item::Table(replacements));
+            }
+
             if !self.manifest.build_constraints.is_empty() {
                 let build_constraints = self
                     .manifest
@@ -1817,6 +2052,7 @@ impl Lock {
         constraints: &[Requirement],
         overrides: &[Override<Requirement>],
         excludes: &[ExcludeDependency],
+        replacements: &Replacements,
         build_constraints: &[Requirement],
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         dependency_metadata: &DependencyMetadata,
@@ -1962,6 +2198,38 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same replacements.
+        {
+            let normalize = |(name, replacement): (PackageName, Replacement<Requirement>)| {
+                Ok::<_, LockError>((
+                    name,
+                    Replacement {
+                        requirement: normalize_requirement(
+                            replacement.requirement,
+                            root,
+                            &self.requires_python,
+                        )?,
+                        provides: replacement.provides,
+                    },
+                ))
+            };
+            let expected: BTreeMap<_, _> = replacements
+                .iter()
+                .map(|(name, replacement)| (name.clone(), replacement.clone()))
+                .map(normalize)
+                .collect::<Result<_, _>>()?;
+            let actual: BTreeMap<_, _> = self
+                .manifest
+                .replacements
+                .clone()
+                .into_iter()
+                .map(normalize)
+                .collect::<Result<_, _>>()?;
+            if expected != actual {
+                return Ok(SatisfiesResult::MismatchedReplacements(expected, actual));
+            }
+        }
+
         // Validate that the lockfile was generated with the same build constraints.
         {
             let expected: BTreeSet<_> = build_constraints
@@ -2663,6 +2931,11 @@ pub enum SatisfiesResult<'lock> {
     ),
     /// The lockfile uses a different set of excludes.
     MismatchedExcludes(BTreeSet<ExcludeDependency>, BTreeSet<ExcludeDependency>),
+    /// The lockfile uses a different set of replacements.
+    MismatchedReplacements(
+        BTreeMap<PackageName, Replacement<Requirement>>,
+        BTreeMap<PackageName, Replacement<Requirement>>,
+    ),
     /// The lockfile uses a different set of build constraints.
     MismatchedBuildConstraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of dependency groups.
@@ -2710,15 +2983,84 @@ struct ResolverOptions {
     /// The [`ResolutionMode`] used to generate this lock.
     #[serde(default)]
     resolution_mode: ResolutionMode,
+    /// The package-specific [`ResolutionMode`] overrides used to generate this lock.
+    #[serde(default)]
+    resolution_package: PackageResolutionModes,
     /// The [`PrereleaseMode`] used to generate this lock.
     #[serde(default)]
     prerelease_mode: PrereleaseMode,
+    /// The package-specific [`PrereleaseMode`] overrides used to generate this lock.
+    #[serde(default)]
+    prerelease_package: PackagePrereleaseModes,
+    /// The package-specific local version preferences used to generate this lock.
+    #[serde(default)]
+    local_version_preference: PackageLocalVersionPreferences,
     /// The [`ForkStrategy`] used to generate this lock.
     #[serde(default)]
     fork_strategy: ForkStrategy,
     /// The [`ExcludeNewer`] setting used to generate this lock.
     #[serde(flatten)]
     exclude_newer: ExcludeNewerWire,
+    /// The [`IndexArtifactPolicies`] used to generate this lock.
+    #[serde(default)]
+    index_artifact_policies: IndexArtifactPolicies,
+    /// The packages whose metadata was read from their distributions, rather than the index.
+    #[serde(default)]
+    reresolve_metadata: BTreeSet<PackageName>,
+}
+
+/// The artifact policies of the indexes used to generate a lock, limited to those indexes that
+/// restrict the types of artifacts that may be selected (e.g., `allow-sdist = false`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(from = "Vec<IndexArtifactPolicyWire>")]
+pub struct IndexArtifactPolicies(BTreeMap<RegistrySource, IndexArtifactPolicy>);
+
+impl IndexArtifactPolicies {
+    /// Collect the artifact policies for the given [`IndexLocations`].
+    pub fn from_locations(
+        index_locations: &IndexLocations,
+        root: &Path,
+    ) -> Result<Self, LockError> {
+        let mut policies = BTreeMap::new();
+        for (index, policy) in index_locations.artifact_policies() {
+            let Source::Registry(source) = Source::from_index_url(index, root)? else {
+                continue;
+            };
+            policies.entry(source).or_insert(policy);
+        }
+        Ok(Self(policies))
+    }
+
+    /// Returns `true` if no index restricts the types of artifacts that may be selected.
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct IndexArtifactPolicyWire {
+    index: RegistrySourceWire,
+    allow_sdist: Option<bool>,
+    allow_wheels: Option<bool>,
+}
+
+impl From<Vec<IndexArtifactPolicyWire>> for IndexArtifactPolicies {
+    fn from(wire: Vec<IndexArtifactPolicyWire>) -> Self {
+        Self(
+            wire.into_iter()
+                .map(|wire| {
+                    (
+                        RegistrySource::from(wire.index),
+                        IndexArtifactPolicy {
+                            allow_sdist: wire.allow_sdist.unwrap_or(true),
+                            allow_wheels: wire.allow_wheels.unwrap_or(true),
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
 }
 
 #[expect(clippy::struct_field_names)]
@@ -2793,6 +3135,9 @@ pub struct ResolverManifest {
     /// The excludes provided to the resolver.
     #[serde(default)]
     excludes: BTreeSet<ExcludeDependency>,
+    /// The replacements provided to the resolver.
+    #[serde(default)]
+    replacements: BTreeMap<PackageName, Replacement<Requirement>>,
     /// The build constraints provided to the resolver.
     #[serde(default)]
     build_constraints: BTreeSet<Requirement>,
@@ -2820,6 +3165,7 @@ impl ResolverManifest {
             constraints: constraints.into_iter().collect(),
             overrides: overrides.into_iter().collect(),
             excludes: excludes.into_iter().collect(),
+            replacements: BTreeMap::new(),
             build_constraints: build_constraints.into_iter().collect(),
             dependency_groups: dependency_groups
                 .into_iter()
@@ -2829,6 +3175,16 @@ impl ResolverManifest {
         }
     }
 
+    /// Set the replacements provided to the resolver.
+    #[must_use]
+    pub fn with_replacements(mut self, replacements: &Replacements) -> Self {
+        self.replacements = replacements
+            .iter()
+            .map(|(name, replacement)| (name.clone(), replacement.clone()))
+            .collect();
+        self
+    }
+
     /// Convert the manifest to a relative form using the given workspace.
     pub fn relative_to(self, root: &Path) -> Result<Self, io::Error> {
         Ok(Self {
@@ -2863,6 +3219,19 @@ impl ResolverManifest {
                 })
                 .collect::<Result<BTreeSet<_>, io::Error>>()?,
             excludes: self.excludes,
+            replacements: self
+                .replacements
+                .into_iter()
+                .map(|(name, replacement)| {
+                    Ok::<_, io::Error>((
+                        name,
+                        Replacement {
+                            requirement: replacement.requirement.relative_to(root)?,
+                            provides: replacement.provides,
+                        },
+                    ))
+                })
+                .collect::<Result<BTreeMap<_, _>, _>>()?,
             build_constraints: self
                 .build_constraints
                 .into_iter()
@@ -3011,6 +3380,8 @@ pub struct Package {
     optional_dependencies: BTreeMap<ExtraName, Vec<Dependency>>,
     /// The resolved PEP 735 dependency groups of the package.
     dependency_groups: BTreeMap<GroupName, Vec<Dependency>>,
+    /// The locked build dependencies of the package, if build dependencies are locked.
+    build_dependencies: Vec<LockedBuildDependency>,
     /// The exact requirements from the package metadata.
     metadata: PackageMetadata,
 }
@@ -3080,6 +3451,7 @@ impl Package {
             dependencies: vec![],
             optional_dependencies: BTreeMap::default(),
             dependency_groups: BTreeMap::default(),
+            build_dependencies: vec![],
             metadata: PackageMetadata {
                 requires_dist,
                 provides_extra,
@@ -3541,7 +3913,7 @@ impl Package {
                 };
                 uv_distribution_types::SourceDist::DirectUrl(direct_dist)
             }
-            Source::Registry(RegistrySource::Url(url)) => {
+            Source::Registry(source @ RegistrySource::Url(url)) => {
                 let Some(ref sdist) = self.sdist else {
                     return Ok(None);
                 };
@@ -3586,17 +3958,25 @@ impl Package {
                     url.to_url().map_err(LockErrorKind::InvalidUrl)?,
                 ));
 
+                // Track the locked wheels, to explain why the source distribution was selected
+                // (e.g., on build failure).
+                let wheels = self
+                    .wheels
+                    .iter()
+                    .map(|wheel| wheel.to_registry_wheel(source, workspace_root))
+                    .collect::<Result<_, LockError>>()?;
+
                 let reg_dist = RegistrySourceDist {
                     name: name.clone(),
                     version: version.clone(),
                     file,
                     ext,
                     index,
-                    wheels: vec![],
+                    wheels,
                 };
                 uv_distribution_types::SourceDist::Registry(reg_dist)
             }
-            Source::Registry(RegistrySource::Path(path)) => {
+            Source::Registry(source @ RegistrySource::Path(path)) => {
                 let Some(ref sdist) = self.sdist else {
                     return Ok(None);
                 };
@@ -3662,13 +4042,19 @@ impl Package {
                         .map_err(LockErrorKind::RegistryVerbatimUrl)?,
                 );
 
+                let wheels = self
+                    .wheels
+                    .iter()
+                    .map(|wheel| wheel.to_registry_wheel(source, workspace_root))
+                    .collect::<Result<_, LockError>>()?;
+
                 let reg_dist = RegistrySourceDist {
                     name: name.clone(),
                     version: version.clone(),
                     file,
                     ext,
                     index,
-                    wheels: vec![],
+                    wheels,
                 };
                 uv_distribution_types::SourceDist::Registry(reg_dist)
             }
@@ -3751,6 +4137,16 @@ impl Package {
             table.insert("wheels", value(wheels));
         }
 
+        if !self.build_dependencies.is_empty() {
+            let build_dependencies = self
+                .build_dependencies
+                .iter()
+                .map(build_dependency_to_toml)
+                .collect::<Result<Vec<_>, _>>()?;
+            let build_dependencies = each_element_on_its_line_array(build_dependencies.iter());
+            table.insert("build-dependencies", value(build_dependencies));
+        }
+
         // Write the package metadata, if non-empty.
         {
             let mut metadata_table = Table::new();
@@ -3904,6 +4300,17 @@ impl Package {
         }
     }
 
+    /// Returns the absolute path to the package's source tree, if it is a local directory (e.g., a
+    /// workspace member or a path dependency).
+    pub fn source_tree(&self, root: &Path) -> Option<PathBuf> {
+        match &self.id.source {
+            Source::Directory(path) | Source::Editable(path) | Source::Virtual(path) => {
+                Some(root.join(path))
+            }
+            _ => None,
+        }
+    }
+
     /// Returns all the hashes associated with this [`Package`].
     fn hashes(&self) -> HashDigests {
         let mut hashes = Vec::with_capacity(
@@ -4017,6 +4424,48 @@ struct PackageWire {
     optional_dependencies: BTreeMap<ExtraName, Vec<DependencyWire>>,
     #[serde(default, rename = "dev-dependencies", alias = "dependency-groups")]
     dependency_groups: BTreeMap<GroupName, Vec<DependencyWire>>,
+    #[serde(default)]
+    build_dependencies: Vec<BuildDependencyWire>,
+}
+
+/// A locked build dependency of a package, as represented in `uv.lock`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct BuildDependencyWire {
+    #[serde(flatten)]
+    requirement: Requirement,
+    #[serde(default)]
+    hashes: Vec<Hash>,
+}
+
+impl From<BuildDependencyWire> for LockedBuildDependency {
+    fn from(wire: BuildDependencyWire) -> Self {
+        Self {
+            requirement: wire.requirement,
+            hashes: wire.hashes.into_iter().map(|hash| hash.0).collect(),
+        }
+    }
+}
+
+/// Returns the TOML representation of a locked build dependency.
+fn build_dependency_to_toml(
+    dependency: &LockedBuildDependency,
+) -> Result<Value, toml_edit::ser::Error> {
+    let mut value = serde::Serialize::serialize(
+        &dependency.requirement,
+        toml_edit::ser::ValueSerializer::new(),
+    )?;
+    if !dependency.hashes.is_empty() {
+        let hashes = dependency
+            .hashes
+            .iter()
+            .map(|hash| Value::from(Hash::from(hash.clone()).to_string()))
+            .collect::<Array>();
+        if let Value::InlineTable(table) = &mut value {
+            table.insert("hashes", Value::Array(hashes));
+        }
+    }
+    Ok(value)
 }
 
 #[derive(Clone, Default, Debug, Eq, PartialEq, serde::Deserialize)]
@@ -4084,6 +4533,11 @@ impl PackageWire {
                 .into_iter()
                 .map(|(group, deps)| Ok((group, unwire_deps(deps)?)))
                 .collect::<Result<_, LockError>>()?,
+            build_dependencies: self
+                .build_dependencies
+                .into_iter()
+                .map(LockedBuildDependency::from)
+                .collect(),
         })
     }
 }
@@ -5714,6 +6168,8 @@ pub struct Dependency {
     /// the PEP 508 reachability of its parent package. It can be evaluated while traversing
     /// dependencies from that package.
     complexified_marker: UniversalMarker,
+    /// The package that this dependency was substituted for via `tool.uv.replace`, if any.
+    replaces: Option<PackageName>,
 }
 
 impl Dependency {
@@ -5731,6 +6187,7 @@ impl Dependency {
             extra,
             simplified_marker,
             complexified_marker: UniversalMarker::from_combined(complexified_marker),
+            replaces: None,
         }
     }
 
@@ -5776,6 +6233,9 @@ impl Dependency {
         {
             table.insert("marker", value(marker));
         }
+        if let Some(replaces) = &self.replaces {
+            table.insert("replaces", value(replaces.to_string()));
+        }
 
         table
     }
@@ -5789,6 +6249,11 @@ impl Dependency {
     pub fn extra(&self) -> &BTreeSet<ExtraName> {
         &self.extra
     }
+
+    /// Returns the package that this dependency replaces, if any.
+    pub fn replaces(&self) -> Option<&PackageName> {
+        self.replaces.as_ref()
+    }
 }
 
 impl Display for Dependency {
@@ -5823,6 +6288,8 @@ struct DependencyWire {
     extra: BTreeSet<ExtraName>,
     #[serde(default)]
     marker: SimplifiedMarkerTree,
+    #[serde(default)]
+    replaces: Option<PackageName>,
 }
 
 impl DependencyWire {
@@ -5840,6 +6307,7 @@ impl DependencyWire {
             extra: self.extra,
             simplified_marker,
             complexified_marker: UniversalMarker::from_combined(complexified_marker),
+            replaces: self.replaces,
         })
     }
 }
@@ -5959,6 +6427,40 @@ fn normalize_url(mut url: DisplaySafeUrl) -> UrlString {
     UrlString::from(url)
 }
 
+/// Returns the package that a dependency of the given distribution was substituted for, if the
+/// dependency was introduced by a replacement rather than requested directly.
+fn replaced_package(
+    dist: &AnnotatedDist,
+    dependency: &Dependency,
+    replacements: &Replacements,
+) -> Option<PackageName> {
+    if replacements.is_empty() {
+        return None;
+    }
+    let metadata = dist.metadata.as_ref()?;
+    let requirements = if let Some(group) = &dist.group {
+        Either::Left(metadata.dependency_groups.get(group).into_iter().flatten())
+    } else {
+        Either::Right(metadata.requires_dist.iter())
+    };
+    let mut replaced = None;
+    for requirement in requirements {
+        if requirement.name == *dependency.package_name() {
+            return None;
+        }
+        if replaced.is_none()
+            && replacements
+                .get(&requirement.name)
+                .is_some_and(|replacement| {
+                    replacement.requirement.name == *dependency.package_name()
+                })
+        {
+            replaced = Some(requirement.name.clone());
+        }
+    }
+    replaced
+}
+
 /// Normalize a [`Requirement`], which could come from a lockfile, a `pyproject.toml`, etc.
 ///
 /// Performs the following steps:
@@ -6260,6 +6762,11 @@ enum WheelTagHint {
         tags: BTreeSet<PlatformTag>,
         best: Option<PlatformTag>,
         markers: MarkerEnvironment,
+        /// Whether the target platform uses musl libc, while all available wheels require glibc.
+        musl: bool,
+        /// Whether the target platform was requested (e.g., with `--python-platform`), rather than
+        /// derived from the current environment.
+        is_cross: bool,
     },
 }
 
@@ -6329,12 +6836,16 @@ impl WheelTagHint {
                 if incompatible_tags.is_empty() {
                     None
                 } else {
+                    let musl = matches!(tags.python_platform().os(), Os::Musllinux { .. })
+                        && incompatible_tags.iter().all(PlatformTag::is_manylinux);
                     Some(Self::PlatformTags {
                         package: name.clone(),
                         version: version.cloned(),
                         tags: incompatible_tags,
                         best,
                         markers: markers.clone(),
+                        musl,
+                        is_cross: tags.is_cross(),
                     })
                 }
             }
@@ -6516,6 +7027,8 @@ impl std::fmt::Display for WheelTagHint {
                 tags,
                 best,
                 markers,
+                musl,
+                is_cross,
             } => {
                 let s = if tags.len() == 1 { "" } else { "s" };
                 if let Some(best) = best {
@@ -6540,7 +7053,7 @@ impl std::fmt::Display for WheelTagHint {
                             .join(", "),
                         format!("\"{example_marker}\"").cyan(),
                         "tool.uv.required-environments".green()
-                    )
+                    )?;
                 } else {
                     if let Some(version) = version {
                         write!(
@@ -6551,7 +7064,7 @@ impl std::fmt::Display for WheelTagHint {
                             tags.iter()
                                 .map(|tag| format!("`{}`", tag.cyan()))
                                 .join(", "),
-                        )
+                        )?;
                     } else {
                         write!(
                             f,
@@ -6560,9 +7073,24 @@ impl std::fmt::Display for WheelTagHint {
                             tags.iter()
                                 .map(|tag| format!("`{}`", tag.cyan()))
                                 .join(", "),
-                        )
+                        )?;
                     }
                 }
+                if *musl {
+                    write!(
+                        f,
+                        "; {} uses musl libc (`{}` tags), but `{}` only publishes `{}` wheels, which require glibc",
+                        if *is_cross {
+                            "the target platform"
+                        } else {
+                            "your environment"
+                        },
+                        "musllinux".cyan(),
+                        package.cyan(),
+                        "manylinux".cyan(),
+                    )?;
+                }
+                Ok(())
             }
         }
     }
@@ -7812,4 +8340,43 @@ wheels = [
             ))
         );
     }
+
+    /// Locks that differ only in the order of their wheels and markers are equivalent.
+    #[test]
+    fn is_equivalent_ignores_order() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+resolution-markers = [
+    "python_full_version >= '3.13'",
+    "python_full_version < '3.13'",
+]
+
+[[package]]
+name = "a"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://example.com/a-0.1.0-py2-none-any.whl", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 },
+    { url = "https://example.com/a-0.1.0-py3-none-any.whl", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 },
+]
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+
+        let reordered = data
+            .replace(
+                "    \"python_full_version >= '3.13'\",\n    \"python_full_version < '3.13'\",\n",
+                "    \"python_full_version < '3.13'\",\n    \"python_full_version >= '3.13'\",\n",
+            )
+            .replace("py2-none-any", "py4-none-any")
+            .replace("py3-none-any", "py2-none-any")
+            .replace("py4-none-any", "py3-none-any");
+        assert_ne!(data, reordered);
+        let reordered: Lock = toml::from_str(&reordered).unwrap();
+        assert!(lock.is_equivalent(&reordered));
+
+        // A lock with a different set of wheels is not equivalent.
+        let changed: Lock = toml::from_str(&data.replace("py2-none-any", "py4-none-any")).unwrap();
+        assert!(!lock.is_equivalent(&changed));
+    }
 }
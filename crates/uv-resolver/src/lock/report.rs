@@ -0,0 +1,426 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+
+use itertools::Itertools;
+use jiff::Timestamp;
+use serde::Serialize;
+
+use uv_configuration::Override;
+use uv_distribution_types::{DistributionId, Requirement, RequirementSource};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::lock::{Lock, Package, Source, SourceDist};
+use crate::{InMemoryIndex, MetadataResponse, VersionMap, VersionsResponse};
+
+/// A report of the changes to a lockfile, for use in a pull request description (e.g.,
+/// `uv lock --upgrade --preview-report report.md`).
+///
+/// The report is derived from the lockfiles and the package metadata that was fetched while
+/// locking, so generating it doesn't require any further network requests. As such, packages are
+/// only reported as held back if their available versions were fetched during resolution.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LockReport {
+    /// The changes to the direct dependencies of the workspace.
+    direct: Vec<PackageChange>,
+    /// The changes to the transitive dependencies of the workspace.
+    transitive: Vec<PackageChange>,
+    /// The packages that were held back from their latest version by a requirement.
+    held_back: Vec<HeldBackPackage>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct PackageChange {
+    name: PackageName,
+    /// The versions of the package in the previous lockfile.
+    previous: BTreeSet<Version>,
+    /// The versions of the package in the updated lockfile.
+    current: BTreeSet<Version>,
+    /// The upload time of the latest version in the updated lockfile, if the index provided one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    released: Option<Timestamp>,
+    /// A link to the PyPI project page for the latest version in the updated lockfile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct HeldBackPackage {
+    name: PackageName,
+    /// The locked version of the package.
+    version: Version,
+    /// The latest available version of the package.
+    latest: Version,
+    /// The requirements that exclude the latest version.
+    constraints: BTreeSet<HeldBackBy>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+struct HeldBackBy {
+    /// The declarer of the requirement: a package (e.g., `foo==1.0.0`), or `constraints` or
+    /// `overrides` for the user-provided constraints and overrides.
+    source: String,
+    /// The requirement that excludes the latest version.
+    requirement: String,
+}
+
+impl LockReport {
+    /// Report the changes between an (optional) existing lockfile and an updated lockfile.
+    ///
+    /// The [`InMemoryIndex`] should be the index used to produce the updated lockfile, and `root`
+    /// is the workspace root, against which relative index paths are resolved.
+    pub fn from_locks(
+        previous: Option<&Lock>,
+        lock: &Lock,
+        index: &InMemoryIndex,
+        root: &Path,
+    ) -> Self {
+        let previous_versions = previous.map(locked_versions).unwrap_or_default();
+        let current_versions = locked_versions(lock);
+        let mut direct_names = direct_dependencies(lock);
+        if let Some(previous) = previous {
+            direct_names.extend(direct_dependencies(previous));
+        }
+
+        let mut direct = Vec::new();
+        let mut transitive = Vec::new();
+        let names = previous_versions
+            .keys()
+            .chain(current_versions.keys())
+            .collect::<BTreeSet<_>>();
+        for name in names {
+            let previous = previous_versions.get(name).cloned().unwrap_or_default();
+            let current = current_versions.get(name).cloned().unwrap_or_default();
+            if previous == current {
+                continue;
+            }
+
+            // Describe the latest version in the updated lockfile.
+            let latest = current.last().and_then(|version| {
+                lock.packages()
+                    .iter()
+                    .find(|package| package.name() == name && package.version() == Some(version))
+            });
+            let released = latest.and_then(release_time);
+            let url = latest
+                .filter(|package| package.is_from_pypi_registry())
+                .and_then(|package| {
+                    Some(format!(
+                        "https://pypi.org/project/{name}/{}/",
+                        package.version()?
+                    ))
+                });
+
+            let change = PackageChange {
+                name: name.clone(),
+                previous,
+                current,
+                released,
+                url,
+            };
+            if direct_names.contains(name) {
+                direct.push(change);
+            } else {
+                transitive.push(change);
+            }
+        }
+
+        let held_back = held_back(lock, index, root);
+
+        Self {
+            direct,
+            transitive,
+            held_back,
+        }
+    }
+
+    /// Returns `true` if the report contains no changes and no held back packages.
+    pub fn is_empty(&self) -> bool {
+        self.direct.is_empty() && self.transitive.is_empty() && self.held_back.is_empty()
+    }
+
+    /// Render the report as Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        writeln!(output, "## Dependency updates").unwrap();
+
+        if self.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "No dependencies were updated.").unwrap();
+            return output;
+        }
+
+        for (title, changes) in [
+            ("Direct dependencies", &self.direct),
+            ("Transitive dependencies", &self.transitive),
+        ] {
+            if changes.is_empty() {
+                continue;
+            }
+            writeln!(output).unwrap();
+            writeln!(output, "### {title}").unwrap();
+            writeln!(output).unwrap();
+            writeln!(output, "| Package | Version | Released |").unwrap();
+            writeln!(output, "| --- | --- | --- |").unwrap();
+            for change in changes {
+                let package = match &change.url {
+                    Some(url) => format!("[`{}`]({url})", change.name),
+                    None => format!("`{}`", change.name),
+                };
+                let version = match (change.previous.is_empty(), change.current.is_empty()) {
+                    (true, _) => format!("{} (added)", change.current.iter().join(", ")),
+                    (_, true) => format!("{} (removed)", change.previous.iter().join(", ")),
+                    _ => format!(
+                        "{} → {}",
+                        change.previous.iter().join(", "),
+                        change.current.iter().join(", ")
+                    ),
+                };
+                let released = change
+                    .released
+                    .map(|released| released.strftime("%Y-%m-%d").to_string())
+                    .unwrap_or_default();
+                writeln!(output, "| {package} | {version} | {released} |").unwrap();
+            }
+        }
+
+        if !self.held_back.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "### Held back").unwrap();
+            writeln!(output).unwrap();
+            writeln!(output, "| Package | Locked | Latest | Held back by |").unwrap();
+            writeln!(output, "| --- | --- | --- | --- |").unwrap();
+            for package in &self.held_back {
+                let constraints = package
+                    .constraints
+                    .iter()
+                    .map(|constraint| {
+                        format!("`{}` ({})", constraint.requirement, constraint.source)
+                    })
+                    .join("<br>");
+                writeln!(
+                    output,
+                    "| `{}` | {} | {} | {constraints} |",
+                    package.name, package.version, package.latest
+                )
+                .unwrap();
+            }
+        }
+
+        output
+    }
+
+    /// Render the report as JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Returns `true` if the package is a workspace member (or another local project), rather than a
+/// dependency.
+fn is_member(lock: &Lock, package: &Package) -> bool {
+    lock.members().contains(package.name())
+        || matches!(package.id.source, Source::Editable(_) | Source::Virtual(_))
+}
+
+/// Collect the versions of each dependency in the lockfile, excluding the workspace members.
+fn locked_versions(lock: &Lock) -> BTreeMap<PackageName, BTreeSet<Version>> {
+    let mut versions: BTreeMap<PackageName, BTreeSet<Version>> = BTreeMap::new();
+    for package in lock.packages() {
+        if is_member(lock, package) {
+            continue;
+        }
+        let Some(version) = package.version() else {
+            continue;
+        };
+        versions
+            .entry(package.name().clone())
+            .or_default()
+            .insert(version.clone());
+    }
+    versions
+}
+
+/// Collect the names of the direct dependencies of the workspace members, along with any
+/// requirements that are attached to the workspace itself (e.g., for a PEP 723 script).
+fn direct_dependencies(lock: &Lock) -> BTreeSet<PackageName> {
+    let mut names = BTreeSet::new();
+    for package in lock.packages() {
+        if !is_member(lock, package) {
+            continue;
+        }
+        let dependencies = package
+            .dependencies()
+            .iter()
+            .chain(package.optional_dependencies().values().flatten())
+            .chain(package.resolved_dependency_groups().values().flatten());
+        names.extend(dependencies.map(|dependency| dependency.package_name().clone()));
+    }
+    names.extend(
+        lock.requirements()
+            .iter()
+            .chain(lock.dependency_groups().values().flatten())
+            .map(|requirement| requirement.name.clone()),
+    );
+    names
+}
+
+/// Return the release time of a package, i.e., the earliest upload time of its distributions.
+fn release_time(package: &Package) -> Option<Timestamp> {
+    package
+        .sdist
+        .iter()
+        .filter_map(SourceDist::upload_time)
+        .chain(package.wheels.iter().filter_map(|wheel| wheel.upload_time))
+        .min()
+}
+
+/// Identify the packages that were held back from their latest version by a requirement.
+fn held_back(lock: &Lock, index: &InMemoryIndex, root: &Path) -> Vec<HeldBackPackage> {
+    let mut held_back = Vec::new();
+    let mut seen = BTreeSet::new();
+    for package in lock.packages() {
+        if is_member(lock, package) {
+            continue;
+        }
+        let Some(version) = package.version() else {
+            continue;
+        };
+        if !seen.insert((package.name(), version)) {
+            continue;
+        }
+        let Some(latest) = latest_version(package, index, root) else {
+            continue;
+        };
+        if latest <= *version {
+            continue;
+        }
+
+        // Find the requirements that exclude the latest version, across the dependents of the
+        // package and the user-provided constraints and overrides.
+        let excludes = |requirement: &Requirement| {
+            requirement.name == *package.name()
+                && matches!(
+                    &requirement.source,
+                    RequirementSource::Registry { specifier, .. } if !specifier.contains(&latest)
+                )
+        };
+        let mut constraints = BTreeSet::new();
+        for dependent in lock.packages() {
+            let depends = dependent
+                .dependencies()
+                .iter()
+                .chain(dependent.optional_dependencies().values().flatten())
+                .chain(dependent.resolved_dependency_groups().values().flatten())
+                .any(|dependency| dependency.package_name() == package.name());
+            if !depends {
+                continue;
+            }
+            let source = match dependent.version() {
+                Some(version) if !is_member(lock, dependent) => {
+                    format!("{}=={version}", dependent.name())
+                }
+                _ => dependent.name().to_string(),
+            };
+            for requirement in requirements(dependent, index) {
+                if excludes(&requirement) {
+                    constraints.insert(HeldBackBy {
+                        source: source.clone(),
+                        requirement: requirement.to_string(),
+                    });
+                }
+            }
+        }
+        for requirement in &lock.manifest.constraints {
+            if excludes(requirement) {
+                constraints.insert(HeldBackBy {
+                    source: "constraints".to_string(),
+                    requirement: requirement.to_string(),
+                });
+            }
+        }
+        for entry in &lock.manifest.overrides {
+            if let Override::Requirement(requirement) = entry {
+                if excludes(requirement) {
+                    constraints.insert(HeldBackBy {
+                        source: "overrides".to_string(),
+                        requirement: requirement.to_string(),
+                    });
+                }
+            }
+        }
+
+        if !constraints.is_empty() {
+            held_back.push(HeldBackPackage {
+                name: package.name().clone(),
+                version: version.clone(),
+                latest,
+                constraints,
+            });
+        }
+    }
+    held_back
+}
+
+/// Return the latest version of a registry package that was fetched during resolution.
+///
+/// Pre-releases are only considered if the locked version is itself a pre-release.
+fn latest_version(package: &Package, index: &InMemoryIndex, root: &Path) -> Option<Version> {
+    let locked = package.version()?;
+    let index_url = package.index(root).ok().flatten()?;
+    let response = index
+        .explicit()
+        .get(&(package.name().clone(), index_url))
+        .or_else(|| index.implicit().get(package.name()))?;
+    let VersionsResponse::Found(version_maps) = &*response else {
+        return None;
+    };
+    version_maps
+        .iter()
+        .flat_map(VersionMap::versions)
+        .filter(|version| locked.any_prerelease() || !version.any_prerelease())
+        .max()
+        .cloned()
+}
+
+/// Return the requirements declared by a package.
+///
+/// The lockfile only records the requirements of local packages, so the requirements of registry
+/// packages are read from the metadata that was fetched during resolution, if any.
+fn requirements(package: &Package, index: &InMemoryIndex) -> Vec<Requirement> {
+    if !package.metadata.requires_dist.is_empty() || !package.metadata.dependency_groups.is_empty()
+    {
+        return package
+            .metadata
+            .requires_dist
+            .iter()
+            .chain(package.metadata.dependency_groups.values().flatten())
+            .cloned()
+            .collect();
+    }
+
+    // The metadata is keyed by the hash of the distribution it was read from.
+    let hashes = package.sdist.iter().filter_map(SourceDist::hash).chain(
+        package
+            .wheels
+            .iter()
+            .filter_map(|wheel| wheel.hash.as_ref()),
+    );
+    for hash in hashes {
+        let Some(response) = index
+            .distributions()
+            .get(&DistributionId::Digest(hash.0.clone()))
+        else {
+            continue;
+        };
+        if let MetadataResponse::Found(archive) = &*response {
+            return archive.metadata.requires_dist.to_vec();
+        }
+    }
+    Vec::new()
+}
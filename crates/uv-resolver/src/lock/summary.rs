@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+
+use uv_normalize::PackageName;
+
+use crate::Lock;
+use crate::lock::{Dependency, Package, simplified_universal_markers};
+
+/// A summary of the forks in a universal [`Lock`], for diagnosing why (and where) a resolution
+/// forked.
+#[derive(Debug)]
+pub struct ForkSummary {
+    /// The simplified marker of each fork, as written to `resolution-markers`.
+    forks: Vec<String>,
+    /// The packages that were locked to more than one version (or source).
+    packages: BTreeMap<PackageName, ForkedPackage>,
+}
+
+#[derive(Debug, Default)]
+struct ForkedPackage {
+    /// Each version of the package, with the markers of the forks that selected it.
+    versions: Vec<(String, Vec<String>)>,
+    /// The dependency edges onto the package, as `(dependent, requirement)`.
+    required_by: BTreeSet<(String, String)>,
+}
+
+impl ForkSummary {
+    /// Summarize the forks in the given [`Lock`].
+    pub fn from_lock(lock: &Lock) -> Self {
+        let forks = simplified_universal_markers(lock.fork_markers(), lock.requires_python());
+
+        // Identify any packages that were locked to multiple versions.
+        let mut counts: FxHashMap<&PackageName, usize> = FxHashMap::default();
+        for package in lock.packages() {
+            *counts.entry(package.name()).or_default() += 1;
+        }
+        let mut packages: BTreeMap<PackageName, ForkedPackage> = BTreeMap::new();
+        for package in lock.packages() {
+            if counts[package.name()] < 2 {
+                continue;
+            }
+            packages
+                .entry(package.name().clone())
+                .or_default()
+                .versions
+                .push((
+                    version_or_source(package),
+                    simplified_universal_markers(&package.fork_markers, lock.requires_python()),
+                ));
+        }
+
+        // Record the requirements on each package, since conflicting requirements (and the markers
+        // on them) are what cause the resolver to fork. Prefer the requirements as declared, rather
+        // than the locked dependencies, which are pinned to the selected versions.
+        for package in lock.packages() {
+            let dependent = format!("{} {}", package.name(), version_or_source(package));
+            let metadata = &package.metadata;
+            if metadata.requires_dist.is_empty() && metadata.dependency_groups.is_empty() {
+                let dependencies = package
+                    .dependencies()
+                    .iter()
+                    .chain(package.optional_dependencies().values().flatten())
+                    .chain(package.resolved_dependency_groups().values().flatten());
+                for dependency in dependencies {
+                    let Some(forked) = packages.get_mut(dependency.package_name()) else {
+                        continue;
+                    };
+                    forked
+                        .required_by
+                        .insert((dependent.clone(), unpinned(dependency)));
+                }
+            } else {
+                let requirements = metadata
+                    .requires_dist
+                    .iter()
+                    .chain(metadata.dependency_groups.values().flatten());
+                for requirement in requirements {
+                    let Some(forked) = packages.get_mut(&requirement.name) else {
+                        continue;
+                    };
+                    forked
+                        .required_by
+                        .insert((dependent.clone(), requirement.to_string()));
+                }
+            }
+        }
+
+        Self { forks, packages }
+    }
+
+    /// Returns `true` if the resolution didn't fork.
+    pub fn is_empty(&self) -> bool {
+        self.forks.is_empty() && self.packages.is_empty()
+    }
+}
+
+/// Display a locked [`Dependency`] without its pinned version, for packages that don't record the
+/// requirements they were resolved from (e.g., registry packages).
+fn unpinned(dependency: &Dependency) -> String {
+    let mut requirement = dependency.package_name().to_string();
+    if !dependency.extra.is_empty() {
+        requirement.push_str(&format!("[{}]", dependency.extra.iter().join(",")));
+    }
+    if let Some(marker) = dependency
+        .simplified_marker
+        .as_simplified_marker_tree()
+        .try_to_string()
+    {
+        requirement.push_str(&format!(" ; {marker}"));
+    }
+    requirement
+}
+
+/// Display the version of a [`Package`], or its source if it has no version.
+fn version_or_source(package: &Package) -> String {
+    match package.version() {
+        Some(version) => format!("v{version}"),
+        None => package.id.source.to_string(),
+    }
+}
+
+impl Display for ForkSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.forks.is_empty() {
+            writeln!(f, "{}", "Resolved without forking".bold())?;
+        } else {
+            writeln!(
+                f,
+                "{}",
+                format!("Resolved {} forks:", self.forks.len()).bold()
+            )?;
+            for fork in &self.forks {
+                writeln!(f, "  {}", fork.cyan())?;
+            }
+        }
+
+        if self.packages.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "{}", "Packages that differ across forks:".bold())?;
+        for (name, forked) in &self.packages {
+            writeln!(f, "  {}", name.bold())?;
+            for (version, markers) in &forked.versions {
+                if markers.is_empty() {
+                    writeln!(f, "    {version}")?;
+                } else {
+                    writeln!(f, "    {version} when {}", markers.join(" or ").cyan())?;
+                }
+            }
+            if !forked.required_by.is_empty() {
+                writeln!(f, "    required by:")?;
+                for (dependent, requirement) in &forked.required_by {
+                    writeln!(f, "      {dependent}: {requirement}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -111,7 +111,7 @@ impl<'env> TreeDisplay<'env> {
                 .or_insert_with(|| graph.add_node(Node::Package(id)));
 
             // Add an edge from the root.
-            graph.add_edge(root, index, Edge::Prod(None, UniversalMarker::TRUE));
+            graph.add_edge(root, index, Edge::Prod(None, UniversalMarker::TRUE, None));
 
             if groups.prod() {
                 // Push its dependencies on the queue.
@@ -163,6 +163,7 @@ impl<'env> TreeDisplay<'env> {
                         group,
                         Some(RequestedExtras::Dependency(&dep.extra)),
                         dep.complexified_marker,
+                        dep.replaces(),
                     ),
                 );
 
@@ -231,6 +232,7 @@ impl<'env> TreeDisplay<'env> {
                         Edge::Prod(
                             Some(RequestedExtras::Requirement(requirement.extras.as_ref())),
                             UniversalMarker::from_combined(marker),
+                            None,
                         ),
                     );
 
@@ -284,6 +286,7 @@ impl<'env> TreeDisplay<'env> {
                                 group,
                                 Some(RequestedExtras::Requirement(requirement.extras.as_ref())),
                                 UniversalMarker::from_combined(marker),
+                                None,
                             ),
                         );
 
@@ -343,11 +346,13 @@ impl<'env> TreeDisplay<'env> {
                             extra,
                             Some(RequestedExtras::Dependency(&dep.extra)),
                             dep.complexified_marker,
+                            dep.replaces(),
                         )
                     } else {
                         Edge::Prod(
                             Some(RequestedExtras::Dependency(&dep.extra)),
                             dep.complexified_marker,
+                            dep.replaces(),
                         )
                     },
                 );
@@ -498,7 +503,16 @@ impl<'env> TreeDisplay<'env> {
         };
 
         let line = {
-            let mut line = format!("{}", package_id.name);
+            let mut line = String::new();
+
+            // In inverted mode, the edge points to the dependent, so the replacement is omitted.
+            if !self.invert
+                && let Some(replaces) = edge.and_then(Edge::replaces)
+            {
+                let _ = write!(line, "{replaces} → ");
+            }
+
+            let _ = write!(line, "{}", package_id.name);
 
             if let Some(extras) = edge.and_then(Edge::extras) {
                 if !extras.is_empty() {
@@ -524,6 +538,9 @@ impl<'env> TreeDisplay<'env> {
                         let _ = write!(line, " (group: {group})");
                     }
                 }
+                if !self.invert && edge.replaces().is_some() {
+                    line.push_str(" (replaced)");
+                }
             }
 
             // Append compressed wheel size, if available in the lockfile.
@@ -750,25 +767,40 @@ enum Node<'env> {
 
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
 enum Edge<'env> {
-    Prod(Option<RequestedExtras<'env>>, UniversalMarker),
+    Prod(
+        Option<RequestedExtras<'env>>,
+        UniversalMarker,
+        Option<&'env PackageName>,
+    ),
     Optional(
         &'env ExtraName,
         Option<RequestedExtras<'env>>,
         UniversalMarker,
+        Option<&'env PackageName>,
     ),
     Dev(
         &'env GroupName,
         Option<RequestedExtras<'env>>,
         UniversalMarker,
+        Option<&'env PackageName>,
     ),
 }
 
 impl<'env> Edge<'env> {
     fn extras(&self) -> Option<RequestedExtras<'env>> {
         match self {
-            Self::Prod(extras, _) => *extras,
-            Self::Optional(_, extras, _) => *extras,
-            Self::Dev(_, extras, _) => *extras,
+            Self::Prod(extras, ..) => *extras,
+            Self::Optional(_, extras, ..) => *extras,
+            Self::Dev(_, extras, ..) => *extras,
+        }
+    }
+
+    /// The package that the dependency replaces, if it was substituted via `tool.uv.replace`.
+    fn replaces(&self) -> Option<&'env PackageName> {
+        match self {
+            Self::Prod(.., replaces) | Self::Optional(.., replaces) | Self::Dev(.., replaces) => {
+                *replaces
+            }
         }
     }
 
@@ -781,9 +813,9 @@ impl<'env> Edge<'env> {
 
     fn marker(&self) -> UniversalMarker {
         match self {
-            Self::Prod(_, marker) | Self::Optional(_, _, marker) | Self::Dev(_, _, marker) => {
-                *marker
-            }
+            Self::Prod(_, marker, _)
+            | Self::Optional(_, _, marker, _)
+            | Self::Dev(_, _, marker, _) => *marker,
         }
     }
 
@@ -3,7 +3,7 @@ use std::collections::BTreeSet;
 
 use either::Either;
 
-use uv_configuration::{Constraints, Excludes, Overrides};
+use uv_configuration::{Constraints, Excludes, Overrides, Replacements};
 use uv_distribution_types::Requirement;
 use uv_normalize::PackageName;
 use uv_types::RequestedRequirements;
@@ -26,6 +26,9 @@ pub struct Manifest {
     /// The dependency excludes for the project.
     pub(crate) excludes: Excludes,
 
+    /// The package replacements for the project.
+    pub(crate) replacements: Replacements,
+
     /// The preferences for the project.
     ///
     /// These represent "preferred" versions of a given package. For example, they may be the
@@ -70,6 +73,7 @@ impl Manifest {
             constraints,
             overrides,
             excludes,
+            replacements: Replacements::default(),
             preferences,
             project,
             workspace_members,
@@ -84,6 +88,7 @@ impl Manifest {
             constraints: Constraints::default(),
             overrides: Overrides::default(),
             excludes: Excludes::default(),
+            replacements: Replacements::default(),
             preferences: Preferences::default(),
             project: None,
             exclusions: Exclusions::default(),
@@ -92,20 +97,38 @@ impl Manifest {
         }
     }
 
+    /// Return the direct requirements for the project.
+    pub fn direct_requirements(&self) -> &[Requirement] {
+        &self.requirements
+    }
+
+    #[must_use]
+    pub fn with_requirements(mut self, requirements: Vec<Requirement>) -> Self {
+        self.requirements = requirements;
+        self
+    }
+
     #[must_use]
     pub fn with_constraints(mut self, constraints: Constraints) -> Self {
         self.constraints = constraints;
         self
     }
 
+    #[must_use]
+    pub fn with_replacements(mut self, replacements: Replacements) -> Self {
+        self.replacements = replacements;
+        self
+    }
+
     #[must_use]
     pub fn with_lookaheads(mut self, lookaheads: Vec<RequestedRequirements>) -> Self {
         self.lookaheads = lookaheads;
         self
     }
 
-    /// Return an iterator over all requirements, constraints, and overrides, in priority order,
-    /// such that requirements come first, followed by constraints, followed by overrides.
+    /// Return an iterator over all requirements, constraints, overrides, and replacements, in
+    /// priority order, such that requirements come first, followed by constraints, followed by
+    /// overrides, followed by replacements.
     ///
     /// At time of writing, this is used for:
     /// - Determining which requirements should allow yanked versions.
@@ -118,6 +141,7 @@ impl Manifest {
     ) -> impl Iterator<Item = Cow<'a, Requirement>> + 'a {
         self.requirements_no_overrides(env, mode)
             .chain(self.overrides(env, mode))
+            .chain(self.replacements(env))
     }
 
     /// Return all requirements that affect manifest-wide candidate selection policy.
@@ -239,6 +263,18 @@ impl Manifest {
         }
     }
 
+    /// Only the replacements from [`Self::requirements`].
+    pub(crate) fn replacements<'a>(
+        &'a self,
+        env: &'a ResolverEnvironment,
+    ) -> impl Iterator<Item = Cow<'a, Requirement>> + 'a {
+        self.replacements
+            .requirements()
+            .filter(|requirement| !self.excludes.contains(&requirement.name))
+            .filter(move |requirement| requirement.evaluate_markers(env.marker_environment(), &[]))
+            .map(Cow::Borrowed)
+    }
+
     /// Return an iterator over the names of all user-provided requirements.
     ///
     /// This includes:
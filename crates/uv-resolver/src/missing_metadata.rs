@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_distribution_types::IndexUrl;
+use uv_normalize::PackageName;
+use uv_warnings::warn_user;
+
+/// Optional metadata that a source may not provide, but that a check relies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MissingMetadataKind {
+    /// The upload time of a distribution, as used by `--exclude-newer`.
+    UploadTime,
+    /// The yank status of a distribution, as used by `--yanked forbid`.
+    Yanked,
+}
+
+impl MissingMetadataKind {
+    /// The user-facing name of the check that relies on the metadata.
+    fn check(self) -> &'static str {
+        match self {
+            Self::UploadTime => "--exclude-newer",
+            Self::Yanked => "--yanked forbid",
+        }
+    }
+
+    /// The user-facing name of the metadata.
+    fn metadata(self) -> &'static str {
+        match self {
+            Self::UploadTime => "upload times",
+            Self::Yanked => "yank status",
+        }
+    }
+}
+
+/// The policy to apply to packages from sources that lack the metadata required by a check.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MissingMetadataPolicy {
+    /// Include the packages, but warn once per source.
+    #[default]
+    Warn,
+    /// Fail with an error that lists the affected sources and packages.
+    Error,
+}
+
+impl MissingMetadataPolicy {
+    /// Returns the [`MissingMetadataPolicy`] for the given strictness (e.g.,
+    /// `--strict-exclude-newer`).
+    pub fn from_strict(strict: bool) -> Self {
+        if strict { Self::Error } else { Self::Warn }
+    }
+}
+
+/// The affected packages for each source and kind of missing metadata.
+type MissingMetadataSources = BTreeMap<(MissingMetadataKind, IndexUrl), BTreeSet<PackageName>>;
+
+/// The packages, by source, that were included without the metadata required by a check (e.g.,
+/// packages from a `--find-links` directory, which lack upload times, under `--exclude-newer`).
+///
+/// Packages are recorded as they're encountered (including concurrently, during resolution), and
+/// reported once, via [`MissingMetadata::report`], such that each source produces a single warning
+/// rather than one warning per file.
+#[derive(Debug, Default, Clone)]
+pub struct MissingMetadata(Arc<Mutex<MissingMetadataSources>>);
+
+impl MissingMetadata {
+    /// Record that the given package was included from a source that lacks the given metadata.
+    pub fn insert(&self, kind: MissingMetadataKind, index: &IndexUrl, package_name: &PackageName) {
+        self.0
+            .lock()
+            .unwrap()
+            .entry((kind, index.clone()))
+            .or_default()
+            .insert(package_name.clone());
+    }
+
+    /// Returns `true` if no packages were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().unwrap().is_empty()
+    }
+
+    /// Report the recorded packages under the given policy, either by emitting a warning for each
+    /// source or by returning an error.
+    pub fn report(&self, policy: MissingMetadataPolicy) -> Result<(), MissingMetadataError> {
+        let sources = std::mem::take(&mut *self.0.lock().unwrap());
+        if sources.is_empty() {
+            return Ok(());
+        }
+        match policy {
+            MissingMetadataPolicy::Warn => {
+                for ((kind, index), packages) in &sources {
+                    warn_user!(
+                        "`{}` could not be applied to packages from `{}`, which does not provide {}: {}",
+                        kind.check(),
+                        index.cyan(),
+                        kind.metadata(),
+                        packages
+                            .iter()
+                            .map(|package| format!("`{}`", package.cyan()))
+                            .join(", "),
+                    );
+                }
+                Ok(())
+            }
+            MissingMetadataPolicy::Error => Err(MissingMetadataError { sources }),
+        }
+    }
+}
+
+/// Packages were included from sources that lack the metadata required by a check, under a strict
+/// policy (e.g., `--strict-exclude-newer`).
+#[derive(Debug)]
+pub struct MissingMetadataError {
+    sources: MissingMetadataSources,
+}
+
+impl Display for MissingMetadataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let checks = self
+            .sources
+            .keys()
+            .map(|(kind, _)| format!("`{}`", kind.check()))
+            .unique()
+            .join(" and ");
+        write!(
+            f,
+            "{checks} could not be applied to packages from sources that lack the required metadata:"
+        )?;
+        for ((kind, index), packages) in &self.sources {
+            write!(
+                f,
+                "\n  {} (no {}): {}",
+                index.cyan(),
+                kind.metadata(),
+                packages.iter().map(|package| package.cyan()).join(", "),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MissingMetadataError {}
+
+impl uv_errors::Hint for MissingMetadataError {
+    fn hints(&self) -> uv_errors::Hints<'_> {
+        uv_errors::Hints::from(format!(
+            "Remove the affected sources, or omit `{}` to include their packages with a warning",
+            "--strict-exclude-newer".green(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn index(url: &str) -> IndexUrl {
+        IndexUrl::from_str(url).unwrap()
+    }
+
+    fn package(name: &str) -> PackageName {
+        PackageName::from_str(name).unwrap()
+    }
+
+    #[test]
+    fn report_strict() {
+        let missing = MissingMetadata::default();
+        let links = index("https://example.com/links/");
+        missing.insert(MissingMetadataKind::UploadTime, &links, &package("foo"));
+        missing.insert(MissingMetadataKind::UploadTime, &links, &package("bar"));
+        missing.insert(MissingMetadataKind::UploadTime, &links, &package("foo"));
+        assert!(!missing.is_empty());
+
+        let err = missing.report(MissingMetadataPolicy::Error).unwrap_err();
+        assert_eq!(
+            anstream::adapter::strip_str(&err.to_string()).to_string(),
+            "`--exclude-newer` could not be applied to packages from sources that lack the required metadata:\n  https://example.com/links/ (no upload times): bar, foo"
+        );
+
+        // Reporting drains the recorded packages.
+        assert!(missing.is_empty());
+        assert!(missing.report(MissingMetadataPolicy::Error).is_ok());
+    }
+
+    #[test]
+    fn report_lenient() {
+        let missing = MissingMetadata::default();
+        missing.insert(
+            MissingMetadataKind::Yanked,
+            &index("https://example.com/links/"),
+            &package("foo"),
+        );
+        assert!(missing.report(MissingMetadataPolicy::Warn).is_ok());
+        assert!(missing.is_empty());
+    }
+}
@@ -1,38 +1,67 @@
 use uv_configuration::{BuildOptions, IndexStrategy};
+use uv_normalize::PackageName;
 use uv_pypi_types::SupportedEnvironments;
 use uv_torch::TorchStrategy;
 
 use crate::fork_strategy::ForkStrategy;
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::requires_python_strategy::RequiresPythonStrategy;
+use crate::{
+    DependencyMode, ExcludeNewer, PackageLocalVersionPreferences, PackagePrereleaseModes,
+    PackageResolutionModes, PrereleaseMode, ResolutionMode, YankedPolicy,
+};
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
+    pub resolution_package: PackageResolutionModes,
     pub prerelease_mode: PrereleaseMode,
+    pub prerelease_package: PackagePrereleaseModes,
+    pub local_version_preference: PackageLocalVersionPreferences,
+    pub yanked: YankedPolicy,
     pub dependency_mode: DependencyMode,
     pub fork_strategy: ForkStrategy,
+    pub requires_python_strategy: RequiresPythonStrategy,
     pub exclude_newer: ExcludeNewer,
+    pub strict_exclude_newer: bool,
     pub index_strategy: IndexStrategy,
     pub artifact_environments: SupportedEnvironments,
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub max_metadata_builds: Option<usize>,
+    pub resolution_effort: Option<usize>,
+    pub excluded_packages: Vec<PackageName>,
+    pub reresolve_metadata: Vec<PackageName>,
+    pub explain: Vec<PackageName>,
+    pub hint_on_failure: bool,
 }
 
 /// Builder for [`Options`].
 #[derive(Debug, Default, Clone)]
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
+    resolution_package: PackageResolutionModes,
     prerelease_mode: PrereleaseMode,
+    prerelease_package: PackagePrereleaseModes,
+    local_version_preference: PackageLocalVersionPreferences,
+    yanked: YankedPolicy,
     dependency_mode: DependencyMode,
     fork_strategy: ForkStrategy,
+    requires_python_strategy: RequiresPythonStrategy,
     exclude_newer: ExcludeNewer,
+    strict_exclude_newer: bool,
     index_strategy: IndexStrategy,
     artifact_environments: SupportedEnvironments,
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    max_metadata_builds: Option<usize>,
+    resolution_effort: Option<usize>,
+    excluded_packages: Vec<PackageName>,
+    reresolve_metadata: Vec<PackageName>,
+    explain: Vec<PackageName>,
+    hint_on_failure: bool,
 }
 
 impl OptionsBuilder {
@@ -48,6 +77,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the package-specific [`ResolutionMode`] overrides.
+    #[must_use]
+    pub fn resolution_package(mut self, resolution_package: PackageResolutionModes) -> Self {
+        self.resolution_package = resolution_package;
+        self
+    }
+
     /// Sets the [`PrereleaseMode`].
     #[must_use]
     pub fn prerelease_mode(mut self, prerelease_mode: PrereleaseMode) -> Self {
@@ -55,6 +91,30 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the package-specific [`PrereleaseMode`] overrides.
+    #[must_use]
+    pub fn prerelease_package(mut self, prerelease_package: PackagePrereleaseModes) -> Self {
+        self.prerelease_package = prerelease_package;
+        self
+    }
+
+    /// Sets the package-specific local version preferences.
+    #[must_use]
+    pub fn local_version_preference(
+        mut self,
+        local_version_preference: PackageLocalVersionPreferences,
+    ) -> Self {
+        self.local_version_preference = local_version_preference;
+        self
+    }
+
+    /// Sets the [`YankedPolicy`].
+    #[must_use]
+    pub fn yanked(mut self, yanked: YankedPolicy) -> Self {
+        self.yanked = yanked;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -69,6 +129,16 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`RequiresPythonStrategy`].
+    #[must_use]
+    pub fn requires_python_strategy(
+        mut self,
+        requires_python_strategy: RequiresPythonStrategy,
+    ) -> Self {
+        self.requires_python_strategy = requires_python_strategy;
+        self
+    }
+
     /// Sets the exclusion date.
     #[must_use]
     pub fn exclude_newer(mut self, exclude_newer: ExcludeNewer) -> Self {
@@ -76,6 +146,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to fail, rather than warn, when `exclude-newer` can't be applied to packages
+    /// from sources that lack upload times (e.g., `--strict-exclude-newer`).
+    #[must_use]
+    pub fn strict_exclude_newer(mut self, strict_exclude_newer: bool) -> Self {
+        self.strict_exclude_newer = strict_exclude_newer;
+        self
+    }
+
     /// Sets the index strategy.
     #[must_use]
     pub fn index_strategy(mut self, index_strategy: IndexStrategy) -> Self {
@@ -111,19 +189,74 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the maximum number of source distributions to build to determine their metadata.
+    #[must_use]
+    pub fn max_metadata_builds(mut self, max_metadata_builds: Option<usize>) -> Self {
+        self.max_metadata_builds = max_metadata_builds;
+        self
+    }
+
+    /// Sets the maximum number of decisions the resolver may make before giving up.
+    #[must_use]
+    pub fn resolution_effort(mut self, resolution_effort: Option<usize>) -> Self {
+        self.resolution_effort = resolution_effort;
+        self
+    }
+
+    /// Sets the packages that must never be included in the resolution (e.g., `--exclude`).
+    #[must_use]
+    pub fn excluded_packages(mut self, excluded_packages: Vec<PackageName>) -> Self {
+        self.excluded_packages = excluded_packages;
+        self
+    }
+
+    /// Sets the packages for which to ignore index-provided metadata.
+    #[must_use]
+    pub fn reresolve_metadata(mut self, reresolve_metadata: Vec<PackageName>) -> Self {
+        self.reresolve_metadata = reresolve_metadata;
+        self
+    }
+
+    /// Sets the packages for which to explain the selected version (e.g., `--explain`).
+    #[must_use]
+    pub fn explain(mut self, explain: Vec<PackageName>) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    /// Sets whether to search for relaxations of the direct requirements when resolution fails
+    /// (e.g., `--hint-on-failure`).
+    #[must_use]
+    pub fn hint_on_failure(mut self, hint_on_failure: bool) -> Self {
+        self.hint_on_failure = hint_on_failure;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
+            resolution_package: self.resolution_package,
             prerelease_mode: self.prerelease_mode,
+            prerelease_package: self.prerelease_package,
+            local_version_preference: self.local_version_preference,
+            yanked: self.yanked,
             dependency_mode: self.dependency_mode,
             fork_strategy: self.fork_strategy,
+            requires_python_strategy: self.requires_python_strategy,
             exclude_newer: self.exclude_newer,
+            strict_exclude_newer: self.strict_exclude_newer,
             index_strategy: self.index_strategy,
             artifact_environments: self.artifact_environments,
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            max_metadata_builds: self.max_metadata_builds,
+            resolution_effort: self.resolution_effort,
+            excluded_packages: self.excluded_packages,
+            reresolve_metadata: self.reresolve_metadata,
+            explain: self.explain,
+            hint_on_failure: self.hint_on_failure,
         }
     }
 }
@@ -165,6 +165,16 @@ impl PreferenceIndex {
             }
         }
     }
+
+    /// Returns `true` if the preference was pinned to the given [`IndexUrl`].
+    ///
+    /// Unlike [`PreferenceIndex::matches`], returns `false` for [`PreferenceIndex::Any`].
+    pub(crate) fn is_explicit_match(&self, index: &IndexUrl) -> bool {
+        match self {
+            Self::Any | Self::Implicit => false,
+            Self::Explicit(_) => self.matches(index),
+        }
+    }
 }
 
 impl From<Option<IndexUrl>> for PreferenceIndex {
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
 use uv_distribution_types::RequirementSource;
 use uv_normalize::PackageName;
 use uv_pep440::Operator;
@@ -41,6 +44,93 @@ impl std::fmt::Display for PrereleaseMode {
     }
 }
 
+/// Package-specific overrides for the [`PrereleaseMode`], e.g., to allow pre-release versions of a
+/// single package while disallowing pre-release versions of all others.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackagePrereleaseModes(BTreeMap<PackageName, PrereleaseMode>);
+
+impl PackagePrereleaseModes {
+    /// Returns the [`PrereleaseMode`] override for the given package, if any.
+    pub fn get(&self, package_name: &PackageName) -> Option<PrereleaseMode> {
+        self.0.get(package_name).copied()
+    }
+
+    /// Returns `true` if there are no package-specific overrides.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the package-specific overrides.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &PrereleaseMode)> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<PackagePrereleaseModeEntry> for PackagePrereleaseModes {
+    fn from_iter<T: IntoIterator<Item = PackagePrereleaseModeEntry>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|entry| (entry.package, entry.mode))
+                .collect(),
+        )
+    }
+}
+
+impl FromIterator<(PackageName, PrereleaseMode)> for PackagePrereleaseModes {
+    fn from_iter<T: IntoIterator<Item = (PackageName, PrereleaseMode)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for PackagePrereleaseModes {
+    type Item = (PackageName, PrereleaseMode);
+    type IntoIter = std::collections::btree_map::IntoIter<PackageName, PrereleaseMode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// A package-specific pre-release mode entry, as in `--prerelease-package PACKAGE=MODE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackagePrereleaseModeEntry {
+    package: PackageName,
+    mode: PrereleaseMode,
+}
+
+impl FromStr for PackagePrereleaseModeEntry {
+    type Err = String;
+
+    /// Parses a [`PackagePrereleaseModeEntry`] from a string in the format `PACKAGE=MODE`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, mode)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid `prerelease-package` value `{s}`: expected format `PACKAGE=MODE`"
+            ));
+        };
+
+        let package = PackageName::from_str(package.trim()).map_err(|err| {
+            format!("Invalid `prerelease-package` package name `{package}`: {err}")
+        })?;
+
+        let mode = match mode.trim() {
+            "disallow" => PrereleaseMode::Disallow,
+            "allow" => PrereleaseMode::Allow,
+            "if-necessary" => PrereleaseMode::IfNecessary,
+            "explicit" => PrereleaseMode::Explicit,
+            "if-necessary-or-explicit" => PrereleaseMode::IfNecessaryOrExplicit,
+            _ => {
+                return Err(format!(
+                    "Invalid `prerelease-package` mode `{mode}`: expected one of `disallow`, `allow`, `if-necessary`, `explicit`, or `if-necessary-or-explicit`"
+                ));
+            }
+        };
+
+        Ok(Self { package, mode })
+    }
+}
+
 /// Like [`PrereleaseMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
@@ -142,3 +232,22 @@ pub(crate) enum AllowPrerelease {
     /// Allow pre-release versions if all versions of this package are pre-release.
     IfNecessary,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_prerelease_package_entry() {
+        let entry = PackagePrereleaseModeEntry::from_str("torch=allow").unwrap();
+        assert_eq!(entry.package, PackageName::from_str("torch").unwrap());
+        assert_eq!(entry.mode, PrereleaseMode::Allow);
+
+        let entry = PackagePrereleaseModeEntry::from_str("Foo_Bar=if-necessary").unwrap();
+        assert_eq!(entry.package, PackageName::from_str("foo-bar").unwrap());
+        assert_eq!(entry.mode, PrereleaseMode::IfNecessary);
+
+        assert!(PackagePrereleaseModeEntry::from_str("torch").is_err());
+        assert!(PackagePrereleaseModeEntry::from_str("torch=nightly").is_err());
+    }
+}
@@ -18,7 +18,7 @@ use uv_distribution_types::{
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerEnvironment, MarkerExpression, MarkerTree, MarkerValueVersion};
-use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, Tags};
+use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, Os, PlatformTag, Tags};
 
 use crate::candidate_selector::CandidateSelector;
 use crate::error::{ErrorTree, PrefixMatch};
@@ -1134,17 +1134,24 @@ impl PubGrubReportFormatter<'_> {
                 // So, instead, we only show the platforms that are linked to otherwise-compatible
                 // wheels (e.g., `manylinux2014` in `cp313-cp313-manylinux2014`). In other words,
                 // we only show platforms for ABI-compatible wheels.
+                let current = self.tags?;
                 let tags = prioritized
-                    .platform_tags(self.tags?)
+                    .platform_tags(current)
                     .cloned()
                     .collect::<BTreeSet<_>>();
                 if tags.is_empty() {
                     None
                 } else {
+                    // On musl, wheels that are only published for glibc (i.e., `manylinux`) will
+                    // never be compatible, which is worth calling out explicitly.
+                    let musl = matches!(current.python_platform().os(), Os::Musllinux { .. })
+                        && tags.iter().all(PlatformTag::is_manylinux);
                     Some(PubGrubHint::PlatformTags {
                         package: name.clone(),
                         version: candidate.version().clone(),
                         tags,
+                        musl,
+                        is_cross: current.is_cross(),
                     })
                 }
             }
@@ -1185,7 +1192,7 @@ impl PubGrubReportFormatter<'_> {
                     reason: reason.clone(),
                 });
             }
-            Some(UnavailablePackage::NotFound) => {}
+            Some(UnavailablePackage::NotFound | UnavailablePackage::Excluded) => {}
             None => {}
         }
 
@@ -1289,7 +1296,7 @@ impl PubGrubReportFormatter<'_> {
         options: &Options,
         hints: &mut IndexSet<PubGrubHint>,
     ) {
-        if selector.prerelease_strategy().allows(name, env) == AllowPrerelease::Yes {
+        if selector.prerelease_strategy_for(name).allows(name, env) == AllowPrerelease::Yes {
             return;
         }
 
@@ -1548,6 +1555,13 @@ pub enum PubGrubHint {
         version: Version,
         // excluded from `PartialEq` and `Hash`
         tags: BTreeSet<PlatformTag>,
+        /// Whether the target platform uses musl libc, while all available wheels require glibc.
+        // excluded from `PartialEq` and `Hash`
+        musl: bool,
+        /// Whether the target platform was requested (e.g., with `--python-platform`), rather than
+        /// derived from the current environment.
+        // excluded from `PartialEq` and `Hash`
+        is_cross: bool,
     },
     /// Versions of a package were excluded by `exclude-newer`.
     ExcludeNewer {
@@ -2094,6 +2108,8 @@ impl std::fmt::Display for PubGrubHint {
                 package,
                 version,
                 tags,
+                musl,
+                is_cross,
             } => {
                 let s = if tags.len() == 1 { "" } else { "s" };
                 write!(
@@ -2104,7 +2120,22 @@ impl std::fmt::Display for PubGrubHint {
                     tags.iter()
                         .map(|tag| format!("`{}`", tag.cyan()))
                         .join(", "),
-                )
+                )?;
+                if *musl {
+                    write!(
+                        f,
+                        "; {} uses musl libc (`{}` tags), but `{}` only publishes `{}` wheels, which require glibc",
+                        if *is_cross {
+                            "the target platform"
+                        } else {
+                            "your environment"
+                        },
+                        "musllinux".cyan(),
+                        package.cyan(),
+                        "manylinux".cyan(),
+                    )?;
+                }
+                Ok(())
             }
             Self::ExcludeNewer {
                 package,
@@ -0,0 +1,257 @@
+use std::fmt::{Display, Formatter};
+
+use owo_colors::OwoColorize;
+
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_normalize::PackageName;
+use uv_pep440::{Operator, Version, VersionSpecifier, VersionSpecifiers};
+
+/// A way of relaxing a direct requirement, to search for a fix to a failed resolution (e.g.,
+/// `--hint-on-failure`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelaxationKind {
+    /// Remove the upper bounds of the requirement (e.g., `foo>=1,<3` to `foo>=1`).
+    UpperBound,
+    /// Remove the lower bounds of the requirement (e.g., `foo>=4,<5` to `foo<5`).
+    LowerBound,
+}
+
+impl RelaxationKind {
+    /// Relax the given requirement, returning `None` if it isn't a registry requirement or has no
+    /// bound to relax.
+    pub fn relax(self, requirement: &Requirement) -> Option<Requirement> {
+        let RequirementSource::Registry {
+            specifier,
+            index,
+            conflict,
+        } = &requirement.source
+        else {
+            return None;
+        };
+        let specifier = self.relax_specifiers(specifier)?;
+        Some(Requirement {
+            source: RequirementSource::Registry {
+                specifier,
+                index: index.clone(),
+                conflict: conflict.clone(),
+            },
+            ..requirement.clone()
+        })
+    }
+
+    /// Relax the given specifiers, returning `None` if they have no bound to relax.
+    fn relax_specifiers(self, specifiers: &VersionSpecifiers) -> Option<VersionSpecifiers> {
+        let mut changed = false;
+        let relaxed = specifiers
+            .iter()
+            .filter_map(|specifier| {
+                let relaxed = self.relax_specifier(specifier);
+                if relaxed.as_ref() != Some(specifier) {
+                    changed = true;
+                }
+                relaxed
+            })
+            .collect::<VersionSpecifiers>();
+        changed.then_some(relaxed)
+    }
+
+    /// Relax a single specifier, returning `None` if it should be removed entirely.
+    fn relax_specifier(self, specifier: &VersionSpecifier) -> Option<VersionSpecifier> {
+        let version = || specifier.version().clone().without_local();
+        match (self, specifier.operator()) {
+            // Exclusions aren't bounds, so they're retained as-is.
+            (_, Operator::NotEqual | Operator::NotEqualStar) => Some(specifier.clone()),
+            (Self::UpperBound, Operator::LessThan | Operator::LessThanEqual) => None,
+            (Self::UpperBound, Operator::GreaterThan | Operator::GreaterThanEqual) => {
+                Some(specifier.clone())
+            }
+            (
+                Self::UpperBound,
+                Operator::Equal | Operator::EqualStar | Operator::ExactEqual | Operator::TildeEqual,
+            ) => Some(VersionSpecifier::greater_than_equal_version(version())),
+            (Self::LowerBound, Operator::GreaterThan | Operator::GreaterThanEqual) => None,
+            (Self::LowerBound, Operator::LessThan | Operator::LessThanEqual) => {
+                Some(specifier.clone())
+            }
+            (Self::LowerBound, Operator::Equal | Operator::ExactEqual) => {
+                Some(VersionSpecifier::less_than_equal_version(version()))
+            }
+            // Removing the lower bound of a prefix match would require computing its upper bound,
+            // so these are retained as-is.
+            (Self::LowerBound, Operator::EqualStar | Operator::TildeEqual) => {
+                Some(specifier.clone())
+            }
+        }
+    }
+}
+
+/// A relaxation of a direct requirement that allows an otherwise-unsatisfiable resolution to
+/// succeed.
+#[derive(Debug, Clone)]
+pub struct Relaxation {
+    /// The way in which the requirement was relaxed.
+    kind: RelaxationKind,
+    /// The name of the relaxed package.
+    name: PackageName,
+    /// The specifiers of the original requirement.
+    specifier: VersionSpecifiers,
+    /// The version of the package selected by the relaxed resolution.
+    version: Version,
+}
+
+impl Relaxation {
+    /// Create a [`Relaxation`] of the given requirement, which allowed the given version to be
+    /// selected.
+    ///
+    /// Returns `None` if the requirement isn't a registry requirement, or if the original
+    /// requirement already allowed the selected version, in which case the relaxation had no
+    /// bearing on the outcome.
+    pub fn new(kind: RelaxationKind, requirement: &Requirement, version: Version) -> Option<Self> {
+        let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+            return None;
+        };
+        if specifier.contains(&version) {
+            return None;
+        }
+        Some(Self {
+            kind,
+            name: requirement.name.clone(),
+            specifier: specifier.clone(),
+            version,
+        })
+    }
+}
+
+impl Display for Relaxation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let selected = format!("{}=={}", self.name, self.version);
+        match self.kind {
+            RelaxationKind::UpperBound => write!(
+                f,
+                "Removing the upper bound on `{}` (`{}`) would allow `{}`, which resolves the conflict",
+                self.name.cyan(),
+                self.specifier.cyan(),
+                selected.cyan(),
+            ),
+            RelaxationKind::LowerBound => {
+                let suggested = std::iter::once(VersionSpecifier::greater_than_equal_version(
+                    self.version.clone(),
+                ))
+                .chain(
+                    self.kind
+                        .relax_specifiers(&self.specifier)
+                        .unwrap_or_default()
+                        .iter()
+                        .cloned(),
+                )
+                .collect::<VersionSpecifiers>();
+                write!(
+                    f,
+                    "Lowering the requirement on `{}` from `{}` to `{}` would allow `{}`, which resolves the conflict",
+                    self.name.cyan(),
+                    self.specifier.cyan(),
+                    suggested.cyan(),
+                    selected.cyan(),
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_distribution_types::Requirement;
+    use uv_pep440::Version;
+    use uv_pep508::Requirement as Pep508Requirement;
+
+    use super::{Relaxation, RelaxationKind};
+
+    fn requirement(s: &str) -> Requirement {
+        Requirement::from(Pep508Requirement::from_str(s).unwrap())
+    }
+
+    fn relax(kind: RelaxationKind, s: &str) -> Option<String> {
+        kind.relax(&requirement(s))
+            .map(|requirement| requirement.to_string())
+    }
+
+    #[test]
+    fn relax_upper_bound() {
+        assert_eq!(
+            relax(RelaxationKind::UpperBound, "foo>=1,<3").as_deref(),
+            Some("foo>=1")
+        );
+        assert_eq!(
+            relax(RelaxationKind::UpperBound, "foo<=3").as_deref(),
+            Some("foo")
+        );
+        assert_eq!(
+            relax(RelaxationKind::UpperBound, "foo==1.2").as_deref(),
+            Some("foo>=1.2")
+        );
+        assert_eq!(
+            relax(RelaxationKind::UpperBound, "foo~=1.2,!=1.3").as_deref(),
+            Some("foo>=1.2, !=1.3")
+        );
+        assert_eq!(relax(RelaxationKind::UpperBound, "foo>=1,!=2"), None);
+        assert_eq!(relax(RelaxationKind::UpperBound, "foo"), None);
+        assert_eq!(
+            relax(
+                RelaxationKind::UpperBound,
+                "foo @ https://example.com/foo-1.0.tar.gz"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn relax_lower_bound() {
+        assert_eq!(
+            relax(RelaxationKind::LowerBound, "foo>=4,<5").as_deref(),
+            Some("foo<5")
+        );
+        assert_eq!(
+            relax(RelaxationKind::LowerBound, "foo==1.2").as_deref(),
+            Some("foo<=1.2")
+        );
+        assert_eq!(relax(RelaxationKind::LowerBound, "foo~=1.2"), None);
+        assert_eq!(relax(RelaxationKind::LowerBound, "foo<5"), None);
+    }
+
+    #[test]
+    fn display() {
+        let relaxation = Relaxation::new(
+            RelaxationKind::UpperBound,
+            &requirement("foo>=1,<3"),
+            Version::from_str("3.1.0").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            anstream::adapter::strip_str(&relaxation.to_string()).to_string(),
+            "Removing the upper bound on `foo` (`>=1, <3`) would allow `foo==3.1.0`, which resolves the conflict"
+        );
+
+        let relaxation = Relaxation::new(
+            RelaxationKind::LowerBound,
+            &requirement("foo>=4,<5"),
+            Version::from_str("3.5.0").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            anstream::adapter::strip_str(&relaxation.to_string()).to_string(),
+            "Lowering the requirement on `foo` from `>=4, <5` to `>=3.5.0, <5` would allow `foo==3.5.0`, which resolves the conflict"
+        );
+
+        // A relaxation that allows a version that was already allowed isn't reported.
+        assert!(
+            Relaxation::new(
+                RelaxationKind::UpperBound,
+                &requirement("foo>=1,<3"),
+                Version::from_str("2.0.0").unwrap(),
+            )
+            .is_none()
+        );
+    }
+}
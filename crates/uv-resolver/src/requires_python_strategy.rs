@@ -0,0 +1,24 @@
+/// How to handle dependencies that don't support the full `requires-python` range of the project.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RequiresPythonStrategy {
+    /// Allow dependencies to narrow the range of Python versions supported by the resolution
+    /// (e.g., a dependency that requires `<3.12` for a project that requires `>=3.9`), and report
+    /// the narrowed range.
+    #[default]
+    Narrow,
+    /// Fail if any selected distribution doesn't support the project's full `requires-python`
+    /// range.
+    Strict,
+}
+
+impl std::fmt::Display for RequiresPythonStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Narrow => write!(f, "narrow"),
+            Self::Strict => write!(f, "strict"),
+        }
+    }
+}
@@ -296,6 +296,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                             annotation = Some((separator, comment));
                         }
                     },
+                    AnnotationStyle::None => {}
                 }
             }
 
@@ -336,6 +337,8 @@ pub enum AnnotationStyle {
     /// Render each annotation on its own line.
     #[default]
     Split,
+    /// Omit the annotations entirely.
+    None,
 }
 
 /// We don't need the edge markers anymore since we switched to propagated markers.
@@ -1,37 +1,43 @@
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
 use indexmap::IndexSet;
+use itertools::Itertools;
 use petgraph::{
     Directed, Direction,
     graph::{Graph, NodeIndex},
 };
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
-use uv_configuration::{Constraints, Overrides};
+use uv_configuration::{Constraints, Overrides, Replacements};
 use uv_distribution::Metadata;
 use uv_distribution_types::{
-    Dist, DistributionId, Edge, Identifier, IndexUrl, Name, Node, Requirement, RequiresPython,
-    ResolutionDiagnostic, ResolvedDist,
+    DerivationChain, DerivationStep, Dist, DistributionId, Edge, Identifier, IndexUrl, Name, Node,
+    Requirement, RequiresPython, ResolutionDiagnostic, ResolvedDist,
 };
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
-use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
+use uv_pep508::{
+    MarkerEnvironment, MarkerExpression, MarkerTree, MarkerTreeKind, MarkerValueVersion,
+};
 use uv_pypi_types::{Conflicts, HashDigests, ParsedUrlError, VerbatimParsedUrl, Yanked};
 
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
 use crate::pins::FilePins;
 use crate::preferences::Preferences;
+use crate::python_requirement::PythonRequirementSource;
 use crate::redirect::url_to_precise;
 use crate::resolution::AnnotatedDist;
 use crate::resolution_mode::ResolutionStrategy;
 use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
-    InMemoryIndex, MetadataResponse, Options, PythonRequirement, ResolveError, VersionsResponse,
+    Explanation, InMemoryIndex, MetadataResponse, NarrowedRequiresPythonError, Options,
+    PythonRequirement, RequiresPythonStrategy, ResolutionMode, ResolveError, VersionsResponse,
+    YankedPolicy,
 };
 
 /// The output of a successful resolution.
@@ -55,8 +61,12 @@ pub struct ResolverOutput {
     pub(crate) constraints: Constraints,
     /// The overrides that were used to build the graph.
     pub(crate) overrides: Overrides,
+    /// The replacements that were used to build the graph.
+    pub(crate) replacements: Replacements,
     /// The options that were used to build the graph.
     pub(crate) options: Options,
+    /// The explanations for the selected versions of the requested packages (e.g., `--explain`).
+    pub(crate) explanations: Vec<Explanation>,
 }
 
 #[derive(Debug, Clone)]
@@ -128,12 +138,14 @@ impl ResolverOutput {
         requirements: &[Requirement],
         constraints: &Constraints,
         overrides: &Overrides,
+        replacements: &Replacements,
         preferences: &Preferences,
         index: &InMemoryIndex,
         git: &GitResolver,
         python: &PythonRequirement,
         conflicts: &Conflicts,
         resolution_strategy: &ResolutionStrategy,
+        forbidden_source_dists: &FxHashMap<PackageName, (Version, IndexUrl)>,
         options: Options,
     ) -> Result<Self, ResolveError> {
         let size_guess = resolutions[0].nodes.len();
@@ -225,8 +237,37 @@ impl ResolverOutput {
         // Discard any unreachable nodes.
         graph.retain_nodes(|graph, node| !graph[node].marker().is_false());
 
-        if matches!(resolution_strategy, ResolutionStrategy::Lowest) {
-            report_missing_lower_bounds(&graph, &mut diagnostics, constraints, overrides);
+        report_missing_lower_bounds(&graph, &mut diagnostics, constraints, overrides, |name| {
+            match options.resolution_package.get(name) {
+                Some(mode) => mode == ResolutionMode::Lowest,
+                None => matches!(resolution_strategy, ResolutionStrategy::Lowest),
+            }
+        });
+
+        // Under `--yanked allow`, pinned yanked versions are selected silently.
+        if options.yanked == YankedPolicy::Allow {
+            diagnostics.retain(|diagnostic| {
+                !matches!(diagnostic, ResolutionDiagnostic::YankedVersion { .. })
+            });
+        }
+
+        if !forbidden_source_dists.is_empty() {
+            report_forbidden_source_dists(&graph, &mut diagnostics, forbidden_source_dists);
+        }
+
+        // In a universal resolution, identify any distributions that don't support the full
+        // `Requires-Python` range, and thus narrow the range supported by the resolution.
+        if python.source() == PythonRequirementSource::RequiresPython
+            && resolutions
+                .iter()
+                .all(|resolution| resolution.env.marker_environment().is_none())
+        {
+            report_narrowed_requires_python(
+                &graph,
+                &mut diagnostics,
+                &requires_python,
+                options.requires_python_strategy,
+            )?;
         }
 
         let output = Self {
@@ -236,8 +277,14 @@ impl ResolverOutput {
             requirements: requirements.to_vec(),
             constraints: constraints.clone(),
             overrides: overrides.clone(),
+            replacements: replacements.clone(),
             options,
             fork_markers,
+            explanations: resolutions
+                .iter()
+                .flat_map(|resolution| resolution.explanations.iter().cloned())
+                .sorted_by(|a, b| a.name().cmp(b.name()))
+                .collect(),
         };
 
         // We only do conflicting distribution detection when no
@@ -526,18 +573,10 @@ impl ResolverOutput {
             }
         }
 
-        // 2. Look for hashes for the distribution (i.e., the specific wheel or source distribution).
-        if let Some(metadata_response) = in_memory.distributions().get(metadata_id) {
-            if let MetadataResponse::Found(ref archive) = *metadata_response {
-                let mut digests = archive.hashes.clone();
-                digests.sort_unstable();
-                if !digests.is_empty() {
-                    return digests;
-                }
-            }
-        }
-
-        // 3. Look for hashes from the registry, which are served at the package level.
+        // 2. Look for hashes from the registry, which are served at the package level. These take
+        // precedence over any hashes computed locally, such that packages that are built from
+        // source (e.g., those that only publish source distributions) are pinned to the published
+        // archive rather than to an artifact built on this machine.
         if url.is_none() {
             // Query the implicit and explicit indexes (lazily) for the hashes.
             let implicit_response = in_memory.implicit().get(name);
@@ -585,6 +624,17 @@ impl ResolverOutput {
             }
         }
 
+        // 3. Look for hashes for the distribution (i.e., the specific wheel or source distribution).
+        if let Some(metadata_response) = in_memory.distributions().get(metadata_id) {
+            if let MetadataResponse::Found(ref archive) = *metadata_response {
+                let mut digests = archive.hashes.clone();
+                digests.sort_unstable();
+                if !digests.is_empty() {
+                    return digests;
+                }
+            }
+        }
+
         HashDigests::empty()
     }
 
@@ -618,16 +668,37 @@ impl ResolverOutput {
         self.base_dists().next().is_none()
     }
 
+    /// Returns the packages in the graph for which no hashes are available (e.g., Git or local
+    /// directory dependencies).
+    pub fn unhashed_packages(&self) -> impl Iterator<Item = &PackageName> {
+        self.base_dists()
+            .filter(|(_, dist)| dist.hashes.is_empty())
+            .map(|(_, dist)| &dist.name)
+    }
+
     /// Returns `true` if the graph contains the given package.
     pub fn contains(&self, name: &PackageName) -> bool {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Returns the versions of the given package in the graph.
+    pub fn versions<'a>(&'a self, name: &'a PackageName) -> impl Iterator<Item = &'a Version> {
+        self.dists()
+            .filter(move |dist| &dist.name == name)
+            .map(|dist| &dist.version)
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
     }
 
+    /// Return the [`Explanation`]s for the packages requested with `--explain`, one per package in
+    /// each fork of the resolution.
+    pub fn explanations(&self) -> &[Explanation] {
+        &self.explanations
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -941,12 +1012,17 @@ fn report_missing_lower_bounds(
     diagnostics: &mut Vec<ResolutionDiagnostic>,
     constraints: &Constraints,
     overrides: &Overrides,
+    is_lowest: impl Fn(&PackageName) -> bool,
 ) {
     for node_index in graph.node_indices() {
         let ResolutionGraphNode::Dist(dist) = graph.node_weight(node_index).unwrap() else {
             // Ignore the root package.
             continue;
         };
+        // Only packages resolved to their lowest compatible version are affected.
+        if !is_lowest(dist.name()) {
+            continue;
+        }
         if !has_lower_bound(node_index, dist.name(), graph, constraints, overrides) {
             diagnostics.push(ResolutionDiagnostic::MissingLowerBound {
                 package_name: dist.name().clone(),
@@ -955,6 +1031,172 @@ fn report_missing_lower_bounds(
     }
 }
 
+/// Find any packages that were resolved to an older version because a newer version was only
+/// usable as a source distribution from an index with `allow-sdist = false`.
+fn report_forbidden_source_dists(
+    graph: &Graph<ResolutionGraphNode, UniversalMarker>,
+    diagnostics: &mut Vec<ResolutionDiagnostic>,
+    forbidden_source_dists: &FxHashMap<PackageName, (Version, IndexUrl)>,
+) {
+    let mut dists = graph
+        .node_weights()
+        .filter_map(|node| {
+            let ResolutionGraphNode::Dist(dist) = node else {
+                return None;
+            };
+            if dist.extra.is_some() || dist.group.is_some() {
+                return None;
+            }
+            let (version, index) = forbidden_source_dists.get(dist.name())?;
+            (dist.version < *version).then(|| (dist.dist.clone(), version.clone(), index.clone()))
+        })
+        .collect::<Vec<_>>();
+    if dists.is_empty() {
+        return;
+    }
+    dists.sort_by(|(a, ..), (b, ..)| {
+        a.name()
+            .cmp(b.name())
+            .then_with(|| a.version().cmp(&b.version()))
+    });
+    dists.dedup_by(|(a, ..), (b, ..)| a.name() == b.name() && a.version() == b.version());
+    diagnostics.push(ResolutionDiagnostic::ForbiddenSourceDistributions { dists });
+}
+
+/// Find any distributions that don't support the full `Requires-Python` range of the resolution
+/// (e.g., a dependency that requires `>=3.9, <3.12` in a resolution for `>=3.9`).
+///
+/// Under [`RequiresPythonStrategy::Strict`], such distributions are an error.
+fn report_narrowed_requires_python(
+    graph: &Graph<ResolutionGraphNode, UniversalMarker>,
+    diagnostics: &mut Vec<ResolutionDiagnostic>,
+    requires_python: &RequiresPython,
+    requires_python_strategy: RequiresPythonStrategy,
+) -> Result<(), ResolveError> {
+    // Ignore upper bounds that only exclude Python 4 (e.g., `<4`), which are commonly declared,
+    // but don't narrow the range in practice.
+    let mut target = requires_python.to_marker_tree();
+    target.and(MarkerTree::expression(MarkerExpression::Version {
+        key: MarkerValueVersion::PythonFullVersion,
+        specifier: VersionSpecifier::less_than_version(Version::new([4])),
+    }));
+
+    let mut unsupported = MarkerTree::FALSE;
+    let mut dists = Vec::new();
+    for node_index in graph.node_indices() {
+        let ResolutionGraphNode::Dist(dist) = &graph[node_index] else {
+            continue;
+        };
+        if !dist.is_base() {
+            continue;
+        }
+        let Some(specifiers) = dist
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.requires_python.as_ref())
+        else {
+            continue;
+        };
+
+        // Determine the Python versions for which the distribution is included in the
+        // resolution, but doesn't support.
+        let mut marker = dist.marker.pep508();
+        marker.and(target);
+        marker.and(
+            RequiresPython::from_specifiers(specifiers)
+                .to_marker_tree()
+                .negate(),
+        );
+        if marker.is_false() {
+            continue;
+        }
+
+        unsupported.or(marker);
+        dists.push((node_index, dist, specifiers));
+    }
+    if dists.is_empty() {
+        return Ok(());
+    }
+    dists.sort_by(|(.., a, _), (.., b, _)| {
+        a.name()
+            .cmp(b.name())
+            .then_with(|| a.version.cmp(&b.version))
+    });
+
+    if requires_python_strategy == RequiresPythonStrategy::Strict {
+        let (node_index, dist, specifiers) = dists[0];
+        return Err(ResolveError::NarrowedRequiresPython(Box::new(
+            NarrowedRequiresPythonError {
+                name: dist.name.clone(),
+                version: dist.version.clone(),
+                requires_python: specifiers.clone(),
+                target: requires_python.clone(),
+                chain: derivation_chain(graph, node_index).unwrap_or_default(),
+            },
+        )));
+    }
+
+    // Compute the range of Python versions that remain supported, if it's narrower.
+    let mut supported = target;
+    supported.and(unsupported.negate());
+    let narrowed = crate::marker::requires_python(supported)
+        .filter(|range| Some(range) != crate::marker::requires_python(target).as_ref())
+        .and_then(|range| requires_python.narrow(&range));
+
+    diagnostics.push(ResolutionDiagnostic::NarrowedRequiresPython {
+        requires_python: requires_python.clone(),
+        narrowed,
+        dists: dists
+            .into_iter()
+            .map(|(_, dist, specifiers)| (dist.dist.clone(), specifiers.clone()))
+            .collect(),
+    });
+    Ok(())
+}
+
+/// Compute a [`DerivationChain`] from the root to the given distribution in the resolution graph,
+/// following the shortest path.
+fn derivation_chain(
+    graph: &Graph<ResolutionGraphNode, UniversalMarker>,
+    target: NodeIndex,
+) -> Option<DerivationChain> {
+    let mut queue = VecDeque::new();
+    queue.push_back((target, Vec::new()));
+
+    let mut seen = FxHashSet::default();
+    while let Some((node_index, path)) = queue.pop_front() {
+        if !seen.insert(node_index) {
+            continue;
+        }
+        for source in graph.neighbors_directed(node_index, Direction::Incoming) {
+            match &graph[source] {
+                ResolutionGraphNode::Root => {
+                    return Some(DerivationChain::from_iter(path));
+                }
+                ResolutionGraphNode::Dist(dist) => {
+                    let mut path = path.clone();
+                    // Skip the edges from an extra or group to its base package.
+                    if graph[node_index].package_name() != Some(dist.name()) {
+                        path.insert(
+                            0,
+                            DerivationStep::new(
+                                dist.name.clone(),
+                                dist.extra.clone(),
+                                dist.group.clone(),
+                                Some(dist.version.clone()),
+                                pubgrub::Ranges::empty(),
+                            ),
+                        );
+                    }
+                    queue.push_back((source, path));
+                }
+            }
+        }
+    }
+
+    None
+}
+
 /// Whether the given package has a lower version bound by another package.
 fn has_lower_bound(
     node_index: NodeIndex,
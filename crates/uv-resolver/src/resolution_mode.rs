@@ -1,3 +1,8 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+
 use crate::resolver::{ForkMap, ForkSet};
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
@@ -26,6 +31,91 @@ impl std::fmt::Display for ResolutionMode {
     }
 }
 
+/// Package-specific overrides for the [`ResolutionMode`], e.g., to resolve the lowest compatible
+/// version of a single package while resolving the highest compatible version of all others.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageResolutionModes(BTreeMap<PackageName, ResolutionMode>);
+
+impl PackageResolutionModes {
+    /// Returns the [`ResolutionMode`] override for the given package, if any.
+    pub fn get(&self, package_name: &PackageName) -> Option<ResolutionMode> {
+        self.0.get(package_name).copied()
+    }
+
+    /// Returns `true` if there are no package-specific overrides.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the package-specific overrides.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &ResolutionMode)> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<PackageResolutionModeEntry> for PackageResolutionModes {
+    fn from_iter<T: IntoIterator<Item = PackageResolutionModeEntry>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|entry| (entry.package, entry.mode))
+                .collect(),
+        )
+    }
+}
+
+impl FromIterator<(PackageName, ResolutionMode)> for PackageResolutionModes {
+    fn from_iter<T: IntoIterator<Item = (PackageName, ResolutionMode)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for PackageResolutionModes {
+    type Item = (PackageName, ResolutionMode);
+    type IntoIter = std::collections::btree_map::IntoIter<PackageName, ResolutionMode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// A package-specific resolution mode entry, as in `--resolution-package PACKAGE=MODE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageResolutionModeEntry {
+    package: PackageName,
+    mode: ResolutionMode,
+}
+
+impl FromStr for PackageResolutionModeEntry {
+    type Err = String;
+
+    /// Parses a [`PackageResolutionModeEntry`] from a string in the format `PACKAGE=MODE`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, mode)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid `resolution-package` value `{s}`: expected format `PACKAGE=MODE`"
+            ));
+        };
+
+        let package = PackageName::from_str(package.trim()).map_err(|err| {
+            format!("Invalid `resolution-package` package name `{package}`: {err}")
+        })?;
+
+        let mode = match mode.trim() {
+            "highest" => ResolutionMode::Highest,
+            "lowest" => ResolutionMode::Lowest,
+            "lowest-direct" => ResolutionMode::LowestDirect,
+            _ => {
+                return Err(format!(
+                    "Invalid `resolution-package` mode `{mode}`: expected one of `highest`, `lowest`, or `lowest-direct`"
+                ));
+            }
+        };
+
+        Ok(Self { package, mode })
+    }
+}
+
 /// Like [`ResolutionMode`], but with any additional information required to select a candidate,
 /// like the set of direct dependencies.
 #[derive(Debug, Clone)]
@@ -40,6 +130,11 @@ pub(crate) enum ResolutionStrategy {
 }
 
 impl ResolutionStrategy {
+    /// Returns `true` if the strategy resolves the lowest compatible version of (some) packages.
+    pub(crate) fn is_lowest(&self) -> bool {
+        matches!(self, Self::Lowest | Self::LowestDirect(..))
+    }
+
     pub(crate) fn from_mode(
         mode: ResolutionMode,
         manifest: &Manifest,
@@ -59,3 +154,22 @@ impl ResolutionStrategy {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_resolution_package_entry() {
+        let entry = PackageResolutionModeEntry::from_str("foo=lowest").unwrap();
+        assert_eq!(entry.package, PackageName::from_str("foo").unwrap());
+        assert_eq!(entry.mode, ResolutionMode::Lowest);
+
+        let entry = PackageResolutionModeEntry::from_str("Foo_Bar=lowest-direct").unwrap();
+        assert_eq!(entry.package, PackageName::from_str("foo-bar").unwrap());
+        assert_eq!(entry.mode, ResolutionMode::LowestDirect);
+
+        assert!(PackageResolutionModeEntry::from_str("foo").is_err());
+        assert!(PackageResolutionModeEntry::from_str("foo=newest").is_err());
+    }
+}
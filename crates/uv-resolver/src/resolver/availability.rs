@@ -3,6 +3,7 @@ use std::iter;
 use std::sync::Arc;
 
 use uv_distribution_types::IncompatibleDist;
+use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_platform_tags::{AbiTag, Tags};
 
@@ -46,6 +47,13 @@ pub enum UnavailableVersion {
     /// The source distribution has a `requires-python` requirement that is not met by the installed
     /// Python version (and static metadata is not available).
     RequiresPython(VersionSpecifiers),
+    /// The version depends on a replaced package, but the replacement doesn't provide a compatible
+    /// version.
+    IncompatibleReplacement {
+        package: PackageName,
+        specifier: VersionSpecifiers,
+        provides: Version,
+    },
 }
 
 impl UnavailableVersion {
@@ -59,6 +67,13 @@ impl UnavailableVersion {
             Self::RequiresPython(requires_python) => {
                 format!("Python {requires_python}")
             }
+            Self::IncompatibleReplacement {
+                package,
+                specifier,
+                provides,
+            } => {
+                format!("{package}{specifier}, but its replacement provides {package}=={provides}")
+            }
         }
     }
 
@@ -70,6 +85,7 @@ impl UnavailableVersion {
             Self::InvalidStructure => format!("has {self}"),
             Self::Offline => format!("needs {self}"),
             Self::RequiresPython(..) => format!("requires {self}"),
+            Self::IncompatibleReplacement { .. } => format!("requires {self}"),
         }
     }
 
@@ -81,6 +97,7 @@ impl UnavailableVersion {
             Self::InvalidStructure => format!("have {self}"),
             Self::Offline => format!("need {self}"),
             Self::RequiresPython(..) => format!("require {self}"),
+            Self::IncompatibleReplacement { .. } => format!("require {self}"),
         }
     }
 
@@ -98,6 +115,7 @@ impl UnavailableVersion {
             Self::InvalidStructure => None,
             Self::Offline => None,
             Self::RequiresPython(..) => None,
+            Self::IncompatibleReplacement { .. } => None,
         }
     }
 }
@@ -158,6 +176,8 @@ pub enum UnavailablePackage {
     InvalidMetadata(UnavailableErrorChain),
     /// The package has an invalid structure.
     InvalidStructure(UnavailableErrorChain),
+    /// The package was excluded from the resolution (i.e., `excluded-packages`).
+    Excluded,
 }
 
 impl UnavailablePackage {
@@ -168,6 +188,7 @@ impl UnavailablePackage {
             Self::NotFound => "not found in the package registry",
             Self::InvalidMetadata(_) => "invalid metadata",
             Self::InvalidStructure(_) => "an invalid package format",
+            Self::Excluded => "excluded from the resolution",
         }
     }
 
@@ -178,6 +199,7 @@ impl UnavailablePackage {
             Self::NotFound => format!("was {self}"),
             Self::InvalidMetadata(_) => format!("has {self}"),
             Self::InvalidStructure(_) => format!("has {self}"),
+            Self::Excluded => format!("was {self}"),
         }
     }
 }
@@ -20,13 +20,14 @@ use tokio::sync::oneshot;
 use tokio_stream::wrappers::ReceiverStream;
 use tracing::{Level, debug, info, instrument, trace, warn};
 
-use uv_configuration::{Constraints, Excludes, Overrides};
-use uv_distribution::{ArchiveMetadata, DistributionDatabase};
+use uv_configuration::{BuildOptions, Constraints, Excludes, Overrides, Replacements};
+use uv_distribution::{ArchiveMetadata, DistributionDatabase, Metadata, MetadataBuilds};
 use uv_distribution_types::{
-    BuiltDist, CompatibleDist, DerivationChain, Dist, DistErrorKind, Identifier, IncompatibleDist,
-    IncompatibleSource, IncompatibleWheel, IndexCapabilities, IndexLocations, IndexMetadata,
-    IndexUrl, InstalledDist, Name, PythonRequirementKind, RemoteSource, Requirement, ResolvedDist,
-    ResolvedDistRef, SourceDist, VersionOrUrlRef, implied_markers,
+    BuiltDist, CompatibleDist, DerivationChain, Dist, DistErrorKind, DistributionId, Identifier,
+    IncompatibleDist, IncompatibleSource, IncompatibleWheel, IndexCapabilities, IndexLocations,
+    IndexMetadata, IndexUrl, InstalledDist, Name, PythonRequirementKind, RemoteSource,
+    RequestedDist, Requirement, RequirementSource, ResolvedDist, ResolvedDistRef,
+    SourceBuildReason, SourceDist, VersionOrUrlRef, implied_markers,
 };
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -43,20 +44,24 @@ use uv_warnings::warn_user_once;
 
 use crate::candidate_selector::{Candidate, CandidateDist, CandidateSelector};
 use crate::dependency_provider::UvDependencyProvider;
-use crate::error::{NoSolutionError, ResolveError, derivation_tree_packages};
+use crate::error::{
+    MetadataBuildLimitError, NoSolutionError, ResolutionEffortError, ResolveError,
+    derivation_tree_packages,
+};
+use crate::explain::{ExplainedRequirement, Explanation, FilterReason, Requirer};
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_strategy::ForkStrategy;
 use crate::fork_urls::ForkUrls;
 use crate::manifest::Manifest;
 use crate::pins::FilePins;
 use crate::preferences::{PreferenceSource, Preferences};
+use crate::prerelease::AllowPrerelease;
 use crate::pubgrub::{
     DependencySource, PubGrubDependency, PubGrubPackage, PubGrubPackageInner, PubGrubPriorities,
     PubGrubPython,
 };
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ResolverOutput;
-use crate::resolution_mode::ResolutionStrategy;
 pub(crate) use crate::resolver::availability::{
     ResolverVersion, UnavailableErrorChain, UnavailablePackage, UnavailableReason,
     UnavailableVersion,
@@ -70,6 +75,7 @@ use crate::resolver::environment::{
 pub(crate) use crate::resolver::fork_map::{ForkMap, ForkSet};
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
+use crate::resolver::prefetch_scheduler::PrefetchScheduler;
 pub use crate::resolver::provider::{
     DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
     VersionsResponse, WheelMetadataResult,
@@ -79,7 +85,10 @@ use crate::resolver::system::SystemDependency;
 pub(crate) use crate::resolver::urls::Urls;
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::yanks::AllowedYanks;
-use crate::{DependencyMode, Exclusions, FlatIndex, Options, ResolutionMode, VersionMap, marker};
+use crate::{
+    DependencyMode, Exclusions, FlatIndex, MissingMetadata, MissingMetadataKind,
+    MissingMetadataPolicy, Options, ResolutionMode, VersionMap, marker,
+};
 pub(crate) use provider::MetadataUnavailable;
 
 mod availability;
@@ -89,6 +98,7 @@ mod environment;
 mod fork_map;
 mod index;
 mod indexes;
+mod prefetch_scheduler;
 mod provider;
 mod reporter;
 mod system;
@@ -97,6 +107,12 @@ mod urls;
 /// The number of conflicts a package may accumulate before we re-prioritize and backtrack.
 const CONFLICT_THRESHOLD: usize = 5;
 
+/// The number of resolver steps between progress messages.
+const PROGRESS_INTERVAL: usize = 1000;
+
+/// The number of packages to report when the resolver exceeds its step limit.
+const MAX_REPORTED_CONFLICTS: usize = 5;
+
 pub struct Resolver<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider> {
     state: ResolverState<InstalledPackages>,
     provider: Provider,
@@ -110,6 +126,7 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     constraints: Constraints,
     overrides: Overrides,
     excludes: Excludes,
+    replacements: Replacements,
     preferences: Preferences,
     git: GitResolver,
     capabilities: IndexCapabilities,
@@ -134,6 +151,25 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     unavailable_packages: Box<HashMap<PackageName, UnavailablePackage>>,
     /// Incompatibilities for packages that are unavailable at specific versions.
     incomplete_packages: Box<HashMap<PackageName, HashMap<Version, MetadataUnavailable>>>,
+    /// The highest version of each package that was rejected because its source distribution is
+    /// forbidden by the index's `allow-sdist` setting.
+    forbidden_source_dists: Box<HashMap<PackageName, (Version, IndexUrl)>>,
+    /// The source distributions that were built to determine their metadata, if limited.
+    metadata_builds: Option<MetadataBuilds>,
+    /// Whether to abort the resolution at the first failed build, rather than waiting for the
+    /// builds that are already in flight and reporting every failure.
+    fail_fast: bool,
+    /// The options that govern the use of source and built distributions, to explain failed
+    /// builds.
+    build_options: BuildOptions,
+    /// The packages that were included from sources that lack upload times, despite
+    /// `exclude-newer`.
+    missing_metadata: MissingMetadata,
+    /// The version of each package that the solver most recently selected, to distinguish builds
+    /// for the selected versions from those triggered by backtracking.
+    selected_versions: Box<HashMap<PackageName, Version>>,
+    /// The scheduler for speculative metadata prefetches.
+    prefetch_scheduler: Box<PrefetchScheduler>,
     /// The options that were used to configure this resolver.
     options: Options,
     /// The reporter to use for this resolver.
@@ -176,12 +212,21 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
         installed_packages: InstalledPackages,
         database: DistributionDatabase<'a, Context>,
     ) -> Result<Self, ResolveError> {
+        let metadata_builds = options
+            .max_metadata_builds
+            .map(|limit| MetadataBuilds::new(Some(limit)));
+        let database = if let Some(metadata_builds) = metadata_builds.clone() {
+            database.with_metadata_builds(metadata_builds)
+        } else {
+            database
+        };
+        let database = database.with_reresolve_metadata(options.reresolve_metadata.clone());
         let provider = DefaultResolverProvider::new(
             database,
             flat_index,
             tags,
             python_requirement.target(),
-            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode),
+            AllowedYanks::from_manifest(&manifest, &env, options.dependency_mode, options.yanked),
             hasher,
             options.exclude_newer.clone(),
             build_context.locations(),
@@ -189,7 +234,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             build_context.capabilities(),
         );
 
-        Ok(Self::new_custom_io(
+        let mut resolver = Self::new_custom_io(
             manifest,
             options,
             hasher,
@@ -204,7 +249,12 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             build_context.locations(),
             provider,
             installed_packages,
-        ))
+            PrefetchScheduler::new(build_context.concurrency().metadata_semaphore.clone()),
+        );
+        resolver.state.metadata_builds = metadata_builds;
+        resolver.state.fail_fast = build_context.concurrency().fail_fast;
+        resolver.state.build_options = build_context.build_options().clone();
+        Ok(resolver)
     }
 }
 
@@ -227,6 +277,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         locations: &IndexLocations,
         provider: Provider,
         installed_packages: InstalledPackages,
+        prefetch_scheduler: PrefetchScheduler,
     ) -> Self {
         let state = ResolverState {
             index: index.clone(),
@@ -242,6 +293,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             constraints: manifest.constraints,
             overrides: manifest.overrides,
             excludes: manifest.excludes,
+            replacements: manifest.replacements,
             preferences: manifest.preferences,
             exclusions: manifest.exclusions,
             hasher: hasher.clone(),
@@ -254,6 +306,13 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             installed_packages,
             unavailable_packages: Box::default(),
             incomplete_packages: Box::default(),
+            forbidden_source_dists: Box::default(),
+            metadata_builds: None,
+            fail_fast: true,
+            build_options: BuildOptions::default(),
+            missing_metadata: MissingMetadata::default(),
+            selected_versions: Box::default(),
+            prefetch_scheduler: Box::new(prefetch_scheduler),
             options,
             reporter: None,
         };
@@ -300,13 +359,37 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             })
             .unwrap();
 
-        let resolve_fut = async move { rx.await.map_err(|_| ResolveError::ChannelClosed) };
+        let scheduler_state = state.clone();
+        let resolve_fut = async move {
+            let resolution = rx.await.map_err(|_| ResolveError::ChannelClosed);
+            // Abandon any prefetches that are still waiting for a permit, so that the fetcher can
+            // complete.
+            scheduler_state.prefetch_scheduler.close();
+            resolution
+        };
 
         // Wait for both to complete.
-        let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)?;
+        let ((), resolution) = tokio::try_join!(requests_fut, resolve_fut)
+            .map_err(|err| state.metadata_build_limit_error(err))?;
 
         state.on_complete();
-        resolution
+        let stats = state.prefetch_scheduler.stats();
+        debug!(
+            "Metadata requests: {} issued, {} cache hits, {} prefetch hits",
+            stats.requests(),
+            stats.cache_hits(),
+            stats.prefetch_hits()
+        );
+        let resolution = resolution?;
+
+        // Report any packages that were included despite lacking upload times.
+        state
+            .missing_metadata
+            .report(MissingMetadataPolicy::from_strict(
+                state.options.strict_exclude_newer,
+            ))?;
+
+        Ok(resolution)
     }
 }
 
@@ -346,6 +429,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
         let mut resolutions = vec![];
+        let mut steps = 0usize;
 
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let Some(split) = state.env.end_user_fork_display() {
@@ -393,6 +477,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 &mut state.pre_visited,
                                 &self.urls,
                                 &self.indexes,
+                                &self.options.excluded_packages,
                                 &state.python_requirement,
                                 request_sink,
                             )?;
@@ -428,7 +513,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 start.elapsed().as_secs_f32()
                             );
 
-                            let resolution = state.into_resolution();
+                            let explanations = self.explain(&state);
+                            let resolution = state.into_resolution(explanations);
 
                             // Walk over the selected versions, and mark them as preferences. We have to
                             // add forks back as to not override the preferences from the lockfile for
@@ -562,6 +648,20 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             .term_intersection_for_package(next_id)
                             .expect("a package was chosen but we don't have a term");
 
+                        // Excluded packages are unavailable in any form (e.g., with extras).
+                        if let Some(name) = next_package.name_no_root()
+                            && self.options.excluded_packages.contains(name)
+                        {
+                            state
+                                .pubgrub
+                                .add_incompatibility(Incompatibility::custom_term(
+                                    next_id,
+                                    term_intersection.clone(),
+                                    UnavailableReason::Package(UnavailablePackage::Excluded),
+                                ));
+                            continue;
+                        }
+
                         if let PubGrubPackageInner::Package { name, .. } = &**next_package {
                             // Check if the decision was due to the package being unavailable
                             if let Some(reason) = self.unavailable_packages.pin().get(name) {
@@ -619,6 +719,28 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                 state.prefetcher.version_tried(next_package, &version);
 
+                steps += 1;
+                if steps.is_multiple_of(PROGRESS_INTERVAL) {
+                    debug!(
+                        "Resolver made {steps} decisions in {:.3}s ({} packages assigned)",
+                        start.elapsed().as_secs_f32(),
+                        state.pubgrub.partial_solution.extract_solution().count()
+                    );
+                }
+                if let Some(limit) = self.options.resolution_effort
+                    && steps > limit
+                {
+                    return Err(state.resolution_effort_error(limit));
+                }
+
+                if self.metadata_builds.is_some() {
+                    if let PubGrubPackageInner::Package { name, .. } = &**next_package {
+                        self.selected_versions
+                            .pin()
+                            .insert(name.clone(), version.clone());
+                    }
+                }
+
                 self.on_progress(next_package, &version);
 
                 if !state
@@ -671,7 +793,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 &dependencies,
                                 &self.git,
                                 &self.workspace_members,
-                                self.selector.resolution_strategy(),
+                                &self.selector,
                             )
                             .map_err(|err| {
                                 enrich_dependency_error(err, next_id, &version, &state.pubgrub)
@@ -698,7 +820,8 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                         // Prioritize the forks.
                         match (self.options.fork_strategy, self.options.resolution_mode) {
-                            (ForkStrategy::Fewest, _) | (_, ResolutionMode::Lowest) => {
+                            (ForkStrategy::Fewest | ForkStrategy::FewestVersions, _)
+                            | (_, ResolutionMode::Lowest) => {
                                 // Prefer solving forks with lower Python bounds, since they're more
                                 // likely to produce solutions that work for forks with higher
                                 // Python bounds (whereas the inverse is not true).
@@ -761,16 +884,233 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.requirements,
             &self.constraints,
             &self.overrides,
+            &self.replacements,
             &self.preferences,
             &self.index,
             &self.git,
             &self.python_requirement,
             &self.conflicts,
             self.selector.resolution_strategy(),
+            &self
+                .forbidden_source_dists
+                .pin()
+                .iter()
+                .map(|(name, forbidden)| (name.clone(), forbidden.clone()))
+                .collect(),
             self.options.clone(),
         )
     }
 
+    /// Explain the selected versions of the requested packages (e.g., `--explain`) in a resolved
+    /// fork, based on the dependency incompatibilities that PubGrub recorded while solving.
+    fn explain(&self, state: &ForkState) -> Vec<Explanation> {
+        let solution: FxHashMap<_, _> = state.pubgrub.partial_solution.extract_solution().collect();
+        let fork = state.env.end_user_fork_display();
+
+        self.options
+            .explain
+            .iter()
+            .map(|name| {
+                // Proxy packages are assigned the same version as their base package.
+                let version = solution.iter().find_map(|(package, version)| {
+                    matches!(
+                        &*state.pubgrub.package_store[*package],
+                        PubGrubPackageInner::Package { name: package_name, .. } if package_name == name
+                    )
+                    .then(|| version.clone())
+                });
+
+                // Collect the requirements on the package (or any of its proxies) from other
+                // packages.
+                let mut requirements = Vec::new();
+                for (package, incompatibilities) in &state.pubgrub.incompatibilities {
+                    if state.pubgrub.package_store[*package].name() != Some(name) {
+                        continue;
+                    }
+                    for id in incompatibilities {
+                        let Kind::FromDependencyOf(
+                            parent,
+                            ref parent_range,
+                            dependency,
+                            ref dependency_range,
+                        ) = state.pubgrub.incompatibility_store[*id].kind
+                        else {
+                            continue;
+                        };
+                        if dependency != *package {
+                            continue;
+                        }
+                        let requirer = match &*state.pubgrub.package_store[parent] {
+                            PubGrubPackageInner::Root(_) => Requirer::Root,
+                            PubGrubPackageInner::Package {
+                                name: parent_name,
+                                extra,
+                                group,
+                                ..
+                            } => Requirer::Package {
+                                name: parent_name.clone(),
+                                extra: extra.clone(),
+                                group: group.clone(),
+                                versions: parent_range.clone(),
+                                selected: solution.get(&parent).cloned(),
+                            },
+                            PubGrubPackageInner::Extra {
+                                name: parent_name,
+                                extra,
+                                ..
+                            } => Requirer::Package {
+                                name: parent_name.clone(),
+                                extra: Some(extra.clone()),
+                                group: None,
+                                versions: parent_range.clone(),
+                                selected: solution.get(&parent).cloned(),
+                            },
+                            PubGrubPackageInner::Group {
+                                name: parent_name,
+                                group,
+                                ..
+                            } => Requirer::Package {
+                                name: parent_name.clone(),
+                                extra: None,
+                                group: Some(group.clone()),
+                                versions: parent_range.clone(),
+                                selected: solution.get(&parent).cloned(),
+                            },
+                            PubGrubPackageInner::Marker {
+                                name: parent_name,
+                                ..
+                            } => Requirer::Package {
+                                name: parent_name.clone(),
+                                extra: None,
+                                group: None,
+                                versions: parent_range.clone(),
+                                selected: solution.get(&parent).cloned(),
+                            },
+                            PubGrubPackageInner::Python(_) | PubGrubPackageInner::System(_) => {
+                                continue;
+                            }
+                        };
+                        // Ignore the edges between the package and its own proxies.
+                        if let Requirer::Package {
+                            name: parent_name,
+                            group: None,
+                            ..
+                        } = &requirer
+                        {
+                            if parent_name == name {
+                                continue;
+                            }
+                        }
+                        let requirement = ExplainedRequirement {
+                            requirer,
+                            range: dependency_range.clone(),
+                        };
+                        if !requirements.contains(&requirement) {
+                            requirements.push(requirement);
+                        }
+                    }
+                }
+                requirements.sort_by_cached_key(|requirement| match &requirement.requirer {
+                    Requirer::Root => None,
+                    Requirer::Package {
+                        name,
+                        extra,
+                        group,
+                        versions,
+                        ..
+                    } => Some((name.clone(), extra.clone(), group.clone(), versions.to_string())),
+                });
+
+                let constraints = self
+                    .constraints
+                    .get(name)
+                    .cloned()
+                    .unwrap_or_default();
+                let overrides = self
+                    .overrides
+                    .global_requirements()
+                    .filter(|requirement| requirement.name == *name)
+                    .cloned()
+                    .collect();
+
+                // Determine which versions satisfied the requirements, but were filtered out.
+                let mut filtered: BTreeMap<FilterReason, BTreeSet<Version>> = BTreeMap::new();
+                if let Some(version) = &version {
+                    let range = requirements
+                        .iter()
+                        .filter(|requirement| requirement.requirer.is_active())
+                        .fold(Range::full(), |range, requirement| {
+                            range.intersection(&requirement.range)
+                        });
+                    if let Some(response) = self.index.implicit().get(name) {
+                        if let VersionsResponse::Found(ref version_maps) = *response {
+                            let allow_prerelease = version.any_prerelease()
+                                || match self
+                                    .selector
+                                    .prerelease_strategy_for(name)
+                                    .allows(name, &state.env)
+                                {
+                                    AllowPrerelease::Yes => true,
+                                    AllowPrerelease::No => false,
+                                    AllowPrerelease::IfNecessary => {
+                                        !version_maps.iter().any(VersionMap::stable)
+                                    }
+                                };
+                            for version_map in version_maps {
+                                for (candidate, handle) in version_map.iter(&range) {
+                                    if candidate == version {
+                                        continue;
+                                    }
+                                    let Some(dist) = handle.prioritized_dist() else {
+                                        continue;
+                                    };
+                                    let reason = if dist.get().is_some() {
+                                        (!allow_prerelease && candidate.any_prerelease())
+                                            .then_some(FilterReason::Prerelease)
+                                    } else if matches!(
+                                        dist.incompatible_source(),
+                                        Some(IncompatibleSource::ExcludeNewer(_))
+                                    ) || matches!(
+                                        dist.incompatible_wheel(),
+                                        Some(IncompatibleWheel::ExcludeNewer(_))
+                                    ) {
+                                        Some(FilterReason::ExcludeNewer)
+                                    } else if matches!(
+                                        dist.incompatible_source(),
+                                        Some(IncompatibleSource::Yanked(_))
+                                    ) || matches!(
+                                        dist.incompatible_wheel(),
+                                        Some(IncompatibleWheel::Yanked(_))
+                                    ) {
+                                        Some(FilterReason::Yanked)
+                                    } else {
+                                        None
+                                    };
+                                    if let Some(reason) = reason {
+                                        filtered.entry(reason).or_default().insert(candidate.clone());
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Explanation {
+                    name: name.clone(),
+                    version,
+                    fork: fork.clone(),
+                    requirements,
+                    constraints,
+                    overrides,
+                    filtered: filtered
+                        .into_iter()
+                        .map(|(reason, versions)| (reason, versions.into_iter().rev().collect()))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
     /// Change the priority of often conflicting packages and backtrack.
     ///
     /// To be called after unit propagation.
@@ -920,7 +1260,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         &fork.dependencies,
                         &self.git,
                         &self.workspace_members,
-                        self.selector.resolution_strategy(),
+                        &self.selector,
                     )
                     .map_err(|err| {
                         enrich_dependency_error(err, package, version, &forked_state.pubgrub)
@@ -979,6 +1319,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 parent: _,
                 source: _,
             } = dependency;
+            if let PubGrubPackageInner::Package { name, .. } = &**package {
+                self.prefetch_scheduler.record_demand(name);
+            }
             let url = package.name().and_then(|name| state.fork_urls.get(name));
             let index = package.name().and_then(|name| state.fork_indexes.get(name));
             self.visit_package(package, url, index, request_sink)?;
@@ -1015,6 +1358,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             return Ok(());
         };
 
+        // Never fetch metadata for excluded packages.
+        if self.options.excluded_packages.contains(name) {
+            return Ok(());
+        }
+
         if let Some(url) = url {
             // Verify that the package is allowed under the hash-checking policy.
             if !self.hasher.allows_url(&url.verbatim) {
@@ -1057,6 +1405,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         pre_visited: &mut FxHashMap<Id<PubGrubPackage>, Range<Version>>,
         urls: &Urls,
         indexes: &Indexes,
+        excluded_packages: &[PackageName],
         python_requirement: &PythonRequirement,
         request_sink: &Sender<Request>,
     ) -> Result<(), ResolveError> {
@@ -1081,6 +1430,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             if indexes.contains_key(name) {
                 continue;
             }
+            // Avoid visiting packages that are excluded from the resolution.
+            if excluded_packages.contains(name) {
+                continue;
+            }
             // Unit propagation often leaves a package's range unchanged. Although prefetching the
             // same package and range is idempotent, selecting its candidate is not free.
             if pre_visited.get(&id) == Some(range) {
@@ -1143,6 +1496,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             | PubGrubPackageInner::Extra { name, .. }
             | PubGrubPackageInner::Group { name, .. }
             | PubGrubPackageInner::Package { name, .. } => {
+                // Excluded packages have no available versions, regardless of any overrides,
+                // constraints, or URLs.
+                if self.options.excluded_packages.contains(name) {
+                    return Ok(None);
+                }
+
                 if let Some(url) = package.name().and_then(|name| fork_urls.get(name)) {
                     self.choose_version_url(id, name, range, url, env, python_requirement, pubgrub)
                 } else {
@@ -1165,6 +1524,64 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
     }
 
+    /// Return the error for a distribution whose metadata couldn't be fetched or built, including
+    /// why its source distribution was built, if the package publishes wheels.
+    fn dist_error(
+        &self,
+        dist: &RequestedDist,
+        err: &Arc<uv_distribution::Error>,
+        chain: DerivationChain,
+    ) -> ResolveError {
+        let reason = match dist {
+            RequestedDist::Installable(Dist::Source(SourceDist::Registry(sdist))) => {
+                SourceBuildReason::from_source_dist(
+                    sdist,
+                    self.build_options.no_binary_package(&sdist.name),
+                    self.tags.as_ref(),
+                )
+            }
+            _ => None,
+        };
+        ResolveError::Dist(
+            DistErrorKind::from_requested_dist(dist, &**err),
+            Box::new(dist.clone()),
+            chain,
+            reason,
+            err.clone(),
+        )
+    }
+
+    /// Return the error for a distribution whose metadata couldn't be built.
+    ///
+    /// Unless builds fail fast, first waits for the metadata requests that are already in flight,
+    /// and reports any other build failures among them alongside the given error.
+    fn build_failure(&self, distribution_id: &DistributionId, err: ResolveError) -> ResolveError {
+        if self.fail_fast {
+            return err;
+        }
+
+        let mut failures = Vec::new();
+        for id in self.index.distributions().keys() {
+            if id == *distribution_id {
+                continue;
+            }
+            let Ok(response) = self.index.distributions().wait_blocking(&id) else {
+                continue;
+            };
+            if let MetadataResponse::Error(dist, err) = &*response {
+                failures.push(self.dist_error(dist, err, DerivationChain::default()));
+            }
+        }
+        if failures.is_empty() {
+            return err;
+        }
+
+        // Sort the other failures, such that the report is independent of completion order.
+        failures.sort_by_cached_key(ToString::to_string);
+        failures.insert(0, err);
+        ResolveError::Multiple(failures)
+    }
+
     /// Select a version for a URL requirement. Since there is only one version per URL, we return
     /// that version if it is in range and `None` otherwise.
     fn choose_version_url(
@@ -1202,11 +1619,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             // TODO(charlie): Add derivation chain for URL dependencies. In practice, this isn't
             // critical since we fetch URL dependencies _prior_ to invoking the resolver.
             MetadataResponse::Error(dist, err) => {
-                return Err(ResolveError::Dist(
-                    DistErrorKind::from_requested_dist(dist, &**err),
-                    dist.clone(),
-                    DerivationChain::default(),
-                    err.clone(),
+                return Err(self.build_failure(
+                    &distribution_id,
+                    self.dist_error(dist, err, DerivationChain::default()),
                 ));
             }
         };
@@ -1361,6 +1776,25 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 incompatible_dist: incompatibility,
                 prioritized_dist: _,
             } => {
+                // Track versions that were rejected because of an index's `allow-sdist` setting,
+                // to warn if an older version is selected instead.
+                if let IncompatibleDist::Source(IncompatibleSource::IndexPolicy(index)) =
+                    incompatibility
+                {
+                    let version = candidate.version();
+                    self.forbidden_source_dists.pin().update_or_insert(
+                        name.clone(),
+                        |existing| {
+                            if existing.0 < *version {
+                                (version.clone(), index.clone())
+                            } else {
+                                existing.clone()
+                            }
+                        },
+                        (version.clone(), index.clone()),
+                    );
+                }
+
                 // If the version is incompatible because no distributions are compatible, exit early.
                 return Ok(Some(ResolverVersion::Unavailable(
                     candidate.version().clone(),
@@ -1705,6 +2139,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         // store the exact file that we selected to satisfy that version.
         pins.insert(candidate, dist);
 
+        // Record the selected file if `exclude-newer` couldn't be applied to it.
+        self.record_missing_upload_time(name, dist);
+
         // Emit a request to fetch the metadata for this version.
         if matches!(&**package, PubGrubPackageInner::Package { .. }) {
             if self.dependency_mode.is_transitive() {
@@ -1734,6 +2171,37 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         Ok(())
     }
 
+    /// Record the selected distribution if an `exclude-newer` cutoff applies to it, but its source
+    /// doesn't provide an upload time (e.g., a `--find-links` directory).
+    ///
+    /// This is tracked per resolution, at selection time, rather than when the [`VersionMap`] is
+    /// constructed, since version maps are cached in the [`InMemoryIndex`] and shared across
+    /// resolutions.
+    fn record_missing_upload_time(&self, name: &PackageName, dist: &CompatibleDist) {
+        let (index, upload_time) = match dist.for_installation() {
+            ResolvedDistRef::InstallableRegistrySourceDist { sdist, .. } => {
+                (&sdist.index, sdist.file.upload_time_utc_ms)
+            }
+            ResolvedDistRef::InstallableRegistryBuiltDist { wheel, .. } => {
+                (&wheel.index, wheel.file.upload_time_utc_ms)
+            }
+            ResolvedDistRef::Installed { .. } => return,
+        };
+        if upload_time.is_some() {
+            return;
+        }
+        if self
+            .options
+            .exclude_newer
+            .exclude_newer_package_for_index(name, self.locations.exclude_newer_for(index))
+            .is_none()
+        {
+            return;
+        }
+        self.missing_metadata
+            .insert(MissingMetadataKind::UploadTime, index, name);
+    }
+
     /// Check if the distribution is incompatible with the Python requirement, and if so, return
     /// the incompatibility.
     fn check_requires_python<'dist>(
@@ -1883,6 +2351,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 }
 
                 // Wait for the metadata to be available.
+                if self.prefetch_scheduler.is_prefetched(distribution_id)
+                    && self.index.distributions().get(distribution_id).is_some()
+                {
+                    self.prefetch_scheduler.stats().prefetch_hit();
+                }
                 let response = self
                     .index
                     .distributions()
@@ -1911,12 +2384,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     MetadataResponse::Error(dist, err) => {
                         let chain = DerivationChainBuilder::from_state(id, version, pubgrub)
                             .unwrap_or_default();
-                        return Err(ResolveError::Dist(
-                            DistErrorKind::from_requested_dist(dist, &**err),
-                            dist.clone(),
-                            chain,
-                            err.clone(),
-                        ));
+                        return Err(
+                            self.build_failure(distribution_id, self.dist_error(dist, err, chain))
+                        );
                     }
                 };
 
@@ -1930,6 +2400,18 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 }
 
+                // If the package depends on a replaced package, ensure that the replacement provides
+                // a compatible version.
+                if let Some(unavailable) = self.incompatible_replacement(
+                    metadata,
+                    extra.as_ref(),
+                    group.as_ref(),
+                    env,
+                    python_requirement,
+                ) {
+                    return Ok(Dependencies::Unavailable(unavailable));
+                }
+
                 // Identify any system dependencies based on the index URL.
                 let system_dependencies = self
                     .options
@@ -2189,6 +2671,51 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
     }
 
+    /// Check the requirements on replaced packages against the version that the replacement
+    /// provides, returning the first incompatibility.
+    fn incompatible_replacement(
+        &self,
+        metadata: &Metadata,
+        extra: Option<&ExtraName>,
+        group: Option<&GroupName>,
+        env: &ResolverEnvironment,
+        python_requirement: &PythonRequirement,
+    ) -> Option<UnavailableVersion> {
+        if self.replacements.is_empty() {
+            return None;
+        }
+        let python_marker = python_requirement.to_marker_tree();
+        let dependencies = if let Some(group) = group {
+            Either::Left(metadata.dependency_groups.get(group).into_iter().flatten())
+        } else {
+            Either::Right(metadata.requires_dist.iter())
+        };
+        dependencies
+            .filter(|requirement| {
+                Self::is_requirement_applicable(
+                    requirement,
+                    extra,
+                    env,
+                    python_marker,
+                    python_requirement,
+                )
+            })
+            .find_map(|requirement| {
+                let replacement = self.replacements.get(&requirement.name)?;
+                if replacement.satisfies(requirement) {
+                    return None;
+                }
+                let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                    return None;
+                };
+                Some(UnavailableVersion::IncompatibleReplacement {
+                    package: requirement.name.clone(),
+                    specifier: specifier.clone(),
+                    provides: replacement.provides.clone()?,
+                })
+            })
+    }
+
     /// The set of the regular and dev dependencies, filtered by Python version,
     /// the markers of this fork and the requested extra.
     fn requirements_for_extra<'data, 'parameters>(
@@ -2211,6 +2738,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     .excludes
                     .contains_for_package(exclusion_package, &requirement.name)
             })
+            .map(move |requirement| self.replacements.apply(requirement))
             .filter(move |requirement| {
                 Self::is_requirement_applicable(
                     requirement,
@@ -2468,6 +2996,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         match request {
             // Fetch package metadata from the registry.
             Request::Package(package_name, index) => {
+                self.prefetch_scheduler.stats().request();
                 let package_versions = provider
                     .get_package_versions(&package_name, index.as_ref())
                     .boxed_local()
@@ -2483,7 +3012,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
             // Fetch distribution metadata from the distribution database.
             Request::Dist(dist) => {
-                if let Some(version) = dist.version() {
+                // Unless the user opted out of index-provided metadata for this package.
+                if let Some(version) = dist.version()
+                    && !self.options.reresolve_metadata.contains(dist.name())
+                {
                     if let Some(index) = dist.index() {
                         // Check the implicit indexes for pre-provided metadata.
                         let versions_response = self.index.implicit().get(dist.name());
@@ -2496,6 +3028,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                         continue;
                                     };
                                     debug!("Found registry-provided metadata for: {dist}");
+                                    self.prefetch_scheduler.stats().cache_hit();
                                     return Ok(Some(Response::Dist {
                                         dist,
                                         metadata: MetadataResponse::Found(
@@ -2519,6 +3052,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                     continue;
                                 };
                                 debug!("Found registry-provided metadata for: {dist}");
+                                self.prefetch_scheduler.stats().cache_hit();
                                 return Ok(Some(Response::Dist {
                                     dist,
                                     metadata: MetadataResponse::Found(
@@ -2530,6 +3064,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 }
 
+                self.prefetch_scheduler.stats().request();
                 let metadata = provider
                     .get_or_build_wheel_metadata(&dist)
                     .boxed_local()
@@ -2625,12 +3160,16 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     return Ok(None);
                 };
 
-                // If the registry provided metadata for this distribution, use it.
-                for version_map in version_map {
+                // If the registry provided metadata for this distribution, use it (unless the user
+                // opted out of index-provided metadata for this package).
+                let use_registry_metadata =
+                    !self.options.reresolve_metadata.contains(&package_name);
+                for version_map in version_map.iter().filter(|_| use_registry_metadata) {
                     if let Some(metadata) = version_map.get_metadata(candidate.version()) {
                         let dist = dist.for_resolution();
                         if version_map.index() == dist.index() {
                             debug!("Found registry-provided metadata for: {dist}");
+                            self.prefetch_scheduler.stats().cache_hit();
 
                             let metadata =
                                 MetadataResponse::Found(ArchiveMetadata::from_metadata23(metadata));
@@ -2701,6 +3240,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     return Ok(None);
                 }
 
+                // Wait for a permit before registering the request, such that the resolver never
+                // blocks on a prefetch that is still queued.
+                let Some(_permit) = self.prefetch_scheduler.acquire(&package_name).await else {
+                    trace!("Abandoning prefetch for: {package_name}");
+                    return Ok(None);
+                };
+
                 // Emit a request to fetch the metadata for this version.
                 let dist = dist.for_resolution();
                 if self.index.distributions().register(dist.distribution_id()) {
@@ -2713,8 +3259,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         });
                     }
 
+                    self.prefetch_scheduler
+                        .insert_prefetched(dist.distribution_id());
                     let response = match dist {
                         ResolvedDist::Installable { dist, .. } => {
+                            self.prefetch_scheduler.stats().request();
                             let metadata = provider
                                 .get_or_build_wheel_metadata(&dist)
                                 .boxed_local()
@@ -2738,6 +3287,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                     Ok(Some(response))
                 } else {
+                    self.prefetch_scheduler.stats().cache_hit();
                     Ok(None)
                 }
             }
@@ -2820,7 +3370,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         for (version, dists) in version_map.iter(&Ranges::full()) {
                             // Included versions are those that survive the effective
                             // `exclude-newer` filter used during resolution. Files with
-                            // missing upload times are treated as excluded (matching
+                            // missing upload times are treated as included (matching
                             // the resolution behavior in `version_map.rs`).
                             let excluded_from_included = || {
                                 let Some(included_version_cutoff) =
@@ -2832,7 +3382,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                     return true;
                                 };
                                 prioritized_dist.files().all(|file| {
-                                    file.upload_time_utc_ms.is_none_or(|upload_time| {
+                                    file.upload_time_utc_ms.is_some_and(|upload_time| {
                                         upload_time >= included_version_cutoff.as_millisecond()
                                     })
                                 })
@@ -2902,6 +3452,35 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         )))
     }
 
+    /// If the resolution stopped because it exceeded the limit on metadata builds, report the
+    /// builds, split into those for the selected versions and those triggered by backtracking.
+    fn metadata_build_limit_error(&self, err: ResolveError) -> ResolveError {
+        let ResolveError::Distribution(uv_distribution::Error::MetadataBuildLimit(
+            package,
+            version,
+            limit,
+        )) = err
+        else {
+            return err;
+        };
+        let builds = self
+            .metadata_builds
+            .as_ref()
+            .map(MetadataBuilds::builds)
+            .unwrap_or_default();
+        let selected_versions = self.selected_versions.pin();
+        let (selected, backtracking) = builds
+            .into_iter()
+            .partition(|build| selected_versions.get(&build.name) == Some(&build.version));
+        ResolveError::MetadataBuildLimit(Box::new(MetadataBuildLimitError {
+            limit,
+            package,
+            version,
+            selected,
+            backtracking,
+        }))
+    }
+
     fn on_progress(&self, package: &PubGrubPackage, version: &Version) {
         if let Some(reporter) = self.reporter.as_ref() {
             match &**package {
@@ -3049,7 +3628,7 @@ impl ForkState {
         dependencies: &[PubGrubDependency],
         git: &GitResolver,
         workspace_members: &BTreeSet<PackageName>,
-        resolution_strategy: &ResolutionStrategy,
+        selector: &CandidateSelector,
     ) -> Result<(), ResolveError> {
         for dependency in dependencies {
             let PubGrubDependency {
@@ -3078,6 +3657,18 @@ impl ForkState {
                 for index in indexes.get(name, &self.env) {
                     self.fork_indexes.insert(name, index, &self.env)?;
                 }
+
+                // A package can't be sourced from both a URL and an explicit index in one fork.
+                if let (Some(url), Some(index)) =
+                    (self.fork_urls.get(name), self.fork_indexes.get(name))
+                {
+                    return Err(ResolveError::ConflictingSources {
+                        package_name: name.clone(),
+                        url: Box::new(url.parsed_url.clone()),
+                        index: index.url.clone(),
+                        env: self.env.clone(),
+                    });
+                }
             }
 
             if let Some(name) = self.pubgrub.package_store[for_package]
@@ -3095,10 +3686,9 @@ impl ForkState {
                 let missing_lower_bound = version
                     .bounding_range()
                     .is_none_or(|(lowest, _highest)| lowest == Bound::Unbounded);
-                let strategy_lowest = matches!(
-                    resolution_strategy,
-                    ResolutionStrategy::Lowest | ResolutionStrategy::LowestDirect(..)
-                );
+                let strategy_lowest = package
+                    .name_no_root()
+                    .is_some_and(|name| selector.resolution_strategy_for(name).is_lowest());
 
                 if !has_url && missing_lower_bound && strategy_lowest {
                     let name = package.name_no_root().unwrap();
@@ -3243,6 +3833,30 @@ impl ForkState {
         }
     }
 
+    /// Report the packages that caused the most backtracking, once the resolver exceeded its
+    /// step limit.
+    fn resolution_effort_error(&self, limit: usize) -> ResolveError {
+        let mut conflicts = FxHashMap::<PackageName, usize>::default();
+        for (id, count) in self
+            .conflict_tracker
+            .affected
+            .iter()
+            .chain(&self.conflict_tracker.culprit)
+        {
+            if let Some(name) = self.pubgrub.package_store[*id].name() {
+                *conflicts.entry(name.clone()).or_default() += count;
+            }
+        }
+        let conflicts = conflicts
+            .into_iter()
+            .sorted_unstable_by(|(a_name, a_count), (b_name, b_count)| {
+                b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+            })
+            .take(MAX_REPORTED_CONFLICTS)
+            .collect();
+        ResolveError::ResolutionEffort(Box::new(ResolutionEffortError { limit, conflicts }))
+    }
+
     fn add_unavailable_version(&mut self, version: Version, reason: UnavailableVersion) {
         // Incompatible requires-python versions are special in that we track
         // them as incompatible dependencies instead of marking the package version
@@ -3314,7 +3928,7 @@ impl ForkState {
         (url, index)
     }
 
-    fn into_resolution(self) -> Resolution {
+    fn into_resolution(self, explanations: Vec<Explanation>) -> Resolution {
         let solution: FxHashMap<_, _> = self.pubgrub.partial_solution.extract_solution().collect();
         let edge_count: usize = solution
             .keys()
@@ -3560,6 +4174,7 @@ impl ForkState {
             edges,
             pins: self.pins,
             env: self.env,
+            explanations,
         }
     }
 }
@@ -3575,6 +4190,8 @@ pub(crate) struct Resolution {
     pub(crate) pins: FilePins,
     /// The environment setting this resolution was found under.
     pub(crate) env: ResolverEnvironment,
+    /// The explanations for the selected versions of the requested packages (e.g., `--explain`).
+    pub(crate) explanations: Vec<Explanation>,
 }
 
 /// Package representation we used during resolution where each extra and also the dev-dependencies
@@ -0,0 +1,303 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use rustc_hash::FxHashMap;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, oneshot};
+
+use uv_distribution_types::DistributionId;
+use uv_normalize::PackageName;
+
+/// Schedules speculative metadata prefetches.
+///
+/// Prefetches are limited by a semaphore that is shared by every resolution in the process
+/// (including those for build requirements), such that the limit is global. Whenever a permit
+/// becomes available to a resolution, the scheduler hands it to the pending prefetch for the
+/// package that appears in the most requirements seen so far, on the basis that its metadata is
+/// the most likely to be needed (and the most likely to unblock further requests). Ties are broken
+/// in request order.
+#[derive(Debug)]
+pub(crate) struct PrefetchScheduler {
+    /// The semaphore that limits the number of prefetches in flight at once.
+    semaphore: Arc<Semaphore>,
+    /// The prefetches waiting for a permit.
+    queue: Mutex<PrefetchQueue>,
+    /// The number of requirements that reference each package, across all visited versions.
+    demand: Mutex<FxHashMap<PackageName, usize>>,
+    /// The distributions whose metadata was fetched by a prefetch.
+    prefetched: papaya::HashSet<DistributionId>,
+    /// Counters for the metadata requests issued during resolution.
+    stats: MetadataStats,
+}
+
+#[derive(Debug, Default)]
+struct PrefetchQueue {
+    /// The prefetches waiting for a permit, in request order.
+    waiting: Vec<(PackageName, oneshot::Sender<OwnedSemaphorePermit>)>,
+    /// Whether the resolution has finished, such that no further prefetches should be admitted.
+    closed: bool,
+}
+
+impl PrefetchScheduler {
+    /// Create a [`PrefetchScheduler`] that draws permits from the given semaphore.
+    pub(crate) fn new(semaphore: Arc<Semaphore>) -> Self {
+        Self {
+            semaphore,
+            queue: Mutex::default(),
+            demand: Mutex::default(),
+            prefetched: papaya::HashSet::default(),
+            stats: MetadataStats::default(),
+        }
+    }
+
+    /// Record that a requirement on the given package was encountered.
+    pub(crate) fn record_demand(&self, name: &PackageName) {
+        let mut demand = self.demand.lock().unwrap();
+        if let Some(count) = demand.get_mut(name) {
+            *count += 1;
+        } else {
+            demand.insert(name.clone(), 1);
+        }
+    }
+
+    /// Wait for a permit to prefetch metadata for the given package.
+    ///
+    /// Returns `None` if the resolution finishes before the prefetch is admitted.
+    pub(crate) async fn acquire(&self, name: &PackageName) -> Option<OwnedSemaphorePermit> {
+        let (sender, mut receiver) = oneshot::channel();
+        {
+            let mut queue = self.queue.lock().unwrap();
+            if queue.closed {
+                return None;
+            }
+            queue.waiting.push((name.clone(), sender));
+        }
+
+        // Until this prefetch is handed a permit, keep acquiring permits from the shared semaphore,
+        // handing each to whichever pending prefetch has the highest demand at that point (which
+        // may be this one).
+        loop {
+            tokio::select! {
+                biased;
+                permit = &mut receiver => {
+                    // The sender is dropped without sending when the scheduler is closed.
+                    return permit.ok();
+                }
+                permit = Arc::clone(&self.semaphore).acquire_owned() => {
+                    self.hand_off(permit.expect("the prefetch semaphore is never closed"));
+                }
+            }
+        }
+    }
+
+    /// Hand a permit to the pending prefetch with the highest demand.
+    fn hand_off(&self, mut permit: OwnedSemaphorePermit) {
+        let mut queue = self.queue.lock().unwrap();
+        let demand = self.demand.lock().unwrap();
+        while !queue.waiting.is_empty() {
+            let mut next = 0;
+            let mut priority = 0;
+            for (index, (name, _)) in queue.waiting.iter().enumerate() {
+                let count = demand.get(name).copied().unwrap_or(0);
+                if index == 0 || count > priority {
+                    next = index;
+                    priority = count;
+                }
+            }
+            let (_, sender) = queue.waiting.remove(next);
+            // If the waiter was cancelled, try the next one.
+            match sender.send(permit) {
+                Ok(()) => return,
+                Err(returned) => permit = returned,
+            }
+        }
+        // Every waiter was cancelled; return the permit to the semaphore.
+        drop(permit);
+    }
+
+    /// Abandon any pending prefetches, and reject any further prefetches.
+    ///
+    /// Once the solver has finished, pending prefetches are no longer useful. Since the limit is
+    /// shared with any enclosing resolution, they may also be waiting on a permit that is held by
+    /// a prefetch in the enclosing resolution, which is itself waiting on the build that triggered
+    /// this resolution.
+    pub(crate) fn close(&self) {
+        let mut queue = self.queue.lock().unwrap();
+        queue.closed = true;
+        queue.waiting.clear();
+    }
+
+    /// Record that the metadata for the given distribution was fetched by a prefetch.
+    pub(crate) fn insert_prefetched(&self, id: DistributionId) {
+        self.prefetched.pin().insert(id);
+    }
+
+    /// Returns `true` if the metadata for the given distribution was fetched by a prefetch.
+    pub(crate) fn is_prefetched(&self, id: &DistributionId) -> bool {
+        self.prefetched.pin().contains(id)
+    }
+
+    /// Return the counters for the metadata requests issued during resolution.
+    pub(crate) fn stats(&self) -> &MetadataStats {
+        &self.stats
+    }
+}
+
+/// Counters for the metadata requests issued during resolution, reported in verbose output.
+#[derive(Debug, Default)]
+pub(crate) struct MetadataStats {
+    /// The number of requests issued to the provider, i.e., to the index or to a build.
+    requests: AtomicUsize,
+    /// The number of metadata lookups served without issuing a request, e.g., from
+    /// registry-provided metadata or a previous request.
+    cache_hits: AtomicUsize,
+    /// The number of times the resolver needed distribution metadata that a prefetch had
+    /// already fetched.
+    prefetch_hits: AtomicUsize,
+}
+
+impl MetadataStats {
+    pub(crate) fn request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn prefetch_hit(&self) {
+        self.prefetch_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn requests(&self) -> usize {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn cache_hits(&self) -> usize {
+        self.cache_hits.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn prefetch_hits(&self) -> usize {
+        self.prefetch_hits.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use futures::FutureExt;
+    use tokio::sync::Semaphore;
+
+    use uv_normalize::PackageName;
+
+    use super::PrefetchScheduler;
+
+    #[test]
+    fn admits_highest_demand_first() {
+        let leaf = PackageName::from_str("leaf").unwrap();
+        let other = PackageName::from_str("other").unwrap();
+        let hub = PackageName::from_str("hub").unwrap();
+
+        let scheduler = PrefetchScheduler::new(Arc::new(Semaphore::new(1)));
+        for _ in 0..3 {
+            scheduler.record_demand(&hub);
+        }
+        scheduler.record_demand(&other);
+
+        // Occupy the only permit.
+        let permit = scheduler.acquire(&leaf).now_or_never().unwrap().unwrap();
+
+        // Queue prefetches in order of increasing demand.
+        let mut other_permit = Box::pin(scheduler.acquire(&other));
+        let mut hub_permit = Box::pin(scheduler.acquire(&hub));
+        assert!((&mut other_permit).now_or_never().is_none());
+        assert!((&mut hub_permit).now_or_never().is_none());
+
+        // Releasing the permit should admit `hub`, despite `other` being queued first.
+        drop(permit);
+        assert!((&mut other_permit).now_or_never().is_none());
+        let hub_permit = (&mut hub_permit).now_or_never().unwrap().unwrap();
+
+        // Releasing again should admit `other`.
+        drop(hub_permit);
+        assert!((&mut other_permit).now_or_never().unwrap().is_some());
+    }
+
+    #[test]
+    fn demand_recorded_while_waiting() {
+        let leaf = PackageName::from_str("leaf").unwrap();
+        let first = PackageName::from_str("first").unwrap();
+        let second = PackageName::from_str("second").unwrap();
+
+        let scheduler = PrefetchScheduler::new(Arc::new(Semaphore::new(1)));
+        let permit = scheduler.acquire(&leaf).now_or_never().unwrap().unwrap();
+
+        // With equal demand, prefetches are admitted in request order...
+        let mut first_permit = Box::pin(scheduler.acquire(&first));
+        let mut second_permit = Box::pin(scheduler.acquire(&second));
+        assert!((&mut first_permit).now_or_never().is_none());
+        assert!((&mut second_permit).now_or_never().is_none());
+
+        // ...unless more requirements on a package are discovered while it's waiting.
+        scheduler.record_demand(&second);
+        drop(permit);
+        assert!((&mut first_permit).now_or_never().is_none());
+        assert!((&mut second_permit).now_or_never().unwrap().is_some());
+        assert!((&mut first_permit).now_or_never().unwrap().is_some());
+    }
+
+    #[test]
+    fn limit_is_shared() {
+        let name = PackageName::from_str("pkg").unwrap();
+
+        // Two resolutions (e.g., a resolution and a nested resolution of build requirements)
+        // share a single permit.
+        let semaphore = Arc::new(Semaphore::new(1));
+        let outer = PrefetchScheduler::new(semaphore.clone());
+        let inner = PrefetchScheduler::new(semaphore);
+
+        let permit = outer.acquire(&name).now_or_never().unwrap().unwrap();
+        let mut inner_permit = Box::pin(inner.acquire(&name));
+        assert!((&mut inner_permit).now_or_never().is_none());
+
+        drop(permit);
+        assert!((&mut inner_permit).now_or_never().unwrap().is_some());
+    }
+
+    #[test]
+    fn cancelled_waiter() {
+        let leaf = PackageName::from_str("leaf").unwrap();
+        let name = PackageName::from_str("pkg").unwrap();
+
+        let scheduler = PrefetchScheduler::new(Arc::new(Semaphore::new(1)));
+        let permit = scheduler.acquire(&leaf).now_or_never().unwrap().unwrap();
+
+        // A waiter that is dropped before it's admitted shouldn't consume the permit.
+        let mut cancelled = Box::pin(scheduler.acquire(&name));
+        assert!((&mut cancelled).now_or_never().is_none());
+        drop(cancelled);
+
+        drop(permit);
+        assert!(scheduler.acquire(&name).now_or_never().unwrap().is_some());
+    }
+
+    #[test]
+    fn closed() {
+        let leaf = PackageName::from_str("leaf").unwrap();
+        let name = PackageName::from_str("pkg").unwrap();
+
+        let scheduler = PrefetchScheduler::new(Arc::new(Semaphore::new(1)));
+        let _permit = scheduler.acquire(&leaf).now_or_never().unwrap().unwrap();
+
+        // Closing the scheduler abandons pending prefetches, even while the permit is held (e.g.,
+        // by an enclosing resolution).
+        let mut pending = Box::pin(scheduler.acquire(&name));
+        assert!((&mut pending).now_or_never().is_none());
+        scheduler.close();
+        assert!((&mut pending).now_or_never().unwrap().is_none());
+
+        // Further prefetches are rejected.
+        assert!(scheduler.acquire(&name).now_or_never().unwrap().is_none());
+    }
+}
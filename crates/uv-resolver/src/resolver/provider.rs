@@ -217,12 +217,14 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                                     .and_then(|flat_index| flat_index.get(package_name))
                                     .cloned(),
                                 self.build_options,
+                                self.index_locations.artifact_policy_for(index),
                             ),
                             MetadataFormat::Flat(metadata) => VersionMap::from_flat_metadata(
                                 metadata,
                                 self.tags.as_ref(),
                                 &self.hasher,
                                 self.build_options,
+                                self.index_locations.artifact_policy_for(index),
                             ),
                         }
                     })
@@ -311,6 +313,8 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                 uv_distribution::Error::WheelMetadata(_, err) => Ok(MetadataResponse::Unavailable(
                     MetadataUnavailable::InvalidStructure(Arc::new(*err)),
                 )),
+                // Exceeding the metadata build limit is fatal.
+                uv_distribution::Error::MetadataBuildLimit(..) => Err(err),
                 uv_distribution::Error::RequiresPython(requires_python, version) => {
                     Ok(MetadataResponse::Unavailable(
                         MetadataUnavailable::RequiresPython(requires_python, version),
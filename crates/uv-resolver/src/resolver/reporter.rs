@@ -17,6 +17,13 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a source distribution build is complete.
     fn on_build_complete(&self, source: &BuildableSource, id: usize);
 
+    /// Callback to invoke when a build is blocked on another process building the same source
+    /// distribution.
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize;
+
+    /// Callback to invoke when another process has finished building the source distribution.
+    fn on_build_wait_complete(&self, source: &BuildableSource, id: usize);
+
     /// Callback to invoke when a download is kicked off.
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize;
 
@@ -27,6 +34,13 @@ pub trait Reporter: Send + Sync {
     /// Callback to invoke when a download is complete.
     fn on_download_complete(&self, name: &PackageName, id: usize);
 
+    /// Callback to invoke when a download is blocked on another process downloading the same
+    /// wheel.
+    fn on_download_wait_start(&self, name: &PackageName) -> usize;
+
+    /// Callback to invoke when another process has finished downloading the wheel.
+    fn on_download_wait_complete(&self, name: &PackageName, id: usize);
+
     /// Callback to invoke when a repository checkout begins.
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize;
 
@@ -78,4 +92,20 @@ impl uv_distribution::Reporter for Facade {
     fn on_download_complete(&self, name: &PackageName, id: usize) {
         self.reporter.on_download_complete(name, id);
     }
+
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_wait_start(source)
+    }
+
+    fn on_build_wait_complete(&self, source: &BuildableSource, id: usize) {
+        self.reporter.on_build_wait_complete(source, id);
+    }
+
+    fn on_download_wait_start(&self, name: &PackageName) -> usize {
+        self.reporter.on_download_wait_start(name)
+    }
+
+    fn on_download_wait_complete(&self, name: &PackageName, id: usize) {
+        self.reporter.on_download_wait_complete(name, id);
+    }
 }
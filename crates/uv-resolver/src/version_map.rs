@@ -11,16 +11,15 @@ use uv_client::{FlatIndexEntry, OwnedArchive, SimpleDetailMetadata, VersionFiles
 use uv_configuration::BuildOptions;
 use uv_distribution_filename::{DistFilename, WheelFilename};
 use uv_distribution_types::{
-    HashComparison, IncompatibleSource, IncompatibleWheel, IndexUrl, PrioritizedDist,
-    RegistryBuiltWheel, RegistrySourceDist, RequiresPython, SourceDistCompatibility,
-    WheelCompatibility,
+    HashComparison, IncompatibleSource, IncompatibleWheel, IndexArtifactPolicy, IndexUrl,
+    PrioritizedDist, RegistryBuiltWheel, RegistrySourceDist, RequiresPython,
+    SourceDistCompatibility, WheelCompatibility,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_platform_tags::{IncompatibleTag, TagCompatibility, Tags};
 use uv_pypi_types::{HashDigest, ResolutionMetadata, Yanked};
 use uv_types::HashStrategy;
-use uv_warnings::warn_user_once;
 
 use crate::flat_index::FlatDistributions;
 use crate::yanks::AllowedYanks;
@@ -55,6 +54,7 @@ impl VersionMap {
         available_version_cutoff: Option<Timestamp>,
         flat_index: Option<FlatDistributions>,
         build_options: &BuildOptions,
+        artifact_policy: IndexArtifactPolicy,
     ) -> Self {
         let mut stable = false;
         let mut local = false;
@@ -100,6 +100,7 @@ impl VersionMap {
                 simple_metadata,
                 no_binary: build_options.no_binary_package(package_name),
                 no_build: build_options.no_build_package(package_name),
+                artifact_policy,
                 index: index.clone(),
                 tags: tags.cloned(),
                 allowed_yanks: allowed_yanks.clone(),
@@ -117,14 +118,19 @@ impl VersionMap {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        artifact_policy: IndexArtifactPolicy,
     ) -> Self {
         let mut stable = false;
         let mut local = false;
         let mut map = BTreeMap::new();
 
-        for (version, prioritized_dist) in
-            FlatDistributions::from_entries(flat_metadata, tags, hasher, build_options)
-        {
+        for (version, prioritized_dist) in FlatDistributions::from_entries(
+            flat_metadata,
+            tags,
+            hasher,
+            build_options,
+            artifact_policy,
+        ) {
             stable |= version.is_stable();
             local |= version.is_local();
             map.insert(version, prioritized_dist);
@@ -465,6 +471,8 @@ struct VersionMapLazy {
     no_binary: bool,
     /// When true, source dists aren't allowed.
     no_build: bool,
+    /// The artifact types that may be selected from the index.
+    artifact_policy: IndexArtifactPolicy,
     /// The URL of the index where this package came from.
     index: IndexUrl,
     /// The set of compatibility tags that determines whether a wheel is usable
@@ -553,11 +561,13 @@ impl VersionMapLazy {
                             (true, Some(upload_time))
                         }
                         None => {
-                            warn_user_once!(
-                                "{} is missing an upload date, but user provided: {included_version_cutoff}",
-                                file.filename,
+                            // The resolver reports selected distributions that lack an upload
+                            // time, per the missing metadata policy.
+                            trace!(
+                                "Including `{}` (no upload time) despite exclude-newer ({included_version_cutoff})",
+                                file.filename
                             );
-                            (true, None)
+                            (false, None)
                         }
                         _ => (false, None),
                     }
@@ -643,6 +653,13 @@ impl VersionMapLazy {
             return SourceDistCompatibility::Incompatible(IncompatibleSource::NoBuild);
         }
 
+        // Check if source distributions are allowed from the index
+        if !self.artifact_policy.allow_sdist {
+            return SourceDistCompatibility::Incompatible(IncompatibleSource::IndexPolicy(
+                self.index.clone(),
+            ));
+        }
+
         // Check if after upload time cutoff
         if excluded {
             return SourceDistCompatibility::Incompatible(IncompatibleSource::ExcludeNewer(
@@ -692,6 +709,13 @@ impl VersionMapLazy {
             return WheelCompatibility::Incompatible(IncompatibleWheel::NoBinary);
         }
 
+        // Check if binaries are allowed from the index
+        if !self.artifact_policy.allow_wheels {
+            return WheelCompatibility::Incompatible(IncompatibleWheel::IndexPolicy(
+                self.index.clone(),
+            ));
+        }
+
         // Check if after upload time cutoff
         if excluded {
             return WheelCompatibility::Incompatible(IncompatibleWheel::ExcludeNewer(upload_time));
@@ -8,6 +8,41 @@ use uv_pep440::Version;
 
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
+/// The policy to apply to versions that are marked as yanked by the relevant index.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum YankedPolicy {
+    /// Allow yanked versions that are pinned with an exact (`==`) requirement or by an existing
+    /// lockfile, without a warning.
+    Allow,
+
+    /// Allow yanked versions that are pinned with an exact (`==`) requirement or by an existing
+    /// lockfile, but warn when they're selected.
+    ///
+    /// When installing from a lockfile, the index is queried to warn if any of the locked
+    /// distributions have since been yanked.
+    #[default]
+    Warn,
+
+    /// Forbid yanked versions, even if they're pinned.
+    ///
+    /// When installing from a lockfile, the index is queried to ensure that none of the locked
+    /// distributions have since been yanked.
+    Forbid,
+}
+
+impl std::fmt::Display for YankedPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Warn => write!(f, "warn"),
+            Self::Forbid => write!(f, "forbid"),
+        }
+    }
+}
+
 /// A set of package versions that are permitted, even if they're marked as yanked by the
 /// relevant index.
 #[derive(Debug, Default, Clone)]
@@ -18,7 +53,13 @@ impl AllowedYanks {
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
+        policy: YankedPolicy,
     ) -> Self {
+        // If yanked versions are forbidden, don't allow any yanks, even for pinned requirements.
+        if policy == YankedPolicy::Forbid {
+            return Self::default();
+        }
+
         let mut allowed_yanks = FxHashMap::<PackageName, FxHashSet<Version>>::default();
 
         // Allow yanks for any pinned input requirements.
@@ -322,7 +322,7 @@ impl Pep723Script {
             self.postlude
         );
 
-        fs_err::write(&self.path, content)?;
+        uv_fs::write_durable_sync(&self.path, content)?;
 
         Ok(())
     }
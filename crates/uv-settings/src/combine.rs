@@ -1,11 +1,15 @@
 use std::path::PathBuf;
-use std::{collections::BTreeMap, num::NonZeroUsize};
+use std::{
+    collections::BTreeMap,
+    num::{NonZeroU64, NonZeroUsize},
+};
 
 use url::Url;
 
 use uv_configuration::{
     BuildIsolation, ExportFormat, IndexStrategy, KeyringProviderType, NoSources, ProxyUrl,
-    Reinstall, RequiredVersion, TargetTriple, TrustedPublishing, Upgrade,
+    Reinstall, RequiredVersion, TargetAbiFlags, TargetImplementation, TargetTriple,
+    TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, PackageConfigSettings, PipExtraIndex,
@@ -17,7 +21,8 @@ use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerOverride, ExcludeNewerPackage, ExcludeNewerValue,
-    ForkStrategy, PrereleaseMode, ResolutionMode,
+    ForkStrategy, PackageLocalVersionPreferences, PackagePrereleaseModes, PackageResolutionModes,
+    PrereleaseMode, ResolutionMode, YankedPolicy,
 };
 use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
@@ -104,6 +109,7 @@ impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
 impl_combine_or!(DisplaySafeUrl);
+impl_combine_or!(NonZeroU64);
 impl_combine_or!(NonZeroUsize);
 impl_combine_or!(PathBuf);
 impl_combine_or!(PipExtraIndex);
@@ -120,11 +126,16 @@ impl_combine_or!(ResolutionMode);
 impl_combine_or!(SchemaConflicts);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
+impl_combine_or!(TargetAbiFlags);
+impl_combine_or!(TargetImplementation);
 impl_combine_or!(TargetTriple);
 impl_combine_or!(TorchMode);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
+impl_combine_or!(YankedPolicy);
 impl_combine_or!(bool);
+impl_combine_or!(u32);
+impl_combine_or!(usize);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -171,6 +182,36 @@ impl Combine for Option<ExcludeNewerPackage> {
     }
 }
 
+impl Combine for Option<PackageResolutionModes> {
+    /// Combine two [`PackageResolutionModes`] instances by merging them, with the values in `self` taking precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(b.into_iter().chain(a).collect()),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<PackagePrereleaseModes> {
+    /// Combine two [`PackagePrereleaseModes`] instances by merging them, with the values in `self` taking precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(b.into_iter().chain(a).collect()),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<PackageLocalVersionPreferences> {
+    /// Combine two [`PackageLocalVersionPreferences`] instances by merging them, with the values in `self` taking precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(b.into_iter().chain(a).collect()),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ConfigSettings> {
     /// Combine two maps by merging the map in `self` with the map in `other`, if they're both
     /// `Some`.
@@ -7,7 +7,7 @@ use tracing::info_span;
 use uv_client::{DEFAULT_CONNECT_TIMEOUT, DEFAULT_READ_TIMEOUT, DEFAULT_READ_TIMEOUT_UPLOAD};
 use uv_configuration::RequiredVersion;
 use uv_dirs::{system_config_file, user_config_dir};
-use uv_distribution_types::Origin;
+use uv_distribution_types::{HttpVersion, Origin};
 use uv_flags::EnvironmentFlags;
 use uv_fs::Simplified;
 use uv_normalize::{GroupName, PackageName};
@@ -292,6 +292,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         install_mirrors: _,
         publish: _,
         add: _,
+        sync: _,
         audit: _,
         pip: _,
         cache_keys: _,
@@ -302,6 +303,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         environments,
         required_environments,
         conflicts,
+        replace,
         workspace,
         sources,
         dev_dependencies,
@@ -309,6 +311,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         dependency_groups,
         managed,
         package,
+        lock_build_dependencies,
         build_backend,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
@@ -317,6 +320,9 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if conflicts.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "conflicts"));
     }
+    if replace.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "replace"));
+    }
     if workspace.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "workspace"));
     }
@@ -347,6 +353,12 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if lock_build_dependencies.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "lock-build-dependencies",
+        ));
+    }
     if build_backend.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -403,17 +415,38 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 index_strategy,
                 keyring_provider,
                 resolution,
+                resolution_package,
                 prerelease,
+                prerelease_package,
+                local_version_preference,
+                yanked,
                 fork_strategy,
+                max_metadata_builds,
+                resolution_effort,
+                excluded_packages,
+                reresolve_metadata,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
                 no_build_isolation,
                 no_build_isolation_package,
+                build_timeout,
+                build_retries,
+                build_python,
+                show_build_warnings,
+                build_warning_pattern,
+                build_events_file,
+                keep_build_logs,
+                keep_build_env,
+                build_dir,
+                build_via_sdist,
+                no_build_environment_reuse,
                 extra_build_dependencies,
                 extra_build_variables,
+                build_env,
                 exclude_newer,
                 exclude_newer_package,
+                strict_exclude_newer,
                 link_mode,
                 compile_bytecode,
                 no_sources,
@@ -441,6 +474,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 check_url,
             },
         add: AddOptions { add_bounds },
+        sync: SyncOptions { keep_extraneous },
         audit: _,
         pip,
         cache_keys,
@@ -451,6 +485,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         environments: _,
         required_environments: _,
         conflicts: _,
+        replace: _,
         workspace: _,
         sources: _,
         dev_dependencies: _,
@@ -458,6 +493,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         dependency_groups: _,
         managed: _,
         package: _,
+        lock_build_dependencies: _,
         build_backend: _,
     } = options;
 
@@ -537,12 +573,36 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if resolution.is_some() {
         masked_fields.push("resolution");
     }
+    if resolution_package.is_some() {
+        masked_fields.push("resolution-package");
+    }
     if prerelease.is_some() {
         masked_fields.push("prerelease");
     }
+    if prerelease_package.is_some() {
+        masked_fields.push("prerelease-package");
+    }
+    if local_version_preference.is_some() {
+        masked_fields.push("local-version-preference");
+    }
+    if yanked.is_some() {
+        masked_fields.push("yanked");
+    }
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
+    if max_metadata_builds.is_some() {
+        masked_fields.push("max-metadata-builds");
+    }
+    if resolution_effort.is_some() {
+        masked_fields.push("resolution-effort");
+    }
+    if excluded_packages.is_some() {
+        masked_fields.push("excluded-packages");
+    }
+    if reresolve_metadata.is_some() {
+        masked_fields.push("reresolve-metadata");
+    }
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
@@ -558,18 +618,57 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if no_build_isolation_package.is_some() {
         masked_fields.push("no-build-isolation-package");
     }
+    if build_timeout.is_some() {
+        masked_fields.push("build-timeout");
+    }
+    if build_retries.is_some() {
+        masked_fields.push("build-retries");
+    }
+    if build_python.is_some() {
+        masked_fields.push("build-python");
+    }
+    if show_build_warnings.is_some() {
+        masked_fields.push("show-build-warnings");
+    }
+    if build_warning_pattern.is_some() {
+        masked_fields.push("build-warning-pattern");
+    }
+    if build_events_file.is_some() {
+        masked_fields.push("build-events-file");
+    }
+    if keep_build_logs.is_some() {
+        masked_fields.push("keep-build-logs");
+    }
+    if keep_build_env.is_some() {
+        masked_fields.push("keep-build-env");
+    }
+    if build_dir.is_some() {
+        masked_fields.push("build-dir");
+    }
+    if build_via_sdist.is_some() {
+        masked_fields.push("build-via-sdist");
+    }
+    if no_build_environment_reuse.is_some() {
+        masked_fields.push("no-build-environment-reuse");
+    }
     if extra_build_dependencies.is_some() {
         masked_fields.push("extra-build-dependencies");
     }
     if extra_build_variables.is_some() {
         masked_fields.push("extra-build-variables");
     }
+    if build_env.is_some() {
+        masked_fields.push("build-env");
+    }
     if exclude_newer.is_some() {
         masked_fields.push("exclude-newer");
     }
     if exclude_newer_package.is_some() {
         masked_fields.push("exclude-newer-package");
     }
+    if strict_exclude_newer.is_some() {
+        masked_fields.push("strict-exclude-newer");
+    }
     if link_mode.is_some() {
         masked_fields.push("link-mode");
     }
@@ -627,6 +726,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if add_bounds.is_some() {
         masked_fields.push("add-bounds");
     }
+    if keep_extraneous.is_some() {
+        masked_fields.push("keep-extraneous");
+    }
     if pip.is_some() {
         masked_fields.push("pip");
     }
@@ -689,6 +791,7 @@ pub struct Concurrency {
     pub downloads: Option<NonZeroUsize>,
     pub builds: Option<NonZeroUsize>,
     pub installs: Option<NonZeroUsize>,
+    pub metadata: Option<NonZeroUsize>,
 }
 
 /// A boolean flag parsed from an environment variable.
@@ -720,6 +823,7 @@ pub struct EnvironmentOptions {
     pub ty_path: Option<PathBuf>,
     pub skip_wheel_filename_check: Option<bool>,
     pub hide_build_output: Option<bool>,
+    pub stream_build_output: bool,
     pub python_install_bin: Option<bool>,
     pub python_install_registry: Option<bool>,
     pub python_no_registry: EnvFlag,
@@ -734,6 +838,7 @@ pub struct EnvironmentOptions {
     /// timeout.
     pub http_read_timeout_upload: Duration,
     pub http_retries: u32,
+    pub http_version: Option<HttpVersion>,
     pub concurrency: Concurrency,
     #[cfg(feature = "tracing-durations-export")]
     pub tracing_durations_file: Option<PathBuf>,
@@ -798,6 +903,22 @@ impl EnvironmentOptions {
                 EnvVars::UV_SKIP_WHEEL_FILENAME_CHECK,
             )?,
             hide_build_output: parse_boolish_environment_variable(EnvVars::UV_HIDE_BUILD_OUTPUT)?,
+            stream_build_output: parse_string_environment_variable(EnvVars::UV_BUILD_OUTPUT)?
+                .map(|value| {
+                    if value == "stream" {
+                        Ok(true)
+                    } else {
+                        Err(Error::InvalidEnvironmentVariable(
+                            InvalidEnvironmentVariable {
+                                name: EnvVars::UV_BUILD_OUTPUT.to_string(),
+                                value,
+                                err: "expected `stream`".to_string(),
+                            },
+                        ))
+                    }
+                })
+                .transpose()?
+                .unwrap_or(false),
             python_install_bin: parse_boolish_environment_variable(EnvVars::UV_PYTHON_INSTALL_BIN)?,
             python_install_registry: parse_boolish_environment_variable(
                 EnvVars::UV_PYTHON_INSTALL_REGISTRY,
@@ -813,6 +934,10 @@ impl EnvironmentOptions {
                     EnvVars::UV_CONCURRENT_INSTALLS,
                     None,
                 )?,
+                metadata: parse_integer_environment_variable(
+                    EnvVars::UV_CONCURRENT_METADATA,
+                    None,
+                )?,
             },
             install_mirrors: PythonInstallMirrors {
                 python_install_mirror: parse_string_environment_variable(
@@ -851,6 +976,7 @@ impl EnvironmentOptions {
             .unwrap_or(DEFAULT_CONNECT_TIMEOUT),
             http_retries: parse_integer_environment_variable(EnvVars::UV_HTTP_RETRIES, None)?
                 .unwrap_or(uv_client::DEFAULT_RETRIES),
+            http_version: parse_typed_environment_variable(EnvVars::UV_HTTP_VERSION, None)?,
             #[cfg(feature = "tracing-durations-export")]
             tracing_durations_file: parse_path_environment_variable(
                 EnvVars::TRACING_DURATIONS_FILE,
@@ -1064,6 +1190,9 @@ impl From<&EnvironmentOptions> for EnvironmentFlags {
         if options.hide_build_output == Some(true) {
             flags.insert(Self::HIDE_BUILD_OUTPUT);
         }
+        if options.stream_build_output {
+            flags.insert(Self::STREAM_BUILD_OUTPUT);
+        }
         flags
     }
 }
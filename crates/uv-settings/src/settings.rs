@@ -1,16 +1,22 @@
 #[cfg(feature = "schemars")]
 use std::borrow::Cow;
-use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
+use std::{
+    fmt::Debug,
+    num::{NonZeroU64, NonZeroUsize},
+    path::Path,
+    path::PathBuf,
+};
 
 use serde::{Deserialize, Serialize};
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
     BuildIsolation, ExcludeDependency, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    ProxyUrl, Reinstall, RequiredVersion, TargetAbiFlags, TargetImplementation, TargetTriple,
+    TrustedHost, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
-    ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, Origin,
+    BuildEnvEntry, ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, Origin,
     PackageConfigSettings, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
 };
 use uv_install_wheel::LinkMode;
@@ -23,7 +29,8 @@ use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewerOverride, ExcludeNewerPackage, ExcludeNewerSpan,
-    ExcludeNewerValue, ForkStrategy, PrereleaseMode, ResolutionMode,
+    ExcludeNewerValue, ForkStrategy, PackageLocalVersionPreferences, PackagePrereleaseModes,
+    PackageResolutionModes, PrereleaseMode, ResolutionMode, YankedPolicy,
     serialize_exclude_newer_package_with_spans,
 };
 use uv_torch::TorchMode;
@@ -92,6 +99,9 @@ pub struct Options {
     #[serde(flatten)]
     pub add: AddOptions,
 
+    #[serde(flatten)]
+    pub sync: SyncOptions,
+
     #[option_group]
     pub audit: Option<AuditOptions>,
 
@@ -169,6 +179,9 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) conflicts: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) replace: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) workspace: Option<serde::de::IgnoredAny>,
 
@@ -190,6 +203,9 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) r#package: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) lock_build_dependencies: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) build_backend: Option<serde::de::IgnoredAny>,
 }
@@ -524,6 +540,17 @@ pub struct InstallerOptions {
     compile_bytecode: Option<bool>,
     reinstall: Option<Reinstall>,
     build_isolation: Option<BuildIsolation>,
+    build_timeout: Option<NonZeroU64>,
+    build_retries: Option<u32>,
+    build_python: Option<String>,
+    show_build_warnings: Option<bool>,
+    build_warning_pattern: Option<Vec<String>>,
+    build_events_file: Option<PathBuf>,
+    keep_build_logs: Option<bool>,
+    keep_build_env: Option<bool>,
+    build_dir: Option<PathBuf>,
+    build_via_sdist: Option<bool>,
+    no_build_environment_reuse: Option<bool>,
     no_build: Option<bool>,
     no_build_package: Option<Vec<PackageName>>,
     no_binary: Option<bool>,
@@ -543,23 +570,44 @@ pub struct ResolverOptions {
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
+    pub resolution_package: Option<PackageResolutionModes>,
     pub prerelease: Option<PrereleaseMode>,
+    pub prerelease_package: Option<PackagePrereleaseModes>,
+    pub local_version_preference: Option<PackageLocalVersionPreferences>,
+    pub yanked: Option<YankedPolicy>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub max_metadata_builds: Option<usize>,
+    pub resolution_effort: Option<usize>,
+    pub excluded_packages: Option<Vec<PackageName>>,
+    pub reresolve_metadata: Option<Vec<PackageName>>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
     pub exclude_newer: Option<ExcludeNewerOverride>,
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
+    pub strict_exclude_newer: Option<bool>,
     pub link_mode: Option<LinkMode>,
     pub torch_backend: Option<TorchMode>,
     pub upgrade: Option<Upgrade>,
     pub build_isolation: Option<BuildIsolation>,
+    pub build_timeout: Option<NonZeroU64>,
+    pub build_retries: Option<u32>,
+    pub build_python: Option<String>,
+    pub show_build_warnings: Option<bool>,
+    pub build_warning_pattern: Option<Vec<String>>,
+    pub build_events_file: Option<PathBuf>,
+    pub keep_build_logs: Option<bool>,
+    pub keep_build_env: Option<bool>,
+    pub build_dir: Option<PathBuf>,
+    pub build_via_sdist: Option<bool>,
+    pub no_build_environment_reuse: Option<bool>,
     pub no_build: Option<bool>,
     pub no_build_package: Option<Vec<PackageName>>,
     pub no_binary: Option<bool>,
     pub no_binary_package: Option<Vec<PackageName>>,
     pub extra_build_dependencies: Option<ExtraBuildDependencies>,
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    pub build_env: Option<Vec<BuildEnvEntry>>,
     pub no_sources: Option<bool>,
     pub no_sources_package: Option<Vec<PackageName>>,
 }
@@ -576,16 +624,37 @@ pub struct ResolverInstallerOptions {
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
+    pub resolution_package: Option<PackageResolutionModes>,
     pub prerelease: Option<PrereleaseMode>,
+    pub prerelease_package: Option<PackagePrereleaseModes>,
+    pub local_version_preference: Option<PackageLocalVersionPreferences>,
+    pub yanked: Option<YankedPolicy>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub max_metadata_builds: Option<usize>,
+    pub resolution_effort: Option<usize>,
+    pub excluded_packages: Option<Vec<PackageName>>,
+    pub reresolve_metadata: Option<Vec<PackageName>>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
     pub build_isolation: Option<BuildIsolation>,
+    pub build_timeout: Option<NonZeroU64>,
+    pub build_retries: Option<u32>,
+    pub build_python: Option<String>,
+    pub show_build_warnings: Option<bool>,
+    pub build_warning_pattern: Option<Vec<String>>,
+    pub build_events_file: Option<PathBuf>,
+    pub keep_build_logs: Option<bool>,
+    pub keep_build_env: Option<bool>,
+    pub build_dir: Option<PathBuf>,
+    pub build_via_sdist: Option<bool>,
+    pub no_build_environment_reuse: Option<bool>,
     pub extra_build_dependencies: Option<ExtraBuildDependencies>,
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    pub build_env: Option<Vec<BuildEnvEntry>>,
     pub exclude_newer: Option<ExcludeNewerOverride>,
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
+    pub strict_exclude_newer: Option<bool>,
     pub link_mode: Option<LinkMode>,
     pub torch_backend: Option<TorchMode>,
     pub compile_bytecode: Option<bool>,
@@ -610,17 +679,38 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            excluded_packages,
+            reresolve_metadata,
             dependency_metadata,
             config_settings,
             config_settings_package,
             no_build_isolation,
             no_build_isolation_package,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             extra_build_dependencies,
             extra_build_variables,
+            build_env,
             exclude_newer,
             exclude_newer_package,
+            strict_exclude_newer,
             link_mode,
             torch_backend,
             compile_bytecode,
@@ -644,8 +734,16 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            excluded_packages,
+            reresolve_metadata,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -653,10 +751,23 @@ impl From<ResolverInstallerSchema> for ResolverInstallerOptions {
                 no_build_isolation,
                 no_build_isolation_package.into_iter().flatten().collect(),
             ),
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             extra_build_dependencies,
             extra_build_variables,
+            build_env,
             exclude_newer,
             exclude_newer_package,
+            strict_exclude_newer,
             link_mode,
             torch_backend,
             compile_bytecode,
@@ -866,6 +977,19 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub resolution: Option<ResolutionMode>,
+    /// The strategy to use when selecting between the different compatible versions for specific
+    /// packages, overriding [`resolution`](#resolution) for those packages.
+    ///
+    /// Accepts a dictionary format of `PACKAGE = "STRATEGY"` pairs, where `STRATEGY` is one of
+    /// `highest`, `lowest`, or `lowest-direct`.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            resolution-package = { numpy = "lowest" }
+        "#
+    )]
+    pub resolution_package: Option<PackageResolutionModes>,
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases,
@@ -880,6 +1004,55 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// The strategy to use when considering pre-release versions of specific packages, overriding
+    /// [`prerelease`](#prerelease) for those packages.
+    ///
+    /// Accepts a dictionary format of `PACKAGE = "STRATEGY"` pairs, where `STRATEGY` is one of
+    /// `disallow`, `allow`, `if-necessary`, `explicit`, or `if-necessary-or-explicit`.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            prerelease-package = { torch = "allow" }
+        "#
+    )]
+    pub prerelease_package: Option<PackagePrereleaseModes>,
+    /// The local version label to prefer for specific packages, when selecting between builds of
+    /// the same version that differ only in their local version label (e.g., `2.3.0+cpu` and
+    /// `2.3.0+cu121` on the PyTorch index).
+    ///
+    /// Accepts a dictionary format of `PACKAGE = "+LABEL"` pairs. When multiple builds of the
+    /// selected version satisfy the requirements, the build with the given label is preferred.
+    /// The preference doesn't affect which version is selected: a newer version without the
+    /// label is still preferred over an older version with it. To require a specific build, use
+    /// an exact requirement instead (e.g., `torch==2.3.0+cu121`).
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            local-version-preference = { torch = "+cu121" }
+        "#
+    )]
+    pub local_version_preference: Option<PackageLocalVersionPreferences>,
+    /// The policy to apply to versions that are marked as yanked by their index.
+    ///
+    /// By default, uv skips yanked versions unless they're pinned with an exact (`==`) requirement
+    /// or by an existing lockfile, and warns when a pinned yanked version is selected (`warn`).
+    /// When installing from a lockfile, uv also queries the index to warn if any of the locked
+    /// distributions have since been yanked. Under `allow`, pinned yanked versions are selected
+    /// without a warning, and the lockfile isn't checked.
+    ///
+    /// Under `forbid`, yanked versions are never selected, even if pinned, and installing from a
+    /// lockfile fails if any of the locked distributions have since been yanked.
+    #[option(
+        default = "\"warn\"",
+        value_type = "str",
+        example = r#"
+            yanked = "forbid"
+        "#,
+        possible_values = true
+    )]
+    pub yanked: Option<YankedPolicy>,
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -890,6 +1063,9 @@ pub struct ResolverInstallerSchema {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `fewest-versions`, uv will additionally prefer a version that's shared by the most
+    /// forks over the version that was previously locked for each fork.
     #[option(
         default = "\"requires-python\"",
         value_type = "str",
@@ -899,6 +1075,76 @@ pub struct ResolverInstallerSchema {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The maximum number of source distributions to build to determine their metadata during
+    /// resolution.
+    ///
+    /// Resolving against source distributions that don't publish static metadata requires
+    /// building them, which can be slow, especially if the resolver backtracks through many
+    /// versions. If the limit is exceeded, resolution fails with a list of the source
+    /// distributions that were built, split into builds for the selected versions and builds
+    /// triggered by backtracking.
+    ///
+    /// Only source distributions from package indexes count towards the limit. By default, the
+    /// number of builds is unlimited.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-metadata-builds = 5
+        "#
+    )]
+    pub max_metadata_builds: Option<usize>,
+    /// The maximum number of decisions the resolver may make before giving up.
+    ///
+    /// Some sets of requirements cause the resolver to backtrack through many versions before
+    /// finding a solution (or proving that none exists). If the limit is exceeded, resolution
+    /// fails with a list of the packages that caused the most backtracking, which are good
+    /// candidates for additional constraints.
+    ///
+    /// By default, the number of decisions is unlimited.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-effort = 100000
+        "#
+    )]
+    pub resolution_effort: Option<usize>,
+    /// Packages to exclude from the resolution.
+    ///
+    /// Excluded packages are treated as if they have no available versions, such that any
+    /// requirement on an excluded package (whether direct or transitive) causes resolution to fail
+    /// with the chain of dependencies that required it. Overrides, constraints, and URL
+    /// requirements cannot re-enable an excluded package.
+    ///
+    /// Unlike `exclude-dependencies`, which silently drops the matching requirements, this setting
+    /// guarantees that the given packages never appear in the resolution.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            excluded-packages = ["pycrypto"]
+        "#
+    )]
+    pub excluded_packages: Option<Vec<PackageName>>,
+    /// Packages for which to ignore the metadata provided by the index.
+    ///
+    /// By default, uv trusts the metadata served by the index (e.g., via PEP 658 `.metadata`
+    /// files) when resolving dependencies. For the given packages, uv will instead download the
+    /// distribution itself and read its dependencies from the wheel's `METADATA` file (or build
+    /// the source distribution), which is necessary for packages whose index metadata does not
+    /// match their distributions.
+    ///
+    /// The affected packages are recorded in the lockfile, such that changes to this setting
+    /// invalidate the existing resolution.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            reresolve-metadata = ["horovod"]
+        "#
+    )]
+    pub reresolve_metadata: Option<Vec<PackageName>>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -968,6 +1214,162 @@ pub struct ResolverInstallerSchema {
     "#
     )]
     pub no_build_isolation_package: Option<Vec<PackageName>>,
+    /// The maximum time (in seconds) to wait for a build backend hook to complete.
+    ///
+    /// If building a source distribution exceeds the timeout, the build backend and any processes
+    /// it spawned are terminated, and the build fails. By default, builds are not subject to a
+    /// timeout.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            build-timeout = 600
+        "#
+    )]
+    pub build_timeout: Option<NonZeroU64>,
+    /// The number of times to retry a build backend hook that fails with a transient filesystem
+    /// error.
+    ///
+    /// Builds that fail due to, e.g., a file held open by an antivirus scanner (`Access is
+    /// denied`), or a stale NFS file handle, are re-run in a fresh output directory. Other build
+    /// failures are never retried. By default, builds are not retried.
+    #[option(
+        default = "0",
+        value_type = "int",
+        example = r#"
+            build-retries = 2
+        "#
+    )]
+    pub build_retries: Option<u32>,
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`.
+    ///
+    /// By default, packages are built with the interpreter of the target environment, and builds
+    /// fail early if the package's `requires-python` excludes it. When set, such packages are
+    /// instead built with the requested interpreter, e.g., `3.11`. If set to `any`, uv selects
+    /// any installed interpreter that satisfies the package's `requires-python`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-python = "3.11"
+        "#
+    )]
+    pub build_python: Option<String>,
+    /// Summarize the warnings emitted by build backends during successful builds.
+    ///
+    /// The output of a build backend is discarded when the build succeeds, including warnings about,
+    /// e.g., deprecated configuration or missing license files. When enabled, lines of build backend
+    /// output that match a [`build-warning-pattern`](#build-warning-pattern) are collected and
+    /// reported for each package once the installation completes. Warnings don't affect the exit
+    /// code.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            show-build-warnings = true
+        "#
+    )]
+    pub show_build_warnings: Option<bool>,
+    /// The patterns that identify a line of build backend output as a warning, for
+    /// [`show-build-warnings`](#show-build-warnings).
+    ///
+    /// Lines that contain any of the patterns, ignoring case, are reported. Defaults to
+    /// `DeprecationWarning`, `SetuptoolsDeprecationWarning`, and `warning:`.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            build-warning-pattern = ["DeprecationWarning", "Missing license"]
+        "#
+    )]
+    pub build_warning_pattern: Option<Vec<String>>,
+    /// Append a structured event to the given file at the start and finish of every source build.
+    ///
+    /// Each event is written as a single line of JSON, including the package name and version, the
+    /// build backend, the interpreter, the isolation mode, whether the build environment was
+    /// reused, and, for finished builds, the duration and the kind of failure, if any. Events of
+    /// the same build share a sequence ID, such that overlapping parallel builds can be correlated.
+    /// The file is created if it doesn't exist.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-events-file = "build-events.jsonl"
+        "#
+    )]
+    pub build_events_file: Option<PathBuf>,
+    /// Retain the build backend output of successful builds in the cache.
+    ///
+    /// The output of every build is written to a log in the cache, which is referenced from the error
+    /// when the build fails. By default, the log is removed once the build succeeds. Only the most
+    /// recent logs of each package are retained.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            keep-build-logs = true
+        "#
+    )]
+    pub keep_build_logs: Option<bool>,
+    /// Retain the temporary build environment when a build backend hook fails.
+    ///
+    /// When building a source distribution fails, the virtual environment in which the build
+    /// backend ran is preserved, and its location is included in the error alongside the source
+    /// tree, e.g., to re-run the failing command by hand. Builds that succeed are cleaned up as
+    /// usual. Retained environments are removed by `uv cache clean`.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            keep-build-env = true
+        "#
+    )]
+    pub keep_build_env: Option<bool>,
+    /// The directory in which to create temporary build directories.
+    ///
+    /// Source distributions are built, and their temporary build environments are created, in
+    /// scratch directories within this directory, which are removed once each build completes. The
+    /// directory is created if it doesn't exist.
+    ///
+    /// Defaults to the `builds-v0` directory within the cache.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-dir = "/tmp/uv-builds"
+        "#
+    )]
+    pub build_dir: Option<PathBuf>,
+    /// Build wheels for local and Git sources from a source distribution.
+    ///
+    /// By default, wheels are built directly from the source tree. When enabled, a source
+    /// distribution is built from the source tree first, and the wheel is built from the extracted
+    /// source distribution instead, such that the wheel only contains the files included in the
+    /// source distribution, as with a package downloaded from an index.
+    ///
+    /// Wheels are always built via a source distribution if the build backend doesn't support
+    /// building wheels directly.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            build-via-sdist = true
+        "#
+    )]
+    pub build_via_sdist: Option<bool>,
+    /// Create a separate build environment for each build.
+    ///
+    /// By default, builds that resolve to the same set of build requirements for the same interpreter
+    /// share a single build environment, rather than each creating and populating their own.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            no-build-environment-reuse = true
+        "#
+    )]
+    pub no_build_environment_reuse: Option<bool>,
     /// Additional build dependencies for packages.
     ///
     /// This allows extending the PEP 517 build environment for the project's dependencies with
@@ -993,6 +1395,24 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    /// Environment variables to make visible to build backends, specified as `KEY` (to pass the
+    /// variable through from the current environment) or `KEY=VALUE` (to set the variable).
+    ///
+    /// When set, builds run with a restricted environment: only the listed variables, the
+    /// variables uv sets itself (e.g., `PATH` and `VIRTUAL_ENV`), and a small set of variables
+    /// required for processes to function (e.g., `HOME` and `TMPDIR`) are visible to the build
+    /// backend. The resulting values are included in the cache key for built wheels.
+    ///
+    /// Unlike `no-build-isolation`, the build requirements are still installed into an isolated
+    /// environment.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            build-env = ["PKG_CONFIG_PATH", "CMAKE_ARGS=-DENABLE_FEATURE=ON"]
+        "#
+    )]
+    pub build_env: Option<Vec<BuildEnvEntry>>,
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// The date is compared against the upload time of each individual distribution artifact
@@ -1036,6 +1456,22 @@ pub struct ResolverInstallerSchema {
         "#
     )]
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
+    /// Fail if [`exclude-newer`](#exclude-newer) can't be applied to a package because its source
+    /// doesn't provide upload times (e.g., a [`find-links`](#find-links) directory).
+    ///
+    /// By default, such packages are included, with a single warning for each source that lists
+    /// the affected packages.
+    ///
+    /// The same applies to [`yanked = "forbid"`](#yanked) when installing from a lockfile, for
+    /// sources that don't provide yank status.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            strict-exclude-newer = true
+        "#
+    )]
+    pub strict_exclude_newer: Option<bool>,
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS and Linux, and `hardlink` on
@@ -1505,6 +1941,162 @@ pub struct PipOptions {
         "#
     )]
     pub no_build_isolation_package: Option<Vec<PackageName>>,
+    /// The maximum time (in seconds) to wait for a build backend hook to complete.
+    ///
+    /// If building a source distribution exceeds the timeout, the build backend and any processes
+    /// it spawned are terminated, and the build fails. By default, builds are not subject to a
+    /// timeout.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            build-timeout = 600
+        "#
+    )]
+    pub build_timeout: Option<NonZeroU64>,
+    /// The number of times to retry a build backend hook that fails with a transient filesystem
+    /// error.
+    ///
+    /// Builds that fail due to, e.g., a file held open by an antivirus scanner (`Access is
+    /// denied`), or a stale NFS file handle, are re-run in a fresh output directory. Other build
+    /// failures are never retried. By default, builds are not retried.
+    #[option(
+        default = "0",
+        value_type = "int",
+        example = r#"
+            build-retries = 2
+        "#
+    )]
+    pub build_retries: Option<u32>,
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`.
+    ///
+    /// By default, packages are built with the interpreter of the target environment, and builds
+    /// fail early if the package's `requires-python` excludes it. When set, such packages are
+    /// instead built with the requested interpreter, e.g., `3.11`. If set to `any`, uv selects
+    /// any installed interpreter that satisfies the package's `requires-python`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-python = "3.11"
+        "#
+    )]
+    pub build_python: Option<String>,
+    /// Summarize the warnings emitted by build backends during successful builds.
+    ///
+    /// The output of a build backend is discarded when the build succeeds, including warnings about,
+    /// e.g., deprecated configuration or missing license files. When enabled, lines of build backend
+    /// output that match a [`build-warning-pattern`](#build-warning-pattern) are collected and
+    /// reported for each package once the installation completes. Warnings don't affect the exit
+    /// code.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            show-build-warnings = true
+        "#
+    )]
+    pub show_build_warnings: Option<bool>,
+    /// The patterns that identify a line of build backend output as a warning, for
+    /// [`show-build-warnings`](#show-build-warnings).
+    ///
+    /// Lines that contain any of the patterns, ignoring case, are reported. Defaults to
+    /// `DeprecationWarning`, `SetuptoolsDeprecationWarning`, and `warning:`.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            build-warning-pattern = ["DeprecationWarning", "Missing license"]
+        "#
+    )]
+    pub build_warning_pattern: Option<Vec<String>>,
+    /// Append a structured event to the given file at the start and finish of every source build.
+    ///
+    /// Each event is written as a single line of JSON, including the package name and version, the
+    /// build backend, the interpreter, the isolation mode, whether the build environment was
+    /// reused, and, for finished builds, the duration and the kind of failure, if any. Events of
+    /// the same build share a sequence ID, such that overlapping parallel builds can be correlated.
+    /// The file is created if it doesn't exist.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-events-file = "build-events.jsonl"
+        "#
+    )]
+    pub build_events_file: Option<PathBuf>,
+    /// Retain the build backend output of successful builds in the cache.
+    ///
+    /// The output of every build is written to a log in the cache, which is referenced from the error
+    /// when the build fails. By default, the log is removed once the build succeeds. Only the most
+    /// recent logs of each package are retained.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            keep-build-logs = true
+        "#
+    )]
+    pub keep_build_logs: Option<bool>,
+    /// Retain the temporary build environment when a build backend hook fails.
+    ///
+    /// When building a source distribution fails, the virtual environment in which the build
+    /// backend ran is preserved, and its location is included in the error alongside the source
+    /// tree, e.g., to re-run the failing command by hand. Builds that succeed are cleaned up as
+    /// usual. Retained environments are removed by `uv cache clean`.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            keep-build-env = true
+        "#
+    )]
+    pub keep_build_env: Option<bool>,
+    /// The directory in which to create temporary build directories.
+    ///
+    /// Source distributions are built, and their temporary build environments are created, in
+    /// scratch directories within this directory, which are removed once each build completes. The
+    /// directory is created if it doesn't exist.
+    ///
+    /// Defaults to the `builds-v0` directory within the cache.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-dir = "/tmp/uv-builds"
+        "#
+    )]
+    pub build_dir: Option<PathBuf>,
+    /// Build wheels for local and Git sources from a source distribution.
+    ///
+    /// By default, wheels are built directly from the source tree. When enabled, a source
+    /// distribution is built from the source tree first, and the wheel is built from the extracted
+    /// source distribution instead, such that the wheel only contains the files included in the
+    /// source distribution, as with a package downloaded from an index.
+    ///
+    /// Wheels are always built via a source distribution if the build backend doesn't support
+    /// building wheels directly.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            build-via-sdist = true
+        "#
+    )]
+    pub build_via_sdist: Option<bool>,
+    /// Create a separate build environment for each build.
+    ///
+    /// By default, builds that resolve to the same set of build requirements for the same interpreter
+    /// share a single build environment, rather than each creating and populating their own.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            no-build-environment-reuse = true
+        "#
+    )]
+    pub no_build_environment_reuse: Option<bool>,
     /// Additional build dependencies for packages.
     ///
     /// This allows extending the PEP 517 build environment for the project's dependencies with
@@ -1530,6 +2122,24 @@ pub struct PipOptions {
         "#
     )]
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    /// Environment variables to make visible to build backends, specified as `KEY` (to pass the
+    /// variable through from the current environment) or `KEY=VALUE` (to set the variable).
+    ///
+    /// When set, builds run with a restricted environment: only the listed variables, the
+    /// variables uv sets itself (e.g., `PATH` and `VIRTUAL_ENV`), and a small set of variables
+    /// required for processes to function (e.g., `HOME` and `TMPDIR`) are visible to the build
+    /// backend. The resulting values are included in the cache key for built wheels.
+    ///
+    /// Unlike `no-build-isolation`, the build requirements are still installed into an isolated
+    /// environment.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            build-env = ["PKG_CONFIG_PATH", "CMAKE_ARGS=-DENABLE_FEATURE=ON"]
+        "#
+    )]
+    pub build_env: Option<Vec<BuildEnvEntry>>,
     /// Validate the Python environment, to detect packages with missing dependencies and other
     /// issues.
     #[option(
@@ -1614,6 +2224,19 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub resolution: Option<ResolutionMode>,
+    /// The strategy to use when selecting between the different compatible versions for specific
+    /// packages, overriding [`resolution`](#resolution) for those packages.
+    ///
+    /// Accepts a dictionary format of `PACKAGE = "STRATEGY"` pairs, where `STRATEGY` is one of
+    /// `highest`, `lowest`, or `lowest-direct`.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            resolution-package = { numpy = "lowest" }
+        "#
+    )]
+    pub resolution_package: Option<PackageResolutionModes>,
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases,
@@ -1628,6 +2251,55 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// The strategy to use when considering pre-release versions of specific packages, overriding
+    /// [`prerelease`](#prerelease) for those packages.
+    ///
+    /// Accepts a dictionary format of `PACKAGE = "STRATEGY"` pairs, where `STRATEGY` is one of
+    /// `disallow`, `allow`, `if-necessary`, `explicit`, or `if-necessary-or-explicit`.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            prerelease-package = { torch = "allow" }
+        "#
+    )]
+    pub prerelease_package: Option<PackagePrereleaseModes>,
+    /// The local version label to prefer for specific packages, when selecting between builds of
+    /// the same version that differ only in their local version label (e.g., `2.3.0+cpu` and
+    /// `2.3.0+cu121` on the PyTorch index).
+    ///
+    /// Accepts a dictionary format of `PACKAGE = "+LABEL"` pairs. When multiple builds of the
+    /// selected version satisfy the requirements, the build with the given label is preferred.
+    /// The preference doesn't affect which version is selected: a newer version without the
+    /// label is still preferred over an older version with it. To require a specific build, use
+    /// an exact requirement instead (e.g., `torch==2.3.0+cu121`).
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            local-version-preference = { torch = "+cu121" }
+        "#
+    )]
+    pub local_version_preference: Option<PackageLocalVersionPreferences>,
+    /// The policy to apply to versions that are marked as yanked by their index.
+    ///
+    /// By default, uv skips yanked versions unless they're pinned with an exact (`==`) requirement
+    /// or by an existing lockfile, and warns when a pinned yanked version is selected (`warn`).
+    /// When installing from a lockfile, uv also queries the index to warn if any of the locked
+    /// distributions have since been yanked. Under `allow`, pinned yanked versions are selected
+    /// without a warning, and the lockfile isn't checked.
+    ///
+    /// Under `forbid`, yanked versions are never selected, even if pinned, and installing from a
+    /// lockfile fails if any of the locked distributions have since been yanked.
+    #[option(
+        default = "\"warn\"",
+        value_type = "str",
+        example = r#"
+            yanked = "forbid"
+        "#,
+        possible_values = true
+    )]
+    pub yanked: Option<YankedPolicy>,
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -1638,6 +2310,9 @@ pub struct PipOptions {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `fewest-versions`, uv will additionally prefer a version that's shared by the most
+    /// forks over the version that was previously locked for each fork.
     #[option(
         default = "\"requires-python\"",
         value_type = "str",
@@ -1647,6 +2322,76 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// The maximum number of source distributions to build to determine their metadata during
+    /// resolution.
+    ///
+    /// Resolving against source distributions that don't publish static metadata requires
+    /// building them, which can be slow, especially if the resolver backtracks through many
+    /// versions. If the limit is exceeded, resolution fails with a list of the source
+    /// distributions that were built, split into builds for the selected versions and builds
+    /// triggered by backtracking.
+    ///
+    /// Only source distributions from package indexes count towards the limit. By default, the
+    /// number of builds is unlimited.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            max-metadata-builds = 5
+        "#
+    )]
+    pub max_metadata_builds: Option<usize>,
+    /// The maximum number of decisions the resolver may make before giving up.
+    ///
+    /// Some sets of requirements cause the resolver to backtrack through many versions before
+    /// finding a solution (or proving that none exists). If the limit is exceeded, resolution
+    /// fails with a list of the packages that caused the most backtracking, which are good
+    /// candidates for additional constraints.
+    ///
+    /// By default, the number of decisions is unlimited.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            resolution-effort = 100000
+        "#
+    )]
+    pub resolution_effort: Option<usize>,
+    /// Packages to exclude from the resolution.
+    ///
+    /// Excluded packages are treated as if they have no available versions, such that any
+    /// requirement on an excluded package (whether direct or transitive) causes resolution to fail
+    /// with the chain of dependencies that required it. Overrides, constraints, and URL
+    /// requirements cannot re-enable an excluded package.
+    ///
+    /// Unlike `exclude-dependencies`, which silently drops the matching requirements, this setting
+    /// guarantees that the given packages never appear in the resolution.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            excluded-packages = ["pycrypto"]
+        "#
+    )]
+    pub excluded_packages: Option<Vec<PackageName>>,
+    /// Packages for which to ignore the metadata provided by the index.
+    ///
+    /// By default, uv trusts the metadata served by the index (e.g., via PEP 658 `.metadata`
+    /// files) when resolving dependencies. For the given packages, uv will instead download the
+    /// distribution itself and read its dependencies from the wheel's `METADATA` file (or build
+    /// the source distribution), which is necessary for packages whose index metadata does not
+    /// match their distributions.
+    ///
+    /// The affected packages are recorded in the lockfile, such that changes to this setting
+    /// invalidate the existing resolution.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            reresolve-metadata = ["horovod"]
+        "#
+    )]
+    pub reresolve_metadata: Option<Vec<PackageName>>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1737,6 +2482,19 @@ pub struct PipOptions {
         "#
     )]
     pub custom_compile_command: Option<String>,
+    /// A custom comment header to include at the top of the output file generated by
+    /// `uv pip compile`, in place of the default header.
+    ///
+    /// Each line of the header is rendered as a comment; lines that don't already start with `#`
+    /// are prefixed with `# `.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            custom-header = "Generated by ./scripts/lock.sh; do not edit."
+        "#
+    )]
+    pub custom_header: Option<String>,
     /// Include distribution hashes in the output file.
     #[option(
         default = "false",
@@ -1792,6 +2550,30 @@ pub struct PipOptions {
         "#
     )]
     pub python_platform: Option<TargetTriple>,
+    /// The Python implementation for which requirements should be resolved (e.g., `pypy`).
+    ///
+    /// Defaults to the implementation of the Python interpreter used for resolution.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            python-implementation = "pypy"
+        "#
+    )]
+    pub python_implementation: Option<TargetImplementation>,
+    /// The ABI flags of the Python interpreter for which requirements should be resolved, as in
+    /// `sys.abiflags` (e.g., `t` for a free-threaded build of CPython).
+    ///
+    /// Defaults to the ABI flags of the Python interpreter used for resolution, if it matches the
+    /// target implementation.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            python-abiflags = "t"
+        "#
+    )]
+    pub python_abiflags: Option<TargetAbiFlags>,
     /// Perform a universal resolution, attempting to generate a single `requirements.txt` output
     /// file that is compatible with all operating systems, architectures, and Python
     /// implementations.
@@ -1849,6 +2631,22 @@ pub struct PipOptions {
         "#
     )]
     pub exclude_newer_package: Option<ExcludeNewerPackage>,
+    /// Fail if [`exclude-newer`](#exclude-newer) can't be applied to a package because its source
+    /// doesn't provide upload times (e.g., a [`find-links`](#find-links) directory).
+    ///
+    /// By default, such packages are included, with a single warning for each source that lists
+    /// the affected packages.
+    ///
+    /// The same applies to [`yanked = "forbid"`](#yanked) when installing from a lockfile, for
+    /// sources that don't provide yank status.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            strict-exclude-newer = true
+        "#
+    )]
+    pub strict_exclude_newer: Option<bool>,
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
     #[option(
@@ -1859,6 +2657,21 @@ pub struct PipOptions {
         "#
     )]
     pub no_emit_package: Option<Vec<PackageName>>,
+    /// Whether to include "unsafe" packages in the output file generated by `uv pip compile`.
+    ///
+    /// When `false`, the packages listed in `no-emit-package` are omitted from the output, or, if
+    /// none are listed, `pip`, `setuptools`, and `wheel`, as in pip-compile. When `true`, all
+    /// packages are included, even those listed in `no-emit-package`.
+    ///
+    /// If unset, only the packages listed in `no-emit-package` are omitted.
+    #[option(
+        default = "null",
+        value_type = "bool",
+        example = r#"
+            allow-unsafe = false
+        "#
+    )]
+    pub allow_unsafe: Option<bool>,
     /// Include `--index-url` and `--extra-index-url` entries in the output file generated by `uv pip compile`.
     #[option(
         default = "false",
@@ -2125,13 +2938,22 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
+            local_version_preference: value.local_version_preference,
+            yanked: value.yanked,
             fork_strategy: value.fork_strategy,
+            max_metadata_builds: value.max_metadata_builds,
+            resolution_effort: value.resolution_effort,
+            excluded_packages: value.excluded_packages,
+            reresolve_metadata: value.reresolve_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             exclude_newer: value.exclude_newer,
             exclude_newer_package: value.exclude_newer_package,
+            strict_exclude_newer: value.strict_exclude_newer,
             link_mode: value.link_mode,
             upgrade: Upgrade::from_args(
                 value.upgrade,
@@ -2151,8 +2973,20 @@ impl From<ResolverInstallerSchema> for ResolverOptions {
                 value.no_build_isolation,
                 value.no_build_isolation_package.unwrap_or_default(),
             ),
+            build_timeout: value.build_timeout,
+            build_retries: value.build_retries,
+            build_python: value.build_python,
+            show_build_warnings: value.show_build_warnings,
+            build_warning_pattern: value.build_warning_pattern,
+            build_events_file: value.build_events_file,
+            keep_build_logs: value.keep_build_logs,
+            keep_build_env: value.keep_build_env,
+            build_dir: value.build_dir,
+            build_via_sdist: value.build_via_sdist,
+            no_build_environment_reuse: value.no_build_environment_reuse,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_env: value.build_env,
             no_sources: value.no_sources,
             no_sources_package: value.no_sources_package,
             torch_backend: value.torch_backend,
@@ -2182,6 +3016,17 @@ impl From<ResolverInstallerSchema> for InstallerOptions {
                 value.no_build_isolation,
                 value.no_build_isolation_package.unwrap_or_default(),
             ),
+            build_timeout: value.build_timeout,
+            build_retries: value.build_retries,
+            build_python: value.build_python,
+            show_build_warnings: value.show_build_warnings,
+            build_warning_pattern: value.build_warning_pattern,
+            build_events_file: value.build_events_file,
+            keep_build_logs: value.keep_build_logs,
+            keep_build_env: value.keep_build_env,
+            build_dir: value.build_dir,
+            build_via_sdist: value.build_via_sdist,
+            no_build_environment_reuse: value.no_build_environment_reuse,
             no_build: value.no_build,
             no_build_package: value.no_build_package,
             no_binary: value.no_binary,
@@ -2210,16 +3055,37 @@ pub struct ToolOptions {
     index_strategy: Option<IndexStrategy>,
     keyring_provider: Option<KeyringProviderType>,
     resolution: Option<ResolutionMode>,
+    resolution_package: Option<PackageResolutionModes>,
     prerelease: Option<PrereleaseMode>,
+    prerelease_package: Option<PackagePrereleaseModes>,
+    local_version_preference: Option<PackageLocalVersionPreferences>,
+    yanked: Option<YankedPolicy>,
     fork_strategy: Option<ForkStrategy>,
+    max_metadata_builds: Option<usize>,
+    resolution_effort: Option<usize>,
+    excluded_packages: Option<Vec<PackageName>>,
+    reresolve_metadata: Option<Vec<PackageName>>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
     build_isolation: Option<BuildIsolation>,
+    build_timeout: Option<NonZeroU64>,
+    build_retries: Option<u32>,
+    build_python: Option<String>,
+    show_build_warnings: Option<bool>,
+    build_warning_pattern: Option<Vec<String>>,
+    build_events_file: Option<PathBuf>,
+    keep_build_logs: Option<bool>,
+    keep_build_env: Option<bool>,
+    build_dir: Option<PathBuf>,
+    build_via_sdist: Option<bool>,
+    no_build_environment_reuse: Option<bool>,
     extra_build_dependencies: Option<ExtraBuildDependencies>,
     extra_build_variables: Option<ExtraBuildVariables>,
+    build_env: Option<Vec<BuildEnvEntry>>,
     exclude_newer: Option<ExcludeNewerOverride>,
     exclude_newer_package: Option<ExcludeNewerPackage>,
+    strict_exclude_newer: Option<bool>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
@@ -2243,18 +3109,39 @@ pub struct ToolOptionsWire {
     index_strategy: Option<IndexStrategy>,
     keyring_provider: Option<KeyringProviderType>,
     resolution: Option<ResolutionMode>,
+    resolution_package: Option<PackageResolutionModes>,
     prerelease: Option<PrereleaseMode>,
+    prerelease_package: Option<PackagePrereleaseModes>,
+    local_version_preference: Option<PackageLocalVersionPreferences>,
+    yanked: Option<YankedPolicy>,
     fork_strategy: Option<ForkStrategy>,
+    max_metadata_builds: Option<usize>,
+    resolution_effort: Option<usize>,
+    excluded_packages: Option<Vec<PackageName>>,
+    reresolve_metadata: Option<Vec<PackageName>>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
     build_isolation: Option<BuildIsolation>,
+    build_timeout: Option<NonZeroU64>,
+    build_retries: Option<u32>,
+    build_python: Option<String>,
+    show_build_warnings: Option<bool>,
+    build_warning_pattern: Option<Vec<String>>,
+    build_events_file: Option<PathBuf>,
+    keep_build_logs: Option<bool>,
+    keep_build_env: Option<bool>,
+    build_dir: Option<PathBuf>,
+    build_via_sdist: Option<bool>,
+    no_build_environment_reuse: Option<bool>,
     extra_build_dependencies: Option<ExtraBuildDependencies>,
     extra_build_variables: Option<ExtraBuildVariables>,
+    build_env: Option<Vec<BuildEnvEntry>>,
     exclude_newer: Option<ExcludeNewerOverride>,
     exclude_newer_span: Option<ExcludeNewerSpan>,
     #[serde(serialize_with = "serialize_exclude_newer_package_with_spans")]
     exclude_newer_package: Option<ExcludeNewerPackage>,
+    strict_exclude_newer: Option<bool>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
@@ -2282,16 +3169,37 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
+            local_version_preference: value.local_version_preference,
+            yanked: value.yanked,
             fork_strategy: value.fork_strategy,
+            max_metadata_builds: value.max_metadata_builds,
+            resolution_effort: value.resolution_effort,
+            excluded_packages: value.excluded_packages,
+            reresolve_metadata: value.reresolve_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             build_isolation: value.build_isolation,
+            build_timeout: value.build_timeout,
+            build_retries: value.build_retries,
+            build_python: value.build_python,
+            show_build_warnings: value.show_build_warnings,
+            build_warning_pattern: value.build_warning_pattern,
+            build_events_file: value.build_events_file,
+            keep_build_logs: value.keep_build_logs,
+            keep_build_env: value.keep_build_env,
+            build_dir: value.build_dir,
+            build_via_sdist: value.build_via_sdist,
+            no_build_environment_reuse: value.no_build_environment_reuse,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_env: value.build_env,
             exclude_newer: value.exclude_newer,
             exclude_newer_package: value.exclude_newer_package,
+            strict_exclude_newer: value.strict_exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -2332,16 +3240,37 @@ impl From<ToolOptionsWire> for ToolOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
+            local_version_preference: value.local_version_preference,
+            yanked: value.yanked,
             fork_strategy: value.fork_strategy,
+            max_metadata_builds: value.max_metadata_builds,
+            resolution_effort: value.resolution_effort,
+            excluded_packages: value.excluded_packages,
+            reresolve_metadata: value.reresolve_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             build_isolation: value.build_isolation,
+            build_timeout: value.build_timeout,
+            build_retries: value.build_retries,
+            build_python: value.build_python,
+            show_build_warnings: value.show_build_warnings,
+            build_warning_pattern: value.build_warning_pattern,
+            build_events_file: value.build_events_file,
+            keep_build_logs: value.keep_build_logs,
+            keep_build_env: value.keep_build_env,
+            build_dir: value.build_dir,
+            build_via_sdist: value.build_via_sdist,
+            no_build_environment_reuse: value.no_build_environment_reuse,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_env: value.build_env,
             exclude_newer,
             exclude_newer_package: value.exclude_newer_package,
+            strict_exclude_newer: value.strict_exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -2380,17 +3309,38 @@ impl From<ToolOptions> for ToolOptionsWire {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
+            local_version_preference: value.local_version_preference,
+            yanked: value.yanked,
             fork_strategy: value.fork_strategy,
+            max_metadata_builds: value.max_metadata_builds,
+            resolution_effort: value.resolution_effort,
+            excluded_packages: value.excluded_packages,
+            reresolve_metadata: value.reresolve_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             build_isolation: value.build_isolation,
+            build_timeout: value.build_timeout,
+            build_retries: value.build_retries,
+            build_python: value.build_python,
+            show_build_warnings: value.show_build_warnings,
+            build_warning_pattern: value.build_warning_pattern,
+            build_events_file: value.build_events_file,
+            keep_build_logs: value.keep_build_logs,
+            keep_build_env: value.keep_build_env,
+            build_dir: value.build_dir,
+            build_via_sdist: value.build_via_sdist,
+            no_build_environment_reuse: value.no_build_environment_reuse,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_env: value.build_env,
             exclude_newer,
             exclude_newer_span,
             exclude_newer_package: value.exclude_newer_package,
+            strict_exclude_newer: value.strict_exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -2415,16 +3365,37 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_package: value.resolution_package,
             prerelease: value.prerelease,
+            prerelease_package: value.prerelease_package,
+            local_version_preference: value.local_version_preference,
+            yanked: value.yanked,
             fork_strategy: value.fork_strategy,
+            max_metadata_builds: value.max_metadata_builds,
+            resolution_effort: value.resolution_effort,
+            excluded_packages: value.excluded_packages,
+            reresolve_metadata: value.reresolve_metadata,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
             build_isolation: value.build_isolation,
+            build_timeout: value.build_timeout,
+            build_retries: value.build_retries,
+            build_python: value.build_python,
+            show_build_warnings: value.show_build_warnings,
+            build_warning_pattern: value.build_warning_pattern,
+            build_events_file: value.build_events_file,
+            keep_build_logs: value.keep_build_logs,
+            keep_build_env: value.keep_build_env,
+            build_dir: value.build_dir,
+            build_via_sdist: value.build_via_sdist,
+            no_build_environment_reuse: value.no_build_environment_reuse,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_env: value.build_env,
             exclude_newer: value.exclude_newer,
             exclude_newer_package: value.exclude_newer_package,
+            strict_exclude_newer: value.strict_exclude_newer,
             link_mode: value.link_mode,
             compile_bytecode: value.compile_bytecode,
             no_sources: value.no_sources,
@@ -2475,17 +3446,38 @@ struct OptionsWire {
     no_proxy: Option<Vec<String>>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
     resolution: Option<ResolutionMode>,
+    resolution_package: Option<PackageResolutionModes>,
     prerelease: Option<PrereleaseMode>,
+    prerelease_package: Option<PackagePrereleaseModes>,
+    local_version_preference: Option<PackageLocalVersionPreferences>,
+    yanked: Option<YankedPolicy>,
     fork_strategy: Option<ForkStrategy>,
+    max_metadata_builds: Option<usize>,
+    resolution_effort: Option<usize>,
+    excluded_packages: Option<Vec<PackageName>>,
+    reresolve_metadata: Option<Vec<PackageName>>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
     no_build_isolation: Option<bool>,
     no_build_isolation_package: Option<Vec<PackageName>>,
+    build_timeout: Option<NonZeroU64>,
+    build_retries: Option<u32>,
+    build_python: Option<String>,
+    show_build_warnings: Option<bool>,
+    build_warning_pattern: Option<Vec<String>>,
+    build_events_file: Option<PathBuf>,
+    keep_build_logs: Option<bool>,
+    keep_build_env: Option<bool>,
+    build_dir: Option<PathBuf>,
+    build_via_sdist: Option<bool>,
+    no_build_environment_reuse: Option<bool>,
     extra_build_dependencies: Option<ExtraBuildDependencies>,
     extra_build_variables: Option<ExtraBuildVariables>,
+    build_env: Option<Vec<BuildEnvEntry>>,
     exclude_newer: Option<ExcludeNewerOverride>,
     exclude_newer_package: Option<ExcludeNewerPackage>,
+    strict_exclude_newer: Option<bool>,
     link_mode: Option<LinkMode>,
     compile_bytecode: Option<bool>,
     no_sources: Option<bool>,
@@ -2516,6 +3508,10 @@ struct OptionsWire {
     // add: AddOptions
     add_bounds: Option<AddBoundsKind>,
 
+    // #[serde(flatten)]
+    // sync: SyncOptions
+    keep_extraneous: Option<Vec<PackageName>>,
+
     audit: Option<AuditOptions>,
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
@@ -2534,10 +3530,12 @@ struct OptionsWire {
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're only respected in `pyproject.toml` files, and should be rejected in `uv.toml` files.
     conflicts: Option<serde::de::IgnoredAny>,
+    replace: Option<serde::de::IgnoredAny>,
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
+    lock_build_dependencies: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
@@ -2580,15 +3578,35 @@ impl TryFrom<OptionsWire> for Options {
             no_proxy,
             allow_insecure_host,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            excluded_packages,
+            reresolve_metadata,
             dependency_metadata,
             config_settings,
             config_settings_package,
             no_build_isolation,
             no_build_isolation_package,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             exclude_newer_package,
+            strict_exclude_newer,
             link_mode,
             compile_bytecode,
             no_sources,
@@ -2612,6 +3630,7 @@ impl TryFrom<OptionsWire> for Options {
             environments,
             required_environments,
             conflicts,
+            replace,
             publish_url,
             trusted_publishing,
             check_url,
@@ -2621,10 +3640,13 @@ impl TryFrom<OptionsWire> for Options {
             dependency_groups,
             extra_build_dependencies,
             extra_build_variables,
+            build_env,
             dev_dependencies,
             managed,
             package,
+            lock_build_dependencies,
             add_bounds: bounds,
+            keep_extraneous,
             // Used by the build backend
             build_backend,
         } = value;
@@ -2658,17 +3680,38 @@ impl TryFrom<OptionsWire> for Options {
                 index_strategy,
                 keyring_provider,
                 resolution,
+                resolution_package,
                 prerelease,
+                prerelease_package,
+                local_version_preference,
+                yanked,
                 fork_strategy,
+                max_metadata_builds,
+                resolution_effort,
+                excluded_packages,
+                reresolve_metadata,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
                 no_build_isolation,
                 no_build_isolation_package,
+                build_timeout,
+                build_retries,
+                build_python,
+                show_build_warnings,
+                build_warning_pattern,
+                build_events_file,
+                keep_build_logs,
+                keep_build_env,
+                build_dir,
+                build_via_sdist,
+                no_build_environment_reuse,
                 extra_build_dependencies,
                 extra_build_variables,
+                build_env,
                 exclude_newer,
                 exclude_newer_package,
+                strict_exclude_newer,
                 link_mode,
                 compile_bytecode,
                 no_sources,
@@ -2698,12 +3741,14 @@ impl TryFrom<OptionsWire> for Options {
                 python_downloads_json_url,
             },
             conflicts,
+            replace,
             publish: PublishOptions {
                 publish_url,
                 trusted_publishing,
                 check_url,
             },
             add: AddOptions { add_bounds: bounds },
+            sync: SyncOptions { keep_extraneous },
             audit,
             workspace,
             sources,
@@ -2712,6 +3757,7 @@ impl TryFrom<OptionsWire> for Options {
             dependency_groups,
             managed,
             package,
+            lock_build_dependencies,
         })
     }
 }
@@ -2794,6 +3840,28 @@ pub struct AddOptions {
     pub add_bounds: Option<AddBoundsKind>,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SyncOptions {
+    /// Extraneous packages to retain in the environment when syncing.
+    ///
+    /// By default, `uv sync` removes any installed packages that aren't included in the lockfile.
+    /// Packages listed here are left in place (e.g., debugging tools installed ad hoc with
+    /// `uv pip install`), but are still reported as extraneous.
+    ///
+    /// Listed packages that are also included in the lockfile are always synced to the locked
+    /// version.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            keep-extraneous = ["ipdb", "line-profiler"]
+        "#
+    )]
+    pub keep_extraneous: Option<Vec<PackageName>>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -2,6 +2,8 @@ use std::{io, path::PathBuf, sync::Arc};
 
 use tempfile::{TempDir, tempdir};
 
+use uv_dirs::DirectorySource;
+
 /// The main state storage abstraction.
 ///
 /// This is appropriate for storing persistent data that is not user-facing, such as managed Python
@@ -50,15 +52,30 @@ impl StateStore {
     pub fn from_settings(state_dir: Option<PathBuf>) -> Result<Self, io::Error> {
         if let Some(state_dir) = state_dir {
             Ok(Self::from_path(state_dir))
-        } else if let Some(data_dir) = uv_dirs::legacy_user_state_dir().filter(|dir| dir.exists()) {
+        } else {
+            Ok(Self::user_with_source().0)
+        }
+    }
+
+    /// Return the user-level state store, i.e., [`StateStore::from_settings`] without a specific
+    /// state directory, along with the [`DirectorySource`] from which its location was resolved.
+    pub fn user_with_source() -> (Self, DirectorySource) {
+        if let Some(data_dir) = uv_dirs::legacy_user_state_dir().filter(|dir| dir.exists()) {
             // If the user has an existing directory at (e.g.) `/Users/user/Library/Application Support/uv`,
             // respect it for backwards compatibility. Otherwise, prefer the XDG strategy, even on
             // macOS.
-            Ok(Self::from_path(data_dir))
-        } else if let Some(data_dir) = uv_dirs::user_state_dir() {
-            Ok(Self::from_path(data_dir))
+            //
+            // On Linux, the legacy and XDG locations are the same, and on Windows, the legacy
+            // location is the default.
+            let source = match uv_dirs::user_state_dir_with_source() {
+                Some((state_dir, source)) if cfg!(windows) || state_dir == data_dir => source,
+                _ => DirectorySource::Legacy,
+            };
+            (Self::from_path(data_dir), source)
+        } else if let Some((data_dir, source)) = uv_dirs::user_state_dir_with_source() {
+            (Self::from_path(data_dir), source)
         } else {
-            Ok(Self::from_path(".uv"))
+            (Self::from_path(".uv"), DirectorySource::Fallback)
         }
     }
 }
@@ -73,6 +90,8 @@ pub enum StateBucket {
     Tools,
     /// Credentials.
     Credentials,
+    /// A registry of project environments.
+    Environments,
 }
 
 impl StateBucket {
@@ -81,6 +100,7 @@ impl StateBucket {
             Self::ManagedPython => "python",
             Self::Tools => "tools",
             Self::Credentials => "credentials",
+            Self::Environments => "environments",
         }
     }
 }
@@ -87,11 +87,26 @@ impl EnvVars {
     #[attr_added_in("0.1.16")]
     pub const UV_PRERELEASE: &'static str = "UV_PRERELEASE";
 
+    /// Equivalent to the `--yanked` command-line argument. For example, if set to `forbid`, uv
+    /// will fail rather than select or install a yanked version.
+    #[attr_added_in("0.11.26")]
+    pub const UV_YANKED: &'static str = "UV_YANKED";
+
     /// Equivalent to the `--fork-strategy` argument. Controls version selection during universal
     /// resolution.
     #[attr_added_in("0.5.9")]
     pub const UV_FORK_STRATEGY: &'static str = "UV_FORK_STRATEGY";
 
+    /// Equivalent to the `--max-metadata-builds` argument. Limits the number of source
+    /// distributions that may be built to determine their metadata during resolution.
+    #[attr_added_in("0.11.26")]
+    pub const UV_MAX_METADATA_BUILDS: &'static str = "UV_MAX_METADATA_BUILDS";
+
+    /// Equivalent to the `--resolution-effort` argument. Limits the number of decisions the
+    /// resolver may make before giving up.
+    #[attr_added_in("0.11.26")]
+    pub const UV_RESOLVER_MAX_STEPS: &'static str = "UV_RESOLVER_MAX_STEPS";
+
     /// Equivalent to the `--system` command-line argument. If set to `true`, uv will
     /// use the first Python interpreter found in the system `PATH`.
     ///
@@ -364,6 +379,9 @@ impl EnvVars {
 
     /// Equivalent to the `--no-sync` command-line argument. If set, uv will skip updating
     /// the environment.
+    ///
+    /// Like `--no-sync`, implies `--frozen`: the lockfile is neither checked nor updated, even if
+    /// `--locked` is provided.
     #[attr_added_in("0.4.18")]
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
@@ -422,6 +440,15 @@ impl EnvVars {
     #[attr_added_in("0.1.45")]
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Sets the maximum number of metadata prefetches that uv will perform concurrently during
+    /// resolution. When the limit is reached, uv prioritizes packages that are required by the
+    /// most dependencies.
+    ///
+    /// The limit is shared by all resolutions, including those for build requirements. Defaults
+    /// to `50`.
+    #[attr_added_in("0.11.26")]
+    pub const UV_CONCURRENT_METADATA: &'static str = "UV_CONCURRENT_METADATA";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     #[attr_added_in("0.2.28")]
@@ -674,6 +701,14 @@ impl EnvVars {
     #[attr_added_in("0.3.4")]
     pub const UV_INTERNAL__TEST_DIR: &'static str = "UV_INTERNAL__TEST_DIR";
 
+    /// Used to abort uv after it rewrites a given file (e.g., `uv.lock` or `pyproject.toml:2`),
+    /// to test recovery from interrupted writes. Only respected in builds with the
+    /// `test-crash-after-write` feature.
+    #[attr_hidden]
+    #[attr_added_in("0.11.26")]
+    pub const UV_INTERNAL__TEST_CRASH_AFTER_WRITE: &'static str =
+        "UV_INTERNAL__TEST_CRASH_AFTER_WRITE";
+
     /// Path to a directory on a filesystem that supports copy-on-write, e.g., btrfs or APFS.
     ///
     /// When populated, uv will run additional tests that require this functionality.
@@ -754,6 +789,11 @@ impl EnvVars {
     #[attr_added_in("0.2.16")]
     pub const XDG_BIN_HOME: &'static str = "XDG_BIN_HOME";
 
+    /// Path to directory for storing non-essential state, such as the registry of project
+    /// environments, on Unix systems.
+    #[attr_added_in("0.11.26")]
+    pub const XDG_STATE_HOME: &'static str = "XDG_STATE_HOME";
+
     /// Path to a CA certificate bundle file for TLS connections.
     ///
     /// Requires a PEM-encoded certificate file (e.g., `certs.pem`, `ca-bundle.crt`). DER-encoded
@@ -821,6 +861,14 @@ impl EnvVars {
     #[attr_added_in("0.7.21")]
     pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
 
+    /// The HTTP version to use for requests, either `1.1` or `2`.
+    ///
+    /// By default, uv negotiates the HTTP version with the server and falls back to HTTP/1.1 for
+    /// the remainder of the invocation if a host fails with an HTTP/2 protocol error. Per-index
+    /// `http` settings take precedence over this value.
+    #[attr_added_in("0.11.26")]
+    pub const UV_HTTP_VERSION: &'static str = "UV_HTTP_VERSION";
+
     /// Timeout (in seconds) for HTTP requests. Equivalent to `UV_HTTP_TIMEOUT`.
     #[attr_added_in("0.1.6")]
     pub const UV_REQUEST_TIMEOUT: &'static str = "UV_REQUEST_TIMEOUT";
@@ -937,6 +985,34 @@ impl EnvVars {
     #[attr_added_in("0.0.5")]
     pub const HOME: &'static str = "HOME";
 
+    /// The standard `TMPDIR` posix env var.
+    #[attr_added_in("0.11.26")]
+    pub const TMPDIR: &'static str = "TMPDIR";
+
+    /// The standard `TEMP` Windows env var.
+    #[attr_added_in("0.11.26")]
+    pub const TEMP: &'static str = "TEMP";
+
+    /// The standard `TMP` Windows env var.
+    #[attr_added_in("0.11.26")]
+    pub const TMP: &'static str = "TMP";
+
+    /// The standard `SYSTEMROOT` Windows env var.
+    #[attr_added_in("0.11.26")]
+    pub const SYSTEMROOT: &'static str = "SYSTEMROOT";
+
+    /// The standard `COMSPEC` Windows env var.
+    #[attr_added_in("0.11.26")]
+    pub const COMSPEC: &'static str = "COMSPEC";
+
+    /// The standard `PATHEXT` Windows env var.
+    #[attr_added_in("0.11.26")]
+    pub const PATHEXT: &'static str = "PATHEXT";
+
+    /// The standard `LANG` posix env var.
+    #[attr_added_in("0.11.26")]
+    pub const LANG: &'static str = "LANG";
+
     /// The standard `SHELL` posix env var.
     #[attr_added_in("0.1.16")]
     pub const SHELL: &'static str = "SHELL";
@@ -1497,6 +1573,82 @@ impl EnvVars {
     #[attr_added_in("0.9.15")]
     pub const UV_HIDE_BUILD_OUTPUT: &'static str = "UV_HIDE_BUILD_OUTPUT";
 
+    /// Control how output from the build backend is displayed when building source distributions.
+    ///
+    /// When set to `stream`, the build backend output is displayed as it's produced, with each line
+    /// prefixed by the name of the package being built. By default, the output is only displayed if
+    /// the build fails (or as debug logs, with `--verbose`).
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_OUTPUT: &'static str = "UV_BUILD_OUTPUT";
+
+    /// The maximum time (in seconds) to wait for a build backend hook to complete when building a
+    /// source distribution.
+    ///
+    /// If the timeout is exceeded, the build backend and any processes it spawned are terminated.
+    /// By default, builds are not subject to a timeout.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_TIMEOUT: &'static str = "UV_BUILD_TIMEOUT";
+
+    /// The number of times to retry a build backend hook that fails with a transient filesystem
+    /// error, such as a file held open by an antivirus scanner or a stale NFS file handle.
+    ///
+    /// Each retry runs the hook in a fresh output directory. By default, builds are not retried.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_RETRIES: &'static str = "UV_BUILD_RETRIES";
+
+    /// The Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`, e.g., `3.11`, or `any` to select any compatible interpreter.
+    ///
+    /// By default, such builds fail.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_PYTHON: &'static str = "UV_BUILD_PYTHON";
+
+    /// Equivalent to the `--build-target` command-line argument. The platform to build wheels for,
+    /// as a wheel platform tag, e.g., `manylinux_2_28_aarch64`.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_TARGET: &'static str = "UV_BUILD_TARGET";
+
+    /// Equivalent to the `--build-dir` command-line argument. The directory in which to create
+    /// temporary build directories, instead of the cache.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_DIR: &'static str = "UV_BUILD_DIR";
+
+    /// Equivalent to the `--no-build-environment-reuse` command-line argument. If set, uv will
+    /// create a separate build environment for each build, rather than sharing build environments
+    /// across builds with identical build requirements.
+    #[attr_added_in("0.11.26")]
+    pub const UV_NO_BUILD_ENVIRONMENT_REUSE: &'static str = "UV_NO_BUILD_ENVIRONMENT_REUSE";
+
+    /// Equivalent to the `--keep-build-env` command-line argument. If set, uv will retain the
+    /// temporary build environment of a source distribution whose build backend fails.
+    #[attr_added_in("0.11.26")]
+    pub const UV_KEEP_BUILD_ENV: &'static str = "UV_KEEP_BUILD_ENV";
+
+    /// Equivalent to the `--keep-build-logs` command-line argument. If set, uv will retain the
+    /// build backend output of successful builds in the cache.
+    #[attr_added_in("0.11.26")]
+    pub const UV_KEEP_BUILD_LOGS: &'static str = "UV_KEEP_BUILD_LOGS";
+
+    /// Equivalent to the `--build-via-sdist` command-line argument. If set, uv will build wheels
+    /// for local and Git sources from a source distribution of the source tree.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_VIA_SDIST: &'static str = "UV_BUILD_VIA_SDIST";
+
+    /// Equivalent to the `--show-build-warnings` command-line argument. If set, uv will summarize
+    /// the warnings emitted by build backends during successful builds.
+    #[attr_added_in("0.11.26")]
+    pub const UV_SHOW_BUILD_WARNINGS: &'static str = "UV_SHOW_BUILD_WARNINGS";
+
+    /// Equivalent to the `--build-warning-pattern` command-line argument. A comma-separated list of
+    /// patterns that identify a line of build backend output as a warning.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_WARNING_PATTERN: &'static str = "UV_BUILD_WARNING_PATTERN";
+
+    /// Equivalent to the `--build-events-file` command-line argument. If set, uv will append a
+    /// JSON event to the given file at the start and finish of every source build.
+    #[attr_added_in("0.11.26")]
+    pub const UV_BUILD_EVENTS_FILE: &'static str = "UV_BUILD_EVENTS_FILE";
+
     /// The time in seconds uv waits for a file lock to become available.
     ///
     /// Defaults to 300s (5 min).
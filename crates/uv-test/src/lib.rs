@@ -234,6 +234,16 @@ impl TestContext {
         self
     }
 
+    /// Filter the package and source tree digest from the paths of build logs in the cache.
+    #[must_use]
+    pub fn with_filtered_build_logs(mut self) -> Self {
+        self.filters.push((
+            r"build-logs-v0[\\/][^\s]+[\\/]build\.log".to_string(),
+            "build-logs-v0/[BUILD]/build.log".to_string(),
+        ));
+        self
+    }
+
     /// Filter hashes from backticked centralized environment cache entry names.
     #[must_use]
     pub fn with_filtered_centralized_environment_hashes(mut self) -> Self {
@@ -609,6 +619,16 @@ impl TestContext {
         self
     }
 
+    /// Adds a filter for the uv version pinned in Dockerfile snippets.
+    #[must_use]
+    pub fn with_dockerfile_filters(mut self) -> Self {
+        self.filters.push((
+            regex::escape(&format!("ghcr.io/astral-sh/uv:{}", uv_version::version())),
+            "ghcr.io/astral-sh/uv:[VERSION]".to_string(),
+        ));
+        self
+    }
+
     /// Add a filter that collapses duplicate whitespace.
     #[must_use]
     pub fn with_collapsed_whitespace(mut self) -> Self {
@@ -1202,6 +1222,10 @@ impl TestContext {
                 EnvVars::XDG_DATA_HOME,
                 self.home_dir.join("data").as_os_str(),
             )
+            .env(
+                EnvVars::XDG_STATE_HOME,
+                self.home_dir.join("state").as_os_str(),
+            )
             .env(EnvVars::UV_NO_SYSTEM_CONFIG, "1")
             .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
             // Installations are not allowed by default; see `Self::with_managed_python_dirs`
@@ -1309,6 +1333,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `pip download` command with options shared across scenarios.
+    pub fn pip_download(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("pip").arg("download");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `pip uninstall` command with options shared across scenarios.
     pub fn pip_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -1456,6 +1488,13 @@ impl TestContext {
         command
     }
 
+    pub fn self_dirs(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("self").arg("dirs");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     pub fn self_update(&self) -> Command {
         let mut command = self.new_command();
         command.arg("self").arg("update");
@@ -1593,6 +1632,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool repair` command with options shared across scenarios.
+    pub fn tool_repair(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("repair");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv add` command for the given requirements.
     pub fn add(&self) -> Command {
         let mut command = self.new_command();
@@ -1934,6 +1981,91 @@ impl TestContext {
             .unwrap_or_else(|_| panic!("Missing file: `{}`", file.user_display()))
     }
 
+    /// Create a project in `temp_dir/<name>` that's built by an in-tree `backend.py`.
+    ///
+    /// The backend defines `write_wheel(wheel_directory, files=None, tag="py3-none-any")`, which
+    /// writes a wheel containing `<name>/__init__.py` and the given `files`, and
+    /// `write_sdist(sdist_directory, files=None)`, which archives the source tree along with a
+    /// `PKG-INFO` and the given `files`. By default, `build_wheel` calls `write_wheel`; the
+    /// `hooks` are appended to the backend and can replace it or define further hooks.
+    ///
+    /// The `pyproject.toml` only declares the build system; tests that need additional metadata
+    /// can overwrite it.
+    pub fn in_tree_backend(
+        &self,
+        name: &str,
+        version: &str,
+        hooks: &str,
+    ) -> anyhow::Result<ChildPath> {
+        const PRELUDE: &str = indoc! {r#"
+            import io
+            import pathlib
+            import tarfile
+            import zipfile
+
+            DISTRIBUTION = NAME.replace("-", "_")
+            METADATA = f"Metadata-Version: 2.1\nName: {NAME}\nVersion: {VERSION}\n"
+
+
+            def write_wheel(wheel_directory, files=None, tag="py3-none-any", metadata=METADATA):
+                wheel_name = f"{DISTRIBUTION}-{VERSION}-{tag}.whl"
+                dist_info = f"{DISTRIBUTION}-{VERSION}.dist-info"
+                records = {f"{DISTRIBUTION}/__init__.py": ""}
+                records.update(files or {})
+                records[f"{dist_info}/METADATA"] = metadata
+                records[f"{dist_info}/WHEEL"] = (
+                    f"Wheel-Version: 1.0\nGenerator: uv-test\nRoot-Is-Purelib: true\nTag: {tag}\n"
+                )
+                with zipfile.ZipFile(pathlib.Path(wheel_directory, wheel_name), "w") as wheel:
+                    for path, contents in records.items():
+                        wheel.writestr(path, contents)
+                    record = "".join(f"{path},,\n" for path in records)
+                    wheel.writestr(f"{dist_info}/RECORD", record + f"{dist_info}/RECORD,,\n")
+                return wheel_name
+
+
+            def write_sdist(sdist_directory, files=None):
+                sdist_name = f"{DISTRIBUTION}-{VERSION}.tar.gz"
+                prefix = f"{DISTRIBUTION}-{VERSION}/"
+                records = {"PKG-INFO": METADATA}
+                records.update(files or {})
+                with tarfile.open(pathlib.Path(sdist_directory, sdist_name), "w:gz") as sdist:
+                    for filename in ["pyproject.toml", "backend.py"]:
+                        sdist.add(filename, arcname=prefix + filename)
+                    for filename, contents in records.items():
+                        contents = contents.encode()
+                        info = tarfile.TarInfo(prefix + filename)
+                        info.size = len(contents)
+                        sdist.addfile(info, io.BytesIO(contents))
+                return sdist_name
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                return write_wheel(wheel_directory)
+        "#};
+
+        let project = self.temp_dir.child(name);
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "{version}"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = []
+            build-backend = "backend"
+            backend-path = ["."]
+        "#})?;
+        project.child("backend.py").write_str(&formatdoc! {r#"
+            NAME = "{name}"
+            VERSION = "{version}"
+            {PRELUDE}
+
+            {hooks}
+        "#})?;
+        Ok(project)
+    }
+
     /// Creates a new `Command` that is intended to be suitable for use in
     /// all tests.
     fn new_command(&self) -> Command {
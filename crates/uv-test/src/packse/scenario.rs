@@ -112,6 +112,15 @@ pub struct PackageMetadata {
     #[serde(default)]
     pub yanked: bool,
 
+    /// The reason that this version was yanked, if any. Only used if `yanked` is set.
+    #[serde(default)]
+    pub yanked_reason: Option<String>,
+
+    /// The upload time of the distributions, as an RFC 3339 timestamp (e.g.,
+    /// `"2024-06-01T00:00:00Z"`). Defaults to a fixed time shared by all scenario packages.
+    #[serde(default)]
+    pub upload_time: Option<String>,
+
     /// Specific wheel tags to produce (e.g., `["cp312-abi3-win_amd64"]`).
     /// An empty list means produce only the default `py3-none-any` wheel.
     #[serde(default)]
@@ -33,8 +33,9 @@ struct DistInfo {
     filename: String,
     sha256: String,
     requires_python: Option<VersionSpecifiers>,
-    upload_time: &'static str,
+    upload_time: String,
     yanked: bool,
+    yanked_reason: Option<String>,
 }
 
 /// All distributions for a given package name, across versions.
@@ -136,8 +137,12 @@ fn build_server_index(scenario: &Scenario) -> ServerIndex {
                         filename,
                         sha256,
                         requires_python: meta.requires_python.clone(),
-                        upload_time: PACKSE_UPLOAD_TIME,
+                        upload_time: meta
+                            .upload_time
+                            .clone()
+                            .unwrap_or_else(|| PACKSE_UPLOAD_TIME.to_string()),
                         yanked: meta.yanked,
+                        yanked_reason: meta.yanked_reason.clone(),
                     });
                 }
             }
@@ -156,8 +161,12 @@ fn build_server_index(scenario: &Scenario) -> ServerIndex {
                     filename,
                     sha256,
                     requires_python: meta.requires_python.clone(),
-                    upload_time: PACKSE_UPLOAD_TIME,
+                    upload_time: meta
+                        .upload_time
+                        .clone()
+                        .unwrap_or_else(|| PACKSE_UPLOAD_TIME.to_string()),
                     yanked: meta.yanked,
+                    yanked_reason: meta.yanked_reason.clone(),
                 });
             }
         }
@@ -185,8 +194,9 @@ fn build_server_index(scenario: &Scenario) -> ServerIndex {
                 filename: artifact.filename.to_string(),
                 sha256: artifact.sha256.to_string(),
                 requires_python: None,
-                upload_time: PACKSE_UPLOAD_TIME,
+                upload_time: PACKSE_UPLOAD_TIME.to_string(),
                 yanked: false,
+                yanked_reason: None,
             });
     }
 
@@ -244,7 +254,10 @@ fn build_simple_api_response(
                 file_obj["requires-python"] = json!(rp);
             }
             if dist.yanked {
-                file_obj["yanked"] = json!(true);
+                file_obj["yanked"] = match &dist.yanked_reason {
+                    Some(reason) => json!(reason),
+                    None => json!(true),
+                };
             }
             file_obj
         })
@@ -9,7 +9,7 @@ use thiserror::Error;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
-use uv_dirs::user_executable_directory;
+use uv_dirs::{DirectorySource, user_executable_directory_with_source};
 use uv_fs::{LockedFile, LockedFileError, LockedFileMode, Simplified};
 use uv_install_wheel::read_record;
 use uv_installer::SitePackages;
@@ -132,13 +132,30 @@ impl InstalledTools {
     /// 2. A directory in the system-appropriate user-level data directory, e.g., `~/.local/uv/tools`
     /// 3. A directory in the local data directory, e.g., `./.uv/tools`
     pub fn from_settings() -> Result<Self, Error> {
-        if let Some(tool_dir) = std::env::var_os(EnvVars::UV_TOOL_DIR).filter(|s| !s.is_empty()) {
-            Ok(Self::from_path(std::path::absolute(tool_dir)?))
+        Ok(Self::from_settings_with_source()?.0)
+    }
+
+    /// Like [`InstalledTools::from_settings`], but also returns the [`DirectorySource`] from which
+    /// the directory was resolved.
+    ///
+    /// Warns if the directory was overridden, but is empty, while the default directory contains
+    /// existing tools.
+    pub fn from_settings_with_source() -> Result<(Self, DirectorySource), Error> {
+        let (root, source) = if let Some(tool_dir) =
+            std::env::var_os(EnvVars::UV_TOOL_DIR).filter(|s| !s.is_empty())
+        {
+            (
+                std::path::absolute(tool_dir)?,
+                DirectorySource::EnvironmentVariable(EnvVars::UV_TOOL_DIR),
+            )
         } else {
-            Ok(Self::from_path(
-                StateStore::from_settings(None)?.bucket(StateBucket::Tools),
-            ))
-        }
+            let (store, source) = StateStore::user_with_source();
+            (store.bucket(StateBucket::Tools), source)
+        };
+        uv_dirs::warn_if_hidden_default("Installed tools", &root, source, || {
+            Some(StateStore::user_with_source().0.bucket(StateBucket::Tools))
+        });
+        Ok((Self::from_path(root), source))
     }
 
     /// Return the expected directory for a tool with the given [`PackageName`].
@@ -199,6 +216,41 @@ impl InstalledTools {
         }
     }
 
+    /// Returns `true` if the tool has a receipt, but its environment is missing, e.g., because the
+    /// environment was deleted by hand.
+    ///
+    /// Note it is generally incorrect to use this without [`Self::acquire_lock`].
+    pub fn is_orphaned(&self, name: &PackageName) -> bool {
+        let tool_dir = self.tool_dir(name);
+        tool_dir.join("uv-receipt.toml").is_file() && !tool_dir.join("pyvenv.cfg").is_file()
+    }
+
+    /// Return the executables in the given directory that link to a tool environment that no
+    /// longer exists.
+    ///
+    /// On Unix, tool executables are symbolic links into the tool environment. On Windows, they're
+    /// trampolines that launch the Python interpreter of the tool environment.
+    pub fn dangling_executables(&self, executable_directory: &Path) -> Result<Vec<PathBuf>, Error> {
+        let entries = match fs_err::read_dir(executable_directory) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut dangling = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Some(target) = executable_target(&path) else {
+                continue;
+            };
+            if target.starts_with(&self.root) && !target.exists() {
+                dangling.push(path);
+            }
+        }
+        dangling.sort();
+        Ok(dangling)
+    }
+
     /// Grab a file lock for the tools directory to prevent concurrent access across processes.
     pub async fn lock(&self) -> Result<LockedFile, Error> {
         Ok(LockedFile::acquire(
@@ -228,7 +280,7 @@ impl InstalledTools {
             .map_err(|err| Error::ReceiptWrite(path.clone(), Box::new(err)))?;
 
         // Save the modified `uv-receipt.toml`.
-        fs_err::write(&path, doc)?;
+        uv_fs::write_durable_sync(&path, doc)?;
 
         Ok(())
     }
@@ -385,7 +437,31 @@ impl InstalledTools {
 
 /// Find the tool executable directory.
 pub fn tool_executable_dir() -> Result<PathBuf, Error> {
-    user_executable_directory(Some(EnvVars::UV_TOOL_BIN_DIR)).ok_or(Error::NoExecutableDirectory)
+    Ok(tool_executable_dir_with_source()?.0)
+}
+
+/// Find the tool executable directory, along with the [`DirectorySource`] from which it was
+/// resolved.
+pub fn tool_executable_dir_with_source() -> Result<(PathBuf, DirectorySource), Error> {
+    user_executable_directory_with_source(Some(EnvVars::UV_TOOL_BIN_DIR))
+        .ok_or(Error::NoExecutableDirectory)
+}
+
+/// Return the path that a tool executable points to, if it's a symbolic link (on Unix) or a
+/// trampoline (on Windows).
+#[cfg(unix)]
+fn executable_target(path: &Path) -> Option<PathBuf> {
+    fs_err::read_link(path).ok()
+}
+
+/// Return the path that a tool executable points to, if it's a symbolic link (on Unix) or a
+/// trampoline (on Windows).
+#[cfg(windows)]
+fn executable_target(path: &Path) -> Option<PathBuf> {
+    uv_trampoline_builder::Launcher::try_from_path(path)
+        .ok()
+        .flatten()
+        .map(|launcher| launcher.python_path)
 }
 
 /// Find the `.dist-info` directory for a package in an environment.
@@ -2,6 +2,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use itertools::Itertools;
 use rustc_hash::FxHashMap;
 
 use uv_configuration::HashCheckingMode;
@@ -9,8 +10,10 @@ use uv_distribution_types::{
     DistributionMetadata, HashGeneration, HashPolicy, Name, Requirement, RequirementSource,
     Resolution, UnresolvedRequirement, VersionId,
 };
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_pep508::RequirementOrigin;
 use uv_pypi_types::{HashDigest, HashDigests, HashError, ResolverMarkerEnvironment};
 use uv_redacted::DisplaySafeUrl;
 
@@ -21,6 +24,9 @@ pub enum HashStrategy {
     None,
     /// Hashes should be generated (specifically, a SHA-256 hash), but not validated.
     Generate(HashGeneration),
+    /// Hashes should be generated (as in [`HashStrategy::Generate`]), and validated against a
+    /// pre-defined list of hashes, if present (e.g., hashes provided in direct URL fragments).
+    GenerateAndVerify(HashGeneration, Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
     /// Hashes should be validated, if present, but ignored if absent.
     ///
     /// If necessary, hashes should be generated to ensure that the archive is valid.
@@ -37,6 +43,14 @@ impl HashStrategy {
         match self {
             Self::None => HashPolicy::None,
             Self::Generate(mode) => HashPolicy::Generate(*mode),
+            Self::GenerateAndVerify(mode, hashes) => {
+                let id = distribution.version_id();
+                if let Some(hashes) = hashes.get(&id) {
+                    HashPolicy::GenerateAndVerify(hashes.as_slice())
+                } else {
+                    HashPolicy::Generate(*mode)
+                }
+            }
             Self::Verify(hashes) => {
                 let id = distribution.version_id();
                 if let Some(hashes) = hashes.get(&id) {
@@ -58,6 +72,13 @@ impl HashStrategy {
         match self {
             Self::None => HashPolicy::None,
             Self::Generate(mode) => HashPolicy::Generate(*mode),
+            Self::GenerateAndVerify(mode, hashes) => {
+                if let Some(hashes) = hashes.get(&id) {
+                    HashPolicy::Any(hashes.as_slice())
+                } else {
+                    HashPolicy::Generate(*mode)
+                }
+            }
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&id) {
                     HashPolicy::Any(hashes.as_slice())
@@ -79,6 +100,13 @@ impl HashStrategy {
         match self {
             Self::None => HashPolicy::None,
             Self::Generate(mode) => HashPolicy::Generate(*mode),
+            Self::GenerateAndVerify(mode, hashes) => {
+                if let Some(hashes) = hashes.get(&id) {
+                    HashPolicy::GenerateAndVerify(hashes.as_slice())
+                } else {
+                    HashPolicy::Generate(*mode)
+                }
+            }
             Self::Verify(hashes) => {
                 if let Some(hashes) = hashes.get(&id) {
                     HashPolicy::All(hashes.as_slice())
@@ -97,6 +125,7 @@ impl HashStrategy {
         match self {
             Self::None => true,
             Self::Generate(_) => true,
+            Self::GenerateAndVerify(..) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => {
                 hashes.contains_key(&VersionId::from_registry(name.clone(), version.clone()))
@@ -109,6 +138,7 @@ impl HashStrategy {
         match self {
             Self::None => true,
             Self::Generate(_) => true,
+            Self::GenerateAndVerify(..) => true,
             Self::Verify(_) => true,
             Self::Require(hashes) => hashes.contains_key(&VersionId::from_url(url)),
         }
@@ -122,7 +152,20 @@ impl HashStrategy {
     ) -> Result<Self, HashStrategyError> {
         Ok(match self {
             Self::None => Self::None,
-            Self::Generate(mode) => Self::Generate(mode),
+            Self::Generate(mode) => {
+                if let Some(hashes) = Self::augment_hashes(&FxHashMap::default(), requirements)? {
+                    Self::GenerateAndVerify(mode, Arc::new(hashes))
+                } else {
+                    Self::Generate(mode)
+                }
+            }
+            Self::GenerateAndVerify(mode, existing) => {
+                if let Some(hashes) = Self::augment_hashes(existing.as_ref(), requirements)? {
+                    Self::GenerateAndVerify(mode, Arc::new(hashes))
+                } else {
+                    Self::GenerateAndVerify(mode, existing)
+                }
+            }
             Self::Verify(existing) => {
                 if let Some(hashes) = Self::augment_hashes(existing.as_ref(), requirements)? {
                     Self::Verify(Arc::new(hashes))
@@ -163,7 +206,7 @@ impl HashStrategy {
             }
 
             // Every constraint must be a pinned version.
-            let Some(id) = Self::pin(requirement) else {
+            let Some(id) = requirement.pinned_version_id() else {
                 if mode.is_require() {
                     return Err(HashStrategyError::UnpinnedRequirement(
                         requirement.to_string(),
@@ -179,7 +222,7 @@ impl HashStrategy {
                 .iter()
                 .map(|digest| HashDigest::from_str(digest))
                 .collect::<Result<Vec<_>, _>>()?;
-            if let Some(fragment_hashes) = requirement.hashes().map(HashDigests::from) {
+            if let Some(fragment_hashes) = requirement.hashes()?.map(HashDigests::from) {
                 merge_digests(&mut digests, fragment_hashes.iter(), requirement)?;
             }
 
@@ -199,10 +242,22 @@ impl HashStrategy {
                 continue;
             }
 
+            // Git repositories and local directories don't refer to a single archive, so there's
+            // nothing to verify against the provided hashes.
+            if mode.is_require() {
+                if let Some(source) = UnhashableSource::from_source(&requirement.source()) {
+                    return Err(HashStrategyError::UnhashableRequirement(
+                        describe(requirement),
+                        source,
+                        mode,
+                    ));
+                }
+            }
+
             // Every requirement must be either a pinned version or a direct URL.
             let id = match &requirement {
                 UnresolvedRequirement::Named(requirement) => {
-                    if let Some(id) = Self::pin(requirement) {
+                    if let Some(id) = requirement.pinned_version_id() {
                         id
                     } else {
                         if mode.is_require() {
@@ -226,7 +281,7 @@ impl HashStrategy {
                 .iter()
                 .map(|digest| HashDigest::from_str(digest))
                 .collect::<Result<Vec<_>, _>>()?;
-            if let Some(fragment_hashes) = requirement.hashes().map(HashDigests::from) {
+            if let Some(fragment_hashes) = requirement.hashes()?.map(HashDigests::from) {
                 merge_digests(&mut digests, fragment_hashes.iter(), requirement)?;
             }
 
@@ -234,7 +289,7 @@ impl HashStrategy {
                 if digests.is_empty() {
                     // If there are _only_ hashes on the constraints, use them.
                     constraint
-                } else if matches!(id, VersionId::ArchiveUrl { .. }) {
+                } else if matches!(id, VersionId::ArchiveUrl { .. } | VersionId::Path(_)) {
                     let mut merged = digests;
                     merge_digests(&mut merged, &constraint, requirement)?;
                     merged
@@ -324,7 +379,7 @@ impl HashStrategy {
         let mut hashes = None;
 
         for requirement in requirements {
-            let Some((id, digests)) = Self::requirement_hashes(requirement) else {
+            let Some((id, digests)) = Self::requirement_hashes(requirement)? else {
                 continue;
             };
             let current = hashes.as_ref().unwrap_or(existing);
@@ -345,53 +400,87 @@ impl HashStrategy {
     }
 
     /// Extract the archive URL hash target and digests for a requirement, if any.
-    fn requirement_hashes(requirement: &Requirement) -> Option<(VersionId, Vec<HashDigest>)> {
-        let mut digests = HashDigests::from(requirement.hashes()?).to_vec();
+    fn requirement_hashes(
+        requirement: &Requirement,
+    ) -> Result<Option<(VersionId, Vec<HashDigest>)>, HashStrategyError> {
+        let Some(hashes) = requirement.hashes()? else {
+            return Ok(None);
+        };
+        let mut digests = HashDigests::from(hashes).to_vec();
         if digests.is_empty() {
-            return None;
+            return Ok(None);
         }
         digests.sort_unstable();
-        let id = Self::pin(requirement)?;
-        Some((id, digests))
+        let Some(id) = requirement.pinned_version_id() else {
+            return Ok(None);
+        };
+        Ok(Some((id, digests)))
     }
+}
 
-    /// Pin a [`Requirement`] to a [`VersionId`], if possible.
-    fn pin(requirement: &Requirement) -> Option<VersionId> {
-        match &requirement.source {
-            RequirementSource::Registry { specifier, .. } => {
-                // Must be a single specifier.
-                let [specifier] = specifier.as_ref() else {
-                    return None;
-                };
-
-                // Must be pinned to a specific version.
-                if *specifier.operator() != uv_pep440::Operator::Equal {
-                    return None;
-                }
+/// A requirement source that can't be verified against a hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnhashableSource {
+    /// A Git repository.
+    Git,
+    /// A local directory.
+    Directory,
+    /// A local directory, installed as editable.
+    Editable,
+}
 
-                Some(VersionId::from_registry(
-                    requirement.name.clone(),
-                    specifier.version().clone(),
-                ))
+impl UnhashableSource {
+    fn from_source(source: &RequirementSource) -> Option<Self> {
+        match source {
+            RequirementSource::GitDirectory { .. } | RequirementSource::GitPath { .. } => {
+                Some(Self::Git)
             }
-            RequirementSource::Url {
-                location,
-                subdirectory,
+            RequirementSource::Directory {
+                editable: Some(true),
                 ..
-            } => Some(VersionId::from_archive(location, subdirectory.as_deref())),
-            RequirementSource::GitDirectory {
-                git, subdirectory, ..
-            } => Some(VersionId::from_git(git, subdirectory.as_deref())),
-            RequirementSource::GitPath {
-                git, install_path, ..
-            } => Some(VersionId::from_git(git, Some(install_path))),
-            RequirementSource::Path { install_path, .. } => {
-                Some(VersionId::from_path(install_path))
-            }
-            RequirementSource::Directory { install_path, .. } => {
-                Some(VersionId::from_directory(install_path))
-            }
+            } => Some(Self::Editable),
+            RequirementSource::Directory { .. } => Some(Self::Directory),
+            RequirementSource::Registry { .. }
+            | RequirementSource::Url { .. }
+            | RequirementSource::Path { .. } => None,
+        }
+    }
+}
+
+impl Display for UnhashableSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Git => f.write_str("a Git repository"),
+            Self::Directory => f.write_str("a local directory"),
+            Self::Editable => f.write_str("an editable directory"),
+        }
+    }
+}
+
+/// Describe a requirement as it was written, along with the file that provided it, if any.
+fn describe(requirement: &UnresolvedRequirement) -> String {
+    let (label, origin) = match requirement {
+        UnresolvedRequirement::Named(requirement) => {
+            (requirement.to_string(), requirement.origin.as_ref())
+        }
+        UnresolvedRequirement::Unnamed(requirement) => {
+            let label = if let Some(given) = requirement.url.verbatim.given() {
+                if requirement.extras.is_empty() {
+                    given.to_string()
+                } else {
+                    format!("{given}[{}]", requirement.extras.iter().join(","))
+                }
+            } else {
+                requirement.to_string()
+            };
+            (label, requirement.origin.as_ref())
+        }
+    };
+    match origin {
+        Some(RequirementOrigin::File(path)) => {
+            format!("`{label}` (from `{}`)", path.user_display())
         }
+        _ => format!("`{label}`"),
     }
 }
 
@@ -417,7 +506,7 @@ fn merge_hashes(
         return Ok(());
     }
 
-    if !matches!(&id, VersionId::ArchiveUrl { .. }) {
+    if !matches!(&id, VersionId::ArchiveUrl { .. } | VersionId::Path(_)) {
         hashes.insert(id, incoming);
         return Ok(());
     }
@@ -475,6 +564,10 @@ pub enum HashStrategyError {
     UnpinnedRequirement(String, HashCheckingMode),
     #[error("In `{1}` mode, all requirements must have a hash, but none were provided for: {0}")]
     MissingHashes(String, HashCheckingMode),
+    #[error(
+        "In `{2}` mode, all requirements must refer to an archive that can be hashed, but {0} refers to {1}"
+    )]
+    UnhashableRequirement(String, UnhashableSource, HashCheckingMode),
     #[error(
         "In `{1}` mode, all requirements must have a hash, but there were no overlapping hashes between the requirements and constraints for: {0}"
     )]
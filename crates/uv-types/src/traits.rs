@@ -4,19 +4,21 @@ use std::ops::Deref;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
-use rustc_hash::FxHashSet;
 
-use uv_cache::Cache;
-use uv_configuration::{BuildKind, BuildOptions, BuildOutput, NoSources};
+use uv_cache::{Cache, CacheShard};
+use uv_configuration::{
+    BuildKind, BuildOptions, BuildOutput, BuildRuntime, Concurrency, NoSources,
+};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    CachedDist, ConfigSettings, DependencyMetadata, DistributionId, ExtraBuildRequires,
-    ExtraBuildVariables, IndexCapabilities, IndexLocations, InstalledDist, IsBuildBackendError,
-    PackageConfigSettings, Requirement, SourceDist,
+    BuildDependencyLock, CachedDist, ConfigSettings, DependencyMetadata, DistributionId,
+    ExtraBuildRequires, ExtraBuildVariables, IndexCapabilities, IndexLocations, InstalledDist,
+    IsBuildBackendError, PackageConfigSettings, Requirement, SourceDist,
 };
 use uv_git::GitResolver;
 use uv_normalize::PackageName;
-use uv_python::{Interpreter, PythonEnvironment};
+use uv_pep440::Version;
+use uv_python::{Interpreter, PythonEnvironment, PythonRequest};
 use uv_workspace::WorkspaceCache;
 
 use crate::{BuildArena, BuildIsolation, ResolvedRequirements};
@@ -117,9 +119,16 @@ pub trait BuildContext {
     /// This method exists to avoid fetching source distributions if we know we can't build them.
     fn build_options(&self) -> &BuildOptions;
 
+    /// The settings that control how source distributions are built, e.g., the build timeout or
+    /// the target platform.
+    fn build_runtime(&self) -> &BuildRuntime;
+
     /// The isolation mode used for building source distributions.
     fn build_isolation(&self) -> BuildIsolation<'_>;
 
+    /// The concurrency limits, which are shared with any nested resolutions and builds.
+    fn concurrency(&self) -> &Concurrency;
+
     /// The [`ConfigSettings`] used to build distributions.
     fn config_settings(&self) -> &ConfigSettings;
 
@@ -146,10 +155,24 @@ pub trait BuildContext {
     /// Get the extra build variables.
     fn extra_build_variables(&self) -> &ExtraBuildVariables;
 
+    /// Get the locked build dependencies, if build dependencies should be locked.
+    fn build_dependency_lock(&self) -> Option<&BuildDependencyLock> {
+        None
+    }
+
+    /// Get the Python interpreter to build packages with if the target interpreter doesn't satisfy
+    /// their `requires-python`, if any.
+    fn build_python(&self) -> Option<&PythonRequest> {
+        None
+    }
+
     /// Resolve the given requirements into a ready-to-install set of package versions.
+    ///
+    /// The given constraints are applied in addition to any build constraints.
     fn resolve<'a>(
         &'a self,
         requirements: &'a [Requirement],
+        constraints: &'a [Requirement],
         build_stack: &'a BuildStack,
     ) -> impl Future<Output = Result<ResolvedRequirements, impl IsBuildBackendError>> + 'a;
 
@@ -169,6 +192,8 @@ pub trait BuildContext {
     ///
     /// `version_id` is for error reporting only.
     /// `dist` is for safety checks and may be null for editable builds.
+    /// `cache_shard` is the cache shard for the source distribution revision, if any, in which the
+    /// results of `get_requires_for_build_wheel` are cached across builds.
     fn setup_build<'a>(
         &'a self,
         source: &'a Path,
@@ -177,6 +202,7 @@ pub trait BuildContext {
         stop_discovery_at: Option<&'a Path>,
         version_id: Option<&'a str>,
         dist: Option<&'a SourceDist>,
+        cache_shard: Option<&'a CacheShard>,
         sources: &'a NoSources,
         build_kind: BuildKind,
         build_output: BuildOutput,
@@ -310,17 +336,44 @@ impl Deref for AnyErrorBuild {
     }
 }
 
-/// The stack of packages being built.
+/// The stack of packages being built, in the order in which the builds were entered.
 #[derive(Debug, Clone, Default)]
-pub struct BuildStack(FxHashSet<DistributionId>);
+pub struct BuildStack(Vec<(DistributionId, String)>);
 
 impl BuildStack {
     pub fn contains(&self, id: &DistributionId) -> bool {
-        self.0.contains(id)
+        self.0.iter().any(|(entry, _)| entry == id)
     }
 
     /// Push a package onto the stack.
-    pub fn insert(&mut self, id: DistributionId) -> bool {
-        self.0.insert(id)
+    ///
+    /// Returns `false` if the package is already on the stack.
+    pub fn insert(
+        &mut self,
+        id: DistributionId,
+        name: &PackageName,
+        version: Option<&Version>,
+    ) -> bool {
+        if self.contains(&id) {
+            return false;
+        }
+        let label = match version {
+            Some(version) => format!("{name} {version}"),
+            None => name.to_string(),
+        };
+        self.0.push((id, label));
+        true
+    }
+
+    /// Return the cycle formed by re-entering the build of the given package, e.g.,
+    /// `["a 1.0", "b 2.0", "a 1.0"]`, if the package is already on the stack.
+    pub fn cycle(&self, id: &DistributionId) -> Option<Vec<String>> {
+        let start = self.0.iter().position(|(entry, _)| entry == id)?;
+        let mut cycle = self.0[start..]
+            .iter()
+            .map(|(_, label)| label.clone())
+            .collect::<Vec<_>>();
+        cycle.push(self.0[start].1.clone());
+        Some(cycle)
     }
 }
@@ -83,6 +83,10 @@ pub fn create_venv(
     seed: bool,
     upgradeable: bool,
 ) -> Result<PythonEnvironment, Error> {
+    // Warn if the interpreter was built for a different libc than the system, as the environment
+    // would otherwise select wheels that can't be loaded.
+    interpreter.warn_on_libc_mismatch();
+
     // Create the virtualenv at the given location.
     let virtualenv = virtualenv::create(
         location,
@@ -21,7 +21,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 use tracing::instrument;
 use uv_build_backend::BuildBackendSettings;
-use uv_configuration::{ExcludeDependency, GitLfsSetting, Override};
+use uv_configuration::{ExcludeDependency, GitLfsSetting, Override, Replacement};
 use uv_distribution_types::{Index, IndexName, RequirementSource};
 use uv_fs::{PortablePathBuf, relative_to};
 use uv_git_types::GitReference;
@@ -197,12 +197,12 @@ pub struct Project {
     /// The optional dependencies of the project.
     pub optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
 
-    /// Used to determine whether a `gui-scripts` section is present.
+    /// The GUI scripts of the project, mapping script names to object references.
     #[serde(default, skip_serializing)]
-    gui_scripts: Option<serde::de::IgnoredAny>,
-    /// Used to determine whether a `scripts` section is present.
+    pub(crate) gui_scripts: Option<BTreeMap<String, String>>,
+    /// The console scripts of the project, mapping script names to object references.
     #[serde(default, skip_serializing)]
-    scripts: Option<serde::de::IgnoredAny>,
+    pub(crate) scripts: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -215,8 +215,8 @@ struct ProjectWire {
     dependencies: Option<Vec<String>>,
     #[serde(default, deserialize_with = "deserialize_optional_dependencies")]
     optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
-    gui_scripts: Option<serde::de::IgnoredAny>,
-    scripts: Option<serde::de::IgnoredAny>,
+    gui_scripts: Option<BTreeMap<String, String>>,
+    scripts: Option<BTreeMap<String, String>>,
 }
 
 impl TryFrom<ProjectWire> for Project {
@@ -292,6 +292,23 @@ where
 /// An override dependency before source lowering.
 pub type OverrideDependency = Override<uv_pep508::Requirement<VerbatimParsedUrl>>;
 
+/// A package replacement before source lowering.
+pub type ReplaceDependency = Replacement<uv_pep508::Requirement<VerbatimParsedUrl>>;
+
+/// The schema for a package replacement, i.e., a requirement string or a table with `requirement`
+/// and `provides`.
+#[cfg(feature = "schemars")]
+#[derive(schemars::JsonSchema)]
+#[schemars(untagged)]
+#[expect(dead_code)]
+enum ReplaceDependencySchema {
+    Requirement(String),
+    Table {
+        requirement: String,
+        provides: Option<String>,
+    },
+}
+
 // NOTE(charlie): When adding fields to this struct, mark them as ignored on `Options` in
 // `crates/uv-settings/src/settings.rs`.
 #[derive(Deserialize, OptionsMetadata, Debug, Clone, PartialEq, Eq)]
@@ -522,6 +539,38 @@ pub struct ToolUv {
     )]
     pub(crate) exclude_dependencies: Option<Vec<ExcludeDependency>>,
 
+    /// Packages to replace wherever they appear in the dependency graph.
+    ///
+    /// Each entry maps the name of a package to a requirement that is substituted for every
+    /// dependency on that package, including transitive dependencies, similar to Cargo's
+    /// `[patch]`. For example, replacements can be used to swap in an organization-internal fork
+    /// of a package. The replacement can be a package from a registry (e.g., `acme-requests`) or,
+    /// via `tool.uv.sources`, a Git repository or local path.
+    ///
+    /// By default, version specifiers on the replaced package are ignored. To validate them,
+    /// declare the version of the replaced package that the replacement provides via `provides`.
+    ///
+    /// Replacements are read from all workspace members, and must not conflict with one another.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<BTreeMap<PackageName, ReplaceDependencySchema>>",
+            description = "A map from package names to PEP 508-style requirements, e.g., `acme-requests>=2.31`, or tables with `requirement` and `provides`."
+        )
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str | dict]",
+        example = r#"
+            [tool.uv.replace]
+            # Use an internal fork wherever `requests` is requested.
+            requests = "acme-requests"
+            # Validate `urllib3` version specifiers against the version provided by the fork.
+            urllib3 = { requirement = "acme-urllib3", provides = "2.2.1" }
+        "#
+    )]
+    pub(crate) replace: Option<BTreeMap<PackageName, ReplaceDependency>>,
+
     /// Constraints to apply when resolving the project's dependencies.
     ///
     /// Constraints are used to restrict the versions of dependencies that are selected during
@@ -585,6 +634,27 @@ pub struct ToolUv {
     pub(crate) build_constraint_dependencies:
         Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
 
+    /// Whether to lock the build dependencies of source distributions alongside the runtime
+    /// dependencies.
+    ///
+    /// When enabled, `uv lock` resolves the build dependencies of every package that can be built
+    /// from source and records them (including their versions or sources, and hashes) for the current
+    /// Python version and platform, and `uv sync` constrains the build environment to exactly those
+    /// build dependencies, failing if they're no longer available. Run `uv lock --upgrade` to refresh
+    /// the locked build dependencies.
+    ///
+    /// !!! note
+    ///     In `uv lock`, `uv sync`, and `uv run`, uv will only read `lock-build-dependencies` from
+    ///     the `pyproject.toml` at the workspace root.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            lock-build-dependencies = true
+        "#
+    )]
+    pub(crate) lock_build_dependencies: Option<bool>,
+
     /// A list of supported environments against which to resolve dependencies.
     ///
     /// By default, uv will resolve for all possible environments during a `uv lock` operation.
@@ -840,6 +840,116 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Set the version specifiers for an existing dependency, retaining its name, extras, and
+    /// marker.
+    pub fn set_dependency_specifiers(
+        &mut self,
+        dependency_type: &DependencyType,
+        index: usize,
+        specifiers: VersionSpecifiers,
+    ) -> Result<(), Error> {
+        let group = match dependency_type {
+            DependencyType::Production => self.dependencies_array()?,
+            DependencyType::Dev => self.dev_dependencies_array()?,
+            DependencyType::Optional(extra) => self.optional_dependencies_array(extra)?,
+            DependencyType::Group(group) => self.dependency_groups_array(group)?,
+        };
+
+        let Some(req) = group.get(index) else {
+            return Err(Error::MissingDependency(index));
+        };
+
+        let mut req = req
+            .as_str()
+            .and_then(try_parse_requirement)
+            .ok_or(Error::MalformedDependencies)?;
+        req.version_or_url = if specifiers.is_empty() {
+            None
+        } else {
+            Some(VersionOrUrl::VersionSpecifier(specifiers))
+        };
+        group.replace(index, req.to_string());
+
+        Ok(())
+    }
+
+    /// Returns all dependencies declared in `project.dependencies`,
+    /// `project.optional-dependencies`, `dependency-groups`, and `tool.uv.dev-dependencies`,
+    /// along with their position in the containing array.
+    ///
+    /// Entries that fail to parse as requirements (e.g., `include-group` tables) are skipped.
+    pub fn dependencies(&self) -> Vec<(DependencyType, usize, Requirement)> {
+        fn parse(array: &Array) -> impl Iterator<Item = (usize, Requirement)> + '_ {
+            array.iter().enumerate().filter_map(|(index, dep)| {
+                dep.as_str()
+                    .and_then(try_parse_requirement)
+                    .map(|req| (index, req))
+            })
+        }
+
+        let mut dependencies = Vec::new();
+
+        if let Some(project) = self.doc.get("project").and_then(Item::as_table) {
+            if let Some(array) = project.get("dependencies").and_then(Item::as_array) {
+                dependencies.extend(
+                    parse(array).map(|(index, req)| (DependencyType::Production, index, req)),
+                );
+            }
+
+            if let Some(extras) = project
+                .get("optional-dependencies")
+                .and_then(Item::as_table_like)
+            {
+                for (extra, array) in extras.iter() {
+                    let Some(array) = array.as_array() else {
+                        continue;
+                    };
+                    let Ok(extra) = ExtraName::from_str(extra) else {
+                        continue;
+                    };
+                    dependencies.extend(
+                        parse(array).map(|(index, req)| {
+                            (DependencyType::Optional(extra.clone()), index, req)
+                        }),
+                    );
+                }
+            }
+        }
+
+        if let Some(groups) = self
+            .doc
+            .get("dependency-groups")
+            .and_then(Item::as_table_like)
+        {
+            for (group, array) in groups.iter() {
+                let Some(array) = array.as_array() else {
+                    continue;
+                };
+                let Ok(group) = GroupName::from_str(group) else {
+                    continue;
+                };
+                dependencies.extend(
+                    parse(array)
+                        .map(|(index, req)| (DependencyType::Group(group.clone()), index, req)),
+                );
+            }
+        }
+
+        if let Some(array) = self
+            .doc
+            .get("tool")
+            .and_then(Item::as_table)
+            .and_then(|tool| tool.get("uv"))
+            .and_then(Item::as_table)
+            .and_then(|uv| uv.get("dev-dependencies"))
+            .and_then(Item::as_array)
+        {
+            dependencies.extend(parse(array).map(|(index, req)| (DependencyType::Dev, index, req)));
+        }
+
+        dependencies
+    }
+
     /// Get the TOML array for `project.dependencies`.
     fn dependencies_array(&mut self) -> Result<&mut Array, Error> {
         // Get or create `project.dependencies`.
@@ -6,6 +6,7 @@ use std::fmt;
 use std::fmt::Display;
 use std::hash::BuildHasherDefault;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use glob::{GlobError, PatternError, glob};
@@ -21,14 +22,17 @@ use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
 use uv_once_map::OnceMap;
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::{MarkerTree, VerbatimUrl};
-use uv_pypi_types::{ConflictError, Conflicts, SupportedEnvironments, VerbatimParsedUrl};
+use uv_pypi_types::{
+    ConflictError, ConflictKind, Conflicts, ModuleName, ObjectReference, ObjectReferenceParseError,
+    SupportedEnvironments, VerbatimParsedUrl,
+};
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
 use crate::pyproject::{
-    OverrideDependency, Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources,
-    ToolUvWorkspace,
+    OverrideDependency, Project, PyProjectToml, PyprojectTomlError, ReplaceDependency, Source,
+    Sources, ToolUvSources, ToolUvWorkspace,
 };
 
 /// The workspace project environment selected by configuration and command-line options.
@@ -151,6 +155,39 @@ fn has_intermediate_pyproject(workspace_root: &Path, project_dir: &Path) -> bool
         .any(|ancestor| ancestor.join("pyproject.toml").is_file())
 }
 
+/// Whether the module exists in the source tree of the project, either in a `src` layout or in a
+/// flat layout.
+///
+/// This is a best-effort check: a module exists if there's a matching package directory, or a file
+/// with a matching stem, such as a Python source file or an extension module.
+fn module_exists(project_root: &Path, module: &ModuleName) -> bool {
+    let components = module.as_ref().split('.').collect::<Vec<_>>();
+    let Some((name, parents)) = components.split_last() else {
+        return false;
+    };
+    [project_root.join("src"), project_root.to_path_buf()]
+        .into_iter()
+        .any(|root| {
+            let parent = parents
+                .iter()
+                .fold(root, |parent, component| parent.join(component));
+            if parent.join(name).is_dir() {
+                return true;
+            }
+            let Ok(entries) = fs_err::read_dir(&parent) else {
+                return false;
+            };
+            entries.flatten().any(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|file_name| file_name.split_once('.'))
+                    .is_some_and(|(stem, _)| stem == *name)
+                    && entry.path().is_file()
+            })
+        })
+}
+
 #[derive(Debug, Clone)]
 pub struct WorkspaceError(Arc<WorkspaceErrorKind>);
 
@@ -204,6 +241,20 @@ pub enum WorkspaceErrorKind {
         first: PathBuf,
         second: PathBuf,
     },
+    #[error("Invalid entry point `{name}` in `{table}` of `{}`", path.user_display())]
+    InvalidEntryPoint {
+        path: PathBuf,
+        table: &'static str,
+        name: String,
+        #[source]
+        err: ObjectReferenceParseError,
+    },
+    #[error("Two workspace members both define the script `{name}`, which would conflict when installed into the same environment: `{}` and `{}`", first.user_display(), second.user_display())]
+    DuplicateScript {
+        name: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
     #[error("pyproject.toml section is declared as dynamic, but must be static: `{0}`")]
     DynamicNotAllowed(&'static str),
     #[error(
@@ -222,6 +273,12 @@ pub enum WorkspaceErrorKind {
     Toml(PathBuf, #[source] Box<PyprojectTomlError>),
     #[error(transparent)]
     Conflicts(#[from] ConflictError),
+    #[error("Conflicting replacements for `{name}` in `tool.uv.replace`: `{first}` and `{second}`")]
+    ConflictingReplacement {
+        name: PackageName,
+        first: Box<uv_pep508::Requirement<VerbatimParsedUrl>>,
+        second: Box<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    },
     // On Windows and Unix, this is not a regular IO failure, but requires e.g. `current_dir` to
     // fail.
     #[error("Failed to normalize workspace member path")]
@@ -733,6 +790,70 @@ impl Workspace {
         Ok(conflicting)
     }
 
+    /// Validate the `project.scripts` and `project.gui-scripts` entry points of all workspace
+    /// members.
+    ///
+    /// Returns an error for object references that aren't of the form `module:object`, and for
+    /// script names that are defined by multiple members, as all members are installed into the
+    /// same environment (unless they're declared as conflicting). Since modules may be generated
+    /// by the build backend, referenced modules that are missing from the source tree only raise a
+    /// warning.
+    pub fn validate_entry_points(&self) -> Result<(), WorkspaceError> {
+        let conflicts = self.conflicts()?;
+        let conflicting = |first: &PackageName, second: &PackageName| {
+            conflicts.iter().any(|set| {
+                [first, second].into_iter().all(|package| {
+                    set.iter().any(|item| {
+                        matches!(item.kind(), ConflictKind::Project) && item.package() == package
+                    })
+                })
+            })
+        };
+
+        let mut scripts: BTreeMap<&str, &WorkspaceMember> = BTreeMap::new();
+        for member in self.packages.values() {
+            let pyproject_toml = member.root.join("pyproject.toml");
+            for (table, entries) in [
+                ("project.scripts", &member.project.scripts),
+                ("project.gui-scripts", &member.project.gui_scripts),
+            ] {
+                for (name, value) in entries.iter().flatten() {
+                    let object_reference = ObjectReference::from_str(value).map_err(|err| {
+                        WorkspaceErrorKind::InvalidEntryPoint {
+                            path: pyproject_toml.clone(),
+                            table,
+                            name: name.clone(),
+                            err,
+                        }
+                    })?;
+
+                    if member.pyproject_toml.is_package(true)
+                        && !module_exists(&member.root, object_reference.module())
+                    {
+                        warn_user_once!(
+                            "The entry point `{name}` in `{table}` of `{}` references the module `{}`, which was not found in the project's source tree",
+                            pyproject_toml.user_display(),
+                            object_reference.module(),
+                        );
+                    }
+
+                    if let Some(first) = scripts.insert(name, member)
+                        && first.project.name != member.project.name
+                        && !conflicting(&first.project.name, &member.project.name)
+                    {
+                        return Err(WorkspaceErrorKind::DuplicateScript {
+                            name: name.clone(),
+                            first: first.root.join("pyproject.toml"),
+                            second: pyproject_toml,
+                        }
+                        .into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns an iterator over the `requires-python` values for each member of the workspace.
     pub fn requires_python(
         &self,
@@ -838,6 +959,44 @@ impl Workspace {
         excludes.clone()
     }
 
+    /// Returns the set of package replacements for the workspace.
+    ///
+    /// Unlike overrides, replacements may be declared by any workspace member, as they apply to
+    /// every dependency edge in the graph. Members that declare different replacements for the
+    /// same package are rejected.
+    pub fn replacements(&self) -> Result<BTreeMap<PackageName, ReplaceDependency>, WorkspaceError> {
+        let mut replacements: BTreeMap<PackageName, ReplaceDependency> = BTreeMap::new();
+        let pyprojects = std::iter::once(&self.pyproject_toml)
+            .chain(self.packages.values().map(|member| &member.pyproject_toml));
+        for pyproject_toml in pyprojects {
+            let Some(replace) = pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.replace.as_ref())
+            else {
+                continue;
+            };
+            for (name, replacement) in replace {
+                match replacements.get(name) {
+                    Some(existing) if existing != replacement => {
+                        return Err(WorkspaceErrorKind::ConflictingReplacement {
+                            name: name.clone(),
+                            first: Box::new(existing.requirement.clone()),
+                            second: Box::new(replacement.requirement.clone()),
+                        }
+                        .into());
+                    }
+                    Some(_) => {}
+                    None => {
+                        replacements.insert(name.clone(), replacement.clone());
+                    }
+                }
+            }
+        }
+        Ok(replacements)
+    }
+
     /// Returns the set of constraints for the workspace.
     pub fn constraints(&self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
         let Some(constraints) = self
@@ -866,6 +1025,16 @@ impl Workspace {
         build_constraints.clone()
     }
 
+    /// Returns `true` if the build dependencies of source distributions should be locked.
+    pub fn lock_build_dependencies(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.lock_build_dependencies)
+            .unwrap_or(false)
+    }
+
     /// The path to the workspace root, the directory containing the top level `pyproject.toml` with
     /// the `uv.tool.workspace`, or the `pyproject.toml` in an implicit single workspace project.
     pub fn install_path(&self) -> &PathBuf {
@@ -2506,8 +2675,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "replace": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "lock-build-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2607,8 +2778,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "replace": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "lock-build-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -2942,8 +3115,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "replace": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "lock-build-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -3052,8 +3227,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "replace": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "lock-build-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -3175,8 +3352,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "replace": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "lock-build-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -3272,8 +3451,10 @@ mod tests {
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "exclude-dependencies": null,
+                      "replace": null,
                       "constraint-dependencies": null,
                       "build-constraint-dependencies": null,
+                      "lock-build-dependencies": null,
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
@@ -14,7 +14,7 @@ use uv_build_backend::check_direct_build;
 use uv_cache::{Cache, CacheBucket};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildKind, BuildOptions, BuildOutput, Concurrency, Constraints,
+    BuildIsolation, BuildKind, BuildOptions, BuildOutput, BuildRuntime, Concurrency, Constraints,
     DependencyGroupsWithDefaults, HashCheckingMode, IndexStrategy, KeyringProviderType, NoSources,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
@@ -226,8 +226,16 @@ async fn build_impl(
         index_strategy,
         keyring_provider,
         resolution: _,
+        resolution_package: _,
         prerelease: _,
+        prerelease_package: _,
+        local_version_preference: _,
+        yanked: _,
         fork_strategy: _,
+        max_metadata_builds: _,
+        resolution_effort: _,
+        excluded_packages: _,
+        reresolve_metadata: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -235,9 +243,11 @@ async fn build_impl(
         extra_build_dependencies,
         extra_build_variables,
         exclude_newer,
+        strict_exclude_newer: _,
         link_mode,
         upgrade: _,
         build_options,
+        build_runtime,
         sources,
         torch_backend: _,
         cuda_driver_version: _,
@@ -416,6 +426,7 @@ async fn build_impl(
             sources.clone(),
             concurrency,
             build_options,
+            build_runtime,
             sdist,
             wheel,
             list,
@@ -532,6 +543,7 @@ async fn build_package(
     sources: NoSources,
     concurrency: &Concurrency,
     build_options: &BuildOptions,
+    build_runtime: &BuildRuntime,
     sdist: bool,
     wheel: bool,
     list: bool,
@@ -652,7 +664,7 @@ async fn build_package(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, None, &hasher, build_options)
+        FlatIndex::from_entries(entries, None, &hasher, build_options, index_locations)
     };
 
     // Initialize any shared state.
@@ -680,6 +692,7 @@ async fn build_package(
         extra_build_variables,
         link_mode,
         build_options,
+        build_runtime,
         &hasher,
         exclude_newer,
         sources.clone(),
@@ -1047,6 +1060,7 @@ async fn build_sdist(
                     None,
                     version_id,
                     dist,
+                    None,
                     sources,
                     BuildKind::Sdist,
                     build_output,
@@ -1154,6 +1168,7 @@ async fn build_wheel(
                     None,
                     version_id,
                     dist,
+                    None,
                     &sources,
                     BuildKind::Wheel,
                     build_output,
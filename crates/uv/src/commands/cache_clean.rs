@@ -1,13 +1,19 @@
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
 use tracing::debug;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, CleanPlan, Removal, removal_size};
+use uv_cli::CacheCleanFormat;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
+use uv_tool::InstalledTools;
 
+use crate::commands::project::registry::EnvironmentRegistry;
 use crate::commands::reporters::{CleaningDirectoryReporter, CleaningPackageReporter};
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
@@ -16,10 +22,22 @@ use crate::printer::Printer;
 pub(crate) async fn cache_clean(
     packages: &[PackageName],
     force: bool,
+    dry_run: bool,
+    preserve_referenced: bool,
+    output_format: CacheCleanFormat,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     if !cache.root().exists() {
+        if dry_run && matches!(output_format, CacheCleanFormat::Json) {
+            let report = DryRunReport::new(&cache, &CleanPlan::default());
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report)?
+            )?;
+            return Ok(ExitStatus::Success);
+        }
         writeln!(
             printer.stderr(),
             "No cache found at: {}",
@@ -28,6 +46,28 @@ pub(crate) async fn cache_clean(
         return Ok(ExitStatus::Success);
     }
 
+    // Under `--dry-run`, report the planned removals without locking or modifying the cache.
+    if dry_run {
+        let preserve = if preserve_referenced {
+            find_referenced_archives(&cache, packages.is_empty())?
+        } else {
+            FxHashSet::default()
+        };
+        let plan = plan_clean(&cache, packages, &preserve)?;
+        let report = DryRunReport::new(&cache, &plan);
+        match output_format {
+            CacheCleanFormat::Text => report.write_text(printer)?,
+            CacheCleanFormat::Json => {
+                writeln!(
+                    printer.stdout(),
+                    "{}",
+                    serde_json::to_string_pretty(&report)?
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
     let cache = match cache.with_exclusive_lock_no_wait() {
         Ok(cache) => cache,
         Err(cache) if force => {
@@ -43,6 +83,13 @@ pub(crate) async fn cache_clean(
         }
     };
 
+    let preserve = if preserve_referenced {
+        find_referenced_archives(&cache, packages.is_empty())?
+    } else {
+        FxHashSet::default()
+    };
+
+    let mut retained = Vec::new();
     let summary = if packages.is_empty() {
         writeln!(
             printer.stderr(),
@@ -50,19 +97,35 @@ pub(crate) async fn cache_clean(
             cache.root().user_display().cyan()
         )?;
 
-        let num_paths = walkdir::WalkDir::new(cache.root()).into_iter().count();
-        let reporter = CleaningDirectoryReporter::new(printer, Some(num_paths));
+        if preserve.is_empty() {
+            let num_paths = walkdir::WalkDir::new(cache.root()).into_iter().count();
+            let reporter = CleaningDirectoryReporter::new(printer, Some(num_paths));
 
-        let root = cache.root().to_path_buf();
-        cache
-            .clear(Box::new(reporter))
-            .with_context(|| format!("Failed to clear cache at: {}", root.user_display()))?
+            let root = cache.root().to_path_buf();
+            cache
+                .clear(Box::new(reporter))
+                .with_context(|| format!("Failed to clear cache at: {}", root.user_display()))?
+        } else {
+            let plan = cache.plan_clear(&preserve)?;
+            let summary = plan.execute().with_context(|| {
+                format!("Failed to clear cache at: {}", cache.root().user_display())
+            })?;
+            retained.extend(plan.preserved);
+            summary
+        }
     } else {
         let reporter = CleaningPackageReporter::new(printer, Some(packages.len()));
         let mut summary = Removal::default();
 
         for package in packages {
-            let removed = cache.remove(package)?;
+            let removed = if preserve.is_empty() {
+                cache.remove(package)?
+            } else {
+                let plan = cache.plan_remove(package, &preserve)?;
+                let removed = plan.execute()?;
+                retained.extend(plan.preserved);
+                removed
+            };
             summary += removed;
             reporter.on_clean(package.as_str(), &summary);
         }
@@ -92,16 +155,209 @@ pub(crate) async fn cache_clean(
 
     // If any, write a summary of the total byte count removed.
     if summary.total_bytes > 0 {
-        let bytes = if summary.total_bytes < 1024 {
-            format!("{}B", summary.total_bytes)
-        } else {
-            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
-            format!("{bytes:.1}{unit}")
-        };
-        write!(printer.stderr(), " ({})", bytes.green())?;
+        write!(
+            printer.stderr(),
+            " ({})",
+            format_bytes(summary.total_bytes).green()
+        )?;
     }
 
     writeln!(printer.stderr())?;
 
+    // If any, write a summary of the entries retained due to `--preserve-referenced`.
+    retained.sort();
+    retained.dedup();
+    if !retained.is_empty() {
+        let bytes = retained.iter().map(removal_size).sum();
+        writeln!(
+            printer.stderr(),
+            "Retained {} referenced by existing environments ({})",
+            format_entries(retained.len()),
+            format_bytes(bytes).green()
+        )?;
+    }
+
     Ok(ExitStatus::Success)
 }
+
+/// Determine the entries that would be removed when cleaning the given packages (or the entire
+/// cache, if no packages are provided).
+fn plan_clean(
+    cache: &Cache,
+    packages: &[PackageName],
+    preserve: &FxHashSet<PathBuf>,
+) -> Result<CleanPlan> {
+    if packages.is_empty() {
+        return Ok(cache.plan_clear(preserve)?);
+    }
+
+    let mut plan = CleanPlan::default();
+    for package in packages {
+        let CleanPlan { entries, preserved } = cache.plan_remove(package, preserve)?;
+        plan.entries.extend(entries);
+        plan.preserved.extend(preserved);
+    }
+    for paths in [&mut plan.entries, &mut plan.preserved] {
+        paths.sort();
+        paths.dedup();
+    }
+    Ok(plan)
+}
+
+/// Find the archives in the cache that are referenced by known environments, i.e., registered
+/// project environments and installed tools.
+///
+/// When clearing the entire cache, environments within the cache are ignored, since they'll be
+/// removed along with it.
+fn find_referenced_archives(cache: &Cache, clear: bool) -> Result<FxHashSet<PathBuf>> {
+    let mut environments = EnvironmentRegistry::from_settings()
+        .environments()
+        .unwrap_or_else(|err| {
+            debug!("Failed to read the environment registry: {err}");
+            Vec::new()
+        });
+
+    match InstalledTools::from_settings().and_then(|installed_tools| {
+        Ok(installed_tools
+            .tools()?
+            .into_iter()
+            .map(|(name, _)| installed_tools.tool_dir(&name))
+            .collect::<Vec<_>>())
+    }) {
+        Ok(tools) => environments.extend(tools),
+        Err(err) => debug!("Failed to read installed tools: {err}"),
+    }
+
+    if clear {
+        environments.retain(|environment| !environment.starts_with(cache.root()));
+    }
+
+    for environment in &environments {
+        debug!(
+            "Searching for cache references in: {}",
+            environment.user_display()
+        );
+    }
+
+    Ok(cache.find_environment_references(&environments)?)
+}
+
+/// The entries that `uv cache clean --dry-run` would remove.
+#[derive(Debug, Serialize)]
+struct DryRunReport {
+    /// The root of the cache.
+    cache_dir: PathBuf,
+    /// The entries that would be removed.
+    entries: Vec<DryRunEntry>,
+    /// The total size of the entries that would be removed, in bytes.
+    total_bytes: u64,
+    /// The entries that would be retained, as they're referenced by an existing environment.
+    preserved: Vec<DryRunEntry>,
+    /// The total size of the entries that would be retained, in bytes.
+    preserved_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct DryRunEntry {
+    /// The path to the cache entry.
+    path: PathBuf,
+    /// The size of the cache entry, in bytes.
+    size: u64,
+}
+
+impl DryRunReport {
+    fn new(cache: &Cache, plan: &CleanPlan) -> Self {
+        let entries = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .map(|path| DryRunEntry {
+                    path: path.clone(),
+                    size: removal_size(path),
+                })
+                .collect::<Vec<_>>()
+        };
+        let entries_to_remove = entries(&plan.entries);
+        let preserved = entries(&plan.preserved);
+        Self {
+            cache_dir: cache.root().to_path_buf(),
+            total_bytes: entries_to_remove.iter().map(|entry| entry.size).sum(),
+            entries: entries_to_remove,
+            preserved_bytes: preserved.iter().map(|entry| entry.size).sum(),
+            preserved,
+        }
+    }
+
+    /// Write a human-readable summary of the planned removals.
+    fn write_text(&self, printer: Printer) -> Result<()> {
+        let canonical_root = fs_err::canonicalize(&self.cache_dir).ok();
+        let display = |path: &Path| {
+            path.strip_prefix(&self.cache_dir)
+                .ok()
+                .or_else(|| {
+                    canonical_root
+                        .as_ref()
+                        .and_then(|root| path.strip_prefix(root).ok())
+                })
+                .map(|relative| relative.portable_display().to_string())
+                .unwrap_or_else(|| path.user_display().to_string())
+        };
+
+        if self.entries.is_empty() {
+            writeln!(printer.stderr(), "No cache entries found")?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Would remove {} from cache at: {} ({})",
+                format_entries(self.entries.len()),
+                self.cache_dir.user_display().cyan(),
+                format_bytes(self.total_bytes).green()
+            )?;
+            for entry in &self.entries {
+                writeln!(
+                    printer.stderr(),
+                    "  {} ({})",
+                    display(&entry.path),
+                    format_bytes(entry.size)
+                )?;
+            }
+        }
+
+        if !self.preserved.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "Would retain {} referenced by existing environments ({})",
+                format_entries(self.preserved.len()),
+                format_bytes(self.preserved_bytes).green()
+            )?;
+            for entry in &self.preserved {
+                writeln!(
+                    printer.stderr(),
+                    "  {} ({})",
+                    display(&entry.path),
+                    format_bytes(entry.size)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Format a number of cache entries, e.g., `1 entry` or `2 entries`.
+fn format_entries(count: usize) -> String {
+    if count == 1 {
+        "1 entry".to_string()
+    } else {
+        format!("{count} entries")
+    }
+}
+
+/// Format a byte count for display, e.g., `512B` or `1.5KiB`.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
@@ -6,7 +6,7 @@ use rustc_hash::FxHashMap;
 use version_ranges::Ranges;
 
 use uv_distribution_types::{
-    DerivationChain, DerivationStep, Dist, DistErrorKind, Name, RequestedDist,
+    DerivationChain, DerivationStep, Dist, DistErrorKind, Name, RequestedDist, SourceBuildReason,
 };
 use uv_errors::{Hint, Hints};
 use uv_normalize::PackageName;
@@ -88,9 +88,21 @@ impl OperationDiagnostic {
                 kind,
                 dist,
                 chain,
+                reason,
                 err,
             )) => {
-                requested_dist_error(kind, dist, &chain, err);
+                requested_dist_error(kind, dist, &chain, reason, err);
+                None
+            }
+            pip::operations::Error::Resolve(uv_resolver::ResolveError::Multiple(errors)) => {
+                for err in errors {
+                    if let uv_resolver::ResolveError::Dist(kind, dist, chain, reason, err) = err {
+                        requested_dist_error(kind, dist, &chain, reason, err);
+                    } else {
+                        let report = miette::Report::from_err(err);
+                        anstream::eprint!("{report:?}");
+                    }
+                }
                 None
             }
             pip::operations::Error::Resolve(uv_resolver::ResolveError::Dependencies(
@@ -102,17 +114,41 @@ impl OperationDiagnostic {
                 dependencies_error(error, &name, &version, &chain);
                 None
             }
+            pip::operations::Error::Resolve(uv_resolver::ResolveError::NarrowedRequiresPython(
+                err,
+            )) => {
+                narrowed_requires_python_error(&err);
+                None
+            }
             pip::operations::Error::Requirements(uv_requirements::Error::Dist(kind, dist, err)) => {
-                dist_error(kind, dist, &DerivationChain::default(), Arc::new(*err));
+                dist_error(
+                    kind,
+                    dist,
+                    &DerivationChain::default(),
+                    None,
+                    Arc::new(*err),
+                );
                 None
             }
             pip::operations::Error::Prepare(uv_installer::PrepareError::Dist(
                 kind,
                 dist,
                 chain,
+                reason,
                 err,
             )) => {
-                dist_error(kind, dist, &chain, Arc::new(*err));
+                dist_error(kind, dist, &chain, reason, Arc::new(*err));
+                None
+            }
+            pip::operations::Error::Prepare(uv_installer::PrepareError::Multiple(errors)) => {
+                for err in errors {
+                    if let uv_installer::PrepareError::Dist(kind, dist, chain, reason, err) = err {
+                        dist_error(kind, dist, &chain, reason, Arc::new(*err));
+                    } else {
+                        let report = miette::Report::from_err(err);
+                        anstream::eprint!("{report:?}");
+                    }
+                }
                 None
             }
             pip::operations::Error::Requirements(err) => {
@@ -155,6 +191,7 @@ fn dist_error(
     kind: DistErrorKind,
     dist: Box<Dist>,
     chain: &DerivationChain,
+    reason: Option<SourceBuildReason>,
     cause: Arc<uv_distribution::Error>,
 ) {
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
@@ -167,7 +204,7 @@ fn dist_error(
         cause: Arc<uv_distribution::Error>,
     }
 
-    let hints = dist_hints(dist.name(), dist.version(), chain, cause.hints());
+    let hints = dist_hints(dist.name(), dist.version(), chain, reason, cause.hints());
     let report = miette::Report::new(Diagnostic { kind, dist, cause });
     anstream::eprint!("{report:?}");
     anstream::eprint!("{hints}");
@@ -180,6 +217,7 @@ fn requested_dist_error(
     kind: DistErrorKind,
     dist: Box<RequestedDist>,
     chain: &DerivationChain,
+    reason: Option<SourceBuildReason>,
     cause: Arc<uv_distribution::Error>,
 ) {
     #[derive(Debug, miette::Diagnostic, thiserror::Error)]
@@ -192,7 +230,7 @@ fn requested_dist_error(
         cause: Arc<uv_distribution::Error>,
     }
 
-    let hints = dist_hints(dist.name(), dist.version(), chain, cause.hints());
+    let hints = dist_hints(dist.name(), dist.version(), chain, reason, cause.hints());
     let report = miette::Report::new(Diagnostic { kind, dist, cause });
     anstream::eprint!("{report:?}");
     anstream::eprint!("{hints}");
@@ -217,7 +255,7 @@ fn dependencies_error(
         cause: Box<uv_resolver::ResolveError>,
     }
 
-    let hints = dist_hints(name, Some(version), chain, error.hints());
+    let hints = dist_hints(name, Some(version), chain, None, error.hints());
     let report = miette::Report::new(Diagnostic {
         name: name.clone(),
         version: version.clone(),
@@ -227,6 +265,15 @@ fn dependencies_error(
     anstream::eprint!("{hints}");
 }
 
+/// Render a dependency that doesn't support the full `requires-python` range, along with the
+/// packages that pulled it in.
+fn narrowed_requires_python_error(err: &uv_resolver::NarrowedRequiresPythonError) {
+    let hints = dist_hints(&err.name, Some(&err.version), &err.chain, None, err.hints());
+    let report = miette::Report::msg(err.to_string());
+    anstream::eprint!("{report:?}");
+    anstream::eprint!("{hints}");
+}
+
 /// Render a [`uv_resolver::NoSolutionError`].
 fn no_solution(err: &uv_resolver::NoSolutionError, context: Option<&'static str>) {
     let header = if let Some(context) = context {
@@ -330,6 +377,7 @@ fn dist_hints(
     name: &PackageName,
     version: Option<&Version>,
     chain: &DerivationChain,
+    reason: Option<SourceBuildReason>,
     cause_hints: Hints<'_>,
 ) -> Hints<'static> {
     let mut hints = Hints::none();
@@ -343,10 +391,35 @@ fn dist_hints(
     } else if !chain.is_empty() {
         hints.push(format_chain(name, version, chain));
     }
+    if let Some(reason) = reason {
+        hints.push(format_source_build_reason(name, version, reason));
+    }
     hints.extend(cause_hints);
     hints.into_owned()
 }
 
+/// Format a [`SourceBuildReason`] as a human-readable error message.
+fn format_source_build_reason(
+    name: &PackageName,
+    version: Option<&Version>,
+    reason: SourceBuildReason,
+) -> String {
+    let package = if let Some(version) = version {
+        format!("`{}` ({})", name.cyan(), format!("v{version}").cyan())
+    } else {
+        format!("`{}`", name.cyan())
+    };
+    match reason {
+        SourceBuildReason::NoBinary => format!(
+            "{package} was built from source because using pre-built wheels is disabled (e.g., with `{}`)",
+            "--no-binary".green(),
+        ),
+        SourceBuildReason::IncompatibleWheels => format!(
+            "{package} was built from source because none of its published wheels are compatible with the current platform",
+        ),
+    }
+}
+
 /// Format a [`DerivationChain`] as a human-readable error message.
 fn format_chain(name: &PackageName, version: Option<&Version>, chain: &DerivationChain) -> String {
     /// Format a step in the [`DerivationChain`] as a human-readable error message.
@@ -469,10 +542,80 @@ fn format_chain(name: &PackageName, version: Option<&Version>, chain: &Derivatio
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
+    use std::str::FromStr;
+
+    use version_ranges::Ranges;
 
+    use uv_distribution_types::{DerivationChain, DerivationStep, SourceBuildReason};
+    use uv_errors::Hints;
+    use uv_normalize::{ExtraName, PackageName};
+    use uv_pep440::Version;
     use uv_workspace::pyproject::{PyprojectTomlError, SourceError};
 
-    use super::hints_for_error;
+    use super::{dist_hints, hints_for_error};
+
+    /// Render the hints for a build failure of `pygraphviz==1.11`, required by
+    /// `networkx[extra]`, which is required by the project.
+    fn build_failure_hints(reason: Option<SourceBuildReason>) -> Vec<String> {
+        let chain = DerivationChain::from_iter([
+            DerivationStep::new(
+                PackageName::from_str("project").unwrap(),
+                None,
+                None,
+                Some(Version::new([0, 1, 0])),
+                Ranges::full(),
+            ),
+            DerivationStep::new(
+                PackageName::from_str("networkx").unwrap(),
+                Some(ExtraName::from_str("extra").unwrap()),
+                None,
+                Some(Version::new([3, 2])),
+                Ranges::full(),
+            ),
+        ]);
+        dist_hints(
+            &PackageName::from_str("pygraphviz").unwrap(),
+            Some(&Version::new([1, 11])),
+            &chain,
+            reason,
+            Hints::none(),
+        )
+        .into_iter()
+        .map(|hint| anstream::adapter::strip_str(&hint).to_string())
+        .collect()
+    }
+
+    #[test]
+    fn build_failure_without_wheels() {
+        assert_eq!(
+            build_failure_hints(None),
+            vec![
+                "`pygraphviz` (v1.11) was included because `project` (v0.1.0) depends on `networkx[extra]` (v3.2) which depends on `pygraphviz`".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_failure_with_incompatible_wheels() {
+        assert_eq!(
+            build_failure_hints(Some(SourceBuildReason::IncompatibleWheels)),
+            vec![
+                "`pygraphviz` (v1.11) was included because `project` (v0.1.0) depends on `networkx[extra]` (v3.2) which depends on `pygraphviz`".to_string(),
+                "`pygraphviz` (v1.11) was built from source because none of its published wheels are compatible with the current platform".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_failure_with_no_binary() {
+        assert_eq!(
+            build_failure_hints(Some(SourceBuildReason::NoBinary)),
+            vec![
+                "`pygraphviz` (v1.11) was included because `project` (v0.1.0) depends on `networkx[extra]` (v3.2) which depends on `pygraphviz`".to_string(),
+                "`pygraphviz` (v1.11) was built from source because using pre-built wheels is disabled (e.g., with `--no-binary`)".to_string(),
+            ]
+        );
+    }
 
     #[test]
     fn collects_source_hints_through_pyproject_errors() {
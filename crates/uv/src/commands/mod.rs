@@ -23,6 +23,7 @@ pub(crate) use cache_size::cache_size;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
+pub(crate) use pip::download::pip_download;
 pub(crate) use pip::freeze::pip_freeze;
 pub(crate) use pip::install::pip_install;
 pub(crate) use pip::list::pip_list;
@@ -37,6 +38,7 @@ pub(crate) use project::export::export;
 pub(crate) use project::format::format;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
 pub(crate) use project::lock::lock;
+pub(crate) use project::narrow_bounds::NarrowBounds;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{ParsedRunCommand, RunCommand, run};
 pub(crate) use project::sync::sync;
@@ -53,11 +55,13 @@ pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
+pub(crate) use self_dirs::self_dirs;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
+pub(crate) use tool::repair::repair as tool_repair;
 pub(crate) use tool::run::ToolRunCommand;
 pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
@@ -94,6 +98,7 @@ mod publish;
 mod pylock;
 mod python;
 pub(crate) mod reporters;
+mod self_dirs;
 #[cfg(feature = "self-update")]
 mod self_update;
 mod tool;
@@ -238,6 +243,8 @@ pub(super) enum ChangeEventKind {
     Added,
     /// The package was reinstalled without changing versions.
     Reinstalled,
+    /// The package is extraneous, but was kept in the environment.
+    Kept,
 }
 
 #[derive(Debug)]
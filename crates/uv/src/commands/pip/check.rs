@@ -2,12 +2,17 @@ use std::fmt::Write;
 use std::time::Instant;
 
 use anyhow::Result;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use uv_cache::Cache;
+use uv_cli::CheckFormat;
 use uv_configuration::TargetTriple;
 use uv_distribution_types::{DependencyMetadata, Diagnostic, InstalledDist};
 use uv_installer::{SitePackages, SitePackagesDiagnostic};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest, PythonVersion,
 };
@@ -19,6 +24,7 @@ use crate::printer::Printer;
 
 /// Check for incompatibilities in installed packages.
 pub(crate) fn pip_check(
+    format: CheckFormat,
     python: Option<&str>,
     system: bool,
     python_version: Option<&PythonVersion>,
@@ -56,14 +62,41 @@ pub(crate) fn pip_check(
     )?;
 
     // Determine the markers and tags to use for resolution.
-    let markers = resolution_markers(python_version, python_platform, environment.interpreter());
-    let tags = resolution_tags(python_version, python_platform, environment.interpreter())?;
+    let markers = resolution_markers(
+        python_version,
+        python_platform,
+        None,
+        environment.interpreter(),
+    );
+    let tags = resolution_tags(
+        python_version,
+        python_platform,
+        None,
+        None,
+        environment.interpreter(),
+    )?;
 
-    // Run the diagnostics.
-    let diagnostics: Vec<SitePackagesDiagnostic> = site_packages
+    // Run the diagnostics, ordering them by package for stable output.
+    let mut diagnostics: Vec<SitePackagesDiagnostic> = site_packages
         .diagnostics(&markers, &tags, dependency_metadata)?
         .into_iter()
         .collect();
+    diagnostics.sort_by(|a, b| a.package().cmp(b.package()));
+
+    if matches!(format, CheckFormat::Json) {
+        let entries = diagnostics.iter().map(Entry::from).collect_vec();
+        writeln!(
+            printer.stdout_important(),
+            "{}",
+            serde_json::to_string(&entries)?
+        )?;
+
+        return if diagnostics.is_empty() {
+            Ok(ExitStatus::Success)
+        } else {
+            Ok(ExitStatus::Failure)
+        };
+    }
 
     if diagnostics.is_empty() {
         writeln!(
@@ -96,3 +129,121 @@ pub(crate) fn pip_check(
         Ok(ExitStatus::Failure)
     }
 }
+
+/// The kind of a diagnostic in the JSON output of `uv pip check`.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Kind {
+    /// A dependency of the package is not installed.
+    MissingDependency,
+    /// The installed version of a dependency doesn't satisfy the package's requirement.
+    VersionConflict,
+    /// The installed version of a dependency doesn't provide an extra requested by the package.
+    MissingExtra,
+    /// The package isn't compatible with the Python version of the environment.
+    RequiresPython,
+    /// The package was built for a different platform.
+    IncompatiblePlatform,
+    /// Multiple distributions of the package are installed.
+    DuplicatePackage,
+    /// The package's `METADATA` file could not be read.
+    MetadataUnavailable,
+    /// The package's `WHEEL` file could not be read.
+    TagsUnavailable,
+}
+
+/// A diagnostic in the JSON output of `uv pip check`.
+#[derive(Debug, Serialize)]
+struct Entry {
+    kind: Kind,
+    /// The package that the diagnostic applies to.
+    package: PackageName,
+    /// The unsatisfied requirement, if any (e.g., `idna>=2.5,<4`, or `>=3.8` for a
+    /// `Requires-Python` mismatch).
+    requirement: Option<String>,
+    /// The installed version of the requirement (i.e., of the dependency, or of Python), or `None`
+    /// if it's missing.
+    installed_version: Option<Version>,
+    /// A human-readable description of the diagnostic.
+    message: String,
+}
+
+impl From<&SitePackagesDiagnostic> for Entry {
+    fn from(diagnostic: &SitePackagesDiagnostic) -> Self {
+        let message = diagnostic.message();
+        match diagnostic {
+            SitePackagesDiagnostic::MetadataUnavailable { package, .. } => Self {
+                kind: Kind::MetadataUnavailable,
+                package: package.clone(),
+                requirement: None,
+                installed_version: None,
+                message,
+            },
+            SitePackagesDiagnostic::TagsUnavailable { package, .. } => Self {
+                kind: Kind::TagsUnavailable,
+                package: package.clone(),
+                requirement: None,
+                installed_version: None,
+                message,
+            },
+            SitePackagesDiagnostic::IncompatiblePythonVersion {
+                package,
+                version,
+                requires_python,
+            } => Self {
+                kind: Kind::RequiresPython,
+                package: package.clone(),
+                requirement: Some(requires_python.to_string()),
+                installed_version: Some(version.clone()),
+                message,
+            },
+            SitePackagesDiagnostic::IncompatiblePlatform { package } => Self {
+                kind: Kind::IncompatiblePlatform,
+                package: package.clone(),
+                requirement: None,
+                installed_version: None,
+                message,
+            },
+            SitePackagesDiagnostic::MissingDependency {
+                package,
+                requirement,
+            } => Self {
+                kind: Kind::MissingDependency,
+                package: package.clone(),
+                requirement: Some(requirement.to_string()),
+                installed_version: None,
+                message,
+            },
+            SitePackagesDiagnostic::IncompatibleDependency {
+                package,
+                version,
+                requirement,
+            } => Self {
+                kind: Kind::VersionConflict,
+                package: package.clone(),
+                requirement: Some(requirement.to_string()),
+                installed_version: Some(version.clone()),
+                message,
+            },
+            SitePackagesDiagnostic::MissingExtra {
+                package,
+                version,
+                requirement,
+                ..
+            } => Self {
+                kind: Kind::MissingExtra,
+                package: package.clone(),
+                requirement: Some(requirement.to_string()),
+                installed_version: Some(version.clone()),
+                message,
+            },
+            SitePackagesDiagnostic::DuplicatePackage { package, .. } => Self {
+                kind: Kind::DuplicatePackage,
+                package: package.clone(),
+                requirement: None,
+                installed_version: None,
+                message,
+            },
+        }
+    }
+}
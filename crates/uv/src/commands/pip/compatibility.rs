@@ -0,0 +1,218 @@
+use std::fmt::Write;
+
+use anyhow::{Result, bail};
+use itertools::Itertools;
+use serde::Serialize;
+
+use uv_cli::DryRunFormat;
+use uv_configuration::BuildOptions;
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{
+    BuiltDist, Dist, File, Name, RegistryBuiltWheel, Resolution, ResolvedDist, SourceDist,
+};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::Tags;
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::printer::Printer;
+
+/// Report, for each package in the resolution, whether a compatible wheel exists for the target
+/// platform, or whether the package would need to be built from source.
+///
+/// Returns an error if any package lacks a compatible wheel.
+pub(crate) fn report_compatibility(
+    resolution: &Resolution,
+    tags: &Tags,
+    build_options: &BuildOptions,
+    format: DryRunFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let entries = resolution
+        .distributions()
+        .map(|dist| Entry::from_dist(dist, tags, build_options))
+        .sorted_unstable_by(|a, b| a.name.cmp(&b.name).then_with(|| a.version.cmp(&b.version)))
+        .collect_vec();
+
+    match format {
+        DryRunFormat::Json => {
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string(&entries)?
+            )?;
+        }
+        DryRunFormat::Text if entries.is_empty() => {}
+        DryRunFormat::Text => {
+            let columns = [
+                Column {
+                    header: String::from("Package"),
+                    rows: entries
+                        .iter()
+                        .map(|entry| entry.name.to_string())
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Version"),
+                    rows: entries
+                        .iter()
+                        .map(|entry| {
+                            entry
+                                .version
+                                .as_ref()
+                                .map(ToString::to_string)
+                                .unwrap_or_default()
+                        })
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Status"),
+                    rows: entries
+                        .iter()
+                        .map(|entry| entry.status.to_string())
+                        .collect_vec(),
+                },
+                Column {
+                    header: String::from("Artifact"),
+                    rows: entries
+                        .iter()
+                        .map(|entry| entry.filename.clone().unwrap_or_default())
+                        .collect_vec(),
+                },
+            ];
+            for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+                writeln!(printer.stdout_important(), "{}", elems.join(" ").trim_end())?;
+            }
+        }
+    }
+
+    let incompatible = entries
+        .iter()
+        .filter(|entry| !matches!(entry.status, Status::Wheel))
+        .collect_vec();
+    if incompatible.is_empty() {
+        return Ok(ExitStatus::Success);
+    }
+
+    let (s, verb) = if incompatible.len() == 1 {
+        ("", "has")
+    } else {
+        ("s", "have")
+    };
+    bail!(
+        "{} package{s} {verb} no compatible wheel for the target platform: {}",
+        incompatible.len(),
+        incompatible.iter().map(|entry| &entry.name).join(", ")
+    )
+}
+
+/// The availability of a built distribution for the target platform.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Status {
+    /// A wheel compatible with the target platform is available.
+    Wheel,
+    /// No compatible wheel is available (or wheels are disabled), so the package would be built
+    /// from source.
+    Build,
+    /// Neither a compatible wheel nor a source distribution is available.
+    Incompatible,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wheel => write!(f, "wheel"),
+            Self::Build => write!(f, "build"),
+            Self::Incompatible => write!(f, "incompatible"),
+        }
+    }
+}
+
+/// An entry in the compatibility report.
+#[derive(Debug, Serialize)]
+struct Entry {
+    name: PackageName,
+    version: Option<Version>,
+    status: Status,
+    filename: Option<String>,
+}
+
+impl Entry {
+    /// Determine the availability of a built distribution for the target platform, based on the
+    /// candidate files for the resolved version (rather than the file that was selected for the
+    /// current platform).
+    fn from_dist(dist: &ResolvedDist, tags: &Tags, build_options: &BuildOptions) -> Self {
+        let (status, filename) = match dist {
+            // Installed distributions are excluded from the resolution; even if present, they say
+            // nothing about the target platform.
+            ResolvedDist::Installed { .. } => (Status::Build, None),
+            ResolvedDist::Installable { dist, .. } => match dist.as_ref() {
+                Dist::Built(BuiltDist::Registry(dist)) => Self::from_candidates(
+                    &dist.wheels,
+                    dist.sdist.as_ref().map(|sdist| sdist.file.as_ref()),
+                    tags,
+                    build_options.no_binary_package(&dist.best_wheel().filename.name),
+                ),
+                Dist::Built(BuiltDist::DirectUrl(wheel)) => {
+                    Self::from_filename(&wheel.filename, tags)
+                }
+                Dist::Built(BuiltDist::Path(wheel)) => Self::from_filename(&wheel.filename, tags),
+                Dist::Built(BuiltDist::GitPath(wheel)) => {
+                    Self::from_filename(&wheel.filename, tags)
+                }
+                Dist::Source(SourceDist::Registry(sdist)) => Self::from_candidates(
+                    &sdist.wheels,
+                    Some(&sdist.file),
+                    tags,
+                    build_options.no_binary_package(&sdist.name),
+                ),
+                Dist::Source(_) => (Status::Build, None),
+            },
+        };
+        Self {
+            name: dist.name().clone(),
+            version: dist.version().cloned(),
+            status,
+            filename,
+        }
+    }
+
+    /// Determine the status of a registry distribution from its candidate wheels and source
+    /// distribution, preferring the most specific wheel that's compatible with the target platform.
+    fn from_candidates(
+        wheels: &[RegistryBuiltWheel],
+        sdist: Option<&File>,
+        tags: &Tags,
+        no_binary: bool,
+    ) -> (Status, Option<String>) {
+        let wheel = if no_binary {
+            None
+        } else {
+            wheels
+                .iter()
+                .map(|wheel| (wheel.filename.compatibility(tags), &wheel.filename))
+                .filter(|(compatibility, _)| compatibility.is_compatible())
+                .max_by_key(|(compatibility, _)| *compatibility)
+        };
+        if let Some((_, filename)) = wheel {
+            (Status::Wheel, Some(filename.to_string()))
+        } else if let Some(sdist) = sdist {
+            (Status::Build, Some(sdist.filename.to_string()))
+        } else {
+            (Status::Incompatible, None)
+        }
+    }
+
+    /// Determine the status of a wheel provided directly (e.g., by URL or path), which may not be
+    /// compatible with the target platform.
+    fn from_filename(filename: &WheelFilename, tags: &Tags) -> (Status, Option<String>) {
+        let status = if filename.is_compatible(tags) {
+            Status::Wheel
+        } else {
+            Status::Incompatible
+        };
+        (status, Some(filename.to_string()))
+    }
+}
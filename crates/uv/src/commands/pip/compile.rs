@@ -14,10 +14,11 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, ExcludeDependency, ExtrasSpecification,
-    IndexStrategy, NoBinary, NoBuild, NoSources, Override, PipCompileFormat, Reinstall, Upgrade,
+    BuildIsolation, BuildOptions, BuildRuntime, Concurrency, Constraints, ExcludeDependency,
+    ExtrasSpecification, IndexStrategy, NoBinary, NoBuild, NoSources, Override, PipCompileFormat,
+    Reinstall, Replacements, Upgrade,
 };
-use uv_configuration::{KeyringProviderType, TargetTriple};
+use uv_configuration::{KeyringProviderType, TargetAbiFlags, TargetImplementation, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
@@ -42,8 +43,9 @@ use uv_requirements::{
 };
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
-    ResolverEnvironment,
+    InMemoryIndex, OptionsBuilder, PackageLocalVersionPreferences, PackagePrereleaseModes,
+    PackageResolutionModes, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
+    ResolverEnvironment, YankedPolicy,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -78,8 +80,18 @@ pub(crate) async fn pip_compile(
     output_file: Option<&Path>,
     format: Option<PipCompileFormat>,
     resolution_mode: ResolutionMode,
+    resolution_package: PackageResolutionModes,
     prerelease_mode: PrereleaseMode,
+    prerelease_package: PackagePrereleaseModes,
+    local_version_preference: PackageLocalVersionPreferences,
+    yanked: YankedPolicy,
     fork_strategy: ForkStrategy,
+    max_metadata_builds: Option<usize>,
+    resolution_effort: Option<usize>,
+    excluded_packages: Vec<PackageName>,
+    reresolve_metadata: Vec<PackageName>,
+    explain: Vec<PackageName>,
+    hint_on_failure: bool,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
@@ -89,6 +101,7 @@ pub(crate) async fn pip_compile(
     include_annotations: bool,
     include_header: bool,
     custom_compile_command: Option<String>,
+    custom_header: Option<String>,
     include_index_url: bool,
     include_find_links: bool,
     include_build_options: bool,
@@ -108,12 +121,16 @@ pub(crate) async fn pip_compile(
     extra_build_dependencies: &ExtraBuildDependencies,
     extra_build_variables: &ExtraBuildVariables,
     build_options: BuildOptions,
+    build_runtime: BuildRuntime,
     install_mirrors: PythonInstallMirrors,
     mut python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    python_implementation: Option<TargetImplementation>,
+    python_abiflags: Option<TargetAbiFlags>,
     python_downloads: PythonDownloads,
     universal: bool,
     exclude_newer: ExcludeNewer,
+    strict_exclude_newer: bool,
     sources: NoSources,
     annotation_style: AnnotationStyle,
     link_mode: LinkMode,
@@ -400,11 +417,14 @@ pub(crate) async fn pip_compile(
         let tags = resolution_tags(
             python_version.as_ref(),
             python_platform.as_ref(),
+            python_implementation,
+            python_abiflags,
             &interpreter,
         )?;
         let marker_env = resolution_markers(
             python_version.as_ref(),
             python_platform.as_ref(),
+            python_implementation,
             &interpreter,
         );
         (Some(tags), ResolverEnvironment::specific(marker_env))
@@ -497,7 +517,13 @@ pub(crate) async fn pip_compile(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, tags.as_deref(), &hasher, &build_options)
+        FlatIndex::from_entries(
+            entries,
+            tags.as_deref(),
+            &hasher,
+            &build_options,
+            &index_locations,
+        )
     };
 
     // Determine whether to enable build isolation.
@@ -545,6 +571,7 @@ pub(crate) async fn pip_compile(
         extra_build_variables,
         link_mode,
         &build_options,
+        &build_runtime,
         &build_hashes,
         exclude_newer.clone(),
         sources,
@@ -552,20 +579,39 @@ pub(crate) async fn pip_compile(
         workspace_cache,
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(&excluded_packages);
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_package(resolution_package)
         .prerelease_mode(prerelease_mode)
+        .prerelease_package(prerelease_package)
+        .local_version_preference(local_version_preference)
+        .yanked(yanked)
         .fork_strategy(fork_strategy)
+        .max_metadata_builds(max_metadata_builds)
+        .resolution_effort(resolution_effort)
+        .excluded_packages(excluded_packages.clone())
+        .reresolve_metadata(reresolve_metadata.clone())
+        .explain(explain)
+        .hint_on_failure(hint_on_failure)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer.clone())
+        .strict_exclude_newer(strict_exclude_newer)
         .index_strategy(index_strategy)
         .torch_backend(torch_backend)
         .build_options(build_options.clone())
         .artifact_environments(artifact_environments)
         .build();
 
+    // Track any legacy (`setup.py` or `setup.cfg`-only) projects, to note them in the header.
+    let legacy_projects = source_trees
+        .iter()
+        .filter(|source_tree| source_tree.is_legacy())
+        .map(|source_tree| source_tree.path().to_path_buf())
+        .collect::<Vec<_>>();
+
     // Resolve the requirements.
     let resolution = match operations::resolve(
         requirements,
@@ -573,6 +619,7 @@ pub(crate) async fn pip_compile(
         overrides,
         override_dependencies,
         excludes,
+        Replacements::default(),
         source_trees,
         project,
         BTreeSet::default(),
@@ -613,24 +660,45 @@ pub(crate) async fn pip_compile(
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
     if include_header {
-        writeln!(
-            writer,
-            "{}",
-            "# This file was autogenerated by uv via the following command:".green()
-        )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
+        if let Some(custom_header) = custom_header {
+            for line in custom_header.lines() {
+                if line.starts_with('#') {
+                    writeln!(writer, "{}", line.green())?;
+                } else {
+                    writeln!(writer, "{}", format!("# {line}").trim_end().green())?;
+                }
+            }
+        } else {
+            writeln!(
+                writer,
+                "{}",
+                "# This file was autogenerated by uv via the following command:".green()
+            )?;
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command
+                    )
                 )
-            )
-            .green()
-        )?;
+                .green()
+            )?;
+        }
+        for path in &legacy_projects {
+            writeln!(
+                writer,
+                "{}",
+                format!(
+                    "# Dependencies extracted from legacy project: {}",
+                    path.user_display()
+                )
+                .green()
+            )?;
+        }
     }
 
     match format {
@@ -774,7 +842,7 @@ pub(crate) async fn pip_compile(
 
     // If any "unsafe" packages were excluded, notify the user.
     let excluded = no_emit_packages
-        .into_iter()
+        .iter()
         .filter(|name| resolution.contains(name))
         .collect::<Vec<_>>();
     if !excluded.is_empty() {
@@ -792,9 +860,30 @@ pub(crate) async fn pip_compile(
     // Commit the output to disk.
     writer.commit().await?;
 
+    // If any packages lack hashes (e.g., Git or local directory dependencies), the output can't be
+    // installed with `--require-hashes`.
+    if generate_hashes && matches!(format, PipCompileFormat::RequirementsTxt) {
+        let unhashed = resolution
+            .unhashed_packages()
+            .filter(|name| !no_emit_packages.contains(name))
+            .collect::<BTreeSet<_>>();
+        if !unhashed.is_empty() {
+            warn_user!(
+                "Omitted hashes for {}, since Git and local directory dependencies can't be hashed; the output is not compatible with `--require-hashes`",
+                unhashed
+                    .iter()
+                    .map(|name| format!("`{}`", name.cyan()))
+                    .join(", "),
+            );
+        }
+    }
+
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
+    // Explain the selected versions of the requested packages.
+    operations::report_explanations(resolution.explanations(), printer)?;
+
     Ok(ExitStatus::Success)
 }
 
@@ -877,6 +966,25 @@ fn cmd(
                 return Some(None);
             }
 
+            // Always skip the `--explain` flag and mark the next item to be skipped
+            if arg == "--explain" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Skip only this argument if option and value are together
+            if arg.starts_with("--explain=") {
+                // Reset state; skip this iteration.
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--hint-on-failure` flag.
+            if arg == "--hint-on-failure" {
+                *skip_next = None;
+                return Some(None);
+            }
+
             // Always skip the `--quiet` flag.
             if arg == "--quiet" || arg == "-q" {
                 *skip_next = None;
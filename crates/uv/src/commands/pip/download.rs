@@ -0,0 +1,626 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use futures::{StreamExt, TryStreamExt};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tracing::debug;
+use url::Url;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::{CanonicalUrl, cache_digest};
+use uv_client::{
+    BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder,
+};
+use uv_configuration::{
+    BuildIsolation, BuildOptions, BuildRuntime, Concurrency, Constraints, ExtrasSpecification,
+    HashCheckingMode, IndexStrategy, KeyringProviderType, NoSources, Override, Reinstall,
+    Replacements, TargetTriple, Upgrade,
+};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::LoweredExtraBuildDependencies;
+use uv_distribution_types::{
+    BuiltDist, ConfigSettings, DependencyMetadata, Dist, ExtraBuildVariables, HashPolicy, Index,
+    IndexLocations, Name, NameRequirementSpecification, Origin, PackageConfigSettings,
+    RemoteSource, Requirement, Resolution, ResolvedDist, SourceDist,
+};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{Simplified, rename_with_retry};
+use uv_install_wheel::LinkMode;
+use uv_preview::Preview;
+use uv_pypi_types::{Conflicts, HashDigest};
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonVersion, VersionRequest,
+};
+use uv_redacted::DisplaySafeUrl;
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
+    ResolutionMode, ResolverEnvironment,
+};
+use uv_settings::PythonInstallMirrors;
+use uv_types::{EmptyInstalledPackages, HashStrategy, SourceTreeEditablePolicy};
+use uv_workspace::WorkspaceCache;
+use uv_workspace::pyproject::ExtraBuildDependencies;
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{ExitStatus, diagnostics, elapsed};
+use crate::printer::Printer;
+
+/// Resolve a set of requirements for the target environment, and download the selected
+/// distributions into a directory.
+pub(crate) async fn pip_download(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    constraints_from_workspace: Vec<Requirement>,
+    overrides_from_workspace: Vec<Override<Requirement>>,
+    dest: &Path,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PrereleaseMode,
+    dependency_mode: DependencyMode,
+    upgrade: Upgrade,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    dependency_metadata: DependencyMetadata,
+    keyring_provider: KeyringProviderType,
+    client_builder: &BaseClientBuilder<'_>,
+    link_mode: LinkMode,
+    hash_checking: Option<HashCheckingMode>,
+    config_settings: &ConfigSettings,
+    config_settings_package: &PackageConfigSettings,
+    build_isolation: BuildIsolation,
+    extra_build_dependencies: &ExtraBuildDependencies,
+    extra_build_variables: &ExtraBuildVariables,
+    build_options: BuildOptions,
+    build_runtime: BuildRuntime,
+    python_version: Option<PythonVersion>,
+    python_platform: Option<TargetTriple>,
+    python_downloads: PythonDownloads,
+    install_mirrors: PythonInstallMirrors,
+    exclude_newer: ExcludeNewer,
+    sources: NoSources,
+    python: Option<String>,
+    system: bool,
+    python_preference: PythonPreference,
+    concurrency: Concurrency,
+    cache: Cache,
+    workspace_cache: WorkspaceCache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let client_builder = client_builder.clone().keyring(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        overrides,
+        mut override_dependencies,
+        excludes,
+        pylock,
+        source_trees,
+        groups,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary,
+        no_build,
+        extras: _,
+    } = operations::read_requirements(
+        requirements,
+        constraints,
+        overrides,
+        &[],
+        &ExtrasSpecification::default(),
+        None,
+        &client_builder,
+    )
+    .await?;
+
+    if pylock.is_some() {
+        bail!("`pylock.toml` files are not supported by `uv pip download`");
+    }
+
+    override_dependencies.extend(overrides_from_workspace);
+
+    let constraints: Vec<NameRequirementSpecification> = constraints
+        .iter()
+        .cloned()
+        .chain(
+            constraints_from_workspace
+                .into_iter()
+                .map(NameRequirementSpecification::from),
+        )
+        .collect();
+
+    // Find an interpreter to use for building distributions.
+    let environment_preference = EnvironmentPreference::from_system_flag(system, false);
+    let python_preference = python_preference.with_system_flag(system);
+    let reporter = PythonDownloadReporter::single(printer);
+    let interpreter = if let Some(python) = python.as_ref() {
+        let request = PythonRequest::parse(python);
+        PythonInstallation::find_or_download(
+            Some(&request),
+            environment_preference,
+            python_preference,
+            python_downloads,
+            &client_builder,
+            &cache,
+            Some(&reporter),
+            install_mirrors.python_install_mirror.as_deref(),
+            install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
+        )
+        .await
+    } else {
+        let request = if let Some(version) = python_version.as_ref() {
+            PythonRequest::Version(VersionRequest::from(version))
+        } else {
+            PythonRequest::default()
+        };
+        PythonInstallation::find_best(
+            &request,
+            environment_preference,
+            python_preference,
+            python_downloads,
+            &client_builder,
+            &cache,
+            Some(&reporter),
+            install_mirrors.python_install_mirror.as_deref(),
+            install_mirrors.pypy_install_mirror.as_deref(),
+            install_mirrors.python_downloads_json_url.as_deref(),
+        )
+        .await
+    }?
+    .into_interpreter();
+
+    debug!(
+        "Using Python {} interpreter at {} for builds",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    );
+
+    // Determine the markers and tags to use for the resolution.
+    let marker_env = resolution_markers(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        None,
+        &interpreter,
+    );
+    let tags = resolution_tags(
+        python_version.as_ref(),
+        python_platform.as_ref(),
+        None,
+        None,
+        &interpreter,
+    )?;
+
+    // Determine the Python requirement, if the user requested a specific version.
+    let python_requirement = if let Some(python_version) = python_version.as_ref() {
+        PythonRequirement::from_python_version(&interpreter, python_version)
+    } else {
+        PythonRequirement::from_interpreter(&interpreter)
+    };
+
+    // Collect the set of required hashes.
+    let hasher = if let Some(hash_checking) = hash_checking {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            Some(&marker_env),
+            hash_checking,
+        )?
+    } else {
+        HashStrategy::None
+    };
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations = index_locations.combine(
+        extra_index_urls
+            .into_iter()
+            .map(Index::from_extra_index_url)
+            .chain(index_url.map(Index::from_index_url))
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        find_links
+            .into_iter()
+            .map(Index::from_find_links)
+            .map(|index| index.with_origin(Origin::RequirementsTxt))
+            .collect(),
+        no_index,
+    );
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache.clone())
+        .index_locations(index_locations.clone())
+        .index_strategy(index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build()?;
+
+    // Combine the `--no-binary` and `--no-build` flags from the requirements files.
+    let build_options = build_options.combine(no_binary, no_build);
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
+        let entries = client
+            .fetch_all(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            &build_options,
+            &index_locations,
+        )
+    };
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let types_build_isolation = match build_isolation {
+        BuildIsolation::Isolate => uv_types::BuildIsolation::Isolated,
+        BuildIsolation::Shared => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::Shared(&environment)
+        }
+        BuildIsolation::SharedPackage(ref packages) => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            uv_types::BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Lower the extra build dependencies, if any.
+    let extra_build_requires =
+        LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
+            .into_inner();
+
+    // Don't enforce hashes for build dependencies, which are never written to the destination.
+    let build_hasher = HashStrategy::None;
+    let build_constraints = Constraints::default();
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        &build_constraints,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &dependency_metadata,
+        state.clone(),
+        index_strategy,
+        config_settings,
+        config_settings_package,
+        types_build_isolation,
+        &extra_build_requires,
+        extra_build_variables,
+        link_mode,
+        &build_options,
+        &build_runtime,
+        &build_hasher,
+        exclude_newer.clone(),
+        sources,
+        SourceTreeEditablePolicy::Project,
+        workspace_cache,
+        concurrency.clone(),
+        preview,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .dependency_mode(dependency_mode)
+        .exclude_newer(exclude_newer)
+        .index_strategy(index_strategy)
+        .build_options(build_options.clone())
+        .build();
+
+    // Resolve the requirements.
+    let (resolution, hasher) = match operations::resolve(
+        requirements,
+        constraints,
+        overrides,
+        override_dependencies,
+        excludes,
+        Replacements::default(),
+        source_trees,
+        project,
+        BTreeSet::default(),
+        &ExtrasSpecification::default(),
+        &groups,
+        Vec::default(),
+        EmptyInstalledPackages,
+        &hasher,
+        &Reinstall::None,
+        &upgrade,
+        Some(&tags),
+        ResolverEnvironment::specific(marker_env),
+        python_requirement,
+        interpreter.markers(),
+        Conflicts::empty(),
+        &client,
+        &flat_index,
+        state.index(),
+        &build_dispatch,
+        &concurrency,
+        options,
+        Box::new(DefaultResolveLogger),
+        printer,
+    )
+    .await
+    {
+        Ok((graph, hasher)) => (Resolution::from(graph), hasher),
+        Err(err) => {
+            return diagnostics::OperationDiagnostic::with_system_certs(
+                client_builder.system_certs(),
+            )
+            .report(err)
+            .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+    };
+
+    // Notify the user of any resolution diagnostics.
+    operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+
+    // Determine the archive to fetch for each distribution.
+    let artifacts = resolution
+        .distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable { dist, .. } => Some(dist.as_ref()),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .map(|dist| Artifact::from_dist(dist, &hasher))
+        .collect::<Result<Vec<_>>>()?;
+
+    fs_err::tokio::create_dir_all(dest).await?;
+
+    let start = std::time::Instant::now();
+    futures::stream::iter(&artifacts)
+        .map(|artifact| artifact.fetch(dest, &client, &cache))
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<()>>()
+        .await?;
+
+    let s = if artifacts.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Downloaded {} {}",
+            format!("{} package{s}", artifacts.len()).bold(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    for artifact in artifacts
+        .iter()
+        .sorted_unstable_by(|a, b| a.dist.name().cmp(b.dist.name()))
+    {
+        writeln!(printer.stderr(), " {} {}", "+".green(), artifact.filename)?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A distribution archive to write to the destination directory.
+#[derive(Debug)]
+struct Artifact<'a> {
+    dist: &'a Dist,
+    /// The filename of the archive, as published.
+    filename: String,
+    /// The location from which the archive should be fetched.
+    location: ArtifactLocation,
+    /// The hashes against which the archive should be validated.
+    hashes: HashPolicy<'a>,
+}
+
+#[derive(Debug)]
+enum ArtifactLocation {
+    /// A remote archive, which is fetched once and then served from the cache.
+    Url(DisplaySafeUrl),
+    /// An archive on the local filesystem.
+    Path(PathBuf),
+}
+
+impl<'a> Artifact<'a> {
+    fn from_dist(dist: &'a Dist, hasher: &'a HashStrategy) -> Result<Self> {
+        let (filename, location, registry_hashes) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let file = &wheels.best_wheel().file;
+                (
+                    file.filename.to_string(),
+                    ArtifactLocation::from_url(file.url.to_url()?),
+                    file.hashes.as_slice(),
+                )
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => (
+                sdist.file.filename.to_string(),
+                ArtifactLocation::from_url(sdist.file.url.to_url()?),
+                sdist.file.hashes.as_slice(),
+            ),
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => (
+                wheel.filename.to_string(),
+                ArtifactLocation::from_url((*wheel.location).clone()),
+                [].as_slice(),
+            ),
+            Dist::Source(SourceDist::DirectUrl(sdist)) => (
+                sdist.filename()?.into_owned(),
+                ArtifactLocation::from_url((*sdist.location).clone()),
+                [].as_slice(),
+            ),
+            Dist::Built(BuiltDist::Path(wheel)) => (
+                wheel.filename.to_string(),
+                ArtifactLocation::Path(wheel.install_path.to_path_buf()),
+                [].as_slice(),
+            ),
+            Dist::Source(SourceDist::Path(sdist)) => (
+                sdist
+                    .install_path
+                    .file_name()
+                    .context("Source distribution path is missing a filename")?
+                    .to_string_lossy()
+                    .into_owned(),
+                ArtifactLocation::Path(sdist.install_path.to_path_buf()),
+                [].as_slice(),
+            ),
+            Dist::Built(BuiltDist::GitPath(_))
+            | Dist::Source(
+                SourceDist::GitDirectory(_) | SourceDist::GitPath(_) | SourceDist::Directory(_),
+            ) => {
+                bail!(
+                    "Git and local directory requirements cannot be downloaded, as they are not distribution archives: `{dist}`"
+                );
+            }
+        };
+
+        // Prefer any hashes provided by the user; otherwise, validate against those published by
+        // the registry.
+        let hashes = match hasher.get(dist) {
+            policy if policy.requires_validation() => policy,
+            _ if registry_hashes.is_empty() => HashPolicy::None,
+            _ => HashPolicy::All(registry_hashes),
+        };
+
+        Ok(Self {
+            dist,
+            filename,
+            location,
+            hashes,
+        })
+    }
+
+    /// Fetch the archive into the destination directory, validating its hashes.
+    async fn fetch(&self, dest: &Path, client: &RegistryClient, cache: &Cache) -> Result<()> {
+        let target = dest.join(&self.filename);
+        match &self.location {
+            ArtifactLocation::Path(path) => {
+                self.validate(path).await?;
+                // Avoid copying an archive onto itself, e.g., when `--find-links` points at the
+                // destination directory.
+                if uv_fs::is_same_file_allow_missing(path, &target) != Some(true) {
+                    fs_err::tokio::copy(path, &target).await?;
+                }
+            }
+            ArtifactLocation::Url(url) => {
+                let entry = cache.entry(
+                    CacheBucket::Downloads,
+                    Path::new(self.dist.name().as_str())
+                        .join(cache_digest(&CanonicalUrl::new(url))),
+                    &self.filename,
+                );
+                let _lock = entry.with_file(".lock").lock().await?;
+
+                if cache
+                    .freshness(&entry, Some(self.dist.name()), None)?
+                    .is_fresh()
+                    && entry.path().is_file()
+                {
+                    debug!("Using cached archive for: {}", self.dist);
+                } else {
+                    if matches!(client.connectivity(), Connectivity::Offline) {
+                        bail!(
+                            "Network connectivity is disabled, but `{}` was not found in the cache",
+                            self.filename
+                        );
+                    }
+                    debug!("Downloading: {url}");
+                    let response = client
+                        .uncached_client(url)
+                        .get(Url::from(url.clone()))
+                        .header(
+                            // Request the archive as-is, rather than a compressed response.
+                            "accept-encoding",
+                            reqwest::header::HeaderValue::from_static("identity"),
+                        )
+                        .send()
+                        .await
+                        .with_context(|| format!("Failed to download: `{url}`"))?
+                        .error_for_status()
+                        .with_context(|| format!("Failed to download: `{url}`"))?;
+
+                    // Stream the response into the cache.
+                    let temp_dir = tempfile::tempdir_in(entry.dir())?;
+                    let temp_file = temp_dir.path().join(&self.filename);
+                    {
+                        let mut writer =
+                            BufWriter::new(fs_err::tokio::File::create(&temp_file).await?);
+                        let mut reader = response.bytes_stream();
+                        while let Some(chunk) = reader.next().await {
+                            writer.write_all(&chunk?).await?;
+                        }
+                        writer.flush().await?;
+                    }
+                    rename_with_retry(&temp_file, entry.path()).await?;
+                }
+
+                // Validate the cached archive, discarding it if the hashes don't match.
+                if let Err(err) = self.validate(entry.path()).await {
+                    fs_err::tokio::remove_file(entry.path()).await?;
+                    return Err(err);
+                }
+                fs_err::tokio::copy(entry.path(), &target).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the archive at the given path against the expected hashes.
+    async fn validate(&self, path: &Path) -> Result<()> {
+        if !self.hashes.requires_validation() {
+            return Ok(());
+        }
+
+        let mut hashers = self
+            .hashes
+            .algorithms()
+            .into_iter()
+            .map(Hasher::from)
+            .collect::<Vec<_>>();
+        let reader = fs_err::tokio::File::open(path).await?;
+        HashReader::new(reader, &mut hashers).finish().await?;
+        let actual = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .collect::<Vec<_>>();
+
+        if !self.hashes.matches(&actual) {
+            return Err(uv_distribution::Error::hash_mismatch(
+                self.dist.to_string(),
+                self.hashes.digests(),
+                &actual,
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl ArtifactLocation {
+    /// Treat `file://` URLs (e.g., from a local `--find-links` directory) as paths.
+    fn from_url(url: DisplaySafeUrl) -> Self {
+        if url.scheme() == "file"
+            && let Ok(path) = url.to_file_path()
+        {
+            return Self::Path(path);
+        }
+        Self::Url(url)
+    }
+}
@@ -97,7 +97,14 @@ pub(crate) fn pip_freeze(
             }
             InstalledDistKind::Url(dist) => {
                 if dist.editable {
-                    format!("-e {}", dist.url)
+                    // Render local editables by their location, as in `pip freeze`, falling back
+                    // to the recorded URL (e.g., for VCS editables).
+                    match dist.url.to_file_path() {
+                        Ok(path) if dist.url.scheme() == "file" => {
+                            format!("-e {}", path.display())
+                        }
+                        _ => format!("-e {}", dist.url),
+                    }
                 } else {
                     format!("{} @ {}", dist.name().bold(), dist.url)
                 }
@@ -1,31 +1,34 @@
 use std::collections::BTreeSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
 use thiserror::Error;
 use tracing::{Level, debug, enabled, warn};
 
 use uv_errors::{Hint, Hints};
 
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, EditableMode,
-    ExcludeDependency, ExtrasSpecification, HashCheckingMode, IndexStrategy, NoSources, Override,
-    Reinstall, Upgrade,
+    BuildIsolation, BuildOptions, BuildRuntime, Concurrency, Constraints, DryRun, EditableMode,
+    ExcludeDependency, ExtrasSpecification, HashCheckingMode, IndexStrategy, NoBinary, NoBuild,
+    NoSources, Override, Reinstall, Replacements, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
+    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, InstallContext,
     NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
+    UnresolvedRequirement,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep440::Version;
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::Conflicts;
@@ -35,8 +38,9 @@ use uv_python::{
 };
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, MissingMetadataPolicy, OptionsBuilder,
+    PackageLocalVersionPreferences, PackagePrereleaseModes, PackageResolutionModes, PrereleaseMode,
+    PythonRequirement, ResolutionMode, ResolverEnvironment, YankedPolicy,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_torch::{AmdGpuArchitecture, TorchMode, TorchSource, TorchStrategy};
@@ -46,9 +50,12 @@ use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::editable::apply_editable_mode;
+use crate::commands::pip::compatibility::report_compatibility;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{Changelog, ExtraneousOptions, Modifications};
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::plan::report_plan;
+use crate::commands::pip::report::{prepare_report_distributions, write_installation_report};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::pylock::{read_pylock_toml, resolve_pylock_toml};
 use crate::commands::reporters::PythonDownloadReporter;
@@ -90,7 +97,15 @@ pub(crate) async fn pip_install(
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
     resolution_mode: ResolutionMode,
+    resolution_package: PackageResolutionModes,
     prerelease_mode: PrereleaseMode,
+    prerelease_package: PackagePrereleaseModes,
+    local_version_preference: PackageLocalVersionPreferences,
+    yanked: YankedPolicy,
+    max_metadata_builds: Option<usize>,
+    resolution_effort: Option<usize>,
+    excluded_packages: Vec<PackageName>,
+    reresolve_metadata: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -112,6 +127,7 @@ pub(crate) async fn pip_install(
     extra_build_dependencies: &ExtraBuildDependencies,
     extra_build_variables: &ExtraBuildVariables,
     build_options: BuildOptions,
+    build_runtime: BuildRuntime,
     modifications: Modifications,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
@@ -119,6 +135,7 @@ pub(crate) async fn pip_install(
     install_mirrors: PythonInstallMirrors,
     strict: bool,
     exclude_newer: ExcludeNewer,
+    strict_exclude_newer: bool,
     sources: NoSources,
     python: Option<String>,
     system: bool,
@@ -130,11 +147,22 @@ pub(crate) async fn pip_install(
     cache: Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
+    compatibility_report: bool,
+    output_format: DryRunFormat,
+    report: Option<PathBuf>,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
 
+    // Disallow writing the installation report to stdout alongside the JSON dry-run plan.
+    if report.as_deref() == Some(Path::new("-"))
+        && dry_run.enabled()
+        && matches!(output_format, DryRunFormat::Json)
+    {
+        anyhow::bail!("`--report -` cannot be used with `--format json`");
+    }
+
     let client_builder = client_builder.clone().keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -168,6 +196,9 @@ pub(crate) async fn pip_install(
 
     override_dependencies.extend(overrides_from_workspace);
 
+    // Reject direct requirements on excluded packages before resolving.
+    operations::check_excluded_requirements(&requirements, &excluded_packages)?;
+
     if pylock.is_some() {
         if !preview.is_enabled(PreviewFeature::Pylock) {
             warn_user!(
@@ -300,17 +331,27 @@ pub(crate) async fn pip_install(
     let marker_env = resolution_markers(
         python_version.as_ref(),
         python_platform.as_ref(),
+        None,
         interpreter,
     );
     let tags = resolution_tags(
         python_version.as_ref(),
         python_platform.as_ref(),
+        None,
+        None,
         interpreter,
     )?;
 
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
 
+    // When reporting on compatibility with the target platform, ignore any installed packages.
+    let reinstall = if compatibility_report {
+        Reinstall::All
+    } else {
+        reinstall
+    };
+
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
@@ -348,8 +389,31 @@ pub(crate) async fn pip_install(
                         debug!("Requirement satisfied: {requirement}");
                     }
                 }
+                if installer_metadata && !dry_run.enabled() {
+                    operations::record_direct_requests(
+                        &site_packages,
+                        &requirements,
+                        InstallContext::Pip,
+                    )?;
+                }
+
                 DefaultInstallLogger.on_check(requirements.len(), start, printer, dry_run)?;
 
+                if dry_run.enabled() {
+                    report_plan(&Changelog::default(), output_format, printer)?;
+                }
+                if let Some(report) = report.as_deref() {
+                    write_installation_report(
+                        report,
+                        None,
+                        &Changelog::default(),
+                        &[],
+                        &FxHashMap::default(),
+                        interpreter.markers(),
+                        printer,
+                    )?;
+                }
+
                 return Ok(ExitStatus::Success);
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -434,13 +498,32 @@ pub(crate) async fn pip_install(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // When reporting on compatibility with the target platform, consider source distributions as
+    // candidates even if building is disabled (e.g., with `--only-binary :all:`), such that packages
+    // without a compatible wheel are reported rather than failing the resolution. Source
+    // distributions are never built, however: their metadata must be available statically.
+    let (resolver_build_options, build_options) = if compatibility_report {
+        (
+            BuildOptions::new(build_options.no_binary().clone(), NoBuild::None),
+            BuildOptions::new(NoBinary::None, NoBuild::All),
+        )
+    } else {
+        (build_options.clone(), build_options)
+    };
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            &resolver_build_options,
+            &index_locations,
+        )
     };
 
     // Determine whether to enable build isolation.
@@ -493,6 +576,7 @@ pub(crate) async fn pip_install(
         extra_build_variables,
         link_mode,
         &build_options,
+        &build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources.clone(),
@@ -500,8 +584,10 @@ pub(crate) async fn pip_install(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(&excluded_packages);
 
+    let mut requested_extras = FxHashMap::default();
     let (resolution, hasher) = if let Some(pylock) = pylock {
         let (install_path, lock) = read_pylock_toml(&pylock, &client_builder).await?;
 
@@ -522,7 +608,7 @@ pub(crate) async fn pip_install(
             .cloned()
             .collect::<Vec<_>>();
 
-        resolve_pylock_toml(
+        let (resolution, hasher) = resolve_pylock_toml(
             lock,
             &install_path,
             interpreter,
@@ -530,23 +616,57 @@ pub(crate) async fn pip_install(
             python_platform.as_ref(),
             &extras,
             &groups,
-            &build_options,
+            &resolver_build_options,
             hash_checking,
-        )?
+        )?;
+
+        // Check whether any of the locked distributions have been yanked since the lockfile was
+        // created.
+        operations::check_yanked(
+            &resolution,
+            &client,
+            &index_locations,
+            yanked,
+            MissingMetadataPolicy::from_strict(strict_exclude_newer),
+            &concurrency,
+        )
+        .await?;
+
+        (resolution, hasher)
     } else {
         // When resolving, don't take any external preferences into account.
         let preferences = Vec::default();
 
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
+            .resolution_package(resolution_package)
             .prerelease_mode(prerelease_mode)
+            .prerelease_package(prerelease_package)
+            .local_version_preference(local_version_preference)
+            .yanked(yanked)
+            .max_metadata_builds(max_metadata_builds)
+            .resolution_effort(resolution_effort)
+            .excluded_packages(excluded_packages.clone())
+            .reresolve_metadata(reresolve_metadata.clone())
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
+            .strict_exclude_newer(strict_exclude_newer)
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
-            .build_options(build_options.clone())
+            .build_options(resolver_build_options.clone())
             .build();
 
+        // Record any extras of the direct requirements, for the installation report.
+        requested_extras = requirements
+            .iter()
+            .filter_map(|requirement| match &requirement.requirement {
+                UnresolvedRequirement::Named(requirement) if !requirement.extras.is_empty() => {
+                    Some((requirement.name.clone(), requirement.extras.to_vec()))
+                }
+                _ => None,
+            })
+            .collect();
+
         // Resolve the requirements.
         let (resolution, hasher) = match operations::resolve(
             requirements,
@@ -554,6 +674,7 @@ pub(crate) async fn pip_install(
             overrides,
             override_dependencies,
             excludes,
+            Replacements::default(),
             source_trees,
             project,
             BTreeSet::default(),
@@ -596,6 +717,20 @@ pub(crate) async fn pip_install(
     // If necessary, convert editable distributions to non-editable.
     let resolution = apply_editable_mode(resolution, editable);
 
+    // Reject any excluded packages, e.g., in a `pylock.toml`.
+    operations::check_excluded_resolution(&resolution, &excluded_packages)?;
+
+    if compatibility_report {
+        operations::diagnose_resolution(resolution.diagnostics(), printer)?;
+        return report_compatibility(
+            &resolution,
+            &tags,
+            &resolver_build_options,
+            output_format,
+            printer,
+        );
+    }
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -617,6 +752,7 @@ pub(crate) async fn pip_install(
         extra_build_variables,
         link_mode,
         &build_options,
+        &build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources,
@@ -624,7 +760,8 @@ pub(crate) async fn pip_install(
         workspace_cache,
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(&excluded_packages);
 
     // Sync the environment.
     match operations::install(
@@ -632,6 +769,7 @@ pub(crate) async fn pip_install(
         site_packages,
         InstallationStrategy::Permissive,
         modifications,
+        &ExtraneousOptions::default(),
         &reinstall,
         &build_options,
         link_mode,
@@ -646,13 +784,48 @@ pub(crate) async fn pip_install(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        InstallContext::Pip,
         dry_run,
         printer,
         preview,
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => {
+            if dry_run.enabled() {
+                report_plan(&changelog, output_format, printer)?;
+            }
+            if let Some(report) = report.as_deref() {
+                // In `--dry-run` mode, nothing was downloaded, so fetch the distributions that
+                // would be installed to read their metadata.
+                let prepared = if dry_run.enabled() {
+                    prepare_report_distributions(
+                        &changelog,
+                        &resolution,
+                        &cache,
+                        &tags,
+                        &hasher,
+                        &build_options,
+                        &client,
+                        &build_dispatch,
+                        state.in_flight(),
+                        &concurrency,
+                    )
+                    .await?
+                } else {
+                    Vec::new()
+                };
+                write_installation_report(
+                    report,
+                    Some(&resolution),
+                    &changelog,
+                    &prepared,
+                    &requested_extras,
+                    interpreter.markers(),
+                    printer,
+                )?;
+            }
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::with_system_certs(
                 client_builder.system_certs(),
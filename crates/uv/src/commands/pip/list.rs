@@ -17,15 +17,18 @@ use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType};
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{
-    DependencyMetadata, Diagnostic, IndexCapabilities, IndexLocations, Name, RequiresPython,
+    DependencyMetadata, Diagnostic, IndexCapabilities, IndexLocations, InstalledDist,
+    InstalledDistKind, Name, RequiresPython,
 };
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
+use uv_pypi_types::{DirectUrl, VcsKind};
 use uv_python::PythonRequest;
 use uv_python::{EnvironmentPreference, Prefix, PythonEnvironment, PythonPreference, Target};
 use uv_resolver::{ExcludeNewer, PrereleaseMode};
+use uv_warnings::write_warning_chain;
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::latest::LatestClient;
@@ -98,8 +101,15 @@ pub(crate) async fn pip_list(
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
         .collect_vec();
 
-    // Determine the latest version for each package.
-    let latest = if outdated && !results.is_empty() {
+    // Determine the latest version for each package. Packages that weren't installed from a
+    // registry (e.g., from a URL, a local path, or a Git repository) can't be compared against the
+    // index, so they're skipped.
+    let candidates = results
+        .iter()
+        .copied()
+        .filter(|dist| InstalledSource::from_dist(dist).is_none())
+        .collect_vec();
+    let latest = if outdated && !candidates.is_empty() {
         let capabilities = IndexCapabilities::default();
 
         let client_builder = client_builder.clone().keyring(keyring_provider);
@@ -134,41 +144,62 @@ pub(crate) async fn pip_list(
             requires_python: Some(&requires_python),
         };
 
-        let reporter = LatestVersionReporter::from(printer).with_length(results.len() as u64);
+        let reporter = LatestVersionReporter::from(printer).with_length(candidates.len() as u64);
 
         // Fetch the latest version for each package.
-        let mut fetches = futures::stream::iter(&results)
+        let mut fetches = futures::stream::iter(&candidates)
             .map(async |dist| {
                 let latest = client
                     .find_latest(dist.name(), None, &download_concurrency)
-                    .await?;
-                Ok::<(&PackageName, Option<DistFilename>), uv_client::Error>((dist.name(), latest))
+                    .await;
+                (dist.name(), latest)
             })
             .buffer_unordered(concurrency.downloads);
 
         let mut map = FxHashMap::default();
-        while let Some((package, version)) = fetches.next().await.transpose()? {
-            if let Some(version) = version.as_ref() {
-                reporter.on_fetch_version(package, version.version());
-            } else {
-                reporter.on_fetch_progress();
+        let mut errors = Vec::new();
+        while let Some((package, result)) = fetches.next().await {
+            match result {
+                Ok(version) => {
+                    if let Some(version) = version.as_ref() {
+                        reporter.on_fetch_version(package, version.version());
+                    } else {
+                        reporter.on_fetch_progress();
+                    }
+                    map.insert(package, version);
+                }
+                Err(err) => {
+                    reporter.on_fetch_progress();
+                    errors.push((package, err));
+                }
             }
-            map.insert(package, version);
         }
         reporter.on_fetch_complete();
+
+        // A failure to fetch a single package shouldn't prevent the rest from being listed.
+        errors.sort_unstable_by_key(|(package, _)| *package);
+        for (package, err) in errors {
+            let err = anyhow::Error::from(err)
+                .context(format!("Failed to fetch the latest version of `{package}`"));
+            write_warning_chain(err.as_ref())?;
+        }
+
         map
     } else {
         FxHashMap::default()
     };
 
-    // Remove any up-to-date packages from the results.
+    // Remove any up-to-date packages from the results, retaining any packages that couldn't be
+    // compared against the index.
     let results = if outdated {
         results
             .into_iter()
             .filter(|dist| {
-                latest[dist.name()]
-                    .as_ref()
-                    .is_some_and(|filename| filename.version() > dist.version())
+                InstalledSource::from_dist(dist).is_some()
+                    || latest
+                        .get(dist.name())
+                        .and_then(Option::as_ref)
+                        .is_some_and(|filename| filename.version() > dist.version())
             })
             .collect_vec()
     } else {
@@ -192,10 +223,12 @@ pub(crate) async fn pip_list(
                         .get(dist.name())
                         .and_then(|filename| filename.as_ref())
                         .map(FileType::from),
+                    source: outdated.then(|| InstalledSource::from_dist(dist)).flatten(),
                     editable_project_location: dist
                         .as_editable()
                         .and_then(|url| url.to_file_path().ok())
                         .map(|path| path.simplified_display().to_string()),
+                    legacy_editable: dist.is_legacy_editable(),
                 })
                 .collect_vec();
             let output = serde_json::to_string(&rows)?;
@@ -243,6 +276,11 @@ pub(crate) async fn pip_list(
                     rows: results
                         .iter()
                         .map(|dist| {
+                            // Packages that weren't installed from a registry are marked with
+                            // their source, rather than the type of the latest distribution.
+                            if let Some(source) = InstalledSource::from_dist(dist) {
+                                return source.to_string();
+                            }
                             latest
                                 .get(dist.name())
                                 .and_then(|filename| filename.as_ref())
@@ -264,9 +302,9 @@ pub(crate) async fn pip_list(
                     header: String::from("Editable project location"),
                     rows: results
                         .iter()
-                        .map(|dist| dist.as_editable())
-                        .map(|url| {
-                            url.and_then(|url| url.to_file_path().ok())
+                        .map(|dist| {
+                            dist.as_editable()
+                                .and_then(|url| url.to_file_path().ok())
                                 .map(|path| path.simplified_display().to_string())
                                 .unwrap_or_default()
                         })
@@ -274,6 +312,24 @@ pub(crate) async fn pip_list(
                 });
             }
 
+            // `.egg-link` installs (e.g., from `setup.py develop`) are marked in a separate column,
+            // which is only displayed if at least one is found.
+            if results.iter().any(|dist| dist.is_legacy_editable()) {
+                columns.push(Column {
+                    header: String::from("Editable mode"),
+                    rows: results
+                        .iter()
+                        .map(|dist| {
+                            if dist.is_legacy_editable() {
+                                String::from("legacy")
+                            } else {
+                                String::new()
+                            }
+                        })
+                        .collect_vec(),
+                });
+            }
+
             for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
                 writeln!(printer.stdout_important(), "{}", elems.join(" ").trim_end())?;
             }
@@ -349,6 +405,73 @@ impl From<&DistFilename> for FileType {
     }
 }
 
+/// The source of a package that wasn't installed from a registry, and so can't be compared against
+/// the latest version on an index.
+#[derive(Debug, Copy, Clone)]
+enum InstalledSource {
+    /// An editable install of a local directory.
+    Editable,
+    /// A non-editable install of a local directory.
+    Directory,
+    /// A local archive (e.g., a wheel or source distribution on disk).
+    Path,
+    /// A remote archive.
+    Url,
+    /// A version control system repository (e.g., a Git repository).
+    Vcs(VcsKind),
+}
+
+impl InstalledSource {
+    /// Determine the [`InstalledSource`] of an installed distribution, or `None` if it was
+    /// installed from a registry.
+    fn from_dist(dist: &InstalledDist) -> Option<Self> {
+        match &dist.kind {
+            InstalledDistKind::Registry(_)
+            | InstalledDistKind::EggInfoFile(_)
+            | InstalledDistKind::EggInfoDirectory(_) => None,
+            InstalledDistKind::LegacyEditable(_) => Some(Self::Editable),
+            InstalledDistKind::Url(dist) => match &*dist.direct_url {
+                DirectUrl::LocalDirectory { dir_info, .. } => {
+                    if dir_info.editable.unwrap_or(false) {
+                        Some(Self::Editable)
+                    } else {
+                        Some(Self::Directory)
+                    }
+                }
+                DirectUrl::ArchiveUrl { url, .. } => {
+                    if url.starts_with("file:") {
+                        Some(Self::Path)
+                    } else {
+                        Some(Self::Url)
+                    }
+                }
+                DirectUrl::VcsUrl { vcs_info, .. } => Some(Self::Vcs(vcs_info.vcs)),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for InstalledSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Editable => write!(f, "editable"),
+            Self::Directory => write!(f, "directory"),
+            Self::Path => write!(f, "path"),
+            Self::Url => write!(f, "url"),
+            Self::Vcs(vcs) => write!(f, "{vcs}"),
+        }
+    }
+}
+
+impl Serialize for InstalledSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 /// An entry in a JSON list of installed packages.
 #[derive(Debug, Serialize)]
 struct Entry {
@@ -359,16 +482,20 @@ struct Entry {
     #[serde(skip_serializing_if = "Option::is_none")]
     latest_filetype: Option<FileType>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<InstalledSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     editable_project_location: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    legacy_editable: bool,
 }
 
 /// A column in a table.
 #[derive(Debug)]
-struct Column {
+pub(super) struct Column {
     /// The header of the column.
-    header: String,
+    pub(super) header: String,
     /// The rows of the column.
-    rows: Vec<String>,
+    pub(super) rows: Vec<String>,
 }
 
 impl<'a> Column {
@@ -381,7 +508,7 @@ impl<'a> Column {
     }
 
     /// Return an iterator of the column, with the header and rows formatted to the maximum width.
-    fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
+    pub(super) fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
         let max_width = self.max_width();
         let header = vec![
             format!("{0:width$}", self.header, width = max_width),
@@ -398,7 +525,7 @@ impl<'a> Column {
 ///
 /// A combination of [`itertools::multizip`] and [`itertools::izip`].
 #[derive(Debug)]
-struct MultiZip<T>(Vec<T>);
+pub(super) struct MultiZip<T>(pub(super) Vec<T>);
 
 impl<T> Iterator for MultiZip<T>
 where
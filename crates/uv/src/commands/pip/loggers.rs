@@ -200,6 +200,10 @@ impl InstallLogger for DefaultInstallLogger {
                         kind: ChangeEventKind::Reinstalled,
                     }),
             )
+            .chain(changelog.kept.iter().map(|distribution| ChangeEvent {
+                dist: distribution,
+                kind: ChangeEventKind::Kept,
+            }))
             .sorted_unstable_by(|a, b| {
                 a.dist
                     .name()
@@ -236,6 +240,16 @@ impl InstallLogger for DefaultInstallLogger {
                         event.dist.long_specifier().dimmed()
                     )?;
                 }
+                ChangeEventKind::Kept => {
+                    writeln!(
+                        printer.stderr(),
+                        " {} {}{} {}",
+                        "=".cyan(),
+                        event.dist.name().bold(),
+                        event.dist.long_specifier().dimmed(),
+                        "kept (extraneous)".dimmed()
+                    )?;
+                }
             }
         }
         Ok(())
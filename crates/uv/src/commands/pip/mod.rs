@@ -1,18 +1,22 @@
 use std::borrow::Cow;
 
-use uv_configuration::TargetTriple;
+use uv_configuration::{TargetAbiFlags, TargetImplementation, TargetTriple};
 use uv_platform_tags::{Tags, TagsError, TagsOptions};
 use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{Interpreter, PythonVersion};
 
 pub(crate) mod check;
+pub(crate) mod compatibility;
 pub(crate) mod compile;
+pub(crate) mod download;
 pub(crate) mod freeze;
 pub(crate) mod install;
 pub(crate) mod latest;
 pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
+pub(crate) mod plan;
+pub(crate) mod report;
 pub(crate) mod show;
 pub(crate) mod sync;
 pub(crate) mod tree;
@@ -21,28 +25,40 @@ pub(crate) mod uninstall;
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
+    python_implementation: Option<TargetImplementation>,
     interpreter: &Interpreter,
 ) -> ResolverMarkerEnvironment {
-    match (python_platform, python_version) {
-        (Some(python_platform), Some(python_version)) => ResolverMarkerEnvironment::from(
-            python_version.markers(&python_platform.markers(interpreter.markers())),
-        ),
-        (Some(python_platform), None) => {
-            ResolverMarkerEnvironment::from(python_platform.markers(interpreter.markers()))
-        }
-        (None, Some(python_version)) => {
-            ResolverMarkerEnvironment::from(python_version.markers(interpreter.markers()))
-        }
-        (None, None) => interpreter.resolver_marker_environment(),
+    if python_platform.is_none() && python_version.is_none() && python_implementation.is_none() {
+        return interpreter.resolver_marker_environment();
     }
+
+    let mut markers = Cow::Borrowed(interpreter.markers());
+    if let Some(python_platform) = python_platform {
+        markers = Cow::Owned(python_platform.markers(&markers));
+    }
+    if let Some(python_implementation) = python_implementation {
+        let implementation_version =
+            resolution_implementation_version(python_version, python_implementation, interpreter);
+        markers = Cow::Owned(python_implementation.markers(&markers, implementation_version));
+    }
+    if let Some(python_version) = python_version {
+        markers = Cow::Owned(python_version.markers(&markers));
+    }
+    ResolverMarkerEnvironment::from(markers.into_owned())
 }
 
 pub(crate) fn resolution_tags<'env>(
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
+    python_implementation: Option<TargetImplementation>,
+    python_abiflags: Option<TargetAbiFlags>,
     interpreter: &'env Interpreter,
 ) -> Result<Cow<'env, Tags>, TagsError> {
-    if python_platform.is_none() && python_version.is_none() {
+    if python_platform.is_none()
+        && python_version.is_none()
+        && python_implementation.is_none()
+        && python_abiflags.is_none()
+    {
         return Ok(Cow::Borrowed(interpreter.tags()?));
     }
 
@@ -61,17 +77,65 @@ pub(crate) fn resolution_tags<'env>(
         interpreter.python_tuple()
     };
 
+    let (implementation_name, implementation_tuple) = if let Some(python_implementation) =
+        python_implementation
+    {
+        (
+            python_implementation.implementation_name(),
+            resolution_implementation_version(python_version, python_implementation, interpreter),
+        )
+    } else {
+        (
+            interpreter.implementation_name(),
+            interpreter.implementation_tuple(),
+        )
+    };
+
+    // The ABI flags of the interpreter only carry over if the implementation is unchanged (e.g., a
+    // free-threaded CPython interpreter says nothing about the ABI of a PyPy target).
+    let (gil_disabled, debug_enabled) = if let Some(python_abiflags) = python_abiflags {
+        (
+            python_abiflags.gil_disabled(),
+            python_abiflags.debug_enabled(),
+        )
+    } else if implementation_name == interpreter.implementation_name() {
+        (interpreter.gil_disabled(), interpreter.debug_enabled())
+    } else {
+        (false, false)
+    };
+
     let tags = Tags::from_env(
         platform,
         version_tuple,
-        interpreter.implementation_name(),
-        interpreter.implementation_tuple(),
+        implementation_name,
+        implementation_tuple,
         TagsOptions {
             manylinux_compatible,
-            gil_disabled: interpreter.gil_disabled(),
-            debug_enabled: interpreter.debug_enabled(),
+            gil_disabled,
+            debug_enabled,
             is_cross: true,
         },
     )?;
     Ok(Cow::Owned(tags))
 }
+
+/// Returns the implementation version to use when resolving for the given implementation, which
+/// may differ from that of the interpreter.
+fn resolution_implementation_version(
+    python_version: Option<&PythonVersion>,
+    python_implementation: TargetImplementation,
+    interpreter: &Interpreter,
+) -> (u8, u8) {
+    let version_tuple = if let Some(python_version) = python_version {
+        (python_version.major(), python_version.minor())
+    } else {
+        interpreter.python_tuple()
+    };
+    if python_implementation == TargetImplementation::CPython {
+        version_tuple
+    } else if python_implementation.implementation_name() == interpreter.implementation_name() {
+        interpreter.implementation_tuple()
+    } else {
+        python_implementation.implementation_version(version_tuple)
+    }
+}
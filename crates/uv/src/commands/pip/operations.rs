@@ -4,26 +4,33 @@ use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, anyhow};
+use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, RegistryClient};
+use uv_client::{BaseClientBuilder, MetadataFormat, RegistryClient, VersionFiles};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, ExcludeDependency, Excludes,
-    ExtrasSpecification, Override, Overrides, Reinstall, Upgrade,
+    ExtrasSpecification, Override, Overrides, Reinstall, Replacements, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
-    CachedDist, DependencyMetadata, Diagnostic, Dist, InstalledDist, InstalledVersion, LocalDist,
-    NameRequirementSpecification, Requirement, ResolutionDiagnostic, UnresolvedRequirement,
-    UnresolvedRequirementSpecification, VersionOrUrlRef,
+    BuiltDist, CachedDist, DependencyMetadata, Diagnostic, Dist, IndexCapabilities, IndexFormat,
+    IndexLocations, IndexMetadataRef, InstalledDist, InstalledVersion, LocalDist,
+    NameRequirementSpecification, Requirement, ResolutionDiagnostic, ResolvedDist, SourceDist,
+    UnresolvedRequirement, UnresolvedRequirementSpecification, VersionOrUrlRef,
+};
+use uv_distribution_types::{
+    DistributionMetadata, InstallContext, InstallReason, InstallRequester, InstalledMetadata, Name,
+    Resolution,
 };
-use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, Plan, Planner, Preparer, SitePackages};
@@ -32,7 +39,7 @@ use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, RequirementOrigin, VerbatimUrl};
 use uv_platform_tags::Tags;
 use uv_preview::Preview;
-use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
+use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment, Yanked};
 use uv_python::managed::{ManagedPythonInstallation, PythonMinorVersionLink};
 use uv_python::{PythonEnvironment, PythonInstallation};
 use uv_requirements::{
@@ -40,8 +47,10 @@ use uv_requirements::{
     RequirementsSpecification, SourceTree, SourceTreeResolution, SourceTreeResolver,
 };
 use uv_resolver::{
-    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput, UpgradePackages,
+    DependencyMode, Exclusions, Explanation, FlatIndex, InMemoryIndex, Manifest, MissingMetadata,
+    MissingMetadataKind, MissingMetadataPolicy, NoSolutionError, Options, Preference, Preferences,
+    PythonRequirement, Relaxation, RelaxationKind, ResolveError, Resolver, ResolverEnvironment,
+    ResolverOutput, UpgradePackages, YankedPolicy,
 };
 use uv_tool::InstalledTools;
 use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
@@ -102,6 +111,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     overrides: Vec<UnresolvedRequirementSpecification>,
     lowered_overrides: Vec<Override<Requirement>>,
     excludes: Vec<ExcludeDependency>,
+    replacements: Replacements,
     source_trees: Vec<SourceTree>,
     mut project: Option<PackageName>,
     workspace_members: BTreeSet<PackageName>,
@@ -356,7 +366,19 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         workspace_members,
         exclusions,
         lookaheads,
-    );
+    )
+    .with_replacements(replacements);
+
+    // If requested, retain the resolver inputs, to search for relaxations if resolution fails.
+    let relaxation_inputs = options.hint_on_failure.then(|| {
+        Box::new((
+            manifest.clone(),
+            options.clone(),
+            resolver_env.clone(),
+            conflicts.clone(),
+            installed_packages.clone(),
+        ))
+    });
 
     // Resolve the dependencies.
     let resolution = {
@@ -389,14 +411,144 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         )?
         .with_reporter(Arc::new(reporter));
 
-        resolver.resolve().await?
+        match (resolver.resolve().await, relaxation_inputs) {
+            (Err(ResolveError::NoSolution(mut err)), Some(inputs)) => {
+                let (manifest, options, resolver_env, conflicts, installed_packages) = *inputs;
+                let relaxations = Box::pin(find_relaxations(
+                    &err,
+                    manifest,
+                    options,
+                    &python_requirement,
+                    resolver_env,
+                    current_environment,
+                    conflicts,
+                    tags,
+                    flat_index,
+                    index,
+                    &hasher,
+                    build_dispatch,
+                    installed_packages,
+                    client,
+                    concurrency,
+                ))
+                .await;
+                err.set_relaxations(relaxations);
+                return Err(ResolveError::NoSolution(err).into());
+            }
+            (result, _) => result?,
+        }
     };
 
     logger.on_complete(resolution.len(), start, printer)?;
 
+    // Notify the user of any warnings emitted by build backends during the resolution.
+    report_build_warnings(build_dispatch, printer)?;
+
     Ok((resolution, hasher))
 }
 
+/// The maximum time to spend searching for relaxations after a failed resolution.
+const RELAXATION_BUDGET: Duration = Duration::from_secs(10);
+
+/// The maximum number of relaxations to suggest after a failed resolution.
+const MAX_RELAXATIONS: usize = 3;
+
+/// Search for relaxations of the direct requirements that would allow a failed resolution to
+/// succeed (e.g., `--hint-on-failure`).
+///
+/// Each direct requirement on a package that's involved in the conflict is relaxed in turn, and
+/// the resolution is retried with the remaining inputs unchanged. The search is bounded by
+/// [`RELAXATION_BUDGET`], and stops after [`MAX_RELAXATIONS`] successful relaxations.
+async fn find_relaxations<InstalledPackages: InstalledPackagesProvider>(
+    err: &NoSolutionError,
+    manifest: Manifest,
+    options: Options,
+    python_requirement: &PythonRequirement,
+    resolver_env: ResolverEnvironment,
+    current_environment: &MarkerEnvironment,
+    conflicts: Conflicts,
+    tags: Option<&Tags>,
+    flat_index: &FlatIndex,
+    index: &InMemoryIndex,
+    hasher: &HashStrategy,
+    build_dispatch: &BuildDispatch<'_>,
+    installed_packages: InstalledPackages,
+    client: &RegistryClient,
+    concurrency: &Concurrency,
+) -> Vec<Relaxation> {
+    let deadline = Instant::now() + RELAXATION_BUDGET;
+    let packages = err.packages().collect::<FxHashSet<_>>();
+    let requirements = manifest.direct_requirements();
+
+    let mut relaxations = Vec::new();
+    'search: for (position, requirement) in requirements.iter().enumerate() {
+        if !packages.contains(&requirement.name) {
+            continue;
+        }
+        for kind in [RelaxationKind::UpperBound, RelaxationKind::LowerBound] {
+            if relaxations.len() >= MAX_RELAXATIONS {
+                break 'search;
+            }
+            let Some(relaxed) = kind.relax(requirement) else {
+                continue;
+            };
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                debug!("Exceeded the time budget while searching for relaxations");
+                break 'search;
+            }
+
+            debug!("Retrying resolution with relaxed requirement: `{relaxed}`");
+            let mut relaxed_requirements = requirements.to_vec();
+            relaxed_requirements[position] = relaxed;
+            let resolver = match Resolver::new(
+                manifest.clone().with_requirements(relaxed_requirements),
+                options.clone(),
+                python_requirement,
+                resolver_env.clone(),
+                current_environment,
+                conflicts.clone(),
+                tags,
+                flat_index,
+                index,
+                hasher,
+                build_dispatch,
+                installed_packages.clone(),
+                DistributionDatabase::new(
+                    client,
+                    build_dispatch,
+                    concurrency.downloads_semaphore.clone(),
+                ),
+            ) {
+                Ok(resolver) => resolver,
+                Err(err) => {
+                    debug!("Failed to initialize resolver for relaxed requirement: {err}");
+                    continue;
+                }
+            };
+
+            match tokio::time::timeout(remaining, resolver.resolve()).await {
+                Ok(Ok(resolution)) => {
+                    let Some(version) = resolution.versions(&requirement.name).max() else {
+                        continue;
+                    };
+                    if let Some(relaxation) = Relaxation::new(kind, requirement, version.clone()) {
+                        relaxations.push(relaxation);
+                    }
+                }
+                Ok(Err(err)) => {
+                    debug!("Resolution with relaxed requirement failed: {err}");
+                }
+                Err(_) => {
+                    debug!("Exceeded the time budget while searching for relaxations");
+                    break 'search;
+                }
+            }
+        }
+    }
+    relaxations
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum Modifications {
     /// Use `pip install` semantics, whereby existing installations are left as-is, unless they are
@@ -412,6 +564,53 @@ pub(crate) enum Modifications {
     Exact,
 }
 
+/// The handling of extraneous packages under [`Modifications::Exact`].
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ExtraneousOptions {
+    /// The extraneous packages to retain in the environment, rather than remove.
+    pub(crate) keep: Vec<PackageName>,
+    /// The packages among `keep` that were requested on the command line (i.e., with `--keep`),
+    /// which are expected to be installed.
+    pub(crate) requested: Vec<PackageName>,
+    /// Whether to report the extraneous packages without modifying the environment.
+    pub(crate) list: bool,
+}
+
+impl ExtraneousOptions {
+    /// Partition the extraneous distributions into those that should be removed and those that
+    /// should be kept.
+    ///
+    /// Only packages that are absent from the resolution are considered extraneous; installed
+    /// packages that conflict with a resolved package (e.g., by version) are reinstalled, and so
+    /// can never be kept.
+    fn partition(
+        &self,
+        modifications: Modifications,
+        extraneous: Vec<InstalledDist>,
+    ) -> (Vec<InstalledDist>, Vec<InstalledDist>) {
+        match modifications {
+            // If we're in `install` mode, ignore any extraneous distributions.
+            Modifications::Sufficient => (vec![], vec![]),
+            Modifications::Exact => extraneous
+                .into_iter()
+                .partition(|dist| !self.keep.contains(dist.name())),
+        }
+    }
+
+    /// Warn about packages requested with `--keep` that aren't installed, as they're likely
+    /// misspelled.
+    fn warn_unmatched(&self, site_packages: &SitePackages) {
+        for name in &self.requested {
+            if site_packages.get_packages(name).is_empty() {
+                warn_user!(
+                    "`{}` was passed to `--keep`, but is not installed",
+                    name.cyan()
+                );
+            }
+        }
+    }
+}
+
 /// A distribution which was or would be modified
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum ChangedDist {
@@ -500,6 +699,8 @@ pub(crate) struct Changelog {
     pub(crate) uninstalled: HashSet<ChangedDist>,
     /// The distributions that were reinstalled.
     pub(crate) reinstalled: HashSet<ChangedDist>,
+    /// The extraneous distributions that were kept in the environment.
+    pub(crate) kept: HashSet<ChangedDist>,
 }
 
 impl Changelog {
@@ -522,6 +723,7 @@ impl Changelog {
             installed,
             uninstalled,
             reinstalled,
+            kept: HashSet::default(),
         }
     }
 
@@ -542,6 +744,26 @@ impl Changelog {
         Self::from_local(installed, Vec::new())
     }
 
+    /// Include distributions that were updated in-place (e.g., to regenerate their entry points)
+    /// as reinstalled.
+    #[must_use]
+    fn with_updated(mut self, updated: impl IntoIterator<Item = InstalledDist>) -> Self {
+        self.reinstalled.extend(
+            updated
+                .into_iter()
+                .map(|dist| ChangedDist::Local(dist.into())),
+        );
+        self
+    }
+
+    /// Include extraneous distributions that were kept in the environment.
+    #[must_use]
+    fn with_kept(mut self, kept: impl IntoIterator<Item = InstalledDist>) -> Self {
+        self.kept
+            .extend(kept.into_iter().map(|dist| ChangedDist::Local(dist.into())));
+        self
+    }
+
     /// Returns `true` if the changelog includes a distribution with the given name, either via
     /// an installation or uninstallation.
     pub(crate) fn includes(&self, name: &PackageName) -> bool {
@@ -555,6 +777,48 @@ impl Changelog {
     }
 }
 
+/// Warn about legacy editable installs that can't be uninstalled, as they may shadow the installed
+/// packages of the same name, or are extraneous under [`Modifications::Exact`].
+fn report_unmanaged_legacy_editables(
+    resolution: &Resolution,
+    site_packages: &SitePackages,
+    modifications: Modifications,
+    extraneous_options: &ExtraneousOptions,
+) {
+    for legacy in site_packages.unmanaged_legacy_editables() {
+        let resolved = resolution
+            .distributions()
+            .any(|dist| dist.name() == &legacy.name);
+        let extraneous = matches!(modifications, Modifications::Exact)
+            && !resolved
+            && !extraneous_options.keep.contains(&legacy.name);
+        if !resolved && !extraneous {
+            continue;
+        }
+        let pth_entry = match (&legacy.target, &legacy.easy_install_pth) {
+            (Some(target), Some(easy_install_pth)) => format!(
+                " and the `{}` entry from {}",
+                target.simplified_display(),
+                easy_install_pth.simplified_display().cyan()
+            ),
+            _ => String::new(),
+        };
+        if resolved {
+            warn_user!(
+                "The legacy editable install of `{}` at {} can't be uninstalled, as its metadata is missing; remove it{pth_entry} to avoid shadowing the installed package",
+                legacy.name,
+                legacy.egg_link.simplified_display().cyan(),
+            );
+        } else {
+            warn_user!(
+                "The legacy editable install of `{}` at {} is extraneous, but can't be uninstalled, as its metadata is missing; remove it{pth_entry} manually",
+                legacy.name,
+                legacy.egg_link.simplified_display().cyan(),
+            );
+        }
+    }
+}
+
 /// Install a set of requirements into the current environment.
 ///
 /// Returns a [`Changelog`] summarizing the changes made to the environment.
@@ -563,6 +827,7 @@ pub(crate) async fn install(
     site_packages: SitePackages,
     installation: InstallationStrategy,
     modifications: Modifications,
+    extraneous_options: &ExtraneousOptions,
     reinstall: &Reinstall,
     build_options: &BuildOptions,
     link_mode: LinkMode,
@@ -577,6 +842,7 @@ pub(crate) async fn install(
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    install_context: InstallContext,
     dry_run: DryRun,
     printer: Printer,
     preview: Preview,
@@ -587,7 +853,7 @@ pub(crate) async fn install(
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let plan = Planner::new(resolution)
         .build(
-            site_packages,
+            site_packages.clone(),
             installation,
             reinstall,
             build_options,
@@ -597,46 +863,78 @@ pub(crate) async fn install(
             build_dispatch.config_settings_package(),
             build_dispatch.extra_build_requires(),
             build_dispatch.extra_build_variables(),
+            build_dispatch.build_runtime().via_sdist(),
             cache,
             venv,
             tags,
         )
         .context("Failed to determine installation plan")?;
 
+    report_unmanaged_legacy_editables(
+        resolution,
+        &site_packages,
+        modifications,
+        extraneous_options,
+    );
+    if matches!(modifications, Modifications::Exact) {
+        extraneous_options.warn_unmatched(&site_packages);
+    }
+
+    if extraneous_options.list {
+        let (extraneous, kept) = extraneous_options.partition(modifications, plan.extraneous);
+        return report_extraneous(extraneous, kept, logger.as_ref(), printer);
+    }
+
     if dry_run.enabled() {
         return report_dry_run(
             dry_run,
             resolution,
             plan,
             modifications,
+            extraneous_options,
             start,
             logger.as_ref(),
             printer,
         );
     }
 
+    // Determine the reason for installing each package, to record in its `.dist-info` directory.
+    let install_reasons = if installer_metadata {
+        update_install_reasons(
+            resolution,
+            &site_packages,
+            &plan,
+            modifications,
+            install_context,
+        )?
+    } else {
+        FxHashMap::default()
+    };
+
     let Plan {
         cached,
         remote,
         reinstalls,
+        entry_points,
         extraneous,
     } = plan;
 
-    // If we're in `install` mode, ignore any extraneous distributions.
-    let extraneous = match modifications {
-        Modifications::Sufficient => vec![],
-        Modifications::Exact => extraneous,
-    };
+    let (extraneous, kept) = extraneous_options.partition(modifications, extraneous);
 
     // Nothing to do.
     if remote.is_empty()
         && cached.is_empty()
         && reinstalls.is_empty()
+        && entry_points.is_empty()
         && extraneous.is_empty()
         && !compile
     {
         logger.on_check(resolution.len(), start, printer, dry_run)?;
-        return Ok(Changelog::default());
+        let changelog = Changelog::default().with_kept(kept);
+        if !changelog.kept.is_empty() {
+            logger.on_complete(&changelog, printer, dry_run)?;
+        }
+        return Ok(changelog);
     }
 
     // Partition into two sets: those that require build isolation, and those that disable it. This
@@ -646,6 +944,7 @@ pub(crate) async fn install(
         cached,
         remote,
         reinstalls,
+        entry_points,
         extraneous,
     }
     .partition(|name| build_dispatch.build_isolation().is_isolated(Some(name)));
@@ -655,10 +954,11 @@ pub(crate) async fn install(
 
     let mut installs = vec![];
     let mut uninstalls = vec![];
+    let mut updates = vec![];
 
     // Execute the isolated-build phase.
     if has_isolated_phase {
-        let (isolated_installs, isolated_uninstalls) = execute_plan(
+        let (isolated_installs, isolated_uninstalls, isolated_updates) = execute_plan(
             isolated_phase,
             None,
             resolution,
@@ -674,16 +974,19 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            &install_reasons,
+            install_context,
             printer,
             preview,
         )
         .await?;
         installs.extend(isolated_installs);
         uninstalls.extend(isolated_uninstalls);
+        updates.extend(isolated_updates);
     }
 
     if has_shared_phase {
-        let (shared_installs, shared_uninstalls) = execute_plan(
+        let (shared_installs, shared_uninstalls, shared_updates) = execute_plan(
             shared_phase,
             if has_isolated_phase {
                 Some(InstallPhase::Shared)
@@ -703,12 +1006,15 @@ pub(crate) async fn install(
             venv,
             logger.as_ref(),
             installer_metadata,
+            &install_reasons,
+            install_context,
             printer,
             preview,
         )
         .await?;
         installs.extend(shared_installs);
         uninstalls.extend(shared_uninstalls);
+        updates.extend(shared_updates);
     }
 
     if compile {
@@ -716,11 +1022,16 @@ pub(crate) async fn install(
     }
 
     // Construct a summary of the changes made to the environment.
-    let changelog = Changelog::from_local(installs, uninstalls);
+    let changelog = Changelog::from_local(installs, uninstalls)
+        .with_updated(updates)
+        .with_kept(kept);
 
     // Notify the user of any environment modifications.
     logger.on_complete(&changelog, printer, dry_run)?;
 
+    // Notify the user of any warnings emitted by build backends.
+    report_build_warnings(build_dispatch, printer)?;
+
     Ok(changelog)
 }
 
@@ -755,13 +1066,16 @@ async fn execute_plan(
     venv: &PythonEnvironment,
     logger: &dyn InstallLogger,
     installer_metadata: bool,
+    install_reasons: &FxHashMap<PackageName, InstallReason>,
+    install_context: InstallContext,
     printer: Printer,
     preview: Preview,
-) -> Result<(Vec<CachedDist>, Vec<InstalledDist>), Error> {
+) -> Result<(Vec<CachedDist>, Vec<InstalledDist>, Vec<InstalledDist>), Error> {
     let Plan {
         cached,
         remote,
         reinstalls,
+        entry_points,
         extraneous,
     } = plan;
 
@@ -780,8 +1094,10 @@ async fn execute_plan(
                 client,
                 build_dispatch,
                 concurrency.downloads_semaphore.clone(),
-            ),
+            )
+            .with_locked(matches!(install_context, InstallContext::Project)),
         )
+        .with_fail_fast(concurrency.fail_fast)
         .with_reporter(Arc::new(
             PrepareReporter::from(printer).with_length(remote.len() as u64),
         ));
@@ -842,6 +1158,16 @@ async fn execute_plan(
         logger.on_uninstall(uninstalls.len(), start, printer, DryRun::Disabled)?;
     }
 
+    // Regenerate the entry points of any editables whose entry points changed.
+    let mut updates = Vec::with_capacity(entry_points.len());
+    for update in entry_points {
+        update
+            .execute(venv)
+            .with_context(|| format!("Failed to update entry points for: {}", update.dist))?;
+        debug!("Updated entry points for: {}", update.dist);
+        updates.push(update.dist);
+    }
+
     // Install the resolved distributions.
     let mut installs = wheels.into_iter().chain(cached).collect::<Vec<_>>();
     if !installs.is_empty() {
@@ -850,6 +1176,7 @@ async fn execute_plan(
             .with_link_mode(link_mode)
             .with_cache(cache)
             .with_installer_metadata(installer_metadata)
+            .with_install_reasons(install_reasons.clone())
             .with_reporter(Arc::new(
                 InstallReporter::from(printer).with_length(installs.len() as u64),
             ))
@@ -861,7 +1188,115 @@ async fn execute_plan(
         logger.on_install(installs.len(), start, printer, DryRun::Disabled)?;
     }
 
-    Ok((installs, uninstalls))
+    Ok((installs, uninstalls, updates))
+}
+
+/// Determine the reason for installing each package in the [`Resolution`], updating the recorded
+/// reasons of any packages that are already installed and won't be reinstalled.
+fn update_install_reasons(
+    resolution: &Resolution,
+    site_packages: &SitePackages,
+    plan: &Plan,
+    modifications: Modifications,
+    install_context: InstallContext,
+) -> Result<FxHashMap<PackageName, InstallReason>, Error> {
+    let mut install_reasons = InstallReason::from_resolution(resolution, install_context);
+
+    // With `pip install` semantics, packages outside the resolution may also depend on a package,
+    // so retain any recorded requesters that are still installed.
+    if matches!(modifications, Modifications::Sufficient) {
+        for (name, install_reason) in &mut install_reasons {
+            for dist in site_packages.get_packages(name) {
+                let Ok(Some(existing)) = dist.read_install_reason() else {
+                    continue;
+                };
+                *install_reason = install_reason
+                    .clone()
+                    .merge(retain_installed(existing, site_packages));
+            }
+        }
+    }
+
+    // Update the recorded reasons of any installed packages that won't be reinstalled.
+    let installs = plan
+        .cached
+        .iter()
+        .map(Name::name)
+        .chain(plan.remote.iter().map(|dist| dist.name()))
+        .collect::<FxHashSet<_>>();
+    for (name, install_reason) in &install_reasons {
+        if installs.contains(name) {
+            continue;
+        }
+        for dist in site_packages.get_packages(name) {
+            write_install_reason(dist, install_reason)?;
+        }
+    }
+
+    Ok(install_reasons)
+}
+
+/// Record that the given requirements were requested directly, for an environment that already
+/// satisfies them (and so won't be modified).
+pub(crate) fn record_direct_requests(
+    site_packages: &SitePackages,
+    requirements: &[UnresolvedRequirementSpecification],
+    install_context: InstallContext,
+) -> Result<(), Error> {
+    for requirement in requirements {
+        let dists = match &requirement.requirement {
+            UnresolvedRequirement::Named(requirement) => {
+                site_packages.get_packages(&requirement.name)
+            }
+            UnresolvedRequirement::Unnamed(requirement) => {
+                site_packages.get_urls(requirement.url.verbatim.raw())
+            }
+        };
+        for dist in dists {
+            let direct = InstallReason {
+                context: install_context,
+                requested_by: vec![InstallRequester::Direct { group: None }],
+            };
+            let install_reason = match dist.read_install_reason() {
+                Ok(Some(existing)) => retain_installed(existing, site_packages).merge(direct),
+                _ => direct,
+            };
+            write_install_reason(dist, &install_reason)?;
+        }
+    }
+    Ok(())
+}
+
+/// Remove any requesters from an [`InstallReason`] that are no longer installed.
+fn retain_installed(
+    mut install_reason: InstallReason,
+    site_packages: &SitePackages,
+) -> InstallReason {
+    install_reason
+        .requested_by
+        .retain(|requester| match requester {
+            InstallRequester::Direct { .. } => true,
+            InstallRequester::Dependency { package, .. } => {
+                !site_packages.get_packages(package).is_empty()
+            }
+        });
+    install_reason
+}
+
+/// Write the [`InstallReason`] for an installed distribution, if it was installed by uv and its
+/// recorded reason has changed.
+fn write_install_reason(dist: &InstalledDist, install_reason: &InstallReason) -> Result<(), Error> {
+    // Leave the metadata of packages installed by other tools as-is.
+    if !matches!(dist.read_installer(), Ok(Some(installer)) if installer == "uv") {
+        return Ok(());
+    }
+    if dist.read_install_reason().ok().flatten().as_ref() == Some(install_reason) {
+        return Ok(());
+    }
+    debug!("Updating install reason for: {dist}");
+    uv_install_wheel::update_install_reason(dist.install_path(), install_reason)
+        .with_context(|| format!("Failed to update install reason for: {dist}"))?;
+    Ok(())
 }
 
 /// Display a message about the interpreter that was selected for the operation.
@@ -986,6 +1421,7 @@ fn report_dry_run(
     resolution: &Resolution,
     plan: Plan,
     modifications: Modifications,
+    extraneous_options: &ExtraneousOptions,
     start: std::time::Instant,
     logger: &dyn InstallLogger,
     printer: Printer,
@@ -994,19 +1430,25 @@ fn report_dry_run(
         cached,
         remote,
         reinstalls,
+        entry_points,
         extraneous,
     } = plan;
 
-    // If we're in `install` mode, ignore any extraneous distributions.
-    let extraneous = match modifications {
-        Modifications::Sufficient => vec![],
-        Modifications::Exact => extraneous,
-    };
+    let (extraneous, kept) = extraneous_options.partition(modifications, extraneous);
 
     // Nothing to do.
-    if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
+    if remote.is_empty()
+        && cached.is_empty()
+        && reinstalls.is_empty()
+        && entry_points.is_empty()
+        && extraneous.is_empty()
+    {
         logger.on_check(resolution.len(), start, printer, dry_run)?;
-        return Ok(Changelog::default());
+        let changelog = Changelog::default().with_kept(kept);
+        if !changelog.kept.is_empty() {
+            logger.on_complete(&changelog, printer, dry_run)?;
+        }
+        return Ok(changelog);
     }
 
     // Download, build, and unzip any missing distributions.
@@ -1041,7 +1483,9 @@ fn report_dry_run(
             .map(|dist| ChangedDist::Local(dist.into())),
     );
 
-    let changelog = Changelog::new(installed, uninstalled);
+    let changelog = Changelog::new(installed, uninstalled)
+        .with_updated(entry_points.into_iter().map(|update| update.dist))
+        .with_kept(kept);
 
     logger.on_complete(&changelog, printer, dry_run)?;
 
@@ -1052,6 +1496,31 @@ fn report_dry_run(
     Ok(changelog)
 }
 
+/// Report the extraneous distributions that would be removed from the environment, without
+/// modifying it.
+fn report_extraneous(
+    extraneous: Vec<InstalledDist>,
+    kept: Vec<InstalledDist>,
+    logger: &dyn InstallLogger,
+    printer: Printer,
+) -> Result<Changelog, Error> {
+    if extraneous.is_empty() {
+        writeln!(printer.stderr(), "No extraneous packages to remove")?;
+    } else {
+        let s = if extraneous.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Would remove {}",
+            format!("{} extraneous package{s}", extraneous.len()).bold()
+        )?;
+    }
+
+    let changelog = Changelog::from_local(Vec::new(), extraneous).with_kept(kept);
+    logger.on_complete(&changelog, printer, DryRun::Enabled)?;
+
+    Ok(Changelog::default())
+}
+
 /// Report any diagnostics on resolved distributions.
 pub(crate) fn diagnose_resolution(
     diagnostics: &[ResolutionDiagnostic],
@@ -1069,6 +1538,246 @@ pub(crate) fn diagnose_resolution(
     Ok(())
 }
 
+/// Reject any direct requirements on excluded packages (e.g., with `--exclude`), before
+/// resolving.
+pub(crate) fn check_excluded_requirements(
+    requirements: &[UnresolvedRequirementSpecification],
+    excluded_packages: &[PackageName],
+) -> Result<(), Error> {
+    if let Some(name) = requirements
+        .iter()
+        .filter_map(|entry| match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => Some(&requirement.name),
+            UnresolvedRequirement::Unnamed(_) => None,
+        })
+        .find(|name| excluded_packages.contains(name))
+    {
+        return Err(Error::ExcludedRequirement(name.clone()));
+    }
+    Ok(())
+}
+
+/// Reject a resolution that includes any excluded packages (e.g., with `--exclude`), as can be
+/// the case for resolutions read from a lockfile or requirements file rather than resolved.
+pub(crate) fn check_excluded_resolution(
+    resolution: &Resolution,
+    excluded_packages: &[PackageName],
+) -> Result<(), Error> {
+    if let Some(dist) = resolution
+        .distributions()
+        .find(|dist| excluded_packages.contains(dist.name()))
+    {
+        return Err(Error::ExcludedDistribution(dist.name().clone()));
+    }
+    Ok(())
+}
+
+/// Report the explanations for the selected versions of the requested packages (e.g., with
+/// `--explain`).
+pub(crate) fn report_explanations(
+    explanations: &[Explanation],
+    printer: Printer,
+) -> Result<(), Error> {
+    for explanation in explanations {
+        writeln!(printer.stderr(), "{explanation}")?;
+    }
+    Ok(())
+}
+
+/// Report the warnings emitted by build backends during successful builds, if requested (e.g.,
+/// with `--show-build-warnings` or `--verbose`).
+pub(crate) fn report_build_warnings(
+    build_dispatch: &BuildDispatch<'_>,
+    printer: Printer,
+) -> Result<(), Error> {
+    let warnings = build_dispatch.take_build_warnings();
+    if !build_dispatch.build_runtime().show_warnings() && !matches!(printer, Printer::Verbose) {
+        return Ok(());
+    }
+    for warnings in warnings {
+        writeln!(
+            printer.stderr(),
+            "{}{} {}",
+            "warning".yellow().bold(),
+            ":".bold(),
+            warnings.to_string().bold()
+        )?;
+    }
+    Ok(())
+}
+
+/// Check whether any of the registry distributions in a resolution read from a lockfile (e.g.,
+/// `uv.lock` or `pylock.toml`) have been yanked since the lockfile was created.
+///
+/// Lockfiles don't record whether a distribution is yanked, so the index is queried for each
+/// distribution. Under `--yanked warn`, a warning is shown for each yanked distribution; under
+/// `--yanked forbid`, the first yanked distribution is an error. Flat indexes (e.g.,
+/// `--find-links`) can't mark distributions as yanked, so, under `--yanked forbid`, their
+/// distributions (like those that can't be found on the index) are reported under the given
+/// [`MissingMetadataPolicy`] instead.
+pub(crate) async fn check_yanked(
+    resolution: &Resolution,
+    client: &RegistryClient,
+    index_locations: &IndexLocations,
+    policy: YankedPolicy,
+    missing_metadata_policy: MissingMetadataPolicy,
+    concurrency: &Concurrency,
+) -> Result<(), Error> {
+    if policy == YankedPolicy::Allow {
+        return Ok(());
+    }
+
+    if client.connectivity().is_offline() {
+        if policy == YankedPolicy::Forbid {
+            warn_user!(
+                "Unable to check whether the locked distributions have been yanked while offline"
+            );
+        } else {
+            debug!("Skipping yank check while offline");
+        }
+        return Ok(());
+    }
+
+    let missing_metadata = MissingMetadata::default();
+    let mut candidates = Vec::new();
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            continue;
+        };
+        let (name, version, filename, index) = match dist.as_ref() {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let wheel = wheels.best_wheel();
+                (
+                    &wheel.filename.name,
+                    &wheel.filename.version,
+                    &wheel.file.filename,
+                    &wheel.index,
+                )
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => (
+                &sdist.name,
+                &sdist.version,
+                &sdist.file.filename,
+                &sdist.index,
+            ),
+            _ => continue,
+        };
+
+        // Flat indexes don't provide yank status, so their distributions can't be checked.
+        let format = index_locations
+            .simple_indexes()
+            .find(|simple_index| simple_index.url() == index)
+            .map(|simple_index| simple_index.format)
+            .unwrap_or_default();
+        if format == IndexFormat::Flat
+            || index_locations
+                .flat_indexes()
+                .any(|flat_index| flat_index.url() == index)
+        {
+            missing_metadata.insert(MissingMetadataKind::Yanked, index, name);
+            continue;
+        }
+
+        candidates.push((dist.as_ref(), name, version, filename, index, format));
+    }
+
+    // Fetch the yank status for each distribution.
+    let capabilities = IndexCapabilities::default();
+    let capabilities = &capabilities;
+    let missing_metadata = &missing_metadata;
+    let mut fetches = futures::stream::iter(candidates)
+        .map(async |(dist, name, version, filename, index, format)| {
+            debug!("Checking whether `{name}=={version}` has been yanked");
+            let archives = match client
+                .simple_detail(
+                    name,
+                    Some(IndexMetadataRef { url: index, format }),
+                    capabilities,
+                    &concurrency.downloads_semaphore,
+                )
+                .await
+            {
+                Ok(archives) => archives,
+                // If the package can't be found on the index (e.g., for a `pylock.toml` that
+                // omits the index, in which case the index is inferred from the file URL), its yank
+                // status is unknown.
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        uv_client::ErrorKind::RemotePackageNotFound(_)
+                            | uv_client::ErrorKind::NoIndex(_)
+                    ) =>
+                {
+                    missing_metadata.insert(MissingMetadataKind::Yanked, index, name);
+                    return Ok(None);
+                }
+                // The check is advisory under `--yanked warn`, so don't fail the install if the
+                // index can't be queried.
+                Err(err) if policy == YankedPolicy::Warn => {
+                    debug!("Failed to check whether `{name}=={version}` has been yanked: {err}");
+                    return Ok(None);
+                }
+                Err(err) => return Err(Error::Client(err)),
+            };
+
+            for (_, archive) in archives {
+                let MetadataFormat::Simple(archive) = archive else {
+                    continue;
+                };
+                for datum in archive.iter() {
+                    let datum_version =
+                        rkyv::deserialize::<Version, rkyv::rancor::Error>(&datum.version)
+                            .expect("archived version always deserializes");
+                    if datum_version != *version {
+                        continue;
+                    }
+                    let files =
+                        rkyv::deserialize::<VersionFiles, rkyv::rancor::Error>(&datum.files)
+                            .expect("archived version files always deserializes");
+                    for (_, file) in files.all() {
+                        if file.filename != *filename {
+                            continue;
+                        }
+                        if let Some(yanked) = file.yanked.filter(|yanked| yanked.is_yanked()) {
+                            let reason = match *yanked {
+                                Yanked::Reason(reason) => Some(reason.to_string()),
+                                Yanked::Bool(_) => None,
+                            };
+                            return Ok::<Option<_>, Error>(Some((dist, reason)));
+                        }
+                    }
+                }
+            }
+            Ok(None)
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut yanked = Vec::new();
+    while let Some(entry) = fetches.next().await.transpose()? {
+        if let Some(entry) = entry {
+            yanked.push(entry);
+        }
+    }
+    yanked.sort_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+
+    for (dist, reason) in yanked {
+        if policy == YankedPolicy::Forbid {
+            return Err(Error::YankedDistribution(Box::new(dist.clone()), reason));
+        }
+        if let Some(reason) = reason {
+            warn_user!("`{dist}` is yanked (reason: \"{reason}\")");
+        } else {
+            warn_user!("`{dist}` is yanked");
+        }
+    }
+
+    if policy == YankedPolicy::Forbid {
+        missing_metadata.report(missing_metadata_policy)?;
+    }
+
+    Ok(())
+}
+
 /// Report any diagnostics on installed distributions in the Python environment.
 pub(crate) fn diagnose_environment(
     resolution: &Resolution,
@@ -1123,14 +1832,33 @@ pub(crate) enum Error {
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 
+    #[error(transparent)]
+    Client(#[from] uv_client::Error),
+
+    #[error(transparent)]
+    MissingMetadata(#[from] uv_resolver::MissingMetadataError),
+
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment(Box<Changelog>),
+
+    #[error("Package `{}` is excluded from the resolution", _0.cyan())]
+    ExcludedRequirement(PackageName),
+
+    #[error("Package `{}` is excluded from the resolution, but is included in the packages to install", _0.cyan())]
+    ExcludedDistribution(PackageName),
+
+    #[error(
+        "`{_0}` has been yanked{}, but yanked versions are forbidden by the `yanked` policy",
+        _1.as_ref().map(|reason| format!(" (reason: \"{reason}\")")).unwrap_or_default()
+    )]
+    YankedDistribution(Box<Dist>, Option<String>),
 }
 
 impl uv_errors::Hint for Error {
     fn hints(&self) -> uv_errors::Hints<'_> {
         match self {
             Self::Resolve(resolve_err) => resolve_err.hints(),
+            Self::MissingMetadata(err) => err.hints(),
             Self::Anyhow(err) => {
                 for cause in err.chain() {
                     if let Some(extra_err) = cause.downcast_ref::<ExtrasWithoutSourceError>() {
@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use uv_cli::DryRunFormat;
+use uv_distribution_types::{Dist, Name};
+use uv_normalize::PackageName;
+
+use crate::commands::pip::operations::{ChangedDist, Changelog, ShortSpecifier};
+use crate::printer::Printer;
+
+/// Report the full set of changes that a dry-run installation would make to the environment,
+/// including version transitions and the distributions that would need to be built from source.
+///
+/// The per-package `+` and `-` lines are emitted by the install logger; in the text format, this
+/// adds the upgrades, downgrades, and source builds that those lines leave implicit.
+pub(crate) fn report_plan(
+    changelog: &Changelog,
+    format: DryRunFormat,
+    printer: Printer,
+) -> Result<()> {
+    let plan = Plan::from_changelog(changelog);
+
+    match format {
+        DryRunFormat::Json => {
+            writeln!(
+                printer.stdout_important(),
+                "{}",
+                serde_json::to_string(&plan)?
+            )?;
+        }
+        DryRunFormat::Text => {
+            for change in &plan.changes {
+                let verb = match change.action {
+                    Action::Upgrade => "upgrade",
+                    Action::Downgrade => "downgrade",
+                    Action::Replace => "replace",
+                    Action::Install | Action::Reinstall | Action::Remove => continue,
+                };
+                let (Some(from), Some(to)) = (&change.from, &change.to) else {
+                    continue;
+                };
+                writeln!(
+                    printer.stderr(),
+                    "Would {verb} {} {} {} {}",
+                    change.name.bold(),
+                    from,
+                    "->".dimmed(),
+                    to
+                )?;
+            }
+            for artifact in &plan.build {
+                writeln!(
+                    printer.stderr(),
+                    "Would build {} {} from source",
+                    artifact.name.bold(),
+                    artifact.version
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The changes that an installation would make to the environment.
+#[derive(Debug, Default, Serialize)]
+struct Plan {
+    /// The distributions that would be downloaded.
+    download: Vec<Artifact>,
+    /// The distributions that would be built from source.
+    build: Vec<Artifact>,
+    /// The changes to each affected package, sorted by name.
+    changes: Vec<Change>,
+}
+
+impl Plan {
+    fn from_changelog(changelog: &Changelog) -> Self {
+        let mut removed: FxHashMap<&PackageName, &ChangedDist> = changelog
+            .uninstalled
+            .iter()
+            .map(|dist| (dist.name(), dist))
+            .collect();
+
+        let mut changes = Vec::new();
+        for dist in &changelog.installed {
+            let to = specifier(dist);
+            let change = if let Some(previous) = removed.remove(dist.name()) {
+                let action = match (previous.short_specifier(), dist.short_specifier()) {
+                    (ShortSpecifier::Version(old), ShortSpecifier::Version(new)) => {
+                        match old.cmp(new) {
+                            Ordering::Less => Action::Upgrade,
+                            Ordering::Greater => Action::Downgrade,
+                            Ordering::Equal => Action::Replace,
+                        }
+                    }
+                    _ => Action::Replace,
+                };
+                Change {
+                    name: dist.name().clone(),
+                    action,
+                    from: Some(specifier(previous)),
+                    to: Some(to),
+                }
+            } else {
+                Change {
+                    name: dist.name().clone(),
+                    action: Action::Install,
+                    from: None,
+                    to: Some(to),
+                }
+            };
+            changes.push(change);
+        }
+        changes.extend(changelog.reinstalled.iter().map(|dist| Change {
+            name: dist.name().clone(),
+            action: Action::Reinstall,
+            from: Some(specifier(dist)),
+            to: Some(specifier(dist)),
+        }));
+        changes.extend(removed.into_values().map(|dist| Change {
+            name: dist.name().clone(),
+            action: Action::Remove,
+            from: Some(specifier(dist)),
+            to: None,
+        }));
+        changes.sort_unstable_by(|a, b| {
+            a.name
+                .cmp(&b.name)
+                .then_with(|| a.action.cmp(&b.action))
+                .then_with(|| a.from.cmp(&b.from))
+        });
+
+        // Any distribution that isn't already available locally would be downloaded; those that
+        // are source distributions would also need to be built.
+        let remote = changelog
+            .installed
+            .iter()
+            .chain(&changelog.reinstalled)
+            .filter_map(|dist| match dist {
+                ChangedDist::Remote(remote) => Some((dist, remote)),
+                ChangedDist::Local(_) => None,
+            })
+            .sorted_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()))
+            .collect::<Vec<_>>();
+        let download = remote
+            .iter()
+            .map(|(dist, _)| Artifact::from(*dist))
+            .collect();
+        let build = remote
+            .iter()
+            .filter(|(_, remote)| matches!(remote.as_ref(), Dist::Source(_)))
+            .map(|(dist, _)| Artifact::from(*dist))
+            .collect();
+
+        Self {
+            download,
+            build,
+            changes,
+        }
+    }
+}
+
+/// A distribution that would be downloaded or built.
+#[derive(Debug, Serialize)]
+struct Artifact {
+    name: PackageName,
+    version: String,
+}
+
+impl From<&ChangedDist> for Artifact {
+    fn from(dist: &ChangedDist) -> Self {
+        Self {
+            name: dist.name().clone(),
+            version: specifier(dist),
+        }
+    }
+}
+
+/// A change to a single package in the environment.
+#[derive(Debug, Serialize)]
+struct Change {
+    name: PackageName,
+    action: Action,
+    /// The currently installed version (or URL), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    /// The version (or URL) that would be installed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Action {
+    /// The package is not currently installed.
+    Install,
+    /// A newer version would replace the installed version.
+    Upgrade,
+    /// An older version would replace the installed version.
+    Downgrade,
+    /// A distribution with the same (or an incomparable) version, e.g., from a different URL,
+    /// would replace the installed distribution.
+    Replace,
+    /// The installed distribution would be reinstalled as-is.
+    Reinstall,
+    /// The package would be removed.
+    Remove,
+}
+
+/// Render the version or URL of a changed distribution.
+fn specifier(dist: &ChangedDist) -> String {
+    match dist.short_specifier() {
+        ShortSpecifier::Version(version) => version.to_string(),
+        ShortSpecifier::Url(url) => url.to_string(),
+    }
+}
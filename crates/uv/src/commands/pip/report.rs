@@ -0,0 +1,406 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_client::RegistryClient;
+use uv_configuration::{BuildOptions, Concurrency};
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{
+    BuiltDist, CachedDist, Dist, InstallContext, InstallReason, InstallRequester, LocalDist, Name,
+    Resolution, ResolvedDist, SourceDist,
+};
+use uv_fs::Simplified;
+use uv_installer::Preparer;
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep508::MarkerEnvironment;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{ArchiveInfo, DirectUrl, HashDigest, Metadata23};
+use uv_types::{HashStrategy, InFlight};
+
+use crate::commands::pip::operations::{ChangedDist, Changelog};
+use crate::printer::Printer;
+
+/// Write an installation report in the format of `pip install --report`, describing the
+/// distributions that were (or, with `--dry-run`, would be) installed.
+///
+/// The metadata of each distribution is read from its unzipped wheel in the cache, including any
+/// wheels that were fetched with [`prepare_report_distributions`] in `--dry-run` mode.
+///
+/// If the path is `-`, the report is written to stdout.
+///
+/// See: <https://pip.pypa.io/en/stable/reference/installation-report/>
+pub(crate) fn write_installation_report(
+    path: &Path,
+    resolution: Option<&Resolution>,
+    changelog: &Changelog,
+    prepared: &[CachedDist],
+    requested_extras: &FxHashMap<PackageName, Vec<ExtraName>>,
+    markers: &MarkerEnvironment,
+    printer: Printer,
+) -> Result<()> {
+    let installed = changelog
+        .installed
+        .iter()
+        .chain(&changelog.reinstalled)
+        .map(Name::name)
+        .collect::<FxHashSet<_>>();
+
+    // Determine the unzipped wheel for each installed distribution.
+    let wheels = changelog
+        .installed
+        .iter()
+        .chain(&changelog.reinstalled)
+        .filter_map(|dist| match dist {
+            ChangedDist::Local(LocalDist::Cached(dist, _)) => Some(&**dist),
+            ChangedDist::Local(LocalDist::Installed(..)) | ChangedDist::Remote(_) => None,
+        })
+        .chain(prepared)
+        .map(|dist| (dist.name(), dist))
+        .collect::<FxHashMap<_, _>>();
+
+    let install = if let Some(resolution) = resolution {
+        let reasons = InstallReason::from_resolution(resolution, InstallContext::Pip);
+        resolution
+            .hashes()
+            .filter_map(|(dist, hashes)| match dist {
+                ResolvedDist::Installable { dist, .. } if installed.contains(dist.name()) => {
+                    Some((dist, hashes))
+                }
+                _ => None,
+            })
+            .sorted_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()))
+            .map(|(dist, hashes)| {
+                let requested = reasons.get(dist.name()).is_some_and(|reason| {
+                    reason
+                        .requested_by
+                        .iter()
+                        .any(|requester| matches!(requester, InstallRequester::Direct { .. }))
+                });
+                let wheel = wheels.get(dist.name()).with_context(|| {
+                    format!("Failed to find the installed wheel for: {}", dist.name())
+                })?;
+                ReportItem::from_dist(
+                    dist,
+                    wheel,
+                    hashes,
+                    requested,
+                    requested_extras
+                        .get(dist.name())
+                        .cloned()
+                        .unwrap_or_default(),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        Vec::new()
+    };
+
+    let report = InstallationReport {
+        version: "1",
+        pip_version: uv_version::version(),
+        install,
+        environment: markers,
+    };
+    let contents = serde_json::to_string_pretty(&report)?;
+
+    if path == Path::new("-") {
+        writeln!(printer.stdout_important(), "{contents}")?;
+    } else {
+        fs_err::write(path, contents + "\n").with_context(|| {
+            format!(
+                "Failed to write installation report to: {}",
+                path.user_display()
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+/// The top-level structure of a pip-compatible installation report.
+#[derive(Debug, Serialize)]
+struct InstallationReport<'a> {
+    version: &'static str,
+    /// The version of the installer that produced the report.
+    pip_version: &'static str,
+    install: Vec<ReportItem>,
+    environment: &'a MarkerEnvironment,
+}
+
+/// A single distribution in an installation report.
+#[derive(Debug, Serialize)]
+struct ReportItem {
+    download_info: DirectUrl,
+    is_direct: bool,
+    is_yanked: bool,
+    requested: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requested_extras: Vec<ExtraName>,
+    metadata: ReportMetadata,
+}
+
+impl ReportItem {
+    fn from_dist(
+        dist: &Dist,
+        wheel: &CachedDist,
+        hashes: &[HashDigest],
+        requested: bool,
+        requested_extras: Vec<ExtraName>,
+    ) -> Result<Self> {
+        let (download_info, is_yanked) = match dist {
+            Dist::Built(BuiltDist::Registry(wheels)) => {
+                let file = &wheels.best_wheel().file;
+                let hashes = if hashes.is_empty() {
+                    file.hashes.as_slice()
+                } else {
+                    hashes
+                };
+                (
+                    DirectUrl::ArchiveUrl {
+                        url: file.url.to_url()?.to_string(),
+                        archive_info: ArchiveInfo::from_hashes(hashes),
+                        subdirectory: None,
+                    },
+                    file.yanked
+                        .as_ref()
+                        .is_some_and(|yanked| yanked.is_yanked()),
+                )
+            }
+            Dist::Source(SourceDist::Registry(sdist)) => {
+                let file = &sdist.file;
+                let hashes = if hashes.is_empty() {
+                    file.hashes.as_slice()
+                } else {
+                    hashes
+                };
+                (
+                    DirectUrl::ArchiveUrl {
+                        url: file.url.to_url()?.to_string(),
+                        archive_info: ArchiveInfo::from_hashes(hashes),
+                        subdirectory: None,
+                    },
+                    file.yanked
+                        .as_ref()
+                        .is_some_and(|yanked| yanked.is_yanked()),
+                )
+            }
+            Dist::Built(BuiltDist::DirectUrl(dist)) => (
+                with_hashes(DirectUrl::from(&dist.parsed_url()), hashes),
+                false,
+            ),
+            Dist::Built(BuiltDist::Path(dist)) => (
+                with_hashes(DirectUrl::from(&dist.parsed_url()), hashes),
+                false,
+            ),
+            Dist::Built(BuiltDist::GitPath(dist)) => (DirectUrl::from(&dist.parsed_url()), false),
+            Dist::Source(SourceDist::DirectUrl(dist)) => (
+                with_hashes(DirectUrl::from(&dist.parsed_url()), hashes),
+                false,
+            ),
+            Dist::Source(SourceDist::Path(dist)) => (
+                with_hashes(DirectUrl::from(&dist.parsed_url()), hashes),
+                false,
+            ),
+            Dist::Source(SourceDist::GitDirectory(dist)) => {
+                (DirectUrl::from(&dist.parsed_url()), false)
+            }
+            Dist::Source(SourceDist::GitPath(dist)) => (DirectUrl::from(&dist.parsed_url()), false),
+            Dist::Source(SourceDist::Directory(dist)) => {
+                (DirectUrl::from(&dist.parsed_url()), false)
+            }
+        };
+
+        let is_direct = !matches!(
+            dist,
+            Dist::Built(BuiltDist::Registry(_)) | Dist::Source(SourceDist::Registry(_))
+        );
+
+        let metadata = uv_metadata::read_flat_wheel_core_metadata(wheel.filename(), wheel.path())
+            .with_context(|| format!("Failed to read the metadata for: {wheel}"))?;
+
+        Ok(Self {
+            download_info,
+            is_direct,
+            is_yanked,
+            requested,
+            requested_extras,
+            metadata: ReportMetadata::from(metadata),
+        })
+    }
+}
+
+/// Attach the resolved hashes to an archive URL.
+fn with_hashes(mut direct_url: DirectUrl, hashes: &[HashDigest]) -> DirectUrl {
+    if let DirectUrl::ArchiveUrl { archive_info, .. } = &mut direct_url {
+        *archive_info = ArchiveInfo::from_hashes(hashes);
+    }
+    direct_url
+}
+
+/// The core metadata of a distribution, converted to JSON as described in PEP 566 (i.e., with
+/// lowercase keys, and a list for each multiple-use field), as used by installation reports.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/core-metadata/>
+#[derive(Debug, Serialize)]
+struct ReportMetadata {
+    metadata_version: String,
+    name: String,
+    version: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dynamic: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    platform: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    supported_platform: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_content_type: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    download_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maintainer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maintainer_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license_expression: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    license_file: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    classifier: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requires_external: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    project_url: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    provides_extra: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    provides_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    obsoletes_dist: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    import_name: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    import_namespace: Vec<String>,
+}
+
+impl From<Metadata23> for ReportMetadata {
+    fn from(metadata: Metadata23) -> Self {
+        // Like pip, accept both comma-separated and whitespace-separated keywords.
+        let keywords = metadata
+            .keywords
+            .map(|keywords| {
+                let keywords = keywords.as_metadata();
+                if keywords.contains(',') {
+                    keywords
+                        .split(',')
+                        .map(|keyword| keyword.trim().to_string())
+                        .collect()
+                } else {
+                    keywords
+                        .split_whitespace()
+                        .map(ToString::to_string)
+                        .collect()
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            metadata_version: metadata.metadata_version,
+            name: metadata.name,
+            version: metadata.version,
+            dynamic: metadata.dynamic,
+            platform: metadata.platforms,
+            supported_platform: metadata.supported_platforms,
+            summary: metadata.summary,
+            description: metadata.description,
+            description_content_type: metadata.description_content_type,
+            keywords,
+            home_page: metadata.home_page,
+            download_url: metadata.download_url,
+            author: metadata.author,
+            author_email: metadata.author_email,
+            maintainer: metadata.maintainer,
+            maintainer_email: metadata.maintainer_email,
+            license: metadata.license,
+            license_expression: metadata.license_expression,
+            license_file: metadata.license_files,
+            classifier: metadata.classifiers,
+            requires_dist: metadata.requires_dist,
+            requires_python: metadata.requires_python,
+            requires_external: metadata.requires_external,
+            project_url: metadata.project_urls.to_vec_str(),
+            provides_extra: metadata.provides_extra,
+            provides_dist: metadata.provides_dist,
+            obsoletes_dist: metadata.obsoletes_dist,
+            import_name: metadata.import_names,
+            import_namespace: metadata.import_namespaces,
+        }
+    }
+}
+
+/// Download (or build) the distributions that would be installed in `--dry-run` mode, such that
+/// their metadata can be included in the installation report, as with `pip install --dry-run
+/// --report`.
+pub(crate) async fn prepare_report_distributions(
+    changelog: &Changelog,
+    resolution: &Resolution,
+    cache: &Cache,
+    tags: &Tags,
+    hasher: &HashStrategy,
+    build_options: &BuildOptions,
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+    in_flight: &InFlight,
+    concurrency: &Concurrency,
+) -> Result<Vec<CachedDist>> {
+    let remote = changelog
+        .installed
+        .iter()
+        .chain(&changelog.reinstalled)
+        .filter_map(|dist| match dist {
+            ChangedDist::Remote(dist) => Some(dist.clone()),
+            ChangedDist::Local(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if remote.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let preparer = Preparer::new(
+        cache,
+        tags,
+        hasher,
+        build_options,
+        DistributionDatabase::new(
+            client,
+            build_dispatch,
+            concurrency.downloads_semaphore.clone(),
+        ),
+    )
+    .with_fail_fast(concurrency.fail_fast);
+
+    Ok(preparer.prepare(remote, in_flight, resolution).await?)
+}
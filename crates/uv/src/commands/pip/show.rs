@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use fs_err::File;
@@ -8,21 +9,29 @@ use rustc_hash::FxHashMap;
 use tracing::debug;
 
 use uv_cache::Cache;
-use uv_distribution_types::{DependencyMetadata, Diagnostic, Name};
-use uv_fs::Simplified;
+use uv_configuration::DependencyGroups;
+use uv_distribution_types::{
+    DependencyMetadata, Diagnostic, InstalledDist, InstalledDistKind, Name,
+};
+use uv_fs::{CWD, Simplified, normalize_path};
 use uv_install_wheel::read_record;
 use uv_installer::SitePackages;
-use uv_normalize::PackageName;
+use uv_normalize::{DefaultGroups, PackageName};
+use uv_pypi_types::ResolverMarkerEnvironment;
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonPreference, PythonRequest, Target,
 };
+use uv_resolver::{Lock, PackageMap, TreeDisplay};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::operations::report_target_environment;
+use crate::commands::project::lock_target::LockTarget;
 use crate::printer::Printer;
 
 /// Show information about one or more installed packages.
-pub(crate) fn pip_show(
+#[expect(clippy::fn_params_excessive_bools)]
+pub(crate) async fn pip_show(
     mut packages: Vec<PackageName>,
     strict: bool,
     dependency_metadata: &DependencyMetadata,
@@ -31,6 +40,7 @@ pub(crate) fn pip_show(
     target: Option<Target>,
     prefix: Option<Prefix>,
     files: bool,
+    why: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -54,6 +64,9 @@ pub(crate) fn pip_show(
         cache,
     )?;
 
+    // Packages in a `--target` or `--prefix` directory aren't managed by a project.
+    let project_environment = target.is_none() && prefix.is_none();
+
     // Apply any `--target` or `--prefix` directories.
     let environment = if let Some(target) = target {
         debug!(
@@ -152,6 +165,14 @@ pub(crate) fn pip_show(
         }
     }
 
+    // If requested, read the lockfile of the project that owns the environment, to show the
+    // dependency paths.
+    let lock = if why && project_environment {
+        read_lock(&environment, cache).await
+    } else {
+        None
+    };
+
     // Print the information for each package.
     for (i, distribution) in distributions.iter().enumerate() {
         if i > 0 {
@@ -212,13 +233,31 @@ pub(crate) fn pip_show(
             }
         }
 
-        // If requests, show the list of installed files.
+        // If requested, show why the package is installed.
+        if why {
+            writeln!(
+                printer.stdout(),
+                "Install-reason: {}",
+                install_reason(distribution)
+            )?;
+            if let Some(lock) = lock.as_ref() {
+                write_lock_paths(lock, &markers, distribution.name(), printer)?;
+            }
+        }
+
+        // If requested, show the list of installed files.
         if files {
-            let path = distribution.install_path().join("RECORD");
-            let record = read_record(&mut File::open(path)?)?;
             writeln!(printer.stdout(), "Files:")?;
-            for entry in record {
-                writeln!(printer.stdout(), "  {}", entry.path)?;
+            if let Some(files) = installed_files(distribution)? {
+                for file in files {
+                    writeln!(printer.stdout(), "  {file}")?;
+                }
+            } else {
+                // Match pip's output for distributions that don't record their files.
+                writeln!(
+                    printer.stdout(),
+                    "Cannot locate RECORD or installed-files.txt"
+                )?;
             }
         }
     }
@@ -238,3 +277,142 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// Read the files installed by a distribution, relative to the `site-packages` directory, or `None`
+/// if the distribution doesn't record its installed files.
+fn installed_files(distribution: &InstalledDist) -> Result<Option<Vec<String>>> {
+    match &distribution.kind {
+        InstalledDistKind::Registry(_) | InstalledDistKind::Url(_) => {
+            let path = distribution.install_path().join("RECORD");
+            let mut file = match File::open(path) {
+                Ok(file) => file,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            let record = read_record(&mut file)?;
+            Ok(Some(record.into_iter().map(|entry| entry.path).collect()))
+        }
+        InstalledDistKind::EggInfoDirectory(dist) => {
+            // Like pip, fall back to the `installed-files.txt` written by setuptools, whose entries
+            // are relative to the `.egg-info` directory.
+            let path = dist.path.join("installed-files.txt");
+            let contents = match fs_err::read_to_string(&path) {
+                Ok(contents) => contents,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            let Some(egg_info) = dist.path.file_name() else {
+                return Ok(None);
+            };
+            Ok(Some(
+                contents
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(|line| {
+                        normalize_path(Path::new(egg_info).join(line))
+                            .portable_display()
+                            .to_string()
+                    })
+                    .collect(),
+            ))
+        }
+        InstalledDistKind::EggInfoFile(_) | InstalledDistKind::LegacyEditable(_) => Ok(None),
+    }
+}
+
+/// Describe the reason an installed package was installed, as recorded at install time.
+fn install_reason(distribution: &InstalledDist) -> String {
+    match distribution.read_install_reason() {
+        Ok(Some(reason)) => return reason.to_string(),
+        Ok(None) => {}
+        Err(err) => debug!("Failed to read install reason for {distribution}: {err}"),
+    }
+    match distribution.read_installer() {
+        Ok(Some(installer)) if installer != "uv" => format!("unknown (installed by {installer})"),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Read the lockfile for the workspace whose project environment is the given environment, if
+/// any.
+///
+/// The workspace is discovered from the current directory and, failing that, from the directory
+/// containing the environment (e.g., for `--python .venv`).
+async fn read_lock(environment: &PythonEnvironment, cache: &Cache) -> Option<Lock> {
+    let candidates = [Some(&**CWD), environment.root().parent()];
+    for root in candidates.into_iter().flatten() {
+        let workspace = match Workspace::discover(
+            root,
+            &DiscoveryOptions::default(),
+            cache,
+            &WorkspaceCache::default(),
+        )
+        .await
+        {
+            Ok(workspace) => workspace,
+            Err(err) => {
+                debug!(
+                    "No workspace found at `{}` for dependency paths: {err}",
+                    root.user_display()
+                );
+                continue;
+            }
+        };
+
+        // Only use the lockfile if the environment belongs to the workspace.
+        let selection = workspace.environment_selection(Some(false));
+        let project_environment = selection
+            .explicit_path()
+            .map_or_else(|| workspace.install_path().join(".venv"), Path::to_path_buf);
+        if !uv_fs::is_same_file_allow_missing(&project_environment, environment.root())
+            .unwrap_or(false)
+        {
+            debug!(
+                "Ignoring lockfile for dependency paths; `{}` is not the project environment",
+                environment.root().user_display()
+            );
+            continue;
+        }
+
+        return match LockTarget::Workspace(&workspace).read().await {
+            Ok(lock) => lock,
+            Err(err) => {
+                debug!("Failed to read lockfile for dependency paths: {err}");
+                None
+            }
+        };
+    }
+    None
+}
+
+/// Show the paths from the workspace members to the given package in the lockfile.
+fn write_lock_paths(
+    lock: &Lock,
+    markers: &ResolverMarkerEnvironment,
+    name: &PackageName,
+    printer: Printer,
+) -> Result<()> {
+    let groups = DependencyGroups::from_all_groups().with_defaults(DefaultGroups::default());
+    let tree = TreeDisplay::new(
+        lock,
+        Some(markers),
+        &PackageMap::default(),
+        usize::from(u8::MAX),
+        &[],
+        std::slice::from_ref(name),
+        &groups,
+        false,
+        true,
+        false,
+    )
+    .to_string();
+    if tree.is_empty() {
+        return Ok(());
+    }
+    writeln!(printer.stdout(), "Lock-paths:")?;
+    for line in tree.lines() {
+        writeln!(printer.stdout(), "  {line}")?;
+    }
+    Ok(())
+}
@@ -6,22 +6,24 @@ use owo_colors::OwoColorize;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, NoSources, Reinstall, Upgrade,
+    BuildIsolation, BuildOptions, BuildRuntime, Concurrency, Constraints, DryRun,
+    ExtrasSpecification, HashCheckingMode, IndexStrategy, NoSources, Reinstall, Replacements,
+    Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Origin,
-    PackageConfigSettings, Resolution,
+    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, InstallContext,
+    Origin, PackageConfigSettings, Resolution,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep440::Version;
 use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::Conflicts;
@@ -31,8 +33,8 @@ use uv_python::{
 };
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    DependencyMode, ExcludeNewer, FlatIndex, MissingMetadataPolicy, OptionsBuilder, PrereleaseMode,
+    PythonRequirement, ResolutionMode, ResolverEnvironment, YankedPolicy,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_torch::{AmdGpuArchitecture, TorchMode, TorchSource, TorchStrategy};
@@ -42,8 +44,9 @@ use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications};
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::plan::report_plan;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::pylock::{read_pylock_toml, resolve_pylock_toml};
 use crate::commands::reporters::PythonDownloadReporter;
@@ -78,12 +81,16 @@ pub(crate) async fn pip_sync(
     extra_build_dependencies: &ExtraBuildDependencies,
     extra_build_variables: &ExtraBuildVariables,
     build_options: BuildOptions,
+    build_runtime: BuildRuntime,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     python_downloads: PythonDownloads,
     install_mirrors: PythonInstallMirrors,
     strict: bool,
+    yanked: YankedPolicy,
     exclude_newer: ExcludeNewer,
+    strict_exclude_newer: bool,
+    excluded_packages: Vec<PackageName>,
     python: Option<String>,
     system: bool,
     break_system_packages: bool,
@@ -95,6 +102,8 @@ pub(crate) async fn pip_sync(
     cache: Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
+    output_format: DryRunFormat,
+    exit_code: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -137,6 +146,9 @@ pub(crate) async fn pip_sync(
     )
     .await?;
 
+    // Reject direct requirements on excluded packages before resolving.
+    operations::check_excluded_requirements(&requirements, &excluded_packages)?;
+
     if pylock.is_some() {
         if !preview.is_enabled(PreviewFeature::Pylock) {
             warn_user!(
@@ -255,11 +267,14 @@ pub(crate) async fn pip_sync(
     let marker_env = resolution_markers(
         python_version.as_ref(),
         python_platform.as_ref(),
+        None,
         interpreter,
     );
     let tags = resolution_tags(
         python_version.as_ref(),
         python_platform.as_ref(),
+        None,
+        None,
         interpreter,
     )?;
 
@@ -337,7 +352,13 @@ pub(crate) async fn pip_sync(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            &build_options,
+            &index_locations,
+        )
     };
 
     // Determine whether to enable build isolation.
@@ -395,6 +416,7 @@ pub(crate) async fn pip_sync(
         extra_build_variables,
         link_mode,
         &build_options,
+        &build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources.clone(),
@@ -402,7 +424,8 @@ pub(crate) async fn pip_sync(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(&excluded_packages);
 
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
@@ -427,7 +450,7 @@ pub(crate) async fn pip_sync(
             .cloned()
             .collect::<Vec<_>>();
 
-        resolve_pylock_toml(
+        let (resolution, hasher) = resolve_pylock_toml(
             lock,
             &install_path,
             interpreter,
@@ -437,7 +460,21 @@ pub(crate) async fn pip_sync(
             &groups,
             &build_options,
             hash_checking,
-        )?
+        )?;
+
+        // Check whether any of the locked distributions have been yanked since the lockfile was
+        // created.
+        operations::check_yanked(
+            &resolution,
+            &client,
+            &index_locations,
+            yanked,
+            MissingMetadataPolicy::from_strict(strict_exclude_newer),
+            &concurrency,
+        )
+        .await?;
+
+        (resolution, hasher)
     } else {
         // When resolving, don't take any external preferences into account.
         let preferences = Vec::default();
@@ -445,11 +482,14 @@ pub(crate) async fn pip_sync(
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
             .prerelease_mode(prerelease_mode)
+            .yanked(yanked)
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
+            .strict_exclude_newer(strict_exclude_newer)
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
+            .excluded_packages(excluded_packages.clone())
             .build();
 
         let (resolution, hasher) = match operations::resolve(
@@ -458,6 +498,7 @@ pub(crate) async fn pip_sync(
             overrides,
             override_dependencies,
             excludes,
+            Replacements::default(),
             source_trees,
             project,
             BTreeSet::default(),
@@ -497,6 +538,9 @@ pub(crate) async fn pip_sync(
         (resolution, hasher)
     };
 
+    // Reject any excluded packages, e.g., in a `pylock.toml`.
+    operations::check_excluded_resolution(&resolution, &excluded_packages)?;
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -518,6 +562,7 @@ pub(crate) async fn pip_sync(
         extra_build_variables,
         link_mode,
         &build_options,
+        &build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources,
@@ -525,14 +570,16 @@ pub(crate) async fn pip_sync(
         workspace_cache,
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(&excluded_packages);
 
     // Sync the environment.
-    match operations::install(
+    let changelog = match operations::install(
         &resolution,
         site_packages,
         InstallationStrategy::Permissive,
         Modifications::Exact,
+        &ExtraneousOptions::default(),
         &reinstall,
         &build_options,
         link_mode,
@@ -547,13 +594,14 @@ pub(crate) async fn pip_sync(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        InstallContext::Pip,
         dry_run,
         printer,
         preview,
     )
     .await
     {
-        Ok(_) => {}
+        Ok(changelog) => changelog,
         Err(err) => {
             return diagnostics::OperationDiagnostic::with_system_certs(
                 client_builder.system_certs(),
@@ -561,6 +609,10 @@ pub(crate) async fn pip_sync(
             .report(err)
             .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
+    };
+
+    if dry_run.enabled() {
+        report_plan(&changelog, output_format, printer)?;
     }
 
     // Notify the user of any resolution diagnostics.
@@ -578,5 +630,10 @@ pub(crate) async fn pip_sync(
         )?;
     }
 
+    // With `--exit-code`, signal that the environment is out of sync.
+    if exit_code && (!changelog.is_empty() || !changelog.reinstalled.is_empty()) {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
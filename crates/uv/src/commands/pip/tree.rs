@@ -1,9 +1,10 @@
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 use std::fmt::Write;
 
 use anyhow::Result;
 use futures::StreamExt;
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use petgraph::Direction;
 use petgraph::graph::{EdgeIndex, NodeIndex};
@@ -18,7 +19,7 @@ use uv_distribution_types::{
     DependencyMetadata, Diagnostic, IndexCapabilities, IndexLocations, Name, RequiresPython,
 };
 use uv_installer::SitePackages;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{Operator, Version, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{Requirement, VersionOrUrl};
 use uv_pypi_types::{ResolutionMetadata, ResolverMarkerEnvironment, VerbatimParsedUrl};
@@ -167,12 +168,18 @@ pub(crate) async fn pip_tree(
     writeln!(printer.stdout(), "{rendered_tree}")?;
 
     if rendered_tree.contains("(*)") {
-        let message = if no_dedupe {
-            "(*) Package tree is a cycle and cannot be shown".italic()
-        } else {
+        writeln!(
+            printer.stdout(),
+            "{}",
             "(*) Package tree already displayed".italic()
-        };
-        writeln!(printer.stdout(), "{message}")?;
+        )?;
+    }
+    if rendered_tree.contains("(cycle)") {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            "(cycle) Package tree is a cycle and cannot be shown".italic()
+        )?;
     }
 
     // Validate that the environment is consistent.
@@ -194,11 +201,7 @@ pub(crate) async fn pip_tree(
 #[derive(Debug)]
 pub(crate) struct DisplayDependencyGraph<'env> {
     /// The constructed dependency graph.
-    graph: petgraph::graph::Graph<
-        &'env ResolutionMetadata,
-        &'env Requirement<VerbatimParsedUrl>,
-        petgraph::Directed,
-    >,
+    graph: petgraph::graph::Graph<&'env ResolutionMetadata, Edge<'env>, petgraph::Directed>,
     /// The packages considered as roots of the dependency tree.
     roots: Vec<NodeIndex>,
     /// The latest known version of each package.
@@ -227,11 +230,8 @@ impl<'env> DisplayDependencyGraph<'env> {
         latest: &'env FxHashMap<&PackageName, Version>,
     ) -> Self {
         // Create a graph.
-        let mut graph = petgraph::graph::Graph::<
-            &ResolutionMetadata,
-            &Requirement<VerbatimParsedUrl>,
-            petgraph::Directed,
-        >::new();
+        let mut graph =
+            petgraph::graph::Graph::<&ResolutionMetadata, Edge, petgraph::Directed>::new();
 
         // Step 1: Add each installed package.
         let mut inverse: FxHashMap<PackageName, Vec<NodeIndex>> = FxHashMap::default();
@@ -247,36 +247,45 @@ impl<'env> DisplayDependencyGraph<'env> {
                 .push(index);
         }
 
-        // Step 2: Add all dependencies.
+        // Step 2: Add all dependencies, along with the dependencies of any extras that are
+        // satisfied by the environment.
         for index in graph.node_indices() {
-            let metadata = &graph[index];
-
-            for requirement in &metadata.requires_dist {
-                if prune.contains(&requirement.name) {
-                    continue;
-                }
-                if !requirement.marker.evaluate(markers, &[]) {
-                    continue;
-                }
+            let metadata = graph[index];
+
+            for extra in std::iter::once(None).chain(
+                metadata
+                    .provides_extra
+                    .iter()
+                    .filter(|extra| Self::is_satisfied(metadata, extra, markers, packages))
+                    .map(Some),
+            ) {
+                for requirement in &metadata.requires_dist {
+                    if prune.contains(&requirement.name) {
+                        continue;
+                    }
+                    if !Self::is_required(requirement, extra, markers) {
+                        continue;
+                    }
 
-                for dep_index in inverse
-                    .get(&requirement.name)
-                    .into_iter()
-                    .flatten()
-                    .copied()
-                {
-                    let dep = &graph[dep_index];
-
-                    // Avoid adding an edge if the dependency is not required by the current package.
-                    if let Some(VersionOrUrl::VersionSpecifier(specifier)) =
-                        requirement.version_or_url.as_ref()
+                    for dep_index in inverse
+                        .get(&requirement.name)
+                        .into_iter()
+                        .flatten()
+                        .copied()
                     {
-                        if !specifier.contains(&dep.version) {
-                            continue;
+                        let dep = &graph[dep_index];
+
+                        // Avoid adding an edge if the dependency is not required by the current package.
+                        if let Some(VersionOrUrl::VersionSpecifier(specifier)) =
+                            requirement.version_or_url.as_ref()
+                        {
+                            if !specifier.contains(&dep.version) {
+                                continue;
+                            }
                         }
-                    }
 
-                    graph.add_edge(index, dep_index, requirement);
+                        graph.add_edge(index, dep_index, Edge { requirement, extra });
+                    }
                 }
             }
         }
@@ -358,6 +367,57 @@ impl<'env> DisplayDependencyGraph<'env> {
         }
     }
 
+    /// Returns `true` if the requirement applies to the environment when the given extra (if
+    /// any) is enabled, but not otherwise.
+    fn is_required(
+        requirement: &Requirement<VerbatimParsedUrl>,
+        extra: Option<&ExtraName>,
+        markers: &ResolverMarkerEnvironment,
+    ) -> bool {
+        match extra {
+            None => requirement.marker.evaluate(markers, &[]),
+            Some(extra) => {
+                !requirement.marker.evaluate(markers, &[])
+                    && requirement
+                        .marker
+                        .evaluate(markers, std::slice::from_ref(extra))
+            }
+        }
+    }
+
+    /// Returns `true` if every requirement of the given extra is installed in the environment,
+    /// such that the extra can be considered enabled.
+    fn is_satisfied(
+        metadata: &ResolutionMetadata,
+        extra: &ExtraName,
+        markers: &ResolverMarkerEnvironment,
+        packages: &FxHashMap<&PackageName, Vec<&ResolutionMetadata>>,
+    ) -> bool {
+        let mut requirements = metadata
+            .requires_dist
+            .iter()
+            .filter(|requirement| Self::is_required(requirement, Some(extra), markers))
+            .peekable();
+
+        // An extra without any applicable requirements doesn't add any edges.
+        if requirements.peek().is_none() {
+            return false;
+        }
+
+        requirements.all(|requirement| {
+            packages
+                .get(&requirement.name)
+                .into_iter()
+                .flatten()
+                .any(|dep| match requirement.version_or_url.as_ref() {
+                    Some(VersionOrUrl::VersionSpecifier(specifier)) => {
+                        specifier.contains(&dep.version)
+                    }
+                    _ => true,
+                })
+        })
+    }
+
     /// Perform a depth-first traversal of the given distribution and its dependencies.
     fn visit(
         &self,
@@ -374,6 +434,16 @@ impl<'env> DisplayDependencyGraph<'env> {
         let package_name = &metadata.name;
         let mut line = format!("{} v{}", package_name, metadata.version);
 
+        // If the package is only required via extras, include them.
+        let extras = cursor
+            .edge_ids()
+            .iter()
+            .map(|edge_id| self.graph[*edge_id].extra)
+            .collect::<Option<BTreeSet<_>>>();
+        if let Some(extras) = extras.filter(|extras| !extras.is_empty()) {
+            let _ = write!(line, " (extra: {})", extras.iter().join(", "));
+        }
+
         // If the current package is not top-level (i.e., it has a parent), include the specifiers.
         if self.show_version_specifiers && !cursor.is_root() {
             line.push(' ');
@@ -408,12 +478,15 @@ impl<'env> DisplayDependencyGraph<'env> {
         // Skip the traversal if:
         // 1. The package is in the current traversal path (i.e., a dependency cycle).
         // 2. The package has been visited and de-duplication is enabled (default).
+        if path.contains(&package_name) {
+            return vec![format!("{line} (cycle)")];
+        }
         if let Some(requirements) = visited.get(package_name) {
-            if !self.no_dedupe || path.contains(&package_name) {
+            if !self.no_dedupe {
                 return if requirements.is_empty() {
                     vec![line]
                 } else {
-                    vec![format!("{} (*)", line)]
+                    vec![format!("{line} (*)")]
                 };
             }
         }
@@ -507,7 +580,7 @@ impl<'env> DisplayDependencyGraph<'env> {
         let mut specifiers = Vec::new();
 
         for edge_id in cursor.edge_ids() {
-            let requirement = &self.graph[*edge_id];
+            let requirement = self.graph[*edge_id].requirement;
 
             let Some(version_or_url) = requirement.version_or_url.as_ref() else {
                 continue;
@@ -633,6 +706,15 @@ impl<'env> DisplayDependencyGraph<'env> {
     }
 }
 
+/// An edge in the dependency graph.
+#[derive(Debug, Clone, Copy)]
+struct Edge<'env> {
+    /// The requirement from which the edge was derived.
+    requirement: &'env Requirement<VerbatimParsedUrl>,
+    /// The extra that enables the requirement, if any.
+    extra: Option<&'env ExtraName>,
+}
+
 /// A node in the dependency graph along with the edge that led to it, or `None` for root nodes.
 #[derive(Debug, Clone)]
 struct Cursor {
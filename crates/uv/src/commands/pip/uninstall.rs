@@ -1,18 +1,26 @@
 use std::fmt::Write;
 
+use std::path::Path;
+
 use anyhow::Result;
+use console::Term;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{DryRun, KeyringProviderType};
 use uv_distribution_types::Requirement;
-use uv_distribution_types::{InstalledMetadata, Name, UnresolvedRequirement};
+use uv_distribution_types::{
+    InstallRequester, InstalledDist, InstalledMetadata, Name, UnresolvedRequirement,
+};
 use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::{ExtraName, PackageName};
 use uv_pep508::UnnamedRequirement;
-use uv_pypi_types::VerbatimParsedUrl;
+use uv_pypi_types::{ResolutionMetadata, ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_python::PythonRequest;
 use uv_python::{EnvironmentPreference, PythonPreference};
 use uv_python::{Prefix, PythonEnvironment, Target};
@@ -23,6 +31,7 @@ use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
 
 /// Uninstall packages from the current environment.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
     python: Option<String>,
@@ -34,6 +43,8 @@ pub(crate) async fn pip_uninstall(
     keyring_provider: KeyringProviderType,
     client_builder: &BaseClientBuilder<'_>,
     dry_run: DryRun,
+    unneeded: bool,
+    yes: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -102,7 +113,7 @@ pub(crate) async fn pip_uninstall(
         .ok();
 
     // Index the current `site-packages` directory.
-    let site_packages = uv_installer::SitePackages::from_environment(&environment)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
 
     // Partition the requirements into named and unnamed requirements.
     let (named, unnamed): (Vec<Requirement>, Vec<UnnamedRequirement<VerbatimParsedUrl>>) = spec
@@ -196,6 +207,47 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
+    let markers = environment.interpreter().resolver_marker_environment();
+    let graph = InstalledGraph::from_site_packages(&site_packages, &markers);
+
+    // Extend the removal set with any dependencies that would no longer be required.
+    let distributions = if unneeded {
+        with_unneeded_dependencies(distributions, &site_packages, &graph)
+    } else {
+        distributions
+    };
+
+    // Warn about any remaining packages whose dependencies would no longer be satisfied.
+    let broken = broken_dependents(&distributions, &site_packages, &graph);
+    if !broken.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}{} Uninstalling would leave the following packages with unsatisfied dependencies:",
+            "warning".yellow().bold(),
+            ":".bold(),
+        )?;
+        for (dependent, requirement) in &broken {
+            writeln!(
+                printer.stderr(),
+                "  - {} requires {}",
+                format!("{}{}", dependent.name(), dependent.installed_version()).bold(),
+                requirement.to_string().cyan(),
+            )?;
+        }
+
+        if !yes && !dry_run.enabled() {
+            let term = Term::stderr();
+            if term.is_term() {
+                let confirmation =
+                    uv_console::confirm("Proceed with uninstallation?", &term, false)?;
+                if !confirmation {
+                    writeln!(printer.stderr(), "No packages were uninstalled")?;
+                    return Ok(ExitStatus::Failure);
+                }
+            }
+        }
+    }
+
     // Uninstall each package.
     if !dry_run.enabled() {
         let layout = environment.interpreter().layout();
@@ -249,3 +301,209 @@ pub(crate) async fn pip_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// The dependencies between the installed packages in an environment.
+///
+/// Markers are evaluated against the environment, with the extras that appear to be enabled for
+/// each package: those recorded as the reason another package was installed, and those whose
+/// dependencies are all installed.
+struct InstalledGraph<'a> {
+    /// The applicable dependencies of each installed distribution, by install path.
+    dependencies: FxHashMap<&'a Path, Vec<&'a uv_pep508::Requirement<VerbatimParsedUrl>>>,
+    /// The installed distributions that depend on each package, along with the requirement.
+    dependents: FxHashMap<
+        &'a PackageName,
+        Vec<(
+            &'a InstalledDist,
+            &'a uv_pep508::Requirement<VerbatimParsedUrl>,
+        )>,
+    >,
+}
+
+impl<'a> InstalledGraph<'a> {
+    /// Read the metadata of each installed distribution, and determine its dependencies.
+    fn from_site_packages(
+        site_packages: &'a SitePackages,
+        markers: &ResolverMarkerEnvironment,
+    ) -> Self {
+        // Collect the extras that were recorded as the reason for installing another package.
+        let recorded_extras = site_packages
+            .iter()
+            .filter_map(|dist| dist.read_install_reason().ok().flatten())
+            .flat_map(|reason| reason.requested_by)
+            .filter_map(|requester| match requester {
+                InstallRequester::Dependency {
+                    package,
+                    extra: Some(extra),
+                    ..
+                } => Some((package, extra)),
+                _ => None,
+            })
+            .collect::<FxHashSet<_>>();
+
+        let mut dependencies = FxHashMap::default();
+        let mut dependents = FxHashMap::<_, Vec<_>>::default();
+        for dist in site_packages.iter() {
+            let Ok(metadata) = dist.read_metadata() else {
+                continue;
+            };
+            let extras = metadata
+                .provides_extra
+                .iter()
+                .filter(|extra| {
+                    recorded_extras.contains(&(dist.name().clone(), (*extra).clone()))
+                        || is_extra_installed(metadata, extra, site_packages, markers)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            let requirements = metadata
+                .requires_dist
+                .iter()
+                .filter(|requirement| requirement.evaluate_markers(markers, &extras))
+                .collect::<Vec<_>>();
+            for requirement in &requirements {
+                dependents
+                    .entry(&requirement.name)
+                    .or_default()
+                    .push((dist, *requirement));
+            }
+            dependencies.insert(dist.install_path(), requirements);
+        }
+
+        Self {
+            dependencies,
+            dependents,
+        }
+    }
+}
+
+/// Returns `true` if all of the dependencies that the given extra adds are installed (e.g., if the
+/// package was installed as `app[extra]` before install reasons were recorded).
+fn is_extra_installed(
+    metadata: &ResolutionMetadata,
+    extra: &ExtraName,
+    site_packages: &SitePackages,
+    markers: &ResolverMarkerEnvironment,
+) -> bool {
+    let mut requirements = metadata
+        .requires_dist
+        .iter()
+        .filter(|requirement| {
+            requirement.evaluate_markers(markers, std::slice::from_ref(extra))
+                && !requirement.evaluate_markers(markers, &[])
+        })
+        .peekable();
+    requirements.peek().is_some()
+        && requirements.all(|requirement| !site_packages.get_packages(&requirement.name).is_empty())
+}
+
+/// Extend the set of distributions to remove with any of their dependencies that aren't required
+/// by another remaining package, transitively.
+///
+/// Packages that were requested directly, per their recorded install reason, are always retained.
+fn with_unneeded_dependencies<'a>(
+    mut distributions: Vec<&'a InstalledDist>,
+    site_packages: &'a SitePackages,
+    graph: &InstalledGraph<'a>,
+) -> Vec<&'a InstalledDist> {
+    let mut removed = distributions
+        .iter()
+        .map(|dist| dist.install_path())
+        .collect::<FxHashSet<_>>();
+
+    // Each time a package is removed, revisit its dependencies, since it may have been the last
+    // package to require them.
+    let mut queue = distributions.clone();
+    while let Some(dist) = queue.pop() {
+        let Some(dependencies) = graph.dependencies.get(dist.install_path()) else {
+            continue;
+        };
+        for dependency in dependencies {
+            for candidate in site_packages.get_packages(&dependency.name) {
+                if removed.contains(candidate.install_path()) {
+                    continue;
+                }
+                if is_requested_directly(candidate) {
+                    debug!(
+                        "Retaining `{}`, which was requested directly",
+                        candidate.name()
+                    );
+                    continue;
+                }
+                if is_required(candidate.name(), graph, &removed) {
+                    continue;
+                }
+                debug!(
+                    "Removing `{}`, which is no longer required",
+                    candidate.name()
+                );
+                removed.insert(candidate.install_path());
+                distributions.push(candidate);
+                queue.push(candidate);
+            }
+        }
+    }
+
+    distributions.sort_unstable_by_key(|dist| dist.install_path());
+    distributions
+}
+
+/// Returns `true` if the package was installed as a direct request, rather than as a dependency.
+fn is_requested_directly(dist: &InstalledDist) -> bool {
+    dist.read_install_reason()
+        .ok()
+        .flatten()
+        .is_some_and(|reason| {
+            reason
+                .requested_by
+                .iter()
+                .any(|requester| matches!(requester, InstallRequester::Direct { .. }))
+        })
+}
+
+/// Returns `true` if any installed package that isn't being removed depends on the given package.
+fn is_required(name: &PackageName, graph: &InstalledGraph, removed: &FxHashSet<&Path>) -> bool {
+    graph.dependents.get(name).is_some_and(|dependents| {
+        dependents
+            .iter()
+            .any(|(dist, _)| !removed.contains(dist.install_path()))
+    })
+}
+
+/// Identify the remaining installed packages that depend on a package that would be removed, along
+/// with the requirement that would no longer be satisfied.
+fn broken_dependents<'a>(
+    distributions: &[&InstalledDist],
+    site_packages: &SitePackages,
+    graph: &InstalledGraph<'a>,
+) -> Vec<(
+    &'a InstalledDist,
+    &'a uv_pep508::Requirement<VerbatimParsedUrl>,
+)> {
+    let removed = distributions
+        .iter()
+        .map(|dist| dist.install_path())
+        .collect::<FxHashSet<_>>();
+
+    // A package is only missing if every installed distribution of it would be removed.
+    let missing = distributions
+        .iter()
+        .map(|dist| dist.name())
+        .filter(|name| {
+            site_packages
+                .get_packages(name)
+                .iter()
+                .all(|dist| removed.contains(dist.install_path()))
+        })
+        .collect::<FxHashSet<_>>();
+
+    let mut broken = missing
+        .into_iter()
+        .filter_map(|name| graph.dependents.get(name))
+        .flatten()
+        .filter(|(dist, _)| !removed.contains(dist.install_path()))
+        .copied()
+        .collect::<Vec<_>>();
+    broken.sort_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+    broken
+}
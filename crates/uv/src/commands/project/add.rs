@@ -49,9 +49,9 @@ use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryResolveLogger,
 };
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications, report_build_warnings};
 use crate::commands::project::install_target::InstallTarget;
-use crate::commands::project::lock::LockMode;
+use crate::commands::project::lock::{LockMode, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     LinkErrorReporting, PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter,
@@ -424,7 +424,13 @@ pub(crate) async fn add(
                             .map(Index::url),
                     )
                     .await?;
-                FlatIndex::from_entries(entries, None, &hasher, &settings.resolver.build_options)
+                FlatIndex::from_entries(
+                    entries,
+                    None,
+                    &hasher,
+                    &settings.resolver.build_options,
+                    &settings.resolver.index_locations,
+                )
             };
 
             // Lower the extra build dependencies, if any.
@@ -462,6 +468,7 @@ pub(crate) async fn add(
                 &extra_build_variables,
                 settings.resolver.link_mode,
                 &settings.resolver.build_options,
+                &settings.resolver.build_runtime,
                 &build_hasher,
                 settings.resolver.exclude_newer.clone(),
                 sources,
@@ -470,7 +477,8 @@ pub(crate) async fn add(
                 WorkspaceCache::default(),
                 concurrency.clone(),
                 preview,
-            );
+            )
+            .with_excluded_packages(&settings.resolver.excluded_packages);
 
             requirements.extend(
                 NamedRequirementsResolver::new(
@@ -486,6 +494,9 @@ pub(crate) async fn add(
                 .resolve(unnamed.into_iter())
                 .await?,
             );
+
+            // Notify the user of any warnings emitted by build backends.
+            report_build_warnings(&build_dispatch, printer)?;
         }
 
         requirements
@@ -605,7 +616,7 @@ pub(crate) async fn add(
         // the discovered members, etc.
         target = if modified {
             let workspace_content = toml.to_string();
-            fs_err::write(
+            uv_fs::write_durable_sync(
                 project.workspace().install_path().join("pyproject.toml"),
                 &workspace_content,
             )?;
@@ -1062,11 +1073,20 @@ async fn lock_and_sync(
     preview: Preview,
     malware_settings: &MalwareCheckSettings,
 ) -> Result<(), ProjectError> {
-    let mut lock = Box::pin(
+    // If we're going to add lower bounds to the `pyproject.toml` after locking, defer writing the
+    // lockfile until the bounds are in place, such that an interruption never leaves a lockfile
+    // that references requirements absent from the `pyproject.toml`.
+    let defer_write = !raw
+        && !dry_run
+        && edits
+            .iter()
+            .any(DependencyEdit::is_unbounded_registry_addition);
+
+    let result = Box::pin(
         project::lock::LockOperation::new(
             if let LockCheck::Enabled(lock_check) = lock_check {
                 LockMode::Locked(target.interpreter(), lock_check)
-            } else if dry_run {
+            } else if dry_run || defer_write {
                 LockMode::DryRun(target.interpreter())
             } else {
                 LockMode::Write(target.interpreter())
@@ -1084,8 +1104,9 @@ async fn lock_and_sync(
         .with_constraints(constraints)
         .execute((&target).into()),
     )
-    .await?
-    .into_lock();
+    .await?;
+    let changed = matches!(result, LockResult::Changed(..));
+    let mut lock = result.into_lock();
 
     // Avoid modifying the user request further if `--raw-sources` is set.
     if !raw {
@@ -1210,6 +1231,9 @@ async fn lock_and_sync(
             )
             .await?
             .into_lock();
+        } else if defer_write && changed {
+            // If no bounds were added after all, write the deferred lockfile as-is.
+            LockTarget::from(&target).commit(&lock).await?;
         }
     }
 
@@ -1253,6 +1277,7 @@ async fn lock_and_sync(
             only_install_package,
         ),
         Modifications::Sufficient,
+        &ExtraneousOptions::default(),
         None,
         settings.into(),
         client_builder,
@@ -1263,6 +1288,7 @@ async fn lock_and_sync(
         cache,
         &WorkspaceCache::default(),
         DryRun::Disabled,
+        false,
         printer,
         preview,
         malware_settings,
@@ -1393,7 +1419,7 @@ impl AddTarget {
                     Ok(false)
                 } else {
                     let pyproject_path = project.root().join("pyproject.toml");
-                    fs_err::write(pyproject_path, content)?;
+                    uv_fs::write_durable_sync(pyproject_path, content)?;
                     Ok(true)
                 }
             }
@@ -1452,55 +1478,61 @@ enum AddTargetSnapshot {
 
 impl AddTargetSnapshot {
     /// Write the snapshot back to disk (e.g., to a `pyproject.toml` and `uv.lock`).
+    ///
+    /// The lockfile is reverted first, such that an interruption never leaves a lockfile that
+    /// references requirements absent from the `pyproject.toml`.
     fn revert(&self) -> Result<(), io::Error> {
         match self {
             Self::Script(script, lock) => {
+                // Write the lockfile back to disk.
+                Self::revert_lock(&LockTarget::from(script), lock.as_deref())?;
+
                 // Write the PEP 723 script back to disk.
                 debug!("Reverting changes to PEP 723 script block");
                 script.write(&script.metadata.raw)?;
 
-                // Write the lockfile back to disk.
-                let target = LockTarget::from(script);
-                if let Some(lock) = lock {
-                    debug!("Reverting changes to `uv.lock`");
-                    fs_err::write(target.lock_path(), lock)?;
-                } else {
-                    debug!("Removing `uv.lock`");
-                    fs_err::remove_file(target.lock_path())?;
-                }
                 Ok(())
             }
             Self::Project(project, lock) => {
+                // Write the lockfile back to disk.
+                Self::revert_lock(&LockTarget::from(project.workspace()), lock.as_deref())?;
+
+                // Write the `pyproject.toml` back to disk.
+                debug!("Reverting changes to `pyproject.toml`");
+                uv_fs::write_durable_sync(
+                    project.root().join("pyproject.toml"),
+                    project.pyproject_toml().as_ref(),
+                )?;
+
                 // Write the workspace `pyproject.toml` back to disk.
                 let workspace = project.workspace();
                 if workspace.install_path() != project.root() {
                     debug!("Reverting changes to workspace `pyproject.toml`");
-                    fs_err::write(
+                    uv_fs::write_durable_sync(
                         workspace.install_path().join("pyproject.toml"),
                         workspace.pyproject_toml().as_ref(),
                     )?;
                 }
 
-                // Write the `pyproject.toml` back to disk.
-                debug!("Reverting changes to `pyproject.toml`");
-                fs_err::write(
-                    project.root().join("pyproject.toml"),
-                    project.pyproject_toml().as_ref(),
-                )?;
-
-                // Write the lockfile back to disk.
-                let target = LockTarget::from(project.workspace());
-                if let Some(lock) = lock {
-                    debug!("Reverting changes to `uv.lock`");
-                    fs_err::write(target.lock_path(), lock)?;
-                } else {
-                    debug!("Removing `uv.lock`");
-                    fs_err::remove_file(target.lock_path())?;
-                }
                 Ok(())
             }
         }
     }
+
+    /// Write the lockfile back to disk, or remove it if it didn't exist.
+    fn revert_lock(target: &LockTarget, lock: Option<&[u8]>) -> Result<(), io::Error> {
+        if let Some(lock) = lock {
+            debug!("Reverting changes to `uv.lock`");
+            uv_fs::write_durable_sync(target.lock_path(), lock)
+        } else {
+            debug!("Removing `uv.lock`");
+            match fs_err::remove_file(target.lock_path()) {
+                Ok(()) => Ok(()),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+                Err(err) => Err(err),
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1510,3 +1542,20 @@ struct DependencyEdit {
     source: Option<Source>,
     edit: ArrayEdit,
 }
+
+impl DependencyEdit {
+    /// Returns `true` if the edit adds a registry requirement without a version specifier, to
+    /// which a lower bound will be added after locking.
+    fn is_unbounded_registry_addition(&self) -> bool {
+        matches!(self.edit, ArrayEdit::Add(_))
+            && self
+                .source
+                .as_ref()
+                .is_none_or(|source| matches!(source, Source::Registry { .. }))
+            && match self.requirement.version_or_url.as_ref() {
+                Some(VersionOrUrl::VersionSpecifier(version)) => version.is_empty(),
+                Some(VersionOrUrl::Url(_)) => false,
+                None => true,
+            }
+    }
+}
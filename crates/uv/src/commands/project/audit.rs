@@ -211,6 +211,7 @@ pub(crate) async fn audit(
         resolution_markers(
             python_version.as_ref(),
             python_platform.as_ref(),
+            None,
             interpreter.as_ref().unwrap(),
         )
     });
@@ -22,7 +22,7 @@ use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceErrorKind};
 
 use crate::commands::pip::loggers::{SummaryInstallLogger, SummaryResolveLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications};
 use crate::commands::project::environment::CachedEnvironment;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
@@ -336,6 +336,7 @@ pub(crate) async fn check(
             None,
             InstallOptions::default(),
             Modifications::Sufficient,
+            &ExtraneousOptions::default(),
             None,
             (&settings).into(),
             &client_builder,
@@ -346,6 +347,7 @@ pub(crate) async fn check(
             cache,
             workspace_cache,
             DryRun::Disabled,
+            frozen.is_some(),
             printer,
             preview,
             &malware_settings,
@@ -588,6 +590,7 @@ pub(crate) async fn check(
                 None,
                 InstallOptions::default(),
                 Modifications::Sufficient,
+                &ExtraneousOptions::default(),
                 None,
                 (&settings).into(),
                 &client_builder,
@@ -598,6 +601,7 @@ pub(crate) async fn check(
                 cache,
                 workspace_cache,
                 DryRun::Disabled,
+                frozen.is_some(),
                 printer,
                 preview,
                 &malware_settings,
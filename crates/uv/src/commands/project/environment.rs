@@ -17,7 +17,7 @@ use uv_cache_key::{cache_digest, hash_digest};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, HashCheckingMode, TargetTriple};
 use uv_distribution_types::{
-    BuiltDist, Dist, Identifier, Node, Resolution, ResolvedDist, SourceDist,
+    BuiltDist, Dist, Identifier, InstallContext, Node, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::PythonExt;
 use uv_preview::Preview;
@@ -125,9 +125,9 @@ fn cached_environment_resolution_hash(
         HashStrategy::None | HashStrategy::Generate(_) => resolution_hash,
         // Never reuse an environment materialized without hash verification for a lock-backed
         // resolution with the same distributions and expected hashes.
-        HashStrategy::Verify(_) | HashStrategy::Require(_) => {
-            hash_digest(&("verify", resolution_hash))
-        }
+        HashStrategy::GenerateAndVerify(..)
+        | HashStrategy::Verify(_)
+        | HashStrategy::Require(_) => hash_digest(&("verify", resolution_hash)),
     }
 }
 
@@ -144,6 +144,7 @@ impl CachedEnvironment {
         resolve: Box<dyn ResolveLogger>,
         install: Box<dyn InstallLogger>,
         installer_metadata: bool,
+        install_context: InstallContext,
         concurrency: &Concurrency,
         cache: &Cache,
         workspace_cache: &WorkspaceCache,
@@ -327,6 +328,7 @@ impl CachedEnvironment {
             state,
             install,
             installer_metadata,
+            install_context,
             concurrency,
             cache,
             printer,
@@ -19,7 +19,9 @@ use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::{
+    DockerfileExport, JsonGraphExport, PylockToml, RequirementsTxtExport, cyclonedx_json,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user;
@@ -74,6 +76,7 @@ pub(crate) async fn export(
     frozen: Option<FrozenSource>,
     include_annotations: bool,
     include_header: bool,
+    distroless: bool,
     include_index_url: bool,
     include_find_links: bool,
     script: Option<Pep723Script>,
@@ -368,6 +371,12 @@ pub(crate) async fn export(
         }
     }
 
+    if distroless && !matches!(format, ExportFormat::DockerfileSnippet) {
+        return Err(anyhow!(
+            "`--distroless` is only supported by the `dockerfile-snippet` format"
+        ));
+    }
+
     // Generate the export.
     match format {
         ExportFormat::RequirementsTxt => {
@@ -472,6 +481,39 @@ pub(crate) async fn export(
 
             export.output_as_json_v1_5(&mut writer)?;
         }
+        ExportFormat::DockerfileSnippet => {
+            if matches!(target, InstallTarget::Script { .. }) {
+                return Err(anyhow!(
+                    "The `dockerfile-snippet` format is not supported for scripts"
+                ));
+            }
+
+            let export = DockerfileExport::from_lock(
+                &target,
+                all_packages,
+                &package,
+                &extras,
+                &groups,
+                &install_options,
+                distroless,
+            )?;
+
+            if include_header {
+                writeln!(
+                    writer,
+                    "{}",
+                    "# This file was autogenerated by uv via the following command:".green()
+                )?;
+                writeln!(writer, "{}", format!("#    {}", cmd()).green())?;
+            }
+            write!(writer, "{export}")?;
+        }
+        ExportFormat::JsonGraph => {
+            let export =
+                JsonGraphExport::from_lock(&target, &prune, &extras, &groups, &install_options)?;
+
+            writeln!(writer, "{}", export.to_json()?)?;
+        }
     }
 
     writer.commit().await?;
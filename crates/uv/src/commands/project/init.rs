@@ -445,7 +445,7 @@ async fn init_project(
             pyproject.add_workspace(path.strip_prefix(workspace.install_path())?)?;
 
             // Save the modified `pyproject.toml`.
-            fs_err::write(
+            uv_fs::write_durable_sync(
                 workspace.install_path().join("pyproject.toml"),
                 pyproject.to_string(),
             )?;
@@ -22,6 +22,7 @@ use uv_workspace::Workspace;
 use uv_workspace::pyproject::{Source, Sources, ToolUvSources};
 
 use crate::commands::project::ProjectError;
+use crate::commands::project::lock_target::LockTarget;
 
 /// A target that can be installed from a lockfile.
 #[derive(Debug, Copy, Clone)]
@@ -119,6 +120,17 @@ impl<'lock> Installable<'lock> for InstallTarget<'lock> {
 }
 
 impl<'lock> InstallTarget<'lock> {
+    /// Return the [`LockTarget`] from which the target's lockfile was read.
+    pub(crate) fn lock_target(&self) -> LockTarget<'lock> {
+        match self {
+            Self::Project { workspace, .. } => LockTarget::from(*workspace),
+            Self::Projects { workspace, .. } => LockTarget::from(*workspace),
+            Self::Workspace { workspace, .. } => LockTarget::from(*workspace),
+            Self::NonProjectWorkspace { workspace, .. } => LockTarget::from(*workspace),
+            Self::Script { script, .. } => LockTarget::from(*script),
+        }
+    }
+
     /// Convert the target's locked packages to a [`Resolution`].
     pub(crate) fn to_resolution(
         self,
@@ -1,8 +1,6 @@
-#![expect(clippy::single_match_else)]
-
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use owo_colors::OwoColorize;
@@ -10,17 +8,21 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::{Cache, Refresh};
+use uv_cli::LockReportFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExcludeDependency,
-    ExtrasSpecification, Override, PackageOverride, Reinstall, Upgrade,
+    BuildOptions, Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun,
+    ExcludeDependency, ExtrasSpecification, Override, PackageOverride, Reinstall, Replacement,
+    Replacements, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
 use uv_distribution_types::{
-    DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Requirement, RequiresPython, UnresolvedRequirementSpecification,
+    BuildDependencyLock, BuildableSource, DependencyMetadata, HashGeneration, HashPolicy, Index,
+    IndexLocations, Name, NameRequirementSpecification, Requirement, RequiresPython,
+    UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
 use uv_normalize::{GroupName, PackageName};
@@ -30,8 +32,9 @@ use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::{ExtrasResolver, LockedRequirements, read_lock_requirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    FlatIndex, ForkSummary, InMemoryIndex, IndexArtifactPolicies, Lock, LockReport, Options,
+    OptionsBuilder, Package, PythonRequirement, RequiresPythonStrategy, ResolverEnvironment,
+    ResolverManifest, SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
@@ -45,6 +48,10 @@ use uv_workspace::{
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::narrow_bounds::{
+    NarrowBounds, narrowed_requirements, report_narrowed, write_narrowed,
+};
+use crate::commands::project::resolution_cache::{ResolutionCache, ResolutionInputs};
 use crate::commands::project::{
     MissingLockfileSource, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
     WorkspacePython, init_script_python_requirement, script_extra_build_requires,
@@ -86,6 +93,12 @@ pub(crate) async fn lock(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    narrow_bounds: NarrowBounds,
+    summary: bool,
+    explain: Vec<PackageName>,
+    requires_python_strategy: RequiresPythonStrategy,
+    preview_report: Option<PathBuf>,
+    preview_report_format: LockReportFormat,
     refresh: Refresh,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
@@ -216,6 +229,8 @@ pub(crate) async fn lock(
             preview,
         )
         .with_refresh(&refresh)
+        .with_explain(&explain)
+        .with_requires_python_strategy(requires_python_strategy)
         .execute(target),
     )
     .await
@@ -265,18 +280,109 @@ pub(crate) async fn lock(
                 }
             }
 
+            // Report the forks in the resolution, e.g., to identify the requirements that cause it
+            // to fork.
+            let forks = ForkSummary::from_lock(lock.lock());
+            if summary {
+                write!(printer.stderr(), "{forks}")?;
+            } else if !forks.is_empty() {
+                debug!("{}", anstream::adapter::strip_str(&forks.to_string()));
+            }
+
+            // Write a report of the changes, e.g., for a pull request description.
+            if let Some(path) = preview_report {
+                let (previous, current) = match &lock {
+                    LockResult::Changed(previous, lock) => (previous.as_ref(), lock),
+                    LockResult::Unchanged(lock) => (Some(lock), lock),
+                };
+                let report =
+                    LockReport::from_locks(previous, current, state.index(), target.install_path());
+                let contents = match preview_report_format {
+                    LockReportFormat::Markdown => report.to_markdown(),
+                    LockReportFormat::Json => format!("{}\n", report.to_json()?),
+                };
+                fs_err::write(&path, contents)?;
+                debug!("Wrote lockfile report to: {}", path.user_display());
+            }
+
+            if narrow_bounds.enabled()
+                && let LockTarget::Workspace(workspace) = target
+            {
+                let narrowed = narrowed_requirements(workspace, lock.lock())?;
+                report_narrowed(workspace, &narrowed, narrow_bounds, printer)?;
+
+                if narrow_bounds == NarrowBounds::Write && !narrowed.is_empty() {
+                    write_narrowed(workspace, &narrowed)?;
+
+                    // The lockfile records the declared requirements, so re-lock against the
+                    // updated `pyproject.toml` files. The locked versions satisfy the narrowed
+                    // bounds, so the resolution itself is unchanged.
+                    if let LockMode::Write(interpreter) = mode {
+                        // Avoid reusing any members cached before the edit.
+                        let workspace_cache = WorkspaceCache::default();
+                        let workspace = VirtualProject::discover(
+                            project_dir,
+                            &DiscoveryOptions::default(),
+                            cache,
+                            &workspace_cache,
+                        )
+                        .await?;
+                        let state = UniversalState::default();
+                        match Box::pin(
+                            LockOperation::new(
+                                LockMode::Write(interpreter),
+                                &settings,
+                                &client_builder,
+                                &state,
+                                Box::new(SummaryResolveLogger),
+                                &concurrency,
+                                cache,
+                                &workspace_cache,
+                                printer,
+                                preview,
+                            )
+                            .execute(LockTarget::Workspace(workspace.workspace())),
+                        )
+                        .await
+                        {
+                            Ok(_) => {}
+                            Err(ProjectError::Operation(err)) => {
+                                return diagnostics::OperationDiagnostic::with_system_certs(
+                                    client_builder.system_certs(),
+                                )
+                                .report(err)
+                                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                            }
+                            Err(err) => return Err(err.into()),
+                        }
+                    }
+                }
+            }
+
             Ok(ExitStatus::Success)
         }
         // Lock mismatches from `--check`/`--locked` are expected validation failures.
         // Handle them here so we return exit code 1 instead of bubbling up as an error (exit code 2).
         Err(err @ ProjectError::LockMismatch(..)) => {
+            // With `--check`, summarize the changes that an update would make.
+            if let ProjectError::LockMismatch(previous, lock, LockCheckSource::Check) = &err {
+                for event in LockEvent::detect_changes(previous.as_deref(), lock, DryRun::Enabled) {
+                    writeln!(printer.stderr(), "{event}")?;
+                }
+            }
             writeln!(printer.stderr(), "{}", err.to_string().bold())?;
             Ok(ExitStatus::Failure)
         }
+        // With `--check`, a failed resolution is reported as an error (exit code 2) to distinguish
+        // it from an outdated lockfile.
         Err(ProjectError::Operation(err)) => {
+            let status = match lock_check {
+                LockCheck::Enabled(LockCheckSource::Check) => ExitStatus::Error,
+                LockCheck::Enabled(_) | LockCheck::Disabled => ExitStatus::Failure,
+            };
             diagnostics::OperationDiagnostic::with_system_certs(client_builder.system_certs())
                 .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+                .map_or(Ok(status), |err| Err(err.into()))
         }
         Err(err) => Err(err.into()),
     }
@@ -299,6 +405,8 @@ pub(crate) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
     refresh: Option<&'env Refresh>,
+    explain: &'env [PackageName],
+    requires_python_strategy: RequiresPythonStrategy,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
     state: &'env UniversalState,
@@ -328,6 +436,8 @@ impl<'env> LockOperation<'env> {
             mode,
             constraints: vec![],
             refresh: None,
+            explain: &[],
+            requires_python_strategy: RequiresPythonStrategy::default(),
             settings,
             client_builder,
             state,
@@ -357,6 +467,29 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set the packages for which to explain the selected version (e.g., `--explain`).
+    ///
+    /// If any packages are provided, the project is always re-resolved, even if the existing
+    /// lockfile satisfies its requirements.
+    #[must_use]
+    pub(crate) fn with_explain(mut self, explain: &'env [PackageName]) -> Self {
+        self.explain = explain;
+        self
+    }
+
+    /// Set the [`RequiresPythonStrategy`] for the [`LockOperation`].
+    ///
+    /// Under [`RequiresPythonStrategy::Strict`], the project is always re-resolved, even if the
+    /// existing lockfile satisfies its requirements.
+    #[must_use]
+    pub(crate) fn with_requires_python_strategy(
+        mut self,
+        requires_python_strategy: RequiresPythonStrategy,
+    ) -> Self {
+        self.requires_python_strategy = requires_python_strategy;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(crate) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -396,6 +529,8 @@ impl<'env> LockOperation<'env> {
                     Some(existing),
                     self.constraints,
                     self.refresh,
+                    self.explain,
+                    self.requires_python_strategy,
                     self.settings,
                     self.client_builder,
                     self.state,
@@ -408,6 +543,14 @@ impl<'env> LockOperation<'env> {
                 ))
                 .await?;
 
+                // If the lockfile only differs in the order of its entries, treat it as unchanged.
+                let result = match result {
+                    LockResult::Changed(Some(prev), cur) if prev.is_equivalent(&cur) => {
+                        LockResult::Unchanged(prev)
+                    }
+                    result => result,
+                };
+
                 // If the lockfile changed, return an error.
                 if let LockResult::Changed(prev, cur) = result {
                     return Err(ProjectError::LockMismatch(
@@ -440,6 +583,8 @@ impl<'env> LockOperation<'env> {
                     existing,
                     self.constraints,
                     self.refresh,
+                    self.explain,
+                    self.requires_python_strategy,
                     self.settings,
                     self.client_builder,
                     self.state,
@@ -472,6 +617,8 @@ async fn do_lock(
     existing_lock: Option<Lock>,
     external: Vec<NameRequirementSpecification>,
     refresh: Option<&Refresh>,
+    explain: &[PackageName],
+    requires_python_strategy: RequiresPythonStrategy,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
     state: &UniversalState,
@@ -490,8 +637,16 @@ async fn do_lock(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
+        prerelease_package,
+        local_version_preference,
+        yanked,
         fork_strategy,
+        max_metadata_builds,
+        resolution_effort,
+        excluded_packages,
+        reresolve_metadata,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -499,9 +654,11 @@ async fn do_lock(
         extra_build_dependencies,
         extra_build_variables,
         exclude_newer,
+        strict_exclude_newer,
         link_mode,
         upgrade,
         build_options,
+        build_runtime,
         sources,
         torch_backend: _,
         cuda_driver_version: _,
@@ -515,6 +672,7 @@ async fn do_lock(
     let requirements = target.requirements();
     let overrides = target.overrides();
     let excludes = target.exclude_dependencies();
+    let replacements = target.replacements()?;
     let constraints = target.constraints();
     let build_constraints = target.build_constraints();
     let dependency_groups = target.dependency_groups()?;
@@ -561,6 +719,28 @@ async fn do_lock(
         }
         lowered_overrides
     };
+    let replacements = {
+        let mut lowered_replacements = Vec::with_capacity(replacements.len());
+        for (name, replacement) in replacements {
+            let lowered = target.lower(
+                vec![replacement.requirement],
+                index_locations,
+                sources,
+                client_builder.credentials_cache(),
+            )?;
+            let Ok([requirement]) = <[Requirement; 1]>::try_from(lowered) else {
+                return Err(ProjectError::AmbiguousReplacement(name));
+            };
+            lowered_replacements.push((
+                name,
+                Replacement {
+                    requirement,
+                    provides: replacement.provides,
+                },
+            ));
+        }
+        Replacements::from_entries(lowered_replacements)
+    };
     let constraints = target.lower(
         constraints,
         index_locations,
@@ -586,6 +766,11 @@ async fn do_lock(
         })
         .collect::<Result<BTreeMap<_, _>, ProjectError>>()?;
 
+    // Validate the entry points of the workspace members.
+    if let LockTarget::Workspace(workspace) = target {
+        workspace.validate_entry_points()?;
+    }
+
     // Collect the conflicts.
     let mut conflicts = target.conflicts()?;
     if let LockTarget::Workspace(workspace) = target {
@@ -767,18 +952,139 @@ async fn do_lock(
 
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
+        .resolution_package(resolution_package.clone())
         .prerelease_mode(*prerelease)
+        .prerelease_package(prerelease_package.clone())
+        .local_version_preference(local_version_preference.clone())
+        .yanked(*yanked)
         .fork_strategy(*fork_strategy)
+        .max_metadata_builds(*max_metadata_builds)
+        .resolution_effort(*resolution_effort)
+        .excluded_packages(excluded_packages.clone())
+        .reresolve_metadata(reresolve_metadata.clone())
+        .explain(explain.to_vec())
+        .requires_python_strategy(requires_python_strategy)
         .exclude_newer(exclude_newer.clone())
+        .strict_exclude_newer(*strict_exclude_newer)
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .artifact_environments(artifact_environments.clone())
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
+    // If none of the inputs to the resolution have changed since the last resolution, reuse it
+    // without querying any index or reading any metadata. Refreshes and upgrades always require a
+    // resolution, as do explanations and strict `requires-python` checks. Locked build dependencies
+    // are resolved for the current environment, so they always require a resolution too.
+    let resolution_cache = (matches!(refresh, None | Some(Refresh::None(_)))
+        && upgrade.is_none()
+        && explain.is_empty()
+        && requires_python_strategy != RequiresPythonStrategy::Strict
+        && !target.lock_build_dependencies())
+    .then(|| {
+        ResolutionCache::from_inputs(
+            cache,
+            &ResolutionInputs {
+                root: target.install_path(),
+                members: packages,
+                required_members,
+                requirements: &requirements,
+                dependency_groups: &dependency_groups,
+                constraints: &constraints,
+                overrides: &overrides,
+                excludes: &excludes,
+                replacements: &replacements,
+                build_constraints: &build_constraints,
+                external: &external,
+                conflicts: &conflicts,
+                environments,
+                required_environments,
+                requires_python: &requires_python,
+                requires_python_strategy,
+                index_locations,
+                index_strategy,
+                resolution,
+                resolution_package,
+                prerelease,
+                prerelease_package,
+                local_version_preference,
+                yanked,
+                fork_strategy,
+                exclude_newer,
+                strict_exclude_newer,
+                max_metadata_builds,
+                resolution_effort,
+                excluded_packages,
+                reresolve_metadata,
+                build_options,
+                dependency_metadata,
+                config_setting,
+                config_settings_package,
+                build_isolation: &settings.build_isolation,
+                extra_build_dependencies,
+                extra_build_variables,
+                sources,
+            },
+        )
+    })
+    .flatten();
+    let existing_lock = match (
+        resolution_cache.as_ref().and_then(ResolutionCache::read),
+        existing_lock,
+    ) {
+        (Some(cached), Some(existing_lock))
+            if existing_lock
+                .to_toml()
+                .is_ok_and(|existing| existing == cached) =>
+        {
+            debug!("Reusing cached resolution for unchanged inputs");
+            logger.on_complete(existing_lock.len(), start, printer)?;
+            return Ok(LockResult::Unchanged(existing_lock));
+        }
+        (Some(cached), None) => {
+            if let Ok(lock) = toml::from_str::<Lock>(&cached) {
+                debug!("Reusing cached resolution for unchanged inputs");
+                logger.on_complete(lock.len(), start, printer)?;
+                return Ok(LockResult::Changed(None, lock));
+            }
+            None
+        }
+        (Some(_), Some(existing_lock)) => {
+            debug!("Ignoring cached resolution, which differs from the existing lockfile");
+            Some(existing_lock)
+        }
+        (None, existing_lock) => existing_lock,
+    };
+
+    // If the build dependencies should be locked, reuse any existing locked build dependencies
+    // (unless they're being upgraded), resolve the build dependencies of every package that can be
+    // built from source, and generate hashes for any newly resolved build dependencies.
+    let lock_build_dependencies = target.lock_build_dependencies();
+    let build_dependency_lock = lock_build_dependencies.then(|| {
+        BuildDependencyLock::new(
+            existing_lock
+                .as_ref()
+                .map(|lock| lock.build_dependencies(target.install_path()))
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|(name, _)| {
+                    !upgrade.is_all()
+                        && !upgrade
+                            .packages()
+                            .is_some_and(|packages| packages.contains(name))
+                })
+                .collect(),
+        )
+        .with_exhaustive(true)
+    });
+    let build_hasher = if lock_build_dependencies {
+        HashStrategy::Generate(HashGeneration::All)
+    } else {
+        HashStrategy::default()
+    };
+
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_hasher = HashStrategy::default();
     let extras = ExtrasSpecification::default();
     let groups = BTreeMap::new();
 
@@ -788,7 +1094,7 @@ async fn do_lock(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, None, &hasher, build_options)
+        FlatIndex::from_entries(entries, None, &hasher, build_options, index_locations)
     };
 
     // Lower the extra build dependencies.
@@ -828,6 +1134,7 @@ async fn do_lock(
         extra_build_variables,
         *link_mode,
         build_options,
+        build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources.clone(),
@@ -835,7 +1142,18 @@ async fn do_lock(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(excluded_packages)
+    .with_build_dependency_lock(build_dependency_lock.clone());
+
+    // Determine the build dependencies to lock: those resolved during this operation, along with
+    // any existing locked build dependencies that weren't upgraded.
+    let locked_build_dependencies = || {
+        build_dependency_lock
+            .as_ref()
+            .map(BuildDependencyLock::to_locked)
+            .unwrap_or_default()
+    };
 
     let database = DistributionDatabase::new(
         &client,
@@ -856,6 +1174,7 @@ async fn do_lock(
             &constraints,
             &overrides,
             &excludes,
+            &replacements,
             &build_constraints,
             &conflicts,
             environments,
@@ -893,13 +1212,35 @@ async fn do_lock(
         None
     };
 
-    match existing_lock {
-        // Resolution from the lockfile succeeded.
-        Some(ValidatedLock::Satisfies(lock)) => {
+    let result = match existing_lock {
+        // Resolution from the lockfile succeeded (and neither explanations nor a strict
+        // `requires-python` check were requested, which require a resolution).
+        Some(ValidatedLock::Satisfies(lock))
+            if explain.is_empty() && requires_python_strategy != RequiresPythonStrategy::Strict =>
+        {
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
-            Ok(LockResult::Unchanged(lock))
+            // Update the locked build dependencies, e.g., if the setting was toggled.
+            if let Some(build_dependency_lock) = &build_dependency_lock {
+                resolve_build_dependencies(
+                    &lock,
+                    target.install_path(),
+                    build_dependency_lock,
+                    build_options,
+                    interpreter,
+                    &database,
+                )
+                .await?;
+            }
+            let updated = lock
+                .clone()
+                .with_build_dependencies(&locked_build_dependencies(), target.install_path())?;
+            if updated == lock {
+                LockResult::Unchanged(lock)
+            } else {
+                LockResult::Changed(Some(lock), updated)
+            }
         }
 
         // The lockfile did not contain enough information to obtain a resolution, fallback
@@ -981,6 +1322,7 @@ async fn do_lock(
                 Vec::new(),
                 overrides.clone(),
                 excludes.clone(),
+                replacements.clone(),
                 source_trees,
                 // The root is always null in workspaces, it "depends on" the projects
                 None,
@@ -1014,6 +1356,9 @@ async fn do_lock(
             // Notify the user of any resolution diagnostics.
             pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
+            // Explain the selected versions of the requested packages.
+            pip::operations::report_explanations(resolution.explanations(), printer)?;
+
             let manifest = ResolverManifest::new(
                 members,
                 requirements,
@@ -1024,6 +1369,7 @@ async fn do_lock(
                 dependency_groups,
                 dependency_metadata.values().cloned(),
             )
+            .with_replacements(&replacements)
             .relative_to(target.install_path())?;
 
             let previous = existing_lock.map(ValidatedLock::into_lock);
@@ -1034,15 +1380,89 @@ async fn do_lock(
             )?
             .with_manifest(manifest)
             .with_conflicts(conflicts)
-            .with_required_environments(lock_required_environments.into_markers());
+            .with_required_environments(lock_required_environments.into_markers())
+            .with_index_artifact_policies(IndexArtifactPolicies::from_locations(
+                index_locations,
+                target.install_path(),
+            )?);
+
+            // Lock the build dependencies of any package that wasn't built during resolution.
+            if let Some(build_dependency_lock) = &build_dependency_lock {
+                resolve_build_dependencies(
+                    &lock,
+                    target.install_path(),
+                    build_dependency_lock,
+                    build_options,
+                    interpreter,
+                    &database,
+                )
+                .await?;
+            }
+            let lock =
+                lock.with_build_dependencies(&locked_build_dependencies(), target.install_path())?;
 
             if previous.as_ref().is_some_and(|previous| *previous == lock) {
-                Ok(LockResult::Unchanged(lock))
+                LockResult::Unchanged(lock)
             } else {
-                Ok(LockResult::Changed(previous, lock))
+                LockResult::Changed(previous, lock)
             }
         }
+    };
+
+    // Notify the user of any warnings emitted by build backends, e.g., while validating the
+    // existing lockfile or resolving build dependencies.
+    pip::operations::report_build_warnings(&build_dispatch, printer)?;
+
+    // Persist the resolution, such that it can be reused if the inputs are unchanged.
+    if let Some(resolution_cache) = resolution_cache {
+        resolution_cache.write(result.lock(), target.install_path());
+    }
+
+    Ok(result)
+}
+
+/// Resolve the build dependencies of every package in the lock that can be built from source, but
+/// lacks locked build dependencies for the current environment, such that they can be locked.
+///
+/// Packages whose build dependencies can't be resolved (e.g., because they can only be built on
+/// another platform) are skipped with a warning.
+async fn resolve_build_dependencies(
+    lock: &Lock,
+    root: &Path,
+    build_dependency_lock: &BuildDependencyLock,
+    build_options: &BuildOptions,
+    interpreter: &Interpreter,
+    database: &DistributionDatabase<'_, BuildDispatch<'_>>,
+) -> Result<(), ProjectError> {
+    let source_dists = lock
+        .source_dists(root)?
+        .into_iter()
+        .filter(|source_dist| !build_options.no_build_package(source_dist.name()))
+        .filter(|source_dist| {
+            build_dependency_lock.requires_resolution(source_dist.name(), interpreter.markers())
+        })
+        .collect::<Vec<_>>();
+
+    let results = futures::future::join_all(source_dists.iter().map(|source_dist| async move {
+        debug!("Resolving build dependencies for: {source_dist}");
+        let result = database
+            .build_wheel_metadata(&BuildableSource::Dist(source_dist), HashPolicy::None)
+            .await;
+        (source_dist, result)
+    }))
+    .await;
+
+    for (source_dist, result) in results {
+        if let Err(err) = result {
+            warn_user!(
+                "Failed to lock the build dependencies of `{}`: {}",
+                source_dist.name(),
+                err
+            );
+        }
     }
+
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -1072,6 +1492,7 @@ impl ValidatedLock {
         constraints: &[Requirement],
         overrides: &[Override<Requirement>],
         excludes: &[ExcludeDependency],
+        replacements: &Replacements,
         build_constraints: &[Requirement],
         conflicts: &Conflicts,
         environments: Option<&SupportedEnvironments>,
@@ -1101,6 +1522,27 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        if *lock.resolution_package() != options.resolution_package {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in package-specific resolution modes"
+            );
+            return Ok(Self::Unusable(lock));
+        }
+        if *lock.prerelease_package() != options.prerelease_package {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in package-specific pre-release modes"
+            );
+            return Ok(Self::Unusable(lock));
+        }
+        if *lock.local_version_preference() != options.local_version_preference {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in local version preferences"
+            );
+            return Ok(Self::Unusable(lock));
+        }
         if lock.fork_strategy() != options.fork_strategy {
             let _ = writeln!(
                 printer.stderr(),
@@ -1121,6 +1563,38 @@ impl ValidatedLock {
                 return Ok(Self::Preferable(lock));
             }
         }
+        if *lock.index_artifact_policies()
+            != IndexArtifactPolicies::from_locations(index_locations, install_path)?
+        {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in index artifact policy (`allow-sdist` or `allow-wheels`)",
+            );
+            return Ok(Self::Preferable(lock));
+        }
+        if !lock
+            .reresolve_metadata()
+            .iter()
+            .eq(options.reresolve_metadata.iter().collect::<BTreeSet<_>>())
+        {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in packages with ignored index metadata (`reresolve-metadata`)",
+            );
+            return Ok(Self::Preferable(lock));
+        }
+        if let Some(package) = lock
+            .packages()
+            .iter()
+            .find(|package| options.excluded_packages.contains(package.name()))
+        {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to excluded package: `{}`",
+                package.name().cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
 
         if upgrade.is_all() {
             // If the user specified `--upgrade`, then we can't use the existing lockfile.
@@ -1276,6 +1750,7 @@ impl ValidatedLock {
                 constraints,
                 overrides,
                 excludes,
+                replacements,
                 build_constraints,
                 dependency_groups,
                 dependency_metadata,
@@ -1376,6 +1851,13 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedReplacements(expected, actual) => {
+                debug!(
+                    "Resolving despite existing lockfile due to mismatched replacements:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MismatchedBuildConstraints(expected, actual) => {
                 debug!(
                     "Resolving despite existing lockfile due to mismatched build constraints:\n  Requested: {:?}\n  Existing: {:?}",
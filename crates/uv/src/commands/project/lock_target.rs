@@ -13,8 +13,8 @@ use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
 use uv_resolver::{Lock, LockVersion, VERSION};
 use uv_scripts::Pep723Script;
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroup};
-use uv_workspace::pyproject::OverrideDependency;
-use uv_workspace::{Editability, Workspace, WorkspaceMember};
+use uv_workspace::pyproject::{OverrideDependency, ReplaceDependency};
+use uv_workspace::{Editability, Workspace, WorkspaceError, WorkspaceMember};
 
 use crate::commands::project::{ProjectError, find_requires_python};
 
@@ -81,6 +81,16 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the set of package replacements for the [`LockTarget`].
+    pub(crate) fn replacements(
+        self,
+    ) -> Result<BTreeMap<PackageName, ReplaceDependency>, WorkspaceError> {
+        match self {
+            Self::Workspace(workspace) => workspace.replacements(),
+            Self::Script(_) => Ok(BTreeMap::new()),
+        }
+    }
+
     /// Returns the set of constraints for the [`LockTarget`].
     pub(crate) fn constraints(self) -> Vec<uv_pep508::Requirement<VerbatimParsedUrl>> {
         match self {
@@ -115,6 +125,16 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns `true` if the build dependencies of source distributions should be locked.
+    ///
+    /// Build dependencies are never locked for scripts.
+    pub(crate) fn lock_build_dependencies(self) -> bool {
+        match self {
+            Self::Workspace(workspace) => workspace.lock_build_dependencies(),
+            Self::Script(_) => false,
+        }
+    }
+
     /// Return the dependency groups that are attached to the target directly, as opposed to being
     /// attached to any members within the target.
     pub(crate) fn dependency_groups(
@@ -342,7 +362,7 @@ impl<'lock> LockTarget<'lock> {
     /// Write the lockfile to disk.
     pub(crate) async fn commit(self, lock: &Lock) -> Result<(), ProjectError> {
         let encoded = lock.to_toml()?;
-        fs_err::tokio::write(self.lock_path(), encoded).await?;
+        uv_fs::write_durable(self.lock_path(), encoded).await?;
         Ok(())
     }
 
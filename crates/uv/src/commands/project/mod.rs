@@ -14,13 +14,13 @@ use uv_cache_key::{cache_digest, cache_name};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
-    GitLfsSetting, Override, PackageOverride, Reinstall, TargetTriple, Upgrade,
+    GitLfsSetting, Override, PackageOverride, Reinstall, Replacements, TargetTriple, Upgrade,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies, LoweredRequirement};
 use uv_distribution_types::{
     ExtraBuildRequirement, ExtraBuildRequires, HashGeneration, Index, IndexCredentialsError,
-    Requirement, RequiresPython, Resolution, UnresolvedRequirement,
+    InstallContext, Requirement, RequiresPython, Resolution, UnresolvedRequirement,
     UnresolvedRequirementSpecification,
 };
 use uv_fs::{CWD, LockedFile, LockedFileError, LockedFileMode, Simplified, verbatim_path};
@@ -57,8 +57,9 @@ use uv_workspace::pyproject::{ExtraBuildDependency, PyProjectToml};
 use uv_workspace::{ProjectEnvironmentSelection, RequiresPythonSources, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
-use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::pip::operations::{Changelog, ExtraneousOptions, Modifications};
 use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::registry::EnvironmentRegistry;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
 use crate::printer::Printer;
@@ -77,7 +78,10 @@ pub(crate) mod init;
 pub(crate) mod install_target;
 pub(crate) mod lock;
 pub(crate) mod lock_target;
+pub(crate) mod narrow_bounds;
+pub(crate) mod registry;
 pub(crate) mod remove;
+pub(crate) mod resolution_cache;
 pub(crate) mod run;
 pub(crate) mod sync;
 pub(crate) mod toolchain;
@@ -261,6 +265,11 @@ pub(crate) enum ProjectError {
     #[error("Environment marker is empty")]
     EmptyEnvironment,
 
+    #[error(
+        "The replacement for `{0}` in `tool.uv.replace` must resolve to a single source, but `tool.uv.sources` provides multiple"
+    )]
+    AmbiguousReplacement(PackageName),
+
     #[error("Project virtual environment directory `{0}` cannot be used because {1}")]
     InvalidProjectEnvironmentDir(PathBuf, String),
 
@@ -323,6 +332,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     Lock(#[from] uv_resolver::LockError),
 
+    #[error(transparent)]
+    MissingMetadata(#[from] uv_resolver::MissingMetadataError),
+
     #[error(transparent)]
     Operation(#[from] pip::operations::Error),
 
@@ -406,6 +418,7 @@ impl uv_errors::Hint for ProjectError {
                 uv_errors::Hints::from(format!("replace `{rhs}` with `{replacement}`"))
             }
             Self::Lock(err) => err.hints(),
+            Self::MissingMetadata(err) => err.hints(),
             Self::Python(err) => err.hints(),
             Self::Operation(err) => err.hints(),
             _ => uv_errors::Hints::none(),
@@ -1634,6 +1647,13 @@ impl ScriptPython {
     }
 }
 
+/// Record a project environment in the [`EnvironmentRegistry`], on a best-effort basis.
+fn register_project_environment(environment: &PythonEnvironment) {
+    if let Err(err) = EnvironmentRegistry::from_settings().register(environment.root()) {
+        debug!("Failed to register project environment: {err}");
+    }
+}
+
 /// The Python environment for a project.
 #[derive(Debug)]
 pub(crate) enum ProjectEnvironment {
@@ -1723,6 +1743,9 @@ impl ProjectEnvironment {
                 if centralized && !dry_run.enabled() {
                     update_project_environment_link(&environment, workspace, link_error_reporting);
                 }
+                if !dry_run.enabled() {
+                    register_project_environment(&environment);
+                }
                 Ok(Self::Existing(environment))
             }
 
@@ -1875,6 +1898,7 @@ impl ProjectEnvironment {
                 if centralized {
                     update_project_environment_link(&environment, workspace, link_error_reporting);
                 }
+                register_project_environment(&environment);
 
                 if replace_environment {
                     Ok(Self::Replaced(environment))
@@ -2122,7 +2146,7 @@ pub(crate) async fn resolve_names(
     printer: Printer,
     preview: Preview,
     lfs: GitLfsSetting,
-) -> Result<Vec<Requirement>, uv_requirements::Error> {
+) -> Result<Vec<Requirement>, ProjectError> {
     // Partition the requirements into named and unnamed requirements.
     let (mut requirements, unnamed): (Vec<_>, Vec<_>) = requirements
         .into_iter()
@@ -2145,11 +2169,17 @@ pub(crate) async fn resolve_names(
         resolver:
             ResolverSettings {
                 build_options,
+                build_runtime,
                 config_setting,
                 config_settings_package,
                 dependency_metadata,
                 exclude_newer,
+                strict_exclude_newer: _,
                 fork_strategy: _,
+                max_metadata_builds: _,
+                resolution_effort: _,
+                excluded_packages,
+                reresolve_metadata: _,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -2159,6 +2189,10 @@ pub(crate) async fn resolve_names(
                 extra_build_variables,
                 prerelease: _,
                 resolution: _,
+                resolution_package: _,
+                prerelease_package: _,
+                local_version_preference: _,
+                yanked: _,
                 sources,
                 torch_backend,
                 cuda_driver_version,
@@ -2247,6 +2281,7 @@ pub(crate) async fn resolve_names(
         extra_build_variables,
         *link_mode,
         build_options,
+        build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources.clone(),
@@ -2254,7 +2289,8 @@ pub(crate) async fn resolve_names(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(excluded_packages);
 
     // Resolve the unnamed requirements.
     requirements.extend(
@@ -2272,6 +2308,9 @@ pub(crate) async fn resolve_names(
         .await?,
     );
 
+    // Notify the user of any warnings emitted by build backends.
+    pip::operations::report_build_warnings(&build_dispatch, printer)?;
+
     Ok(requirements)
 }
 
@@ -2345,8 +2384,16 @@ pub(crate) async fn resolve_environment(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_package,
         prerelease,
+        prerelease_package,
+        local_version_preference,
+        yanked,
         fork_strategy,
+        max_metadata_builds,
+        resolution_effort,
+        excluded_packages,
+        reresolve_metadata,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -2354,9 +2401,11 @@ pub(crate) async fn resolve_environment(
         extra_build_dependencies,
         extra_build_variables,
         exclude_newer,
+        strict_exclude_newer,
         link_mode,
         upgrade,
         build_options,
+        build_runtime,
         sources,
         torch_backend,
         cuda_driver_version,
@@ -2380,8 +2429,9 @@ pub(crate) async fn resolve_environment(
     // Determine the tags and marker environment to use for resolution.
     let (tags, resolver_environment) = match resolution_scope {
         EnvironmentResolution::Specific => {
-            let tags = pip::resolution_tags(None, python_platform, interpreter)?;
-            let marker_environment = pip::resolution_markers(None, python_platform, interpreter);
+            let tags = pip::resolution_tags(None, python_platform, None, None, interpreter)?;
+            let marker_environment =
+                pip::resolution_markers(None, python_platform, None, interpreter);
             (
                 Some(tags),
                 ResolverEnvironment::specific(marker_environment),
@@ -2451,9 +2501,18 @@ pub(crate) async fn resolve_environment(
 
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
+        .resolution_package(resolution_package.clone())
         .prerelease_mode(*prerelease)
+        .prerelease_package(prerelease_package.clone())
+        .local_version_preference(local_version_preference.clone())
+        .yanked(*yanked)
         .fork_strategy(*fork_strategy)
+        .max_metadata_builds(*max_metadata_builds)
+        .resolution_effort(*resolution_effort)
+        .excluded_packages(excluded_packages.clone())
+        .reresolve_metadata(reresolve_metadata.clone())
         .exclude_newer(exclude_newer.clone())
+        .strict_exclude_newer(*strict_exclude_newer)
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .build();
@@ -2501,7 +2560,13 @@ pub(crate) async fn resolve_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, tags.as_deref(), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            tags.as_deref(),
+            &hasher,
+            build_options,
+            index_locations,
+        )
     };
 
     // Lower the extra build dependencies, if any.
@@ -2527,6 +2592,7 @@ pub(crate) async fn resolve_environment(
         extra_build_variables,
         *link_mode,
         build_options,
+        build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources.clone(),
@@ -2534,7 +2600,8 @@ pub(crate) async fn resolve_environment(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(excluded_packages);
 
     // Resolve the requirements.
     Ok(pip::operations::resolve(
@@ -2543,6 +2610,7 @@ pub(crate) async fn resolve_environment(
         overrides,
         override_dependencies,
         excludes,
+        Replacements::default(),
         source_trees,
         project,
         BTreeSet::default(),
@@ -2583,6 +2651,7 @@ pub(crate) async fn sync_environment(
     state: &PlatformState,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    install_context: InstallContext,
     concurrency: &Concurrency,
     cache: &Cache,
     printer: Printer,
@@ -2599,15 +2668,22 @@ pub(crate) async fn sync_environment(
         extra_build_dependencies,
         extra_build_variables,
         exclude_newer,
+        strict_exclude_newer: _,
+        excluded_packages,
         link_mode,
         compile_bytecode,
         reinstall,
         build_options,
+        build_runtime,
         sources,
+        yanked: _,
     } = settings;
 
     let client_builder = client_builder.clone().keyring(keyring_provider);
 
+    // Reject any excluded packages, e.g., in a lockfile that predates the exclusion.
+    pip::operations::check_excluded_resolution(resolution, excluded_packages)?;
+
     let site_packages = SitePackages::from_environment(&venv)?;
 
     // Determine the markers tags to use for resolution.
@@ -2643,7 +2719,7 @@ pub(crate) async fn sync_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options, index_locations)
     };
 
     // Lower the extra build dependencies, if any.
@@ -2669,6 +2745,7 @@ pub(crate) async fn sync_environment(
         extra_build_variables,
         link_mode,
         build_options,
+        build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources,
@@ -2676,7 +2753,8 @@ pub(crate) async fn sync_environment(
         workspace_cache,
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(excluded_packages);
 
     // Sync the environment.
     pip::operations::install(
@@ -2684,6 +2762,7 @@ pub(crate) async fn sync_environment(
         site_packages,
         InstallationStrategy::Permissive,
         modifications,
+        &ExtraneousOptions::default(),
         reinstall,
         build_options,
         link_mode,
@@ -2698,6 +2777,7 @@ pub(crate) async fn sync_environment(
         &venv,
         logger,
         installer_metadata,
+        install_context,
         dry_run,
         printer,
         preview,
@@ -2741,6 +2821,7 @@ pub(crate) async fn update_environment(
     resolve: Box<dyn ResolveLogger>,
     install: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    install_context: InstallContext,
     concurrency: &Concurrency,
     cache: &Cache,
     workspace_cache: &WorkspaceCache,
@@ -2754,11 +2835,17 @@ pub(crate) async fn update_environment(
         resolver:
             ResolverSettings {
                 build_options,
+                build_runtime,
                 config_setting,
                 config_settings_package,
                 dependency_metadata,
                 exclude_newer,
+                strict_exclude_newer,
                 fork_strategy,
+                max_metadata_builds,
+                resolution_effort,
+                excluded_packages,
+                reresolve_metadata,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -2768,6 +2855,10 @@ pub(crate) async fn update_environment(
                 extra_build_variables,
                 prerelease,
                 resolution,
+                resolution_package,
+                prerelease_package,
+                local_version_preference,
+                yanked,
                 sources,
                 torch_backend,
                 cuda_driver_version,
@@ -2794,8 +2885,8 @@ pub(crate) async fn update_environment(
 
     // Determine markers and tags to use for resolution.
     let interpreter = venv.interpreter();
-    let marker_env = pip::resolution_markers(None, python_platform, interpreter);
-    let tags = pip::resolution_tags(None, python_platform, interpreter)?;
+    let marker_env = pip::resolution_markers(None, python_platform, None, interpreter);
+    let tags = pip::resolution_tags(None, python_platform, None, None, interpreter)?;
 
     // Check if the current environment satisfies the requirements
     let site_packages = SitePackages::from_environment(&venv)?;
@@ -2889,9 +2980,18 @@ pub(crate) async fn update_environment(
 
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
+        .resolution_package(resolution_package.clone())
         .prerelease_mode(*prerelease)
+        .prerelease_package(prerelease_package.clone())
+        .local_version_preference(local_version_preference.clone())
+        .yanked(*yanked)
         .fork_strategy(*fork_strategy)
+        .max_metadata_builds(*max_metadata_builds)
+        .resolution_effort(*resolution_effort)
+        .excluded_packages(excluded_packages.clone())
+        .reresolve_metadata(reresolve_metadata.clone())
         .exclude_newer(exclude_newer.clone())
+        .strict_exclude_newer(*strict_exclude_newer)
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
         .build();
@@ -2913,7 +3013,13 @@ pub(crate) async fn update_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            build_options,
+            index_locations,
+        )
     };
 
     // Create a build dispatch.
@@ -2934,6 +3040,7 @@ pub(crate) async fn update_environment(
         extra_build_variables,
         *link_mode,
         build_options,
+        build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources.clone(),
@@ -2941,7 +3048,8 @@ pub(crate) async fn update_environment(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(excluded_packages);
 
     // Resolve the requirements.
     let (resolution, hasher) = match pip::operations::resolve(
@@ -2950,6 +3058,7 @@ pub(crate) async fn update_environment(
         overrides,
         override_dependencies,
         excludes,
+        Replacements::default(),
         source_trees,
         project,
         BTreeSet::default(),
@@ -2985,6 +3094,7 @@ pub(crate) async fn update_environment(
         site_packages,
         InstallationStrategy::Permissive,
         modifications,
+        &ExtraneousOptions::default(),
         reinstall,
         build_options,
         *link_mode,
@@ -2999,6 +3109,7 @@ pub(crate) async fn update_environment(
         &venv,
         install,
         installer_metadata,
+        install_context,
         dry_run,
         printer,
         preview,
@@ -3387,7 +3498,7 @@ fn format_requires_python_sources(conflicts: &RequiresPythonSources) -> String {
         .join("\n")
 }
 
-fn format_optional_requires_python_sources(
+pub(crate) fn format_optional_requires_python_sources(
     conflicts: &RequiresPythonSources,
     workspace_non_trivial: bool,
 ) -> String {
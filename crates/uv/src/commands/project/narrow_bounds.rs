@@ -0,0 +1,376 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_normalize::PackageName;
+use uv_pep440::{Operator, Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{Requirement, VersionOrUrl};
+use uv_resolver::Lock;
+use uv_workspace::Workspace;
+use uv_workspace::pyproject::DependencyType;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+
+use crate::printer::Printer;
+
+/// Whether to raise the lower bounds of direct dependencies to their locked versions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NarrowBounds {
+    /// Leave the declared specifiers untouched.
+    #[default]
+    Disabled,
+    /// Report the proposed specifiers without modifying any `pyproject.toml`.
+    Report,
+    /// Apply the proposed specifiers to each `pyproject.toml`.
+    Write,
+}
+
+impl NarrowBounds {
+    /// Determine the [`NarrowBounds`] setting based on the command-line arguments.
+    pub(crate) fn from_args(narrow_bounds: bool, write: bool) -> Self {
+        match (narrow_bounds, write) {
+            (true, true) => Self::Write,
+            (true, false) => Self::Report,
+            (false, _) => Self::Disabled,
+        }
+    }
+
+    /// Returns `true` if bound narrowing is enabled.
+    pub(crate) fn enabled(self) -> bool {
+        !matches!(self, Self::Disabled)
+    }
+}
+
+/// A proposed change to the specifiers of a direct dependency.
+#[derive(Debug)]
+pub(crate) struct NarrowedRequirement {
+    /// The workspace member that declares the dependency.
+    member: PackageName,
+    /// The `pyproject.toml` that declares the dependency.
+    path: PathBuf,
+    /// The table that declares the dependency.
+    dependency_type: DependencyType,
+    /// The index of the dependency in the containing array.
+    index: usize,
+    /// The requirement as currently declared.
+    previous: Requirement,
+    /// The requirement with its lower bound raised to the locked version.
+    narrowed: Requirement,
+}
+
+/// Compute the narrowed requirements for every workspace member, based on the versions in the
+/// given [`Lock`].
+///
+/// Workspace members, URL requirements, and dependencies that don't resolve to a registry
+/// distribution are skipped.
+pub(crate) fn narrowed_requirements(
+    workspace: &Workspace,
+    lock: &Lock,
+) -> Result<Vec<NarrowedRequirement>> {
+    let mut narrowed = Vec::new();
+
+    for (member_name, member) in workspace.packages() {
+        let pyproject = PyProjectTomlMut::from_toml(
+            &member.pyproject_toml().raw,
+            DependencyTarget::PyProjectToml,
+        )?;
+
+        for (dependency_type, index, requirement) in pyproject.dependencies() {
+            if workspace.packages().contains_key(&requirement.name) {
+                continue;
+            }
+
+            let mut locked_versions = BTreeSet::new();
+            for package in lock.packages() {
+                if package.name() == &requirement.name
+                    && package.index(workspace.install_path())?.is_some()
+                    && package.is_included_by_marker(requirement.marker)
+                    && let Some(version) = package.version()
+                {
+                    locked_versions.insert(version.clone());
+                }
+            }
+
+            let Some(specifiers) = narrow_requirement(&requirement, &locked_versions) else {
+                continue;
+            };
+
+            let mut requirement_narrowed = requirement.clone();
+            requirement_narrowed.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+
+            narrowed.push(NarrowedRequirement {
+                member: member_name.clone(),
+                path: member.root().join("pyproject.toml"),
+                dependency_type,
+                index,
+                previous: requirement,
+                narrowed: requirement_narrowed,
+            });
+        }
+    }
+
+    Ok(narrowed)
+}
+
+/// Apply the narrowed requirements to each `pyproject.toml`, preserving formatting.
+pub(crate) fn write_narrowed(
+    workspace: &Workspace,
+    narrowed: &[NarrowedRequirement],
+) -> Result<()> {
+    for (member_name, member) in workspace.packages() {
+        let mut edits = narrowed
+            .iter()
+            .filter(|requirement| requirement.member == *member_name)
+            .peekable();
+        if edits.peek().is_none() {
+            continue;
+        }
+
+        let mut pyproject = PyProjectTomlMut::from_toml(
+            &member.pyproject_toml().raw,
+            DependencyTarget::PyProjectToml,
+        )?;
+        let mut path = None;
+        for edit in edits {
+            let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &edit.narrowed.version_or_url
+            else {
+                continue;
+            };
+            pyproject.set_dependency_specifiers(
+                &edit.dependency_type,
+                edit.index,
+                specifiers.clone(),
+            )?;
+            path = Some(&edit.path);
+        }
+
+        if let Some(path) = path {
+            uv_fs::write_durable_sync(path, pyproject.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Report the narrowed requirements, in the form `old → new`.
+pub(crate) fn report_narrowed(
+    workspace: &Workspace,
+    narrowed: &[NarrowedRequirement],
+    mode: NarrowBounds,
+    printer: Printer,
+) -> Result<()> {
+    if narrowed.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "All dependency lower bounds match the lockfile".bold()
+        )?;
+        return Ok(());
+    }
+
+    let verb = match mode {
+        NarrowBounds::Write => "Narrowed",
+        NarrowBounds::Report | NarrowBounds::Disabled => "Would narrow",
+    };
+    for requirement in narrowed {
+        let location = if workspace.packages().len() > 1 {
+            format!(
+                "{} in `{}`",
+                dependency_table(&requirement.dependency_type),
+                requirement.member
+            )
+        } else {
+            dependency_table(&requirement.dependency_type)
+        };
+        writeln!(
+            printer.stderr(),
+            "{} `{}` → `{}` ({location})",
+            verb.green().bold(),
+            requirement.previous,
+            requirement.narrowed,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Return the name of the TOML table that contains the given [`DependencyType`].
+fn dependency_table(dependency_type: &DependencyType) -> String {
+    match dependency_type {
+        DependencyType::Production => "project.dependencies".to_string(),
+        DependencyType::Dev => "tool.uv.dev-dependencies".to_string(),
+        DependencyType::Optional(extra) => format!("project.optional-dependencies.{extra}"),
+        DependencyType::Group(group) => format!("dependency-groups.{group}"),
+    }
+}
+
+/// Return the specifiers for a requirement with its lower bound raised to the lowest locked
+/// version, or `None` if the requirement should be left as-is.
+///
+/// Upper bounds and exclusions are preserved. Exact pins are never modified, and compatible
+/// release specifiers are rewritten at their existing precision, such that `~=1.2` with `1.4.2`
+/// locked becomes `~=1.4`.
+fn narrow_requirement(
+    requirement: &Requirement,
+    locked_versions: &BTreeSet<Version>,
+) -> Option<VersionSpecifiers> {
+    let lowest = locked_versions.first()?;
+
+    // A pre-release lower bound would opt the requirement into pre-releases.
+    if lowest.any_prerelease() {
+        return None;
+    }
+    let lowest = lowest.clone().without_local();
+
+    let specifiers = match &requirement.version_or_url {
+        Some(VersionOrUrl::VersionSpecifier(specifiers)) => specifiers.clone(),
+        Some(VersionOrUrl::Url(_)) => return None,
+        None => VersionSpecifiers::empty(),
+    };
+
+    // The locked version must already satisfy the declared specifiers.
+    if !specifiers.contains(&lowest) {
+        return None;
+    }
+
+    // Exact pins can't be narrowed any further.
+    if specifiers.iter().any(|specifier| {
+        matches!(
+            specifier.operator(),
+            Operator::Equal | Operator::EqualStar | Operator::ExactEqual
+        )
+    }) {
+        return None;
+    }
+
+    let mut narrowed = Vec::new();
+    let mut has_compatible_release = false;
+    for specifier in specifiers.iter() {
+        match specifier.operator() {
+            Operator::GreaterThan | Operator::GreaterThanEqual => {}
+            Operator::TildeEqual => {
+                let version =
+                    lowest.only_release_at_precision(specifier.version().release().len())?;
+                narrowed.push(VersionSpecifier::from_version(Operator::TildeEqual, version).ok()?);
+                has_compatible_release = true;
+            }
+            Operator::NotEqual | Operator::NotEqualStar => {
+                // Drop exclusions that fall below the new lower bound.
+                if specifier.version() >= &lowest {
+                    narrowed.push(specifier.clone());
+                }
+            }
+            Operator::LessThan | Operator::LessThanEqual => narrowed.push(specifier.clone()),
+            Operator::Equal | Operator::EqualStar | Operator::ExactEqual => return None,
+        }
+    }
+    if !has_compatible_release {
+        narrowed.insert(0, VersionSpecifier::greater_than_equal_version(lowest));
+    }
+
+    let narrowed = VersionSpecifiers::from_iter(narrowed);
+    if narrowed == specifiers {
+        return None;
+    }
+    Some(narrowed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+    use uv_pep508::Requirement;
+
+    use super::narrow_requirement;
+
+    fn locked_versions(versions: &[&str]) -> BTreeSet<Version> {
+        versions
+            .iter()
+            .map(|version| Version::from_str(version).expect("valid version"))
+            .collect()
+    }
+
+    fn narrow(requirement: &str, versions: &[&str]) -> Option<String> {
+        let requirement = Requirement::from_str(requirement).expect("valid requirement");
+        narrow_requirement(&requirement, &locked_versions(versions))
+            .map(|specifiers| specifiers.to_string())
+    }
+
+    #[test]
+    fn narrow_requirement_raises_lower_bound() {
+        assert_eq!(
+            narrow("requests>=2", &["2.32.3"]),
+            Some(">=2.32.3".to_string())
+        );
+        assert_eq!(
+            narrow("requests>1", &["2.32.3"]),
+            Some(">=2.32.3".to_string())
+        );
+        assert_eq!(
+            narrow("requests", &["2.32.3"]),
+            Some(">=2.32.3".to_string())
+        );
+    }
+
+    #[test]
+    fn narrow_requirement_preserves_upper_bounds() {
+        assert_eq!(
+            narrow("requests>=2,<3", &["2.32.3"]),
+            Some(">=2.32.3, <3".to_string())
+        );
+        assert_eq!(
+            narrow("requests<=2.40", &["2.32.3"]),
+            Some(">=2.32.3, <=2.40".to_string())
+        );
+    }
+
+    #[test]
+    fn narrow_requirement_drops_exclusions_below_floor() {
+        assert_eq!(
+            narrow("requests>=2,!=2.1,!=2.40", &["2.32.3"]),
+            Some(">=2.32.3, !=2.40".to_string())
+        );
+    }
+
+    #[test]
+    fn narrow_requirement_preserves_compatible_release_precision() {
+        assert_eq!(
+            narrow("requests~=2.1", &["2.32.3"]),
+            Some("~=2.32".to_string())
+        );
+        assert_eq!(
+            narrow("requests~=2.1.0", &["2.32.3"]),
+            None,
+            "`~=2.1.0` doesn't admit `2.32.3`"
+        );
+    }
+
+    #[test]
+    fn narrow_requirement_uses_lowest_locked_version() {
+        assert_eq!(
+            narrow("numpy>=1", &["1.26.4", "2.1.0"]),
+            Some(">=1.26.4".to_string())
+        );
+    }
+
+    #[test]
+    fn narrow_requirement_skips_unchanged_and_pinned() {
+        assert_eq!(narrow("requests>=2.32.3", &["2.32.3"]), None);
+        assert_eq!(narrow("requests==2.32.3", &["2.32.3"]), None);
+        assert_eq!(narrow("requests==2.*", &["2.32.3"]), None);
+        assert_eq!(narrow("requests>=2", &[]), None);
+    }
+
+    #[test]
+    fn narrow_requirement_skips_prereleases_and_strips_local_versions() {
+        assert_eq!(narrow("torch>=2", &["2.5.0rc1"]), None);
+        assert_eq!(
+            narrow("torch>=2", &["2.5.1+cpu"]),
+            Some(">=2.5.1".to_string())
+        );
+    }
+}
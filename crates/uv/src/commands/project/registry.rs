@@ -0,0 +1,101 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use uv_cache_key::cache_digest;
+use uv_dirs::DirectorySource;
+use uv_fs::Simplified;
+use uv_state::{StateBucket, StateStore};
+
+/// A best-effort registry of the project environments that uv has used.
+///
+/// The registry allows `uv cache clean --preserve-referenced` to find environments that may link
+/// to entries in the cache. Environments are registered whenever a project command uses them, so
+/// environments created by other means (e.g., `uv venv`) or that haven't been used since the
+/// registry was introduced are missing. Entries for environments that no longer exist are removed
+/// when reading the registry.
+///
+/// Each environment is recorded as a separate file, named by a digest of its path, to avoid
+/// contention between concurrent uv processes.
+#[derive(Debug, Clone)]
+pub(crate) struct EnvironmentRegistry {
+    root: PathBuf,
+    source: DirectorySource,
+}
+
+impl EnvironmentRegistry {
+    /// Return the [`EnvironmentRegistry`] in the uv state directory.
+    ///
+    /// The registry is non-essential state, so it's stored in `$XDG_STATE_HOME/uv/environments`
+    /// where the platform has a state directory, and in the persistent data directory otherwise.
+    pub(crate) fn from_settings() -> Self {
+        let (root, source) =
+            if let Some((state_dir, source)) = uv_dirs::user_local_state_dir_with_source() {
+                (state_dir.join("environments"), source)
+            } else {
+                let (store, source) = StateStore::user_with_source();
+                (store.bucket(StateBucket::Environments), source)
+            };
+        Self { root, source }
+    }
+
+    /// Return the directory containing the registry.
+    pub(crate) fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Return the [`DirectorySource`] from which the registry directory was resolved.
+    pub(crate) fn source(&self) -> DirectorySource {
+        self.source
+    }
+
+    /// Register an environment at the given path.
+    pub(crate) fn register(&self, environment: &Path) -> io::Result<()> {
+        let environment = std::path::absolute(environment)?;
+        let Some(contents) = environment.to_str() else {
+            debug!(
+                "Skipping registration of environment with non-UTF-8 path: {}",
+                environment.user_display()
+            );
+            return Ok(());
+        };
+        let entry = self.root.join(cache_digest(&environment));
+        if entry.is_file() {
+            return Ok(());
+        }
+        fs_err::create_dir_all(&self.root)?;
+        uv_fs::write_atomic_sync(entry, contents)
+    }
+
+    /// Return the registered environments that still exist, removing the entries for any
+    /// environments that don't.
+    pub(crate) fn environments(&self) -> io::Result<Vec<PathBuf>> {
+        let entries = match fs_err::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut environments = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Ok(contents) = fs_err::read_to_string(&path) else {
+                continue;
+            };
+            let environment = PathBuf::from(contents);
+            if environment.join("pyvenv.cfg").is_file() {
+                environments.push(environment);
+            } else {
+                debug!(
+                    "Removing registry entry for missing environment: {}",
+                    environment.user_display()
+                );
+                if let Err(err) = fs_err::remove_file(&path) {
+                    debug!("Failed to remove registry entry: {err}");
+                }
+            }
+        }
+        environments.sort();
+        Ok(environments)
+    }
+}
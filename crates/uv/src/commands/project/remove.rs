@@ -25,7 +25,7 @@ use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications};
 use crate::commands::project::add::{AddTarget, PythonTarget};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
@@ -187,19 +187,19 @@ pub(crate) async fn remove(
     }
 
     let content = toml.to_string();
+    let modified = target.is_modified(&content);
 
-    // Save the modified `pyproject.toml` or script.
-    target.write(&content)?;
-
-    // If `--frozen`, exit early. There's no reason to lock and sync, since we don't need a `uv.lock`
-    // to exist at all.
+    // If `--frozen`, save the modified `pyproject.toml` or script and exit early. There's no reason
+    // to lock and sync, since we don't need a `uv.lock` to exist at all.
     if frozen.is_some() {
+        target.write(&content)?;
         return Ok(ExitStatus::Success);
     }
 
     // If we're modifying a script, and lockfile doesn't exist, don't create it.
     if let RemoveTarget::Script(ref script) = target {
         if !LockTarget::from(script).lock_path().is_file() {
+            target.write(&content)?;
             writeln!(
                 printer.stderr(),
                 "Updated `{}`",
@@ -314,7 +314,7 @@ pub(crate) async fn remove(
     let state = UniversalState::default();
 
     // Lock and sync the environment, if necessary.
-    let lock = match Box::pin(
+    let result = Box::pin(
         project::lock::LockOperation::new(
             mode,
             &settings.resolver,
@@ -329,8 +329,21 @@ pub(crate) async fn remove(
         )
         .execute((&target).into()),
     )
-    .await
-    {
+    .await;
+
+    // Save the modified `pyproject.toml` or script only once the lockfile has been written, such
+    // that an interruption never leaves a lockfile that references requirements absent from the
+    // `pyproject.toml`.
+    if modified {
+        match &target {
+            AddTarget::Script(script, _) => script.write(&content)?,
+            AddTarget::Project(project, _) => {
+                uv_fs::write_durable_sync(project.root().join("pyproject.toml"), &content)?
+            }
+        }
+    }
+
+    let lock = match result {
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::with_system_certs(
@@ -375,6 +388,7 @@ pub(crate) async fn remove(
         None,
         InstallOptions::default(),
         Modifications::Exact,
+        &ExtraneousOptions::default(),
         None,
         (&settings).into(),
         &client_builder,
@@ -385,6 +399,7 @@ pub(crate) async fn remove(
         cache,
         &WorkspaceCache::default(),
         DryRun::Disabled,
+        false,
         printer,
         preview,
         &malware_settings,
@@ -416,6 +431,14 @@ enum RemoveTarget {
 }
 
 impl RemoveTarget {
+    /// Returns `true` if the updated content differs from the content on disk.
+    fn is_modified(&self, content: &str) -> bool {
+        match self {
+            Self::Script(script) => content != script.metadata.raw,
+            Self::Project(project) => content != project.pyproject_toml().raw,
+        }
+    }
+
     /// Write the updated content to the target.
     ///
     /// Returns `true` if the content was modified.
@@ -436,7 +459,7 @@ impl RemoveTarget {
                     Ok(false)
                 } else {
                     let pyproject_path = project.root().join("pyproject.toml");
-                    fs_err::write(pyproject_path, content)?;
+                    uv_fs::write_durable_sync(pyproject_path, content)?;
                     Ok(true)
                 }
             }
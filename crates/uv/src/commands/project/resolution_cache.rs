@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize, Serializer};
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheEntry};
+use uv_cache_info::CacheInfo;
+use uv_cache_key::cache_digest;
+use uv_configuration::{
+    BuildIsolation, BuildOptions, ExcludeDependency, IndexStrategy, NoSources, Override,
+    Replacements,
+};
+use uv_distribution_types::{
+    ConfigSettings, DependencyMetadata, ExtraBuildVariables, IndexLocations,
+    NameRequirementSpecification, PackageConfigSettings, Requirement, RequiresPython,
+};
+use uv_fs::write_atomic_sync;
+use uv_normalize::{GroupName, PackageName};
+use uv_pypi_types::{Conflicts, SupportedEnvironments};
+use uv_resolver::{
+    ExcludeNewer, ForkStrategy, Lock, PackageLocalVersionPreferences, PackagePrereleaseModes,
+    PackageResolutionModes, PrereleaseMode, RequiresPythonStrategy, ResolutionMode, YankedPolicy,
+};
+use uv_workspace::pyproject::ExtraBuildDependencies;
+use uv_workspace::{Editability, WorkspaceMember};
+
+/// A cache of universal resolutions, keyed by the inputs to the resolver.
+///
+/// When none of the inputs to a resolution (the requirements, constraints, overrides, indexes,
+/// supported Python range, `exclude-newer` cutoff, supported environments, and other resolver
+/// settings) have changed since the last resolution, and none of the local source trees in the
+/// resolution (e.g., workspace members and path dependencies) have been modified (per their
+/// `cache-keys`), the cached lockfile can be reused without reading any package metadata or
+/// querying any index.
+#[derive(Debug)]
+pub(crate) struct ResolutionCache {
+    entry: CacheEntry,
+}
+
+/// A resolution persisted to the [`ResolutionCache`].
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResolution {
+    /// The lockfile produced by the resolution, in TOML format.
+    lock: String,
+    /// The [`CacheInfo`] of each local source tree in the resolution (as determined by its
+    /// `cache-keys`), or `None` if it could not be computed.
+    source_trees: BTreeMap<PathBuf, Option<CacheInfo>>,
+}
+
+/// The inputs to a universal resolution, used to key the [`ResolutionCache`].
+///
+/// Each input is serialized explicitly, with any unordered collections sorted, such that the key
+/// is independent of the in-memory representation of the inputs.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct ResolutionInputs<'a> {
+    pub(crate) root: &'a Path,
+    #[serde(serialize_with = "serialize_members")]
+    pub(crate) members: &'a BTreeMap<PackageName, WorkspaceMember>,
+    pub(crate) required_members: &'a BTreeMap<PackageName, Editability>,
+    pub(crate) requirements: &'a [Requirement],
+    pub(crate) dependency_groups: &'a BTreeMap<GroupName, Vec<Requirement>>,
+    pub(crate) constraints: &'a [Requirement],
+    pub(crate) overrides: &'a [Override],
+    pub(crate) excludes: &'a [ExcludeDependency],
+    #[serde(serialize_with = "serialize_replacements")]
+    pub(crate) replacements: &'a Replacements,
+    pub(crate) build_constraints: &'a [Requirement],
+    #[serde(serialize_with = "serialize_external")]
+    pub(crate) external: &'a [NameRequirementSpecification],
+    #[serde(serialize_with = "serialize_conflicts")]
+    pub(crate) conflicts: &'a Conflicts,
+    pub(crate) environments: Option<&'a SupportedEnvironments>,
+    pub(crate) required_environments: Option<&'a SupportedEnvironments>,
+    pub(crate) requires_python: &'a RequiresPython,
+    pub(crate) requires_python_strategy: RequiresPythonStrategy,
+    pub(crate) index_locations: &'a IndexLocations,
+    pub(crate) index_strategy: &'a IndexStrategy,
+    pub(crate) resolution: &'a ResolutionMode,
+    pub(crate) resolution_package: &'a PackageResolutionModes,
+    pub(crate) prerelease: &'a PrereleaseMode,
+    pub(crate) prerelease_package: &'a PackagePrereleaseModes,
+    pub(crate) local_version_preference: &'a PackageLocalVersionPreferences,
+    pub(crate) yanked: &'a YankedPolicy,
+    pub(crate) fork_strategy: &'a ForkStrategy,
+    #[serde(serialize_with = "serialize_exclude_newer")]
+    pub(crate) exclude_newer: &'a ExcludeNewer,
+    pub(crate) strict_exclude_newer: &'a bool,
+    pub(crate) max_metadata_builds: &'a Option<usize>,
+    pub(crate) resolution_effort: &'a Option<usize>,
+    pub(crate) excluded_packages: &'a [PackageName],
+    pub(crate) reresolve_metadata: &'a [PackageName],
+    pub(crate) build_options: &'a BuildOptions,
+    #[serde(serialize_with = "serialize_dependency_metadata")]
+    pub(crate) dependency_metadata: &'a DependencyMetadata,
+    pub(crate) config_setting: &'a ConfigSettings,
+    pub(crate) config_settings_package: &'a PackageConfigSettings,
+    pub(crate) build_isolation: &'a BuildIsolation,
+    pub(crate) extra_build_dependencies: &'a ExtraBuildDependencies,
+    pub(crate) extra_build_variables: &'a ExtraBuildVariables,
+    pub(crate) sources: &'a NoSources,
+}
+
+/// Serialize the workspace members by name and root; their contents are tracked separately via
+/// their `cache-keys`.
+fn serialize_members<S: Serializer>(
+    members: &BTreeMap<PackageName, WorkspaceMember>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(members.iter().map(|(name, member)| (name, member.root())))
+}
+
+fn serialize_replacements<S: Serializer>(
+    replacements: &Replacements,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(replacements.iter().collect::<BTreeMap<_, _>>())
+}
+
+fn serialize_external<S: Serializer>(
+    external: &[NameRequirementSpecification],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(
+        external
+            .iter()
+            .map(|specification| (&specification.requirement, &specification.hashes)),
+    )
+}
+
+fn serialize_conflicts<S: Serializer>(
+    conflicts: &Conflicts,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(conflicts.iter().map(|set| set.iter().collect::<Vec<_>>()))
+}
+
+fn serialize_exclude_newer<S: Serializer>(
+    exclude_newer: &ExcludeNewer,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let package = exclude_newer.package.iter().collect::<BTreeMap<_, _>>();
+    (&exclude_newer.global, package).serialize(serializer)
+}
+
+fn serialize_dependency_metadata<S: Serializer>(
+    dependency_metadata: &DependencyMetadata,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut entries = dependency_metadata.values().collect::<Vec<_>>();
+    entries.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+    serializer.collect_seq(entries)
+}
+
+impl ResolutionCache {
+    /// Create a [`ResolutionCache`] entry for the given resolver inputs.
+    ///
+    /// The key is derived from the serialized inputs, along with the version of uv. Returns `None`
+    /// if the inputs could not be serialized.
+    pub(crate) fn from_inputs(cache: &Cache, inputs: &ResolutionInputs) -> Option<Self> {
+        let inputs = match serde_json::to_string(inputs) {
+            Ok(inputs) => inputs,
+            Err(err) => {
+                debug!("Failed to serialize resolution cache key: {err}");
+                return None;
+            }
+        };
+        let digest = cache_digest(&(uv_version::version(), inputs));
+        Some(Self {
+            entry: cache.entry(CacheBucket::Resolutions, "", format!("{digest}.json")),
+        })
+    }
+
+    /// Read the cached lockfile, if one exists and none of its local source trees have changed.
+    pub(crate) fn read(&self) -> Option<String> {
+        let contents = fs_err::read(self.entry.path()).ok()?;
+        let cached = match serde_json::from_slice::<CachedResolution>(&contents) {
+            Ok(cached) => cached,
+            Err(err) => {
+                debug!("Ignoring malformed resolution cache entry: {err}");
+                return None;
+            }
+        };
+        for (path, cache_info) in &cached.source_trees {
+            if CacheInfo::from_directory(path).ok() != *cache_info {
+                debug!(
+                    "Ignoring cached resolution due to change in: `{}`",
+                    path.display()
+                );
+                return None;
+            }
+        }
+        Some(cached.lock)
+    }
+
+    /// Write the given [`Lock`] to the cache, along with the [`CacheInfo`] of its local source
+    /// trees.
+    pub(crate) fn write(&self, lock: &Lock, root: &Path) {
+        let lock_toml = match lock.to_toml() {
+            Ok(lock) => lock,
+            Err(err) => {
+                debug!("Failed to serialize lockfile for resolution cache: {err}");
+                return;
+            }
+        };
+        let source_trees = std::iter::once(root.to_path_buf())
+            .chain(
+                lock.packages()
+                    .iter()
+                    .filter_map(|package| package.source_tree(root)),
+            )
+            .map(|path| {
+                let cache_info = CacheInfo::from_directory(&path).ok();
+                (path, cache_info)
+            })
+            .collect();
+        let cached = CachedResolution {
+            lock: lock_toml,
+            source_trees,
+        };
+        let result = serde_json::to_vec(&cached)
+            .map_err(std::io::Error::other)
+            .and_then(|contents| {
+                fs_err::create_dir_all(self.entry.dir())?;
+                write_atomic_sync(self.entry.path(), contents)
+            });
+        if let Err(err) = result {
+            debug!("Failed to write resolution cache entry: {err}");
+        }
+    }
+}
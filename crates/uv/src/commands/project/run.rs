@@ -1,10 +1,10 @@
 use std::borrow::Cow;
 use std::env::VarError;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt::Write;
 use std::io;
 use std::io::Read;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
@@ -23,7 +23,7 @@ use uv_configuration::{
     InstallOptions, TargetTriple,
 };
 use uv_distribution::LoweredExtraBuildDependencies;
-use uv_distribution_types::Requirement;
+use uv_distribution_types::{InstallContext, Requirement};
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
@@ -62,7 +62,7 @@ struct GistFile {
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications};
 use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironment};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
@@ -89,6 +89,8 @@ pub(crate) async fn run(
     command: Option<RunCommand>,
     requirements: Vec<RequirementsSource>,
     show_resolution: bool,
+    which: bool,
+    from_path: bool,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     active: Option<bool>,
@@ -173,6 +175,12 @@ pub(crate) async fn run(
     // The lockfile used for the base environment.
     let mut base_lock: Option<(Lock, PathBuf)> = None;
 
+    // Whether the command to execute is a PEP 723 script with a lockfile.
+    let script_locked = script
+        .as_ref()
+        .and_then(Pep723Item::as_script)
+        .is_some_and(|script| LockTarget::from(script).lock_path().is_file());
+
     // Determine whether the command to execute is a PEP 723 script.
     let temp_dir;
     let script_interpreter = if let Some(script) = script {
@@ -194,12 +202,40 @@ pub(crate) async fn run(
             }
         }
 
-        // If a lockfile already exists, lock the script.
+        // If a lockfile already exists and we're not syncing, reuse the script environment as-is.
         if let Some(target) = script
             .as_script()
             .map(LockTarget::from)
-            .filter(|target| target.lock_path().is_file())
+            .filter(|_| script_locked && no_sync)
+        {
+            debug!("Skipping script environment synchronization due to `--no-sync`");
+
+            let environment = ScriptEnvironment::get_or_init(
+                (&script).into(),
+                python.as_deref().map(PythonRequest::parse),
+                &client_builder,
+                python_preference,
+                python_downloads,
+                &install_mirrors,
+                no_sync,
+                no_config,
+                active.map_or(Some(false), Some),
+                &cache,
+                DryRun::Disabled,
+                printer,
+            )
+            .await?
+            .into_environment()?;
+
+            project::sync::warn_if_stale(&environment, target).await;
+
+            Some(environment.into_interpreter())
+        } else if let Some(target) = script
+            .as_script()
+            .map(LockTarget::from)
+            .filter(|_| script_locked)
         {
+            // If a lockfile already exists, lock the script.
             debug!("Found existing lockfile for script");
 
             // Discover the interpreter for the script.
@@ -287,6 +323,7 @@ pub(crate) async fn run(
                 editable.clone(),
                 install_options,
                 modifications,
+                &ExtraneousOptions::default(),
                 python_platform.as_ref(),
                 (&settings).into(),
                 &client_builder,
@@ -301,6 +338,7 @@ pub(crate) async fn run(
                 &cache,
                 workspace_cache,
                 DryRun::Disabled,
+                frozen.is_some(),
                 printer,
                 preview,
                 &malware_settings,
@@ -437,6 +475,7 @@ pub(crate) async fn run(
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
+                    InstallContext::Project,
                     &concurrency,
                     &cache,
                     workspace_cache,
@@ -521,7 +560,7 @@ pub(crate) async fn run(
                 "`--package` is a no-op for Python scripts with inline metadata, which always run in isolation"
             );
         }
-        if no_sync {
+        if no_sync && !script_locked {
             warn_user!(
                 "`--no-sync` is a no-op for Python scripts with inline metadata, which always run in isolation"
             );
@@ -722,6 +761,8 @@ pub(crate) async fn run(
             if no_sync {
                 debug!("Skipping environment synchronization due to `--no-sync`");
 
+                project::sync::warn_if_stale(&venv, LockTarget::from(project.workspace())).await;
+
                 // If we're not syncing, we should still attempt to respect the locked preferences
                 // in any `--with` requirements.
                 if !isolated && !requirements.is_empty() {
@@ -842,6 +883,7 @@ pub(crate) async fn run(
                     editable,
                     install_options,
                     modifications,
+                    &ExtraneousOptions::default(),
                     python_platform.as_ref(),
                     (&settings).into(),
                     &client_builder,
@@ -856,6 +898,7 @@ pub(crate) async fn run(
                     &cache,
                     workspace_cache,
                     DryRun::Disabled,
+                    frozen.is_some(),
                     printer,
                     preview,
                     &malware_settings,
@@ -1007,6 +1050,7 @@ pub(crate) async fn run(
                     Box::new(SummaryInstallLogger)
                 },
                 installer_metadata,
+                InstallContext::Overlay,
                 &concurrency,
                 &cache,
                 workspace_cache,
@@ -1263,34 +1307,105 @@ pub(crate) async fn run(
         return Ok(ExitStatus::Error);
     };
 
+    // Determine the executable directories of the run environment, in order of precedence.
+    let environment_path = ephemeral_env
+        .as_ref()
+        .map(PythonEnvironment::scripts)
+        .into_iter()
+        .chain(requirements_env.as_ref().map(PythonEnvironment::scripts))
+        .chain(std::iter::once(base_interpreter.scripts()))
+        .chain(
+            // On Windows, non-virtual Python distributions put `python.exe` in the top-level
+            // directory, rather than in the `Scripts` subdirectory.
+            cfg!(windows)
+                .then(|| base_interpreter.sys_executable().parent())
+                .flatten(),
+        )
+        .dedup()
+        .map(PathBuf::from)
+        .collect::<Vec<_>>();
+    let system_path = std::env::var_os(EnvVars::PATH)
+        .as_ref()
+        .iter()
+        .flat_map(std::env::split_paths)
+        .collect::<Vec<_>>();
+
+    // Resolve external commands given by name against the run environment and the `PATH`.
+    let mut executable = None;
+    let command = if let RunCommand::External(name, args) = command {
+        let site_packages = ephemeral_env
+            .iter()
+            .chain(requirements_env.as_ref())
+            .flat_map(|env| env.interpreter().site_packages())
+            .chain(base_interpreter.site_packages())
+            .map(Cow::into_owned)
+            .collect::<Vec<_>>();
+        match ExternalExecutable::find(
+            &name,
+            &environment_path,
+            &system_path,
+            &site_packages,
+            from_path,
+        ) {
+            Some(ExternalExecutable::Environment(path)) => {
+                debug!(
+                    "Resolved `{}` to `{}` in the run environment",
+                    name.to_string_lossy(),
+                    path.user_display()
+                );
+                executable = Some(path);
+                RunCommand::External(name, args)
+            }
+            Some(ExternalExecutable::Path(path)) => {
+                debug!(
+                    "Resolved `{}` to `{}` on the `PATH`",
+                    name.to_string_lossy(),
+                    path.user_display()
+                );
+                executable = Some(path);
+                RunCommand::External(name, args)
+            }
+            Some(ExternalExecutable::Module(module)) => {
+                warn_user!(
+                    "`{}` is installed in the run environment without its `{}` script; running `{}` instead",
+                    module,
+                    name.to_string_lossy(),
+                    format!("python -m {module}").green(),
+                );
+                RunCommand::PythonModule(OsString::from(module), args)
+            }
+            None => {
+                debug!(
+                    "Unable to resolve `{}` in the run environment or on the `PATH`",
+                    name.to_string_lossy()
+                );
+                RunCommand::External(name, args)
+            }
+        }
+    } else {
+        command
+    };
+
     debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
+    let mut process = command.as_command(interpreter, executable.as_deref());
+
+    // If requested, display the command instead of running it.
+    if which {
+        let mut line = Path::new(process.as_std().get_program())
+            .simplified_display()
+            .to_string();
+        for arg in process.as_std().get_args() {
+            line.push(' ');
+            line.push_str(&arg.to_string_lossy());
+        }
+        writeln!(printer.stdout(), "{line}")?;
+        return Ok(ExitStatus::Success);
+    }
+
     process.envs(env_file_environment);
 
     // Construct the `PATH` environment variable.
-    let new_path = std::env::join_paths(
-        ephemeral_env
-            .as_ref()
-            .map(PythonEnvironment::scripts)
-            .into_iter()
-            .chain(requirements_env.as_ref().map(PythonEnvironment::scripts))
-            .chain(std::iter::once(base_interpreter.scripts()))
-            .chain(
-                // On Windows, non-virtual Python distributions put `python.exe` in the top-level
-                // directory, rather than in the `Scripts` subdirectory.
-                cfg!(windows)
-                    .then(|| base_interpreter.sys_executable().parent())
-                    .flatten(),
-            )
-            .dedup()
-            .map(PathBuf::from)
-            .chain(
-                std::env::var_os(EnvVars::PATH)
-                    .as_ref()
-                    .iter()
-                    .flat_map(std::env::split_paths),
-            ),
-    )?;
+    let new_path = std::env::join_paths(environment_path.into_iter().chain(system_path))?;
     process.env(EnvVars::PATH, new_path);
 
     // Increment recursion depth counter.
@@ -1711,7 +1826,9 @@ impl RunCommand {
     }
 
     /// Convert a [`RunCommand`] into a [`Command`].
-    fn as_command(&self, interpreter: &Interpreter) -> Command {
+    ///
+    /// For external commands, `executable` is the resolved path to the command, if known.
+    fn as_command(&self, interpreter: &Interpreter, executable: Option<&Path>) -> Command {
         match self {
             Self::Python(args) => {
                 let mut process = Command::new(interpreter.sys_executable());
@@ -1824,11 +1941,18 @@ impl RunCommand {
 
                 process
             }
-            Self::External(executable, args) => {
-                let mut process = if cfg!(windows) {
-                    WindowsRunnable::from_script_path(interpreter.scripts(), executable).into()
-                } else {
-                    Command::new(executable)
+            Self::External(name, args) => {
+                let mut process = match executable {
+                    Some(executable) if cfg!(windows) => WindowsRunnable::from_script_path(
+                        executable.parent().unwrap_or(executable),
+                        executable.file_name().unwrap_or(name),
+                    )
+                    .into(),
+                    Some(executable) => Command::new(executable),
+                    None if cfg!(windows) => {
+                        WindowsRunnable::from_script_path(interpreter.scripts(), name).into()
+                    }
+                    None => Command::new(name),
                 };
                 process.args(args);
                 process
@@ -1919,6 +2043,94 @@ impl std::fmt::Display for RunCommand {
     }
 }
 
+/// Console scripts that can be invoked as `python -m <module>`, if the package is installed
+/// without its scripts.
+const MODULE_FALLBACKS: &[(&str, &str)] = &[
+    ("black", "black"),
+    ("coverage", "coverage"),
+    ("flake8", "flake8"),
+    ("ipython", "IPython"),
+    ("isort", "isort"),
+    ("jupyter", "jupyter"),
+    ("mypy", "mypy"),
+    ("pip", "pip"),
+    ("pylint", "pylint"),
+    ("pytest", "pytest"),
+    ("sphinx-build", "sphinx"),
+    ("tox", "tox"),
+    ("twine", "twine"),
+    ("uvicorn", "uvicorn"),
+];
+
+/// The resolved target of an external command given by name, e.g., `uv run pytest`.
+#[derive(Debug)]
+enum ExternalExecutable {
+    /// An executable in the run environment.
+    Environment(PathBuf),
+    /// An executable on the `PATH`.
+    Path(PathBuf),
+    /// A module in the run environment that provides the command, for use with `python -m`.
+    Module(&'static str),
+}
+
+impl ExternalExecutable {
+    /// Find the target of an external command.
+    ///
+    /// By default, the run environment takes precedence over the `PATH`: an executable in the run
+    /// environment is preferred, followed by a module in the run environment that's known to
+    /// provide the command, and finally an executable on the `PATH`. If `from_path` is set, the
+    /// `PATH` is searched first.
+    ///
+    /// Returns `None` if the command is a path (e.g., `./pytest`), rather than a name, or if it
+    /// can't be found.
+    fn find(
+        name: &OsStr,
+        environment_path: &[PathBuf],
+        system_path: &[PathBuf],
+        site_packages: &[PathBuf],
+        from_path: bool,
+    ) -> Option<Self> {
+        let mut components = Path::new(name).components();
+        if !matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        ) {
+            return None;
+        }
+
+        let cwd = std::env::current_dir().ok()?;
+        let find_in = |dirs: &[PathBuf]| {
+            let paths = std::env::join_paths(dirs).ok()?;
+            which::which_in(name, Some(paths), &cwd).ok()
+        };
+        let find_module = || {
+            let name = name.to_str()?;
+            let (_, module) = MODULE_FALLBACKS
+                .iter()
+                .find(|(command, _)| *command == name)?;
+            site_packages
+                .iter()
+                .any(|site_packages| {
+                    site_packages.join(module).join("__main__.py").is_file()
+                        || site_packages.join(format!("{module}.py")).is_file()
+                })
+                .then_some(Self::Module(module))
+        };
+
+        if from_path {
+            find_in(system_path)
+                .map(Self::Path)
+                .or_else(|| find_in(environment_path).map(Self::Environment))
+                .or_else(find_module)
+        } else {
+            find_in(environment_path)
+                .map(Self::Environment)
+                .or_else(find_module)
+                .or_else(|| find_in(system_path).map(Self::Path))
+        }
+    }
+}
+
 /// Resolve a GitHub Gist URL to its raw file URL using the GitHub API.
 async fn resolve_gist_url(
     url: &DisplaySafeUrl,
@@ -7,20 +7,24 @@ use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
 use serde::Serialize;
-use tracing::{trace, warn};
+use tracing::{debug, trace, warn};
 use uv_audit::Dependency;
 use uv_audit::osv::{self, Filter};
 use uv_cache::Cache;
+use uv_cache_key::hash_digest;
 use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, CachedClient, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
     ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    TargetTriple, Upgrade,
+    Reinstall, TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::LoweredExtraBuildDependencies;
-use uv_distribution_types::{Dist, Index, Name, Requirement, Resolution, ResolvedDist, SourceDist};
+use uv_distribution_types::{
+    BuildDependencyLock, Dist, Index, InstallContext, Name, Requirement, Resolution, ResolvedDist,
+    SourceDist,
+};
 use uv_fs::{PortablePathBuf, Simplified};
 use uv_installer::{InstallationStrategy, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
@@ -29,7 +33,11 @@ use uv_preview::{Preview, PreviewFeature};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitDirectoryUrl, ParsedGitPathUrl, ParsedUrl};
 use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_redacted::DisplaySafeUrl;
-use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
+use uv_resolver::{
+    FlatIndex, ForkStrategy, Installable, Lock, MissingMetadataPolicy,
+    PackageLocalVersionPreferences, PackagePrereleaseModes, PackageResolutionModes, PrereleaseMode,
+    ResolutionMode,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::{MalwareCheckSettings, PythonInstallMirrors};
 use uv_types::{BuildIsolation, HashStrategy, SourceTreeEditablePolicy};
@@ -39,7 +47,7 @@ use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace,
 
 use crate::commands::editable::apply_editable_mode;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
-use crate::commands::pip::operations::{ChangedDist, Changelog, Modifications};
+use crate::commands::pip::operations::{ChangedDist, Changelog, ExtraneousOptions, Modifications};
 use crate::commands::pip::resolution_markers;
 use crate::commands::pip::{operations, resolution_tags};
 use crate::commands::project::install_target::InstallTarget;
@@ -58,11 +66,13 @@ use crate::settings::{
 };
 
 /// Sync the project environment.
+#[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn sync(
     project_dir: &Path,
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
+    verify: bool,
     active: Option<bool>,
     all_packages: bool,
     package: Vec<PackageName>,
@@ -71,6 +81,7 @@ pub(crate) async fn sync(
     editable: Option<EditableMode>,
     install_options: InstallOptions,
     modifications: Modifications,
+    extraneous_options: ExtraneousOptions,
     python: Option<String>,
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
@@ -202,6 +213,31 @@ pub(crate) async fn sync(
         })
         .ok();
 
+    // If requested, verify the files of each installed package, and reinstall any packages with
+    // missing or modified files.
+    let settings = if verify {
+        let site_packages = SitePackages::from_environment(&environment)?;
+        let modified = site_packages.verify()?;
+        if modified.is_empty() {
+            debug!("Verified the contents of all installed packages");
+            settings
+        } else {
+            for name in &modified {
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!("Found missing or modified files in `{name}`").dimmed()
+                )?;
+            }
+            ResolverInstallerSettings {
+                reinstall: Reinstall::Packages(modified, Vec::new()).combine(settings.reinstall),
+                ..settings
+            }
+        }
+    } else {
+        settings
+    };
+
     let sync_report = SyncReport {
         dry_run: dry_run.enabled(),
         environment: EnvironmentReport::from(&environment),
@@ -280,6 +316,7 @@ pub(crate) async fn sync(
                 Box::new(DefaultResolveLogger),
                 Box::new(DefaultInstallLogger),
                 installer_metadata,
+                InstallContext::Project,
                 &concurrency,
                 cache,
                 workspace_cache,
@@ -428,6 +465,7 @@ pub(crate) async fn sync(
         editable,
         install_options,
         modifications,
+        &extraneous_options,
         python_platform.as_ref(),
         (&settings).into(),
         &client_builder,
@@ -438,6 +476,7 @@ pub(crate) async fn sync(
         cache,
         workspace_cache,
         dry_run,
+        frozen.is_some(),
         printer,
         preview,
         &malware_settings,
@@ -643,6 +682,7 @@ pub(crate) async fn do_sync(
     editable: Option<EditableMode>,
     install_options: InstallOptions,
     modifications: Modifications,
+    extraneous_options: &ExtraneousOptions,
     python_platform: Option<&TargetTriple>,
     settings: InstallerSettingsRef<'_>,
     client_builder: &BaseClientBuilder<'_>,
@@ -653,6 +693,7 @@ pub(crate) async fn do_sync(
     cache: &Cache,
     workspace_cache: &WorkspaceCache,
     dry_run: DryRun,
+    frozen: bool,
     printer: Printer,
     preview: Preview,
     malware_settings: &MalwareCheckSettings,
@@ -669,11 +710,15 @@ pub(crate) async fn do_sync(
         extra_build_dependencies,
         extra_build_variables,
         exclude_newer,
+        strict_exclude_newer,
+        excluded_packages,
         link_mode,
         compile_bytecode,
         reinstall,
         build_options,
+        build_runtime,
         sources,
+        yanked,
     } = settings;
 
     // Lower the extra build dependencies with source resolution.
@@ -694,11 +739,17 @@ pub(crate) async fn do_sync(
             // Try to get extra build dependencies from the script metadata
             let resolver_settings = ResolverSettings {
                 build_options: build_options.clone(),
+                build_runtime: build_runtime.clone(),
                 config_setting: config_setting.clone(),
                 config_settings_package: config_settings_package.clone(),
                 dependency_metadata: dependency_metadata.clone(),
                 exclude_newer: exclude_newer.clone(),
+                strict_exclude_newer,
                 fork_strategy: ForkStrategy::default(),
+                max_metadata_builds: None,
+                resolution_effort: None,
+                excluded_packages: excluded_packages.to_vec(),
+                reresolve_metadata: Vec::new(),
                 index_locations: index_locations.clone(),
                 index_strategy,
                 keyring_provider,
@@ -708,6 +759,10 @@ pub(crate) async fn do_sync(
                 extra_build_variables: extra_build_variables.clone(),
                 prerelease: PrereleaseMode::default(),
                 resolution: ResolutionMode::default(),
+                resolution_package: PackageResolutionModes::default(),
+                prerelease_package: PackagePrereleaseModes::default(),
+                local_version_preference: PackageLocalVersionPreferences::default(),
+                yanked,
                 sources: sources.clone(),
                 torch_backend: None,
                 cuda_driver_version: None,
@@ -748,7 +803,7 @@ pub(crate) async fn do_sync(
     target.validate_groups(groups)?;
 
     // Determine the markers to use for resolution.
-    let marker_env = resolution_markers(None, python_platform, venv.interpreter());
+    let marker_env = resolution_markers(None, python_platform, None, venv.interpreter());
 
     // Validate that the platform is supported by the lockfile.
     let environments = target.lock().supported_environments();
@@ -775,7 +830,7 @@ pub(crate) async fn do_sync(
     }
 
     // Determine the tags to use for the resolution.
-    let tags = resolution_tags(None, python_platform, venv.interpreter())?;
+    let tags = resolution_tags(None, python_platform, None, None, venv.interpreter())?;
 
     // Read the lockfile.
     let resolution = target.to_resolution(
@@ -787,6 +842,9 @@ pub(crate) async fn do_sync(
         &install_options,
     )?;
 
+    // Reject any excluded packages, e.g., in a lockfile that predates the exclusion.
+    operations::check_excluded_resolution(&resolution, excluded_packages)?;
+
     // Always skip virtual projects, which shouldn't be built or installed.
     let resolution = apply_no_virtual_project(resolution);
 
@@ -819,6 +877,11 @@ pub(crate) async fn do_sync(
     // Read the build constraints from the lockfile.
     let build_constraints = target.build_constraints();
 
+    // Read the locked build dependencies from the lockfile, if any.
+    let build_dependency_lock = Some(target.lock().build_dependencies(target.install_path()))
+        .filter(|build_dependencies| !build_dependencies.is_empty())
+        .map(BuildDependencyLock::new);
+
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
     let build_hasher = HashStrategy::default();
@@ -832,7 +895,13 @@ pub(crate) async fn do_sync(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            build_options,
+            index_locations,
+        )
     };
 
     // Create a build dispatch.
@@ -853,6 +922,7 @@ pub(crate) async fn do_sync(
         extra_build_variables,
         link_mode,
         build_options,
+        build_runtime,
         &build_hasher,
         exclude_newer.clone(),
         sources.clone(),
@@ -860,7 +930,9 @@ pub(crate) async fn do_sync(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_excluded_packages(excluded_packages)
+    .with_build_dependency_lock(build_dependency_lock);
 
     // Run a malware check against OSV before installing.
     if malware_settings.enabled {
@@ -881,6 +953,20 @@ pub(crate) async fn do_sync(
         .await?;
     }
 
+    // Check whether any of the locked distributions have been yanked since the lockfile was
+    // created. With `--frozen`, the lockfile is used as-is, without querying the index.
+    if !frozen {
+        operations::check_yanked(
+            &resolution,
+            &client,
+            index_locations,
+            yanked,
+            MissingMetadataPolicy::from_strict(strict_exclude_newer),
+            concurrency,
+        )
+        .await?;
+    }
+
     let site_packages = SitePackages::from_environment(venv)?;
 
     // Sync the environment.
@@ -889,6 +975,7 @@ pub(crate) async fn do_sync(
         site_packages,
         InstallationStrategy::Strict,
         modifications,
+        extraneous_options,
         reinstall,
         build_options,
         link_mode,
@@ -903,15 +990,105 @@ pub(crate) async fn do_sync(
         venv,
         logger,
         installer_metadata,
+        InstallContext::Project,
         dry_run,
         printer,
         preview,
     )
     .await?;
 
+    // Record the lockfile and interpreter that the environment was synced with, such that
+    // `--no-sync` can detect a stale environment without parsing the lockfile.
+    if !dry_run.enabled() {
+        if let Err(err) = write_lock_digest(venv, target.lock_target()).await {
+            debug!("Failed to record the lockfile digest for the environment: {err}");
+        }
+        if let Err(err) = fs_err::tokio::write(
+            venv.root().join(INTERPRETER_FILE),
+            interpreter_identity(venv),
+        )
+        .await
+        {
+            debug!("Failed to record the interpreter for the environment: {err}");
+        }
+    }
+
     Ok(changelog)
 }
 
+/// The file in an environment that records a digest of the lockfile it was last synced from.
+const LOCK_DIGEST_FILE: &str = ".uv-lock-digest";
+
+/// The file in an environment that records the interpreter it was last synced with.
+const INTERPRETER_FILE: &str = ".uv-interpreter";
+
+/// Returns an identifier for the environment's interpreter, which changes if the base interpreter
+/// is upgraded or replaced.
+fn interpreter_identity(venv: &PythonEnvironment) -> String {
+    let interpreter = venv.interpreter();
+    format!(
+        "{} {}",
+        interpreter.python_full_version(),
+        interpreter.sys_base_prefix().display()
+    )
+}
+
+/// Record a digest of the lockfile in the environment.
+async fn write_lock_digest(venv: &PythonEnvironment, lock: LockTarget<'_>) -> std::io::Result<()> {
+    let path = venv.root().join(LOCK_DIGEST_FILE);
+    match lock.read_bytes().await? {
+        Some(encoded) => fs_err::tokio::write(path, hash_digest(&encoded)).await,
+        None => match fs_err::tokio::remove_file(path).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        },
+    }
+}
+
+/// Warn if the environment was last synced from a different lockfile or interpreter, without
+/// parsing the lockfile.
+///
+/// Environments without a recorded digest (e.g., those that were never synced) are assumed to be
+/// up-to-date.
+pub(crate) async fn warn_if_stale(venv: &PythonEnvironment, lock: LockTarget<'_>) {
+    let command = match lock {
+        LockTarget::Workspace(_) => "uv sync".to_string(),
+        LockTarget::Script(script) => format!("uv sync --script {}", script.path.user_display()),
+    };
+
+    if let Ok(recorded) = fs_err::tokio::read_to_string(venv.root().join(INTERPRETER_FILE)).await {
+        if recorded.trim() != interpreter_identity(venv) {
+            warn_user!(
+                "The environment may be out-of-date, as its interpreter changed since it was last synced; run `{}` to update it",
+                command.green(),
+            );
+            return;
+        }
+    }
+
+    let Ok(recorded) = fs_err::tokio::read_to_string(venv.root().join(LOCK_DIGEST_FILE)).await
+    else {
+        return;
+    };
+    let digest = match lock.read_bytes().await {
+        Ok(Some(encoded)) => hash_digest(&encoded),
+        Ok(None) => return,
+        Err(err) => {
+            debug!("Failed to read the lockfile: {err}");
+            return;
+        }
+    };
+    if recorded.trim() == digest {
+        debug!("The environment was synced from the current lockfile");
+    } else {
+        warn_user!(
+            "The environment may be out-of-date, as `{}` changed since it was last synced; run `{}` to update it",
+            lock.lock_filename().user_display(),
+            command.green(),
+        );
+    }
+}
+
 /// Run a malware check against OSV before installing dependencies.
 ///
 /// This queries the OSV batch endpoint with [`Filter::Malware`] to detect only `MAL-`-prefixed
@@ -1404,6 +1581,11 @@ impl PackageChangesReport {
                 .chain(changelog.reinstalled.iter().map(|dist| {
                     PackageChangeReport::from_dist(dist, PackageChangeAction::Reinstalled)
                 }))
+                .chain(
+                    changelog.kept.iter().map(|dist| {
+                        PackageChangeReport::from_dist(dist, PackageChangeAction::Kept)
+                    }),
+                )
                 .collect();
 
         changes.sort_by(|a, b| {
@@ -1445,6 +1627,7 @@ enum PackageChangeAction {
     Uninstalled,
     Installed,
     Reinstalled,
+    Kept,
 }
 
 /// The report for a lock operation.
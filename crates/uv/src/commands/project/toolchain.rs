@@ -68,8 +68,8 @@ pub(crate) fn resolution_from_lock(
     interpreter: &Interpreter,
     build_options: &BuildOptions,
 ) -> Result<Resolution> {
-    let marker_environment = resolution_markers(None, None, interpreter);
-    let tags = resolution_tags(None, None, interpreter)?;
+    let marker_environment = resolution_markers(None, None, None, interpreter);
+    let tags = resolution_tags(None, None, None, None, interpreter)?;
     let extras = ExtrasSpecification::default().with_defaults(DefaultExtras::default());
     let groups = DependencyGroupsWithDefaults::none();
     Ok(lock.to_resolution(
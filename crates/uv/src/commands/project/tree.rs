@@ -183,6 +183,7 @@ pub(crate) async fn tree(
         resolution_markers(
             python_version.as_ref(),
             python_platform.as_ref(),
+            None,
             interpreter.as_ref().unwrap(),
         )
     });
@@ -212,8 +213,16 @@ pub(crate) async fn tree(
                 index_strategy: _,
                 keyring_provider,
                 resolution: _,
+                resolution_package: _,
                 prerelease: _,
+                prerelease_package: _,
+                local_version_preference: _,
+                yanked: _,
                 fork_strategy: _,
+                max_metadata_builds: _,
+                resolution_effort: _,
+                excluded_packages: _,
+                reresolve_metadata: _,
                 dependency_metadata: _,
                 config_setting: _,
                 config_settings_package: _,
@@ -221,9 +230,11 @@ pub(crate) async fn tree(
                 extra_build_dependencies: _,
                 extra_build_variables: _,
                 exclude_newer: _,
+                strict_exclude_newer: _,
                 link_mode: _,
                 upgrade: _,
                 build_options: _,
+                build_runtime: _,
                 sources: _,
                 torch_backend: _,
                 cuda_driver_version: _,
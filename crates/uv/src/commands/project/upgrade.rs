@@ -206,7 +206,7 @@ pub(crate) async fn upgrade(
             bail!("Dependency `{package}` was not found in `project.dependencies`");
         }
         let pyproject_path = project.project_root().join("pyproject.toml");
-        fs_err::write(pyproject_path, pyproject.to_string())?;
+        uv_fs::write_durable_sync(pyproject_path, pyproject.to_string())?;
         Some(proposed_text)
     };
 
@@ -31,7 +31,7 @@ use uv_workspace::{
 };
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications};
 use crate::commands::project::add::{AddTarget, PythonTarget};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
@@ -453,10 +453,12 @@ fn update_project(
     pyproject_path: &Path,
     workspace_cache: &WorkspaceCache,
 ) -> Result<VirtualProject> {
-    // Save to disk
+    // Save to disk. The `pyproject.toml` is written durably before the lockfile is updated, such
+    // that an interruption never leaves a lockfile that records a version the `pyproject.toml`
+    // doesn't declare; the stale lockfile is updated on the next invocation instead.
     toml.set_version(new_version)?;
     let content = toml.to_string();
-    fs_err::write(pyproject_path, &content)?;
+    uv_fs::write_durable_sync(pyproject_path, &content)?;
 
     // Update the `pyproject.toml` in-memory.
     let project = project
@@ -736,6 +738,7 @@ async fn lock_and_sync(
         None,
         install_options,
         Modifications::Sufficient,
+        &ExtraneousOptions::default(),
         None,
         settings.into(),
         &client_builder,
@@ -746,6 +749,7 @@ async fn lock_and_sync(
         cache,
         &workspace_cache,
         DryRun::Disabled,
+        false,
         printer,
         preview,
         malware_settings,
@@ -78,8 +78,8 @@ pub(crate) fn resolve_pylock_toml(
         }
     }
 
-    let tags = resolution_tags(python_version, python_platform, interpreter)?;
-    let marker_env = resolution_markers(python_version, python_platform, interpreter);
+    let tags = resolution_tags(python_version, python_platform, None, None, interpreter)?;
+    let marker_env = resolution_markers(python_version, python_platform, None, interpreter);
 
     let resolution = lock.to_resolution(
         install_path,
@@ -1,14 +1,19 @@
 use anyhow::Result;
+use serde::Serialize;
 use std::fmt::Write;
 use std::path::Path;
 
 use uv_cache::Cache;
+use uv_cli::PythonListFormat;
 use uv_client::BaseClientBuilder;
 use uv_configuration::DependencyGroupsWithDefaults;
 use uv_errors::ErrorWithHints;
 use uv_fs::Simplified;
+use uv_pep440::Version;
+use uv_platform::Libc;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -21,6 +26,19 @@ use crate::commands::{
 };
 use crate::printer::Printer;
 
+/// The JSON output of `uv python find`.
+#[derive(Serialize)]
+struct PrintData {
+    key: String,
+    version: Version,
+    path: String,
+    implementation: String,
+    os: String,
+    arch: String,
+    libc: String,
+    system_libc: Option<String>,
+}
+
 /// Find a Python interpreter.
 #[expect(clippy::fn_params_excessive_bools)]
 pub(crate) async fn find(
@@ -28,6 +46,7 @@ pub(crate) async fn find(
     request: Option<String>,
     show_version: bool,
     resolve_links: bool,
+    output_format: PythonListFormat,
     no_project: bool,
     no_config: bool,
     system: bool,
@@ -117,20 +136,13 @@ pub(crate) async fn find(
         }
     }
 
-    if show_version {
-        writeln!(
-            printer.stdout(),
-            "{}",
-            python.interpreter().python_version()
-        )?;
-    } else {
-        let path = if resolve_links {
-            dunce::canonicalize(python.interpreter().sys_executable())?
-        } else {
-            std::path::absolute(python.interpreter().sys_executable())?
-        };
-        writeln!(printer.stdout(), "{}", path.simplified_display())?;
-    }
+    print_interpreter(
+        python.interpreter(),
+        show_version,
+        resolve_links,
+        output_format,
+        printer,
+    )?;
 
     Ok(ExitStatus::Success)
 }
@@ -139,6 +151,7 @@ pub(crate) async fn find_script(
     script: Pep723ItemRef<'_>,
     show_version: bool,
     resolve_links: bool,
+    output_format: PythonListFormat,
     client_builder: &BaseClientBuilder<'_>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -173,16 +186,68 @@ pub(crate) async fn find_script(
         Ok(ScriptInterpreter::Environment(environment)) => environment.into_interpreter(),
     };
 
-    if show_version {
-        writeln!(printer.stdout(), "{}", interpreter.python_version())?;
+    print_interpreter(
+        &interpreter,
+        show_version,
+        resolve_links,
+        output_format,
+        printer,
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Print the discovered interpreter in the requested format.
+fn print_interpreter(
+    interpreter: &Interpreter,
+    show_version: bool,
+    resolve_links: bool,
+    output_format: PythonListFormat,
+    printer: Printer,
+) -> Result<()> {
+    let key = interpreter.key();
+
+    // Interpreters built for a different libc than the system (e.g., a glibc interpreter copied
+    // onto a musl system) produce incorrect platform tags.
+    interpreter.warn_on_libc_mismatch();
+
+    // Report the libc of the current system alongside that of the interpreter.
+    let system_libc = if matches!(key.libc(), Libc::Some(_)) {
+        uv_platform::Platform::from_env()
+            .ok()
+            .map(|platform| platform.libc)
+    } else {
+        None
+    };
+
+    let path = if resolve_links {
+        dunce::canonicalize(interpreter.sys_executable())?
     } else {
-        let path = if resolve_links {
-            dunce::canonicalize(interpreter.sys_executable())?
-        } else {
-            std::path::absolute(interpreter.sys_executable())?
-        };
-        writeln!(printer.stdout(), "{}", path.simplified_display())?;
+        std::path::absolute(interpreter.sys_executable())?
+    };
+
+    match output_format {
+        PythonListFormat::Json => {
+            let data = PrintData {
+                key: key.to_string(),
+                version: interpreter.python_version().clone(),
+                path: path.simplified_display().to_string(),
+                implementation: key.implementation().to_string(),
+                os: key.os().to_string(),
+                arch: key.arch().to_string(),
+                libc: key.libc().to_string(),
+                system_libc: system_libc.map(|libc| libc.to_string()),
+            };
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        PythonListFormat::Text => {
+            if show_version {
+                writeln!(printer.stdout(), "{}", interpreter.python_version())?;
+            } else {
+                writeln!(printer.stdout(), "{}", path.simplified_display())?;
+            }
+        }
     }
 
-    Ok(ExitStatus::Success)
+    Ok(())
 }
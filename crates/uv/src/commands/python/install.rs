@@ -328,10 +328,12 @@ async fn perform_install(
     let installations_dir = installations.root();
     let scratch_dir = installations.scratch();
     let _lock = installations.lock().await?;
-    let existing_installations: Vec<_> = installations
+    // Installations that were left incomplete (e.g., by an interrupted process) are not considered
+    // installed, such that requests for them are installed again.
+    let (existing_installations, incomplete_installations): (Vec<_>, Vec<_>) = installations
         .find_all()?
         .inspect(|installation| trace!("Found existing installation {}", installation.key()))
-        .collect();
+        .partition(ManagedPythonInstallation::is_complete);
 
     // Resolve the requests
     let mut is_default_install = false;
@@ -471,6 +473,7 @@ async fn perform_install(
         for request in &requests {
             let mut matching_installations = existing_installations
                 .iter()
+                .chain(&incomplete_installations)
                 .filter(|installation| request.matches_installation(installation))
                 .peekable();
 
@@ -553,7 +556,19 @@ async fn perform_install(
                 debug!("Found `{}` for request `{}`", installation.key(), request);
                 satisfied.push(installation);
             } else {
-                debug!("No installation found for request `{}`", request);
+                if let Some(installation) = incomplete_installations
+                    .iter()
+                    .find(|inst| request.download.key() == inst.key())
+                {
+                    writeln!(
+                        printer.stderr(),
+                        "Found an incomplete installation of `{}`; reinstalling",
+                        installation.key().green()
+                    )?;
+                    changelog.existing.insert(installation.key().clone());
+                } else {
+                    debug!("No installation found for request `{}`", request);
+                }
                 unsatisfied.push(Cow::Borrowed(request));
             }
         }
@@ -682,6 +697,7 @@ async fn perform_install(
         if let Err(e) = installation.ensure_dylib_patched() {
             e.warn_user(installation);
         }
+        installation.mark_complete()?;
 
         let upgradeable = (default || is_default_install)
             || requested_minor_versions.contains(&installation.key().version().python_version());
@@ -16,10 +16,12 @@ use uv_python::{
 };
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user_once;
-use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+use uv_workspace::{DiscoveryOptions, RequiresPythonSources, VirtualProject, WorkspaceCache};
 
 use crate::commands::{
-    ExitStatus, project::find_requires_python, reporters::PythonDownloadReporter,
+    ExitStatus,
+    project::{find_requires_python, format_optional_requires_python_sources},
+    reporters::PythonDownloadReporter,
 };
 use crate::printer::Printer;
 
@@ -32,6 +34,8 @@ pub(crate) async fn pin(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     no_project: bool,
+    strict: bool,
+    force: bool,
     global: bool,
     rm: bool,
     install_mirrors: PythonInstallMirrors,
@@ -115,13 +119,14 @@ pub(crate) async fn pin(
                 if let Some(virtual_project) = &virtual_project
                     && let Some(download_list) = &download_list
                 {
-                    warn_if_existing_pin_incompatible_with_project(
+                    check_existing_pin_compatible_with_project(
                         pin,
                         virtual_project,
                         python_preference,
                         download_list,
                         cache,
-                    );
+                        strict,
+                    )?;
                 }
             }
             return Ok(ExitStatus::Success);
@@ -167,19 +172,30 @@ pub(crate) async fn pin(
     };
 
     if let Some(virtual_project) = &virtual_project {
-        if let Some(request_version) = request.as_pep440_version() {
-            assert_pin_compatible_with_project(
+        let request_version = request.as_pep440_version();
+        if let Some(request_version) = &request_version {
+            // Refuse to pin an incompatible version unless `--force` is used
+            if let Err(err) = assert_pin_compatible_with_project(
                 &Pin {
                     request: &request,
-                    version: &request_version,
+                    version: request_version,
                     resolved: false,
                     existing: false,
                 },
                 virtual_project,
-            )?;
-        } else {
+            ) {
+                if !force {
+                    return Err(err);
+                }
+                warn_user_once!("{err}");
+            }
+        }
+        // If the request doesn't determine a version, or we're pinning the interpreter itself,
+        // check the version of the resolved Python
+        if request_version.is_none() || resolved {
             if let Some(python) = &python {
-                // Warn if the resolved Python is incompatible with the Python requirement unless --resolved is used
+                // Warn if the resolved Python is incompatible with the Python requirement, unless
+                // `--resolved` or `--strict` is used
                 if let Err(err) = assert_pin_compatible_with_project(
                     &Pin {
                         request: &request,
@@ -189,7 +205,7 @@ pub(crate) async fn pin(
                     },
                     virtual_project,
                 ) {
-                    if resolved {
+                    if (resolved || strict) && !force {
                         return Err(err);
                     }
                     warn_user_once!("{err}");
@@ -255,13 +271,16 @@ pub(crate) async fn pin(
 }
 
 /// Check if pinned request is compatible with the workspace/project's `Requires-Python`.
-fn warn_if_existing_pin_incompatible_with_project(
+///
+/// Incompatibilities are reported as warnings, or as errors if `strict` is set.
+fn check_existing_pin_compatible_with_project(
     pin: &PythonRequest,
     virtual_project: &VirtualProject,
     python_preference: PythonPreference,
     downloads_list: &ManagedPythonDownloadList,
     cache: &Cache,
-) {
+    strict: bool,
+) -> Result<()> {
     // Check if the pinned version is compatible with the project.
     if let Some(pin_version) = pin.as_pep440_version() {
         if let Err(err) = assert_pin_compatible_with_project(
@@ -273,8 +292,11 @@ fn warn_if_existing_pin_incompatible_with_project(
             },
             virtual_project,
         ) {
+            if strict {
+                return Err(err);
+            }
             warn_user_once!("{err}");
-            return;
+            return Ok(());
         }
     }
 
@@ -303,6 +325,9 @@ fn warn_if_existing_pin_incompatible_with_project(
                 },
                 virtual_project,
             ) {
+                if strict {
+                    return Err(err);
+                }
                 warn_user_once!("{err}");
             }
         }
@@ -313,6 +338,8 @@ fn warn_if_existing_pin_incompatible_with_project(
             );
         }
     }
+
+    Ok(())
 }
 
 /// Utility struct for representing pins in error messages.
@@ -328,26 +355,24 @@ fn assert_pin_compatible_with_project(pin: &Pin, virtual_project: &VirtualProjec
     // Don't factor in requires-python settings on dependency-groups
     let groups = DependencyGroupsWithDefaults::none();
 
-    let (requires_python, project_type) = match virtual_project {
+    let (workspace, project_type) = match virtual_project {
         VirtualProject::Project(project_workspace) => {
             debug!(
                 "Discovered project `{}` at: {}",
                 project_workspace.project_name(),
                 project_workspace.workspace().install_path().display()
             );
-
-            let requires_python = find_requires_python(project_workspace.workspace(), &groups)?;
-            (requires_python, "project")
+            (project_workspace.workspace(), "project")
         }
         VirtualProject::NonProject(workspace) => {
             debug!(
                 "Discovered virtual workspace at: {}",
                 workspace.install_path().display()
             );
-            let requires_python = find_requires_python(workspace, &groups)?;
-            (requires_python, "workspace")
+            (&**workspace, "workspace")
         }
     };
+    let requires_python = find_requires_python(workspace, &groups)?;
 
     let Some(requires_python) = requires_python else {
         return Ok(());
@@ -364,8 +389,25 @@ fn assert_pin_compatible_with_project(pin: &Pin, virtual_project: &VirtualProjec
         String::new()
     };
 
+    // In a workspace with multiple members, identify the members that exclude the version
+    let sources = if workspace.packages().len() > 1 {
+        let conflicting_requires = workspace
+            .requires_python(&groups)?
+            .into_iter()
+            .filter(|(.., requires)| !requires.contains(pin.version))
+            .collect::<RequiresPythonSources>();
+        format_optional_requires_python_sources(&conflicting_requires, true)
+    } else {
+        String::new()
+    };
+    let sources = if sources.is_empty() {
+        ".".to_string()
+    } else {
+        sources
+    };
+
     Err(anyhow::anyhow!(
-        "The {given} Python version `{}`{resolved} is incompatible with the {} `requires-python` value of `{}`.",
+        "The {given} Python version `{}`{resolved} is incompatible with the {} `requires-python` value of `{}`{sources}",
         pin.request.to_canonical_string(),
         project_type,
         requires_python
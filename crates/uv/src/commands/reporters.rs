@@ -212,6 +212,60 @@ impl ProgressReporter {
         progress.finish_with_message(message);
     }
 
+    /// Report that we're waiting on another process to finish the same download or build.
+    fn on_wait_start(&self, activity: &str, name: &str) -> usize {
+        let ProgressMode::Multi {
+            multi_progress,
+            state,
+        } = &self.mode
+        else {
+            return 0;
+        };
+
+        let mut state = state.lock().unwrap();
+        let id = state.id();
+
+        let progress = multi_progress.insert_before(
+            &self.root,
+            ProgressBar::with_draw_target(None, self.printer.target()),
+        );
+
+        progress.set_style(ProgressStyle::with_template("{wide_msg}").unwrap());
+        let message = format!(
+            "    {} for another process {activity} {name}...",
+            "Waiting".bold().cyan(),
+        );
+        if multi_progress.is_hidden() && !*HAS_UV_TEST_NO_CLI_PROGRESS {
+            let _ = writeln!(self.printer.stderr(), "{message}");
+        }
+        progress.set_message(message);
+
+        state.headers += 1;
+        state.bars.insert(id, ProgressBarKind::Spinner { progress });
+        id
+    }
+
+    fn on_wait_complete(&self, id: usize) {
+        let ProgressMode::Multi { state, .. } = &self.mode else {
+            return;
+        };
+
+        let progress = {
+            let mut state = state.lock().unwrap();
+            state.headers -= 1;
+            state.bars.remove(&id).unwrap()
+        };
+        progress.finish_and_clear();
+    }
+
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize {
+        self.on_wait_start("building", &source.to_color_string())
+    }
+
+    fn on_download_wait_start(&self, name: &PackageName) -> usize {
+        self.on_wait_start("downloading", name.as_str())
+    }
+
     fn on_request_start(&self, direction: Direction, name: String, size: Option<u64>) -> usize {
         let ProgressMode::Multi {
             multi_progress,
@@ -500,6 +554,22 @@ impl uv_installer::PrepareReporter for PrepareReporter {
         self.reporter.on_download_complete(id);
     }
 
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_wait_start(source)
+    }
+
+    fn on_build_wait_complete(&self, _source: &BuildableSource, id: usize) {
+        self.reporter.on_wait_complete(id);
+    }
+
+    fn on_download_wait_start(&self, name: &PackageName) -> usize {
+        self.reporter.on_download_wait_start(name)
+    }
+
+    fn on_download_wait_complete(&self, _name: &PackageName, id: usize) {
+        self.reporter.on_wait_complete(id);
+    }
+
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
         self.reporter.on_checkout_start(url, rev)
     }
@@ -583,6 +653,22 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
     fn on_download_complete(&self, _name: &PackageName, id: usize) {
         self.reporter.on_download_complete(id);
     }
+
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_wait_start(source)
+    }
+
+    fn on_build_wait_complete(&self, _source: &BuildableSource, id: usize) {
+        self.reporter.on_wait_complete(id);
+    }
+
+    fn on_download_wait_start(&self, name: &PackageName) -> usize {
+        self.reporter.on_download_wait_start(name)
+    }
+
+    fn on_download_wait_complete(&self, _name: &PackageName, id: usize) {
+        self.reporter.on_wait_complete(id);
+    }
 }
 
 impl uv_distribution::Reporter for ResolverReporter {
@@ -606,6 +692,22 @@ impl uv_distribution::Reporter for ResolverReporter {
         self.reporter.on_download_complete(id);
     }
 
+    fn on_build_wait_start(&self, source: &BuildableSource) -> usize {
+        self.reporter.on_build_wait_start(source)
+    }
+
+    fn on_build_wait_complete(&self, _source: &BuildableSource, id: usize) {
+        self.reporter.on_wait_complete(id);
+    }
+
+    fn on_download_wait_start(&self, name: &PackageName) -> usize {
+        self.reporter.on_download_wait_start(name)
+    }
+
+    fn on_download_wait_complete(&self, _name: &PackageName, id: usize) {
+        self.reporter.on_wait_complete(id);
+    }
+
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
         self.reporter.on_checkout_start(url, rev)
     }
@@ -0,0 +1,207 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_auth::TextCredentialStore;
+use uv_cache::Cache;
+use uv_cli::SelfDirsFormat;
+use uv_dirs::DirectorySource;
+use uv_fs::Simplified;
+use uv_python::managed::{ManagedPythonInstallations, python_executable_dir_with_source};
+use uv_state::{StateBucket, StateStore};
+use uv_tool::{InstalledTools, tool_executable_dir_with_source};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::registry::EnvironmentRegistry;
+use crate::printer::Printer;
+
+/// Show the directories used by uv (`uv self dirs`).
+pub(crate) fn self_dirs(
+    cache: &Cache,
+    cache_source: DirectorySource,
+    output_format: SelfDirsFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let directories = [
+        Directory::new(
+            "cache",
+            "Cache",
+            (cache.root().to_path_buf(), cache_source),
+            || None,
+        ),
+        {
+            let (installations, source) =
+                ManagedPythonInstallations::from_settings_with_source(None)
+                    .context("Failed to initialize toolchain settings")?;
+            Directory::new(
+                "python",
+                "Managed Python installations",
+                (installations.root().to_path_buf(), source),
+                || Some(default_bucket(StateBucket::ManagedPython)),
+            )
+        },
+        Directory::new(
+            "python-bin",
+            "Managed Python executables",
+            python_executable_dir_with_source()?,
+            // The executable directory is typically shared with other applications, so its
+            // contents aren't a reliable indicator of existing uv data.
+            || None,
+        ),
+        {
+            let (installed_tools, source) = InstalledTools::from_settings_with_source()
+                .context("Failed to initialize tools settings")?;
+            Directory::new(
+                "tools",
+                "Installed tools",
+                (installed_tools.root().to_path_buf(), source),
+                || Some(default_bucket(StateBucket::Tools)),
+            )
+        },
+        Directory::new(
+            "tool-bin",
+            "Tool executables",
+            tool_executable_dir_with_source()?,
+            || None,
+        ),
+        Directory::new(
+            "credentials",
+            "Credentials",
+            TextCredentialStore::directory_path_with_source()?,
+            || Some(default_bucket(StateBucket::Credentials)),
+        ),
+        {
+            let registry = EnvironmentRegistry::from_settings();
+            Directory::new(
+                "environments",
+                "Project environment registry",
+                (registry.root().to_path_buf(), registry.source()),
+                || None,
+            )
+        },
+        Directory::new(
+            "config",
+            "User configuration",
+            uv_dirs::user_uv_config_dir_with_source()
+                .context("Failed to determine the configuration directory")?,
+            || None,
+        ),
+    ];
+
+    match output_format {
+        SelfDirsFormat::Text => {
+            for directory in &directories {
+                writeln!(
+                    printer.stdout(),
+                    "{}: {} ({})",
+                    directory.purpose,
+                    directory.path.simplified_display().cyan(),
+                    directory.source,
+                )?;
+            }
+        }
+        SelfDirsFormat::Json => {
+            let directories = directories
+                .iter()
+                .map(DirectoryJson::from)
+                .collect::<Vec<_>>();
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&directories)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A directory used by uv.
+#[derive(Debug)]
+struct Directory {
+    /// A short, stable identifier for the directory.
+    name: &'static str,
+    /// A human-readable description of what the directory is used for.
+    purpose: &'static str,
+    /// The resolved path to the directory.
+    path: PathBuf,
+    /// How the path was determined.
+    source: DirectorySource,
+    /// The default location of the directory, if the directory was overridden but is empty while
+    /// the default location contains existing data.
+    existing: Option<PathBuf>,
+}
+
+impl Directory {
+    /// Create a [`Directory`], checking whether an overridden directory hides existing data at
+    /// its default location.
+    fn new(
+        name: &'static str,
+        purpose: &'static str,
+        (path, source): (PathBuf, DirectorySource),
+        default: impl FnOnce() -> Option<PathBuf>,
+    ) -> Self {
+        let existing = uv_dirs::warn_if_hidden_default(purpose, &path, source, default);
+        Self {
+            name,
+            purpose,
+            path,
+            source,
+            existing,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DirectoryJson<'a> {
+    name: &'a str,
+    purpose: &'a str,
+    path: &'a Path,
+    source: SourceJson,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    existing: Option<&'a Path>,
+}
+
+impl<'a> From<&'a Directory> for DirectoryJson<'a> {
+    fn from(directory: &'a Directory) -> Self {
+        Self {
+            name: directory.name,
+            purpose: directory.purpose,
+            path: &directory.path,
+            source: SourceJson::from(directory.source),
+            existing: directory.existing.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", content = "name", rename_all = "kebab-case")]
+enum SourceJson {
+    Argument(&'static str),
+    EnvironmentVariable(&'static str),
+    Setting(&'static str),
+    Legacy,
+    Default,
+    Fallback,
+}
+
+impl From<DirectorySource> for SourceJson {
+    fn from(source: DirectorySource) -> Self {
+        match source {
+            DirectorySource::Argument(name) => Self::Argument(name),
+            DirectorySource::EnvironmentVariable(name) => Self::EnvironmentVariable(name),
+            DirectorySource::Setting(name) => Self::Setting(name),
+            DirectorySource::Legacy => Self::Legacy,
+            DirectorySource::Default => Self::Default,
+            DirectorySource::Fallback => Self::Fallback,
+        }
+    }
+}
+
+/// Return the default location of the given bucket in the state directory.
+fn default_bucket(bucket: StateBucket) -> PathBuf {
+    StateStore::user_with_source().0.bucket(bucket)
+}
@@ -15,7 +15,7 @@ use uv_cache::{Cache, Refresh};
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroupsWithDefaults, ExcludeDependency,
-    ExtrasSpecification, GitLfsSetting, InstallOptions, Override, TargetTriple,
+    ExtrasSpecification, GitLfsSetting, InstallOptions, Override, Replacements, TargetTriple,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{
@@ -377,7 +377,7 @@ impl ToolLock {
     pub(crate) fn write(directory: &Path, lock: Option<&Self>) -> anyhow::Result<()> {
         let path = directory.join("uv.lock");
         if let Some(lock) = lock {
-            uv_fs::write_atomic_sync(&path, lock.lock.to_toml()?)?;
+            uv_fs::write_durable_sync(&path, lock.lock.to_toml()?)?;
         } else {
             match fs_err::remove_file(path) {
                 Ok(()) => (),
@@ -413,8 +413,16 @@ impl ToolLock {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            excluded_packages,
+            reresolve_metadata,
             dependency_metadata,
             config_setting,
             config_settings_package,
@@ -422,9 +430,11 @@ impl ToolLock {
             extra_build_dependencies,
             extra_build_variables,
             exclude_newer,
+            strict_exclude_newer,
             link_mode,
             upgrade,
             build_options,
+            build_runtime,
             sources,
             torch_backend: _,
             cuda_driver_version: _,
@@ -456,9 +466,18 @@ impl ToolLock {
 
         let options = OptionsBuilder::new()
             .resolution_mode(*resolution)
+            .resolution_package(resolution_package.clone())
             .prerelease_mode(*prerelease)
+            .prerelease_package(prerelease_package.clone())
+            .local_version_preference(local_version_preference.clone())
+            .yanked(*yanked)
             .fork_strategy(*fork_strategy)
+            .max_metadata_builds(*max_metadata_builds)
+            .resolution_effort(*resolution_effort)
+            .excluded_packages(excluded_packages.clone())
+            .reresolve_metadata(reresolve_metadata.clone())
             .exclude_newer(exclude_newer.clone())
+            .strict_exclude_newer(*strict_exclude_newer)
             .index_strategy(*index_strategy)
             .build_options(build_options.clone())
             .build();
@@ -470,7 +489,7 @@ impl ToolLock {
             let entries = client
                 .fetch_all(index_locations.flat_indexes().map(Index::url))
                 .await?;
-            FlatIndex::from_entries(entries, None, &hasher, build_options)
+            FlatIndex::from_entries(entries, None, &hasher, build_options, index_locations)
         };
 
         let extra_build_requires =
@@ -495,6 +514,7 @@ impl ToolLock {
             extra_build_variables,
             *link_mode,
             build_options,
+            build_runtime,
             &build_hasher,
             exclude_newer.clone(),
             sources.clone(),
@@ -502,7 +522,8 @@ impl ToolLock {
             workspace_cache.clone(),
             concurrency.clone(),
             preview,
-        );
+        )
+        .with_excluded_packages(excluded_packages);
         let database = DistributionDatabase::new(
             &client,
             &build_dispatch,
@@ -528,6 +549,7 @@ impl ToolLock {
             constraints,
             &overrides,
             excludes,
+            &Replacements::default(),
             build_constraints,
             &Conflicts::empty(),
             None,
@@ -589,8 +611,8 @@ impl ToolLock {
             }
         }
 
-        let markers = pip::resolution_markers(None, python_platform, interpreter);
-        let tags = pip::resolution_tags(None, python_platform, interpreter)?;
+        let markers = pip::resolution_markers(None, python_platform, None, interpreter);
+        let tags = pip::resolution_tags(None, python_platform, None, None, interpreter)?;
         Ok(ToolLockInstallTarget {
             tool_lock: self,
             project_name,
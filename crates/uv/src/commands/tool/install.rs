@@ -14,8 +14,8 @@ use uv_configuration::{
 };
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ExtraBuildRequires, IndexCapabilities, NameRequirementSpecification, Requirement,
-    RequirementSource, UnresolvedRequirementSpecification,
+    ExtraBuildRequires, IndexCapabilities, InstallContext, NameRequirementSpecification,
+    Requirement, RequirementSource, UnresolvedRequirementSpecification,
 };
 use uv_installer::{InstallationStrategy, Planner, SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
@@ -600,11 +600,14 @@ pub(crate) async fn install(
                 let markers = resolution_markers(
                     None,
                     python_platform.as_ref(),
+                    None,
                     environment.environment().interpreter(),
                 );
                 let tags = resolution_tags(
                     None,
                     python_platform.as_ref(),
+                    None,
+                    None,
                     environment.environment().interpreter(),
                 )?;
 
@@ -760,7 +763,13 @@ pub(crate) async fn install(
             let extra_build_requires =
                 LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
                     .into_inner();
-            let tags = resolution_tags(None, python_platform.as_ref(), environment.interpreter())?;
+            let tags = resolution_tags(
+                None,
+                python_platform.as_ref(),
+                None,
+                None,
+                environment.interpreter(),
+            )?;
             let hash_strategy =
                 HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
             let plan = Planner::new(&resolution).build(
@@ -774,6 +783,7 @@ pub(crate) async fn install(
                 config_settings_package,
                 &extra_build_requires,
                 extra_build_variables,
+                settings.resolver.build_runtime.via_sdist(),
                 &cache,
                 &environment,
                 &tags,
@@ -827,6 +837,7 @@ pub(crate) async fn install(
                     &state,
                     Box::new(DefaultInstallLogger),
                     installer_metadata,
+                    InstallContext::Tool,
                     &concurrency,
                     &cache,
                     printer,
@@ -850,6 +861,7 @@ pub(crate) async fn install(
                 Box::new(DefaultResolveLogger),
                 Box::new(DefaultInstallLogger),
                 installer_metadata,
+                InstallContext::Tool,
                 &concurrency,
                 &cache,
                 workspace_cache,
@@ -1041,6 +1053,7 @@ pub(crate) async fn install(
             &state,
             Box::new(DefaultInstallLogger),
             installer_metadata,
+            InstallContext::Tool,
             &concurrency,
             &cache,
             printer,
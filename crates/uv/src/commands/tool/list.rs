@@ -50,6 +50,7 @@ pub(crate) async fn list(
                 .is_some_and(|err| err.kind() == std::io::ErrorKind::NotFound) =>
         {
             writeln!(printer.stderr(), "No tools installed")?;
+            warn_dangling_executables(&installed_tools)?;
             return Ok(ExitStatus::Success);
         }
         Err(err) => return Err(err.into()),
@@ -60,6 +61,7 @@ pub(crate) async fn list(
 
     if tools.is_empty() {
         writeln!(printer.stderr(), "No tools installed")?;
+        warn_dangling_executables(&installed_tools)?;
         return Ok(ExitStatus::Success);
     }
 
@@ -75,6 +77,16 @@ pub(crate) async fn list(
             continue;
         };
 
+        // Skip tools whose environment was removed, e.g., by hand
+        if installed_tools.is_orphaned(&name) {
+            warn_user!(
+                "Tool `{name}` environment was removed (run `{}` to remove its receipt, or `{}` to reinstall)",
+                "uv tool repair".green(),
+                format!("uv tool install {name} --reinstall").green()
+            );
+            continue;
+        }
+
         // Get the tool environment
         let tool_env = match installed_tools.get_environment(&name, cache) {
             Ok(Some(env)) => env,
@@ -295,5 +307,22 @@ pub(crate) async fn list(
         }
     }
 
+    warn_dangling_executables(&installed_tools)?;
+
     Ok(ExitStatus::Success)
 }
+
+/// Warn about executables that link to tool environments that no longer exist.
+fn warn_dangling_executables(installed_tools: &InstalledTools) -> Result<()> {
+    let Ok(executable_directory) = uv_tool::tool_executable_dir() else {
+        return Ok(());
+    };
+    for path in installed_tools.dangling_executables(&executable_directory)? {
+        warn_user!(
+            "Executable `{}` links to a tool environment that no longer exists (run `{}` to remove)",
+            path.simplified_display().cyan(),
+            "uv tool repair".green()
+        );
+    }
+    Ok(())
+}
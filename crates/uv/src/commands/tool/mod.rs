@@ -10,6 +10,7 @@ pub(crate) mod common;
 pub(crate) mod dir;
 pub(crate) mod install;
 pub(crate) mod list;
+pub(crate) mod repair;
 pub(crate) mod run;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
@@ -0,0 +1,172 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+#[cfg(unix)]
+use uv_fs::replace_symlink;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_tool::{InstalledTools, Tool, entrypoint_paths};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Repair the installed tools.
+pub(crate) async fn repair(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let lock = match installed_tools.lock().await {
+        Ok(lock) => Some(lock),
+        Err(err)
+            if err
+                .as_io_error()
+                .is_some_and(|err| err.kind() == std::io::ErrorKind::NotFound) =>
+        {
+            None
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut repaired = false;
+
+    if lock.is_some() {
+        let mut tools = installed_tools.tools()?.into_iter().collect::<Vec<_>>();
+        tools.sort_by_key(|(name, _)| name.clone());
+
+        for (name, tool) in tools {
+            let Ok(tool) = tool else {
+                warn_user!(
+                    "Ignoring malformed tool `{name}` (run `{}` to remove)",
+                    format!("uv tool uninstall {name}").green()
+                );
+                continue;
+            };
+
+            // Remove the receipts of tools whose environment was removed.
+            if installed_tools.is_orphaned(&name) {
+                installed_tools.remove_environment(&name)?;
+                writeln!(
+                    printer.stderr(),
+                    "Removed orphaned receipt for `{}`",
+                    name.cyan()
+                )?;
+                repaired = true;
+                continue;
+            }
+
+            // Re-link any executables that are missing from the executable directory.
+            repaired |= relink_entrypoints(&installed_tools, &name, &tool, cache, printer)?;
+        }
+    }
+
+    // Remove any executables that link to tool environments that no longer exist.
+    if let Ok(executable_directory) = uv_tool::tool_executable_dir() {
+        for path in installed_tools.dangling_executables(&executable_directory)? {
+            debug!(
+                "Removing dangling executable: `{}`",
+                path.simplified_display()
+            );
+            fs_err::remove_file(&path)?;
+            writeln!(
+                printer.stderr(),
+                "Removed dangling executable `{}`",
+                path.simplified_display().cyan()
+            )?;
+            repaired = true;
+        }
+    }
+
+    if !repaired {
+        writeln!(printer.stderr(), "Nothing to repair")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Re-link the executables of a tool whose environment exists, but whose executables are missing.
+///
+/// Returns `true` if any executables were re-linked.
+fn relink_entrypoints(
+    installed_tools: &InstalledTools,
+    name: &PackageName,
+    tool: &Tool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<bool> {
+    let missing = tool
+        .entrypoints()
+        .iter()
+        .filter(|entrypoint| fs_err::symlink_metadata(&entrypoint.install_path).is_err())
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return Ok(false);
+    }
+
+    let Some(environment) = installed_tools.get_environment(name, cache)? else {
+        warn_user!(
+            "Tool `{name}` environment not found (run `{}` to reinstall)",
+            format!("uv tool install {name} --reinstall").green()
+        );
+        return Ok(false);
+    };
+    let site_packages = SitePackages::from_environment(environment.environment())?;
+
+    let mut relinked = false;
+    for entrypoint in missing {
+        // Find the executable in the environment of the package that provides it.
+        let package = match &entrypoint.from {
+            Some(from) => PackageName::from_owned(from.clone())?,
+            None => name.clone(),
+        };
+        let Some(dist) = site_packages.get_packages(&package).into_iter().next() else {
+            warn_user!(
+                "Package `{package}` is no longer installed in the environment for tool `{name}` (run `{}` to reinstall)",
+                format!("uv tool install {name} --reinstall").green()
+            );
+            continue;
+        };
+        let source = entrypoint_paths(&site_packages, dist.name(), dist.version())?
+            .into_iter()
+            .map(|(_, source_path)| source_path)
+            .find(|source_path| source_path.file_name() == entrypoint.install_path.file_name());
+        let Some(source) = source else {
+            warn_user!(
+                "Executable `{}` is no longer provided by the environment for tool `{name}` (run `{}` to reinstall)",
+                entrypoint.name,
+                format!("uv tool install {name} --reinstall").green()
+            );
+            continue;
+        };
+
+        debug!(
+            "Re-linking executable: `{}` to `{}`",
+            entrypoint.install_path.simplified_display(),
+            source.simplified_display()
+        );
+        if let Some(parent) = entrypoint.install_path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+
+        #[cfg(unix)]
+        replace_symlink(&source, &entrypoint.install_path)
+            .context("Failed to install executable")?;
+
+        #[cfg(windows)]
+        fs_err::copy(&source, &entrypoint.install_path).context("Failed to install entrypoint")?;
+
+        writeln!(
+            printer.stderr(),
+            "Re-linked executable `{}` for `{}`",
+            entrypoint.name.cyan(),
+            name.cyan()
+        )?;
+        relinked = true;
+    }
+
+    Ok(relinked)
+}
@@ -20,7 +20,7 @@ use uv_configuration::{Concurrency, Constraints, GitLfsSetting, TargetTriple};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::InstalledDist;
 use uv_distribution_types::{
-    IndexCapabilities, IndexUrl, Name, NameRequirementSpecification, Requirement,
+    IndexCapabilities, IndexUrl, InstallContext, Name, NameRequirementSpecification, Requirement,
     RequirementSource, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
@@ -1045,8 +1045,14 @@ async fn get_or_create_environment(
 
                     // Determine the markers and tags to use for the resolution.
                     let markers =
-                        pip::resolution_markers(None, python_platform.as_ref(), &interpreter);
-                    let tags = pip::resolution_tags(None, python_platform.as_ref(), &interpreter)?;
+                        pip::resolution_markers(None, python_platform.as_ref(), None, &interpreter);
+                    let tags = pip::resolution_tags(
+                        None,
+                        python_platform.as_ref(),
+                        None,
+                        None,
+                        &interpreter,
+                    )?;
 
                     // Check if the installed packages meet the requirements.
                     let site_packages = SitePackages::from_environment(environment.environment())?;
@@ -1122,6 +1128,7 @@ async fn get_or_create_environment(
             Box::new(SummaryInstallLogger)
         },
         installer_metadata,
+        InstallContext::Tool,
         concurrency,
         cache,
         workspace_cache,
@@ -1182,6 +1189,7 @@ async fn get_or_create_environment(
                         Box::new(SummaryInstallLogger)
                     },
                     installer_metadata,
+                    InstallContext::Tool,
                     concurrency,
                     cache,
                     workspace_cache,
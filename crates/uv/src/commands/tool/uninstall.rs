@@ -8,12 +8,17 @@ use tracing::debug;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 use uv_tool::{InstalledTools, Tool, ToolEntrypoint};
+use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
 /// Uninstall a tool.
-pub(crate) async fn uninstall(name: Vec<PackageName>, printer: Printer) -> Result<ExitStatus> {
+pub(crate) async fn uninstall(
+    name: Vec<PackageName>,
+    keep: Vec<PackageName>,
+    printer: Printer,
+) -> Result<ExitStatus> {
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = match installed_tools.lock().await {
         Ok(lock) => lock,
@@ -35,7 +40,7 @@ pub(crate) async fn uninstall(name: Vec<PackageName>, printer: Printer) -> Resul
     };
 
     // Perform the uninstallation.
-    do_uninstall(&installed_tools, name, printer).await?;
+    do_uninstall(&installed_tools, name, &keep, printer).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installed_tools.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -97,12 +102,26 @@ impl IgnoreCurrentlyBeingDeleted for Result<(), std::io::Error> {
 async fn do_uninstall(
     installed_tools: &InstalledTools,
     names: Vec<PackageName>,
+    keep: &[PackageName],
     printer: Printer,
 ) -> Result<()> {
     let mut dangling = false;
     let mut entrypoints = if names.is_empty() {
         let mut entrypoints = vec![];
-        for (name, receipt) in installed_tools.tools()? {
+        let tools = installed_tools.tools()?;
+        for name in keep {
+            if !tools.iter().any(|(tool, _)| tool == name) {
+                warn_user!(
+                    "`{}` was passed to `--keep`, but is not installed",
+                    name.cyan()
+                );
+            }
+        }
+        for (name, receipt) in tools {
+            if keep.contains(&name) {
+                debug!("Keeping tool `{name}`");
+                continue;
+            }
             let Ok(receipt) = receipt else {
                 // If the tool is not installed properly, attempt to remove the environment anyway.
                 match installed_tools.remove_environment(&name) {
@@ -10,7 +10,9 @@ use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, DryRun, HashCheckingMode, TargetTriple};
 use uv_distribution::LoweredExtraBuildDependencies;
-use uv_distribution_types::{ExtraBuildRequires, Name, Requirement, RequirementSource};
+use uv_distribution_types::{
+    ExtraBuildRequires, InstallContext, Name, Requirement, RequirementSource,
+};
 use uv_errors::{ErrorOptions, write_error_chain_with_options};
 use uv_fs::CWD;
 use uv_installer::{InstallationStrategy, Planner, SitePackages};
@@ -406,6 +408,7 @@ async fn upgrade_tool(
                 &state,
                 Box::new(DefaultInstallLogger),
                 installer_metadata,
+                InstallContext::Tool,
                 concurrency,
                 cache,
                 printer,
@@ -436,6 +439,8 @@ async fn upgrade_tool(
             let tags = resolution_tags(
                 None,
                 python_platform,
+                None,
+                None,
                 environment.environment().interpreter(),
             )?;
             let plan = Planner::new(&resolution).build(
@@ -449,6 +454,7 @@ async fn upgrade_tool(
                 config_settings_package,
                 &extra_build_requires,
                 extra_build_variables,
+                settings.resolver.build_runtime.via_sdist(),
                 cache,
                 environment.environment(),
                 &tags,
@@ -479,6 +485,7 @@ async fn upgrade_tool(
                     &state,
                     Box::new(UpgradeInstallLogger::new(name.clone())),
                     installer_metadata,
+                    InstallContext::Tool,
                     concurrency,
                     cache,
                     printer,
@@ -519,6 +526,7 @@ async fn upgrade_tool(
             &state,
             Box::new(DefaultInstallLogger),
             installer_metadata,
+            InstallContext::Tool,
             concurrency,
             cache,
             printer,
@@ -545,6 +553,7 @@ async fn upgrade_tool(
             Box::new(SummaryResolveLogger),
             Box::new(UpgradeInstallLogger::new(name.clone())),
             installer_metadata,
+            InstallContext::Tool,
             concurrency,
             cache,
             workspace_cache,
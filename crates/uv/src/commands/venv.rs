@@ -11,7 +11,7 @@ use tracing::warn;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, IndexStrategy,
+    BuildOptions, BuildRuntime, Concurrency, Constraints, DependencyGroups, DryRun, IndexStrategy,
     KeyringProviderType, NoBinary, NoBuild, NoSources,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
@@ -298,6 +298,7 @@ pub(crate) async fn venv(
                 Some(tags),
                 &HashStrategy::None,
                 &BuildOptions::new(NoBinary::None, NoBuild::All),
+                index_locations,
             )
         };
 
@@ -313,6 +314,7 @@ pub(crate) async fn venv(
 
         // Do not allow builds
         let build_options = BuildOptions::new(NoBinary::None, NoBuild::All);
+        let build_runtime = BuildRuntime::default();
         let extra_build_requires = ExtraBuildRequires::default();
         let extra_build_variables = uv_distribution_types::ExtraBuildVariables::default();
         // Prep the build context.
@@ -333,6 +335,7 @@ pub(crate) async fn venv(
             &extra_build_variables,
             link_mode,
             &build_options,
+            &build_runtime,
             &build_hasher,
             exclude_newer,
             sources,
@@ -363,7 +366,7 @@ pub(crate) async fn venv(
         // Since the virtual environment is empty, and the set of requirements is trivial (no
         // constraints, no editables, etc.), we can use the build dispatch APIs directly.
         let requirements = build_dispatch
-            .resolve(&requirements, &build_stack)
+            .resolve(&requirements, &[], &build_stack)
             .await
             .map_err(|err| VenvError::Seed(err.into()))?;
         let installed = build_dispatch
@@ -19,7 +19,7 @@ use uv_settings::MalwareCheckSettings;
 use uv_workspace::WorkspaceCache;
 
 use crate::commands::pip::loggers::DefaultInstallLogger;
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications};
 use crate::commands::pip::{resolution_markers, resolution_tags};
 use crate::commands::project::UniversalState;
 use crate::commands::project::install_target::InstallTarget;
@@ -61,11 +61,15 @@ pub(crate) async fn collect_module_owners(
         extra_build_dependencies: &settings.extra_build_dependencies,
         extra_build_variables: &settings.extra_build_variables,
         exclude_newer: &settings.exclude_newer,
+        strict_exclude_newer: settings.strict_exclude_newer,
+        excluded_packages: &settings.excluded_packages,
         link_mode: settings.link_mode,
         compile_bytecode: false,
         reinstall: &reinstall,
         build_options: &settings.build_options,
+        build_runtime: &settings.build_runtime,
         sources: settings.sources.clone(),
+        yanked: settings.yanked,
     };
 
     do_sync(
@@ -76,6 +80,7 @@ pub(crate) async fn collect_module_owners(
         None,
         InstallOptions::default(),
         Modifications::Sufficient,
+        &ExtraneousOptions::default(),
         None,
         installer_settings,
         client_builder,
@@ -86,6 +91,7 @@ pub(crate) async fn collect_module_owners(
         cache,
         workspace_cache,
         DryRun::Disabled,
+        false,
         Printer::Silent,
         preview,
         malware_settings,
@@ -118,8 +124,8 @@ fn selected_package_ids(
     groups: &DependencyGroupsWithDefaults,
     settings: &ResolverSettings,
 ) -> Result<Option<BTreeMap<PackageName, String>>> {
-    let marker_env = resolution_markers(None, None, venv.interpreter());
-    let tags = resolution_tags(None, None, venv.interpreter())?;
+    let marker_env = resolution_markers(None, None, None, venv.interpreter());
+    let tags = resolution_tags(None, None, None, None, venv.interpreter())?;
 
     let resolution = target.to_resolution(
         &marker_env,
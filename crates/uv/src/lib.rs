@@ -29,8 +29,8 @@ use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
     CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
-    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
+    PythonNamespace, SelfCommand, SelfDirsArgs, SelfNamespace, ToolCommand, ToolNamespace,
+    TopLevelArgs, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
 use uv_client::BaseClientBuilder;
 use uv_configuration::min_stack_size;
@@ -53,9 +53,9 @@ use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 use crate::commands::{ExitStatus, ParsedRunCommand, RunCommand, ScriptPath, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings, resolve_color,
+    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipDownloadSettings,
+    PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings, PublishSettings, resolve_color,
 };
 
 pub(crate) mod child;
@@ -556,7 +556,9 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
     if cache_settings.no_cache {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let (cache, cache_source) =
+        Cache::from_settings_with_source(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache_source = cache_settings.source.unwrap_or(cache_source);
     // This check happens after the first (fallible) workspace discovery, which we need to resolve
     // the settings that go into the cache constructor, but the check happens before the first
     // workspace discovery that's used beyond settings discovery.
@@ -607,7 +609,9 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
     )
     .http_proxy(globals.network_settings.http_proxy.clone())
     .https_proxy(globals.network_settings.https_proxy.clone())
-    .no_proxy(globals.network_settings.no_proxy.clone());
+    .no_proxy(globals.network_settings.no_proxy.clone())
+    .retry_missing_artifacts(globals.network_settings.retry_missing_artifacts)
+    .http_version(globals.network_settings.http_version);
 
     match *cli.command {
         Commands::Auth(AuthNamespace {
@@ -755,8 +759,18 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.output_file.as_deref(),
                 args.format,
                 args.settings.resolution,
+                args.settings.resolution_package,
                 args.settings.prerelease,
+                args.settings.prerelease_package,
+                args.settings.local_version_preference,
+                args.settings.yanked,
                 args.settings.fork_strategy,
+                args.settings.max_metadata_builds,
+                args.settings.resolution_effort,
+                args.settings.excluded_packages,
+                args.settings.reresolve_metadata,
+                args.explain,
+                args.hint_on_failure,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
@@ -766,6 +780,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 !args.settings.no_annotate,
                 !args.settings.no_header,
                 args.settings.custom_compile_command,
+                args.settings.custom_header,
                 args.settings.emit_index_url,
                 args.settings.emit_find_links,
                 args.settings.emit_build_options,
@@ -785,12 +800,16 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &args.settings.extra_build_dependencies,
                 &args.settings.extra_build_variables,
                 args.settings.build_options,
+                args.settings.build_runtime,
                 args.settings.install_mirrors,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.settings.python_implementation,
+                args.settings.python_abiflags,
                 globals.python_downloads,
                 args.settings.universal,
                 args.settings.exclude_newer,
+                args.settings.strict_exclude_newer,
                 args.settings.sources,
                 args.settings.annotation_style,
                 args.settings.link_mode,
@@ -873,12 +892,16 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &args.settings.extra_build_dependencies,
                 &args.settings.extra_build_variables,
                 args.settings.build_options,
+                args.settings.build_runtime,
                 args.settings.python_version,
                 args.settings.python_platform,
                 globals.python_downloads,
                 args.settings.install_mirrors,
                 args.settings.strict,
+                args.settings.yanked,
                 args.settings.exclude_newer,
+                args.settings.strict_exclude_newer,
+                args.settings.excluded_packages,
                 args.settings.python,
                 args.settings.system,
                 args.settings.break_system_packages,
@@ -890,6 +913,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 cache,
                 workspace_cache,
                 args.dry_run,
+                args.output_format,
+                args.exit_code,
                 printer,
                 globals.preview,
             ))
@@ -1012,7 +1037,15 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &args.settings.extras,
                 &groups,
                 args.settings.resolution,
+                args.settings.resolution_package,
                 args.settings.prerelease,
+                args.settings.prerelease_package,
+                args.settings.local_version_preference,
+                args.settings.yanked,
+                args.settings.max_metadata_builds,
+                args.settings.resolution_effort,
+                args.settings.excluded_packages,
+                args.settings.reresolve_metadata,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.index_locations,
@@ -1034,6 +1067,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 &args.settings.extra_build_dependencies,
                 &args.settings.extra_build_variables,
                 args.settings.build_options,
+                args.settings.build_runtime,
                 args.modifications,
                 args.settings.python_version,
                 args.settings.python_platform,
@@ -1041,6 +1075,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.install_mirrors,
                 args.settings.strict,
                 args.settings.exclude_newer,
+                args.settings.strict_exclude_newer,
                 args.settings.sources,
                 args.settings.python,
                 args.settings.system,
@@ -1052,6 +1087,92 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 cache,
                 workspace_cache,
                 args.dry_run,
+                args.compatibility_report,
+                args.output_format,
+                args.report,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Download(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipDownloadSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            let mut requirements = Vec::with_capacity(args.package.len() + args.requirements.len());
+            for package in args.package {
+                requirements.push(RequirementsSource::from_package_argument(&package)?);
+            }
+            requirements.extend(
+                args.requirements
+                    .into_iter()
+                    .map(RequirementsSource::from_requirements_file)
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            let dest = args.dest.unwrap_or_else(|| CWD.to_path_buf());
+
+            Box::pin(commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                args.constraints_from_workspace,
+                args.overrides_from_workspace,
+                &dest,
+                args.settings.resolution,
+                args.settings.prerelease,
+                args.settings.dependency_mode,
+                args.settings.upgrade,
+                args.settings.index_locations,
+                args.settings.index_strategy,
+                args.settings.dependency_metadata,
+                args.settings.keyring_provider,
+                &client_builder.subcommand(vec!["pip".to_owned(), "download".to_owned()]),
+                args.settings.link_mode,
+                args.settings.hash_checking,
+                &args.settings.config_setting,
+                &args.settings.config_settings_package,
+                args.settings.build_isolation.clone(),
+                &args.settings.extra_build_dependencies,
+                &args.settings.extra_build_variables,
+                args.settings.build_options,
+                args.settings.build_runtime,
+                args.settings.python_version,
+                args.settings.python_platform,
+                globals.python_downloads,
+                args.settings.install_mirrors,
+                args.settings.exclude_newer,
+                args.settings.sources,
+                args.settings.python,
+                args.settings.system,
+                globals.python_preference,
+                globals.concurrency,
+                cache,
+                workspace_cache,
                 printer,
                 globals.preview,
             ))
@@ -1090,6 +1211,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 &client_builder.subcommand(vec!["pip".to_owned(), "uninstall".to_owned()]),
                 args.dry_run,
+                args.unneeded,
+                args.yes,
                 printer,
             )
             .await
@@ -1172,9 +1295,11 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.target,
                 args.settings.prefix,
                 args.files,
+                args.why,
                 &cache,
                 printer,
             )
+            .await
         }
         Commands::Pip(PipNamespace {
             command: PipCommand::Tree(args),
@@ -1220,6 +1345,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             let cache = cache.init().await?;
 
             commands::pip_check(
+                args.format,
                 args.settings.python.as_deref(),
                 args.settings.system,
                 args.settings.python_version.as_ref(),
@@ -1239,7 +1365,16 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, args.force, cache, printer).await
+            commands::cache_clean(
+                &args.package,
+                args.force,
+                args.dry_run,
+                args.preserve_referenced,
+                args.output_format,
+                cache,
+                printer,
+            )
+            .await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
@@ -1441,6 +1576,9 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             commands::self_version(short, output_format, printer)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Dirs(SelfDirsArgs { output_format }),
+        }) => commands::self_dirs(&cache, cache_source, output_format, printer),
         #[cfg(not(feature = "self-update"))]
         Commands::Self_(_) => {
             return Err(ExternallyInstalledError {
@@ -1745,7 +1883,15 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             let args = settings::ToolUninstallSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            commands::tool_uninstall(args.name, printer).await
+            commands::tool_uninstall(args.name, args.keep, printer).await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Repair,
+        }) => {
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            commands::tool_repair(&cache, printer).await
         }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::UpdateShell,
@@ -1884,6 +2030,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                     (&script).into(),
                     args.show_version,
                     args.resolve_links,
+                    args.output_format,
                     // TODO(zsol): is this the right thing to do here?
                     &client_builder.subcommand(vec!["python".to_owned(), "find".to_owned()]),
                     globals.python_preference,
@@ -1899,6 +2046,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                     args.request,
                     args.show_version,
                     args.resolve_links,
+                    args.output_format,
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
@@ -1928,6 +2076,8 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.python_downloads,
                 args.no_project,
+                args.strict,
+                args.force,
                 args.global,
                 args.rm,
                 args.install_mirrors,
@@ -2287,6 +2437,8 @@ async fn run_project(
                 command,
                 requirements,
                 args.show_resolution || globals.verbose > 0,
+                args.which,
+                args.from_path,
                 args.lock_check,
                 args.frozen,
                 args.active,
@@ -2348,6 +2500,7 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.verify,
                 args.active,
                 args.all_packages,
                 args.package,
@@ -2356,6 +2509,7 @@ async fn run_project(
                 args.editable,
                 args.install_options,
                 args.modifications,
+                args.extraneous_options,
                 args.python,
                 args.python_platform,
                 args.install_mirrors,
@@ -2411,6 +2565,12 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
+                args.narrow_bounds,
+                args.summary,
+                args.explain,
+                args.requires_python_strategy,
+                args.preview_report,
+                args.preview_report_format,
                 args.refresh,
                 args.python,
                 args.install_mirrors,
@@ -2764,6 +2924,7 @@ async fn run_project(
                 args.frozen,
                 args.include_annotations,
                 args.include_header,
+                args.distroless,
                 args.include_index_url,
                 args.include_find_links,
                 script,
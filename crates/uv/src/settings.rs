@@ -14,17 +14,17 @@ use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     AddArgs, AuditArgs, AuditOutputFormat, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs,
-    ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, MetadataArgs,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
-    VersionFormat,
+    CheckFormat, ColorChoice, DryRunFormat, ExternalCommand, GlobalArgs, InitArgs, ListFormat,
+    LockArgs, LockReportFormat, Maybe, MetadataArgs, PipCheckArgs, PipCompileArgs, PipDownloadArgs,
+    PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs,
+    PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat,
+    PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs,
+    SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs,
+    TreeArgs, UpgradeArgs, VenvArgs, VersionArgs, VersionBumpSpec, VersionFormat,
 };
 use uv_cli::{
-    AuthorFrom, BuildArgs, CheckArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
-    ResolverInstallerArgs, ToolUpgradeArgs,
+    AuthorFrom, BuildArgs, BuildTargetArgs, CheckArgs, ExportArgs, FormatArgs, PublishArgs,
+    PythonDirArgs, ResolverInstallerArgs, ToolUpgradeArgs,
     options::{
         Flag, FlagSource, check_conflicts, flag, indexes_from_args, resolve_flag,
         resolve_flag_pair, resolver_installer_options, resolver_installer_options_with_indexes,
@@ -33,15 +33,17 @@ use uv_cli::{
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun, EditableMode, EnvFile,
-    ExcludeDependency, ExportFormat, ExtrasSpecification, GitLfsSetting, HashCheckingMode,
-    IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild, NoSources, Override,
-    PackageOverride, PipCompileFormat, ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion,
-    TargetTriple, TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+    BuildIsolation, BuildOptions, BuildRuntime, Concurrency, DependencyGroups, DryRun,
+    EditableMode, EnvFile, ExcludeDependency, ExportFormat, ExtrasSpecification, GitLfsSetting,
+    HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    NoSources, Override, PackageOverride, PipCompileFormat, ProjectBuildBackend, ProxyUrl,
+    Reinstall, RequiredVersion, TargetAbiFlags, TargetImplementation, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade, VersionControlSystem,
 };
+use uv_dirs::DirectorySource;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
-    PackageConfigSettings, Requirement,
+    BuildTarget, ConfigSettings, DependencyMetadata, ExtraBuildVariables, HttpVersion, Index,
+    IndexLocations, IndexUrl, PackageConfigSettings, Requirement,
 };
 use uv_install_wheel::LinkMode;
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
@@ -53,7 +55,8 @@ use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerOverride, ExcludeNewerPackage,
-    ForkStrategy, PrereleaseMode, ResolutionMode,
+    ForkStrategy, PackageLocalVersionPreferences, PackagePrereleaseModes, PackageResolutionModes,
+    PrereleaseMode, RequiresPythonStrategy, ResolutionMode, YankedPolicy,
 };
 use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, MalwareCheckSettings, Options, PipOptions,
@@ -66,9 +69,9 @@ use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::{DependencyType, ExtraBuildDependencies, OverrideDependency};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{ExtraneousOptions, Modifications};
 use crate::commands::{
-    InitKind, InitProjectKind, PythonUpgrade, PythonUpgradeSource, ToolRunCommand,
+    InitKind, InitProjectKind, NarrowBounds, PythonUpgrade, PythonUpgradeSource, ToolRunCommand,
 };
 
 /// The default publish URL.
@@ -115,9 +118,8 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_downloads))
                     .map(NonZeroUsize::get)
                     .unwrap_or(Concurrency::DEFAULT_DOWNLOADS),
-                environment
-                    .concurrency
-                    .builds
+                args.concurrent_builds
+                    .combine(environment.concurrency.builds)
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_builds))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
@@ -127,19 +129,31 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
-            ),
+            )
+            .with_metadata(
+                environment
+                    .concurrency
+                    .metadata
+                    .map(NonZeroUsize::get)
+                    .unwrap_or(Concurrency::DEFAULT_METADATA),
+            )
+            .with_fail_fast(!args.no_fail_fast),
             show_settings: args.show_settings,
             preview: resolve_preview(args, workspace, environment),
             python_preference,
-            python_downloads: flag(
-                args.allow_python_downloads,
-                args.no_python_downloads,
-                "python-downloads",
-            )
-            .map(PythonDownloads::from)
-            .combine(env(env::UV_PYTHON_DOWNLOADS))
-            .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
-            .unwrap_or_default(),
+            python_downloads: args
+                .python_downloads
+                .combine(
+                    flag(
+                        args.allow_python_downloads,
+                        args.no_python_downloads,
+                        "python-downloads",
+                    )
+                    .map(PythonDownloads::from),
+                )
+                .combine(env(env::UV_PYTHON_DOWNLOADS))
+                .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
+                .unwrap_or_default(),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: resolve_flag(args.no_progress, "no-progress", environment.no_progress)
@@ -274,6 +288,8 @@ pub(crate) struct NetworkSettings {
     pub(crate) read_timeout: Duration,
     pub(crate) connect_timeout: Duration,
     pub(crate) retries: u32,
+    pub(crate) retry_missing_artifacts: bool,
+    pub(crate) http_version: Option<HttpVersion>,
 }
 
 impl NetworkSettings {
@@ -393,6 +409,8 @@ impl NetworkSettings {
             read_timeout: environment.http_read_timeout,
             connect_timeout: environment.http_connect_timeout,
             retries: environment.http_retries,
+            retry_missing_artifacts: !args.no_retry_missing_artifacts,
+            http_version: environment.http_version,
         }
     }
 
@@ -415,19 +433,46 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    /// Where `no_cache` or `cache_dir` was set, if the cache location was configured.
+    pub(crate) source: Option<DirectorySource>,
 }
 
 impl CacheSettings {
     /// Resolve the [`CacheSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: CacheArgs, workspace: Option<&FilesystemOptions>) -> Self {
+        let workspace_no_cache = workspace
+            .and_then(|workspace| workspace.globals.no_cache)
+            .unwrap_or(false);
+        let workspace_cache_dir =
+            workspace.and_then(|workspace| workspace.globals.cache_dir.clone());
+
+        // The CLI arguments may also be provided via environment variables.
+        let source = if args.no_cache {
+            if std::env::var_os(EnvVars::UV_NO_CACHE).is_some() {
+                Some(DirectorySource::EnvironmentVariable(EnvVars::UV_NO_CACHE))
+            } else {
+                Some(DirectorySource::Argument("--no-cache"))
+            }
+        } else if workspace_no_cache {
+            Some(DirectorySource::Setting("no-cache"))
+        } else if let Some(cache_dir) = &args.cache_dir {
+            if std::env::var_os(EnvVars::UV_CACHE_DIR)
+                .is_some_and(|value| PathBuf::from(value) == *cache_dir)
+            {
+                Some(DirectorySource::EnvironmentVariable(EnvVars::UV_CACHE_DIR))
+            } else {
+                Some(DirectorySource::Argument("--cache-dir"))
+            }
+        } else if workspace_cache_dir.is_some() {
+            Some(DirectorySource::Setting("cache-dir"))
+        } else {
+            None
+        };
+
         Self {
-            no_cache: args.no_cache
-                || workspace
-                    .and_then(|workspace| workspace.globals.no_cache)
-                    .unwrap_or(false),
-            cache_dir: args
-                .cache_dir
-                .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            no_cache: args.no_cache || workspace_no_cache,
+            cache_dir: args.cache_dir.or(workspace_cache_dir),
+            source,
         }
     }
 }
@@ -681,6 +726,8 @@ pub(crate) struct RunSettings {
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
+    pub(crate) which: bool,
+    pub(crate) from_path: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) no_project: bool,
@@ -730,6 +777,8 @@ impl RunSettings {
             exact,
             script: _,
             gui_script: _,
+            which,
+            from_path,
             command: _,
             with,
             with_editable,
@@ -839,6 +888,8 @@ impl RunSettings {
                 .collect(),
             isolated,
             show_resolution,
+            which,
+            from_path,
             all_packages,
             package,
             no_project,
@@ -1173,14 +1224,34 @@ impl ToolUpgradeSettings {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
             pre,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            exclude,
+            no_index_metadata_package,
             config_setting,
             config_setting_package: config_settings_package,
+            build_env,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             link_mode,
             compile_bytecode,
@@ -1188,6 +1259,8 @@ impl ToolUpgradeSettings {
             no_sources,
             no_sources_package,
             exclude_newer_package,
+            strict_exclude_newer,
+            no_strict_exclude_newer,
             build,
         } = args;
 
@@ -1211,16 +1284,38 @@ impl ToolUpgradeSettings {
             index_strategy,
             keyring_provider,
             resolution,
+            resolution_package,
             prerelease,
             pre,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            exclude,
+            no_index_metadata_package,
             config_setting,
             config_settings_package,
+            build_env,
             no_build_isolation,
             no_build_isolation_package,
             build_isolation,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             exclude_newer,
             exclude_newer_package,
+            strict_exclude_newer,
+            no_strict_exclude_newer,
             link_mode,
             compile_bytecode,
             no_compile_bytecode,
@@ -1312,15 +1407,17 @@ impl ToolListSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct ToolUninstallSettings {
     pub(crate) name: Vec<PackageName>,
+    pub(crate) keep: Vec<PackageName>,
 }
 
 impl ToolUninstallSettings {
     /// Resolve the [`ToolUninstallSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: ToolUninstallArgs, _filesystem: Option<FilesystemOptions>) -> Self {
-        let ToolUninstallArgs { name, all } = args;
+        let ToolUninstallArgs { name, all, keep } = args;
 
         Self {
             name: if all { vec![] } else { name },
+            keep,
         }
     }
 }
@@ -1664,6 +1761,7 @@ pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
     pub(crate) resolve_links: bool,
+    pub(crate) output_format: PythonListFormat,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
     pub(crate) python_downloads_json_url: Option<String>,
@@ -1680,6 +1778,7 @@ impl PythonFindSettings {
             request,
             show_version,
             resolve_links,
+            output_format,
             no_project,
             system,
             no_system,
@@ -1708,6 +1807,7 @@ impl PythonFindSettings {
             request,
             show_version,
             resolve_links,
+            output_format,
             no_project,
             system: flag(system, no_system, "system").unwrap_or_default(),
             python_downloads_json_url,
@@ -1721,6 +1821,8 @@ pub(crate) struct PythonPinSettings {
     pub(crate) request: Option<String>,
     pub(crate) resolved: bool,
     pub(crate) no_project: bool,
+    pub(crate) strict: bool,
+    pub(crate) force: bool,
     pub(crate) global: bool,
     pub(crate) rm: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -1738,6 +1840,8 @@ impl PythonPinSettings {
             no_resolved,
             resolved,
             no_project,
+            strict,
+            force,
             global,
             rm,
             python_downloads_json_url,
@@ -1758,6 +1862,8 @@ impl PythonPinSettings {
             request,
             resolved: flag(resolved, no_resolved, "resolved").unwrap_or(false),
             no_project,
+            strict,
+            force,
             global,
             rm,
             install_mirrors,
@@ -1772,6 +1878,7 @@ pub(crate) struct SyncSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) verify: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
     pub(crate) extras: ExtrasSpecification,
@@ -1779,6 +1886,7 @@ pub(crate) struct SyncSettings {
     pub(crate) editable: Option<EditableMode>,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
+    pub(crate) extraneous_options: ExtraneousOptions,
     pub(crate) all_packages: bool,
     pub(crate) package: Vec<PackageName>,
     pub(crate) python: Option<String>,
@@ -1815,6 +1923,8 @@ impl SyncSettings {
             no_editable_package,
             inexact,
             exact,
+            keep,
+            list_extraneous,
             no_install_project,
             only_install_project,
             no_install_workspace,
@@ -1838,12 +1948,17 @@ impl SyncSettings {
             python_platform,
             check,
             no_check,
+            verify,
             output_format,
         } = args;
         let filesystem_install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
             .unwrap_or_default();
+        let filesystem_keep_extraneous = filesystem
+            .as_ref()
+            .and_then(|fs| fs.sync.keep_extraneous.clone())
+            .unwrap_or_default();
 
         let settings = ResolverInstallerSettings::combine(
             resolver_installer_options(installer, build),
@@ -1855,7 +1970,8 @@ impl SyncSettings {
         let dry_run = if check {
             DryRun::Check
         } else {
-            DryRun::from_args(dry_run)
+            // Listing the extraneous packages shouldn't modify the lockfile or environment.
+            DryRun::from_args(dry_run || list_extraneous)
         };
 
         // Resolve flags from CLI and environment variables.
@@ -1929,6 +2045,7 @@ impl SyncSettings {
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             dry_run,
+            verify,
             script,
             active: flag(active, no_active, "active"),
             extras: ExtrasSpecification::from_args(
@@ -1973,6 +2090,15 @@ impl SyncSettings {
             } else {
                 Modifications::Sufficient
             },
+            extraneous_options: ExtraneousOptions {
+                keep: keep
+                    .iter()
+                    .cloned()
+                    .chain(filesystem_keep_extraneous)
+                    .collect(),
+                requested: keep,
+                list: list_extraneous,
+            },
             all_packages,
             package,
             python: python.and_then(Maybe::into_option),
@@ -1993,6 +2119,12 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) narrow_bounds: NarrowBounds,
+    pub(crate) summary: bool,
+    pub(crate) explain: Vec<PackageName>,
+    pub(crate) requires_python_strategy: RequiresPythonStrategy,
+    pub(crate) preview_report: Option<PathBuf>,
+    pub(crate) preview_report_format: LockReportFormat,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -2012,6 +2144,13 @@ impl LockSettings {
             locked,
             check_exists,
             dry_run,
+            narrow_bounds,
+            write,
+            summary,
+            explain,
+            requires_python_strategy,
+            preview_report,
+            preview_report_format,
             script,
             resolver,
             build,
@@ -2041,6 +2180,12 @@ impl LockSettings {
             lock_check,
             frozen: resolve_frozen(frozen),
             dry_run: DryRun::from_args(dry_run),
+            narrow_bounds: NarrowBounds::from_args(narrow_bounds, write),
+            summary,
+            explain,
+            requires_python_strategy,
+            preview_report,
+            preview_report_format,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -2773,6 +2918,7 @@ pub(crate) struct ExportSettings {
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) include_annotations: bool,
     pub(crate) include_header: bool,
+    pub(crate) distroless: bool,
     pub(crate) include_index_url: bool,
     pub(crate) include_find_links: bool,
     pub(crate) script: Option<PathBuf>,
@@ -2810,6 +2956,7 @@ impl ExportSettings {
             no_annotate,
             header,
             no_header,
+            distroless,
             emit_index_url,
             no_emit_index_url,
             emit_find_links,
@@ -2913,6 +3060,7 @@ impl ExportSettings {
             frozen: resolve_frozen(frozen),
             include_annotations: flag(annotate, no_annotate, "annotate").unwrap_or(true),
             include_header: flag(header, no_header, "header").unwrap_or(true),
+            distroless,
             include_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url")
                 .unwrap_or(false),
             include_find_links: flag(emit_find_links, no_emit_find_links, "emit-find-links")
@@ -3259,6 +3407,8 @@ fn workspace_overrides(filesystem: Option<&FilesystemOptions>) -> Vec<Override<R
 #[derive(Debug, Clone)]
 pub(crate) struct PipCompileSettings {
     pub(crate) format: Option<PipCompileFormat>,
+    pub(crate) explain: Vec<PackageName>,
+    pub(crate) hint_on_failure: bool,
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
@@ -3277,7 +3427,7 @@ pub(crate) struct PipCompileSettings {
 impl PipCompileSettings {
     /// Resolve the [`PipCompileSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(
-        args: PipCompileArgs,
+        args: Box<PipCompileArgs>,
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
     ) -> Self {
@@ -3293,6 +3443,8 @@ impl PipCompileSettings {
             refresh,
             no_deps,
             deps,
+            explain,
+            hint_on_failure,
             group,
             output_file,
             format,
@@ -3306,7 +3458,9 @@ impl PipCompileSettings {
             header,
             annotation_style,
             custom_compile_command,
+            custom_header,
             resolver,
+            build_target: BuildTargetArgs { build_target },
             python,
             system,
             no_system,
@@ -3318,9 +3472,13 @@ impl PipCompileSettings {
             only_binary,
             python_version,
             python_platform,
+            python_implementation,
+            python_abiflags,
             universal,
             no_universal,
             no_emit_package,
+            allow_unsafe,
+            no_allow_unsafe,
             emit_index_url,
             no_emit_index_url,
             emit_find_links,
@@ -3333,7 +3491,7 @@ impl PipCompileSettings {
             no_emit_index_annotation,
             torch_backend,
             compat_args: _,
-        } = args;
+        } = *args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
             configuration
@@ -3391,6 +3549,8 @@ impl PipCompileSettings {
 
         Self {
             format,
+            explain,
+            hint_on_failure,
             src_file,
             constraints: constraints
                 .into_iter()
@@ -3432,11 +3592,15 @@ impl PipCompileSettings {
                     no_annotate: flag(no_annotate, annotate, "annotate"),
                     no_header: flag(no_header, header, "header"),
                     custom_compile_command,
+                    custom_header,
                     generate_hashes: flag(generate_hashes, no_generate_hashes, "generate-hashes"),
                     python_version,
                     python_platform,
+                    python_implementation,
+                    python_abiflags,
                     universal: flag(universal, no_universal, "universal"),
                     no_emit_package,
+                    allow_unsafe: flag(allow_unsafe, no_allow_unsafe, "allow-unsafe"),
                     emit_index_url: flag(emit_index_url, no_emit_index_url, "emit-index-url"),
                     emit_find_links: flag(emit_find_links, no_emit_find_links, "emit-find-links"),
                     emit_build_options: flag(
@@ -3460,7 +3624,8 @@ impl PipCompileSettings {
                 },
                 filesystem,
                 environment,
-            ),
+            )
+            .with_build_target(build_target),
         }
     }
 }
@@ -3472,6 +3637,8 @@ pub(crate) struct PipSyncSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) output_format: DryRunFormat,
+    pub(crate) exit_code: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -3515,6 +3682,8 @@ impl PipSyncSettings {
             strict,
             no_strict,
             dry_run,
+            output_format,
+            exit_code,
             torch_backend,
             compat_args: _,
         } = *args;
@@ -3530,6 +3699,8 @@ impl PipSyncSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            output_format,
+            exit_code,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -3580,6 +3751,9 @@ pub(crate) struct PipInstallSettings {
     pub(crate) excludes: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) compatibility_report: bool,
+    pub(crate) output_format: DryRunFormat,
+    pub(crate) report: Option<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Override<Requirement>>,
     pub(crate) excludes_from_workspace: Vec<ExcludeDependency>,
@@ -3592,7 +3766,7 @@ pub(crate) struct PipInstallSettings {
 impl PipInstallSettings {
     /// Resolve the [`PipInstallSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(
-        args: PipInstallArgs,
+        args: Box<PipInstallArgs>,
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
     ) -> Self {
@@ -3636,9 +3810,12 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            compatibility_report,
+            output_format,
+            report,
             torch_backend,
             compat_args: _,
-        } = args;
+        } = *args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
             configuration
@@ -3700,6 +3877,9 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            compatibility_report,
+            output_format,
+            report,
             constraints_from_workspace,
             overrides_from_workspace,
             excludes_from_workspace,
@@ -3751,12 +3931,112 @@ impl PipInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `pip download` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PipDownloadSettings {
+    pub(crate) package: Vec<String>,
+    pub(crate) requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) dest: Option<PathBuf>,
+    pub(crate) constraints_from_workspace: Vec<Requirement>,
+    pub(crate) overrides_from_workspace: Vec<Override<Requirement>>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: PipSettings,
+}
+
+impl PipDownloadSettings {
+    /// Resolve the [`PipDownloadSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(
+        args: Box<PipDownloadArgs>,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let PipDownloadArgs {
+            package,
+            requirements,
+            constraints,
+            overrides,
+            dest,
+            resolver,
+            refresh,
+            no_deps,
+            deps,
+            require_hashes,
+            no_require_hashes,
+            verify_hashes,
+            no_verify_hashes,
+            python,
+            system,
+            no_system,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            python_version,
+            python_platform,
+        } = *args;
+
+        let constraints_from_workspace = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_dependencies
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|requirement| {
+                    Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let overrides_from_workspace = workspace_overrides(filesystem.as_ref());
+
+        Self {
+            package,
+            requirements,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            dest,
+            constraints_from_workspace,
+            overrides_from_workspace,
+            refresh: Refresh::from(refresh),
+            settings: PipSettings::combine(
+                PipOptions {
+                    python: python.and_then(Maybe::into_option),
+                    system: flag(system, no_system, "system"),
+                    no_build: flag(no_build, build, "build"),
+                    no_binary,
+                    only_binary,
+                    no_deps: flag(no_deps, deps, "deps"),
+                    python_version,
+                    python_platform,
+                    require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                    ..PipOptions::from(resolver)
+                },
+                filesystem,
+                environment,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip uninstall` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) unneeded: bool,
+    pub(crate) yes: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -3779,6 +4059,8 @@ impl PipUninstallSettings {
             target,
             prefix,
             dry_run,
+            unneeded,
+            yes,
             compat_args: _,
         } = args;
 
@@ -3786,6 +4068,8 @@ impl PipUninstallSettings {
             package,
             requirements,
             dry_run: DryRun::from_args(dry_run),
+            unneeded,
+            yes,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -3918,6 +4202,7 @@ impl PipListSettings {
 pub(crate) struct PipShowSettings {
     pub(crate) package: Vec<PackageName>,
     pub(crate) files: bool,
+    pub(crate) why: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -3933,6 +4218,7 @@ impl PipShowSettings {
             strict,
             no_strict,
             files,
+            why,
             python,
             system,
             no_system,
@@ -3944,6 +4230,7 @@ impl PipShowSettings {
         Self {
             package,
             files,
+            why,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -4017,6 +4304,7 @@ impl PipTreeSettings {
 /// The resolved settings to use for a `pip check` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCheckSettings {
+    pub(crate) format: CheckFormat,
     pub(crate) settings: PipSettings,
 }
 
@@ -4028,6 +4316,7 @@ impl PipCheckSettings {
         environment: EnvironmentOptions,
     ) -> Self {
         let PipCheckArgs {
+            format,
             python,
             system,
             no_system,
@@ -4036,6 +4325,7 @@ impl PipCheckSettings {
         } = args;
 
         Self {
+            format,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -4104,6 +4394,7 @@ impl BuildSettings {
             build,
             refresh,
             resolver,
+            build_target: BuildTargetArgs { build_target },
         } = args;
         let filesystem_install_mirrors = match &filesystem {
             Some(fs) => fs.install_mirrors.clone(),
@@ -4151,7 +4442,8 @@ impl BuildSettings {
                 resolver_options(resolver, build),
                 filesystem,
                 &environment,
-            ),
+            )
+            .with_build_target(build_target),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -4276,11 +4568,15 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) extra_build_dependencies: &'a ExtraBuildDependencies,
     pub(crate) extra_build_variables: &'a ExtraBuildVariables,
     pub(crate) exclude_newer: &'a ExcludeNewer,
+    pub(crate) strict_exclude_newer: bool,
+    pub(crate) excluded_packages: &'a [PackageName],
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
+    pub(crate) build_runtime: &'a BuildRuntime,
     pub(crate) sources: NoSources,
+    pub(crate) yanked: YankedPolicy,
 }
 
 /// The resolved settings to use for an invocation of the uv CLI when resolving dependencies.
@@ -4290,11 +4586,17 @@ pub(crate) struct InstallerSettingsRef<'a> {
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ResolverSettings {
     pub(crate) build_options: BuildOptions,
+    pub(crate) build_runtime: BuildRuntime,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) exclude_newer: ExcludeNewer,
+    pub(crate) strict_exclude_newer: bool,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) max_metadata_builds: Option<usize>,
+    pub(crate) resolution_effort: Option<usize>,
+    pub(crate) excluded_packages: Vec<PackageName>,
+    pub(crate) reresolve_metadata: Vec<PackageName>,
     pub(crate) index_locations: IndexLocations,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
@@ -4304,6 +4606,10 @@ pub(crate) struct ResolverSettings {
     pub(crate) extra_build_variables: ExtraBuildVariables,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: PackageResolutionModes,
+    pub(crate) prerelease_package: PackagePrereleaseModes,
+    pub(crate) local_version_preference: PackageLocalVersionPreferences,
+    pub(crate) yanked: YankedPolicy,
     pub(crate) sources: NoSources,
     pub(crate) torch_backend: Option<TorchMode>,
     pub(crate) cuda_driver_version: Option<Version>,
@@ -4312,6 +4618,13 @@ pub(crate) struct ResolverSettings {
 }
 
 impl ResolverSettings {
+    /// Build wheels for the given platform, if not the current platform (e.g., `--build-target`).
+    #[must_use]
+    fn with_build_target(mut self, build_target: Option<BuildTarget>) -> Self {
+        self.build_runtime = self.build_runtime.with_target(build_target);
+        self
+    }
+
     /// Resolve the [`ResolverSettings`] from the CLI and filesystem configuration.
     fn combine(
         mut args: ResolverOptions,
@@ -4366,8 +4679,16 @@ impl From<ResolverOptions> for ResolverSettings {
         Self {
             index_locations,
             resolution: value.resolution.unwrap_or_default(),
+            resolution_package: value.resolution_package.unwrap_or_default(),
             prerelease: value.prerelease.unwrap_or_default(),
+            prerelease_package: value.prerelease_package.unwrap_or_default(),
+            local_version_preference: value.local_version_preference.unwrap_or_default(),
+            yanked: value.yanked.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            max_metadata_builds: value.max_metadata_builds,
+            resolution_effort: value.resolution_effort,
+            excluded_packages: value.excluded_packages.unwrap_or_default(),
+            reresolve_metadata: value.reresolve_metadata.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -4377,7 +4698,10 @@ impl From<ResolverOptions> for ResolverSettings {
             config_settings_package: value.config_settings_package.unwrap_or_default(),
             build_isolation: value.build_isolation.unwrap_or_default(),
             extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
-            extra_build_variables: value.extra_build_variables.unwrap_or_default(),
+            extra_build_variables: value
+                .extra_build_variables
+                .unwrap_or_default()
+                .with_build_env(value.build_env),
             exclude_newer: ExcludeNewer::from_args(
                 value.exclude_newer,
                 value
@@ -4387,6 +4711,7 @@ impl From<ResolverOptions> for ResolverSettings {
                     .map(Into::into)
                     .collect(),
             ),
+            strict_exclude_newer: value.strict_exclude_newer.unwrap_or_default(),
             link_mode: value.link_mode.unwrap_or_default(),
             torch_backend: value.torch_backend,
             cuda_driver_version: None,
@@ -4400,6 +4725,22 @@ impl From<ResolverOptions> for ResolverSettings {
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
             ),
+            build_runtime: BuildRuntime::default()
+                .with_timeout(
+                    value
+                        .build_timeout
+                        .map(|seconds| Duration::from_secs(seconds.get())),
+                )
+                .with_retries(value.build_retries.unwrap_or_default())
+                .with_build_python(value.build_python)
+                .with_show_warnings(value.show_build_warnings.unwrap_or_default())
+                .with_warning_patterns(value.build_warning_pattern.unwrap_or_default())
+                .with_events_file(value.build_events_file)
+                .with_keep_logs(value.keep_build_logs.unwrap_or_default())
+                .with_keep_environment(value.keep_build_env.unwrap_or_default())
+                .with_build_dir(value.build_dir)
+                .with_via_sdist(value.build_via_sdist.unwrap_or_default())
+                .with_no_environment_reuse(value.no_build_environment_reuse.unwrap_or_default()),
         }
     }
 }
@@ -4487,6 +4828,24 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                     ),
                     NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
                 ),
+                build_runtime: BuildRuntime::default()
+                    .with_timeout(
+                        value
+                            .build_timeout
+                            .map(|seconds| Duration::from_secs(seconds.get())),
+                    )
+                    .with_retries(value.build_retries.unwrap_or_default())
+                    .with_build_python(value.build_python)
+                    .with_show_warnings(value.show_build_warnings.unwrap_or_default())
+                    .with_warning_patterns(value.build_warning_pattern.unwrap_or_default())
+                    .with_events_file(value.build_events_file)
+                    .with_keep_logs(value.keep_build_logs.unwrap_or_default())
+                    .with_keep_environment(value.keep_build_env.unwrap_or_default())
+                    .with_build_dir(value.build_dir)
+                    .with_via_sdist(value.build_via_sdist.unwrap_or_default())
+                    .with_no_environment_reuse(
+                        value.no_build_environment_reuse.unwrap_or_default(),
+                    ),
                 config_setting: value.config_settings.unwrap_or_default(),
                 config_settings_package: value.config_settings_package.unwrap_or_default(),
                 dependency_metadata: DependencyMetadata::from_entries(
@@ -4501,16 +4860,28 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .map(Into::into)
                         .collect(),
                 ),
+                strict_exclude_newer: value.strict_exclude_newer.unwrap_or_default(),
                 fork_strategy: value.fork_strategy.unwrap_or_default(),
+                max_metadata_builds: value.max_metadata_builds,
+                resolution_effort: value.resolution_effort,
+                excluded_packages: value.excluded_packages.unwrap_or_default(),
+                reresolve_metadata: value.reresolve_metadata.unwrap_or_default(),
                 index_locations,
                 index_strategy: value.index_strategy.unwrap_or_default(),
                 keyring_provider: value.keyring_provider.unwrap_or_default(),
                 link_mode: value.link_mode.unwrap_or_default(),
                 build_isolation: value.build_isolation.unwrap_or_default(),
                 extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
-                extra_build_variables: value.extra_build_variables.unwrap_or_default(),
+                extra_build_variables: value
+                    .extra_build_variables
+                    .unwrap_or_default()
+                    .with_build_env(value.build_env),
                 prerelease: value.prerelease.unwrap_or_default(),
                 resolution: value.resolution.unwrap_or_default(),
+                resolution_package: value.resolution_package.unwrap_or_default(),
+                prerelease_package: value.prerelease_package.unwrap_or_default(),
+                local_version_preference: value.local_version_preference.unwrap_or_default(),
+                yanked: value.yanked.unwrap_or_default(),
                 sources: NoSources::from_args(
                     value.no_sources,
                     value.no_sources_package.unwrap_or_default(),
@@ -4550,12 +4921,21 @@ pub(crate) struct PipSettings {
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
     pub(crate) build_options: BuildOptions,
+    pub(crate) build_runtime: BuildRuntime,
     pub(crate) allow_empty_requirements: bool,
     pub(crate) strict: bool,
     pub(crate) dependency_mode: DependencyMode,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_package: PackageResolutionModes,
     pub(crate) prerelease: PrereleaseMode,
+    pub(crate) prerelease_package: PackagePrereleaseModes,
+    pub(crate) local_version_preference: PackageLocalVersionPreferences,
+    pub(crate) yanked: YankedPolicy,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) max_metadata_builds: Option<usize>,
+    pub(crate) resolution_effort: Option<usize>,
+    pub(crate) excluded_packages: Vec<PackageName>,
+    pub(crate) reresolve_metadata: Vec<PackageName>,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -4563,13 +4943,17 @@ pub(crate) struct PipSettings {
     pub(crate) no_annotate: bool,
     pub(crate) no_header: bool,
     pub(crate) custom_compile_command: Option<String>,
+    pub(crate) custom_header: Option<String>,
     pub(crate) generate_hashes: bool,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
     pub(crate) python_version: Option<PythonVersion>,
     pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) python_implementation: Option<TargetImplementation>,
+    pub(crate) python_abiflags: Option<TargetAbiFlags>,
     pub(crate) universal: bool,
     pub(crate) exclude_newer: ExcludeNewer,
+    pub(crate) strict_exclude_newer: bool,
     pub(crate) no_emit_package: Vec<PackageName>,
     pub(crate) emit_index_url: bool,
     pub(crate) emit_find_links: bool,
@@ -4586,6 +4970,13 @@ pub(crate) struct PipSettings {
 }
 
 impl PipSettings {
+    /// Build wheels for the given platform, if not the current platform (e.g., `--build-target`).
+    #[must_use]
+    fn with_build_target(mut self, build_target: Option<BuildTarget>) -> Self {
+        self.build_runtime = self.build_runtime.with_target(build_target);
+        self
+    }
+
     /// Resolve the [`PipSettings`] from the CLI and filesystem configuration.
     fn combine(
         args: PipOptions,
@@ -4620,8 +5011,20 @@ impl PipSettings {
             only_binary,
             no_build_isolation,
             no_build_isolation_package,
+            build_timeout,
+            build_retries,
+            build_python,
+            show_build_warnings,
+            build_warning_pattern,
+            build_events_file,
+            keep_build_logs,
+            keep_build_env,
+            build_dir,
+            build_via_sdist,
+            no_build_environment_reuse,
             extra_build_dependencies,
             extra_build_variables,
+            build_env,
             strict,
             extra,
             all_extras,
@@ -4630,8 +5033,16 @@ impl PipSettings {
             no_deps,
             allow_empty_requirements,
             resolution,
+            resolution_package,
             prerelease,
+            prerelease_package,
+            local_version_preference,
+            yanked,
             fork_strategy,
+            max_metadata_builds,
+            resolution_effort,
+            excluded_packages,
+            reresolve_metadata,
             dependency_metadata,
             output_file,
             no_strip_extras,
@@ -4639,14 +5050,18 @@ impl PipSettings {
             no_annotate,
             no_header,
             custom_compile_command,
+            custom_header,
             generate_hashes,
             config_settings,
             config_settings_package,
             python_version,
             python_platform,
+            python_implementation,
+            python_abiflags,
             universal,
             exclude_newer,
             no_emit_package,
+            allow_unsafe,
             emit_index_url,
             emit_find_links,
             emit_build_options,
@@ -4664,6 +5079,7 @@ impl PipSettings {
             reinstall,
             reinstall_package,
             exclude_newer_package,
+            strict_exclude_newer,
         } = pip.unwrap_or_default();
 
         let ResolverInstallerSchema {
@@ -4675,15 +5091,35 @@ impl PipSettings {
             index_strategy: top_level_index_strategy,
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
+            resolution_package: top_level_resolution_package,
             prerelease: top_level_prerelease,
+            prerelease_package: top_level_prerelease_package,
+            local_version_preference: top_level_local_version_preference,
+            yanked: top_level_yanked,
             fork_strategy: top_level_fork_strategy,
+            max_metadata_builds: top_level_max_metadata_builds,
+            resolution_effort: top_level_resolution_effort,
+            excluded_packages: top_level_excluded_packages,
+            reresolve_metadata: top_level_reresolve_metadata,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
             no_build_isolation: top_level_no_build_isolation,
             no_build_isolation_package: top_level_no_build_isolation_package,
+            build_timeout: top_level_build_timeout,
+            build_retries: top_level_build_retries,
+            build_python: top_level_build_python,
+            show_build_warnings: top_level_show_build_warnings,
+            build_warning_pattern: top_level_build_warning_pattern,
+            build_events_file: top_level_build_events_file,
+            keep_build_logs: top_level_keep_build_logs,
+            keep_build_env: top_level_keep_build_env,
+            build_dir: top_level_build_dir,
+            build_via_sdist: top_level_build_via_sdist,
+            no_build_environment_reuse: top_level_no_build_environment_reuse,
             extra_build_dependencies: top_level_extra_build_dependencies,
             extra_build_variables: top_level_extra_build_variables,
+            build_env: top_level_build_env,
             exclude_newer: top_level_exclude_newer,
             link_mode: top_level_link_mode,
             compile_bytecode: top_level_compile_bytecode,
@@ -4698,6 +5134,7 @@ impl PipSettings {
             no_binary: top_level_no_binary,
             no_binary_package: top_level_no_binary_package,
             exclude_newer_package: top_level_exclude_newer_package,
+            strict_exclude_newer: top_level_strict_exclude_newer,
             torch_backend: top_level_torch_backend,
         } = top_level;
 
@@ -4713,8 +5150,17 @@ impl PipSettings {
         let index_strategy = index_strategy.combine(top_level_index_strategy);
         let keyring_provider = keyring_provider.combine(top_level_keyring_provider);
         let resolution = resolution.combine(top_level_resolution);
+        let resolution_package = resolution_package.combine(top_level_resolution_package);
         let prerelease = prerelease.combine(top_level_prerelease);
+        let prerelease_package = prerelease_package.combine(top_level_prerelease_package);
+        let local_version_preference =
+            local_version_preference.combine(top_level_local_version_preference);
+        let yanked = yanked.combine(top_level_yanked);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let max_metadata_builds = max_metadata_builds.combine(top_level_max_metadata_builds);
+        let resolution_effort = resolution_effort.combine(top_level_resolution_effort);
+        let excluded_packages = excluded_packages.combine(top_level_excluded_packages);
+        let reresolve_metadata = reresolve_metadata.combine(top_level_reresolve_metadata);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
@@ -4722,9 +5168,22 @@ impl PipSettings {
         let no_build_isolation = no_build_isolation.combine(top_level_no_build_isolation);
         let no_build_isolation_package =
             no_build_isolation_package.combine(top_level_no_build_isolation_package);
+        let build_timeout = build_timeout.combine(top_level_build_timeout);
+        let build_retries = build_retries.combine(top_level_build_retries);
+        let build_python = build_python.combine(top_level_build_python);
+        let show_build_warnings = show_build_warnings.combine(top_level_show_build_warnings);
+        let build_warning_pattern = build_warning_pattern.combine(top_level_build_warning_pattern);
+        let build_events_file = build_events_file.combine(top_level_build_events_file);
+        let keep_build_logs = keep_build_logs.combine(top_level_keep_build_logs);
+        let keep_build_env = keep_build_env.combine(top_level_keep_build_env);
+        let build_dir = build_dir.combine(top_level_build_dir);
+        let build_via_sdist = build_via_sdist.combine(top_level_build_via_sdist);
+        let no_build_environment_reuse =
+            no_build_environment_reuse.combine(top_level_no_build_environment_reuse);
         let extra_build_dependencies =
             extra_build_dependencies.combine(top_level_extra_build_dependencies);
         let extra_build_variables = extra_build_variables.combine(top_level_extra_build_variables);
+        let build_env = build_env.combine(top_level_build_env);
         let exclude_newer = args
             .exclude_newer
             .combine(exclude_newer)
@@ -4734,6 +5193,11 @@ impl PipSettings {
             .combine(exclude_newer_package)
             .combine(top_level_exclude_newer_package)
             .unwrap_or_default();
+        let strict_exclude_newer = args
+            .strict_exclude_newer
+            .combine(strict_exclude_newer)
+            .combine(top_level_strict_exclude_newer)
+            .unwrap_or_default();
         let link_mode = link_mode.combine(top_level_link_mode);
         let compile_bytecode = compile_bytecode.combine(top_level_compile_bytecode);
         let no_sources = no_sources.combine(top_level_no_sources);
@@ -4783,11 +5247,34 @@ impl PipSettings {
                 DependencyMode::Transitive
             },
             resolution: args.resolution.combine(resolution).unwrap_or_default(),
+            resolution_package: args
+                .resolution_package
+                .combine(resolution_package)
+                .unwrap_or_default(),
             prerelease: args.prerelease.combine(prerelease).unwrap_or_default(),
+            prerelease_package: args
+                .prerelease_package
+                .combine(prerelease_package)
+                .unwrap_or_default(),
+            local_version_preference: args
+                .local_version_preference
+                .combine(local_version_preference)
+                .unwrap_or_default(),
+            yanked: args.yanked.combine(yanked).unwrap_or_default(),
             fork_strategy: args
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            max_metadata_builds: args.max_metadata_builds.combine(max_metadata_builds),
+            resolution_effort: args.resolution_effort.combine(resolution_effort),
+            excluded_packages: args
+                .excluded_packages
+                .combine(excluded_packages)
+                .unwrap_or_default(),
+            reresolve_metadata: args
+                .reresolve_metadata
+                .combine(reresolve_metadata)
+                .unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
@@ -4805,6 +5292,7 @@ impl PipSettings {
             no_annotate: args.no_annotate.combine(no_annotate).unwrap_or_default(),
             no_header: args.no_header.combine(no_header).unwrap_or_default(),
             custom_compile_command: args.custom_compile_command.combine(custom_compile_command),
+            custom_header: args.custom_header.combine(custom_header),
             annotation_style: args
                 .annotation_style
                 .combine(annotation_style)
@@ -4841,7 +5329,8 @@ impl PipSettings {
             extra_build_variables: args
                 .extra_build_variables
                 .combine(extra_build_variables)
-                .unwrap_or_default(),
+                .unwrap_or_default()
+                .with_build_env(args.build_env.combine(build_env)),
             config_setting: args
                 .config_settings
                 .combine(config_settings)
@@ -4855,15 +5344,27 @@ impl PipSettings {
             amd_gpu_architecture: environment.amd_gpu_architecture,
             python_version: args.python_version.combine(python_version),
             python_platform: args.python_platform.combine(python_platform),
+            python_implementation: args.python_implementation.combine(python_implementation),
+            python_abiflags: args.python_abiflags.combine(python_abiflags),
             universal: args.universal.combine(universal).unwrap_or_default(),
             exclude_newer: ExcludeNewer::from_args(
                 exclude_newer,
                 exclude_newer_package.into_iter().map(Into::into).collect(),
             ),
-            no_emit_package: args
-                .no_emit_package
-                .combine(no_emit_package)
-                .unwrap_or_default(),
+            strict_exclude_newer,
+            no_emit_package: match args.allow_unsafe.combine(allow_unsafe) {
+                // Include every package, as in pip-compile's `--allow-unsafe`.
+                Some(true) => Vec::new(),
+                // Omit the "unsafe" packages, falling back to pip-compile's defaults.
+                Some(false) => args
+                    .no_emit_package
+                    .combine(no_emit_package)
+                    .unwrap_or_else(default_unsafe_packages),
+                None => args
+                    .no_emit_package
+                    .combine(no_emit_package)
+                    .unwrap_or_default(),
+            },
             emit_index_url: args
                 .emit_index_url
                 .combine(emit_index_url)
@@ -4951,6 +5452,50 @@ impl PipSettings {
                     top_level_no_build_package.unwrap_or_default(),
                 )),
             ),
+            build_runtime: BuildRuntime::default()
+                .with_timeout(
+                    args.build_timeout
+                        .combine(build_timeout)
+                        .map(|seconds| Duration::from_secs(seconds.get())),
+                )
+                .with_retries(
+                    args.build_retries
+                        .combine(build_retries)
+                        .unwrap_or_default(),
+                )
+                .with_build_python(args.build_python.combine(build_python))
+                .with_show_warnings(
+                    args.show_build_warnings
+                        .combine(show_build_warnings)
+                        .unwrap_or_default(),
+                )
+                .with_warning_patterns(
+                    args.build_warning_pattern
+                        .combine(build_warning_pattern)
+                        .unwrap_or_default(),
+                )
+                .with_events_file(args.build_events_file.combine(build_events_file))
+                .with_keep_logs(
+                    args.keep_build_logs
+                        .combine(keep_build_logs)
+                        .unwrap_or_default(),
+                )
+                .with_keep_environment(
+                    args.keep_build_env
+                        .combine(keep_build_env)
+                        .unwrap_or_default(),
+                )
+                .with_build_dir(args.build_dir.combine(build_dir))
+                .with_via_sdist(
+                    args.build_via_sdist
+                        .combine(build_via_sdist)
+                        .unwrap_or_default(),
+                )
+                .with_no_environment_reuse(
+                    args.no_build_environment_reuse
+                        .combine(no_build_environment_reuse)
+                        .unwrap_or_default(),
+                ),
             install_mirrors: environment
                 .install_mirrors
                 .combine(filesystem_install_mirrors),
@@ -4958,6 +5503,14 @@ impl PipSettings {
     }
 }
 
+/// The packages that pip-compile considers "unsafe" to pin in a requirements file by default.
+fn default_unsafe_packages() -> Vec<PackageName> {
+    ["pip", "setuptools", "wheel"]
+        .into_iter()
+        .map(|name| PackageName::from_str(name).expect("valid package name"))
+        .collect()
+}
+
 impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
     fn from(settings: &'a ResolverInstallerSettings) -> Self {
         Self {
@@ -4971,11 +5524,15 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
             extra_build_dependencies: &settings.resolver.extra_build_dependencies,
             extra_build_variables: &settings.resolver.extra_build_variables,
             exclude_newer: &settings.resolver.exclude_newer,
+            strict_exclude_newer: settings.resolver.strict_exclude_newer,
+            excluded_packages: &settings.resolver.excluded_packages,
             link_mode: settings.resolver.link_mode,
             compile_bytecode: settings.compile_bytecode,
             reinstall: &settings.reinstall,
             build_options: &settings.resolver.build_options,
+            build_runtime: &settings.resolver.build_runtime,
             sources: settings.resolver.sources.clone(),
+            yanked: settings.resolver.yanked,
         }
     }
 }
@@ -247,6 +247,130 @@ fn build_sdist_missing_backend_path() -> Result<()> {
     Ok(())
 }
 
+/// An in-tree build backend can span multiple `backend-path` entries, which are resolved relative
+/// to the source tree and added to `sys.path` in order.
+#[test]
+fn build_backend_path_multiple_entries() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        description = "A test project"
+
+        [build-system]
+        requires = ["flit_core >=3.4,<4"]
+        build-backend = "backend"
+        backend-path = ["_custom_build", "vendor/backend"]
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+    project.child("_custom_build/backend.py").write_str(
+        "from vendored import *
+",
+    )?;
+    project.child("vendor/backend/vendored.py").write_str(
+        "from flit_core.buildapi import *
+",
+    )?;
+
+    // Build from outside the source tree, such that relative entries can't be resolved against
+    // the working directory.
+    uv_snapshot!(context.filters(), context.build().arg("--wheel").arg(project.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    ");
+
+    Ok(())
+}
+
+/// An in-tree build backend can be a package that imports its own submodules.
+#[test]
+fn build_backend_path_nested_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        description = "A test project"
+
+        [build-system]
+        requires = ["flit_core >=3.4,<4"]
+        build-backend = "buildpkg.backend:api"
+        backend-path = ["tools"]
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+    project.child("tools/buildpkg/__init__.py").touch()?;
+    project.child("tools/buildpkg/backend.py").write_str(
+        "from ._impl import api
+",
+    )?;
+    project.child("tools/buildpkg/_impl.py").write_str(
+        "from flit_core import buildapi as api
+",
+    )?;
+
+    uv_snapshot!(context.filters(), context.build().arg("--wheel").arg(project.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    ");
+
+    Ok(())
+}
+
+/// A `backend-path` entry must refer to a location within the source tree (per PEP 517).
+#[test]
+fn build_backend_path_outside_source_tree() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        description = "A test project"
+
+        [build-system]
+        requires = ["flit_core >=3.4,<4"]
+        build-backend = "backend"
+        backend-path = [".", "../outside"]
+    "#})?;
+    project.child("src/project/__init__.py").touch()?;
+    context.temp_dir.child("outside/backend.py").write_str(
+        "from flit_core.buildapi import *
+",
+    )?;
+
+    uv_snapshot!(context.filters(), context.build().arg("--wheel").arg(project.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+      × Failed to build `[TEMP_DIR]/project`
+      ╰─▶ `backend-path` entry `../outside` refers to a location outside the source tree
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn build_sdist() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -528,7 +652,7 @@ fn build_wheel_from_sdist() -> Result<()> {
 
 #[test]
 fn build_fail() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
     let filters = context
         .filters()
         .into_iter()
@@ -595,6 +719,7 @@ fn build_fail() -> Result<()> {
       × Failed to build `[TEMP_DIR]/project`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_sdist` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
     hint: Build failures usually indicate a problem with the package or the build environment
     "#);
@@ -602,6 +727,87 @@ fn build_fail() -> Result<()> {
     Ok(())
 }
 
+/// Builds fail early if the interpreter doesn't satisfy the package's `requires-python`, unless
+/// an interpreter for builds is selected with `--build-python`.
+#[test]
+fn build_requires_python() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.12", "3.11"]);
+
+    let project = context.in_tree_backend(
+        "project",
+        "0.1.0",
+        indoc! {r#"
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                print(f"Building with Python {sys.version_info[0]}.{sys.version_info[1]}", file=sys.stderr)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = "<3.12"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.build().arg("--wheel").arg("--python").arg("3.12").arg("project"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+      × Failed to build `[TEMP_DIR]/project`
+      ╰─▶ `project@0.1.0` requires Python <3.12, but the build environment uses Python 3.12.[X]
+
+    hint: To build with a compatible Python version, select an interpreter for builds with `--build-python` (e.g., `--build-python "<3.12"`)
+    "#);
+
+    uv_snapshot!(context.filters(), context.build().arg("--wheel").arg("--python").arg("3.12").arg("--build-python").arg("3.11").arg("project"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+    Building with Python 3.11
+    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    ");
+
+    // With `any`, a compatible interpreter is selected.
+    uv_snapshot!(context.filters(), context.build().arg("--wheel").arg("--python").arg("3.12").arg("--build-python").arg("any").arg("project"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+    Building with Python 3.11
+    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    ");
+
+    // The requested interpreter must be compatible, too.
+    uv_snapshot!(context.filters(), context.build().arg("--wheel").arg("--python").arg("3.12").arg("--build-python").arg("3.12").arg("project"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+      × Failed to build `[TEMP_DIR]/project`
+      ╰─▶ `project@0.1.0` requires Python <3.12, but the build environment uses Python 3.12.[X]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn build_workspace() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -809,7 +1015,7 @@ fn build_workspace() -> Result<()> {
 
 #[test]
 fn build_all_with_failure() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
     let filters = context
         .filters()
         .into_iter()
@@ -926,6 +1132,7 @@ fn build_all_with_failure() -> Result<()> {
       × Failed to build `member-b @ [TEMP_DIR]/project/packages/member_b`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_sdist` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
     hint: Build failures usually indicate a problem with the package or the build environment
     Successfully built dist/project-0.1.0.tar.gz
@@ -1566,8 +1773,7 @@ async fn build_transitive_url_build_requirement_hashes() -> Result<()> {
         .mount(&server)
         .await;
 
-    let project = context.temp_dir.child("project");
-
+    let project = context.in_tree_backend("project", "0.1.0", "")?;
     project.child("pyproject.toml").write_str(&formatdoc! {r#"
         [project]
         name = "project"
@@ -1584,37 +1790,6 @@ async fn build_transitive_url_build_requirement_hashes() -> Result<()> {
         version = "1.0.0"
         requires-dist = ["ok @ {ok_wheel_url}#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f"]
     "#})?;
-    project.child("backend.py").write_str(indoc! {r#"
-        import pathlib
-        import zipfile
-
-
-        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
-            wheel_name = "project-0.1.0-py3-none-any.whl"
-            wheel_path = pathlib.Path(wheel_directory, wheel_name)
-            records = [
-                ("project/__init__.py", b""),
-                (
-                    "project-0.1.0.dist-info/METADATA",
-                    b"Metadata-Version: 2.1\nName: project\nVersion: 0.1.0\n",
-                ),
-                (
-                    "project-0.1.0.dist-info/WHEEL",
-                    b"Wheel-Version: 1.0\nGenerator: uv-test\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
-                ),
-            ]
-
-            with zipfile.ZipFile(wheel_path, "w") as wheel:
-                for path, contents in records:
-                    wheel.writestr(path, contents)
-                record = "\n".join(f"{path},," for path, _ in records)
-                wheel.writestr(
-                    "project-0.1.0.dist-info/RECORD",
-                    record + "\nproject-0.1.0.dist-info/RECORD,,\n",
-                )
-
-            return wheel_name
-    "#})?;
     uv_snapshot!(
         &filters,
         context
@@ -1770,7 +1945,7 @@ fn build_hide_build_output_env_var() -> Result<()> {
 /// Test that `UV_HIDE_BUILD_OUTPUT` hides build output even on failure.
 #[test]
 fn build_hide_build_output_on_failure() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
     let filters = context
         .filters()
         .into_iter()
@@ -1813,6 +1988,7 @@ fn build_hide_build_output_on_failure() -> Result<()> {
       × Failed to build `[TEMP_DIR]/project`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_sdist` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
     hint: Build failures usually indicate a problem with the package or the build environment
     ");
@@ -2759,7 +2935,9 @@ fn build_with_nonnormalized_name() -> Result<()> {
 #[test]
 fn force_pep517() -> Result<()> {
     // We need to use a real `uv_build` package.
-    let context = uv_test::test_context!("3.12").with_exclude_newer("2025-05-27T00:00:00Z");
+    let context = uv_test::test_context!("3.12")
+        .with_exclude_newer("2025-05-27T00:00:00Z")
+        .with_filtered_build_logs();
 
     context.init().assert().success();
 
@@ -2799,6 +2977,7 @@ fn force_pep517() -> Result<()> {
       × Failed to build `[TEMP_DIR]/`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `uv_build.build_sdist` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
     hint: Build failures usually indicate a problem with the package or the build environment
     ");
@@ -3043,3 +3222,96 @@ fn build_no_gitignore() -> Result<()> {
 
     Ok(())
 }
+
+/// With `--build-target`, the build backend is instructed to build for the target platform, and
+/// the tags of the built wheel are validated against the target.
+#[test]
+fn build_target() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // An in-tree backend that tags the wheel with `PLAT` (or a fixed tag, if `WRONG_TAG` is set),
+    // recording the environment variables it was called with.
+    let project = context.in_tree_backend(
+        "project",
+        "0.1.0",
+        indoc! {r#"
+            import os
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                with open("env.txt", "w") as fp:
+                    for key in ["_PYTHON_HOST_PLATFORM", "ARCHFLAGS", "PLAT"]:
+                        fp.write(f"{key}={os.environ.get(key)}\n")
+                platform = os.environ.get("WRONG_TAG") or os.environ["PLAT"]
+                return write_wheel(wheel_directory, tag=f"cp312-cp312-{platform}")
+        "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--wheel")
+        .arg("--build-target")
+        .arg("macosx_11_0_arm64")
+        .arg(project.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+    Successfully built project/dist/project-0.1.0-cp312-cp312-macosx_11_0_arm64.whl
+    ");
+
+    assert_snapshot!(fs_err::read_to_string(project.child("env.txt"))?, @"
+    _PYTHON_HOST_PLATFORM=macosx-11.0-arm64
+    ARCHFLAGS=-arch arm64
+    PLAT=macosx_11_0_arm64
+    ");
+
+    // A wheel that doesn't match the target is rejected.
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--wheel")
+        .arg(project.path())
+        .env(EnvVars::UV_BUILD_TARGET, "manylinux_2_28_aarch64")
+        .env("WRONG_TAG", "win_amd64"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Building wheel...
+      × Failed to build `[TEMP_DIR]/project`
+      ╰─▶ The built wheel `project-0.1.0-cp312-cp312-win_amd64.whl` is tagged for `cp312-win_amd64`, which is incompatible with the build target `manylinux_2_28_aarch64`
+
+    hint: The build backend may not support cross-compilation, or may require additional configuration (e.g., a cross-compiler) to build for the target. Build the package on the target platform, or remove `--build-target`
+    ");
+
+    // Unsupported targets are rejected.
+    uv_snapshot!(context.filters(), context.build()
+        .arg("--wheel")
+        .arg("--build-target")
+        .arg("freebsd_14_0_amd64")
+        .arg(project.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'freebsd_14_0_amd64' for '--build-target <PLATFORM_TAG>': `freebsd_14_0_amd64` is not a supported build target; expected a Linux (`manylinux`, `musllinux`, or `linux`), macOS (`arm64` or `x86_64`), or Windows platform tag
+
+    For more information, try '--help'.
+    ");
+
+    // Commands that install what they build don't accept a build target.
+    context
+        .pip_install()
+        .arg("--build-target")
+        .arg("macosx_11_0_arm64")
+        .arg(project.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unexpected argument '--build-target' found",
+        ));
+
+    Ok(())
+}
@@ -949,6 +949,138 @@ fn symlinked_file() -> Result<()> {
     Ok(())
 }
 
+/// Test that PEP 639 license metadata round-trips through the source distribution and the wheel,
+/// with the license expression normalized and the license files copied into `licenses/`.
+#[test]
+fn pep639_license_files() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let project = context.temp_dir.child("project");
+    context
+        .init()
+        .arg("--lib")
+        .arg(project.path())
+        .assert()
+        .success();
+
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "1.0.0"
+        license = "apache-2.0 or mit"
+        license-files = ["LICENSE", "NOTICE*", "licenses/**/*.txt"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#
+    })?;
+    project.child("LICENSE").write_str("Apache License")?;
+    project.child("NOTICE").write_str("Notice")?;
+    project.child("NOTICE.md").write_str("Notice (Markdown)")?;
+    project
+        .child("licenses")
+        .child("vendored")
+        .child("LICENSE.txt")
+        .write_str("MIT License")?;
+    // Not matched by any glob.
+    project.child("licenses").child("README.md").write_str("")?;
+
+    context
+        .build_backend()
+        .arg("build-sdist")
+        .arg(context.temp_dir.path())
+        .current_dir(project.path())
+        .assert()
+        .success();
+    context
+        .build_backend()
+        .arg("build-wheel")
+        .arg(context.temp_dir.path())
+        .current_dir(project.path())
+        .assert()
+        .success();
+
+    // The source distribution contains the license files, and declares them in `PKG-INFO`.
+    let sdist = context.temp_dir.child("sdist");
+    unpack_tar_gz(
+        &context.temp_dir.path().join("project-1.0.0.tar.gz"),
+        sdist.path(),
+    )?;
+    let sdist_root = sdist.path().join("project-1.0.0");
+    assert_snapshot!(fs_err::read_to_string(sdist_root.join("PKG-INFO"))?, @"
+    Metadata-Version: 2.4
+    Name: project
+    Version: 1.0.0
+    License-Expression: Apache-2.0 OR MIT
+    License-File: LICENSE
+    License-File: NOTICE
+    License-File: NOTICE.md
+    License-File: licenses/vendored/LICENSE.txt
+    ");
+    for license_file in [
+        "LICENSE",
+        "NOTICE",
+        "NOTICE.md",
+        "licenses/vendored/LICENSE.txt",
+    ] {
+        assert!(sdist_root.join(license_file).is_file(), "{license_file}");
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install().arg("project-1.0.0-py3-none-any.whl"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==1.0.0 (from file://[TEMP_DIR]/project-1.0.0-py3-none-any.whl)
+    ");
+
+    // The installed wheel contains the license files in `.dist-info/licenses`, and declares them
+    // in `METADATA`.
+    let dist_info = context.site_packages().join("project-1.0.0.dist-info");
+    assert_snapshot!(fs_err::read_to_string(dist_info.join("METADATA"))?, @"
+    Metadata-Version: 2.4
+    Name: project
+    Version: 1.0.0
+    License-Expression: Apache-2.0 OR MIT
+    License-File: LICENSE
+    License-File: NOTICE
+    License-File: NOTICE.md
+    License-File: licenses/vendored/LICENSE.txt
+    ");
+    let licenses = dist_info.join("licenses");
+    let mut license_files: Vec<_> = walkdir::WalkDir::new(&licenses)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| {
+            entry
+                .path()
+                .strip_prefix(&licenses)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect();
+    license_files.sort();
+    assert_snapshot!(license_files.join("\n"), @"
+    LICENSE
+    NOTICE
+    NOTICE.md
+    licenses/vendored/LICENSE.txt
+    ");
+    assert_eq!(
+        fs_err::read_to_string(licenses.join("licenses/vendored/LICENSE.txt"))?,
+        "MIT License"
+    );
+
+    Ok(())
+}
+
 /// Ignore invalid build backend settings when not building.
 ///
 /// They may be from another `uv_build` version that has a different schema.
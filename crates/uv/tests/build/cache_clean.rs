@@ -1,12 +1,42 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 
 use uv_cache::Cache;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_static::EnvVars;
 
+use uv_test::packse::PackseServer;
+use uv_test::packse::scenario::{Package, PackageMetadata, Scenario};
 use uv_test::uv_snapshot;
 
+/// Serve a local index containing a single wheel, `foo==1.0.0`.
+fn foo_index() -> Result<PackseServer> {
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    Ok(PackseServer::from_scenario(&scenario))
+}
+
+/// Return the number of entries in the archive bucket.
+fn archive_count(context: &uv_test::TestContext) -> Result<usize> {
+    Ok(fs_err::read_dir(context.cache_dir.child("archive-v0").path())?.count())
+}
+
 /// `cache clean` should remove all packages.
 #[test]
 fn clean_all() -> Result<()> {
@@ -367,3 +397,183 @@ fn clean_handles_verbatim_paths() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache clean --dry-run` should list the entries that would be removed, without removing them.
+#[test]
+fn clean_dry_run() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let server = foo_index()?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("foo")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The index entries do not have stable keys, so we filter them out.
+            (r"index/[a-f0-9]+/", "index/[INDEX]/"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.clean().arg("--dry-run").arg("foo"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would remove 3 entries from cache at: [CACHE_DIR]/ ([SIZE])
+      archive-v0/[HASH] ([SIZE])
+      simple-v21/index/[INDEX]/foo.rkyv ([SIZE])
+      wheels-v6/index/[INDEX]/foo ([SIZE])
+    ");
+
+    // Nothing should be removed.
+    assert_eq!(archive_count(&context)?, 1);
+
+    // The JSON output should include the path and size of each entry.
+    let output = context
+        .clean()
+        .arg("--dry-run")
+        .arg("--output-format")
+        .arg("json")
+        .arg("foo")
+        .output()?;
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let entries = report["entries"]
+        .as_array()
+        .expect("entries should be an array");
+    assert_eq!(entries.len(), 3);
+    assert_eq!(
+        report["total_bytes"].as_u64(),
+        Some(
+            entries
+                .iter()
+                .map(|entry| entry["size"].as_u64().unwrap())
+                .sum()
+        )
+    );
+    assert_eq!(report["preserved"].as_array().map(Vec::len), Some(0));
+
+    // The JSON output requires `--dry-run`.
+    uv_snapshot!(context.filters(), context.clean().arg("--output-format").arg("json"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --dry-run
+
+    Usage: uv cache clean --dry-run --cache-dir [CACHE_DIR] --output-format <OUTPUT_FORMAT> [PACKAGE]...
+
+    For more information, try '--help'.
+    ");
+
+    // Removing the package should remove the same number of entries.
+    uv_snapshot!(&filters, context.clean().arg("foo"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed [N] files ([SIZE])
+    ");
+    assert_eq!(archive_count(&context)?, 0);
+
+    Ok(())
+}
+
+/// `cache clean --preserve-referenced` should retain the archives linked into a project
+/// environment, along with the wheel cache entries that point to them.
+///
+/// Hard links are only detected on Unix.
+#[cfg(unix)]
+#[test]
+fn clean_preserve_referenced() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let server = foo_index()?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo"]
+        "#,
+    )?;
+
+    // Sync the project, to populate the cache and register the project environment.
+    context
+        .sync()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--link-mode")
+        .arg("hardlink")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The index entries do not have stable keys, so we filter them out.
+            (r"index/[a-f0-9]+/", "index/[INDEX]/"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.clean().arg("--dry-run").arg("--preserve-referenced"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would remove 7 entries from cache at: [CACHE_DIR]/ ([SIZE])
+      .gitignore ([SIZE])
+      CACHEDIR.TAG ([SIZE])
+      interpreter-v4 ([SIZE])
+      resolutions-v0 ([SIZE])
+      sdists-v9 ([SIZE])
+      simple-v21 ([SIZE])
+      wheels-v6/index/[INDEX]/foo/foo-1.0.0-py3-none-any.lock ([SIZE])
+    Would retain 3 entries referenced by existing environments ([SIZE])
+      archive-v0/[HASH] ([SIZE])
+      wheels-v6/index/[INDEX]/foo/foo-1.0.0-py3-none-any ([SIZE])
+      wheels-v6/index/[INDEX]/foo/foo-1.0.0-py3-none-any.http ([SIZE])
+    ");
+
+    uv_snapshot!(&filters, context.clean().arg("--preserve-referenced"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Clearing cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    Retained 3 entries referenced by existing environments ([SIZE])
+    ");
+
+    // The archive linked into the environment should be retained, along with the pointer to it,
+    // such that the archive is reused rather than downloaded again.
+    assert_eq!(archive_count(&context)?, 1);
+    let pointers = fs_err::read_dir(context.cache_dir.child("wheels-v6").child("index").path())?
+        .map(|entry| {
+            Ok(entry?
+                .path()
+                .join("foo")
+                .join("foo-1.0.0-py3-none-any.http"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    assert!(pointers.iter().any(|pointer| pointer.is_file()));
+
+    Ok(())
+}
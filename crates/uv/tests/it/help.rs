@@ -64,10 +64,18 @@ fn help() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -147,10 +155,18 @@ fn help_flag() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -230,10 +246,18 @@ fn help_short_flag() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -293,10 +317,18 @@ fn help_flag_workspace() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -455,6 +487,15 @@ fn help_subcommand() {
 
               [env: UV_OFFLINE=]
 
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying.
+
+              By default, if downloading a file from an index fails with a `404 Not Found` or `410
+              Gone`, uv will refresh the package's index metadata once and retry with an equivalent file
+              for the same version (e.g., if the index re-uploaded or relocated the file). Use this flag
+              to disable that behavior in reproducibility-sensitive environments.
+
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host.
 
@@ -476,6 +517,20 @@ fn help_subcommand() {
 
               [env: UV_NO_PROGRESS=]
 
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+
+              Independent source distributions are built in parallel, up to the given limit. Builds that
+              require other packages to be built first (e.g., as build dependencies) wait for those
+              builds to complete. Defaults to the number of available CPU cores.
+
+          --no-fail-fast
+              Continue building after a build fails, and report every failure.
+
+              By default, uv stops at the first failed build. With `--no-fail-fast`, the remaining
+              source distributions are built to completion, such that all failures are reported at once.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
 
@@ -739,6 +794,15 @@ fn help_subsubcommand() {
 
               [env: UV_OFFLINE=]
 
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying.
+
+              By default, if downloading a file from an index fails with a `404 Not Found` or `410
+              Gone`, uv will refresh the package's index metadata once and retry with an equivalent file
+              for the same version (e.g., if the index re-uploaded or relocated the file). Use this flag
+              to disable that behavior in reproducibility-sensitive environments.
+
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host.
 
@@ -760,6 +824,20 @@ fn help_subsubcommand() {
 
               [env: UV_NO_PROGRESS=]
 
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+
+              Independent source distributions are built in parallel, up to the given limit. Builds that
+              require other packages to be built first (e.g., as build dependencies) wait for those
+              builds to complete. Defaults to the number of available CPU cores.
+
+          --no-fail-fast
+              Continue building after a build fails, and report every failure.
+
+              By default, uv stops at the first failed build. With `--no-fail-fast`, the remaining
+              source distributions are built to completion, such that all failures are reported at once.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
 
@@ -853,10 +931,18 @@ fn help_flag_subcommand() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -937,10 +1023,18 @@ fn help_flag_subsubcommand() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -1108,10 +1202,18 @@ fn help_with_global_option() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -1233,10 +1335,18 @@ fn help_with_no_pager() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
@@ -20,6 +20,8 @@ mod network;
 #[cfg(feature = "test-pypi")]
 mod publish;
 
+mod self_dirs;
+
 #[cfg(feature = "self-update")]
 mod self_update;
 
@@ -1104,3 +1104,71 @@ async fn retry_read_timeout_stream() {
       ╰─▶ Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: [TIME]).
     ");
 }
+
+/// The `http` setting of an index applies to the hosts that the index redirects to, e.g., a CDN
+/// that serves its files.
+#[cfg(feature = "test-pypi")]
+#[tokio::test]
+async fn index_http_version_redirect() {
+    use wiremock::matchers::path;
+
+    let context = uv_test::test_context!("3.12");
+
+    let server = MockServer::start().await;
+    let body = json!({
+        "name": "iniconfig",
+        "files": [{
+            "filename": "iniconfig-2.0.0-py3-none-any.whl",
+            "url": format!("{}/files/iniconfig-2.0.0-py3-none-any.whl", server.uri()),
+            "hashes": {
+                "sha256": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+            },
+            "requires-python": ">=3.7",
+            "upload-time": "2024-01-01T00:00:00Z"
+        }]
+    });
+    Mock::given(method("GET"))
+        .and(path("/simple/iniconfig/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(body.to_string(), "application/vnd.pypi.simple.v1+json"),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/iniconfig-2.0.0-py3-none-any.whl"))
+        .respond_with(ResponseTemplate::new(302).insert_header(
+            "Location",
+            "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+        ))
+        .mount(&server)
+        .await;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml
+        .write_str(&format!(
+            indoc::indoc! {r#"
+                [[index]]
+                url = "{}/simple"
+                default = true
+                http = "1.1"
+            "#},
+            server.uri()
+        ))
+        .unwrap();
+
+    let output = context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--config-file")
+        .arg(uv_toml.path())
+        .arg("--verbose")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("Using HTTP/1.1 for `files.pythonhosted.org:443`"),
+        "The redirect target should inherit the index's HTTP version:\n{stderr}"
+    );
+}
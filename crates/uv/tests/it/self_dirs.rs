@@ -0,0 +1,188 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+use uv_test::uv_snapshot;
+
+#[test]
+#[cfg(not(windows))]
+fn self_dirs() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context.self_dirs(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache: [CACHE_DIR]/ (`--cache-dir`)
+    Managed Python installations: [HOME]/data/uv/python (`XDG_DATA_HOME`)
+    Managed Python executables: [HOME]/data/../bin (`XDG_DATA_HOME`)
+    Installed tools: [HOME]/data/uv/tools (`XDG_DATA_HOME`)
+    Tool executables: [HOME]/data/../bin (`XDG_DATA_HOME`)
+    Credentials: [HOME]/data/uv/credentials (`XDG_DATA_HOME`)
+    Project environment registry: [HOME]/state/uv/environments (`XDG_STATE_HOME`)
+    User configuration: [UV_USER_CONFIG_DIR]/ (default)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn self_dirs_json() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context.self_dirs()
+        .arg("--output-format")
+        .arg("json")
+        .env(EnvVars::UV_TOOL_DIR, context.temp_dir.child("tools").as_os_str()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "cache",
+        "purpose": "Cache",
+        "path": "[CACHE_DIR]/",
+        "source": {
+          "type": "argument",
+          "name": "--cache-dir"
+        }
+      },
+      {
+        "name": "python",
+        "purpose": "Managed Python installations",
+        "path": "[HOME]/data/uv/python",
+        "source": {
+          "type": "environment-variable",
+          "name": "XDG_DATA_HOME"
+        }
+      },
+      {
+        "name": "python-bin",
+        "purpose": "Managed Python executables",
+        "path": "[HOME]/data/../bin",
+        "source": {
+          "type": "environment-variable",
+          "name": "XDG_DATA_HOME"
+        }
+      },
+      {
+        "name": "tools",
+        "purpose": "Installed tools",
+        "path": "[TEMP_DIR]/tools",
+        "source": {
+          "type": "environment-variable",
+          "name": "UV_TOOL_DIR"
+        }
+      },
+      {
+        "name": "tool-bin",
+        "purpose": "Tool executables",
+        "path": "[HOME]/data/../bin",
+        "source": {
+          "type": "environment-variable",
+          "name": "XDG_DATA_HOME"
+        }
+      },
+      {
+        "name": "credentials",
+        "purpose": "Credentials",
+        "path": "[HOME]/data/uv/credentials",
+        "source": {
+          "type": "environment-variable",
+          "name": "XDG_DATA_HOME"
+        }
+      },
+      {
+        "name": "environments",
+        "purpose": "Project environment registry",
+        "path": "[HOME]/state/uv/environments",
+        "source": {
+          "type": "environment-variable",
+          "name": "XDG_STATE_HOME"
+        }
+      },
+      {
+        "name": "config",
+        "purpose": "User configuration",
+        "path": "[UV_USER_CONFIG_DIR]/",
+        "source": {
+          "type": "default"
+        }
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+}
+
+/// If an override points to an empty directory, but there's existing data at the default
+/// location, warn the user, both in `uv self dirs` and in the commands that use the directory.
+#[test]
+#[cfg(not(windows))]
+fn self_dirs_override_hides_existing() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    context
+        .home_dir
+        .child("data")
+        .child("uv")
+        .child("tools")
+        .child("black")
+        .create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.self_dirs()
+        .env(EnvVars::UV_TOOL_DIR, context.temp_dir.child("tools").as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache: [CACHE_DIR]/ (`--cache-dir`)
+    Managed Python installations: [HOME]/data/uv/python (`XDG_DATA_HOME`)
+    Managed Python executables: [HOME]/data/../bin (`XDG_DATA_HOME`)
+    Installed tools: [TEMP_DIR]/tools (`UV_TOOL_DIR`)
+    Tool executables: [HOME]/data/../bin (`XDG_DATA_HOME`)
+    Credentials: [HOME]/data/uv/credentials (`XDG_DATA_HOME`)
+    Project environment registry: [HOME]/state/uv/environments (`XDG_STATE_HOME`)
+    User configuration: [UV_USER_CONFIG_DIR]/ (default)
+
+    ----- stderr -----
+    warning: Installed tools directory `tools` (from `UV_TOOL_DIR`) is empty, but existing data was found at the default location: `[HOME]/data/uv/tools`
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, context.temp_dir.child("tools").as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Installed tools directory `tools` (from `UV_TOOL_DIR`) is empty, but existing data was found at the default location: `[HOME]/data/uv/tools`
+    No tools installed
+    ");
+
+    // Once the new directory is populated, the warning is no longer shown.
+    context
+        .temp_dir
+        .child("tools")
+        .child("black")
+        .create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.self_dirs()
+        .env(EnvVars::UV_TOOL_DIR, context.temp_dir.child("tools").as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Cache: [CACHE_DIR]/ (`--cache-dir`)
+    Managed Python installations: [HOME]/data/uv/python (`XDG_DATA_HOME`)
+    Managed Python executables: [HOME]/data/../bin (`XDG_DATA_HOME`)
+    Installed tools: [TEMP_DIR]/tools (`UV_TOOL_DIR`)
+    Tool executables: [HOME]/data/../bin (`XDG_DATA_HOME`)
+    Credentials: [HOME]/data/uv/credentials (`XDG_DATA_HOME`)
+    Project environment registry: [HOME]/state/uv/environments (`XDG_STATE_HOME`)
+    User configuration: [UV_USER_CONFIG_DIR]/ (default)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
@@ -93,10 +93,18 @@ fn upgrade_help() {
               UV_SYSTEM_CERTS=]
           --offline
               Disable network access [env: UV_OFFLINE=]
+          --no-retry-missing-artifacts
+              Fail when a registry artifact is missing, rather than refreshing the index metadata and
+              retrying
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --concurrent-builds <N>
+              The maximum number of source distributions to build concurrently [env:
+              UV_CONCURRENT_BUILDS=]
+          --no-fail-fast
+              Continue building after a build fails, and report every failure
           --directory <DIRECTORY>
               Change to the given directory prior to running the command [env: UV_WORKING_DIR=]
           --project <PROJECT>
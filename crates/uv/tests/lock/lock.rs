@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
@@ -6,8 +9,12 @@ use insta::assert_snapshot;
 use url::Url;
 
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::Requirement;
 use uv_static::EnvVars;
 use uv_test::packse::PackseServer;
+use uv_test::packse::scenario::{Package, PackageMetadata, Scenario};
 #[cfg(feature = "test-git")]
 use uv_test::{READ_ONLY_GITHUB_TOKEN, decode_token};
 use uv_test::{download_to_disk, uv_snapshot, venv_bin_path};
@@ -601,39 +608,93 @@ fn lock_sdist_git_subdirectory() -> Result<()> {
     Ok(())
 }
 
-/// Lock a Git requirement using PEP 508.
+/// Lock Git requirements pinned to a branch and a tag within subdirectories of a local repository,
+/// then advance the branch.
 #[test]
 #[cfg(feature = "test-git")]
-fn lock_sdist_git_pep508() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+fn lock_git_branch_subdirectory() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let repository = context.temp_dir.child("repository");
+    for name in ["foo", "bar"] {
+        repository
+            .child(format!("packages/{name}/src/{name}/__init__.py"))
+            .touch()?;
+        repository
+            .child(format!("packages/{name}/pyproject.toml"))
+            .write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#})?;
+    }
+
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .arg("-C")
+            .arg(repository.path())
+            .arg("-c")
+            .arg("user.name=Example")
+            .arg("-c")
+            .arg("user.email=example@example.com")
+            .args(args)
+            .env("GIT_AUTHOR_DATE", "2000-01-01T00:00:00Z")
+            .env("GIT_COMMITTER_DATE", "2000-01-01T00:00:00Z")
+            .assert()
+            .success()
+    };
+    let commit = |message: &str| -> Result<String> {
+        git(&["add", "--all", "."]);
+        git(&["commit", "-m", message]);
+        let output = git(&["rev-parse", "HEAD"]).get_output().stdout.clone();
+        Ok(String::from_utf8(output)?.trim().to_string())
+    };
+
+    git(&["init", "--initial-branch", "main"]);
+    let initial = commit("Initial commit")?;
+    git(&["tag", "v1"]);
+
+    let repository_url = Url::from_directory_path(repository.path())
+        .map_err(|()| anyhow::anyhow!("failed to convert repository path to file URL"))?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@0.0.1"]
-        "#,
-    )?;
+        dependencies = ["foo", "bar"]
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+        [tool.uv.sources]
+        foo = {{ git = "{repository_url}", subdirectory = "packages/foo", branch = "main" }}
+        bar = {{ git = "{repository_url}", subdirectory = "packages/bar", tag = "v1" }}
+    "#})?;
+
+    let mut filters = context.filters();
+    filters.push((initial.as_str(), "[INITIAL]"));
+    filters.push((&initial[..8], "[INITIAL]"));
+
+    uv_snapshot!(filters, context.lock(), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
     ");
 
+    // The lockfile should capture the commit that each reference resolved to.
     let lock = context.read("uv.lock");
-
     insta::with_settings!({
-        filters => context.filters(),
+        filters => filters.clone(),
     }, {
-        assert_snapshot!(
-            lock, @r#"
+        assert_snapshot!(lock, @r#"
         version = 1
         revision = 3
         requires-python = ">=3.12"
@@ -641,36 +702,303 @@ fn lock_sdist_git_pep508() -> Result<()> {
         [options]
         exclude-newer = "2024-03-25T00:00:00Z"
 
+        [[package]]
+        name = "bar"
+        version = "0.1.0"
+        source = { git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbar&tag=v1#[INITIAL]" }
+
+        [[package]]
+        name = "foo"
+        version = "0.1.0"
+        source = { git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Ffoo&branch=main#[INITIAL]" }
+
         [[package]]
         name = "project"
         version = "0.1.0"
         source = { virtual = "." }
         dependencies = [
-            { name = "uv-public-pypackage" },
+            { name = "bar" },
+            { name = "foo" },
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.1" }]
+        requires-dist = [
+            { name = "bar", git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbar&tag=v1" },
+            { name = "foo", git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Ffoo&branch=main" },
+        ]
+        "#);
+    });
+
+    // Move the branch (and the tagged package) forward.
+    for name in ["foo", "bar"] {
+        repository
+            .child(format!("packages/{name}/pyproject.toml"))
+            .write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.2.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#})?;
+    }
+    let moved = commit("Bump versions")?;
+    filters.push((moved.as_str(), "[MOVED]"));
+    filters.push((&moved[..8], "[MOVED]"));
+
+    // Re-locking should respect the locked commit.
+    uv_snapshot!(filters, context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Upgrading `foo` should advance the branch, without touching the tagged `bar`.
+    uv_snapshot!(filters, context.lock().arg("--upgrade-package").arg("foo"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Updated foo v0.1.0 ([INITIAL]) -> v0.2.0 ([MOVED])
+    ");
+
+    let lock = context.read("uv.lock");
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
 
         [[package]]
-        name = "uv-public-pypackage"
+        name = "bar"
         version = "0.1.0"
-        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.1#0dacfd662c64cb4ceb16e6cf65a157a8b715b979" }
-        "#
-        );
+        source = { git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbar&tag=v1#[INITIAL]" }
+
+        [[package]]
+        name = "foo"
+        version = "0.2.0"
+        source = { git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Ffoo&branch=main#[MOVED]" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "bar" },
+            { name = "foo" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "bar", git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbar&tag=v1" },
+            { name = "foo", git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Ffoo&branch=main" },
+        ]
+        "#);
     });
 
-    // Re-run with `--locked`.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    // Remove the subdirectory from the branch.
+    fs_err::remove_dir_all(repository.child("packages/foo").path())?;
+    let removed = commit("Remove foo")?;
+    filters.push((removed.as_str(), "[REMOVED]"));
+
+    // The lockfile still points to a commit that contains the subdirectory.
+    uv_snapshot!(filters, context.lock().arg("--locked"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
     ");
 
-    // Re-lock with a precise commit that maps to the same tag.
+    // Upgrading `foo` should fail, naming the subdirectory, the branch, and the commit.
+    uv_snapshot!(filters, context.lock().arg("--upgrade-package").arg("foo"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `foo @ git+file://[TEMP_DIR]/repository/@main#subdirectory=packages/foo`
+      ╰─▶ The source distribution `git+file://[TEMP_DIR]/repository/@main#subdirectory=packages/foo` has no subdirectory `packages/foo` at branch `main` (commit `[REMOVED]`)
+    ");
+
+    Ok(())
+}
+
+/// Lock Git requirements on the same branch in different subdirectories of a local repository,
+/// alongside a requirement on a tag, then upgrade one of the packages on the branch.
+#[test]
+#[cfg(feature = "test-git")]
+fn lock_git_branch_shared_subdirectories() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let repository = context.temp_dir.child("repository");
+    let write_package = |name: &str, version: &str| -> Result<()> {
+        repository
+            .child(format!("packages/{name}/src/{name}/__init__.py"))
+            .touch()?;
+        repository
+            .child(format!("packages/{name}/pyproject.toml"))
+            .write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "{version}"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#})?;
+        Ok(())
+    };
+    for name in ["foo", "bar", "baz"] {
+        write_package(name, "0.1.0")?;
+    }
+
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .arg("-C")
+            .arg(repository.path())
+            .arg("-c")
+            .arg("user.name=Example")
+            .arg("-c")
+            .arg("user.email=example@example.com")
+            .args(args)
+            .env("GIT_AUTHOR_DATE", "2000-01-01T00:00:00Z")
+            .env("GIT_COMMITTER_DATE", "2000-01-01T00:00:00Z")
+            .assert()
+            .success()
+    };
+    let commit = |message: &str| -> Result<String> {
+        git(&["add", "--all", "."]);
+        git(&["commit", "-m", message]);
+        let output = git(&["rev-parse", "HEAD"]).get_output().stdout.clone();
+        Ok(String::from_utf8(output)?.trim().to_string())
+    };
+
+    git(&["init", "--initial-branch", "main"]);
+    let initial = commit("Initial commit")?;
+    git(&["tag", "v1"]);
+
+    let repository_url = Url::from_directory_path(repository.path())
+        .map_err(|()| anyhow::anyhow!("failed to convert repository path to file URL"))?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo", "bar", "baz"]
+
+        [tool.uv.sources]
+        foo = {{ git = "{repository_url}", subdirectory = "packages/foo", branch = "main" }}
+        bar = {{ git = "{repository_url}", subdirectory = "packages/bar", branch = "main" }}
+        baz = {{ git = "{repository_url}", subdirectory = "packages/baz", tag = "v1" }}
+    "#})?;
+
+    let mut filters = context.filters();
+    filters.push((initial.as_str(), "[INITIAL]"));
+    filters.push((&initial[..8], "[INITIAL]"));
+
+    uv_snapshot!(filters, context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Move the branch forward.
+    for name in ["foo", "bar", "baz"] {
+        write_package(name, "0.2.0")?;
+    }
+    let moved = commit("Bump versions")?;
+    filters.push((moved.as_str(), "[MOVED]"));
+    filters.push((&moved[..8], "[MOVED]"));
+
+    // Upgrading `foo` should advance the branch. A branch resolves to a single commit, so `bar`,
+    // which shares the branch, advances too; `baz`, on a tag, is untouched.
+    uv_snapshot!(filters, context.lock().arg("--upgrade-package").arg("foo"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated bar v0.1.0 ([INITIAL]) -> v0.2.0 ([MOVED])
+    Updated foo v0.1.0 ([INITIAL]) -> v0.2.0 ([MOVED])
+    ");
+
+    let lock = context.read("uv.lock");
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "bar"
+        version = "0.2.0"
+        source = { git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbar&branch=main#[MOVED]" }
+
+        [[package]]
+        name = "baz"
+        version = "0.1.0"
+        source = { git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbaz&tag=v1#[INITIAL]" }
+
+        [[package]]
+        name = "foo"
+        version = "0.2.0"
+        source = { git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Ffoo&branch=main#[MOVED]" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "bar" },
+            { name = "baz" },
+            { name = "foo" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "bar", git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbar&branch=main" },
+            { name = "baz", git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Fbaz&tag=v1" },
+            { name = "foo", git = "file://[TEMP_DIR]/repository/?subdirectory=packages%2Ffoo&branch=main" },
+        ]
+        "#);
+    });
+
+    Ok(())
+}
+
+/// Lock a Git requirement using PEP 508.
+#[test]
+#[cfg(feature = "test-git")]
+fn lock_sdist_git_pep508() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -678,7 +1006,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@0dacfd662c64cb4ceb16e6cf65a157a8b715b979"]
+        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@0.0.1"]
         "#,
     )?;
 
@@ -714,17 +1042,27 @@ fn lock_sdist_git_pep508() -> Result<()> {
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0dacfd662c64cb4ceb16e6cf65a157a8b715b979" }]
+        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.1" }]
 
         [[package]]
         name = "uv-public-pypackage"
         version = "0.1.0"
-        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0dacfd662c64cb4ceb16e6cf65a157a8b715b979#0dacfd662c64cb4ceb16e6cf65a157a8b715b979" }
+        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.1#0dacfd662c64cb4ceb16e6cf65a157a8b715b979" }
         "#
         );
     });
 
-    // Re-lock with a different commit.
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Re-lock with a precise commit that maps to the same tag.
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -732,7 +1070,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389"]
+        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@0dacfd662c64cb4ceb16e6cf65a157a8b715b979"]
         "#,
     )?;
 
@@ -743,7 +1081,6 @@ fn lock_sdist_git_pep508() -> Result<()> {
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
-    Updated uv-public-pypackage v0.1.0 (0dacfd66) -> v0.1.0 (b270df1a)
     ");
 
     let lock = context.read("uv.lock");
@@ -769,17 +1106,17 @@ fn lock_sdist_git_pep508() -> Result<()> {
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=b270df1a2fb5d012294e9aaf05e7e0bab1e6a389" }]
+        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0dacfd662c64cb4ceb16e6cf65a157a8b715b979" }]
 
         [[package]]
         name = "uv-public-pypackage"
         version = "0.1.0"
-        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=b270df1a2fb5d012294e9aaf05e7e0bab1e6a389#b270df1a2fb5d012294e9aaf05e7e0bab1e6a389" }
+        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0dacfd662c64cb4ceb16e6cf65a157a8b715b979#0dacfd662c64cb4ceb16e6cf65a157a8b715b979" }
         "#
         );
     });
 
-    // Re-lock with a different tag (which matches the new commit).
+    // Re-lock with a different commit.
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -787,7 +1124,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@0.0.2"]
+        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@b270df1a2fb5d012294e9aaf05e7e0bab1e6a389"]
         "#,
     )?;
 
@@ -798,6 +1135,7 @@ fn lock_sdist_git_pep508() -> Result<()> {
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
+    Updated uv-public-pypackage v0.1.0 (0dacfd66) -> v0.1.0 (b270df1a)
     ");
 
     let lock = context.read("uv.lock");
@@ -823,25 +1161,17 @@ fn lock_sdist_git_pep508() -> Result<()> {
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.2" }]
+        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=b270df1a2fb5d012294e9aaf05e7e0bab1e6a389" }]
 
         [[package]]
         name = "uv-public-pypackage"
         version = "0.1.0"
-        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.2#b270df1a2fb5d012294e9aaf05e7e0bab1e6a389" }
+        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=b270df1a2fb5d012294e9aaf05e7e0bab1e6a389#b270df1a2fb5d012294e9aaf05e7e0bab1e6a389" }
         "#
         );
     });
 
-    Ok(())
-}
-
-/// Lock a Git requirement using `tool.uv.sources` with a short revision.
-#[test]
-#[cfg(feature = "test-git")]
-fn lock_sdist_git_short_rev() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
-
+    // Re-lock with a different tag (which matches the new commit).
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -849,10 +1179,72 @@ fn lock_sdist_git_short_rev() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["uv-public-pypackage"]
-
-        [tool.uv.sources]
-        uv-public-pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", rev = "0dacfd6" }
+        dependencies = ["uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git@0.0.2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "uv-public-pypackage" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.2" }]
+
+        [[package]]
+        name = "uv-public-pypackage"
+        version = "0.1.0"
+        source = { git = "https://github.com/astral-test/uv-public-pypackage.git?rev=0.0.2#b270df1a2fb5d012294e9aaf05e7e0bab1e6a389" }
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Lock a Git requirement using `tool.uv.sources` with a short revision.
+#[test]
+#[cfg(feature = "test-git")]
+fn lock_sdist_git_short_rev() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["uv-public-pypackage"]
+
+        [tool.uv.sources]
+        uv-public-pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", rev = "0dacfd6" }
         "#,
     )?;
 
@@ -2714,6 +3106,111 @@ fn lock_project_with_excludes() -> Result<()> {
     Ok(())
 }
 
+/// Lock a project with `tool.uv.excluded-packages`, which can't be re-enabled by overrides or
+/// constraints.
+#[test]
+fn lock_excluded_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // `a` depends on `b`, which depends on `pycrypto`.
+    let mut scenario = Scenario::empty();
+    for (name, requires) in [
+        ("a", vec!["b"]),
+        ("b", vec!["pycrypto"]),
+        ("c", vec![]),
+        ("pycrypto", vec![]),
+    ] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires: requires
+                            .into_iter()
+                            .map(Requirement::from_str)
+                            .collect::<Result<_, _>>()?,
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a", "c"]
+
+        [tool.uv]
+        excluded-packages = ["pycrypto"]
+        override-dependencies = ["pycrypto==1.0.0"]
+        constraint-dependencies = ["pycrypto<2"]
+
+        [[tool.uv.index]]
+        url = "{}"
+        default = true
+        "#,
+        server.index_url()
+    })?;
+
+    // The error should show the chain of dependencies that required the excluded package.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because pycrypto was excluded from the resolution and b==1.0.0 depends on pycrypto==1.0.0, we can conclude that b==1.0.0 cannot be used.
+          And because only b==1.0.0 is available and a==1.0.0 depends on b, we can conclude that a==1.0.0 cannot be used.
+          And because only a==1.0.0 is available and your project depends on a, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    // Lock without the excluded package.
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["c"]
+
+        [[tool.uv.index]]
+        url = "{}"
+        default = true
+        "#,
+        server.index_url()
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Excluding a locked package on the command line should invalidate the lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--exclude").arg("c"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to excluded package: `c`
+      × No solution found when resolving dependencies:
+      ╰─▶ Because c was excluded from the resolution and your project depends on c, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
 /// Lock a project with `uv.tool.constraint-dependencies`.
 #[test]
 fn lock_project_with_constraints() -> Result<()> {
@@ -2769,6 +3266,146 @@ fn lock_project_with_constraints() -> Result<()> {
     Ok(())
 }
 
+/// Lock a project with `uv.tool.constraint-dependencies` that include markers. The constraint
+/// should only apply to the matching fork.
+#[test]
+fn lock_project_with_constraint_markers() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv]
+        constraint-dependencies = ["idna<3.4 ; python_version >= '3.13'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    // The resolution should fork on the constraint's marker, with `idna<3.4` only in the
+    // `python_full_version >= '3.13'` fork.
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "python_full_version >= '3.13'",
+            "python_full_version < '3.13'",
+        ]
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+        constraints = [{ name = "idna", marker = "python_full_version >= '3.13'", specifier = "<3.4" }]
+
+        [[package]]
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna", version = "3.3", source = { registry = "https://pypi.org/simple" }, marker = "python_full_version >= '3.13'" },
+            { name = "idna", version = "3.6", source = { registry = "https://pypi.org/simple" }, marker = "python_full_version < '3.13'" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737, upload-time = "2023-05-27T11:12:46.688Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873, upload-time = "2023-05-27T11:12:44.474Z" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.3"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "python_full_version >= '3.13'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/62/08/e3fc7c8161090f742f504f40b1bccbfc544d4a4e09eb774bf40aafce5436/idna-3.3.tar.gz", hash = "sha256:9d643ff0a55b762d5cdb124b8eaa99c66322e2157b69160bc32796e824360e6d", size = 286689, upload-time = "2021-10-12T23:33:41.312Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/04/a2/d918dcd22354d8958fe113e1a3630137e0fc8b44859ade3063982eacd2a4/idna-3.3-py3-none-any.whl", hash = "sha256:84d9dd047ffa80596e0f246e2eab0b391788b0503584e8945f2368256d2735ff", size = 61160, upload-time = "2021-10-12T23:33:38.02Z" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "python_full_version < '3.13'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "anyio", specifier = "==3.7.0" }]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+        ]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    // The constraint doesn't apply on Python 3.12.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
 /// Lock a project with `uv.tool.constraint-dependencies` that reference `tool.uv.sources`.
 #[test]
 fn lock_project_with_constraint_sources() -> Result<()> {
@@ -2907,6 +3544,7 @@ fn lock_project_with_build_constraints() -> Result<()> {
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
 
     hint: `requests` (v1.2.0) was included because `project` (v0.1.0) depends on `requests==1.2`
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     ");
 
     Ok(())
@@ -2968,6 +3606,303 @@ fn lock_project_with_build_constraint_sources() -> Result<()> {
     Ok(())
 }
 
+/// Lock the build dependencies of every package that can be built from source with
+/// `tool.uv.lock-build-dependencies`, and constrain the build environment to them during `uv sync`.
+#[test]
+fn lock_build_dependencies() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (
+                r"platform_machine == '[^']+'",
+                "platform_machine == '[MACHINE]'",
+            ),
+            (r"sys_platform == '[^']+'", "sys_platform == '[PLATFORM]'"),
+        ])
+        .collect::<Vec<_>>();
+
+    // Populate the `--find-links` entries.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    for wheel in [
+        "tqdm-1000.0.0-py3-none-any.whl",
+        "ok-1.0.0-py3-none-any.whl",
+        "basic_package-0.1.0-py3-none-any.whl",
+    ] {
+        fs_err::copy(
+            context.workspace_root.join("test/links").join(wheel),
+            context.temp_dir.join("links").join(wheel),
+        )?;
+    }
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv]
+        lock-build-dependencies = true
+        no-index = true
+        find-links = ["{}"]
+
+        [tool.uv.sources]
+        child = {{ path = "child" }}
+        "#,
+        context.temp_dir.join("links").portable_display(),
+    })?;
+
+    // Create a package with static metadata, built by an in-tree backend that requires `tqdm` and a
+    // local wheel, and requests `ok` via `get_requires_for_build_wheel`. Since the metadata is
+    // static, the package isn't built during resolution.
+    let child = context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+
+
+            def get_requires_for_build_wheel(config_settings=None):
+                return ["ok"]
+
+
+            def prepare_metadata_for_build_wheel(metadata_directory, config_settings=None):
+                dist_info = os.path.join(metadata_directory, "child-0.1.0.dist-info")
+                os.makedirs(dist_info, exist_ok=True)
+                with open(os.path.join(dist_info, "METADATA"), "w") as f:
+                    f.write(METADATA)
+                return "child-0.1.0.dist-info"
+        "#},
+    )?;
+    child.child("pyproject.toml").write_str(&formatdoc! {
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["tqdm", "basic-package @ {}"]
+        build-backend = "backend"
+        backend-path = ["."]
+        "#,
+        Url::from_file_path(
+            context
+                .temp_dir
+                .join("links/basic_package-0.1.0-py3-none-any.whl"),
+        )
+        .unwrap(),
+    })?;
+
+    uv_snapshot!(filters, context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `ok`, `tqdm`
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The build dependencies, including the local wheel and the requirements returned by
+    // `get_requires_for_build_wheel`, should be locked for the current environment.
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { directory = "child" }
+        build-dependencies = [
+            { name = "basic-package", marker = "python_full_version == '3.12.*' and platform_machine == '[MACHINE]' and sys_platform == '[PLATFORM]'", path = "links/basic_package-0.1.0-py3-none-any.whl", hashes = ["sha256:7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82"] },
+            { name = "ok", marker = "python_full_version == '3.12.*' and platform_machine == '[MACHINE]' and sys_platform == '[PLATFORM]'", specifier = "==1.0.0", hashes = ["sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f"] },
+            { name = "tqdm", marker = "python_full_version == '3.12.*' and platform_machine == '[MACHINE]' and sys_platform == '[PLATFORM]'", specifier = "==1000.0.0", hashes = ["sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13"] },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "child" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "child", directory = "child" }]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(filters, context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Install from the lockfile, constraining the build environment to the locked build
+    // dependencies.
+    uv_snapshot!(filters, context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `ok`, `tqdm`
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // If the locked build dependencies are no longer available, the build should fail.
+    let lock = lock.replace(r#"specifier = "==1000.0.0""#, r#"specifier = "==1001.0.0""#);
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    uv_snapshot!(filters, context.sync().arg("--frozen").arg("--reinstall").arg("--refresh"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ Failed to resolve the locked build requirements for `child`
+      ├─▶ No solution found when resolving: `tqdm`, `basic-package @ file://[TEMP_DIR]/links/basic_package-0.1.0-py3-none-any.whl`
+      ╰─▶ Because there is no version of tqdm==1001.0.0 and you require tqdm==1001.0.0, we can conclude that your requirements are unsatisfiable.
+
+    hint: `child` was included because `project` (v0.1.0) depends on `child`
+    hint: The locked build requirements may no longer be available; run `uv lock --upgrade` to refresh them
+    ");
+
+    // Upgrading should refresh the locked build dependencies.
+    uv_snapshot!(filters, context.lock().arg("--upgrade"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `ok`, `tqdm`
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { directory = "child" }
+        build-dependencies = [
+            { name = "basic-package", marker = "python_full_version == '3.12.*' and platform_machine == '[MACHINE]' and sys_platform == '[PLATFORM]'", path = "links/basic_package-0.1.0-py3-none-any.whl", hashes = ["sha256:7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82"] },
+            { name = "ok", marker = "python_full_version == '3.12.*' and platform_machine == '[MACHINE]' and sys_platform == '[PLATFORM]'", specifier = "==1.0.0", hashes = ["sha256:79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f"] },
+            { name = "tqdm", marker = "python_full_version == '3.12.*' and platform_machine == '[MACHINE]' and sys_platform == '[PLATFORM]'", specifier = "==1000.0.0", hashes = ["sha256:a34996d4bd5abb2336e14ff0a2d22b92cfd0f0ed344e6883041ce01953276a13"] },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "child" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "child", directory = "child" }]
+        "#
+        );
+    });
+
+    uv_snapshot!(filters, context.sync().arg("--frozen").arg("--reinstall"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `ok`, `tqdm`
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // Disabling the setting should remove the locked build dependencies.
+    pyproject_toml.write_str(&fs_err::read_to_string(&pyproject_toml)?.replace(
+        "lock-build-dependencies = true",
+        "lock-build-dependencies = false",
+    ))?;
+
+    uv_snapshot!(filters, context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => filters.clone(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { directory = "child" }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "child" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "child", directory = "child" }]
+        "#
+        );
+    });
+
+    Ok(())
+}
+
 /// Lock a project with a dependency that has an extra.
 #[test]
 fn lock_dependency_extra() -> Result<()> {
@@ -6854,6 +7789,9 @@ fn lock_requires_python() -> Result<()> {
     error: No interpreter found for Python >=3.12 in [PYTHON SOURCES]
 
     hint: A managed Python download is available for Python >=3.12, but Python downloads are set to 'never'
+
+    hint: Found 1 Python interpreter, but none are compatible with Python >=3.12:
+      - CPython 3.9.[X] at `[PYTHON-3.9]`: incompatible with Python >=3.12
     ");
 
     Ok(())
@@ -7221,6 +8159,93 @@ fn lock_requires_python_fork() -> Result<()> {
     Ok(())
 }
 
+/// Report the forks in a resolution with `--summary`.
+#[test]
+fn lock_summary() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = [
+            "iniconfig>=2 ; python_version >= '3.12'",
+            "iniconfig<2 ; python_version < '3.12'",
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--summary"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Resolved 2 forks:
+      python_full_version >= '3.12'
+      python_full_version < '3.12'
+    Packages that differ across forks:
+      iniconfig
+        v1.1.1 when python_full_version < '3.12'
+        v2.0.0 when python_full_version >= '3.12'
+        required by:
+          project v0.1.0: iniconfig<2 ; python_full_version < '3.12'
+          project v0.1.0: iniconfig>=2 ; python_full_version >= '3.12'
+    ");
+
+    // The requirements are disjoint, so minimizing the number of versions still requires two
+    // versions of `iniconfig`. The strategy is recorded in the lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--fork-strategy").arg("fewest-versions").arg("--summary"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Resolved 2 forks:
+      python_full_version >= '3.12'
+      python_full_version < '3.12'
+    Packages that differ across forks:
+      iniconfig
+        v1.1.1 when python_full_version < '3.12'
+        v2.0.0 when python_full_version >= '3.12'
+        required by:
+          project v0.1.0: iniconfig<2 ; python_full_version < '3.12'
+          project v0.1.0: iniconfig>=2 ; python_full_version >= '3.12'
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"fork-strategy = "fewest-versions""#));
+
+    // Without forks, the report says so.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--summary"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Removed iniconfig v1.1.1
+    Resolved without forking
+    ");
+
+    Ok(())
+}
+
 /// Lock a requirement from PyPI, respecting the `Requires-Python` metadata
 #[test]
 fn lock_requires_python_wheels() -> Result<()> {
@@ -8935,6 +9960,7 @@ fn lock_index_absolute_path_from_config() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/local_index`, which does not provide upload times: `tqdm`
     Resolved 2 packages in [TIME]
     ");
 
@@ -10079,6 +11105,1008 @@ fn lock_resolution_mode() -> Result<()> {
     Ok(())
 }
 
+/// A transitive dependency with an upper bound on `requires-python` narrows the range of Python
+/// versions supported by the lockfile, which is reported by default, and rejected with
+/// `--requires-python-strategy strict`.
+#[test]
+fn lock_requires_python_strategy() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    requires: vec![Requirement::from_str("bar")?],
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("bar")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    requires_python: Some(VersionSpecifiers::from_str(">=3.12, <3.14")?),
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo"]
+        "#,
+    )?;
+
+    // By default, `bar` narrows the supported range.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: The supported Python range was narrowed from `>=3.12` to `>=3.12, <3.14`, as not all dependencies support the full range: `bar==1.0.0` (requires Python `>=3.12, <3.14`)
+    ");
+
+    // In strict mode, the narrowing is an error, even if the lockfile is up-to-date.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--requires-python-strategy")
+        .arg("strict"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × `bar==1.0.0` requires Python `>=3.12, <3.14`, which does not cover the full supported Python range (`>=3.12`)
+
+    hint: `bar` (v1.0.0) was included because `project` (v0.1.0) depends on `foo` (v1.0.0) which depends on `bar`
+    hint: Consider narrowing `requires-python` to `>=3.12, <3.14`, or use `--requires-python-strategy narrow` to allow dependencies to narrow the supported Python range
+    ");
+
+    // If `bar` is only required on the Python versions it supports, the range isn't narrowed.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bar ; python_version < '3.14'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--requires-python-strategy")
+        .arg("strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Removed foo v1.0.0
+    ");
+
+    // Narrowing `requires-python` to the range supported by `bar` satisfies the strict mode.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12, <3.14"
+        dependencies = ["foo"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--requires-python-strategy")
+        .arg("strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Added foo v1.0.0
+    ");
+
+    Ok(())
+}
+
+/// Lock a project in which many packages share a dependency, with metadata prefetches limited to
+/// one at a time, and verify the metadata request counters in the verbose output.
+///
+/// The order in which queued prefetches are admitted is covered by the unit tests for the
+/// prefetch scheduler, since it depends on the timing of the index responses.
+#[test]
+fn lock_concurrent_metadata() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Each of `a` through `d` depends on `hub`, which depends on `core`.
+    let mut scenario = Scenario::empty();
+    for name in ["a", "b", "c", "d"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires: vec![Requirement::from_str("hub")?],
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    scenario.packages.insert(
+        PackageName::from_str("hub")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    requires: vec![Requirement::from_str("core")?],
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("core")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a", "b", "c", "d"]
+        "#,
+    )?;
+
+    let mut lockfiles = vec![];
+    for concurrent_metadata in ["1", "50"] {
+        let output = context
+            .lock()
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--verbose")
+            .env(EnvVars::UV_CONCURRENT_METADATA, concurrent_metadata)
+            .output()?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "{stderr}");
+
+        let stats = stderr
+            .lines()
+            .find_map(|line| line.split_once("Metadata requests: "))
+            .map(|(_, stats)| stats)
+            .unwrap_or_else(|| panic!("missing metadata request counters:\n{stderr}"));
+        let [issued, prefetch_hits] = ["issued", "prefetch hits"].map(|label| {
+            stats
+                .split(", ")
+                .find_map(|counter| counter.strip_suffix(label))
+                .and_then(|count| count.trim().parse::<usize>().ok())
+                .unwrap_or_else(|| panic!("missing `{label}` counter: {stats}"))
+        });
+
+        // Each of the six packages requires one request for its versions and one for the
+        // metadata of the selected version, regardless of how many packages depend on it or
+        // whether the metadata was prefetched; `hub` is requested by four packages, but fetched
+        // once.
+        assert_eq!(issued, 12, "{stats}");
+        // A prefetch can only satisfy a lookup for one of the six distributions.
+        assert!(prefetch_hits <= 6, "{stats}");
+
+        lockfiles.push(context.read("uv.lock"));
+        fs_err::remove_file(context.temp_dir.child("uv.lock"))?;
+    }
+
+    // Limiting the concurrency should not affect the resolution.
+    assert_eq!(lockfiles[0], lockfiles[1]);
+
+    Ok(())
+}
+
+/// Override the `--resolution` for a single package with `--resolution-package`, and ensure that
+/// the override is recorded in the lockfile.
+#[test]
+fn lock_resolution_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    for name in ["foo", "bar"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([
+                    (
+                        Version::from_str("1.0.0")?,
+                        PackageMetadata {
+                            wheel: true,
+                            ..PackageMetadata::default()
+                        },
+                    ),
+                    (
+                        Version::from_str("2.0.0")?,
+                        PackageMetadata {
+                            wheel: true,
+                            ..PackageMetadata::default()
+                        },
+                    ),
+                ]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo", "bar"]
+        "#,
+    )?;
+
+    // Only `foo` should be resolved to its lowest compatible version.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--resolution-package")
+        .arg("foo=lowest"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The direct dependency `foo` is unpinned. Consider setting a lower bound when using `--resolution lowest` or `--resolution lowest-direct` to avoid using outdated versions.
+    Resolved 3 packages in [TIME]
+    warning: The transitive dependency `foo` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("[options.resolution-package]\nfoo = \"lowest\"\n"));
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0\""));
+    assert!(lock.contains("name = \"bar\"\nversion = \"2.0.0\""));
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--resolution-package")
+        .arg("foo=lowest")
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Removing the override should invalidate the lockfile.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in package-specific resolution modes
+    Resolved 3 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    // Setting the override in `tool.uv` should match the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo", "bar"]
+
+        [tool.uv]
+        resolution-package = { foo = "lowest" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Inverting the strategies should select the lowest version of every package except `foo`.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--resolution")
+        .arg("lowest")
+        .arg("--resolution-package")
+        .arg("foo=highest"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in resolution mode: `highest` vs. `lowest`
+    warning: The direct dependency `bar` is unpinned. Consider setting a lower bound when using `--resolution lowest` or `--resolution lowest-direct` to avoid using outdated versions.
+    Resolved 3 packages in [TIME]
+    warning: The transitive dependency `bar` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    Updated bar v2.0.0 -> v1.0.0
+    Updated foo v1.0.0 -> v2.0.0
+    ");
+
+    Ok(())
+}
+
+/// Override the `--prerelease` mode for a single package with `--prerelease-package`, and ensure
+/// that the override is recorded in the lockfile.
+#[test]
+fn lock_prerelease_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    for name in ["foo", "bar"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([
+                    (
+                        Version::from_str("1.0.0")?,
+                        PackageMetadata {
+                            wheel: true,
+                            ..PackageMetadata::default()
+                        },
+                    ),
+                    (
+                        Version::from_str("2.0.0a1")?,
+                        PackageMetadata {
+                            wheel: true,
+                            ..PackageMetadata::default()
+                        },
+                    ),
+                ]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo", "bar"]
+        "#,
+    )?;
+
+    // Only `foo` should be resolved to its pre-release.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--prerelease-package")
+        .arg("foo=allow"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("[options.prerelease-package]\nfoo = \"allow\"\n"));
+    assert!(lock.contains("name = \"foo\"\nversion = \"2.0.0a1\""));
+    assert!(lock.contains("name = \"bar\"\nversion = \"1.0.0\""));
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--prerelease-package")
+        .arg("foo=allow")
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Removing the override should invalidate the lockfile.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in package-specific pre-release modes
+    Resolved 3 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    // Setting the override in `tool.uv` should match the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo", "bar"]
+
+        [tool.uv]
+        prerelease-package = { foo = "allow" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Inverting the modes should select the pre-release of every package except `foo`.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--prerelease")
+        .arg("allow")
+        .arg("--prerelease-package")
+        .arg("foo=disallow"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in package-specific pre-release modes
+    Resolved 3 packages in [TIME]
+    Updated bar v1.0.0 -> v2.0.0a1
+    Updated foo v2.0.0a1 -> v1.0.0
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("[options.prerelease-package]\nfoo = \"disallow\"\n"));
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0\""));
+    assert!(lock.contains("name = \"bar\"\nversion = \"2.0.0a1\""));
+
+    Ok(())
+}
+
+/// Prefer a build with a given local version label with `local-version-preference`, and ensure
+/// that the preference is recorded in the lockfile.
+#[test]
+fn lock_local_version_preference() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: ["1.0.0", "1.0.0+cpu", "1.0.0+cu121"]
+                .into_iter()
+                .map(|version| {
+                    Ok((
+                        Version::from_str(version)?,
+                        PackageMetadata {
+                            wheel: true,
+                            ..PackageMetadata::default()
+                        },
+                    ))
+                })
+                .collect::<Result<_>>()?,
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo==1.0.0"]
+        "#,
+    )?;
+
+    // Without a preference, the highest local version is selected.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0+cu121\""));
+
+    // Prefer the `+cpu` build.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo==1.0.0"]
+
+        [tool.uv]
+        local-version-preference = { foo = "+cpu" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in local version preferences
+    Resolved 2 packages in [TIME]
+    Updated foo v1.0.0+cu121 -> v1.0.0+cpu
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("[options.local-version-preference]\nfoo = \"+cpu\"\n"));
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0+cpu\""));
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Switching the preference on the command line should invalidate the lockfile.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--local-version-preference")
+        .arg("foo=+cu121"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in local version preferences
+    Resolved 2 packages in [TIME]
+    Updated foo v1.0.0+cpu -> v1.0.0+cu121
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("[options.local-version-preference]\nfoo = \"+cu121\"\n"));
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0+cu121\""));
+
+    // A preference for a label that doesn't exist falls back to the highest local version.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--local-version-preference")
+        .arg("foo=+rocm6"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in local version preferences
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0+cu121\""));
+
+    // The preference also applies when selecting the lowest version.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--resolution")
+        .arg("lowest"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in resolution mode: `highest` vs. `lowest`
+    Resolved 2 packages in [TIME]
+    Updated foo v1.0.0+cu121 -> v1.0.0+cpu
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0+cpu\""));
+
+    Ok(())
+}
+
+/// Lock a project that pins a yanked version under each `--yanked` policy.
+#[test]
+fn lock_yanked_policy() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([
+                (
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        yanked: true,
+                        yanked_reason: Some("Broken build".to_string()),
+                        ..PackageMetadata::default()
+                    },
+                ),
+                (
+                    Version::from_str("1.1.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                ),
+            ]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo==1.0.0"]
+        "#,
+    )?;
+
+    // By default, the pinned yanked version is selected with a warning.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: `foo==1.0.0` is yanked (reason: "Broken build")
+    "#);
+
+    // Under `--yanked allow`, the pinned yanked version is selected silently.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--yanked")
+        .arg("allow"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Under `--yanked forbid`, resolution fails, and the error includes the yank reason.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--yanked")
+        .arg("forbid"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because foo==1.0.0 was yanked (reason: Broken build) and your project depends on foo==1.0.0, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    // Unpinned requirements are unaffected, since yanked versions are skipped regardless.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo"]
+        "#,
+    )?;
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--yanked")
+        .arg("forbid"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.1.0\""));
+
+    Ok(())
+}
+
+/// Build a scenario in which `client` depends on `requests`, which can be replaced by
+/// `acme-requests`.
+fn replace_scenario() -> Result<Scenario> {
+    let mut scenario = Scenario::empty();
+    for (name, version, requires) in [
+        ("requests", "2.0.0", vec![]),
+        ("acme-requests", "2.31.0", vec![]),
+        ("client", "1.0.0", vec!["requests>=2"]),
+        ("client", "2.0.0", vec!["requests>=3"]),
+    ] {
+        scenario
+            .packages
+            .entry(PackageName::from_str(name)?)
+            .or_insert_with(|| Package {
+                versions: BTreeMap::new(),
+            })
+            .versions
+            .insert(
+                Version::from_str(version)?,
+                PackageMetadata {
+                    wheel: true,
+                    requires: requires
+                        .into_iter()
+                        .map(Requirement::from_str)
+                        .collect::<Result<_, _>>()?,
+                    ..PackageMetadata::default()
+                },
+            );
+    }
+    Ok(scenario)
+}
+
+/// Replace a transitive dependency throughout the graph with `tool.uv.replace`.
+#[test]
+fn lock_replace() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::from_scenario(&replace_scenario()?);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["client"]
+
+        [tool.uv.replace]
+        requests = "acme-requests"
+        "#,
+    )?;
+
+    // Without `provides`, the version specifiers on `requests` are ignored.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(
+        "[manifest.replacements]\nrequests = { requirement = { name = \"acme-requests\" } }\n"
+    ));
+    assert!(lock.contains(
+        "dependencies = [\n    { name = \"acme-requests\", replaces = \"requests\" },\n]"
+    ));
+    assert!(!lock.contains("name = \"requests\""));
+
+    uv_snapshot!(context.filters(), context.tree()
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project v0.1.0
+    └── client v2.0.0
+        └── requests → acme-requests v2.31.0 (replaced)
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // With `provides`, versions of `client` that require an incompatible `requests` are rejected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["client"]
+
+        [tool.uv.replace]
+        requests = { requirement = "acme-requests", provides = "2.31.0" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Updated client v2.0.0 -> v1.0.0
+    ");
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["client>=2"]
+
+        [tool.uv.replace]
+        requests = { requirement = "acme-requests", provides = "2.31.0" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only client<=2.0.0 is available and client==2.0.0 requires requests>=3, but its replacement provides requests==2.31.0, we can conclude that client>=2.0.0 cannot be used.
+          And because your project depends on client>=2, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
+/// Workspace members that declare different replacements for the same package are rejected.
+#[test]
+fn lock_replace_conflict() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [tool.uv.replace]
+        requests = "acme-requests"
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [tool.uv.replace]
+        requests = "other-requests"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Conflicting replacements for `requests` in `tool.uv.replace`: `acme-requests` and `other-requests`
+    ");
+
+    Ok(())
+}
+
 /// Lock a requirement from PyPI, filtering out wheels that target an ABI that is non-overlapping
 /// with the `Requires-Python` constraint.
 #[test]
@@ -13102,6 +15130,255 @@ fn lock_upgrade_drop_fork_markers() -> Result<()> {
     Ok(())
 }
 
+/// Explain the selected versions of a package in each fork, even if the lockfile is up-to-date.
+#[test]
+fn lock_explain() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::new("fork/basic.toml");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a>=2 ; sys_platform == 'linux'", "a<2 ; sys_platform == 'darwin'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--explain")
+        .arg("a"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    a==1.0.0 was selected for split (markers: sys_platform == 'darwin') because:
+      - project==0.1.0 requires a<2
+    a==2.0.0 was selected for split (markers: sys_platform == 'linux') because:
+      - project==0.1.0 requires a>=2
+    a is not in the resolution for split (markers: sys_platform != 'darwin' and sys_platform != 'linux') because:
+      - no package in the resolution requires it
+    ");
+
+    // The lockfile is up-to-date, but the project is re-resolved to explain the selection.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--explain")
+        .arg("a")
+        .arg("--explain")
+        .arg("b"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    a==1.0.0 was selected for split (markers: python_full_version >= '3.12' and sys_platform == 'darwin') because:
+      - project==0.1.0 requires a<2
+    a==2.0.0 was selected for split (markers: python_full_version >= '3.12' and sys_platform == 'linux') because:
+      - project==0.1.0 requires a>=2
+    a is not in the resolution for split (markers: python_full_version >= '3.12' and sys_platform != 'darwin' and sys_platform != 'linux') because:
+      - no package in the resolution requires it
+    b is not in the resolution for split (markers: python_full_version >= '3.12' and sys_platform == 'darwin') because:
+      - no package in the resolution requires it
+    b is not in the resolution for split (markers: python_full_version >= '3.12' and sys_platform == 'linux') because:
+      - no package in the resolution requires it
+    b is not in the resolution for split (markers: python_full_version >= '3.12' and sys_platform != 'darwin' and sys_platform != 'linux') because:
+      - no package in the resolution requires it
+    ");
+
+    Ok(())
+}
+
+/// Write a report of the changes made by `--upgrade` with `--preview-report`.
+#[test]
+fn lock_preview_report() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let version = |version: &str, upload_time: &str, requires: &[&str]| -> Result<_> {
+        Ok((
+            Version::from_str(version)?,
+            PackageMetadata {
+                wheel: true,
+                upload_time: Some(upload_time.to_string()),
+                requires: requires
+                    .iter()
+                    .map(|requirement| Requirement::from_str(requirement))
+                    .collect::<Result<_, _>>()?,
+                ..PackageMetadata::default()
+            },
+        ))
+    };
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([
+                version("1.0.0", "2024-01-01T00:00:00Z", &["qux>=1"])?,
+                version("2.0.0", "2024-03-01T00:00:00Z", &["qux>=1"])?,
+            ]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("baz")?,
+        Package {
+            versions: BTreeMap::from([version("1.0.0", "2024-01-01T00:00:00Z", &["bar>=1,<2"])?]),
+        },
+    );
+    for name in ["bar", "qux"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([
+                    version("1.0.0", "2024-01-01T00:00:00Z", &[])?,
+                    version("2.0.0", "2024-03-01T00:00:00Z", &[])?,
+                ]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo>=1", "baz>=1"]
+        "#,
+    )?;
+
+    // Lock to the lowest versions, to leave room for an upgrade.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--resolution")
+        .arg("lowest"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--upgrade")
+        .arg("--preview-report")
+        .arg("report.md"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in resolution mode: `lowest` vs. `highest`
+    Resolved 5 packages in [TIME]
+    Updated foo v1.0.0 -> v2.0.0
+    Updated qux v1.0.0 -> v2.0.0
+    ");
+
+    // `bar` is held back by the upper bound declared by `baz`.
+    assert_snapshot!(context.read("report.md"), @"
+    ## Dependency updates
+
+    ### Direct dependencies
+
+    | Package | Version | Released |
+    | --- | --- | --- |
+    | `foo` | 1.0.0 → 2.0.0 | 2024-03-01 |
+
+    ### Transitive dependencies
+
+    | Package | Version | Released |
+    | --- | --- | --- |
+    | `qux` | 1.0.0 → 2.0.0 | 2024-03-01 |
+
+    ### Held back
+
+    | Package | Locked | Latest | Held back by |
+    | --- | --- | --- | --- |
+    | `bar` | 1.0.0 | 2.0.0 | `bar>=1, <2` (baz==1.0.0) |
+    ");
+
+    // The report can also be written as JSON.
+    context
+        .lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--resolution")
+        .arg("lowest")
+        .assert()
+        .success();
+
+    context
+        .lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--upgrade")
+        .arg("--preview-report")
+        .arg("report.json")
+        .arg("--preview-report-format")
+        .arg("json")
+        .assert()
+        .success();
+
+    assert_snapshot!(context.read("report.json"), @r#"
+    {
+      "direct": [
+        {
+          "name": "foo",
+          "previous": [
+            "1.0.0"
+          ],
+          "current": [
+            "2.0.0"
+          ],
+          "released": "2024-03-01T00:00:00Z"
+        }
+      ],
+      "transitive": [
+        {
+          "name": "qux",
+          "previous": [
+            "1.0.0"
+          ],
+          "current": [
+            "2.0.0"
+          ],
+          "released": "2024-03-01T00:00:00Z"
+        }
+      ],
+      "held-back": [
+        {
+          "name": "bar",
+          "version": "1.0.0",
+          "latest": "2.0.0",
+          "constraints": [
+            {
+              "source": "baz==1.0.0",
+              "requirement": "bar>=1, <2"
+            }
+          ]
+        }
+      ]
+    }
+    "#);
+
+    Ok(())
+}
+
 /// Warn when there are missing bounds on transitive dependencies with `--resolution lowest`.
 #[test]
 fn lock_warn_missing_transitive_lower_bounds() -> Result<()> {
@@ -13367,61 +15644,228 @@ fn lock_find_links_ignore_explicit_index() -> Result<()> {
     Ok(())
 }
 
-/// Ensure that `[[tool.uv.index]]` entries with `format = "flat"` can use relative paths in the
-/// `url` field.
+/// Ensure that `[[tool.uv.index]]` entries with `format = "flat"` can use relative paths in the
+/// `url` field.
+#[test]
+fn lock_find_links_relative_url() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Populate the `--find-links` entries.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+
+    for entry in fs_err::read_dir(context.workspace_root.join("test/links"))? {
+        let entry = entry?;
+        let path = entry.path();
+        if path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .is_some_and(|file_name| file_name.starts_with("tqdm-"))
+        {
+            let dest = context
+                .temp_dir
+                .join("links")
+                .join(path.file_name().unwrap());
+            fs_err::copy(&path, &dest)?;
+        }
+    }
+
+    let workspace = context.temp_dir.child("workspace");
+
+    let pyproject_toml = workspace.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [[tool.uv.index]]
+        name = "local"
+        format = "flat"
+        url = "./links"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock")).unwrap();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697, upload-time = "2022-10-25T02:36:22.414Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/d1/d6/3965ed04c63042e047cb6a3e6ed1a63a35087b6a609aa3a15ed8ac56c221/colorama-0.4.6-py2.py3-none-any.whl", hash = "sha256:4f1d9991f5acc0ca119f9d443620b77f9d6b33703e51011c16baf57afb285fc6", size = 25335, upload-time = "2022-10-25T02:36:20.889Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "tqdm" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "tqdm" }]
+
+        [[package]]
+        name = "tqdm"
+        version = "4.66.2"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/ea/85/3ce0f9f7d3f596e7ea57f4e5ce8c18cb44e4a9daa58ddb46ee0d13d6bff8/tqdm-4.66.2.tar.gz", hash = "sha256:6cd52cdf0fef0e0f543299cfc96fec90d7b8a7e88745f411ec33eb44d5ed3531", size = 169462, upload-time = "2024-02-10T18:19:57.214Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/2a/14/e75e52d521442e2fcc9f1df3c5e456aead034203d4797867980de558ab34/tqdm-4.66.2-py3-none-any.whl", hash = "sha256:1ee4f8a893eb9bef51c6e35730cebf234d5d0b6bd112b0271e10ed7c24a02bd9", size = 78296, upload-time = "2024-02-10T18:19:53.524Z" },
+        ]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&workspace), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Respect `allow-sdist = false` on an index, and record the policy in the lockfile.
 #[test]
-fn lock_find_links_relative_url() -> Result<()> {
+fn lock_index_allow_sdist() -> Result<()> {
     let context = uv_test::test_context!("3.12");
 
-    // Populate the `--find-links` entries.
-    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    // Populate an index with a wheel for `ok==1.0.0`, and only a source distribution for
+    // `ok==2.0.0`.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        links.join("ok-1.0.0-py3-none-any.whl"),
+    )?;
+    links.child("ok-2.0.0.tar.gz").touch()?;
 
-    for entry in fs_err::read_dir(context.workspace_root.join("test/links"))? {
-        let entry = entry?;
-        let path = entry.path();
-        if path
-            .file_name()
-            .and_then(|file_name| file_name.to_str())
-            .is_some_and(|file_name| file_name.starts_with("tqdm-"))
-        {
-            let dest = context
-                .temp_dir
-                .join("links")
-                .join(path.file_name().unwrap());
-            fs_err::copy(&path, &dest)?;
-        }
-    }
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok<2"]
 
-    let workspace = context.temp_dir.child("workspace");
+        [[tool.uv.index]]
+        name = "local"
+        format = "flat"
+        url = "./links"
+        default = true
+        "#,
+    )?;
 
-    let pyproject_toml = workspace.child("pyproject.toml");
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `ok`
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Forbid source distributions from the index; the lockfile should be considered outdated.
     pyproject_toml.write_str(
         r#"
         [project]
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["tqdm"]
+        dependencies = ["ok<2"]
 
         [[tool.uv.index]]
         name = "local"
         format = "flat"
         url = "./links"
-        explicit = true
+        default = true
+        allow-sdist = false
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock().current_dir(&workspace), @"
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to change in index artifact policy (`allow-sdist` or `allow-wheels`)
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `ok`
+    Resolved 2 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    // Without the upper bound, `ok==2.0.0` is skipped in favor of the `ok==1.0.0` wheel.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [[tool.uv.index]]
+        name = "local"
+        format = "flat"
+        url = "./links"
+        default = true
+        allow-sdist = false
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Resolved 3 packages in [TIME]
+    Resolving despite existing lockfile due to change in index artifact policy (`allow-sdist` or `allow-wheels`)
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `ok`
+    Resolved 2 packages in [TIME]
+    warning: Selected older versions because newer source distributions are forbidden by `allow-sdist = false`: `ok==1.0.0` (instead of `ok==2.0.0` from `file://[TEMP_DIR]/links`)
     ");
 
-    let lock = fs_err::read_to_string(workspace.join("uv.lock")).unwrap();
+    let lock = context.read("uv.lock");
 
     insta::with_settings!({
         filters => context.filters(),
@@ -13434,14 +15878,16 @@ fn lock_find_links_relative_url() -> Result<()> {
 
         [options]
         exclude-newer = "2024-03-25T00:00:00Z"
+        index-artifact-policies = [
+            { index = "links", allow-sdist = false },
+        ]
 
         [[package]]
-        name = "colorama"
-        version = "0.4.6"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697, upload-time = "2022-10-25T02:36:22.414Z" }
+        name = "ok"
+        version = "1.0.0"
+        source = { registry = "links" }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/d1/d6/3965ed04c63042e047cb6a3e6ed1a63a35087b6a609aa3a15ed8ac56c221/colorama-0.4.6-py2.py3-none-any.whl", hash = "sha256:4f1d9991f5acc0ca119f9d443620b77f9d6b33703e51011c16baf57afb285fc6", size = 25335, upload-time = "2022-10-25T02:36:20.889Z" },
+            { path = "ok-1.0.0-py3-none-any.whl" },
         ]
 
         [[package]]
@@ -13449,36 +15895,153 @@ fn lock_find_links_relative_url() -> Result<()> {
         version = "0.1.0"
         source = { virtual = "." }
         dependencies = [
-            { name = "tqdm" },
+            { name = "ok" },
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "tqdm" }]
+        requires-dist = [{ name = "ok" }]
+        "#
+        );
+    });
 
-        [[package]]
-        name = "tqdm"
-        version = "4.66.2"
-        source = { registry = "https://pypi.org/simple" }
-        dependencies = [
-            { name = "colorama", marker = "sys_platform == 'win32'" },
+    // Re-run with `--check`.
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // If only a source distribution satisfies the requirement, the error should name the policy.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok>=2"]
+
+        [[tool.uv.index]]
+        name = "local"
+        format = "flat"
+        url = "./links"
+        default = true
+        allow-sdist = false
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only ok<=2.0.0 is available and ok==2.0.0 has no usable wheels, and source distributions from `file://[TEMP_DIR]/links` are forbidden by `allow-sdist = false`, we can conclude that ok>=2.0.0 cannot be used.
+          And because your project depends on ok>=2, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
+/// Respect `allow-sdist = false` on an index for `--find-links` locations with the same URL.
+#[test]
+fn lock_find_links_allow_sdist() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Populate the location with a wheel for `ok==1.0.0`, and only a source distribution for
+    // `ok==2.0.0`.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        links.join("ok-1.0.0-py3-none-any.whl"),
+    )?;
+    links.child("ok-2.0.0.tar.gz").touch()?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok"]
+
+        [tool.uv]
+        find-links = ["./links"]
+
+        [[tool.uv.index]]
+        name = "links"
+        format = "flat"
+        url = "./links"
+        explicit = true
+        allow-sdist = false
+        "#,
+    )?;
+
+    // `ok==2.0.0` is skipped in favor of the `ok==1.0.0` wheel.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-index"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: Selected older versions because newer source distributions are forbidden by `allow-sdist = false`: `ok==1.0.0` (instead of `ok==2.0.0` from `file://[TEMP_DIR]/links`)
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        index-artifact-policies = [
+            { index = "links", allow-sdist = false },
         ]
-        sdist = { url = "https://files.pythonhosted.org/packages/ea/85/3ce0f9f7d3f596e7ea57f4e5ce8c18cb44e4a9daa58ddb46ee0d13d6bff8/tqdm-4.66.2.tar.gz", hash = "sha256:6cd52cdf0fef0e0f543299cfc96fec90d7b8a7e88745f411ec33eb44d5ed3531", size = 169462, upload-time = "2024-02-10T18:19:57.214Z" }
+
+        [[package]]
+        name = "ok"
+        version = "1.0.0"
+        source = { registry = "links" }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/2a/14/e75e52d521442e2fcc9f1df3c5e456aead034203d4797867980de558ab34/tqdm-4.66.2-py3-none-any.whl", hash = "sha256:1ee4f8a893eb9bef51c6e35730cebf234d5d0b6bd112b0271e10ed7c24a02bd9", size = 78296, upload-time = "2024-02-10T18:19:53.524Z" },
+            { path = "ok-1.0.0-py3-none-any.whl" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "ok" },
         ]
+
+        [package.metadata]
+        requires-dist = [{ name = "ok" }]
         "#
         );
     });
 
-    // Re-run with `--locked`.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked").current_dir(&workspace), @"
+    // Re-run with `--check`.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-index").arg("--check"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
-    Resolved 3 packages in [TIME]
+    Resolved 2 packages in [TIME]
     ");
 
     Ok(())
@@ -13833,6 +16396,7 @@ fn lock_find_links_explicit_index() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `tqdm`
     Resolved 2 packages in [TIME]
     ");
 
@@ -13935,6 +16499,7 @@ fn lock_find_links_higher_priority_index() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `tqdm`
     Resolved 2 packages in [TIME]
     ");
 
@@ -14419,6 +16984,7 @@ fn lock_sources_url_offline_validates_transitive_source_tree() -> Result<()> {
 
     ----- stderr -----
     Resolved 3 packages in [TIME]
+    Update anyio v0.1.0 -> v0.2.0
     The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
     ");
 
@@ -16010,6 +18576,9 @@ fn check_outdated_lock() -> Result<()> {
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
+    Add iniconfig v2.0.0
+    Remove sortedcollections v2.1.0
+    Remove sortedcontainers v2.4.0
     The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
     ");
 
@@ -16022,9 +18591,203 @@ fn check_outdated_lock() -> Result<()> {
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
+    Add iniconfig v2.0.0
+    Remove sortedcollections v2.1.0
+    Remove sortedcontainers v2.4.0
+    The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
+
+/// Checks that `uv lock --check` reports an outdated lock when newer versions become available
+/// through a later `--exclude-newer` cutoff.
+#[test]
+fn check_outdated_lock_exclude_newer() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--exclude-newer")
+        .arg("2022-01-01T00:00:00Z"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // With the same cutoff, the lockfile is up-to-date.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--check")
+        .arg("--exclude-newer")
+        .arg("2022-01-01T00:00:00Z"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // With a later cutoff, the lockfile is outdated, since a newer version of `iniconfig` is
+    // available.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--check")
+        .arg("--exclude-newer")
+        .arg("2024-03-25T00:00:00Z"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to change of exclude newer timestamp from `2022-01-01T00:00:00Z` to `2024-03-25T00:00:00Z`
+    Resolved 2 packages in [TIME]
+    Update iniconfig v1.1.1 -> v2.0.0
     The lockfile at `uv.lock` needs to be updated, but `--check` was provided. To update the lockfile, run `uv lock`.
     ");
 
+    // The lockfile should not be modified.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--check")
+        .arg("--exclude-newer")
+        .arg("2022-01-01T00:00:00Z"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Checks that `uv lock --check` accepts a lockfile that only differs in the order of its wheels
+/// and markers.
+#[test]
+fn check_reordered_lock() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=2 ; python_full_version >= '3.13'",
+            "iniconfig<2 ; python_full_version < '3.13'",
+            "markupsafe==2.1.5",
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Reverse the order of the entries in each list of wheels and markers.
+    let lock = context.read("uv.lock");
+    let mut reordered = Vec::new();
+    let mut entries: Option<Vec<&str>> = None;
+    for line in lock.lines() {
+        if let Some(list) = entries.as_mut() {
+            if line != "]" {
+                list.push(line);
+                continue;
+            }
+            reordered.extend(list.drain(..).rev());
+            entries = None;
+        } else if line == "resolution-markers = [" || line == "wheels = [" {
+            entries = Some(Vec::new());
+        }
+        reordered.push(line);
+    }
+    let reordered = reordered.join("\n") + "\n";
+    assert_ne!(lock, reordered);
+    context.temp_dir.child("uv.lock").write_str(&reordered)?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--check"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // The lockfile should not be modified.
+    assert_eq!(context.read("uv.lock"), reordered);
+
+    Ok(())
+}
+
+/// Checks that `uv lock --check` exits with an error, rather than a failure, when the resolution
+/// itself fails.
+#[test]
+fn check_lock_resolution_error() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Require a version that doesn't exist.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==0.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of iniconfig==0.0.0 and your project depends on iniconfig==0.0.0, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
     Ok(())
 }
 
@@ -16163,6 +18926,110 @@ fn normalize_false_marker_requires_dist() -> Result<()> {
     Ok(())
 }
 
+/// When two indexes provide the same version of a package with different files, re-locking should
+/// prefer the index recorded in the lockfile, rather than switching to the files (and hashes) of
+/// the higher-priority index.
+#[test]
+fn lock_unsafe_best_match_same_version() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Both indexes provide `foo==1.0.0` and `bar==1.0.0`, but the `foo` wheels differ in their
+    // metadata, and thus in their hashes.
+    let scenario = |foo_requires_python: &str| -> Result<Scenario> {
+        let mut scenario = Scenario::empty();
+        scenario.packages.insert(
+            PackageName::from_str("foo")?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires_python: Some(VersionSpecifiers::from_str(foo_requires_python)?),
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+        scenario.packages.insert(
+            PackageName::from_str("bar")?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+        Ok(scenario)
+    };
+    let first = PackseServer::from_scenario(&scenario(">=3.11")?);
+    let second = PackseServer::from_scenario(&scenario(">=3.10")?);
+
+    // Return the registry that `foo` was locked from.
+    let foo_source = |lock: &str| -> String {
+        let package = lock
+            .split("[[package]]")
+            .find(|package| package.contains("name = \"foo\""))
+            .expect("`foo` should be locked");
+        package
+            .lines()
+            .find_map(|line| line.strip_prefix("source = { registry = \""))
+            .and_then(|line| line.strip_suffix("\" }"))
+            .expect("`foo` should be locked from a registry")
+            .to_string()
+    };
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_pyproject = |indexes: [&PackseServer; 2], dependencies: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = {dependencies}
+
+            [tool.uv]
+            index-strategy = "unsafe-best-match"
+
+            [[tool.uv.index]]
+            url = "{}"
+
+            [[tool.uv.index]]
+            url = "{}"
+            default = true
+            "#,
+            indexes[0].index_url(),
+            indexes[1].index_url(),
+        })
+    };
+
+    // With `second` at a higher priority, `foo` is locked from `second`.
+    write_pyproject([&second, &first], r#"["foo"]"#)?;
+    context.lock().assert().success();
+    assert_eq!(foo_source(&context.read("uv.lock")), second.index_url());
+
+    // After swapping the priorities and adding a dependency, `foo` is still locked from `second`.
+    write_pyproject([&first, &second], r#"["foo", "bar"]"#)?;
+    context.lock().assert().success();
+    assert_eq!(foo_source(&context.read("uv.lock")), second.index_url());
+
+    // Re-locking is stable, so `--locked` succeeds.
+    context.lock().arg("--locked").assert().success();
+
+    // Upgrading `foo` discards the preference, so `foo` is locked from the highest-priority index.
+    context
+        .lock()
+        .arg("--upgrade-package")
+        .arg("foo")
+        .assert()
+        .success();
+    assert_eq!(foo_source(&context.read("uv.lock")), first.index_url());
+
+    Ok(())
+}
+
 /// Change indexes between locking operations.
 #[tokio::test]
 async fn lock_change_index() -> Result<()> {
@@ -23383,110 +26250,545 @@ fn lock_dependency_metadata() -> Result<()> {
         "#,
     )?;
 
-    // The lockfile should update.
-    uv_snapshot!(context.filters(), context.lock(), @"
+    // The lockfile should update.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Added idna v3.6
+    Added sniffio v1.3.1
+    Removed typing-extensions v4.10.0
+    ");
+
+    // Use a blanket match.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [[tool.uv.dependency-metadata]]
+        name = "anyio"
+        requires-dist = ["iniconfig"]
+        "#,
+    )?;
+
+    // The lockfile should update.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Removed idna v3.6
+    Added iniconfig v2.0.0
+    Removed sniffio v1.3.1
+    ");
+
+    // Update the static metadata.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [[tool.uv.dependency-metadata]]
+        name = "anyio"
+        version = "3.7.0"
+        requires_dist = ["typing-extensions"]
+        "#,
+    )?;
+
+    // The operation should warn.
+    uv_snapshot!(context.filters(), context.lock(), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Failed to parse `pyproject.toml` during settings discovery:
+      TOML parse error at line 11, column 9
+         |
+      11 |         requires_dist = ["typing-extensions"]
+         |         ^^^^^^^^^^^^^
+      unknown field `requires_dist`, expected one of `name`, `version`, `requires-dist`, `requires-python`, `provides-extra`, `provides-extras`
+
+    Resolved 4 packages in [TIME]
+    Added idna v3.6
+    Removed iniconfig v2.0.0
+    Added sniffio v1.3.1
+    "#);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "test-git")]
+fn lock_dependency_metadata_git() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [tool.uv.sources]
+        anyio = { git = "https://github.com/agronholm/anyio", tag = "4.6.2" }
+
+        [[tool.uv.dependency-metadata]]
+        name = "anyio"
+        version = "4.6.0.post2"
+        requires-dist = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [manifest]
+
+        [[manifest.dependency-metadata]]
+        name = "anyio"
+        version = "4.6.0.post2"
+        requires-dist = ["iniconfig"]
+
+        [[package]]
+        name = "anyio"
+        version = "4.6.0.post2"
+        source = { git = "https://github.com/agronholm/anyio?tag=4.6.2#c4844254e6db0cb804c240ba07405db73d810e0b" }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "anyio", git = "https://github.com/agronholm/anyio?tag=4.6.2" }]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Re-run with `--offline`. We shouldn't need a network connection to validate an
+    // already-correct lockfile with immutable metadata.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==4.6.2 (from git+https://github.com/agronholm/anyio@c4844254e6db0cb804c240ba07405db73d810e0b)
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lock_strip_fragment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+    ");
+
+    Ok(())
+}
+
+/// Serve `basic_package-0.1.0-py3-none-any.whl` from a mock server, returning its URL.
+async fn serve_basic_package_wheel(
+    context: &uv_test::TestContext,
+    server: &wiremock::MockServer,
+) -> Result<String> {
+    use wiremock::{
+        Mock, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    let wheel = fs_err::read(
+        context
+            .workspace_root
+            .join("test/links/basic_package-0.1.0-py3-none-any.whl"),
+    )?;
+    Mock::given(method("GET"))
+        .and(path("/files/basic_package-0.1.0-py3-none-any.whl"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel))
+        .mount(server)
+        .await;
+
+    Ok(format!(
+        "{}/files/basic_package-0.1.0-py3-none-any.whl",
+        server.uri()
+    ))
+}
+
+/// The hash in a direct URL fragment is verified against the artifact and recorded in the
+/// lockfile.
+#[tokio::test]
+async fn lock_direct_url_hash_fragment() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+    let server = wiremock::MockServer::start().await;
+    let wheel_url = serve_basic_package_wheel(&context, &server).await?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = ["basic-package @ {wheel_url}#sha512=765bde25938af485e492e25ee0e8cde262462565122c1301213a69bf9ceb2008e3997b652a604092a238c4b1a6a334e697ff3cee3c22f9a617cb14f34e26ef17"]
+        "#,
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.13"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "basic-package"
+        version = "0.1.0"
+        source = { url = "http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl" }
+        wheels = [
+            { url = "http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl", hash = "sha512:765bde25938af485e492e25ee0e8cde262462565122c1301213a69bf9ceb2008e3997b652a604092a238c4b1a6a334e697ff3cee3c22f9a617cb14f34e26ef17" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "basic-package" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "basic-package", url = "http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl" }]
+        "#
+        );
+    });
+
+    // Install from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
-    Added idna v3.6
-    Added sniffio v1.3.1
-    Removed typing-extensions v4.10.0
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + basic-package==0.1.0 (from http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl)
     ");
 
-    // Use a blanket match.
-    pyproject_toml.write_str(
-        r#"
+    Ok(())
+}
+
+/// A direct URL whose artifact doesn't match the hash in its fragment is rejected.
+#[tokio::test]
+async fn lock_direct_url_hash_fragment_mismatch() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+    let server = wiremock::MockServer::start().await;
+    let wheel_url = serve_basic_package_wheel(&context, &server).await?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
         [project]
         name = "project"
         version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["anyio==3.7.0"]
-
-        [[tool.uv.dependency-metadata]]
-        name = "anyio"
-        requires-dist = ["iniconfig"]
+        requires-python = ">=3.13"
+        dependencies = ["basic-package @ {wheel_url}#sha256=0000000000000000000000000000000000000000000000000000000000000000"]
         "#,
-    )?;
+    })?;
 
-    // The lockfile should update.
     uv_snapshot!(context.filters(), context.lock(), @"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
-    Removed idna v3.6
-    Added iniconfig v2.0.0
-    Removed sniffio v1.3.1
+      × Failed to download `basic-package @ http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl#sha256=0000000000000000000000000000000000000000000000000000000000000000`
+      ╰─▶ Hash mismatch for `basic-package @ http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl#sha256=0000000000000000000000000000000000000000000000000000000000000000`
+
+          Expected:
+            sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+          Computed:
+            sha256:7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82
     ");
 
-    // Update the static metadata.
-    pyproject_toml.write_str(
-        r#"
+    Ok(())
+}
+
+/// A direct URL with a hash fragment for an unsupported algorithm is rejected.
+#[tokio::test]
+async fn lock_direct_url_hash_fragment_unsupported() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+    let server = wiremock::MockServer::start().await;
+    let wheel_url = serve_basic_package_wheel(&context, &server).await?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
         [project]
         name = "project"
         version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["anyio==3.7.0"]
-
-        [[tool.uv.dependency-metadata]]
-        name = "anyio"
-        version = "3.7.0"
-        requires_dist = ["typing-extensions"]
+        requires-python = ">=3.13"
+        dependencies = ["basic-package @ {wheel_url}#sha1=c6e1ee63fa4b2d3e8b3d0c30e1d1ed2b4c7e1f0a"]
         "#,
-    )?;
+    })?;
 
-    // The operation should warn.
-    uv_snapshot!(context.filters(), context.lock(), @r#"
-    success: true
-    exit_code: 0
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    warning: Failed to parse `pyproject.toml` during settings discovery:
-      TOML parse error at line 11, column 9
-         |
-      11 |         requires_dist = ["typing-extensions"]
-         |         ^^^^^^^^^^^^^
-      unknown field `requires_dist`, expected one of `name`, `version`, `requires-dist`, `requires-python`, `provides-extra`, `provides-extras`
-
-    Resolved 4 packages in [TIME]
-    Added idna v3.6
-    Removed iniconfig v2.0.0
-    Added sniffio v1.3.1
-    "#);
+    error: Unsupported hash algorithm (expected one of: `md5`, `sha256`, `sha384`, `sha512`, or `blake2b`) on: `sha1=c6e1ee63fa4b2d3e8b3d0c30e1d1ed2b4c7e1f0a`
+    ");
 
     Ok(())
 }
 
-#[test]
-#[cfg(feature = "test-git")]
-fn lock_dependency_metadata_git() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+/// When an index serves metadata (via PEP 658) that disagrees with the wheel itself,
+/// `reresolve-metadata` should read the dependencies from the wheel instead, and record the
+/// affected packages in the lockfile.
+#[tokio::test]
+async fn lock_reresolve_metadata() -> Result<()> {
+    use wiremock::{
+        Mock, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    let context = uv_test::test_context!("3.13");
+    let server = wiremock::MockServer::start().await;
+
+    // The index claims that `basic-package` depends on `ok`, but the wheel has no dependencies.
+    for (package, filename) in [
+        ("basic-package", "basic_package-0.1.0-py3-none-any.whl"),
+        ("ok", "ok-1.0.0-py3-none-any.whl"),
+    ] {
+        let body = serde_json::json!({
+            "meta": { "api-version": "1.1" },
+            "name": package,
+            "files": [{
+                "filename": filename,
+                "url": format!("{}/files/{filename}", server.uri()),
+                "hashes": {},
+                "upload-time": "2024-03-24T00:00:00Z",
+                "core-metadata": true,
+            }],
+        });
+        Mock::given(method("GET"))
+            .and(path(format!("/simple/{package}/")))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(body.to_string(), "application/vnd.pypi.simple.v1+json"),
+            )
+            .mount(&server)
+            .await;
+
+        let wheel = fs_err::read(context.workspace_root.join("test/links").join(filename))?;
+        Mock::given(method("GET"))
+            .and(path(format!("/files/{filename}")))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel))
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/files/basic_package-0.1.0-py3-none-any.whl.metadata"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(indoc! {"
+            Metadata-Version: 2.3
+            Name: basic-package
+            Version: 0.1.0
+            Requires-Python: >=3.13
+            Requires-Dist: ok
+        "}))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/ok-1.0.0-py3-none-any.whl.metadata"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(indoc! {"
+            Metadata-Version: 2.3
+            Name: ok
+            Version: 1.0.0
+            Requires-Python: >=3.8
+        "}))
+        .mount(&server)
+        .await;
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
+    pyproject_toml.write_str(&formatdoc! { r#"
         [project]
         name = "project"
         version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["anyio"]
-
-        [tool.uv.sources]
-        anyio = { git = "https://github.com/agronholm/anyio", tag = "4.6.2" }
+        requires-python = ">=3.13"
+        dependencies = ["basic-package"]
 
-        [[tool.uv.dependency-metadata]]
-        name = "anyio"
-        version = "4.6.0.post2"
-        requires-dist = ["iniconfig"]
+        [[tool.uv.index]]
+        url = "{}/simple/"
+        default = true
         "#,
-    )?;
+        server.uri(),
+    })?;
 
+    // By default, the index-provided metadata is trusted.
     uv_snapshot!(context.filters(), context.lock(), @"
     success: true
     exit_code: 0
@@ -23496,6 +26798,18 @@ fn lock_dependency_metadata_git() -> Result<()> {
     Resolved 3 packages in [TIME]
     ");
 
+    // With `--no-index-metadata-package`, the metadata is read from the wheel.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-index-metadata-package").arg("basic-package"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to change in packages with ignored index metadata (`reresolve-metadata`)
+    Resolved 2 packages in [TIME]
+    Removed ok v1.0.0
+    ");
+
     let lock = context.read("uv.lock");
 
     insta::with_settings!({
@@ -23505,33 +26819,20 @@ fn lock_dependency_metadata_git() -> Result<()> {
             lock, @r#"
         version = 1
         revision = 3
-        requires-python = ">=3.12"
+        requires-python = ">=3.13"
 
         [options]
         exclude-newer = "2024-03-25T00:00:00Z"
-
-        [manifest]
-
-        [[manifest.dependency-metadata]]
-        name = "anyio"
-        version = "4.6.0.post2"
-        requires-dist = ["iniconfig"]
-
-        [[package]]
-        name = "anyio"
-        version = "4.6.0.post2"
-        source = { git = "https://github.com/agronholm/anyio?tag=4.6.2#c4844254e6db0cb804c240ba07405db73d810e0b" }
-        dependencies = [
-            { name = "iniconfig" },
+        reresolve-metadata = [
+            "basic-package",
         ]
 
         [[package]]
-        name = "iniconfig"
-        version = "2.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        name = "basic-package"
+        version = "0.1.0"
+        source = { registry = "http://[LOCALHOST]/simple/" }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+            { url = "http://[LOCALHOST]/files/basic_package-0.1.0-py3-none-any.whl", upload-time = "2024-03-24T00:00:00Z" },
         ]
 
         [[package]]
@@ -23539,66 +26840,109 @@ fn lock_dependency_metadata_git() -> Result<()> {
         version = "0.1.0"
         source = { virtual = "." }
         dependencies = [
-            { name = "anyio" },
+            { name = "basic-package" },
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "anyio", git = "https://github.com/agronholm/anyio?tag=4.6.2" }]
+        requires-dist = [{ name = "basic-package" }]
         "#
         );
     });
 
-    // Re-run with `--locked`.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    // Dropping the setting invalidates the lockfile.
+    uv_snapshot!(context.filters(), context.lock(), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
+    Resolving despite existing lockfile due to change in packages with ignored index metadata (`reresolve-metadata`)
     Resolved 3 packages in [TIME]
+    Added ok v1.0.0
     ");
 
-    // Re-run with `--offline`. We shouldn't need a network connection to validate an
-    // already-correct lockfile with immutable metadata.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--offline").arg("--no-cache"), @"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    Ok(())
+}
 
-    ----- stderr -----
-    Resolved 3 packages in [TIME]
-    ");
+/// Serve `basic-package` from a mock Simple API index.
+async fn mount_basic_package_index(
+    context: &uv_test::TestContext,
+    server: &wiremock::MockServer,
+) -> Result<()> {
+    use wiremock::{
+        Mock, ResponseTemplate,
+        matchers::{method, path},
+    };
 
-    // Install from the lockfile.
-    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    let filename = "basic_package-0.1.0-py3-none-any.whl";
+    let body = serde_json::json!({
+        "meta": { "api-version": "1.1" },
+        "name": "basic-package",
+        "files": [{
+            "filename": filename,
+            "url": format!("{}/files/{filename}", server.uri()),
+            "hashes": {},
+            "upload-time": "2024-03-24T00:00:00Z",
+        }],
+    });
+    Mock::given(method("GET"))
+        .and(path("/simple/basic-package/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(body.to_string(), "application/vnd.pypi.simple.v1+json"),
+        )
+        .mount(server)
+        .await;
 
-    ----- stderr -----
-    Prepared 2 packages in [TIME]
-    Installed 2 packages in [TIME]
-     + anyio==4.6.2 (from git+https://github.com/agronholm/anyio@c4844254e6db0cb804c240ba07405db73d810e0b)
-     + iniconfig==2.0.0
-    ");
+    let wheel = fs_err::read(context.workspace_root.join("test/links").join(filename))?;
+    Mock::given(method("GET"))
+        .and(path(format!("/files/{filename}")))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel))
+        .mount(server)
+        .await;
 
     Ok(())
 }
 
-#[test]
-fn lock_strip_fragment() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+/// Re-locking a project whose inputs are unchanged should reuse the cached resolution, without
+/// querying the index.
+#[tokio::test]
+async fn lock_resolution_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+    let server = wiremock::MockServer::start().await;
+    mount_basic_package_index(&context, &server).await?;
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
+    pyproject_toml.write_str(&formatdoc! { r#"
         [project]
         name = "project"
         version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = ["iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"]
+        requires-python = ">=3.13"
+        dependencies = ["basic-package", "child"]
+
+        [tool.uv.sources]
+        child = {{ path = "child" }}
+
+        [[tool.uv.index]]
+        url = "{}/simple/"
+        default = true
         "#,
-    )?;
+        server.uri(),
+    })?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
 
     uv_snapshot!(context.filters(), context.lock(), @"
     success: true
@@ -23606,64 +26950,83 @@ fn lock_strip_fragment() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
     ");
 
-    let lock = context.read("uv.lock");
+    // With all mocks removed, an unchanged project should still lock without any index requests.
+    server.reset().await;
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r#"
-        version = 1
-        revision = 3
-        requires-python = ">=3.12"
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+    assert!(server.received_requests().await.unwrap().is_empty());
 
-        [options]
-        exclude-newer = "2024-03-25T00:00:00Z"
+    // The same is true even if the lockfile was removed.
+    let lock = context.read("uv.lock");
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-        [[package]]
-        name = "iniconfig"
-        version = "2.0.0"
-        source = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
-        ]
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+    assert!(server.received_requests().await.unwrap().is_empty());
+    assert_eq!(context.read("uv.lock"), lock);
 
-        [[package]]
-        name = "project"
-        version = "0.1.0"
-        source = { virtual = "." }
-        dependencies = [
-            { name = "iniconfig" },
-        ]
+    // `--refresh` bypasses the cache.
+    uv_snapshot!(context.filters(), context.lock().arg("--refresh"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
 
-        [package.metadata]
-        requires-dist = [{ name = "iniconfig", url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }]
-        "#
-        );
-    });
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because basic-package was not found in the package registry and your project depends on basic-package, we can conclude that your project's requirements are unsatisfiable.
+    ");
 
-    // Re-run with `--locked`.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    // As does `--upgrade`.
+    server.reset().await;
+    mount_basic_package_index(&context, &server).await?;
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
     ");
+    assert!(!server.received_requests().await.unwrap().is_empty());
 
-    // Install from the lockfile.
-    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    // Modifying a path dependency invalidates the cached resolution.
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = ["basic-package"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    uv_snapshot!(context.filters(), context.tree().arg("--no-dedupe"), @"
     success: true
     exit_code: 0
     ----- stdout -----
+    project v0.1.0
+    ├── basic-package v0.1.0
+    └── child v0.1.0
+        └── basic-package v0.1.0
 
     ----- stderr -----
-    Installed 1 package in [TIME]
-     + iniconfig==2.0.0 (from https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl)
+    Resolved 3 packages in [TIME]
     ");
 
     Ok(())
@@ -23828,6 +27191,130 @@ fn lock_invalid_project_table() -> Result<()> {
     Ok(())
 }
 
+/// An entry point without a `:` is rejected when locking.
+#[test]
+fn lock_invalid_entry_point() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        mycli = "project.cli.main"
+        "#,
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid entry point `mycli` in `project.scripts` of `pyproject.toml`
+      Caused by: Expected an object reference of the form `module:object`, but `project.cli.main` has no `:` (did you mean `project.cli:main`?)
+    ");
+
+    Ok(())
+}
+
+/// An entry point referencing a module that's missing from the source tree raises a warning.
+#[test]
+fn lock_entry_point_missing_module() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        mycli = "project.cli:main"
+        mygui = "project.gui:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    })?;
+    context
+        .temp_dir
+        .child("src/project/cli.py")
+        .write_str("def main(): pass")?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The entry point `mygui` in `project.scripts` of `pyproject.toml` references the module `project.gui`, which was not found in the project's source tree
+    Resolved 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Two workspace members can't define the same script.
+#[test]
+fn lock_entry_point_duplicate_script() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [project.scripts]
+        mycli = "project:main"
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    })?;
+
+    let pyproject_toml = context.temp_dir.child("child/pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.gui-scripts]
+        mycli = "child:main"
+        "#,
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Two workspace members both define the script `mycli`, which would conflict when installed into the same environment: `child/pyproject.toml` and `pyproject.toml`
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn lock_missing_name() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -25102,6 +28589,60 @@ fn lock_multiple_sources_index_non_total() -> Result<()> {
     Ok(())
 }
 
+/// If `tool.uv.environments` is declared, marker-gated index sources must cover every supported
+/// environment.
+#[test]
+fn lock_multiple_sources_index_environments_gap() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["jinja2>=3,<3.1.4"]
+
+        [tool.uv]
+        environments = [
+            "sys_platform == 'linux'",
+            "sys_platform == 'darwin'",
+            "sys_platform == 'win32'",
+        ]
+
+        [tool.uv.sources]
+        jinja2 = [
+            { index = "torch-cu118", marker = "sys_platform == 'linux'" },
+            { index = "pypi", marker = "sys_platform == 'darwin'" },
+        ]
+
+        [[tool.uv.index]]
+        name = "torch-cu118"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu118"
+
+        [[tool.uv.index]]
+        name = "pypi"
+        url = "https://pypi.org/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `project @ file://[TEMP_DIR]/`
+      ├─▶ Failed to parse entry: `jinja2`
+      ╰─▶ Index sources for `jinja2` must cover all supported environments (from `tool.uv.environments`), but no index is declared for: `sys_platform == 'win32'`
+
+    hint: Add an entry for `jinja2` with `marker = "sys_platform == 'win32'"` to `tool.uv.sources`, or remove the environment from `tool.uv.environments`
+    "#);
+
+    Ok(())
+}
+
 #[test]
 fn lock_multiple_sources_index_explicit() -> Result<()> {
     let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
@@ -25573,6 +29114,72 @@ fn lock_multiple_sources_index_overlapping_extras() -> Result<()> {
     Ok(())
 }
 
+/// A package can't be requested from a direct URL in one workspace member and pinned to an
+/// explicit index in another.
+#[test]
+fn lock_conflicting_sources_url_and_index() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "child",
+            "iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+        ]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+
+        [[tool.uv.index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        explicit = true
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.uv.sources]
+        iniconfig = { index = "test" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to resolve dependencies for `project` (v0.1.0)
+      ╰─▶ Requirements contain conflicting sources for package `iniconfig` in all marker environments: it is requested from both a direct URL and an explicit index:
+          - https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl
+          - https://test.pypi.org/simple
+
+    hint: A package can be installed from a direct URL or from an index, but not both in the same environment; use a single source for `iniconfig` across the workspace
+    ");
+
+    Ok(())
+}
+
 /// Sources will be ignored when an `extra` is applied, but references a non-existent extra.
 #[test]
 fn lock_multiple_index_with_missing_extra() -> Result<()> {
@@ -25867,6 +29474,184 @@ fn lock_dry_run_noop() -> Result<()> {
     Ok(())
 }
 
+/// Raise the lower bounds of direct dependencies to their locked versions with `--narrow-bounds`.
+#[test]
+fn lock_narrow_bounds() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::new("fork/fork-upgrade.toml");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "foo>=1",
+            "bar<3 ; sys_platform != 'win32'",
+        ]
+
+        [project.optional-dependencies]
+        test = ["bar==2"]
+
+        [dependency-groups]
+        dev = ["bar"]
+
+        [[tool.uv.index]]
+        url = "{}"
+        default = true
+        "#,
+        server.index_url()
+    })?;
+
+    // Report the narrowed bounds, without modifying the `pyproject.toml`.
+    uv_snapshot!(context.filters(), context.lock().arg("--narrow-bounds").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would narrow `foo>=1` → `foo>=2.0.0` (project.dependencies)
+    Would narrow `bar<3 ; sys_platform != 'win32'` → `bar>=2.0.0,<3 ; sys_platform != 'win32'` (project.dependencies)
+    Would narrow `bar` → `bar>=2.0.0` (dependency-groups.dev)
+    ");
+
+    assert!(context.read("pyproject.toml").contains(r#""foo>=1","#));
+
+    // Apply the narrowed bounds.
+    uv_snapshot!(context.filters(), context.lock().arg("--narrow-bounds").arg("--write").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Narrowed `foo>=1` → `foo>=2.0.0` (project.dependencies)
+    Narrowed `bar<3 ; sys_platform != 'win32'` → `bar>=2.0.0,<3 ; sys_platform != 'win32'` (project.dependencies)
+    Narrowed `bar` → `bar>=2.0.0` (dependency-groups.dev)
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "foo>=2.0.0",
+            "bar>=2.0.0,<3 ; sys_platform != 'win32'",
+        ]
+
+        [project.optional-dependencies]
+        test = ["bar==2"]
+
+        [dependency-groups]
+        dev = ["bar>=2.0.0"]
+
+        [[tool.uv.index]]
+        url = "http://[LOCALHOST]/simple/"
+        default = true
+        "#
+        );
+    });
+
+    // The lockfile should reflect the narrowed requirements.
+    uv_snapshot!(context.filters(), context.lock().arg("--check").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Once narrowed, there's nothing left to do.
+    uv_snapshot!(context.filters(), context.lock().arg("--narrow-bounds").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    All dependency lower bounds match the lockfile
+    ");
+
+    Ok(())
+}
+
+/// With `--narrow-bounds`, dependencies on workspace members and exact pins are skipped, while the
+/// dependencies of every member, including optional dependencies, are narrowed.
+#[test]
+fn lock_narrow_bounds_workspace() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::new("fork/fork-upgrade.toml");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child", "foo>=1"]
+
+        [project.optional-dependencies]
+        pinned = ["bar==2"]
+        test = ["bar>=1"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = {{ workspace = true }}
+
+        [[tool.uv.index]]
+        url = "{}"
+        default = true
+        "#,
+        server.index_url()
+    })?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["bar"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--narrow-bounds").arg("--write").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Narrowed `bar` → `bar>=2.0.0` (project.dependencies in `child`)
+    Narrowed `foo>=1` → `foo>=2.0.0` (project.dependencies in `project`)
+    Narrowed `bar>=1` → `bar>=2.0.0` (project.optional-dependencies.test in `project`)
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+    assert!(pyproject_toml.contains(r#"dependencies = ["child", "foo>=2.0.0"]"#));
+    assert!(pyproject_toml.contains(r#"pinned = ["bar==2"]"#));
+    assert!(pyproject_toml.contains(r#"test = ["bar>=2.0.0"]"#));
+    assert!(
+        context
+            .read("child/pyproject.toml")
+            .contains(r#"dependencies = ["bar>=2.0.0"]"#)
+    );
+
+    Ok(())
+}
+
 #[test]
 fn lock_group_include() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -28957,7 +32742,7 @@ fn lock_bump_static_version() -> Result<()> {
 
 #[test]
 fn lock_derivation_chain_prod() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -28985,6 +32770,7 @@ fn lock_derivation_chain_prod() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -29014,7 +32800,7 @@ fn lock_derivation_chain_prod() -> Result<()> {
 
 #[test]
 fn lock_derivation_chain_extra() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -29043,6 +32829,7 @@ fn lock_derivation_chain_extra() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -29072,7 +32859,7 @@ fn lock_derivation_chain_extra() -> Result<()> {
 
 #[test]
 fn lock_derivation_chain_group() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -29103,6 +32890,7 @@ fn lock_derivation_chain_group() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -29132,7 +32920,7 @@ fn lock_derivation_chain_group() -> Result<()> {
 
 #[test]
 fn lock_derivation_chain_extended() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -29174,6 +32962,7 @@ fn lock_derivation_chain_extended() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -34869,6 +38658,144 @@ fn lock_exclude_newer_package() -> Result<()> {
     Ok(())
 }
 
+/// Limit a single package with `exclude-newer-package`, without a global `exclude-newer`, such
+/// that all other packages resolve to their latest versions.
+#[test]
+fn lock_exclude_newer_package_only() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    for name in ["boto3", "requests"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([
+                    (
+                        Version::from_str("1.0.0")?,
+                        PackageMetadata {
+                            wheel: true,
+                            upload_time: Some("2024-01-01T00:00:00Z".to_string()),
+                            ..PackageMetadata::default()
+                        },
+                    ),
+                    (
+                        Version::from_str("2.0.0")?,
+                        PackageMetadata {
+                            wheel: true,
+                            upload_time: Some("2024-03-01T00:00:00Z".to_string()),
+                            ..PackageMetadata::default()
+                        },
+                    ),
+                ]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["boto3", "requests"]
+        "#,
+    )?;
+
+    // Only `boto3` should be limited by the cutoff.
+    uv_snapshot!(context.filters(), context.lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--exclude-newer-package")
+        .arg("boto3=2024-02-01T00:00:00Z"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("[options.exclude-newer-package]\nboto3 = \"2024-02-01T00:00:00Z\"\n"));
+    assert!(!lock.contains("\nexclude-newer = "));
+    assert!(lock.contains("name = \"boto3\"\nversion = \"1.0.0\""));
+    assert!(lock.contains("name = \"requests\"\nversion = \"2.0.0\""));
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--exclude-newer-package")
+        .arg("boto3=2024-02-01T00:00:00Z")
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Setting the cutoff in `tool.uv` should match the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["boto3", "requests"]
+
+        [tool.uv]
+        exclude-newer-package = { boto3 = "2024-02-01T00:00:00Z" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Removing the cutoff should invalidate the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["boto3", "requests"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--locked"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to removal of exclude newer for package `boto3`
+    Resolved 3 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
+
 /// Test that the resolver emits a hint when all versions are excluded by `--exclude-newer`.
 ///
 /// See: <https://github.com/astral-sh/uv/issues/18014>
@@ -34939,20 +38866,19 @@ async fn lock_exclude_newer_index_disable() -> Result<()> {
         proxy_uri = proxy.uri()
     ))?;
 
+    // The index doesn't provide upload times, so `exclude-newer` can't be applied to its packages.
     uv_snapshot!(context.filters(), context.lock(), @"
-    success: false
-    exit_code: 1
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    warning: iniconfig-2.0.0.tar.gz is missing an upload date, but user provided: 2024-03-25T00:00:00Z
-    warning: iniconfig-2.0.0-py3-none-any.whl is missing an upload date, but user provided: 2024-03-25T00:00:00Z
-      × No solution found when resolving dependencies:
-      ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig>=2, we can conclude that your project's requirements are unsatisfiable.
-
-    hint: `iniconfig` was filtered by `exclude-newer` to only include packages uploaded before 2024-03-25T00:00:00Z. The latest version satisfying the requirement is v2.0.0. Consider using `exclude-newer-package` to override the cutoff for this package.
+    warning: `--exclude-newer` could not be applied to packages from `http://[LOCALHOST]/no-upload-time/simple`, which does not provide upload times: `iniconfig`
+    Resolved 2 packages in [TIME]
     ");
 
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
     pyproject_toml.write_str(&format!(
         r#"
         [project]
@@ -35023,37 +38949,33 @@ async fn lock_exclude_newer_index_value() -> Result<()> {
     ))?;
 
     uv_snapshot!(context.filters(), context.lock(), @"
-    success: false
-    exit_code: 1
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     warning: Setting `exclude-newer` on configured indexes is experimental and may change without warning. Pass `--preview-features index-exclude-newer` to disable this warning.
-    warning: iniconfig-2.0.0.tar.gz is missing an upload date, but user provided: 2025-01-01T00:00:00Z
-    warning: iniconfig-2.0.0-py3-none-any.whl is missing an upload date, but user provided: 2025-01-01T00:00:00Z
-      × No solution found when resolving dependencies:
-      ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig>=2, we can conclude that your project's requirements are unsatisfiable.
-
-    hint: `iniconfig` was filtered by the index-specific `exclude-newer` setting to only include packages uploaded before 2025-01-01T00:00:00Z. The latest version satisfying the requirement is v2.0.0. Consider updating that index's cutoff, setting it to `false`, or using `exclude-newer-package` to override the cutoff for this package.
+    warning: `--exclude-newer` could not be applied to packages from `http://[LOCALHOST]/no-upload-time/simple`, which does not provide upload times: `iniconfig`
+    Resolved 2 packages in [TIME]
     ");
 
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
     uv_snapshot!(context.filters(), context
         .lock()
         .arg("--preview-features")
         .arg("index-exclude-newer"), @"
-    success: false
-    exit_code: 1
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    warning: iniconfig-2.0.0.tar.gz is missing an upload date, but user provided: 2025-01-01T00:00:00Z
-    warning: iniconfig-2.0.0-py3-none-any.whl is missing an upload date, but user provided: 2025-01-01T00:00:00Z
-      × No solution found when resolving dependencies:
-      ╰─▶ Because there are no versions of iniconfig and your project depends on iniconfig>=2, we can conclude that your project's requirements are unsatisfiable.
-
-    hint: `iniconfig` was filtered by the index-specific `exclude-newer` setting to only include packages uploaded before 2025-01-01T00:00:00Z. The latest version satisfying the requirement is v2.0.0. Consider updating that index's cutoff, setting it to `false`, or using `exclude-newer-package` to override the cutoff for this package.
+    warning: `--exclude-newer` could not be applied to packages from `http://[LOCALHOST]/no-upload-time/simple`, which does not provide upload times: `iniconfig`
+    Resolved 2 packages in [TIME]
     ");
 
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
     pyproject_toml.write_str(&format!(
         r#"
         [project]
@@ -35089,6 +39011,123 @@ async fn lock_exclude_newer_index_value() -> Result<()> {
     Ok(())
 }
 
+/// Mix an index that provides upload times with a `--find-links` directory that doesn't. The
+/// packages from the `--find-links` directory should be included with a single warning, unless
+/// `--strict-exclude-newer` is set.
+#[test]
+fn lock_exclude_newer_find_links_missing_upload_time() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([
+                (
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        upload_time: Some("2024-01-01T00:00:00Z".to_string()),
+                        ..PackageMetadata::default()
+                    },
+                ),
+                (
+                    Version::from_str("2.0.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        upload_time: Some("2024-06-01T00:00:00Z".to_string()),
+                        ..PackageMetadata::default()
+                    },
+                ),
+            ]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    // Populate the `--find-links` entries.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/tqdm-1000.0.0-py3-none-any.whl"),
+        context
+            .temp_dir
+            .join("links/tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo", "tqdm"]
+        "#,
+    )?;
+
+    // By default, `tqdm` is included with a warning.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--find-links")
+        .arg(context.temp_dir.join("links"))
+        .arg("--exclude-newer")
+        .arg("2024-03-01T00:00:00Z"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `tqdm`
+    Resolved 3 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("name = \"foo\"\nversion = \"1.0.0\""));
+    assert!(lock.contains("name = \"tqdm\"\nversion = \"1000.0.0\""));
+
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    // With `--strict-exclude-newer`, the resolution fails.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--find-links")
+        .arg(context.temp_dir.join("links"))
+        .arg("--exclude-newer")
+        .arg("2024-03-01T00:00:00Z")
+        .arg("--strict-exclude-newer"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--exclude-newer` could not be applied to packages from sources that lack the required metadata:
+      file://[TEMP_DIR]/links (no upload times): tqdm
+
+    hint: Remove the affected sources, or omit `--strict-exclude-newer` to include their packages with a warning
+    ");
+
+    // Without `--exclude-newer`, there's nothing to warn about.
+    uv_snapshot!(context.filters(), context.lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--find-links")
+        .arg(context.temp_dir.join("links"))
+        .arg("--strict-exclude-newer"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Test that the resolver emits a hint when a pinned version is excluded by `--exclude-newer`,
 /// even though older versions of the same package are still available.
 ///
@@ -36524,6 +40563,7 @@ fn lock_supported_environment_abi3_wheel() -> Result<()> {
 
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/local_index`, which does not provide upload times: `abi3-package`
     Resolved 2 packages in [TIME]
     ");
 
@@ -777,6 +777,10 @@ fn conflict_in_fork() -> Result<()> {
               a{sys_platform == 'os2'}>=2
           and your project depends on a{sys_platform == 'os2'}<2, we can conclude that your project's requirements are unsatisfiable.
 
+          The conflicting requirements on d were introduced by:
+              project -> a -> b -> d==1 (only when `sys_platform == 'os2'`)
+              project -> a -> c -> d==2 (only when `sys_platform == 'os2'`)
+
     hint: The resolution failed for an environment that is not the current one, consider limiting the environments with `tool.uv.environments`.
     "
     );
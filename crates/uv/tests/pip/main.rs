@@ -7,6 +7,8 @@ mod pip_compile_scenarios;
 
 mod pip_debug;
 
+mod pip_download;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod pip_exclude_newer_relative;
 
@@ -1,8 +1,25 @@
 use anyhow::Result;
+use assert_fs::fixture::ChildPath;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
-
-use uv_test::uv_snapshot;
+use assert_fs::fixture::PathCreateDir;
+
+use uv_test::{TestContext, uv_snapshot};
+
+/// Seed a `.dist-info` directory for the given package into the environment, with the given
+/// additional `METADATA` headers.
+fn seed_dist(context: &TestContext, name: &str, version: &str, headers: &str) -> Result<()> {
+    let dist_info =
+        ChildPath::new(context.site_packages()).child(format!("{name}-{version}.dist-info"));
+    dist_info.create_dir_all()?;
+    dist_info.child("METADATA").write_str(&format!(
+        "Metadata-Version: 2.1\nName: {name}\nVersion: {version}\n{headers}"
+    ))?;
+    dist_info
+        .child("WHEEL")
+        .write_str("Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n")?;
+    Ok(())
+}
 
 #[test]
 fn check_compatible_packages() -> Result<()> {
@@ -296,3 +313,94 @@ fn check_dependency_metadata_from_config_file() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn check_format_json_compatible() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    seed_dist(&context, "lib", "1.0.0", "")?;
+    seed_dist(&context, "app", "1.0.0", "Requires-Dist: lib>=1\n")?;
+
+    uv_snapshot!(context.pip_check().arg("--format").arg("json"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    []
+
+    ----- stderr -----
+    Checked 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Check an environment with a missing dependency, a version conflict, a missing extra, and a
+/// package that requires a newer version of Python, in both output formats.
+#[test]
+fn check_format_json() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    seed_dist(
+        &context,
+        "app",
+        "1.0.0",
+        "Requires-Dist: lib>=2\nRequires-Dist: missing\nRequires-Dist: plugin[fast]\n",
+    )?;
+    seed_dist(&context, "lib", "1.0.0", "")?;
+    seed_dist(&context, "plugin", "1.0.0", "Provides-Extra: slow\n")?;
+    seed_dist(&context, "modern", "1.0.0", "Requires-Python: >=4\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_check(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 4 packages in [TIME]
+    Found 4 incompatibilities
+    The package `app` requires `lib>=2`, but `1.0.0` is installed
+    The package `app` requires `missing`, but it's not installed
+    The package `app` requires `plugin[fast]`, but the installed version of `plugin` (`1.0.0`) does not provide the extra `fast`
+    The package `modern` requires Python >=4, but `3.12.[X]` is installed
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--format").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [{"kind":"version-conflict","package":"app","requirement":"lib>=2","installed_version":"1.0.0","message":"The package `app` requires `lib>=2`, but `1.0.0` is installed"},{"kind":"missing-dependency","package":"app","requirement":"missing","installed_version":null,"message":"The package `app` requires `missing`, but it's not installed"},{"kind":"missing-extra","package":"app","requirement":"plugin[fast]","installed_version":"1.0.0","message":"The package `app` requires `plugin[fast]`, but the installed version of `plugin` (`1.0.0`) does not provide the extra `fast`"},{"kind":"requires-python","package":"modern","requirement":">=4","installed_version":"3.12.[X]","message":"The package `modern` requires Python >=4, but `3.12.[X]` is installed"}]
+
+    ----- stderr -----
+    Checked 4 packages in [TIME]
+    "#);
+
+    Ok(())
+}
+
+/// An extra that's only referenced by the markers of a dependency (as in metadata that omits
+/// `Provides-Extra`) is considered to be provided.
+#[test]
+fn check_extra_without_provides_extra() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    seed_dist(&context, "app", "1.0.0", "Requires-Dist: plugin[fast]\n")?;
+    seed_dist(
+        &context,
+        "plugin",
+        "1.0.0",
+        "Requires-Dist: lib; extra == 'fast'\n",
+    )?;
+    seed_dist(&context, "lib", "1.0.0", "")?;
+
+    uv_snapshot!(context.pip_check(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 3 packages in [TIME]
+    All installed packages are compatible
+    ");
+
+    Ok(())
+}
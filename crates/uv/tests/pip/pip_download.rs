@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::Requirement;
+use uv_test::packse::PackseServer;
+use uv_test::packse::scenario::{Package, PackageMetadata, Scenario};
+use uv_test::uv_snapshot;
+
+/// A scenario in which each package has a single `1.0.0` wheel, with the given dependencies and
+/// wheel tags.
+fn scenario(packages: &[(&str, &[&str], &[&str])]) -> Result<Scenario> {
+    let mut scenario = Scenario::empty();
+    for (name, requires, wheel_tags) in packages {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires: requires
+                            .iter()
+                            .map(|requirement| Requirement::from_str(requirement))
+                            .collect::<Result<_, _>>()?,
+                        wheel: true,
+                        wheel_tags: wheel_tags
+                            .iter()
+                            .map(|tag| tag.parse())
+                            .collect::<Result<_, _>>()
+                            .map_err(anyhow::Error::msg)?,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    Ok(scenario)
+}
+
+/// Download a set of requirements, then install them from the destination directory without
+/// network access.
+#[test]
+fn download_requirements_txt() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::from_scenario(&scenario(&[
+        ("app", &["lib"], &[]),
+        ("lib", &["base"], &[]),
+        ("base", &[], &[]),
+    ])?);
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("app==1.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("-d")
+        .arg("wheels"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+     + app-1.0.0-py3-none-any.whl
+     + base-1.0.0-py3-none-any.whl
+     + lib-1.0.0-py3-none-any.whl
+    "
+    );
+
+    let wheels = context.temp_dir.child("wheels");
+    wheels
+        .child("app-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    wheels
+        .child("base-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    wheels
+        .child("lib-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    // Install from the downloaded distributions, without access to the index.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--find-links")
+        .arg("wheels")
+        .arg("--no-index")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + app==1.0.0
+     + base==1.0.0
+     + lib==1.0.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Download wheels for a platform other than the current one.
+#[test]
+fn download_python_platform() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::from_scenario(&scenario(&[
+        ("app", &["native"], &[]),
+        (
+            "native",
+            &[],
+            &[
+                "cp312-cp312-win_amd64",
+                "cp312-cp312-manylinux_2_17_x86_64",
+                "cp312-cp312-macosx_11_0_arm64",
+            ],
+        ),
+    ])?);
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("app")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("windows")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("-d")
+        .arg("windows"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+     + app-1.0.0-py3-none-any.whl
+     + native-1.0.0-cp312-cp312-win_amd64.whl
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("app")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("-d")
+        .arg("linux"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+     + app-1.0.0-py3-none-any.whl
+     + native-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl
+    "
+    );
+
+    // Only the wheel for the requested platform is downloaded.
+    let windows = context.temp_dir.child("windows");
+    windows
+        .child("native-1.0.0-cp312-cp312-win_amd64.whl")
+        .assert(predicates::path::is_file());
+    windows
+        .child("native-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl")
+        .assert(predicates::path::missing());
+    let linux = context.temp_dir.child("linux");
+    linux
+        .child("native-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl")
+        .assert(predicates::path::is_file());
+    linux
+        .child("native-1.0.0-cp312-cp312-win_amd64.whl")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// A second download of the same requirements is served from the cache.
+#[test]
+fn download_cached() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::from_scenario(&scenario(&[("app", &[], &[])])?);
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("app==1.0.0")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("-d")
+        .arg("first"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + app-1.0.0-py3-none-any.whl
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("app==1.0.0")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("-d")
+        .arg("second")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+     + app-1.0.0-py3-none-any.whl
+    "
+    );
+
+    context
+        .temp_dir
+        .child("second")
+        .child("app-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    Ok(())
+}
+
+/// Download distributions from a local `--find-links` directory, without access to an index.
+#[test]
+fn download_find_links() {
+    let context = uv_test::test_context!("3.12");
+    let find_links = context.workspace_root.join("test/links");
+
+    uv_snapshot!(context.filters(), context.pip_download()
+        .arg("tqdm==1000.0.0")
+        .arg("ok==1.0.0")
+        .arg("--find-links")
+        .arg(&find_links)
+        .arg("--no-index")
+        .arg("-d")
+        .arg("wheels"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+     + ok-1.0.0-py3-none-any.whl
+     + tqdm-1000.0.0-py3-none-any.whl
+    "
+    );
+
+    let wheels = context.temp_dir.child("wheels");
+    wheels
+        .child("ok-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    wheels
+        .child("tqdm-1000.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+}
@@ -2,6 +2,8 @@ use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
+use indoc::indoc;
+use url::Url;
 
 use uv_test::uv_snapshot;
 
@@ -149,7 +151,7 @@ fn freeze_with_editable() -> Result<()> {
     exit_code: 0
     ----- stdout -----
     anyio==4.3.0
-    -e file://[WORKSPACE]/test/packages/poetry_editable
+    -e [WORKSPACE]/test/packages/poetry_editable
 
     ----- stderr -----
     warning: The package `anyio` requires `idna>=2.8`, but it's not installed
@@ -612,3 +614,81 @@ fn freeze_exclude() {
     "
     );
 }
+
+/// Freeze an environment with registry, URL, and editable installs, then recreate it from the
+/// output.
+#[test]
+fn freeze_round_trip() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let find_links = context.workspace_root.join("test/links");
+
+    // Create a local package with an in-tree build backend that supports editable builds.
+    let project = context.in_tree_backend(
+        "editable-project",
+        "0.1.0",
+        indoc! {r#"
+            import pathlib
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                return write_wheel(
+                    wheel_directory, {"editable_project.pth": str(pathlib.Path.cwd()) + "\n"}
+                )
+
+
+            build_editable = build_wheel
+        "#},
+    )?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(&format!(
+        "ok==1.0.0\ntqdm @ {}\n-e {}\n",
+        Url::from_file_path(find_links.join("tqdm-1000.0.0-py3-none-any.whl")).unwrap(),
+        project.path().display()
+    ))?;
+
+    context
+        .pip_install()
+        .arg("-r")
+        .arg(requirements_in.path())
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_freeze().arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    -e [TEMP_DIR]/editable-project
+    ok==1.0.0
+    tqdm @ file://[WORKSPACE]/test/links/tqdm-1000.0.0-py3-none-any.whl
+
+    ----- stderr -----
+    ");
+
+    // Recreate the environment from the output of `pip freeze`.
+    let frozen = context.pip_freeze().output()?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&String::from_utf8(frozen.stdout.clone())?)?;
+
+    context.reset_venv();
+    context
+        .pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .assert()
+        .success();
+
+    let refrozen = context.pip_freeze().output()?;
+    assert_eq!(
+        String::from_utf8(refrozen.stdout)?,
+        String::from_utf8(frozen.stdout)?
+    );
+
+    Ok(())
+}
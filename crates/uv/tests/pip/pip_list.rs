@@ -5,6 +5,10 @@ use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 use assert_fs::prelude::*;
 
+use url::Url;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
 use uv_static::EnvVars;
 use uv_test::uv_snapshot;
 
@@ -323,9 +327,10 @@ fn list_outdated_git() -> Result<()> {
     success: true
     exit_code: 0
     ----- stdout -----
-    Package   Version Latest Type
-    --------- ------- ------ -----
-    iniconfig 1.0.0   2.0.0  wheel
+    Package             Version Latest Type
+    ------------------- ------- ------ -----
+    iniconfig           1.0.0   2.0.0  wheel
+    uv-public-pypackage 0.1.0          git
 
     ----- stderr -----
     "
@@ -378,6 +383,126 @@ fn list_outdated_index() -> Result<()> {
     Ok(())
 }
 
+/// Packages installed from a URL, path, or editable install are marked rather than compared against
+/// the index, and failures to fetch the latest version of a package are reported as warnings.
+#[tokio::test]
+async fn list_outdated_sources() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+    let links_dir = context.workspace_root.join("test/links");
+
+    // Install `ok` and `validation` from the registry, and `basic-package` from a local wheel.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("ok==1.0.0")
+        .arg("validation==1.0.0")
+        .arg(format!(
+            "basic-package @ {}",
+            Url::from_file_path(links_dir.join("basic_package-0.1.0-py3-none-any.whl")).unwrap()
+        ))
+        .arg("--find-links")
+        .arg(&links_dir)
+        .arg("--no-index"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + basic-package==0.1.0 (from file://[WORKSPACE]/test/links/basic_package-0.1.0-py3-none-any.whl)
+     + ok==1.0.0
+     + validation==1.0.0
+    "
+    );
+
+    // Add a legacy editable install.
+    let target = context.temp_dir.child("zstandard_project");
+    target.child("zstandard.egg-info").create_dir_all()?;
+    target
+        .child("zstandard.egg-info")
+        .child("PKG-INFO")
+        .write_str("Metadata-Version: 2.1\nName: zstandard\nVersion: 0.22.0\n")?;
+    ChildPath::new(context.site_packages())
+        .child("zstandard.egg-link")
+        .write_str(target.path().to_str().unwrap())?;
+
+    // Serve a newer version of `ok`, but fail to serve `validation`.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/ok/"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(
+                serde_json::json!({
+                    "meta": { "api-version": "1.1" },
+                    "name": "ok",
+                    "files": [{
+                        "filename": "ok-2.0.0-py3-none-any.whl",
+                        "url": format!("{}/files/ok-2.0.0-py3-none-any.whl", server.uri()),
+                        "hashes": {},
+                    }],
+                })
+                .to_string(),
+                "application/vnd.pypi.simple.v1+json",
+            ),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/simple/validation/"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"\-\-\-\-\-\-+.*", "[UNDERLINE]"), ("  +", " ")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_list()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_HTTP_RETRIES, "0")
+        .arg("--outdated")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri())), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Latest Type Editable project location Editable mode
+    [UNDERLINE]
+    basic-package 0.1.0 path
+    ok 1.0.0 2.0.0 wheel
+    zstandard 0.22.0 editable [TEMP_DIR]/zstandard_project legacy
+
+    ----- stderr -----
+    warning: Failed to fetch the latest version of `validation`
+     Caused by: Failed to fetch: `http://[LOCALHOST]/simple/validation/`
+     Caused by: HTTP status server error (500 Internal Server Error) for url (http://[LOCALHOST]/simple/validation/)
+    ");
+
+    uv_snapshot!(filters, context.pip_list()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_HTTP_RETRIES, "0")
+        .arg("--outdated")
+        .arg("--format")
+        .arg("json")
+        .arg("--index-url")
+        .arg(format!("{}/simple", server.uri())), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"basic-package","version":"0.1.0","source":"path"},{"name":"ok","version":"1.0.0","latest_version":"2.0.0","latest_filetype":"wheel"},{"name":"zstandard","version":"0.22.0","source":"editable","editable_project_location":"[TEMP_DIR]/zstandard_project","legacy_editable":true}]
+
+    ----- stderr -----
+    warning: Failed to fetch the latest version of `validation`
+     Caused by: Failed to fetch: `http://[LOCALHOST]/simple/validation/`
+     Caused by: HTTP status server error (500 Internal Server Error) for url (http://[LOCALHOST]/simple/validation/)
+    "#);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "test-pypi")]
 fn list_editable() {
@@ -761,14 +886,26 @@ Version: 0.22.0
     success: true
     exit_code: 0
     ----- stdout -----
-    Package Version Editable project location
+    Package Version Editable project location Editable mode
     [UNDERLINE]
-    zstandard 0.22.0 [TEMP_DIR]/zstandard_project
+    zstandard 0.22.0 [TEMP_DIR]/zstandard_project legacy
 
     ----- stderr -----
     "
     );
 
+    uv_snapshot!(filters, context.pip_list()
+        .arg("--editable")
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"zstandard","version":"0.22.0","editable_project_location":"[TEMP_DIR]/zstandard_project","legacy_editable":true}]
+
+    ----- stderr -----
+    "#);
+
     Ok(())
 }
 
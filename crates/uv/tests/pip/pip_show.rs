@@ -2,13 +2,16 @@ use std::env::current_dir;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
+use assert_fs::fixture::ChildPath;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
+use insta::assert_snapshot;
+use url::Url;
 
 use uv_static::EnvVars;
 
-use uv_test::uv_snapshot;
+use uv_test::{TestContext, uv_snapshot};
 
 #[test]
 fn show_empty() {
@@ -512,6 +515,7 @@ fn show_files() {
       requests-2.31.0.dist-info/REQUESTED
       requests-2.31.0.dist-info/WHEEL
       requests-2.31.0.dist-info/top_level.txt
+      requests-2.31.0.dist-info/uv_reason.json
       requests/__init__.py
       requests/__version__.py
       requests/_internal_utils.py
@@ -535,6 +539,113 @@ fn show_files() {
     ");
 }
 
+/// Show multiple packages from a seeded environment, including their installed files and reverse
+/// dependencies, and legacy `.egg-info` installs that may not record their files.
+#[test]
+fn show_files_seeded() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let site_packages = ChildPath::new(context.site_packages());
+
+    for (name, requires_dist) in [("app", "Requires-Dist: lib>=1.0\n"), ("lib", "")] {
+        let dist_info = site_packages.child(format!("{name}-1.0.0.dist-info"));
+        dist_info.child("METADATA").write_str(&format!(
+            "Metadata-Version: 2.1\nName: {name}\nVersion: 1.0.0\n{requires_dist}"
+        ))?;
+        dist_info
+            .child("WHEEL")
+            .write_str("Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n")?;
+        dist_info.child("RECORD").write_str(&formatdoc! {"
+            {name}/__init__.py,,
+            {name}-1.0.0.dist-info/METADATA,,
+            {name}-1.0.0.dist-info/WHEEL,,
+            {name}-1.0.0.dist-info/RECORD,,
+        "})?;
+    }
+
+    // A `setup.py install`-style package, which records its files in `installed-files.txt`.
+    let egg_info = site_packages.child("legacy-2.0.0-py3.12.egg-info");
+    egg_info
+        .child("PKG-INFO")
+        .write_str("Metadata-Version: 1.0\nName: legacy\nVersion: 2.0.0\n")?;
+    egg_info.child("installed-files.txt").write_str(indoc! {"
+        ../legacy/__init__.py
+        PKG-INFO
+        installed-files.txt
+    "})?;
+
+    // A package that doesn't record its files at all.
+    site_packages
+        .child("bare-3.0.0-py3.12.egg-info")
+        .child("PKG-INFO")
+        .write_str("Metadata-Version: 1.0\nName: bare\nVersion: 3.0.0\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("lib")
+        .arg("app")
+        .arg("legacy")
+        .arg("bare")
+        .arg("unknown")
+        .arg("--files"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: app
+    Version: 1.0.0
+    Location: [SITE_PACKAGES]/
+    Requires: lib
+    Required-by:
+    Files:
+      app/__init__.py
+      app-1.0.0.dist-info/METADATA
+      app-1.0.0.dist-info/WHEEL
+      app-1.0.0.dist-info/RECORD
+    ---
+    Name: bare
+    Version: 3.0.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Files:
+    Cannot locate RECORD or installed-files.txt
+    ---
+    Name: legacy
+    Version: 2.0.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Files:
+      legacy/__init__.py
+      legacy-2.0.0-py3.12.egg-info/PKG-INFO
+      legacy-2.0.0-py3.12.egg-info/installed-files.txt
+    ---
+    Name: lib
+    Version: 1.0.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by: app
+    Files:
+      lib/__init__.py
+      lib-1.0.0.dist-info/METADATA
+      lib-1.0.0.dist-info/WHEEL
+      lib-1.0.0.dist-info/RECORD
+
+    ----- stderr -----
+    warning: Package(s) not found for: unknown
+    ");
+
+    // Like pip, fail if none of the packages are installed.
+    uv_snapshot!(context.filters(), context.pip_show().arg("unknown"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Package(s) not found for: unknown
+    ");
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "test-pypi")]
 fn show_target() -> Result<()> {
@@ -638,3 +749,275 @@ fn show_prefix() -> Result<()> {
 
     Ok(())
 }
+
+/// Create a local package with a minimal in-tree build backend, so it can be built offline.
+fn write_local_package(
+    context: &TestContext,
+    name: &str,
+    requires_dist: &[String],
+) -> Result<ChildPath> {
+    let requires_dist_metadata = requires_dist
+        .iter()
+        .map(|req| format!("Requires-Dist: {req}\\n"))
+        .collect::<String>();
+    let dir = context.in_tree_backend(
+        name,
+        "0.1.0",
+        &formatdoc! {r#"
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                return write_wheel(wheel_directory, metadata=METADATA + "{requires_dist_metadata}")
+        "#},
+    )?;
+    dir.child("pyproject.toml").write_str(&formatdoc! {r#"
+        [project]
+        name = "{name}"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = {requires_dist:?}
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    Ok(dir)
+}
+
+/// `--why` shows the recorded reason for installing each package, which is kept up-to-date as the
+/// package is requested again.
+#[test]
+fn show_why() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = write_local_package(&context, "child", &[])?;
+    let child_url = Url::from_directory_path(child.path()).unwrap();
+    write_local_package(&context, "parent", &[format!("child @ {child_url}")])?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./parent")?;
+
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("child")
+        .arg("parent")
+        .arg("--why"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: child
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by: parent
+    Install-reason: required by parent (pip)
+    ---
+    Name: parent
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires: child
+    Required-by:
+    Install-reason: requested directly (pip)
+
+    ----- stderr -----
+    ");
+
+    let reason = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("child-0.1.0.dist-info")
+            .join("uv_reason.json"),
+    )?;
+    assert_snapshot!(reason, @r#"{"context":"pip","requested-by":[{"kind":"dependency","package":"parent"}]}"#);
+
+    // Requesting the package directly updates its reason, without reinstalling it.
+    requirements_txt.write_str("./child")?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("child")
+        .arg("--why"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: child
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by: parent
+    Install-reason: requested directly, required by parent (pip)
+
+    ----- stderr -----
+    ");
+
+    // Once the dependent is removed, only the direct request remains.
+    context.pip_uninstall().arg("parent").assert().success();
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("child")
+        .arg("--why"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: child
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Install-reason: requested directly (pip)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// `--why` falls back to the installer for packages that weren't installed by uv.
+#[test]
+fn show_why_other_installer() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let dist_info = context.site_packages().join("foreign-1.0.0.dist-info");
+    fs_err::create_dir_all(&dist_info)?;
+    fs_err::write(
+        dist_info.join("METADATA"),
+        "Metadata-Version: 2.1\nName: foreign\nVersion: 1.0.0\n",
+    )?;
+    fs_err::write(dist_info.join("INSTALLER"), "pip\n")?;
+    fs_err::write(dist_info.join("RECORD"), "")?;
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("foreign")
+        .arg("--why"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: foreign
+    Version: 1.0.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by:
+    Install-reason: unknown (installed by pip)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// `--why` shows the paths to the package in the lockfile of the current project.
+#[test]
+fn show_why_lock_paths() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    write_local_package(&context, "child", &[])?;
+    let parent = write_local_package(&context, "parent", &["child".to_string()])?;
+    let pyproject_toml = fs_err::read_to_string(parent.child("pyproject.toml"))?;
+    parent.child("pyproject.toml").write_str(&format!(
+        "{pyproject_toml}\n[tool.uv.sources]\nchild = {{ path = \"../child\" }}\n"
+    ))?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["parent"]
+
+        [tool.uv.sources]
+        parent = { path = "parent" }
+    "#})?;
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("child")
+        .arg("--why"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: child
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by: parent
+    Install-reason: required by parent (project)
+    Lock-paths:
+      child v0.1.0
+      └── parent v0.1.0
+          └── project v0.1.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// `--why` reads the lockfile of the project that owns the environment, rather than the project in
+/// the current directory.
+#[test]
+fn show_why_lock_paths_environment_project() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = write_local_package(&context, "child", &[])?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.sources]
+        child = { path = "child" }
+    "#})?;
+
+    context.sync().assert().success();
+
+    // `child` is a project of its own, but its environment isn't the active one.
+    uv_snapshot!(context.filters(), context.pip_show()
+        .arg("child")
+        .arg("--why")
+        .current_dir(&child), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Name: child
+    Version: 0.1.0
+    Location: [SITE_PACKAGES]/
+    Requires:
+    Required-by: project
+    Install-reason: required by project (project)
+    Lock-paths:
+      child v0.1.0
+      └── project v0.1.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
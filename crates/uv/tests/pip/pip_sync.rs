@@ -1,4 +1,6 @@
+use std::collections::BTreeMap;
 use std::env::consts::EXE_SUFFIX;
+use std::str::FromStr;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
@@ -12,7 +14,11 @@ use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use uv_fs::{Simplified, copy_dir_all};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_static::EnvVars;
+use uv_test::packse::PackseServer;
+use uv_test::packse::scenario::{Package, PackageMetadata, Scenario};
 use uv_test::{download_to_disk, site_packages_path, uv_snapshot};
 
 #[test]
@@ -138,6 +144,59 @@ fn install() -> Result<()> {
     Ok(())
 }
 
+/// Reject a package that's excluded with `--exclude`, whether it's required directly or read from a
+/// `pylock.toml`, which is installed without resolving.
+#[test]
+fn install_excluded_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--exclude")
+        .arg("iniconfig"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` is excluded from the resolution
+    "
+    );
+
+    let pylock_toml = context.temp_dir.child("pylock.toml");
+    pylock_toml.write_str(indoc! {r#"
+        lock-version = "1.0"
+        created-by = "uv"
+        requires-python = ">=3.12"
+
+        [[packages]]
+        name = "iniconfig"
+        version = "2.0.0"
+        wheels = [{ url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", upload-time = 2023-01-07T11:08:09Z, size = 5892, hashes = { sha256 = "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" } }]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--exclude")
+        .arg("iniconfig"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `iniconfig` is excluded from the resolution, but is included in the packages to install
+    "
+    );
+
+    context.assert_not_installed("iniconfig");
+
+    Ok(())
+}
+
 /// Install a package into a virtual environment using copy semantics.
 #[test]
 fn install_copy() -> Result<()> {
@@ -1168,6 +1227,226 @@ fn warn_on_yanked_dry_run() -> Result<()> {
     Ok(())
 }
 
+/// Sync from a `pylock.toml` that pins a yanked version, which the lockfile doesn't record.
+#[test]
+fn warn_on_yanked_pylock() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.2"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    // Under `--yanked forbid`, the sync fails.
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml")
+        .arg("--yanked")
+        .arg("forbid"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `colorama==0.4.2` has been yanked (reason: "Bad build, missing files, will not install"), but yanked versions are forbidden by the `yanked` policy
+    "#
+    );
+
+    // By default, the yanked version is installed with a warning.
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_sync()
+        .arg("--preview")
+        .arg("pylock.toml"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + colorama==0.4.2
+    "#
+    );
+
+    Ok(())
+}
+
+/// Report the packages that a sync would install, remove, and replace, without modifying the
+/// environment.
+#[test]
+fn dry_run_sync_plan() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    for (name, versions) in [
+        ("kept", vec!["1.0.0"]),
+        ("added", vec!["1.0.0"]),
+        ("removed", vec!["1.0.0"]),
+        ("upgraded", vec!["1.0.0", "2.0.0"]),
+    ] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: versions
+                    .into_iter()
+                    .map(|version| {
+                        Ok((
+                            Version::from_str(version)?,
+                            PackageMetadata {
+                                requires_python: None,
+                                wheel: true,
+                                ..PackageMetadata::default()
+                            },
+                        ))
+                    })
+                    .collect::<Result<BTreeMap<_, _>>>()?,
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    // Seed the environment.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("kept==1.0.0\nremoved==1.0.0\nupgraded==1.0.0")?;
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .assert()
+        .success();
+
+    requirements_txt.write_str("kept==1.0.0\nadded==1.0.0\nupgraded==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 2 packages
+    Would uninstall 2 packages
+    Would install 2 packages
+     + added==1.0.0
+     - removed==1.0.0
+     - upgraded==1.0.0
+     + upgraded==2.0.0
+    Would upgrade upgraded 1.0.0 -> 2.0.0
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"download":[{"name":"added","version":"1.0.0"},{"name":"upgraded","version":"2.0.0"}],"build":[],"changes":[{"name":"added","action":"install","to":"1.0.0"},{"name":"removed","action":"remove","from":"1.0.0"},{"name":"upgraded","action":"upgrade","from":"1.0.0","to":"2.0.0"}]}
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 2 packages
+    Would uninstall 2 packages
+    Would install 2 packages
+     + added==1.0.0
+     - removed==1.0.0
+     - upgraded==1.0.0
+     + upgraded==2.0.0
+    "#);
+
+    // With `--exit-code`, exit with a failure if the environment would change.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("--exit-code")
+        .arg("--quiet"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // The environment should be unchanged.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    kept==1.0.0
+    removed==1.0.0
+    upgraded==1.0.0
+
+    ----- stderr -----
+    ");
+
+    // Once the environment is in sync, `--exit-code` succeeds.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("--exit-code"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Checked 3 packages in [TIME]
+    Would make no changes
+    ");
+
+    // `--exit-code` requires `--dry-run`.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--exit-code"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --dry-run
+
+    Usage: uv pip sync --dry-run --cache-dir [CACHE_DIR] --exit-code --exclude-newer <EXCLUDE_NEWER> <SRC_FILE>...
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
 /// Resolve a local wheel.
 #[test]
 fn install_local_wheel() -> Result<()> {
@@ -2863,6 +3142,7 @@ fn find_links_no_index_match() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[WORKSPACE]/test/links`, which does not provide upload times: `tqdm`
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
@@ -2893,6 +3173,7 @@ fn find_links_offline_match() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[WORKSPACE]/test/links`, which does not provide upload times: `tqdm`
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
@@ -4161,12 +4442,11 @@ fn require_hashes_git() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to download and build `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
-      ╰─▶ Hash-checking is not supported for Git repositories: `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82`
+    error: In `--require-hashes` mode, all requirements must refer to an archive that can be hashed, but `anyio @ git+https://github.com/agronholm/anyio@4a23745badf5bf5ef7928f1e346e9986bd696d82` (from `requirements.txt`) refers to a Git repository
     "
     );
 
@@ -4191,12 +4471,11 @@ fn require_hashes_source_tree() -> Result<()> {
         .arg("requirements.txt")
         .arg("--require-hashes"), @"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-      × Failed to build `black @ file://[WORKSPACE]/test/packages/black_editable`
-      ╰─▶ Hash-checking is not supported for local directories: `black @ file://[WORKSPACE]/test/packages/black_editable`
+    error: In `--require-hashes` mode, all requirements must refer to an archive that can be hashed, but `black @ file://[WORKSPACE]/test/packages/black_editable` (from `requirements.txt`) refers to a local directory
     "
     );
 
@@ -4464,7 +4743,7 @@ fn require_hashes_editable() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/test/packages/black_editable[d]
+    error: In `--require-hashes` mode, all requirements must refer to an archive that can be hashed, but `file://[WORKSPACE]/test/packages/black_editable[d]` (from `requirements.txt`) refers to an editable directory
     "
     );
 
@@ -5597,6 +5876,83 @@ fn target_no_build_isolation() -> Result<()> {
     Ok(())
 }
 
+/// Disable build isolation for a single package with `--no-build-isolation-package`, e.g., for
+/// packages like `flash-attn` that import `torch` at build time.
+#[test]
+fn no_build_isolation_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
+
+    // Create a `torch` stand-in, and a package that imports it from its build backend.
+    for (name, import) in [("torch", ""), ("flash-attn", "import torch\n")] {
+        context.in_tree_backend(name, "1.0.0", import)?;
+    }
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./torch")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + torch==1.0.0 (from file://[TEMP_DIR]/torch)
+    ");
+
+    requirements_txt.write_str("./torch\n./flash-attn")?;
+
+    // The build fails in an isolated environment, as `torch` isn't declared as a build dependency.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+      × Failed to build `flash-attn @ file://[TEMP_DIR]/flash-attn`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stderr]
+          Traceback (most recent call last):
+            File "<string>", line 8, in <module>
+            File "[TEMP_DIR]/flash-attn/backend.py", line 49, in <module>
+              import torch
+          ModuleNotFoundError: No module named 'torch'
+
+    hint: This error likely indicates that `flash-attn@1.0.0` depends on `torch`, but doesn't declare it as a build dependency. If `flash-attn` is a first-party package, consider adding `torch` to its `build-system.requires`. Otherwise, either add it to your `pyproject.toml` under:
+
+    [tool.uv.extra-build-dependencies]
+    flash-attn = ["torch"]
+
+    or `uv pip install torch` into the environment and re-run with `--no-build-isolation-package flash-attn`.
+    "#);
+
+    // Disabling build isolation for `flash-attn` exposes the installed `torch` to its build.
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--no-build-isolation-package")
+        .arg("flash-attn"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + flash-attn==1.0.0 (from file://[TEMP_DIR]/flash-attn)
+    ");
+
+    Ok(())
+}
+
 /// Sync to a `--target` directory without a virtual environment.
 #[test]
 fn target_system() -> Result<()> {
@@ -5748,6 +6104,8 @@ fn incompatible_build_constraint() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     "
     );
 
@@ -6542,3 +6900,44 @@ fn sync_with_target_installs_missing_python() -> Result<()> {
     );
     Ok(())
 }
+
+/// An extraneous legacy editable install whose metadata is missing can't be uninstalled, so its
+/// location is reported instead.
+#[test]
+fn sync_unmanaged_legacy_editable() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let site_packages = ChildPath::new(context.site_packages());
+
+    // A legacy editable install of `legacy`, whose `.egg-info` directory was removed.
+    let project = context.temp_dir.child("legacy_project");
+    project.child("legacy").child("__init__.py").touch()?;
+    site_packages
+        .child("legacy.egg-link")
+        .write_str(&format!("{}\n.\n", project.path().display()))?;
+    site_packages
+        .child("easy-install.pth")
+        .write_str(&format!("{}\n", project.path().display()))?;
+
+    context.in_tree_backend("child", "0.1.0", "")?;
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./child")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: The legacy editable install of `legacy` at [SITE_PACKAGES]/legacy.egg-link is extraneous, but can't be uninstalled, as its metadata is missing; remove it and the `[TEMP_DIR]/legacy_project` entry from [SITE_PACKAGES]/easy-install.pth manually
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // The legacy editable install is left in place.
+    assert!(site_packages.child("legacy.egg-link").exists());
+
+    Ok(())
+}
@@ -592,7 +592,7 @@ fn cycle() {
     ├── fixtures v3.0.0
     │   ├── pbr v6.0.0
     │   ├── six v1.16.0
-    │   └── testtools v2.3.0 (*)
+    │   └── testtools v2.3.0 (cycle)
     ├── pbr v6.0.0
     ├── python-mimeparse v1.6.0
     ├── six v1.16.0
@@ -603,6 +603,84 @@ fn cycle() {
         ├── six v1.16.0
         └── traceback2 v1.4.0 (*)
     (*) Package tree already displayed
+    (cycle) Package tree is a cycle and cannot be shown
+
+    ----- stderr -----
+    "
+    );
+}
+
+/// Show dependencies that are only required by an installed extra.
+#[test]
+#[cfg(feature = "test-pypi")]
+fn extra() {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str(
+            r"
+        requests[socks]==2.31.0
+        certifi==2024.2.2
+        charset-normalizer==3.3.2
+        idna==3.6
+        pysocks==1.7.1
+        urllib3==2.2.1
+    ",
+        )
+        .unwrap();
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + pysocks==1.7.1
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_tree(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    requests v2.31.0
+    ├── certifi v2024.2.2
+    ├── charset-normalizer v3.3.2
+    ├── idna v3.6
+    ├── pysocks v1.7.1 (extra: socks)
+    └── urllib3 v2.2.1
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_tree().arg("--invert"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    certifi v2024.2.2
+    └── requests v2.31.0
+    charset-normalizer v3.3.2
+    └── requests v2.31.0
+    idna v3.6
+    └── requests v2.31.0
+    pysocks v1.7.1
+    └── requests v2.31.0 (extra: socks)
+    urllib3 v2.2.1
+    └── requests v2.31.0
 
     ----- stderr -----
     "
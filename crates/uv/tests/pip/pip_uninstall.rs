@@ -1,11 +1,19 @@
 #[cfg(windows)]
 use std::path::{Component, Prefix};
 
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::fixture::ChildPath;
 use assert_fs::prelude::*;
 
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
+use uv_pep508::Requirement;
+use uv_test::packse::PackseServer;
+use uv_test::packse::scenario::{Package, PackageMetadata, Scenario};
 use uv_test::uv_snapshot;
 
 #[test]
@@ -692,7 +700,7 @@ fn uninstall_egg_info_top_level_drive_relative() -> Result<()> {
     Ok(())
 }
 
-/// `--yes` is accepted for `pip uninstall` compatibility, but emits a warning.
+/// `--yes` is accepted for `pip uninstall` compatibility.
 #[test]
 fn yes_flag() {
     let context = uv_test::test_context!("3.12");
@@ -705,14 +713,13 @@ fn yes_flag() {
     ----- stdout -----
 
     ----- stderr -----
-    warning: `--yes` has no effect (uv never asks for confirmation)
     warning: Skipping flask as it is not installed
     warning: No packages to uninstall
     "
     );
 }
 
-/// `-y` is accepted for `pip uninstall` compatibility, but emits a warning.
+/// `-y` is accepted for `pip uninstall` compatibility.
 #[test]
 fn yes_short_flag() {
     let context = uv_test::test_context!("3.12");
@@ -725,9 +732,199 @@ fn yes_short_flag() {
     ----- stdout -----
 
     ----- stderr -----
-    warning: `--yes` has no effect (uv never asks for confirmation)
     warning: Skipping flask as it is not installed
     warning: No packages to uninstall
     "
     );
 }
+
+/// Warn when uninstalling a package that other installed packages depend on, and remove
+/// dependencies that are no longer required with `--unneeded`.
+#[test]
+fn uninstall_dependents_and_unneeded() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    for (name, requires) in [
+        ("app", vec!["lib", "helper", "shared"]),
+        ("other", vec!["lib"]),
+        ("lib", vec!["base"]),
+        ("helper", vec![]),
+        ("base", vec![]),
+        ("shared", vec![]),
+    ] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires: requires
+                            .into_iter()
+                            .map(Requirement::from_str)
+                            .collect::<Result<_, _>>()?,
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    // `shared` is requested directly, so it's never removed as an unneeded dependency.
+    context
+        .pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("app")
+        .arg("other")
+        .arg("shared")
+        .assert()
+        .success();
+
+    // Removing `lib` would break both `app` and `other`.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--dry-run")
+        .arg("lib"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Uninstalling would leave the following packages with unsatisfied dependencies:
+      - app==1.0.0 requires lib
+      - other==1.0.0 requires lib
+    Would uninstall 1 package
+     - lib==1.0.0
+    ");
+
+    // `lib` is still required by `other`, but `helper` is only required by `app`.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--dry-run")
+        .arg("--unneeded")
+        .arg("app"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 2 packages
+     - app==1.0.0
+     - helper==1.0.0
+    ");
+
+    // Removing both dependents cascades to `lib` and, in turn, `base`.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--unneeded")
+        .arg("app")
+        .arg("other"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 5 packages in [TIME]
+     - app==1.0.0
+     - base==1.0.0
+     - helper==1.0.0
+     - lib==1.0.0
+     - other==1.0.0
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_freeze(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    shared==1.0.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Dependencies that an installed package requires through one of its extras are retained by
+/// `--unneeded`, and reported as broken if uninstalled.
+#[test]
+fn uninstall_unneeded_respects_extras() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("app")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    extras: BTreeMap::from([(
+                        ExtraName::from_str("fast")?,
+                        vec![Requirement::from_str("speedups")?],
+                    )]),
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    for (name, requires) in [("tool", vec!["speedups"]), ("speedups", vec![])] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires: requires
+                            .into_iter()
+                            .map(Requirement::from_str)
+                            .collect::<Result<_, _>>()?,
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    context
+        .pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("app[fast]")
+        .arg("tool")
+        .assert()
+        .success();
+
+    // `speedups` is still required by `app[fast]`.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--dry-run")
+        .arg("--unneeded")
+        .arg("tool"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would uninstall 1 package
+     - tool==1.0.0
+    ");
+
+    // Removing `speedups` would break both `app[fast]` and `tool`.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("--dry-run")
+        .arg("speedups"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Uninstalling would leave the following packages with unsatisfied dependencies:
+      - app==1.0.0 requires speedups ; extra == 'fast'
+      - tool==1.0.0 requires speedups
+    Would uninstall 1 package
+     - speedups==1.0.0
+    ");
+
+    Ok(())
+}
@@ -20,7 +20,7 @@ use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use uv_fs::Simplified;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::Requirement;
 use uv_static::EnvVars;
@@ -535,15 +535,13 @@ fn compile_constraints_markers() -> Result<()> {
 }
 
 /// Resolve a package from a `requirements.in` file, with a `constraints.txt` file that uses an
-/// extra. The constraint should be enforced, but the extra should _not_ be included in the output
-/// (though it currently _is_ included).
+/// extra. Like `pip`, constraints with extras should be rejected.
 #[test]
 fn compile_constraint_extra() -> Result<()> {
     let context = uv_test::test_context!("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
     requirements_in.write_str("flask")?;
 
-    // Constrain a transitive dependency based on the Python version
     let constraints_txt = context.temp_dir.child("constraints.txt");
     constraints_txt.write_str("flask[dotenv]<24.3.0")?;
 
@@ -551,32 +549,74 @@ fn compile_constraint_extra() -> Result<()> {
             .arg("requirements.in")
             .arg("--constraint")
             .arg("constraints.txt"), @"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt
-    blinker==1.7.0
-        # via flask
-    click==8.1.7
-        # via flask
-    flask==3.0.2
-        # via
-        #   -c constraints.txt
-        #   -r requirements.in
-    itsdangerous==2.1.2
-        # via flask
-    jinja2==3.1.3
-        # via flask
-    markupsafe==2.1.5
-        # via
-        #   jinja2
-        #   werkzeug
-    werkzeug==3.0.1
-        # via flask
 
     ----- stderr -----
-    Resolved 7 packages in [TIME]
+    error: Constraints cannot have extras (found: `flask[dotenv]<24.3.0`) at constraints.txt:1:1
+    "
+    );
+
+    Ok(())
+}
+
+/// Resolve a package from a `requirements.in` file, with a `constraints.txt` file that includes
+/// an editable. Editables can't be applied as constraints, so they should be rejected.
+#[test]
+fn compile_constraint_editable() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc::indoc! {r"
+        idna<3.4
+        -e ../../test/packages/poetry_editable
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--constraint")
+            .arg("constraints.txt"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Editable requirements are not allowed as constraints at constraints.txt:2:1
+    "
+    );
+
+    Ok(())
+}
+
+/// Resolve a package from a `requirements.in` file that includes a `constraints.txt` file with an
+/// extra. The nested constraint should be rejected with its location.
+#[test]
+fn compile_constraint_extra_nested() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc::indoc! {r"
+        flask
+        -c constraints.txt
+    "})?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc::indoc! {r"
+        # Pin flask.
+        flask[dotenv]<24.3.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Error parsing included file in `requirements.in` at position 6
+      Caused by: Constraints cannot have extras (found: `flask[dotenv]<24.3.0`) at constraints.txt:2:1
     "
     );
 
@@ -1184,6 +1224,7 @@ setup(
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] setup.cfg --extra dev
+    # Dependencies extracted from legacy project: setup.cfg
     anyio==4.3.0
         # via dummypkg (setup.cfg)
     idna==3.6
@@ -1232,6 +1273,7 @@ setup(
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] setup.py --extra dev
+    # Dependencies extracted from legacy project: setup.py
     anyio==4.3.0
         # via dummypkg (setup.py)
     idna==3.6
@@ -1249,6 +1291,42 @@ setup(
     Ok(())
 }
 
+/// Compile a `setup.py` file for which the legacy metadata build fails, retaining the build
+/// failure hint.
+#[test]
+fn compile_setup_py_build_failure() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let setup_py = context.temp_dir.child("setup.py");
+    setup_py.write_str(indoc! {r#"
+        import sys
+
+        sys.stderr.write("broken.c:1:10: fatal error: graphviz/cgraph.h: No such file or directory\n")
+        sys.exit(1)
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("setup.py")
+            .arg("--no-cache"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to extract dependencies from legacy project: `setup.py`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          broken.c:1:10: fatal error: graphviz/cgraph.h: No such file or directory
+
+    hint: This error likely indicates that you need to install a library that provides "graphviz/cgraph.h" for `file://[TEMP_DIR]/`
+    "#
+    );
+
+    Ok(())
+}
+
 /// Resolve a `pyproject.toml` file with an invalid project name.
 #[test]
 fn compile_pyproject_toml_invalid_name() -> Result<()> {
@@ -1541,7 +1619,11 @@ fn compile_python_312_annotation_line() -> Result<()> {
 /// Compile for 3.12 when only a different interpreter version is available.
 #[test]
 fn compile_fallback_interpreter() -> Result<()> {
-    let context = uv_test::test_context!("3.10").with_filtered_python_sources();
+    let context = uv_test::test_context!("3.10")
+        .with_filtered_python_sources()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_python_names()
+        .with_filtered_exe_suffix();
     let requirements_in = context.temp_dir.child("requirements.in");
     requirements_in.write_str("black==23.10.1")?;
 
@@ -1672,6 +1754,10 @@ fn compile_fallback_interpreter() -> Result<()> {
     error: No interpreter found for PyPy in [PYTHON SOURCES]
 
     hint: A managed Python download is available for PyPy, but Python downloads are set to 'never'
+
+    hint: Found 2 Python interpreters, but none are compatible with PyPy:
+      - CPython 3.10.[X] at `.venv/[BIN]/[PYTHON]`: incompatible with PyPy
+      - CPython 3.10.[X] at `[PYTHON-3.10]`: incompatible with PyPy
     "
     );
 
@@ -1688,6 +1774,10 @@ fn compile_fallback_interpreter() -> Result<()> {
     error: No interpreter found for Python >=3.12 in [PYTHON SOURCES]
 
     hint: A managed Python download is available for Python >=3.12, but Python downloads are set to 'never'
+
+    hint: Found 2 Python interpreters, but none are compatible with Python >=3.12:
+      - CPython 3.10.[X] at `.venv/[BIN]/[PYTHON]`: incompatible with Python >=3.12
+      - CPython 3.10.[X] at `[PYTHON-3.10]`: incompatible with Python >=3.12
     "
     );
 
@@ -1894,6 +1984,11 @@ fn compile_python_build_version_different_than_target() -> Result<()> {
     error: No interpreter found for PyPy 3.11 in [PYTHON SOURCES]
 
     hint: A managed Python download is available for PyPy 3.11, but Python downloads are set to 'never'
+
+    hint: Found 3 Python interpreters, but none are compatible with PyPy 3.11:
+      - CPython 3.12.[X] at `[PYTHON-3.12]`: incompatible with PyPy 3.11
+      - CPython 3.10.[X] at `[PYTHON-3.10]`: incompatible with PyPy 3.11
+      - CPython 3.11.[X] at `[PYTHON-3.11]`: incompatible with PyPy 3.11
     "
     );
 
@@ -5458,6 +5553,77 @@ fn generate_hashes_registry() -> Result<()> {
     Ok(())
 }
 
+/// Include the registry hash of the source distribution for a package that only publishes a
+/// source distribution, rather than the hash of the wheel built from it, such that the output can
+/// be installed with `--require-hashes`.
+#[test]
+fn generate_hashes_source_distribution_only() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"--hash=sha256:[0-9a-f]{64}", "--hash=sha256:[HASH]")])
+        .collect::<Vec<_>>();
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("source-distribution==0.0.1")?;
+
+    uv_snapshot!(filters, context.pip_compile()
+        .arg("requirements.in")
+        .arg("--generate-hashes")
+        .arg("--output-file")
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --generate-hashes --output-file requirements.txt
+    source-distribution==0.0.1 \
+        --hash=sha256:[HASH]
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // The hash matches the published source distribution, so the output is compatible with
+    // `--require-hashes`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--require-hashes")
+        .arg("--dry-run")
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + source-distribution==0.0.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--require-hashes")
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    "
+    );
+
+    Ok(())
+}
+
 /// Include hashes from the URL in the generated output.
 #[test]
 fn generate_hashes_source_distribution_url() -> Result<()> {
@@ -5557,6 +5723,7 @@ fn generate_hashes_git() -> Result<()> {
 
     ----- stderr -----
     Resolved 3 packages in [TIME]
+    warning: Omitted hashes for `anyio`, since Git and local directory dependencies can't be hashed; the output is not compatible with `--require-hashes`
     "
     );
 
@@ -5636,6 +5803,7 @@ fn generate_hashes_local_directory() -> Result<()> {
 
     ----- stderr -----
     Resolved 4 packages in [TIME]
+    warning: Omitted hashes for `poetry-editable`, since Git and local directory dependencies can't be hashed; the output is not compatible with `--require-hashes`
     ");
 
     Ok(())
@@ -5679,6 +5847,7 @@ fn generate_hashes_editable() -> Result<()> {
 
     ----- stderr -----
     Resolved 4 packages in [TIME]
+    warning: Omitted hashes for `poetry-editable`, since Git and local directory dependencies can't be hashed; the output is not compatible with `--require-hashes`
     ");
 
     Ok(())
@@ -6585,80 +6754,423 @@ fn custom_compile_command() -> Result<()> {
     Ok(())
 }
 
-/// Emit warnings when users pass redundant options from `pip-compile`.
+/// Render the same resolution with each annotation style and header option, including a package
+/// that is required by multiple parents and one that is only required via an extra.
 #[test]
-fn allow_unsafe() -> Result<()> {
+fn annotation_and_header_styles() -> Result<()> {
     let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("a")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    requires: vec![Requirement::from_str("c")?],
+                    extras: BTreeMap::from([(
+                        ExtraName::from_str("x")?,
+                        vec![Requirement::from_str("d")?],
+                    )]),
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("b")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    requires: vec![Requirement::from_str("c")?],
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    for name in ["c", "d"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("werkzeug==3.0.1")?;
+    requirements_in.write_str("a[x]\nb")?;
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--allow-unsafe"), @"
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--annotation-style")
+            .arg("split"), @"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --allow-unsafe
-    markupsafe==2.1.5
-        # via werkzeug
-    werkzeug==3.0.1
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotation-style split
+    a==1.0.0
+        # via -r requirements.in
+    b==1.0.0
         # via -r requirements.in
+    c==1.0.0
+        # via
+        #   a
+        #   b
+    d==1.0.0
+        # via a
 
     ----- stderr -----
-    warning: pip-compile's `--allow-unsafe` has no effect (uv can safely pin `pip` and other packages)
-    Resolved 2 packages in [TIME]
-    "
-    );
-
-    Ok(())
-}
-
-/// Emit warnings when users pass redundant options from `pip-compile`.
-#[test]
-fn resolver_legacy() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("werkzeug==3.0.1")?;
+    Resolved 4 packages in [TIME]
+    ");
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--resolver=legacy"), @"
-    success: false
-    exit_code: 2
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--annotation-style")
+            .arg("line"), @"
+    success: true
+    exit_code: 0
     ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotation-style line
+    a==1.0.0                  # via -r requirements.in
+    b==1.0.0                  # via -r requirements.in
+    c==1.0.0                  # via a, b
+    d==1.0.0                  # via a
 
     ----- stderr -----
-    error: pip-compile's `--resolver=legacy` is unsupported (uv always backtracks)
-    "
-    );
-
-    Ok(())
-}
-
-/// Avoid including the `--index` and `-i` flags in the header.
-#[test]
-fn hide_index_urls() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
-    let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str("iniconfig")?;
+    Resolved 4 packages in [TIME]
+    ");
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--default-index")
-            .arg("https://test.pypi.org/simple/"), @"
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--annotation-style")
+            .arg("none"), @"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
-    iniconfig==2.0.0
-        # via -r requirements.in
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotation-style none
+    a==1.0.0
+    b==1.0.0
+    c==1.0.0
+    d==1.0.0
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    "
-    );
+    Resolved 4 packages in [TIME]
+    ");
+
+    // With `--no-strip-extras`, the extra is retained on the requirement.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--annotation-style")
+            .arg("none")
+            .arg("--no-strip-extras")
+            .arg("--no-header"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a[x]==1.0.0
+    b==1.0.0
+    c==1.0.0
+    d==1.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Each line of a custom header is rendered as a comment.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--annotation-style")
+            .arg("none")
+            .arg("--custom-header")
+            .arg("Generated by ./scripts/lock.sh\n\n# Do not edit."), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # Generated by ./scripts/lock.sh
+    #
+    # Do not edit.
+    a==1.0.0
+    b==1.0.0
+    c==1.0.0
+    d==1.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // `--custom-header` conflicts with `--no-header`.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--custom-header")
+            .arg("Generated by ./scripts/lock.sh")
+            .arg("--no-header"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--custom-header <CUSTOM_HEADER>' cannot be used with '--no-header'
+
+    Usage: uv pip compile --cache-dir [CACHE_DIR] --custom-header <CUSTOM_HEADER> --exclude-newer <EXCLUDE_NEWER> <SRC_FILE|--group <GROUP>>
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// Omit pip-compile's "unsafe" packages with `--no-allow-unsafe`, and include them with
+/// `--allow-unsafe`.
+#[test]
+fn allow_unsafe() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("a")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    requires: vec![
+                        Requirement::from_str("setuptools")?,
+                        Requirement::from_str("wheel")?,
+                    ],
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("setuptools")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("70.0.0")?,
+                PackageMetadata {
+                    requires: vec![Requirement::from_str("b")?],
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    for name in ["b", "wheel"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("a")?;
+
+    let mut filters = context.filters();
+    filters.push((r"sha256:[a-f0-9]{64}", "sha256:[HASH]"));
+
+    // By default, the "unsafe" packages are pinned.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--no-header"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a==1.0.0
+        # via -r requirements.in
+    b==1.0.0
+        # via setuptools
+    setuptools==70.0.0
+        # via a
+    wheel==1.0.0
+        # via a
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // With `--no-allow-unsafe`, `setuptools` and `wheel` are omitted, but their dependencies are
+    // retained.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--no-header")
+            .arg("--no-allow-unsafe"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a==1.0.0
+        # via -r requirements.in
+    b==1.0.0
+        # via setuptools
+
+    # The following packages were excluded from the output:
+    # setuptools
+    # wheel
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // The omitted packages don't receive any hashes.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--no-header")
+            .arg("--no-allow-unsafe")
+            .arg("--generate-hashes"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a==1.0.0 \
+        --hash=sha256:[HASH]
+        # via -r requirements.in
+    b==1.0.0 \
+        --hash=sha256:[HASH]
+        # via setuptools
+
+    # The following packages were excluded from the output:
+    # setuptools
+    # wheel
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // `--unsafe-package` replaces the default set of "unsafe" packages.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--no-header")
+            .arg("--no-allow-unsafe")
+            .arg("--unsafe-package")
+            .arg("b"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a==1.0.0
+        # via -r requirements.in
+    setuptools==70.0.0
+        # via a
+    wheel==1.0.0
+        # via a
+
+    # The following packages were excluded from the output:
+    # b
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // With `--allow-unsafe`, even packages passed to `--unsafe-package` are pinned.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(server.index_url())
+            .arg("--no-header")
+            .arg("--unsafe-package")
+            .arg("setuptools")
+            .arg("--allow-unsafe"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a==1.0.0
+        # via -r requirements.in
+    b==1.0.0
+        # via setuptools
+    setuptools==70.0.0
+        # via a
+    wheel==1.0.0
+        # via a
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Emit warnings when users pass redundant options from `pip-compile`.
+#[test]
+fn resolver_legacy() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("werkzeug==3.0.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--resolver=legacy"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: pip-compile's `--resolver=legacy` is unsupported (uv always backtracks)
+    "
+    );
+
+    Ok(())
+}
+
+/// Avoid including the `--index` and `-i` flags in the header.
+#[test]
+fn hide_index_urls() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--default-index")
+            .arg("https://test.pypi.org/simple/"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
@@ -8556,6 +9068,46 @@ fn universal_constraint_marker() -> Result<()> {
     Ok(())
 }
 
+/// Perform a universal resolution with a constraint that only applies to some environments. The
+/// constraint should only apply to the matching fork.
+#[test]
+fn universal_constraint_marker_fork() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("anyio==3.0.0 ; sys_platform == 'win32'")?;
+
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
+            .arg("requirements.in")
+            .arg("-c")
+            .arg("constraints.txt")
+            .arg("--universal"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in -c constraints.txt --universal
+    anyio==3.0.0 ; sys_platform == 'win32'
+        # via
+        #   -c constraints.txt
+        #   -r requirements.in
+    anyio==4.3.0 ; sys_platform != 'win32'
+        # via -r requirements.in
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Perform a universal resolution with a divergent requirement, and a third requirement that's
 /// compatible with both forks.
 ///
@@ -13884,6 +14436,180 @@ fn python_platform() -> Result<()> {
     Ok(())
 }
 
+/// Compile against a dedicated Python implementation and ABI, which may differ from that of the
+/// current interpreter.
+#[test]
+fn python_implementation() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = |tag: &str| -> Result<PackageMetadata> {
+        Ok(PackageMetadata {
+            wheel: true,
+            wheel_tags: vec![tag.parse().map_err(anyhow::Error::msg)?],
+            ..PackageMetadata::default()
+        })
+    };
+
+    let mut scenario = Scenario::empty();
+    // Only the older version of `foo` has a PyPy wheel.
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([
+                (
+                    Version::from_str("1.0.0")?,
+                    wheel("pp312-pypy312_pp73-manylinux_2_17_x86_64")?,
+                ),
+                (
+                    Version::from_str("2.0.0")?,
+                    wheel("cp312-cp312-manylinux_2_17_x86_64")?,
+                ),
+            ]),
+        },
+    );
+    // Only the older version of `bar` has a free-threaded wheel.
+    scenario.packages.insert(
+        PackageName::from_str("bar")?,
+        Package {
+            versions: BTreeMap::from([
+                (
+                    Version::from_str("1.0.0")?,
+                    wheel("cp313-cp313t-manylinux_2_17_x86_64")?,
+                ),
+                (
+                    Version::from_str("2.0.0")?,
+                    wheel("cp313-cp313-manylinux_2_17_x86_64")?,
+                ),
+            ]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("baz")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("foo\nbaz ; platform_python_implementation == 'PyPy'")?;
+
+    // For CPython, the latest version of `foo` is selected, and `baz` is omitted.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-manylinux_2_17
+    foo==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // For PyPy, the older version of `foo` is selected, and `baz` is included.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--python-implementation")
+        .arg("pypy"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-manylinux_2_17 --python-implementation pypy
+    baz==1.0.0
+        # via -r requirements.in
+    foo==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    requirements_in.write_str("bar")?;
+
+    // For a default build of CPython, the latest version of `bar` is selected.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--python-version")
+        .arg("3.13"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-manylinux_2_17 --python-version 3.13
+    bar==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: The requested Python version 3.13 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 1 package in [TIME]
+    ");
+
+    // For a free-threaded build of CPython, the older version of `bar` is selected.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-manylinux_2_17")
+        .arg("--python-version")
+        .arg("3.13")
+        .arg("--python-abiflags")
+        .arg("t"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-platform x86_64-manylinux_2_17 --python-version 3.13 --python-abiflags t
+    bar==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    warning: The requested Python version 3.13 is not available; 3.12.[X] will be used to build dependencies instead.
+    Resolved 1 package in [TIME]
+    ");
+
+    // ABI flags are specific to CPython.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-implementation")
+        .arg("pypy")
+        .arg("--python-abiflags")
+        .arg("t"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Only CPython can be freethreading, not: pypy
+    ");
+
+    Ok(())
+}
+
 /// Resolve a specific source distribution via a Git HTTPS dependency.
 #[test]
 #[cfg(feature = "test-git")]
@@ -14529,6 +15255,8 @@ fn incompatible_build_constraint() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     "
     );
 
@@ -14599,6 +15327,8 @@ build-constraint-dependencies = [
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     "
     );
 
@@ -15742,7 +16472,7 @@ fn same_version_multi_index_incompatibility() -> Result<()> {
 /// Show the derivation chain on build failure.
 #[test]
 fn compile_derivation_chain() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let child = context.temp_dir.child("child");
     child.child("pyproject.toml").write_str(
@@ -15783,6 +16513,7 @@ fn compile_derivation_chain() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -15856,6 +16587,46 @@ fn invalid_platform() -> Result<()> {
     Ok(())
 }
 
+/// When targeting musl, call out that the available wheels are `manylinux`-only.
+#[test]
+fn musl_platform_manylinux_only() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let find_links = context.temp_dir.child("links");
+    find_links.create_dir_all()?;
+    find_links
+        .child("musl_package-1.0.0-cp312-cp312-manylinux_2_17_x86_64.whl")
+        .touch()?;
+    find_links
+        .child("musl_package-1.0.0-cp312-cp312-manylinux_2_28_x86_64.whl")
+        .touch()?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("musl-package")?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("--python-platform")
+        .arg("x86_64-unknown-linux-musl")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(find_links.path())
+        .arg("requirements.in"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only musl-package==1.0.0 is available and musl-package==1.0.0 has no wheels with a matching platform tag (e.g., `musllinux_1_2_x86_64`), we can conclude that all versions of musl-package cannot be used.
+          And because you require musl-package, we can conclude that your requirements are unsatisfiable.
+
+    hint: Wheels are available for `musl-package` (v1.0.0) on the following platforms: `manylinux_2_17_x86_64`, `manylinux_2_28_x86_64`; the target platform uses musl libc (`musllinux` tags), but `musl-package` only publishes `manylinux` wheels, which require glibc
+    ");
+
+    Ok(())
+}
+
 /// Treat `sys_platform` and `sys.platform` as equivalent markers in the marker algebra.
 #[test]
 fn universal_disjoint_deprecated_markers() -> Result<()> {
@@ -16094,14 +16865,206 @@ fn dynamic_version_source_dist() -> Result<()> {
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] [TEMP_DIR]/requirements.in --no-index
-    foo==1.2.3
+    #    uv pip compile --cache-dir [CACHE_DIR] [TEMP_DIR]/requirements.in --no-index
+    foo==1.2.3
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Write a source distribution for `name==version` with dynamic dependencies, such that it must be
+/// built to determine its metadata.
+fn write_dynamic_sdist(
+    context: &TestContext,
+    name: &str,
+    version: &str,
+    requires_dist: &[&str],
+) -> Result<()> {
+    let pyproject_toml = format!(
+        indoc! {r#"
+            [project]
+            name = "{name}"
+            version = "{version}"
+            dynamic = ["dependencies"]
+
+            [build-system]
+            requires = []
+            build-backend = "backend"
+            backend-path = ["."]
+        "#},
+        name = name,
+        version = version,
+    );
+    let metadata = std::iter::once(format!(
+        "Metadata-Version: 2.1\\nName: {name}\\nVersion: {version}\\n"
+    ))
+    .chain(
+        requires_dist
+            .iter()
+            .map(|requirement| format!("Requires-Dist: {requirement}\\n")),
+    )
+    .collect::<String>();
+    let backend_py = format!(
+        indoc! {r#"
+            import os
+
+
+            def prepare_metadata_for_build_wheel(metadata_directory, config_settings=None):
+                dist_info = "{name}-{version}.dist-info"
+                os.makedirs(os.path.join(metadata_directory, dist_info))
+                with open(os.path.join(metadata_directory, dist_info, "METADATA"), "w") as f:
+                    f.write("{metadata}")
+                return dist_info
+        "#},
+        name = name,
+        version = version,
+        metadata = metadata,
+    );
+
+    let file = File::create(
+        context
+            .temp_dir
+            .child("links")
+            .child(format!("{name}-{version}.tar.gz"))
+            .path(),
+    )?;
+    write_tar_gz(
+        file,
+        &[
+            (&format!("{name}-{version}/pyproject.toml"), &pyproject_toml),
+            (&format!("{name}-{version}/backend.py"), &backend_py),
+        ],
+    )
+}
+
+/// Resolution fails once it requires more metadata builds than allowed by `--max-metadata-builds`,
+/// listing the builds for the selected versions separately from those triggered by backtracking.
+#[test]
+fn compile_max_metadata_builds() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    fs_err::create_dir_all(context.temp_dir.child("links").path())?;
+
+    // `b==2.0.0` is tried first, but `a` requires `b==1.0.0`.
+    write_dynamic_sdist(&context, "a", "1.0.0", &["b==1.0.0"])?;
+    write_dynamic_sdist(&context, "b", "1.0.0", &[])?;
+    write_dynamic_sdist(&context, "b", "2.0.0", &[])?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("b\na")?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("links").path())
+        .arg("--max-metadata-builds")
+        .arg("2"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution required building more than 2 source distributions to determine their metadata (while building `b==1.0.0`)
+
+      Builds for the selected versions:
+        a==1.0.0 ([TIME])
+
+      Builds triggered by backtracking:
+        b==2.0.0 ([TIME])
+
+    hint: Pin the packages built while backtracking (e.g., `b`) to avoid building versions that the resolver would reject
+
+    hint: Use `--no-build-package` to disallow building specific packages, or raise the limit with `--max-metadata-builds`
+    ");
+
+    // With a sufficient limit, resolution succeeds.
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.temp_dir.child("links").path())
+        .env(EnvVars::UV_MAX_METADATA_BUILDS, "3"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    a==1.0.0
+        # via -r requirements.in
+    b==1.0.0
+        # via
+        #   -r requirements.in
+        #   a
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Exceed the resolver step limit on an index that requires backtracking, and report the
+/// packages that caused the most conflicts.
+#[test]
+fn compile_resolution_effort() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let server = PackseServer::new("backtracking/wrong-backtracking-basic.toml");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("a\nb")?;
+
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--resolution-effort")
+        .arg("5"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution exceeded the maximum of 5 resolver steps
+
+      Packages that caused the most backtracking:
+        a (3 conflicts)
+        b (3 conflicts)
+
+    hint: Add constraints for the packages that caused the most backtracking (e.g., `a`, `b`) to narrow the search
+
+    hint: Raise the limit with `--resolution-effort`
+    ");
+
+    // With a sufficient limit, resolution succeeds.
+    uv_snapshot!(context.filters(), context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .env(EnvVars::UV_RESOLVER_MAX_STEPS, "100"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    a==1.0.0
+        # via
+        #   -r requirements.in
+        #   b
+    b==2.0.9
         # via -r requirements.in
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    "
-    );
+    Resolved 2 packages in [TIME]
+    ");
 
     Ok(())
 }
@@ -19244,3 +20207,451 @@ async fn compile_missing_python_download_error_warning() {
       Caused by: tunnel error: unsuccessful
     ");
 }
+
+/// Explain the selected version of a package, including the requirements that constrained it and
+/// the versions that were skipped, along with a package that's absent from the resolution.
+#[test]
+fn explain() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = |requires: &[&str], yanked: bool| -> Result<PackageMetadata> {
+        Ok(PackageMetadata {
+            requires: requires
+                .iter()
+                .map(|requirement| Requirement::from_str(requirement))
+                .collect::<Result<_, _>>()?,
+            wheel: true,
+            yanked,
+            ..PackageMetadata::default()
+        })
+    };
+
+    // `a==2.0.0` requires `c>=2`, which conflicts with `b`, so `a` is backtracked to `1.0.0`,
+    // leaving `d` out of the resolution.
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("a")?,
+        Package {
+            versions: BTreeMap::from([
+                (Version::from_str("1.0.0")?, wheel(&["c<2"], false)?),
+                (Version::from_str("2.0.0")?, wheel(&["c>=2", "d"], false)?),
+            ]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("b")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                wheel(&["c>=1.1,<2"], false)?,
+            )]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("c")?,
+        Package {
+            versions: BTreeMap::from([
+                (Version::from_str("1.0.0")?, wheel(&[], false)?),
+                (Version::from_str("1.1.0")?, wheel(&[], false)?),
+                (Version::from_str("1.2.0")?, wheel(&[], true)?),
+                (Version::from_str("1.3.0a1")?, wheel(&[], false)?),
+                (Version::from_str("2.0.0")?, wheel(&[], false)?),
+            ]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("d")?,
+        Package {
+            versions: BTreeMap::from([(Version::from_str("1.0.0")?, wheel(&[], false)?)]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("a\nb\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--explain")
+        .arg("c")
+        .arg("--explain")
+        .arg("d"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    a==1.0.0
+        # via -r requirements.in
+    b==1.0.0
+        # via -r requirements.in
+    c==1.1.0
+        # via
+        #   a
+        #   b
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    c==1.1.0 was selected because:
+      - a==1.0.0 requires c<2
+      - b==1.0.0 requires c>=1.1, <2
+      - it is also required by versions that were not selected:
+        - a==2.0.0 requires c>=2, but a==1.0.0 was selected
+      - 1 pre-release version was skipped: 1.3.0a1
+      - 1 yanked version was skipped: 1.2.0
+    d is not in the resolution because:
+      - it is only required by versions that were not selected:
+        - a==2.0.0 requires d, but a==1.0.0 was selected
+    ");
+
+    Ok(())
+}
+
+/// Show the chain of dependencies that introduced each conflicting requirement, eliding the middle
+/// of long chains and noting requirements that only apply under certain markers.
+#[test]
+fn conflict_dependency_chain() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = |requires: &[&str]| -> Result<PackageMetadata> {
+        Ok(PackageMetadata {
+            requires: requires
+                .iter()
+                .map(|requirement| Requirement::from_str(requirement))
+                .collect::<Result<_, _>>()?,
+            wheel: true,
+            ..PackageMetadata::default()
+        })
+    };
+
+    // `a` requires `foo>=2` through `b`, `c`, and `d`, while `x[opt]` requires `foo<2` through `y`
+    // and `z`, but only on Linux. `long` requires `foo<2` through a chain of eight packages.
+    let mut scenario = Scenario::empty();
+    for (name, requires) in [
+        ("a", vec!["b"]),
+        ("b", vec!["c"]),
+        ("c", vec!["d"]),
+        ("d", vec!["foo>=2"]),
+        ("y", vec!["z"]),
+        ("z", vec!["foo<2; sys_platform == 'linux'"]),
+        ("long", vec!["long1"]),
+        ("long1", vec!["long2"]),
+        ("long2", vec!["long3"]),
+        ("long3", vec!["long4"]),
+        ("long4", vec!["long5"]),
+        ("long5", vec!["long6"]),
+        ("long6", vec!["foo<2"]),
+    ] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(Version::from_str("1.0.0")?, wheel(&requires)?)]),
+            },
+        );
+    }
+    let mut x = wheel(&[])?;
+    x.extras.insert(
+        ExtraName::from_str("opt")?,
+        vec![Requirement::from_str("y")?],
+    );
+    scenario.packages.insert(
+        PackageName::from_str("x")?,
+        Package {
+            versions: BTreeMap::from([(Version::from_str("1.0.0")?, x)]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([
+                (Version::from_str("1.0.0")?, wheel(&[])?),
+                (Version::from_str("2.0.0")?, wheel(&[])?),
+            ]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("a\nx[opt]\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--universal")
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only a==1.0.0 is available and a==1.0.0 depends on b, we can conclude that all versions of a depend on b.
+          And because only b==1.0.0 is available and b==1.0.0 depends on c, we can conclude that all versions of a depend on c.
+          And because only c==1.0.0 is available and c==1.0.0 depends on d, we can conclude that all versions of a depend on d.
+          And because only d==1.0.0 is available and d==1.0.0 depends on foo>=2, we can conclude that all versions of a depend on foo>=2.
+          And because z==1.0.0 depends on foo{sys_platform == 'linux'}<2 and only z==1.0.0 is available, we can conclude that all versions of a and all versions of z are incompatible.
+          And because y==1.0.0 depends on z and only y==1.0.0 is available, we can conclude that all versions of a and all versions of y are incompatible.
+          And because x[opt]==1.0.0 depends on y and only x[opt]==1.0.0 is available, we can conclude that all versions of a and all versions of x[opt] are incompatible.
+          And because you require a and x[opt], we can conclude that your requirements are unsatisfiable.
+
+          The conflicting requirements on foo were introduced by:
+              a -> b -> c -> d -> foo>=2
+              x[opt] -> y -> z -> foo<2 (only when `sys_platform == 'linux'`)
+    ");
+
+    requirements_in.write_str("a\nlong\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only a==1.0.0 is available and a==1.0.0 depends on b, we can conclude that all versions of a depend on b.
+          And because only b==1.0.0 is available and b==1.0.0 depends on c, we can conclude that all versions of a depend on c.
+          And because only c==1.0.0 is available and c==1.0.0 depends on d, we can conclude that all versions of a depend on d.
+          And because only d==1.0.0 is available and d==1.0.0 depends on foo>=2, we can conclude that all versions of a depend on foo>=2. (1)
+
+          Because only long6==1.0.0 is available and long6==1.0.0 depends on foo<2, we can conclude that all versions of long6 depend on foo<2.
+          And because long5==1.0.0 depends on long6, we can conclude that long5==1.0.0 depends on foo<2.
+          And because only long5==1.0.0 is available and long4==1.0.0 depends on long5, we can conclude that long4==1.0.0 depends on foo<2.
+          And because only long4==1.0.0 is available and long3==1.0.0 depends on long4, we can conclude that long3==1.0.0 depends on foo<2.
+          And because we know from (1) that all versions of a depend on foo>=2, we can conclude that all versions of a and long3==1.0.0 are incompatible.
+          And because only long3==1.0.0 is available, we can conclude that all versions of a and all versions of long3 are incompatible.
+          And because long2==1.0.0 depends on long3 and only long2==1.0.0 is available, we can conclude that all versions of a and all versions of long2 are incompatible.
+          And because long1==1.0.0 depends on long2 and only long1==1.0.0 is available, we can conclude that all versions of a and all versions of long1 are incompatible.
+          And because long==1.0.0 depends on long1 and only long==1.0.0 is available, we can conclude that all versions of a and all versions of long are incompatible.
+          And because you require a and long, we can conclude that your requirements are unsatisfiable.
+
+          The conflicting requirements on foo were introduced by:
+              a -> b -> c -> d -> foo>=2
+              long -> long1 -> (3 more) -> long5 -> long6 -> foo<2
+    ");
+
+    // When the conflicting requirements are declared directly, the chains are omitted.
+    requirements_in.write_str("d\nfoo<2\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only d==1.0.0 is available and d==1.0.0 depends on foo>=2, we can conclude that all versions of d depend on foo>=2.
+          And because you require d and foo<2, we can conclude that your requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
+/// With `--hint-on-failure`, suggest relaxations of the direct requirements that would allow a
+/// failed resolution to succeed.
+#[test]
+fn hint_on_failure() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = |requires: &[&str]| -> Result<PackageMetadata> {
+        Ok(PackageMetadata {
+            requires: requires
+                .iter()
+                .map(|requirement| Requirement::from_str(requirement))
+                .collect::<Result<_, _>>()?,
+            wheel: true,
+            ..PackageMetadata::default()
+        })
+    };
+
+    // `package-b>=4` requires `package-a>=3`, which conflicts with `package-a<3`. Either removing
+    // the upper bound on `package-a` or lowering the requirement on `package-b` resolves.
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("package-a")?,
+        Package {
+            versions: BTreeMap::from([
+                (Version::from_str("1.0.0")?, wheel(&[])?),
+                (Version::from_str("2.0.0")?, wheel(&[])?),
+                (Version::from_str("3.0.0")?, wheel(&[])?),
+            ]),
+        },
+    );
+    scenario.packages.insert(
+        PackageName::from_str("package-b")?,
+        Package {
+            versions: BTreeMap::from([
+                (Version::from_str("3.0.0")?, wheel(&["package-a<3"])?),
+                (Version::from_str("4.0.0")?, wheel(&["package-a>=3"])?),
+            ]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("package-a<3\npackage-b>=4\n")?;
+
+    // Without `--hint-on-failure`, no relaxations are suggested.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only package-b<=4.0.0 is available and package-b==4.0.0 depends on package-a>=3, we can conclude that package-b>=4.0.0 depends on package-a>=3.
+          And because you require package-a<3 and package-b>=4, we can conclude that your requirements are unsatisfiable.
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--hint-on-failure")
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only package-b<=4.0.0 is available and package-b==4.0.0 depends on package-a>=3, we can conclude that package-b>=4.0.0 depends on package-a>=3.
+          And because you require package-a<3 and package-b>=4, we can conclude that your requirements are unsatisfiable.
+
+    hint: Removing the upper bound on `package-a` (`<3`) would allow `package-a==3.0.0`, which resolves the conflict
+    hint: Lowering the requirement on `package-b` from `>=4` to `>=3.0.0` would allow `package-b==3.0.0`, which resolves the conflict
+    ");
+
+    // If the conflict can't be resolved by relaxing a direct requirement, no hints are shown.
+    requirements_in.write_str("package-b>=4\npackage-c\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--hint-on-failure")
+        .arg("--index-url")
+        .arg(server.index_url()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because package-c was not found in the package registry and you require package-c, we can conclude that your requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
+/// With `--show-build-warnings`, the warnings emitted by builds during the resolution are
+/// summarized once the resolution completes.
+#[test]
+fn compile_show_build_warnings() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("./child")?;
+
+    // The version is dynamic, such that the metadata can only be determined by building a wheel.
+    let child = context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                print("backend.py:8: SetuptoolsDeprecationWarning: License classifiers are deprecated.", file=sys.stderr)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        requires-python = ">=3.12"
+        dynamic = ["version"]
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--show-build-warnings"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --show-build-warnings
+    ./child
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: The build backend emitted a warning while building `child`:
+      backend.py:8: SetuptoolsDeprecationWarning: License classifiers are deprecated.
+    ");
+
+    Ok(())
+}
+
+/// Respect `allow-sdist = false` on an index for `--find-links` locations with the same URL.
+#[test]
+fn compile_find_links_allow_sdist() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("ok")?;
+
+    // Populate the location with a wheel for `ok==1.0.0`, and only a source distribution for
+    // `ok==2.0.0`.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/ok-1.0.0-py3-none-any.whl"),
+        links.join("ok-1.0.0-py3-none-any.whl"),
+    )?;
+    links.child("ok-2.0.0.tar.gz").touch()?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(
+        r#"
+        [[index]]
+        name = "links"
+        format = "flat"
+        url = "./links"
+        explicit = true
+        allow-sdist = false
+        "#,
+    )?;
+
+    // `ok==2.0.0` is skipped in favor of the `ok==1.0.0` wheel.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-header")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg("./links"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ok==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Selected older versions because newer source distributions are forbidden by `allow-sdist = false`: `ok==1.0.0` (instead of `ok==2.0.0` from `file://[TEMP_DIR]/links`)
+    ");
+
+    Ok(())
+}
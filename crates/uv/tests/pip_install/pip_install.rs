@@ -1,8 +1,10 @@
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use async_zip::base::write::ZipFileWriter;
@@ -15,6 +17,7 @@ use futures::io::AllowStdIo;
 use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use predicates::prelude::predicate;
+use sha2::{Digest, Sha256};
 use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
 use url::Url;
 use walkdir::WalkDir;
@@ -24,11 +27,14 @@ use wiremock::{
 };
 
 use uv_fs::{PortablePath, Simplified};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_static::EnvVars;
 #[cfg(feature = "test-git")]
 use uv_test::decode_token;
 use uv_test::find_links::FindLinksServer;
 use uv_test::packse::PackseServer;
+use uv_test::packse::scenario::{Package, PackageMetadata, Scenario, WheelTag};
 use uv_test::{
     DEFAULT_PYTHON_VERSION, TestContext, apply_filters, download_to_disk, get_bin, uv_snapshot,
     venv_bin_path,
@@ -265,7 +271,7 @@ fn invalid_pyproject_toml_option_unknown_field() -> Result<()> {
         |
       2 | unknown = "field"
         | ^^^^^^^
-      unknown field `unknown`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+      unknown field `unknown`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `keep-extraneous`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `lock-build-dependencies`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
 
     Resolved in [TIME]
     Checked in [TIME]
@@ -463,7 +469,7 @@ async fn cache_uv_toml_credentials() -> Result<()> {
 /// fields. See: <https://hatch.pypa.io/latest/config/context/>
 #[test]
 fn invalid_pyproject_toml_requirement_indirect() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
     let pyproject_toml = context.temp_dir.child("path_dep/pyproject.toml");
     pyproject_toml.write_str(
         r#"[project]
@@ -486,6 +492,7 @@ dependencies = ["flask==1.0.x"]
       × Failed to build `project @ file://[TEMP_DIR]/path_dep`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stdout]
           configuration error: `project.dependencies[0]` must be pep508
@@ -870,6 +877,86 @@ async fn start_requirements_server(
     server.uri()
 }
 
+/// Install from a remote `requirements.txt` that includes other files, which are resolved relative
+/// to the remote URL rather than the working directory.
+#[tokio::test]
+async fn install_remote_requirements_txt_nested() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let find_links = context.workspace_root.join("test/links");
+
+    let server = MockServer::start().await;
+    for (file, contents) in [
+        // The inclusions loop back to `base.txt`, which should only be read once.
+        (
+            "/reqs/base.txt",
+            "-r common.txt\n-c ../constraints/pins.txt\n-r base.txt\n",
+        ),
+        ("/reqs/common.txt", "ok\n-r base.txt\n"),
+        ("/constraints/pins.txt", "ok==1.0.0\n"),
+        ("/reqs/broken.txt", "-r nested/broken.txt\n"),
+        ("/reqs/nested/broken.txt", "ok\n-r ../missing.txt\n"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(file))
+            .respond_with(ResponseTemplate::new(200).set_body_string(contents))
+            .mount(&server)
+            .await;
+    }
+
+    // Create a local file with the same name, which should be ignored.
+    context.temp_dir.child("common.txt").write_str("tqdm")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(format!("{}/reqs/base.txt", server.uri()))
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[WORKSPACE]/test/links`, which does not provide upload times: `ok`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    ");
+
+    // Errors should cite the chain of inclusions.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(format!("{}/reqs/broken.txt", server.uri()))
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Error parsing included file in `http://[LOCALHOST]/reqs/broken.txt` at position 0
+      Caused by: Error parsing included file in `http://[LOCALHOST]/reqs/nested/broken.txt` at position 3
+      Caused by: Error while accessing remote requirements file: `http://[LOCALHOST]/reqs/missing.txt`
+    ");
+
+    // Remote files can't be fetched in offline mode.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(format!("{}/reqs/base.txt", server.uri()))
+        .arg("--offline"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Network connectivity is disabled, but a remote requirements file was requested: http://[LOCALHOST]/reqs/base.txt
+    ");
+
+    Ok(())
+}
+
 /// Warn (but don't fail) when unsupported flags are set in the `requirements.txt`.
 #[test]
 fn install_unsupported_flag() -> Result<()> {
@@ -3659,6 +3746,139 @@ fn only_binary_editable_setup_py() {
     );
 }
 
+/// Install a legacy project, with only a `setup.py`, as editable via the default `setuptools`
+/// backend.
+#[test]
+fn install_editable_setup_py_legacy() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.child("setup.py").write_str(indoc! {r#"
+        from setuptools import setup
+
+        setup(name="example", version="0.1.0", packages=["example"])
+    "#})?;
+    editable_dir
+        .child("example")
+        .child("__init__.py")
+        .write_str("VALUE = 1\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg("editable"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example==0.1.0 (from file://[TEMP_DIR]/editable)
+    "
+    );
+
+    // Changes to the source tree are reflected without reinstalling.
+    editable_dir
+        .child("example")
+        .child("__init__.py")
+        .write_str("VALUE = 2\n")?;
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import example; assert example.VALUE == 2")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Install a legacy project as editable with a `setuptools` version that predates PEP 660, which
+/// falls back to `setup.py develop` semantics.
+#[test]
+fn install_editable_setup_py_develop() -> Result<()> {
+    let context = uv_test::test_context!("3.11");
+
+    let editable_dir = context.temp_dir.child("editable");
+    editable_dir.child("pyproject.toml").write_str(indoc! {r#"
+        [build-system]
+        requires = ["setuptools<64"]
+        build-backend = "setuptools.build_meta:__legacy__"
+    "#})?;
+    editable_dir.child("setup.py").write_str(indoc! {r#"
+        from setuptools import setup
+
+        setup(
+            name="example",
+            version="0.1.0",
+            packages=["example"],
+            package_dir={"": "src"},
+        )
+    "#})?;
+    editable_dir
+        .child("src")
+        .child("example")
+        .child("__init__.py")
+        .write_str("VALUE = 1\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg("editable"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example==0.1.0 (from file://[TEMP_DIR]/editable)
+    "
+    );
+
+    // Changes to the source tree are reflected without reinstalling.
+    editable_dir
+        .child("src")
+        .child("example")
+        .child("__init__.py")
+        .write_str("VALUE = 2\n")?;
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import example; assert example.VALUE == 2")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// Installing as editable with a build backend that lacks `build_editable` fails with a clear
+/// error.
+#[test]
+fn install_editable_unsupported_backend() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend("child", "0.1.0", "")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg("./child"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ╰─▶ The build backend `backend` does not support editable installs
+
+    hint: Consider installing without `--editable`
+    "
+    );
+
+    Ok(())
+}
+
 /// We should not recommend `--prerelease=allow` in source distribution build failures, since we
 /// don't propagate the `--prerelease` flag to the source distribution build regardless.
 #[test]
@@ -4196,6 +4416,69 @@ fn install_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Install a package from a `requirements.txt` file, with a `constraints.txt` file that uses
+/// markers. Only the constraints that match the environment should be applied.
+#[test]
+fn install_constraints_txt_markers() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.7.0")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc! {r"
+        idna<3.4 ; python_version < '3.12'
+        sniffio<1.3 ; python_version >= '3.12'
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("-r")
+            .arg("requirements.txt")
+            .arg("--constraint")
+            .arg("constraints.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.2.0
+    "
+    );
+
+    Ok(())
+}
+
+/// Install a package with a `constraints.txt` file that includes an extra, which is rejected.
+#[test]
+fn install_constraints_txt_extra() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(indoc! {r"
+        idna<3.4
+        anyio[trio]==3.7.0
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+            .arg("anyio")
+            .arg("--constraint")
+            .arg("constraints.txt"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Constraints cannot have extras (found: `anyio[trio]==3.7.0`) at constraints.txt:2:1
+    "
+    );
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` file, with a `constraints.txt` file.
 #[test]
 #[expect(clippy::disallowed_types)]
@@ -5842,7 +6125,7 @@ fn requires_python_source_dist_installed_incompatible_registry() {
 /// Install with `--no-build-isolation`, to disable isolation during PEP 517 builds.
 #[test]
 fn no_build_isolation() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
     let requirements_in = context.temp_dir.child("requirements.in");
     requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz")?;
 
@@ -5859,6 +6142,7 @@ fn no_build_isolation() -> Result<()> {
       × Failed to build `anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.prepare_metadata_for_build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -5870,7 +6154,7 @@ fn no_build_isolation() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     anyio = ["setuptools"]
 
-    or `uv pip install setuptools` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install setuptools` into the environment and re-run with `--no-build-isolation-package anyio`.
     "#
     );
 
@@ -5915,7 +6199,7 @@ fn no_build_isolation() -> Result<()> {
 /// Ensure that `UV_NO_BUILD_ISOLATION` env var does the same as the `--no-build-isolation` flag
 #[test]
 fn respect_no_build_isolation_env_var() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
     let requirements_in = context.temp_dir.child("requirements.in");
     requirements_in.write_str("anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz")?;
 
@@ -5932,6 +6216,7 @@ fn respect_no_build_isolation_env_var() -> Result<()> {
       × Failed to build `anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.prepare_metadata_for_build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -5943,7 +6228,7 @@ fn respect_no_build_isolation_env_var() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     anyio = ["setuptools"]
 
-    or `uv pip install setuptools` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install setuptools` into the environment and re-run with `--no-build-isolation-package anyio`.
     "#
     );
 
@@ -6111,6 +6396,7 @@ fn dry_run_install_url_dependency() -> std::result::Result<(), Box<dyn std::erro
      + anyio @ https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz
      + idna==3.6
      + sniffio==1.3.1
+    Would build anyio https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz from source
     "
     );
 
@@ -6162,6 +6448,7 @@ fn dry_run_uninstall_url_dependency() -> std::result::Result<(), Box<dyn std::er
     Would install 1 package
      - anyio==4.2.0 (from https://files.pythonhosted.org/packages/2d/b8/7333d87d5f03247215d86a86362fd3e324111788c6cdd8d2e6196a6ba833/anyio-4.2.0.tar.gz)
      + anyio==4.3.0
+    Would upgrade anyio 4.2.0 -> 4.3.0
     "
     );
 
@@ -6314,6 +6601,7 @@ fn dry_run_install_then_upgrade() -> std::result::Result<(), Box<dyn std::error:
     Would install 1 package
      - httpx==0.25.0
      + httpx==0.25.1
+    Would upgrade httpx 0.25.0 -> 0.25.1
     "
     );
 
@@ -7769,6 +8057,7 @@ async fn find_links_uppercase_html() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `http://[LOCALHOST]/`, which does not provide upload times: `tqdm`
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
@@ -8083,7 +8372,7 @@ fn require_hashes_editable() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: file://[WORKSPACE]/test/packages/black_editable[d]
+    error: In `--require-hashes` mode, all requirements must refer to an archive that can be hashed, but `file://[WORKSPACE]/test/packages/black_editable[d]` (from `requirements.txt`) refers to an editable directory
     "
     );
 
@@ -8328,60 +8617,154 @@ fn require_hashes_unnamed_repeated() -> Result<()> {
     Ok(())
 }
 
-/// If a hash is only included as a override, that's not good enough for `--require-hashes`.
-///
-/// TODO(charlie): This _should_ be allowed. It's a bug.
+/// A hash in the fragment of a local file URL satisfies `--require-hashes`, and is verified against
+/// the archive.
 #[test]
-fn require_hashes_override() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+fn require_hashes_path_fragment() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
 
-    // Include the hash in the override file.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("anyio==4.0.0")?;
+    let wheel = context
+        .workspace_root
+        .join("test/links/basic_package-0.1.0-py3-none-any.whl");
+    let wheel_url = Url::from_file_path(&wheel).unwrap();
 
-    let overrides_txt = context.temp_dir.child("overrides.txt");
-    overrides_txt.write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+    // A mismatched hash.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "basic-package @ {wheel_url}#sha256=0000000000000000000000000000000000000000000000000000000000000000"
+    ))?;
 
-    // Install the editable packages.
-    uv_snapshot!(context.pip_install()
+    uv_snapshot!(context.filters(), context.pip_install()
         .arg("-r")
-        .arg(requirements_txt.path())
-        .arg("--require-hashes")
-        .arg("--override")
-        .arg(overrides_txt.path()), @"
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @"
     success: false
-    exit_code: 2
+    exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
-    "
-    );
+    Resolved 1 package in [TIME]
+      × Failed to read `basic-package @ file://[WORKSPACE]/test/links/basic_package-0.1.0-py3-none-any.whl#sha256=0000000000000000000000000000000000000000000000000000000000000000`
+      ╰─▶ Hash mismatch for `basic-package @ file://[WORKSPACE]/test/links/basic_package-0.1.0-py3-none-any.whl#sha256=0000000000000000000000000000000000000000000000000000000000000000`
 
-    // Include the hash in the requirements file, but pin the version in the override file.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        "anyio --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
-    )?;
+          Expected:
+            sha256:0000000000000000000000000000000000000000000000000000000000000000
 
-    let overrides_txt = context.temp_dir.child("overrides.txt");
-    overrides_txt.write_str("anyio==4.0.0")?;
+          Computed:
+            sha256:7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82
+    ");
 
-    // Install the editable packages.
-    uv_snapshot!(context.pip_install()
+    // Conflicting hashes for the same archive.
+    requirements_txt.write_str(&format!(
+        "basic-package @ {wheel_url}#sha256=7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82\nbasic-package @ {wheel_url}#sha256=0000000000000000000000000000000000000000000000000000000000000000"
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
         .arg("-r")
-        .arg(requirements_txt.path())
-        .arg("--require-hashes")
-        .arg("--override")
-        .arg(overrides_txt.path()), @"
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @"
     success: false
     exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
-    "
-    );
+    error: Conflicting archive URL hashes for `basic-package @ file://[WORKSPACE]/test/links/basic_package-0.1.0-py3-none-any.whl#sha256=0000000000000000000000000000000000000000000000000000000000000000`: `sha256:7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82` conflicts with `sha256:0000000000000000000000000000000000000000000000000000000000000000`
+    ");
+
+    // An unsupported hash algorithm.
+    requirements_txt.write_str(&format!(
+        "basic-package @ {wheel_url}#sha1=c6e1ee63fa4b2d3e8b3d0c30e1d1ed2b4c7e1f0a"
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unsupported hash algorithm (expected one of: `md5`, `sha256`, `sha384`, `sha512`, or `blake2b`) on: `sha1=c6e1ee63fa4b2d3e8b3d0c30e1d1ed2b4c7e1f0a`
+    ");
+
+    // A matching hash.
+    requirements_txt.write_str(&format!(
+        "basic-package @ {wheel_url}#sha256=7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82"
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + basic-package==0.1.0 (from file://[WORKSPACE]/test/links/basic_package-0.1.0-py3-none-any.whl#sha256=7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82)
+    ");
+
+    Ok(())
+}
+
+/// If a hash is only included as a override, that's not good enough for `--require-hashes`.
+///
+/// TODO(charlie): This _should_ be allowed. It's a bug.
+#[test]
+fn require_hashes_override() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Include the hash in the override file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("anyio==4.0.0 --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f")?;
+
+    // Install the editable packages.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .arg("--override")
+        .arg(overrides_txt.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio==4.0.0
+    "
+    );
+
+    // Include the hash in the requirements file, but pin the version in the override file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "anyio --hash=sha256:cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f",
+    )?;
+
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("anyio==4.0.0")?;
+
+    // Install the editable packages.
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .arg("--override")
+        .arg(overrides_txt.path()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must have their versions pinned with `==`, but found: anyio
+    "
+    );
 
     Ok(())
 }
@@ -8428,7 +8811,7 @@ fn install_with_excludes_from_stdin() -> Result<()> {
 
     uv_snapshot!(context.pip_install()
         .arg("anyio==4.0.1")
-        .arg("--exclude")
+        .arg("--excludes")
         .arg("-")
         .stdin(std::fs::File::open(excludes_txt)?), @"
     success: true
@@ -8443,6 +8826,25 @@ fn install_with_excludes_from_stdin() -> Result<()> {
     Ok(())
 }
 
+/// Reject a direct requirement on a package excluded with `--exclude`, before resolving.
+#[test]
+fn install_excluded_package() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio")
+        .arg("pycrypto")
+        .arg("--exclude")
+        .arg("pycrypto"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `pycrypto` is excluded from the resolution
+    ");
+}
+
 /// Provide valid hashes for all dependencies with `--require-hashes` with accompanying markers.
 /// Critically, one package (`requests`) depends on another (`urllib3`).
 #[test]
@@ -8582,6 +8984,178 @@ fn require_hashes_marker() -> Result<()> {
     Ok(())
 }
 
+/// Under `--require-hashes`, hashes may be provided for the wheel, the source distribution, or
+/// both, and (with `--no-binary`) the source distribution is verified before it's built.
+#[test]
+fn require_hashes_source_and_wheel() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Build a source distribution and a wheel for a package with an in-tree build backend.
+    let project = context.in_tree_backend(
+        "hashed",
+        "1.0.0",
+        indoc! {r#"
+            def build_sdist(sdist_directory, config_settings=None):
+                return write_sdist(sdist_directory)
+        "#},
+    )?;
+
+    let links = context.temp_dir.child("links");
+    context
+        .build()
+        .arg("--out-dir")
+        .arg(links.path())
+        .arg(project.path())
+        .assert()
+        .success();
+
+    let sha256 = |path: &std::path::Path| -> Result<String> {
+        Ok(format!("{:x}", Sha256::digest(fs::read(path)?)))
+    };
+    let wheel = sha256(&links.child("hashed-1.0.0-py3-none-any.whl"))?;
+    let sdist = sha256(&links.child("hashed-1.0.0.tar.gz"))?;
+
+    let mut filters = context.filters();
+    filters.push((wheel.as_str(), "[WHEEL_SHA256]"));
+    filters.push((sdist.as_str(), "[SDIST_SHA256]"));
+
+    // Refresh the cache on each install, such that every case unpacks or builds the artifact (and
+    // verifies its hash), rather than reusing a wheel that an earlier case unpacked or built.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    let install = || {
+        let mut command = context.pip_install();
+        command
+            .arg("-r")
+            .arg("requirements.txt")
+            .arg("--require-hashes")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(links.path())
+            .arg("--refresh");
+        command
+    };
+
+    // A hash for the wheel alone is sufficient to install the wheel.
+    requirements_txt.write_str(&format!("hashed==1.0.0 --hash=sha256:{wheel}"))?;
+    uv_snapshot!(filters, install(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `hashed`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + hashed==1.0.0
+    ");
+
+    // With `--no-binary`, the source distribution is used instead, and must match a hash.
+    context.reset_venv();
+    uv_snapshot!(filters, install().arg("--no-binary").arg("hashed"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `hashed`
+    Resolved 1 package in [TIME]
+      × Failed to download and build `hashed==1.0.0`
+      ╰─▶ Hash mismatch for `hashed==1.0.0`
+
+          Expected:
+            sha256:[WHEEL_SHA256]
+
+          Computed:
+            sha256:[SDIST_SHA256]
+
+    hint: `hashed` (v1.0.0) was built from source because using pre-built wheels is disabled (e.g., with `--no-binary`)
+    ");
+
+    // A hash for the source distribution alone is sufficient to build from source.
+    context.reset_venv();
+    requirements_txt.write_str(&format!("hashed==1.0.0 --hash=sha256:{sdist}"))?;
+    uv_snapshot!(filters, install().arg("--no-binary").arg("hashed"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `hashed`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + hashed==1.0.0
+    ");
+
+    // With hashes for both, either artifact can be used.
+    context.reset_venv();
+    requirements_txt.write_str(&format!(
+        "hashed==1.0.0 --hash=sha256:{sdist} --hash=sha256:{wheel}"
+    ))?;
+    uv_snapshot!(filters, install(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `hashed`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + hashed==1.0.0
+    ");
+
+    context.reset_venv();
+    uv_snapshot!(filters, install().arg("--no-binary").arg("hashed"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `hashed`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + hashed==1.0.0
+    ");
+
+    // Hashes may be supplied by a constraint, rather than the requirement itself.
+    context.reset_venv();
+    requirements_txt.write_str("hashed==1.0.0")?;
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str(&format!("hashed==1.0.0 --hash=sha256:{sdist}"))?;
+    uv_snapshot!(filters, install()
+        .arg("-c")
+        .arg("constraints.txt")
+        .arg("--no-binary")
+        .arg("hashed"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide upload times: `hashed`
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + hashed==1.0.0
+    ");
+
+    // Local directories can't be hashed, so they're rejected upfront.
+    requirements_txt.write_str("-e ./project")?;
+    uv_snapshot!(filters, install(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all requirements must refer to an archive that can be hashed, but `./project` (from `requirements.txt`) refers to an editable directory
+    ");
+
+    Ok(())
+}
+
 /// Provide valid hashes for all dependencies with `--require-hashes`.
 #[test]
 fn verify_hashes() -> Result<()> {
@@ -9736,6 +10310,8 @@ fn incompatible_build_constraint() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     "
     );
 
@@ -9765,6 +10341,8 @@ fn incompatible_build_constraint_from_stdin() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     "
     );
 
@@ -9798,6 +10376,76 @@ fn compatible_build_constraint() -> Result<()> {
     Ok(())
 }
 
+/// Build constraints apply to both the static `build-system.requires` and the requirements
+/// returned by the backend's `get_requires_for_build_wheel` hook.
+#[test]
+fn build_constraint_build_requires() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let project = context.in_tree_backend(
+        "project",
+        "0.1.0",
+        indoc! {r#"
+            import importlib.metadata
+            import json
+
+
+            def get_requires_for_build_wheel(config_settings=None):
+                return ["wheel"]
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                # Record the versions installed into the build environment.
+                marker = pathlib.Path(__file__).resolve().parent.parent / "versions.json"
+                marker.write_text(
+                    json.dumps(
+                        {
+                            name: importlib.metadata.version(name)
+                            for name in ["setuptools", "wheel"]
+                        }
+                    )
+                )
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["setuptools"]
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+
+    let constraints_txt = context.temp_dir.child("build_constraints.txt");
+    constraints_txt.write_str("setuptools<69\nwheel<0.40")?;
+
+    context
+        .pip_install()
+        .arg("./project")
+        .arg("--build-constraint")
+        .arg("build_constraints.txt")
+        .assert()
+        .success();
+
+    let versions: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("versions.json"),
+    )?)?;
+    let version = |name: &str| -> Result<uv_pep440::Version> {
+        Ok(versions[name]
+            .as_str()
+            .ok_or_else(|| anyhow!("missing version for `{name}`"))?
+            .parse()?)
+    };
+    assert!(version("setuptools")? < uv_pep440::Version::new([69]));
+    assert!(version("wheel")? < uv_pep440::Version::new([0, 40]));
+
+    Ok(())
+}
+
 /// Include `build-constraint-dependencies` in pyproject.toml with an incompatible constraint.
 #[test]
 fn incompatible_build_constraint_in_pyproject_toml() -> Result<()> {
@@ -9823,6 +10471,8 @@ build-constraint-dependencies = [
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     "
     );
 
@@ -9961,7 +10611,7 @@ build-constraint-dependencies = [
 
 #[test]
 fn install_build_isolation_package() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     // Create a package.
     let package = context.temp_dir.child("project");
@@ -9990,6 +10640,7 @@ fn install_build_isolation_package() -> Result<()> {
       × Failed to build `iniconfig @ https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `hatchling.build.prepare_metadata_for_build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -10001,7 +10652,7 @@ fn install_build_isolation_package() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     iniconfig = ["hatchling"]
 
-    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation-package iniconfig`.
     "#
     );
 
@@ -10310,7 +10961,7 @@ fn missing_top_level() {
 /// Show a dedicated error when the user attempts to install `sklearn`.
 #[test]
 fn sklearn() {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     uv_snapshot!(context.filters(), context.pip_install().arg("sklearn"), @"
     success: false
@@ -10321,6 +10972,7 @@ fn sklearn() {
       × Failed to build `sklearn==0.0.post12`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           The 'sklearn' PyPI package is deprecated, use 'scikit-learn'
@@ -10348,7 +11000,7 @@ fn sklearn() {
 
 #[test]
 fn resolve_derivation_chain() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(indoc! {r#"
@@ -10377,6 +11029,7 @@ fn resolve_derivation_chain() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -10562,7 +11215,7 @@ fn missing_subdirectory_git() -> Result<()> {
 
     ----- stderr -----
       × Failed to download and build `workspace-in-root-test @ git+https://github.com/astral-sh/workspace-in-root-test#subdirectory=missing`
-      ╰─▶ The source distribution `git+https://github.com/astral-sh/workspace-in-root-test#subdirectory=missing` has no subdirectory `missing`
+      ╰─▶ The source distribution `git+https://github.com/astral-sh/workspace-in-root-test#subdirectory=missing` has no subdirectory `missing` at the default branch (commit `d3ab48d2338296d47e28dbb2fb327c5e2ac4ac68`)
     "
     );
 
@@ -10907,7 +11560,9 @@ fn cyclic_build_dependency() {
     Resolved 1 package in [TIME]
       × Failed to download and build `circular-one==0.2.0`
       ├─▶ Failed to install requirements from `build-system.requires`
-      ╰─▶ Cyclic build dependency detected for `circular-one`
+      ╰─▶ Cyclic build dependency detected: circular-one 0.2.0 -> circular-one 0.2.0
+
+    hint: Build `circular-one` without isolation (e.g., with `--no-build-isolation-package circular-one`), or provide a pre-built wheel for one of the packages in the cycle
     "
     );
 
@@ -10931,24 +11586,82 @@ fn cyclic_build_dependency() {
     );
 }
 
+/// `a` and `b` are local path packages that each declare the other as a build dependency.
 #[test]
-#[cfg(feature = "test-git")]
-fn direct_url_json_git_preserves_repository_url() -> Result<()> {
+fn cyclic_build_dependency_path() -> Result<()> {
     let context = uv_test::test_context!("3.12");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str(
-        "uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git",
-    )?;
 
-    uv_snapshot!(context.pip_install()
-        .arg("-r")
-        .arg("requirements.txt")
-        .arg("--strict"), @"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    let a = context.temp_dir.child("a");
+    a.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "a"
+        version = "1.0.0"
 
-    ----- stderr -----
+        [build-system]
+        requires = ["b"]
+        build-backend = "a_backend"
+        backend-path = ["."]
+
+        [tool.uv.sources]
+        b = { path = "../b" }
+        "#
+    })?;
+
+    let b = context.temp_dir.child("b");
+    b.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "b"
+        version = "2.0.0"
+
+        [build-system]
+        requires = ["a"]
+        build-backend = "b_backend"
+        backend-path = ["."]
+
+        [tool.uv.sources]
+        a = { path = "../a" }
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./a"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `a @ file://[TEMP_DIR]/a`
+      ├─▶ Failed to install requirements from `build-system.requires`
+      ├─▶ Failed to build `b @ file://[TEMP_DIR]/b`
+      ├─▶ Failed to install requirements from `build-system.requires`
+      ╰─▶ Cyclic build dependency detected: a -> b -> a
+
+    hint: Build `a` without isolation (e.g., with `--no-build-isolation-package a`), or provide a pre-built wheel for one of the packages in the cycle
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "test-git")]
+fn direct_url_json_git_preserves_repository_url() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage.git",
+    )?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
@@ -12641,6 +13354,65 @@ fn pep_751_install_registry_wheel() -> Result<()> {
     Ok(())
 }
 
+/// Install from a `pylock.toml` that pins a yanked version, which the lockfile doesn't record.
+#[test]
+fn pep_751_install_yanked() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["colorama==0.4.2"]
+        "#,
+    )?;
+
+    context
+        .export()
+        .arg("-o")
+        .arg("pylock.toml")
+        .assert()
+        .success();
+
+    // Under `--yanked forbid`, the install fails.
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_install()
+        .arg("--preview")
+        .arg("-r")
+        .arg("pylock.toml")
+        .arg("--yanked")
+        .arg("forbid"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `colorama==0.4.2` has been yanked (reason: "Bad build, missing files, will not install"), but yanked versions are forbidden by the `yanked` policy
+    "#
+    );
+
+    // By default, the yanked version is installed with a warning.
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_install()
+        .arg("--preview")
+        .arg("-r")
+        .arg("pylock.toml"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `colorama==0.4.2` is yanked (reason: "Bad build, missing files, will not install")
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + colorama==0.4.2
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn pep_751_install_registry_sdist() -> Result<()> {
     let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
@@ -14476,6 +15248,89 @@ fn config_settings_package() -> Result<()> {
     Ok(())
 }
 
+/// Packages built in the same install receive their own `--config-settings-package` values, merged
+/// over the global `--config-settings`.
+#[test]
+fn config_settings_package_multiple() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Create a package with a build backend that records the config settings it receives.
+    for name in ["alpha", "beta"] {
+        context.in_tree_backend(
+            name,
+            "0.1.0",
+            indoc! {r#"
+                import json
+
+
+                def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                    settings = json.dumps(config_settings, sort_keys=True)
+                    return write_wheel(wheel_directory, {f"{NAME}/settings.json": settings})
+            "#},
+        )?;
+    }
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("./alpha\n./beta")?;
+
+    let settings = |name: &str| -> Result<String> {
+        Ok(fs::read_to_string(
+            context.site_packages().join(name).join("settings.json"),
+        )?)
+    };
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--config-settings")
+        .arg("global=1")
+        .arg("--config-settings-package")
+        .arg("alpha:cmake.define.FOO=ON")
+        .arg("--config-settings-package")
+        .arg("beta:setup-args=-Dblas=openblas"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + alpha==0.1.0 (from file://[TEMP_DIR]/alpha)
+     + beta==0.1.0 (from file://[TEMP_DIR]/beta)
+    ");
+
+    assert_snapshot!(settings("alpha")?, @r#"{"cmake.define.FOO": "ON", "global": "1"}"#);
+    assert_snapshot!(settings("beta")?, @r#"{"global": "1", "setup-args": "-Dblas=openblas"}"#);
+
+    // Changing the settings for one package rebuilds only that package.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--config-settings")
+        .arg("global=1")
+        .arg("--config-settings-package")
+        .arg("alpha:cmake.define.FOO=OFF")
+        .arg("--config-settings-package")
+        .arg("beta:setup-args=-Dblas=openblas"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ alpha==0.1.0 (from file://[TEMP_DIR]/alpha)
+    ");
+
+    assert_snapshot!(settings("alpha")?, @r#"{"cmake.define.FOO": "OFF", "global": "1"}"#);
+    assert_snapshot!(settings("beta")?, @r#"{"global": "1", "setup-args": "-Dblas=openblas"}"#);
+
+    Ok(())
+}
+
 #[test]
 fn reject_invalid_archive_member_names() {
     let context = uv_test::test_context!("3.12").with_exclude_newer("2025-10-07T00:00:00Z");
@@ -14748,7 +15603,9 @@ fn already_installed_url_dependency_no_sources() -> Result<()> {
 /// Test that build dependencies respect locked versions from the resolution.
 #[test]
 fn pip_install_build_dependencies_respect_locked_versions() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that arbitrarily requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -14839,6 +15696,7 @@ fn pip_install_build_dependencies_respect_locked_versions() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 3.0 but got 4.3.0
@@ -14907,6 +15765,7 @@ fn pip_install_build_dependencies_respect_locked_versions() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 4.0 but got 3.7.1
@@ -16552,9 +17411,2485 @@ fn handle_record_mismatches() -> Result<()> {
     foo-0.1.0.dist-info/REQUESTED,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0
     foo-0.1.0.dist-info/WHEEL,sha256=[SHA256],[SIZE]
     foo-0.1.0.dist-info/uv_cache.json,sha256=[SHA256],[SIZE]
+    foo-0.1.0.dist-info/uv_reason.json,sha256=_mDdd8yjBGPyL5UOu7HJetN0df61qw_n_0Vn9hqzxoc,52
     foo/__init__.py,,49
     foo/py.typed,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0
     ");
 
     Ok(())
 }
+
+/// Concurrent installs that share a cache should build a source distribution once, with the other
+/// processes reporting that they're waiting on the in-flight build rather than repeating it.
+#[test]
+fn install_concurrent_build() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // The build announces that it started, then blocks until the test releases it.
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import time
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                root = pathlib.Path(__file__).resolve().parent.parent
+                with (root / "builds.txt").open("a") as f:
+                    f.write("built\n")
+                (root / "started").touch()
+                while not (root / "release").exists():
+                    time.sleep(0.01)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+
+    // Install into a separate environment from each process, sharing the cache.
+    let install = |i: usize| {
+        let venv = context.temp_dir.child(format!("venv-{i}"));
+        context.venv().arg(venv.as_os_str()).assert().success();
+        let mut command = context.pip_install();
+        command
+            .arg("--offline")
+            .arg("./child")
+            .env(EnvVars::VIRTUAL_ENV, venv.as_os_str())
+            // Render the progress messages, which are written to stderr when it isn't a terminal.
+            .env_remove(EnvVars::UV_TEST_NO_CLI_PROGRESS)
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped());
+        command.spawn()
+    };
+
+    // Start the first build, and wait for the backend to be invoked.
+    let mut builder = install(0)?;
+    while !context.temp_dir.child("started").exists() {
+        assert!(builder.try_wait()?.is_none(), "The build exited early");
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    // Every other process should wait on the in-flight build.
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let waiters = (1..4)
+        .map(|i| {
+            let mut waiter = install(i)?;
+            let stderr = waiter.stderr.take().unwrap();
+            let sender = sender.clone();
+            let reader = std::thread::spawn(move || {
+                let mut output = String::new();
+                for line in std::io::BufRead::lines(std::io::BufReader::new(stderr)) {
+                    let line = line.unwrap();
+                    if line.contains("Waiting for another process building child") {
+                        sender.send(i).unwrap();
+                    }
+                    output.push_str(&line);
+                    output.push('\n');
+                }
+                output
+            });
+            Ok((waiter, reader))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let waiting = (1..4)
+        .map(|_| receiver.recv_timeout(std::time::Duration::from_secs(60)))
+        .collect::<Result<Vec<_>, _>>();
+
+    // Release the build either way, such that no process is left behind.
+    context.temp_dir.child("release").touch()?;
+    assert!(builder.wait()?.success());
+    for (mut waiter, reader) in waiters {
+        let status = waiter.wait()?;
+        let output = reader.join().unwrap();
+        assert!(status.success(), "{output}");
+    }
+    let waiting = waiting.context("A process didn't report that it was waiting on the build")?;
+    assert_eq!(waiting.len(), 3);
+
+    // Only one of the processes should have run the build backend.
+    let builds = fs_err::read_to_string(context.temp_dir.join("builds.txt"))?;
+    assert_eq!(builds.lines().count(), 1);
+
+    Ok(())
+}
+
+/// With `UV_BUILD_OUTPUT=stream`, the build backend output is displayed as it's produced, prefixed
+/// with the package name, while still being inspected on failure.
+#[test]
+fn install_stream_build_output() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                if os.environ.get("FAIL"):
+                    print("ModuleNotFoundError: No module named 'distutils'", file=sys.stderr)
+                    sys.exit(1)
+                print("Writing to stdout")
+                print("Writing to stderr", file=sys.stderr)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+
+    // The output of both streams is forwarded, prefixed with the package name. The relative order
+    // of the two streams isn't deterministic, so we don't snapshot it.
+    let output = context
+        .pip_install()
+        .arg("./child")
+        .env(EnvVars::UV_BUILD_OUTPUT, "stream")
+        .output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[child] Writing to stdout\n"), "{stderr}");
+    assert!(stderr.contains("[child] Writing to stderr\n"), "{stderr}");
+
+    // On failure, the output isn't repeated, but it's still used to diagnose the failure.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--reinstall")
+        .arg("--no-cache")
+        .arg("./child")
+        .env(EnvVars::UV_BUILD_OUTPUT, "stream")
+        .env("FAIL", "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    [child] ModuleNotFoundError: No module named 'distutils'
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+    hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `child >0.1.0`) to avoid building a version of `child` that depends on `distutils`.
+    ");
+
+    // Invalid values are rejected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .env(EnvVars::UV_BUILD_OUTPUT, "live"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse environment variable `UV_BUILD_OUTPUT` with invalid value `live`: expected `stream`
+    ");
+
+    Ok(())
+}
+
+/// With `UV_BUILD_TIMEOUT`, a build backend that exceeds the timeout is terminated along with any
+/// processes it spawned, and the captured output is still used to diagnose the failure.
+#[test]
+fn install_build_timeout() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+            import subprocess
+            import sys
+            import time
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                print("ModuleNotFoundError: No module named 'distutils'", file=sys.stderr, flush=True)
+
+                # Spawn a subprocess that records a heartbeat until it's terminated with the backend.
+                subprocess.Popen(
+                    [
+                        sys.executable,
+                        "-c",
+                        "import itertools, pathlib, sys, time\n"
+                        "for beat in itertools.count():\n"
+                        "    pathlib.Path(sys.argv[1]).write_text(str(beat))\n"
+                        "    time.sleep(0.05)",
+                        os.environ["MARKER"],
+                    ]
+                )
+                while not os.path.exists(os.environ["MARKER"]):
+                    time.sleep(0.01)
+                time.sleep(60)
+        "#},
+    )?;
+
+    let marker = context.temp_dir.child("heartbeat");
+
+    let mut filters = context.filters();
+    filters.push((r"\(signal: 9 \(SIGKILL\)\)|\(exit code: 1\)", "([STATUS])"));
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("--no-cache")
+        .arg("./child")
+        .env(EnvVars::UV_BUILD_TIMEOUT, "1")
+        .env("MARKER", marker.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build backend was terminated after exceeding the build timeout ([TIME])
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed ([STATUS])
+
+          [stderr]
+          ModuleNotFoundError: No module named 'distutils'
+
+    hint: To allow builds to take longer, increase the timeout with `--build-timeout` or `UV_BUILD_TIMEOUT`
+    hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `child >0.1.0`) to avoid building a version of `child` that depends on `distutils`.
+    ");
+
+    // The subprocess was terminated along with the build backend, so its heartbeat stopped.
+    let heartbeat = fs_err::read_to_string(&marker)?;
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    assert_eq!(fs_err::read_to_string(&marker)?, heartbeat);
+
+    // A timeout of zero is rejected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .arg("--build-timeout")
+        .arg("0"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '0' for '--build-timeout <SECONDS>': number would be zero for non-zero type
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// With `--build-retries`, a build backend hook that fails with a transient filesystem error is
+/// re-run, while other failures are reported immediately.
+#[test]
+fn install_build_retries() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                # Fail with a transient error until the remaining failures are exhausted, leaving
+                # a partial build artifact behind.
+                failures = pathlib.Path(os.environ["FAILURES"])
+                remaining = int(failures.read_text())
+                if remaining:
+                    failures.write_text(str(remaining - 1))
+                    pathlib.Path("child.pyd").write_text("")
+                    print("PermissionError: [WinError 5] Access is denied: 'child.pyd'", file=sys.stderr)
+                    sys.exit(1)
+                if pathlib.Path("child.pyd").exists():
+                    print("Found a partial build artifact: 'child.pyd'", file=sys.stderr)
+                    sys.exit(1)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+
+    let failures = context.temp_dir.child("failures");
+
+    // By default, the build isn't retried.
+    failures.write_str("1")?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-cache")
+        .arg("./child")
+        .env("FAILURES", failures.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          PermissionError: [WinError 5] Access is denied: 'child.pyd'
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    // With a retry, the build succeeds on the second attempt, in a fresh copy of the source tree.
+    fs_err::remove_file(context.temp_dir.child("child").child("child.pyd"))?;
+    failures.write_str("1")?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-cache")
+        .arg("./child")
+        .arg("--build-retries")
+        .arg("1")
+        .env("FAILURES", failures.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // If every attempt fails, the error reports the number of attempts.
+    failures.write_str("3")?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-cache")
+        .arg("--reinstall")
+        .arg("./child")
+        .env(EnvVars::UV_BUILD_RETRIES, "1")
+        .env("FAILURES", failures.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build failed after 2 attempts
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          PermissionError: [WinError 5] Access is denied: 'child.pyd'
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    Ok(())
+}
+
+/// With `--build-events-file`, a JSON event is appended at the start and finish of each build,
+/// including the classified failure of failed builds, and for each build that fails while setting
+/// up its build environment.
+#[test]
+fn install_build_events() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend("child", "0.1.0", "")?;
+    context.in_tree_backend(
+        "broken",
+        "0.1.0",
+        indoc! {r#"
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                print(
+                    "broken.c:1:10: fatal error: graphviz/cgraph.h: No such file or directory",
+                    file=sys.stderr,
+                )
+                sys.exit(1)
+        "#},
+    )?;
+
+    // A project whose build requirements can't be resolved.
+    context
+        .temp_dir
+        .child("unresolvable")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [project]
+            name = "unresolvable"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["missing"]
+            build-backend = "backend"
+        "#})?;
+
+    let events = context.temp_dir.child("events").child("build.jsonl");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-cache")
+        .arg("./child")
+        .arg("--build-events-file")
+        .arg(events.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-cache")
+        .arg("./broken")
+        .env(EnvVars::UV_BUILD_EVENTS_FILE, events.path()), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `broken @ file://[TEMP_DIR]/broken`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          broken.c:1:10: fatal error: graphviz/cgraph.h: No such file or directory
+
+    hint: This error likely indicates that you need to install a library that provides "graphviz/cgraph.h" for `broken@0.1.0`
+    "#);
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-cache")
+        .arg("--no-index")
+        .arg("./unresolvable")
+        .arg("--build-events-file")
+        .arg(events.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `unresolvable @ file://[TEMP_DIR]/unresolvable`
+      ├─▶ Failed to resolve requirements from `build-system.requires`
+      ├─▶ No solution found when resolving: `missing`
+      ╰─▶ Because missing was not found in the provided package locations and you require missing, we can conclude that your requirements are unsatisfiable.
+
+          hint: Packages were unavailable because index lookups were disabled and no additional package locations were provided (try: `--find-links <uri>`)
+    ");
+
+    // Each invocation is identified by a distinct ID.
+    let contents = fs::read_to_string(events.path())?;
+    let invocations = contents
+        .lines()
+        .map(|line| {
+            let event: serde_json::Value = serde_json::from_str(line)?;
+            Ok(event["invocation_id"]
+                .as_str()
+                .expect("invocation ID should be a string")
+                .to_string())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(invocations.len(), 5);
+    assert_eq!(invocations[0], invocations[1]);
+    assert_eq!(invocations[2], invocations[3]);
+    assert_ne!(invocations[1], invocations[2]);
+    assert_ne!(invocations[3], invocations[4]);
+
+    let mut filters = context.filters();
+    filters.push((r#""invocation_id":"[^"]+""#, r#""invocation_id":"[ID]""#));
+    filters.push((r#""timestamp_ms":\d+"#, r#""timestamp_ms":[TIME]"#));
+    filters.push((r#""duration_ms":\d+"#, r#""duration_ms":[TIME]"#));
+    filters.push((r#""executable":"[^"]+""#, r#""executable":"[PYTHON]""#));
+    filters.push((r#""version":"3\.12\.\d+""#, r#""version":"3.12.[X]""#));
+
+    // Events are appended across invocations, and each invocation numbers its builds from one.
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        assert_snapshot!(contents, @r#"
+        {"schema_version":2,"invocation_id":"[ID]","event":"build-start","sequence":1,"timestamp_ms":[TIME],"kind":"wheel","package":"child","version":"0.1.0","backend":"backend","interpreter":{"implementation":"cpython","version":"3.12.[X]","executable":"[PYTHON]"},"isolation":"isolated","environment_reused":false}
+        {"schema_version":2,"invocation_id":"[ID]","event":"build-finish","sequence":1,"timestamp_ms":[TIME],"kind":"wheel","package":"child","version":"0.1.0","backend":"backend","interpreter":{"implementation":"cpython","version":"3.12.[X]","executable":"[PYTHON]"},"isolation":"isolated","environment_reused":false,"status":"success","duration_ms":[TIME]}
+        {"schema_version":2,"invocation_id":"[ID]","event":"build-start","sequence":1,"timestamp_ms":[TIME],"kind":"wheel","package":"broken","version":"0.1.0","backend":"backend","interpreter":{"implementation":"cpython","version":"3.12.[X]","executable":"[PYTHON]"},"isolation":"isolated","environment_reused":false}
+        {"schema_version":2,"invocation_id":"[ID]","event":"build-finish","sequence":1,"timestamp_ms":[TIME],"kind":"wheel","package":"broken","version":"0.1.0","backend":"backend","interpreter":{"implementation":"cpython","version":"3.12.[X]","executable":"[PYTHON]"},"isolation":"isolated","environment_reused":false,"status":"failure","duration_ms":[TIME],"failure":{"kind":"missing-header","cause":"header","detail":"graphviz/cgraph.h"}}
+        {"schema_version":2,"invocation_id":"[ID]","event":"setup-failure","sequence":1,"timestamp_ms":[TIME],"kind":"wheel","package":"unresolvable","version":null,"interpreter":{"implementation":"cpython","version":"3.12.[X]","executable":"[PYTHON]"},"status":"failure","failure":{"kind":"requirements-resolve"}}
+        "#);
+    });
+
+    Ok(())
+}
+
+/// Builds with identical build requirements share a single build environment, unless
+/// `--no-build-environment-reuse` is set. Either way, no build environments outlive the
+/// invocation.
+#[test]
+fn install_shared_build_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    for name in ["alpha", "beta"] {
+        context.in_tree_backend(
+            name,
+            "0.1.0",
+            indoc! {r#"
+                import sys
+
+
+                def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                    # Record the build environment in which the backend is running.
+                    marker = pathlib.Path(__file__).resolve().parent.parent / "environments.txt"
+                    with marker.open("a") as f:
+                        f.write(sys.prefix + "\n")
+                    return write_wheel(wheel_directory)
+            "#},
+        )?;
+    }
+
+    let environments = context.temp_dir.child("environments.txt");
+    let builds = context.cache_dir.child("builds-v0");
+    let count_builds = || -> usize { fs::read_dir(builds.path()).map_or(0, Iterator::count) };
+
+    // Both builds run in the same environment, which is removed once the builds complete.
+    context
+        .pip_install()
+        .arg("./alpha")
+        .arg("./beta")
+        .assert()
+        .success();
+    let prefixes = fs_err::read_to_string(&environments)?;
+    let prefixes = prefixes.lines().collect::<Vec<_>>();
+    assert_eq!(prefixes.len(), 2);
+    assert_eq!(prefixes[0], prefixes[1]);
+    assert_eq!(count_builds(), 0);
+
+    // With reuse disabled, each build creates its own environment.
+    fs_err::remove_file(&environments)?;
+    context
+        .pip_install()
+        .arg("--reinstall")
+        .arg("--refresh")
+        .arg("--no-build-environment-reuse")
+        .arg("./alpha")
+        .arg("./beta")
+        .assert()
+        .success();
+    let prefixes = fs_err::read_to_string(&environments)?;
+    let prefixes = prefixes.lines().collect::<Vec<_>>();
+    assert_eq!(prefixes.len(), 2);
+    assert_ne!(prefixes[0], prefixes[1]);
+    assert_eq!(count_builds(), 0);
+
+    // The same applies to `UV_NO_BUILD_ENVIRONMENT_REUSE`.
+    fs_err::remove_file(&environments)?;
+    context
+        .pip_install()
+        .arg("--reinstall")
+        .arg("--refresh")
+        .arg("./alpha")
+        .arg("./beta")
+        .env(EnvVars::UV_NO_BUILD_ENVIRONMENT_REUSE, "1")
+        .assert()
+        .success();
+    let prefixes = fs_err::read_to_string(&environments)?;
+    let prefixes = prefixes.lines().collect::<Vec<_>>();
+    assert_eq!(prefixes.len(), 2);
+    assert_ne!(prefixes[0], prefixes[1]);
+    assert_eq!(count_builds(), 0);
+
+    Ok(())
+}
+
+/// With `--build-env`, the build backend only sees the allowlisted environment variables, with
+/// either the value from the environment or an explicit value.
+#[test]
+fn install_build_env() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let project = context.in_tree_backend(
+        "project",
+        "0.1.0",
+        indoc! {r#"
+            import json
+            import os
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                # Record the variables visible to the backend.
+                marker = pathlib.Path(__file__).resolve().parent.parent / "environment.json"
+                marker.write_text(
+                    json.dumps({key: os.environ.get(key) for key in ["ALLOWED", "HIDDEN", "SET"]})
+                )
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+
+    let environment = context.temp_dir.child("environment.json");
+
+    // Without an allowlist, the backend inherits the environment.
+    context
+        .pip_install()
+        .arg("./project")
+        .env("ALLOWED", "allowed")
+        .env("HIDDEN", "hidden")
+        .assert()
+        .success();
+    assert_eq!(
+        fs_err::read_to_string(&environment)?,
+        r#"{"ALLOWED": "allowed", "HIDDEN": "hidden", "SET": null}"#
+    );
+
+    // With an allowlist, variables absent from the allowlist are hidden. Since the allowlist is
+    // part of the cache key, the project is rebuilt.
+    context
+        .pip_install()
+        .arg("--reinstall")
+        .arg("./project")
+        .arg("--build-env")
+        .arg("ALLOWED")
+        .arg("--build-env")
+        .arg("SET=value")
+        .env("ALLOWED", "allowed")
+        .env("HIDDEN", "hidden")
+        .assert()
+        .success();
+    assert_eq!(
+        fs_err::read_to_string(&environment)?,
+        r#"{"ALLOWED": "allowed", "HIDDEN": null, "SET": "value"}"#
+    );
+
+    Ok(())
+}
+
+/// Write projects with a build backend that records the start and end of each build to the file
+/// at `BUILD_EVENTS`.
+///
+/// If `BUILD_RENDEZVOUS` is set, each build waits until that many builds have started, such that
+/// the builds are known to run concurrently. If a `fail` file exists next to the backend, the
+/// build fails.
+fn write_recording_build_projects(context: &uv_test::TestContext, names: &[&str]) -> Result<()> {
+    for name in names {
+        context.in_tree_backend(
+            name,
+            "0.1.0",
+            indoc! {r#"
+                import os
+                import sys
+                import time
+
+
+                def record(event):
+                    with open(os.environ["BUILD_EVENTS"], "a") as f:
+                        f.write(f"{event} {NAME}\n")
+
+
+                def started():
+                    with open(os.environ["BUILD_EVENTS"]) as f:
+                        return sum(line.startswith("start ") for line in f)
+
+
+                def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                    record("start")
+
+                    if rendezvous := os.environ.get("BUILD_RENDEZVOUS"):
+                        deadline = time.monotonic() + 60
+                        while started() < int(rendezvous):
+                            if time.monotonic() > deadline:
+                                sys.exit(f"Timed out waiting for {rendezvous} builds to start")
+                            time.sleep(0.01)
+
+                    record("end")
+
+                    if (pathlib.Path(__file__).parent / "fail").exists():
+                        sys.exit(f"Failed to build {NAME}")
+
+                    return write_wheel(wheel_directory)
+            "#},
+        )?;
+    }
+    Ok(())
+}
+
+/// Read and reset the build events recorded by [`write_recording_build_projects`].
+fn read_build_events(events: &assert_fs::fixture::ChildPath) -> Result<Vec<String>> {
+    let lines = fs_err::read_to_string(events)?
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+    fs_err::remove_file(events)?;
+    Ok(lines)
+}
+
+/// Independent source distributions are built concurrently, up to `--concurrent-builds`.
+#[test]
+fn install_concurrent_builds() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_recording_build_projects(&context, &["alpha", "beta"])?;
+    let events = context.temp_dir.child("build-events.txt");
+
+    // With two concurrent builds, both builds start before either completes.
+    context
+        .pip_install()
+        .arg("--no-cache")
+        .arg("--concurrent-builds")
+        .arg("2")
+        .arg("./alpha")
+        .arg("./beta")
+        .env("BUILD_EVENTS", events.path())
+        .env("BUILD_RENDEZVOUS", "2")
+        .assert()
+        .success();
+    let recorded = read_build_events(&events)?;
+    assert_eq!(recorded.len(), 4, "{recorded:?}");
+    assert!(
+        recorded[..2]
+            .iter()
+            .all(|event| event.starts_with("start ")),
+        "{recorded:?}"
+    );
+
+    // With a single concurrent build, each build completes before the next one starts.
+    context
+        .pip_install()
+        .arg("--no-cache")
+        .arg("--reinstall")
+        .arg("--concurrent-builds")
+        .arg("1")
+        .arg("./alpha")
+        .arg("./beta")
+        .env("BUILD_EVENTS", events.path())
+        .assert()
+        .success();
+    let recorded = read_build_events(&events)?;
+    assert_eq!(recorded.len(), 4, "{recorded:?}");
+    for build in recorded.chunks(2) {
+        assert_eq!(build[1], build[0].replace("start ", "end "), "{recorded:?}");
+    }
+
+    Ok(())
+}
+
+/// A build that requires another package to be built first (here, as a build dependency) waits for
+/// that build to complete, even if only a single build can run at a time.
+#[test]
+fn install_concurrent_builds_dependent() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    write_recording_build_projects(&context, &["child", "parent"])?;
+    let events = context.temp_dir.child("build-events.txt");
+
+    let child_url = Url::from_file_path(context.temp_dir.child("child").path()).unwrap();
+    context
+        .temp_dir
+        .child("parent/pyproject.toml")
+        .write_str(&formatdoc! {r#"
+            [project]
+            name = "parent"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["child @ {child_url}"]
+            build-backend = "backend"
+            backend-path = ["."]
+        "#})?;
+
+    context
+        .pip_install()
+        .arg("--no-cache")
+        .arg("--concurrent-builds")
+        .arg("1")
+        .arg("./parent")
+        .env("BUILD_EVENTS", events.path())
+        .assert()
+        .success();
+    assert_eq!(
+        read_build_events(&events)?,
+        ["start child", "end child", "start parent", "end parent"]
+    );
+
+    Ok(())
+}
+
+/// With `--no-fail-fast`, the remaining builds complete after a build fails, and every failure is
+/// reported.
+#[test]
+fn install_no_fail_fast() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
+    write_recording_build_projects(&context, &["alpha", "beta", "gamma"])?;
+    context.temp_dir.child("alpha/fail").touch()?;
+    context.temp_dir.child("beta/fail").touch()?;
+    let events = context.temp_dir.child("build-events.txt");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-fail-fast")
+        .arg("./alpha")
+        .arg("./beta")
+        .arg("./gamma")
+        .env("BUILD_EVENTS", events.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+      × Failed to build `alpha @ file://[TEMP_DIR]/alpha`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stderr]
+          Failed to build alpha
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+      × Failed to build `beta @ file://[TEMP_DIR]/beta`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stderr]
+          Failed to build beta
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    "
+    );
+
+    // All three builds ran to completion.
+    let recorded = read_build_events(&events)?;
+    assert_eq!(
+        recorded
+            .iter()
+            .filter(|event| event.starts_with("end "))
+            .count(),
+        3,
+        "{recorded:?}"
+    );
+
+    Ok(())
+}
+
+/// With `--no-fail-fast`, a build that fails during resolution is reported along with the failures
+/// of the other builds that were already in progress.
+#[test]
+fn install_no_fail_fast_resolution() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
+    write_recording_build_projects(&context, &["alpha", "beta"])?;
+    let events = context.temp_dir.child("build-events.txt");
+
+    // Archive each project as a failing source distribution, with dynamic metadata such that it
+    // must be built to resolve its dependencies.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    for name in ["alpha", "beta"] {
+        let project = context.temp_dir.child(name);
+        let pyproject_toml = fs::read_to_string(project.child("pyproject.toml"))?.replace(
+            "requires-python = \">=3.12\"\n",
+            "requires-python = \">=3.12\"\ndynamic = [\"dependencies\"]\n",
+        );
+        let backend = fs::read_to_string(project.child("backend.py"))?;
+        let pyproject_toml_path = format!("{name}-0.1.0/pyproject.toml");
+        let backend_path = format!("{name}-0.1.0/backend.py");
+        let fail_path = format!("{name}-0.1.0/fail");
+        write_tar_gz(
+            File::create(links.child(format!("{name}-0.1.0.tar.gz")).path())?,
+            &[
+                (pyproject_toml_path.as_str(), pyproject_toml.as_str()),
+                (backend_path.as_str(), backend.as_str()),
+                (fail_path.as_str(), ""),
+            ],
+        )?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--no-fail-fast")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("alpha")
+        .arg("beta")
+        .env("BUILD_EVENTS", events.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to build `alpha==0.1.0`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stderr]
+          Failed to build alpha
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+      × Failed to build `beta==0.1.0`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stderr]
+          Failed to build beta
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    "
+    );
+
+    // Both builds ran, even though the resolution needed only the first to fail.
+    let recorded = read_build_events(&events)?;
+    assert_eq!(
+        recorded
+            .iter()
+            .filter(|event| event.starts_with("end "))
+            .count(),
+        2,
+        "{recorded:?}"
+    );
+
+    Ok(())
+}
+
+/// The requirements returned by `get_requires_for_build_wheel` are cached per source
+/// distribution, such that the hook isn't invoked again when the same source distribution is
+/// built in a subsequent run, unless the cache is refreshed.
+#[test]
+fn install_cached_build_requires() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let child = context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+
+
+            def log_hook(hook):
+                with open(os.environ["HOOK_LOG"], "a") as fp:
+                    fp.write(hook + "\n")
+
+
+            def get_requires_for_build_wheel(config_settings=None):
+                log_hook("get_requires_for_build_wheel")
+                return []
+
+
+            def prepare_metadata_for_build_wheel(metadata_directory, config_settings=None):
+                log_hook("prepare_metadata_for_build_wheel")
+                dist_info = pathlib.Path(metadata_directory, "child-0.1.0.dist-info")
+                dist_info.mkdir()
+                dist_info.joinpath("METADATA").write_text(METADATA)
+                return dist_info.name
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                log_hook("build_wheel")
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dynamic = ["dependencies"]
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+
+    // Archive the project as a source distribution.
+    let source_dist = context.temp_dir.child("child-0.1.0.tar.gz");
+    // Flush the file after we're done.
+    {
+        let file = File::create(source_dist.path())?;
+        write_tar_gz(
+            file,
+            &[
+                (
+                    "child-0.1.0/pyproject.toml",
+                    &fs::read_to_string(child.child("pyproject.toml"))?,
+                ),
+                (
+                    "child-0.1.0/backend.py",
+                    &fs::read_to_string(child.child("backend.py"))?,
+                ),
+            ],
+        )?;
+    }
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("child @ ./child-0.1.0.tar.gz")?;
+
+    let hook_log = context.temp_dir.child("hooks.log");
+
+    // Resolving the source distribution requires its metadata, which invokes the hooks.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .env("HOOK_LOG", hook_log.path())
+        .assert()
+        .success();
+    assert_snapshot!(fs::read_to_string(&hook_log)?, @"
+    get_requires_for_build_wheel
+    prepare_metadata_for_build_wheel
+    ");
+
+    // Building the wheel in a subsequent run reuses the requirements from the first build.
+    fs::remove_file(&hook_log)?;
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.in")
+        .env("HOOK_LOG", hook_log.path())
+        .assert()
+        .success();
+    assert_snapshot!(fs::read_to_string(&hook_log)?, @"build_wheel");
+
+    // With `--refresh`, the cached requirements are ignored. Use a copy of the source
+    // distribution, such that the wheel isn't already cached.
+    let refresh = context.temp_dir.child("refresh");
+    refresh.create_dir_all()?;
+    fs::copy(&source_dist, refresh.child("child-0.1.0.tar.gz"))?;
+    requirements_in.write_str("child @ ./refresh/child-0.1.0.tar.gz")?;
+
+    fs::remove_file(&hook_log)?;
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .env("HOOK_LOG", hook_log.path())
+        .assert()
+        .success();
+    fs::remove_file(&hook_log)?;
+    context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.in")
+        .arg("--refresh")
+        .env("HOOK_LOG", hook_log.path())
+        .assert()
+        .success();
+    assert_snapshot!(fs::read_to_string(&hook_log)?, @"
+    get_requires_for_build_wheel
+    build_wheel
+    ");
+
+    Ok(())
+}
+
+/// The build backend output is persisted to a log in the cache, which is referenced from the error
+/// on failure.
+#[test]
+fn install_build_log() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                print("Compiling child", flush=True)
+                print("warning: deprecated API", file=sys.stderr, flush=True)
+                print("Linking child", flush=True)
+                if os.environ.get("FAIL"):
+                    print("error: linker failed", file=sys.stderr, flush=True)
+                    sys.exit(1)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+
+    let build_logs = context.cache_dir.child("build-logs-v0").child("child");
+    let find_log = || -> Option<PathBuf> {
+        fs::read_dir(build_logs.path())
+            .ok()?
+            .map(|entry| entry.unwrap().path().join("build.log"))
+            .find(|path| path.is_file())
+    };
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .env("FAIL", "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stdout]
+          Compiling child
+          Linking child
+
+          [stderr]
+          warning: deprecated API
+          error: linker failed
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    // The log contains the output of every hook invocation. The streams are read concurrently, so
+    // only the order of the lines within each stream is deterministic.
+    let log = find_log().expect("the build log to exist");
+    let contents = fs::read_to_string(&log)?;
+    let stream = |prefix: &str| {
+        contents
+            .lines()
+            .filter(|line| line.starts_with('#') || line.starts_with(prefix))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    assert_snapshot!(stream("[stdout]"), @"
+    # get_requires_for_build_wheel
+    # build_wheel
+    [stdout] Compiling child
+    [stdout] Linking child
+    ");
+    assert_snapshot!(stream("[stderr]"), @"
+    # get_requires_for_build_wheel
+    # build_wheel
+    [stderr] warning: deprecated API
+    [stderr] error: linker failed
+    ");
+
+    // The error references the log.
+    let output = context
+        .pip_install()
+        .arg("./child")
+        .env("FAIL", "1")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let path = stderr
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("full log: "))
+        .expect("the error to reference the build log");
+    assert_eq!(
+        fs::canonicalize(context.temp_dir.join(path))?,
+        fs::canonicalize(&log)?
+    );
+
+    // A log of a successful build is removed.
+    context.pip_install().arg("./child").assert().success();
+    assert_eq!(find_log(), None);
+
+    // Unless the logs are retained.
+    context
+        .pip_install()
+        .arg("--reinstall")
+        .arg("--keep-build-logs")
+        .arg("./child")
+        .assert()
+        .success();
+    assert!(find_log().is_some());
+
+    // Only the most recent logs of each package are retained.
+    for stale in 0..8 {
+        build_logs
+            .child(format!("0.0.{stale}-0123456789abcdef"))
+            .child("build.log")
+            .touch()?;
+    }
+    context
+        .pip_install()
+        .arg("--reinstall")
+        .arg("./child")
+        .env("FAIL", "1")
+        .assert()
+        .failure();
+    assert_eq!(fs::read_dir(build_logs.path())?.count(), 5);
+
+    // The log is removed when the package is removed from the cache.
+    context.clean().arg("child").assert().success();
+    assert_eq!(find_log(), None);
+
+    Ok(())
+}
+
+/// With `--keep-build-env`, the build environment of a failed build is retained and referenced from
+/// the error.
+#[test]
+fn install_keep_build_env() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filter((r"builds-v0/[^\s]+", "builds-v0/[TMP]"))
+        .with_filtered_build_logs();
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                if os.environ.get("FAIL"):
+                    print("error: linker failed", file=sys.stderr, flush=True)
+                    sys.exit(1)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+
+    let builds = context.cache_dir.child("builds-v0");
+    let count_builds = || -> usize { fs::read_dir(builds.path()).map_or(0, Iterator::count) };
+
+    // Without the flag, the build environment is removed.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .env("FAIL", "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stderr]
+          error: linker failed
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+    assert_eq!(count_builds(), 0);
+
+    // Increasing the verbosity doesn't retain the build environment.
+    context
+        .pip_install()
+        .arg("./child")
+        .arg("-vv")
+        .env("FAIL", "1")
+        .assert()
+        .failure();
+    assert_eq!(count_builds(), 0);
+
+    // With the flag, the build environment survives and the error references it.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .arg("--keep-build-env")
+        .env("FAIL", "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+          build environment: [CACHE_DIR]/builds-v0/[TMP]
+          source tree: child
+
+          [stderr]
+          error: linker failed
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+    assert_eq!(count_builds(), 1);
+
+    // The retained environment is a virtual environment in which the build backend can be re-run.
+    let output = context
+        .pip_install()
+        .arg("./child")
+        .env(EnvVars::UV_KEEP_BUILD_ENV, "1")
+        .env("FAIL", "1")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let path = stderr
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("build environment: "))
+        .expect("the error to reference the build environment");
+    assert!(context.temp_dir.join(path).join("pyvenv.cfg").is_file());
+    assert_eq!(count_builds(), 2);
+
+    // The environment of a successful build is removed, even with the flag.
+    context
+        .pip_install()
+        .arg("./child")
+        .arg("--keep-build-env")
+        .assert()
+        .success();
+    assert_eq!(count_builds(), 2);
+
+    // Retained build environments are removed with `uv cache clean`.
+    context.clean().assert().success();
+    assert_eq!(count_builds(), 0);
+
+    Ok(())
+}
+
+/// With `--show-build-warnings`, the warnings emitted by a successful build are summarized once the
+/// installation completes.
+#[test]
+fn install_show_build_warnings() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                print("running bdist_wheel")
+                print("backend.py:8: SetuptoolsDeprecationWarning: License classifiers are deprecated.", file=sys.stderr)
+                print("WARNING: Missing license file", file=sys.stderr)
+                return write_wheel(wheel_directory)
+        "#},
+    )?;
+
+    // By default, the output of a successful build is discarded.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // With the flag, the warnings are reported after the installation.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .arg("--reinstall")
+        .arg("--no-cache")
+        .arg("--show-build-warnings"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    warning: The build backend emitted 2 warnings while building `child==0.1.0`:
+      backend.py:8: SetuptoolsDeprecationWarning: License classifiers are deprecated.
+      WARNING: Missing license file
+    ");
+
+    // Custom patterns replace the defaults.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .arg("--reinstall")
+        .arg("--no-cache")
+        .env(EnvVars::UV_SHOW_BUILD_WARNINGS, "1")
+        .env(EnvVars::UV_BUILD_WARNING_PATTERN, "missing license"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    warning: The build backend emitted a warning while building `child==0.1.0`:
+      WARNING: Missing license file
+    ");
+
+    Ok(())
+}
+
+/// If the build backend doesn't implement `build_wheel` for the source tree, the wheel is built
+/// from a source distribution instead.
+#[test]
+fn install_build_via_sdist_fallback() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filter((
+        r#"File "<string>", line \d+"#,
+        r#"File "<string>", line [LINE]"#,
+    ));
+
+    // An in-tree backend that only implements `build_wheel` for source distributions (i.e., if
+    // `PKG-INFO` is present). The `other` backend instead raises an exception for source trees,
+    // and the `never` backend doesn't implement `build_wheel` at all.
+    for (name, mode) in [("child", "missing"), ("other", "raise"), ("never", "never")] {
+        context.in_tree_backend(
+            name,
+            "0.1.0",
+            &formatdoc! {r#"
+                import os
+
+                MODE = "{mode}"
+                IS_SDIST = os.path.exists("PKG-INFO")
+
+
+                class UnsupportedOperation(Exception):
+                    pass
+
+
+                def build_sdist(sdist_directory, config_settings=None):
+                    return write_sdist(sdist_directory)
+
+
+                def _build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                    if not IS_SDIST:
+                        raise UnsupportedOperation("Wheels can only be built from a source distribution")
+                    return write_wheel(wheel_directory)
+
+
+                if (IS_SDIST and MODE != "never") or MODE == "raise":
+                    build_wheel = _build_wheel
+                else:
+                    del build_wheel
+            "#},
+        )?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // Only a missing `build_wheel` hook falls back to a source distribution. Exceptions raised by
+    // the hook fail the build, whatever their type.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./other"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `other @ file://[TEMP_DIR]/other`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          Traceback (most recent call last):
+            File "<string>", line [LINE], in <module>
+            File "[TEMP_DIR]/other/backend.py", line 65, in _build_wheel
+              raise UnsupportedOperation("Wheels can only be built from a source distribution")
+          backend.UnsupportedOperation: Wheels can only be built from a source distribution
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    "#);
+
+    // If the backend doesn't implement `build_wheel` for the source distribution either, the
+    // error names the missing hook.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./never"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `never @ file://[TEMP_DIR]/never`
+      ├─▶ Failed to build the wheel from the source distribution while building a wheel via a source distribution
+      ╰─▶ The build backend `backend` does not support building wheels
+    ");
+
+    Ok(())
+}
+
+/// With `--build-via-sdist`, wheels for local sources are built from a source distribution, such
+/// that files generated for the source distribution are included in the wheel.
+#[test]
+fn install_build_via_sdist() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import os
+
+
+            def build_sdist(sdist_directory, config_settings=None):
+                return write_sdist(sdist_directory, {"_version.py": "version = '0.1.0'\n"})
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                # The version file is only generated for source distributions.
+                files = {}
+                if os.path.exists("_version.py"):
+                    files["child/_version.py"] = pathlib.Path("_version.py").read_text()
+                return write_wheel(wheel_directory, files)
+        "#},
+    )?;
+
+    // By default, the wheel is built from the source tree.
+    context.pip_install().arg("./child").assert().success();
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import child._version")
+        .assert()
+        .failure();
+
+    // The wheel built from the source tree is cached separately, so it isn't reused.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .arg("--reinstall")
+        .arg("--build-via-sdist"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import child._version")
+        .assert()
+        .success();
+
+    // Likewise, the wheel built via the source distribution isn't reused without the setting.
+    context
+        .pip_install()
+        .arg("./child")
+        .arg("--reinstall")
+        .assert()
+        .success();
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import child._version")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+/// With `--build-via-sdist`, errors indicate the stage of building the wheel that failed.
+#[test]
+fn install_build_via_sdist_errors() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // A backend that fails to build a source distribution, one that returns a source distribution
+    // that can't be extracted, and one that fails to build a wheel from its source distribution.
+    for name in ["bad-sdist", "bad-archive", "bad-wheel"] {
+        context.in_tree_backend(
+            name,
+            "0.1.0",
+            indoc! {r#"
+                import os
+                import sys
+
+
+                def build_sdist(sdist_directory, config_settings=None):
+                    if NAME == "bad-sdist":
+                        print("Missing generated files", file=sys.stderr)
+                        sys.exit(1)
+                    if NAME == "bad-archive":
+                        open(os.path.join(sdist_directory, "bad-archive-0.1.0.txt"), "w").close()
+                        return "bad-archive-0.1.0.txt"
+                    return write_sdist(sdist_directory)
+
+
+                def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                    print("Missing compiler", file=sys.stderr)
+                    sys.exit(1)
+            "#},
+        )?;
+    }
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./bad-sdist")
+        .arg("--build-via-sdist"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `bad-sdist @ file://[TEMP_DIR]/bad-sdist`
+      ├─▶ Failed to build the source distribution while building a wheel via a source distribution
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_sdist` failed (exit status: 1)
+
+          [stderr]
+          Missing generated files
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./bad-archive")
+        .env(EnvVars::UV_BUILD_VIA_SDIST, "1"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `bad-archive @ file://[TEMP_DIR]/bad-archive`
+      ├─▶ Failed to extract the source distribution while building a wheel via a source distribution
+      ├─▶ The build backend returned a source distribution with an unsupported extension: `[CACHE_DIR]/builds-v0/[TMP]/bad-archive-0.1.0.txt`
+      ╰─▶ `.tar.gz`, `.zip`, `.tar.bz2`, `.tar.lz`, `.tar.lzma`, `.tar.xz`, `.tar.zst`, `.tar`, `.tbz`, `.tgz`, `.tlz`, or `.txz`
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./bad-wheel")
+        .arg("--build-via-sdist"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `bad-wheel @ file://[TEMP_DIR]/bad-wheel`
+      ├─▶ Failed to build the wheel from the source distribution while building a wheel via a source distribution
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          Missing compiler
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    Ok(())
+}
+
+/// Replacing a legacy editable install (i.e., an `.egg-link` file, as created by
+/// `setup.py develop`) removes the `.egg-link` file and its `easy-install.pth` entry.
+#[test]
+fn install_replaces_legacy_editable() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let site_packages = assert_fs::fixture::ChildPath::new(context.site_packages());
+
+    // A legacy editable install of `child`.
+    let project = context.temp_dir.child("child_project");
+    project.child("child").child("__init__.py").touch()?;
+    project
+        .child("child.egg-info")
+        .child("PKG-INFO")
+        .write_str("Metadata-Version: 2.1\nName: child\nVersion: 0.1.0\n")?;
+    site_packages
+        .child("child.egg-link")
+        .write_str(&format!("{}\n.\n", project.path().display()))?;
+    // Include a duplicate entry with a trailing separator, which must be removed too.
+    site_packages.child("easy-install.pth").write_str(&format!(
+        "import sys; sys.__plen = len(sys.path)\n{}\n{}/\n",
+        project.path().display(),
+        project.path().display()
+    ))?;
+
+    context.in_tree_backend("child", "0.1.0", "")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - child==0.1.0
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // Neither the `.egg-link` file nor the `easy-install.pth` entries remain.
+    assert!(!site_packages.child("child.egg-link").exists());
+    assert_eq!(
+        fs_err::read_to_string(site_packages.child("easy-install.pth"))?,
+        "import sys; sys.__plen = len(sys.path)\n"
+    );
+
+    Ok(())
+}
+
+/// A legacy editable install whose metadata is missing can't be uninstalled, so its location is
+/// reported instead.
+#[test]
+fn install_unmanaged_legacy_editable() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let site_packages = assert_fs::fixture::ChildPath::new(context.site_packages());
+
+    // A legacy editable install of `child`, whose `.egg-info` directory was removed.
+    let project = context.temp_dir.child("child_project");
+    project.child("child").child("__init__.py").touch()?;
+    site_packages
+        .child("child.egg-link")
+        .write_str(&format!("{}\n.\n", project.path().display()))?;
+    site_packages
+        .child("easy-install.pth")
+        .write_str(&format!("{}\n", project.path().display()))?;
+
+    context.in_tree_backend("child", "0.1.0", "")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: The legacy editable install of `child` at [SITE_PACKAGES]/child.egg-link can't be uninstalled, as its metadata is missing; remove it and the `[TEMP_DIR]/child_project` entry from [SITE_PACKAGES]/easy-install.pth to avoid shadowing the installed package
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // The legacy editable install is left in place.
+    assert!(site_packages.child("child.egg-link").exists());
+
+    Ok(())
+}
+
+/// Install a wheel containing paths longer than `MAX_PATH` (260 characters), as generated by, e.g.,
+/// protobuf, which fail to install on Windows without long path support unless extended-length
+/// paths are used.
+#[test]
+#[cfg(windows)]
+fn install_long_paths() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            # A module nested 300 characters deep within the wheel.
+            LONG = "/".join(["child"] + ["d" * 58] * 5) + "/generated_pb2.py"
+            assert len(LONG) > 300
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                return write_wheel(wheel_directory, {LONG: "VALUE = 42\n"})
+        "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    let module = format!("child.{}.generated_pb2", vec!["d".repeat(58); 5].join("."));
+    context
+        .assert_command(&format!("from {module} import VALUE; assert VALUE == 42"))
+        .success();
+
+    // Uninstalling removes the nested files.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+    assert!(!context.site_packages().join("child").exists());
+
+    Ok(())
+}
+
+/// With `--build-dir`, scratch build directories are created in the given directory rather than the
+/// cache, and removed once the build completes.
+#[test]
+fn install_build_dir() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filter((r"scratch/[^\s/]+", "scratch/[TMP]"))
+        .with_filtered_build_logs();
+
+    context.in_tree_backend(
+        "child",
+        "0.1.0",
+        indoc! {r#"
+            import sys
+
+
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                print(f"environment: {sys.prefix}", file=sys.stderr, flush=True)
+                print(f"output: {wheel_directory}", file=sys.stderr, flush=True)
+                sys.exit(1)
+        "#},
+    )?;
+
+    // The build directory is created if it doesn't exist.
+    let scratch = context.temp_dir.child("scratch");
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .arg("--build-dir")
+        .arg(scratch.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
+
+          [stderr]
+          environment: [TEMP_DIR]/scratch/[TMP]
+          output: [TEMP_DIR]/scratch/[TMP]
+
+
+    hint: Build failures usually indicate a problem with the package or the build environment
+    ");
+
+    // The scratch directories are removed after the build.
+    assert_eq!(fs::read_dir(scratch.path())?.count(), 0);
+
+    // The cache is not used for builds.
+    assert!(!context.cache_dir.child("builds-v0").exists());
+
+    // The build directory is only used if a source distribution is built.
+    let file = context.temp_dir.child("file");
+    file.touch()?;
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .env(EnvVars::UV_BUILD_DIR, file.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // A build directory that exists as a file is rejected when building.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./child")
+        .env(EnvVars::UV_BUILD_DIR, file.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `child @ file://[TEMP_DIR]/child`
+      ├─▶ Failed to create a temporary build directory
+      ╰─▶ Build directory `file` exists, but is not a directory
+    ");
+
+    Ok(())
+}
+
+/// Building a source distribution for a foreign `--python-platform` should warn that the build
+/// targets the host platform.
+#[test]
+#[cfg(not(windows))]
+fn install_cross_platform_build_warning() -> Result<()> {
+    let context = uv_test::test_context!("3.12")
+        .with_filter((r"host platform \([^)]+\)", "host platform ([PLATFORM])"));
+
+    context.in_tree_backend("child", "0.1.0", "")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("./child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Building `child @ file://[TEMP_DIR]/child` from source for the host platform ([PLATFORM]) rather than the target platform (Windows x86_64). The resulting wheel may not be compatible with the target; consider building on the target platform instead.
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    // A `--python-version` alone doesn't change the platform, so there's no warning.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--reinstall")
+        .arg("--no-cache")
+        .arg("--python-version")
+        .arg("3.12")
+        .arg("./child"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ child==0.1.0 (from file://[TEMP_DIR]/child)
+    ");
+
+    Ok(())
+}
+
+/// Report whether each package has a compatible wheel for a foreign `--python-platform`, without
+/// modifying the environment.
+#[test]
+fn install_compatibility_report() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    for (name, wheel_tags, wheel) in [
+        (
+            "native",
+            vec!["cp311-cp311-win_amd64", "cp311-cp311-manylinux_2_17_x86_64"],
+            true,
+        ),
+        ("pure", vec![], true),
+        ("source-only", vec![], false),
+    ] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires_python: None,
+                        sdist: !wheel,
+                        wheel,
+                        wheel_tags: wheel_tags
+                            .into_iter()
+                            .map(WheelTag::from_str)
+                            .collect::<Result<_, _>>()
+                            .map_err(|err| anyhow!(err))?,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    // Every package has a compatible wheel for Windows.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--dry-run")
+        .arg("--compatibility-report")
+        .arg("native")
+        .arg("pure"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Version Status Artifact
+    ------- ------- ------ --------------------------------------
+    native  1.0.0   wheel  native-1.0.0-cp311-cp311-win_amd64.whl
+    pure    1.0.0   wheel  pure-1.0.0-py3-none-any.whl
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // A package without a wheel would need to be built from source.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--dry-run")
+        .arg("--compatibility-report")
+        .arg("--output-format")
+        .arg("json")
+        .arg("native")
+        .arg("source-only"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    [{"name":"native","version":"1.0.0","status":"wheel","filename":"native-1.0.0-cp311-cp311-win_amd64.whl"},{"name":"source-only","version":"1.0.0","status":"build","filename":"source_only-1.0.0.tar.gz"}]
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: 1 package has no compatible wheel for the target platform: source-only
+    "#);
+
+    // With `--only-binary :all:`, packages without a compatible wheel are reported rather than
+    // failing the resolution.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--dry-run")
+        .arg("--compatibility-report")
+        .arg("native")
+        .arg("source-only"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    Package     Version Status Artifact
+    ----------- ------- ------ --------------------------------------
+    native      1.0.0   wheel  native-1.0.0-cp311-cp311-win_amd64.whl
+    source-only 1.0.0   build  source_only-1.0.0.tar.gz
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: 1 package has no compatible wheel for the target platform: source-only
+    ");
+
+    // The report requires `--dry-run`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--compatibility-report")
+        .arg("pure"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --dry-run
+
+    Usage: uv pip install --dry-run --cache-dir [CACHE_DIR] --compatibility-report --exclude-newer <EXCLUDE_NEWER> <PACKAGE|--requirements <REQUIREMENTS>|--editable <EDITABLE>|--group <GROUP>>
+
+    For more information, try '--help'.
+    ");
+
+    // Nothing should have been installed.
+    context.assert_command("import pure").failure();
+
+    Ok(())
+}
+
+/// Report the upgrades, downgrades, and source builds that an installation would perform, without
+/// modifying the environment.
+#[test]
+fn dry_run_install_plan() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    for (name, versions, wheel) in [
+        ("upgraded", vec!["1.0.0", "2.0.0"], true),
+        ("downgraded", vec!["1.0.0", "2.0.0"], true),
+        ("source-only", vec!["1.0.0"], false),
+    ] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: versions
+                    .into_iter()
+                    .map(|version| {
+                        Ok((
+                            Version::from_str(version)?,
+                            PackageMetadata {
+                                requires_python: None,
+                                sdist: !wheel,
+                                wheel,
+                                ..PackageMetadata::default()
+                            },
+                        ))
+                    })
+                    .collect::<Result<BTreeMap<_, _>>>()?,
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    // Seed the environment.
+    context
+        .pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("upgraded==1.0.0")
+        .arg("downgraded==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("upgraded==2.0.0")
+        .arg("downgraded==1.0.0")
+        .arg("source-only"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 3 packages
+    Would uninstall 2 packages
+    Would install 3 packages
+     - downgraded==2.0.0
+     + downgraded==1.0.0
+     + source-only==1.0.0
+     - upgraded==1.0.0
+     + upgraded==2.0.0
+    Would downgrade downgraded 2.0.0 -> 1.0.0
+    Would upgrade upgraded 1.0.0 -> 2.0.0
+    Would build source-only 1.0.0 from source
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        .arg("upgraded==2.0.0")
+        .arg("downgraded==1.0.0")
+        .arg("source-only"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"download":[{"name":"downgraded","version":"1.0.0"},{"name":"source-only","version":"1.0.0"},{"name":"upgraded","version":"2.0.0"}],"build":[{"name":"source-only","version":"1.0.0"}],"changes":[{"name":"downgraded","action":"downgrade","from":"2.0.0","to":"1.0.0"},{"name":"source-only","action":"install","to":"1.0.0"},{"name":"upgraded","action":"upgrade","from":"1.0.0","to":"2.0.0"}]}
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 3 packages
+    Would uninstall 2 packages
+    Would install 3 packages
+     - downgraded==2.0.0
+     + downgraded==1.0.0
+     + source-only==1.0.0
+     - upgraded==1.0.0
+     + upgraded==2.0.0
+    "#);
+
+    // If the requirements are already satisfied, there's nothing to report.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        .arg("upgraded==1.0.0")
+        .arg("downgraded==2.0.0"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"download":[],"build":[],"changes":[]}
+
+    ----- stderr -----
+    Checked 2 packages in [TIME]
+    Would make no changes
+    "#);
+
+    // The environment should be unchanged.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    downgraded==2.0.0
+    upgraded==1.0.0
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Write a pip-compatible installation report, both in `--dry-run` mode and for a real install.
+#[test]
+fn install_report() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("app")?,
+        Package {
+            versions: BTreeMap::from([(
+                Version::from_str("1.0.0")?,
+                PackageMetadata {
+                    requires_python: None,
+                    requires: vec![uv_pep508::Requirement::from_str("lib")?],
+                    extras: BTreeMap::from([(
+                        uv_normalize::ExtraName::from_str("fast")?,
+                        vec![uv_pep508::Requirement::from_str("speedups")?],
+                    )]),
+                    wheel: true,
+                    ..PackageMetadata::default()
+                },
+            )]),
+        },
+    );
+    for name in ["lib", "speedups"] {
+        scenario.packages.insert(
+            PackageName::from_str(name)?,
+            Package {
+                versions: BTreeMap::from([(
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        requires_python: None,
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                )]),
+            },
+        );
+    }
+    let server = PackseServer::from_scenario(&scenario);
+
+    // In `--dry-run` mode, the report is written to stdout.
+    let output = context
+        .pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("--report")
+        .arg("-")
+        .arg("app[fast]")
+        .output()?;
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        report["install"]
+            .as_array()
+            .expect("`install` should be an array")
+            .len(),
+        3
+    );
+    context
+        .temp_dir
+        .child("dry-run-report.json")
+        .write_binary(&output.stdout)?;
+
+    // Nothing should have been installed.
+    context.assert_command("import app").failure();
+
+    // Otherwise, the report is written to the given path.
+    context
+        .pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--report")
+        .arg("report.json")
+        .arg("app[fast]")
+        .assert()
+        .success();
+
+    // Validate both reports against pip's installation report format.
+    let schema = context
+        .workspace_root
+        .join("test/schemas/pip-installation-report.json");
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("jsonschema==4.21.1")
+        .arg("python")
+        .arg("-c")
+        .arg(indoc! {r"
+            import json, sys
+
+            import jsonschema
+
+            with open(sys.argv[1]) as f:
+                schema = json.load(f)
+            for path in sys.argv[2:]:
+                with open(path) as f:
+                    jsonschema.validate(json.load(f), schema)
+        "})
+        .arg(&schema)
+        .arg("dry-run-report.json")
+        .arg("report.json"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + attrs==23.2.0
+     + jsonschema==4.21.1
+     + jsonschema-specifications==2023.12.1
+     + referencing==0.34.0
+     + rpds-py==0.18.0
+    ");
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(context.temp_dir.child("report.json"))?)?;
+    let install = serde_json::to_string_pretty(&report["install"])?;
+
+    let mut filters = context.filters();
+    filters.push((r"sha256=[0-9a-f]{64}", "sha256=[HASH]"));
+    filters.push((r#""sha256": "[0-9a-f]{64}""#, r#""sha256": "[HASH]""#));
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        assert_snapshot!(install, @r#"
+    [
+      {
+        "download_info": {
+          "archive_info": {
+            "hash": "sha256=[HASH]",
+            "hashes": {
+              "sha256": "[HASH]"
+            }
+          },
+          "url": "http://[LOCALHOST]/files/app-1.0.0-py3-none-any.whl"
+        },
+        "is_direct": false,
+        "is_yanked": false,
+        "metadata": {
+          "metadata_version": "2.3",
+          "name": "app",
+          "provides_extra": [
+            "fast"
+          ],
+          "requires_dist": [
+            "lib",
+            "speedups ; extra == 'fast'"
+          ],
+          "version": "1.0.0"
+        },
+        "requested": true,
+        "requested_extras": [
+          "fast"
+        ]
+      },
+      {
+        "download_info": {
+          "archive_info": {
+            "hash": "sha256=[HASH]",
+            "hashes": {
+              "sha256": "[HASH]"
+            }
+          },
+          "url": "http://[LOCALHOST]/files/lib-1.0.0-py3-none-any.whl"
+        },
+        "is_direct": false,
+        "is_yanked": false,
+        "metadata": {
+          "metadata_version": "2.3",
+          "name": "lib",
+          "version": "1.0.0"
+        },
+        "requested": false
+      },
+      {
+        "download_info": {
+          "archive_info": {
+            "hash": "sha256=[HASH]",
+            "hashes": {
+              "sha256": "[HASH]"
+            }
+          },
+          "url": "http://[LOCALHOST]/files/speedups-1.0.0-py3-none-any.whl"
+        },
+        "is_direct": false,
+        "is_yanked": false,
+        "metadata": {
+          "metadata_version": "2.3",
+          "name": "speedups",
+          "version": "1.0.0"
+        },
+        "requested": false
+      }
+    ]
+    "#);
+    });
+
+    // The report can't be written to stdout alongside the JSON dry-run plan.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("--index-url")
+        .arg(server.index_url())
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        .arg("--report")
+        .arg("-")
+        .arg("app[fast]"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--report -` cannot be used with `--format json`
+    ");
+
+    Ok(())
+}
+
+/// On case-insensitive filesystems, reject wheels with entries whose paths differ only in case,
+/// rather than letting one silently overwrite the other.
+#[test]
+#[cfg(any(target_os = "macos", windows))]
+fn reject_wheel_case_collision() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = context
+        .temp_dir
+        .join("case_collision-0.1.0-py3-none-any.whl");
+    let mut writer = ZipFileWriter::new(Vec::new());
+    for (name, contents) in [
+        (
+            "case_collision-0.1.0.dist-info/METADATA",
+            "Metadata-Version: 2.1\nName: case-collision\nVersion: 0.1.0\n",
+        ),
+        (
+            "case_collision-0.1.0.dist-info/WHEEL",
+            "Wheel-Version: 1.0\nGenerator: uv-test\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+        ),
+        ("case_collision-0.1.0.dist-info/licenses/LICENSE", "MIT"),
+        ("case_collision-0.1.0.dist-info/licenses/license", "MIT"),
+        ("case_collision-0.1.0.dist-info/RECORD", ""),
+    ] {
+        let entry = ZipEntryBuilder::new(name.to_string().into(), Compression::Stored);
+        block_on(writer.write_entry_whole(entry, contents.as_bytes()))?;
+    }
+    fs_err::write(&wheel, block_on(writer.close())?)?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(&wheel), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to read `case-collision @ file://[TEMP_DIR]/case_collision-0.1.0-py3-none-any.whl`
+      ├─▶ Failed to extract archive: case_collision-0.1.0-py3-none-any.whl
+      ╰─▶ Archive contains entries whose paths differ only in case, which can't be extracted to a case-insensitive filesystem: `case_collision-0.1.0.dist-info/licenses/LICENSE` and `case_collision-0.1.0.dist-info/licenses/license`
+    "
+    );
+
+    Ok(())
+}
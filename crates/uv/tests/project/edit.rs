@@ -5189,6 +5189,137 @@ fn add_lower_bound_local() -> Result<()> {
     Ok(())
 }
 
+/// Interrupting `uv add` between writes should leave each file in either its old or its new state,
+/// and never leave a lockfile that references requirements absent from the `pyproject.toml`.
+#[test]
+#[cfg(feature = "test-crash-after-write")]
+fn add_crash_between_writes() -> Result<()> {
+    let server = uv_test::packse::PackseServer::new("local/local-simple.toml");
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        url = "{}"
+    "#, server.index_url()})?;
+    context
+        .lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+
+    let old_pyproject = context.read("pyproject.toml");
+    let old_lock = context.read("uv.lock");
+
+    // Perform the complete `uv add`, to capture the final state of each file.
+    context
+        .add()
+        .arg("a")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+    let new_pyproject = context.read("pyproject.toml");
+    let new_lock = context.read("uv.lock");
+    assert!(new_pyproject.contains(r#""a>=1.2.3","#));
+
+    let add_with_crash = |crash_point: &str| -> Result<()> {
+        pyproject_toml.write_str(&old_pyproject)?;
+        context.temp_dir.child("uv.lock").write_str(&old_lock)?;
+        context
+            .add()
+            .arg("a")
+            .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+            .env(EnvVars::UV_INTERNAL__TEST_CRASH_AFTER_WRITE, crash_point)
+            .assert()
+            .failure();
+        Ok(())
+    };
+
+    // Interrupt after adding the unbounded requirement to the `pyproject.toml`. The lockfile
+    // shouldn't have been written yet.
+    add_with_crash("pyproject.toml")?;
+    assert!(context.read("pyproject.toml").contains(r#""a","#));
+    assert_eq!(context.read("uv.lock"), old_lock);
+
+    // Interrupt after adding the lower bound to the `pyproject.toml`. The lockfile still shouldn't
+    // have been written.
+    add_with_crash("pyproject.toml:2")?;
+    assert_eq!(context.read("pyproject.toml"), new_pyproject);
+    assert_eq!(context.read("uv.lock"), old_lock);
+
+    // Interrupt after writing the lockfile, which should be consistent with the `pyproject.toml`.
+    add_with_crash("uv.lock")?;
+    assert_eq!(context.read("pyproject.toml"), new_pyproject);
+    assert_eq!(context.read("uv.lock"), new_lock);
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check").env_remove(EnvVars::UV_EXCLUDE_NEWER), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Interrupting `uv remove` between writes should never leave a lockfile that references
+/// requirements absent from the `pyproject.toml`.
+#[test]
+#[cfg(feature = "test-crash-after-write")]
+fn remove_crash_between_writes() -> Result<()> {
+    let server = uv_test::packse::PackseServer::new("local/local-simple.toml");
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["a"]
+
+        [[tool.uv.index]]
+        url = "{}"
+    "#, server.index_url()})?;
+    context
+        .lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+
+    let old_pyproject = context.read("pyproject.toml");
+
+    // Interrupt after writing the lockfile. The `pyproject.toml` shouldn't have been written yet.
+    context
+        .remove()
+        .arg("a")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .env(EnvVars::UV_INTERNAL__TEST_CRASH_AFTER_WRITE, "uv.lock")
+        .assert()
+        .failure();
+    assert_eq!(context.read("pyproject.toml"), old_pyproject);
+    assert!(!context.read("uv.lock").contains(r#"name = "a""#));
+
+    // The next invocation should update the `pyproject.toml` to match.
+    context
+        .remove()
+        .arg("a")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+    assert!(context.read("pyproject.toml").contains("dependencies = []"));
+
+    Ok(())
+}
+
 /// Add dependencies to a non-project workspace root.
 #[test]
 fn add_non_project() -> Result<()> {
@@ -8784,7 +8915,7 @@ fn remove_include_default_groups() -> Result<()> {
 /// Revert changes to the `pyproject.toml` and `uv.lock` when the `add` operation fails.
 #[test]
 fn fail_to_add_revert_project() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     context
         .temp_dir
@@ -8827,6 +8958,7 @@ fn fail_to_add_revert_project() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -8874,7 +9006,7 @@ fn fail_to_add_revert_project() -> Result<()> {
 /// In this case, the project has an existing lockfile.
 #[test]
 fn fail_to_edit_revert_project() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     context
         .temp_dir
@@ -8931,6 +9063,7 @@ fn fail_to_edit_revert_project() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -8979,7 +9112,7 @@ fn fail_to_edit_revert_project() -> Result<()> {
 /// Revert changes to the root `pyproject.toml` and `uv.lock` when the `add` operation fails.
 #[test]
 fn fail_to_add_revert_workspace_root() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     context
         .temp_dir
@@ -9043,6 +9176,7 @@ fn fail_to_add_revert_workspace_root() -> Result<()> {
       × Failed to build `broken @ file://[TEMP_DIR]/broken`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_editable` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -9091,7 +9225,7 @@ fn fail_to_add_revert_workspace_root() -> Result<()> {
 /// Revert changes to the root `pyproject.toml` and `uv.lock` when the `add` operation fails.
 #[test]
 fn fail_to_add_revert_workspace_member() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     context
         .temp_dir
@@ -9160,6 +9294,7 @@ fn fail_to_add_revert_workspace_member() -> Result<()> {
       × Failed to build `broken @ file://[TEMP_DIR]/broken`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_editable` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -13346,6 +13481,7 @@ fn add_with_build_constraints() -> Result<()> {
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
 
     hint: `requests` (v1.2.0) was included because `project` (v0.1.0) depends on `requests==1.2`
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     hint: If you want to add the package regardless of the failed resolution, provide the `--frozen` flag to skip locking and syncing
     ");
 
@@ -9832,3 +9832,416 @@ fn requirements_txt_emit_indexes() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn dockerfile_snippet() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_dockerfile_filters();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        test = ["iniconfig"]
+
+        [dependency-groups]
+        dev = ["typing-extensions"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("dockerfile-snippet").arg("--extra").arg("test").arg("--no-dev"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --format dockerfile-snippet --extra test --no-dev
+    FROM python:3.12-slim AS builder
+    COPY --from=ghcr.io/astral-sh/uv:[VERSION] /uv /uvx /bin/
+
+    # Compile bytecode, copy packages out of the cache mount, and use the image's Python
+    ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=0
+
+    WORKDIR /app
+
+    # Install the dependencies, which only change with the lockfile
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        --mount=type=bind,source=uv.lock,target=uv.lock \
+        --mount=type=bind,source=pyproject.toml,target=pyproject.toml \
+        uv sync --locked --no-install-project --no-editable --extra test --no-dev
+
+    # Install the project
+    COPY . /app
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        uv sync --locked --no-editable --extra test --no-dev
+
+    FROM python:3.12-slim
+
+    # Run the application as a non-root user
+    RUN groupadd --system app && useradd --system --gid app --create-home app
+    COPY --from=builder --chown=app:app /app /app
+    ENV PATH="/app/.venv/bin:$PATH"
+    USER app
+    WORKDIR /app
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "#);
+
+    Ok(())
+}
+
+/// If any package must be built from source, the build stage uses the full Python image.
+#[test]
+fn dockerfile_snippet_source_distribution() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_dockerfile_filters();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("dockerfile-snippet").arg("--no-header"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    FROM python:3.11 AS builder
+    COPY --from=ghcr.io/astral-sh/uv:[VERSION] /uv /uvx /bin/
+
+    # Compile bytecode, copy packages out of the cache mount, and use the image's Python
+    ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=0
+
+    WORKDIR /app
+
+    # Install the dependencies, which only change with the lockfile
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        --mount=type=bind,source=uv.lock,target=uv.lock \
+        --mount=type=bind,source=pyproject.toml,target=pyproject.toml \
+        uv sync --locked --no-install-project --no-editable
+
+    # Install the project
+    COPY . /app
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        uv sync --locked --no-editable
+
+    FROM python:3.11-slim
+
+    # Run the application as a non-root user
+    RUN groupadd --system app && useradd --system --gid app --create-home app
+    COPY --from=builder --chown=app:app /app /app
+    ENV PATH="/app/.venv/bin:$PATH"
+    USER app
+    WORKDIR /app
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "#);
+
+    Ok(())
+}
+
+/// Only the dependencies of the requested packages determine whether a build stage is required.
+#[test]
+fn dockerfile_snippet_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_dockerfile_filters();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        lint = []
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("dockerfile-snippet").arg("--no-header").arg("--package").arg("project").arg("--group").arg("lint"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    FROM python:3.12-slim AS builder
+    COPY --from=ghcr.io/astral-sh/uv:[VERSION] /uv /uvx /bin/
+
+    # Compile bytecode, copy packages out of the cache mount, and use the image's Python
+    ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_DOWNLOADS=0
+
+    WORKDIR /app
+
+    # Install the dependencies, which only change with the lockfile
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        --mount=type=bind,source=uv.lock,target=uv.lock \
+        --mount=type=bind,source=pyproject.toml,target=pyproject.toml \
+        uv sync --frozen --no-install-workspace --no-editable --package project --group lint
+
+    # Install the project
+    COPY . /app
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        uv sync --locked --no-editable --package project --group lint
+
+    FROM python:3.12-slim
+
+    # Run the application as a non-root user
+    RUN groupadd --system app && useradd --system --gid app --create-home app
+    COPY --from=builder --chown=app:app /app /app
+    ENV PATH="/app/.venv/bin:$PATH"
+    USER app
+    WORKDIR /app
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#);
+
+    Ok(())
+}
+
+/// With `--distroless`, Python is installed by uv and copied into a distroless final stage.
+#[test]
+fn dockerfile_snippet_distroless() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_dockerfile_filters();
+
+    // Without a minor version, the major version is used as-is.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3"
+        dependencies = []
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("dockerfile-snippet").arg("--no-header").arg("--distroless"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    FROM debian:bookworm-slim AS builder
+    COPY --from=ghcr.io/astral-sh/uv:[VERSION] /uv /uvx /bin/
+
+    # Compile bytecode, copy packages out of the cache mount, and install Python in a
+    # directory that's copied into the final image
+    ENV UV_COMPILE_BYTECODE=1 UV_LINK_MODE=copy UV_PYTHON_INSTALL_DIR=/python UV_PYTHON_PREFERENCE=only-managed
+
+    RUN --mount=type=cache,target=/root/.cache/uv uv python install 3
+
+    WORKDIR /app
+
+    # Install the dependencies, which only change with the lockfile
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        --mount=type=bind,source=uv.lock,target=uv.lock \
+        --mount=type=bind,source=pyproject.toml,target=pyproject.toml \
+        uv sync --locked --no-install-project --no-editable
+
+    # Install the project
+    COPY . /app
+    RUN --mount=type=cache,target=/root/.cache/uv \
+        uv sync --locked --no-editable
+
+    FROM gcr.io/distroless/cc-debian12:nonroot
+
+    # Run the application as the image's non-root user, with the Python installation
+    # at the same path as in the build stage
+    COPY --from=builder /python /python
+    COPY --from=builder --chown=nonroot:nonroot /app /app
+    ENV PATH="/app/.venv/bin:$PATH"
+    USER nonroot
+    WORKDIR /app
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "#);
+
+    // The flag is rejected for other formats.
+    uv_snapshot!(context.filters(), context.export().arg("--distroless"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: `--distroless` is only supported by the `dockerfile-snippet` format
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn json_graph() -> Result<()> {
+    let context = uv_test::test_context!("3.13");
+
+    let find_links = context.workspace_root.join("test/links");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = ["child>=0.1"]
+
+        [project.optional-dependencies]
+        wheel = ["basic-package>=0.1"]
+
+        [dependency-groups]
+        dev = ["grandchild ; sys_platform == 'linux'"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["{}"]
+
+        [tool.uv.workspace]
+        members = ["child", "grandchild"]
+
+        [tool.uv.sources]
+        child = {{ workspace = true }}
+        grandchild = {{ workspace = true }}
+        "#,
+        find_links.portable_display(),
+    })?;
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = ["grandchild"]
+
+        [tool.uv.sources]
+        grandchild = { workspace = true }
+        "#
+        })?;
+
+    context
+        .temp_dir
+        .child("grandchild")
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "grandchild"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        "#
+        })?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("json-graph").arg("--extra").arg("wheel"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "version": 1,
+      "nodes": [
+        {
+          "id": "basic-package==0.1.0 @ registry+[WORKSPACE]/test/links",
+          "name": "basic-package",
+          "version": "0.1.0",
+          "source": {
+            "kind": "registry",
+            "path": "[WORKSPACE]/test/links"
+          }
+        },
+        {
+          "id": "child==0.1.0 @ editable+child",
+          "name": "child",
+          "version": "0.1.0",
+          "source": {
+            "kind": "editable",
+            "path": "child"
+          }
+        },
+        {
+          "id": "grandchild==0.1.0 @ editable+grandchild",
+          "name": "grandchild",
+          "version": "0.1.0",
+          "source": {
+            "kind": "editable",
+            "path": "grandchild"
+          }
+        },
+        {
+          "id": "project==0.1.0 @ virtual+.",
+          "name": "project",
+          "version": "0.1.0",
+          "source": {
+            "kind": "virtual",
+            "path": "."
+          }
+        }
+      ],
+      "edges": [
+        {
+          "to": "grandchild==0.1.0 @ editable+grandchild",
+          "group": "dev",
+          "marker": "sys_platform == 'linux'"
+        },
+        {
+          "to": "project==0.1.0 @ virtual+."
+        },
+        {
+          "from": "child==0.1.0 @ editable+child",
+          "to": "grandchild==0.1.0 @ editable+grandchild"
+        },
+        {
+          "from": "project==0.1.0 @ virtual+.",
+          "to": "basic-package==0.1.0 @ registry+[WORKSPACE]/test/links",
+          "specifier": ">=0.1",
+          "extra": "wheel"
+        },
+        {
+          "from": "project==0.1.0 @ virtual+.",
+          "to": "child==0.1.0 @ editable+child"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "#);
+
+    Ok(())
+}
@@ -990,6 +990,8 @@ fn run_pep723_script_build_constraints() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     ");
 
     // Compatible build constraints.
@@ -1948,6 +1950,8 @@ fn run_with_build_constraints() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     ");
 
     // Change the build constraint to be compatible with `requests==1.2`.
@@ -2893,6 +2897,181 @@ fn run_no_sync_env_var() -> Result<()> {
     Ok(())
 }
 
+/// `--no-sync` warns, without blocking, if the lockfile changed since the environment was synced.
+#[test]
+fn run_no_sync_stale_environment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    context.sync().assert().success();
+
+    // The environment was synced from the current lockfile.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--locked").arg("python").arg("-c").arg("print('hello')"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    ");
+
+    // Update the lockfile, without syncing the environment.
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+        "#
+    })?;
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.run().env(EnvVars::UV_NO_SYNC, "1").arg("python").arg("-c").arg("print('hello')"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    warning: The environment may be out-of-date, as `uv.lock` changed since it was last synced; run `uv sync` to update it
+    ");
+
+    // Syncing the environment clears the warning.
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("python").arg("-c").arg("print('hello')"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// `--no-sync` reuses the environment of a locked script, and warns if it is out-of-date.
+#[test]
+fn run_no_sync_stale_script() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = []
+        # ///
+
+        print("Hello, world!")
+       "#
+    })?;
+
+    context
+        .lock()
+        .arg("--script")
+        .arg("main.py")
+        .assert()
+        .success();
+    context.run().arg("main.py").assert().success();
+
+    // The environment was synced from the current lockfile.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("main.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+
+    // Update the lockfile, without syncing the environment.
+    script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = []
+        # ///
+
+        print("Hello, world!")
+       "#
+    })?;
+    context
+        .lock()
+        .arg("--script")
+        .arg("main.py")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("main.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    warning: The environment may be out-of-date, as `main.py.lock` changed since it was last synced; run `uv sync --script main.py` to update it
+    ");
+
+    Ok(())
+}
+
+/// `--no-sync` warns if the interpreter changed since the environment was synced.
+#[test]
+fn run_no_sync_stale_interpreter() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    context.sync().assert().success();
+
+    // Simulate an in-place upgrade of the base interpreter.
+    context
+        .venv
+        .child(".uv-interpreter")
+        .write_str("3.12.0 /usr")?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("python").arg("-c").arg("print('hello')"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    warning: The environment may be out-of-date, as its interpreter changed since it was last synced; run `uv sync` to update it
+    ");
+
+    // Syncing the environment clears the warning.
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("python").arg("-c").arg("print('hello')"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_empty_requirements_txt() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -3795,6 +3974,133 @@ fn run_module() {
     ");
 }
 
+/// Commands are resolved from the run environment before the `PATH`, unless `--from-path` is
+/// provided.
+#[test]
+#[cfg(unix)]
+fn run_which() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = uv_test::test_context!("3.12");
+
+    // Create a `greet` script in both the environment and a directory on the `PATH`.
+    let venv_script = context.venv.child("bin").child("greet");
+    venv_script.write_str("#!/bin/sh\necho 'Hello from the environment'\n")?;
+    fs_err::set_permissions(&venv_script, std::fs::Permissions::from_mode(0o755))?;
+
+    let path_dir = context.temp_dir.child("path");
+    let path_script = path_dir.child("greet");
+    path_script.write_str("#!/bin/sh\necho 'Hello from the PATH'\n")?;
+    fs_err::set_permissions(&path_script, std::fs::Permissions::from_mode(0o755))?;
+
+    let path = std::env::join_paths([path_dir.path()])?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--which").arg("greet").env(EnvVars::PATH, &path), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/bin/greet
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("greet").env(EnvVars::PATH, &path), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from the environment
+
+    ----- stderr -----
+    ");
+
+    // With `--from-path`, the `PATH` takes precedence.
+    uv_snapshot!(context.filters(), context.run().arg("--from-path").arg("--which").arg("greet").env(EnvVars::PATH, &path), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/path/greet
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("--from-path").arg("greet").env(EnvVars::PATH, &path), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from the PATH
+
+    ----- stderr -----
+    ");
+
+    // Arguments are included in the output.
+    uv_snapshot!(context.filters(), context.run().arg("--which").arg("greet").arg("--name").arg("uv").env(EnvVars::PATH, &path), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/bin/greet --name uv
+
+    ----- stderr -----
+    ");
+
+    // Paths are not resolved against the environment or the `PATH`.
+    uv_snapshot!(context.filters(), context.run().arg("--which").arg("./greet").env(EnvVars::PATH, &path), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ./greet
+
+    ----- stderr -----
+    ");
+
+    // `python` refers to the environment's interpreter.
+    uv_snapshot!(context.filters(), context.run().arg("--which").arg("python").env(EnvVars::PATH, &path), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/bin/python3
+
+    ----- stderr -----
+    ");
+
+    // The resolved executable is shown in the verbose output.
+    context
+        .run()
+        .arg("-v")
+        .arg("greet")
+        .env(EnvVars::PATH, &path)
+        .assert()
+        .success()
+        .stderr(contains(
+            "Resolved `greet` to `.venv/bin/greet` in the run environment",
+        ));
+
+    Ok(())
+}
+
+/// If a command is missing from the environment, but a module known to provide it is installed,
+/// fall back to `python -m <module>`.
+#[test]
+fn run_module_fallback() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let module = ChildPath::new(context.site_packages()).child("pytest");
+    module
+        .child("__main__.py")
+        .write_str("import sys\nprint('Hello from pytest', sys.argv[1:])\n")?;
+
+    uv_snapshot!(context.filters(), context.run().arg("pytest").arg("-x"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from pytest ['-x']
+
+    ----- stderr -----
+    warning: `pytest` is installed in the run environment without its `pytest` script; running `python -m pytest` instead
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_module_stdin() {
     let context = uv_test::test_context!("3.12");
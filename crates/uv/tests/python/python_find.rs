@@ -1129,6 +1129,7 @@ fn python_find_script_python_not_found() {
     No interpreter found in [PYTHON SOURCES]
 
     hint: A managed Python download is available, but Python downloads are set to 'never'
+    hint: No Python interpreters were found on the system
     ");
 }
 
@@ -1225,6 +1226,48 @@ fn python_find_show_version() {
     ");
 }
 
+#[test]
+fn python_find_output_format_json() {
+    let context = uv_test::test_context_with_versions!(&["3.12"])
+        .with_filtered_python_sources()
+        .with_filtered_python_keys();
+
+    // The platform and libc fields depend on the host.
+    let filters = [
+        (r#""(os|arch|libc)":"[^"]+""#, r#""$1":"[$1]""#),
+        (
+            r#""system_libc":(?:null|"[^"]+")"#,
+            r#""system_libc":[SYSTEM_LIBC]"#,
+        ),
+    ]
+    .into_iter()
+    .chain(context.filters())
+    .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.python_find().arg("--output-format").arg("json").arg("3.12"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"key":"cpython-3.12.[X]-[PLATFORM]","version":"3.12.[X]","path":"[PYTHON-3.12]","implementation":"cpython","os":"[os]","arch":"[arch]","libc":"[libc]","system_libc":[SYSTEM_LIBC]}
+
+    ----- stderr -----
+    "#);
+
+    // `--show-version` only applies to the text output.
+    uv_snapshot!(context.filters(), context.python_find().arg("--output-format").arg("json").arg("--show-version"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--output-format <OUTPUT_FORMAT>' cannot be used with '--show-version'
+
+    Usage: uv python find --cache-dir [CACHE_DIR] --output-format <OUTPUT_FORMAT> [REQUEST]
+
+    For more information, try '--help'.
+    ");
+}
+
 #[test]
 fn python_find_path() {
     let context = uv_test::test_context_with_versions!(&[]).with_filtered_not_executable();
@@ -201,6 +201,65 @@ fn python_reinstall() {
     ");
 }
 
+/// An installation that was left incomplete, e.g., by an interrupted process, is ignored during
+/// discovery and reinstalled on the next install.
+#[test]
+fn python_install_incomplete() {
+    let context = uv_test::test_context_with_versions!(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    let patch_dir = context.temp_dir.child("managed").child(format!(
+        "cpython-3.12.8-{}",
+        platform_key_from_env().unwrap()
+    ));
+    let executable = if cfg!(windows) {
+        patch_dir.child("python.exe")
+    } else {
+        patch_dir.child("bin").child("python3.12")
+    };
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.8"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.8 in [TIME]
+     + cpython-3.12.8-[PLATFORM] (python3.12)
+    ");
+
+    // Simulate an interrupted install by removing the interpreter.
+    fs_err::remove_file(&executable).unwrap();
+
+    // The incomplete installation is ignored.
+    uv_snapshot!(context.filters(), context.python_find().arg("--managed-python").arg("3.12"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring incomplete managed Python installation `cpython-3.12.8-[PLATFORM]`; run `uv python install --reinstall cpython-3.12.8-[PLATFORM]` to repair it, or `uv python uninstall cpython-3.12.8-[PLATFORM]` to remove it
+    error: No interpreter found for Python 3.12 in virtual environments or managed installations
+    ");
+
+    // Installing again replaces the incomplete installation.
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.8"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Found an incomplete installation of `cpython-3.12.8-[PLATFORM]`; reinstalling
+    Installed Python 3.12.8 in [TIME]
+     ~ cpython-3.12.8-[PLATFORM] (python3.12)
+    ");
+
+    executable.assert(predicate::path::exists());
+}
+
 #[test]
 fn python_reinstall_patch() {
     let context = uv_test::test_context_with_versions!(&[])
@@ -258,6 +317,8 @@ fn python_install_automatic() {
     error: No interpreter found in [PYTHON SOURCES]
 
     hint: A managed Python download is available, but Python downloads are set to 'never'
+
+    hint: No Python interpreters were found on the system
     ");
 
     // Otherwise, we should fetch the latest Python version
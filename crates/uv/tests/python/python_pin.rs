@@ -581,6 +581,111 @@ fn python_pin_compatible_with_requires_python() -> Result<()> {
     Ok(())
 }
 
+/// A pin that is excluded by a workspace member's `requires-python` should identify the member.
+#[test]
+fn python_pin_incompatible_with_workspace_member() -> Result<()> {
+    let context =
+        uv_test::test_context_with_versions!(&["3.11", "3.12"]).with_filtered_python_sources();
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+    let child = context.temp_dir.child("child");
+    child.create_dir_all()?;
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = "<3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    // The requested version is excluded by the member.
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requested Python version `3.12` is incompatible with the project `requires-python` value of `==3.11.*` (from workspace member `child`'s `project.requires-python`).
+    ");
+
+    // With `--force`, the pin is written anyway.
+    uv_snapshot!(context.filters(), context.python_pin().arg("3.12").arg("--force"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Pinned `.python-version` to `3.12`
+
+    ----- stderr -----
+    warning: The requested Python version `3.12` is incompatible with the project `requires-python` value of `==3.11.*` (from workspace member `child`'s `project.requires-python`).
+    ");
+
+    // Existing pins are validated when displayed, and `--strict` makes that an error.
+    uv_snapshot!(context.filters(), context.python_pin(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12
+
+    ----- stderr -----
+    warning: The pinned Python version `3.12` is incompatible with the project `requires-python` value of `==3.11.*` (from workspace member `child`'s `project.requires-python`).
+    ");
+    uv_snapshot!(context.filters(), context.python_pin().arg("--strict"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    3.12
+
+    ----- stderr -----
+    error: The pinned Python version `3.12` is incompatible with the project `requires-python` value of `==3.11.*` (from workspace member `child`'s `project.requires-python`).
+    ");
+
+    // A request that resolves to an incompatible interpreter warns by default...
+    uv_snapshot!(context.filters(), context.python_pin().arg(">=3.12"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Updated `.python-version` from `3.12` -> `>=3.12`
+
+    ----- stderr -----
+    warning: The requested Python version `>=3.12` resolves to `3.12.[X]` which  is incompatible with the project `requires-python` value of `==3.11.*` (from workspace member `child`'s `project.requires-python`).
+    ");
+
+    // ...but errors with `--strict`.
+    uv_snapshot!(context.filters(), context.python_pin().arg(">=3.12").arg("--strict"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requested Python version `>=3.12` resolves to `3.12.[X]` which  is incompatible with the project `requires-python` value of `==3.11.*` (from workspace member `child`'s `project.requires-python`).
+    ");
+
+    // `--resolved` refuses to pin the incompatible interpreter without `--force`.
+    uv_snapshot!(context.filters(), context.python_pin().arg(">=3.12").arg("--resolved"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requested Python version `>=3.12` resolves to `3.12.[X]` which  is incompatible with the project `requires-python` value of `==3.11.*` (from workspace member `child`'s `project.requires-python`).
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn warning_pinned_python_version_not_installed() -> Result<()> {
     let context = uv_test::test_context_with_versions!(&["3.10", "3.11"]);
@@ -635,6 +740,8 @@ fn python_pin_resolve_no_python() {
     error: No interpreter found for Python 3.12 in [PYTHON SOURCES]
 
     hint: A managed Python download is available for Python 3.12, but Python downloads are set to 'never'
+
+    hint: No Python interpreters were found on the system
     ");
 }
 
@@ -778,6 +885,10 @@ fn python_pin_resolve() {
     error: No interpreter found for PyPy in managed installations or search path
 
     hint: A managed Python download is available for PyPy, but Python downloads are set to 'never'
+
+    hint: Found 2 Python interpreters, but none are compatible with PyPy:
+      - CPython 3.12.[X] at `[PYTHON-3.12]`: incompatible with PyPy
+      - CPython 3.13.[X] at `[PYTHON-3.13]`: incompatible with PyPy
     ");
 
     let python_version = context.read(PYTHON_VERSION_FILENAME);
@@ -2506,3 +2506,28 @@ fn no_clear_conflicts_with_allow_existing() {
     "
     );
 }
+
+/// Warn when the interpreter was built for a different libc than the system.
+#[test]
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+fn create_venv_libc_mismatch() {
+    let context = uv_test::test_context_with_versions!(&["3.12"]);
+
+    // Pretend that the system uses musl, while the interpreter was built for glibc.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .env(EnvVars::UV_LIBC, "musl"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    warning: The Python interpreter at `[PYTHON-3.12]` was built for glibc, but the system uses musl libc; wheels selected for this interpreter may not be compatible with the system
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+}
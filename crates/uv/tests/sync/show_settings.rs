@@ -55,11 +55,15 @@ fn pip_compile_baseline() {
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
+            retry_missing_artifacts: true,
+            http_version: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            metadata: 50,
+            fail_fast: true,
         },
         show_settings: true,
         preview: Preview {
@@ -75,9 +79,16 @@ fn pip_compile_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        source: Some(
+            Argument(
+                "--cache-dir",
+            ),
+        ),
     }
     PipCompileSettings {
         format: None,
+        explain: [],
+        hint_on_failure: false,
         src_file: [
             "requirements.in",
         ],
@@ -148,19 +159,48 @@ fn pip_compile_baseline() {
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
-            extra_build_variables: ExtraBuildVariables(
-                {},
-            ),
+            extra_build_variables: ExtraBuildVariables {
+                packages: {},
+                build_env: None,
+            },
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
             },
+            build_runtime: BuildRuntime {
+                timeout: None,
+                retries: 0,
+                build_python: None,
+                target: None,
+                show_warnings: false,
+                warning_patterns: [],
+                events_file: None,
+                keep_logs: false,
+                keep_environment: false,
+                build_dir: None,
+                via_sdist: false,
+                no_environment_reuse: false,
+            },
             allow_empty_requirements: false,
             strict: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: PackageResolutionModes(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: PackagePrereleaseModes(
+                {},
+            ),
+            local_version_preference: PackageLocalVersionPreferences(
+                {},
+            ),
+            yanked: Warn,
             fork_strategy: RequiresPython,
+            max_metadata_builds: None,
+            resolution_effort: None,
+            excluded_packages: [],
+            reresolve_metadata: [],
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -170,6 +210,7 @@ fn pip_compile_baseline() {
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            custom_header: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -179,6 +220,8 @@ fn pip_compile_baseline() {
             ),
             python_version: None,
             python_platform: None,
+            python_implementation: None,
+            python_abiflags: None,
             universal: false,
             exclude_newer: ExcludeNewer {
                 global: None,
@@ -186,6 +229,7 @@ fn pip_compile_baseline() {
                     {},
                 ),
             },
+            strict_exclude_newer: false,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -242,11 +286,15 @@ fn pip_install_baseline() {
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
+            retry_missing_artifacts: true,
+            http_version: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            metadata: 50,
+            fail_fast: true,
         },
         show_settings: true,
         preview: Preview {
@@ -262,6 +310,11 @@ fn pip_install_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        source: Some(
+            Argument(
+                "--cache-dir",
+            ),
+        ),
     }
     PipInstallSettings {
         package: [],
@@ -275,6 +328,9 @@ fn pip_install_baseline() {
         excludes: [],
         build_constraints: [],
         dry_run: Disabled,
+        compatibility_report: false,
+        output_format: Text,
+        report: None,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -333,19 +389,48 @@ fn pip_install_baseline() {
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
-            extra_build_variables: ExtraBuildVariables(
-                {},
-            ),
+            extra_build_variables: ExtraBuildVariables {
+                packages: {},
+                build_env: None,
+            },
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
             },
+            build_runtime: BuildRuntime {
+                timeout: None,
+                retries: 0,
+                build_python: None,
+                target: None,
+                show_warnings: false,
+                warning_patterns: [],
+                events_file: None,
+                keep_logs: false,
+                keep_environment: false,
+                build_dir: None,
+                via_sdist: false,
+                no_environment_reuse: false,
+            },
             allow_empty_requirements: false,
             strict: false,
             dependency_mode: Transitive,
             resolution: Highest,
+            resolution_package: PackageResolutionModes(
+                {},
+            ),
             prerelease: IfNecessaryOrExplicit,
+            prerelease_package: PackagePrereleaseModes(
+                {},
+            ),
+            local_version_preference: PackageLocalVersionPreferences(
+                {},
+            ),
+            yanked: Warn,
             fork_strategy: RequiresPython,
+            max_metadata_builds: None,
+            resolution_effort: None,
+            excluded_packages: [],
+            reresolve_metadata: [],
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -355,6 +440,7 @@ fn pip_install_baseline() {
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            custom_header: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -364,6 +450,8 @@ fn pip_install_baseline() {
             ),
             python_version: None,
             python_platform: None,
+            python_implementation: None,
+            python_abiflags: None,
             universal: false,
             exclude_newer: ExcludeNewer {
                 global: None,
@@ -371,6 +459,7 @@ fn pip_install_baseline() {
                     {},
                 ),
             },
+            strict_exclude_newer: false,
             no_emit_package: [],
             emit_index_url: false,
             emit_find_links: false,
@@ -425,11 +514,15 @@ fn lock_baseline() {
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
+            retry_missing_artifacts: true,
+            http_version: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            metadata: 50,
+            fail_fast: true,
         },
         show_settings: true,
         preview: Preview {
@@ -445,11 +538,22 @@ fn lock_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        source: Some(
+            Argument(
+                "--cache-dir",
+            ),
+        ),
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        narrow_bounds: Disabled,
+        summary: false,
+        explain: [],
+        requires_python_strategy: Narrow,
+        preview_report: None,
+        preview_report_format: Markdown,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -470,6 +574,20 @@ fn lock_baseline() {
                 no_binary: None,
                 no_build: None,
             },
+            build_runtime: BuildRuntime {
+                timeout: None,
+                retries: 0,
+                build_python: None,
+                target: None,
+                show_warnings: false,
+                warning_patterns: [],
+                events_file: None,
+                keep_logs: false,
+                keep_environment: false,
+                build_dir: None,
+                via_sdist: false,
+                no_environment_reuse: false,
+            },
             config_setting: ConfigSettings(
                 {},
             ),
@@ -485,7 +603,12 @@ fn lock_baseline() {
                     {},
                 ),
             },
+            strict_exclude_newer: false,
             fork_strategy: RequiresPython,
+            max_metadata_builds: None,
+            resolution_effort: None,
+            excluded_packages: [],
+            reresolve_metadata: [],
             index_locations: IndexLocations {
                 indexes: [],
                 flat_index: [],
@@ -498,11 +621,22 @@ fn lock_baseline() {
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
             ),
-            extra_build_variables: ExtraBuildVariables(
-                {},
-            ),
+            extra_build_variables: ExtraBuildVariables {
+                packages: {},
+                build_env: None,
+            },
             prerelease: IfNecessaryOrExplicit,
             resolution: Highest,
+            resolution_package: PackageResolutionModes(
+                {},
+            ),
+            prerelease_package: PackagePrereleaseModes(
+                {},
+            ),
+            local_version_preference: PackageLocalVersionPreferences(
+                {},
+            ),
+            yanked: Warn,
             sources: None,
             torch_backend: None,
             cuda_driver_version: None,
@@ -547,11 +681,15 @@ fn version_baseline() {
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
+            retry_missing_artifacts: true,
+            http_version: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            metadata: 50,
+            fail_fast: true,
         },
         show_settings: true,
         preview: Preview {
@@ -567,6 +705,11 @@ fn version_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        source: Some(
+            Argument(
+                "--cache-dir",
+            ),
+        ),
     }
     VersionSettings {
         value: None,
@@ -599,6 +742,20 @@ fn version_baseline() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_runtime: BuildRuntime {
+                    timeout: None,
+                    retries: 0,
+                    build_python: None,
+                    target: None,
+                    show_warnings: false,
+                    warning_patterns: [],
+                    events_file: None,
+                    keep_logs: false,
+                    keep_environment: false,
+                    build_dir: None,
+                    via_sdist: false,
+                    no_environment_reuse: false,
+                },
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -614,7 +771,12 @@ fn version_baseline() {
                         {},
                     ),
                 },
+                strict_exclude_newer: false,
                 fork_strategy: RequiresPython,
+                max_metadata_builds: None,
+                resolution_effort: None,
+                excluded_packages: [],
+                reresolve_metadata: [],
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -627,11 +789,22 @@ fn version_baseline() {
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
-                extra_build_variables: ExtraBuildVariables(
-                    {},
-                ),
+                extra_build_variables: ExtraBuildVariables {
+                    packages: {},
+                    build_env: None,
+                },
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_package: PackageResolutionModes(
+                    {},
+                ),
+                prerelease_package: PackagePrereleaseModes(
+                    {},
+                ),
+                local_version_preference: PackageLocalVersionPreferences(
+                    {},
+                ),
+                yanked: Warn,
                 sources: None,
                 torch_backend: None,
                 cuda_driver_version: None,
@@ -684,11 +857,15 @@ fn tool_install_baseline() {
             read_timeout: [TIME],
             connect_timeout: [TIME],
             retries: 3,
+            retry_missing_artifacts: true,
+            http_version: None,
         },
         concurrency: Concurrency {
             downloads: 50,
             builds: 16,
             installs: 8,
+            metadata: 50,
+            fail_fast: true,
         },
         show_settings: true,
         preview: Preview {
@@ -704,6 +881,11 @@ fn tool_install_baseline() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        source: Some(
+            Argument(
+                "--cache-dir",
+            ),
+        ),
     }
     ToolInstallSettings {
         package: "anyio",
@@ -736,16 +918,37 @@ fn tool_install_baseline() {
             index_strategy: None,
             keyring_provider: None,
             resolution: None,
+            resolution_package: None,
             prerelease: None,
+            prerelease_package: None,
+            local_version_preference: None,
+            yanked: None,
             fork_strategy: None,
+            max_metadata_builds: None,
+            resolution_effort: None,
+            excluded_packages: None,
+            reresolve_metadata: None,
             dependency_metadata: None,
             config_settings: None,
             config_settings_package: None,
             build_isolation: None,
+            build_timeout: None,
+            build_retries: None,
+            build_python: None,
+            show_build_warnings: None,
+            build_warning_pattern: None,
+            build_events_file: None,
+            keep_build_logs: None,
+            keep_build_env: None,
+            build_dir: None,
+            build_via_sdist: None,
+            no_build_environment_reuse: None,
             extra_build_dependencies: None,
             extra_build_variables: None,
+            build_env: None,
             exclude_newer: None,
             exclude_newer_package: None,
+            strict_exclude_newer: None,
             link_mode: Some(
                 Clone,
             ),
@@ -766,6 +969,20 @@ fn tool_install_baseline() {
                     no_binary: None,
                     no_build: None,
                 },
+                build_runtime: BuildRuntime {
+                    timeout: None,
+                    retries: 0,
+                    build_python: None,
+                    target: None,
+                    show_warnings: false,
+                    warning_patterns: [],
+                    events_file: None,
+                    keep_logs: false,
+                    keep_environment: false,
+                    build_dir: None,
+                    via_sdist: false,
+                    no_environment_reuse: false,
+                },
                 config_setting: ConfigSettings(
                     {},
                 ),
@@ -781,7 +998,12 @@ fn tool_install_baseline() {
                         {},
                     ),
                 },
+                strict_exclude_newer: false,
                 fork_strategy: RequiresPython,
+                max_metadata_builds: None,
+                resolution_effort: None,
+                excluded_packages: [],
+                reresolve_metadata: [],
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -794,11 +1016,22 @@ fn tool_install_baseline() {
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
                 ),
-                extra_build_variables: ExtraBuildVariables(
-                    {},
-                ),
+                extra_build_variables: ExtraBuildVariables {
+                    packages: {},
+                    build_env: None,
+                },
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_package: PackageResolutionModes(
+                    {},
+                ),
+                prerelease_package: PackagePrereleaseModes(
+                    {},
+                ),
+                local_version_preference: PackageLocalVersionPreferences(
+                    {},
+                ),
+                yanked: Warn,
                 sources: None,
                 torch_backend: None,
                 cuda_driver_version: None,
@@ -898,6 +1131,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -909,13 +1145,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
-             no_annotate: false,
              no_header: false,
              custom_compile_command: None,
+             custom_header: None,
     -        generate_hashes: false,
     +        generate_hashes: true,
              config_setting: ConfigSettings(
@@ -937,9 +1173,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: LowestDirect,
     +        resolution: Highest,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
     "
     );
@@ -952,9 +1188,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             .arg("--resolution=highest")
             .arg("--no-generate-hashes"), @"
     ...
-             no_annotate: false,
              no_header: false,
              custom_compile_command: None,
+             custom_header: None,
     -        generate_hashes: true,
     +        generate_hashes: false,
              config_setting: ConfigSettings(
@@ -1051,6 +1287,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1062,13 +1301,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
-             no_annotate: false,
              no_header: false,
              custom_compile_command: None,
+             custom_header: None,
     -        generate_hashes: false,
     +        generate_hashes: true,
              config_setting: ConfigSettings(
@@ -1123,9 +1362,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
     +        python_platform: Some(
     +            X8664UnknownLinuxGnu,
     +        ),
+             python_implementation: None,
+             python_abiflags: None,
              universal: false,
-             exclude_newer: ExcludeNewer {
-                 global: None,
     ...
     "
     );
@@ -1207,6 +1446,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1242,6 +1484,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1297,6 +1542,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1385,6 +1633,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
     +            no_index: true,
@@ -1437,9 +1688,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
     "
     );
@@ -1506,6 +1757,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +                Index {
     +                    name: None,
@@ -1541,6 +1795,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1562,9 +1819,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
     "
     );
@@ -1611,9 +1868,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
     "
     );
@@ -1632,9 +1889,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         .arg("requirements.in")
         .env(EnvVars::XDG_CONFIG_HOME, xdg.path()), @"
     ...
-             no_annotate: false,
              no_header: false,
              custom_compile_command: None,
+             custom_header: None,
     -        generate_hashes: false,
     +        generate_hashes: true,
              config_setting: ConfigSettings(
@@ -1717,9 +1974,9 @@ fn resolve_system_configuration_can_be_disabled() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
     ");
 
@@ -1783,18 +2040,18 @@ fn resolve_tool() -> anyhow::Result<()> {
     +        resolution: Some(
     +            LowestDirect,
     +        ),
+             resolution_package: None,
              prerelease: None,
-             fork_strategy: None,
-             dependency_metadata: None,
+             prerelease_package: None,
     ...
-                     {},
-                 ),
+                     build_env: None,
+                 },
                  prerelease: IfNecessaryOrExplicit,
     -            resolution: Highest,
     +            resolution: LowestDirect,
-                 sources: None,
-                 torch_backend: None,
-                 cuda_driver_version: None,
+                 resolution_package: PackageResolutionModes(
+                     {},
+                 ),
     ...
     "
     );
@@ -1851,9 +2108,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
     "
     );
@@ -1953,6 +2210,9 @@ fn resolve_both() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -1964,13 +2224,13 @@ fn resolve_both() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
-             no_annotate: false,
              no_header: false,
              custom_compile_command: None,
+             custom_header: None,
     -        generate_hashes: false,
     +        generate_hashes: true,
              config_setting: ConfigSettings(
@@ -2084,6 +2344,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -2095,13 +2358,13 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
-             no_annotate: false,
              no_header: false,
              custom_compile_command: None,
+             custom_header: None,
     -        generate_hashes: false,
     +        generate_hashes: true,
              config_setting: ConfigSettings(
@@ -2438,6 +2701,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -2449,13 +2715,13 @@ fn resolve_config_file() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
-             no_annotate: false,
              no_header: false,
              custom_compile_command: None,
+             custom_header: None,
     -        generate_hashes: false,
     +        generate_hashes: true,
              config_setting: ConfigSettings(
@@ -2493,7 +2759,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `system-certs`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `preview-features`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `http-proxy`, `https-proxy`, `no-proxy`, `allow-insecure-host`, `resolution`, `resolution-package`, `prerelease`, `prerelease-package`, `local-version-preference`, `yanked`, `fork-strategy`, `max-metadata-builds`, `resolution-effort`, `excluded-packages`, `reresolve-metadata`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `build-env`, `exclude-newer`, `exclude-newer-package`, `strict-exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `no-sources-package`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `torch-backend`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `keep-extraneous`, `audit`, `pip`, `cache-keys`, `override-dependencies`, `exclude-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `replace`, `workspace`, `sources`, `managed`, `package`, `lock-build-dependencies`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -2584,9 +2850,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
              dependency_mode: Transitive,
     -        resolution: Highest,
     +        resolution: LowestDirect,
-             prerelease: IfNecessaryOrExplicit,
-             fork_strategy: RequiresPython,
-             dependency_metadata: DependencyMetadata(
+             resolution_package: PackageResolutionModes(
+                 {},
+             ),
     ...
     "
     );
@@ -2736,6 +3002,9 @@ fn index_priority() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +                Index {
     +                    name: None,
@@ -2773,6 +3042,9 @@ fn index_priority() -> anyhow::Result<()> {
     +                    ignore_error_codes: None,
     +                    cache_control: None,
     +                    exclude_newer: None,
+    +                    http: None,
+    +                    allow_sdist: None,
+    +                    allow_wheels: None,
     +                },
     +            ],
                  flat_index: [],
@@ -4456,3 +4728,382 @@ fn build_isolation_override() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Read the `build-timeout` from a `uv.toml` file in the current directory.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_build_timeout() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    let baseline = capture_uv_snapshot!(
+        context.filters(),
+        add_shared_args(context.pip_compile())
+            .arg("--show-settings")
+            .arg("requirements.in")
+    );
+
+    // Write a `uv.toml` file with a build timeout.
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r"
+        build-timeout = 600
+    "})?;
+
+    let configured = diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in"), @r#"
+    ...
+                 no_build: None,
+             },
+             build_runtime: BuildRuntime {
+    -            timeout: None,
+    +            timeout: Some(
+    +                [TIME],
+    +            ),
+                 retries: 0,
+                 build_python: None,
+                 target: None,
+    ...
+    "#);
+
+    // The timeout can also be provided on the command line, without any configuration.
+    fs_err::remove_file(config.path())?;
+    diff_uv_snapshot!(context.filters(), &configured, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .arg("--build-timeout")
+        .arg("60"), @"");
+
+    Ok(())
+}
+
+/// Read the `build-retries` from a `uv.toml` file in the current directory.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_build_retries() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    let baseline = capture_uv_snapshot!(
+        context.filters(),
+        add_shared_args(context.pip_compile())
+            .arg("--show-settings")
+            .arg("requirements.in")
+    );
+
+    // Write a `uv.toml` file with a number of build retries.
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r"
+        build-retries = 2
+    "})?;
+
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in"), @r#"
+    ...
+                 no_binary: None,
+                 no_build: None,
+                 timeout: None,
+    -            retries: 0,
+    +            retries: 2,
+                 build_python: None,
+                 target: None,
+                 show_warnings: false,
+    ...
+    "#);
+
+    // The command line takes precedence over the configuration.
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .arg("--build-retries")
+        .arg("1"), @r#"
+    ...
+                 no_binary: None,
+                 no_build: None,
+                 timeout: None,
+    -            retries: 0,
+    +            retries: 1,
+                 build_python: None,
+                 target: None,
+                 show_warnings: false,
+    ...
+    "#);
+
+    Ok(())
+}
+
+/// Read the `build-python` from a `uv.toml` file in the current directory.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_build_python() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    let baseline = capture_uv_snapshot!(
+        context.filters(),
+        add_shared_args(context.pip_compile())
+            .arg("--show-settings")
+            .arg("requirements.in")
+    );
+
+    // Write a `uv.toml` file with an interpreter for builds.
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        build-python = "3.11"
+    "#})?;
+
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in"), @r#"
+    ...
+                 no_build: None,
+                 timeout: None,
+                 retries: 0,
+    -            build_python: None,
+    +            build_python: Some(
+    +                "3.11",
+    +            ),
+                 target: None,
+                 show_warnings: false,
+                 warning_patterns: [],
+    ...
+    "#);
+
+    // The command line takes precedence over the configuration.
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .arg("--build-python")
+        .arg("any"), @r#"
+    ...
+                 no_build: None,
+                 timeout: None,
+                 retries: 0,
+    -            build_python: None,
+    +            build_python: Some(
+    +                "any",
+    +            ),
+                 target: None,
+                 show_warnings: false,
+                 warning_patterns: [],
+    ...
+    "#);
+
+    Ok(())
+}
+
+/// Read the `--build-target` from the command line.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_build_target() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    let baseline = capture_uv_snapshot!(
+        context.filters(),
+        add_shared_args(context.pip_compile())
+            .arg("--show-settings")
+            .arg("requirements.in")
+    );
+
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("--build-target")
+        .arg("manylinux_2_28_aarch64")
+        .arg("requirements.in"), @r#"
+    ...
+                 timeout: None,
+                 retries: 0,
+                 build_python: None,
+    -            target: None,
+    +            target: Some(
+    +                BuildTarget(
+    +                    Manylinux {
+    +                        major: 2,
+    +                        minor: 28,
+    +                        arch: Aarch64,
+    +                    },
+    +                ),
+    +            ),
+                 show_warnings: false,
+                 warning_patterns: [],
+                 events_file: None,
+    ...
+    "#);
+
+    // An unsupported target is rejected.
+    uv_snapshot!(context.filters(), add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("--build-target")
+        .arg("any")
+        .arg("requirements.in"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'any' for '--build-target <PLATFORM_TAG>': `any` is not a supported build target; expected a Linux (`manylinux`, `musllinux`, or `linux`), macOS (`arm64` or `x86_64`), or Windows platform tag
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+/// Read the `show-build-warnings` and `build-warning-pattern` from a `uv.toml` file in the current
+/// directory.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_show_build_warnings() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    let baseline = capture_uv_snapshot!(
+        context.filters(),
+        add_shared_args(context.pip_compile())
+            .arg("--show-settings")
+            .arg("requirements.in")
+    );
+
+    // Write a `uv.toml` file that enables build warnings, with a custom pattern.
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        show-build-warnings = true
+        build-warning-pattern = ["Missing license"]
+    "#})?;
+
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in"), @r#"
+    ...
+                 retries: 0,
+                 build_python: None,
+                 target: None,
+    -            show_warnings: false,
+    -            warning_patterns: [],
+    +            show_warnings: true,
+    +            warning_patterns: [
+    +                "Missing license",
+    +            ],
+                 events_file: None,
+                 keep_logs: false,
+                 keep_environment: false,
+    ...
+    "#);
+
+    // Patterns on the command line are combined with those in the configuration.
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .arg("--build-warning-pattern")
+        .arg("DeprecationWarning"), @r#"
+    ...
+                 retries: 0,
+                 build_python: None,
+                 target: None,
+    -            show_warnings: false,
+    -            warning_patterns: [],
+    +            show_warnings: true,
+    +            warning_patterns: [
+    +                "DeprecationWarning",
+    +                "Missing license",
+    +            ],
+                 events_file: None,
+                 keep_logs: false,
+                 keep_environment: false,
+    ...
+    "#);
+
+    Ok(())
+}
+
+/// Read the `build-events-file` from a `uv.toml` file in the current directory.
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "Configuration tests are not yet supported on Windows"
+)]
+fn resolve_build_events_file() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio>3.0.0")?;
+
+    let baseline = capture_uv_snapshot!(
+        context.filters(),
+        add_shared_args(context.pip_compile())
+            .arg("--show-settings")
+            .arg("requirements.in")
+    );
+
+    // Write a `uv.toml` file with a build events file.
+    let config = context.temp_dir.child("uv.toml");
+    config.write_str(indoc::indoc! {r#"
+        build-events-file = "build-events.jsonl"
+    "#})?;
+
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in"), @r#"
+    ...
+                 target: None,
+                 show_warnings: false,
+                 warning_patterns: [],
+    -            events_file: None,
+    +            events_file: Some(
+    +                "build-events.jsonl",
+    +            ),
+                 keep_logs: false,
+                 keep_environment: false,
+                 build_dir: None,
+    ...
+    "#);
+
+    // The command line takes precedence over the configuration.
+    diff_uv_snapshot!(context.filters(), &baseline, add_shared_args(context.pip_compile())
+        .arg("--show-settings")
+        .arg("requirements.in")
+        .arg("--build-events-file")
+        .arg("events.jsonl"), @r#"
+    ...
+                 target: None,
+                 show_warnings: false,
+                 warning_patterns: [],
+    -            events_file: None,
+    +            events_file: Some(
+    +                "events.jsonl",
+    +            ),
+                 keep_logs: false,
+                 keep_environment: false,
+                 build_dir: None,
+    ...
+    "#);
+
+    Ok(())
+}
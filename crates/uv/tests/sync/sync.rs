@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
 use anyhow::{Result, anyhow};
 use assert_cmd::prelude::*;
 use assert_fs::{fixture::ChildPath, prelude::*};
@@ -13,8 +16,12 @@ use wiremock::matchers::{basic_auth, body_string_contains, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_static::EnvVars;
 
+use uv_test::packse::PackseServer;
+use uv_test::packse::scenario::{Package, PackageMetadata, Scenario};
 use uv_test::{TestContext, download_to_disk, uv_snapshot, venv_bin_path};
 
 #[test]
@@ -318,6 +325,41 @@ fn frozen() -> Result<()> {
     Ok(())
 }
 
+/// Reject a locked package that's excluded with `--exclude` when syncing with `--frozen`, which
+/// installs from the lockfile without resolving.
+#[test]
+fn frozen_excluded_package() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // `idna` is a transitive dependency of `anyio`.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--exclude").arg("idna"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `idna` is excluded from the resolution, but is included in the packages to install
+    ");
+
+    // Nothing should have been installed.
+    context.assert_not_installed("anyio");
+
+    Ok(())
+}
+
 #[test]
 fn empty() -> Result<()> {
     let context = uv_test::test_context!("3.12");
@@ -1339,6 +1381,260 @@ fn check() -> Result<()> {
     Ok(())
 }
 
+/// Packages installed into the environment by other tools (e.g., `pip`) are detected from their
+/// `.dist-info` metadata, rather than from modification times, so changes to bytecode are ignored.
+/// With `--verify`, packages with modified files are reinstalled.
+#[test]
+fn sync_verify() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.sync().assert().success();
+
+    // Simulate an editor compiling bytecode, and a `pip install` of an extraneous package.
+    let site_packages = ChildPath::new(context.site_packages());
+    site_packages
+        .child("iniconfig")
+        .child("__pycache__")
+        .child("__init__.cpython-312.pyc")
+        .write_str("")?;
+    let dist_info = site_packages.child("extraneous-1.0.0.dist-info");
+    dist_info
+        .child("METADATA")
+        .write_str("Metadata-Version: 2.1\nName: extraneous\nVersion: 1.0.0\n")?;
+    dist_info.child("INSTALLER").write_str("pip\n")?;
+    dist_info.child("RECORD").write_str(indoc! {"
+        extraneous-1.0.0.dist-info/METADATA,,
+        extraneous-1.0.0.dist-info/INSTALLER,,
+        extraneous-1.0.0.dist-info/RECORD,,
+    "})?;
+
+    // The extraneous package is detected and removed.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - extraneous==1.0.0
+    ");
+
+    // Modify an installed file. Without `--verify`, the change goes undetected.
+    let init = site_packages.child("iniconfig").child("__init__.py");
+    init.write_str("")?;
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    // With `--verify`, the package is reinstalled.
+    uv_snapshot!(context.filters(), context.sync().arg("--verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Found missing or modified files in `iniconfig`
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ iniconfig==2.0.0
+    ");
+    assert!(!fs_err::read_to_string(&init)?.is_empty());
+
+    Ok(())
+}
+
+/// Retain extraneous packages with `--keep` and `keep-extraneous`, but never in place of a locked
+/// package.
+#[test]
+fn sync_keep_extraneous() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let find_links = context.workspace_root.join("test/links");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0"]
+        "#,
+    )?;
+
+    context
+        .sync()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .assert()
+        .success();
+
+    // Install a few packages that aren't in the lockfile.
+    context
+        .pip_install()
+        .arg("tqdm==1000.0.0")
+        .arg("validation==1.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .assert()
+        .success();
+
+    // List the extraneous packages, without removing them.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--list-extraneous")
+        .arg("--keep")
+        .arg("validation")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would use project environment at: .venv
+    Resolved 2 packages in [TIME]
+    Found up-to-date lockfile at: uv.lock
+    Would remove 1 extraneous package
+     - tqdm==1000.0.0
+     = validation==1.0.0 kept (extraneous)
+    ");
+
+    // Remove `tqdm`, but keep `validation`.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--keep")
+        .arg("validation")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - tqdm==1000.0.0
+     = validation==1.0.0 kept (extraneous)
+    ");
+
+    // A kept package can't shadow a locked package.
+    context
+        .pip_install()
+        .arg("ok==2.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links)
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--keep")
+        .arg("ok")
+        .arg("--keep")
+        .arg("validation")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - ok==2.0.0
+     + ok==1.0.0
+     = validation==1.0.0 kept (extraneous)
+    ");
+
+    // Extraneous packages can also be kept with `keep-extraneous`.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0"]
+
+        [tool.uv]
+        keep-extraneous = ["validation"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Checked 1 package in [TIME]
+     = validation==1.0.0 kept (extraneous)
+    ");
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--list-extraneous")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would use project environment at: .venv
+    Resolved 2 packages in [TIME]
+    Found up-to-date lockfile at: uv.lock
+    No extraneous packages to remove
+     = validation==1.0.0 kept (extraneous)
+    ");
+
+    // Warn about packages passed to `--keep` that aren't installed.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--keep")
+        .arg("validaton")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(&find_links), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: `validaton` was passed to `--keep`, but is not installed
+    Checked 1 package in [TIME]
+     = validation==1.0.0 kept (extraneous)
+    ");
+
+    Ok(())
+}
+
 /// Sync development dependencies in a non-project workspace root.
 #[test]
 fn sync_non_project_dev_dependencies() -> Result<()> {
@@ -1827,7 +2123,7 @@ fn sync_build_isolation() -> Result<()> {
 /// Use a `pip install` step to pre-install build dependencies for `--no-build-isolation-package`.
 #[test]
 fn sync_build_isolation_package() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -1856,6 +2152,7 @@ fn sync_build_isolation_package() -> Result<()> {
       × Failed to build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `hatchling.build.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -1868,7 +2165,7 @@ fn sync_build_isolation_package() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     source-distribution = ["hatchling"]
 
-    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation-package source-distribution`.
     "#);
 
     // Install `hatchling` for `source-distribution`.
@@ -1915,7 +2212,7 @@ fn sync_build_isolation_package() -> Result<()> {
 /// By default, isolated dependencies should be installed before non-isolated dependencies.
 #[test]
 fn sync_build_isolation_package_order() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -1944,6 +2241,7 @@ fn sync_build_isolation_package_order() -> Result<()> {
       × Failed to build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `hatchling.build.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -1956,7 +2254,7 @@ fn sync_build_isolation_package_order() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     source-distribution = ["hatchling"]
 
-    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation-package source-distribution`.
     "#);
 
     // Add `hatchling`.
@@ -2077,7 +2375,9 @@ fn sync_build_isolation_package_order() -> Result<()> {
 /// Use dedicated extra groups to install dependencies for `--no-build-isolation-package`.
 #[test]
 fn sync_build_isolation_extra() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -2120,6 +2420,7 @@ fn sync_build_isolation_extra() -> Result<()> {
       × Failed to build `source-distribution @ https://files.pythonhosted.org/packages/10/1f/57aa4cce1b1abf6b433106676e15f9fa2c92ed2bd4cf77c3b50a9e9ac773/source_distribution-0.0.1.tar.gz`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `hatchling.build.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -2132,7 +2433,7 @@ fn sync_build_isolation_extra() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     source-distribution = ["hatchling"]
 
-    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install hatchling` into the environment and re-run with `--no-build-isolation-package source-distribution`.
     "#);
 
     // Running `uv sync` with `--all-extras` should succeed, because we install the build dependencies
@@ -2204,9 +2505,171 @@ fn sync_build_isolation_extra() -> Result<()> {
     Ok(())
 }
 
+/// If a locked artifact is missing from the index, refresh the index metadata and retry with the
+/// current location of the artifact.
+#[test]
+fn sync_missing_artifact() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Point the locked wheel at a location that no longer exists, as if the index had moved it.
+    let lock = context.read("uv.lock");
+    let lock = lock.replace(
+        "ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46",
+        "ef/a6/fake",
+    );
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    // With `--no-retry-missing-artifacts`, the sync should fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen").arg("--no-retry-missing-artifacts"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download `iniconfig==2.0.0`
+      ├─▶ Failed to fetch: `https://files.pythonhosted.org/packages/ef/a6/fake/iniconfig-2.0.0-py3-none-any.whl`
+      ╰─▶ HTTP status client error (404 Not Found) for url (https://files.pythonhosted.org/packages/ef/a6/fake/iniconfig-2.0.0-py3-none-any.whl)
+
+    hint: `iniconfig` (v2.0.0) was included because `project` (v0.1.0) depends on `iniconfig`
+    ");
+
+    // Otherwise, the index metadata should be refreshed, and the wheel fetched from its current
+    // location.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `iniconfig-2.0.0-py3-none-any.whl` was not found at its previous location; retrying with the location from the refreshed index metadata
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // If the locked version no longer exists on the index, the sync should fail.
+    context.venv().arg("--clear").assert().success();
+    let lock = lock.replace("2.0.0", "99.0.0");
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download `iniconfig==99.0.0`
+      ├─▶ The locked version `iniconfig==99.0.0` no longer exists on the index: https://pypi.org/simple
+      ├─▶ Failed to fetch: `https://files.pythonhosted.org/packages/ef/a6/fake/iniconfig-99.0.0-py3-none-any.whl`
+      ╰─▶ HTTP status client error (404 Not Found) for url (https://files.pythonhosted.org/packages/ef/a6/fake/iniconfig-99.0.0-py3-none-any.whl)
+
+    hint: `iniconfig` (v99.0.0) was included because `project` (v0.1.0) depends on `iniconfig`
+    hint: The version may have been removed from the index after the lockfile was created; re-lock with `uv lock --upgrade-package iniconfig` to select an available version
+    ");
+
+    Ok(())
+}
+
+/// Build requirements that are missing from the cache should be reported when offline.
+#[test]
+fn sync_build_requirements_offline() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        description = "A project"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["flit_core>=3.8,<4"]
+        build-backend = "flit_core.buildapi"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // Clear the virtual environment.
+    context.venv().arg("--clear").assert().success();
+
+    // Remove the cached wheels and build environments, but retain the index metadata, such that
+    // the build requirements can be resolved, but not installed.
+    for entry in fs_err::read_dir(&*context.cache_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !(name.starts_with("simple-") || name.starts_with("interpreter-"))
+            && entry.file_type()?.is_dir()
+        {
+            fs_err::remove_dir_all(entry.path())?;
+        }
+    }
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"https://files\.pythonhosted\.org/packages/[^`]+/flit_core-",
+            "https://files.pythonhosted.org/packages/[PATH]/flit_core-",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.sync().arg("--offline"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/`
+      ├─▶ Failed to install requirements from `build-system.requires`
+      ├─▶ Network connectivity is disabled, but build requirements are missing from the cache: `flit-core==3.9.0`
+      ├─▶ Failed to download `flit-core==3.9.0`
+      ╰─▶ Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://files.pythonhosted.org/packages/[PATH]/flit_core-3.9.0-py3-none-any.whl`
+
+    hint: Build requirements can only be read from the cache when the network is disabled; run the command without `--offline` to populate the cache, or use `--no-build` to install from pre-built wheels only
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn sync_extra_build_dependencies() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that arbitrarily requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -2262,6 +2725,7 @@ fn sync_extra_build_dependencies() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Missing `anyio` module
@@ -2338,6 +2802,7 @@ fn sync_extra_build_dependencies() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Missing `anyio` module
@@ -2407,6 +2872,7 @@ fn sync_extra_build_dependencies() -> Result<()> {
       × Failed to build `bad-child @ file://[TEMP_DIR]/bad_child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Found `anyio` module
@@ -2451,7 +2917,9 @@ fn sync_extra_build_dependencies() -> Result<()> {
 
 #[test]
 fn sync_extra_build_dependencies_setuptools_legacy() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that uses legacy setuptools (no pyproject.toml) and requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -2502,6 +2970,7 @@ fn sync_extra_build_dependencies_setuptools_legacy() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Missing `anyio` module
@@ -2543,7 +3012,9 @@ fn sync_extra_build_dependencies_setuptools_legacy() -> Result<()> {
 
 #[test]
 fn sync_extra_build_dependencies_setuptools() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that uses setuptools with pyproject.toml and requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -2608,6 +3079,7 @@ fn sync_extra_build_dependencies_setuptools() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Missing `anyio` module
@@ -2727,7 +3199,9 @@ fn sync_extra_build_dependencies_sources() -> Result<()> {
 
 #[test]
 fn sync_extra_build_dependencies_index() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that arbitrarily requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -2798,6 +3272,7 @@ fn sync_extra_build_dependencies_index() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 3.0 but got 4.3.0
@@ -2853,6 +3328,7 @@ fn sync_extra_build_dependencies_index() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 4.3 but got 3.5.0
@@ -2881,7 +3357,9 @@ fn sync_extra_build_dependencies_index() -> Result<()> {
 
 #[test]
 fn sync_extra_build_dependencies_sources_from_child() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     let anyio_local = context.workspace_root.join("test/packages/anyio_local");
 
@@ -2950,6 +3428,7 @@ fn sync_extra_build_dependencies_sources_from_child() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Found system anyio instead of local anyio
@@ -2964,7 +3443,9 @@ fn sync_extra_build_dependencies_sources_from_child() -> Result<()> {
 
 #[test]
 fn sync_build_dependencies_module_error_hints() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that arbitrarily requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -3015,6 +3496,7 @@ fn sync_build_dependencies_module_error_hints() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -3029,7 +3511,7 @@ fn sync_build_dependencies_module_error_hints() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     child = ["anyio"]
 
-    or `uv pip install anyio` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install anyio` into the environment and re-run with `--no-build-isolation-package child`.
     "#);
 
     // Adding `extra-build-dependencies` should solve the issue
@@ -3081,6 +3563,7 @@ fn sync_build_dependencies_module_error_hints() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -3095,7 +3578,7 @@ fn sync_build_dependencies_module_error_hints() -> Result<()> {
     [tool.uv.extra-build-dependencies]
     child = ["scikit-learn"]
 
-    or `uv pip install scikit-learn` into the environment and re-run with `--no-build-isolation`.
+    or `uv pip install scikit-learn` into the environment and re-run with `--no-build-isolation-package child`.
     "#);
 
     // Adding `extra-build-dependencies` should solve the issue
@@ -6456,7 +6939,9 @@ fn no_install_project_no_build_locked_dynamic_metadata() -> Result<()> {
 
 #[test]
 fn sync_extra_build_dependencies_script() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that arbitrarily requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -6517,6 +7002,7 @@ fn sync_extra_build_dependencies_script() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Missing `anyio` module
@@ -9098,106 +9584,316 @@ fn sync_scripts_project_not_packaged() -> Result<()> {
        "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.sync(), @"
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Skipping installation of entry points (`project.scripts`) for package `foo` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+#[test]
+/// Check warning message for <https://github.com/astral-sh/uv/issues/18388>
+/// if a workspace member has scripts but no `build-system`.
+fn sync_scripts_workspace_member_not_packaged() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["member"]
+        "#,
+    )?;
+
+    let member = context.temp_dir.child("member");
+    fs_err::create_dir_all(&member)?;
+
+    let member_pyproject_toml = member.child("pyproject.toml");
+    member_pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "member"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        member = "main:main"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--all-packages"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: Skipping installation of entry points (`project.scripts`) for package `member` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+#[test]
+/// Check that the warning is not emitted for workspace members that are not being synced.
+fn sync_scripts_workspace_member_not_packaged_not_synced() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["member"]
+        "#,
+    )?;
+
+    let member = context.temp_dir.child("member");
+    fs_err::create_dir_all(&member)?;
+
+    let member_pyproject_toml = member.child("pyproject.toml");
+    member_pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "member"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        member = "main:main"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Regenerate the entry points of an editable when they change, without rebuilding it.
+#[test]
+fn sync_editable_entry_points() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let bin = context
+        .venv
+        .join(if cfg!(windows) { "Scripts" } else { "bin" });
+    let script = |name: &str| bin.join(format!("{name}{}", std::env::consts::EXE_SUFFIX));
+
+    // Use the default cache keys, which include the `pyproject.toml` itself.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_pyproject_toml = |scripts: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [project.scripts]
+            {scripts}
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+            "#
+        })
+    };
+    write_pyproject_toml(r#"foo = "project:main""#)?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main():\n    print('Hello')\n")?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    assert!(script("foo").is_file());
+
+    // Adding a script should generate it, without rebuilding the project.
+    write_pyproject_toml(indoc! {r#"
+        foo = "project:main"
+        bar = "project:main"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    assert!(script("foo").is_file());
+    assert!(script("bar").is_file());
+
+    // Renaming a script should remove the old script.
+    write_pyproject_toml(indoc! {r#"
+        baz = "project:main"
+        bar = "project:main"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    assert!(!script("foo").exists());
+    assert!(script("bar").is_file());
+    assert!(script("baz").is_file());
+
+    let output = std::process::Command::new(script("baz")).output()?;
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "Hello");
+
+    // Once the entry points are up-to-date, there's nothing to do.
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked 1 package in [TIME]
+    ");
+
+    // The `RECORD` should reflect the regenerated scripts.
+    uv_snapshot!(context.filters(), context.pip_uninstall().arg("project"), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    warning: Skipping installation of entry points (`project.scripts`) for package `foo` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
-    Checked in [TIME]
+    Uninstalled 1 package in [TIME]
+     - project==0.1.0 (from file://[TEMP_DIR]/)
     ");
 
+    assert!(!script("bar").exists());
+    assert!(!script("baz").exists());
+
     Ok(())
 }
 
+/// Regenerate the `.exe` trampolines of an editable when its entry points change.
 #[test]
-/// Check warning message for <https://github.com/astral-sh/uv/issues/18388>
-/// if a workspace member has scripts but no `build-system`.
-fn sync_scripts_workspace_member_not_packaged() -> Result<()> {
+#[cfg(windows)]
+fn sync_editable_entry_points_trampolines() -> Result<()> {
     let context = uv_test::test_context!("3.12");
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "root"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = []
-
-        [tool.uv.workspace]
-        members = ["member"]
-        "#,
-    )?;
+    let scripts = context.venv.join("Scripts");
 
-    let member = context.temp_dir.child("member");
-    fs_err::create_dir_all(&member)?;
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    let write_pyproject_toml = |scripts: &str| {
+        pyproject_toml.write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
 
-    let member_pyproject_toml = member.child("pyproject.toml");
-    member_pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "member"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = []
+            {scripts}
 
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+            "#
+        })
+    };
+    write_pyproject_toml(indoc! {r#"
         [project.scripts]
-        member = "main:main"
-        "#,
-    )?;
+        foo = "project:main"
+    "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main():\n    print('Hello')\n")?;
 
-    uv_snapshot!(context.filters(), context.sync().arg("--all-packages"), @"
+    uv_snapshot!(context.filters(), context.sync(), @"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    warning: Skipping installation of entry points (`project.scripts`) for package `member` because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`
-    Checked in [TIME]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
     ");
 
-    Ok(())
-}
-
-#[test]
-/// Check that the warning is not emitted for workspace members that are not being synced.
-fn sync_scripts_workspace_member_not_packaged_not_synced() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    assert!(scripts.join("foo.exe").is_file());
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "root"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = []
+    // Replace the console script with a GUI script.
+    write_pyproject_toml(indoc! {r#"
+        [project.gui-scripts]
+        foo-gui = "project:main"
+    "#})?;
 
-        [tool.uv.workspace]
-        members = ["member"]
-        "#,
-    )?;
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    let member = context.temp_dir.child("member");
-    fs_err::create_dir_all(&member)?;
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
 
-    let member_pyproject_toml = member.child("pyproject.toml");
-    member_pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "member"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = []
+    assert!(!scripts.join("foo.exe").exists());
+    assert!(scripts.join("foo-gui.exe").is_file());
 
+    // Restore the console script, and check that the regenerated trampoline runs.
+    write_pyproject_toml(indoc! {r#"
         [project.scripts]
-        member = "main:main"
-        "#,
-    )?;
+        foo = "project:main"
+    "#})?;
 
     uv_snapshot!(context.filters(), context.sync(), @"
     success: true
@@ -9205,10 +9901,15 @@ fn sync_scripts_workspace_member_not_packaged_not_synced() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Checked in [TIME]
+    Resolved 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
     ");
 
+    assert!(!scripts.join("foo-gui.exe").exists());
+    let output = std::process::Command::new(scripts.join("foo.exe")).output()?;
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "Hello");
+
     Ok(())
 }
 
@@ -9811,6 +10512,99 @@ fn sync_no_sources_package() -> Result<()> {
     Ok(())
 }
 
+/// Sync a Git requirement on a branch within a subdirectory of a local repository, where the
+/// locked commit no longer contains the subdirectory (e.g., after a bad merge of the lockfile).
+#[test]
+#[cfg(feature = "test-git")]
+fn sync_git_branch_missing_subdirectory() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let repository = context.temp_dir.child("repository");
+    repository
+        .child("packages/foo/src/foo/__init__.py")
+        .touch()?;
+    repository
+        .child("packages/foo/pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(repository.path())
+            .arg("-c")
+            .arg("user.name=Example")
+            .arg("-c")
+            .arg("user.email=example@example.com")
+            .args(args)
+            .env("GIT_AUTHOR_DATE", "2000-01-01T00:00:00Z")
+            .env("GIT_COMMITTER_DATE", "2000-01-01T00:00:00Z")
+            .assert()
+            .success()
+    };
+    let commit = |message: &str| -> Result<String> {
+        git(&["add", "--all", "."]);
+        git(&["commit", "-m", message]);
+        let output = git(&["rev-parse", "HEAD"]).get_output().stdout.clone();
+        Ok(String::from_utf8(output)?.trim().to_string())
+    };
+
+    git(&["init", "--initial-branch", "main"]);
+    let initial = commit("Initial commit")?;
+
+    let repository_url = Url::from_directory_path(repository.path())
+        .map_err(|()| anyhow!("failed to convert repository path to file URL"))?;
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo"]
+
+        [tool.uv.sources]
+        foo = {{ git = "{repository_url}", subdirectory = "packages/foo", branch = "main" }}
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Move the package to another subdirectory on the branch, and point the lockfile at the new
+    // commit.
+    fs_err::rename(
+        repository.child("packages/foo").path(),
+        repository.child("packages/foo-renamed").path(),
+    )?;
+    let moved = commit("Move foo")?;
+    let lock = context.read("uv.lock").replace(&initial, &moved);
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    let mut filters = context.filters();
+    filters.push((moved.as_str(), "[MOVED]"));
+
+    // Syncing should fail, naming the subdirectory, the branch, and the commit.
+    uv_snapshot!(filters, context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `foo @ git+file://[TEMP_DIR]/repository/@[MOVED]#subdirectory=packages/foo`
+      ╰─▶ The source distribution `git+file://[TEMP_DIR]/repository/@[MOVED]#subdirectory=packages/foo` has no subdirectory `packages/foo` at branch `main` (commit `[MOVED]`)
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn sync_python_version() -> Result<()> {
     let context = uv_test::test_context_with_versions!(&["3.10", "3.11", "3.12"]);
@@ -10593,7 +11387,7 @@ fn sync_multiple_sources_index_disjoint_extras() -> Result<()> {
 
 #[test]
 fn sync_derivation_chain() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -10627,6 +11421,7 @@ fn sync_derivation_chain() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -10656,7 +11451,7 @@ fn sync_derivation_chain() -> Result<()> {
 
 #[test]
 fn sync_derivation_chain_extra() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -10691,6 +11486,7 @@ fn sync_derivation_chain_extra() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -10720,7 +11516,7 @@ fn sync_derivation_chain_extra() -> Result<()> {
 
 #[test]
 fn sync_derivation_chain_group() -> Result<()> {
-    let context = uv_test::test_context!("3.12");
+    let context = uv_test::test_context!("3.12").with_filtered_build_logs();
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -10757,6 +11553,7 @@ fn sync_derivation_chain_group() -> Result<()> {
       × Failed to build `wsgiref==0.1.2`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Traceback (most recent call last):
@@ -12127,6 +12924,7 @@ fn find_links_relative_in_config_works_from_subdir() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
+    warning: `--exclude-newer` could not be applied to packages from `file://[TEMP_DIR]/packages`, which does not provide upload times: `ok`
     Resolved 2 packages in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
@@ -12841,6 +13639,8 @@ fn sync_script_with_incompatible_build_constraints() -> Result<()> {
       ├─▶ Failed to resolve requirements from `setup.py` build
       ├─▶ No solution found when resolving: `setuptools>=40.8.0`
       ╰─▶ Because you require setuptools>=40.8.0 and setuptools==1, we can conclude that your requirements are unsatisfiable.
+
+    hint: `setuptools` was constrained to `setuptools==1` by a build constraint; consider relaxing the constraint in `--build-constraint` or `build-constraint-dependencies`
     ");
 
     Ok(())
@@ -14807,6 +15607,10 @@ fn sync_python_preference() -> Result<()> {
     error: No interpreter found for Python 3.11 in managed installations
 
     hint: A managed Python download is available for Python 3.11, but Python downloads are set to 'never'
+
+    hint: Found 2 Python interpreters, but none can be used:
+      - CPython 3.12.[X] at `[PYTHON-3.12]`: incompatible with Python 3.11
+      - CPython 3.11.[X] at `[PYTHON-3.11]`: excluded by the Python preference 'only managed'
     ");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -14837,33 +15641,134 @@ fn sync_python_preference() -> Result<()> {
     Checked in [TIME]
     ");
 
-    // But it can be overridden via the CLI
-    uv_snapshot!(context.filters(), context.sync().arg("--managed-python"), @"
-    success: true
-    exit_code: 0
+    // But it can be overridden via the CLI
+    uv_snapshot!(context.filters(), context.sync().arg("--managed-python"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // `uv run` will invalidate the environment too
+    uv_snapshot!(context.filters(), context.run().arg("python").arg("--version"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.11.[X]
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    // A per-command `--python-preference` also takes precedence over the `pyproject.toml`
+    uv_snapshot!(context.filters(), context.sync().arg("--python-preference").arg("only-managed"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Checked in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// `--python-downloads never` takes precedence over the environment and the `pyproject.toml`, and
+/// reports the interpreters that were rejected.
+#[test]
+fn sync_python_downloads_never() -> Result<()> {
+    let context = uv_test::test_context_with_versions!(&["3.11"]).with_filtered_python_sources();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        python-downloads = "automatic"
+        "#,
+    )?;
+
+    // The interpreter is incompatible with the `requires-python`.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--python-downloads")
+        .arg("never")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS, "automatic"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python >=3.12 in [PYTHON SOURCES]
+
+    hint: A managed Python download is available for Python >=3.12, but Python downloads are set to 'never'
+
+    hint: Found 1 Python interpreter, but none are compatible with Python >=3.12:
+      - CPython 3.11.[X] at `[PYTHON-3.11]`: incompatible with Python >=3.12
+    ");
+
+    // If no interpreters are found, we say so.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--python-downloads")
+        .arg("never")
+        .env(EnvVars::UV_PYTHON_SEARCH_PATH, ""), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python >=3.12 in [PYTHON SOURCES]
+
+    hint: A managed Python download is available for Python >=3.12, but Python downloads are set to 'never'
+
+    hint: No Python interpreters were found on the system
+    ");
+
+    // Without a flag or environment variable, the `python-downloads` setting in the
+    // `pyproject.toml` is respected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        python-downloads = "never"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().env_remove(EnvVars::UV_PYTHON_DOWNLOADS), @"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Using CPython 3.12.[X]
-    Removed virtual environment at: .venv
-    Creating virtual environment at: .venv
-    Resolved 1 package in [TIME]
-    Checked in [TIME]
-    ");
+    error: No interpreter found for Python >=3.12 in [PYTHON SOURCES]
 
-    // `uv run` will invalidate the environment too
-    uv_snapshot!(context.filters(), context.run().arg("python").arg("--version"), @"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Python 3.11.[X]
+    hint: A managed Python download is available for Python >=3.12, but Python downloads are set to 'never'
 
-    ----- stderr -----
-    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
-    Removed virtual environment at: .venv
-    Creating virtual environment at: .venv
-    Resolved 1 package in [TIME]
-    Checked in [TIME]
+    hint: Found 1 Python interpreter, but none are compatible with Python >=3.12:
+      - CPython 3.11.[X] at `[PYTHON-3.11]`: incompatible with Python >=3.12
     ");
 
     Ok(())
@@ -15092,7 +15997,9 @@ fn sync_does_not_remove_empty_virtual_environment_directory() -> Result<()> {
 /// Test that build dependencies respect locked versions from the lockfile.
 #[test]
 fn sync_build_dependencies_respect_locked_versions() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Write a test package that arbitrarily requires `anyio` at build time
     let child = context.temp_dir.child("child");
@@ -15182,6 +16089,7 @@ fn sync_build_dependencies_respect_locked_versions() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 3.0 but got 4.3.0
@@ -15249,6 +16157,7 @@ fn sync_build_dependencies_respect_locked_versions() -> Result<()> {
       × Failed to build `child @ file://[TEMP_DIR]/child`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 4.0 but got 3.7.1
@@ -15349,7 +16258,9 @@ fn sync_build_dependencies_respect_locked_versions() -> Result<()> {
 
 #[test]
 fn sync_extra_build_variables() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_filtered_counts();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_counts()
+        .with_filtered_build_logs();
 
     // Create a build backend that asserts that `EXPECTED_ANYIO_VERSION` matches the installed version of `anyio`.
     let build_backend = context.temp_dir.child("build_backend.py");
@@ -15414,6 +16325,7 @@ fn sync_extra_build_variables() -> Result<()> {
       × Failed to build `parent @ file://[TEMP_DIR]/`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_editable` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 3.0 but got 4.3.0
@@ -15448,6 +16360,7 @@ fn sync_extra_build_variables() -> Result<()> {
       × Failed to build `parent @ file://[TEMP_DIR]/`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `build_backend.build_editable` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stderr]
           Expected `anyio` version 3.0 but got 4.3.0
@@ -16304,6 +17217,100 @@ async fn sync_zstd_wheel() -> Result<()> {
     Ok(())
 }
 
+/// A direct URL wheel that's pinned by hash in the lockfile is reused from the cache, without
+/// revalidating it against the server.
+#[tokio::test]
+async fn sync_frozen_direct_url_wheel_reuses_cache() -> Result<()> {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{method, path},
+    };
+
+    let context = uv_test::test_context!("3.13");
+    let server = MockServer::start().await;
+
+    // Serve the wheel with a policy that would otherwise require revalidation on every request.
+    Mock::given(method("GET"))
+        .and(path("/files/basic_package-0.1.0-py3-none-any.whl"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Cache-Control", "max-age=0, must-revalidate")
+                .set_body_bytes(fs_err::read(
+                    context
+                        .workspace_root
+                        .join("test/links/basic_package-0.1.0-py3-none-any.whl"),
+                )?),
+        )
+        .mount(&server)
+        .await;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.13"
+        dependencies = ["basic-package @ {}/files/basic_package-0.1.0-py3-none-any.whl"]
+        "#,
+        server.uri()
+    })?;
+
+    let context = context.with_filter((server.uri(), "[SERVER]"));
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + basic-package==0.1.0 (from [SERVER]/files/basic_package-0.1.0-py3-none-any.whl)
+    ");
+
+    // The lockfile records the hash of the archive.
+    let lock = context.read("uv.lock");
+    assert!(
+        lock.contains("sha256:7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82")
+    );
+
+    let requests = server.received_requests().await.unwrap_or_default().len();
+
+    // Re-syncing the existing environment is a no-op.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    ");
+
+    // Recreating the environment reuses the cached archive.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.13.[X] interpreter at: [PYTHON-3.13]
+    Creating virtual environment at: .venv
+    Installed 1 package in [TIME]
+     + basic-package==0.1.0 (from [SERVER]/files/basic_package-0.1.0-py3-none-any.whl)
+    ");
+
+    assert_eq!(
+        server.received_requests().await.unwrap_or_default().len(),
+        requests,
+        "The cached archive should be reused without contacting the server"
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(not(windows))]
 fn toggle_workspace_editable() -> Result<()> {
@@ -17616,3 +18623,239 @@ fn sync_frozen_workspace_member_git_credentials() -> Result<()> {
 
     Ok(())
 }
+
+/// Sync from a lockfile that pins a yanked version under each `--yanked` policy.
+#[test]
+fn sync_yanked_policy() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let mut scenario = Scenario::empty();
+    scenario.packages.insert(
+        PackageName::from_str("foo")?,
+        Package {
+            versions: BTreeMap::from([
+                (
+                    Version::from_str("1.0.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        yanked: true,
+                        yanked_reason: Some("Broken build".to_string()),
+                        ..PackageMetadata::default()
+                    },
+                ),
+                (
+                    Version::from_str("1.1.0")?,
+                    PackageMetadata {
+                        wheel: true,
+                        ..PackageMetadata::default()
+                    },
+                ),
+            ]),
+        },
+    );
+    let server = PackseServer::from_scenario(&scenario);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["foo==1.0.0"]
+
+        [[tool.uv.index]]
+        url = "{}"
+        default = true
+        "#,
+        server.index_url(),
+    })?;
+
+    // Lock the yanked version, as if it were yanked after the lockfile was created.
+    context
+        .lock()
+        .arg("--yanked")
+        .arg("allow")
+        .assert()
+        .success();
+
+    // Under `--yanked forbid`, installing from the lockfile fails, and the error includes the yank
+    // reason.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked")
+        .arg("--yanked")
+        .arg("forbid"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `foo==1.0.0` has been yanked (reason: "Broken build"), but yanked versions are forbidden by the `yanked` policy
+    "#);
+
+    // The same applies when the policy is set via the environment.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked")
+        .env(EnvVars::UV_YANKED, "forbid"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `foo==1.0.0` has been yanked (reason: "Broken build"), but yanked versions are forbidden by the `yanked` policy
+    "#);
+
+    // By default, the lockfile is installed with a warning.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: `foo==1.0.0` is yanked (reason: "Broken build")
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0
+    "#);
+
+    // Under `--yanked allow`, the index isn't queried.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked")
+        .arg("--yanked")
+        .arg("allow"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Checked 1 package in [TIME]
+    ");
+
+    // With `--frozen`, the lockfile is used as-is, without querying the index.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--frozen")
+        .arg("--yanked")
+        .arg("forbid"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    ");
+
+    // With `--offline`, the index can't be queried, so the check is skipped with a warning.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked")
+        .arg("--offline")
+        .arg("--yanked")
+        .arg("forbid"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: Unable to check whether the locked distributions have been yanked while offline
+    Checked 1 package in [TIME]
+    ");
+
+    // By default, the check is skipped silently.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked")
+        .arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Checked 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Sync from a lockfile with `--yanked forbid`, where a package comes from a `--find-links`
+/// directory, which can't mark distributions as yanked.
+#[test]
+fn sync_yanked_forbid_find_links() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Populate the `--find-links` entries.
+    fs_err::create_dir_all(context.temp_dir.join("links"))?;
+    fs_err::copy(
+        context
+            .workspace_root
+            .join("test/links/tqdm-1000.0.0-py3-none-any.whl"),
+        context
+            .temp_dir
+            .join("links/tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["tqdm"]
+
+        [tool.uv]
+        no-index = true
+        find-links = ["links"]
+        "#,
+    )?;
+
+    context
+        .lock()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+
+    // By default, the package is installed with a warning.
+    uv_snapshot!(context.filters(), context.sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--locked")
+        .arg("--yanked")
+        .arg("forbid"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: `--yanked forbid` could not be applied to packages from `file://[TEMP_DIR]/links`, which does not provide yank status: `tqdm`
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    ");
+
+    // With `--strict-exclude-newer`, the sync fails.
+    uv_snapshot!(context.filters(), context.sync()
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--locked")
+        .arg("--reinstall")
+        .arg("--yanked")
+        .arg("forbid")
+        .arg("--strict-exclude-newer"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `--yanked forbid` could not be applied to packages from sources that lack the required metadata:
+      file://[TEMP_DIR]/links (no yank status): tqdm
+
+    hint: Remove the affected sources, or omit `--strict-exclude-newer` to include their packages with a warning
+    ");
+
+    Ok(())
+}
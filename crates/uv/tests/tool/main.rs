@@ -12,6 +12,9 @@ mod tool_install;
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod tool_list;
 
+#[cfg(all(feature = "test-python", feature = "test-pypi"))]
+mod tool_repair;
+
 #[cfg(all(feature = "test-python", feature = "test-pypi"))]
 mod tool_run;
 
@@ -3390,7 +3390,9 @@ fn tool_install_no_binary_package_env_var() {
 /// Test installing a package that can't be installed.
 #[test]
 fn tool_install_uninstallable() {
-    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let context = uv_test::test_context!("3.12")
+        .with_filtered_exe_suffix()
+        .with_filtered_build_logs();
     let tool_dir = context.temp_dir.child("tools");
     let bin_dir = context.temp_dir.child("bin");
 
@@ -3421,6 +3423,7 @@ fn tool_install_uninstallable() {
       × Failed to build `pyenv==0.0.1`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta:__legacy__.build_wheel` failed (exit status: 1)
+          full log: [CACHE_DIR]/build-logs-v0/[BUILD]/build.log
 
           [stdout]
           running bdist_wheel
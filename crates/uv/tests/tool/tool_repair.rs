@@ -0,0 +1,229 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+use indoc::indoc;
+
+use uv_static::EnvVars;
+
+use uv_test::{TestContext, uv_snapshot};
+
+/// Create a package named `name` that provides an executable of the same name, built by an
+/// in-tree backend so that it can be installed without network access.
+pub(crate) fn tool_package(context: &TestContext, name: &str) -> Result<()> {
+    context.in_tree_backend(
+        name,
+        "0.1.0",
+        indoc! {r#"
+            def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+                return write_wheel(
+                    wheel_directory,
+                    {
+                        f"{DISTRIBUTION}/__init__.py": "def main():\n    print('Hello, world!')\n",
+                        f"{DISTRIBUTION}-{VERSION}.dist-info/entry_points.txt": (
+                            f"[console_scripts]\n{NAME} = {DISTRIBUTION}:main\n"
+                        ),
+                    },
+                )
+        "#},
+    )?;
+    Ok(())
+}
+
+#[test]
+fn tool_repair_nothing() {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_repair()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Nothing to repair
+    ");
+}
+
+#[test]
+fn tool_repair_orphaned_receipt() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    tool_package(&context, "foo")?;
+
+    context
+        .tool_install()
+        .arg("./foo")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Remove the environment, but retain the receipt.
+    for entry in fs_err::read_dir(tool_dir.child("foo").path())? {
+        let path = entry?.path();
+        if path
+            .file_name()
+            .is_some_and(|name| name == "uv-receipt.toml")
+        {
+            continue;
+        }
+        if path.is_dir() {
+            fs_err::remove_dir_all(&path)?;
+        } else {
+            fs_err::remove_file(&path)?;
+        }
+    }
+
+    // The orphaned receipt and the dangling executable are reported.
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Tool `foo` environment was removed (run `uv tool repair` to remove its receipt, or `uv tool install foo --reinstall` to reinstall)
+    warning: Executable `[TEMP_DIR]/bin/foo` links to a tool environment that no longer exists (run `uv tool repair` to remove)
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_repair()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed orphaned receipt for `foo`
+    Removed dangling executable `[TEMP_DIR]/bin/foo`
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No tools installed
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn tool_repair_dangling_executable() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    tool_package(&context, "foo")?;
+    tool_package(&context, "bar")?;
+
+    for package in ["./foo", "./bar"] {
+        context
+            .tool_install()
+            .arg(package)
+            .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+            .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+            .assert()
+            .success();
+    }
+
+    // Remove the tool directory entirely.
+    fs_err::remove_dir_all(tool_dir.child("foo").path())?;
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    bar v0.1.0
+    - bar
+
+    ----- stderr -----
+    warning: Executable `[TEMP_DIR]/bin/foo` links to a tool environment that no longer exists (run `uv tool repair` to remove)
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_repair()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed dangling executable `[TEMP_DIR]/bin/foo`
+    ");
+
+    assert!(!bin_dir.child("foo").exists());
+    assert!(bin_dir.child("bar").exists());
+
+    Ok(())
+}
+
+#[test]
+fn tool_repair_missing_executable() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    tool_package(&context, "foo")?;
+
+    context
+        .tool_install()
+        .arg("./foo")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Remove the executable, but retain the environment.
+    fs_err::remove_file(
+        bin_dir
+            .child(format!("foo{}", std::env::consts::EXE_SUFFIX))
+            .path(),
+    )?;
+
+    uv_snapshot!(context.filters(), context.tool_repair()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Re-linked executable `foo` for `foo`
+    ");
+
+    // The executable works again.
+    uv_snapshot!(context.filters(), Command::new(bin_dir.child(format!("foo{}", std::env::consts::EXE_SUFFIX)).as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    ");
+
+    // A second repair is a no-op.
+    uv_snapshot!(context.filters(), context.tool_repair()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Nothing to repair
+    ");
+
+    Ok(())
+}
@@ -2474,6 +2474,8 @@ fn tool_run_hint_version_not_available() {
     error: No interpreter found for Python 3.12 in [PYTHON SOURCES]
 
     hint: A managed Python download is available for Python 3.12, but Python downloads are set to 'never'
+
+    hint: No Python interpreters were found on the system
     ");
 
     uv_snapshot!(context.filters(), context.tool_run()
@@ -238,3 +238,66 @@ fn tool_uninstall_all_missing_receipt() {
     Removed dangling environment for `black`
     ");
 }
+
+#[test]
+fn tool_uninstall_all_keep() -> anyhow::Result<()> {
+    let context = uv_test::test_context!("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    crate::tool_repair::tool_package(&context, "foo")?;
+    crate::tool_repair::tool_package(&context, "bar")?;
+    crate::tool_repair::tool_package(&context, "baz")?;
+
+    for package in ["./foo", "./bar", "./baz"] {
+        context
+            .tool_install()
+            .arg(package)
+            .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+            .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+            .assert()
+            .success();
+    }
+
+    // `--keep` can't be combined with explicit names.
+    uv_snapshot!(context.filters(), context.tool_uninstall().arg("foo").arg("--keep").arg("bar")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '<NAME>...' cannot be used with '--keep <KEEP>'
+
+    Usage: uv tool uninstall --cache-dir [CACHE_DIR] <NAME>...
+
+    For more information, try '--help'.
+    ");
+
+    // Unknown names passed to `--keep` are reported, as they're likely misspelled.
+    uv_snapshot!(context.filters(), context.tool_uninstall().arg("--all").arg("--keep").arg("bar").arg("--keep").arg("qux")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `qux` was passed to `--keep`, but is not installed
+    Uninstalled 2 executables: baz, foo
+    ");
+
+    uv_snapshot!(context.filters(), context.tool_list()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    bar v0.1.0
+    - bar
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}